@@ -370,6 +370,8 @@ fn main() -> Result<()> {
                         backend: "tesseract".to_string(),
                         language: "eng".to_string(),
                         tesseract_config: None,
+                        fallback: None,
+                        vocabulary: None,
                     });
                 } else {
                     config.ocr = None;
@@ -390,6 +392,7 @@ fn main() -> Result<()> {
                         max_overlap,
                         embedding: None,
                         preset: None,
+                        respect_sentences: false,
                     });
                 } else {
                     config.chunking = None;
@@ -469,6 +472,8 @@ fn main() -> Result<()> {
                         backend: "tesseract".to_string(),
                         language: "eng".to_string(),
                         tesseract_config: None,
+                        fallback: None,
+                        vocabulary: None,
                     });
                 } else {
                     config.ocr = None;
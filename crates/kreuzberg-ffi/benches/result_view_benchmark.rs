@@ -51,6 +51,7 @@ fn create_test_result(content_size: usize, chunk_count: usize) -> ExtractionResu
                             total_chunks: chunk_count,
                             first_page: Some(1 + (i / 10)),
                             last_page: Some(1 + (i / 10)),
+                            annotations: Default::default(),
                         },
                     }
                 })
@@ -69,6 +70,10 @@ fn create_test_result(content_size: usize, chunk_count: usize) -> ExtractionResu
         chunks,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     }
 }
 
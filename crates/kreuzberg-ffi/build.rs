@@ -12,12 +12,9 @@ fn main() {
 fn run() -> Result<(), String> {
     let crate_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| "CARGO_MANIFEST_DIR not set".to_string())?;
 
-    let config =
-        cbindgen::Config::from_file("cbindgen.toml").map_err(|e| format!("Failed to load cbindgen config: {}", e))?;
+    let out_dir = PathBuf::from(env::var("OUT_DIR").map_err(|_| "OUT_DIR not set".to_string())?);
 
-    cbindgen::generate_with_config(&crate_dir, config)
-        .map_err(|e| format!("Failed to generate C bindings: {}", e))?
-        .write_to_file("kreuzberg.h");
+    generate_header(&crate_dir, &out_dir)?;
 
     // Generate pkg-config files
     let pc_template = std::fs::read_to_string("kreuzberg-ffi.pc.in")
@@ -49,7 +46,6 @@ fn run() -> Result<(), String> {
         _ => "",
     };
 
-    let out_dir = PathBuf::from(env::var("OUT_DIR").map_err(|_| "OUT_DIR not set".to_string())?);
     let profile_dir = out_dir
         .ancestors()
         .nth(3)
@@ -99,12 +95,60 @@ Cflags: -I${{includedir}}
     }
 
     println!("cargo:rerun-if-changed=cbindgen.toml");
-    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-env-changed={}", HEADER_CHECK_ENV);
+    println!("cargo:rerun-if-env-changed={}", HEADER_OUT_ENV);
     println!("cargo:rerun-if-changed=kreuzberg-ffi.pc.in");
 
     Ok(())
 }
 
+/// Fail the build instead of rewriting `kreuzberg.h` when it is out of date (for CI).
+const HEADER_CHECK_ENV: &str = "KREUZBERG_FFI_HEADER_CHECK";
+/// Extra path the generated header is copied to, e.g. a binding's vendored copy.
+const HEADER_OUT_ENV: &str = "KREUZBERG_FFI_HEADER_OUT";
+
+/// Generate the C header from the `extern "C"` items of this crate.
+///
+/// The header is always written to `$OUT_DIR/kreuzberg.h`. The checked-in `kreuzberg.h`
+/// is rewritten only when its content changed, so unchanged builds leave it untouched.
+fn generate_header(crate_dir: &str, out_dir: &Path) -> Result<(), String> {
+    let config =
+        cbindgen::Config::from_file("cbindgen.toml").map_err(|e| format!("Failed to load cbindgen config: {}", e))?;
+
+    let bindings = cbindgen::generate_with_config(crate_dir, config)
+        .map_err(|e| format!("Failed to generate C bindings: {}", e))?;
+
+    let mut header = Vec::new();
+    bindings.write(&mut header);
+
+    fs::write(out_dir.join("kreuzberg.h"), &header).map_err(|e| format!("Failed to write generated header: {}", e))?;
+
+    let checked_in = Path::new(crate_dir).join("kreuzberg.h");
+    let up_to_date = fs::read(&checked_in).is_ok_and(|existing| existing == header);
+
+    if !up_to_date {
+        if env::var_os(HEADER_CHECK_ENV).is_some() {
+            return Err(format!(
+                "{} is out of date; rebuild kreuzberg-ffi without {} and commit the regenerated header",
+                checked_in.display(),
+                HEADER_CHECK_ENV
+            ));
+        }
+        fs::write(&checked_in, &header).map_err(|e| format!("Failed to write {}: {}", checked_in.display(), e))?;
+    }
+
+    if let Some(extra) = env::var_os(HEADER_OUT_ENV) {
+        let extra = PathBuf::from(extra);
+        if let Some(parent) = extra.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&extra, &header).map_err(|e| format!("Failed to write {}: {}", extra.display(), e))?;
+    }
+
+    Ok(())
+}
+
 /// Copy bundled PDFium library from kreuzberg build output to profile directory.
 /// This enables Java/other language bindings to find libpdfium.dylib/so/dll at runtime.
 fn copy_pdfium_to_profile_dir(profile_dir: &Path) -> Result<(), String> {
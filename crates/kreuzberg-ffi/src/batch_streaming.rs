@@ -208,6 +208,8 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_streaming(
         // Extract file
         match extract_file_internal(file_path, &config) {
             Ok(result) => {
+                crate::stats::record_result(&result);
+
                 // Create zero-copy view
                 let view = create_result_view(&result);
 
@@ -223,6 +225,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_streaming(
                 }
             }
             Err(e) => {
+                crate::stats::record_error(&e);
                 if let Some(err_cb) = error_callback
                     && let Ok(err_msg) = CString::new(e)
                 {
@@ -389,6 +392,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_parallel(
 
                 match extract_file_internal(path, &config) {
                     Ok(result) => {
+                        crate::stats::record_result(&result);
                         let view = create_result_view(&result);
 
                         // SAFETY: Callback must be thread-safe. user_data was converted to usize
@@ -401,6 +405,7 @@ pub unsafe extern "C" fn kreuzberg_extract_batch_parallel(
                         }
                     }
                     Err(e) => {
+                        crate::stats::record_error(&e);
                         if let Some(err_cb) = error_callback {
                             if let Ok(err_msg) = CString::new(e) {
                                 unsafe { err_cb(*index, err_msg.as_ptr(), user_data_ptr as *mut c_void) };
@@ -0,0 +1,324 @@
+//! Per-batch string interning for batch extraction results.
+//!
+//! Batch results repeat a handful of short strings (MIME types, language codes) across every
+//! document. Instead of allocating one C string per result, each batch keeps an interning
+//! table: every result of the batch points at the table's single copy of each distinct value,
+//! and the table is released with a single free once nothing references it any more.
+//!
+//! # Ownership
+//!
+//! The table is reference counted: the `CBatchResult` holds one reference and every shared
+//! `mime_type` / `language` field holds one. `kreuzberg_free_batch_result` and
+//! `kreuzberg_free_result` release their references instead of freeing shared strings, so
+//! results and their batch can be freed in any order. Shared strings must not be passed to
+//! `kreuzberg_free_string`.
+//!
+//! Results returned by the single-document functions never share strings.
+
+use crate::{CBatchResult, CExtractionResult, CStringGuard, kreuzberg_free_result};
+use std::collections::{HashMap, HashSet};
+use std::ffi::{CString, NulError};
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex, MutexGuard};
+
+/// A published interning table and the number of references left to it.
+struct SharedTable {
+    strings: Vec<CString>,
+    references: usize,
+}
+
+#[derive(Default)]
+struct TableRegistry {
+    next_id: u64,
+    tables: HashMap<u64, SharedTable>,
+    /// Shared string address -> owning table
+    owners: HashMap<usize, u64>,
+    /// `CBatchResult` address -> table it keeps alive
+    batches: HashMap<usize, u64>,
+}
+
+static REGISTRY: LazyLock<Mutex<TableRegistry>> = LazyLock::new(|| Mutex::new(TableRegistry::default()));
+
+/// Number of live tables, so freeing results outside of batches skips the registry lock.
+static LIVE_TABLES: AtomicUsize = AtomicUsize::new(0);
+
+fn registry() -> MutexGuard<'static, TableRegistry> {
+    // Every update leaves the registry consistent, so poisoning is ignored
+    REGISTRY.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl TableRegistry {
+    fn release(&mut self, id: u64) {
+        let Some(table) = self.tables.get_mut(&id) else {
+            return;
+        };
+
+        table.references -= 1;
+        if table.references > 0 {
+            return;
+        }
+
+        for string in &table.strings {
+            self.owners.remove(&(string.as_ptr() as usize));
+        }
+        // Dropping the table frees every shared string at once
+        self.tables.remove(&id);
+        LIVE_TABLES.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Interning table filled while a batch is converted to C results.
+pub(crate) struct BatchStrings {
+    strings: HashMap<String, CString>,
+}
+
+impl BatchStrings {
+    pub(crate) fn new() -> Self {
+        Self {
+            strings: HashMap::new(),
+        }
+    }
+
+    /// Return the table's copy of `value`, allocating it on first use.
+    ///
+    /// The pointer stays owned by the table; it is only valid in results later passed to
+    /// [`BatchStrings::publish`] or [`BatchStrings::discard`].
+    pub(crate) fn intern(&mut self, value: &str) -> Result<*mut c_char, NulError> {
+        if let Some(existing) = self.strings.get(value) {
+            return Ok(existing.as_ptr() as *mut c_char);
+        }
+
+        let c_string = CString::new(value)?;
+        // The heap buffer does not move when the CString is moved into the map
+        let ptr = c_string.as_ptr() as *mut c_char;
+        self.strings.insert(value.to_owned(), c_string);
+        Ok(ptr)
+    }
+
+    fn addresses(&self) -> HashSet<usize> {
+        self.strings.values().map(|s| s.as_ptr() as usize).collect()
+    }
+
+    /// Hand the table over to `batch`, counting one reference per shared field of its results.
+    pub(crate) fn publish(self, batch: *mut CBatchResult) {
+        if self.strings.is_empty() {
+            return;
+        }
+
+        let results = unsafe { std::slice::from_raw_parts((*batch).results, (*batch).count) };
+        let addresses = self.addresses();
+        let shared_fields = results
+            .iter()
+            .filter(|result| !result.is_null())
+            .map(|&result| {
+                let result = unsafe { &*result };
+                [result.mime_type, result.language]
+                    .iter()
+                    .filter(|field| addresses.contains(&(**field as usize)))
+                    .count()
+            })
+            .sum::<usize>();
+
+        let mut registry = registry();
+        let id = registry.next_id;
+        registry.next_id += 1;
+        for address in addresses {
+            registry.owners.insert(address, id);
+        }
+        registry.batches.insert(batch as usize, id);
+        registry.tables.insert(
+            id,
+            SharedTable {
+                strings: self.strings.into_values().collect(),
+                references: shared_fields + 1,
+            },
+        );
+        LIVE_TABLES.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Free `results` of a batch that failed to convert, together with the table.
+    pub(crate) fn discard(self, results: Vec<*mut CExtractionResult>) {
+        let addresses = self.addresses();
+        for result in results {
+            if !result.is_null() {
+                let fields = unsafe { &mut *result };
+                for field in [&mut fields.mime_type, &mut fields.language] {
+                    if addresses.contains(&(*field as usize)) {
+                        *field = ptr::null_mut();
+                    }
+                }
+            }
+            unsafe { kreuzberg_free_result(result) };
+        }
+    }
+}
+
+/// A result string field, either owned by the result or shared through a batch table.
+pub(crate) enum CStringField {
+    Owned(CStringGuard),
+    Shared(*mut c_char),
+}
+
+impl CStringField {
+    /// Allocate `value` for a single result, or intern it when converting a batch.
+    pub(crate) fn new(value: &str, strings: Option<&mut BatchStrings>) -> Result<Self, NulError> {
+        match strings {
+            Some(strings) => strings.intern(value).map(Self::Shared),
+            None => CString::new(value).map(|s| Self::Owned(CStringGuard::new(s))),
+        }
+    }
+
+    pub(crate) fn into_raw(self) -> *mut c_char {
+        match self {
+            Self::Owned(guard) => guard.into_raw(),
+            Self::Shared(ptr) => ptr,
+        }
+    }
+}
+
+/// Release a result field's reference if it points into a batch table.
+///
+/// Returns `false` for strings owned by the result, which the caller frees itself.
+pub(crate) fn release_shared_string(ptr: *mut c_char) -> bool {
+    if ptr.is_null() || LIVE_TABLES.load(Ordering::Relaxed) == 0 {
+        return false;
+    }
+
+    let mut registry = registry();
+    match registry.owners.get(&(ptr as usize)).copied() {
+        Some(id) => {
+            registry.release(id);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Release the reference a batch holds on its table, if it has one.
+pub(crate) fn release_batch_strings(batch: *mut CBatchResult) {
+    if LIVE_TABLES.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let mut registry = registry();
+    if let Some(id) = registry.batches.remove(&(batch as usize)) {
+        registry.release(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{kreuzberg_free_batch_result, to_c_batch_extraction_result};
+    use kreuzberg::core::config::ExtractionConfig;
+    use kreuzberg::types::{ExtractionResult, Metadata};
+    use std::ffi::CStr;
+
+    fn result(mime_type: &str, language: Option<&str>) -> ExtractionResult {
+        ExtractionResult {
+            content: "text".to_string(),
+            mime_type: mime_type.to_string(),
+            metadata: Metadata {
+                language: language.map(str::to_string),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    fn batch(results: Vec<ExtractionResult>) -> (*mut CBatchResult, Vec<*mut CExtractionResult>) {
+        let config = ExtractionConfig::default();
+        let mut strings = BatchStrings::new();
+        let c_results: Vec<_> = results
+            .into_iter()
+            .map(|result| to_c_batch_extraction_result(result, &config, &mut strings).unwrap())
+            .collect();
+
+        let count = c_results.len();
+        let results_ptr = Box::into_raw(c_results.clone().into_boxed_slice()) as *mut *mut CExtractionResult;
+        let batch = Box::into_raw(Box::new(CBatchResult {
+            results: results_ptr,
+            count,
+            success: true,
+            _padding2: [0u8; 7],
+        }));
+        strings.publish(batch);
+        (batch, c_results)
+    }
+
+    fn table_of(batch: *mut CBatchResult) -> Option<u64> {
+        registry().batches.get(&(batch as usize)).copied()
+    }
+
+    fn table_alive(id: u64) -> bool {
+        registry().tables.contains_key(&id)
+    }
+
+    #[test]
+    fn test_batch_results_share_repeated_strings() {
+        let (batch, results) = batch(vec![
+            result("application/pdf", Some("en")),
+            result("application/pdf", Some("en")),
+            result("text/plain", None),
+        ]);
+        let [first, second, third] = [results[0], results[1], results[2]].map(|r| unsafe { &*r });
+
+        assert_eq!(first.mime_type, second.mime_type);
+        assert_eq!(first.language, second.language);
+        assert_ne!(first.mime_type, third.mime_type);
+        assert!(third.language.is_null());
+        assert_eq!(
+            unsafe { CStr::from_ptr(third.mime_type) }.to_str().unwrap(),
+            "text/plain"
+        );
+
+        let id = table_of(batch).unwrap();
+        for result in results {
+            unsafe { kreuzberg_free_result(result) };
+        }
+        assert!(table_alive(id));
+
+        unsafe { kreuzberg_free_batch_result(batch) };
+        assert!(!table_alive(id));
+    }
+
+    #[test]
+    fn test_batch_freed_before_results_keeps_strings_alive() {
+        let (batch, results) = batch(vec![result("text/html", Some("de")), result("text/html", Some("de"))]);
+        let id = table_of(batch).unwrap();
+
+        unsafe { kreuzberg_free_batch_result(batch) };
+        assert!(table_alive(id));
+        assert_eq!(
+            unsafe { CStr::from_ptr((*results[1]).mime_type) }.to_str().unwrap(),
+            "text/html"
+        );
+
+        unsafe { kreuzberg_free_result(results[0]) };
+        assert!(table_alive(id));
+        unsafe { kreuzberg_free_result(results[1]) };
+        assert!(!table_alive(id));
+    }
+
+    #[test]
+    fn test_discard_frees_unpublished_results() {
+        let config = ExtractionConfig::default();
+        let mut strings = BatchStrings::new();
+        let results = vec![
+            to_c_batch_extraction_result(result("text/plain", Some("en")), &config, &mut strings).unwrap(),
+            to_c_batch_extraction_result(result("text/plain", Some("en")), &config, &mut strings).unwrap(),
+        ];
+
+        strings.discard(results);
+    }
+}
@@ -0,0 +1,176 @@
+//! Result cache inspection and invalidation FFI module.
+//!
+//! Extraction results are cached when the configuration has a `cache` section; see
+//! `kreuzberg::cache::backend`. Keys depend on the document bytes, so the entry of a
+//! replaced document stays cached until the backend evicts it, and a document whose bytes
+//! did not change keeps its result even when a registered plugin would now produce a
+//! different one. `kreuzberg_cache_key_for` returns the key of a file's cached result and
+//! `kreuzberg_cache_invalidate` removes that single entry, instead of clearing the whole cache.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! const char* config = "{\"cache\": {\"backend\": \"redis\", \"url\": \"redis://cache:6379/0\"}}";
+//! char* key = kreuzberg_cache_key_for("/uploads/report.pdf", config);
+//! if (key != NULL) {
+//!     // store the key next to the document, then on re-upload:
+//!     kreuzberg_cache_invalidate(key);
+//!     kreuzberg_free_string(key);
+//! }
+//! ```
+
+use crate::{clear_last_error, parse_extraction_config_from_json, set_last_error, string_to_c_string};
+use kreuzberg::cache::backend::{invalidate_result, result_cache_key_for_file};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::Path;
+use std::ptr;
+
+/// Cache key of the result of extracting a file with a configuration.
+///
+/// The key is a hex digest of the file's bytes, detected MIME type, configuration and
+/// Kreuzberg version, identical to the one used by `kreuzberg_extract_file_sync_with_config`
+/// with the same arguments. The configured cache backend is created if needed, so
+/// `kreuzberg_cache_invalidate` reaches it afterwards.
+///
+/// # Returns
+///
+/// The key, or NULL on error (check `kreuzberg_last_error`), including when the
+/// configuration has no `cache` section or sets `use_cache` to false.
+///
+/// # Safety
+///
+/// - `file_path` must be a valid null-terminated C string
+/// - `config_json` must be a valid null-terminated C string containing JSON
+/// - Returned string must be freed with `kreuzberg_free_string`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_cache_key_for(file_path: *const c_char, config_json: *const c_char) -> *mut c_char {
+    crate::ffi_panic_guard!("kreuzberg_cache_key_for", {
+        clear_last_error();
+
+        if file_path.is_null() || config_json.is_null() {
+            set_last_error("file_path and config_json cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        // SAFETY: Both pointers were checked for NULL and must be null-terminated strings.
+        let (path_str, config_str) = match (
+            unsafe { CStr::from_ptr(file_path) }.to_str(),
+            unsafe { CStr::from_ptr(config_json) }.to_str(),
+        ) {
+            (Ok(path), Ok(config)) => (path, config),
+            (Err(e), _) | (_, Err(e)) => {
+                set_last_error(format!("Invalid UTF-8 in argument: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = match parse_extraction_config_from_json(config_str) {
+            Ok(config) => config,
+            Err(e) => {
+                set_last_error(e);
+                return ptr::null_mut();
+            }
+        };
+
+        match result_cache_key_for_file(Path::new(path_str), None, &config) {
+            Ok(Some(key)) => match string_to_c_string(key) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Ok(None) => {
+                set_last_error("Result caching is not enabled: the configuration has no cache section".to_string());
+                ptr::null_mut()
+            }
+            Err(e) => {
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Remove the cached result stored under `key`.
+///
+/// The key is removed from every cache backend this process has used, whether through an
+/// extraction or `kreuzberg_cache_key_for`. A key that is not cached is not an error.
+///
+/// # Returns
+///
+/// true on success, false on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `key` must be a valid null-terminated C string returned by `kreuzberg_cache_key_for`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_cache_invalidate(key: *const c_char) -> bool {
+    crate::ffi_panic_guard_bool!("kreuzberg_cache_invalidate", {
+        clear_last_error();
+
+        if key.is_null() {
+            set_last_error("key cannot be NULL".to_string());
+            return false;
+        }
+
+        // SAFETY: The pointer was checked for NULL and must be a null-terminated string.
+        let key = match unsafe { CStr::from_ptr(key) }.to_str() {
+            Ok(key) => key,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in key: {}", e));
+                return false;
+            }
+        };
+
+        match invalidate_result(key) {
+            Ok(_) => true,
+            Err(e) => {
+                set_last_error(e.to_string());
+                false
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_cache_key_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("note.txt");
+        std::fs::write(&path, "hello").unwrap();
+        let path = CString::new(path.to_str().unwrap()).unwrap();
+        let config = CString::new(r#"{"cache": {"backend": "memory", "max_entries": 4}}"#).unwrap();
+
+        let key = unsafe { kreuzberg_cache_key_for(path.as_ptr(), config.as_ptr()) };
+        assert!(!key.is_null());
+        let key_str = unsafe { CStr::from_ptr(key) }.to_str().unwrap().to_string();
+        assert!(key_str.bytes().all(|b| b.is_ascii_hexdigit()));
+
+        let again = unsafe { kreuzberg_cache_key_for(path.as_ptr(), config.as_ptr()) };
+        assert_eq!(unsafe { CStr::from_ptr(again) }.to_str().unwrap(), key_str);
+
+        assert!(unsafe { kreuzberg_cache_invalidate(key) });
+        unsafe {
+            crate::kreuzberg_free_string(key);
+            crate::kreuzberg_free_string(again);
+        }
+    }
+
+    #[test]
+    fn test_cache_key_requires_cache_section() {
+        let path = CString::new("Cargo.toml").unwrap();
+        let config = CString::new("{}").unwrap();
+
+        let key = unsafe { kreuzberg_cache_key_for(path.as_ptr(), config.as_ptr()) };
+        assert!(key.is_null());
+
+        let invalid = CString::new("../../etc/passwd").unwrap();
+        assert!(!unsafe { kreuzberg_cache_invalidate(invalid.as_ptr()) });
+    }
+}
@@ -12,6 +12,7 @@
 
 use crate::{clear_last_error, set_last_error};
 use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::core::fields::FieldSelection;
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use std::ptr;
@@ -406,6 +407,42 @@ pub unsafe extern "C" fn kreuzberg_config_merge(
     base_ref.enable_quality_processing = override_ref.enable_quality_processing;
     base_ref.force_ocr = override_ref.force_ocr;
     base_ref.max_concurrent_extractions = override_ref.max_concurrent_extractions;
+    base_ref.emit_term_offsets = override_ref.emit_term_offsets;
+    base_ref.emit_provenance = override_ref.emit_provenance;
+    base_ref.detect_barcodes = override_ref.detect_barcodes;
+    base_ref.incremental = override_ref.incremental;
+    base_ref.resource_profile = override_ref.resource_profile;
+    if override_ref.adaptive_concurrency.is_some() {
+        base_ref.adaptive_concurrency = override_ref.adaptive_concurrency.clone();
+    }
+    if override_ref.fields.is_some() {
+        base_ref.fields = override_ref.fields.clone();
+    }
+    if override_ref.spill_dir.is_some() {
+        base_ref.spill_dir = override_ref.spill_dir.clone();
+    }
+    if override_ref.spill_threshold_bytes.is_some() {
+        base_ref.spill_threshold_bytes = override_ref.spill_threshold_bytes;
+    }
+    if override_ref.input_policy.is_some() {
+        base_ref.input_policy = override_ref.input_policy.clone();
+    }
+    if override_ref.stage_timeouts.is_some() {
+        base_ref.stage_timeouts = override_ref.stage_timeouts.clone();
+    }
+    if override_ref.retry.is_some() {
+        base_ref.retry = override_ref.retry.clone();
+    }
+    if override_ref.cache.is_some() {
+        base_ref.cache = override_ref.cache.clone();
+    }
+    // Overrides merge per MIME pattern: patterns of the override config replace the same patterns of the base
+    if let Some(overrides) = &override_ref.overrides {
+        base_ref
+            .overrides
+            .get_or_insert_with(Default::default)
+            .extend(overrides.clone());
+    }
 
     // Merge nested optional fields
     if override_ref.ocr.is_some() {
@@ -433,10 +470,54 @@ pub unsafe extern "C" fn kreuzberg_config_merge(
         base_ref.language_detection = override_ref.language_detection.clone();
     }
 
+    if override_ref.ocr_correction.is_some() {
+        base_ref.ocr_correction = override_ref.ocr_correction.clone();
+    }
+
+    if override_ref.classification.is_some() {
+        base_ref.classification = override_ref.classification.clone();
+    }
+
+    if override_ref.kv_extraction.is_some() {
+        base_ref.kv_extraction = override_ref.kv_extraction.clone();
+    }
+
+    if override_ref.preview.is_some() {
+        base_ref.preview = override_ref.preview.clone();
+    }
+
     if override_ref.pages.is_some() {
         base_ref.pages = override_ref.pages.clone();
     }
 
+    if override_ref.normalization.is_some() {
+        base_ref.normalization = override_ref.normalization.clone();
+    }
+
+    if override_ref.cleanup.is_some() {
+        base_ref.cleanup = override_ref.cleanup.clone();
+    }
+
+    if override_ref.watermarks.is_some() {
+        base_ref.watermarks = override_ref.watermarks.clone();
+    }
+
+    if override_ref.text_options.is_some() {
+        base_ref.text_options = override_ref.text_options.clone();
+    }
+
+    if override_ref.csv_options.is_some() {
+        base_ref.csv_options = override_ref.csv_options.clone();
+    }
+
+    if override_ref.spreadsheet_options.is_some() {
+        base_ref.spreadsheet_options = override_ref.spreadsheet_options.clone();
+    }
+
+    if override_ref.pptx_options.is_some() {
+        base_ref.pptx_options = override_ref.pptx_options.clone();
+    }
+
     #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
     if override_ref.keywords.is_some() {
         base_ref.keywords = override_ref.keywords.clone();
@@ -785,6 +866,17 @@ fn parse_extraction_config_from_json(json_str: &str) -> FfiResult<ExtractionConf
         config.html_options = Some(parse_html_options(html_opts_val)?);
     }
 
+    if let Some(policy) = config.images.as_ref().and_then(|images| images.ocr_policy.as_ref())
+        && policy.pages.as_ref().is_some_and(|pages| pages.contains(&0))
+    {
+        return Err("images.ocr_policy.pages entries must be 1-indexed page numbers".to_string());
+    }
+
+    FieldSelection::new(config.fields.as_deref())
+        .validate()
+        .map_err(|e| e.to_string())?;
+    kreuzberg::core::overrides::validate_overrides(&config).map_err(|e| e.to_string())?;
+
     Ok(config)
 }
 
@@ -821,6 +913,129 @@ mod tests {
         assert_eq!(ocr.language, "eng");
     }
 
+    #[test]
+    fn test_parse_config_with_auto_ocr_language() {
+        let json = r#"{"force_ocr": true, "ocr": {"language": "auto"}}"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        let ocr = config.ocr.unwrap();
+        assert_eq!(ocr.backend, "tesseract");
+        assert_eq!(ocr.language, "auto");
+    }
+
+    #[test]
+    fn test_parse_config_with_ocr_vocabulary() {
+        let json = r#"{"ocr": {"vocabulary": {"words": ["Metformin", "XJ-4410"], "max_distance": 2}}}"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        let vocabulary = config.ocr.unwrap().vocabulary.unwrap();
+        assert_eq!(vocabulary.words, ["Metformin", "XJ-4410"]);
+        assert_eq!(vocabulary.words_file, None);
+        assert!(vocabulary.correct);
+        assert_eq!(vocabulary.max_distance, 2);
+        assert_eq!(vocabulary.min_word_length, 4);
+    }
+
+    #[test]
+    fn test_parse_config_with_ocr_correction() {
+        let json = r#"{
+            "ocr_correction": {
+                "rules": [
+                    {"from": "5", "to": "S", "context": "alphabetic"},
+                    {"from": "vv", "to": "w"}
+                ]
+            }
+        }"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        let correction = config.ocr_correction.unwrap();
+        assert!(correction.builtin_rules);
+        assert_eq!(correction.rules.len(), 2);
+        assert_eq!(correction.rules[0].context, kreuzberg::OcrConfusionContext::Alphabetic);
+        assert_eq!(correction.rules[1].context, kreuzberg::OcrConfusionContext::Anywhere);
+    }
+
+    #[test]
+    fn test_parse_config_with_classification() {
+        let json = r#"{
+            "classification": {
+                "min_confidence": 0.5,
+                "types": [
+                    {"label": "purchase_order", "keywords": ["purchase order", "ship to"]},
+                    {"label": "invoice", "keywords": ["remit to"], "title_keywords": ["rechnung"]}
+                ]
+            }
+        }"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        let classification = config.classification.unwrap();
+        assert!(classification.builtin_types);
+        assert_eq!(classification.min_confidence, 0.5);
+        assert_eq!(classification.types.len(), 2);
+        assert!(classification.types[0].title_keywords.is_empty());
+        assert_eq!(classification.types[1].title_keywords, vec!["rechnung"]);
+    }
+
+    #[test]
+    fn test_parse_config_with_kv_extraction() {
+        let json = r#"{"kv_extraction": {"labels": ["Kundennummer"], "max_label_words": 3}}"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        let kv_extraction = config.kv_extraction.unwrap();
+        assert!(kv_extraction.builtin_labels);
+        assert_eq!(kv_extraction.labels, vec!["Kundennummer"]);
+        assert_eq!(kv_extraction.max_label_words, 3);
+    }
+
+    #[test]
+    fn test_parse_config_with_image_ocr_policy() {
+        let json = r#"{
+            "images": {
+                "extract_images": true,
+                "ocr_policy": {"min_width": 32, "min_bytes": 2048, "pages": [1, 2], "skip_decorative": true}
+            }
+        }"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        let policy = config.images.unwrap().ocr_policy.unwrap();
+        assert_eq!(policy.min_width, Some(32));
+        assert_eq!(policy.min_height, None);
+        assert_eq!(policy.min_bytes, Some(2048));
+        assert_eq!(policy.pages, Some(vec![1, 2]));
+        assert!(policy.skip_masks);
+        assert!(policy.skip_decorative);
+
+        let json = r#"{"images": {"ocr_policy": {"pages": [0]}}}"#;
+        let err = parse_extraction_config_from_json(json).unwrap_err();
+        assert!(err.contains("1-indexed"));
+    }
+
+    #[test]
+    fn test_parse_config_with_normalization() {
+        let json = r#"{"normalization": {"unicode": "nfkc", "dehyphenate": false}}"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        let normalization = config.normalization.unwrap();
+        assert_eq!(normalization.unicode, Some(kreuzberg::UnicodeNormalization::Nfkc));
+        assert!(!normalization.dehyphenate);
+        assert!(normalization.collapse_whitespace);
+
+        let json = r#"{"normalization": {"unicode": null}}"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        assert_eq!(config.normalization.unwrap().unicode, None);
+    }
+
+    #[test]
+    fn test_parse_config_with_fields() {
+        let json = r#"{"fields": ["content", "metadata.title", "tables"]}"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        assert_eq!(
+            config.fields,
+            Some(vec![
+                "content".to_string(),
+                "metadata.title".to_string(),
+                "tables".to_string()
+            ])
+        );
+
+        let json = r#"{"fields": ["contents"]}"#;
+        let err = parse_extraction_config_from_json(json).unwrap_err();
+        assert!(err.contains("Unknown result field 'contents'"));
+    }
+
     #[test]
     fn test_parse_invalid_json() {
         let json = "{invalid json}";
@@ -848,6 +1063,16 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_parse_chunking_respect_sentences() {
+        let json = r#"{"chunking": {"max_chars": 500, "max_overlap": 50, "respect_sentences": true}}"#;
+        let config = parse_extraction_config_from_json(json).unwrap();
+        assert!(config.chunking.unwrap().respect_sentences);
+
+        let config = parse_extraction_config_from_json(r#"{"chunking": {"max_chars": 500}}"#).unwrap();
+        assert!(!config.chunking.unwrap().respect_sentences);
+    }
+
     #[test]
     fn test_config_to_json() {
         let json_str = r#"{"use_cache": true}"#;
@@ -963,6 +1188,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_config_merge_format_options() {
+        let base_json = r#"{"csv_options": {"max_rows": 10}}"#;
+        let override_json = r#"{"spreadsheet_options": {"max_rows": 5}, "pptx_options": {"include_notes": false}}"#;
+
+        let base_ptr = unsafe { kreuzberg_config_from_json(std::ffi::CString::new(base_json).unwrap().as_ptr()) };
+        let override_ptr =
+            unsafe { kreuzberg_config_from_json(std::ffi::CString::new(override_json).unwrap().as_ptr()) };
+
+        assert!(!base_ptr.is_null());
+        assert!(!override_ptr.is_null());
+
+        let result = unsafe { kreuzberg_config_merge(base_ptr, override_ptr) };
+        assert_eq!(result, 1);
+
+        let base_ref = unsafe { &*base_ptr };
+        assert_eq!(base_ref.csv_options.as_ref().and_then(|c| c.max_rows), Some(10));
+        assert_eq!(base_ref.spreadsheet_options.as_ref().and_then(|s| s.max_rows), Some(5));
+        assert!(!base_ref.pptx_options.as_ref().unwrap().include_notes);
+
+        unsafe {
+            kreuzberg_config_free(base_ptr);
+            kreuzberg_config_free(override_ptr);
+        }
+    }
+
+    #[test]
+    fn test_config_merge_post_processor_sections() {
+        let base_json = r#"{"classification": {"min_confidence": 0.5}}"#;
+        let override_json = r#"{"ocr_correction": {}, "kv_extraction": {"max_label_words": 3}}"#;
+
+        let base_ptr = unsafe { kreuzberg_config_from_json(std::ffi::CString::new(base_json).unwrap().as_ptr()) };
+        let override_ptr =
+            unsafe { kreuzberg_config_from_json(std::ffi::CString::new(override_json).unwrap().as_ptr()) };
+
+        let result = unsafe { kreuzberg_config_merge(base_ptr, override_ptr) };
+        assert_eq!(result, 1);
+
+        let base_ref = unsafe { &*base_ptr };
+        assert_eq!(base_ref.classification.as_ref().unwrap().min_confidence, 0.5);
+        assert!(base_ref.ocr_correction.is_some());
+        assert_eq!(base_ref.kv_extraction.as_ref().unwrap().max_label_words, 3);
+
+        unsafe {
+            kreuzberg_config_free(base_ptr);
+            kreuzberg_config_free(override_ptr);
+        }
+    }
+
+    #[test]
+    fn test_config_merge_mime_overrides() {
+        let base_json = r#"{"overrides": {"image/*": {"force_ocr": true}, "text/html": {"use_cache": false}}}"#;
+        let override_json = r#"{"overrides": {"image/*": {"ocr": {"language": "deu"}}}}"#;
+
+        let base_ptr = unsafe { kreuzberg_config_from_json(std::ffi::CString::new(base_json).unwrap().as_ptr()) };
+        let override_ptr =
+            unsafe { kreuzberg_config_from_json(std::ffi::CString::new(override_json).unwrap().as_ptr()) };
+
+        let result = unsafe { kreuzberg_config_merge(base_ptr, override_ptr) };
+        assert_eq!(result, 1);
+
+        let overrides = unsafe { &*base_ptr }.overrides.as_ref().unwrap();
+        assert_eq!(overrides["image/*"], serde_json::json!({"ocr": {"language": "deu"}}));
+        assert!(overrides.contains_key("text/html"));
+
+        unsafe {
+            kreuzberg_config_free(base_ptr);
+            kreuzberg_config_free(override_ptr);
+        }
+    }
+
+    #[test]
+    fn test_parse_config_rejects_invalid_override() {
+        let json = r#"{"overrides": {"image/*": {"force_ocr": "yes"}}}"#;
+
+        let err = parse_extraction_config_from_json(json).unwrap_err();
+        assert!(err.contains("image/*"));
+    }
+
     #[test]
     fn test_config_merge_null_base() {
         let override_json = r#"{"force_ocr": true}"#;
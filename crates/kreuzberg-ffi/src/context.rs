@@ -0,0 +1,420 @@
+//! Configuration contexts FFI module.
+//!
+//! A server that extracts documents for several tenants must not let one tenant's OCR
+//! languages, redaction post-processors or cached results reach another. A context bundles
+//! the configuration of one tenant, created once with `kreuzberg_context_new`, and the
+//! `kreuzberg_context_extract_*` functions extract with it instead of a per-call config.
+//!
+//! The context JSON is an extraction configuration with two optional additions:
+//!
+//! - `namespace` - cache namespace of the tenant; overrides `cache.namespace`, so results of
+//!   different contexts are never shared even when their documents and configurations match.
+//!   Without a `cache` section the context caches with the default (disk) backend
+//! - `plugins` - names of the application-registered post-processors the context runs
+//!   (default: all of them). Built-in post-processors (classification, key-value extraction,
+//!   preview, quality, OCR correction, ...) are not affected and keep running as configured;
+//!   overrides `postprocessor.enabled_processors`
+//!
+//! Contexts are immutable and can be shared between threads. Plugin registration stays
+//! process-wide; `plugins` only selects which registered post-processors a context runs.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! KreuzbergContext* tenant_a = kreuzberg_context_new(
+//!     "{\"namespace\": \"tenant-a\", \"plugins\": [\"redact_pii\"],"
+//!     " \"ocr\": {\"language\": \"deu\"}, \"cache\": {\"backend\": \"memory\"}}");
+//! KreuzbergContext* tenant_b = kreuzberg_context_new(
+//!     "{\"namespace\": \"tenant-b\", \"plugins\": [], \"cache\": {\"backend\": \"memory\"}}");
+//!
+//! CExtractionResult* result = kreuzberg_context_extract_file_sync(tenant_a, "/uploads/a/scan.pdf");
+//! // ...
+//! kreuzberg_free_result(result);
+//!
+//! kreuzberg_context_clear_cache(tenant_b);
+//! kreuzberg_context_free(tenant_a);
+//! kreuzberg_context_free(tenant_b);
+//! ```
+
+use crate::{
+    CExtractionResult, FfiResult, clear_last_error, crash_dump, parse_extraction_config_from_json, set_last_error,
+    to_c_extraction_result,
+};
+use kreuzberg::cache::backend::backend_for;
+use kreuzberg::core::config::{CacheConfig, ExtractionConfig};
+use kreuzberg::core::pipeline::BUILTIN_POST_PROCESSORS;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::Path;
+use std::ptr;
+
+/// Configuration of one tenant, used by the `kreuzberg_context_*` functions.
+///
+/// Created by `kreuzberg_context_new` and released with `kreuzberg_context_free`.
+/// Immutable after creation, so a context can be used from several threads at once.
+pub struct KreuzbergContext {
+    config: ExtractionConfig,
+}
+
+impl KreuzbergContext {
+    /// Parse a context JSON: an extraction configuration plus `namespace` and `plugins`.
+    fn from_json(json: &str) -> FfiResult<Self> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse context JSON: {}", e))?;
+        let object = value
+            .as_object_mut()
+            .ok_or_else(|| "Context JSON must be an object".to_string())?;
+
+        let namespace = match object.remove("namespace") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(namespace)) => Some(namespace),
+            Some(_) => return Err("Context namespace must be a string".to_string()),
+        };
+        let plugins = match object.remove("plugins") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(plugins) => Some(
+                serde_json::from_value::<Vec<String>>(plugins)
+                    .map_err(|_| "Context plugins must be an array of post-processor names".to_string())?,
+            ),
+        };
+
+        let mut config = parse_extraction_config_from_json(&value.to_string())?;
+        if let Some(namespace) = namespace {
+            config.cache.get_or_insert_with(CacheConfig::default).namespace = Some(namespace);
+        }
+        if let Some(plugins) = plugins {
+            // `enabled_processors` is a whitelist over every post-processor, built-ins included
+            let enabled = BUILTIN_POST_PROCESSORS
+                .iter()
+                .map(|name| name.to_string())
+                .chain(plugins)
+                .collect();
+            let mut postprocessor = config.postprocessor.take().unwrap_or_default();
+            postprocessor.enabled_processors = Some(enabled);
+            postprocessor.build_lookup_sets();
+            config.postprocessor = Some(postprocessor);
+        }
+
+        // Surface an invalid namespace here instead of on the first extraction
+        if let Some(cache) = config.cache.as_ref().filter(|_| config.use_cache) {
+            backend_for(cache).map_err(|e| e.to_string())?;
+        }
+
+        Ok(Self { config })
+    }
+}
+
+/// Borrow the context behind `context`, recording an error when it is NULL.
+///
+/// # Safety
+///
+/// `context` must be NULL or a pointer returned by `kreuzberg_context_new` that was not freed.
+unsafe fn context_ref<'a>(context: *const KreuzbergContext) -> Option<&'a KreuzbergContext> {
+    if context.is_null() {
+        set_last_error("context cannot be NULL".to_string());
+        return None;
+    }
+    // SAFETY: Checked for NULL above; the caller guarantees the context is live.
+    Some(unsafe { &*context })
+}
+
+/// Create a context with its own configuration, cache namespace and post-processor set.
+///
+/// # Returns
+///
+/// The context, or NULL on error (check `kreuzberg_last_error`), including for an invalid
+/// configuration or cache namespace.
+///
+/// # Safety
+///
+/// - `config_json` must be a valid null-terminated C string containing JSON, or NULL for
+///   the default configuration
+/// - The returned context must be freed with `kreuzberg_context_free`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_context_new(config_json: *const c_char) -> *mut KreuzbergContext {
+    crate::ffi_panic_guard!("kreuzberg_context_new", {
+        clear_last_error();
+
+        let json = if config_json.is_null() {
+            "{}"
+        } else {
+            // SAFETY: The pointer was checked for NULL and must be a null-terminated string.
+            match unsafe { CStr::from_ptr(config_json) }.to_str() {
+                Ok(json) => json,
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in context JSON: {}", e));
+                    return ptr::null_mut();
+                }
+            }
+        };
+
+        match KreuzbergContext::from_json(json) {
+            Ok(context) => Box::into_raw(Box::new(context)),
+            Err(e) => {
+                set_last_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Free a context.
+///
+/// Results extracted with the context stay valid.
+///
+/// # Safety
+///
+/// - `context` must be a pointer returned by `kreuzberg_context_new`, or NULL
+/// - `context` must not be used after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_context_free(context: *mut KreuzbergContext) {
+    if !context.is_null() {
+        // SAFETY: The pointer was created by Box::into_raw in kreuzberg_context_new.
+        drop(unsafe { Box::from_raw(context) });
+    }
+}
+
+/// Extract a file with the configuration of a context (synchronous).
+///
+/// # Safety
+///
+/// - `context` must be a valid pointer returned by `kreuzberg_context_new`
+/// - `file_path` must be a valid null-terminated C string
+/// - The returned pointer must be freed with `kreuzberg_free_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_context_extract_file_sync(
+    context: *const KreuzbergContext,
+    file_path: *const c_char,
+) -> *mut CExtractionResult {
+    crate::ffi_panic_guard!("kreuzberg_context_extract_file_sync", {
+        clear_last_error();
+
+        // SAFETY: The caller guarantees the context is NULL or live.
+        let Some(context) = (unsafe { context_ref(context) }) else {
+            return ptr::null_mut();
+        };
+        if file_path.is_null() {
+            set_last_error("file_path cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        // SAFETY: The pointer was checked for NULL and must be a null-terminated string.
+        let path = match unsafe { CStr::from_ptr(file_path) }.to_str() {
+            Ok(path) => Path::new(path),
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in file path: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = &context.config;
+        crash_dump::record_input(Some(crash_dump::CrashDocument::File(path)), config);
+
+        match kreuzberg::extract_file_sync(path, None, config) {
+            Ok(result) => match to_c_extraction_result(result, config) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                crate::stats::record_error(&e.to_string());
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Extract a byte array with the configuration of a context (synchronous).
+///
+/// # Safety
+///
+/// - `context` must be a valid pointer returned by `kreuzberg_context_new`
+/// - `data` must be a valid pointer to a byte array of length `data_len`
+/// - `mime_type` must be a valid null-terminated C string
+/// - The returned pointer must be freed with `kreuzberg_free_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_context_extract_bytes_sync(
+    context: *const KreuzbergContext,
+    data: *const u8,
+    data_len: usize,
+    mime_type: *const c_char,
+) -> *mut CExtractionResult {
+    crate::ffi_panic_guard!("kreuzberg_context_extract_bytes_sync", {
+        clear_last_error();
+
+        // SAFETY: The caller guarantees the context is NULL or live.
+        let Some(context) = (unsafe { context_ref(context) }) else {
+            return ptr::null_mut();
+        };
+        if data.is_null() || mime_type.is_null() {
+            set_last_error("data and mime_type cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        // SAFETY: The caller guarantees `data` points to `data_len` readable bytes.
+        let bytes = unsafe { std::slice::from_raw_parts(data, data_len) };
+        // SAFETY: The pointer was checked for NULL and must be a null-terminated string.
+        let mime_type = match unsafe { CStr::from_ptr(mime_type) }.to_str() {
+            Ok(mime_type) => mime_type,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in MIME type: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = &context.config;
+        crash_dump::record_input(
+            Some(crash_dump::CrashDocument::Bytes { data: bytes, mime_type }),
+            config,
+        );
+
+        match kreuzberg::extract_bytes_sync(bytes, mime_type, config) {
+            Ok(result) => match to_c_extraction_result(result, config) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                crate::stats::record_error(&e.to_string());
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Remove every cached result of a context.
+///
+/// Only the context's cache namespace is cleared; other contexts keep their results.
+///
+/// # Returns
+///
+/// The number of removed results, 0 when the context does not cache results, or -1 on
+/// error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `context` must be a valid pointer returned by `kreuzberg_context_new`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_context_clear_cache(context: *const KreuzbergContext) -> i64 {
+    crate::ffi_panic_guard_i32!("kreuzberg_context_clear_cache", {
+        clear_last_error();
+
+        // SAFETY: The caller guarantees the context is NULL or live.
+        let Some(context) = (unsafe { context_ref(context) }) else {
+            return -1;
+        };
+        let Some(cache) = context.config.cache.as_ref().filter(|_| context.config.use_cache) else {
+            return 0;
+        };
+
+        match backend_for(cache).and_then(|backend| backend.clear()) {
+            Ok(removed) => i64::try_from(removed).unwrap_or(i64::MAX),
+            Err(e) => {
+                set_last_error(e.to_string());
+                -1
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kreuzberg::core::config::CacheBackendKind;
+    use std::ffi::CString;
+
+    fn context(json: &str) -> *mut KreuzbergContext {
+        let json = CString::new(json).unwrap();
+        unsafe { kreuzberg_context_new(json.as_ptr()) }
+    }
+
+    #[test]
+    fn test_context_applies_namespace_and_plugins() {
+        let context = context(
+            r#"{"namespace": "ctx-test-a", "plugins": ["quality"], "cache": {"backend": "memory"}, "force_ocr": true}"#,
+        );
+        assert!(!context.is_null());
+
+        let config = unsafe { &(*context).config };
+        assert!(config.force_ocr);
+        assert_eq!(config.cache.as_ref().unwrap().namespace.as_deref(), Some("ctx-test-a"));
+        let enabled = config.postprocessor.as_ref().unwrap().enabled_set.as_ref().unwrap();
+        assert!(enabled.contains("quality"));
+        assert!(enabled.contains("document-classification"));
+        assert!(!enabled.contains("redact_pii"));
+
+        unsafe { kreuzberg_context_free(context) };
+    }
+
+    #[test]
+    fn test_context_without_plugins_keeps_builtin_processors() {
+        let context = context(r#"{"plugins": [], "classification": {}, "use_cache": false}"#);
+        assert!(!context.is_null());
+        let data = b"Dear Ms Jones,\n\nPlease find the documents enclosed.\n\nYours sincerely,\nJ. Smith";
+        let mime = CString::new("text/plain").unwrap();
+
+        let result = unsafe { kreuzberg_context_extract_bytes_sync(context, data.as_ptr(), data.len(), mime.as_ptr()) };
+        assert!(!result.is_null());
+        let metadata = unsafe { std::ffi::CStr::from_ptr((*result).metadata_json) };
+        let metadata: serde_json::Value = serde_json::from_str(metadata.to_str().unwrap()).unwrap();
+        assert_eq!(metadata["document_type"]["label"], "letter");
+
+        unsafe {
+            crate::kreuzberg_free_result(result);
+            kreuzberg_context_free(context);
+        }
+    }
+
+    #[test]
+    fn test_context_namespace_without_cache_section() {
+        // Caching stays off so the test does not create a disk cache directory
+        let context = context(r#"{"namespace": "ctx-test-d", "use_cache": false}"#);
+        assert!(!context.is_null());
+
+        let cache = unsafe { (*context).config.cache.as_ref() }.expect("namespace implies a cache");
+        assert_eq!(cache.namespace.as_deref(), Some("ctx-test-d"));
+        assert_eq!(cache.backend, CacheBackendKind::Disk);
+
+        unsafe { kreuzberg_context_free(context) };
+    }
+
+    #[test]
+    fn test_context_caches_are_isolated() {
+        let tenant_a = context(r#"{"namespace": "ctx-test-b", "cache": {"backend": "memory"}}"#);
+        let tenant_b = context(r#"{"namespace": "ctx-test-c", "cache": {"backend": "memory"}}"#);
+        let data = b"tenant document";
+        let mime = CString::new("text/plain").unwrap();
+
+        let result =
+            unsafe { kreuzberg_context_extract_bytes_sync(tenant_a, data.as_ptr(), data.len(), mime.as_ptr()) };
+        assert!(!result.is_null());
+        unsafe { crate::kreuzberg_free_result(result) };
+
+        assert_eq!(unsafe { kreuzberg_context_clear_cache(tenant_b) }, 0);
+        assert_eq!(unsafe { kreuzberg_context_clear_cache(tenant_a) }, 1);
+
+        unsafe {
+            kreuzberg_context_free(tenant_a);
+            kreuzberg_context_free(tenant_b);
+        }
+    }
+
+    #[test]
+    fn test_context_rejects_invalid_input() {
+        assert!(context(r#"{"namespace": "../other", "cache": {"backend": "memory"}}"#).is_null());
+        assert!(context(r#"{"plugins": "quality"}"#).is_null());
+        assert!(context("[]").is_null());
+
+        let path = CString::new("Cargo.toml").unwrap();
+        assert!(unsafe { kreuzberg_context_extract_file_sync(ptr::null(), path.as_ptr()) }.is_null());
+        assert_eq!(unsafe { kreuzberg_context_clear_cache(ptr::null()) }, -1);
+    }
+}
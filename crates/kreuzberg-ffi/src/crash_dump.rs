@@ -0,0 +1,370 @@
+//! Crash-dump capture for panics caught at the FFI boundary.
+//!
+//! Hosts register a [`CrashDumpCallback`] with `kreuzberg_set_crash_dump_callback`. Whenever one of
+//! the `ffi_panic_guard*` macros catches a panic, the callback receives a JSON crash dump with the
+//! backtrace, the FFI entry point (stage) that panicked, a fingerprint of the document being
+//! extracted and a hash of the effective configuration. Document contents and file paths are never
+//! included, so dumps can be shipped to a crash reporter as-is.
+//!
+//! Nothing is captured while no callback is registered: the panic hook that records backtraces is
+//! only installed on first registration, and extraction inputs are only fingerprinted while a
+//! callback is active.
+
+use kreuzberg::ExtractionConfig;
+use kreuzberg::cache::blob_store::blob_hash;
+use kreuzberg::panic_context::PanicContext;
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+use std::path::{Path, PathBuf};
+use std::sync::{Once, RwLock};
+use std::time::UNIX_EPOCH;
+
+/// Version of the crash-dump JSON layout.
+const CRASH_DUMP_VERSION: u32 = 1;
+
+/// Callback invoked with a crash dump after a panic was caught at the FFI boundary.
+///
+/// # Arguments
+///
+/// * `dump_json` - Null-terminated JSON crash dump, valid only for the duration of the call
+/// * `user_data` - The pointer passed to `kreuzberg_set_crash_dump_callback`
+///
+/// # Safety
+///
+/// The callback runs on the thread that panicked, before the failing FFI call returns. It must
+/// copy `dump_json` if it needs it afterwards and must not unwind across the FFI boundary.
+pub type CrashDumpCallback = unsafe extern "C" fn(dump_json: *const c_char, user_data: *mut c_void);
+
+#[derive(Clone, Copy)]
+struct Registration {
+    callback: CrashDumpCallback,
+    /// Stored as an address so the registration can live in a static.
+    user_data: usize,
+}
+
+/// Details of a panic captured by the panic hook, before the guard sees the unwound payload.
+struct CapturedPanic {
+    backtrace: String,
+    location: Option<String>,
+    thread: Option<String>,
+}
+
+/// Document being extracted by the current FFI call.
+pub(crate) enum CrashDocument<'a> {
+    File(&'a Path),
+    Bytes { data: &'a [u8], mime_type: &'a str },
+}
+
+enum RecordedDocument {
+    File(PathBuf),
+    Bytes {
+        fingerprint: String,
+        size_bytes: usize,
+        mime_type: String,
+    },
+}
+
+struct RecordedInput {
+    document: Option<RecordedDocument>,
+    config_hash: Option<String>,
+}
+
+static REGISTRATION: RwLock<Option<Registration>> = RwLock::new(None);
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    static CURRENT_INPUT: RefCell<Option<RecordedInput>> = const { RefCell::new(None) };
+    // Per thread so concurrent FFI calls never pick up each other's backtraces. Panics raised on
+    // worker threads stay with the worker; the guard then reports the message without a backtrace.
+    static LAST_PANIC: RefCell<Option<CapturedPanic>> = const { RefCell::new(None) };
+}
+
+fn registration() -> Option<Registration> {
+    REGISTRATION.read().ok().and_then(|registration| *registration)
+}
+
+/// Returns whether a crash-dump callback is currently registered.
+pub fn is_enabled() -> bool {
+    registration().is_some()
+}
+
+fn install_panic_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if is_enabled() {
+                let captured = CapturedPanic {
+                    backtrace: Backtrace::force_capture().to_string(),
+                    location: info
+                        .location()
+                        .map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column())),
+                    thread: std::thread::current().name().map(str::to_string),
+                };
+                LAST_PANIC.with(|slot| *slot.borrow_mut() = Some(captured));
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Forgets the input and panic recorded by a previous FFI call on this thread.
+pub fn clear_thread_state() {
+    CURRENT_INPUT.with(|input| *input.borrow_mut() = None);
+    LAST_PANIC.with(|slot| *slot.borrow_mut() = None);
+}
+
+/// Records the document and configuration of the current FFI call so that a crash dump can
+/// identify them. Does nothing while no callback is registered.
+pub(crate) fn record_input(document: Option<CrashDocument<'_>>, config: &ExtractionConfig) {
+    if !is_enabled() {
+        return;
+    }
+
+    let document = document.map(|document| match document {
+        CrashDocument::File(path) => RecordedDocument::File(path.to_path_buf()),
+        CrashDocument::Bytes { data, mime_type } => RecordedDocument::Bytes {
+            fingerprint: blob_hash(data),
+            size_bytes: data.len(),
+            mime_type: mime_type.to_string(),
+        },
+    });
+    let config_hash = serde_json::to_vec(config).ok().map(|json| blob_hash(&json));
+
+    CURRENT_INPUT.with(|input| *input.borrow_mut() = Some(RecordedInput { document, config_hash }));
+}
+
+fn document_json(document: RecordedDocument) -> serde_json::Value {
+    match document {
+        // Files are only read once a panic happened, keeping the happy path free of extra I/O.
+        RecordedDocument::File(path) => match std::fs::read(&path) {
+            Ok(data) => serde_json::json!({
+                "fingerprint": blob_hash(&data),
+                "size_bytes": data.len(),
+                "mime_type": kreuzberg::detect_mime_type(&path, false).ok(),
+            }),
+            Err(_) => serde_json::json!({
+                "fingerprint": null,
+                "size_bytes": null,
+                "mime_type": kreuzberg::detect_mime_type(&path, false).ok(),
+            }),
+        },
+        RecordedDocument::Bytes {
+            fingerprint,
+            size_bytes,
+            mime_type,
+        } => serde_json::json!({
+            "fingerprint": fingerprint,
+            "size_bytes": size_bytes,
+            "mime_type": mime_type,
+        }),
+    }
+}
+
+fn build_dump(context: &PanicContext, captured: Option<CapturedPanic>, input: Option<RecordedInput>) -> String {
+    let (document, config_hash) = match input {
+        Some(input) => (input.document.map(document_json), input.config_hash),
+        None => (None, None),
+    };
+    let timestamp_ms = context
+        .timestamp
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0);
+
+    serde_json::json!({
+        "version": CRASH_DUMP_VERSION,
+        "kreuzberg_version": env!("CARGO_PKG_VERSION"),
+        "stage": context.function,
+        "message": context.message,
+        "location": captured.as_ref().and_then(|captured| captured.location.clone()),
+        "guard_location": format!("{}:{}", context.file, context.line),
+        "thread": captured.as_ref().and_then(|captured| captured.thread.clone()),
+        "timestamp_ms": timestamp_ms,
+        "backtrace": captured.map(|captured| captured.backtrace),
+        "document": document,
+        "config_hash": config_hash,
+    })
+    .to_string()
+}
+
+/// Delivers a crash dump for a panic caught by an FFI guard to the registered callback.
+pub fn report(context: &PanicContext) {
+    let input = CURRENT_INPUT.with(|input| input.borrow_mut().take());
+    let captured = LAST_PANIC.with(|slot| slot.borrow_mut().take());
+
+    // Copied out of the lock so the callback may re-register without deadlocking.
+    let Some(registration) = registration() else {
+        return;
+    };
+    let Ok(dump) = CString::new(build_dump(context, captured, input)) else {
+        return;
+    };
+
+    // SAFETY: the registrant guarantees the callback and user_data stay valid while registered.
+    unsafe { (registration.callback)(dump.as_ptr(), registration.user_data as *mut c_void) };
+}
+
+/// Register a callback receiving a JSON crash dump whenever a panic is caught at the FFI boundary.
+///
+/// The dump is a JSON object with `version`, `kreuzberg_version`, `stage` (the FFI function that
+/// panicked), `message`, `location`, `guard_location`, `thread`, `timestamp_ms`, `backtrace`,
+/// `document` (`fingerprint`, `size_bytes`, `mime_type`, or null) and `config_hash`. Registering a
+/// callback replaces the previous one; passing NULL unregisters it.
+///
+/// The failing call still returns its usual error value and `kreuzberg_last_error_code` reports
+/// a panic, so crash reporting is independent of regular error handling.
+///
+/// # Safety
+///
+/// * `callback` must remain callable, and `user_data` valid, until it is unregistered
+/// * `callback` may be invoked from any thread that calls into the library
+///
+/// # Example (C)
+///
+/// ```c
+/// void on_crash(const char* dump_json, void* user_data) {
+///     report_to_crash_service(dump_json);
+/// }
+///
+/// kreuzberg_set_crash_dump_callback(on_crash, NULL);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_set_crash_dump_callback(
+    callback: Option<CrashDumpCallback>,
+    user_data: *mut c_void,
+) {
+    let registration = callback.map(|callback| Registration {
+        callback,
+        user_data: user_data as usize,
+    });
+    if registration.is_some() {
+        install_panic_hook();
+    }
+    if let Ok(mut slot) = REGISTRATION.write() {
+        *slot = registration;
+    }
+    LAST_PANIC.with(|slot| *slot.borrow_mut() = None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::sync::Mutex as StdMutex;
+
+    // The registration is process-wide, so tests touching it must not interleave.
+    static TEST_LOCK: StdMutex<()> = StdMutex::new(());
+    static DUMPS: StdMutex<Vec<String>> = StdMutex::new(Vec::new());
+
+    unsafe extern "C" fn collect_dump(dump_json: *const c_char, user_data: *mut c_void) {
+        assert_eq!(user_data as usize, 0xC0FFEE);
+        let dump = unsafe { CStr::from_ptr(dump_json) }.to_string_lossy().into_owned();
+        DUMPS.lock().unwrap().push(dump);
+    }
+
+    fn take_dumps() -> Vec<serde_json::Value> {
+        DUMPS
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|dump| serde_json::from_str(&dump).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_no_dump_without_callback() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { kreuzberg_set_crash_dump_callback(None, std::ptr::null_mut()) };
+        take_dumps();
+
+        let result: *mut c_char = crate::ffi_panic_guard!("test_no_callback", { panic!("boom") });
+
+        assert!(result.is_null());
+        assert!(take_dumps().is_empty());
+    }
+
+    #[test]
+    fn test_dump_describes_panic_and_input() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { kreuzberg_set_crash_dump_callback(Some(collect_dump), 0xC0FFEE as *mut c_void) };
+        take_dumps();
+
+        let config = ExtractionConfig::default();
+        let ok = crate::ffi_panic_guard_bool!("test_crash_stage", {
+            record_input(
+                Some(CrashDocument::Bytes {
+                    data: b"hello",
+                    mime_type: "text/plain",
+                }),
+                &config,
+            );
+            panic!("boom in stage")
+        });
+        unsafe { kreuzberg_set_crash_dump_callback(None, std::ptr::null_mut()) };
+
+        assert!(!ok);
+        let dumps = take_dumps();
+        assert_eq!(dumps.len(), 1);
+        let dump = &dumps[0];
+        assert_eq!(dump["version"], CRASH_DUMP_VERSION);
+        assert_eq!(dump["stage"], "test_crash_stage");
+        assert!(dump["message"].as_str().unwrap().contains("boom in stage"));
+        assert!(dump["location"].as_str().unwrap().contains("crash_dump.rs"));
+        assert!(dump["backtrace"].is_string());
+        assert_eq!(dump["document"]["fingerprint"], blob_hash(b"hello"));
+        assert_eq!(dump["document"]["size_bytes"], 5);
+        assert_eq!(dump["document"]["mime_type"], "text/plain");
+        assert_eq!(
+            dump["config_hash"],
+            blob_hash(&serde_json::to_vec(&config).unwrap()).as_str()
+        );
+    }
+
+    #[test]
+    fn test_input_is_not_carried_over_between_calls() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { kreuzberg_set_crash_dump_callback(Some(collect_dump), 0xC0FFEE as *mut c_void) };
+        take_dumps();
+
+        let config = ExtractionConfig::default();
+        let first = crate::ffi_panic_guard_i32!("test_first_call", {
+            record_input(Some(CrashDocument::File(Path::new("missing.pdf"))), &config);
+            0
+        });
+        let second = crate::ffi_panic_guard_i32!("test_second_call", { panic!("unrelated") });
+        unsafe { kreuzberg_set_crash_dump_callback(None, std::ptr::null_mut()) };
+
+        assert_eq!(first, 0);
+        assert_eq!(second, -1);
+        let dumps = take_dumps();
+        assert_eq!(dumps.len(), 1);
+        assert!(dumps[0]["document"].is_null());
+        assert!(dumps[0]["config_hash"].is_null());
+    }
+
+    #[test]
+    fn test_panic_is_taken_by_the_guard_on_the_panicking_thread() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { kreuzberg_set_crash_dump_callback(Some(collect_dump), 0xC0FFEE as *mut c_void) };
+        take_dumps();
+
+        let worker = std::thread::spawn(|| {
+            let result = std::panic::catch_unwind(|| panic!("caught on another thread"));
+            let captured = LAST_PANIC.with(|slot| slot.borrow().is_some());
+            (result.is_err(), captured)
+        });
+        assert_eq!(worker.join().unwrap(), (true, true));
+        assert!(LAST_PANIC.with(|slot| slot.borrow().is_none()));
+
+        let result = crate::ffi_panic_guard_i32!("test_taken_stage", { panic!("taken") });
+        unsafe { kreuzberg_set_crash_dump_callback(None, std::ptr::null_mut()) };
+
+        assert_eq!(result, -1);
+        assert!(LAST_PANIC.with(|slot| slot.borrow().is_none()));
+        let dumps = take_dumps();
+        assert_eq!(dumps.len(), 1);
+        assert!(dumps[0]["location"].as_str().unwrap().contains("crash_dump.rs"));
+    }
+}
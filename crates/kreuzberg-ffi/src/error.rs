@@ -16,6 +16,14 @@
 //! - **Plugin (5)**: Plugin registration/execution errors
 //! - **UnsupportedFormat (6)**: Unsupported MIME type or file format
 //! - **Internal (7)**: Internal library errors (should rarely occur)
+//! - **FileTooLarge (8)**: Input larger than the input policy's `max_file_size_bytes`
+//! - **MimeTypeNotAllowed (9)**: MIME type missing from the input policy's `allowed_mime_types`
+//! - **ContentMismatch (10)**: Magic bytes contradict the declared or extension-derived MIME type
+//! - **EncryptedInput (11)**: Encrypted input rejected by the input policy
+//! - **Timeout (12)**: An extraction stage exceeded its budget in `ExtractionConfig::stage_timeouts`
+//!
+//! Codes 8-11 are raised by `ExtractionConfig::input_policy` and correspond to
+//! `kreuzberg::InputRejection`.
 //!
 //! # Usage in Bindings
 //!
@@ -30,6 +38,11 @@
 //!     PLUGIN = 5
 //!     UNSUPPORTED_FORMAT = 6
 //!     INTERNAL = 7
+//!     FILE_TOO_LARGE = 8
+//!     MIME_TYPE_NOT_ALLOWED = 9
+//!     CONTENT_MISMATCH = 10
+//!     ENCRYPTED_INPUT = 11
+//!     TIMEOUT = 12
 //! ```
 //!
 //! **Ruby** (packages/ruby/lib/kreuzberg.rb):
@@ -64,7 +77,12 @@
 //! - `kreuzberg_error_code_plugin()` -> 5
 //! - `kreuzberg_error_code_unsupported_format()` -> 6
 //! - `kreuzberg_error_code_internal()` -> 7
-//! - `kreuzberg_error_code_count()` -> 8
+//! - `kreuzberg_error_code_file_too_large()` -> 8
+//! - `kreuzberg_error_code_mime_type_not_allowed()` -> 9
+//! - `kreuzberg_error_code_content_mismatch()` -> 10
+//! - `kreuzberg_error_code_encrypted_input()` -> 11
+//! - `kreuzberg_error_code_timeout()` -> 12
+//! - `kreuzberg_error_code_count()` -> 13
 //! - `kreuzberg_error_code_name(code: u32)` -> *const c_char (error name)
 //!
 //! # Thread Safety
@@ -87,7 +105,7 @@ use std::ffi::CStr;
 /// # Repr and Stability
 ///
 /// - Uses `#[repr(u32)]` for C ABI compatibility
-/// - Error codes are guaranteed stable (0-7, never changing)
+/// - Error codes are guaranteed stable (0-12, never changing)
 /// - Can be safely cast to `int32_t` in C/C++ code
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -108,6 +126,16 @@ pub enum ErrorCode {
     UnsupportedFormat = 6,
     /// Internal library error (indicates a bug, should rarely occur)
     Internal = 7,
+    /// Input larger than the input policy allows
+    FileTooLarge = 8,
+    /// Input MIME type not allowed by the input policy
+    MimeTypeNotAllowed = 9,
+    /// Input content does not match its declared MIME type
+    ContentMismatch = 10,
+    /// Encrypted input rejected by the input policy
+    EncryptedInput = 11,
+    /// Extraction stage exceeded its time budget
+    Timeout = 12,
 }
 
 impl ErrorCode {
@@ -133,6 +161,11 @@ impl ErrorCode {
             ErrorCode::Plugin => "plugin",
             ErrorCode::UnsupportedFormat => "unsupported_format",
             ErrorCode::Internal => "internal",
+            ErrorCode::FileTooLarge => "file_too_large",
+            ErrorCode::MimeTypeNotAllowed => "mime_type_not_allowed",
+            ErrorCode::ContentMismatch => "content_mismatch",
+            ErrorCode::EncryptedInput => "encrypted_input",
+            ErrorCode::Timeout => "timeout",
         }
     }
 
@@ -150,12 +183,17 @@ impl ErrorCode {
             ErrorCode::Plugin => "Plugin error",
             ErrorCode::UnsupportedFormat => "Unsupported format",
             ErrorCode::Internal => "Internal library error",
+            ErrorCode::FileTooLarge => "Input exceeds the maximum file size",
+            ErrorCode::MimeTypeNotAllowed => "MIME type not allowed",
+            ErrorCode::ContentMismatch => "Content does not match the declared type",
+            ErrorCode::EncryptedInput => "Encrypted input not allowed",
+            ErrorCode::Timeout => "Extraction stage exceeded its time budget",
         }
     }
 
     /// Converts from numeric error code to enum variant.
     ///
-    /// Returns `None` if the code is outside the valid range [0, 7].
+    /// Returns `None` if the code is outside the valid range [0, 12].
     ///
     /// # Examples
     ///
@@ -175,22 +213,27 @@ impl ErrorCode {
             5 => Some(ErrorCode::Plugin),
             6 => Some(ErrorCode::UnsupportedFormat),
             7 => Some(ErrorCode::Internal),
+            8 => Some(ErrorCode::FileTooLarge),
+            9 => Some(ErrorCode::MimeTypeNotAllowed),
+            10 => Some(ErrorCode::ContentMismatch),
+            11 => Some(ErrorCode::EncryptedInput),
+            12 => Some(ErrorCode::Timeout),
             _ => None,
         }
     }
 
-    /// Checks if a numeric code is valid (within [0, 7]).
+    /// Checks if a numeric code is valid (within [0, 12]).
     ///
     /// # Examples
     ///
     /// ```rust,ignore
     /// assert!(ErrorCode::is_valid(0));
-    /// assert!(ErrorCode::is_valid(7));
-    /// assert!(!ErrorCode::is_valid(8));
+    /// assert!(ErrorCode::is_valid(12));
+    /// assert!(!ErrorCode::is_valid(13));
     /// ```
     #[inline]
     pub fn is_valid(code: u32) -> bool {
-        code <= 7
+        code <= 12
     }
 }
 
@@ -293,9 +336,69 @@ pub extern "C" fn kreuzberg_error_code_internal() -> u32 {
     ErrorCode::Internal as u32
 }
 
+/// Returns the file too large error code (8).
+///
+/// # C Signature
+///
+/// ```c
+/// uint32_t kreuzberg_error_code_file_too_large(void);
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_error_code_file_too_large() -> u32 {
+    ErrorCode::FileTooLarge as u32
+}
+
+/// Returns the MIME type not allowed error code (9).
+///
+/// # C Signature
+///
+/// ```c
+/// uint32_t kreuzberg_error_code_mime_type_not_allowed(void);
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_error_code_mime_type_not_allowed() -> u32 {
+    ErrorCode::MimeTypeNotAllowed as u32
+}
+
+/// Returns the content mismatch error code (10).
+///
+/// # C Signature
+///
+/// ```c
+/// uint32_t kreuzberg_error_code_content_mismatch(void);
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_error_code_content_mismatch() -> u32 {
+    ErrorCode::ContentMismatch as u32
+}
+
+/// Returns the encrypted input error code (11).
+///
+/// # C Signature
+///
+/// ```c
+/// uint32_t kreuzberg_error_code_encrypted_input(void);
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_error_code_encrypted_input() -> u32 {
+    ErrorCode::EncryptedInput as u32
+}
+
+/// Returns the timeout error code (12).
+///
+/// # C Signature
+///
+/// ```c
+/// uint32_t kreuzberg_error_code_timeout(void);
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_error_code_timeout() -> u32 {
+    ErrorCode::Timeout as u32
+}
+
 /// Returns the total count of valid error codes.
 ///
-/// Currently 8 error codes (0-7). This helps bindings validate error codes.
+/// Currently 13 error codes (0-12). This helps bindings validate error codes.
 ///
 /// # C Signature
 ///
@@ -304,14 +407,14 @@ pub extern "C" fn kreuzberg_error_code_internal() -> u32 {
 /// ```
 #[unsafe(no_mangle)]
 pub extern "C" fn kreuzberg_error_code_count() -> u32 {
-    8
+    13
 }
 
 /// Returns the name of an error code as a C string.
 ///
 /// # Arguments
 ///
-/// - `code`: Numeric error code (0-7)
+/// - `code`: Numeric error code (0-12)
 ///
 /// # Returns
 ///
@@ -344,6 +447,11 @@ pub extern "C" fn kreuzberg_error_code_name(code: u32) -> *const c_char {
             ErrorCode::Plugin => c"plugin".as_ptr(),
             ErrorCode::UnsupportedFormat => c"unsupported_format".as_ptr(),
             ErrorCode::Internal => c"internal".as_ptr(),
+            ErrorCode::FileTooLarge => c"file_too_large".as_ptr(),
+            ErrorCode::MimeTypeNotAllowed => c"mime_type_not_allowed".as_ptr(),
+            ErrorCode::ContentMismatch => c"content_mismatch".as_ptr(),
+            ErrorCode::EncryptedInput => c"encrypted_input".as_ptr(),
+            ErrorCode::Timeout => c"timeout".as_ptr(),
         },
         None => c"unknown".as_ptr(),
     }
@@ -353,7 +461,7 @@ pub extern "C" fn kreuzberg_error_code_name(code: u32) -> *const c_char {
 ///
 /// # Arguments
 ///
-/// - `code`: Numeric error code (0-7)
+/// - `code`: Numeric error code (0-12)
 ///
 /// # Returns
 ///
@@ -379,6 +487,11 @@ pub extern "C" fn kreuzberg_error_code_description(code: u32) -> *const c_char {
             ErrorCode::Plugin => c"Plugin error".as_ptr(),
             ErrorCode::UnsupportedFormat => c"Unsupported format".as_ptr(),
             ErrorCode::Internal => c"Internal library error".as_ptr(),
+            ErrorCode::FileTooLarge => c"Input exceeds the maximum file size".as_ptr(),
+            ErrorCode::MimeTypeNotAllowed => c"MIME type not allowed".as_ptr(),
+            ErrorCode::ContentMismatch => c"Content does not match the declared type".as_ptr(),
+            ErrorCode::EncryptedInput => c"Encrypted input not allowed".as_ptr(),
+            ErrorCode::Timeout => c"Extraction stage exceeded its time budget".as_ptr(),
         },
         None => c"Unknown error code".as_ptr(),
     }
@@ -395,7 +508,7 @@ pub extern "C" fn kreuzberg_error_code_description(code: u32) -> *const c_char {
 pub struct CErrorDetails {
     /// The error message (must be freed with kreuzberg_free_string)
     pub message: *mut c_char,
-    /// Numeric error code (0-7 for Kreuzberg errors, 1-7 for panic_shield codes)
+    /// Numeric error code (0-12 for Kreuzberg errors, 1-7 for panic_shield codes)
     pub error_code: u32,
     /// Human-readable error type name (must be freed with kreuzberg_free_string)
     pub error_type: *mut c_char,
@@ -422,7 +535,7 @@ pub struct CErrorDetails {
 /// A `CErrorDetails` structure with the following characteristics:
 /// - All non-NULL string pointers must be freed with `kreuzberg_free_string()`
 /// - NULL pointers indicate the field is not available
-/// - `error_code` is a numeric code (0-7)
+/// - `error_code` is a numeric code (0-12)
 /// - `source_line` is 0 if unknown
 /// - `is_panic` is 1 if error originated from a panic, 0 otherwise
 ///
@@ -522,7 +635,7 @@ pub extern "C" fn kreuzberg_get_error_details() -> CErrorDetails {
 /// Classifies an error based on the error message string.
 ///
 /// Analyzes an error message and attempts to classify it into one of the standard
-/// Kreuzberg error codes (0-7). This is useful for converting error messages from
+/// Kreuzberg error codes (0-12). This is useful for converting error messages from
 /// external libraries or system calls into Kreuzberg error categories.
 ///
 /// # Arguments
@@ -531,12 +644,14 @@ pub extern "C" fn kreuzberg_get_error_details() -> CErrorDetails {
 ///
 /// # Returns
 ///
-/// Numeric error code (0-7) indicating the most likely error classification.
+/// Numeric error code (0-12) indicating the most likely error classification.
 /// Returns 7 (Internal) if the message cannot be reliably classified.
 ///
 /// # Classification Rules
 ///
-/// The classifier looks for common keywords and patterns:
+/// Messages of input policy rejections (`Input rejected (<reason>): ...`) map to their
+/// reason's code (8-11) and stage timeouts (`Timeout in <stage> stage after <n> ms`) to 12.
+/// Otherwise the classifier looks for common keywords and patterns:
 /// - **0 (Validation)**: "invalid", "validation", "parameter", "constraint", "format mismatch"
 /// - **1 (Parsing)**: "parse", "parsing", "corrupt", "unexpected", "malformed", "invalid format"
 /// - **2 (OCR)**: "ocr", "tesseract", "recognition", "optical"
@@ -575,12 +690,30 @@ pub unsafe extern "C" fn kreuzberg_classify_error(error_message: *const c_char)
     }
 
     // SAFETY: Caller has verified that error_message is a valid null-terminated C string.
-    let message_str = match unsafe { std::ffi::CStr::from_ptr(error_message) }.to_str() {
-        Ok(s) => s,
-        Err(_) => return ErrorCode::Internal as u32,
-    };
+    match unsafe { std::ffi::CStr::from_ptr(error_message) }.to_str() {
+        Ok(message) => classify_error_message(message) as u32,
+        Err(_) => ErrorCode::Internal as u32,
+    }
+}
 
-    let lower = message_str.to_lowercase();
+/// Classify an error message with the rules of [`kreuzberg_classify_error`].
+pub(crate) fn classify_error_message(message: &str) -> ErrorCode {
+    let lower = message.to_lowercase();
+
+    // Input policy rejections name their reason, which maps to a code of its own
+    if let Some((_, rest)) = lower.split_once("input rejected (")
+        && let Some((reason, _)) = rest.split_once(')')
+        && let Some(code) = (8..=11)
+            .filter_map(ErrorCode::from_code)
+            .find(|code| code.name() == reason)
+    {
+        return code;
+    }
+
+    // Stage timeouts name the stage, which may itself match the keywords below
+    if lower.contains("timeout in ") && lower.contains(" stage after ") {
+        return ErrorCode::Timeout;
+    }
 
     // Check for missing dependency errors first (before OCR, since tesseract could match both)
     if lower.contains("not found")
@@ -589,7 +722,7 @@ pub unsafe extern "C" fn kreuzberg_classify_error(error_message: *const c_char)
         || lower.contains("not installed")
         || lower.contains("unavailable")
     {
-        return ErrorCode::MissingDependency as u32;
+        return ErrorCode::MissingDependency;
     }
 
     // Check for validation errors
@@ -599,7 +732,7 @@ pub unsafe extern "C" fn kreuzberg_classify_error(error_message: *const c_char)
         || lower.contains("constraint")
         || lower.contains("format mismatch")
     {
-        return ErrorCode::Validation as u32;
+        return ErrorCode::Validation;
     }
 
     // Check for parsing errors
@@ -609,7 +742,7 @@ pub unsafe extern "C" fn kreuzberg_classify_error(error_message: *const c_char)
         || lower.contains("unexpected")
         || lower.contains("malformed")
     {
-        return ErrorCode::Parsing as u32;
+        return ErrorCode::Parsing;
     }
 
     // Check for OCR errors (after missing dependency to avoid "missing tesseract" matching OCR)
@@ -618,7 +751,7 @@ pub unsafe extern "C" fn kreuzberg_classify_error(error_message: *const c_char)
         || lower.contains("recognition")
         || lower.contains("optical")
     {
-        return ErrorCode::Ocr as u32;
+        return ErrorCode::Ocr;
     }
 
     // Check for I/O errors
@@ -631,22 +764,22 @@ pub unsafe extern "C" fn kreuzberg_classify_error(error_message: *const c_char)
         || lower.contains("disk")
         || lower.contains("exists")
     {
-        return ErrorCode::Io as u32;
+        return ErrorCode::Io;
     }
 
     // Check for plugin errors
     if lower.contains("plugin") || lower.contains("loader") || lower.contains("registry") || lower.contains("extension")
     {
-        return ErrorCode::Plugin as u32;
+        return ErrorCode::Plugin;
     }
 
     // Check for unsupported format errors
     if lower.contains("unsupported") || lower.contains("unknown format") || lower.contains("mime type") {
-        return ErrorCode::UnsupportedFormat as u32;
+        return ErrorCode::UnsupportedFormat;
     }
 
     // Default to internal error
-    ErrorCode::Internal as u32
+    ErrorCode::Internal
 }
 
 #[cfg(test)]
@@ -663,6 +796,11 @@ mod tests {
         assert_eq!(ErrorCode::Plugin as u32, 5);
         assert_eq!(ErrorCode::UnsupportedFormat as u32, 6);
         assert_eq!(ErrorCode::Internal as u32, 7);
+        assert_eq!(ErrorCode::FileTooLarge as u32, 8);
+        assert_eq!(ErrorCode::MimeTypeNotAllowed as u32, 9);
+        assert_eq!(ErrorCode::ContentMismatch as u32, 10);
+        assert_eq!(ErrorCode::EncryptedInput as u32, 11);
+        assert_eq!(ErrorCode::Timeout as u32, 12);
     }
 
     #[test]
@@ -675,6 +813,9 @@ mod tests {
         assert_eq!(ErrorCode::Plugin.name(), "plugin");
         assert_eq!(ErrorCode::UnsupportedFormat.name(), "unsupported_format");
         assert_eq!(ErrorCode::Internal.name(), "internal");
+        assert_eq!(ErrorCode::FileTooLarge.name(), "file_too_large");
+        assert_eq!(ErrorCode::EncryptedInput.name(), "encrypted_input");
+        assert_eq!(ErrorCode::Timeout.name(), "timeout");
     }
 
     #[test]
@@ -699,29 +840,32 @@ mod tests {
         assert_eq!(ErrorCode::from_code(5), Some(ErrorCode::Plugin));
         assert_eq!(ErrorCode::from_code(6), Some(ErrorCode::UnsupportedFormat));
         assert_eq!(ErrorCode::from_code(7), Some(ErrorCode::Internal));
+        assert_eq!(ErrorCode::from_code(8), Some(ErrorCode::FileTooLarge));
+        assert_eq!(ErrorCode::from_code(11), Some(ErrorCode::EncryptedInput));
+        assert_eq!(ErrorCode::from_code(12), Some(ErrorCode::Timeout));
     }
 
     #[test]
     fn test_from_code_invalid() {
-        assert_eq!(ErrorCode::from_code(8), None);
+        assert_eq!(ErrorCode::from_code(13), None);
         assert_eq!(ErrorCode::from_code(99), None);
         assert_eq!(ErrorCode::from_code(u32::MAX), None);
     }
 
     #[test]
     fn test_is_valid() {
-        for code in 0..=7 {
+        for code in 0..=12 {
             assert!(ErrorCode::is_valid(code), "Code {} should be valid", code);
         }
 
-        assert!(!ErrorCode::is_valid(8));
+        assert!(!ErrorCode::is_valid(13));
         assert!(!ErrorCode::is_valid(99));
         assert!(!ErrorCode::is_valid(u32::MAX));
     }
 
     #[test]
     fn test_error_code_count() {
-        assert_eq!(kreuzberg_error_code_count(), 8);
+        assert_eq!(kreuzberg_error_code_count(), 13);
     }
 
     #[test]
@@ -734,6 +878,11 @@ mod tests {
         assert_eq!(kreuzberg_error_code_plugin(), 5);
         assert_eq!(kreuzberg_error_code_unsupported_format(), 6);
         assert_eq!(kreuzberg_error_code_internal(), 7);
+        assert_eq!(kreuzberg_error_code_file_too_large(), 8);
+        assert_eq!(kreuzberg_error_code_mime_type_not_allowed(), 9);
+        assert_eq!(kreuzberg_error_code_content_mismatch(), 10);
+        assert_eq!(kreuzberg_error_code_encrypted_input(), 11);
+        assert_eq!(kreuzberg_error_code_timeout(), 12);
     }
 
     // Note: The FFI pointer tests below are disabled due to a pre-existing issue with how
@@ -927,6 +1076,30 @@ mod tests {
         assert_eq!(unsafe { kreuzberg_classify_error(msg.as_ptr()) }, 6);
     }
 
+    #[test]
+    fn test_classify_error_input_rejected() {
+        let err = kreuzberg::KreuzbergError::input_rejected(
+            kreuzberg::InputRejection::FileTooLarge,
+            "Input is 20 bytes, the limit is 10 bytes",
+        );
+        let msg = CString::new(err.to_string()).unwrap();
+        assert_eq!(unsafe { kreuzberg_classify_error(msg.as_ptr()) }, 8);
+
+        let msg = CString::new("Input rejected (content_mismatch): Declared as 'application/pdf'").unwrap();
+        assert_eq!(unsafe { kreuzberg_classify_error(msg.as_ptr()) }, 10);
+
+        let msg = CString::new("Input rejected (encrypted_input): Encrypted PDF inputs are not accepted").unwrap();
+        assert_eq!(unsafe { kreuzberg_classify_error(msg.as_ptr()) }, 11);
+    }
+
+    #[test]
+    fn test_classify_error_timeout() {
+        let err =
+            kreuzberg::KreuzbergError::timeout(kreuzberg::ExtractionStage::Ocr, std::time::Duration::from_millis(1500));
+        let msg = CString::new(err.to_string()).unwrap();
+        assert_eq!(unsafe { kreuzberg_classify_error(msg.as_ptr()) }, 12);
+    }
+
     #[test]
     fn test_classify_error_internal() {
         let msg = CString::new("Something weird happened").unwrap();
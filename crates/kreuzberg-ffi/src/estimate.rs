@@ -0,0 +1,129 @@
+//! Dry-run cost estimate FFI module.
+//!
+//! `kreuzberg_estimate` inspects a document cheaply (page count, image coverage, text layer)
+//! and predicts how long extracting it takes and how much memory it needs, without
+//! extracting it. Schedulers use it to send heavy documents to bigger workers; see
+//! `kreuzberg::core::estimate` for the model.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! const char* config = "{\"ocr\": {\"backend\": \"tesseract\", \"language\": \"eng\"}}";
+//! char* estimate = kreuzberg_estimate("/uploads/scan.pdf", config);
+//! if (estimate != NULL) {
+//!     // {"profile":{"mime_type":"application/pdf","page_count":412,...},"ocr_pages":412,
+//!     //  "stages":[...],"duration_ms":1303370,"peak_memory_bytes":4120412160}
+//!     enqueue(estimate);
+//!     kreuzberg_free_string(estimate);
+//! }
+//! ```
+
+use crate::{clear_last_error, parse_extraction_config_from_json, set_last_error, string_to_c_string};
+use kreuzberg::core::estimate::estimate;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::Path;
+use std::ptr;
+
+/// Estimate the cost of extracting a file with a configuration, as JSON.
+///
+/// The object has `profile` (`mime_type`, `size_bytes`, `page_count`, `text_pages`,
+/// `image_coverage`, `has_text_layer`, `text_chars`), `ocr_pages`, `stages` (`stage` is
+/// `extraction`, `ocr` or `post_processing`, with `duration_ms` and `memory_bytes`),
+/// `duration_ms` and `peak_memory_bytes`. The file is read but not extracted.
+///
+/// # Returns
+///
+/// JSON object string, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `file_path` must be a valid null-terminated C string
+/// - `config_json` must be a valid null-terminated C string containing JSON
+/// - Returned string must be freed with `kreuzberg_free_string`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_estimate(file_path: *const c_char, config_json: *const c_char) -> *mut c_char {
+    crate::ffi_panic_guard!("kreuzberg_estimate", {
+        clear_last_error();
+
+        if file_path.is_null() || config_json.is_null() {
+            set_last_error("file_path and config_json cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        // SAFETY: Both pointers were checked for NULL and must be null-terminated strings.
+        let (path_str, config_str) = match (
+            unsafe { CStr::from_ptr(file_path) }.to_str(),
+            unsafe { CStr::from_ptr(config_json) }.to_str(),
+        ) {
+            (Ok(path), Ok(config)) => (path, config),
+            (Err(e), _) | (_, Err(e)) => {
+                set_last_error(format!("Invalid UTF-8 in argument: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = match parse_extraction_config_from_json(config_str) {
+            Ok(config) => config,
+            Err(e) => {
+                set_last_error(e);
+                return ptr::null_mut();
+            }
+        };
+
+        let estimate = match estimate(Path::new(path_str), &config) {
+            Ok(estimate) => estimate,
+            Err(e) => {
+                set_last_error(e.to_string());
+                return ptr::null_mut();
+            }
+        };
+
+        match serde_json::to_string(&estimate) {
+            Ok(json) => match string_to_c_string(json) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Failed to serialize estimate: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_estimate_text_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("note.txt");
+        std::fs::write(&path, "hello ".repeat(1000)).unwrap();
+        let path = CString::new(path.to_str().unwrap()).unwrap();
+        let config = CString::new("{}").unwrap();
+
+        let json = unsafe { kreuzberg_estimate(path.as_ptr(), config.as_ptr()) };
+        assert!(!json.is_null());
+        let value: serde_json::Value = serde_json::from_str(unsafe { CStr::from_ptr(json) }.to_str().unwrap()).unwrap();
+        assert_eq!(value["profile"]["mime_type"], "text/plain");
+        assert_eq!(value["profile"]["size_bytes"], 6000);
+        assert_eq!(value["ocr_pages"], 0);
+        assert_eq!(value["stages"][0]["stage"], "extraction");
+        unsafe { crate::kreuzberg_free_string(json) };
+    }
+
+    #[test]
+    fn test_estimate_missing_file() {
+        let path = CString::new("/nonexistent/scan.pdf").unwrap();
+        let config = CString::new("{}").unwrap();
+
+        assert!(unsafe { kreuzberg_estimate(path.as_ptr(), config.as_ptr()) }.is_null());
+        assert!(unsafe { kreuzberg_estimate(ptr::null(), config.as_ptr()) }.is_null());
+    }
+}
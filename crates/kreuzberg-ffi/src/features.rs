@@ -0,0 +1,78 @@
+//! Feature detection FFI module.
+//!
+//! Reports which features the loaded library was compiled with, so host SDKs can adapt at
+//! runtime (hide embedding options, skip OCR tests, refuse gRPC) instead of failing later
+//! with a missing symbol or a `MissingDependency` error.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! char* features = kreuzberg_features();
+//! if (features != NULL) {
+//!     printf("%s\n", features);
+//!     // {"version":"4.0.0","features":{"embeddings":true,"grpc-server":false,...},
+//!     //  "pdf_engine":{"name":"pdfium","linking":"bundled","version":"7350"},
+//!     //  "ocr_backends":[{"name":"tesseract","version":"5.5.1"}]}
+//!     kreuzberg_free_string(features);
+//! }
+//! ```
+
+use crate::{clear_last_error, set_last_error, string_to_c_string};
+use kreuzberg::features::compiled_features;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// Get the compiled-in features and component versions as JSON.
+///
+/// The object has `version` (Kreuzberg version), `features` (Cargo feature name to
+/// boolean, e.g. `embeddings`, `html`, `ocr`, `grpc-server`), `pdf_engine` (`null`
+/// without PDF support) and `ocr_backends` (registered OCR backends with versions).
+///
+/// # Returns
+///
+/// JSON object string, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - Returned string must be freed with `kreuzberg_free_string`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_features() -> *mut c_char {
+    crate::ffi_panic_guard!("kreuzberg_features", {
+        clear_last_error();
+
+        match serde_json::to_string(&compiled_features()) {
+            Ok(json) => match string_to_c_string(json) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Failed to serialize features: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kreuzberg_free_string;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_features_json() {
+        let ptr = unsafe { kreuzberg_features() };
+        assert!(!ptr.is_null());
+
+        let json = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { kreuzberg_free_string(ptr) };
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], env!("CARGO_PKG_VERSION"));
+        assert!(value["features"]["pdf"].is_boolean());
+        assert!(value["ocr_backends"].is_array());
+    }
+}
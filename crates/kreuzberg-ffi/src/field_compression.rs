@@ -0,0 +1,213 @@
+//! Compression of the large JSON fields of `CExtractionResult`.
+//!
+//! With `result_compression` set to `"gzip"` or `"zstd"` in the config JSON, `chunks_json`,
+//! `images_json` and `page_structure_json` carry the compressed JSON instead of a
+//! null-terminated string. Their lengths are in `chunks_json_len`, `images_json_len` and
+//! `page_structure_json_len`, and the codec in `json_field_encoding`. For documents with
+//! thousands of chunks or embedded images this cuts the bytes copied across the boundary,
+//! and hosts like the JVM build one string from the decompressed JSON instead of holding
+//! both an off-heap and an on-heap copy of a multi-megabyte field.
+//!
+//! Hosts decompress a field with `kreuzberg_decompress_field` (or their own gzip/zstd
+//! decoder) and read the JSON as before. `kreuzberg_free_result` frees compressed fields;
+//! they must not be passed to `kreuzberg_free_string`.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! const char* config = "{\"chunking\": {\"max_chars\": 1000}, \"result_compression\": \"zstd\"}";
+//! CExtractionResult* result = kreuzberg_extract_file_sync_with_config("book.pdf", config);
+//! if (result != NULL && result->chunks_json != NULL) {
+//!     char* chunks = kreuzberg_decompress_field(result->chunks_json, result->chunks_json_len,
+//!                                               result->json_field_encoding);
+//!     parse_chunks(chunks);
+//!     kreuzberg_free_string(chunks);
+//! }
+//! kreuzberg_free_result(result);
+//! ```
+
+use crate::{CStringGuard, clear_last_error, set_last_error, string_to_c_string};
+use kreuzberg::ResultCompression;
+use std::ffi::CString;
+use std::io::{Read, Write};
+use std::os::raw::c_char;
+use std::ptr;
+
+/// `json_field_encoding` of uncompressed fields
+pub(crate) const ENCODING_NONE: u32 = 0;
+/// `json_field_encoding` of gzip-compressed fields
+pub(crate) const ENCODING_GZIP: u32 = 1;
+/// `json_field_encoding` of zstd-compressed fields
+pub(crate) const ENCODING_ZSTD: u32 = 2;
+
+/// Level used for zstd, its default speed/ratio trade-off
+const ZSTD_LEVEL: i32 = 3;
+
+/// The `json_field_encoding` value of a codec.
+pub(crate) fn encoding_of(compression: Option<ResultCompression>) -> u32 {
+    match compression {
+        None => ENCODING_NONE,
+        Some(ResultCompression::Gzip) => ENCODING_GZIP,
+        Some(ResultCompression::Zstd) => ENCODING_ZSTD,
+    }
+}
+
+/// A JSON result field, either a C string or a compressed byte buffer, freed on drop.
+pub(crate) enum JsonField {
+    Text(CStringGuard, usize),
+    Compressed(Box<[u8]>),
+}
+
+impl JsonField {
+    /// Store `json` as a C string, or compressed with `compression`.
+    pub(crate) fn new(json: String, compression: Option<ResultCompression>) -> Result<Self, String> {
+        let Some(compression) = compression else {
+            let len = json.len();
+            let c_string = CString::new(json).map_err(|e| format!("Failed to convert JSON to C string: {}", e))?;
+            return Ok(Self::Text(CStringGuard::new(c_string), len));
+        };
+
+        let compressed = match compression {
+            ResultCompression::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+                encoder.write_all(json.as_bytes()).and_then(|_| encoder.finish())
+            }
+            ResultCompression::Zstd => zstd::encode_all(json.as_bytes(), ZSTD_LEVEL),
+        }
+        .map_err(|e| format!("Failed to compress JSON field: {}", e))?;
+        Ok(Self::Compressed(compressed.into_boxed_slice()))
+    }
+
+    /// Hand the field to the caller as a pointer and its length in bytes.
+    pub(crate) fn into_raw(self) -> (*mut c_char, usize) {
+        match self {
+            Self::Text(guard, len) => (guard.into_raw(), len),
+            Self::Compressed(bytes) => {
+                let len = bytes.len();
+                (Box::into_raw(bytes) as *mut u8 as *mut c_char, len)
+            }
+        }
+    }
+}
+
+/// Free a field produced by [`JsonField::into_raw`].
+///
+/// # Safety
+///
+/// `ptr`, `len` and `encoding` must come from the same `CExtractionResult` field.
+pub(crate) unsafe fn free_json_field(ptr: *mut c_char, len: usize, encoding: u32) {
+    if ptr.is_null() {
+        return;
+    }
+    if encoding == ENCODING_NONE {
+        unsafe { drop(CString::from_raw(ptr)) };
+    } else {
+        unsafe { drop(Box::from_raw(ptr::slice_from_raw_parts_mut(ptr as *mut u8, len))) };
+    }
+}
+
+fn decompress(data: &[u8], encoding: u32) -> Result<Vec<u8>, String> {
+    let mut json = Vec::new();
+    match encoding {
+        ENCODING_NONE => json.extend_from_slice(data),
+        ENCODING_GZIP => {
+            flate2::read::GzDecoder::new(data)
+                .read_to_end(&mut json)
+                .map_err(|e| format!("Failed to decompress gzip field: {}", e))?;
+        }
+        ENCODING_ZSTD => {
+            json = zstd::decode_all(data).map_err(|e| format!("Failed to decompress zstd field: {}", e))?;
+        }
+        other => return Err(format!("Unknown field encoding {} (expected 0, 1 or 2)", other)),
+    }
+    Ok(json)
+}
+
+/// Decompress a JSON field of a `CExtractionResult` into a null-terminated string.
+///
+/// `encoding` is the result's `json_field_encoding`: 0 (none), 1 (gzip) or 2 (zstd). With
+/// encoding 0 the first `len` bytes of `data` are copied, so hosts can call this for every
+/// field regardless of the configured compression.
+///
+/// # Returns
+///
+/// The JSON string, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `data` must point to at least `len` readable bytes, e.g. `chunks_json` and `chunks_json_len`
+/// - `data` stays owned by the result; free it with `kreuzberg_free_result`
+/// - Returned string must be freed with `kreuzberg_free_string`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_decompress_field(data: *const c_char, len: usize, encoding: u32) -> *mut c_char {
+    crate::ffi_panic_guard!("kreuzberg_decompress_field", {
+        clear_last_error();
+
+        if data.is_null() {
+            set_last_error("data cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        // SAFETY: The pointer was checked for NULL and the caller guarantees `len` readable bytes.
+        let bytes = unsafe { std::slice::from_raw_parts(data as *const u8, len) };
+        let json = match decompress(bytes, encoding).and_then(|json| {
+            String::from_utf8(json).map_err(|e| format!("Decompressed field is not valid UTF-8: {}", e))
+        }) {
+            Ok(json) => json,
+            Err(e) => {
+                set_last_error(e);
+                return ptr::null_mut();
+            }
+        };
+
+        match string_to_c_string(json) {
+            Ok(ptr) => ptr,
+            Err(e) => {
+                set_last_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn round_trip(compression: Option<ResultCompression>) {
+        let json = format!("[{}]", vec![r#"{"content":"chunk text"}"#; 200].join(","));
+        let (ptr, len) = JsonField::new(json.clone(), compression).unwrap().into_raw();
+        let encoding = encoding_of(compression);
+        if compression.is_some() {
+            assert!(len < json.len() / 4);
+        } else {
+            assert_eq!(len, json.len());
+        }
+
+        let decompressed = unsafe { kreuzberg_decompress_field(ptr, len, encoding) };
+        assert!(!decompressed.is_null());
+        assert_eq!(unsafe { CStr::from_ptr(decompressed) }.to_str().unwrap(), json);
+        unsafe {
+            crate::kreuzberg_free_string(decompressed);
+            free_json_field(ptr, len, encoding);
+        }
+    }
+
+    #[test]
+    fn test_fields_round_trip() {
+        round_trip(None);
+        round_trip(Some(ResultCompression::Gzip));
+        round_trip(Some(ResultCompression::Zstd));
+    }
+
+    #[test]
+    fn test_decompress_rejects_bad_input() {
+        let data = b"not compressed";
+        let ptr = data.as_ptr() as *const c_char;
+
+        assert!(unsafe { kreuzberg_decompress_field(ptr, data.len(), ENCODING_ZSTD) }.is_null());
+        assert!(unsafe { kreuzberg_decompress_field(ptr, data.len(), 7) }.is_null());
+        assert!(unsafe { kreuzberg_decompress_field(ptr::null(), 0, ENCODING_NONE) }.is_null());
+    }
+}
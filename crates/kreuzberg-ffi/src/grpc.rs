@@ -0,0 +1,117 @@
+//! gRPC server FFI module.
+//!
+//! Starts Kreuzberg's gRPC service (see `proto/kreuzberg/v1/kreuzberg.proto` in the
+//! kreuzberg crate) from a host process, so a supervisor can expose extraction to
+//! languages without a good C FFI story (Erlang, PHP) over the network instead.
+//!
+//! Requires the `grpc-server` feature; without it `kreuzberg_grpc_serve` fails with an
+//! error message.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! // Blocks until the server stops
+//! if (!kreuzberg_grpc_serve("127.0.0.1", 50051, NULL)) {
+//!     fprintf(stderr, "gRPC server failed: %s\n", kreuzberg_last_error());
+//! }
+//! ```
+
+use crate::{clear_last_error, set_last_error};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// Run the gRPC server, blocking the calling thread until it stops.
+///
+/// # Arguments
+///
+/// * `host` - IP address to bind to (e.g., "127.0.0.1")
+/// * `port` - Port to bind to (e.g., 50051)
+/// * `config_json` - Default extraction configuration as JSON, or NULL to discover
+///   kreuzberg.toml/yaml/json from the working directory
+///
+/// # Returns
+///
+/// `false` if the server could not start or failed (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `host` must be a valid null-terminated C string
+/// - `config_json` must be a valid null-terminated C string or NULL
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_grpc_serve(host: *const c_char, port: u16, config_json: *const c_char) -> bool {
+    crate::ffi_panic_guard!("kreuzberg_grpc_serve", {
+        clear_last_error();
+
+        if host.is_null() {
+            set_last_error("host cannot be NULL".to_string());
+            return false;
+        }
+
+        let host = match unsafe { CStr::from_ptr(host) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in host: {}", e));
+                return false;
+            }
+        };
+
+        let config_str = if config_json.is_null() {
+            None
+        } else {
+            match unsafe { CStr::from_ptr(config_json) }.to_str() {
+                Ok(s) => Some(s),
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in config JSON: {}", e));
+                    return false;
+                }
+            }
+        };
+
+        match serve(host, port, config_str) {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(e);
+                false
+            }
+        }
+    })
+}
+
+#[cfg(feature = "grpc-server")]
+fn serve(host: &str, port: u16, config_json: Option<&str>) -> Result<(), String> {
+    let config = config_json.map(crate::parse_extraction_config_from_json).transpose()?;
+
+    let rt = tokio::runtime::Runtime::new().map_err(|e| format!("Failed to create runtime: {}", e))?;
+
+    rt.block_on(async {
+        match config {
+            Some(config) => kreuzberg::grpc::serve_grpc_with_config(host, port, config).await,
+            None => kreuzberg::grpc::serve_grpc(host, port).await,
+        }
+    })
+    .map_err(|e| format!("gRPC server failed: {}", e))
+}
+
+#[cfg(not(feature = "grpc-server"))]
+fn serve(_host: &str, _port: u16, _config_json: Option<&str>) -> Result<(), String> {
+    Err("gRPC server requires kreuzberg-ffi to be built with the grpc-server feature".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_grpc_serve_rejects_null_host() {
+        let served = unsafe { kreuzberg_grpc_serve(std::ptr::null(), 50051, std::ptr::null()) };
+        assert!(!served);
+    }
+
+    #[test]
+    fn test_grpc_serve_rejects_invalid_host() {
+        let host = CString::new("not-an-ip").unwrap();
+        let served = unsafe { kreuzberg_grpc_serve(host.as_ptr(), 50051, std::ptr::null()) };
+        assert!(!served);
+    }
+}
@@ -0,0 +1,122 @@
+//! Image blob store FFI module.
+//!
+//! When `images.blob_store_dir` is configured, `images_json` carries a `blob` reference
+//! (`{"hash", "path", "size"}`) for each image instead of its bytes. This module reads
+//! the referenced bytes on demand, verifying them against the reference.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! // blob_json is the "blob" object of an entry in result->images_json
+//! uintptr_t len = 0;
+//! uint8_t* bytes = kreuzberg_image_blob_read(blob_json, &len);
+//! if (bytes != NULL) {
+//!     fwrite(bytes, 1, len, out);
+//!     kreuzberg_free_bytes(bytes, len);
+//! }
+//! ```
+
+use crate::{clear_last_error, set_last_error};
+use kreuzberg::cache::blob_store::read_blob;
+use kreuzberg::types::ImageBlobRef;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// Read the bytes of an image stored in the blob store.
+///
+/// # Arguments
+///
+/// * `blob_json` - The `blob` object of an extracted image as a JSON string
+/// * `out_len` - Pointer to receive the number of bytes
+///
+/// # Returns
+///
+/// Pointer to the image bytes, or NULL on error (check `kreuzberg_last_error`).
+/// Fails when the blob is missing or its bytes no longer match the reference.
+///
+/// # Safety
+///
+/// - `blob_json` must be a valid null-terminated C string
+/// - `out_len` must be a valid writable pointer
+/// - Returned bytes must be freed with `kreuzberg_free_bytes` using the length written to `out_len`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_image_blob_read(blob_json: *const c_char, out_len: *mut usize) -> *mut u8 {
+    crate::ffi_panic_guard!("kreuzberg_image_blob_read", {
+        if blob_json.is_null() || out_len.is_null() {
+            set_last_error("Blob JSON and out_len cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        clear_last_error();
+
+        // SAFETY: We've verified blob_json is not null and it must be a valid C string.
+        let json = match unsafe { CStr::from_ptr(blob_json) }.to_str() {
+            Ok(json) => json,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in blob JSON: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let blob: ImageBlobRef = match serde_json::from_str(json) {
+            Ok(blob) => blob,
+            Err(e) => {
+                set_last_error(format!("Invalid blob reference: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        match read_blob(&blob) {
+            Ok(data) => {
+                let data = data.into_boxed_slice();
+                // SAFETY: We've verified out_len is not null.
+                unsafe { *out_len = data.len() };
+                Box::into_raw(data) as *mut u8
+            }
+            Err(e) => {
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Free a byte buffer returned by `kreuzberg_image_blob_read`.
+///
+/// # Safety
+///
+/// - `ptr` must have been returned by `kreuzberg_image_blob_read`, or be NULL
+/// - `len` must be the length written to `out_len` by that call
+/// - `ptr` must not be used after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_free_bytes(ptr: *mut u8, len: usize) {
+    if !ptr.is_null() {
+        // SAFETY: ptr and len describe a boxed slice allocated by kreuzberg_image_blob_read.
+        unsafe { drop(Box::from_raw(ptr::slice_from_raw_parts_mut(ptr, len))) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kreuzberg::cache::blob_store::store_blob;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_image_blob_read_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let blob = store_blob(dir.path(), b"png bytes", "png").unwrap();
+        let json = CString::new(serde_json::to_string(&blob).unwrap()).unwrap();
+
+        let mut len = 0;
+        let bytes = unsafe { kreuzberg_image_blob_read(json.as_ptr(), &mut len) };
+        assert!(!bytes.is_null());
+        assert_eq!(unsafe { std::slice::from_raw_parts(bytes, len) }, b"png bytes");
+        unsafe { kreuzberg_free_bytes(bytes, len) };
+
+        std::fs::remove_file(&blob.path).unwrap();
+        let missing = unsafe { kreuzberg_image_blob_read(json.as_ptr(), &mut len) };
+        assert!(missing.is_null());
+    }
+}
@@ -4,40 +4,76 @@
 //! Go (cgo), C# (P/Invoke), Zig, and other languages with C FFI support.
 
 mod batch_streaming;
+mod batch_strings;
+mod cache;
 mod config;
+mod context;
+mod crash_dump;
 mod error;
+mod estimate;
+mod features;
+mod field_compression;
+mod grpc;
+mod image_blob;
+mod lifecycle;
+mod memory_stats;
 mod panic_shield;
 mod result;
 mod result_pool;
+mod result_verify;
 mod result_view;
+mod shared_memory;
+mod spreadsheet_streaming;
+mod stats;
 mod string_intern;
 mod validation;
 
 pub use batch_streaming::{
     ErrorCallback, ResultCallback, kreuzberg_extract_batch_parallel, kreuzberg_extract_batch_streaming,
 };
+pub use cache::{kreuzberg_cache_invalidate, kreuzberg_cache_key_for};
+pub use context::{
+    KreuzbergContext, kreuzberg_context_clear_cache, kreuzberg_context_extract_bytes_sync,
+    kreuzberg_context_extract_file_sync, kreuzberg_context_free, kreuzberg_context_new,
+};
+pub use crash_dump::{CrashDumpCallback, kreuzberg_set_crash_dump_callback};
 pub use error::ErrorCode as KreuzbergErrorCode;
 pub use error::{
-    CErrorDetails, kreuzberg_classify_error, kreuzberg_error_code_count, kreuzberg_error_code_description,
-    kreuzberg_error_code_internal, kreuzberg_error_code_io, kreuzberg_error_code_missing_dependency,
-    kreuzberg_error_code_name, kreuzberg_error_code_ocr, kreuzberg_error_code_parsing, kreuzberg_error_code_plugin,
+    CErrorDetails, kreuzberg_classify_error, kreuzberg_error_code_content_mismatch, kreuzberg_error_code_count,
+    kreuzberg_error_code_description, kreuzberg_error_code_encrypted_input, kreuzberg_error_code_file_too_large,
+    kreuzberg_error_code_internal, kreuzberg_error_code_io, kreuzberg_error_code_mime_type_not_allowed,
+    kreuzberg_error_code_missing_dependency, kreuzberg_error_code_name, kreuzberg_error_code_ocr,
+    kreuzberg_error_code_parsing, kreuzberg_error_code_plugin, kreuzberg_error_code_timeout,
     kreuzberg_error_code_unsupported_format, kreuzberg_error_code_validation, kreuzberg_get_error_details,
 };
+pub use estimate::kreuzberg_estimate;
+pub use features::kreuzberg_features;
+pub use field_compression::kreuzberg_decompress_field;
+pub use grpc::kreuzberg_grpc_serve;
+pub use image_blob::{kreuzberg_free_bytes, kreuzberg_image_blob_read};
+pub use lifecycle::{kreuzberg_init, kreuzberg_shutdown};
+pub use memory_stats::{kreuzberg_memory_reset_peak, kreuzberg_memory_stats};
 pub use panic_shield::{
     ErrorCode, StructuredError, clear_structured_error, get_last_error_code, get_last_error_message,
-    get_last_panic_context, set_structured_error,
+    get_last_panic_context, record_panic, set_structured_error,
 };
 pub use result::{
     CMetadataField, kreuzberg_result_get_chunk_count, kreuzberg_result_get_detected_language,
-    kreuzberg_result_get_metadata_field, kreuzberg_result_get_page_count,
+    kreuzberg_result_get_exif_field, kreuzberg_result_get_metadata_field, kreuzberg_result_get_page_count,
 };
 pub use result_pool::{
     CResultPoolStats, ResultPool, kreuzberg_extract_file_into_pool, kreuzberg_extract_file_into_pool_view,
     kreuzberg_result_pool_free, kreuzberg_result_pool_new, kreuzberg_result_pool_reset, kreuzberg_result_pool_stats,
 };
+pub use result_verify::kreuzberg_result_verify;
 pub use result_view::{
     CExtractionResultView, kreuzberg_get_result_view, kreuzberg_view_get_content, kreuzberg_view_get_mime_type,
 };
+pub use shared_memory::{
+    CSharedExtractionResult, CSharedImage, kreuzberg_extract_file_shared, kreuzberg_free_shared_result,
+};
+pub use spreadsheet_streaming::{RowCallback, kreuzberg_extract_spreadsheet_rows};
+pub use stats::{kreuzberg_stats, kreuzberg_stats_reset};
 pub use string_intern::{
     CStringInternStats, kreuzberg_free_interned_string, kreuzberg_intern_string, kreuzberg_string_intern_reset,
     kreuzberg_string_intern_stats,
@@ -51,7 +87,10 @@ use std::ptr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use batch_strings::{BatchStrings, CStringField};
+use field_compression::{JsonField, free_json_field};
 use kreuzberg::core::config::{ExtractionConfig, OcrConfig};
+use kreuzberg::core::fields::FieldSelection;
 use kreuzberg::plugins::registry::get_ocr_backend_registry;
 use kreuzberg::plugins::{OcrBackend, Plugin, ProcessingStage};
 use kreuzberg::types::ExtractionResult;
@@ -434,6 +473,11 @@ fn parse_extraction_config_from_json(config_str: &str) -> FfiResult<ExtractionCo
         config.html_options = Some(options);
     }
 
+    FieldSelection::new(config.fields.as_deref())
+        .validate()
+        .map_err(|e| e.to_string())?;
+    kreuzberg::core::overrides::validate_overrides(&config).map_err(|e| e.to_string())?;
+
     Ok(config)
 }
 
@@ -471,7 +515,8 @@ impl Drop for CStringGuard {
 /// C-compatible extraction result structure
 ///
 /// Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
-/// Field order: 11 pointers (8 bytes each) + 1 bool + 7 bytes padding = 96 bytes total
+/// Field order: 16 pointers (8 bytes each) + 1 bool + 7 bytes padding + 1 u32 + 4 bytes padding
+/// + 3 lengths (8 bytes each) = 168 bytes total
 #[repr(C)]
 pub struct CExtractionResult {
     /// Extracted text content (null-terminated UTF-8 string, must be freed with kreuzberg_free_string)
@@ -490,16 +535,37 @@ pub struct CExtractionResult {
     pub detected_languages_json: *mut c_char,
     /// Metadata as JSON object (null-terminated string, or NULL if no metadata, must be freed with kreuzberg_free_string)
     pub metadata_json: *mut c_char,
-    /// Text chunks as JSON array (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    /// Text chunks as JSON array (null-terminated string, or compressed per `json_field_encoding`; NULL if not available, freed with kreuzberg_free_result)
     pub chunks_json: *mut c_char,
-    /// Extracted images as JSON array (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    /// Extracted images as JSON array (null-terminated string, or compressed per `json_field_encoding`; NULL if not available, freed with kreuzberg_free_result)
     pub images_json: *mut c_char,
-    /// Page structure as JSON object (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    /// Page structure as JSON object (null-terminated string, or compressed per `json_field_encoding`; NULL if not available, freed with kreuzberg_free_result)
     pub page_structure_json: *mut c_char,
+    /// Structured EXIF data (camera, timestamps, GPS) as JSON object for image inputs (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    pub exif_json: *mut c_char,
+    /// Term byte offsets as JSON array when `emit_term_offsets` is enabled (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    pub term_offsets_json: *mut c_char,
+    /// Detected barcodes and QR codes (format, payload, page, bbox) as JSON array when `detect_barcodes` is enabled (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    pub barcodes_json: *mut c_char,
+    /// Label/value pairs (key, value, page, key_bbox, value_bbox) as JSON array when `kv_extraction` is configured (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    pub key_values_json: *mut c_char,
+    /// Watermarks and stamps (kind, text, pages, page_coverage, rotation) as JSON array when `watermarks` is configured (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+    pub watermarks_json: *mut c_char,
     /// Whether extraction was successful
     pub success: bool,
     /// Padding to match Java MemoryLayout (7 bytes padding to align to 8-byte boundary)
     _padding1: [u8; 7],
+    /// Encoding of `chunks_json`, `images_json` and `page_structure_json`: 0 = null-terminated JSON,
+    /// 1 = gzip, 2 = zstd (set by `result_compression`; decode with kreuzberg_decompress_field)
+    pub json_field_encoding: u32,
+    /// Padding to align the lengths to an 8-byte boundary
+    _padding2: [u8; 4],
+    /// Length of `chunks_json` in bytes, excluding any null terminator
+    pub chunks_json_len: usize,
+    /// Length of `images_json` in bytes, excluding any null terminator
+    pub images_json_len: usize,
+    /// Length of `page_structure_json` in bytes, excluding any null terminator
+    pub page_structure_json_len: usize,
 }
 
 /// Helper function to convert ExtractionResult to CExtractionResult
@@ -507,7 +573,40 @@ pub struct CExtractionResult {
 /// Uses RAII guards to prevent memory leaks if any string allocation fails.
 /// All allocated C strings are automatically freed if an error occurs before
 /// the final result is constructed.
-fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*mut CExtractionResult, String> {
+///
+/// Fields not listed in `config.fields` are dropped before serialization and come
+/// back as NULL (or an empty string for `content`).
+///
+/// Every result handed to the caller passes through here, so it is counted in the
+/// extraction statistics (`kreuzberg_stats`) before conversion.
+fn to_c_extraction_result(
+    result: ExtractionResult,
+    config: &ExtractionConfig,
+) -> std::result::Result<*mut CExtractionResult, String> {
+    convert_extraction_result(result, config, None)
+}
+
+/// Convert one result of a batch, sharing its MIME type and language through `strings`.
+///
+/// See the `batch_strings` module for how the shared strings are released.
+fn to_c_batch_extraction_result(
+    result: ExtractionResult,
+    config: &ExtractionConfig,
+    strings: &mut BatchStrings,
+) -> std::result::Result<*mut CExtractionResult, String> {
+    convert_extraction_result(result, config, Some(strings))
+}
+
+fn convert_extraction_result(
+    mut result: ExtractionResult,
+    config: &ExtractionConfig,
+    mut strings: Option<&mut BatchStrings>,
+) -> std::result::Result<*mut CExtractionResult, String> {
+    stats::record_result(&result);
+
+    let selection = FieldSelection::new(config.fields.as_deref());
+    selection.apply(&mut result);
+
     let ExtractionResult {
         content,
         mime_type,
@@ -517,6 +616,10 @@ fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*mut
         chunks,
         images,
         pages,
+        term_offsets,
+        barcodes,
+        provenance: _,
+        schema_version: _,
     } = result;
 
     let sanitized_content = if content.contains('\0') {
@@ -529,14 +632,14 @@ fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*mut
         CString::new(sanitized_content).map_err(|e| format!("Failed to convert content to C string: {}", e))?,
     );
 
-    let mime_type_guard = CStringGuard::new(
-        CString::new(mime_type).map_err(|e| format!("Failed to convert MIME type to C string: {}", e))?,
-    );
+    let mime_type_field = CStringField::new(&mime_type, strings.as_deref_mut())
+        .map_err(|e| format!("Failed to convert MIME type to C string: {}", e))?;
 
-    let language_guard = match &metadata.language {
-        Some(lang) => Some(CStringGuard::new(
-            CString::new(lang.as_str()).map_err(|e| format!("Failed to convert language to C string: {}", e))?,
-        )),
+    let language_field = match &metadata.language {
+        Some(lang) => Some(
+            CStringField::new(lang, strings.as_deref_mut())
+                .map_err(|e| format!("Failed to convert language to C string: {}", e))?,
+        ),
         None => None,
     };
 
@@ -577,46 +680,104 @@ fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*mut
     };
 
     let metadata_json_guard = {
-        let json =
-            serde_json::to_string(&metadata).map_err(|e| format!("Failed to serialize metadata to JSON: {}", e))?;
+        let json = if selection.is_all() {
+            serde_json::to_string(&metadata)
+        } else {
+            selection
+                .metadata_value(&metadata)
+                .and_then(|value| serde_json::to_string(&value))
+        }
+        .map_err(|e| format!("Failed to serialize metadata to JSON: {}", e))?;
         Some(CStringGuard::new(CString::new(json).map_err(|e| {
             format!("Failed to convert metadata JSON to C string: {}", e)
         })?))
     };
 
-    let chunks_json_guard = match chunks {
+    // The large JSON fields are optionally compressed, see the `field_compression` module
+    let compression = config.result_compression;
+
+    let chunks_json_field = match chunks {
         Some(chunks) if !chunks.is_empty() => {
             let json =
                 serde_json::to_string(&chunks).map_err(|e| format!("Failed to serialize chunks to JSON: {}", e))?;
-            Some(CStringGuard::new(CString::new(json).map_err(|e| {
-                format!("Failed to convert chunks JSON to C string: {}", e)
-            })?))
+            Some(JsonField::new(json, compression)?)
         }
         _ => None,
     };
 
-    let images_json_guard = match images {
+    let images_json_field = match images {
         Some(images) if !images.is_empty() => {
             let json =
                 serde_json::to_string(&images).map_err(|e| format!("Failed to serialize images to JSON: {}", e))?;
-            Some(CStringGuard::new(CString::new(json).map_err(|e| {
-                format!("Failed to convert images JSON to C string: {}", e)
-            })?))
+            Some(JsonField::new(json, compression)?)
         }
         _ => None,
     };
 
-    let page_structure_json_guard = match &metadata.pages {
+    let page_structure_json_field = match &metadata.pages {
         Some(page_structure) => {
             let json = serde_json::to_string(&page_structure)
                 .map_err(|e| format!("Failed to serialize page structure to JSON: {}", e))?;
+            Some(JsonField::new(json, compression)?)
+        }
+        _ => None,
+    };
+
+    let exif_json_guard = match result::exif_details_of(&metadata) {
+        Some(exif) if selection.includes_metadata_key("exif_details") => {
+            let json =
+                serde_json::to_string(exif).map_err(|e| format!("Failed to serialize EXIF data to JSON: {}", e))?;
             Some(CStringGuard::new(CString::new(json).map_err(|e| {
-                format!("Failed to convert page structure JSON to C string: {}", e)
+                format!("Failed to convert EXIF JSON to C string: {}", e)
             })?))
         }
         _ => None,
     };
 
+    let term_offsets_json_guard = match term_offsets {
+        Some(term_offsets) => {
+            let json = serde_json::to_string(&term_offsets)
+                .map_err(|e| format!("Failed to serialize term offsets to JSON: {}", e))?;
+            Some(CStringGuard::new(CString::new(json).map_err(|e| {
+                format!("Failed to convert term offsets JSON to C string: {}", e)
+            })?))
+        }
+        None => None,
+    };
+
+    let barcodes_json_guard = match barcodes {
+        Some(barcodes) => {
+            let json =
+                serde_json::to_string(&barcodes).map_err(|e| format!("Failed to serialize barcodes to JSON: {}", e))?;
+            Some(CStringGuard::new(CString::new(json).map_err(|e| {
+                format!("Failed to convert barcodes JSON to C string: {}", e)
+            })?))
+        }
+        None => None,
+    };
+
+    let key_values_json_guard = match &metadata.key_values {
+        Some(key_values) => {
+            let json = serde_json::to_string(key_values)
+                .map_err(|e| format!("Failed to serialize key-value pairs to JSON: {}", e))?;
+            Some(CStringGuard::new(CString::new(json).map_err(|e| {
+                format!("Failed to convert key-value pairs JSON to C string: {}", e)
+            })?))
+        }
+        None => None,
+    };
+
+    let watermarks_json_guard = match &metadata.watermarks {
+        Some(watermarks) => {
+            let json = serde_json::to_string(watermarks)
+                .map_err(|e| format!("Failed to serialize watermarks to JSON: {}", e))?;
+            Some(CStringGuard::new(CString::new(json).map_err(|e| {
+                format!("Failed to convert watermarks JSON to C string: {}", e)
+            })?))
+        }
+        None => None,
+    };
+
     let _pages_json_guard = match pages {
         Some(pages) if !pages.is_empty() => {
             let json =
@@ -628,20 +789,35 @@ fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*mut
         _ => None,
     };
 
+    let (chunks_json, chunks_json_len) = chunks_json_field.map_or((ptr::null_mut(), 0), JsonField::into_raw);
+    let (images_json, images_json_len) = images_json_field.map_or((ptr::null_mut(), 0), JsonField::into_raw);
+    let (page_structure_json, page_structure_json_len) =
+        page_structure_json_field.map_or((ptr::null_mut(), 0), JsonField::into_raw);
+
     Ok(Box::into_raw(Box::new(CExtractionResult {
         content: content_guard.into_raw(),
-        mime_type: mime_type_guard.into_raw(),
-        language: language_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        mime_type: mime_type_field.into_raw(),
+        language: language_field.map_or(ptr::null_mut(), |f| f.into_raw()),
         date: date_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         subject: subject_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         tables_json: tables_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         detected_languages_json: detected_languages_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         metadata_json: metadata_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
-        chunks_json: chunks_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
-        images_json: images_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
-        page_structure_json: page_structure_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        chunks_json,
+        images_json,
+        page_structure_json,
+        exif_json: exif_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        term_offsets_json: term_offsets_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        barcodes_json: barcodes_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        key_values_json: key_values_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        watermarks_json: watermarks_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         success: true,
         _padding1: [0u8; 7],
+        json_field_encoding: field_compression::encoding_of(compression),
+        _padding2: [0u8; 4],
+        chunks_json_len,
+        images_json_len,
+        page_structure_json_len,
     })))
 }
 
@@ -687,9 +863,10 @@ pub unsafe extern "C" fn kreuzberg_extract_file_sync(file_path: *const c_char) -
 
         let path = Path::new(path_str);
         let config = ExtractionConfig::default();
+        crash_dump::record_input(Some(crash_dump::CrashDocument::File(path)), &config);
 
         match kreuzberg::extract_file_sync(path, None, &config) {
-            Ok(result) => match to_c_extraction_result(result) {
+            Ok(result) => match to_c_extraction_result(result, &config) {
                 Ok(ptr) => ptr,
                 Err(e) => {
                     set_last_error(e);
@@ -697,6 +874,7 @@ pub unsafe extern "C" fn kreuzberg_extract_file_sync(file_path: *const c_char) -
                 }
             },
             Err(e) => {
+                stats::record_error(&e.to_string());
                 set_last_error(e.to_string());
                 ptr::null_mut()
             }
@@ -906,6 +1084,10 @@ pub unsafe extern "C" fn kreuzberg_get_embedding_preset(name: *const c_char) ->
 ///     kreuzberg_free_result(result);
 /// }
 /// ```
+///
+/// Use `"language": "auto"` to detect the script and language of each page and pick the
+/// matching traineddata; the languages found per page are reported under `ocr_pages` in
+/// `metadata_json`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn kreuzberg_extract_file_sync_with_config(
     file_path: *const c_char,
@@ -948,9 +1130,10 @@ pub unsafe extern "C" fn kreuzberg_extract_file_sync_with_config(
                 }
             }
         };
+        crash_dump::record_input(Some(crash_dump::CrashDocument::File(path)), &config);
 
         match kreuzberg::extract_file_sync(path, None, &config) {
-            Ok(result) => match to_c_extraction_result(result) {
+            Ok(result) => match to_c_extraction_result(result, &config) {
                 Ok(ptr) => ptr,
                 Err(e) => {
                     set_last_error(e);
@@ -958,6 +1141,7 @@ pub unsafe extern "C" fn kreuzberg_extract_file_sync_with_config(
                 }
             },
             Err(e) => {
+                stats::record_error(&e.to_string());
                 set_last_error(e.to_string());
                 ptr::null_mut()
             }
@@ -1019,9 +1203,16 @@ pub unsafe extern "C" fn kreuzberg_extract_bytes_sync(
         };
 
         let config = ExtractionConfig::default();
+        crash_dump::record_input(
+            Some(crash_dump::CrashDocument::Bytes {
+                data: bytes,
+                mime_type: mime_str,
+            }),
+            &config,
+        );
 
         match kreuzberg::extract_bytes_sync(bytes, mime_str, &config) {
-            Ok(result) => match to_c_extraction_result(result) {
+            Ok(result) => match to_c_extraction_result(result, &config) {
                 Ok(ptr) => ptr,
                 Err(e) => {
                     set_last_error(e);
@@ -1029,6 +1220,7 @@ pub unsafe extern "C" fn kreuzberg_extract_bytes_sync(
                 }
             },
             Err(e) => {
+                stats::record_error(&e.to_string());
                 set_last_error(e.to_string());
                 ptr::null_mut()
             }
@@ -1108,9 +1300,16 @@ pub unsafe extern "C" fn kreuzberg_extract_bytes_sync_with_config(
                 }
             }
         };
+        crash_dump::record_input(
+            Some(crash_dump::CrashDocument::Bytes {
+                data: bytes,
+                mime_type: mime_str,
+            }),
+            &config,
+        );
 
         match kreuzberg::extract_bytes_sync(bytes, mime_str, &config) {
-            Ok(result) => match to_c_extraction_result(result) {
+            Ok(result) => match to_c_extraction_result(result, &config) {
                 Ok(ptr) => ptr,
                 Err(e) => {
                     set_last_error(e);
@@ -1118,6 +1317,7 @@ pub unsafe extern "C" fn kreuzberg_extract_bytes_sync_with_config(
                 }
             },
             Err(e) => {
+                stats::record_error(&e.to_string());
                 set_last_error(e.to_string());
                 ptr::null_mut()
             }
@@ -1217,16 +1417,17 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_files_sync(
             paths.push(Path::new(path_str));
         }
 
+        crash_dump::record_input(None, &config);
+
         match kreuzberg::batch_extract_file_sync(paths, &config) {
             Ok(results) => {
+                let mut strings = BatchStrings::new();
                 let mut c_results = Vec::with_capacity(results.len());
                 for result in results {
-                    match to_c_extraction_result(result) {
+                    match to_c_batch_extraction_result(result, &config, &mut strings) {
                         Ok(ptr) => c_results.push(ptr),
                         Err(e) => {
-                            for c_res in c_results {
-                                unsafe { kreuzberg_free_result(c_res) };
-                            }
+                            strings.discard(c_results);
                             set_last_error(e);
                             return ptr::null_mut();
                         }
@@ -1236,14 +1437,17 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_files_sync(
                 let results_array = c_results.into_boxed_slice();
                 let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
 
-                Box::into_raw(Box::new(CBatchResult {
+                let batch = Box::into_raw(Box::new(CBatchResult {
                     results: results_ptr,
                     count,
                     success: true,
                     _padding2: [0u8; 7],
-                }))
+                }));
+                strings.publish(batch);
+                batch
             }
             Err(e) => {
+                stats::record_error(&e.to_string());
                 set_last_error(e.to_string());
                 ptr::null_mut()
             }
@@ -1321,16 +1525,17 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_bytes_sync(
             contents.push((bytes, mime_str));
         }
 
+        crash_dump::record_input(None, &config);
+
         match kreuzberg::batch_extract_bytes_sync(contents, &config) {
             Ok(results) => {
+                let mut strings = BatchStrings::new();
                 let mut c_results = Vec::with_capacity(results.len());
                 for result in results {
-                    match to_c_extraction_result(result) {
+                    match to_c_batch_extraction_result(result, &config, &mut strings) {
                         Ok(ptr) => c_results.push(ptr),
                         Err(e) => {
-                            for c_res in c_results {
-                                unsafe { kreuzberg_free_result(c_res) };
-                            }
+                            strings.discard(c_results);
                             set_last_error(e);
                             return ptr::null_mut();
                         }
@@ -1340,14 +1545,17 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_bytes_sync(
                 let results_array = c_results.into_boxed_slice();
                 let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
 
-                Box::into_raw(Box::new(CBatchResult {
+                let batch = Box::into_raw(Box::new(CBatchResult {
                     results: results_ptr,
                     count,
                     success: true,
                     _padding2: [0u8; 7],
-                }))
+                }));
+                strings.publish(batch);
+                batch
             }
             Err(e) => {
+                stats::record_error(&e.to_string());
                 set_last_error(e.to_string());
                 ptr::null_mut()
             }
@@ -1410,9 +1618,13 @@ pub unsafe extern "C" fn kreuzberg_load_extraction_config_from_file(file_path: *
 /// - `batch_result` can be NULL (no-op)
 /// - `batch_result` must not be used after this call
 /// - All results and strings within the batch result will be freed automatically
+///
+/// Results of one batch share their `mime_type` and `language` strings; the shared strings
+/// are freed together once the batch and every result referencing them have been freed.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn kreuzberg_free_batch_result(batch_result: *mut CBatchResult) {
     if !batch_result.is_null() {
+        batch_strings::release_batch_strings(batch_result);
         let batch = unsafe { Box::from_raw(batch_result) };
 
         // NOTE: Do not free individual results here - calling code is responsible for that.
@@ -1507,10 +1719,11 @@ pub unsafe extern "C" fn kreuzberg_free_result(result: *mut CExtractionResult) {
         if !result_box.content.is_null() {
             unsafe { drop(CString::from_raw(result_box.content)) };
         }
-        if !result_box.mime_type.is_null() {
+        // Batch results share these with the rest of their batch
+        if !result_box.mime_type.is_null() && !batch_strings::release_shared_string(result_box.mime_type) {
             unsafe { drop(CString::from_raw(result_box.mime_type)) };
         }
-        if !result_box.language.is_null() {
+        if !result_box.language.is_null() && !batch_strings::release_shared_string(result_box.language) {
             unsafe { drop(CString::from_raw(result_box.language)) };
         }
         if !result_box.date.is_null() {
@@ -1528,11 +1741,30 @@ pub unsafe extern "C" fn kreuzberg_free_result(result: *mut CExtractionResult) {
         if !result_box.metadata_json.is_null() {
             unsafe { drop(CString::from_raw(result_box.metadata_json)) };
         }
-        if !result_box.chunks_json.is_null() {
-            unsafe { drop(CString::from_raw(result_box.chunks_json)) };
+        let encoding = result_box.json_field_encoding;
+        unsafe {
+            free_json_field(result_box.chunks_json, result_box.chunks_json_len, encoding);
+            free_json_field(result_box.images_json, result_box.images_json_len, encoding);
+            free_json_field(
+                result_box.page_structure_json,
+                result_box.page_structure_json_len,
+                encoding,
+            );
+        }
+        if !result_box.exif_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.exif_json)) };
         }
-        if !result_box.images_json.is_null() {
-            unsafe { drop(CString::from_raw(result_box.images_json)) };
+        if !result_box.term_offsets_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.term_offsets_json)) };
+        }
+        if !result_box.barcodes_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.barcodes_json)) };
+        }
+        if !result_box.key_values_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.key_values_json)) };
+        }
+        if !result_box.watermarks_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.watermarks_json)) };
         }
     }
 }
@@ -1824,6 +2056,10 @@ impl OcrBackend for FfiOcrBackend {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -1896,16 +2132,8 @@ pub unsafe extern "C" fn kreuzberg_register_ocr_backend(name: *const c_char, cal
         let backend = Arc::new(FfiOcrBackend::new(name_str.to_string(), callback, None));
 
         let registry = get_ocr_backend_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.register(backend) {
+        match registry.update(|registry| registry.register(backend)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to register OCR backend: {}", e));
@@ -1964,16 +2192,8 @@ pub unsafe extern "C" fn kreuzberg_register_ocr_backend_with_languages(
         let backend = Arc::new(FfiOcrBackend::new(name_str.to_string(), callback, supported_languages));
 
         let registry = get_ocr_backend_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.register(backend) {
+        match registry.update(|registry| registry.register(backend)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to register OCR backend: {}", e));
@@ -2105,8 +2325,9 @@ fn parse_processing_stage(stage: Option<&str>) -> FfiResult<ProcessingStage> {
             "early" => Ok(ProcessingStage::Early),
             "middle" => Ok(ProcessingStage::Middle),
             "late" => Ok(ProcessingStage::Late),
+            "chunk" => Ok(ProcessingStage::Chunk),
             other => Err(format!(
-                "Invalid processing stage '{}'. Expected one of: early, middle, late",
+                "Invalid processing stage '{}'. Expected one of: early, middle, late, chunk",
                 other
             )),
         },
@@ -2179,16 +2400,8 @@ pub unsafe extern "C" fn kreuzberg_register_post_processor(
         ));
 
         let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.register(processor, priority) {
+        match registry.update(|registry| registry.register(processor, priority)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to register PostProcessor: {}", e));
@@ -2266,16 +2479,8 @@ pub unsafe extern "C" fn kreuzberg_register_post_processor_with_stage(
         let processor = Arc::new(FfiPostProcessor::new(name_str.to_string(), callback, stage));
 
         let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.register(processor, priority) {
+        match registry.update(|registry| registry.register(processor, priority)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to register PostProcessor: {}", e));
@@ -2320,16 +2525,8 @@ pub unsafe extern "C" fn kreuzberg_unregister_post_processor(name: *const c_char
         };
 
         let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.remove(name_str) {
+        match registry.update(|registry| registry.remove(name_str)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to remove PostProcessor: {}", e));
@@ -2350,17 +2547,7 @@ pub unsafe extern "C" fn kreuzberg_clear_post_processors() -> bool {
     ffi_panic_guard_bool!("kreuzberg_clear_post_processors", {
         clear_last_error();
 
-        let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
-
-        *registry_guard = Default::default();
+        *kreuzberg::plugins::registry::get_post_processor_registry().edit() = Default::default();
         true
     })
 }
@@ -2376,17 +2563,9 @@ pub unsafe extern "C" fn kreuzberg_list_post_processors() -> *mut c_char {
     ffi_panic_guard!("kreuzberg_list_post_processors", {
         clear_last_error();
 
-        let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-        let registry_guard = match registry.read() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry read lock: {}", e));
-                return ptr::null_mut();
-            }
-        };
+        let registry = kreuzberg::plugins::registry::get_post_processor_registry().snapshot();
 
-        match serde_json::to_string(&registry_guard.list()) {
+        match serde_json::to_string(&registry.list()) {
             Ok(json) => match CString::new(json) {
                 Ok(cstr) => cstr.into_raw(),
                 Err(e) => {
@@ -2680,16 +2859,8 @@ pub unsafe extern "C" fn kreuzberg_register_document_extractor(
         ));
 
         let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.register(extractor) {
+        match registry.update(|registry| registry.register(extractor)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to register DocumentExtractor: {}", e));
@@ -2734,16 +2905,8 @@ pub unsafe extern "C" fn kreuzberg_unregister_document_extractor(name: *const c_
         };
 
         let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.remove(name_str) {
+        match registry.update(|registry| registry.remove(name_str)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to remove DocumentExtractor: {}", e));
@@ -2764,17 +2927,9 @@ pub unsafe extern "C" fn kreuzberg_list_document_extractors() -> *mut c_char {
     ffi_panic_guard!("kreuzberg_list_document_extractors", {
         clear_last_error();
 
-        let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
-        let registry_guard = match registry.read() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry read lock: {}", e));
-                return ptr::null_mut();
-            }
-        };
+        let registry = kreuzberg::plugins::registry::get_document_extractor_registry().snapshot();
 
-        match serde_json::to_string(&registry_guard.list()) {
+        match serde_json::to_string(&registry.list()) {
             Ok(json) => match CString::new(json) {
                 Ok(cstr) => cstr.into_raw(),
                 Err(e) => {
@@ -2973,16 +3128,8 @@ pub unsafe extern "C" fn kreuzberg_register_validator(
         let validator = Arc::new(FfiValidator::new(name_str.to_string(), callback, priority));
 
         let registry = kreuzberg::plugins::registry::get_validator_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.register(validator) {
+        match registry.update(|registry| registry.register(validator)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to register Validator: {}", e));
@@ -3027,16 +3174,8 @@ pub unsafe extern "C" fn kreuzberg_unregister_validator(name: *const c_char) ->
         };
 
         let registry = kreuzberg::plugins::registry::get_validator_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
 
-        match registry_guard.remove(name_str) {
+        match registry.update(|registry| registry.remove(name_str)) {
             Ok(()) => true,
             Err(e) => {
                 set_last_error(format!("Failed to remove Validator: {}", e));
@@ -3057,17 +3196,7 @@ pub unsafe extern "C" fn kreuzberg_clear_validators() -> bool {
     ffi_panic_guard_bool!("kreuzberg_clear_validators", {
         clear_last_error();
 
-        let registry = kreuzberg::plugins::registry::get_validator_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
-
-        *registry_guard = Default::default();
+        *kreuzberg::plugins::registry::get_validator_registry().edit() = Default::default();
         true
     })
 }
@@ -3083,17 +3212,9 @@ pub unsafe extern "C" fn kreuzberg_list_validators() -> *mut c_char {
     ffi_panic_guard!("kreuzberg_list_validators", {
         clear_last_error();
 
-        let registry = kreuzberg::plugins::registry::get_validator_registry();
-        let registry_guard = match registry.read() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry read lock: {}", e));
-                return ptr::null_mut();
-            }
-        };
+        let registry = kreuzberg::plugins::registry::get_validator_registry().snapshot();
 
-        match serde_json::to_string(&registry_guard.list()) {
+        match serde_json::to_string(&registry.list()) {
             Ok(json) => match CString::new(json) {
                 Ok(cstr) => cstr.into_raw(),
                 Err(e) => {
@@ -3261,17 +3382,7 @@ pub unsafe extern "C" fn kreuzberg_clear_document_extractors() -> bool {
     ffi_panic_guard_bool!("kreuzberg_clear_document_extractors", {
         clear_last_error();
 
-        let registry = kreuzberg::plugins::registry::get_document_extractor_registry();
-        let mut registry_guard = match registry.write() {
-            Ok(guard) => guard,
-            Err(e) => {
-                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-                set_last_error(format!("Failed to acquire registry write lock: {}", e));
-                return false;
-            }
-        };
-
-        *registry_guard = Default::default();
+        *kreuzberg::plugins::registry::get_document_extractor_registry().edit() = Default::default();
         true
     })
 }
@@ -4031,7 +4142,7 @@ pub unsafe extern "C" fn kreuzberg_validate_chunking_params(max_chars: usize, ma
 const _: () = {
     const fn assert_c_extraction_result_size() {
         const SIZE: usize = std::mem::size_of::<CExtractionResult>();
-        const _: () = assert!(SIZE == 96, "CExtractionResult size must be 96 bytes");
+        const _: () = assert!(SIZE == 168, "CExtractionResult size must be 168 bytes");
     }
 
     const fn assert_c_extraction_result_alignment() {
@@ -4106,4 +4217,113 @@ mod tests {
             assert!(!error.is_null());
         }
     }
+
+    #[test]
+    fn test_jupyter_notebook_bytes() {
+        let notebook = r##"{
+            "nbformat": 4,
+            "nbformat_minor": 5,
+            "metadata": {"kernelspec": {"name": "python3", "language": "python"}},
+            "cells": [
+                {"cell_type": "markdown", "metadata": {}, "source": ["# Report"]},
+                {
+                    "cell_type": "code",
+                    "execution_count": 1,
+                    "metadata": {},
+                    "source": ["plot()"],
+                    "outputs": [{
+                        "output_type": "display_data",
+                        "metadata": {},
+                        "data": {"image/png": "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAAAAAA6fptVAAAACklEQVR4nGNgAAAAAgABSK+kcQAAAABJRU5ErkJggg=="}
+                    }]
+                }
+            ]
+        }"##;
+        let data = notebook.as_bytes();
+
+        unsafe {
+            let mime = kreuzberg_detect_mime_type_from_bytes(data.as_ptr(), data.len());
+            assert!(!mime.is_null());
+            assert_eq!(CStr::from_ptr(mime).to_str().unwrap(), "application/x-ipynb+json");
+
+            let config = CString::new(r#"{"images": {"extract_images": true}}"#).unwrap();
+            let result = kreuzberg_extract_bytes_sync_with_config(data.as_ptr(), data.len(), mime, config.as_ptr());
+            kreuzberg_free_string(mime);
+            assert!(!result.is_null());
+
+            let content = CStr::from_ptr((*result).content).to_str().unwrap();
+            assert!(content.contains("# Report"));
+            assert!(content.contains("```python\nplot()\n```"));
+            assert!(!(*result).images_json.is_null());
+
+            kreuzberg_free_result(result);
+        }
+    }
+
+    #[test]
+    fn test_extract_bytes_with_selected_fields() {
+        let data = "Quarterly report. ".repeat(50);
+        let mime = CString::new("text/plain").unwrap();
+
+        unsafe {
+            let config = CString::new(
+                r#"{"use_cache": false, "chunking": {"max_chars": 100, "max_overlap": 10}, "fields": ["content"]}"#,
+            )
+            .unwrap();
+            let result =
+                kreuzberg_extract_bytes_sync_with_config(data.as_ptr(), data.len(), mime.as_ptr(), config.as_ptr());
+            assert!(!result.is_null());
+
+            let content = CStr::from_ptr((*result).content).to_str().unwrap();
+            assert!(content.starts_with("Quarterly report."));
+            assert!((*result).chunks_json.is_null());
+            assert!((*result).page_structure_json.is_null());
+            assert_eq!(CStr::from_ptr((*result).metadata_json).to_str().unwrap(), "{}");
+            kreuzberg_free_result(result);
+
+            let config = CString::new(
+                r#"{"use_cache": false, "chunking": {"max_chars": 100, "max_overlap": 10}, "fields": ["chunks"]}"#,
+            )
+            .unwrap();
+            let result =
+                kreuzberg_extract_bytes_sync_with_config(data.as_ptr(), data.len(), mime.as_ptr(), config.as_ptr());
+            assert!(!result.is_null());
+
+            assert_eq!(CStr::from_ptr((*result).content).to_str().unwrap(), "");
+            assert!(!(*result).chunks_json.is_null());
+            kreuzberg_free_result(result);
+
+            let config = CString::new(r#"{"fields": ["chunk"]}"#).unwrap();
+            let result =
+                kreuzberg_extract_bytes_sync_with_config(data.as_ptr(), data.len(), mime.as_ptr(), config.as_ptr());
+            assert!(result.is_null());
+            let error = CStr::from_ptr(kreuzberg_last_error()).to_str().unwrap();
+            assert!(error.contains("Unknown result field 'chunk'"));
+        }
+    }
+
+    #[test]
+    fn test_extract_bytes_with_compressed_fields() {
+        let data = "Quarterly report. ".repeat(50);
+        let mime = CString::new("text/plain").unwrap();
+
+        unsafe {
+            let config = CString::new(
+                r#"{"use_cache": false, "chunking": {"max_chars": 100, "max_overlap": 10}, "result_compression": "gzip"}"#,
+            )
+            .unwrap();
+            let result =
+                kreuzberg_extract_bytes_sync_with_config(data.as_ptr(), data.len(), mime.as_ptr(), config.as_ptr());
+            assert!(!result.is_null());
+            assert_eq!((*result).json_field_encoding, 1);
+            assert!((*result).chunks_json_len > 0);
+
+            let chunks = kreuzberg_decompress_field((*result).chunks_json, (*result).chunks_json_len, 1);
+            assert!(!chunks.is_null());
+            let json: serde_json::Value = serde_json::from_str(CStr::from_ptr(chunks).to_str().unwrap()).unwrap();
+            assert!(json.as_array().is_some_and(|chunks| chunks.len() > 1));
+            kreuzberg_free_string(chunks);
+            kreuzberg_free_result(result);
+        }
+    }
 }
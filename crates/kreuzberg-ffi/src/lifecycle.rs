@@ -0,0 +1,298 @@
+//! Library lifecycle FFI module.
+//!
+//! The library sets itself up lazily: the runtime behind the synchronous functions, the
+//! extractor registry and OCR engines are created on first use and live until the process
+//! exits. That is fine for applications, but hosts that load the library as a plugin and
+//! unload it again (`dlclose`, test runners, `AssemblyLoadContext.Unload`) crash when a
+//! worker thread wakes up in unmapped code.
+//!
+//! `kreuzberg_init` sets everything up front with explicit options, and `kreuzberg_shutdown`
+//! joins the runtime threads, drops pooled OCR engines and releases cache backends, so the
+//! library can be unloaded afterwards. Both are optional for hosts that never unload it.
+//!
+//! Not torn down: the rayon thread pool (its threads are idle between batches and cannot be
+//! joined), loaded embedding models, and the log subscriber, which keeps the `log_level`
+//! of the first `kreuzberg_init` call.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! void* lib = dlopen("libkreuzberg_ffi.so", RTLD_NOW);
+//! // ... resolve symbols ...
+//! if (!kreuzberg_init("{\"worker_threads\":4,\"log_level\":\"warn\",\"preload\":{\"extractors\":true}}")) {
+//!     fprintf(stderr, "init failed: %s\n", kreuzberg_last_error());
+//! }
+//!
+//! CExtractionResult* result = kreuzberg_extract_file_sync("document.pdf");
+//! kreuzberg_free_result(result);
+//!
+//! if (!kreuzberg_shutdown()) {
+//!     fprintf(stderr, "shutdown failed: %s\n", kreuzberg_last_error());
+//! }
+//! dlclose(lib);
+//! ```
+
+use crate::{clear_last_error, set_last_error};
+use kreuzberg::cache::backend::release_backends;
+use kreuzberg::core::runtime::{init_runtime, shutdown_runtime};
+use kreuzberg::ocr::{EnginePoolConfig, configure_engine_pool};
+use serde::Deserialize;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How long `kreuzberg_shutdown` waits for blocking tasks (file reads, OCR) to finish.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Whether `kreuzberg_init` succeeded and `kreuzberg_shutdown` was not called since.
+static INITIALIZED: Mutex<bool> = Mutex::new(false);
+
+/// Options accepted by `kreuzberg_init`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct InitOptions {
+    /// Runtime worker threads (default: one per CPU core)
+    worker_threads: Option<usize>,
+
+    /// Log filter for messages written to stderr, e.g. `warn` or `kreuzberg=debug`
+    log_level: Option<String>,
+
+    /// Tesseract engine pool settings
+    ocr_engine_pool: Option<EnginePoolOptions>,
+
+    /// Work done now instead of during the first extraction
+    preload: PreloadOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EnginePoolOptions {
+    pool_size: Option<usize>,
+    reuse: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PreloadOptions {
+    /// Register the built-in extractors
+    extractors: bool,
+
+    /// Embedding presets whose models are downloaded and loaded
+    embedding_presets: Vec<String>,
+
+    /// Directory embedding models are cached in (default: `.kreuzberg/embeddings`)
+    embedding_cache_dir: Option<std::path::PathBuf>,
+}
+
+impl Default for PreloadOptions {
+    fn default() -> Self {
+        Self {
+            extractors: true,
+            embedding_presets: Vec::new(),
+            embedding_cache_dir: None,
+        }
+    }
+}
+
+impl InitOptions {
+    fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid init options: {}", e))
+    }
+}
+
+fn install_logging(level: &str) -> Result<(), String> {
+    let filter =
+        tracing_subscriber::EnvFilter::try_new(level).map_err(|e| format!("Invalid log_level '{}': {}", level, e))?;
+    // A subscriber installed by an earlier kreuzberg_init stays in place
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .try_init();
+    Ok(())
+}
+
+fn preload(options: &PreloadOptions) -> Result<(), String> {
+    if options.extractors {
+        kreuzberg::extractors::ensure_initialized().map_err(|e| e.to_string())?;
+    }
+
+    #[cfg(not(all(windows, target_env = "gnu")))]
+    for name in &options.embedding_presets {
+        let preset =
+            kreuzberg::embeddings::get_preset(name).ok_or_else(|| format!("Unknown embedding preset: {}", name))?;
+        kreuzberg::embeddings::get_or_init_model(preset.model.clone(), options.embedding_cache_dir.clone())
+            .map_err(|e| format!("Failed to load embedding preset '{}': {}", name, e))?;
+    }
+    #[cfg(all(windows, target_env = "gnu"))]
+    if !options.embedding_presets.is_empty() {
+        return Err("Embedding presets are not available in this build".to_string());
+    }
+
+    Ok(())
+}
+
+fn init(options: InitOptions) -> Result<(), String> {
+    if let Some(level) = options.log_level.as_deref() {
+        install_logging(level)?;
+    }
+
+    let created = init_runtime(options.worker_threads).map_err(|e| e.to_string())?;
+    if !created && options.worker_threads.is_some() {
+        return Err("worker_threads cannot be applied: the runtime was already started by an earlier call".to_string());
+    }
+
+    if let Some(pool) = options.ocr_engine_pool {
+        let defaults = EnginePoolConfig::default();
+        configure_engine_pool(EnginePoolConfig {
+            pool_size: pool.pool_size.unwrap_or(defaults.pool_size),
+            reuse: pool.reuse.unwrap_or(defaults.reuse),
+        });
+    }
+
+    preload(&options.preload).inspect_err(|_| {
+        // Undo the setup so a retry with corrected options starts from scratch
+        configure_engine_pool(EnginePoolConfig::default());
+        if created {
+            let _ = shutdown_runtime(SHUTDOWN_TIMEOUT);
+        }
+    })
+}
+
+/// Set up the library: logging, the runtime thread pool, the OCR engine pool and preloads.
+///
+/// `options_json` is a JSON object with the optional fields `worker_threads` (runtime
+/// worker threads, default one per CPU core), `log_level` (a filter such as `warn` or
+/// `kreuzberg=debug` for log messages written to stderr, default no logging),
+/// `ocr_engine_pool` (`pool_size` and `reuse`, as in the OCR engine pool) and `preload`
+/// (`extractors`, default true; `embedding_presets`, preset names whose models are loaded
+/// now; `embedding_cache_dir`).
+///
+/// Calling `kreuzberg_init` is optional. It must not be called again before
+/// `kreuzberg_shutdown`.
+///
+/// # Returns
+///
+/// true on success, false on error (check `kreuzberg_last_error`), including for unknown
+/// options and a second call without `kreuzberg_shutdown` in between. A failed preload
+/// stops the runtime it started, so the call can be retried with corrected options.
+///
+/// # Safety
+///
+/// - `options_json` must be a valid null-terminated C string containing JSON, or NULL for
+///   the default options
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_init(options_json: *const c_char) -> bool {
+    crate::ffi_panic_guard_bool!("kreuzberg_init", {
+        clear_last_error();
+
+        let json = if options_json.is_null() {
+            "{}"
+        } else {
+            // SAFETY: The pointer was checked for NULL and must be a null-terminated string.
+            match unsafe { CStr::from_ptr(options_json) }.to_str() {
+                Ok(json) => json,
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in init options: {}", e));
+                    return false;
+                }
+            }
+        };
+        let options = match InitOptions::from_json(json) {
+            Ok(options) => options,
+            Err(e) => {
+                set_last_error(e);
+                return false;
+            }
+        };
+
+        let mut initialized = INITIALIZED.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if *initialized {
+            set_last_error("kreuzberg_init was already called; call kreuzberg_shutdown first".to_string());
+            return false;
+        }
+
+        match init(options) {
+            Ok(()) => {
+                *initialized = true;
+                true
+            }
+            Err(e) => {
+                set_last_error(e);
+                false
+            }
+        }
+    })
+}
+
+/// Tear the library down so it can be unloaded.
+///
+/// Joins the runtime threads (waiting up to 10 seconds for running file reads and OCR),
+/// drops pooled OCR engines and releases cache backends, closing Redis connections and
+/// freeing in-memory caches. Disk cache entries are kept. Works whether or not
+/// `kreuzberg_init` was called; the library can be used or initialized again afterwards.
+///
+/// # Returns
+///
+/// true on success, false on error (check `kreuzberg_last_error`). Fails without tearing
+/// anything down while a synchronous extraction is still running on another thread.
+///
+/// # Safety
+///
+/// - No other thread may be inside a Kreuzberg call that is about to start an extraction
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_shutdown() -> bool {
+    crate::ffi_panic_guard_bool!("kreuzberg_shutdown", {
+        clear_last_error();
+
+        let mut initialized = INITIALIZED.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Err(e) = shutdown_runtime(SHUTDOWN_TIMEOUT) {
+            set_last_error(e.to_string());
+            return false;
+        }
+        configure_engine_pool(EnginePoolConfig::default());
+        release_backends();
+        *initialized = false;
+        true
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_last_error_message;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_init_options_defaults_and_validation() {
+        let options = InitOptions::from_json("{}").unwrap();
+        assert!(options.worker_threads.is_none());
+        assert!(options.preload.extractors);
+        assert!(options.preload.embedding_presets.is_empty());
+
+        let options =
+            InitOptions::from_json(r#"{"ocr_engine_pool":{"reuse":true},"preload":{"extractors":false}}"#).unwrap();
+        assert_eq!(options.ocr_engine_pool.unwrap().reuse, Some(true));
+        assert!(!options.preload.extractors);
+
+        assert!(InitOptions::from_json(r#"{"threads":4}"#).is_err());
+        assert!(install_logging("kreuzberg=loud").is_err());
+    }
+
+    #[test]
+    fn test_init_validates_options_and_rejects_second_call() {
+        let options = CString::new(r#"{"worker_threads":"many"}"#).unwrap();
+        assert!(!unsafe { kreuzberg_init(options.as_ptr()) });
+        assert!(get_last_error_message().unwrap().contains("Invalid init options"));
+
+        let options = CString::new(r#"{"preload":{"embedding_presets":["no-such-preset"]}}"#).unwrap();
+        assert!(!unsafe { kreuzberg_init(options.as_ptr()) });
+
+        assert!(unsafe { kreuzberg_init(std::ptr::null()) });
+        assert!(!unsafe { kreuzberg_init(std::ptr::null()) });
+        assert!(get_last_error_message().unwrap().contains("already called"));
+
+        // Other tests extract concurrently, so reset the flag instead of shutting down
+        *INITIALIZED.lock().unwrap() = false;
+    }
+}
@@ -0,0 +1,233 @@
+//! Native memory reporting FFI module.
+//!
+//! Memory allocated by Kreuzberg (decoded images, extraction results, native libraries) lives
+//! outside the JVM, Go and .NET heaps, so host profilers never see it. This module reports
+//! it to embedders as JSON.
+//!
+//! # Allocator Tracking
+//!
+//! With the `memory-tracking` feature, the library installs a global allocator that wraps
+//! the system allocator and counts live and peak bytes. Only Rust-side allocations go through
+//! it: C libraries such as pdfium or tesseract allocate from the C heap directly and are not
+//! counted. Without the feature, allocator fields are `null` and `tracking_enabled` is `false`. The feature installs a
+//! `#[global_allocator]`, so it must not be combined with another global allocator in the
+//! same binary.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! char* stats = kreuzberg_memory_stats();
+//! if (stats != NULL) {
+//!     printf("%s\n", stats);
+//!     // {"allocator":{"tracking_enabled":true,"current_bytes":1048576,...},"caches":{...}}
+//!     kreuzberg_free_string(stats);
+//! }
+//! ```
+
+use crate::string_intern::kreuzberg_string_intern_stats;
+use crate::{clear_last_error, set_last_error, string_to_c_string};
+use serde::Serialize;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// Allocator counters; all fields are `None` unless `memory-tracking` is enabled.
+#[derive(Debug, Serialize)]
+struct AllocatorStats {
+    tracking_enabled: bool,
+    current_bytes: Option<usize>,
+    peak_bytes: Option<usize>,
+    total_allocations: Option<usize>,
+    total_deallocations: Option<usize>,
+}
+
+/// Memory held by in-process caches.
+#[derive(Debug, Serialize)]
+struct CacheMemoryStats {
+    string_intern_bytes: usize,
+    string_intern_entries: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct MemoryStats {
+    allocator: AllocatorStats,
+    caches: CacheMemoryStats,
+}
+
+#[cfg(feature = "memory-tracking")]
+mod tracking {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    pub(super) static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+    pub(super) static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+    pub(super) static TOTAL_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    pub(super) static TOTAL_DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// System allocator that counts live and peak bytes.
+    pub struct TrackingAllocator;
+
+    impl TrackingAllocator {
+        fn record_alloc(size: usize) {
+            let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+            PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+            TOTAL_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn record_dealloc(size: usize) {
+            CURRENT_BYTES.fetch_sub(size, Ordering::Relaxed);
+            TOTAL_DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // SAFETY: every call is forwarded unchanged to the system allocator; only counters are added.
+    unsafe impl GlobalAlloc for TrackingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                Self::record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                Self::record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            Self::record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                Self::record_dealloc(layout.size());
+                Self::record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: TrackingAllocator = TrackingAllocator;
+}
+
+#[cfg(feature = "memory-tracking")]
+fn allocator_stats() -> AllocatorStats {
+    use std::sync::atomic::Ordering;
+
+    AllocatorStats {
+        tracking_enabled: true,
+        current_bytes: Some(tracking::CURRENT_BYTES.load(Ordering::Relaxed)),
+        peak_bytes: Some(tracking::PEAK_BYTES.load(Ordering::Relaxed)),
+        total_allocations: Some(tracking::TOTAL_ALLOCATIONS.load(Ordering::Relaxed)),
+        total_deallocations: Some(tracking::TOTAL_DEALLOCATIONS.load(Ordering::Relaxed)),
+    }
+}
+
+#[cfg(not(feature = "memory-tracking"))]
+fn allocator_stats() -> AllocatorStats {
+    AllocatorStats {
+        tracking_enabled: false,
+        current_bytes: None,
+        peak_bytes: None,
+        total_allocations: None,
+        total_deallocations: None,
+    }
+}
+
+fn collect_memory_stats() -> MemoryStats {
+    let intern = kreuzberg_string_intern_stats();
+
+    MemoryStats {
+        allocator: allocator_stats(),
+        caches: CacheMemoryStats {
+            string_intern_bytes: intern.total_memory_bytes,
+            string_intern_entries: intern.unique_count,
+        },
+    }
+}
+
+/// Get native memory usage as JSON.
+///
+/// Reports allocator usage (current and peak bytes, allocation counts) when built with
+/// the `memory-tracking` feature, and memory held by in-process caches.
+///
+/// # Returns
+///
+/// JSON object string, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - Returned string must be freed with `kreuzberg_free_string`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_memory_stats() -> *mut c_char {
+    crate::ffi_panic_guard!("kreuzberg_memory_stats", {
+        clear_last_error();
+
+        match serde_json::to_string(&collect_memory_stats()) {
+            Ok(json) => match string_to_c_string(json) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Failed to serialize memory stats: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Reset the allocator peak to the current usage.
+///
+/// Lets embedders report the peak per interval (e.g. per scrape) instead of since startup.
+/// Does nothing unless built with the `memory-tracking` feature.
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_memory_reset_peak() {
+    #[cfg(feature = "memory-tracking")]
+    {
+        use std::sync::atomic::Ordering;
+
+        let current = tracking::CURRENT_BYTES.load(Ordering::Relaxed);
+        tracking::PEAK_BYTES.store(current, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_memory_stats_json() {
+        let ptr = unsafe { kreuzberg_memory_stats() };
+        assert!(!ptr.is_null());
+
+        let json = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { crate::kreuzberg_free_string(ptr) };
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let allocator = &value["allocator"];
+        assert_eq!(
+            allocator["tracking_enabled"].as_bool(),
+            Some(cfg!(feature = "memory-tracking"))
+        );
+        assert!(value["caches"]["string_intern_entries"].as_u64().is_some());
+
+        if cfg!(feature = "memory-tracking") {
+            kreuzberg_memory_reset_peak();
+            let buffer = std::hint::black_box(vec![0u8; 1 << 20]);
+            let stats = collect_memory_stats();
+            assert!(stats.allocator.peak_bytes >= Some(buffer.len()));
+        } else {
+            assert!(allocator["current_bytes"].is_null());
+        }
+    }
+}
@@ -72,6 +72,13 @@ pub fn set_structured_error(error: StructuredError) {
     LAST_STRUCTURED_ERROR.with(|last| *last.borrow_mut() = Some(error));
 }
 
+/// Records a panic caught at the FFI boundary: stores it as the last error and hands a crash dump
+/// to the registered crash-dump callback, if any.
+pub fn record_panic(context: PanicContext) {
+    crate::crash_dump::report(&context);
+    set_structured_error(StructuredError::from_panic(context));
+}
+
 /// Gets the last structured error message (for compatibility with existing code).
 pub fn get_last_error_message() -> Option<String> {
     LAST_STRUCTURED_ERROR.with(|last| last.borrow().as_ref().map(|e| e.full_message()))
@@ -125,16 +132,18 @@ pub fn clear_structured_error() {
 /// - Catch any panics that occur in the wrapped code
 /// - Create a PanicContext with file/line/function information
 /// - Store the structured error in thread-local storage
+/// - Deliver a crash dump to the callback registered with `kreuzberg_set_crash_dump_callback`
 /// - Return a null pointer (for pointer-returning functions) or false (for bool-returning functions) to indicate failure
 #[macro_export]
 macro_rules! ffi_panic_guard {
     ($function_name:expr, $body:expr) => {{
+        $crate::crash_dump::clear_thread_state();
         match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)) {
             Ok(result) => result,
             Err(panic_info) => {
                 let context =
                     kreuzberg::panic_context::PanicContext::new(file!(), line!(), $function_name, panic_info.as_ref());
-                $crate::panic_shield::set_structured_error($crate::panic_shield::StructuredError::from_panic(context));
+                $crate::panic_shield::record_panic(context);
                 std::ptr::null_mut()
             }
         }
@@ -147,12 +156,13 @@ macro_rules! ffi_panic_guard {
 #[macro_export]
 macro_rules! ffi_panic_guard_bool {
     ($function_name:expr, $body:expr) => {{
+        $crate::crash_dump::clear_thread_state();
         match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)) {
             Ok(result) => result,
             Err(panic_info) => {
                 let context =
                     kreuzberg::panic_context::PanicContext::new(file!(), line!(), $function_name, panic_info.as_ref());
-                $crate::panic_shield::set_structured_error($crate::panic_shield::StructuredError::from_panic(context));
+                $crate::panic_shield::record_panic(context);
                 false
             }
         }
@@ -165,12 +175,13 @@ macro_rules! ffi_panic_guard_bool {
 #[macro_export]
 macro_rules! ffi_panic_guard_i32 {
     ($function_name:expr, $body:expr) => {{
+        $crate::crash_dump::clear_thread_state();
         match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body)) {
             Ok(result) => result,
             Err(panic_info) => {
                 let context =
                     kreuzberg::panic_context::PanicContext::new(file!(), line!(), $function_name, panic_info.as_ref());
-                $crate::panic_shield::set_structured_error($crate::panic_shield::StructuredError::from_panic(context));
+                $crate::panic_shield::record_panic(context);
                 -1
             }
         }
@@ -8,7 +8,7 @@
 //! with `kreuzberg_free_string()`.
 
 use crate::{clear_last_error, set_last_error};
-use kreuzberg::types::ExtractionResult;
+use kreuzberg::types::{ExifDetails, ExtractionResult, FormatMetadata, Metadata};
 use std::ffi::CString;
 use std::os::raw::c_char;
 use std::ptr;
@@ -252,20 +252,12 @@ pub unsafe extern "C" fn kreuzberg_result_get_metadata_field(
 ) -> CMetadataField {
     if result.is_null() {
         set_last_error("Result cannot be NULL".to_string());
-        return CMetadataField {
-            name: field_name,
-            json_value: ptr::null_mut(),
-            is_null: 1,
-        };
+        return missing_field(field_name);
     }
 
     if field_name.is_null() {
         set_last_error("Field name cannot be NULL".to_string());
-        return CMetadataField {
-            name: ptr::null(),
-            json_value: ptr::null_mut(),
-            is_null: 1,
-        };
+        return missing_field(ptr::null());
     }
 
     clear_last_error();
@@ -275,11 +267,7 @@ pub unsafe extern "C" fn kreuzberg_result_get_metadata_field(
         Ok(s) => s,
         Err(e) => {
             set_last_error(format!("Invalid UTF-8 in field name: {}", e));
-            return CMetadataField {
-                name: field_name,
-                json_value: ptr::null_mut(),
-                is_null: 1,
-            };
+            return missing_field(field_name);
         }
     };
 
@@ -291,34 +279,202 @@ pub unsafe extern "C" fn kreuzberg_result_get_metadata_field(
         Ok(val) => val,
         Err(e) => {
             set_last_error(format!("Failed to serialize metadata: {}", e));
-            return CMetadataField {
-                name: field_name,
-                json_value: ptr::null_mut(),
-                is_null: 1,
-            };
+            return missing_field(field_name);
         }
     };
 
-    // Navigate the JSON path using dot notation
-    let mut current = &metadata_json;
-    for part in field_str.split('.') {
-        if let Some(obj) = current.as_object() {
-            match obj.get(part) {
-                Some(val) => current = val,
-                None => {
-                    return CMetadataField {
-                        name: field_name,
-                        json_value: ptr::null_mut(),
-                        is_null: 1,
-                    };
-                }
-            }
-        } else {
-            return CMetadataField {
-                name: field_name,
-                json_value: ptr::null_mut(),
-                is_null: 1,
-            };
+    lookup_json_field(&metadata_json, field_name, field_str)
+}
+
+/// Get a structured EXIF field by name.
+///
+/// Image inputs carry structured EXIF metadata with `camera`, `timestamps` and `gps`
+/// sections. This accessor returns a value from that object as a JSON string, using
+/// dot notation for nested fields (e.g., "camera.model", "gps.latitude"). Pass an
+/// empty string to get the whole EXIF object.
+///
+/// GPS data is absent when extraction ran with `images.strip_gps` enabled.
+///
+/// # Arguments
+///
+/// * `result` - Pointer to an ExtractionResult structure
+/// * `field_name` - Null-terminated C string with the field path
+///
+/// # Returns
+///
+/// A CMetadataField structure containing:
+/// - `name`: The field name (caller should not free)
+/// - `json_value`: Pointer to field value as JSON string (must free with `kreuzberg_free_string`),
+///   or NULL if the result has no EXIF data or the field doesn't exist
+/// - `is_null`: 1 if field doesn't exist, 0 if it does
+///
+/// # Safety
+///
+/// - `result` must be a valid pointer to an ExtractionResult
+/// - `field_name` must be a valid null-terminated C string
+/// - Neither parameter can be NULL
+/// - The returned `json_value` (if non-NULL) must be freed with `kreuzberg_free_string`
+///
+/// # Example (C)
+///
+/// ```c
+/// ExtractionResult* result = kreuzberg_extract_file("photo.jpg", NULL);
+/// if (result != NULL) {
+///     CMetadataField lat = kreuzberg_result_get_exif_field(result, "gps.latitude");
+///     if (!lat.is_null) {
+///         printf("Latitude: %s\n", lat.json_value);
+///         kreuzberg_free_string(lat.json_value);
+///     }
+///     kreuzberg_result_free(result);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_result_get_exif_field(
+    result: *const ExtractionResult,
+    field_name: *const c_char,
+) -> CMetadataField {
+    if result.is_null() {
+        set_last_error("Result cannot be NULL".to_string());
+        return missing_field(field_name);
+    }
+
+    if field_name.is_null() {
+        set_last_error("Field name cannot be NULL".to_string());
+        return missing_field(ptr::null());
+    }
+
+    clear_last_error();
+
+    // SAFETY: We've verified field_name is not null and it must be a valid C string.
+    let field_str = match unsafe { std::ffi::CStr::from_ptr(field_name) }.to_str() {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(format!("Invalid UTF-8 in field name: {}", e));
+            return missing_field(field_name);
+        }
+    };
+
+    // SAFETY: We've verified result is not null.
+    let result_ref = unsafe { &*result };
+
+    let Some(exif) = exif_details_of(&result_ref.metadata) else {
+        return missing_field(field_name);
+    };
+
+    let exif_json = match serde_json::to_value(exif) {
+        Ok(val) => val,
+        Err(e) => {
+            set_last_error(format!("Failed to serialize EXIF data: {}", e));
+            return missing_field(field_name);
+        }
+    };
+
+    lookup_json_field(&exif_json, field_name, field_str)
+}
+
+/// Migrate a serialized extraction result to another schema version.
+///
+/// Stored results written by older releases can be upgraded before loading them,
+/// and current results downgraded for readers that only understand an older layout.
+/// Results without a `schema_version` field have their version inferred.
+///
+/// # Arguments
+///
+/// * `result_json` - Null-terminated JSON string of a serialized ExtractionResult
+/// * `target_version` - Schema version to migrate to
+///
+/// # Returns
+///
+/// A pointer to the migrated result as a JSON string, or NULL on error
+/// (check `kreuzberg_last_error`), e.g. for malformed JSON or unknown versions.
+///
+/// The returned pointer must be freed with `kreuzberg_free_string()`.
+///
+/// # Safety
+///
+/// - `result_json` must be a valid null-terminated C string
+/// - `result_json` cannot be NULL
+/// - The returned pointer (if non-NULL) must be freed with `kreuzberg_free_string`
+///
+/// # Example (C)
+///
+/// ```c
+/// char* migrated = kreuzberg_result_migrate(stored_json, 2);
+/// if (migrated != NULL) {
+///     // Load the migrated result...
+///     kreuzberg_free_string(migrated);
+/// } else {
+///     printf("Migration failed: %s\n", kreuzberg_last_error());
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_result_migrate(result_json: *const c_char, target_version: u32) -> *mut c_char {
+    if result_json.is_null() {
+        set_last_error("Result JSON cannot be NULL".to_string());
+        return ptr::null_mut();
+    }
+
+    clear_last_error();
+
+    // SAFETY: We've verified result_json is not null and it must be a valid C string.
+    let json_str = match unsafe { std::ffi::CStr::from_ptr(result_json) }.to_str() {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(format!("Invalid UTF-8 in result JSON: {}", e));
+            return ptr::null_mut();
+        }
+    };
+
+    let value = match serde_json::from_str(json_str) {
+        Ok(value) => value,
+        Err(e) => {
+            set_last_error(format!("Failed to parse result JSON: {}", e));
+            return ptr::null_mut();
+        }
+    };
+
+    let migrated = match kreuzberg::migrate_result(value, target_version) {
+        Ok(migrated) => migrated,
+        Err(e) => {
+            set_last_error(e.to_string());
+            return ptr::null_mut();
+        }
+    };
+
+    match CString::new(migrated.to_string()) {
+        Ok(c_string) => c_string.into_raw(),
+        Err(e) => {
+            set_last_error(format!("Failed to convert migrated result to C string: {}", e));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Structured EXIF metadata of an image input's metadata.
+pub(crate) fn exif_details_of(metadata: &Metadata) -> Option<&ExifDetails> {
+    match &metadata.format {
+        Some(FormatMetadata::Image(image)) => image.exif_details.as_ref(),
+        _ => None,
+    }
+}
+
+fn missing_field(field_name: *const c_char) -> CMetadataField {
+    CMetadataField {
+        name: field_name,
+        json_value: ptr::null_mut(),
+        is_null: 1,
+    }
+}
+
+/// Navigate a dot-separated path in a JSON value and return the value found as a field.
+///
+/// An empty path returns the root value.
+fn lookup_json_field(root: &serde_json::Value, field_name: *const c_char, field_str: &str) -> CMetadataField {
+    let mut current = root;
+    for part in field_str.split('.').filter(|part| !part.is_empty()) {
+        match current.as_object().and_then(|obj| obj.get(part)) {
+            Some(val) => current = val,
+            None => return missing_field(field_name),
         }
     }
 
@@ -332,20 +488,12 @@ pub unsafe extern "C" fn kreuzberg_result_get_metadata_field(
             },
             Err(e) => {
                 set_last_error(format!("Failed to convert field value to C string: {}", e));
-                CMetadataField {
-                    name: field_name,
-                    json_value: ptr::null_mut(),
-                    is_null: 1,
-                }
+                missing_field(field_name)
             }
         },
         Err(e) => {
             set_last_error(format!("Failed to serialize field value: {}", e));
-            CMetadataField {
-                name: field_name,
-                json_value: ptr::null_mut(),
-                is_null: 1,
-            }
+            missing_field(field_name)
         }
     }
 }
@@ -388,6 +536,7 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        annotations: Default::default(),
                     },
                 },
                 kreuzberg::types::Chunk {
@@ -401,11 +550,16 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        annotations: Default::default(),
                     },
                 },
             ]),
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         }
     }
 
@@ -506,6 +660,92 @@ mod tests {
         }
     }
 
+    fn create_image_result() -> ExtractionResult {
+        use kreuzberg::types::{ExifCamera, GpsCoordinates, ImageMetadata};
+        use std::collections::HashMap;
+
+        let metadata = Metadata {
+            format: Some(FormatMetadata::Image(ImageMetadata {
+                width: 640,
+                height: 480,
+                format: "JPEG".to_string(),
+                exif: HashMap::new(),
+                exif_details: Some(ExifDetails {
+                    camera: Some(ExifCamera {
+                        make: Some("Canon".to_string()),
+                        model: Some("EOS R5".to_string()),
+                        ..Default::default()
+                    }),
+                    timestamps: None,
+                    gps: Some(GpsCoordinates {
+                        latitude: 52.52,
+                        longitude: 13.405,
+                        altitude: None,
+                    }),
+                }),
+            })),
+            ..Default::default()
+        };
+
+        ExtractionResult {
+            content: "Image: JPEG 640x480".to_string(),
+            mime_type: "image/jpeg".to_string(),
+            metadata,
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_result_get_exif_field() {
+        let result_ptr = Box::into_raw(Box::new(create_image_result()));
+
+        let model = std::ffi::CString::new("camera.model").unwrap();
+        let field = unsafe { kreuzberg_result_get_exif_field(result_ptr, model.as_ptr()) };
+        assert_eq!(field.is_null, 0);
+        let value_str = unsafe { CStr::from_ptr(field.json_value).to_str().unwrap() };
+        assert_eq!(value_str, r#""EOS R5""#);
+        unsafe { crate::kreuzberg_free_string(field.json_value) };
+
+        let latitude = std::ffi::CString::new("gps.latitude").unwrap();
+        let field = unsafe { kreuzberg_result_get_exif_field(result_ptr, latitude.as_ptr()) };
+        assert_eq!(field.is_null, 0);
+        let value_str = unsafe { CStr::from_ptr(field.json_value).to_str().unwrap() };
+        assert_eq!(value_str, "52.52");
+        unsafe { crate::kreuzberg_free_string(field.json_value) };
+
+        let missing = std::ffi::CString::new("timestamps.original").unwrap();
+        let field = unsafe { kreuzberg_result_get_exif_field(result_ptr, missing.as_ptr()) };
+        assert_eq!(field.is_null, 1);
+        assert!(field.json_value.is_null());
+
+        unsafe {
+            let _ = Box::from_raw(result_ptr);
+        }
+    }
+
+    #[test]
+    fn test_result_get_exif_field_without_exif() {
+        let result_ptr = Box::into_raw(Box::new(create_test_result()));
+
+        let field_name = std::ffi::CString::new("").unwrap();
+        let field = unsafe { kreuzberg_result_get_exif_field(result_ptr, field_name.as_ptr()) };
+
+        assert_eq!(field.is_null, 1);
+        assert!(field.json_value.is_null());
+
+        unsafe {
+            let _ = Box::from_raw(result_ptr);
+        }
+    }
+
     #[test]
     fn test_result_get_metadata_field_null_result() {
         let field_name = std::ffi::CString::new("title").unwrap();
@@ -514,4 +754,26 @@ mod tests {
         assert_eq!(field.is_null, 1);
         assert!(field.json_value.is_null());
     }
+
+    #[test]
+    fn test_result_migrate() {
+        let stored = std::ffi::CString::new(
+            r#"{"content":"é ab","mime_type":"text/plain","metadata":{},"tables":[],
+               "chunks":[{"content":"ab","metadata":{"char_start":2,"char_end":4,"chunk_index":0,"total_chunks":1}}]}"#,
+        )
+        .unwrap();
+
+        let migrated_ptr = unsafe { kreuzberg_result_migrate(stored.as_ptr(), ExtractionResult::SCHEMA_VERSION) };
+        assert!(!migrated_ptr.is_null());
+
+        let migrated_str = unsafe { CStr::from_ptr(migrated_ptr).to_str().unwrap() };
+        let migrated: ExtractionResult = serde_json::from_str(migrated_str).unwrap();
+        assert_eq!(migrated.schema_version, ExtractionResult::SCHEMA_VERSION);
+        assert_eq!(migrated.chunks.unwrap()[0].metadata.byte_start, 3);
+        unsafe { crate::kreuzberg_free_string(migrated_ptr) };
+
+        let unknown = unsafe { kreuzberg_result_migrate(stored.as_ptr(), 99) };
+        assert!(unknown.is_null());
+        assert!(unsafe { kreuzberg_result_migrate(ptr::null(), 1) }.is_null());
+    }
 }
@@ -0,0 +1,410 @@
+//! Result consistency verification FFI module.
+//!
+//! Post-processors, validators and OCR backends registered from host languages can
+//! modify an `ExtractionResult` before it is returned. A faulty plugin can leave chunk
+//! offsets pointing past the content or pages out of order, which only surfaces later
+//! when the result is persisted or indexed. `kreuzberg_result_verify` checks the
+//! internal consistency of a result and reports every problem found as JSON.
+//!
+//! # Checks
+//!
+//! - `utf8`: content, chunk and page text are valid UTF-8
+//! - `chunk_offsets`: chunk byte ranges lie within the content, on character boundaries
+//! - `chunk_indices`: chunk indices are sequential and `total_chunks` matches the chunk count
+//! - `page_numbers`: page numbers (boundaries, page contents, page info, chunk page ranges)
+//!   are 1-indexed and strictly increasing, and page boundaries lie within the content
+//! - `term_offsets`: term byte ranges lie within the content
+//!
+//! # Example (C)
+//!
+//! ```c
+//! char* report = kreuzberg_result_verify(result);
+//! if (report != NULL) {
+//!     printf("%s\n", report);
+//!     // {"valid":false,"issues":[{"check":"chunk_offsets","location":"chunks[3]",
+//!     //   "message":"byte_end 120 exceeds content length 100"}]}
+//!     kreuzberg_free_string(report);
+//! }
+//! ```
+
+use crate::{clear_last_error, set_last_error, string_to_c_string};
+use kreuzberg::types::ExtractionResult;
+use serde::Serialize;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// A single consistency problem.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+struct VerificationIssue {
+    check: &'static str,
+    location: String,
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct VerificationReport {
+    valid: bool,
+    issues: Vec<VerificationIssue>,
+}
+
+impl VerificationReport {
+    fn push(&mut self, check: &'static str, location: impl Into<String>, message: String) {
+        self.issues.push(VerificationIssue {
+            check,
+            location: location.into(),
+            message,
+        });
+    }
+}
+
+/// Check that a byte range lies within `content` and on character boundaries.
+fn check_range(content: &str, byte_start: usize, byte_end: usize) -> Option<String> {
+    if byte_start > byte_end {
+        Some(format!("byte_start {} is after byte_end {}", byte_start, byte_end))
+    } else if byte_end > content.len() {
+        Some(format!(
+            "byte_end {} exceeds content length {}",
+            byte_end,
+            content.len()
+        ))
+    } else if !content.is_char_boundary(byte_start) || !content.is_char_boundary(byte_end) {
+        Some(format!(
+            "byte range {}..{} splits a UTF-8 character",
+            byte_start, byte_end
+        ))
+    } else {
+        None
+    }
+}
+
+/// Check that 1-indexed page numbers are strictly increasing.
+fn check_page_numbers(report: &mut VerificationReport, field: &str, numbers: impl IntoIterator<Item = usize>) {
+    let mut previous = 0;
+    for (idx, number) in numbers.into_iter().enumerate() {
+        if number <= previous {
+            report.push(
+                "page_numbers",
+                format!("{}[{}]", field, idx),
+                format!("page number {} does not follow {}", number, previous),
+            );
+        }
+        previous = previous.max(number);
+    }
+}
+
+fn check_utf8(report: &mut VerificationReport, location: String, text: &str) {
+    if let Err(e) = std::str::from_utf8(text.as_bytes()) {
+        report.push("utf8", location, format!("invalid UTF-8: {}", e));
+    }
+}
+
+fn verify_result(result: &ExtractionResult) -> VerificationReport {
+    let mut report = VerificationReport {
+        valid: true,
+        issues: Vec::new(),
+    };
+    let content = result.content.as_str();
+
+    check_utf8(&mut report, "content".to_string(), content);
+
+    if let Some(chunks) = &result.chunks {
+        for (idx, chunk) in chunks.iter().enumerate() {
+            let location = format!("chunks[{}]", idx);
+            let metadata = &chunk.metadata;
+
+            check_utf8(&mut report, location.clone(), &chunk.content);
+            if let Some(message) = check_range(content, metadata.byte_start, metadata.byte_end) {
+                report.push("chunk_offsets", location.clone(), message);
+            }
+            if metadata.chunk_index != idx {
+                report.push(
+                    "chunk_indices",
+                    location.clone(),
+                    format!("chunk_index {} at position {}", metadata.chunk_index, idx),
+                );
+            }
+            if metadata.total_chunks != chunks.len() {
+                report.push(
+                    "chunk_indices",
+                    location.clone(),
+                    format!(
+                        "total_chunks {} but {} chunks present",
+                        metadata.total_chunks,
+                        chunks.len()
+                    ),
+                );
+            }
+            match (metadata.first_page, metadata.last_page) {
+                (Some(0), _) | (_, Some(0)) => {
+                    report.push("page_numbers", location, "page numbers are 1-indexed".to_string());
+                }
+                (Some(first), Some(last)) if first > last => {
+                    report.push(
+                        "page_numbers",
+                        location,
+                        format!("first_page {} is after last_page {}", first, last),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(structure) = &result.metadata.pages {
+        if let Some(boundaries) = &structure.boundaries {
+            check_page_numbers(
+                &mut report,
+                "metadata.pages.boundaries",
+                boundaries.iter().map(|boundary| boundary.page_number),
+            );
+
+            let mut previous_end = 0;
+            for (idx, boundary) in boundaries.iter().enumerate() {
+                let location = format!("metadata.pages.boundaries[{}]", idx);
+                if let Some(message) = check_range(content, boundary.byte_start, boundary.byte_end) {
+                    report.push("page_numbers", location, message);
+                } else if boundary.byte_start < previous_end {
+                    report.push(
+                        "page_numbers",
+                        location,
+                        format!(
+                            "byte_start {} overlaps the previous page ending at {}",
+                            boundary.byte_start, previous_end
+                        ),
+                    );
+                }
+                previous_end = previous_end.max(boundary.byte_end);
+            }
+        }
+
+        if let Some(pages) = &structure.pages {
+            check_page_numbers(
+                &mut report,
+                "metadata.pages.pages",
+                pages.iter().map(|page| page.number),
+            );
+        }
+    }
+
+    if let Some(pages) = &result.pages {
+        check_page_numbers(&mut report, "pages", pages.iter().map(|page| page.page_number));
+        for (idx, page) in pages.iter().enumerate() {
+            check_utf8(&mut report, format!("pages[{}]", idx), &page.content);
+        }
+    }
+
+    if let Some(term_offsets) = &result.term_offsets {
+        for (idx, term) in term_offsets.iter().enumerate() {
+            if let Some(message) = check_range(content, term.byte_start, term.byte_end) {
+                report.push("term_offsets", format!("term_offsets[{}]", idx), message);
+            }
+        }
+    }
+
+    report.valid = report.issues.is_empty();
+    report
+}
+
+/// Verify the internal consistency of an extraction result.
+///
+/// Checks chunk offsets against the content bounds, chunk indices, page number
+/// ordering, term offsets and UTF-8 validity, so bindings can detect corruption
+/// introduced by faulty plugins before persisting a result.
+///
+/// # Arguments
+///
+/// * `result` - Pointer to an ExtractionResult structure
+///
+/// # Returns
+///
+/// JSON object string `{"valid": bool, "issues": [{"check", "location", "message"}]}`,
+/// or NULL on error (check `kreuzberg_last_error`). An inconsistent result is not an
+/// error: it is reported with `"valid": false`.
+///
+/// # Safety
+///
+/// - `result` must be a valid pointer to an ExtractionResult
+/// - `result` cannot be NULL
+/// - Returned string must be freed with `kreuzberg_free_string`
+///
+/// # Example (C)
+///
+/// ```c
+/// ExtractionResult* result = kreuzberg_extract_file("document.pdf", config);
+/// if (result != NULL) {
+///     char* report = kreuzberg_result_verify(result);
+///     if (report != NULL && strstr(report, "\"valid\":false") != NULL) {
+///         fprintf(stderr, "Inconsistent result: %s\n", report);
+///     }
+///     kreuzberg_free_string(report);
+///     kreuzberg_result_free(result);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_result_verify(result: *const ExtractionResult) -> *mut c_char {
+    crate::ffi_panic_guard!("kreuzberg_result_verify", {
+        if result.is_null() {
+            set_last_error("Result cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        clear_last_error();
+
+        // SAFETY: We've verified result is not null and it must be a valid ExtractionResult.
+        let result_ref = unsafe { &*result };
+
+        match serde_json::to_string(&verify_result(result_ref)) {
+            Ok(json) => match string_to_c_string(json) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Failed to serialize verification report: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kreuzberg::types::{
+        Chunk, ChunkMetadata, Metadata, PageBoundary, PageContent, PageStructure, PageUnitType, TermOffset,
+    };
+    use std::ffi::CStr;
+
+    fn chunk(byte_start: usize, byte_end: usize, chunk_index: usize, total_chunks: usize) -> Chunk {
+        Chunk {
+            content: "chunk".to_string(),
+            embedding: None,
+            metadata: ChunkMetadata {
+                byte_start,
+                byte_end,
+                token_count: None,
+                chunk_index,
+                total_chunks,
+                first_page: Some(1),
+                last_page: Some(1),
+                annotations: Default::default(),
+            },
+        }
+    }
+
+    fn page(page_number: usize) -> PageContent {
+        PageContent {
+            page_number,
+            content: "page".to_string(),
+            tables: vec![],
+            images: vec![],
+            content_blob: None,
+        }
+    }
+
+    fn create_result() -> ExtractionResult {
+        let content = "First page. Second page.".to_string();
+        let pages = Some(PageStructure {
+            total_count: 2,
+            unit_type: PageUnitType::Page,
+            boundaries: Some(vec![
+                PageBoundary {
+                    byte_start: 0,
+                    byte_end: 12,
+                    page_number: 1,
+                },
+                PageBoundary {
+                    byte_start: 12,
+                    byte_end: content.len(),
+                    page_number: 2,
+                },
+            ]),
+            pages: None,
+        });
+
+        ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages,
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: Some(vec![chunk(0, 12, 0, 2), chunk(12, 24, 1, 2)]),
+            images: None,
+            pages: Some(vec![page(1), page(2)]),
+            term_offsets: Some(vec![TermOffset {
+                term: "first".to_string(),
+                byte_start: 0,
+                byte_end: 5,
+                page: Some(1),
+            }]),
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_verify_consistent_result() {
+        let report = verify_result(&create_result());
+        assert!(report.valid);
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_verify_reports_every_issue() {
+        let mut result = create_result();
+        result.chunks = Some(vec![chunk(0, 12, 0, 3), chunk(12, 40, 2, 3)]);
+        result.pages = Some(vec![page(2), page(1)]);
+
+        let report = verify_result(&result);
+        assert!(!report.valid);
+
+        let checks: Vec<(&str, &str)> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.check, issue.location.as_str()))
+            .collect();
+        assert_eq!(
+            checks,
+            vec![
+                ("chunk_indices", "chunks[0]"),
+                ("chunk_offsets", "chunks[1]"),
+                ("chunk_indices", "chunks[1]"),
+                ("chunk_indices", "chunks[1]"),
+                ("page_numbers", "pages[1]"),
+            ]
+        );
+        assert_eq!(report.issues[1].message, "byte_end 40 exceeds content length 24");
+    }
+
+    #[test]
+    fn test_check_range_rejects_split_characters() {
+        assert!(check_range("Grüße", 0, 3).is_some());
+        assert!(check_range("Grüße", 0, 4).is_none());
+        assert!(check_range("Grüße", 4, 2).is_some());
+    }
+
+    #[test]
+    fn test_result_verify_json() {
+        let result = create_result();
+        let ptr = unsafe { kreuzberg_result_verify(&result) };
+        assert!(!ptr.is_null());
+
+        let json = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { crate::kreuzberg_free_string(ptr) };
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, serde_json::json!({ "valid": true, "issues": [] }));
+    }
+
+    #[test]
+    fn test_result_verify_null() {
+        let ptr = unsafe { kreuzberg_result_verify(ptr::null()) };
+        assert!(ptr.is_null());
+    }
+}
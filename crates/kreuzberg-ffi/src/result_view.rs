@@ -449,6 +449,7 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        annotations: Default::default(),
                     },
                 },
                 kreuzberg::types::Chunk {
@@ -462,11 +463,16 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        annotations: Default::default(),
                     },
                 },
             ]),
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         }
     }
 
@@ -754,6 +760,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let result_ptr = &result as *const ExtractionResult;
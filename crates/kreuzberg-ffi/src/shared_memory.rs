@@ -0,0 +1,360 @@
+//! Shared-memory result transfer FFI module.
+//!
+//! `kreuzberg_extract_file_shared` returns an extraction result whose content and image
+//! bytes are written to a shared-memory segment instead of C strings and JSON arrays.
+//! Bindings map the segment straight into a `MappedByteBuffer` (JVM) or
+//! `MemoryMappedFile` (CLR) and read the content and images at the reported offsets,
+//! so multi-hundred-MB outputs are not copied once into a C string and again into the
+//! managed heap.
+//!
+//! The segment is a file in `/dev/shm` (tmpfs, never written to disk) when available,
+//! and in the system temporary directory otherwise. Its layout is the UTF-8 content
+//! followed by each image, every block starting at an 8-byte aligned offset.
+//!
+//! # Example (C)
+//!
+//! ```c
+//! CSharedExtractionResult* shared = kreuzberg_extract_file_shared("scan.pdf", NULL);
+//! if (shared != NULL) {
+//!     int fd = open(shared->segment_path, O_RDONLY);
+//!     uint8_t* base = mmap(NULL, shared->segment_size, PROT_READ, MAP_SHARED, fd, 0);
+//!     fwrite(base + shared->content_offset, 1, shared->content_len, stdout);
+//!     munmap(base, shared->segment_size);
+//!     close(fd);
+//!     kreuzberg_free_shared_result(shared);
+//! }
+//! ```
+
+use crate::{
+    CExtractionResult, clear_last_error, kreuzberg_free_result, parse_extraction_config_from_json, set_last_error,
+    to_c_extraction_result,
+};
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::core::fields::FieldSelection;
+use kreuzberg::types::ExtractionResult;
+use std::ffi::{CStr, CString};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Alignment of every block in a segment.
+const SEGMENT_ALIGNMENT: usize = 8;
+
+static NEXT_SEGMENT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Location of one extracted image in a shared-memory segment.
+///
+/// Entries are in the same order as the images in `images_json`.
+#[repr(C)]
+pub struct CSharedImage {
+    /// Byte offset of the image data in the segment
+    pub offset: usize,
+    /// Length of the image data in bytes
+    pub len: usize,
+}
+
+/// Extraction result whose content and images live in a shared-memory segment.
+///
+/// `result` carries everything else: its `content` is an empty string and the images in
+/// its `images_json` have empty `data` arrays.
+#[repr(C)]
+pub struct CSharedExtractionResult {
+    /// Result without content and image bytes
+    pub result: *mut CExtractionResult,
+    /// Path of the segment file (null-terminated UTF-8)
+    pub segment_path: *mut c_char,
+    /// Total size of the segment in bytes
+    pub segment_size: usize,
+    /// Byte offset of the UTF-8 content (not null-terminated)
+    pub content_offset: usize,
+    /// Length of the content in bytes
+    pub content_len: usize,
+    /// Image locations, or NULL if there are no images
+    pub images: *mut CSharedImage,
+    /// Number of entries in `images`
+    pub image_count: usize,
+}
+
+struct SegmentLayout {
+    path: PathBuf,
+    size: usize,
+    content_len: usize,
+    images: Vec<CSharedImage>,
+}
+
+fn segment_dir() -> PathBuf {
+    let shm = Path::new("/dev/shm");
+    if shm.is_dir() {
+        shm.to_path_buf()
+    } else {
+        std::env::temp_dir()
+    }
+}
+
+fn write_block(writer: &mut BufWriter<File>, position: &mut usize, data: &[u8]) -> io::Result<usize> {
+    let padding = position.next_multiple_of(SEGMENT_ALIGNMENT) - *position;
+    writer.write_all(&[0u8; SEGMENT_ALIGNMENT][..padding])?;
+    writer.write_all(data)?;
+
+    let offset = *position + padding;
+    *position = offset + data.len();
+    Ok(offset)
+}
+
+fn write_segment_blocks(file: File, result: &mut ExtractionResult) -> io::Result<(usize, usize, Vec<CSharedImage>)> {
+    let mut writer = BufWriter::new(file);
+    let mut position = 0;
+
+    let content = std::mem::take(&mut result.content);
+    write_block(&mut writer, &mut position, content.as_bytes())?;
+
+    let mut images = Vec::new();
+    for image in result.images.iter_mut().flatten() {
+        let data = std::mem::take(&mut image.data);
+        let offset = write_block(&mut writer, &mut position, &data)?;
+        images.push(CSharedImage {
+            offset,
+            len: data.len(),
+        });
+    }
+
+    writer.flush()?;
+    Ok((position, content.len(), images))
+}
+
+/// Move the content and image bytes of a result into a new segment file.
+fn write_segment(result: &mut ExtractionResult) -> io::Result<SegmentLayout> {
+    let path = segment_dir().join(format!(
+        "kreuzberg-{}-{}.seg",
+        std::process::id(),
+        NEXT_SEGMENT_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    // The segment name is predictable, so keep other users from reading extracted content
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let file = options.open(&path)?;
+
+    match write_segment_blocks(file, result) {
+        Ok((size, content_len, images)) => Ok(SegmentLayout {
+            path,
+            size,
+            content_len,
+            images,
+        }),
+        Err(e) => {
+            let _ = fs::remove_file(&path);
+            Err(e)
+        }
+    }
+}
+
+fn to_c_shared_result(
+    mut result: ExtractionResult,
+    config: &ExtractionConfig,
+) -> std::result::Result<*mut CSharedExtractionResult, String> {
+    FieldSelection::new(config.fields.as_deref()).apply(&mut result);
+    let layout = write_segment(&mut result).map_err(|e| format!("Failed to write shared-memory segment: {}", e))?;
+
+    let segment_path = match CString::new(layout.path.to_string_lossy().into_owned()) {
+        Ok(path) => path,
+        Err(e) => {
+            let _ = fs::remove_file(&layout.path);
+            return Err(format!("Failed to convert segment path to C string: {}", e));
+        }
+    };
+    let c_result = match to_c_extraction_result(result, config) {
+        Ok(c_result) => c_result,
+        Err(e) => {
+            let _ = fs::remove_file(&layout.path);
+            return Err(e);
+        }
+    };
+
+    let image_count = layout.images.len();
+    let images = if image_count == 0 {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(layout.images.into_boxed_slice()) as *mut CSharedImage
+    };
+
+    Ok(Box::into_raw(Box::new(CSharedExtractionResult {
+        result: c_result,
+        segment_path: segment_path.into_raw(),
+        segment_size: layout.size,
+        content_offset: 0,
+        content_len: layout.content_len,
+        images,
+        image_count,
+    })))
+}
+
+/// Extract a file, placing the content and image bytes in a shared-memory segment.
+///
+/// # Arguments
+///
+/// * `file_path` - Path to the file to extract
+/// * `config_json` - Extraction configuration as JSON, or NULL for defaults
+///
+/// # Returns
+///
+/// Pointer to a CSharedExtractionResult, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - `file_path` must be a valid null-terminated C string
+/// - `config_json` must be a valid null-terminated C string or NULL
+/// - The returned pointer must be freed with `kreuzberg_free_shared_result`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extract_file_shared(
+    file_path: *const c_char,
+    config_json: *const c_char,
+) -> *mut CSharedExtractionResult {
+    crate::ffi_panic_guard!("kreuzberg_extract_file_shared", {
+        clear_last_error();
+
+        if file_path.is_null() {
+            set_last_error("file_path cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        // SAFETY: We've verified file_path is not null and it must be a valid C string.
+        let path_str = match unsafe { CStr::from_ptr(file_path) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in file path: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = if config_json.is_null() {
+            ExtractionConfig::default()
+        } else {
+            // SAFETY: We've verified config_json is not null and it must be a valid C string.
+            let config_str = match unsafe { CStr::from_ptr(config_json) }.to_str() {
+                Ok(s) => s,
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in config JSON: {}", e));
+                    return ptr::null_mut();
+                }
+            };
+
+            match parse_extraction_config_from_json(config_str) {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    set_last_error(e);
+                    return ptr::null_mut();
+                }
+            }
+        };
+
+        match kreuzberg::extract_file_sync(Path::new(path_str), None, &config) {
+            Ok(result) => match to_c_shared_result(result, &config) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                crate::stats::record_error(&e.to_string());
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Free a shared result and remove its segment.
+///
+/// Mappings created from the segment stay valid on Linux and macOS. On Windows, unmap
+/// the segment before calling this function, otherwise the segment file is left behind.
+///
+/// # Safety
+///
+/// - `shared` must have been returned by `kreuzberg_extract_file_shared`, or be NULL
+/// - `shared` must not be used after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_free_shared_result(shared: *mut CSharedExtractionResult) {
+    if shared.is_null() {
+        return;
+    }
+
+    // SAFETY: shared was allocated by to_c_shared_result.
+    let shared = unsafe { Box::from_raw(shared) };
+    unsafe { kreuzberg_free_result(shared.result) };
+
+    if !shared.segment_path.is_null() {
+        // SAFETY: segment_path was created with CString::into_raw.
+        let path = unsafe { CString::from_raw(shared.segment_path) };
+        if let Ok(path) = path.to_str() {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    if !shared.images.is_null() {
+        // SAFETY: images was created from a boxed slice of image_count entries.
+        unsafe {
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                shared.images,
+                shared.image_count,
+            )))
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_file_shared_writes_content_to_segment() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("large.txt");
+        let text = "shared memory transfer\n".repeat(1000);
+        fs::write(&input, &text).unwrap();
+        let input = CString::new(input.to_str().unwrap()).unwrap();
+
+        let shared = unsafe { kreuzberg_extract_file_shared(input.as_ptr(), ptr::null()) };
+        assert!(!shared.is_null());
+
+        let shared_ref = unsafe { &*shared };
+        let segment_path = unsafe { CStr::from_ptr(shared_ref.segment_path) }
+            .to_str()
+            .unwrap()
+            .to_string();
+        let segment = fs::read(&segment_path).unwrap();
+        assert_eq!(segment.len(), shared_ref.segment_size);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&segment_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let content = &segment[shared_ref.content_offset..shared_ref.content_offset + shared_ref.content_len];
+        assert_eq!(std::str::from_utf8(content).unwrap().trim_end(), text.trim_end());
+        let c_content = unsafe { CStr::from_ptr((*shared_ref.result).content) };
+        assert!(c_content.to_bytes().is_empty());
+        assert_eq!(shared_ref.image_count, 0);
+
+        unsafe { kreuzberg_free_shared_result(shared) };
+        assert!(!Path::new(&segment_path).exists());
+    }
+
+    #[test]
+    fn test_write_block_aligns_offsets() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = BufWriter::new(File::create(dir.path().join("segment")).unwrap());
+        let mut position = 0;
+
+        assert_eq!(write_block(&mut writer, &mut position, b"abc").unwrap(), 0);
+        assert_eq!(write_block(&mut writer, &mut position, b"defgh").unwrap(), 8);
+        assert_eq!(write_block(&mut writer, &mut position, b"").unwrap(), 16);
+        assert_eq!(position, 16);
+    }
+}
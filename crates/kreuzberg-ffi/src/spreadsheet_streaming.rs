@@ -0,0 +1,292 @@
+//! Spreadsheet row streaming FFI module.
+//!
+//! Delivers the rows of a workbook to a user-provided callback one at a time,
+//! so hosts can pipe very large sheets straight into a database without
+//! materializing the whole extraction result.
+//!
+//! Sheet selection and row limits come from the `spreadsheet_options` section of
+//! the extraction config JSON. XLSX/XLSB sheets are read cell by cell; other
+//! formats (XLS, ODS) are loaded one sheet at a time.
+//!
+//! # Safety Model
+//!
+//! - All pointers passed to the callback are borrowed and valid only during the callback
+//! - Caller must copy cell values before the callback returns if persistence is needed
+//!
+//! # Example (C)
+//!
+//! ```c
+//! int on_row(const char* sheet, size_t sheet_index, size_t row_index,
+//!            const char* const* cells, size_t cell_count, void* user_data) {
+//!     for (size_t i = 0; i < cell_count; i++) {
+//!         printf("%s[%zu][%zu] = %s\n", sheet, row_index, i, cells[i]);
+//!     }
+//!     return 0; // Continue
+//! }
+//!
+//! const char* config = "{\"spreadsheet_options\": {\"sheets\": [\"Sheet1\"], \"max_rows\": 1000}}";
+//! int rc = kreuzberg_extract_spreadsheet_rows("data.xlsx", config, on_row, NULL);
+//! ```
+
+use crate::{clear_last_error, parse_extraction_config_from_json, set_last_error};
+use kreuzberg::extraction::excel::{ExcelRow, for_each_excel_row};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Callback function invoked for each non-empty spreadsheet row.
+///
+/// # Arguments
+///
+/// * `sheet_name` - Null-terminated UTF-8 sheet name (valid only during callback)
+/// * `sheet_index` - Zero-based position of the sheet in the workbook
+/// * `row_index` - Zero-based row number within the sheet (spreadsheet row 1 is 0)
+/// * `cells` - Array of `cell_count` null-terminated UTF-8 cell values, indexed from column A
+/// * `cell_count` - Number of cells in the row
+/// * `user_data` - User-provided context pointer
+///
+/// # Returns
+///
+/// - `0` to continue with the next row
+/// - Non-zero to stop reading (no further callbacks)
+///
+/// # Safety
+///
+/// - `sheet_name`, `cells` and every cell string are valid only during the callback
+/// - `user_data` is passed through opaquely (caller manages lifetime)
+pub type RowCallback = unsafe extern "C" fn(
+    sheet_name: *const c_char,
+    sheet_index: usize,
+    row_index: usize,
+    cells: *const *const c_char,
+    cell_count: usize,
+    user_data: *mut c_void,
+) -> c_int;
+
+/// Stream the rows of a spreadsheet to a callback.
+///
+/// # Arguments
+///
+/// * `file_path` - Null-terminated path to an XLSX/XLSM/XLSB/XLS/ODS file
+/// * `config_json` - Optional JSON configuration string (NULL for defaults); only
+///   `spreadsheet_options.sheets` and `spreadsheet_options.max_rows` are used
+/// * `row_callback` - Callback invoked for each non-empty row
+/// * `user_data` - Optional user context passed to the callback
+///
+/// # Returns
+///
+/// - `0` on success (all rows delivered or cancelled by callback)
+/// - `-1` on error (invalid arguments, unknown sheet, unreadable workbook); see `kreuzberg_last_error`
+///
+/// # Safety
+///
+/// - `file_path` must be a valid null-terminated UTF-8 string
+/// - `config_json` must be valid null-terminated UTF-8 if not NULL
+/// - `row_callback` must be a valid function pointer
+/// - Pointers passed to the callback must not be stored for later use
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extract_spreadsheet_rows(
+    file_path: *const c_char,
+    config_json: *const c_char,
+    row_callback: RowCallback,
+    user_data: *mut c_void,
+) -> c_int {
+    clear_last_error();
+
+    if file_path.is_null() {
+        set_last_error("File path cannot be NULL".to_string());
+        return -1;
+    }
+
+    // SAFETY: Caller guarantees file_path is a valid null-terminated string
+    let path = match unsafe { CStr::from_ptr(file_path) }.to_str() {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(format!("Invalid UTF-8 in file path: {}", e));
+            return -1;
+        }
+    };
+
+    let config = if !config_json.is_null() {
+        match unsafe { CStr::from_ptr(config_json) }.to_str() {
+            Ok(config_str) => match parse_extraction_config_from_json(config_str) {
+                Ok(cfg) => cfg,
+                Err(e) => {
+                    set_last_error(format!("Invalid configuration: {}", e));
+                    return -1;
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in config: {}", e));
+                return -1;
+            }
+        }
+    } else {
+        Default::default()
+    };
+    let options = config.spreadsheet_options.unwrap_or_default();
+
+    let mut current_sheet: Option<(usize, CString)> = None;
+    let outcome = for_each_excel_row(path, &options, |row: ExcelRow<'_>| {
+        if current_sheet
+            .as_ref()
+            .is_none_or(|(index, _)| *index != row.sheet_index)
+        {
+            current_sheet = Some((row.sheet_index, to_c_string_lossy(row.sheet_name)));
+        }
+        let sheet_name = current_sheet
+            .as_ref()
+            .map_or(std::ptr::null(), |(_, name)| name.as_ptr());
+
+        let cells: Vec<CString> = row.cells.iter().map(|cell| to_c_string_lossy(cell)).collect();
+        let cell_ptrs: Vec<*const c_char> = cells.iter().map(|cell| cell.as_ptr()).collect();
+
+        // SAFETY: Callback contract requires not storing the borrowed pointers
+        let status = unsafe {
+            row_callback(
+                sheet_name,
+                row.sheet_index,
+                row.row_index,
+                cell_ptrs.as_ptr(),
+                cell_ptrs.len(),
+                user_data,
+            )
+        };
+        status == 0
+    });
+
+    match outcome {
+        Ok(()) => 0,
+        Err(e) => {
+            set_last_error(format!("Spreadsheet streaming failed: {}", e));
+            -1
+        }
+    }
+}
+
+/// Convert to a C string, dropping interior NUL bytes rather than failing.
+fn to_c_string_lossy(value: &str) -> CString {
+    CString::new(value).unwrap_or_else(|_| {
+        let cleaned: String = value.chars().filter(|&c| c != '\0').collect();
+        CString::new(cleaned).unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[derive(Default)]
+    struct RowLog {
+        rows: Vec<(String, usize, usize, Vec<String>)>,
+        stop_after: Option<usize>,
+    }
+
+    unsafe extern "C" fn log_row(
+        sheet_name: *const c_char,
+        sheet_index: usize,
+        row_index: usize,
+        cells: *const *const c_char,
+        cell_count: usize,
+        user_data: *mut c_void,
+    ) -> c_int {
+        // SAFETY: Test harness guarantees user_data is a valid RowLog
+        let log = unsafe { &mut *(user_data as *mut RowLog) };
+        // SAFETY: Callback contract guarantees valid pointers during the call
+        let sheet = unsafe { CStr::from_ptr(sheet_name) }.to_string_lossy().into_owned();
+        let values = (0..cell_count)
+            .map(|i| unsafe { CStr::from_ptr(*cells.add(i)) }.to_string_lossy().into_owned())
+            .collect();
+        log.rows.push((sheet, sheet_index, row_index, values));
+
+        match log.stop_after {
+            Some(limit) if log.rows.len() >= limit => 1,
+            _ => 0,
+        }
+    }
+
+    fn workbook_path() -> Option<CString> {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../test_documents/spreadsheets/excel_multi_sheet.xlsx");
+        if !path.exists() {
+            println!("Skipping test: Test file not found at {:?}", path);
+            return None;
+        }
+        Some(CString::new(path.to_str().unwrap()).unwrap())
+    }
+
+    #[test]
+    fn test_spreadsheet_rows_selected_sheet() {
+        let Some(path) = workbook_path() else { return };
+        let config = CString::new(r#"{"spreadsheet_options": {"sheets": ["second_sheet"]}}"#).unwrap();
+        let mut log = RowLog::default();
+
+        let rc = unsafe {
+            kreuzberg_extract_spreadsheet_rows(
+                path.as_ptr(),
+                config.as_ptr(),
+                log_row,
+                &mut log as *mut RowLog as *mut c_void,
+            )
+        };
+
+        assert_eq!(rc, 0);
+        assert!(!log.rows.is_empty());
+        assert!(
+            log.rows
+                .iter()
+                .all(|(sheet, index, _, _)| sheet == "second_sheet" && *index == 1)
+        );
+    }
+
+    #[test]
+    fn test_spreadsheet_rows_max_rows_and_cancellation() {
+        let Some(path) = workbook_path() else { return };
+        let config = CString::new(r#"{"spreadsheet_options": {"sheets": [0], "max_rows": 1}}"#).unwrap();
+        let mut log = RowLog::default();
+
+        let rc = unsafe {
+            kreuzberg_extract_spreadsheet_rows(
+                path.as_ptr(),
+                config.as_ptr(),
+                log_row,
+                &mut log as *mut RowLog as *mut c_void,
+            )
+        };
+        assert_eq!(rc, 0);
+        assert!(log.rows.len() <= 2, "header plus one data row, got {}", log.rows.len());
+
+        let mut log = RowLog {
+            stop_after: Some(1),
+            ..Default::default()
+        };
+        let rc = unsafe {
+            kreuzberg_extract_spreadsheet_rows(
+                path.as_ptr(),
+                ptr::null(),
+                log_row,
+                &mut log as *mut RowLog as *mut c_void,
+            )
+        };
+        assert_eq!(rc, 0);
+        assert_eq!(log.rows.len(), 1);
+    }
+
+    #[test]
+    fn test_spreadsheet_rows_unknown_sheet() {
+        let Some(path) = workbook_path() else { return };
+        let config = CString::new(r#"{"spreadsheet_options": {"sheets": ["missing"]}}"#).unwrap();
+
+        let rc =
+            unsafe { kreuzberg_extract_spreadsheet_rows(path.as_ptr(), config.as_ptr(), log_row, ptr::null_mut()) };
+
+        assert_eq!(rc, -1);
+    }
+
+    #[test]
+    fn test_spreadsheet_rows_null_path() {
+        let rc = unsafe { kreuzberg_extract_spreadsheet_rows(ptr::null(), ptr::null(), log_row, ptr::null_mut()) };
+
+        assert_eq!(rc, -1);
+    }
+}
@@ -0,0 +1,248 @@
+//! Extraction statistics FFI module.
+//!
+//! Accumulates counters across every extraction call made through the C API so embedders
+//! can export throughput and error rates (e.g. to Prometheus) without wrapping each call:
+//! documents by MIME type, pages processed, OCR pages, result cache hit rate and errors by
+//! error code. Counters start when the library is loaded and are shared by all threads.
+//!
+//! Batch calls count every document; a document that failed inside a batch counts as an
+//! error with the code its message classifies as (see `kreuzberg_classify_error`).
+//!
+//! # Example (C)
+//!
+//! ```c
+//! char* stats = kreuzberg_stats();
+//! if (stats != NULL) {
+//!     printf("%s\n", stats);
+//!     // {"documents":{"total":42,"by_mime_type":{"application/pdf":40,"text/plain":2}},
+//!     //  "pages":310,"ocr_pages":12,"cache":{"hits":5,"misses":37,"hit_rate":0.119},
+//!     //  "errors":{"total":1,"by_code":{"parsing":1}},"elapsed_secs":93.4}
+//!     kreuzberg_free_string(stats);
+//! }
+//! kreuzberg_stats_reset();
+//! ```
+
+use crate::error::classify_error_message;
+use crate::{clear_last_error, set_last_error, string_to_c_string};
+use kreuzberg::ExtractionResult;
+use kreuzberg::core::counters::{pipeline_counters, reset_pipeline_counters};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::{LazyLock, Mutex, MutexGuard};
+use std::time::Instant;
+
+/// Counters owned by the FFI layer; OCR and cache counters live in the core crate.
+struct CallCounters {
+    started: Instant,
+    documents_by_mime: BTreeMap<String, u64>,
+    pages: u64,
+    errors_by_code: BTreeMap<&'static str, u64>,
+}
+
+impl CallCounters {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            documents_by_mime: BTreeMap::new(),
+            pages: 0,
+            errors_by_code: BTreeMap::new(),
+        }
+    }
+}
+
+static COUNTERS: LazyLock<Mutex<CallCounters>> = LazyLock::new(|| Mutex::new(CallCounters::new()));
+
+fn counters() -> MutexGuard<'static, CallCounters> {
+    // The counters stay consistent even if a holder panicked, so poisoning is ignored
+    COUNTERS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[derive(Debug, Serialize)]
+struct DocumentStats {
+    total: u64,
+    by_mime_type: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct CacheStats {
+    hits: u64,
+    misses: u64,
+    hit_rate: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorStats {
+    total: u64,
+    by_code: BTreeMap<&'static str, u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct ExtractionStats {
+    documents: DocumentStats,
+    pages: u64,
+    ocr_pages: u64,
+    cache: CacheStats,
+    errors: ErrorStats,
+    elapsed_secs: f64,
+}
+
+/// Record an extraction result returned to the caller.
+///
+/// Results carrying `metadata.error` (failed batch documents) are counted as errors.
+pub(crate) fn record_result(result: &ExtractionResult) {
+    if let Some(error) = &result.metadata.error {
+        record_error(&error.message);
+        return;
+    }
+
+    let pages = result
+        .metadata
+        .pages
+        .as_ref()
+        .map(|structure| structure.total_count)
+        .or_else(|| result.pages.as_ref().map(Vec::len))
+        .unwrap_or(0);
+
+    let mut counters = counters();
+    *counters.documents_by_mime.entry(result.mime_type.clone()).or_default() += 1;
+    counters.pages += pages as u64;
+}
+
+/// Record a failed extraction by the error code its message classifies as.
+pub(crate) fn record_error(message: &str) {
+    let code = classify_error_message(message);
+    *counters().errors_by_code.entry(code.name()).or_default() += 1;
+}
+
+fn collect_stats() -> ExtractionStats {
+    let counters = counters();
+    let pipeline = pipeline_counters();
+
+    ExtractionStats {
+        documents: DocumentStats {
+            total: counters.documents_by_mime.values().sum(),
+            by_mime_type: counters.documents_by_mime.clone(),
+        },
+        pages: counters.pages,
+        ocr_pages: pipeline.ocr_pages,
+        cache: CacheStats {
+            hits: pipeline.cache_hits,
+            misses: pipeline.cache_misses,
+            hit_rate: pipeline.cache_hit_rate(),
+        },
+        errors: ErrorStats {
+            total: counters.errors_by_code.values().sum(),
+            by_code: counters.errors_by_code.clone(),
+        },
+        elapsed_secs: counters.started.elapsed().as_secs_f64(),
+    }
+}
+
+/// Get the extraction statistics accumulated since the library was loaded (or last reset) as JSON.
+///
+/// The object has `documents` (`total` and `by_mime_type`), `pages` (pages of documents
+/// with a known page count), `ocr_pages`, `cache` (`hits`, `misses` and `hit_rate`, `null`
+/// before the first lookup), `errors` (`total` and `by_code`, keyed by error code name such
+/// as `parsing` or `timeout`) and `elapsed_secs` (seconds covered by the counters).
+///
+/// # Returns
+///
+/// JSON object string, or NULL on error (check `kreuzberg_last_error`).
+///
+/// # Safety
+///
+/// - Returned string must be freed with `kreuzberg_free_string`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_stats() -> *mut c_char {
+    crate::ffi_panic_guard!("kreuzberg_stats", {
+        clear_last_error();
+
+        match serde_json::to_string(&collect_stats()) {
+            Ok(json) => match string_to_c_string(json) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Failed to serialize extraction stats: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Reset every extraction statistic to zero and restart `elapsed_secs`.
+///
+/// Lets embedders report per-interval counts (e.g. per scrape) instead of totals.
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_stats_reset() {
+    *counters() = CallCounters::new();
+    reset_pipeline_counters();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kreuzberg_free_string;
+    use kreuzberg::types::{ErrorMetadata, Metadata};
+    use std::ffi::CStr;
+
+    fn result(mime_type: &str, metadata: Metadata) -> ExtractionResult {
+        ExtractionResult {
+            content: String::new(),
+            mime_type: mime_type.to_string(),
+            metadata,
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    fn stats_json() -> serde_json::Value {
+        let ptr = unsafe { kreuzberg_stats() };
+        assert!(!ptr.is_null());
+
+        let json = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { kreuzberg_free_string(ptr) };
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_stats_accumulate_results_and_errors() {
+        // Other tests extract concurrently, so only lower bounds are checked
+        let before = stats_json();
+
+        let document = result("application/x-stats-test", Metadata::default());
+        let failed = result(
+            "text/plain",
+            Metadata {
+                error: Some(ErrorMetadata {
+                    error_type: "Parsing".to_string(),
+                    message: "Failed to parse document: corrupt xref table".to_string(),
+                }),
+                ..Default::default()
+            },
+        );
+        record_result(&document);
+        record_result(&failed);
+        record_error("Timeout in extraction stage after 5000 ms");
+
+        let after = stats_json();
+        let count = |value: &serde_json::Value, pointer: &str| value.pointer(pointer).and_then(|v| v.as_u64());
+        assert!(count(&after, "/documents/by_mime_type/application~1x-stats-test") >= Some(1));
+        assert!(count(&after, "/errors/by_code/parsing") > count(&before, "/errors/by_code/parsing"));
+        assert!(count(&after, "/errors/by_code/timeout") > count(&before, "/errors/by_code/timeout"));
+        assert!(after["elapsed_secs"].is_number());
+        assert!(after["cache"]["hits"].is_u64());
+    }
+}
@@ -13,7 +13,8 @@ use kreuzberg::plugins::registry::{get_post_processor_registry, get_validator_re
 use kreuzberg::{
     Chunk as RustChunk, ChunkMetadata as RustChunkMetadata, ChunkingConfig as RustChunkingConfig,
     EmbeddingConfig as RustEmbeddingConfig, EmbeddingModelType as RustEmbeddingModelType, ExtractionConfig,
-    ExtractionResult as RustExtractionResult, ImageExtractionConfig as RustImageExtractionConfig, KNOWN_FORMATS,
+    ExtractionResult as RustExtractionResult, ImageExtractionConfig as RustImageExtractionConfig,
+    ImagePreprocessingConfig as RustImagePreprocessingConfig, KNOWN_FORMATS,
     LanguageDetectionConfig as RustLanguageDetectionConfig, OcrConfig as RustOcrConfig, PdfConfig as RustPdfConfig,
     PostProcessorConfig as RustPostProcessorConfig, TesseractConfig as RustTesseractConfig,
     TokenReductionConfig as RustTokenReductionConfig,
@@ -22,7 +23,7 @@ use lazy_static::lazy_static;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use once_cell::sync::Lazy;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ffi::{CStr, c_char};
 
 // KNOWN_FORMAT_FIELDS is imported from kreuzberg::core::formats::KNOWN_FORMATS
@@ -131,7 +132,7 @@ unsafe extern "C" {
     /// Get the internal error code constant (7)
     pub fn kreuzberg_error_code_internal() -> u32;
 
-    /// Get the total count of valid error codes (8)
+    /// Get the total count of valid error codes (12)
     pub fn kreuzberg_error_code_count() -> u32;
 }
 
@@ -252,6 +253,10 @@ fn convert_error(err: kreuzberg::KreuzbergError) -> napi::Error {
             Error::new(Status::InvalidArg, format!("Unsupported format: {}", format))
         }
 
+        err @ KreuzbergError::InputRejected { .. } => Error::new(Status::InvalidArg, err.to_string()),
+
+        err @ KreuzbergError::Timeout { .. } => Error::new(Status::GenericFailure, err.to_string()),
+
         KreuzbergError::Other(msg) => Error::new(Status::GenericFailure, msg),
     }
 }
@@ -503,6 +508,7 @@ pub struct JsOcrConfig {
     pub backend: String,
     pub language: Option<String>,
     pub tesseract_config: Option<JsTesseractConfig>,
+    pub vocabulary: Option<JsOcrVocabularyConfig>,
 }
 
 impl From<JsOcrConfig> for RustOcrConfig {
@@ -511,6 +517,42 @@ impl From<JsOcrConfig> for RustOcrConfig {
             backend: val.backend,
             language: val.language.unwrap_or_else(|| "eng".to_string()),
             tesseract_config: val.tesseract_config.map(Into::into),
+            fallback: None,
+            vocabulary: val.vocabulary.map(Into::into),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsOcrVocabularyConfig {
+    pub words: Option<Vec<String>>,
+    pub words_file: Option<String>,
+    pub correct: Option<bool>,
+    pub max_distance: Option<u32>,
+    pub min_word_length: Option<u32>,
+}
+
+impl From<JsOcrVocabularyConfig> for kreuzberg::OcrVocabularyConfig {
+    fn from(val: JsOcrVocabularyConfig) -> Self {
+        let defaults = kreuzberg::OcrVocabularyConfig::default();
+        kreuzberg::OcrVocabularyConfig {
+            words: val.words.unwrap_or_default(),
+            words_file: val.words_file,
+            correct: val.correct.unwrap_or(defaults.correct),
+            max_distance: val.max_distance.map_or(defaults.max_distance, |d| d as usize),
+            min_word_length: val.min_word_length.map_or(defaults.min_word_length, |l| l as usize),
+        }
+    }
+}
+
+impl From<kreuzberg::OcrVocabularyConfig> for JsOcrVocabularyConfig {
+    fn from(config: kreuzberg::OcrVocabularyConfig) -> Self {
+        Self {
+            words: Some(config.words),
+            words_file: config.words_file,
+            correct: Some(config.correct),
+            max_distance: Some(config.max_distance as u32),
+            min_word_length: Some(config.min_word_length as u32),
         }
     }
 }
@@ -520,6 +562,7 @@ pub struct JsTesseractConfig {
     pub psm: Option<i32>,
     pub enable_table_detection: Option<bool>,
     pub tessedit_char_whitelist: Option<String>,
+    pub preprocessing: Option<JsImagePreprocessingConfig>,
 }
 
 impl From<JsTesseractConfig> for RustTesseractConfig {
@@ -534,10 +577,54 @@ impl From<JsTesseractConfig> for RustTesseractConfig {
         if let Some(whitelist) = val.tessedit_char_whitelist {
             config.tessedit_char_whitelist = whitelist;
         }
+        config.preprocessing = val.preprocessing.map(Into::into);
         config
     }
 }
 
+#[napi(object)]
+pub struct JsImagePreprocessingConfig {
+    pub target_dpi: Option<i32>,
+    pub auto_rotate: Option<bool>,
+    pub deskew: Option<bool>,
+    pub denoise: Option<bool>,
+    pub contrast_enhance: Option<bool>,
+    pub binarization_method: Option<String>,
+    pub invert_colors: Option<bool>,
+    pub auto_tune: Option<bool>,
+}
+
+impl From<JsImagePreprocessingConfig> for RustImagePreprocessingConfig {
+    fn from(val: JsImagePreprocessingConfig) -> Self {
+        let default = RustImagePreprocessingConfig::default();
+        RustImagePreprocessingConfig {
+            target_dpi: val.target_dpi.unwrap_or(default.target_dpi),
+            auto_rotate: val.auto_rotate.unwrap_or(default.auto_rotate),
+            deskew: val.deskew.unwrap_or(default.deskew),
+            denoise: val.denoise.unwrap_or(default.denoise),
+            contrast_enhance: val.contrast_enhance.unwrap_or(default.contrast_enhance),
+            binarization_method: val.binarization_method.unwrap_or(default.binarization_method),
+            invert_colors: val.invert_colors.unwrap_or(default.invert_colors),
+            auto_tune: val.auto_tune.unwrap_or(default.auto_tune),
+        }
+    }
+}
+
+impl From<RustImagePreprocessingConfig> for JsImagePreprocessingConfig {
+    fn from(val: RustImagePreprocessingConfig) -> Self {
+        JsImagePreprocessingConfig {
+            target_dpi: Some(val.target_dpi),
+            auto_rotate: Some(val.auto_rotate),
+            deskew: Some(val.deskew),
+            denoise: Some(val.denoise),
+            contrast_enhance: Some(val.contrast_enhance),
+            binarization_method: Some(val.binarization_method),
+            invert_colors: Some(val.invert_colors),
+            auto_tune: Some(val.auto_tune),
+        }
+    }
+}
+
 /// Embedding model type configuration for Node.js bindings.
 ///
 /// This struct represents different embedding model sources:
@@ -619,6 +706,7 @@ impl From<JsChunkingConfig> for RustChunkingConfig {
             max_overlap: val.max_overlap.unwrap_or(200) as usize,
             embedding: val.embedding.map(Into::into),
             preset: val.preset,
+            respect_sentences: false,
         }
     }
 }
@@ -680,6 +768,11 @@ pub struct JsImageExtractionConfig {
     pub auto_adjust_dpi: Option<bool>,
     pub min_dpi: Option<i32>,
     pub max_dpi: Option<i32>,
+    pub strip_gps: Option<bool>,
+    pub perceptual_hash: Option<bool>,
+    pub deduplicate_images: Option<bool>,
+    pub duplicate_hash_distance: Option<u32>,
+    pub blob_store_dir: Option<String>,
 }
 
 impl From<JsImageExtractionConfig> for RustImageExtractionConfig {
@@ -691,6 +784,15 @@ impl From<JsImageExtractionConfig> for RustImageExtractionConfig {
             auto_adjust_dpi: val.auto_adjust_dpi.unwrap_or(true),
             min_dpi: val.min_dpi.unwrap_or(72),
             max_dpi: val.max_dpi.unwrap_or(600),
+            strip_gps: val.strip_gps.unwrap_or(false),
+            output_format: None,
+            downscale_to: None,
+            max_bytes: None,
+            perceptual_hash: val.perceptual_hash.unwrap_or(false),
+            deduplicate_images: val.deduplicate_images.unwrap_or(false),
+            duplicate_hash_distance: val.duplicate_hash_distance.unwrap_or(5),
+            ocr_policy: None,
+            blob_store_dir: val.blob_store_dir.map(Into::into),
         }
     }
 }
@@ -1196,6 +1298,45 @@ pub struct JsPageConfig {
     pub marker_format: Option<String>,
 }
 
+#[napi(object)]
+pub struct JsDocumentTypeRule {
+    pub label: String,
+    pub keywords: Option<Vec<String>>,
+    pub title_keywords: Option<Vec<String>>,
+}
+
+#[napi(object)]
+pub struct JsClassificationConfig {
+    pub builtin_types: Option<bool>,
+    pub types: Option<Vec<JsDocumentTypeRule>>,
+    pub min_confidence: Option<f64>,
+}
+
+#[napi(object)]
+pub struct JsKeyValueExtractionConfig {
+    pub builtin_labels: Option<bool>,
+    pub labels: Option<Vec<String>>,
+    pub max_label_words: Option<u32>,
+}
+
+#[napi(object)]
+pub struct JsCleanupConfig {
+    pub strip_repeated: Option<bool>,
+    pub tag_instead: Option<bool>,
+}
+
+#[napi(object)]
+pub struct JsWatermarkConfig {
+    pub exclude_text: Option<bool>,
+    pub stamp_words: Option<Vec<String>>,
+}
+
+#[napi(object)]
+pub struct JsCsvConfig {
+    pub delimiter: Option<String>,
+    pub max_rows: Option<u32>,
+}
+
 #[napi(object)]
 pub struct JsExtractionConfig {
     pub use_cache: Option<bool>,
@@ -1207,11 +1348,18 @@ pub struct JsExtractionConfig {
     pub pdf_options: Option<JsPdfConfig>,
     pub token_reduction: Option<JsTokenReductionConfig>,
     pub language_detection: Option<JsLanguageDetectionConfig>,
+    pub classification: Option<JsClassificationConfig>,
+    pub kv_extraction: Option<JsKeyValueExtractionConfig>,
+    pub cleanup: Option<JsCleanupConfig>,
+    pub watermarks: Option<JsWatermarkConfig>,
     pub postprocessor: Option<JsPostProcessorConfig>,
     pub keywords: Option<JsKeywordConfig>,
     pub html_options: Option<JsHtmlOptions>,
     pub max_concurrent_extractions: Option<u32>,
     pub pages: Option<JsPageConfig>,
+    pub csv_options: Option<JsCsvConfig>,
+    pub incremental: Option<bool>,
+    pub overrides: Option<HashMap<String, serde_json::Value>>,
 }
 
 impl TryFrom<JsPageConfig> for kreuzberg::core::config::PageConfig {
@@ -1238,6 +1386,143 @@ impl From<kreuzberg::core::config::PageConfig> for JsPageConfig {
     }
 }
 
+impl From<JsClassificationConfig> for kreuzberg::ClassificationConfig {
+    fn from(val: JsClassificationConfig) -> Self {
+        let defaults = kreuzberg::ClassificationConfig::default();
+        kreuzberg::ClassificationConfig {
+            builtin_types: val.builtin_types.unwrap_or(defaults.builtin_types),
+            types: val
+                .types
+                .unwrap_or_default()
+                .into_iter()
+                .map(|rule| kreuzberg::DocumentTypeRule {
+                    label: rule.label,
+                    keywords: rule.keywords.unwrap_or_default(),
+                    title_keywords: rule.title_keywords.unwrap_or_default(),
+                })
+                .collect(),
+            min_confidence: val.min_confidence.unwrap_or(defaults.min_confidence),
+        }
+    }
+}
+
+impl From<kreuzberg::ClassificationConfig> for JsClassificationConfig {
+    fn from(config: kreuzberg::ClassificationConfig) -> Self {
+        Self {
+            builtin_types: Some(config.builtin_types),
+            types: Some(
+                config
+                    .types
+                    .into_iter()
+                    .map(|rule| JsDocumentTypeRule {
+                        label: rule.label,
+                        keywords: Some(rule.keywords),
+                        title_keywords: Some(rule.title_keywords),
+                    })
+                    .collect(),
+            ),
+            min_confidence: Some(config.min_confidence),
+        }
+    }
+}
+
+impl From<JsKeyValueExtractionConfig> for kreuzberg::KeyValueExtractionConfig {
+    fn from(val: JsKeyValueExtractionConfig) -> Self {
+        let defaults = kreuzberg::KeyValueExtractionConfig::default();
+        kreuzberg::KeyValueExtractionConfig {
+            builtin_labels: val.builtin_labels.unwrap_or(defaults.builtin_labels),
+            labels: val.labels.unwrap_or_default(),
+            max_label_words: val
+                .max_label_words
+                .map(|words| words as usize)
+                .unwrap_or(defaults.max_label_words),
+        }
+    }
+}
+
+impl From<kreuzberg::KeyValueExtractionConfig> for JsKeyValueExtractionConfig {
+    fn from(config: kreuzberg::KeyValueExtractionConfig) -> Self {
+        Self {
+            builtin_labels: Some(config.builtin_labels),
+            labels: Some(config.labels),
+            max_label_words: Some(config.max_label_words as u32),
+        }
+    }
+}
+
+impl From<JsCleanupConfig> for kreuzberg::CleanupConfig {
+    fn from(val: JsCleanupConfig) -> Self {
+        let defaults = kreuzberg::CleanupConfig::default();
+        kreuzberg::CleanupConfig {
+            strip_repeated: val.strip_repeated.unwrap_or(defaults.strip_repeated),
+            tag_instead: val.tag_instead.unwrap_or(defaults.tag_instead),
+        }
+    }
+}
+
+impl From<kreuzberg::CleanupConfig> for JsCleanupConfig {
+    fn from(config: kreuzberg::CleanupConfig) -> Self {
+        Self {
+            strip_repeated: Some(config.strip_repeated),
+            tag_instead: Some(config.tag_instead),
+        }
+    }
+}
+
+impl From<JsWatermarkConfig> for kreuzberg::WatermarkConfig {
+    fn from(val: JsWatermarkConfig) -> Self {
+        kreuzberg::WatermarkConfig {
+            exclude_text: val.exclude_text.unwrap_or(false),
+            stamp_words: val.stamp_words.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<kreuzberg::WatermarkConfig> for JsWatermarkConfig {
+    fn from(config: kreuzberg::WatermarkConfig) -> Self {
+        Self {
+            exclude_text: Some(config.exclude_text),
+            stamp_words: Some(config.stamp_words),
+        }
+    }
+}
+
+impl TryFrom<JsCsvConfig> for kreuzberg::CsvConfig {
+    type Error = Error;
+
+    fn try_from(val: JsCsvConfig) -> Result<Self> {
+        let delimiter = match val.delimiter {
+            Some(delimiter) => {
+                let mut chars = delimiter.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(c),
+                    _ => {
+                        return Err(Error::new(
+                            Status::InvalidArg,
+                            format!("csvOptions.delimiter must be a single character, got '{}'", delimiter),
+                        ));
+                    }
+                }
+            }
+            None => None,
+        };
+
+        Ok(kreuzberg::CsvConfig {
+            delimiter,
+            max_rows: val.max_rows.map(|v| v as usize),
+        })
+    }
+}
+
+impl From<kreuzberg::CsvConfig> for JsCsvConfig {
+    fn from(config: kreuzberg::CsvConfig) -> Self {
+        Self {
+            delimiter: config.delimiter.map(String::from),
+            max_rows: config.max_rows.map(|v| v as u32),
+        }
+    }
+}
+
 impl TryFrom<JsExtractionConfig> for ExtractionConfig {
     type Error = Error;
 
@@ -1262,11 +1547,37 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
             pdf_options: val.pdf_options.map(Into::into),
             token_reduction: val.token_reduction.map(Into::into),
             language_detection: val.language_detection.map(Into::into),
+            classification: val.classification.map(Into::into),
+            kv_extraction: val.kv_extraction.map(Into::into),
             keywords,
             postprocessor: val.postprocessor.map(Into::into),
             html_options,
             max_concurrent_extractions: val.max_concurrent_extractions.map(|v| v as usize),
+            adaptive_concurrency: None,
+            spill_dir: None,
+            spill_threshold_bytes: None,
+            resource_profile: Default::default(),
+            input_policy: None,
+            stage_timeouts: None,
+            retry: None,
+            cache: None,
+            ocr_correction: None,
             pages: val.pages.map(|p| p.try_into()).transpose()?,
+            text_options: None,
+            csv_options: val.csv_options.map(|c| c.try_into()).transpose()?,
+            spreadsheet_options: None,
+            pptx_options: None,
+            preview: None,
+            normalization: None,
+            watermarks: val.watermarks.map(Into::into),
+            cleanup: val.cleanup.map(Into::into),
+            emit_term_offsets: false,
+            emit_provenance: false,
+            detect_barcodes: false,
+            incremental: val.incremental.unwrap_or(false),
+            fields: None,
+            result_compression: None,
+            overrides: val.overrides.map(|overrides| overrides.into_iter().collect()),
         })
     }
 }
@@ -1289,7 +1600,9 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                     } else {
                         Some(tc.tessedit_char_whitelist)
                     },
+                    preprocessing: tc.preprocessing.map(Into::into),
                 }),
+                vocabulary: ocr.vocabulary.map(Into::into),
             }),
             force_ocr: Some(val.force_ocr),
             chunking: val.chunking.map(|chunk| JsChunkingConfig {
@@ -1327,6 +1640,11 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                 auto_adjust_dpi: Some(img.auto_adjust_dpi),
                 min_dpi: Some(img.min_dpi),
                 max_dpi: Some(img.max_dpi),
+                strip_gps: Some(img.strip_gps),
+                perceptual_hash: Some(img.perceptual_hash),
+                deduplicate_images: Some(img.deduplicate_images),
+                duplicate_hash_distance: Some(img.duplicate_hash_distance),
+                blob_store_dir: img.blob_store_dir.and_then(|p| p.to_str().map(String::from)),
             }),
             pdf_options: val.pdf_options.map(|pdf| JsPdfConfig {
                 extract_images: Some(pdf.extract_images),
@@ -1342,6 +1660,10 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                 min_confidence: Some(ld.min_confidence),
                 detect_multiple: Some(ld.detect_multiple),
             }),
+            classification: val.classification.map(JsClassificationConfig::from),
+            kv_extraction: val.kv_extraction.map(JsKeyValueExtractionConfig::from),
+            cleanup: val.cleanup.map(JsCleanupConfig::from),
+            watermarks: val.watermarks.map(JsWatermarkConfig::from),
             postprocessor: val.postprocessor.map(|pp| JsPostProcessorConfig {
                 enabled: Some(pp.enabled),
                 enabled_processors: pp.enabled_processors,
@@ -1351,6 +1673,9 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
             html_options: val.html_options.as_ref().map(JsHtmlOptions::from),
             max_concurrent_extractions: val.max_concurrent_extractions.map(|v| v as u32),
             pages: val.pages.map(JsPageConfig::from),
+            csv_options: val.csv_options.map(JsCsvConfig::from),
+            incremental: Some(val.incremental),
+            overrides: val.overrides.map(|overrides| overrides.into_iter().collect()),
         })
     }
 }
@@ -1457,6 +1782,7 @@ pub struct JsTable {
     pub cells: Vec<Vec<String>>,
     pub markdown: String,
     pub page_number: u32,
+    pub column_types: Option<Vec<String>>,
 }
 
 #[napi(object)]
@@ -1473,6 +1799,39 @@ pub struct JsExtractedImage {
     pub description: Option<String>,
     #[napi(ts_type = "JsExtractionResult | undefined")]
     pub ocr_result: Option<serde_json::Value>,
+    pub perceptual_hash: Option<String>,
+    pub blob: Option<JsImageBlobRef>,
+    pub caption: Option<String>,
+    pub figure_index: Option<u32>,
+}
+
+#[napi(object)]
+pub struct JsImageBlobRef {
+    pub hash: String,
+    pub path: String,
+    pub size: u32,
+}
+
+impl From<JsImageBlobRef> for kreuzberg::ImageBlobRef {
+    fn from(val: JsImageBlobRef) -> Self {
+        kreuzberg::ImageBlobRef {
+            hash: val.hash,
+            path: val.path,
+            size: val.size as usize,
+        }
+    }
+}
+
+impl TryFrom<kreuzberg::ImageBlobRef> for JsImageBlobRef {
+    type Error = napi::Error;
+
+    fn try_from(val: kreuzberg::ImageBlobRef) -> Result<Self> {
+        Ok(JsImageBlobRef {
+            hash: val.hash,
+            path: val.path,
+            size: usize_to_u32(val.size, "blob.size")?,
+        })
+    }
 }
 
 #[napi(object)]
@@ -1561,6 +1920,10 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     is_mask: img.is_mask,
                     description: img.description,
                     ocr_result,
+                    perceptual_hash: img.perceptual_hash,
+                    blob: img.blob.map(JsImageBlobRef::try_from).transpose()?,
+                    caption: img.caption,
+                    figure_index: img.figure_index.map(|i| i as u32),
                 });
             }
             Some(js_images)
@@ -1579,6 +1942,9 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number as u32,
+                    column_types: t
+                        .column_types
+                        .map(|types| types.iter().map(|t| t.as_str().to_string()).collect()),
                 })
                 .collect(),
             detected_languages: val.detected_languages,
@@ -1639,6 +2005,16 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
             let image_preprocessing = metadata_map
                 .remove("image_preprocessing")
                 .and_then(|v| serde_json::from_value(v).ok());
+            let xmp = metadata_map.remove("xmp").and_then(|v| serde_json::from_value(v).ok());
+            let outline = metadata_map
+                .remove("outline")
+                .and_then(|v| serde_json::from_value(v).ok());
+            let key_values = metadata_map
+                .remove("key_values")
+                .and_then(|v| serde_json::from_value(v).ok());
+            let watermarks = metadata_map
+                .remove("watermarks")
+                .and_then(|v| serde_json::from_value(v).ok());
             let json_schema = metadata_map.remove("json_schema");
             let error = metadata_map
                 .remove("error")
@@ -1664,6 +2040,10 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                 subject,
                 format,
                 image_preprocessing,
+                xmp,
+                outline,
+                key_values,
+                watermarks,
                 json_schema,
                 error,
                 additional,
@@ -1701,6 +2081,10 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     is_mask: img.is_mask,
                     description: img.description,
                     ocr_result,
+                    perceptual_hash: img.perceptual_hash,
+                    blob: img.blob.map(Into::into),
+                    caption: img.caption,
+                    figure_index: img.figure_index.map(|i| i as usize),
                 });
             }
             Some(rust_images)
@@ -1744,6 +2128,7 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                         total_chunks: chunk.metadata.total_chunks as usize,
                         first_page: chunk.metadata.first_page.map(|v| v as usize),
                         last_page: chunk.metadata.last_page.map(|v| v as usize),
+                        annotations: Default::default(),
                     },
                 });
             }
@@ -1763,12 +2148,23 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number as usize,
+                    column_types: t.column_types.and_then(|types| {
+                        types
+                            .iter()
+                            .map(|t| t.parse::<kreuzberg::types::CellType>())
+                            .collect::<std::result::Result<Vec<_>, _>>()
+                            .ok()
+                    }),
                 })
                 .collect(),
             detected_languages: val.detected_languages,
             chunks,
             images,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: RustExtractionResult::SCHEMA_VERSION,
         })
     }
 }
@@ -2343,6 +2739,7 @@ pub fn register_post_processor(_env: Env, processor: Object) -> Result<()> {
             "early" => ProcessingStage::Early,
             "middle" => ProcessingStage::Middle,
             "late" => ProcessingStage::Late,
+            "chunk" => ProcessingStage::Chunk,
             _ => ProcessingStage::Middle,
         }
     } else if let Ok(stage_fn) = processor.get_named_property::<Function<(), String>>("processingStage") {
@@ -2351,6 +2748,7 @@ pub fn register_post_processor(_env: Env, processor: Object) -> Result<()> {
             "early" => ProcessingStage::Early,
             "middle" => ProcessingStage::Middle,
             "late" => ProcessingStage::Late,
+            "chunk" => ProcessingStage::Chunk,
             _ => ProcessingStage::Middle,
         }
     } else {
@@ -2370,20 +2768,14 @@ pub fn register_post_processor(_env: Env, processor: Object) -> Result<()> {
     };
 
     let arc_processor: Arc<dyn RustPostProcessor> = Arc::new(js_processor);
-    let registry = get_post_processor_registry();
-    let mut registry = registry.write().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire write lock on PostProcessor registry: {}", e),
-        )
-    })?;
-
-    registry.register(arc_processor, 0).map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to register PostProcessor '{}': {}", name, e),
-        )
-    })?;
+    get_post_processor_registry()
+        .update(|registry| registry.register(arc_processor, 0))
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to register PostProcessor '{}': {}", name, e),
+            )
+        })?;
 
     Ok(())
 }
@@ -2391,48 +2783,28 @@ pub fn register_post_processor(_env: Env, processor: Object) -> Result<()> {
 /// Unregister a postprocessor by name
 #[napi]
 pub fn unregister_post_processor(name: String) -> Result<()> {
-    let registry = get_post_processor_registry();
-    let mut registry = registry.write().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire write lock on PostProcessor registry: {}", e),
-        )
-    })?;
-
-    registry.remove(&name).map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to unregister PostProcessor '{}': {}", name, e),
-        )
-    })?;
+    get_post_processor_registry()
+        .update(|registry| registry.remove(&name))
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to unregister PostProcessor '{}': {}", name, e),
+            )
+        })?;
     Ok(())
 }
 
 /// Clear all registered postprocessors
 #[napi]
 pub fn clear_post_processors() -> Result<()> {
-    let registry = get_post_processor_registry();
-    let mut registry = registry.write().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire write lock on PostProcessor registry: {}", e),
-        )
-    })?;
-
-    *registry = Default::default();
+    *get_post_processor_registry().edit() = Default::default();
     Ok(())
 }
 
 /// List all registered post-processors
 #[napi]
 pub fn list_post_processors() -> Result<Vec<String>> {
-    let registry = get_post_processor_registry();
-    let registry = registry.read().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire read lock on PostProcessor registry: {}", e),
-        )
-    })?;
+    let registry = get_post_processor_registry().snapshot();
 
     Ok(registry.list())
 }
@@ -2616,20 +2988,14 @@ pub fn register_validator(_env: Env, validator: Object) -> Result<()> {
     };
 
     let arc_validator: Arc<dyn RustValidator> = Arc::new(js_validator);
-    let registry = get_validator_registry();
-    let mut registry = registry.write().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire write lock on Validator registry: {}", e),
-        )
-    })?;
-
-    registry.register(arc_validator).map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to register Validator '{}': {}", name, e),
-        )
-    })?;
+    get_validator_registry()
+        .update(|registry| registry.register(arc_validator))
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to register Validator '{}': {}", name, e),
+            )
+        })?;
 
     Ok(())
 }
@@ -2637,48 +3003,28 @@ pub fn register_validator(_env: Env, validator: Object) -> Result<()> {
 /// Unregister a validator by name
 #[napi]
 pub fn unregister_validator(name: String) -> Result<()> {
-    let registry = get_validator_registry();
-    let mut registry = registry.write().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire write lock on Validator registry: {}", e),
-        )
-    })?;
-
-    registry.remove(&name).map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to unregister Validator '{}': {}", name, e),
-        )
-    })?;
+    get_validator_registry()
+        .update(|registry| registry.remove(&name))
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to unregister Validator '{}': {}", name, e),
+            )
+        })?;
     Ok(())
 }
 
 /// Clear all registered validators
 #[napi]
 pub fn clear_validators() -> Result<()> {
-    let registry = get_validator_registry();
-    let mut registry = registry.write().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire write lock on Validator registry: {}", e),
-        )
-    })?;
-
-    *registry = Default::default();
+    *get_validator_registry().edit() = Default::default();
     Ok(())
 }
 
 /// List all registered validators
 #[napi]
 pub fn list_validators() -> Result<Vec<String>> {
-    let registry = get_validator_registry();
-    let registry = registry.read().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire read lock on Validator registry: {}", e),
-        )
-    })?;
+    let registry = get_validator_registry().snapshot();
 
     Ok(registry.list())
 }
@@ -2818,6 +3164,10 @@ impl RustOcrBackend for JsOcrBackend {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -2936,20 +3286,14 @@ pub fn register_ocr_backend(_env: Env, backend: Object) -> Result<()> {
     };
 
     let arc_backend: Arc<dyn RustOcrBackend> = Arc::new(js_ocr_backend);
-    let registry = get_ocr_backend_registry();
-    let mut registry = registry.write().map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to acquire write lock on OCR backend registry: {}", e),
-        )
-    })?;
-
-    registry.register(arc_backend).map_err(|e| {
-        Error::new(
-            Status::GenericFailure,
-            format!("Failed to register OCR backend '{}': {}", name, e),
-        )
-    })?;
+    get_ocr_backend_registry()
+        .update(|registry| registry.register(arc_backend))
+        .map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to register OCR backend '{}': {}", name, e),
+            )
+        })?;
 
     Ok(())
 }
@@ -3152,6 +3496,40 @@ pub fn detect_mime_type_from_path(path: String, check_exists: Option<bool>) -> R
     kreuzberg::core::mime::detect_mime_type(&path, check_exists.unwrap_or(true)).map_err(convert_error)
 }
 
+/// Read image bytes from the blob store.
+///
+/// When `images.blobStoreDir` is configured, extracted images carry a `blob`
+/// reference and an empty `data` buffer. This reads the bytes on demand and
+/// verifies them against the reference.
+///
+/// # Parameters
+///
+/// * `blob` - The `blob` reference of an extracted image
+///
+/// # Returns
+///
+/// A Buffer with the image bytes.
+///
+/// # Errors
+///
+/// Throws an error if the blob file is missing or no longer matches the reference.
+///
+/// # Example
+///
+/// ```typescript
+/// import { extractFileSync, readImageBlob } from 'kreuzberg';
+///
+/// const result = extractFileSync('document.pdf', null, { images: { blobStoreDir: '/tmp/kreuzberg-images' } });
+/// const image = result.images[0];
+/// const data = image.blob ? readImageBlob(image.blob) : image.data;
+/// ```
+#[napi]
+pub fn read_image_blob(blob: JsImageBlobRef) -> Result<Buffer> {
+    kreuzberg::cache::blob_store::read_blob(&blob.into())
+        .map(Buffer::from)
+        .map_err(convert_error)
+}
+
 /// Validate that a MIME type is supported by Kreuzberg.
 ///
 /// Checks if a MIME type is in the list of supported formats. Note that any
@@ -317,6 +317,22 @@ describe("Configuration Options", () => {
 		});
 	});
 
+	describe("Incremental extraction", () => {
+		it("should return the same content when re-extracting unchanged pages", () => {
+			const config: ExtractionConfigType = {
+				useCache: false,
+				incremental: true,
+			};
+			const first = extractFileSync(pdfPath, null, config);
+			const second = extractFileSync(pdfPath, null, config);
+			expect(second.content).toBe(first.content);
+			expect(second.metadata.incremental).toEqual({
+				reused_pages: expect.any(Number),
+				extracted_pages: 0,
+			});
+		});
+	});
+
 	describe("Combined configurations", () => {
 		it("should handle complex configuration with multiple options", () => {
 			const config: ExtractionConfigType = {
@@ -51,13 +51,19 @@ import type { PanicContext } from "./errors.js";
 import type {
 	Chunk,
 	ChunkingConfig,
+	ClassificationConfig,
+	CleanupConfig,
+	CsvConfig,
 	ErrorClassification,
 	ExtractedImage,
 	ExtractionConfig as ExtractionConfigType,
 	ExtractionResult,
 	HtmlConversionOptions,
 	HtmlPreprocessingOptions,
+	ImageBlobRef,
 	ImageExtractionConfig,
+	ImagePreprocessingConfig,
+	KeyValueExtractionConfig,
 	KeywordConfig,
 	LanguageDetectionConfig,
 	OcrBackendProtocol,
@@ -71,6 +77,7 @@ import type {
 	TesseractConfig,
 	TokenReductionConfig,
 	ValidatorProtocol,
+	WatermarkConfig,
 } from "./types.js";
 
 /**
@@ -125,6 +132,7 @@ interface NativeBinding {
 	detectMimeType(filePath: string): string;
 	detectMimeTypeFromBytes(data: Buffer): string;
 	detectMimeTypeFromPath(filePath: string, checkExists?: boolean): string;
+	readImageBlob(blob: ImageBlobRef): Buffer;
 	validateMimeType(mimeType: string): string;
 	getExtensionsForMime(mimeType: string): string[];
 	listEmbeddingPresets(): string[];
@@ -376,6 +384,10 @@ function convertImage(rawImage: unknown): ExtractedImage {
 			isMask: false,
 			description: null,
 			ocrResult: null,
+			perceptualHash: null,
+			blob: null,
+			caption: null,
+			figureIndex: null,
 		};
 	}
 
@@ -403,6 +415,14 @@ function convertImage(rawImage: unknown): ExtractedImage {
 		description: (image["description"] as string | null) ?? null,
 		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
 		ocrResult: image["ocrResult"] ? convertResult(image["ocrResult"]) : null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		perceptualHash: (image["perceptualHash"] as string | null) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		blob: (image["blob"] as ImageBlobRef | null) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		caption: (image["caption"] as string | null) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		figureIndex: (image["figureIndex"] as number | null) ?? null,
 	};
 }
 
@@ -503,6 +523,26 @@ function normalizeTesseractConfig(config?: TesseractConfig) {
 	setIfDefined(normalized, "psm", config.psm);
 	setIfDefined(normalized, "enableTableDetection", config.enableTableDetection);
 	setIfDefined(normalized, "tesseditCharWhitelist", config.tesseditCharWhitelist);
+	setIfDefined(normalized, "preprocessing", normalizeImagePreprocessingConfig(config.preprocessing));
+	return normalized;
+}
+
+function normalizeImagePreprocessingConfig(
+	preprocessing?: ImagePreprocessingConfig,
+): NativeExtractionConfig | undefined {
+	if (!preprocessing) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "targetDpi", preprocessing.targetDpi);
+	setIfDefined(normalized, "autoRotate", preprocessing.autoRotate);
+	setIfDefined(normalized, "deskew", preprocessing.deskew);
+	setIfDefined(normalized, "denoise", preprocessing.denoise);
+	setIfDefined(normalized, "contrastEnhance", preprocessing.contrastEnhance);
+	setIfDefined(normalized, "binarizationMethod", preprocessing.binarizationMethod);
+	setIfDefined(normalized, "invertColors", preprocessing.invertColors);
+	setIfDefined(normalized, "autoTune", preprocessing.autoTune);
 	return normalized;
 }
 
@@ -550,6 +590,11 @@ function normalizeImageExtractionConfig(images?: ImageExtractionConfig): NativeE
 	setIfDefined(normalized, "autoAdjustDpi", images.autoAdjustDpi);
 	setIfDefined(normalized, "minDpi", images.minDpi);
 	setIfDefined(normalized, "maxDpi", images.maxDpi);
+	setIfDefined(normalized, "stripGps", images.stripGps);
+	setIfDefined(normalized, "perceptualHash", images.perceptualHash);
+	setIfDefined(normalized, "deduplicateImages", images.deduplicateImages);
+	setIfDefined(normalized, "duplicateHashDistance", images.duplicateHashDistance);
+	setIfDefined(normalized, "blobStoreDir", images.blobStoreDir);
 	return normalized;
 }
 
@@ -590,6 +635,63 @@ function normalizeLanguageDetectionConfig(
 	return normalized;
 }
 
+function normalizeClassificationConfig(classification?: ClassificationConfig): NativeExtractionConfig | undefined {
+	if (!classification) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "builtinTypes", classification.builtinTypes);
+	setIfDefined(
+		normalized,
+		"types",
+		classification.types?.map((rule) => {
+			const normalizedRule: NativeExtractionConfig = { label: rule.label };
+			setIfDefined(normalizedRule, "keywords", rule.keywords);
+			setIfDefined(normalizedRule, "titleKeywords", rule.titleKeywords);
+			return normalizedRule;
+		}),
+	);
+	setIfDefined(normalized, "minConfidence", classification.minConfidence);
+	return normalized;
+}
+
+function normalizeKeyValueExtractionConfig(
+	kvExtraction?: KeyValueExtractionConfig,
+): NativeExtractionConfig | undefined {
+	if (!kvExtraction) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "builtinLabels", kvExtraction.builtinLabels);
+	setIfDefined(normalized, "labels", kvExtraction.labels);
+	setIfDefined(normalized, "maxLabelWords", kvExtraction.maxLabelWords);
+	return normalized;
+}
+
+function normalizeCleanupConfig(cleanup?: CleanupConfig): NativeExtractionConfig | undefined {
+	if (!cleanup) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "stripRepeated", cleanup.stripRepeated);
+	setIfDefined(normalized, "tagInstead", cleanup.tagInstead);
+	return normalized;
+}
+
+function normalizeWatermarkConfig(watermarks?: WatermarkConfig): NativeExtractionConfig | undefined {
+	if (!watermarks) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "excludeText", watermarks.excludeText);
+	setIfDefined(normalized, "stampWords", watermarks.stampWords);
+	return normalized;
+}
+
 function normalizePostProcessorConfig(postprocessor?: PostProcessorConfig): NativeExtractionConfig | undefined {
 	if (!postprocessor) {
 		return undefined;
@@ -686,6 +788,17 @@ function normalizePageConfig(pages?: PageConfig): NativeExtractionConfig | undef
 	return normalized;
 }
 
+function normalizeCsvConfig(csv?: CsvConfig): NativeExtractionConfig | undefined {
+	if (!csv) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "delimiter", csv.delimiter);
+	setIfDefined(normalized, "maxRows", csv.maxRows);
+	return normalized;
+}
+
 function normalizeExtractionConfig(config: ExtractionConfigType | null): NativeExtractionConfig | null {
 	if (!config) {
 		return null;
@@ -696,6 +809,7 @@ function normalizeExtractionConfig(config: ExtractionConfigType | null): NativeE
 	setIfDefined(normalized, "enableQualityProcessing", config.enableQualityProcessing);
 	setIfDefined(normalized, "forceOcr", config.forceOcr);
 	setIfDefined(normalized, "maxConcurrentExtractions", config.maxConcurrentExtractions);
+	setIfDefined(normalized, "incremental", config.incremental);
 
 	const ocr = normalizeOcrConfig(config.ocr);
 	setIfDefined(normalized, "ocr", ocr);
@@ -715,6 +829,18 @@ function normalizeExtractionConfig(config: ExtractionConfigType | null): NativeE
 	const languageDetection = normalizeLanguageDetectionConfig(config.languageDetection);
 	setIfDefined(normalized, "languageDetection", languageDetection);
 
+	const classification = normalizeClassificationConfig(config.classification);
+	setIfDefined(normalized, "classification", classification);
+
+	const kvExtraction = normalizeKeyValueExtractionConfig(config.kvExtraction);
+	setIfDefined(normalized, "kvExtraction", kvExtraction);
+
+	const cleanup = normalizeCleanupConfig(config.cleanup);
+	setIfDefined(normalized, "cleanup", cleanup);
+
+	const watermarks = normalizeWatermarkConfig(config.watermarks);
+	setIfDefined(normalized, "watermarks", watermarks);
+
 	const postprocessor = normalizePostProcessorConfig(config.postprocessor);
 	setIfDefined(normalized, "postprocessor", postprocessor);
 
@@ -724,6 +850,9 @@ function normalizeExtractionConfig(config: ExtractionConfigType | null): NativeE
 	const pages = normalizePageConfig(config.pages);
 	setIfDefined(normalized, "pages", pages);
 
+	const csvOptions = normalizeCsvConfig(config.csvOptions);
+	setIfDefined(normalized, "csvOptions", csvOptions);
+
 	const htmlOptions = normalizeHtmlOptions(config.htmlOptions);
 	setIfDefined(normalized, "htmlOptions", htmlOptions);
 
@@ -1984,6 +2113,36 @@ export function detectMimeTypeFromPath(filePath: string, checkExists?: boolean):
 	return binding.detectMimeTypeFromPath(filePath, checkExists);
 }
 
+/**
+ * Read the bytes of an extracted image.
+ *
+ * When `images.blobStoreDir` is configured, images carry a `blob` reference and an
+ * empty `data` buffer. The bytes are then read from the blob store and verified
+ * against the reference; otherwise the inline data is returned.
+ *
+ * @param image - An image from `ExtractionResult.images`
+ * @returns The image bytes
+ *
+ * @throws {Error} If the blob file is missing or no longer matches the reference
+ *
+ * @example
+ * ```typescript
+ * import { extractFileSync, readImageData } from '@kreuzberg/node';
+ *
+ * const result = extractFileSync('document.pdf', null, { images: { blobStoreDir: '/tmp/kreuzberg-images' } });
+ * for (const image of result.images ?? []) {
+ *   const data = readImageData(image);
+ * }
+ * ```
+ */
+export function readImageData(image: ExtractedImage): Uint8Array {
+	if (!image.blob || image.data.length > 0) {
+		return image.data;
+	}
+	const binding = getBinding();
+	return new Uint8Array(binding.readImageBlob(image.blob));
+}
+
 /**
  * Validate that a MIME type is supported by Kreuzberg.
  *
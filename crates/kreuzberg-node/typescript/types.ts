@@ -43,6 +43,49 @@ export interface TesseractConfig {
 	 * Default: null (recognize all).
 	 */
 	tesseditCharWhitelist?: string;
+
+	/** Image preprocessing applied to page images before OCR. */
+	preprocessing?: ImagePreprocessingConfig;
+}
+
+/**
+ * Image preprocessing applied before Tesseract OCR.
+ *
+ * @example
+ * ```typescript
+ * const tesseractConfig: TesseractConfig = {
+ *   preprocessing: { autoTune: true, targetDpi: 300 }
+ * };
+ * ```
+ */
+export interface ImagePreprocessingConfig {
+	/** Resolution pages are scaled towards. Default: 300. */
+	targetDpi?: number;
+
+	/** Detect and correct page rotation. Default: true. */
+	autoRotate?: boolean;
+
+	/** Straighten skewed text lines. Default: true. */
+	deskew?: boolean;
+
+	/** Remove speckle noise with a median filter. Default: false. */
+	denoise?: boolean;
+
+	/** Stretch intensities to the full range. Default: false. */
+	contrastEnhance?: boolean;
+
+	/** Binarization method: 'otsu', 'adaptive' or 'sauvola'. Default: 'otsu'. */
+	binarizationMethod?: string;
+
+	/** Invert colors (white text on black background). Default: false. */
+	invertColors?: boolean;
+
+	/**
+	 * Choose binarization, denoising, contrast enhancement and upscaling per page from
+	 * image statistics. The choices are recorded in `metadata.preprocessing` and in each
+	 * `metadata.ocr_pages` entry. Default: false.
+	 */
+	autoTune?: boolean;
 }
 
 /**
@@ -115,6 +158,86 @@ export interface LanguageDetectionConfig {
 	detectMultiple?: boolean;
 }
 
+/**
+ * Document type recognised by its keywords.
+ *
+ * Keywords are words or phrases matched case-insensitively on word boundaries.
+ */
+export interface DocumentTypeRule {
+	/** Label written to `metadata.document_type.label`. */
+	label: string;
+
+	/** Keywords typical of the type anywhere in the document. */
+	keywords?: string[];
+
+	/** Keywords that identify the type when they appear in the first lines, e.g. a title. */
+	titleKeywords?: string[];
+}
+
+/**
+ * Document type classification configuration.
+ *
+ * Labels documents as `invoice`, `contract`, `report`, `letter` or a custom type, using
+ * keyword and layout features. The result is written to `metadata.document_type` as
+ * `{ label, confidence, scores }`.
+ */
+export interface ClassificationConfig {
+	/** Include the built-in invoice, contract, report and letter types. Default: true. */
+	builtinTypes?: boolean;
+
+	/** Additional document types; a type with a built-in label adds to its keywords. */
+	types?: DocumentTypeRule[];
+
+	/** Confidence (0.0-1.0) below which the document is labelled `unknown`. Default: 0.3. */
+	minConfidence?: number;
+}
+
+/**
+ * Key-value pair extraction configuration.
+ *
+ * Finds label/value pairs such as `Invoice number: 2024-117` or `Total  1,130.50` and writes
+ * them to `metadata.key_values`. PDFs with a text layer are read from word positions, so
+ * pairs get bounding boxes.
+ */
+export interface KeyValueExtractionConfig {
+	/** Recognise common business labels (invoice number, due date, total, ...) without a colon. Default: true. */
+	builtinLabels?: boolean;
+
+	/** Additional labels recognised without a colon, matched case-insensitively by whole words. */
+	labels?: string[];
+
+	/** Most words in a label followed by a colon. Default: 5. */
+	maxLabelWords?: number;
+}
+
+/**
+ * Cleanup of text repeated across pages.
+ *
+ * Removes running headers and footers, page numbers and watermarks that recur on most pages
+ * before post-processing and chunking. Needs page boundaries, so PDFs require `pages`.
+ */
+export interface CleanupConfig {
+	/** Detect lines repeated across most pages. Default: true. */
+	stripRepeated?: boolean;
+
+	/** Keep repeated lines in the content and record them in `metadata.repeated_text` instead. Default: false. */
+	tagInstead?: boolean;
+}
+
+/**
+ * Watermark and stamp detection.
+ *
+ * Diagonal text and images repeated on most pages of PDFs, and stamp words such as DRAFT or
+ * CONFIDENTIAL on a line of their own in any format, are written to `metadata.watermarks`.
+ */
+export interface WatermarkConfig {
+	/** Remove the lines holding watermark text from the content. Default: false. */
+	excludeText?: boolean;
+
+	/** Additional stamp words, matched case-insensitively when written in capitals. Default: []. */
+	stampWords?: string[];
+}
+
 /**
  * Token reduction configuration for optimizing token usage.
  *
@@ -168,6 +291,24 @@ export interface ImageExtractionConfig {
 
 	/** Maximum DPI to avoid excessive file sizes. Default: 300. */
 	maxDpi?: number;
+
+	/** Omit GPS coordinates from the EXIF metadata of image inputs. Default: false. */
+	stripGps?: boolean;
+
+	/** Compute a perceptual hash for each extracted image (`ExtractedImage.perceptualHash`). Default: false. */
+	perceptualHash?: boolean;
+
+	/** Drop near-duplicate images such as repeated logos and watermarks. Implies perceptualHash. Default: false. */
+	deduplicateImages?: boolean;
+
+	/** Maximum number of differing hash bits for two images to count as duplicates. Default: 5. */
+	duplicateHashDistance?: number;
+
+	/**
+	 * Write image bytes to this content-addressed directory instead of inlining them.
+	 * Images then carry a `blob` reference and an empty `data` buffer; read the bytes with `readImageBlob`.
+	 */
+	blobStoreDir?: string;
 }
 
 /**
@@ -377,6 +518,19 @@ export interface PageConfig {
 	markerFormat?: string;
 }
 
+/**
+ * CSV/TSV parsing configuration.
+ *
+ * The delimiter, quoting and header row are detected automatically; these options
+ * override detection and limit how many rows are parsed into the table.
+ */
+export interface CsvConfig {
+	/** Single-character field delimiter. Auto-detected when omitted. */
+	delimiter?: string;
+	/** Maximum number of data rows to parse. Unlimited when omitted. */
+	maxRows?: number;
+}
+
 /**
  * Main extraction configuration interface.
  *
@@ -411,6 +565,18 @@ export interface ExtractionConfig {
 	/** Language detection configuration for automatic language identification. */
 	languageDetection?: LanguageDetectionConfig;
 
+	/** Document type classification written to `metadata.document_type`. */
+	classification?: ClassificationConfig;
+
+	/** Key-value pair extraction written to `metadata.key_values`. */
+	kvExtraction?: KeyValueExtractionConfig;
+
+	/** Removal of headers, footers and page numbers repeated across pages. */
+	cleanup?: CleanupConfig;
+
+	/** Watermark and stamp detection written to `metadata.watermarks`. */
+	watermarks?: WatermarkConfig;
+
 	/** Post-processor configuration for customizing extraction results. */
 	postprocessor?: PostProcessorConfig;
 
@@ -423,8 +589,14 @@ export interface ExtractionConfig {
 	/** Page tracking and extraction configuration for multi-page documents. */
 	pages?: PageConfig;
 
+	/** CSV/TSV parsing options (delimiter override, row limit). */
+	csvOptions?: CsvConfig;
+
 	/** Maximum number of concurrent extractions in batch operations. Default: 4. */
 	maxConcurrentExtractions?: number;
+
+	/** Re-extract only the new and changed pages of a PDF extracted before, reusing stored pages. Default: false. */
+	incremental?: boolean;
 }
 
 /**
@@ -441,8 +613,14 @@ export interface Table {
 
 	/** Page number where this table was found (1-indexed) */
 	pageNumber: number;
+
+	/** Inferred type of each column, when the extractor provides it (e.g. CSV/TSV) */
+	columnTypes?: CellType[] | null;
 }
 
+/** Inferred data type of a table column. */
+export type CellType = "string" | "number" | "date";
+
 export interface ExcelMetadata {
 	sheetCount?: number;
 	sheetNames?: string[];
@@ -471,6 +649,7 @@ export interface ImageMetadata {
 	height?: number;
 	format?: string;
 	exif?: Record<string, string>;
+	exif_details?: Record<string, unknown> | null;
 }
 
 export interface XmlMetadata {
@@ -555,6 +734,116 @@ export interface ImagePreprocessingMetadata {
 	resizeError?: string | null;
 }
 
+/** Page image statistics measured by auto-tuned preprocessing. */
+export interface PageStatistics {
+	contrast: number;
+	speckle_ratio: number;
+	illumination_variation: number;
+	skew_degrees: number;
+	line_height?: number | null;
+}
+
+/** Preprocessing chosen for a page when `ImagePreprocessingConfig.autoTune` is enabled. */
+export interface PreprocessingDecision {
+	binarization_method: "otsu" | "adaptive" | "sauvola";
+	denoise: boolean;
+	contrast_enhance: boolean;
+	deskew_degrees: number;
+	estimated_dpi?: number | null;
+	target_dpi: number;
+	scale_factor: number;
+	statistics: PageStatistics;
+}
+
+/** OCR backend, languages and preprocessing chosen for one page. */
+export interface OcrPageMetadata {
+	page: number;
+	backend: string;
+	confidence?: number | null;
+	fallback_attempted: boolean;
+	languages?: string[];
+	preprocessing?: PreprocessingDecision;
+}
+
+/** A heading in the document outline, with nested sub-headings. */
+export interface OutlineEntry {
+	level: number;
+	title: string;
+	page?: number | null;
+	byte_offset?: number | null;
+	children?: OutlineEntry[];
+}
+
+/** Rectangle with its origin at the top-left corner of the page, in PDF points. */
+export interface KeyValueBoundingBox {
+	x: number;
+	y: number;
+	width: number;
+	height: number;
+}
+
+/** A label and its value found by key-value extraction, e.g. `Invoice number` and `2024-117`. */
+export interface KeyValuePair {
+	key: string;
+	value: string;
+	page?: number | null;
+	key_bbox?: KeyValueBoundingBox | null;
+	value_bbox?: KeyValueBoundingBox | null;
+}
+
+/** A watermark or stamp with the pages it appears on; `rotation` is set for diagonal text, in degrees. */
+export interface Watermark {
+	kind: "text" | "image";
+	text?: string | null;
+	pages: number[];
+	page_coverage: number;
+	rotation?: number | null;
+}
+
+/** A figure found through its caption; `image_index` is unset for figures drawn as vector graphics. */
+export interface Figure {
+	figure_index: number;
+	label: string;
+	caption: string;
+	page_number?: number | null;
+	image_index?: number | null;
+}
+
+export interface XmpMetadata {
+	title?: string | null;
+	creator?: string[] | null;
+	description?: string | null;
+	subject?: string[] | null;
+	rights?: string | null;
+	publisher?: string[] | null;
+	contributor?: string[] | null;
+	language?: string[] | null;
+	date?: string[] | null;
+	identifier?: string | null;
+	format?: string | null;
+	createDate?: string | null;
+	modifyDate?: string | null;
+	metadataDate?: string | null;
+	creatorTool?: string | null;
+	rating?: string | null;
+	label?: string | null;
+	producer?: string | null;
+	keywords?: string | null;
+	pdfVersion?: string | null;
+	trapped?: string | null;
+	headline?: string | null;
+	credit?: string | null;
+	source?: string | null;
+	city?: string | null;
+	state?: string | null;
+	country?: string | null;
+	authorsPosition?: string | null;
+	captionWriter?: string | null;
+	instructions?: string | null;
+	dateCreated?: string | null;
+	category?: string | null;
+}
+
 export interface ErrorMetadata {
 	errorType?: string;
 	message?: string;
@@ -583,8 +872,9 @@ export interface PageBoundary {
  * - "page": Standard document pages (PDF, DOCX, images)
  * - "slide": Presentation slides (PPTX, ODP)
  * - "sheet": Spreadsheet sheets (XLSX, ODS)
+ * - "chapter": Ebook chapters (EPUB, MOBI)
  */
-export type PageUnitType = "page" | "slide" | "sheet";
+export type PageUnitType = "page" | "slide" | "sheet" | "chapter";
 
 /**
  * Detailed per-page metadata.
@@ -701,6 +991,32 @@ export interface ExtractedImage {
 
 	/** OCR extraction result if OCR was run on this image, null otherwise */
 	ocrResult?: ExtractionResult | null;
+
+	/** Perceptual hash (16 hex digits) when image hashing is enabled, null otherwise */
+	perceptualHash?: string | null;
+
+	/** Blob store reference when `images.blobStoreDir` is set, null otherwise */
+	blob?: ImageBlobRef | null;
+
+	/** Caption of the figure this image shows, null when no caption was found */
+	caption?: string | null;
+
+	/** Index of the figure in `metadata.figures`, null when no caption was found */
+	figureIndex?: number | null;
+}
+
+/**
+ * Reference to image bytes kept in the content-addressed blob store.
+ */
+export interface ImageBlobRef {
+	/** Content hash of the image bytes (16 hex digits) */
+	hash: string;
+
+	/** Path of the blob file */
+	path: string;
+
+	/** Size of the image bytes */
+	size: number;
 }
 
 /**
@@ -772,6 +1088,7 @@ export interface Metadata {
 	width?: number;
 	height?: number;
 	exif?: Record<string, string>;
+	exif_details?: Record<string, unknown> | null;
 
 	element_count?: number;
 	unique_elements?: string[];
@@ -809,6 +1126,20 @@ export interface Metadata {
 
 	image_preprocessing?: ImagePreprocessingMetadata | null;
 
+	preprocessing?: PreprocessingDecision | null;
+
+	ocr_pages?: OcrPageMetadata[] | null;
+
+	xmp?: XmpMetadata | null;
+
+	outline?: OutlineEntry[] | null;
+
+	key_values?: KeyValuePair[] | null;
+
+	watermarks?: Watermark[] | null;
+
+	figures?: Figure[] | null;
+
 	json_schema?: Record<string, unknown> | null;
 
 	page_structure?: PageStructure | null;
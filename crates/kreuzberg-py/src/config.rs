@@ -3,6 +3,7 @@
 //! Provides Python-friendly wrappers around the Rust configuration structs.
 //! All types support both construction and field access from Python.
 
+use crate::plugins::{json_value_to_py, python_to_json};
 use html_to_markdown_rs::options::{
     CodeBlockStyle, ConversionOptions, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle, PreprocessingPreset,
     WhitespaceMode,
@@ -52,11 +53,18 @@ impl ExtractionConfig {
         pdf_options=None,
         token_reduction=None,
         language_detection=None,
+        classification=None,
+        kv_extraction=None,
+        cleanup=None,
+        watermarks=None,
         keywords=None,
         postprocessor=None,
         html_options=None,
         max_concurrent_extractions=None,
-        pages=None
+        pages=None,
+        csv_options=None,
+        incremental=None,
+        overrides=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -69,13 +77,21 @@ impl ExtractionConfig {
         pdf_options: Option<PdfConfig>,
         token_reduction: Option<TokenReductionConfig>,
         language_detection: Option<LanguageDetectionConfig>,
+        classification: Option<ClassificationConfig>,
+        kv_extraction: Option<KeyValueExtractionConfig>,
+        cleanup: Option<CleanupConfig>,
+        watermarks: Option<WatermarkConfig>,
         keywords: Option<KeywordConfig>,
         postprocessor: Option<PostProcessorConfig>,
         html_options: Option<Bound<'_, PyDict>>,
         max_concurrent_extractions: Option<usize>,
         pages: Option<PageConfig>,
+        csv_options: Option<CsvConfig>,
+        incremental: Option<bool>,
+        overrides: Option<Bound<'_, PyDict>>,
     ) -> PyResult<Self> {
         let (html_options_inner, html_options_dict) = parse_html_options_dict(html_options)?;
+        let overrides = parse_overrides_dict(overrides)?;
         Ok(Self {
             inner: kreuzberg::ExtractionConfig {
                 use_cache: use_cache.unwrap_or(true),
@@ -87,11 +103,37 @@ impl ExtractionConfig {
                 pdf_options: pdf_options.map(Into::into),
                 token_reduction: token_reduction.map(Into::into),
                 language_detection: language_detection.map(Into::into),
+                classification: classification.map(Into::into),
+                kv_extraction: kv_extraction.map(Into::into),
                 keywords: keywords.map(Into::into),
                 postprocessor: postprocessor.map(Into::into),
                 html_options: html_options_inner,
                 max_concurrent_extractions,
+                adaptive_concurrency: None,
+                spill_dir: None,
+                spill_threshold_bytes: None,
+                resource_profile: Default::default(),
+                input_policy: None,
+                stage_timeouts: None,
+                retry: None,
+                cache: None,
+                ocr_correction: None,
                 pages: pages.map(Into::into),
+                text_options: None,
+                csv_options: csv_options.map(Into::into),
+                spreadsheet_options: None,
+                pptx_options: None,
+                preview: None,
+                normalization: None,
+                watermarks: watermarks.map(Into::into),
+                cleanup: cleanup.map(Into::into),
+                emit_term_offsets: false,
+                emit_provenance: false,
+                detect_barcodes: false,
+                incremental: incremental.unwrap_or(false),
+                fields: None,
+                result_compression: None,
+                overrides,
             },
             html_options_dict,
         })
@@ -137,6 +179,16 @@ impl ExtractionConfig {
         self.inner.force_ocr = value;
     }
 
+    #[getter]
+    fn incremental(&self) -> bool {
+        self.inner.incremental
+    }
+
+    #[setter]
+    fn set_incremental(&mut self, value: bool) {
+        self.inner.incremental = value;
+    }
+
     #[getter]
     fn chunking(&self) -> Option<ChunkingConfig> {
         self.inner.chunking.clone().map(Into::into)
@@ -187,6 +239,46 @@ impl ExtractionConfig {
         self.inner.language_detection = value.map(Into::into);
     }
 
+    #[getter]
+    fn classification(&self) -> Option<ClassificationConfig> {
+        self.inner.classification.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_classification(&mut self, value: Option<ClassificationConfig>) {
+        self.inner.classification = value.map(Into::into);
+    }
+
+    #[getter]
+    fn kv_extraction(&self) -> Option<KeyValueExtractionConfig> {
+        self.inner.kv_extraction.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_kv_extraction(&mut self, value: Option<KeyValueExtractionConfig>) {
+        self.inner.kv_extraction = value.map(Into::into);
+    }
+
+    #[getter]
+    fn cleanup(&self) -> Option<CleanupConfig> {
+        self.inner.cleanup.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_cleanup(&mut self, value: Option<CleanupConfig>) {
+        self.inner.cleanup = value.map(Into::into);
+    }
+
+    #[getter]
+    fn watermarks(&self) -> Option<WatermarkConfig> {
+        self.inner.watermarks.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_watermarks(&mut self, value: Option<WatermarkConfig>) {
+        self.inner.watermarks = value.map(Into::into);
+    }
+
     #[getter]
     fn keywords(&self) -> Option<KeywordConfig> {
         self.inner.keywords.clone().map(Into::into)
@@ -230,6 +322,24 @@ impl ExtractionConfig {
         Ok(())
     }
 
+    #[getter]
+    fn overrides<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        self.inner
+            .overrides
+            .as_ref()
+            .map(|overrides| {
+                let object = overrides.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+                json_value_to_py(py, &serde_json::Value::Object(object))
+            })
+            .transpose()
+    }
+
+    #[setter]
+    fn set_overrides(&mut self, value: Option<Bound<'_, PyDict>>) -> PyResult<()> {
+        self.inner.overrides = parse_overrides_dict(value)?;
+        Ok(())
+    }
+
     #[getter]
     fn pages(&self) -> Option<PageConfig> {
         self.inner.pages.clone().map(Into::into)
@@ -240,6 +350,16 @@ impl ExtractionConfig {
         self.inner.pages = value.map(Into::into);
     }
 
+    #[getter]
+    fn csv_options(&self) -> Option<CsvConfig> {
+        self.inner.csv_options.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_csv_options(&mut self, value: Option<CsvConfig>) {
+        self.inner.csv_options = value.map(Into::into);
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ExtractionConfig(use_cache={}, enable_quality_processing={}, ocr={}, force_ocr={})",
@@ -328,6 +448,25 @@ impl From<kreuzberg::ExtractionConfig> for ExtractionConfig {
     }
 }
 
+/// Convert a dict of MIME pattern to config section dict into `ExtractionConfig::overrides`.
+fn parse_overrides_dict(
+    overrides: Option<Bound<'_, PyDict>>,
+) -> PyResult<Option<std::collections::BTreeMap<String, serde_json::Value>>> {
+    let Some(dict) = overrides else {
+        return Ok(None);
+    };
+
+    let mut parsed = std::collections::BTreeMap::new();
+    for (pattern, section) in dict.iter() {
+        let pattern: String = pattern
+            .extract()
+            .map_err(|_| PyValueError::new_err("overrides keys must be MIME pattern strings"))?;
+        let section = python_to_json(&section).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        parsed.insert(pattern, section);
+    }
+    Ok(Some(parsed))
+}
+
 fn parse_html_options_dict(
     options: Option<Bound<'_, PyDict>>,
 ) -> PyResult<(Option<ConversionOptions>, Option<Py<PyDict>>)> {
@@ -593,13 +732,20 @@ pub struct OcrConfig {
 #[pymethods]
 impl OcrConfig {
     #[new]
-    #[pyo3(signature = (backend=None, language=None, tesseract_config=None))]
-    fn new(backend: Option<String>, language: Option<String>, tesseract_config: Option<TesseractConfig>) -> Self {
+    #[pyo3(signature = (backend=None, language=None, tesseract_config=None, vocabulary=None))]
+    fn new(
+        backend: Option<String>,
+        language: Option<String>,
+        tesseract_config: Option<TesseractConfig>,
+        vocabulary: Option<OcrVocabularyConfig>,
+    ) -> Self {
         Self {
             inner: kreuzberg::OcrConfig {
                 backend: backend.unwrap_or_else(|| "tesseract".to_string()),
                 language: language.unwrap_or_else(|| "eng".to_string()),
                 tesseract_config: tesseract_config.map(Into::into),
+                fallback: None,
+                vocabulary: vocabulary.map(Into::into),
             },
         }
     }
@@ -634,6 +780,16 @@ impl OcrConfig {
         self.inner.tesseract_config = value.map(Into::into);
     }
 
+    #[getter]
+    fn vocabulary(&self) -> Option<OcrVocabularyConfig> {
+        self.inner.vocabulary.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_vocabulary(&mut self, value: Option<OcrVocabularyConfig>) {
+        self.inner.vocabulary = value.map(Into::into);
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "OcrConfig(backend='{}', language='{}', tesseract_config={})",
@@ -660,6 +816,123 @@ impl From<kreuzberg::OcrConfig> for OcrConfig {
     }
 }
 
+/// Domain vocabulary for OCR.
+///
+/// Words come from `words` and from `words_file` (one entry per line, lines starting
+/// with `#` are comments). They are added to Tesseract's user words, and with
+/// `correct=True` OCR words within `max_distance` edits of exactly one vocabulary
+/// word are replaced with it.
+///
+/// Example:
+///     >>> from kreuzberg import OcrConfig, OcrVocabularyConfig
+///     >>> config = OcrConfig(vocabulary=OcrVocabularyConfig(words=["Metformin", "XJ-4410"]))
+#[pyclass(name = "OcrVocabularyConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct OcrVocabularyConfig {
+    inner: kreuzberg::OcrVocabularyConfig,
+}
+
+#[pymethods]
+impl OcrVocabularyConfig {
+    #[new]
+    #[pyo3(signature = (words=None, words_file=None, correct=None, max_distance=None, min_word_length=None))]
+    fn new(
+        words: Option<Vec<String>>,
+        words_file: Option<String>,
+        correct: Option<bool>,
+        max_distance: Option<usize>,
+        min_word_length: Option<usize>,
+    ) -> Self {
+        let defaults = kreuzberg::OcrVocabularyConfig::default();
+        Self {
+            inner: kreuzberg::OcrVocabularyConfig {
+                words: words.unwrap_or_default(),
+                words_file,
+                correct: correct.unwrap_or(defaults.correct),
+                max_distance: max_distance.unwrap_or(defaults.max_distance),
+                min_word_length: min_word_length.unwrap_or(defaults.min_word_length),
+            },
+        }
+    }
+
+    #[getter]
+    fn words(&self) -> Vec<String> {
+        self.inner.words.clone()
+    }
+
+    #[setter]
+    fn set_words(&mut self, value: Vec<String>) {
+        self.inner.words = value;
+    }
+
+    #[getter]
+    fn words_file(&self) -> Option<String> {
+        self.inner.words_file.clone()
+    }
+
+    #[setter]
+    fn set_words_file(&mut self, value: Option<String>) {
+        self.inner.words_file = value;
+    }
+
+    #[getter]
+    fn correct(&self) -> bool {
+        self.inner.correct
+    }
+
+    #[setter]
+    fn set_correct(&mut self, value: bool) {
+        self.inner.correct = value;
+    }
+
+    #[getter]
+    fn max_distance(&self) -> usize {
+        self.inner.max_distance
+    }
+
+    #[setter]
+    fn set_max_distance(&mut self, value: usize) {
+        self.inner.max_distance = value;
+    }
+
+    #[getter]
+    fn min_word_length(&self) -> usize {
+        self.inner.min_word_length
+    }
+
+    #[setter]
+    fn set_min_word_length(&mut self, value: usize) {
+        self.inner.min_word_length = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "OcrVocabularyConfig(words={:?}, words_file={}, correct={}, max_distance={}, min_word_length={})",
+            self.inner.words,
+            self.inner
+                .words_file
+                .as_ref()
+                .map(|path| format!("'{}'", path))
+                .unwrap_or_else(|| "None".to_string()),
+            self.inner.correct,
+            self.inner.max_distance,
+            self.inner.min_word_length
+        )
+    }
+}
+
+impl From<OcrVocabularyConfig> for kreuzberg::OcrVocabularyConfig {
+    fn from(config: OcrVocabularyConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::OcrVocabularyConfig> for OcrVocabularyConfig {
+    fn from(config: kreuzberg::OcrVocabularyConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
 /// Embedding model type.
 ///
 /// Specifies which model to use for embedding generation.
@@ -873,6 +1146,7 @@ impl ChunkingConfig {
                 max_overlap: max_overlap.unwrap_or(200),
                 embedding: embedding.map(Into::into),
                 preset,
+                respect_sentences: false,
             },
         }
     }
@@ -964,7 +1238,12 @@ impl ImageExtractionConfig {
         max_image_dimension=None,
         auto_adjust_dpi=None,
         min_dpi=None,
-        max_dpi=None
+        max_dpi=None,
+        strip_gps=None,
+        perceptual_hash=None,
+        deduplicate_images=None,
+        duplicate_hash_distance=None,
+        blob_store_dir=None
     ))]
     fn new(
         extract_images: Option<bool>,
@@ -973,6 +1252,11 @@ impl ImageExtractionConfig {
         auto_adjust_dpi: Option<bool>,
         min_dpi: Option<i32>,
         max_dpi: Option<i32>,
+        strip_gps: Option<bool>,
+        perceptual_hash: Option<bool>,
+        deduplicate_images: Option<bool>,
+        duplicate_hash_distance: Option<u32>,
+        blob_store_dir: Option<String>,
     ) -> Self {
         Self {
             inner: kreuzberg::ImageExtractionConfig {
@@ -982,6 +1266,15 @@ impl ImageExtractionConfig {
                 auto_adjust_dpi: auto_adjust_dpi.unwrap_or(true),
                 min_dpi: min_dpi.unwrap_or(72),
                 max_dpi: max_dpi.unwrap_or(600),
+                strip_gps: strip_gps.unwrap_or(false),
+                output_format: None,
+                downscale_to: None,
+                max_bytes: None,
+                perceptual_hash: perceptual_hash.unwrap_or(false),
+                deduplicate_images: deduplicate_images.unwrap_or(false),
+                duplicate_hash_distance: duplicate_hash_distance.unwrap_or(5),
+                ocr_policy: None,
+                blob_store_dir: blob_store_dir.map(Into::into),
             },
         }
     }
@@ -1046,6 +1339,59 @@ impl ImageExtractionConfig {
         self.inner.max_dpi = value;
     }
 
+    #[getter]
+    fn strip_gps(&self) -> bool {
+        self.inner.strip_gps
+    }
+
+    #[setter]
+    fn set_strip_gps(&mut self, value: bool) {
+        self.inner.strip_gps = value;
+    }
+
+    #[getter]
+    fn perceptual_hash(&self) -> bool {
+        self.inner.perceptual_hash
+    }
+
+    #[setter]
+    fn set_perceptual_hash(&mut self, value: bool) {
+        self.inner.perceptual_hash = value;
+    }
+
+    #[getter]
+    fn deduplicate_images(&self) -> bool {
+        self.inner.deduplicate_images
+    }
+
+    #[setter]
+    fn set_deduplicate_images(&mut self, value: bool) {
+        self.inner.deduplicate_images = value;
+    }
+
+    #[getter]
+    fn duplicate_hash_distance(&self) -> u32 {
+        self.inner.duplicate_hash_distance
+    }
+
+    #[setter]
+    fn set_duplicate_hash_distance(&mut self, value: u32) {
+        self.inner.duplicate_hash_distance = value;
+    }
+
+    #[getter]
+    fn blob_store_dir(&self) -> Option<String> {
+        self.inner
+            .blob_store_dir
+            .as_ref()
+            .map(|dir| dir.to_string_lossy().into_owned())
+    }
+
+    #[setter]
+    fn set_blob_store_dir(&mut self, value: Option<String>) {
+        self.inner.blob_store_dir = value.map(Into::into);
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImageExtractionConfig(extract_images={}, target_dpi={}, max_image_dimension={})",
@@ -1286,6 +1632,381 @@ impl From<kreuzberg::LanguageDetectionConfig> for LanguageDetectionConfig {
     }
 }
 
+/// Document type classification configuration.
+///
+/// The detected type is written to ``metadata["document_type"]``.
+///
+/// Example:
+///     >>> from kreuzberg import ClassificationConfig, DocumentTypeRule
+///     >>> config = ClassificationConfig(
+///     ...     types=[DocumentTypeRule("purchase_order", keywords=["purchase order", "ship to"])],
+///     ...     min_confidence=0.4,
+///     ... )
+#[pyclass(name = "ClassificationConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct ClassificationConfig {
+    inner: kreuzberg::ClassificationConfig,
+}
+
+#[pymethods]
+impl ClassificationConfig {
+    #[new]
+    #[pyo3(signature = (builtin_types=None, types=None, min_confidence=None))]
+    fn new(builtin_types: Option<bool>, types: Option<Vec<DocumentTypeRule>>, min_confidence: Option<f64>) -> Self {
+        let defaults = kreuzberg::ClassificationConfig::default();
+        Self {
+            inner: kreuzberg::ClassificationConfig {
+                builtin_types: builtin_types.unwrap_or(defaults.builtin_types),
+                types: types
+                    .map(|types| types.into_iter().map(Into::into).collect())
+                    .unwrap_or_default(),
+                min_confidence: min_confidence.unwrap_or(defaults.min_confidence),
+            },
+        }
+    }
+
+    #[getter]
+    fn builtin_types(&self) -> bool {
+        self.inner.builtin_types
+    }
+
+    #[setter]
+    fn set_builtin_types(&mut self, value: bool) {
+        self.inner.builtin_types = value;
+    }
+
+    #[getter]
+    fn types(&self) -> Vec<DocumentTypeRule> {
+        self.inner.types.iter().cloned().map(Into::into).collect()
+    }
+
+    #[setter]
+    fn set_types(&mut self, value: Vec<DocumentTypeRule>) {
+        self.inner.types = value.into_iter().map(Into::into).collect();
+    }
+
+    #[getter]
+    fn min_confidence(&self) -> f64 {
+        self.inner.min_confidence
+    }
+
+    #[setter]
+    fn set_min_confidence(&mut self, value: f64) {
+        self.inner.min_confidence = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ClassificationConfig(builtin_types={}, types=[{} rules], min_confidence={})",
+            self.inner.builtin_types,
+            self.inner.types.len(),
+            self.inner.min_confidence
+        )
+    }
+}
+
+impl From<ClassificationConfig> for kreuzberg::ClassificationConfig {
+    fn from(config: ClassificationConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::ClassificationConfig> for ClassificationConfig {
+    fn from(config: kreuzberg::ClassificationConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
+/// Document type recognised by its keywords, for ``ClassificationConfig.types``.
+///
+/// Example:
+///     >>> from kreuzberg import DocumentTypeRule
+///     >>> rule = DocumentTypeRule(
+///     ...     "resume", keywords=["work experience", "skills"], title_keywords=["curriculum vitae"]
+///     ... )
+#[pyclass(name = "DocumentTypeRule", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct DocumentTypeRule {
+    inner: kreuzberg::DocumentTypeRule,
+}
+
+#[pymethods]
+impl DocumentTypeRule {
+    #[new]
+    #[pyo3(signature = (label, keywords=None, title_keywords=None))]
+    fn new(label: String, keywords: Option<Vec<String>>, title_keywords: Option<Vec<String>>) -> Self {
+        Self {
+            inner: kreuzberg::DocumentTypeRule {
+                label,
+                keywords: keywords.unwrap_or_default(),
+                title_keywords: title_keywords.unwrap_or_default(),
+            },
+        }
+    }
+
+    #[getter]
+    fn label(&self) -> String {
+        self.inner.label.clone()
+    }
+
+    #[setter]
+    fn set_label(&mut self, value: String) {
+        self.inner.label = value;
+    }
+
+    #[getter]
+    fn keywords(&self) -> Vec<String> {
+        self.inner.keywords.clone()
+    }
+
+    #[setter]
+    fn set_keywords(&mut self, value: Vec<String>) {
+        self.inner.keywords = value;
+    }
+
+    #[getter]
+    fn title_keywords(&self) -> Vec<String> {
+        self.inner.title_keywords.clone()
+    }
+
+    #[setter]
+    fn set_title_keywords(&mut self, value: Vec<String>) {
+        self.inner.title_keywords = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DocumentTypeRule(label={:?}, keywords={:?}, title_keywords={:?})",
+            self.inner.label, self.inner.keywords, self.inner.title_keywords
+        )
+    }
+}
+
+impl From<DocumentTypeRule> for kreuzberg::DocumentTypeRule {
+    fn from(rule: DocumentTypeRule) -> Self {
+        rule.inner
+    }
+}
+
+impl From<kreuzberg::DocumentTypeRule> for DocumentTypeRule {
+    fn from(rule: kreuzberg::DocumentTypeRule) -> Self {
+        Self { inner: rule }
+    }
+}
+
+/// Key-value pair extraction configuration.
+///
+/// Label/value pairs are written to ``metadata["key_values"]``.
+///
+/// Example:
+///     >>> from kreuzberg import KeyValueExtractionConfig
+///     >>> config = KeyValueExtractionConfig(labels=["Kundennummer", "Fällig am"])
+#[pyclass(name = "KeyValueExtractionConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct KeyValueExtractionConfig {
+    inner: kreuzberg::KeyValueExtractionConfig,
+}
+
+#[pymethods]
+impl KeyValueExtractionConfig {
+    #[new]
+    #[pyo3(signature = (builtin_labels=None, labels=None, max_label_words=None))]
+    fn new(builtin_labels: Option<bool>, labels: Option<Vec<String>>, max_label_words: Option<usize>) -> Self {
+        let defaults = kreuzberg::KeyValueExtractionConfig::default();
+        Self {
+            inner: kreuzberg::KeyValueExtractionConfig {
+                builtin_labels: builtin_labels.unwrap_or(defaults.builtin_labels),
+                labels: labels.unwrap_or_default(),
+                max_label_words: max_label_words.unwrap_or(defaults.max_label_words),
+            },
+        }
+    }
+
+    #[getter]
+    fn builtin_labels(&self) -> bool {
+        self.inner.builtin_labels
+    }
+
+    #[setter]
+    fn set_builtin_labels(&mut self, value: bool) {
+        self.inner.builtin_labels = value;
+    }
+
+    #[getter]
+    fn labels(&self) -> Vec<String> {
+        self.inner.labels.clone()
+    }
+
+    #[setter]
+    fn set_labels(&mut self, value: Vec<String>) {
+        self.inner.labels = value;
+    }
+
+    #[getter]
+    fn max_label_words(&self) -> usize {
+        self.inner.max_label_words
+    }
+
+    #[setter]
+    fn set_max_label_words(&mut self, value: usize) {
+        self.inner.max_label_words = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "KeyValueExtractionConfig(builtin_labels={}, labels={:?}, max_label_words={})",
+            self.inner.builtin_labels, self.inner.labels, self.inner.max_label_words
+        )
+    }
+}
+
+impl From<KeyValueExtractionConfig> for kreuzberg::KeyValueExtractionConfig {
+    fn from(config: KeyValueExtractionConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::KeyValueExtractionConfig> for KeyValueExtractionConfig {
+    fn from(config: kreuzberg::KeyValueExtractionConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
+/// Cleanup of text repeated across pages.
+///
+/// Running headers and footers, page numbers and watermarks are removed, or with
+/// ``tag_instead`` recorded in ``metadata["repeated_text"]``.
+///
+/// Example:
+///     >>> from kreuzberg import CleanupConfig
+///     >>> config = CleanupConfig(tag_instead=True)
+#[pyclass(name = "CleanupConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct CleanupConfig {
+    inner: kreuzberg::CleanupConfig,
+}
+
+#[pymethods]
+impl CleanupConfig {
+    #[new]
+    #[pyo3(signature = (strip_repeated=None, tag_instead=None))]
+    fn new(strip_repeated: Option<bool>, tag_instead: Option<bool>) -> Self {
+        let defaults = kreuzberg::CleanupConfig::default();
+        Self {
+            inner: kreuzberg::CleanupConfig {
+                strip_repeated: strip_repeated.unwrap_or(defaults.strip_repeated),
+                tag_instead: tag_instead.unwrap_or(defaults.tag_instead),
+            },
+        }
+    }
+
+    #[getter]
+    fn strip_repeated(&self) -> bool {
+        self.inner.strip_repeated
+    }
+
+    #[setter]
+    fn set_strip_repeated(&mut self, value: bool) {
+        self.inner.strip_repeated = value;
+    }
+
+    #[getter]
+    fn tag_instead(&self) -> bool {
+        self.inner.tag_instead
+    }
+
+    #[setter]
+    fn set_tag_instead(&mut self, value: bool) {
+        self.inner.tag_instead = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CleanupConfig(strip_repeated={}, tag_instead={})",
+            self.inner.strip_repeated, self.inner.tag_instead
+        )
+    }
+}
+
+impl From<CleanupConfig> for kreuzberg::CleanupConfig {
+    fn from(config: CleanupConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::CleanupConfig> for CleanupConfig {
+    fn from(config: kreuzberg::CleanupConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
+/// Watermark and stamp detection.
+///
+/// Diagonal text and repeated images in PDFs, and stamp words such as DRAFT or
+/// CONFIDENTIAL on a line of their own, are written to ``metadata["watermarks"]``.
+///
+/// Example:
+///     >>> from kreuzberg import WatermarkConfig
+///     >>> config = WatermarkConfig(exclude_text=True, stamp_words=["Attorney Work Product"])
+#[pyclass(name = "WatermarkConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct WatermarkConfig {
+    inner: kreuzberg::WatermarkConfig,
+}
+
+#[pymethods]
+impl WatermarkConfig {
+    #[new]
+    #[pyo3(signature = (exclude_text=None, stamp_words=None))]
+    fn new(exclude_text: Option<bool>, stamp_words: Option<Vec<String>>) -> Self {
+        Self {
+            inner: kreuzberg::WatermarkConfig {
+                exclude_text: exclude_text.unwrap_or(false),
+                stamp_words: stamp_words.unwrap_or_default(),
+            },
+        }
+    }
+
+    #[getter]
+    fn exclude_text(&self) -> bool {
+        self.inner.exclude_text
+    }
+
+    #[setter]
+    fn set_exclude_text(&mut self, value: bool) {
+        self.inner.exclude_text = value;
+    }
+
+    #[getter]
+    fn stamp_words(&self) -> Vec<String> {
+        self.inner.stamp_words.clone()
+    }
+
+    #[setter]
+    fn set_stamp_words(&mut self, value: Vec<String>) {
+        self.inner.stamp_words = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "WatermarkConfig(exclude_text={}, stamp_words={:?})",
+            self.inner.exclude_text, self.inner.stamp_words
+        )
+    }
+}
+
+impl From<WatermarkConfig> for kreuzberg::WatermarkConfig {
+    fn from(config: WatermarkConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::WatermarkConfig> for WatermarkConfig {
+    fn from(config: kreuzberg::WatermarkConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
 /// Post-processor configuration.
 ///
 /// Example:
@@ -1399,7 +2120,8 @@ impl ImagePreprocessingConfig {
         denoise=None,
         contrast_enhance=None,
         binarization_method=None,
-        invert_colors=None
+        invert_colors=None,
+        auto_tune=None
     ))]
     fn new(
         target_dpi: Option<i32>,
@@ -1409,6 +2131,7 @@ impl ImagePreprocessingConfig {
         contrast_enhance: Option<bool>,
         binarization_method: Option<String>,
         invert_colors: Option<bool>,
+        auto_tune: Option<bool>,
     ) -> Self {
         Self {
             inner: kreuzberg::types::ImagePreprocessingConfig {
@@ -1419,6 +2142,7 @@ impl ImagePreprocessingConfig {
                 contrast_enhance: contrast_enhance.unwrap_or(false),
                 binarization_method: binarization_method.unwrap_or_else(|| "otsu".to_string()),
                 invert_colors: invert_colors.unwrap_or(false),
+                auto_tune: auto_tune.unwrap_or(false),
             },
         }
     }
@@ -1493,10 +2217,20 @@ impl ImagePreprocessingConfig {
         self.inner.invert_colors = value;
     }
 
+    #[getter]
+    fn auto_tune(&self) -> bool {
+        self.inner.auto_tune
+    }
+
+    #[setter]
+    fn set_auto_tune(&mut self, value: bool) {
+        self.inner.auto_tune = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "ImagePreprocessingConfig(target_dpi={}, auto_rotate={}, deskew={}, denoise={})",
-            self.inner.target_dpi, self.inner.auto_rotate, self.inner.deskew, self.inner.denoise
+            "ImagePreprocessingConfig(target_dpi={}, auto_rotate={}, deskew={}, denoise={}, auto_tune={})",
+            self.inner.target_dpi, self.inner.auto_rotate, self.inner.deskew, self.inner.denoise, self.inner.auto_tune
         )
     }
 }
@@ -1605,6 +2339,10 @@ impl TesseractConfig {
                 tessedit_use_primary_params_model: tessedit_use_primary_params_model.unwrap_or(true),
                 textord_space_size_is_variable: textord_space_size_is_variable.unwrap_or(true),
                 thresholding_method: thresholding_method.unwrap_or(false),
+                preserve_interword_spaces: false,
+                user_words_file: None,
+                user_patterns_file: None,
+                variables: Default::default(),
             },
         }
     }
@@ -2197,6 +2935,77 @@ impl From<kreuzberg::core::config::PageConfig> for PageConfig {
     }
 }
 
+/// CSV/TSV extraction configuration.
+///
+/// The delimiter, quoting and header row are detected automatically; these
+/// options override or bound that detection.
+///
+/// Example:
+///     >>> from kreuzberg import CsvConfig, ExtractionConfig
+///     >>> config = ExtractionConfig(csv_options=CsvConfig(delimiter=";", max_rows=1000))
+#[pyclass(name = "CsvConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct CsvConfig {
+    inner: kreuzberg::CsvConfig,
+}
+
+#[pymethods]
+impl CsvConfig {
+    #[new]
+    #[pyo3(signature = (delimiter=None, max_rows=None))]
+    fn new(delimiter: Option<char>, max_rows: Option<usize>) -> Self {
+        Self {
+            inner: kreuzberg::CsvConfig { delimiter, max_rows },
+        }
+    }
+
+    #[getter]
+    fn delimiter(&self) -> Option<char> {
+        self.inner.delimiter
+    }
+
+    #[setter]
+    fn set_delimiter(&mut self, value: Option<char>) {
+        self.inner.delimiter = value;
+    }
+
+    #[getter]
+    fn max_rows(&self) -> Option<usize> {
+        self.inner.max_rows
+    }
+
+    #[setter]
+    fn set_max_rows(&mut self, value: Option<usize>) {
+        self.inner.max_rows = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CsvConfig(delimiter={}, max_rows={})",
+            self.inner
+                .delimiter
+                .map(|d| format!("{:?}", d))
+                .unwrap_or_else(|| "None".to_string()),
+            self.inner
+                .max_rows
+                .map(|m| m.to_string())
+                .unwrap_or_else(|| "None".to_string())
+        )
+    }
+}
+
+impl From<CsvConfig> for kreuzberg::CsvConfig {
+    fn from(config: CsvConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::CsvConfig> for CsvConfig {
+    fn from(config: kreuzberg::CsvConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
 impl From<kreuzberg::keywords::KeywordConfig> for KeywordConfig {
     fn from(config: kreuzberg::keywords::KeywordConfig) -> Self {
         Self { inner: config }
@@ -2301,6 +3110,9 @@ pub fn config_merge(py: Python<'_>, base: Py<ExtractionConfig>, override_config:
     if override_config.inner.force_ocr != override_default.force_ocr {
         base_mut.inner.force_ocr = override_config.inner.force_ocr;
     }
+    if override_config.inner.incremental != override_default.incremental {
+        base_mut.inner.incremental = override_config.inner.incremental;
+    }
     if override_config.inner.ocr.is_some() {
         base_mut.inner.ocr = override_config.inner.ocr.clone();
     }
@@ -2334,6 +3146,9 @@ pub fn config_merge(py: Python<'_>, base: Py<ExtractionConfig>, override_config:
     if override_config.inner.pages.is_some() {
         base_mut.inner.pages = override_config.inner.pages.clone();
     }
+    if override_config.inner.csv_options.is_some() {
+        base_mut.inner.csv_options = override_config.inner.csv_options.clone();
+    }
 
     Ok(())
 }
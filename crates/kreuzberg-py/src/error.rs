@@ -41,6 +41,12 @@ pyo3::create_exception!(
     PyException,
     "Raised when plugin operations fail (initialization, registration, execution)."
 );
+pyo3::create_exception!(
+    kreuzberg,
+    ExtractionTimeoutError,
+    PyException,
+    "Raised when an extraction stage exceeds its time budget."
+);
 
 /// Format an error message with its source chain.
 ///
@@ -76,6 +82,7 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
             "CacheError" => PyErr::from_type(py.get_type::<CacheError>(), (message,)),
             "ImageProcessingError" => PyErr::from_type(py.get_type::<ImageProcessingError>(), (message,)),
             "PluginError" => PyErr::from_type(py.get_type::<PluginError>(), (message,)),
+            "ExtractionTimeoutError" => PyErr::from_type(py.get_type::<ExtractionTimeoutError>(), (message,)),
             _ => PyRuntimeError::new_err(message),
         }
     })
@@ -86,6 +93,8 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
 /// Maps error variants to appropriate Python exception types:
 /// - `Validation` → `ValidationError` (custom exception)
 /// - `UnsupportedFormat` → `ValidationError` (custom exception)
+/// - `InputRejected` → `ValidationError` (custom exception, message starts with the reason)
+/// - `Timeout` → `ExtractionTimeoutError` (custom exception, message names the stage)
 /// - `Parsing` → `ParsingError` (custom exception)
 /// - `Io` → `OSError` (system error - must bubble up!)
 /// - `Ocr` → `OCRError` (custom exception)
@@ -111,6 +120,8 @@ pub fn to_py_err(error: kreuzberg::KreuzbergError) -> PyErr {
             exception_from_module("ValidationError", format_error_with_source(message, source))
         }
         KreuzbergError::UnsupportedFormat(msg) => exception_from_module("ValidationError", msg),
+        err @ KreuzbergError::InputRejected { .. } => exception_from_module("ValidationError", err.to_string()),
+        err @ KreuzbergError::Timeout { .. } => exception_from_module("ExtractionTimeoutError", err.to_string()),
         KreuzbergError::Parsing { message, source } => {
             exception_from_module("ParsingError", format_error_with_source(message, source))
         }
@@ -61,12 +61,19 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<config::ExtractionConfig>()?;
     m.add_class::<config::OcrConfig>()?;
+    m.add_class::<config::OcrVocabularyConfig>()?;
     m.add_class::<config::PdfConfig>()?;
     m.add_class::<config::PageConfig>()?;
+    m.add_class::<config::CsvConfig>()?;
     m.add_class::<config::ChunkingConfig>()?;
     m.add_class::<config::EmbeddingConfig>()?;
     m.add_class::<config::EmbeddingModelType>()?;
     m.add_class::<config::LanguageDetectionConfig>()?;
+    m.add_class::<config::ClassificationConfig>()?;
+    m.add_class::<config::DocumentTypeRule>()?;
+    m.add_class::<config::KeyValueExtractionConfig>()?;
+    m.add_class::<config::CleanupConfig>()?;
+    m.add_class::<config::WatermarkConfig>()?;
     m.add_class::<config::TokenReductionConfig>()?;
     m.add_class::<config::ImageExtractionConfig>()?;
     m.add_class::<config::PostProcessorConfig>()?;
@@ -114,6 +121,7 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(detect_mime_type_from_bytes, m)?)?;
     m.add_function(wrap_pyfunction!(detect_mime_type_from_path, m)?)?;
+    m.add_function(wrap_pyfunction!(read_image_blob, m)?)?;
     m.add_function(wrap_pyfunction!(validate_mime_type, m)?)?;
     m.add_function(wrap_pyfunction!(get_extensions_for_mime, m)?)?;
     m.add_function(wrap_pyfunction!(get_last_error_code, m)?)?;
@@ -290,6 +298,31 @@ fn detect_mime_type_from_path(path: &str) -> PyResult<String> {
     kreuzberg::detect_mime_type(path, true).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
 }
 
+/// Read image bytes from the blob store.
+///
+/// Extracted images carry a ``blob`` reference instead of their bytes when
+/// ``ImageExtractionConfig.blob_store_dir`` is set. The bytes are verified against
+/// the reference.
+///
+/// Args:
+///     hash (str): Content hash from the blob reference
+///     path (str): Blob file path from the blob reference
+///     size (int): Size in bytes from the blob reference
+///
+/// Returns:
+///     bytes: Image data
+///
+/// Example:
+///     >>> from kreuzberg._internal_bindings import read_image_blob
+///     >>> blob = result.images[0]["blob"]
+///     >>> data = read_image_blob(blob["hash"], blob["path"], blob["size"])
+#[pyfunction]
+fn read_image_blob(py: Python<'_>, hash: String, path: String, size: usize) -> PyResult<Py<pyo3::types::PyBytes>> {
+    let blob = kreuzberg::ImageBlobRef { hash, path, size };
+    let data = Python::detach(py, || kreuzberg::cache::blob_store::read_blob(&blob)).map_err(error::to_py_err)?;
+    Ok(pyo3::types::PyBytes::new(py, &data).unbind())
+}
+
 /// Validate and normalize a MIME type.
 ///
 /// Checks if the provided MIME type is supported. Accepts specific supported types
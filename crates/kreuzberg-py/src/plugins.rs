@@ -37,9 +37,8 @@
 //! ```rust,ignore
 //! py.detach(|| {
 //!     // GIL is released here - other Python threads can run
-//!     let registry = get_ocr_backend_registry();
-//!     let mut registry = registry.write()?; // Expensive lock acquisition
-//!     registry.register(backend)
+//!     // Copies the registry and runs the plugin's `initialize`
+//!     get_ocr_backend_registry().update(|registry| registry.register(backend))
 //! })
 //! ```
 //! - **When**: Writing to registries, I/O operations, expensive computations
@@ -98,7 +97,6 @@
 //!
 //! - **hasattr() failures**: If GIL acquisition fails, log warning and use safe defaults
 //! - **Method call failures**: Convert PyErr to KreuzbergError with context
-//! - **Lock poisoning**: Handle registry lock poisoning gracefully
 //! - **Pattern**: Always provide fallback behavior, never panic on GIL errors
 //!
 //! ## Common Pitfalls and Solutions
@@ -675,6 +673,10 @@ fn dict_to_extraction_result(_py: Python<'_>, dict: &Bound<'_, PyAny>) -> Result
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     })
 }
 
@@ -830,6 +832,7 @@ fn extract_tables(obj: &Bound<'_, PyAny>) -> Result<Vec<Table>> {
             cells,
             markdown,
             page_number,
+            column_types: None,
         });
     }
 
@@ -837,7 +840,7 @@ fn extract_tables(obj: &Bound<'_, PyAny>) -> Result<Vec<Table>> {
 }
 
 /// Convert Python value to serde_json::Value.
-fn python_to_json(obj: &Bound<'_, PyAny>) -> Result<serde_json::Value> {
+pub(crate) fn python_to_json(obj: &Bound<'_, PyAny>) -> Result<serde_json::Value> {
     if obj.is_none() {
         Ok(serde_json::Value::Null)
     } else if let Ok(b) = obj.extract::<bool>() {
@@ -939,17 +942,14 @@ pub fn register_ocr_backend(py: Python<'_>, backend: Py<PyAny>) -> PyResult<()>
     let arc_backend: Arc<dyn OcrBackend> = Arc::new(rust_backend);
 
     py.detach(|| {
-        let registry = get_ocr_backend_registry();
-        let mut registry = registry.write().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to acquire write lock on OCR registry: {}", e))
-        })?;
-
-        registry.register(arc_backend).map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to register OCR backend '{}': {}",
-                backend_name, e
-            ))
-        })
+        get_ocr_backend_registry()
+            .update(|registry| registry.register(arc_backend))
+            .map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!(
+                    "Failed to register OCR backend '{}': {}",
+                    backend_name, e
+                ))
+            })
     })?;
 
     Ok(())
@@ -1004,6 +1004,7 @@ impl PythonPostProcessor {
                 "early" => ProcessingStage::Early,
                 "middle" => ProcessingStage::Middle,
                 "late" => ProcessingStage::Late,
+                "chunk" => ProcessingStage::Chunk,
                 _ => ProcessingStage::Middle,
             }
         } else {
@@ -1268,20 +1269,14 @@ pub fn register_post_processor(py: Python<'_>, processor: Py<PyAny>) -> PyResult
     let arc_processor: Arc<dyn PostProcessor> = Arc::new(rust_processor);
 
     py.detach(|| {
-        let registry = get_post_processor_registry();
-        let mut registry = registry.write().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to acquire write lock on PostProcessor registry: {}",
-                e
-            ))
-        })?;
-
-        registry.register(arc_processor, 0).map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to register PostProcessor '{}': {}",
-                processor_name, e
-            ))
-        })
+        get_post_processor_registry()
+            .update(|registry| registry.register(arc_processor, 0))
+            .map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!(
+                    "Failed to register PostProcessor '{}': {}",
+                    processor_name, e
+                ))
+            })
     })?;
 
     Ok(())
@@ -1315,17 +1310,14 @@ pub fn register_post_processor(py: Python<'_>, processor: Py<PyAny>) -> PyResult
 #[pyfunction]
 pub fn unregister_post_processor(py: Python<'_>, name: &str) -> PyResult<()> {
     py.detach(|| {
-        let registry = get_post_processor_registry();
-        let mut registry = registry.write().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to acquire write lock on PostProcessor registry: {}",
-                e
-            ))
-        })?;
-
-        registry.remove(name).map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to unregister PostProcessor '{}': {}", name, e))
-        })
+        get_post_processor_registry()
+            .update(|registry| registry.remove(name))
+            .map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!(
+                    "Failed to unregister PostProcessor '{}': {}",
+                    name, e
+                ))
+            })
     })?;
 
     Ok(())
@@ -1347,17 +1339,11 @@ pub fn unregister_post_processor(py: Python<'_>, name: &str) -> PyResult<()> {
 #[pyfunction]
 pub fn clear_post_processors(py: Python<'_>) -> PyResult<()> {
     py.detach(|| {
-        let registry = get_post_processor_registry();
-        let mut registry = registry.write().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to acquire write lock on PostProcessor registry: {}",
-                e
-            ))
-        })?;
-
-        registry.shutdown_all().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to clear PostProcessor registry: {}", e))
-        })
+        get_post_processor_registry()
+            .update(|registry| registry.shutdown_all())
+            .map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to clear PostProcessor registry: {}", e))
+            })
     })?;
 
     Ok(())
@@ -1605,20 +1591,14 @@ pub fn register_validator(py: Python<'_>, validator: Py<PyAny>) -> PyResult<()>
     let arc_validator: Arc<dyn Validator> = Arc::new(rust_validator);
 
     py.detach(|| {
-        let registry = get_validator_registry();
-        let mut registry = registry.write().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to acquire write lock on Validator registry: {}",
-                e
-            ))
-        })?;
-
-        registry.register(arc_validator).map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to register Validator '{}': {}",
-                validator_name, e
-            ))
-        })
+        get_validator_registry()
+            .update(|registry| registry.register(arc_validator))
+            .map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!(
+                    "Failed to register Validator '{}': {}",
+                    validator_name, e
+                ))
+            })
     })?;
 
     Ok(())
@@ -1652,17 +1632,11 @@ pub fn register_validator(py: Python<'_>, validator: Py<PyAny>) -> PyResult<()>
 #[pyfunction]
 pub fn unregister_validator(py: Python<'_>, name: &str) -> PyResult<()> {
     py.detach(|| {
-        let registry = get_validator_registry();
-        let mut registry = registry.write().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to acquire write lock on Validator registry: {}",
-                e
-            ))
-        })?;
-
-        registry.remove(name).map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to unregister Validator '{}': {}", name, e))
-        })
+        get_validator_registry()
+            .update(|registry| registry.remove(name))
+            .map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to unregister Validator '{}': {}", name, e))
+            })
     })?;
 
     Ok(())
@@ -1684,17 +1658,11 @@ pub fn unregister_validator(py: Python<'_>, name: &str) -> PyResult<()> {
 #[pyfunction]
 pub fn clear_validators(py: Python<'_>) -> PyResult<()> {
     py.detach(|| {
-        let registry = get_validator_registry();
-        let mut registry = registry.write().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!(
-                "Failed to acquire write lock on Validator registry: {}",
-                e
-            ))
-        })?;
-
-        registry.shutdown_all().map_err(|e| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to clear Validator registry: {}", e))
-        })
+        get_validator_registry()
+            .update(|registry| registry.shutdown_all())
+            .map_err(|e| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to clear Validator registry: {}", e))
+            })
     })?;
 
     Ok(())
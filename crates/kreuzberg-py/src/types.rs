@@ -322,6 +322,22 @@ impl ExtractionResult {
                 if let Some(desc) = &img.description {
                     img_dict.set_item("description", desc)?;
                 }
+                if let Some(hash) = &img.perceptual_hash {
+                    img_dict.set_item("perceptual_hash", hash)?;
+                }
+                if let Some(blob) = &img.blob {
+                    let blob_dict = PyDict::new(py);
+                    blob_dict.set_item("hash", &blob.hash)?;
+                    blob_dict.set_item("path", &blob.path)?;
+                    blob_dict.set_item("size", blob.size)?;
+                    img_dict.set_item("blob", blob_dict)?;
+                }
+                if let Some(caption) = &img.caption {
+                    img_dict.set_item("caption", caption)?;
+                }
+                if let Some(figure_index) = img.figure_index {
+                    img_dict.set_item("figure_index", figure_index)?;
+                }
 
                 if let Some(ocr) = img.ocr_result {
                     let ocr_py = Self::from_rust(*ocr, py)?;
@@ -468,6 +484,10 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
             };
 
             let py_result = ExtractionResult::from_rust(rust_result, py).expect("conversion should succeed");
@@ -494,6 +514,10 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
             };
             rust_result
                 .metadata
@@ -523,6 +547,7 @@ mod tests {
 ///     cells (list[list[str]]): Table data as nested lists (rows of columns)
 ///     markdown (str): Markdown representation of the table
 ///     page_number (int): Page number where table was found
+///     column_types (list[str] | None): Inferred column types ("string", "number", "date"), if known
 ///
 /// Example:
 ///     >>> result = extract_file_sync("document.pdf", None, ExtractionConfig())
@@ -539,6 +564,9 @@ pub struct ExtractedTable {
 
     #[pyo3(get)]
     pub page_number: usize,
+
+    #[pyo3(get)]
+    pub column_types: Option<Vec<String>>,
 }
 
 #[pymethods]
@@ -586,6 +614,9 @@ impl ExtractedTable {
             cells: cells.unbind(),
             markdown: table.markdown,
             page_number: table.page_number,
+            column_types: table
+                .column_types
+                .map(|types| types.iter().map(|t| t.as_str().to_string()).collect()),
         })
     }
 }
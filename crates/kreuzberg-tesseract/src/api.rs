@@ -9,10 +9,14 @@ use std::os::raw::{c_char, c_double, c_float, c_int, c_void};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+/// `OEM_DEFAULT`, the engine mode `TessBaseAPIInit3` uses.
+const OEM_DEFAULT: c_int = 3;
+
 #[derive(Clone)]
 pub struct TesseractConfiguration {
     datapath: String,
     language: String,
+    init_variables: Vec<(String, String)>,
     variables: HashMap<String, String>,
 }
 
@@ -41,6 +45,7 @@ impl TesseractAPI {
             config: Arc::new(Mutex::new(TesseractConfiguration {
                 datapath: String::new(),
                 language: String::new(),
+                init_variables: Vec::new(),
                 variables: HashMap::new(),
             })),
         }
@@ -87,6 +92,71 @@ impl TesseractAPI {
         }
     }
 
+    /// Initializes the Tesseract engine and sets variables before the language data is loaded.
+    ///
+    /// Parameters that are only read while initializing, such as `user_words_file` and
+    /// `user_patterns_file`, have no effect when set with [`set_variable`](Self::set_variable)
+    /// afterwards and must be passed here.
+    ///
+    /// # Arguments
+    ///
+    /// * `datapath` - Path to the directory containing Tesseract data files.
+    /// * `language` - Language code (e.g., "eng" for English).
+    /// * `variables` - Variable names and values to set during initialization.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if initialization is successful, otherwise returns an error.
+    pub fn init_with_variables<P: AsRef<Path>>(
+        &self,
+        datapath: P,
+        language: &str,
+        variables: &[(&str, &str)],
+    ) -> Result<()> {
+        let datapath_str = datapath.as_ref().to_str().unwrap().to_owned();
+        let language_str = language.to_owned();
+
+        {
+            let mut config = self.config.lock().map_err(|_| TesseractError::MutexLockError)?;
+            config.datapath = datapath_str.clone();
+            config.language = language_str.clone();
+            config.init_variables = variables
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+        }
+
+        let datapath = CString::new(datapath_str).unwrap();
+        let language = CString::new(language_str).unwrap();
+        let names: Vec<_> = variables.iter().map(|(name, _)| CString::new(*name).unwrap()).collect();
+        let values: Vec<_> = variables
+            .iter()
+            .map(|(_, value)| CString::new(*value).unwrap())
+            .collect();
+        let name_ptrs: Vec<_> = names.iter().map(|cs| cs.as_ptr()).collect();
+        let value_ptrs: Vec<_> = values.iter().map(|cs| cs.as_ptr()).collect();
+        let handle = self.handle.lock().map_err(|_| TesseractError::MutexLockError)?;
+        let result = unsafe {
+            TessBaseAPIInit4(
+                *handle,
+                datapath.as_ptr(),
+                language.as_ptr(),
+                OEM_DEFAULT,
+                std::ptr::null(),
+                0,
+                name_ptrs.as_ptr(),
+                value_ptrs.as_ptr(),
+                variables.len(),
+                0,
+            )
+        };
+        if result != 0 {
+            Err(TesseractError::InitError)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Gets the confidence values for all recognized words.
     ///
     /// # Returns
@@ -950,6 +1020,10 @@ impl TesseractAPI {
                 oem,
                 config_ptr_ptrs.as_ptr(),
                 config_ptrs.len() as c_int,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+                0,
             )
         };
         if result != 0 {
@@ -1247,7 +1321,18 @@ impl Clone for TesseractAPI {
         };
 
         if !config.datapath.is_empty() {
-            new_api.init(&config.datapath, &config.language).unwrap();
+            if config.init_variables.is_empty() {
+                new_api.init(&config.datapath, &config.language).unwrap();
+            } else {
+                let init_variables: Vec<_> = config
+                    .init_variables
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str()))
+                    .collect();
+                new_api
+                    .init_with_variables(&config.datapath, &config.language, &init_variables)
+                    .unwrap();
+            }
             for (name, value) in &config.variables {
                 new_api.set_variable(name, value).unwrap();
             }
@@ -1324,6 +1409,10 @@ unsafe extern "C" {
         oem: c_int,
         configs: *const *const c_char,
         configs_size: c_int,
+        vars_vec: *const *const c_char,
+        vars_values: *const *const c_char,
+        vars_vec_size: usize,
+        set_only_non_debug_params: c_int,
     ) -> c_int;
     fn TessBaseAPIInit5(
         handle: *mut c_void,
@@ -22,6 +22,8 @@ use wasm_bindgen::prelude::*;
 /// - `Plugin` → Plugin-specific error
 /// - `LockPoisoned` → Lock poisoning (internal error)
 /// - `UnsupportedFormat` → Unsupported MIME type
+/// - `InputRejected` → Input refused by the configured input policy
+/// - `Timeout` → Extraction stage exceeded its time budget
 /// - `Other` → Generic error
 pub fn convert_error(err: KreuzbergError) -> JsValue {
     use kreuzberg::KreuzbergError;
@@ -57,6 +59,10 @@ pub fn convert_error(err: KreuzbergError) -> JsValue {
             ("UnsupportedFormatError", format!("Unsupported format: {}", format))
         }
 
+        err @ KreuzbergError::InputRejected { .. } => ("InputRejectedError", err.to_string()),
+
+        err @ KreuzbergError::Timeout { .. } => ("TimeoutError", err.to_string()),
+
         KreuzbergError::Other(msg) => ("Error", msg),
     };
 
@@ -130,6 +130,8 @@ pub fn get_mime_from_extension(extension: String) -> Option<String> {
         "rst" => Some("text/x-rst".to_string()),
         "org" => Some("text/x-org".to_string()),
         "epub" => Some("application/epub+zip".to_string()),
+        "mobi" => Some("application/x-mobipocket-ebook".to_string()),
+        "azw" => Some("application/vnd.amazon.ebook".to_string()),
         "rtf" => Some("application/rtf".to_string()),
         "bib" => Some("application/x-bibtex".to_string()),
         "ipynb" => Some("application/x-ipynb+json".to_string()),
@@ -41,61 +41,11 @@ use kreuzberg::plugins::{OcrBackend, Plugin, PostProcessor, ProcessingStage, Val
 use kreuzberg::{ExtractionConfig, ExtractionResult, KreuzbergError, OcrConfig};
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 #[allow(unused_imports)]
 use wasm_bindgen_futures::JsFuture;
 
-// ============================================================================
-// LOCK POISONING RECOVERY HELPERS
-// ============================================================================
-
-/// Attempt to acquire a write lock with detailed error context and poisoning recovery.
-///
-/// When lock poisoning occurs, this function:
-/// 1. Extracts the inner guard (which may still be usable)
-/// 2. Logs a warning to the browser console about the poisoning
-/// 3. Returns the guard for recovery attempts
-///
-/// # Context Provided
-/// - Which registry failed (POST_PROCESSORS, VALIDATORS, or OCR_BACKENDS)
-/// - Clear indication that the data may be in an inconsistent state
-fn acquire_write_lock<'a, T>(registry: &'a RwLock<T>, registry_name: &str) -> Result<RwLockWriteGuard<'a, T>, String> {
-    match registry.write() {
-        Ok(guard) => Ok(guard),
-        Err(poison) => {
-            // SAFETY: We're extracting the guard from the poison error. The guard contains
-            // the data even though the lock was poisoned. This is safe as long as the lock
-            // holder didn't corrupt the data before panicking.
-            let guard = poison.into_inner();
-            web_sys::console::warn_1(
-                &format!(
-                    "WARN: {} registry write lock was poisoned but recovered; data may be in inconsistent state",
-                    registry_name
-                )
-                .into(),
-            );
-            Ok(guard)
-        }
-    }
-}
-
-/// Attempt to acquire a read lock with detailed error context about poisoning.
-///
-/// For read operations, we cannot safely recover from poisoning since we can't verify
-/// the data is uncorrupted. Returns a contextual error message indicating:
-/// - Which registry failed
-/// - That the lock is poisoned
-/// - A hint that a previous operation may have panicked
-fn acquire_read_lock<'a, T>(registry: &'a RwLock<T>, registry_name: &str) -> Result<RwLockReadGuard<'a, T>, String> {
-    registry.read().map_err(|_| {
-        format!(
-            "Failed to acquire {} registry read lock: lock poisoned (possible panic in previous operation)",
-            registry_name
-        )
-    })
-}
-
 // ============================================================================
 // WASM SINGLE-THREADED FUTURE WRAPPER
 // ============================================================================
@@ -410,6 +360,7 @@ pub fn register_post_processor(processor: JsValue) -> Result<(), JsValue> {
             match stage_str.to_lowercase().as_str() {
                 "early" => ProcessingStage::Early,
                 "late" => ProcessingStage::Late,
+                "chunk" => ProcessingStage::Chunk,
                 _ => ProcessingStage::Middle,
             }
         } else {
@@ -421,11 +372,8 @@ pub fn register_post_processor(processor: JsValue) -> Result<(), JsValue> {
 
     // Create wrapper and register
     let wrapper = JsPostProcessorWrapper::new(processor, name.clone(), stage);
-    let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-    let mut registry = acquire_write_lock(&registry, "POST_PROCESSORS").map_err(|e| JsValue::from_str(&e))?;
-
-    registry
-        .register(Arc::new(wrapper), 0)
+    kreuzberg::plugins::registry::get_post_processor_registry()
+        .update(|registry| registry.register(Arc::new(wrapper), 0))
         .map_err(|e| JsValue::from_str(&format!("Registration failed: {}", e)))
 }
 
@@ -446,11 +394,8 @@ pub fn register_post_processor(processor: JsValue) -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn unregister_post_processor(name: String) -> Result<(), JsValue> {
-    let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-    let mut registry = acquire_write_lock(&registry, "POST_PROCESSORS").map_err(|e| JsValue::from_str(&e))?;
-
-    registry
-        .remove(&name)
+    kreuzberg::plugins::registry::get_post_processor_registry()
+        .update(|registry| registry.remove(&name))
         .map_err(|e| JsValue::from_str(&format!("Unregistration failed: {}", e)))
 }
 
@@ -467,17 +412,14 @@ pub fn unregister_post_processor(name: String) -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn clear_post_processors() -> Result<(), JsValue> {
-    let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-    let mut registry = acquire_write_lock(&registry, "POST_PROCESSORS").map_err(|e| JsValue::from_str(&e))?;
-
-    let names = registry.list();
-    for name in names {
-        registry
-            .remove(&name)
-            .map_err(|e| JsValue::from_str(&format!("Failed to remove post-processor: {}", e)))?;
-    }
-
-    Ok(())
+    kreuzberg::plugins::registry::get_post_processor_registry()
+        .update(|registry| {
+            for name in registry.list() {
+                registry.remove(&name)?;
+            }
+            Ok(())
+        })
+        .map_err(|e| JsValue::from_str(&format!("Failed to remove post-processor: {}", e)))
 }
 
 /// List all registered post-processor names.
@@ -494,8 +436,7 @@ pub fn clear_post_processors() -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn list_post_processors() -> Result<js_sys::Array, JsValue> {
-    let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-    let registry = acquire_read_lock(&registry, "POST_PROCESSORS").map_err(|e| JsValue::from_str(&e))?;
+    let registry = kreuzberg::plugins::registry::get_post_processor_registry().snapshot();
 
     let names = registry.list();
     let arr = js_sys::Array::new();
@@ -715,11 +656,8 @@ pub fn register_validator(validator: JsValue) -> Result<(), JsValue> {
 
     // Create wrapper and register
     let wrapper = JsValidatorWrapper::new(validator, name.clone(), priority);
-    let registry = kreuzberg::plugins::registry::get_validator_registry();
-    let mut registry = acquire_write_lock(&registry, "VALIDATORS").map_err(|e| JsValue::from_str(&e))?;
-
-    registry
-        .register(Arc::new(wrapper))
+    kreuzberg::plugins::registry::get_validator_registry()
+        .update(|registry| registry.register(Arc::new(wrapper)))
         .map_err(|e| JsValue::from_str(&format!("Registration failed: {}", e)))
 }
 
@@ -740,11 +678,8 @@ pub fn register_validator(validator: JsValue) -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn unregister_validator(name: String) -> Result<(), JsValue> {
-    let registry = kreuzberg::plugins::registry::get_validator_registry();
-    let mut registry = acquire_write_lock(&registry, "VALIDATORS").map_err(|e| JsValue::from_str(&e))?;
-
-    registry
-        .remove(&name)
+    kreuzberg::plugins::registry::get_validator_registry()
+        .update(|registry| registry.remove(&name))
         .map_err(|e| JsValue::from_str(&format!("Unregistration failed: {}", e)))
 }
 
@@ -761,17 +696,14 @@ pub fn unregister_validator(name: String) -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn clear_validators() -> Result<(), JsValue> {
-    let registry = kreuzberg::plugins::registry::get_validator_registry();
-    let mut registry = acquire_write_lock(&registry, "VALIDATORS").map_err(|e| JsValue::from_str(&e))?;
-
-    let names = registry.list();
-    for name in names {
-        registry
-            .remove(&name)
-            .map_err(|e| JsValue::from_str(&format!("Failed to remove validator: {}", e)))?;
-    }
-
-    Ok(())
+    kreuzberg::plugins::registry::get_validator_registry()
+        .update(|registry| {
+            for name in registry.list() {
+                registry.remove(&name)?;
+            }
+            Ok(())
+        })
+        .map_err(|e| JsValue::from_str(&format!("Failed to remove validator: {}", e)))
 }
 
 /// List all registered validator names.
@@ -788,8 +720,7 @@ pub fn clear_validators() -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn list_validators() -> Result<js_sys::Array, JsValue> {
-    let registry = kreuzberg::plugins::registry::get_validator_registry();
-    let registry = acquire_read_lock(&registry, "VALIDATORS").map_err(|e| JsValue::from_str(&e))?;
+    let registry = kreuzberg::plugins::registry::get_validator_registry().snapshot();
 
     let names = registry.list();
     let arr = js_sys::Array::new();
@@ -957,6 +888,10 @@ impl OcrBackend for JsOcrBackendWrapper {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -993,6 +928,10 @@ impl OcrBackend for JsOcrBackendWrapper {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -1006,6 +945,10 @@ impl OcrBackend for JsOcrBackendWrapper {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -1107,11 +1050,8 @@ pub fn register_ocr_backend(backend: JsValue) -> Result<(), JsValue> {
 
     // Create wrapper and register
     let wrapper = JsOcrBackendWrapper::new(backend, name.clone(), supported_languages);
-    let registry = kreuzberg::plugins::registry::get_ocr_backend_registry();
-    let mut registry = acquire_write_lock(&registry, "OCR_BACKENDS").map_err(|e| JsValue::from_str(&e))?;
-
-    registry
-        .register(Arc::new(wrapper))
+    kreuzberg::plugins::registry::get_ocr_backend_registry()
+        .update(|registry| registry.register(Arc::new(wrapper)))
         .map_err(|e| JsValue::from_str(&format!("Registration failed: {}", e)))
 }
 
@@ -1132,11 +1072,8 @@ pub fn register_ocr_backend(backend: JsValue) -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn unregister_ocr_backend(name: String) -> Result<(), JsValue> {
-    let registry = kreuzberg::plugins::registry::get_ocr_backend_registry();
-    let mut registry = acquire_write_lock(&registry, "OCR_BACKENDS").map_err(|e| JsValue::from_str(&e))?;
-
-    registry
-        .remove(&name)
+    kreuzberg::plugins::registry::get_ocr_backend_registry()
+        .update(|registry| registry.remove(&name))
         .map_err(|e| JsValue::from_str(&format!("Unregistration failed: {}", e)))
 }
 
@@ -1153,17 +1090,14 @@ pub fn unregister_ocr_backend(name: String) -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn clear_ocr_backends() -> Result<(), JsValue> {
-    let registry = kreuzberg::plugins::registry::get_ocr_backend_registry();
-    let mut registry = acquire_write_lock(&registry, "OCR_BACKENDS").map_err(|e| JsValue::from_str(&e))?;
-
-    let names = registry.list();
-    for name in names {
-        registry
-            .remove(&name)
-            .map_err(|e| JsValue::from_str(&format!("Failed to remove OCR backend: {}", e)))?;
-    }
-
-    Ok(())
+    kreuzberg::plugins::registry::get_ocr_backend_registry()
+        .update(|registry| {
+            for name in registry.list() {
+                registry.remove(&name)?;
+            }
+            Ok(())
+        })
+        .map_err(|e| JsValue::from_str(&format!("Failed to remove OCR backend: {}", e)))
 }
 
 /// List all registered OCR backend names.
@@ -1180,8 +1114,7 @@ pub fn clear_ocr_backends() -> Result<(), JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn list_ocr_backends() -> Result<js_sys::Array, JsValue> {
-    let registry = kreuzberg::plugins::registry::get_ocr_backend_registry();
-    let registry = acquire_read_lock(&registry, "OCR_BACKENDS").map_err(|e| JsValue::from_str(&e))?;
+    let registry = kreuzberg::plugins::registry::get_ocr_backend_registry().snapshot();
 
     let names = registry.list();
     let arr = js_sys::Array::new();
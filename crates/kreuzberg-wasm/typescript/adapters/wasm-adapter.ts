@@ -209,6 +209,7 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
 						cells: t.cells as string[][],
 						markdown: t.markdown,
 						pageNumber: t.pageNumber,
+						...(Array.isArray(t.columnTypes) ? { columnTypes: t.columnTypes as string[] } : {}),
 					});
 				}
 			}
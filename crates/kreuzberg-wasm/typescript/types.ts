@@ -39,6 +39,8 @@ export interface ExtractionConfig {
 	images?: ImageExtractionConfig;
 	/** Page extraction configuration */
 	pages?: PageExtractionConfig;
+	/** CSV/TSV parsing options */
+	csvOptions?: CsvConfig;
 	/** Language detection configuration */
 	languageDetection?: LanguageDetectionConfig;
 	/** PDF extraction options */
@@ -111,6 +113,12 @@ export interface ImageExtractionConfig {
 	minDpi?: number;
 	/** Maximum DPI threshold */
 	maxDpi?: number;
+	/** Compute a perceptual hash for each extracted image */
+	perceptualHash?: boolean;
+	/** Drop near-duplicate images (repeated logos, watermarks) */
+	deduplicateImages?: boolean;
+	/** Maximum differing hash bits for two images to count as duplicates */
+	duplicateHashDistance?: number;
 }
 
 /**
@@ -133,6 +141,16 @@ export interface PageExtractionConfig {
 	enabled?: boolean;
 }
 
+/**
+ * CSV/TSV parsing configuration
+ */
+export interface CsvConfig {
+	/** Single-character field delimiter (auto-detected when omitted) */
+	delimiter?: string;
+	/** Maximum number of data rows to parse */
+	maxRows?: number;
+}
+
 /**
  * Language detection configuration
  */
@@ -210,6 +228,8 @@ export interface Table {
 	headers?: string[];
 	/** Table rows */
 	rows?: string[][];
+	/** Inferred column types ("string", "number" or "date"), if available */
+	columnTypes?: string[] | null;
 }
 
 /**
@@ -272,6 +292,8 @@ export interface ExtractedImage {
 	description?: string | null;
 	/** Optional OCR result from the image */
 	ocrResult?: ExtractionResult | string | null;
+	/** Perceptual hash when image hashing is enabled */
+	perceptualHash?: string | null;
 }
 
 /**
@@ -43,6 +43,21 @@ enum PdfiumLinkStrategy {
     System,
 }
 
+// ============================================================================
+// GRPC CODE GENERATION
+// ============================================================================
+
+/// Generate the gRPC service and message types (grpc-server feature, requires protoc)
+#[cfg(feature = "grpc-server")]
+fn compile_grpc_protos() {
+    println!("cargo:rerun-if-changed=proto/kreuzberg/v1/kreuzberg.proto");
+
+    tonic_prost_build::configure()
+        .build_client(false)
+        .compile_protos(&["proto/kreuzberg/v1/kreuzberg.proto"], &["proto"])
+        .unwrap_or_else(|e| panic!("Failed to compile gRPC protos (is protoc installed?): {}", e));
+}
+
 // ============================================================================
 // MAIN BUILD ORCHESTRATION
 // ============================================================================
@@ -53,6 +68,9 @@ fn main() {
 
     println!("cargo::rustc-check-cfg=cfg(coverage)");
 
+    #[cfg(feature = "grpc-server")]
+    compile_grpc_protos();
+
     // Skip pdfium linking if the pdf feature is not enabled
     if !cfg!(feature = "pdf") {
         tracing::debug!("PDF feature not enabled, skipping pdfium linking");
@@ -352,6 +370,8 @@ fn get_pdfium_url_and_lib(target: &str) -> (String, String) {
         .filter(|v| !v.is_empty())
         .unwrap_or_else(|| get_latest_version("bblanchon/pdfium-binaries"));
     tracing::debug!("Using pdfium-binaries version: {}", version);
+    // Reported by kreuzberg::features::compiled_features()
+    println!("cargo:rustc-env=KREUZBERG_PDFIUM_VERSION={}", version);
 
     let url = format!(
         "https://github.com/bblanchon/pdfium-binaries/releases/download/chromium/{}/pdfium-{}-{}.tgz",
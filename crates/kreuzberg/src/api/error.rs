@@ -6,7 +6,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 
-use crate::error::KreuzbergError;
+use crate::error::{InputRejection, KreuzbergError};
 
 use super::types::ErrorResponse;
 
@@ -34,6 +34,8 @@ impl ApiError {
             KreuzbergError::Plugin { .. } => "PluginError",
             KreuzbergError::LockPoisoned(_) => "LockPoisonedError",
             KreuzbergError::UnsupportedFormat(_) => "UnsupportedFormatError",
+            KreuzbergError::InputRejected { .. } => "InputRejectedError",
+            KreuzbergError::Timeout { .. } => "TimeoutError",
             KreuzbergError::Other(_) => "Error",
         };
 
@@ -75,6 +77,17 @@ impl From<KreuzbergError> for ApiError {
         match &error {
             KreuzbergError::Validation { .. } => Self::validation(error),
             KreuzbergError::Parsing { .. } | KreuzbergError::Ocr { .. } => Self::unprocessable(error),
+            KreuzbergError::InputRejected { reason, .. } => {
+                let status = match reason {
+                    InputRejection::FileTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+                    InputRejection::MimeTypeNotAllowed | InputRejection::ContentMismatch => {
+                        StatusCode::UNSUPPORTED_MEDIA_TYPE
+                    }
+                    InputRejection::Encrypted => StatusCode::UNPROCESSABLE_ENTITY,
+                };
+                Self::new(status, error)
+            }
+            KreuzbergError::Timeout { .. } => Self::new(StatusCode::GATEWAY_TIMEOUT, error),
             _ => Self::internal(error),
         }
     }
@@ -0,0 +1,664 @@
+//! 1D barcode decoding along sampled rows and columns.
+//!
+//! Each scan line is converted to alternating light/dark run lengths and matched against the
+//! EAN/UPC, Code 128 and Code 39 bar patterns in both reading directions. A payload is only
+//! reported once several scan lines agree on it.
+
+use super::BitMatrix;
+use crate::types::BarcodeFormat;
+
+/// Approximate number of rows (and columns) sampled per image.
+const SCAN_LINES: usize = 300;
+
+/// Scan lines that must decode the same payload before it is reported.
+const MIN_AGREEING_LINES: usize = 2;
+
+/// Mean deviation from a bar pattern allowed per pixel of the pattern.
+const MAX_AVERAGE_VARIANCE: f32 = 0.4;
+
+/// Deviation allowed for a single bar or space, in modules.
+const MAX_INDIVIDUAL_VARIANCE: f32 = 0.7;
+
+/// Light margin required on both sides of a symbol, in modules.
+const QUIET_ZONE_MODULES: f32 = 3.0;
+
+/// EAN/UPC digit widths in L-code order (light, dark, light, dark).
+const DIGIT_PATTERNS: [[u8; 4]; 10] = [
+    [3, 2, 1, 1],
+    [2, 2, 2, 1],
+    [2, 1, 2, 2],
+    [1, 4, 1, 1],
+    [1, 1, 3, 2],
+    [1, 2, 3, 1],
+    [1, 1, 1, 4],
+    [1, 3, 1, 2],
+    [1, 2, 1, 3],
+    [3, 1, 1, 2],
+];
+
+/// L/G parity of the six left-hand EAN-13 digits (bit 5 = first digit), indexed by the
+/// implied leading digit.
+const EAN13_PARITY: [u8; 10] = [0x00, 0x0B, 0x0D, 0x0E, 0x13, 0x19, 0x1C, 0x15, 0x16, 0x1A];
+
+/// Code 128 symbol widths (dark first); 106 is the stop pattern with its final bar.
+const CODE128_PATTERNS: [&[u8]; 107] = [
+    &[2, 1, 2, 2, 2, 2],
+    &[2, 2, 2, 1, 2, 2],
+    &[2, 2, 2, 2, 2, 1],
+    &[1, 2, 1, 2, 2, 3],
+    &[1, 2, 1, 3, 2, 2],
+    &[1, 3, 1, 2, 2, 2],
+    &[1, 2, 2, 2, 1, 3],
+    &[1, 2, 2, 3, 1, 2],
+    &[1, 3, 2, 2, 1, 2],
+    &[2, 2, 1, 2, 1, 3],
+    &[2, 2, 1, 3, 1, 2],
+    &[2, 3, 1, 2, 1, 2],
+    &[1, 1, 2, 2, 3, 2],
+    &[1, 2, 2, 1, 3, 2],
+    &[1, 2, 2, 2, 3, 1],
+    &[1, 1, 3, 2, 2, 2],
+    &[1, 2, 3, 1, 2, 2],
+    &[1, 2, 3, 2, 2, 1],
+    &[2, 2, 3, 2, 1, 1],
+    &[2, 2, 1, 1, 3, 2],
+    &[2, 2, 1, 2, 3, 1],
+    &[2, 1, 3, 2, 1, 2],
+    &[2, 2, 3, 1, 1, 2],
+    &[3, 1, 2, 1, 3, 1],
+    &[3, 1, 1, 2, 2, 2],
+    &[3, 2, 1, 1, 2, 2],
+    &[3, 2, 1, 2, 2, 1],
+    &[3, 1, 2, 2, 1, 2],
+    &[3, 2, 2, 1, 1, 2],
+    &[3, 2, 2, 2, 1, 1],
+    &[2, 1, 2, 1, 2, 3],
+    &[2, 1, 2, 3, 2, 1],
+    &[2, 3, 2, 1, 2, 1],
+    &[1, 1, 1, 3, 2, 3],
+    &[1, 3, 1, 1, 2, 3],
+    &[1, 3, 1, 3, 2, 1],
+    &[1, 1, 2, 3, 1, 3],
+    &[1, 3, 2, 1, 1, 3],
+    &[1, 3, 2, 3, 1, 1],
+    &[2, 1, 1, 3, 1, 3],
+    &[2, 3, 1, 1, 1, 3],
+    &[2, 3, 1, 3, 1, 1],
+    &[1, 1, 2, 1, 3, 3],
+    &[1, 1, 2, 3, 3, 1],
+    &[1, 3, 2, 1, 3, 1],
+    &[1, 1, 3, 1, 2, 3],
+    &[1, 1, 3, 3, 2, 1],
+    &[1, 3, 3, 1, 2, 1],
+    &[3, 1, 3, 1, 2, 1],
+    &[2, 1, 1, 3, 3, 1],
+    &[2, 3, 1, 1, 3, 1],
+    &[2, 1, 3, 1, 1, 3],
+    &[2, 1, 3, 3, 1, 1],
+    &[2, 1, 3, 1, 3, 1],
+    &[3, 1, 1, 1, 2, 3],
+    &[3, 1, 1, 3, 2, 1],
+    &[3, 3, 1, 1, 2, 1],
+    &[3, 1, 2, 1, 1, 3],
+    &[3, 1, 2, 3, 1, 1],
+    &[3, 3, 2, 1, 1, 1],
+    &[3, 1, 4, 1, 1, 1],
+    &[2, 2, 1, 4, 1, 1],
+    &[4, 3, 1, 1, 1, 1],
+    &[1, 1, 1, 2, 2, 4],
+    &[1, 1, 1, 4, 2, 2],
+    &[1, 2, 1, 1, 2, 4],
+    &[1, 2, 1, 4, 2, 1],
+    &[1, 4, 1, 1, 2, 2],
+    &[1, 4, 1, 2, 2, 1],
+    &[1, 1, 2, 2, 1, 4],
+    &[1, 1, 2, 4, 1, 2],
+    &[1, 2, 2, 1, 1, 4],
+    &[1, 2, 2, 4, 1, 1],
+    &[1, 4, 2, 1, 1, 2],
+    &[1, 4, 2, 2, 1, 1],
+    &[2, 4, 1, 2, 1, 1],
+    &[2, 2, 1, 1, 1, 4],
+    &[4, 1, 3, 1, 1, 1],
+    &[2, 4, 1, 1, 1, 2],
+    &[1, 3, 4, 1, 1, 1],
+    &[1, 1, 1, 2, 4, 2],
+    &[1, 2, 1, 1, 4, 2],
+    &[1, 2, 1, 2, 4, 1],
+    &[1, 1, 4, 2, 1, 2],
+    &[1, 2, 4, 1, 1, 2],
+    &[1, 2, 4, 2, 1, 1],
+    &[4, 1, 1, 2, 1, 2],
+    &[4, 2, 1, 1, 1, 2],
+    &[4, 2, 1, 2, 1, 1],
+    &[2, 1, 2, 1, 4, 1],
+    &[2, 1, 4, 1, 2, 1],
+    &[4, 1, 2, 1, 2, 1],
+    &[1, 1, 1, 1, 4, 3],
+    &[1, 1, 1, 3, 4, 1],
+    &[1, 3, 1, 1, 4, 1],
+    &[1, 1, 4, 1, 1, 3],
+    &[1, 1, 4, 3, 1, 1],
+    &[4, 1, 1, 1, 1, 3],
+    &[4, 1, 1, 3, 1, 1],
+    &[1, 1, 3, 1, 4, 1],
+    &[1, 1, 4, 1, 3, 1],
+    &[3, 1, 1, 1, 4, 1],
+    &[4, 1, 1, 1, 3, 1],
+    &[2, 1, 1, 4, 1, 2],
+    &[2, 1, 1, 2, 1, 4],
+    &[2, 1, 1, 2, 3, 2],
+    &[2, 3, 3, 1, 1, 1, 2],
+];
+
+const CODE128_START_A: usize = 103;
+const CODE128_START_C: usize = 105;
+const CODE128_STOP: usize = 106;
+
+/// Code 39 characters in the order of [`CODE39_PATTERNS`].
+const CODE39_ALPHABET: &[u8; 43] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-. $/+%";
+
+/// Code 39 wide-element masks (bit 8 is the first bar).
+const CODE39_PATTERNS: [u16; 43] = [
+    0x034, 0x121, 0x061, 0x160, 0x031, 0x130, 0x070, 0x025, 0x124, 0x064, 0x109, 0x049, 0x148, 0x019, 0x118, 0x058,
+    0x00D, 0x10C, 0x04C, 0x01C, 0x103, 0x043, 0x142, 0x013, 0x112, 0x052, 0x007, 0x106, 0x046, 0x016, 0x181, 0x0C1,
+    0x1C0, 0x091, 0x190, 0x0D0, 0x085, 0x184, 0x0C4, 0x0A8, 0x0A2, 0x08A, 0x02A,
+];
+
+const CODE39_START_STOP: u16 = 0x094;
+
+/// A decoded 1D barcode with its pixel bounds `(left, top, right, bottom)`, exclusive.
+pub(super) struct DecodedLinear {
+    pub(super) format: BarcodeFormat,
+    pub(super) payload: String,
+    pub(super) bounds: (usize, usize, usize, usize),
+}
+
+/// Format, payload and the run index just past the symbol.
+type RunDecode = (BarcodeFormat, String, usize);
+
+/// Decoder tried at each dark run of a scan line.
+type Decoder = fn(&[usize], usize) -> Option<RunDecode>;
+
+struct Hit {
+    code: DecodedLinear,
+    lines: usize,
+}
+
+/// Alternating run lengths of a scan line; even indices are light, odd indices dark.
+struct Runs {
+    lengths: Vec<usize>,
+    starts: Vec<usize>,
+}
+
+impl Runs {
+    fn new(line: impl Iterator<Item = bool>) -> Self {
+        let mut lengths = vec![0];
+        let mut starts = vec![0];
+        let mut dark = false;
+        for (i, pixel) in line.enumerate() {
+            if pixel != dark {
+                lengths.push(0);
+                starts.push(i);
+                dark = pixel;
+            }
+            if let Some(length) = lengths.last_mut() {
+                *length += 1;
+            }
+        }
+        Self { lengths, starts }
+    }
+}
+
+/// Find every 1D barcode in the matrix.
+pub(super) fn detect(matrix: &BitMatrix) -> Vec<DecodedLinear> {
+    let mut hits: Vec<Hit> = Vec::new();
+
+    let row_step = (matrix.height() / SCAN_LINES).max(1);
+    for y in (0..matrix.height()).step_by(row_step) {
+        let line: Vec<bool> = (0..matrix.width()).map(|x| matrix.get(x, y)).collect();
+        for (format, payload, start, end) in decode_line(&line) {
+            record(&mut hits, format, payload, (start, y, end, y + 1), row_step);
+        }
+    }
+
+    let column_step = (matrix.width() / SCAN_LINES).max(1);
+    for x in (0..matrix.width()).step_by(column_step) {
+        let line: Vec<bool> = (0..matrix.height()).map(|y| matrix.get(x, y)).collect();
+        for (format, payload, start, end) in decode_line(&line) {
+            record(&mut hits, format, payload, (x, start, x + 1, end), column_step);
+        }
+    }
+
+    hits.into_iter()
+        .filter(|hit| hit.lines >= MIN_AGREEING_LINES)
+        .map(|hit| hit.code)
+        .collect()
+}
+
+/// Merge a scan-line decode into an adjacent hit with the same payload, or start a new one.
+///
+/// Scan lines that cross the same bars belong to one symbol even when a scratch or stain
+/// interrupts them, so a hit reaches across gaps of up to half its length.
+fn record(
+    hits: &mut Vec<Hit>,
+    format: BarcodeFormat,
+    payload: String,
+    bounds: (usize, usize, usize, usize),
+    step: usize,
+) {
+    let (left, top, right, bottom) = bounds;
+    let adjacent = |hit: &&mut Hit| {
+        let (hit_left, hit_top, hit_right, hit_bottom) = hit.code.bounds;
+        let reach = (2 * step).max((hit_right - hit_left).max(hit_bottom - hit_top) / 2);
+        let overlaps_x = left <= hit_right && hit_left <= right;
+        let overlaps_y = top <= hit_bottom && hit_top <= bottom;
+        hit.code.format == format
+            && hit.code.payload == payload
+            && (overlaps_x || overlaps_y)
+            && left <= hit_right + reach
+            && hit_left <= right + reach
+            && top <= hit_bottom + reach
+            && hit_top <= bottom + reach
+    };
+    if let Some(hit) = hits.iter_mut().find(adjacent) {
+        let (hit_left, hit_top, hit_right, hit_bottom) = hit.code.bounds;
+        hit.code.bounds = (
+            hit_left.min(left),
+            hit_top.min(top),
+            hit_right.max(right),
+            hit_bottom.max(bottom),
+        );
+        hit.lines += 1;
+    } else {
+        hits.push(Hit {
+            code: DecodedLinear {
+                format,
+                payload,
+                bounds,
+            },
+            lines: 1,
+        });
+    }
+}
+
+/// Decode a scan line in both directions, returning pixel spans along the line.
+fn decode_line(line: &[bool]) -> Vec<(BarcodeFormat, String, usize, usize)> {
+    let mut found = decode_runs(&Runs::new(line.iter().copied()));
+    let reversed = decode_runs(&Runs::new(line.iter().rev().copied()));
+    found.extend(
+        reversed
+            .into_iter()
+            .map(|(format, payload, start, end)| (format, payload, line.len() - end, line.len() - start)),
+    );
+    found
+}
+
+fn decode_runs(runs: &Runs) -> Vec<(BarcodeFormat, String, usize, usize)> {
+    let decoders: [Decoder; 4] = [decode_ean13, decode_ean8, decode_code128, decode_code39];
+
+    let mut found = Vec::new();
+    for start in (1..runs.lengths.len()).step_by(2) {
+        for decoder in decoders {
+            if let Some((format, payload, end)) = decoder(&runs.lengths, start) {
+                let span_end = runs.starts[end - 1] + runs.lengths[end - 1];
+                found.push((format, payload, runs.starts[start], span_end));
+            }
+        }
+    }
+    found
+}
+
+/// Average deviation of `runs` from `pattern`, or `None` when any element is too far off.
+fn pattern_variance(runs: &[usize], pattern: &[u8]) -> Option<f32> {
+    let total: usize = runs.iter().sum();
+    let modules: usize = pattern.iter().map(|&p| p as usize).sum();
+    if total < modules {
+        return None;
+    }
+    let unit = total as f32 / modules as f32;
+    let mut variance = 0.0;
+    for (&run, &width) in runs.iter().zip(pattern) {
+        let deviation = (run as f32 - width as f32 * unit).abs();
+        if deviation > MAX_INDIVIDUAL_VARIANCE * unit {
+            return None;
+        }
+        variance += deviation;
+    }
+    let variance = variance / total as f32;
+    (variance <= MAX_AVERAGE_VARIANCE).then_some(variance)
+}
+
+/// Whether the light runs at `before` and `after` are wide enough to be quiet zones.
+fn has_quiet_zones(lengths: &[usize], before: usize, after: usize, unit: f32) -> bool {
+    let minimum = QUIET_ZONE_MODULES * unit;
+    lengths[before] as f32 >= minimum && lengths.get(after).is_some_and(|&length| length as f32 >= minimum)
+}
+
+/// Match four runs to an EAN digit, returning the digit and whether it used G (even) parity.
+fn match_digit(runs: &[usize], allow_even_parity: bool) -> Option<(u8, bool)> {
+    let mut best: Option<(f32, u8, bool)> = None;
+    for (digit, pattern) in DIGIT_PATTERNS.iter().enumerate() {
+        let mut reversed = *pattern;
+        reversed.reverse();
+        let candidates = [(pattern, false), (&reversed, true)];
+        for (candidate, even) in candidates.into_iter().take(if allow_even_parity { 2 } else { 1 }) {
+            if let Some(variance) = pattern_variance(runs, candidate)
+                && best.is_none_or(|(lowest, _, _)| variance < lowest)
+            {
+                best = Some((variance, digit as u8, even));
+            }
+        }
+    }
+    best.map(|(_, digit, even)| (digit, even))
+}
+
+fn ean_checksum_valid(digits: &[u8]) -> bool {
+    let Some((&check, data)) = digits.split_last() else {
+        return false;
+    };
+    let sum: u32 = data
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| u32::from(digit) * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    (10 - sum % 10) % 10 == u32::from(check)
+}
+
+/// Decode the guard-delimited EAN digit halves, returning the digits and left-half parity.
+fn decode_ean_halves(lengths: &[usize], start: usize, half: usize) -> Option<(Vec<u8>, u8, usize)> {
+    let digit_runs = 4 * half;
+    let middle = start + 3 + digit_runs;
+    let right = middle + 5;
+    let end = right + digit_runs + 3;
+    let runs = lengths.get(start..end)?;
+
+    pattern_variance(&runs[..3], &[1, 1, 1])?;
+    pattern_variance(&lengths[middle..right], &[1, 1, 1, 1, 1])?;
+    pattern_variance(&lengths[end - 3..end], &[1, 1, 1])?;
+    let unit = runs[..3].iter().sum::<usize>() as f32 / 3.0;
+    if !has_quiet_zones(lengths, start - 1, end, unit) {
+        return None;
+    }
+
+    let mut digits = Vec::with_capacity(2 * half);
+    let mut parity = 0u8;
+    for i in 0..half {
+        let offset = start + 3 + 4 * i;
+        let (digit, even) = match_digit(&lengths[offset..offset + 4], true)?;
+        digits.push(digit);
+        if even {
+            parity |= 1 << (half - 1 - i);
+        }
+    }
+    for i in 0..half {
+        let offset = right + 4 * i;
+        digits.push(match_digit(&lengths[offset..offset + 4], false)?.0);
+    }
+    Some((digits, parity, end))
+}
+
+fn decode_ean13(lengths: &[usize], start: usize) -> Option<RunDecode> {
+    let (mut digits, parity, end) = decode_ean_halves(lengths, start, 6)?;
+    let first = EAN13_PARITY.iter().position(|&p| p == parity)? as u8;
+    digits.insert(0, first);
+    if !ean_checksum_valid(&digits) {
+        return None;
+    }
+
+    let text: String = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+    if first == 0 {
+        Some((BarcodeFormat::UpcA, text[1..].to_string(), end))
+    } else {
+        Some((BarcodeFormat::Ean13, text, end))
+    }
+}
+
+fn decode_ean8(lengths: &[usize], start: usize) -> Option<RunDecode> {
+    let (digits, parity, end) = decode_ean_halves(lengths, start, 4)?;
+    if parity != 0 || !ean_checksum_valid(&digits) {
+        return None;
+    }
+    let text = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+    Some((BarcodeFormat::Ean8, text, end))
+}
+
+fn match_code128(runs: &[usize]) -> Option<usize> {
+    CODE128_PATTERNS
+        .iter()
+        .enumerate()
+        .filter_map(|(code, pattern)| pattern_variance(runs, &pattern[..6]).map(|variance| (variance, code)))
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, code)| code)
+}
+
+fn decode_code128(lengths: &[usize], start: usize) -> Option<RunDecode> {
+    let first = lengths.get(start..start + 6)?;
+    let start_code = match_code128(first)?;
+    if !(CODE128_START_A..=CODE128_START_C).contains(&start_code) {
+        return None;
+    }
+    let unit = first.iter().sum::<usize>() as f32 / 11.0;
+
+    let mut codes = vec![start_code];
+    let mut position = start + 6;
+    let end = loop {
+        let code = match_code128(lengths.get(position..position + 6)?)?;
+        if code == CODE128_STOP {
+            pattern_variance(lengths.get(position..position + 7)?, CODE128_PATTERNS[CODE128_STOP])?;
+            break position + 7;
+        }
+        codes.push(code);
+        position += 6;
+    };
+    if codes.len() < 3 || !has_quiet_zones(lengths, start - 1, end, unit) {
+        return None;
+    }
+
+    let (&checksum, data) = codes[1..].split_last()?;
+    let sum = data
+        .iter()
+        .enumerate()
+        .fold(start_code, |acc, (i, &code)| acc + (i + 1) * code);
+    if sum % 103 != checksum {
+        return None;
+    }
+    Some((BarcodeFormat::Code128, code128_text(start_code, data)?, end))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CodeSet {
+    A,
+    B,
+    C,
+}
+
+/// Translate Code 128 data values to text; FNC1 separators become ASCII GS.
+fn code128_text(start_code: usize, data: &[usize]) -> Option<String> {
+    let mut set = match start_code {
+        CODE128_START_A => CodeSet::A,
+        CODE128_START_C => CodeSet::C,
+        _ => CodeSet::B,
+    };
+    let mut shifted = false;
+    let mut text = String::new();
+
+    for (i, &code) in data.iter().enumerate() {
+        let current = match (shifted, set) {
+            (true, CodeSet::A) => CodeSet::B,
+            (true, CodeSet::B) => CodeSet::A,
+            _ => set,
+        };
+        shifted = false;
+        match (current, code) {
+            (_, 102) => {
+                if i > 0 {
+                    text.push('\u{1d}');
+                }
+            }
+            (CodeSet::C, 0..=99) => text.push_str(&format!("{code:02}")),
+            (CodeSet::C, 100) | (CodeSet::A, 100) => set = CodeSet::B,
+            (CodeSet::C, 101) | (CodeSet::B, 101) => set = CodeSet::A,
+            (CodeSet::A | CodeSet::B, 99) => set = CodeSet::C,
+            (CodeSet::A | CodeSet::B, 98) => shifted = true,
+            (CodeSet::A, 0..=63) | (CodeSet::B, 0..=95) => text.push(char::from(code as u8 + 32)),
+            (CodeSet::A, 64..=95) => text.push(char::from(code as u8 - 64)),
+            (CodeSet::A | CodeSet::B, 96 | 97) | (CodeSet::A, 101) | (CodeSet::B, 100) => {}
+            _ => return None,
+        }
+    }
+    Some(text)
+}
+
+/// Decode nine runs (bar first) as a Code 39 character mask: the three widest are wide.
+fn code39_pattern(runs: &[usize]) -> Option<u16> {
+    let mut sorted = runs.to_vec();
+    sorted.sort_unstable();
+    let (widest_narrow, narrowest_wide) = (sorted[5], sorted[6]);
+    if 2 * narrowest_wide < 3 * widest_narrow || sorted[8] > 2 * narrowest_wide || widest_narrow > 2 * sorted[0] {
+        return None;
+    }
+    Some(
+        runs.iter()
+            .fold(0, |mask, &run| (mask << 1) | u16::from(run >= narrowest_wide)),
+    )
+}
+
+fn decode_code39(lengths: &[usize], start: usize) -> Option<RunDecode> {
+    let first = lengths.get(start..start + 9)?;
+    if code39_pattern(first)? != CODE39_START_STOP {
+        return None;
+    }
+    let narrow = *first.iter().min()? as f32;
+
+    let mut text = String::new();
+    let mut position = start + 9;
+    let end = loop {
+        let gap = *lengths.get(position)? as f32;
+        if gap > 3.0 * narrow {
+            return None;
+        }
+        let pattern = code39_pattern(lengths.get(position + 1..position + 10)?)?;
+        position += 10;
+        if pattern == CODE39_START_STOP {
+            break position;
+        }
+        let index = CODE39_PATTERNS.iter().position(|&p| p == pattern)?;
+        text.push(char::from(CODE39_ALPHABET[index]));
+    };
+    if text.is_empty() || !has_quiet_zones(lengths, start - 1, end, narrow) {
+        return None;
+    }
+    Some((BarcodeFormat::Code39, text, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Render module widths (starting with a bar) as scan-line runs with quiet zones.
+    fn line(widths: &[u8], unit: usize) -> Vec<bool> {
+        let mut line = vec![false; 12 * unit];
+        for (i, &width) in widths.iter().enumerate() {
+            line.extend(std::iter::repeat_n(i % 2 == 0, width as usize * unit));
+        }
+        line.extend(std::iter::repeat_n(false, 12 * unit));
+        line
+    }
+
+    fn ean13_widths(digits: &str) -> Vec<u8> {
+        let digits: Vec<usize> = digits.bytes().map(|b| (b - b'0') as usize).collect();
+        let parity = EAN13_PARITY[digits[0]];
+        let mut widths = vec![1, 1, 1];
+        for (i, &digit) in digits[1..7].iter().enumerate() {
+            let mut pattern = DIGIT_PATTERNS[digit];
+            if parity & (1 << (5 - i)) != 0 {
+                pattern.reverse();
+            }
+            widths.extend(pattern);
+        }
+        widths.extend([1, 1, 1, 1, 1]);
+        for &digit in &digits[7..] {
+            widths.extend(DIGIT_PATTERNS[digit]);
+        }
+        widths.extend([1, 1, 1]);
+        widths
+    }
+
+    #[test]
+    fn test_code128_patterns_are_well_formed() {
+        for (code, pattern) in CODE128_PATTERNS.iter().enumerate() {
+            let modules: u8 = pattern.iter().sum();
+            let bars: u8 = pattern.iter().step_by(2).sum();
+            assert_eq!(modules, if code == CODE128_STOP { 13 } else { 11 }, "code {code}");
+            assert_eq!(bars % 2, 0, "code {code}");
+        }
+    }
+
+    #[test]
+    fn test_decodes_ean13_in_both_directions() {
+        let mut scan = line(&ean13_widths("4006381333931"), 2);
+        assert_eq!(
+            decode_line(&scan),
+            vec![(BarcodeFormat::Ean13, "4006381333931".to_string(), 24, 214)]
+        );
+
+        scan.reverse();
+        let found = decode_line(&scan);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, "4006381333931");
+    }
+
+    #[test]
+    fn test_reports_upc_a_for_leading_zero() {
+        let found = decode_line(&line(&ean13_widths("0036000291452"), 1));
+        assert_eq!(found[0].0, BarcodeFormat::UpcA);
+        assert_eq!(found[0].1, "036000291452");
+    }
+
+    #[test]
+    fn test_rejects_bad_ean_check_digit() {
+        assert!(decode_line(&line(&ean13_widths("4006381333932"), 2)).is_empty());
+    }
+
+    #[test]
+    fn test_decodes_code128_with_code_set_switch() {
+        // Start B, "AB", Code C, "12", "34", checksum, stop.
+        let values = [104, 33, 34, 99, 12, 34];
+        let checksum = values
+            .iter()
+            .skip(1)
+            .enumerate()
+            .fold(values[0], |acc, (i, &v)| acc + (i + 1) * v)
+            % 103;
+        let mut widths = Vec::new();
+        for code in values.into_iter().chain([checksum, CODE128_STOP]) {
+            widths.extend_from_slice(CODE128_PATTERNS[code]);
+        }
+
+        let found = decode_line(&line(&widths, 2));
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].0, found[0].1.as_str()), (BarcodeFormat::Code128, "AB1234"));
+    }
+
+    #[test]
+    fn test_decodes_code39() {
+        let mut widths = Vec::new();
+        for pattern in [
+            CODE39_START_STOP,
+            CODE39_PATTERNS[10],
+            CODE39_PATTERNS[1],
+            CODE39_START_STOP,
+        ] {
+            if !widths.is_empty() {
+                widths.push(1);
+            }
+            widths.extend((0..9).rev().map(|bit| if pattern >> bit & 1 == 1 { 3 } else { 1 }));
+        }
+
+        let found = decode_line(&line(&widths, 2));
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].0, found[0].1.as_str()), (BarcodeFormat::Code39, "A1"));
+    }
+}
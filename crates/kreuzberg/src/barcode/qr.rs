@@ -0,0 +1,987 @@
+//! QR code detection and decoding.
+//!
+//! Finder patterns are located by scanning for the 1:1:3:1:1 dark/light ratio, grouped into
+//! right-angled triples, and the symbol is sampled through a perspective transform anchored
+//! on the finders (and the bottom-right alignment pattern when present). Format information,
+//! unmasking, block de-interleaving and Reed-Solomon correction follow ISO/IEC 18004.
+
+use super::BitMatrix;
+use super::reed_solomon;
+
+/// Error correction codewords per block, indexed by `[level][version]` (levels L, M, Q, H).
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28, 30, 30, 26, 28, 30,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28, 28, 28, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30, 30, 30, 30, 28, 30,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24, 30, 30, 30, 30, 30,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+/// Number of error correction blocks, indexed by `[level][version]` (levels L, M, Q, H).
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13, 14, 15, 16, 17, 18,
+        19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25, 26, 28, 29,
+        31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29, 34, 34, 35, 38, 40,
+        43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32, 35, 37, 40, 42, 45,
+        48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// Mask applied to the 15-bit format information.
+const FORMAT_MASK: u32 = 0x5412;
+
+/// Maximum Hamming distance accepted when decoding format information.
+const MAX_FORMAT_ERRORS: u32 = 3;
+
+/// Upper bound on finder candidates considered when forming triples.
+const MAX_FINDER_CANDIDATES: usize = 24;
+
+const ALPHANUMERIC: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// A decoded QR code with the image positions of its four corners.
+pub(super) struct DecodedQr {
+    pub(super) payload: String,
+    pub(super) corners: [(f32, f32); 4],
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FinderPattern {
+    x: f32,
+    y: f32,
+    module: f32,
+    count: u32,
+}
+
+/// Find and decode every QR code in the matrix.
+pub(super) fn detect(matrix: &BitMatrix) -> Vec<DecodedQr> {
+    let mut candidates: Vec<FinderPattern> = find_finder_patterns(matrix)
+        .into_iter()
+        .filter(|p| p.count >= 2)
+        .collect();
+    candidates.sort_by_key(|p| std::cmp::Reverse(p.count));
+    candidates.truncate(MAX_FINDER_CANDIDATES);
+
+    let mut triples = Vec::new();
+    for i in 0..candidates.len() {
+        for j in i + 1..candidates.len() {
+            for k in j + 1..candidates.len() {
+                if let Some(score) = triple_score(&candidates[i], &candidates[j], &candidates[k]) {
+                    triples.push((score, [i, j, k]));
+                }
+            }
+        }
+    }
+    triples.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut used = vec![false; candidates.len()];
+    let mut decoded = Vec::new();
+    for (_, indices) in triples {
+        if indices.iter().any(|&i| used[i]) {
+            continue;
+        }
+        let [top_left, top_right, bottom_left] = orient(indices.map(|i| candidates[i]));
+        if let Some(code) = decode_symbol(matrix, &top_left, &top_right, &bottom_left) {
+            for i in indices {
+                used[i] = true;
+            }
+            decoded.push(code);
+        }
+    }
+    decoded
+}
+
+/// Whether run lengths match the finder's 1:1:3:1:1 ratio.
+fn is_finder_ratio(counts: &[usize; 5]) -> bool {
+    let total: usize = counts.iter().sum();
+    if total < 7 || counts.contains(&0) {
+        return false;
+    }
+    let module = total as f32 / 7.0;
+    let tolerance = module / 2.0;
+    counts.iter().enumerate().all(|(i, &count)| {
+        let expected = if i == 2 { 3.0 } else { 1.0 };
+        (count as f32 - expected * module).abs() < expected * tolerance
+    })
+}
+
+fn find_finder_patterns(matrix: &BitMatrix) -> Vec<FinderPattern> {
+    let mut patterns = Vec::new();
+    for y in 0..matrix.height() {
+        let mut counts = [0usize; 5];
+        let mut state = 0;
+        for x in 0..matrix.width() {
+            if matrix.get(x, y) {
+                if state % 2 == 1 {
+                    state += 1;
+                }
+                counts[state] += 1;
+            } else if state % 2 == 1 {
+                counts[state] += 1;
+            } else if state == 4 {
+                if is_finder_ratio(&counts) {
+                    add_finder_candidate(matrix, &counts, x, y, &mut patterns);
+                }
+                counts = [counts[2], counts[3], counts[4], 1, 0];
+                state = 3;
+            } else if state > 0 || counts[0] > 0 {
+                state += 1;
+                counts[state] += 1;
+            }
+        }
+        if state == 4 && is_finder_ratio(&counts) {
+            add_finder_candidate(matrix, &counts, matrix.width(), y, &mut patterns);
+        }
+    }
+    patterns
+}
+
+/// Confirm a horizontal finder hit vertically and horizontally, then merge it into `patterns`.
+fn add_finder_candidate(
+    matrix: &BitMatrix,
+    counts: &[usize; 5],
+    end: usize,
+    y: usize,
+    patterns: &mut Vec<FinderPattern>,
+) {
+    let total: usize = counts.iter().sum();
+    let similar = |other: usize| 5 * other.abs_diff(total) < 2 * total;
+
+    let column = (end as f32 - counts[4] as f32 - counts[3] as f32 - counts[2] as f32 / 2.0) as isize;
+    let Some((center_y, vertical_total)) = cross_check(|i| matrix.dark(column, i), y as isize, counts[2]) else {
+        return;
+    };
+    if !similar(vertical_total) {
+        return;
+    }
+    let row = center_y as isize;
+    let Some((center_x, horizontal_total)) = cross_check(|i| matrix.dark(i, row), column, counts[2]) else {
+        return;
+    };
+    if !similar(horizontal_total) {
+        return;
+    }
+
+    let module = (vertical_total + horizontal_total) as f32 / 14.0;
+    if let Some(existing) = patterns.iter_mut().find(|p| {
+        (p.x - center_x).abs() <= module
+            && (p.y - center_y).abs() <= module
+            && (p.module - module).abs() <= p.module.max(1.0)
+    }) {
+        let weight = existing.count as f32;
+        existing.x = (existing.x * weight + center_x) / (weight + 1.0);
+        existing.y = (existing.y * weight + center_y) / (weight + 1.0);
+        existing.module = (existing.module * weight + module) / (weight + 1.0);
+        existing.count += 1;
+    } else {
+        patterns.push(FinderPattern {
+            x: center_x,
+            y: center_y,
+            module,
+            count: 1,
+        });
+    }
+}
+
+/// Look for a 1:1:3:1:1 pattern along a line through `center`.
+///
+/// Returns the refined center along the line and the total pattern length.
+fn cross_check(line: impl Fn(isize) -> Option<bool>, center: isize, max_count: usize) -> Option<(f32, usize)> {
+    let mut counts = [0usize; 5];
+
+    let mut i = center;
+    while line(i) == Some(true) {
+        counts[2] += 1;
+        i -= 1;
+    }
+    while line(i) == Some(false) && counts[1] <= max_count {
+        counts[1] += 1;
+        i -= 1;
+    }
+    while line(i) == Some(true) && counts[0] <= max_count {
+        counts[0] += 1;
+        i -= 1;
+    }
+
+    let mut i = center + 1;
+    while line(i) == Some(true) {
+        counts[2] += 1;
+        i += 1;
+    }
+    while line(i) == Some(false) && counts[3] <= max_count {
+        counts[3] += 1;
+        i += 1;
+    }
+    while line(i) == Some(true) && counts[4] <= max_count {
+        counts[4] += 1;
+        i += 1;
+    }
+
+    if !is_finder_ratio(&counts) {
+        return None;
+    }
+    let center = i as f32 - counts[4] as f32 - counts[3] as f32 - counts[2] as f32 / 2.0;
+    Some((center, counts.iter().sum()))
+}
+
+fn distance(a: &FinderPattern, b: &FinderPattern) -> f32 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Score how closely three finders form the corners of a QR symbol (lower is better).
+fn triple_score(a: &FinderPattern, b: &FinderPattern, c: &FinderPattern) -> Option<f32> {
+    let modules = [a.module, b.module, c.module];
+    let largest = modules.iter().copied().fold(f32::MIN, f32::max);
+    let smallest = modules.iter().copied().fold(f32::MAX, f32::min);
+    if largest > smallest * 1.5 {
+        return None;
+    }
+
+    let mut sides = [distance(b, c), distance(a, c), distance(a, b)];
+    sides.sort_by(f32::total_cmp);
+    let [leg_a, leg_b, hypotenuse] = sides;
+    if leg_b > leg_a * 1.25 {
+        return None;
+    }
+    let angle_error = (hypotenuse * hypotenuse - leg_a * leg_a - leg_b * leg_b).abs() / (hypotenuse * hypotenuse);
+    if angle_error > 0.25 {
+        return None;
+    }
+
+    let module = (a.module + b.module + c.module) / 3.0;
+    let modules_across = (leg_a + leg_b) / 2.0 / module + 7.0;
+    if !(17.0..=185.0).contains(&modules_across) {
+        return None;
+    }
+    Some(angle_error + leg_b / leg_a - 1.0)
+}
+
+/// Order a triple as top-left, top-right, bottom-left.
+fn orient(patterns: [FinderPattern; 3]) -> [FinderPattern; 3] {
+    let [a, b, c] = patterns;
+    let (bc, ac, ab) = (distance(&b, &c), distance(&a, &c), distance(&a, &b));
+    let (top_left, mut first, mut second) = if bc >= ac && bc >= ab {
+        (a, b, c)
+    } else if ac >= ab {
+        (b, a, c)
+    } else {
+        (c, a, b)
+    };
+    let cross = (first.x - top_left.x) * (second.y - top_left.y) - (first.y - top_left.y) * (second.x - top_left.x);
+    if cross < 0.0 {
+        std::mem::swap(&mut first, &mut second);
+    }
+    [top_left, first, second]
+}
+
+fn decode_symbol(
+    matrix: &BitMatrix,
+    top_left: &FinderPattern,
+    top_right: &FinderPattern,
+    bottom_left: &FinderPattern,
+) -> Option<DecodedQr> {
+    // Finder run lengths are stretched when the symbol is rotated, so also measure the module
+    // size along the symbol axes and try every version between the two estimates.
+    let extents: Vec<f32> = [
+        (top_left, top_right),
+        (top_right, top_left),
+        (top_left, bottom_left),
+        (bottom_left, top_left),
+    ]
+    .into_iter()
+    .filter_map(|(from, toward)| finder_extent(matrix, from, toward))
+    .collect();
+    let run_module = (top_left.module + top_right.module + bottom_left.module) / 3.0;
+    let axis_module = if extents.is_empty() {
+        run_module
+    } else {
+        extents.iter().sum::<f32>() / extents.len() as f32 / 7.0
+    };
+
+    let width = (distance(top_left, top_right) + distance(top_left, bottom_left)) / 2.0;
+    let version_for = |module: f32| ((width / module + 7.0 - 17.0) / 4.0).round() as isize;
+    let (low, high) = {
+        let (a, b) = (version_for(run_module), version_for(axis_module));
+        (a.min(b) - 1, a.max(b) + 1)
+    };
+    let preferred = version_for(axis_module);
+    let mut versions: Vec<isize> = (low.max(1)..=high.min(40)).collect();
+    versions.sort_by_key(|version| (version - preferred).abs());
+
+    versions
+        .into_iter()
+        .find_map(|version| decode_version(matrix, top_left, top_right, bottom_left, axis_module, version as usize))
+}
+
+/// Width of a finder pattern measured through its center along the line toward another finder.
+fn finder_extent(matrix: &BitMatrix, from: &FinderPattern, toward: &FinderPattern) -> Option<f32> {
+    const STEP: f32 = 0.25;
+
+    let length = distance(from, toward);
+    if length == 0.0 {
+        return None;
+    }
+    let direction = ((toward.x - from.x) / length, (toward.y - from.y) / length);
+    let limit = (from.module * 10.0 / STEP).ceil() as usize;
+
+    // Walk dark (center), light, dark (outer ring) and stop at the light beyond it.
+    let edge = |sign: f32| {
+        let mut state = 0;
+        for step in 0..=limit {
+            let offset = sign * STEP * step as f32;
+            let x = from.x + direction.0 * offset;
+            let y = from.y + direction.1 * offset;
+            let dark = matrix.dark(x.floor() as isize, y.floor() as isize)?;
+            if dark == (state % 2 == 1) {
+                state += 1;
+                if state == 3 {
+                    return Some(offset.abs());
+                }
+            }
+        }
+        None
+    };
+    Some(edge(1.0)? + edge(-1.0)?)
+}
+
+fn decode_version(
+    matrix: &BitMatrix,
+    top_left: &FinderPattern,
+    top_right: &FinderPattern,
+    bottom_left: &FinderPattern,
+    module: f32,
+    version: usize,
+) -> Option<DecodedQr> {
+    let size = 17 + 4 * version;
+    let far = size as f32 - 3.5;
+
+    let mut bottom_right = (
+        top_right.x - top_left.x + bottom_left.x,
+        top_right.y - top_left.y + bottom_left.y,
+    );
+    let mut source_bottom_right = far;
+    if version >= 2 {
+        let correction = 1.0 - 3.0 / (size as f32 - 7.0);
+        let estimate = (
+            top_left.x + correction * (bottom_right.0 - top_left.x),
+            top_left.y + correction * (bottom_right.1 - top_left.y),
+        );
+        if let Some(found) = find_alignment_pattern(matrix, estimate, module) {
+            bottom_right = found;
+            source_bottom_right = far - 3.0;
+        }
+    }
+
+    let transform = Perspective::quad_to_quad(
+        [
+            (3.5, 3.5),
+            (far, 3.5),
+            (source_bottom_right, source_bottom_right),
+            (3.5, far),
+        ],
+        [
+            (top_left.x, top_left.y),
+            (top_right.x, top_right.y),
+            bottom_right,
+            (bottom_left.x, bottom_left.y),
+        ],
+    );
+
+    let grid = sample_grid(matrix, &transform, size)?;
+    let (level, mask) = read_format(&grid, size)?;
+    let codewords = read_codewords(&grid, version, mask);
+    let data = correct_blocks(codewords, version, level)?;
+    let payload = parse_segments(&data, version)?;
+
+    let size = size as f32;
+    let corners = [(0.0, 0.0), (size, 0.0), (size, size), (0.0, size)].map(|(x, y)| transform.apply(x, y));
+    Some(DecodedQr { payload, corners })
+}
+
+/// Locate the alignment pattern nearest to `estimate` within a few modules.
+fn find_alignment_pattern(matrix: &BitMatrix, estimate: (f32, f32), module: f32) -> Option<(f32, f32)> {
+    let radius = (module * 4.0).ceil().max(4.0) as isize;
+    let (center_x, center_y) = (estimate.0.round() as isize, estimate.1.round() as isize);
+    let left = (center_x - radius).max(0);
+    let right = (center_x + radius).min(matrix.width() as isize - 1);
+    let top = (center_y - radius).max(0);
+    let bottom = (center_y + radius).min(matrix.height() as isize - 1);
+    if left >= right || top >= bottom {
+        return None;
+    }
+
+    let about_module = |length: usize| (length as f32 - module).abs() <= module * 0.5 + 0.5;
+    let mut best: Option<((f32, f32), f32)> = None;
+    for y in top..=bottom {
+        let mut runs: Vec<(isize, usize, bool)> = Vec::new();
+        for x in left..=right {
+            let dark = matrix.dark(x, y) == Some(true);
+            match runs.last_mut() {
+                Some((_, length, color)) if *color == dark => *length += 1,
+                _ => runs.push((x, 1, dark)),
+            }
+        }
+        for window in runs.windows(3) {
+            let [(_, before, false), (start, length, true), (_, after, false)] = *window else {
+                continue;
+            };
+            if !(about_module(before) && about_module(length) && about_module(after)) {
+                continue;
+            }
+            let x = start as f32 + length as f32 / 2.0;
+            let Some(y) = alignment_center_vertical(matrix, x as isize, y, &about_module) else {
+                continue;
+            };
+            let offset = (x - estimate.0).hypot(y - estimate.1);
+            if best.is_none_or(|(_, closest)| offset < closest) {
+                best = Some(((x, y), offset));
+            }
+        }
+    }
+    best.map(|(point, _)| point)
+}
+
+/// Confirm the light/dark/light alignment center vertically, returning its center row.
+fn alignment_center_vertical(
+    matrix: &BitMatrix,
+    x: isize,
+    y: isize,
+    about_module: &impl Fn(usize) -> bool,
+) -> Option<f32> {
+    let run = |start: isize, step: isize, dark: bool| {
+        let mut length = 0;
+        let mut i = start;
+        while matrix.dark(x, i) == Some(dark) {
+            length += 1;
+            i += step;
+        }
+        (length, i)
+    };
+
+    let (up, above) = run(y, -1, true);
+    let (down, below) = run(y + 1, 1, true);
+    let (light_above, _) = run(above, -1, false);
+    let (light_below, _) = run(below, 1, false);
+    if about_module(up + down) && about_module(light_above) && about_module(light_below) {
+        Some((above + 1) as f32 + (up + down) as f32 / 2.0)
+    } else {
+        None
+    }
+}
+
+/// Projective mapping between two quadrilaterals.
+#[derive(Debug, Clone, Copy)]
+struct Perspective {
+    a11: f32,
+    a12: f32,
+    a13: f32,
+    a21: f32,
+    a22: f32,
+    a23: f32,
+    a31: f32,
+    a32: f32,
+    a33: f32,
+}
+
+impl Perspective {
+    fn quad_to_quad(source: [(f32, f32); 4], destination: [(f32, f32); 4]) -> Self {
+        let to_square = Self::square_to_quad(source).adjoint();
+        Self::square_to_quad(destination).times(&to_square)
+    }
+
+    /// Map the unit square (corners in clockwise order from the origin) onto `quad`.
+    fn square_to_quad(quad: [(f32, f32); 4]) -> Self {
+        let [(x0, y0), (x1, y1), (x2, y2), (x3, y3)] = quad;
+        let dx3 = x0 - x1 + x2 - x3;
+        let dy3 = y0 - y1 + y2 - y3;
+        if dx3 == 0.0 && dy3 == 0.0 {
+            return Self {
+                a11: x1 - x0,
+                a21: x2 - x1,
+                a31: x0,
+                a12: y1 - y0,
+                a22: y2 - y1,
+                a32: y0,
+                a13: 0.0,
+                a23: 0.0,
+                a33: 1.0,
+            };
+        }
+        let (dx1, dx2, dy1, dy2) = (x1 - x2, x3 - x2, y1 - y2, y3 - y2);
+        let denominator = dx1 * dy2 - dx2 * dy1;
+        let a13 = (dx3 * dy2 - dx2 * dy3) / denominator;
+        let a23 = (dx1 * dy3 - dx3 * dy1) / denominator;
+        Self {
+            a11: x1 - x0 + a13 * x1,
+            a21: x3 - x0 + a23 * x3,
+            a31: x0,
+            a12: y1 - y0 + a13 * y1,
+            a22: y3 - y0 + a23 * y3,
+            a32: y0,
+            a13,
+            a23,
+            a33: 1.0,
+        }
+    }
+
+    fn adjoint(&self) -> Self {
+        Self {
+            a11: self.a22 * self.a33 - self.a23 * self.a32,
+            a21: self.a23 * self.a31 - self.a21 * self.a33,
+            a31: self.a21 * self.a32 - self.a22 * self.a31,
+            a12: self.a13 * self.a32 - self.a12 * self.a33,
+            a22: self.a11 * self.a33 - self.a13 * self.a31,
+            a32: self.a12 * self.a31 - self.a11 * self.a32,
+            a13: self.a12 * self.a23 - self.a13 * self.a22,
+            a23: self.a13 * self.a21 - self.a11 * self.a23,
+            a33: self.a11 * self.a22 - self.a12 * self.a21,
+        }
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self {
+            a11: self.a11 * other.a11 + self.a21 * other.a12 + self.a31 * other.a13,
+            a21: self.a11 * other.a21 + self.a21 * other.a22 + self.a31 * other.a23,
+            a31: self.a11 * other.a31 + self.a21 * other.a32 + self.a31 * other.a33,
+            a12: self.a12 * other.a11 + self.a22 * other.a12 + self.a32 * other.a13,
+            a22: self.a12 * other.a21 + self.a22 * other.a22 + self.a32 * other.a23,
+            a32: self.a12 * other.a31 + self.a22 * other.a32 + self.a32 * other.a33,
+            a13: self.a13 * other.a11 + self.a23 * other.a12 + self.a33 * other.a13,
+            a23: self.a13 * other.a21 + self.a23 * other.a22 + self.a33 * other.a23,
+            a33: self.a13 * other.a31 + self.a23 * other.a32 + self.a33 * other.a33,
+        }
+    }
+
+    fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        let denominator = self.a13 * x + self.a23 * y + self.a33;
+        (
+            (self.a11 * x + self.a21 * y + self.a31) / denominator,
+            (self.a12 * x + self.a22 * y + self.a32) / denominator,
+        )
+    }
+}
+
+/// Sample the center of every module; `grid[row * size + column]` is true for dark modules.
+fn sample_grid(matrix: &BitMatrix, transform: &Perspective, size: usize) -> Option<Vec<bool>> {
+    let (width, height) = (matrix.width() as f32, matrix.height() as f32);
+    let mut grid = Vec::with_capacity(size * size);
+    for row in 0..size {
+        for column in 0..size {
+            let (x, y) = transform.apply(column as f32 + 0.5, row as f32 + 0.5);
+            if !(-1.0..=width).contains(&x) || !(-1.0..=height).contains(&y) {
+                return None;
+            }
+            let x = (x as isize).clamp(0, matrix.width() as isize - 1);
+            let y = (y as isize).clamp(0, matrix.height() as isize - 1);
+            grid.push(matrix.dark(x, y) == Some(true));
+        }
+    }
+    Some(grid)
+}
+
+/// Decode the error correction level index (L, M, Q, H) and mask pattern.
+fn read_format(grid: &[bool], size: usize) -> Option<(usize, u32)> {
+    let bit = |x: usize, y: usize| u32::from(grid[y * size + x]);
+
+    let mut first = 0;
+    for i in 0..6 {
+        first |= bit(8, i) << i;
+    }
+    first |= bit(8, 7) << 6 | bit(8, 8) << 7 | bit(7, 8) << 8;
+    for i in 9..15 {
+        first |= bit(14 - i, 8) << i;
+    }
+    let mut second = 0;
+    for i in 0..8 {
+        second |= bit(size - 1 - i, 8) << i;
+    }
+    for i in 8..15 {
+        second |= bit(8, size - 15 + i) << i;
+    }
+
+    let (distance, data) = (0..32u32)
+        .map(|data| {
+            let code = format_codeword(data);
+            let distance = (code ^ first).count_ones().min((code ^ second).count_ones());
+            (distance, data)
+        })
+        .min()?;
+    if distance > MAX_FORMAT_ERRORS {
+        return None;
+    }
+    let level = match data >> 3 {
+        1 => 0,
+        0 => 1,
+        3 => 2,
+        _ => 3,
+    };
+    Some((level, data & 7))
+}
+
+/// BCH-encode and mask five bits of format data.
+fn format_codeword(data: u32) -> u32 {
+    let mut remainder = data;
+    for _ in 0..10 {
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+    }
+    ((data << 10) | remainder) ^ FORMAT_MASK
+}
+
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let size = 17 + 4 * version;
+    let mut positions: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+/// Modules reserved for finder, timing, alignment, format and version patterns.
+fn function_modules(version: usize) -> Vec<bool> {
+    let size = 17 + 4 * version;
+    let mut reserved = vec![false; size * size];
+    let mut fill = |left: usize, top: usize, width: usize, height: usize| {
+        for y in top..(top + height).min(size) {
+            for x in left..(left + width).min(size) {
+                reserved[y * size + x] = true;
+            }
+        }
+    };
+
+    fill(6, 0, 1, size);
+    fill(0, 6, size, 1);
+    fill(0, 0, 9, 9);
+    fill(size - 8, 0, 8, 9);
+    fill(0, size - 8, 9, 8);
+
+    let positions = alignment_positions(version);
+    let last = positions.len().saturating_sub(1);
+    for (i, &y) in positions.iter().enumerate() {
+        for (j, &x) in positions.iter().enumerate() {
+            if (i == 0 && (j == 0 || j == last)) || (i == last && j == 0) {
+                continue;
+            }
+            fill(x - 2, y - 2, 5, 5);
+        }
+    }
+
+    if version >= 7 {
+        fill(size - 11, 0, 3, 6);
+        fill(0, size - 11, 6, 3);
+    }
+    reserved
+}
+
+fn mask_bit(mask: u32, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+fn raw_data_modules(version: usize) -> usize {
+    let mut modules = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let alignments = version / 7 + 2;
+        modules -= (25 * alignments - 10) * alignments - 55;
+        if version >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+/// Read the unmasked codewords in the two-column zigzag placement order.
+fn read_codewords(grid: &[bool], version: usize, mask: u32) -> Vec<u8> {
+    let size = 17 + 4 * version;
+    let reserved = function_modules(version);
+    let total_bits = raw_data_modules(version) / 8 * 8;
+    let mut codewords = vec![0u8; total_bits / 8];
+
+    let mut bit = 0;
+    let mut right = size as isize - 1;
+    while right >= 1 {
+        if right == 6 {
+            right = 5;
+        }
+        let upward = (right + 1) & 2 == 0;
+        for vertical in 0..size {
+            let y = if upward { size - 1 - vertical } else { vertical };
+            for offset in 0..2 {
+                let x = right as usize - offset;
+                if reserved[y * size + x] || bit >= total_bits {
+                    continue;
+                }
+                if grid[y * size + x] != mask_bit(mask, x, y) {
+                    codewords[bit / 8] |= 1 << (7 - bit % 8);
+                }
+                bit += 1;
+            }
+        }
+        right -= 2;
+    }
+    codewords
+}
+
+/// De-interleave the blocks, correct each one and concatenate their data codewords.
+fn correct_blocks(codewords: Vec<u8>, version: usize, level: usize) -> Option<Vec<u8>> {
+    let block_count = NUM_ERROR_CORRECTION_BLOCKS[level][version] as usize;
+    let ec_len = ECC_CODEWORDS_PER_BLOCK[level][version] as usize;
+    let short_blocks = block_count - codewords.len() % block_count;
+    let short_len = codewords.len() / block_count;
+
+    let mut blocks: Vec<Vec<u8>> = (0..block_count).map(|_| Vec::with_capacity(short_len + 1)).collect();
+    let mut source = codewords.into_iter();
+    for i in 0..=short_len {
+        for (j, block) in blocks.iter_mut().enumerate() {
+            if i != short_len - ec_len || j >= short_blocks {
+                block.push(source.next()?);
+            }
+        }
+    }
+
+    let mut data = Vec::new();
+    for mut block in blocks {
+        reed_solomon::correct(&mut block, ec_len)?;
+        data.extend_from_slice(&block[..block.len() - ec_len]);
+    }
+    Some(data)
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    fn read(&mut self, count: usize) -> Option<u32> {
+        if count > self.remaining() {
+            return None;
+        }
+        let mut value = 0;
+        for _ in 0..count {
+            let byte = self.data[self.position / 8];
+            value = (value << 1) | u32::from((byte >> (7 - self.position % 8)) & 1);
+            self.position += 1;
+        }
+        Some(value)
+    }
+}
+
+/// Character count indicator width for a mode in a version range.
+fn count_bits(mode: u32, version: usize) -> usize {
+    let group = match version {
+        1..=9 => 0,
+        10..=26 => 1,
+        _ => 2,
+    };
+    match mode {
+        0b0001 => [10, 12, 14][group],
+        0b0010 => [9, 11, 13][group],
+        0b0100 => [8, 16, 16][group],
+        _ => [8, 10, 12][group],
+    }
+}
+
+/// Parse numeric, alphanumeric, byte and kanji segments into text.
+fn parse_segments(data: &[u8], version: usize) -> Option<String> {
+    let mut reader = BitReader { data, position: 0 };
+    let mut text = String::new();
+    let mut bytes = Vec::new();
+
+    while reader.remaining() >= 4 {
+        let mode = reader.read(4)?;
+        if mode != 0b0100 && !bytes.is_empty() {
+            text.push_str(&decode_bytes(&std::mem::take(&mut bytes)));
+        }
+        match mode {
+            0b0000 => break,
+            0b0001 => {
+                let mut count = reader.read(count_bits(mode, version))? as usize;
+                while count > 0 {
+                    let (digits, bits) = match count {
+                        1 => (1, 4),
+                        2 => (2, 7),
+                        _ => (3, 10),
+                    };
+                    let value = reader.read(bits)?;
+                    if value >= 10u32.pow(digits) {
+                        return None;
+                    }
+                    text.push_str(&format!("{value:0width$}", width = digits as usize));
+                    count -= digits as usize;
+                }
+            }
+            0b0010 => {
+                let mut count = reader.read(count_bits(mode, version))? as usize;
+                while count >= 2 {
+                    let value = reader.read(11)? as usize;
+                    if value >= 45 * 45 {
+                        return None;
+                    }
+                    text.push(ALPHANUMERIC[value / 45] as char);
+                    text.push(ALPHANUMERIC[value % 45] as char);
+                    count -= 2;
+                }
+                if count == 1 {
+                    text.push(*ALPHANUMERIC.get(reader.read(6)? as usize)? as char);
+                }
+            }
+            0b0100 => {
+                let count = reader.read(count_bits(mode, version))?;
+                for _ in 0..count {
+                    bytes.push(reader.read(8)? as u8);
+                }
+            }
+            0b1000 => {
+                let count = reader.read(count_bits(mode, version))?;
+                let mut shift_jis = Vec::with_capacity(count as usize * 2);
+                for _ in 0..count {
+                    let value = reader.read(13)?;
+                    let mut code = ((value / 0xC0) << 8) | (value % 0xC0);
+                    code += if code < 0x1F00 { 0x8140 } else { 0xC140 };
+                    shift_jis.extend_from_slice(&[(code >> 8) as u8, code as u8]);
+                }
+                text.push_str(&decode_shift_jis(&shift_jis)?);
+            }
+            0b0111 => {
+                let first = reader.read(8)?;
+                if first & 0x80 != 0 {
+                    reader.read(if first & 0x40 == 0 { 8 } else { 16 })?;
+                }
+            }
+            0b0011 => {
+                reader.read(16)?;
+            }
+            0b0101 => {}
+            0b1001 => {
+                reader.read(8)?;
+            }
+            _ => return None,
+        }
+    }
+    if !bytes.is_empty() {
+        text.push_str(&decode_bytes(&bytes));
+    }
+    Some(text)
+}
+
+/// Byte segments are UTF-8 in practice; fall back to ISO-8859-1, the standard's default.
+fn decode_bytes(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+#[cfg(feature = "quality")]
+fn decode_shift_jis(bytes: &[u8]) -> Option<String> {
+    let (text, _, had_errors) = encoding_rs::SHIFT_JIS.decode(bytes);
+    (!had_errors).then(|| text.into_owned())
+}
+
+#[cfg(not(feature = "quality"))]
+fn decode_shift_jis(_bytes: &[u8]) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alignment_positions_match_the_standard() {
+        assert!(alignment_positions(1).is_empty());
+        assert_eq!(alignment_positions(2), vec![6, 18]);
+        assert_eq!(alignment_positions(7), vec![6, 22, 38]);
+        assert_eq!(alignment_positions(32), vec![6, 34, 60, 86, 112, 138]);
+        assert_eq!(alignment_positions(40), vec![6, 30, 58, 86, 114, 142, 170]);
+    }
+
+    #[test]
+    fn test_data_modules_match_codeword_capacity() {
+        for version in 1..=40 {
+            let size = 17 + 4 * version;
+            let reserved = function_modules(version).iter().filter(|&&r| r).count();
+            assert_eq!(size * size - reserved, raw_data_modules(version), "version {version}");
+        }
+    }
+
+    #[test]
+    fn test_parse_segments_reads_mixed_modes() {
+        // Numeric "01234567" followed by byte "é" (UTF-8) in a version 1 symbol.
+        let mut bits = String::new();
+        bits.push_str("0001");
+        bits.push_str(&format!("{:010b}", 8));
+        bits.push_str(&format!("{:010b}{:010b}{:07b}", 12, 345, 67));
+        bits.push_str("0100");
+        bits.push_str(&format!("{:08b}", 2));
+        bits.push_str(&format!("{:08b}{:08b}", 0xC3, 0xA9));
+        bits.push_str("0000");
+        while !bits.len().is_multiple_of(8) {
+            bits.push('0');
+        }
+        let data: Vec<u8> = bits
+            .as_bytes()
+            .chunks(8)
+            .map(|chunk| u8::from_str_radix(std::str::from_utf8(chunk).unwrap(), 2).unwrap())
+            .collect();
+
+        assert_eq!(parse_segments(&data, 1).as_deref(), Some("01234567é"));
+    }
+
+    #[test]
+    fn test_read_format_tolerates_errors() {
+        let size = 21;
+        let code = format_codeword(0b01_101);
+        let mut grid = vec![false; size * size];
+        let mut set = |x: usize, y: usize, i: u32| grid[y * size + x] = (code >> i) & 1 == 1;
+        for i in 0..6 {
+            set(8, i as usize, i);
+        }
+        set(8, 7, 6);
+        set(8, 8, 7);
+        set(7, 8, 8);
+        for i in 9..15 {
+            set(14 - i as usize, 8, i);
+        }
+        grid[8 * size + 1] = !grid[8 * size + 1];
+        grid[2 * size + 8] = !grid[2 * size + 8];
+
+        assert_eq!(read_format(&grid, size), Some((0, 5)));
+    }
+}
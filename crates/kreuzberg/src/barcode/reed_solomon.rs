@@ -0,0 +1,210 @@
+//! Reed-Solomon error correction over GF(256), as used by QR codes.
+//!
+//! Codewords are polynomials whose first byte is the highest-degree coefficient, with
+//! generator roots α^0, α^1, ... (primitive polynomial 0x11D).
+
+const PRIMITIVE: u16 = 0x11D;
+
+struct Field {
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+impl Field {
+    fn new() -> Self {
+        let mut exp = [0u8; 512];
+        let mut log = [0u8; 256];
+        let mut value: u16 = 1;
+        for (power, slot) in exp.iter_mut().enumerate().take(255) {
+            *slot = value as u8;
+            log[value as usize] = power as u8;
+            value <<= 1;
+            if value & 0x100 != 0 {
+                value ^= PRIMITIVE;
+            }
+        }
+        for power in 255..512 {
+            exp[power] = exp[power - 255];
+        }
+        Self { exp, log }
+    }
+
+    fn mul(&self, a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            self.exp[self.log[a as usize] as usize + self.log[b as usize] as usize]
+        }
+    }
+
+    fn div(&self, a: u8, b: u8) -> u8 {
+        debug_assert!(b != 0);
+        if a == 0 {
+            0
+        } else {
+            self.exp[self.log[a as usize] as usize + 255 - self.log[b as usize] as usize]
+        }
+    }
+
+    /// α raised to `power`, for any non-negative power.
+    fn alpha(&self, power: usize) -> u8 {
+        self.exp[power % 255]
+    }
+
+    /// Evaluate a polynomial stored lowest degree first.
+    fn eval(&self, poly: &[u8], x: u8) -> u8 {
+        poly.iter()
+            .rev()
+            .fold(0, |acc, &coefficient| self.mul(acc, x) ^ coefficient)
+    }
+}
+
+/// Correct up to `ec_len / 2` byte errors in a block of data followed by `ec_len` EC bytes.
+///
+/// Returns the number of corrected bytes, or `None` when the block is not correctable.
+pub(crate) fn correct(block: &mut [u8], ec_len: usize) -> Option<usize> {
+    let field = Field::new();
+    let n = block.len();
+
+    let syndromes: Vec<u8> = (0..ec_len)
+        .map(|j| block.iter().fold(0, |acc, &byte| field.mul(acc, field.alpha(j)) ^ byte))
+        .collect();
+    if syndromes.iter().all(|&s| s == 0) {
+        return Some(0);
+    }
+
+    // Berlekamp-Massey: error locator polynomial, lowest degree first.
+    let mut locator = vec![1u8];
+    let mut previous = vec![1u8];
+    let mut errors = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = 1u8;
+    for step in 0..ec_len {
+        let discrepancy = (1..=errors).fold(syndromes[step], |acc, i| {
+            acc ^ field.mul(*locator.get(i).unwrap_or(&0), syndromes[step - i])
+        });
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+
+        let factor = field.div(discrepancy, previous_discrepancy);
+        let mut next = locator.clone();
+        next.resize(next.len().max(previous.len() + shift), 0);
+        for (i, &coefficient) in previous.iter().enumerate() {
+            next[i + shift] ^= field.mul(factor, coefficient);
+        }
+
+        if 2 * errors <= step {
+            previous = std::mem::replace(&mut locator, next);
+            errors = step + 1 - errors;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            locator = next;
+            shift += 1;
+        }
+    }
+    while locator.len() > 1 && locator.last() == Some(&0) {
+        locator.pop();
+    }
+    if errors * 2 > ec_len || locator.len() - 1 != errors {
+        return None;
+    }
+
+    // Chien search: byte k holds the coefficient of x^(n-1-k), located by X = α^(n-1-k).
+    let positions: Vec<usize> = (0..n)
+        .filter(|&k| field.eval(&locator, field.alpha(255 - (n - 1 - k) % 255)) == 0)
+        .collect();
+    if positions.len() != errors {
+        return None;
+    }
+
+    // Forney: e = X · Ω(X⁻¹) / Λ'(X⁻¹) for generator roots starting at α^0.
+    let mut evaluator = vec![0u8; ec_len];
+    for (i, &s) in syndromes.iter().enumerate() {
+        for (j, &l) in locator.iter().enumerate() {
+            if i + j < ec_len {
+                evaluator[i + j] ^= field.mul(s, l);
+            }
+        }
+    }
+    let derivative: Vec<u8> = locator
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, &coefficient)| if i % 2 == 1 { coefficient } else { 0 })
+        .collect();
+
+    for &k in &positions {
+        let x = field.alpha(n - 1 - k);
+        let x_inverse = field.alpha(255 - (n - 1 - k) % 255);
+        let denominator = field.eval(&derivative, x_inverse);
+        if denominator == 0 {
+            return None;
+        }
+        let magnitude = field.mul(x, field.div(field.eval(&evaluator, x_inverse), denominator));
+        block[k] ^= magnitude;
+    }
+
+    Some(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Append `ec_len` EC bytes to `data` by polynomial division.
+    fn encode(data: &[u8], ec_len: usize) -> Vec<u8> {
+        let field = Field::new();
+        let mut generator = vec![1u8];
+        for i in 0..ec_len {
+            let mut next = vec![0u8; generator.len() + 1];
+            for (j, &coefficient) in generator.iter().enumerate() {
+                next[j] ^= coefficient;
+                next[j + 1] ^= field.mul(coefficient, field.alpha(i));
+            }
+            generator = next;
+        }
+
+        let mut remainder = vec![0u8; ec_len];
+        for &byte in data {
+            let factor = byte ^ remainder[0];
+            remainder.rotate_left(1);
+            remainder[ec_len - 1] = 0;
+            for (slot, &coefficient) in remainder.iter_mut().zip(&generator[1..]) {
+                *slot ^= field.mul(coefficient, factor);
+            }
+        }
+        [data, &remainder].concat()
+    }
+
+    #[test]
+    fn test_correct_accepts_valid_block() {
+        let mut block = encode(b"kreuzberg", 10);
+        assert_eq!(correct(&mut block, 10), Some(0));
+    }
+
+    #[test]
+    fn test_correct_repairs_up_to_half_the_ec_bytes() {
+        let original = encode(b"barcode payload", 10);
+        let mut block = original.clone();
+        for &k in &[0, 4, 9, 17, 24] {
+            block[k] ^= 0x5A;
+        }
+
+        assert_eq!(correct(&mut block, 10), Some(5));
+        assert_eq!(block, original);
+    }
+
+    #[test]
+    fn test_correct_rejects_too_many_errors() {
+        let original = encode(b"barcode payload", 6);
+        let mut block = original.clone();
+        for k in 0..5 {
+            block[k * 3] ^= 0xFF;
+        }
+
+        assert!(correct(&mut block, 6).is_none_or(|_| block != original));
+    }
+}
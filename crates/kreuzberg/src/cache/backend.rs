@@ -0,0 +1,644 @@
+//! Pluggable storage of cached extraction results.
+//!
+//! Setting `ExtractionConfig::cache` makes `extract_file` and `extract_bytes` look up
+//! results before extracting and store them afterwards. Results are keyed by a hash of the
+//! document bytes, MIME type, configuration and library version, and stored as JSON in a
+//! [`CacheBackend`]:
+//!
+//! - [`DiskCacheBackend`] - one file per result in a local directory (default)
+//! - [`MemoryCacheBackend`] - process-wide LRU bounded by `max_entries`
+//! - `RedisCacheBackend` - a Redis server shared by several instances (`redis-cache` feature)
+//!
+//! Backends are created once per distinct [`CacheConfig`] and shared by all extractions
+//! using it, so the in-memory LRU survives across calls. Failing lookups and writes are
+//! logged and the document is extracted as if nothing was cached; only a backend that
+//! cannot be created fails the extraction.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use indexmap::IndexMap;
+
+use super::blob_store::blob_hash;
+use crate::core::config::{CacheBackendKind, CacheConfig, ExtractionConfig};
+use crate::core::counters::record_cache_lookup;
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+
+/// Results kept by the in-memory backend when `max_entries` is not set.
+pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+/// Storage of cached values addressed by string keys.
+///
+/// Implementations must be safe to share between threads; keys are hex digests.
+pub trait CacheBackend: Send + Sync {
+    /// Short name of the backend, e.g. `"disk"`.
+    fn name(&self) -> &'static str;
+
+    /// Stored value of `key`, or `None` if nothing is stored.
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Store `value` under `key`, replacing any previous value.
+    fn set(&self, key: &str, value: &[u8]) -> Result<()>;
+
+    /// Remove the value of `key`, if any.
+    fn remove(&self, key: &str) -> Result<()>;
+
+    /// Remove every value and return how many were removed.
+    fn clear(&self) -> Result<usize>;
+}
+
+/// Stores every value in its own file.
+pub struct DiskCacheBackend {
+    dir: PathBuf,
+}
+
+impl DiskCacheBackend {
+    /// Create the backend, creating `dir` if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
+
+impl CacheBackend for DiskCacheBackend {
+    fn name(&self) -> &'static str {
+        "disk"
+    }
+
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match fs::read(self.path(key)) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(KreuzbergError::cache(format!("Failed to read cache file: {}", e))),
+        }
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<()> {
+        let path = self.path(key);
+        let temp_path = path.with_extension(format!("tmp.{}.{:?}", std::process::id(), std::thread::current().id()));
+        fs::write(&temp_path, value)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to write cache file: {}", e)))?;
+        fs::rename(&temp_path, &path).map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            KreuzbergError::cache(format!("Failed to rename cache file: {}", e))
+        })
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        match fs::remove_file(self.path(key)) {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                Err(KreuzbergError::cache(format!("Failed to remove cache file: {}", e)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn clear(&self) -> Result<usize> {
+        let entries = fs::read_dir(&self.dir)
+            .map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
+
+        let mut removed = 0;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") && fs::remove_file(&path).is_ok() {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// Keeps values in memory, evicting the least recently used one beyond `max_entries`.
+pub struct MemoryCacheBackend {
+    max_entries: usize,
+    /// Values ordered from least to most recently used
+    entries: Mutex<IndexMap<String, Arc<[u8]>>>,
+}
+
+impl MemoryCacheBackend {
+    /// Create an empty backend holding at most `max_entries` values.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries: max_entries.max(1),
+            entries: Mutex::new(IndexMap::new()),
+        }
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, IndexMap<String, Arc<[u8]>>>> {
+        self.entries
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Memory cache mutex poisoned: {}", e)))
+    }
+}
+
+impl CacheBackend for MemoryCacheBackend {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let mut entries = self.lock()?;
+        let Some(index) = entries.get_index_of(key) else {
+            return Ok(None);
+        };
+        let last = entries.len() - 1;
+        entries.move_index(index, last);
+        Ok(entries.get_index(last).map(|(_, value)| value.to_vec()))
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<()> {
+        let mut entries = self.lock()?;
+        entries.shift_remove(key);
+        entries.insert(key.to_string(), Arc::from(value));
+        while entries.len() > self.max_entries {
+            entries.shift_remove_index(0);
+        }
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        self.lock()?.shift_remove(key);
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<usize> {
+        let mut entries = self.lock()?;
+        let removed = entries.len();
+        entries.clear();
+        Ok(removed)
+    }
+}
+
+/// Stores values on a Redis server under a `kreuzberg:` key prefix.
+///
+/// Redis evicts values according to its own `maxmemory-policy`; `max_entries` does not apply.
+#[cfg(feature = "redis-cache")]
+pub struct RedisCacheBackend {
+    client: redis::Client,
+    prefix: String,
+    /// Reused connection, dropped after a failed command so the next one reconnects
+    connection: Mutex<Option<redis::Connection>>,
+}
+
+#[cfg(feature = "redis-cache")]
+impl RedisCacheBackend {
+    /// Create the backend for a `redis://` or `rediss://` URL.
+    ///
+    /// The connection is opened by the first command, so an unreachable server does not
+    /// fail here.
+    pub fn new(url: &str) -> Result<Self> {
+        let client = redis::Client::open(url)
+            .map_err(|e| KreuzbergError::validation(format!("Invalid Redis cache URL: {}", e)))?;
+        Ok(Self {
+            client,
+            prefix: "kreuzberg:results:".to_string(),
+            connection: Mutex::new(None),
+        })
+    }
+
+    /// Keep the values of this backend under `kreuzberg:results:<namespace>:`.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.prefix = format!("kreuzberg:results:{}:", namespace);
+        self
+    }
+
+    fn with_connection<T>(&self, command: impl FnOnce(&mut redis::Connection) -> redis::RedisResult<T>) -> Result<T> {
+        let mut slot = self
+            .connection
+            .lock()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Redis cache mutex poisoned: {}", e)))?;
+        let connection = match slot.as_mut() {
+            Some(connection) => connection,
+            None => slot.insert(
+                self.client
+                    .get_connection()
+                    .map_err(|e| KreuzbergError::cache(format!("Failed to connect to Redis: {}", e)))?,
+            ),
+        };
+        command(connection).map_err(|e| {
+            *slot = None;
+            KreuzbergError::cache(format!("Redis command failed: {}", e))
+        })
+    }
+}
+
+#[cfg(feature = "redis-cache")]
+impl CacheBackend for RedisCacheBackend {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        use redis::Commands;
+        let key = format!("{}{}", self.prefix, key);
+        self.with_connection(|connection| connection.get(&key))
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<()> {
+        use redis::Commands;
+        let key = format!("{}{}", self.prefix, key);
+        self.with_connection(|connection| connection.set(&key, value))
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        use redis::Commands;
+        let key = format!("{}{}", self.prefix, key);
+        self.with_connection(|connection| connection.del(&key))
+    }
+
+    fn clear(&self) -> Result<usize> {
+        use redis::Commands;
+        let pattern = format!("{}*", self.prefix);
+        let keys: Vec<String> =
+            self.with_connection(|connection| Ok(connection.scan_match::<_, String>(&pattern)?.collect()))?;
+        if keys.is_empty() {
+            return Ok(0);
+        }
+        self.with_connection(|connection| connection.del(&keys))
+    }
+}
+
+/// Backends created so far, by configuration.
+static BACKENDS: LazyLock<Mutex<HashMap<CacheConfig, Arc<dyn CacheBackend>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The backend described by `config`, created on first use and shared afterwards.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Cache` if the disk cache directory cannot be created and
+/// `KreuzbergError::Validation` for an invalid Redis URL, or for the Redis backend without
+/// the `redis-cache` feature.
+pub fn backend_for(config: &CacheConfig) -> Result<Arc<dyn CacheBackend>> {
+    let mut backends = BACKENDS
+        .lock()
+        .map_err(|e| KreuzbergError::LockPoisoned(format!("Cache backend registry poisoned: {}", e)))?;
+    if let Some(backend) = backends.get(config) {
+        return Ok(Arc::clone(backend));
+    }
+    if let Some(namespace) = config.namespace.as_deref() {
+        validate_namespace(namespace)?;
+    }
+
+    let backend: Arc<dyn CacheBackend> = match config.backend {
+        CacheBackendKind::Disk => {
+            let dir = config.url.as_ref().map(PathBuf::from).unwrap_or_else(default_disk_dir);
+            Arc::new(DiskCacheBackend::new(match config.namespace.as_deref() {
+                Some(namespace) => dir.join(namespace),
+                None => dir,
+            })?)
+        }
+        CacheBackendKind::Memory => Arc::new(MemoryCacheBackend::new(
+            config.max_entries.unwrap_or(DEFAULT_MAX_ENTRIES),
+        )),
+        #[cfg(feature = "redis-cache")]
+        CacheBackendKind::Redis => {
+            let url = config
+                .url
+                .as_deref()
+                .ok_or_else(|| KreuzbergError::validation("The Redis cache backend requires a url"))?;
+            let backend = RedisCacheBackend::new(url)?;
+            Arc::new(match config.namespace.as_deref() {
+                Some(namespace) => backend.with_namespace(namespace),
+                None => backend,
+            })
+        }
+        #[cfg(not(feature = "redis-cache"))]
+        CacheBackendKind::Redis => {
+            return Err(KreuzbergError::validation(
+                "The Redis cache backend requires the `redis-cache` feature",
+            ));
+        }
+    };
+    backends.insert(config.clone(), Arc::clone(&backend));
+    Ok(backend)
+}
+
+/// Drop every shared backend, closing Redis connections and freeing in-memory caches.
+///
+/// Disk and Redis entries are kept; the next [`backend_for`] call opens the backend again.
+pub fn release_backends() {
+    BACKENDS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clear();
+}
+
+/// Namespaces become directory names and key prefixes, so they are restricted to a safe
+/// character set.
+fn validate_namespace(namespace: &str) -> Result<()> {
+    let valid = !namespace.is_empty()
+        && namespace.len() <= 64
+        && namespace
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+    if valid {
+        Ok(())
+    } else {
+        Err(KreuzbergError::validation(format!(
+            "Invalid cache namespace '{}': use 1 to 64 ASCII letters, digits, '-' or '_'",
+            namespace
+        )))
+    }
+}
+
+fn default_disk_dir() -> PathBuf {
+    std::env::var_os("KREUZBERG_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            std::env::current_dir()
+                .unwrap_or_else(|_| PathBuf::from("."))
+                .join(".kreuzberg")
+        })
+        .join("results")
+}
+
+/// Cache key of the result of extracting the file at `path` with `config`, or `None` when
+/// `config` does not cache results.
+///
+/// The MIME type is detected from the path unless given, as `extract_file` does. The
+/// configured backend is created if needed, so [`invalidate_result`] reaches it afterwards.
+///
+/// # Errors
+///
+/// Returns the errors of reading the file, detecting its MIME type and creating the backend.
+pub fn result_cache_key_for_file(
+    path: &Path,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+) -> Result<Option<String>> {
+    if !ResultCache::is_enabled(config) {
+        return Ok(None);
+    }
+    let mime_type = crate::core::mime::detect_or_validate(Some(path), mime_type)?;
+    let content = crate::core::io::read_file_sync(path)?;
+    Ok(ResultCache::for_extraction(config, &content, &mime_type)?.map(|slot| slot.key))
+}
+
+/// Remove the result stored under `key` from every backend created by this process.
+///
+/// Returns the number of backends the key was removed from; a key that is not stored is
+/// not an error. Backends are created by the first extraction using them, or by
+/// [`result_cache_key_for_file`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` for keys that are not hex digests and the first
+/// error of a backend that failed to remove the key.
+pub fn invalidate_result(key: &str) -> Result<usize> {
+    if key.is_empty() || !key.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(KreuzbergError::validation(format!("Invalid cache key '{}'", key)));
+    }
+
+    let backends: Vec<Arc<dyn CacheBackend>> = BACKENDS
+        .lock()
+        .map_err(|e| KreuzbergError::LockPoisoned(format!("Cache backend registry poisoned: {}", e)))?
+        .values()
+        .cloned()
+        .collect();
+    for backend in &backends {
+        backend.remove(key)?;
+    }
+    Ok(backends.len())
+}
+
+/// Bytes identifying everything in `config` that can change an extraction result.
+fn config_fingerprint(config: &ExtractionConfig) -> Result<Vec<u8>> {
+    #[cfg_attr(not(feature = "html"), allow(unused_mut))]
+    let mut fingerprint = serde_json::to_vec(config)?;
+
+    // Set programmatically and skipped by serde, but changes the converted HTML
+    #[cfg(feature = "html")]
+    if let Some(html_options) = &config.html_options {
+        fingerprint.extend_from_slice(format!("\0{html_options:?}").as_bytes());
+    }
+
+    Ok(fingerprint)
+}
+
+/// Cached result slot of one extraction.
+pub(crate) struct ResultCache {
+    backend: Arc<dyn CacheBackend>,
+    key: String,
+}
+
+impl ResultCache {
+    /// Whether `config` caches extraction results.
+    pub(crate) fn is_enabled(config: &ExtractionConfig) -> bool {
+        config.use_cache && config.cache.is_some()
+    }
+
+    /// The slot of extracting `content` as `mime_type` with `config`, or `None` when
+    /// result caching is off.
+    pub(crate) fn for_extraction(config: &ExtractionConfig, content: &[u8], mime_type: &str) -> Result<Option<Self>> {
+        let Some(cache_config) = config.cache.as_ref().filter(|_| Self::is_enabled(config)) else {
+            return Ok(None);
+        };
+
+        let backend = backend_for(cache_config)?;
+        let key = blob_hash(
+            format!(
+                "{}\0{}\0{}\0{}",
+                env!("CARGO_PKG_VERSION"),
+                mime_type,
+                blob_hash(&config_fingerprint(config)?),
+                blob_hash(content)
+            )
+            .as_bytes(),
+        );
+        Ok(Some(Self { backend, key }))
+    }
+
+    /// The stored result, if any; unreadable entries are removed.
+    pub(crate) fn get(&self) -> Option<ExtractionResult> {
+        let result = self.lookup();
+        record_cache_lookup(result.is_some());
+        result
+    }
+
+    fn lookup(&self) -> Option<ExtractionResult> {
+        let data = match self.backend.get(&self.key) {
+            Ok(data) => data?,
+            Err(e) => {
+                tracing::warn!("Failed to read {} result cache: {}", self.backend.name(), e);
+                return None;
+            }
+        };
+        match serde_json::from_slice(&data) {
+            Ok(result) => Some(result),
+            Err(_) => {
+                let _ = self.backend.remove(&self.key);
+                None
+            }
+        }
+    }
+
+    /// Store `result`, logging failures.
+    pub(crate) fn store(&self, result: &ExtractionResult) {
+        let stored = serde_json::to_vec(result)
+            .map_err(KreuzbergError::from)
+            .and_then(|data| self.backend.set(&self.key, &data));
+        if let Err(e) = stored {
+            tracing::warn!("Failed to write {} result cache: {}", self.backend.name(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_backend_evicts_least_recently_used() {
+        let backend = MemoryCacheBackend::new(2);
+        backend.set("a", b"1").unwrap();
+        backend.set("b", b"2").unwrap();
+        assert_eq!(backend.get("a").unwrap(), Some(b"1".to_vec()));
+
+        backend.set("c", b"3").unwrap();
+        assert_eq!(backend.get("b").unwrap(), None);
+        assert_eq!(backend.get("a").unwrap(), Some(b"1".to_vec()));
+        assert_eq!(backend.get("c").unwrap(), Some(b"3".to_vec()));
+        assert_eq!(backend.clear().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_disk_backend_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let backend = DiskCacheBackend::new(dir.path()).unwrap();
+
+        assert_eq!(backend.get("key").unwrap(), None);
+        backend.set("key", b"value").unwrap();
+        assert_eq!(backend.get("key").unwrap(), Some(b"value".to_vec()));
+        backend.remove("key").unwrap();
+        backend.remove("key").unwrap();
+        assert_eq!(backend.get("key").unwrap(), None);
+
+        backend.set("other", b"value").unwrap();
+        assert_eq!(backend.clear().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_namespaces_get_separate_backends() {
+        let dir = tempfile::tempdir().unwrap();
+        let namespaced = |namespace: &str| CacheConfig {
+            url: Some(dir.path().to_string_lossy().into_owned()),
+            namespace: Some(namespace.to_string()),
+            ..Default::default()
+        };
+
+        let tenant_a = backend_for(&namespaced("tenant-a")).unwrap();
+        let tenant_b = backend_for(&namespaced("tenant_b")).unwrap();
+        tenant_a.set("key", b"a").unwrap();
+        assert_eq!(tenant_b.get("key").unwrap(), None);
+        assert!(dir.path().join("tenant-a").join("key.json").exists());
+
+        let memory = |namespace: &str| CacheConfig {
+            backend: CacheBackendKind::Memory,
+            namespace: Some(namespace.to_string()),
+            ..Default::default()
+        };
+        backend_for(&memory("tenant-a")).unwrap().set("key", b"a").unwrap();
+        assert_eq!(backend_for(&memory("tenant-b")).unwrap().get("key").unwrap(), None);
+
+        assert!(backend_for(&namespaced("../escape")).is_err());
+        assert!(backend_for(&namespaced("")).is_err());
+    }
+
+    #[test]
+    fn test_invalidate_result_removes_key_from_backends() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("note.txt");
+        fs::write(&path, "hello").unwrap();
+        let config = ExtractionConfig {
+            cache: Some(CacheConfig {
+                backend: CacheBackendKind::Memory,
+                max_entries: Some(5),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let key = result_cache_key_for_file(&path, None, &config).unwrap().unwrap();
+        let backend = backend_for(config.cache.as_ref().unwrap()).unwrap();
+        backend.set(&key, b"{}").unwrap();
+
+        assert!(invalidate_result(&key).unwrap() >= 1);
+        assert_eq!(backend.get(&key).unwrap(), None);
+        assert!(invalidate_result("../escape").is_err());
+        assert_eq!(
+            result_cache_key_for_file(&path, None, &ExtractionConfig::default()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_result_cache_keys_depend_on_config_and_content() {
+        let config = ExtractionConfig {
+            cache: Some(CacheConfig {
+                backend: CacheBackendKind::Memory,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let key = |config: &ExtractionConfig, content: &[u8]| {
+            ResultCache::for_extraction(config, content, "text/plain")
+                .unwrap()
+                .map(|slot| slot.key)
+        };
+
+        let base = key(&config, b"hello").unwrap();
+        assert_eq!(key(&config, b"hello").unwrap(), base);
+        assert_ne!(key(&config, b"world").unwrap(), base);
+
+        let changed = ExtractionConfig {
+            enable_quality_processing: !config.enable_quality_processing,
+            ..config.clone()
+        };
+        assert_ne!(key(&changed, b"hello").unwrap(), base);
+
+        let disabled = ExtractionConfig {
+            use_cache: false,
+            ..config.clone()
+        };
+        assert!(key(&disabled, b"hello").is_none());
+        assert!(key(&ExtractionConfig::default(), b"hello").is_none());
+    }
+
+    #[cfg(feature = "html")]
+    #[test]
+    fn test_result_cache_keys_depend_on_html_options() {
+        let config = ExtractionConfig {
+            cache: Some(CacheConfig {
+                backend: CacheBackendKind::Memory,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let key = |config: &ExtractionConfig| {
+            ResultCache::for_extraction(config, b"<h1>Title</h1>", "text/html")
+                .unwrap()
+                .map(|slot| slot.key)
+                .unwrap()
+        };
+        let with_options = |heading_style| ExtractionConfig {
+            html_options: Some(html_to_markdown_rs::ConversionOptions {
+                heading_style,
+                ..Default::default()
+            }),
+            ..config.clone()
+        };
+
+        let atx = key(&with_options(html_to_markdown_rs::HeadingStyle::Atx));
+        assert_eq!(key(&with_options(html_to_markdown_rs::HeadingStyle::Atx)), atx);
+        assert_ne!(key(&with_options(html_to_markdown_rs::HeadingStyle::Underlined)), atx);
+        assert_ne!(key(&config), atx);
+    }
+}
@@ -0,0 +1,380 @@
+//! Content-addressed storage for extracted image bytes.
+//!
+//! With `images.blob_store_dir` configured, image bytes are written once to
+//! `<dir>/<hash[..2]>/<hash>.<format>` and results carry an [`ImageBlobRef`] instead of
+//! the bytes themselves. This keeps the extraction cache, FFI JSON and binding objects
+//! small, and images repeated across documents are stored once. Bytes are read back on
+//! demand with [`read_blob`] or [`read_image_data`].
+//!
+//! With `spill_dir` configured, [`spill_result`] uses the same layout to bound the memory
+//! a result holds: once page text and image bytes exceed `spill_threshold_bytes`, the
+//! remaining pages and images are written to disk and read back with
+//! [`read_page_content`] and [`read_image_data`].
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::fast_hash;
+use crate::error::{KreuzbergError, Result};
+use crate::types::{ExtractedImage, ExtractionResult, ImageBlobRef, PageContent};
+
+/// Page text and image bytes a result keeps in memory when `spill_threshold_bytes` is unset.
+pub const DEFAULT_SPILL_THRESHOLD_BYTES: usize = 64 * 1024 * 1024;
+
+/// Hex-encoded content address of a blob.
+pub fn blob_hash(data: &[u8]) -> String {
+    format!("{:016x}", fast_hash(data))
+}
+
+/// Write bytes to the blob store and return a reference to them.
+///
+/// Writing is idempotent: a blob that already exists with the same size is not rewritten.
+pub fn store_blob(dir: &Path, data: &[u8], format: &str) -> Result<ImageBlobRef> {
+    let hash = blob_hash(data);
+    let extension: String = format.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+    let extension = if extension.is_empty() {
+        "bin".to_string()
+    } else {
+        extension
+    };
+
+    let shard_dir = dir.join(&hash[..2]);
+    let path = shard_dir.join(format!("{}.{}", hash, extension));
+
+    let exists = fs::metadata(&path).is_ok_and(|meta| meta.len() == data.len() as u64);
+    if !exists {
+        fs::create_dir_all(&shard_dir)?;
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let temp_path = shard_dir.join(format!(
+            "{}.tmp.{}.{:?}.{}",
+            hash,
+            std::process::id(),
+            std::thread::current().id(),
+            timestamp
+        ));
+
+        fs::write(&temp_path, data)?;
+        fs::rename(&temp_path, &path).map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            KreuzbergError::cache(format!("Failed to store blob {}: {}", hash, e))
+        })?;
+    }
+
+    Ok(ImageBlobRef {
+        hash,
+        path: path.to_string_lossy().into_owned(),
+        size: data.len(),
+    })
+}
+
+/// Read the bytes of a blob, verifying their size and hash.
+pub fn read_blob(blob: &ImageBlobRef) -> Result<Vec<u8>> {
+    let data = fs::read(&blob.path)?;
+
+    if data.len() != blob.size || blob_hash(&data) != blob.hash {
+        return Err(KreuzbergError::cache(format!(
+            "Blob {} at {} does not match its reference",
+            blob.hash, blob.path
+        )));
+    }
+
+    Ok(data)
+}
+
+/// Bytes of an extracted image, read from the blob store when they are not inlined.
+pub fn read_image_data(image: &ExtractedImage) -> Result<Cow<'_, [u8]>> {
+    match &image.blob {
+        Some(blob) if image.data.is_empty() => read_blob(blob).map(Cow::Owned),
+        _ => Ok(Cow::Borrowed(&image.data)),
+    }
+}
+
+/// Text of a page, read from disk when the page was spilled.
+pub fn read_page_content(page: &PageContent) -> Result<Cow<'_, str>> {
+    match &page.content_blob {
+        Some(blob) if page.content.is_empty() => {
+            let data = read_blob(blob)?;
+            String::from_utf8(data)
+                .map(Cow::Owned)
+                .map_err(|e| KreuzbergError::cache(format!("Blob {} is not UTF-8 text: {}", blob.hash, e)))
+        }
+        _ => Ok(Cow::Borrowed(&page.content)),
+    }
+}
+
+/// Move the bytes of an image into the blob store, leaving a reference behind.
+pub fn store_image(image: &mut ExtractedImage, dir: &Path) -> Result<()> {
+    if image.blob.is_some() && image.data.is_empty() {
+        return Ok(());
+    }
+
+    image.blob = Some(store_blob(dir, &image.data, &image.format)?);
+    image.data = Vec::new();
+    Ok(())
+}
+
+/// Move the bytes of every document and page image into the blob store.
+///
+/// Returns one message per image that could not be stored; those images keep their bytes.
+pub fn store_result_images(result: &mut ExtractionResult, dir: &Path) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut stored = HashMap::new();
+
+    for image in result.images.iter_mut().flatten() {
+        match store_image(image, dir) {
+            Ok(()) => {
+                if let Some(blob) = &image.blob {
+                    stored.insert(image.image_index, blob.clone());
+                }
+            }
+            Err(e) => errors.push(format!("image {}: {}", image.image_index, e)),
+        }
+    }
+
+    // Page images are copies of the document-level images, so reuse their references
+    for page in result.pages.iter_mut().flatten() {
+        for image in page.images.iter_mut() {
+            let blob = stored
+                .get(&image.image_index)
+                .filter(|blob| blob.size == image.data.len())
+                .cloned();
+            let image = Arc::make_mut(image);
+            match blob {
+                Some(blob) => {
+                    image.blob = Some(blob);
+                    image.data = Vec::new();
+                }
+                None => {
+                    let _ = store_image(image, dir);
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Write the page text and image bytes a result holds beyond `threshold` bytes to `dir`.
+///
+/// Pages are kept in memory in order until their text exceeds the threshold, then
+/// images until the combined size does; everything after that is replaced by a blob
+/// reference. `content`, tables and metadata always stay in memory.
+///
+/// Returns one message per page or image that could not be written; those keep their data.
+pub fn spill_result(result: &mut ExtractionResult, dir: &Path, threshold: usize) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut retained = 0usize;
+
+    for page in result.pages.iter_mut().flatten() {
+        if page.content.is_empty() {
+            continue;
+        }
+        retained += page.content.len();
+        if retained <= threshold {
+            continue;
+        }
+
+        match store_blob(dir, page.content.as_bytes(), "txt") {
+            Ok(blob) => {
+                page.content_blob = Some(blob);
+                page.content = String::new();
+            }
+            Err(e) => errors.push(format!("page {}: {}", page.page_number, e)),
+        }
+    }
+
+    let mut spilled = HashMap::new();
+    for image in result.images.iter_mut().flatten() {
+        if image.data.is_empty() {
+            continue;
+        }
+        retained += image.data.len();
+        if retained <= threshold {
+            continue;
+        }
+
+        match store_image(image, dir) {
+            Ok(()) => {
+                if let Some(blob) = &image.blob {
+                    spilled.insert(image.image_index, blob.clone());
+                }
+            }
+            Err(e) => errors.push(format!("image {}: {}", image.image_index, e)),
+        }
+    }
+
+    // Page images are copies of the document-level images, so reuse their references
+    for page in result.pages.iter_mut().flatten() {
+        for image in page.images.iter_mut() {
+            if image.data.is_empty() {
+                continue;
+            }
+            let blob = spilled
+                .get(&image.image_index)
+                .filter(|blob| blob.size == image.data.len())
+                .cloned();
+            if blob.is_none() {
+                retained += image.data.len();
+                if retained <= threshold {
+                    continue;
+                }
+            }
+
+            let image = Arc::make_mut(image);
+            match blob {
+                Some(blob) => {
+                    image.blob = Some(blob);
+                    image.data = Vec::new();
+                }
+                None => {
+                    if let Err(e) = store_image(image, dir) {
+                        errors.push(format!("page {} image {}: {}", page.page_number, image.image_index, e));
+                    }
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn image(data: &[u8], image_index: usize) -> ExtractedImage {
+        ExtractedImage {
+            data: data.to_vec(),
+            format: "png".to_string(),
+            image_index,
+            page_number: Some(1),
+            width: None,
+            height: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
+        }
+    }
+
+    #[test]
+    fn test_store_blob_is_content_addressed() {
+        let dir = tempdir().unwrap();
+
+        let first = store_blob(dir.path(), b"image bytes", "png").unwrap();
+        let second = store_blob(dir.path(), b"image bytes", "png").unwrap();
+        let other = store_blob(dir.path(), b"other bytes", "../jpeg").unwrap();
+
+        assert_eq!(first, second);
+        assert_ne!(first.hash, other.hash);
+        assert_eq!(first.size, 11);
+        assert!(first.path.ends_with(&format!("{}.png", first.hash)));
+        assert!(other.path.ends_with(&format!("{}.jpeg", other.hash)));
+        assert_eq!(read_blob(&first).unwrap(), b"image bytes");
+    }
+
+    #[test]
+    fn test_read_blob_detects_modified_blob() {
+        let dir = tempdir().unwrap();
+        let blob = store_blob(dir.path(), b"image bytes", "png").unwrap();
+
+        fs::write(&blob.path, b"IMAGE BYTES").unwrap();
+
+        assert!(read_blob(&blob).is_err());
+    }
+
+    #[test]
+    fn test_store_result_images_replaces_bytes_with_references() {
+        let dir = tempdir().unwrap();
+        let mut result = ExtractionResult {
+            content: String::new(),
+            mime_type: "application/pdf".to_string(),
+            metadata: Default::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: Some(vec![image(b"logo", 0), image(b"logo", 1)]),
+            pages: Some(vec![crate::types::PageContent {
+                page_number: 1,
+                content: String::new(),
+                tables: vec![],
+                images: vec![Arc::new(image(b"logo", 0))],
+                content_blob: None,
+            }]),
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        };
+
+        let errors = store_result_images(&mut result, dir.path());
+
+        assert!(errors.is_empty());
+        let images = result.images.as_ref().unwrap();
+        assert!(images.iter().all(|image| image.data.is_empty()));
+        assert_eq!(images[0].blob, images[1].blob);
+        let page_image = &result.pages.as_ref().unwrap()[0].images[0];
+        assert_eq!(page_image.blob, images[0].blob);
+        assert_eq!(read_image_data(page_image).unwrap().as_ref(), b"logo");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_spill_result_keeps_data_up_to_threshold() {
+        let dir = tempdir().unwrap();
+        let page = |page_number: usize, content: &str, images: Vec<Arc<ExtractedImage>>| PageContent {
+            page_number,
+            content: content.to_string(),
+            tables: vec![],
+            images,
+            content_blob: None,
+        };
+        let mut result = ExtractionResult {
+            content: "first second third".to_string(),
+            mime_type: "application/pdf".to_string(),
+            metadata: Default::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: Some(vec![image(b"logo", 0)]),
+            pages: Some(vec![
+                page(1, "first", vec![]),
+                page(2, "second", vec![]),
+                page(3, "third", vec![Arc::new(image(b"logo", 0))]),
+            ]),
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        };
+
+        let errors = spill_result(&mut result, dir.path(), 8);
+
+        assert!(errors.is_empty());
+        assert_eq!(result.content, "first second third");
+        let pages = result.pages.as_ref().unwrap();
+        assert_eq!(pages[0].content, "first");
+        assert!(pages[0].content_blob.is_none());
+        assert!(pages[1].content.is_empty());
+        assert_eq!(read_page_content(&pages[1]).unwrap(), "second");
+        assert_eq!(read_page_content(&pages[2]).unwrap(), "third");
+
+        let images = result.images.as_ref().unwrap();
+        assert!(images[0].data.is_empty());
+        assert_eq!(pages[2].images[0].blob, images[0].blob);
+        assert_eq!(read_image_data(&pages[2].images[0]).unwrap().as_ref(), b"logo");
+    }
+}
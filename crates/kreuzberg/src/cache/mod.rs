@@ -23,6 +23,9 @@
 //! This approach ensures that lock poisoning (rare in practice) is surfaced to users
 //! rather than causing panics, maintaining system stability during concurrent operations.
 
+pub mod backend;
+pub mod blob_store;
+
 use crate::error::{KreuzbergError, Result};
 use ahash::AHasher;
 use serde::{Deserialize, Serialize};
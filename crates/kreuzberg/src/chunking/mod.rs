@@ -6,6 +6,7 @@
 //! # Features
 //!
 //! - **Smart splitting**: Respects word and sentence boundaries
+//! - **Sentence packing**: Optionally keeps whole sentences together, with language-specific rules
 //! - **Markdown-aware**: Preserves Markdown structure (headings, code blocks, lists)
 //! - **Configurable overlap**: Overlap chunks to maintain context
 //! - **Unicode support**: Handles CJK characters and emojis correctly
@@ -27,6 +28,8 @@
 //!     overlap: 50,
 //!     trim: true,
 //!     chunker_type: ChunkerType::Text,
+//!     respect_sentences: false,
+//!     language: None,
 //! };
 //!
 //! let long_text = "This is a very long document...".repeat(100);
@@ -55,6 +58,7 @@ use std::sync::Arc;
 use text_splitter::{Characters, ChunkCapacity, ChunkConfig, MarkdownSplitter, TextSplitter};
 
 pub mod processor;
+pub mod sentences;
 pub use processor::ChunkingProcessor;
 
 /// Threshold below which we use O(1) direct validation instead of precomputing a BitVec.
@@ -83,6 +87,10 @@ pub struct ChunkingConfig {
     pub overlap: usize,
     pub trim: bool,
     pub chunker_type: ChunkerType,
+    /// Pack whole sentences into chunks, see [`sentences`]; the markdown chunker rejects it
+    pub respect_sentences: bool,
+    /// ISO 639 code selecting the sentence rules, `None` for the English rules
+    pub language: Option<String>,
 }
 
 impl Default for ChunkingConfig {
@@ -92,6 +100,8 @@ impl Default for ChunkingConfig {
             overlap: 100,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         }
     }
 }
@@ -409,6 +419,37 @@ fn calculate_page_range(
     Ok((first_page, last_page))
 }
 
+/// Build a chunk for `content` at `byte_start..byte_end`, mapped to its pages.
+fn build_chunk(
+    content: &str,
+    byte_start: usize,
+    byte_end: usize,
+    chunk_index: usize,
+    total_chunks: usize,
+    page_boundaries: Option<&[PageBoundary]>,
+) -> Result<Chunk> {
+    let (first_page, last_page) = if let Some(boundaries) = page_boundaries {
+        calculate_page_range(byte_start, byte_end, boundaries)?
+    } else {
+        (None, None)
+    };
+
+    Ok(Chunk {
+        content: content.to_string(),
+        embedding: None,
+        metadata: ChunkMetadata {
+            byte_start,
+            byte_end,
+            token_count: None,
+            chunk_index,
+            total_chunks,
+            first_page,
+            last_page,
+            annotations: Default::default(),
+        },
+    })
+}
+
 /// Split text into chunks with optional page boundary tracking.
 ///
 /// # Arguments
@@ -432,6 +473,8 @@ fn calculate_page_range(
 ///     overlap: 50,
 ///     trim: true,
 ///     chunker_type: ChunkerType::Text,
+///     respect_sentences: false,
+///     language: None,
 /// };
 /// let result = chunk_text("Long text...", &config, None)?;
 /// assert!(!result.chunks.is_empty());
@@ -443,6 +486,12 @@ pub fn chunk_text(
     config: &ChunkingConfig,
     page_boundaries: Option<&[PageBoundary]>,
 ) -> Result<ChunkingResult> {
+    if config.respect_sentences && config.chunker_type == ChunkerType::Markdown {
+        return Err(KreuzbergError::validation(
+            "respect_sentences is only supported by the text chunker".to_string(),
+        ));
+    }
+
     if text.is_empty() {
         return Ok(ChunkingResult {
             chunks: vec![],
@@ -454,6 +503,27 @@ pub fn chunk_text(
         validate_utf8_boundaries(text, boundaries)?;
     }
 
+    if config.respect_sentences {
+        let ranges = sentences::sentence_chunk_ranges(text, config)?;
+        let total_chunks = ranges.len();
+        let chunks = ranges
+            .into_iter()
+            .enumerate()
+            .map(|(index, range)| {
+                build_chunk(
+                    &text[range.clone()],
+                    range.start,
+                    range.end,
+                    index,
+                    total_chunks,
+                    page_boundaries,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let chunk_count = chunks.len();
+        return Ok(ChunkingResult { chunks, chunk_count });
+    }
+
     let chunk_config = build_chunk_config(config.max_characters, config.overlap, config.trim)?;
 
     let text_chunks: Vec<&str> = match config.chunker_type {
@@ -484,25 +554,14 @@ pub fn chunk_text(
         };
         byte_offset = byte_end - overlap_chars;
 
-        let (first_page, last_page) = if let Some(boundaries) = page_boundaries {
-            calculate_page_range(byte_start, byte_end, boundaries)?
-        } else {
-            (None, None)
-        };
-
-        chunks.push(Chunk {
-            content: chunk_text.to_string(),
-            embedding: None,
-            metadata: ChunkMetadata {
-                byte_start,
-                byte_end,
-                token_count: None,
-                chunk_index: index,
-                total_chunks,
-                first_page,
-                last_page,
-            },
-        });
+        chunks.push(build_chunk(
+            chunk_text,
+            byte_start,
+            byte_end,
+            index,
+            total_chunks,
+            page_boundaries,
+        )?);
     }
 
     let chunk_count = chunks.len();
@@ -522,6 +581,8 @@ pub fn chunk_text_with_type(
         overlap,
         trim,
         chunker_type,
+        respect_sentences: false,
+        language: None,
     };
     chunk_text(text, &config, None)
 }
@@ -549,6 +610,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "This is a short text.";
         let result = chunk_text(text, &config, None).unwrap();
@@ -564,6 +627,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -579,6 +644,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "abcdefghijklmnopqrstuvwxyz0123456789";
         let result = chunk_text(text, &config, None).unwrap();
@@ -602,6 +669,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Markdown,
+            respect_sentences: false,
+            language: None,
         };
         let markdown = "# Title\n\nParagraph one.\n\n## Section\n\nParagraph two.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -616,6 +685,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Markdown,
+            respect_sentences: false,
+            language: None,
         };
         let markdown = "# Code Example\n\n```python\nprint('hello')\n```\n\nSome text after code.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -630,6 +701,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Markdown,
+            respect_sentences: false,
+            language: None,
         };
         let markdown = "Check out [this link](https://example.com) for more info.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -644,6 +717,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "  Leading and trailing spaces  should be trimmed  ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -658,6 +733,8 @@ mod tests {
             overlap: 5,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "  Text with spaces  ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -672,6 +749,8 @@ mod tests {
             overlap: 20,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let result = chunk_text("Some text", &config, None);
         assert!(result.is_err());
@@ -709,6 +788,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let texts = vec!["First text", "Second text", "Third text"];
         let results = chunk_texts_batch(&texts, &config).unwrap();
@@ -723,6 +804,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let texts = vec![
             "Short",
@@ -743,6 +826,8 @@ mod tests {
             overlap: 20,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let texts = vec!["Text one", "Text two"];
         let result = chunk_texts_batch(&texts, &config);
@@ -765,6 +850,8 @@ mod tests {
             overlap: 20,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "a".repeat(1000);
         let result = chunk_text(&text, &config, None).unwrap();
@@ -779,6 +866,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Line one\nLine two\nLine three\nLine four\nLine five";
         let result = chunk_text(text, &config, None).unwrap();
@@ -792,6 +881,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Markdown,
+            respect_sentences: false,
+            language: None,
         };
         let markdown = "# List Example\n\n- Item 1\n- Item 2\n- Item 3\n\nMore text.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -806,6 +897,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Markdown,
+            respect_sentences: false,
+            language: None,
         };
         let markdown = "# Table\n\n| Col1 | Col2 |\n|------|------|\n| A    | B    |\n| C    | D    |";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -820,6 +913,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Special chars: @#$%^&*()[]{}|\\<>?/~`";
         let result = chunk_text(text, &config, None).unwrap();
@@ -834,6 +929,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Unicode: 你好世界 🌍 café résumé";
         let result = chunk_text(text, &config, None).unwrap();
@@ -849,6 +946,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "日本語のテキストです。これは長い文章で、複数のチャンクに分割されるべきです。";
         let result = chunk_text(text, &config, None).unwrap();
@@ -862,6 +961,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "English text mixed with 中文文本 and some français";
         let result = chunk_text(text, &config, None).unwrap();
@@ -875,6 +976,8 @@ mod tests {
             overlap: 5,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE FFFFF";
         let result = chunk_text(text, &config, None).unwrap();
@@ -927,6 +1030,8 @@ mod tests {
             overlap: 0,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE FFFFF";
         let result = chunk_text(text, &config, None).unwrap();
@@ -953,6 +1058,8 @@ mod tests {
             overlap: 3,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "0123456789 ABCDEFGHIJ KLMNOPQRST UVWXYZ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -987,6 +1094,8 @@ mod tests {
                 overlap,
                 trim: false,
                 chunker_type: ChunkerType::Text,
+                respect_sentences: false,
+                language: None,
             };
             let text = "Word ".repeat(30);
             let result = chunk_text(&text, &config, None).unwrap();
@@ -1019,6 +1128,8 @@ mod tests {
             overlap: 5,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE";
         let result = chunk_text(text, &config, None).unwrap();
@@ -1048,6 +1159,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Page one content here. Page two starts here and continues.";
 
@@ -1080,6 +1193,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "This is some test content that should be split into multiple chunks.";
 
@@ -1099,6 +1214,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Some text content here.";
         let boundaries: Vec<PageBoundary> = vec![];
@@ -1119,6 +1236,8 @@ mod tests {
             overlap: 5,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "0123456789 AAAAAAAAAA 1111111111 BBBBBBBBBB 2222222222";
 
@@ -1157,6 +1276,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Page one content here. Page two content.";
 
@@ -1182,6 +1303,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Page one content here. Page two content.";
 
@@ -1214,6 +1337,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Page one content here. Page two content.";
 
@@ -1282,6 +1407,8 @@ mod tests {
                 overlap: 5,
                 trim: false,
                 chunker_type: ChunkerType::Text,
+                respect_sentences: false,
+                language: None,
             },
             Some(&boundaries),
         );
@@ -1298,6 +1425,8 @@ mod tests {
                 overlap: 5,
                 trim: true,
                 chunker_type: ChunkerType::Text,
+                respect_sentences: false,
+                language: None,
             },
             Some(&boundaries),
         );
@@ -1329,6 +1458,8 @@ mod tests {
                 overlap: 5,
                 trim: false,
                 chunker_type: ChunkerType::Text,
+                respect_sentences: false,
+                language: None,
             },
             Some(&boundaries),
         );
@@ -1352,6 +1483,8 @@ mod tests {
                 overlap: 5,
                 trim: true,
                 chunker_type: ChunkerType::Text,
+                respect_sentences: false,
+                language: None,
             },
             Some(&boundaries),
         );
@@ -1385,6 +1518,8 @@ mod tests {
                 overlap: 5,
                 trim: true,
                 chunker_type: ChunkerType::Text,
+                respect_sentences: false,
+                language: None,
             },
             Some(&boundaries),
         );
@@ -1401,6 +1536,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "First page content here.Second page content here.Third page.";
 
@@ -1438,6 +1575,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "All content on single page fits in one chunk.";
 
@@ -1462,6 +1601,8 @@ mod tests {
             overlap: 0,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD";
 
@@ -1591,6 +1732,8 @@ mod tests {
             overlap: 5,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Page One Content Here.Page Two.";
 
@@ -1623,6 +1766,8 @@ mod tests {
             overlap: 2,
             trim: false,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "0123456789ABCDEFGHIJ";
 
@@ -1987,6 +2132,8 @@ mod tests {
             overlap: 50,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
 
         let text = "🌍 Hello World ".repeat(200);
@@ -2039,6 +2186,8 @@ mod tests {
             overlap: 100,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
 
         let large_text = "This is a large document with lots of emoji: 🌍 🚀 💻 🎉 🔥 ✨ 🎨 🌟 ".repeat(100);
@@ -2101,6 +2250,8 @@ mod tests {
             overlap: 10,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Hello 👋 World 🌍 End";
 
@@ -2136,6 +2287,8 @@ mod tests {
             overlap: 20,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Test text ".repeat(50);
         let text_len = text.len();
@@ -2186,6 +2339,8 @@ mod tests {
             overlap: 50,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Lorem ipsum dolor sit amet ".repeat(100);
         let text_len = text.len();
@@ -2237,6 +2392,8 @@ mod tests {
             overlap: 30,
             trim: true,
             chunker_type: ChunkerType::Text,
+            respect_sentences: false,
+            language: None,
         };
         let text = "Mixed language: 你好 مرحبا Здравствуй ".repeat(50);
 
@@ -2302,12 +2459,8 @@ pub fn ensure_initialized() -> Result<()> {
 /// **Note:** This is called automatically on first use.
 /// Explicit calling is optional.
 pub fn register_chunking_processor() -> Result<()> {
-    let registry = crate::plugins::registry::get_post_processor_registry();
-    let mut registry = registry
-        .write()
-        .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
-
-    registry.register(Arc::new(ChunkingProcessor), 50)?;
+    crate::plugins::registry::get_post_processor_registry()
+        .update(|registry| registry.register(Arc::new(ChunkingProcessor), 50))?;
 
     Ok(())
 }
@@ -59,6 +59,8 @@ impl PostProcessor for ChunkingProcessor {
             overlap: chunking_config.max_overlap,
             trim: true,
             chunker_type: crate::chunking::ChunkerType::Text,
+            respect_sentences: chunking_config.respect_sentences,
+            language: crate::chunking::sentences::result_language(result),
         };
 
         let chunking_result = crate::chunking::chunk_text(&result.content, &chunk_config, None)
@@ -98,6 +100,7 @@ mod tests {
                 max_overlap: 10,
                 embedding: None,
                 preset: None,
+                respect_sentences: false,
             }),
             ..Default::default()
         };
@@ -111,6 +114,10 @@ mod tests {
 	            chunks: None,
 	            images: None,
 	            pages: None,
+	            term_offsets: None,
+	            barcodes: None,
+	            provenance: None,
+	            schema_version: ExtractionResult::SCHEMA_VERSION,
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -134,6 +141,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -169,6 +180,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config_with_chunking = ExtractionConfig {
@@ -177,6 +192,7 @@ mod tests {
                 max_overlap: 10,
                 embedding: None,
                 preset: None,
+                respect_sentences: false,
             }),
             ..Default::default()
         };
@@ -199,6 +215,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let long_result = ExtractionResult {
@@ -210,6 +230,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -0,0 +1,469 @@
+//! Language-aware sentence segmentation for chunk boundaries.
+//!
+//! With `respect_sentences` enabled, [`chunk_text`](super::chunk_text) packs whole sentences
+//! into chunks instead of cutting the text into character windows, so a chunk never starts
+//! or ends mid-sentence and embeddings see complete statements.
+//!
+//! Sentences end at `.`, `!`, `?` and `…` followed by whitespace, at the sentence-final
+//! marks of other scripts (`।`, `؟`, `։`, ...), directly after CJK full stops (`。！？`), and
+//! at blank lines. A period does not end a sentence after a known abbreviation of the
+//! document language (`Dr.`, `z.B.`, `Mme.`), after an initial (`J. Smith`), in dotted
+//! abbreviations (`U.S.`, `e.g.`), before a number (`No. 5`), or before a lowercase word.
+//! Languages that write ordinals with a period (German `3. Oktober`) keep them inside the
+//! sentence; Greek also ends questions with `;`. Unknown languages use the English rules.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::chunking::sentences::split_sentences;
+//!
+//! let text = "Dr. Weber kam am 3. Oktober. Er blieb z.B. zwei Tage. Danach reiste er ab.";
+//! let sentences: Vec<&str> = split_sentences(text, Some("deu"))
+//!     .into_iter()
+//!     .map(|range| text[range].trim_end())
+//!     .collect();
+//!
+//! assert_eq!(
+//!     sentences,
+//!     ["Dr. Weber kam am 3. Oktober.", "Er blieb z.B. zwei Tage.", "Danach reiste er ab."]
+//! );
+//! ```
+
+use super::ChunkingConfig;
+use crate::error::Result;
+use crate::types::ExtractionResult;
+use std::ops::Range;
+use text_splitter::TextSplitter;
+
+/// Longest word, in characters, that is checked against the abbreviation lists.
+const MAX_ABBREVIATION_CHARS: usize = 16;
+
+/// Punctuation that may precede an abbreviation, as in `(e.g. ...`.
+const OPENING_PUNCTUATION: &[char] = &['(', '[', '{', '"', '\'', '“', '‘', '„', '«', '¿', '¡'];
+
+/// Sentence-splitting rules of one language.
+struct LanguageRules {
+    /// Lowercase abbreviations without their final period
+    abbreviations: &'static [&'static str],
+    /// Numbers followed by a period are ordinals, not sentence ends
+    ordinal_period: bool,
+    /// `;` is a question mark
+    semicolon_question: bool,
+}
+
+const ENGLISH: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "mr", "mrs", "ms", "dr", "prof", "st", "jr", "sr", "rev", "hon", "gen", "col", "lt", "capt", "sgt", "maj",
+        "sen", "rep", "gov", "pres", "mt", "ft", "vs", "approx", "dept", "est", "e.g", "i.e", "cf", "al",
+    ],
+    ordinal_period: false,
+    semicolon_question: false,
+};
+
+const GERMAN: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "hr", "hrn", "fr", "dr", "prof", "dipl", "ing", "st", "str", "nr", "bzw", "ca", "evtl", "ggf", "vgl", "bspw",
+        "inkl", "zzgl", "sog", "gem", "abs", "z.b", "d.h", "u.a", "s.o", "s.u", "o.ä", "u.u",
+    ],
+    ordinal_period: true,
+    semicolon_question: false,
+};
+
+const FRENCH: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "m", "mm", "mme", "mmes", "mlle", "mlles", "dr", "pr", "me", "st", "ste", "av", "bd", "cf", "env", "p.ex",
+        "c.-à-d", "vol", "chap",
+    ],
+    ordinal_period: false,
+    semicolon_question: false,
+};
+
+const SPANISH: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "sr", "sra", "srta", "sres", "dr", "dra", "d", "dña", "ud", "uds", "lic", "ing", "prof", "av", "avda", "pág",
+        "aprox", "p.ej",
+    ],
+    ordinal_period: false,
+    semicolon_question: false,
+};
+
+const ITALIAN: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "sig", "sigg", "sig.ra", "sig.na", "dott", "dott.ssa", "prof", "prof.ssa", "ing", "avv", "arch", "geom", "on",
+        "egr", "gent", "p.es",
+    ],
+    ordinal_period: false,
+    semicolon_question: false,
+};
+
+const PORTUGUESE: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "sr", "sra", "srta", "dr", "dra", "prof", "profa", "eng", "exmo", "exma", "av", "v.exa", "pág", "aprox",
+    ],
+    ordinal_period: false,
+    semicolon_question: false,
+};
+
+const DUTCH: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "dhr", "mevr", "mr", "dr", "drs", "ir", "ing", "prof", "bijv", "blz", "o.a", "i.p.v", "m.b.t", "d.w.z", "t.a.v",
+    ],
+    ordinal_period: false,
+    semicolon_question: false,
+};
+
+const RUSSIAN: LanguageRules = LanguageRules {
+    abbreviations: &[
+        "г", "гг", "ул", "пр", "им", "проф", "акад", "напр", "см", "т.е", "т.к", "т.н",
+    ],
+    ordinal_period: false,
+    semicolon_question: false,
+};
+
+const GREEK: LanguageRules = LanguageRules {
+    abbreviations: &["κ", "κα", "δρ", "π.χ", "βλ"],
+    ordinal_period: false,
+    semicolon_question: true,
+};
+
+/// Danish, Norwegian, Finnish, Czech, Polish and Hungarian: ordinal periods only.
+const ORDINAL_PERIOD: LanguageRules = LanguageRules {
+    abbreviations: &[],
+    ordinal_period: true,
+    semicolon_question: false,
+};
+
+impl LanguageRules {
+    /// Rules for an ISO 639-1 or 639-3 code, with or without region (`en`, `eng`, `de-AT`).
+    fn for_language(language: Option<&str>) -> &'static LanguageRules {
+        let code = language
+            .and_then(|language| language.split(['-', '_']).next())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match code.as_str() {
+            "de" | "deu" | "ger" => &GERMAN,
+            "fr" | "fra" | "fre" => &FRENCH,
+            "es" | "spa" => &SPANISH,
+            "it" | "ita" => &ITALIAN,
+            "pt" | "por" => &PORTUGUESE,
+            "nl" | "nld" | "dut" => &DUTCH,
+            "ru" | "rus" => &RUSSIAN,
+            "el" | "ell" | "gre" => &GREEK,
+            "da" | "dan" | "no" | "nor" | "nb" | "nob" | "nn" | "nno" | "fi" | "fin" | "cs" | "ces" | "cze" | "pl"
+            | "pol" | "hu" | "hun" => &ORDINAL_PERIOD,
+            _ => &ENGLISH,
+        }
+    }
+}
+
+/// CJK sentence-final marks, which are not followed by a space.
+fn is_cjk_terminator(c: char) -> bool {
+    matches!(c, '。' | '！' | '？' | '｡' | '．')
+}
+
+/// Sentence-final marks that end a sentence when followed by whitespace.
+fn is_terminator(c: char, rules: &LanguageRules) -> bool {
+    matches!(
+        c,
+        '.' | '!' | '?' | '…' | '‼' | '⁇' | '⁈' | '⁉' | '।' | '॥' | '؟' | '۔' | '։' | '።' | '။' | '។'
+    ) || (c == ';' && rules.semicolon_question)
+}
+
+/// Quotes and brackets that close a sentence, as in `He said "stop."`.
+fn is_closing(c: char) -> bool {
+    matches!(
+        c,
+        ')' | ']' | '}' | '"' | '\'' | '”' | '’' | '»' | '」' | '』' | '）' | '】' | '〉' | '》'
+    )
+}
+
+/// The word before byte offset `idx`, without opening punctuation.
+fn preceding_word(text: &str, idx: usize) -> &str {
+    let mut start = idx;
+    for (pos, c) in text[..idx].char_indices().rev().take(MAX_ABBREVIATION_CHARS + 1) {
+        if c.is_whitespace() {
+            break;
+        }
+        start = pos;
+    }
+    text[start..idx].trim_start_matches(OPENING_PUNCTUATION)
+}
+
+/// Whether the period after `word` belongs to an abbreviation rather than ending a sentence.
+fn is_abbreviation(word: &str, rules: &LanguageRules) -> bool {
+    let mut chars = word.chars();
+    if let (Some(first), None) = (chars.next(), chars.next())
+        && first.is_uppercase()
+    {
+        return true;
+    }
+    if word.contains('.')
+        && word
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().count() <= 3 && part.chars().all(char::is_alphabetic))
+    {
+        return true;
+    }
+    if rules.ordinal_period && word.len() <= 3 && word.bytes().all(|b| b.is_ascii_digit()) {
+        return true;
+    }
+    let lower = word.to_lowercase();
+    rules.abbreviations.contains(&lower.as_str())
+}
+
+/// Byte offset after the whitespace starting at `pos`.
+fn skip_whitespace(text: &str, pos: usize) -> usize {
+    text[pos..]
+        .find(|c: char| !c.is_whitespace())
+        .map_or(text.len(), |offset| pos + offset)
+}
+
+/// Where the next sentence starts if the sentence ends at character `c` at byte offset `idx`.
+fn sentence_end(text: &str, idx: usize, c: char, rules: &LanguageRules) -> Option<usize> {
+    if c == '\n' {
+        let rest = text[idx + 1..].trim_start_matches([' ', '\t', '\r']);
+        return rest.starts_with('\n').then(|| skip_whitespace(text, idx + 1));
+    }
+
+    let cjk = is_cjk_terminator(c);
+    if !cjk && !is_terminator(c, rules) {
+        return None;
+    }
+
+    let mut end = idx + c.len_utf8();
+    let mut ellipsis = c == '…';
+    for next in text[end..].chars() {
+        if next == '.' && c == '.' {
+            ellipsis = true;
+        } else if !(is_terminator(next, rules) || is_cjk_terminator(next) || is_closing(next)) {
+            break;
+        }
+        end += next.len_utf8();
+    }
+    if cjk {
+        return Some(skip_whitespace(text, end));
+    }
+
+    if !text[end..].chars().next().is_none_or(char::is_whitespace) {
+        return None;
+    }
+    let next_start = skip_whitespace(text, end);
+    let Some(next) = text[next_start..].chars().next() else {
+        return Some(next_start);
+    };
+    if next.is_lowercase() {
+        return None;
+    }
+    if c == '.' && !ellipsis && (next.is_numeric() || is_abbreviation(preceding_word(text, idx), rules)) {
+        return None;
+    }
+    Some(next_start)
+}
+
+/// Split `text` into sentences using the rules of `language` (ISO 639-1 or 639-3 code).
+///
+/// The ranges are contiguous byte ranges that cover the whole text; each sentence keeps the
+/// whitespace that follows it.
+pub fn split_sentences(text: &str, language: Option<&str>) -> Vec<Range<usize>> {
+    let rules = LanguageRules::for_language(language);
+    let mut sentences = Vec::new();
+    let mut start = 0;
+
+    for (idx, c) in text.char_indices() {
+        if idx < start {
+            continue;
+        }
+        if let Some(next_start) = sentence_end(text, idx, c, rules) {
+            sentences.push(start..next_start);
+            start = next_start;
+        }
+    }
+    if start < text.len() {
+        sentences.push(start..text.len());
+    }
+    sentences
+}
+
+/// Language whose sentence rules apply to `result`: the first detected language, falling
+/// back to the language from the document metadata.
+pub(crate) fn result_language(result: &ExtractionResult) -> Option<String> {
+    result
+        .detected_languages
+        .as_ref()
+        .and_then(|languages| languages.first())
+        .or(result.metadata.language.as_ref())
+        .cloned()
+}
+
+/// Byte ranges of chunks made of whole sentences of `config.language`.
+///
+/// Sentences are packed into chunks of at most `max_characters` characters. Each chunk
+/// repeats as many whole sentences from the end of the previous one as fit into `overlap`
+/// characters. Sentences longer than `max_characters` are split like text without
+/// `respect_sentences`.
+pub(super) fn sentence_chunk_ranges(text: &str, config: &ChunkingConfig) -> Result<Vec<Range<usize>>> {
+    let sentences = split_sentences(text, config.language.as_deref());
+    let lengths: Vec<usize> = sentences
+        .iter()
+        .map(|range| text[range.clone()].chars().count())
+        .collect();
+    let splitter = TextSplitter::new(super::build_chunk_config(
+        config.max_characters,
+        config.overlap,
+        config.trim,
+    )?);
+
+    let mut ranges = Vec::new();
+    let mut next = 0;
+    while next < sentences.len() {
+        if lengths[next] > config.max_characters {
+            let sentence = sentences[next].clone();
+            ranges.extend(
+                splitter
+                    .chunk_indices(&text[sentence.clone()])
+                    .map(|(offset, chunk)| sentence.start + offset..sentence.start + offset + chunk.len()),
+            );
+            next += 1;
+            continue;
+        }
+
+        let first = next;
+        let mut size = 0;
+        while next < sentences.len() && size + lengths[next] <= config.max_characters {
+            size += lengths[next];
+            next += 1;
+        }
+        ranges.push(sentences[first].start..sentences[next - 1].end);
+
+        // Step back over the sentences to repeat, leaving room for the next sentence.
+        if let Some(&following) = lengths.get(next) {
+            let mut overlap = 0;
+            while next > first + 1
+                && overlap + lengths[next - 1] <= config.overlap
+                && overlap + lengths[next - 1] + following <= config.max_characters
+            {
+                overlap += lengths[next - 1];
+                next -= 1;
+            }
+        }
+    }
+
+    if config.trim {
+        ranges = ranges
+            .into_iter()
+            .filter_map(|range| {
+                let chunk = &text[range.clone()];
+                let trimmed = chunk.trim_start();
+                let start = range.start + chunk.len() - trimmed.len();
+                let end = start + trimmed.trim_end().len();
+                (start < end).then_some(start..end)
+            })
+            .collect();
+    }
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunking::ChunkerType;
+
+    fn sentences<'a>(text: &'a str, language: Option<&str>) -> Vec<&'a str> {
+        split_sentences(text, language)
+            .into_iter()
+            .map(|range| text[range].trim())
+            .collect()
+    }
+
+    #[test]
+    fn test_split_sentences_handles_abbreviations_and_numbers() {
+        let text = "Dr. Smith met J. R. Tolkien in the U.S. on Jan. 3. See No. 5 for details... \
+                    It was late! Was it? \"Yes.\" Then e.g. tea followed.\n\nHeading\nNext line.";
+
+        assert_eq!(
+            sentences(text, Some("en")),
+            [
+                "Dr. Smith met J. R. Tolkien in the U.S. on Jan. 3.",
+                "See No. 5 for details...",
+                "It was late!",
+                "Was it?",
+                "\"Yes.\"",
+                "Then e.g. tea followed.",
+                "Heading\nNext line.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_sentences_uses_language_rules() {
+        assert_eq!(
+            sentences("Am 3. Oktober kam Hr. Weber. Er blieb.", Some("de-DE")),
+            ["Am 3. Oktober kam Hr. Weber.", "Er blieb."]
+        );
+        assert_eq!(
+            sentences("Am 3. Oktober kam er.", Some("eng")),
+            ["Am 3.", "Oktober kam er."]
+        );
+        assert_eq!(
+            sentences("Mme. Dupont est arrivée. Elle reste.", Some("fra")),
+            ["Mme. Dupont est arrivée.", "Elle reste."]
+        );
+        assert_eq!(
+            sentences("Τι ώρα είναι; Είναι αργά.", Some("ell")),
+            ["Τι ώρα είναι;", "Είναι αργά."]
+        );
+        assert_eq!(
+            sentences("今日は晴れです。明日は雨！本当？", Some("jpn")),
+            ["今日は晴れです。", "明日は雨！", "本当？"]
+        );
+    }
+
+    #[test]
+    fn test_split_sentences_covers_text() {
+        let text = "  One. Two three. Four  ";
+        let ranges = split_sentences(text, None);
+
+        assert_eq!(ranges.first().map(|range| range.start), Some(0));
+        assert_eq!(ranges.last().map(|range| range.end), Some(text.len()));
+        assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert!(split_sentences("", None).is_empty());
+    }
+
+    #[test]
+    fn test_sentence_chunks_respect_size_and_overlap() {
+        let config = ChunkingConfig {
+            max_characters: 60,
+            overlap: 25,
+            trim: true,
+            chunker_type: ChunkerType::Text,
+            respect_sentences: true,
+            language: None,
+        };
+        let text = "The first sentence is here. A second one follows. Short third. \
+                    The fourth sentence is a little longer than the rest. Fifth. \
+                    This final sentence is far too long to fit into a single chunk of sixty characters.";
+
+        let ranges = sentence_chunk_ranges(text, &config).unwrap();
+        let chunks: Vec<&str> = ranges.iter().map(|range| &text[range.clone()]).collect();
+
+        assert_eq!(chunks[0], "The first sentence is here. A second one follows.");
+        assert_eq!(chunks[1], "A second one follows. Short third.");
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 60));
+        assert!(chunks[..4].iter().all(|chunk| chunk.ends_with('.')));
+        assert!(chunks.len() >= 6);
+        assert!(chunks.last().unwrap().ends_with("characters."));
+    }
+
+    #[test]
+    fn test_markdown_chunker_rejects_respect_sentences() {
+        let config = ChunkingConfig {
+            chunker_type: ChunkerType::Markdown,
+            respect_sentences: true,
+            ..Default::default()
+        };
+
+        let result = super::super::chunk_text("# Title\n\nOne sentence. Another one.", &config, None);
+        assert!(matches!(result, Err(crate::KreuzbergError::Validation { .. })));
+    }
+}
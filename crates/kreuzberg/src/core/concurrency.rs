@@ -0,0 +1,347 @@
+//! Concurrency control for batch extraction.
+//!
+//! Batch functions admit documents through a [`ConcurrencyController`]. With a fixed limit
+//! it behaves like a semaphore. With an [`AdaptiveConcurrencyConfig`] the limit moves
+//! between `min_concurrent` and `max_concurrent` as documents complete, and admission
+//! additionally respects an in-flight byte budget and a memory watermark, so a few very
+//! large documents cannot exhaust memory while thousands of small ones keep the CPUs busy.
+//! Inside a container the watermark applies to the cgroup memory limit, not the host's
+//! physical memory.
+
+use crate::core::config::{AdaptiveConcurrencyConfig, ExtractionConfig};
+#[cfg(target_os = "linux")]
+use crate::core::resource_profile::container_memory_limit;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Admission control for in-flight batch extractions.
+#[derive(Debug)]
+pub(crate) struct ConcurrencyController {
+    min_concurrent: usize,
+    max_concurrent: usize,
+    adaptive: bool,
+    memory_high_watermark: Option<f64>,
+    max_in_flight_bytes: Option<u64>,
+    target_latency: Option<Duration>,
+    state: Mutex<ControllerState>,
+    released: Notify,
+}
+
+#[derive(Debug)]
+struct ControllerState {
+    limit: usize,
+    in_flight: usize,
+    in_flight_bytes: u64,
+}
+
+impl ConcurrencyController {
+    /// Controller for a batch run with `config`: adaptive when `adaptive_concurrency` is set,
+    /// otherwise fixed at `max_concurrent_extractions`.
+    pub(crate) fn for_config(config: &ExtractionConfig) -> Self {
+        // Conservative concurrency multiplier (1.5x instead of 2.0x) to reduce contention
+        // on external libraries (pdfium, tesseract) which have their own internal threading.
+        let max_concurrent = config
+            .max_concurrent_extractions
+            .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
+
+        match &config.adaptive_concurrency {
+            Some(adaptive) => Self::adaptive(adaptive, max_concurrent),
+            None => Self::fixed(max_concurrent),
+        }
+    }
+
+    /// Controller admitting at most `limit` documents at a time.
+    pub(crate) fn fixed(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            min_concurrent: limit,
+            max_concurrent: limit,
+            adaptive: false,
+            memory_high_watermark: None,
+            max_in_flight_bytes: None,
+            target_latency: None,
+            state: Mutex::new(ControllerState {
+                limit,
+                in_flight: 0,
+                in_flight_bytes: 0,
+            }),
+            released: Notify::new(),
+        }
+    }
+
+    /// Adaptive controller; `default_max` applies when the config has no `max_concurrent`.
+    pub(crate) fn adaptive(config: &AdaptiveConcurrencyConfig, default_max: usize) -> Self {
+        let min_concurrent = config.min_concurrent.max(1);
+        let max_concurrent = config.max_concurrent.unwrap_or(default_max).max(min_concurrent);
+        Self {
+            min_concurrent,
+            max_concurrent,
+            adaptive: true,
+            memory_high_watermark: Some(config.memory_high_watermark),
+            max_in_flight_bytes: config.max_in_flight_bytes,
+            target_latency: config.target_latency_ms.map(Duration::from_millis),
+            state: Mutex::new(ControllerState {
+                limit: max_concurrent.div_ceil(2).max(min_concurrent),
+                in_flight: 0,
+                in_flight_bytes: 0,
+            }),
+            released: Notify::new(),
+        }
+    }
+
+    /// Upper bound the limit can reach.
+    pub(crate) fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Wait until a document of `bytes` input bytes may start.
+    ///
+    /// The returned permit releases its slot and reports the document latency when dropped.
+    pub(crate) async fn acquire(self: &Arc<Self>, bytes: u64) -> ConcurrencyPermit {
+        loop {
+            let released = self.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+
+            if self.try_admit(bytes) {
+                return ConcurrencyPermit {
+                    controller: Arc::clone(self),
+                    bytes,
+                    started: Instant::now(),
+                };
+            }
+
+            released.await;
+        }
+    }
+
+    fn try_admit(&self, bytes: u64) -> bool {
+        let mut state = self.lock_state();
+
+        // An idle controller always admits, so oversized documents still make progress
+        if state.in_flight > 0 {
+            if state.in_flight >= state.limit {
+                return false;
+            }
+            if self
+                .max_in_flight_bytes
+                .is_some_and(|budget| state.in_flight_bytes.saturating_add(bytes) > budget)
+            {
+                return false;
+            }
+            if self.under_memory_pressure() {
+                return false;
+            }
+        }
+
+        state.in_flight += 1;
+        state.in_flight_bytes = state.in_flight_bytes.saturating_add(bytes);
+        true
+    }
+
+    fn release(&self, bytes: u64, latency: Duration) {
+        {
+            let mut state = self.lock_state();
+            state.in_flight = state.in_flight.saturating_sub(1);
+            state.in_flight_bytes = state.in_flight_bytes.saturating_sub(bytes);
+
+            if self.adaptive {
+                state.limit = if self.under_memory_pressure() {
+                    (state.limit / 2).max(self.min_concurrent)
+                } else if self.target_latency.is_some_and(|target| latency > target) {
+                    state.limit.saturating_sub(1).max(self.min_concurrent)
+                } else {
+                    (state.limit + 1).min(self.max_concurrent)
+                };
+            }
+        }
+
+        self.released.notify_waiters();
+    }
+
+    fn under_memory_pressure(&self) -> bool {
+        self.memory_high_watermark
+            .zip(memory_in_use_fraction())
+            .is_some_and(|(watermark, in_use)| in_use > watermark)
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, ControllerState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Slot held by an in-flight extraction.
+#[derive(Debug)]
+pub(crate) struct ConcurrencyPermit {
+    controller: Arc<ConcurrencyController>,
+    bytes: u64,
+    started: Instant,
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        self.controller.release(self.bytes, self.started.elapsed());
+    }
+}
+
+/// Memory usage files of cgroup v2 and cgroup v1, in the lookup order of the limit files,
+/// each with the `memory.stat` file and key of its inactive page cache.
+#[cfg(target_os = "linux")]
+const CGROUP_MEMORY_USAGE_FILES: &[(&str, &str, &str)] = &[
+    (
+        "/sys/fs/cgroup/memory.current",
+        "/sys/fs/cgroup/memory.stat",
+        "inactive_file",
+    ),
+    (
+        "/sys/fs/cgroup/memory/memory.usage_in_bytes",
+        "/sys/fs/cgroup/memory/memory.stat",
+        "total_inactive_file",
+    ),
+];
+
+/// Memory the cgroup of the process currently uses, excluding inactive page cache the
+/// kernel reclaims before enforcing the limit (`None` = not in a cgroup).
+///
+/// Unlike the limit, read on every call.
+#[cfg(target_os = "linux")]
+fn container_memory_usage() -> Option<u64> {
+    CGROUP_MEMORY_USAGE_FILES
+        .iter()
+        .find_map(|(usage_file, stat_file, inactive_key)| {
+            let usage = std::fs::read_to_string(usage_file).ok()?.trim().parse::<u64>().ok()?;
+            let inactive = std::fs::read_to_string(stat_file)
+                .ok()
+                .and_then(|stat| parse_stat_field(&stat, inactive_key))
+                .unwrap_or(0);
+            Some(usage.saturating_sub(inactive))
+        })
+}
+
+/// Fraction of the memory available to the process currently in use, if the platform
+/// reports it: the cgroup's working set against its limit in a container, since host figures
+/// would let the container reach its OOM killer first, and physical memory otherwise.
+#[cfg(target_os = "linux")]
+fn memory_in_use_fraction() -> Option<f64> {
+    if let Some(limit) = container_memory_limit().filter(|&limit| limit > 0) {
+        return container_memory_usage().map(|usage| usage as f64 / limit as f64);
+    }
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_meminfo(&meminfo)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn memory_in_use_fraction() -> Option<f64> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn parse_meminfo(meminfo: &str) -> Option<f64> {
+    let field = |name: &str| {
+        meminfo.lines().find_map(|line| {
+            line.strip_prefix(name)?
+                .trim()
+                .trim_end_matches("kB")
+                .trim()
+                .parse::<f64>()
+                .ok()
+        })
+    };
+
+    let total = field("MemTotal:")?;
+    let available = field("MemAvailable:")?;
+    (total > 0.0).then(|| 1.0 - available / total)
+}
+
+/// Value of `key` in a cgroup `memory.stat` file (`<key> <bytes>` per line).
+#[cfg(target_os = "linux")]
+fn parse_stat_field(stat: &str, key: &str) -> Option<u64> {
+    stat.lines().find_map(|line| {
+        let (name, value) = line.split_once(' ')?;
+        if name == key { value.trim().parse().ok() } else { None }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fixed_controller_limits_in_flight() {
+        let controller = Arc::new(ConcurrencyController::fixed(2));
+
+        let first = controller.acquire(10).await;
+        let _second = controller.acquire(10).await;
+        assert!(!controller.try_admit(10));
+
+        drop(first);
+        assert!(controller.try_admit(10));
+        assert_eq!(controller.lock_state().limit, 2);
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_controller_respects_byte_budget() {
+        let config = AdaptiveConcurrencyConfig {
+            max_concurrent: Some(8),
+            memory_high_watermark: 1.0,
+            max_in_flight_bytes: Some(100),
+            ..Default::default()
+        };
+        let controller = Arc::new(ConcurrencyController::adaptive(&config, 4));
+        assert_eq!(controller.lock_state().limit, 4);
+
+        // Oversized documents run alone
+        let large = controller.acquire(500).await;
+        assert!(!controller.try_admit(1));
+        drop(large);
+
+        let _small = controller.acquire(60).await;
+        assert!(!controller.try_admit(60));
+        assert!(controller.try_admit(40));
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_controller_adjusts_limit() {
+        let config = AdaptiveConcurrencyConfig {
+            min_concurrent: 2,
+            max_concurrent: Some(5),
+            memory_high_watermark: 1.0,
+            target_latency_ms: Some(60_000),
+            ..Default::default()
+        };
+        let controller = Arc::new(ConcurrencyController::adaptive(&config, 16));
+        assert_eq!(controller.lock_state().limit, 3);
+
+        for _ in 0..4 {
+            drop(controller.acquire(0).await);
+        }
+        assert_eq!(controller.lock_state().limit, 5);
+
+        assert!(controller.try_admit(0));
+        controller.release(0, Duration::from_secs(120));
+        assert_eq!(controller.lock_state().limit, 4);
+
+        let mut permits = Vec::new();
+        for _ in 0..4 {
+            permits.push(controller.acquire(0).await);
+        }
+        assert!(!controller.try_admit(0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_meminfo() {
+        let meminfo = "MemTotal:       16000000 kB\nMemFree:         1000000 kB\nMemAvailable:    4000000 kB\n";
+        assert_eq!(parse_meminfo(meminfo), Some(0.75));
+        assert_eq!(parse_meminfo("MemTotal: 100 kB\n"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_stat_field() {
+        let stat = "anon 1048576\nfile 4096000\nactive_file 1024000\ninactive_file 3072000\n";
+        assert_eq!(parse_stat_field(stat, "inactive_file"), Some(3_072_000));
+        assert_eq!(parse_stat_field(stat, "total_inactive_file"), None);
+    }
+}
@@ -6,7 +6,7 @@
 use crate::{KreuzbergError, Result};
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, LazyLock};
 use std::time::SystemTime;
@@ -47,6 +47,187 @@ impl Default for PageConfig {
     }
 }
 
+/// Unicode normalization form applied to decoded text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnicodeNormalization {
+    /// Canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+impl UnicodeNormalization {
+    /// Lowercase name of the normalization form (e.g. "nfc").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Nfc => "nfc",
+            Self::Nfd => "nfd",
+            Self::Nfkc => "nfkc",
+            Self::Nfkd => "nfkd",
+        }
+    }
+}
+
+impl std::str::FromStr for UnicodeNormalization {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "nfc" => Ok(Self::Nfc),
+            "nfd" => Ok(Self::Nfd),
+            "nfkc" => Ok(Self::Nfkc),
+            "nfkd" => Ok(Self::Nfkd),
+            other => Err(format!(
+                "Invalid unicode normalization form '{}': expected one of nfc, nfd, nfkc, nfkd",
+                other
+            )),
+        }
+    }
+}
+
+/// Text decoding configuration for plain text, CSV/TSV and HTML documents.
+///
+/// Controls how raw bytes are transcoded to UTF-8 before extraction. The
+/// encoding that was actually used is reported in `metadata.encoding`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextExtractionConfig {
+    /// Source encoding: "auto" to detect, or any WHATWG label (e.g. "cp1251", "shift_jis")
+    #[serde(default = "default_text_encoding")]
+    pub encoding: String,
+
+    /// Unicode normalization applied after decoding (None = keep as decoded)
+    #[serde(default)]
+    pub normalize_unicode: Option<UnicodeNormalization>,
+}
+
+impl Default for TextExtractionConfig {
+    fn default() -> Self {
+        Self {
+            encoding: default_text_encoding(),
+            normalize_unicode: None,
+        }
+    }
+}
+
+/// Deterministic content normalization profile.
+///
+/// Applied to the extracted content (and per-page content) after post-processing and
+/// before chunking, so that chunk boundaries, embeddings and hashes are stable across
+/// extractors and OCR backends. Every step is enabled when the block is present; switch
+/// individual steps off as needed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NormalizationConfig {
+    /// Unicode normalization form (None = keep code points as extracted)
+    #[serde(default = "default_normalization_form")]
+    pub unicode: Option<UnicodeNormalization>,
+
+    /// Collapse runs of spaces and tabs, strip trailing whitespace and squeeze blank lines
+    #[serde(default = "default_true")]
+    pub collapse_whitespace: bool,
+
+    /// Replace typographic single and double quotes with ASCII `'` and `"`
+    #[serde(default = "default_true")]
+    pub standardize_quotes: bool,
+
+    /// Replace Unicode hyphens, dashes and the minus sign with ASCII `-`
+    #[serde(default = "default_true")]
+    pub standardize_dashes: bool,
+
+    /// Join words hyphenated across line breaks and remove soft hyphens
+    #[serde(default = "default_true")]
+    pub dehyphenate: bool,
+}
+
+impl Default for NormalizationConfig {
+    fn default() -> Self {
+        Self {
+            unicode: default_normalization_form(),
+            collapse_whitespace: true,
+            standardize_quotes: true,
+            standardize_dashes: true,
+            dehyphenate: true,
+        }
+    }
+}
+
+/// Delimited text (CSV/TSV) extraction configuration.
+///
+/// The delimiter, quote character and header row are detected automatically;
+/// these options only override or bound that detection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsvConfig {
+    /// Field delimiter (None = auto-detect from `,`, `;`, tab and `|`)
+    #[serde(default)]
+    pub delimiter: Option<char>,
+
+    /// Maximum number of data rows parsed into the table (None = no limit)
+    #[serde(default)]
+    pub max_rows: Option<usize>,
+}
+
+/// Reference to a worksheet, either by zero-based index or by name.
+///
+/// Deserializes from a JSON number or string, so `["Summary", 2]` selects the
+/// sheet named "Summary" and the third sheet of the workbook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SheetSelector {
+    /// Zero-based position of the sheet in the workbook
+    Index(usize),
+    /// Sheet name as shown in the workbook
+    Name(String),
+}
+
+/// Spreadsheet (XLSX/XLS/ODS) extraction configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpreadsheetConfig {
+    /// Sheets to extract, in workbook order (None = all sheets)
+    #[serde(default)]
+    pub sheets: Option<Vec<SheetSelector>>,
+
+    /// Maximum number of data rows extracted per sheet, excluding the header (None = no limit)
+    #[serde(default)]
+    pub max_rows: Option<usize>,
+
+    /// Read XLSX/XLSB sheets cell by cell instead of loading each sheet into memory.
+    ///
+    /// Combined with `max_rows`, reading stops as soon as the limit is reached.
+    #[serde(default)]
+    pub stream: bool,
+}
+
+/// PowerPoint (PPTX) extraction configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PptxConfig {
+    /// Append each slide's speaker notes to its content under a `### Notes:` heading
+    #[serde(default = "default_true")]
+    pub include_notes: bool,
+
+    /// Prefix each slide with a `<!-- Slide number: N -->` marker and return one page per slide
+    /// in `ExtractionResult.pages`, even without a `pages` configuration
+    #[serde(default)]
+    pub slide_markers: bool,
+}
+
+impl Default for PptxConfig {
+    fn default() -> Self {
+        Self {
+            include_notes: true,
+            slide_markers: false,
+        }
+    }
+}
+
 /// Main extraction configuration.
 ///
 /// This struct contains all configuration options for the extraction process.
@@ -81,6 +262,10 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub force_ocr: bool,
 
+    /// Correction of common OCR character confusions in OCR-derived text (None = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_correction: Option<OcrCorrectionConfig>,
+
     /// Text chunking configuration (None = chunking disabled)
     #[serde(default)]
     pub chunking: Option<ChunkingConfig>,
@@ -102,10 +287,100 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub language_detection: Option<LanguageDetectionConfig>,
 
+    /// Document type classification written to `metadata.document_type` (None = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification: Option<ClassificationConfig>,
+
+    /// Key-value pair extraction written to `metadata.key_values` (None = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kv_extraction: Option<KeyValueExtractionConfig>,
+
+    /// Preview snippet of the first sentences written to `metadata.preview` (None = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<PreviewConfig>,
+
     /// Page extraction configuration (None = no page tracking)
     #[serde(default)]
     pub pages: Option<PageConfig>,
 
+    /// Text decoding options for plain text, CSV/TSV and HTML (None = UTF-8 with lossy fallback)
+    #[serde(default)]
+    pub text_options: Option<TextExtractionConfig>,
+
+    /// CSV/TSV dialect options (None = auto-detect everything)
+    #[serde(default)]
+    pub csv_options: Option<CsvConfig>,
+
+    /// Spreadsheet sheet selection and row limits (None = all sheets, all rows)
+    #[serde(default)]
+    pub spreadsheet_options: Option<SpreadsheetConfig>,
+
+    /// PowerPoint speaker notes and slide structure options (None = notes included, no slide markers)
+    #[serde(default)]
+    pub pptx_options: Option<PptxConfig>,
+
+    /// Content normalization applied before chunking (None = content kept as extracted)
+    #[serde(default)]
+    pub normalization: Option<NormalizationConfig>,
+
+    /// Removal of headers, footers and page numbers repeated across pages (None = content kept as extracted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cleanup: Option<CleanupConfig>,
+
+    /// Watermark and stamp detection written to `metadata.watermarks` (None = disabled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watermarks: Option<WatermarkConfig>,
+
+    /// Emit per-term byte offsets in `ExtractionResult::term_offsets` for position-aware indexing
+    #[serde(default)]
+    pub emit_term_offsets: bool,
+
+    /// Attribute every byte range of the content to its source in `ExtractionResult::provenance`
+    #[serde(default)]
+    pub emit_provenance: bool,
+
+    /// Detect barcodes and QR codes on page images and return them in `ExtractionResult::barcodes`
+    #[serde(default)]
+    pub detect_barcodes: bool,
+
+    /// Re-extract only the new and changed pages of a PDF that was extracted before.
+    ///
+    /// Page hashes and the extracted pages are stored under `.kreuzberg/incremental`; see
+    /// [`crate::core::incremental`]. Other formats are always extracted in full.
+    #[serde(default)]
+    pub incremental: bool,
+
+    /// Size, MIME type, signature and encryption checks applied before extraction (None = accept everything)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_policy: Option<InputPolicyConfig>,
+
+    /// Time budgets of extraction stages; see [`crate::core::watchdog`] (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage_timeouts: Option<StageTimeoutConfig>,
+
+    /// Retries of batch extractions that failed for transient reasons; see
+    /// [`crate::core::retry`] (None = every document is attempted once)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+
+    /// Storage of cached extraction results; see [`crate::cache::backend`] (None = results are not cached).
+    ///
+    /// Ignored when `use_cache` is false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheConfig>,
+
+    /// Result fields returned to language bindings (None = all fields).
+    ///
+    /// Accepts top-level result fields (`content`, `tables`, `chunks`, ...) and
+    /// `metadata.<key>` selectors; see [`crate::core::fields`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+
+    /// Compression of the large JSON fields (chunks, images, page structure) of results returned
+    /// over the C FFI (None = plain JSON strings). Other bindings ignore it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_compression: Option<ResultCompression>,
+
     /// Keyword extraction configuration (None = no keyword extraction)
     #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
     #[serde(default)]
@@ -129,6 +404,285 @@ pub struct ExtractionConfig {
     /// large batches. Defaults to twice the number of CPU cores.
     #[serde(default)]
     pub max_concurrent_extractions: Option<usize>,
+
+    /// Adaptive concurrency for batch operations (None = fixed `max_concurrent_extractions`).
+    ///
+    /// When set, the number of in-flight extractions is adjusted after every document
+    /// based on memory pressure and per-document latency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_concurrency: Option<AdaptiveConcurrencyConfig>,
+
+    /// Directory page contents and images are written to once a result holds more than
+    /// `spill_threshold_bytes` of them (None = keep everything in memory).
+    ///
+    /// Spilled pages keep a `content_blob` reference instead of their text and spilled
+    /// images a `blob` reference instead of their bytes; see [`crate::cache::blob_store`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spill_dir: Option<PathBuf>,
+
+    /// Bytes of page content and image data kept in memory before spilling to `spill_dir`
+    /// (None = 64 MiB).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spill_threshold_bytes: Option<usize>,
+
+    /// Degradation of expensive features in low-resource environments; see
+    /// [`crate::core::resource_profile`] (default: standard, which turns minimal under a low
+    /// container memory limit).
+    #[serde(default)]
+    pub resource_profile: ResourceProfile,
+
+    /// Partial configurations merged over this one for documents of matching MIME types, keyed
+    /// by `type/subtype`, `type/*` or `*/*`; see [`crate::core::overrides`] (None = the same
+    /// configuration for every format).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+/// Compression codec for large JSON result fields handed to FFI hosts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultCompression {
+    /// gzip (RFC 1952), decodable by any runtime's standard library
+    Gzip,
+    /// Zstandard, faster and smaller than gzip
+    Zstd,
+}
+
+/// How much of the configured work extraction may do in a constrained environment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceProfile {
+    /// No embeddings, image extraction, barcode detection, keyword extraction, classification
+    /// or key-value extraction; OCR at reduced DPI
+    Minimal,
+    /// The configuration as given, or minimal when the container memory limit is low
+    #[default]
+    Standard,
+    /// The configuration as given, whatever the memory limit
+    Full,
+}
+
+/// Adaptive concurrency for batch extraction.
+///
+/// Mixed corpora (a few multi-gigabyte PDFs among thousands of small files) either
+/// underuse the machine with a low fixed limit or run out of memory with a high one. The
+/// adaptive controller starts at half of `max_concurrent` and, after every document,
+/// halves the limit when memory use exceeds `memory_high_watermark`, lowers it by
+/// one when the document took longer than `target_latency_ms`, and raises it by one
+/// otherwise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdaptiveConcurrencyConfig {
+    /// Lower bound for in-flight extractions
+    #[serde(default = "default_min_concurrent")]
+    pub min_concurrent: usize,
+
+    /// Upper bound for in-flight extractions (None = `max_concurrent_extractions`, or num_cpus * 1.5)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<usize>,
+
+    /// Fraction of memory in use (0.0-1.0) above which no new extraction starts and the limit
+    /// is halved: of the cgroup memory limit in a container, of physical memory otherwise.
+    /// Only measured on Linux.
+    #[serde(default = "default_memory_high_watermark")]
+    pub memory_high_watermark: f64,
+
+    /// Maximum combined input size of in-flight documents, in bytes (None = unlimited).
+    ///
+    /// A document larger than the budget still runs, but only once nothing else is in flight.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_in_flight_bytes: Option<u64>,
+
+    /// Per-document latency above which the limit is lowered, in milliseconds (None = ignore latency)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_latency_ms: Option<u64>,
+}
+
+impl Default for AdaptiveConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            min_concurrent: default_min_concurrent(),
+            max_concurrent: None,
+            memory_high_watermark: default_memory_high_watermark(),
+            max_in_flight_bytes: None,
+            target_latency_ms: None,
+        }
+    }
+}
+
+/// Checks an input must pass before it is handed to an extractor.
+///
+/// Internet-facing services can state their upload policy here once instead of
+/// pre-validating in every host language. A failed check returns
+/// `KreuzbergError::InputRejected`, whose [`InputRejection`](crate::InputRejection) reason
+/// maps to a distinct FFI error code; see [`crate::core::input_policy`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InputPolicyConfig {
+    /// Largest accepted input in bytes (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_file_size_bytes: Option<u64>,
+
+    /// Accepted MIME types; `type/*` accepts a whole family, e.g. `image/*` (None = every supported type)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_mime_types: Option<Vec<String>>,
+
+    /// Reject inputs whose magic bytes contradict the declared or extension-derived MIME type
+    #[serde(default)]
+    pub reject_mime_mismatch: bool,
+
+    /// Handling of password-protected inputs
+    #[serde(default)]
+    pub encrypted: EncryptedInputPolicy,
+}
+
+impl InputPolicyConfig {
+    /// Whether the checks need the input's bytes, not only its size and MIME type.
+    pub fn inspects_content(&self) -> bool {
+        self.reject_mime_mismatch || self.encrypted == EncryptedInputPolicy::Reject
+    }
+}
+
+/// Handling of password-protected inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptedInputPolicy {
+    /// Hand encrypted inputs to the extractor, which fails unless it can decrypt them
+    /// (e.g. with `pdf_options.passwords`)
+    #[default]
+    Allow,
+    /// Reject encrypted inputs before extraction
+    Reject,
+}
+
+/// Time budgets of extraction stages, in milliseconds.
+///
+/// A stage running past its budget is abandoned and the extraction fails with
+/// `KreuzbergError::Timeout` naming the stage, so a pathological document cannot wedge a
+/// worker; see [`crate::core::watchdog`]. Stages without a budget run unbounded.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTimeoutConfig {
+    /// Budget of the format extractor, including the OCR it runs (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extraction_ms: Option<u64>,
+
+    /// Budget of OCR for one PDF page or image (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_ms: Option<u64>,
+
+    /// Budget of the post-processing pipeline (None = unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_processing_ms: Option<u64>,
+}
+
+impl StageTimeoutConfig {
+    /// Budget of `stage`, if one is configured.
+    pub fn budget(&self, stage: crate::ExtractionStage) -> Option<std::time::Duration> {
+        let millis = match stage {
+            crate::ExtractionStage::Extraction => self.extraction_ms,
+            crate::ExtractionStage::Ocr => self.ocr_ms,
+            crate::ExtractionStage::PostProcessing => self.post_processing_ms,
+        };
+        millis.map(std::time::Duration::from_millis)
+    }
+}
+
+/// Retry policy for documents of a batch whose extraction failed for a transient reason.
+///
+/// Network filesystems that drop a read or an OCR subprocess that crashes fail one attempt
+/// without saying anything about the document. Such failures are retried with exponential
+/// backoff: the n-th retry waits `initial_backoff_ms * multiplier^(n-1)`, capped at
+/// `max_backoff_ms`. Validation, parsing and unsupported-format errors are never retried.
+/// The number of attempts is recorded in the `extraction_attempts` metadata; see
+/// [`crate::core::retry`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Attempts per document including the first one; 1 disables retries
+    #[serde(default = "default_retry_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Wait before the first retry, in milliseconds
+    #[serde(default = "default_retry_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Upper bound of the wait before a retry, in milliseconds
+    #[serde(default = "default_retry_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+
+    /// Factor the wait grows by after every retry
+    #[serde(default = "default_retry_multiplier")]
+    pub multiplier: f64,
+
+    /// Error classes that are retried
+    #[serde(default = "default_retry_on")]
+    pub retry_on: Vec<RetryableError>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_retry_max_attempts(),
+            initial_backoff_ms: default_retry_initial_backoff_ms(),
+            max_backoff_ms: default_retry_max_backoff_ms(),
+            multiplier: default_retry_multiplier(),
+            retry_on: default_retry_on(),
+        }
+    }
+}
+
+/// Class of extraction errors a [`RetryConfig`] retries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryableError {
+    /// `KreuzbergError::Io`, e.g. a read from a network filesystem that timed out
+    Io,
+    /// `KreuzbergError::Ocr`, e.g. a crashed OCR subprocess
+    Ocr,
+    /// `KreuzbergError::Timeout`, a stage that ran past its budget
+    Timeout,
+    /// `KreuzbergError::Cache`, a cache backend that was unreachable
+    Cache,
+    /// `KreuzbergError::Plugin`, a failed post-processor or custom backend
+    Plugin,
+}
+
+/// Extraction result cache.
+///
+/// Results are keyed by a hash of the document bytes, MIME type and configuration, so
+/// instances sharing a Redis backend (or a disk cache directory) reuse each other's results.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CacheConfig {
+    /// Where results are stored
+    #[serde(default)]
+    pub backend: CacheBackendKind,
+
+    /// Redis connection URL, or cache directory of the disk backend
+    /// (None = `$KREUZBERG_CACHE_DIR/results`, falling back to `.kreuzberg/results`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Most results kept by the in-memory backend before the least recently used one is evicted
+    /// (None = 1024); other backends rely on their own eviction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_entries: Option<usize>,
+
+    /// Tenant whose results are kept apart from those of other namespaces, even for identical
+    /// documents and configurations: disk results go to a `<namespace>` subdirectory, Redis
+    /// keys get a `kreuzberg:results:<namespace>:` prefix and the in-memory backend keeps one
+    /// LRU per namespace (None = shared). ASCII letters, digits, `-` and `_` only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+/// Storage backend of the extraction result cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheBackendKind {
+    /// One file per result in a local directory
+    #[default]
+    Disk,
+    /// Process-wide in-memory LRU
+    Memory,
+    /// Shared Redis server; requires the `redis-cache` feature
+    Redis,
 }
 
 /// Post-processor configuration.
@@ -184,6 +738,173 @@ pub struct OcrConfig {
     /// Tesseract-specific configuration (optional)
     #[serde(default)]
     pub tesseract_config: Option<crate::types::TesseractConfig>,
+
+    /// Retry low-confidence pages with a secondary backend (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<OcrFallbackConfig>,
+
+    /// Domain vocabulary passed to the OCR backend and used to correct its output (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vocabulary: Option<OcrVocabularyConfig>,
+}
+
+/// Confidence-gated OCR fallback.
+///
+/// Pages whose primary OCR result reports a `confidence` below `min_confidence` are
+/// processed again with `backend`. The backend used for each page is recorded in the
+/// `ocr_pages` metadata entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrFallbackConfig {
+    /// Fallback OCR backend (any registered backend, e.g. a custom ONNX or cloud plugin)
+    pub backend: String,
+
+    /// Confidence (0.0-1.0) below which a page is retried
+    #[serde(default = "default_fallback_min_confidence")]
+    pub min_confidence: f64,
+
+    /// Language for the fallback backend (defaults to the primary language)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl OcrFallbackConfig {
+    /// OCR configuration for the fallback backend, derived from the primary configuration.
+    pub fn to_ocr_config(&self, primary: &OcrConfig) -> OcrConfig {
+        OcrConfig {
+            backend: self.backend.clone(),
+            language: self.language.clone().unwrap_or_else(|| primary.language.clone()),
+            tesseract_config: primary.tesseract_config.clone(),
+            fallback: None,
+            vocabulary: primary.vocabulary.clone(),
+        }
+    }
+}
+
+/// Domain vocabulary for OCR.
+///
+/// Word lists for specialized corpora (drug names, part numbers, product codes). The
+/// words are given to Tesseract as user words, and after recognition each OCR word that
+/// is not in the vocabulary but within `max_distance` edits of exactly one vocabulary
+/// word is replaced with it. Words come from `words`, `words_file`, or both.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::OcrVocabularyConfig;
+///
+/// let vocabulary = OcrVocabularyConfig {
+///     words: vec!["Metformin".to_string(), "Lisinopril".to_string()],
+///     ..Default::default()
+/// };
+/// assert!(vocabulary.correct);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrVocabularyConfig {
+    /// Inline vocabulary words
+    #[serde(default)]
+    pub words: Vec<String>,
+
+    /// File of vocabulary words, one per line; blank lines and lines starting with `#` are skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub words_file: Option<String>,
+
+    /// Correct OCR output against the vocabulary after recognition
+    #[serde(default = "default_true")]
+    pub correct: bool,
+
+    /// Maximum edit distance between an OCR word and the vocabulary word replacing it
+    #[serde(default = "default_vocabulary_max_distance")]
+    pub max_distance: usize,
+
+    /// OCR words shorter than this many characters are never corrected
+    #[serde(default = "default_vocabulary_min_word_length")]
+    pub min_word_length: usize,
+}
+
+impl Default for OcrVocabularyConfig {
+    fn default() -> Self {
+        Self {
+            words: Vec::new(),
+            words_file: None,
+            correct: true,
+            max_distance: default_vocabulary_max_distance(),
+            min_word_length: default_vocabulary_min_word_length(),
+        }
+    }
+}
+
+/// Correction of characters OCR engines commonly confuse.
+///
+/// Applied by the `ocr-correction` post-processor to text that came from OCR only;
+/// natively extracted text is left as is. The built-in rules read `O`/`o` as `0` and
+/// `l`/`I` as `1` in numbers, `0` as `o`/`O` and `1` as `l`/`I` inside words, and a
+/// word-initial `rn` as `m`. See [`crate::ocr::correction`].
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::{OcrConfusionContext, OcrConfusionRule, OcrCorrectionConfig};
+///
+/// let correction = OcrCorrectionConfig {
+///     rules: vec![OcrConfusionRule {
+///         from: "5".to_string(),
+///         to: "S".to_string(),
+///         context: OcrConfusionContext::Alphabetic,
+///     }],
+///     ..Default::default()
+/// };
+/// assert!(correction.builtin_rules);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrCorrectionConfig {
+    /// Apply the built-in O/0, l/1 and rn/m rules
+    #[serde(default = "default_true")]
+    pub builtin_rules: bool,
+
+    /// Additional rules, applied after the built-in ones
+    #[serde(default)]
+    pub rules: Vec<OcrConfusionRule>,
+}
+
+impl Default for OcrCorrectionConfig {
+    fn default() -> Self {
+        Self {
+            builtin_rules: true,
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// Replacement of a character sequence OCR misreads, in a given context.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OcrConfusionRule {
+    /// Sequence as misread by OCR
+    pub from: String,
+
+    /// Intended sequence
+    pub to: String,
+
+    /// Where the replacement applies
+    #[serde(default)]
+    pub context: OcrConfusionContext,
+}
+
+/// Context an [`OcrConfusionRule`] applies in.
+///
+/// Words are runs of letters and digits; contextual rules only match inside them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OcrConfusionContext {
+    /// In words that become a number once every numeric rule is applied, e.g. `2O24`
+    Numeric,
+    /// Between two letters, in words that have no other digits left afterwards, e.g. `c0de`;
+    /// a cased `to` also requires the following letter to have its case
+    Alphabetic,
+    /// At the start of a word, followed by a letter, e.g. `rnodem`
+    WordStart,
+    /// Everywhere in OCR-derived text
+    #[default]
+    Anywhere,
 }
 
 /// Chunking configuration.
@@ -204,6 +925,10 @@ pub struct ChunkingConfig {
     /// Use a preset configuration (overrides individual settings if provided)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preset: Option<String>,
+
+    /// Keep whole sentences together, split with the rules of the document language
+    #[serde(default)]
+    pub respect_sentences: bool,
 }
 
 /// Embedding configuration for text chunks.
@@ -290,6 +1015,177 @@ pub struct ImageExtractionConfig {
     /// Maximum DPI threshold
     #[serde(default = "default_max_dpi")]
     pub max_dpi: i32,
+
+    /// Omit GPS coordinates from image EXIF metadata (for privacy-sensitive deployments)
+    #[serde(default)]
+    pub strip_gps: bool,
+
+    /// Re-encode extracted images to this format (original bytes are kept when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<ImageOutputFormat>,
+
+    /// Downscale extracted images so that neither side exceeds this many pixels
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downscale_to: Option<u32>,
+
+    /// Maximum encoded size of an extracted image in bytes
+    ///
+    /// Larger images are re-compressed (JPEG) and downscaled until they fit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_bytes: Option<usize>,
+
+    /// Compute a perceptual hash for each extracted image (`ExtractedImage.perceptual_hash`)
+    #[serde(default)]
+    pub perceptual_hash: bool,
+
+    /// Drop images that are near-duplicates of an earlier image (e.g. logos repeated on every page)
+    #[serde(default)]
+    pub deduplicate_images: bool,
+
+    /// Maximum number of differing hash bits for two images to count as duplicates
+    #[serde(default = "default_duplicate_hash_distance")]
+    pub duplicate_hash_distance: u32,
+
+    /// Which extracted images are sent to OCR (every image is OCR'd when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_policy: Option<ImageOcrPolicy>,
+
+    /// Write image bytes to this content-addressed directory instead of inlining them
+    ///
+    /// Results then carry an `ExtractedImage.blob` reference and an empty `data` field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob_store_dir: Option<PathBuf>,
+}
+
+impl ImageExtractionConfig {
+    /// Whether extracted images must be re-encoded before they are returned.
+    pub fn transcodes_images(&self) -> bool {
+        self.output_format.is_some() || self.downscale_to.is_some() || self.max_bytes.is_some()
+    }
+
+    /// Whether extracted images must be perceptually hashed.
+    pub fn hashes_images(&self) -> bool {
+        self.perceptual_hash || self.deduplicate_images
+    }
+}
+
+/// Routing policy deciding which extracted images are OCR'd.
+///
+/// Documents with hundreds of icons, bullets and borders spend most of their OCR time on
+/// images that carry no text. An image is OCR'd only if it passes every configured filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageOcrPolicy {
+    /// Skip images narrower than this many pixels
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_width: Option<u32>,
+
+    /// Skip images shorter than this many pixels
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_height: Option<u32>,
+
+    /// Skip images whose encoded data is smaller than this many bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_bytes: Option<usize>,
+
+    /// Only OCR images on these pages (1-indexed); images without a page number are skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pages: Option<Vec<usize>>,
+
+    /// Skip mask and transparency-layer images
+    #[serde(default = "default_true")]
+    pub skip_masks: bool,
+
+    /// Skip decorative images: rules, borders and spacers whose aspect ratio exceeds
+    /// [`ImageOcrPolicy::DECORATIVE_ASPECT_RATIO`]
+    #[serde(default)]
+    pub skip_decorative: bool,
+}
+
+impl Default for ImageOcrPolicy {
+    fn default() -> Self {
+        Self {
+            min_width: None,
+            min_height: None,
+            min_bytes: None,
+            pages: None,
+            skip_masks: true,
+            skip_decorative: false,
+        }
+    }
+}
+
+impl ImageOcrPolicy {
+    /// Images at least this many times wider than tall (or taller than wide) are decorative.
+    pub const DECORATIVE_ASPECT_RATIO: u32 = 10;
+
+    /// Whether an extracted image should be sent to OCR under this policy.
+    pub fn should_ocr(&self, image: &crate::types::ExtractedImage) -> bool {
+        if self.skip_masks && image.is_mask {
+            return false;
+        }
+        if self.min_bytes.is_some_and(|min_bytes| image.data.len() < min_bytes) {
+            return false;
+        }
+        if let Some(pages) = &self.pages
+            && !image.page_number.is_some_and(|page| pages.contains(&page))
+        {
+            return false;
+        }
+
+        // Images with unknown dimensions are only filtered by the checks above
+        if let (Some(width), Some(height)) = (image.width, image.height) {
+            if self.min_width.is_some_and(|min_width| width < min_width)
+                || self.min_height.is_some_and(|min_height| height < min_height)
+            {
+                return false;
+            }
+            let (long, short) = (width.max(height), width.min(height).max(1));
+            if self.skip_decorative && long >= short.saturating_mul(Self::DECORATIVE_ASPECT_RATIO) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Encoding applied to extracted images when `output_format` is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageOutputFormat {
+    /// Lossless PNG
+    Png,
+    /// Lossy JPEG (transparency is flattened)
+    Jpeg,
+    /// Lossless WebP
+    Webp,
+}
+
+impl ImageOutputFormat {
+    /// Lowercase name of the format, as reported in `ExtractedImage.format`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+impl std::str::FromStr for ImageOutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "jpeg" | "jpg" => Ok(Self::Jpeg),
+            "webp" => Ok(Self::Webp),
+            other => Err(format!(
+                "Invalid image output format '{}': expected one of png, jpeg, webp",
+                other
+            )),
+        }
+    }
 }
 
 /// PDF-specific configuration.
@@ -337,6 +1233,221 @@ pub struct LanguageDetectionConfig {
     pub detect_multiple: bool,
 }
 
+/// Document type classification.
+///
+/// The `document-classification` post-processor scores every document type by the
+/// keywords found in the content, keywords found in the first lines (titles, headers)
+/// and, for the built-in `invoice`, `contract`, `report` and `letter` types, layout
+/// features such as amounts, numbered clauses or a salutation. The best type and its
+/// confidence are written to `metadata.document_type`; see [`crate::text::classification`].
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::{ClassificationConfig, DocumentTypeRule};
+///
+/// let classification = ClassificationConfig {
+///     types: vec![DocumentTypeRule {
+///         label: "purchase_order".to_string(),
+///         keywords: vec!["purchase order".to_string(), "ship to".to_string()],
+///         title_keywords: vec!["purchase order".to_string()],
+///     }],
+///     ..Default::default()
+/// };
+/// assert!(classification.builtin_types);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassificationConfig {
+    /// Include the built-in invoice, contract, report and letter types
+    #[serde(default = "default_true")]
+    pub builtin_types: bool,
+
+    /// Additional document types; a type with a built-in label adds to its keywords
+    #[serde(default)]
+    pub types: Vec<DocumentTypeRule>,
+
+    /// Confidence (0.0-1.0) below which the document is labelled `unknown`
+    #[serde(default = "default_classification_min_confidence")]
+    pub min_confidence: f64,
+}
+
+impl Default for ClassificationConfig {
+    fn default() -> Self {
+        Self {
+            builtin_types: true,
+            types: Vec::new(),
+            min_confidence: default_classification_min_confidence(),
+        }
+    }
+}
+
+/// Document type recognised by its keywords.
+///
+/// Keywords are words or phrases matched case-insensitively on word boundaries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentTypeRule {
+    /// Label written to `metadata.document_type.label`
+    pub label: String,
+
+    /// Keywords typical of the type anywhere in the document
+    #[serde(default)]
+    pub keywords: Vec<String>,
+
+    /// Keywords that identify the type when they appear in the first lines, e.g. a title
+    #[serde(default)]
+    pub title_keywords: Vec<String>,
+}
+
+/// Key-value pair extraction for semi-structured documents.
+///
+/// Finds label/value pairs such as `Invoice number: 2024-117` or `Total   1,130.50` and
+/// writes them to `metadata.key_values`. In PDFs with a text layer, pairs are found from
+/// word positions, so a value printed to the right of or below its label is matched and
+/// both get a bounding box; other formats are scanned line by line. See
+/// [`crate::text::key_values`].
+///
+/// A label followed by a colon is always recognised. Known labels (the built-in business
+/// labels and `labels`) are also recognised without a colon when their value contains a digit.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::KeyValueExtractionConfig;
+///
+/// let kv_extraction = KeyValueExtractionConfig {
+///     labels: vec!["Kundennummer".to_string(), "Fällig am".to_string()],
+///     ..Default::default()
+/// };
+/// assert!(kv_extraction.builtin_labels);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyValueExtractionConfig {
+    /// Recognise common business labels (invoice number, due date, total, ...) without a colon
+    #[serde(default = "default_true")]
+    pub builtin_labels: bool,
+
+    /// Additional labels recognised without a colon, matched case-insensitively by whole words
+    #[serde(default)]
+    pub labels: Vec<String>,
+
+    /// Most words in a label followed by a colon
+    #[serde(default = "default_kv_max_label_words")]
+    pub max_label_words: usize,
+}
+
+impl Default for KeyValueExtractionConfig {
+    fn default() -> Self {
+        Self {
+            builtin_labels: true,
+            labels: Vec::new(),
+            max_label_words: default_kv_max_label_words(),
+        }
+    }
+}
+
+/// Document preview snippet.
+///
+/// The `document-preview` post-processor writes the first `sentences` sentences of the
+/// final content to `metadata.preview`, skipping headings, running headers and footers,
+/// page numbers and other boilerplate; see [`crate::text::preview`].
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::PreviewConfig;
+///
+/// let preview = PreviewConfig {
+///     sentences: 2,
+///     ..Default::default()
+/// };
+/// assert!(preview.skip_boilerplate);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewConfig {
+    /// Number of sentences in the preview
+    #[serde(default = "default_preview_sentences")]
+    pub sentences: usize,
+
+    /// Skip headings, running headers and footers, page numbers, tables and notices
+    #[serde(default = "default_true")]
+    pub skip_boilerplate: bool,
+}
+
+impl Default for PreviewConfig {
+    fn default() -> Self {
+        Self {
+            sentences: default_preview_sentences(),
+            skip_boilerplate: true,
+        }
+    }
+}
+
+/// Cleanup of text repeated across pages.
+///
+/// Lines among the first and last lines of each page that recur on most pages, such as
+/// running headers and footers, page numbers and watermarks, are removed before
+/// post-processing and chunking; see [`crate::text::cleanup`]. Requires page boundaries
+/// or per-page content, so PDFs need a `pages` section.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::CleanupConfig;
+///
+/// let cleanup = CleanupConfig {
+///     tag_instead: true,
+///     ..Default::default()
+/// };
+/// assert!(cleanup.strip_repeated);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanupConfig {
+    /// Detect lines repeated across most pages
+    #[serde(default = "default_true")]
+    pub strip_repeated: bool,
+
+    /// Keep repeated lines in the content and record them in `metadata.repeated_text` instead
+    #[serde(default)]
+    pub tag_instead: bool,
+}
+
+impl Default for CleanupConfig {
+    fn default() -> Self {
+        Self {
+            strip_repeated: true,
+            tag_instead: false,
+        }
+    }
+}
+
+/// Watermark and stamp detection.
+///
+/// Reports DRAFT, CONFIDENTIAL and similar stamps in `metadata.watermarks` with the pages
+/// they appear on: text drawn diagonally and images placed on most pages of a PDF, and
+/// stamp words standing alone on a line in any format; see [`crate::text::watermarks`].
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::WatermarkConfig;
+///
+/// let watermarks = WatermarkConfig {
+///     exclude_text: true,
+///     stamp_words: vec!["Attorney Work Product".to_string()],
+/// };
+/// assert_eq!(watermarks.stamp_words.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatermarkConfig {
+    /// Remove the lines holding watermark text from the content
+    #[serde(default)]
+    pub exclude_text: bool,
+
+    /// Additional stamp words recognised on a line of their own, matched case-insensitively
+    #[serde(default)]
+    pub stamp_words: Vec<String>,
+}
+
 fn default_true() -> bool {
     true
 }
@@ -346,6 +1457,15 @@ fn default_eng() -> String {
 fn default_tesseract_backend() -> String {
     "tesseract".to_string()
 }
+fn default_fallback_min_confidence() -> f64 {
+    0.6
+}
+fn default_vocabulary_max_distance() -> usize {
+    1
+}
+fn default_vocabulary_min_word_length() -> usize {
+    4
+}
 fn default_page_marker_format() -> String {
     "\n\n<!-- PAGE {page_num} -->\n\n".to_string()
 }
@@ -367,6 +1487,9 @@ fn default_target_dpi() -> i32 {
 fn default_max_dimension() -> i32 {
     4096
 }
+fn default_duplicate_hash_distance() -> u32 {
+    5
+}
 fn default_min_dpi() -> i32 {
     72
 }
@@ -376,9 +1499,45 @@ fn default_max_dpi() -> i32 {
 fn default_reduction_mode() -> String {
     "off".to_string()
 }
+fn default_min_concurrent() -> usize {
+    1
+}
+fn default_memory_high_watermark() -> f64 {
+    0.85
+}
 fn default_confidence() -> f64 {
     0.8
 }
+fn default_classification_min_confidence() -> f64 {
+    0.3
+}
+fn default_kv_max_label_words() -> usize {
+    5
+}
+fn default_preview_sentences() -> usize {
+    3
+}
+fn default_retry_max_attempts() -> u32 {
+    3
+}
+fn default_retry_initial_backoff_ms() -> u64 {
+    200
+}
+fn default_retry_max_backoff_ms() -> u64 {
+    5_000
+}
+fn default_retry_multiplier() -> f64 {
+    2.0
+}
+fn default_retry_on() -> Vec<RetryableError> {
+    vec![RetryableError::Io, RetryableError::Ocr]
+}
+fn default_text_encoding() -> String {
+    "auto".to_string()
+}
+fn default_normalization_form() -> Option<UnicodeNormalization> {
+    Some(UnicodeNormalization::Nfc)
+}
 
 impl Default for ExtractionConfig {
     fn default() -> Self {
@@ -393,13 +1552,39 @@ impl Default for ExtractionConfig {
             pdf_options: None,
             token_reduction: None,
             language_detection: None,
+            classification: None,
+            kv_extraction: None,
+            preview: None,
             pages: None,
+            text_options: None,
+            csv_options: None,
+            spreadsheet_options: None,
+            pptx_options: None,
+            normalization: None,
+            cleanup: None,
+            watermarks: None,
+            emit_term_offsets: false,
+            emit_provenance: false,
+            detect_barcodes: false,
+            incremental: false,
+            fields: None,
+            result_compression: None,
             #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
             keywords: None,
             postprocessor: None,
             #[cfg(feature = "html")]
             html_options: None,
             max_concurrent_extractions: None,
+            adaptive_concurrency: None,
+            spill_dir: None,
+            spill_threshold_bytes: None,
+            input_policy: None,
+            stage_timeouts: None,
+            retry: None,
+            cache: None,
+            ocr_correction: None,
+            resource_profile: ResourceProfile::default(),
+            overrides: None,
         }
     }
 }
@@ -470,6 +1655,7 @@ impl ExtractionConfig {
 
         let config: Self = toml::from_str(&content)
             .map_err(|e| KreuzbergError::validation(format!("Invalid TOML in {}: {}", path.display(), e)))?;
+        crate::core::overrides::validate_overrides(&config)?;
 
         let config_arc = Arc::new(config.clone());
         CONFIG_CACHE.insert(path.to_path_buf(), (mtime, config_arc));
@@ -501,6 +1687,7 @@ impl ExtractionConfig {
 
         let config: Self = serde_yaml_ng::from_str(&content)
             .map_err(|e| KreuzbergError::validation(format!("Invalid YAML in {}: {}", path.display(), e)))?;
+        crate::core::overrides::validate_overrides(&config)?;
 
         let config_arc = Arc::new(config.clone());
         CONFIG_CACHE.insert(path.to_path_buf(), (mtime, config_arc));
@@ -532,6 +1719,7 @@ impl ExtractionConfig {
 
         let config: Self = serde_json::from_str(&content)
             .map_err(|e| KreuzbergError::validation(format!("Invalid JSON in {}: {}", path.display(), e)))?;
+        crate::core::overrides::validate_overrides(&config)?;
 
         let config_arc = Arc::new(config.clone());
         CONFIG_CACHE.insert(path.to_path_buf(), (mtime, config_arc));
@@ -620,12 +1808,20 @@ impl ExtractionConfig {
     /// - `Some(config)` if found
     /// - `None` if no config file found
     pub fn discover() -> Result<Option<Self>> {
+        match Self::discover_path()? {
+            Some(path) => Ok(Some(Self::from_toml_file(path)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Path of the `kreuzberg.toml` that [`ExtractionConfig::discover`] loads, if any.
+    pub fn discover_path() -> Result<Option<PathBuf>> {
         let mut current = std::env::current_dir().map_err(KreuzbergError::Io)?;
 
         loop {
             let kreuzberg_toml = current.join("kreuzberg.toml");
             if kreuzberg_toml.exists() {
-                return Ok(Some(Self::from_toml_file(kreuzberg_toml)?));
+                return Ok(Some(kreuzberg_toml));
             }
 
             if let Some(parent) = current.parent() {
@@ -672,6 +1868,56 @@ enable_quality_processing = true
         assert!(config.enable_quality_processing);
     }
 
+    #[test]
+    fn test_from_toml_file_with_mime_overrides() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("kreuzberg.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+force_ocr = true
+
+[ocr]
+language = "eng"
+
+[overrides."application/pdf"]
+force_ocr = false
+
+[overrides."image/*"]
+ocr.language = "deu"
+        "#,
+        )
+        .unwrap();
+
+        let config = ExtractionConfig::from_toml_file(&config_path).unwrap();
+        let pdf = crate::core::overrides::resolve_for_mime(&config, "application/pdf").unwrap();
+        let image = crate::core::overrides::resolve_for_mime(&config, "image/png").unwrap();
+
+        assert!(!pdf.force_ocr);
+        assert!(image.force_ocr);
+        assert_eq!(image.ocr.as_ref().unwrap().language, "deu");
+    }
+
+    #[test]
+    fn test_from_yaml_file_rejects_invalid_override() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("kreuzberg.yaml");
+
+        fs::write(
+            &config_path,
+            r#"
+overrides:
+  "image/*":
+    force_ocr: "sometimes"
+        "#,
+        )
+        .unwrap();
+
+        let err = ExtractionConfig::from_yaml_file(&config_path).unwrap_err();
+        assert!(err.to_string().contains("image/*"));
+    }
+
     #[test]
     fn test_from_file_auto_detects_toml() {
         let dir = tempdir().unwrap();
@@ -827,6 +2073,73 @@ max_dpi = 600
         assert!(images.auto_adjust_dpi);
         assert_eq!(images.min_dpi, 72);
         assert_eq!(images.max_dpi, 600);
+        assert!(images.ocr_policy.is_none());
+    }
+
+    #[test]
+    fn test_image_ocr_policy() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("kreuzberg.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+[images.ocr_policy]
+min_width = 64
+min_bytes = 1024
+pages = [1, 3]
+skip_decorative = true
+        "#,
+        )
+        .unwrap();
+
+        let config = ExtractionConfig::from_toml_file(&config_path).unwrap();
+        let policy = config.images.unwrap().ocr_policy.unwrap();
+        assert!(policy.skip_masks);
+
+        let image = crate::types::ExtractedImage {
+            data: vec![0; 2048],
+            format: "png".to_string(),
+            image_index: 0,
+            page_number: Some(3),
+            width: Some(200),
+            height: Some(100),
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
+        };
+        assert!(policy.should_ocr(&image));
+
+        let rejected = [
+            crate::types::ExtractedImage {
+                width: Some(32),
+                ..image.clone()
+            },
+            crate::types::ExtractedImage {
+                data: vec![0; 512],
+                ..image.clone()
+            },
+            crate::types::ExtractedImage {
+                page_number: Some(2),
+                ..image.clone()
+            },
+            crate::types::ExtractedImage {
+                is_mask: true,
+                ..image.clone()
+            },
+            crate::types::ExtractedImage {
+                width: Some(1200),
+                height: Some(80),
+                ..image.clone()
+            },
+        ];
+        assert!(rejected.iter().all(|image| !policy.should_ocr(image)));
     }
 
     #[test]
@@ -1194,6 +2507,36 @@ language = "eng"
         assert!(ocr.tesseract_config.is_none());
     }
 
+    #[test]
+    fn test_ocr_fallback_config() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("kreuzberg.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+[ocr]
+backend = "tesseract"
+language = "deu"
+
+[ocr.fallback]
+backend = "onnx-ocr"
+"#,
+        )
+        .unwrap();
+
+        let config = ExtractionConfig::from_toml_file(&config_path).unwrap();
+        let ocr = config.ocr.unwrap();
+        let fallback = ocr.fallback.as_ref().unwrap();
+        assert_eq!(fallback.backend, "onnx-ocr");
+        assert_eq!(fallback.min_confidence, 0.6);
+
+        let fallback_config = fallback.to_ocr_config(&ocr);
+        assert_eq!(fallback_config.backend, "onnx-ocr");
+        assert_eq!(fallback_config.language, "deu");
+        assert!(fallback_config.fallback.is_none());
+    }
+
     #[test]
     fn test_tesseract_config_defaults() {
         let tess = crate::types::TesseractConfig::default();
@@ -0,0 +1,399 @@
+//! Configuration schema for language bindings.
+//!
+//! Describes every config struct that bindings expose as a typed class: the field names
+//! its `Deserialize` implementation accepts, the value each field takes when omitted,
+//! and which fields hold another config section. Bindings generate their config classes
+//! from this schema and validate values with [`validate_config_section`], so their field
+//! lists, defaults and validation cannot drift from the Rust structs.
+//!
+//! Field names are read from the derived `Deserialize` implementation and defaults from
+//! deserializing an empty object, so adding a field to a config struct adds it to the
+//! schema without further changes. Fields without a serde default are marked `required`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::config_schema::{config_schema, validate_config_section};
+//! use serde_json::json;
+//!
+//! let schema = config_schema();
+//! let pptx = schema.iter().find(|section| section.name == "PptxConfig").unwrap();
+//! assert!(pptx.fields.iter().any(|field| field.name == "include_notes"));
+//!
+//! let normalized = validate_config_section("PptxConfig", &json!({"slide_markers": true})).unwrap();
+//! assert_eq!(normalized["include_notes"], json!(true));
+//!
+//! assert!(validate_config_section("PptxConfig", &json!({"slide_marker": true})).is_err());
+//! ```
+
+use crate::core::config::{
+    AdaptiveConcurrencyConfig, CacheConfig, ChunkingConfig, ClassificationConfig, CleanupConfig, CsvConfig,
+    EmbeddingConfig, ExtractionConfig, ImageExtractionConfig, ImageOcrPolicy, KeyValueExtractionConfig,
+    LanguageDetectionConfig, NormalizationConfig, OcrConfig, OcrCorrectionConfig, OcrFallbackConfig,
+    OcrVocabularyConfig, PageConfig, PostProcessorConfig, PptxConfig, PreviewConfig, RetryConfig, SpreadsheetConfig,
+    StageTimeoutConfig, TextExtractionConfig, TokenReductionConfig, WatermarkConfig,
+};
+use crate::types::{ImagePreprocessingConfig, TesseractConfig};
+use crate::{KreuzbergError, Result};
+use serde::de::{self, DeserializeOwned, Visitor};
+use serde::{Deserializer, Serialize};
+use serde_json::Value;
+
+/// One config struct in the schema.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSection {
+    /// Rust struct name, e.g. `OcrConfig`
+    pub name: &'static str,
+    /// Fields in declaration order
+    pub fields: Vec<ConfigField>,
+}
+
+/// One field of a config struct.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigField {
+    /// Field name as accepted in configuration files and binding configs
+    pub name: &'static str,
+    /// Value used when the field is omitted (`null` for required fields)
+    pub default: Value,
+    /// Config section held by this field, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<&'static str>,
+    /// Whether the field has no default and must be given
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+}
+
+/// A config struct and how to introspect and validate it.
+struct SectionType {
+    name: &'static str,
+    fields: fn() -> &'static [&'static str],
+    normalize: fn(Value) -> serde_json::Result<Value>,
+    /// Fields that hold another section, as (field, section) pairs
+    nested: &'static [(&'static str, &'static str)],
+    /// Required fields, with a placeholder value (as JSON) used to compute the other
+    /// fields' defaults
+    required: &'static [(&'static str, &'static str)],
+}
+
+impl SectionType {
+    fn of<T: DeserializeOwned + Serialize>(
+        name: &'static str,
+        nested: &'static [(&'static str, &'static str)],
+        required: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        Self {
+            name,
+            fields: struct_fields::<T>,
+            normalize: normalize::<T>,
+            nested,
+            required,
+        }
+    }
+
+    fn schema(&self) -> ConfigSection {
+        let seed = self
+            .required
+            .iter()
+            .map(|(field, placeholder)| {
+                let placeholder = serde_json::from_str(placeholder).unwrap_or(Value::Null);
+                (field.to_string(), placeholder)
+            })
+            .collect();
+        let defaults = (self.normalize)(Value::Object(seed)).unwrap_or(Value::Null);
+
+        let fields = (self.fields)()
+            .iter()
+            .map(|&name| {
+                let required = self.required.iter().any(|(field, _)| *field == name);
+                ConfigField {
+                    name,
+                    default: if required {
+                        Value::Null
+                    } else {
+                        defaults.get(name).cloned().unwrap_or(Value::Null)
+                    },
+                    section: self.nested_section(name),
+                    required,
+                }
+            })
+            .collect();
+
+        ConfigSection {
+            name: self.name,
+            fields,
+        }
+    }
+
+    fn nested_section(&self, field: &str) -> Option<&'static str> {
+        self.nested
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, section)| *section)
+    }
+}
+
+fn section_types() -> Vec<SectionType> {
+    vec![
+        SectionType::of::<ExtractionConfig>(
+            "ExtractionConfig",
+            &[
+                ("ocr", "OcrConfig"),
+                ("chunking", "ChunkingConfig"),
+                ("images", "ImageExtractionConfig"),
+                #[cfg(feature = "pdf")]
+                ("pdf_options", "PdfConfig"),
+                ("token_reduction", "TokenReductionConfig"),
+                ("language_detection", "LanguageDetectionConfig"),
+                ("classification", "ClassificationConfig"),
+                ("kv_extraction", "KeyValueExtractionConfig"),
+                ("preview", "PreviewConfig"),
+                ("pages", "PageConfig"),
+                ("text_options", "TextExtractionConfig"),
+                ("csv_options", "CsvConfig"),
+                ("spreadsheet_options", "SpreadsheetConfig"),
+                ("pptx_options", "PptxConfig"),
+                ("normalization", "NormalizationConfig"),
+                ("cleanup", "CleanupConfig"),
+                ("watermarks", "WatermarkConfig"),
+                #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+                ("keywords", "KeywordConfig"),
+                ("postprocessor", "PostProcessorConfig"),
+                ("adaptive_concurrency", "AdaptiveConcurrencyConfig"),
+                ("ocr_correction", "OcrCorrectionConfig"),
+                ("stage_timeouts", "StageTimeoutConfig"),
+                ("retry", "RetryConfig"),
+                ("cache", "CacheConfig"),
+            ],
+            &[],
+        ),
+        SectionType::of::<OcrConfig>(
+            "OcrConfig",
+            &[
+                ("tesseract_config", "TesseractConfig"),
+                ("fallback", "OcrFallbackConfig"),
+                ("vocabulary", "OcrVocabularyConfig"),
+            ],
+            &[],
+        ),
+        SectionType::of::<OcrFallbackConfig>("OcrFallbackConfig", &[], &[("backend", r#""tesseract""#)]),
+        SectionType::of::<OcrVocabularyConfig>("OcrVocabularyConfig", &[], &[]),
+        SectionType::of::<OcrCorrectionConfig>("OcrCorrectionConfig", &[], &[]),
+        SectionType::of::<TesseractConfig>("TesseractConfig", &[("preprocessing", "ImagePreprocessingConfig")], &[]),
+        SectionType::of::<ImagePreprocessingConfig>("ImagePreprocessingConfig", &[], &[]),
+        SectionType::of::<ChunkingConfig>("ChunkingConfig", &[("embedding", "EmbeddingConfig")], &[]),
+        SectionType::of::<EmbeddingConfig>(
+            "EmbeddingConfig",
+            &[],
+            &[("model", r#"{"type": "preset", "name": "balanced"}"#)],
+        ),
+        SectionType::of::<ImageExtractionConfig>("ImageExtractionConfig", &[("ocr_policy", "ImageOcrPolicy")], &[]),
+        SectionType::of::<ImageOcrPolicy>("ImageOcrPolicy", &[], &[]),
+        #[cfg(feature = "pdf")]
+        SectionType::of::<crate::core::config::PdfConfig>("PdfConfig", &[], &[]),
+        SectionType::of::<TokenReductionConfig>("TokenReductionConfig", &[], &[]),
+        SectionType::of::<LanguageDetectionConfig>("LanguageDetectionConfig", &[], &[]),
+        SectionType::of::<ClassificationConfig>("ClassificationConfig", &[], &[]),
+        SectionType::of::<KeyValueExtractionConfig>("KeyValueExtractionConfig", &[], &[]),
+        SectionType::of::<PreviewConfig>("PreviewConfig", &[], &[]),
+        SectionType::of::<CleanupConfig>("CleanupConfig", &[], &[]),
+        SectionType::of::<WatermarkConfig>("WatermarkConfig", &[], &[]),
+        SectionType::of::<PageConfig>("PageConfig", &[], &[]),
+        SectionType::of::<TextExtractionConfig>("TextExtractionConfig", &[], &[]),
+        SectionType::of::<CsvConfig>("CsvConfig", &[], &[]),
+        SectionType::of::<SpreadsheetConfig>("SpreadsheetConfig", &[], &[]),
+        SectionType::of::<PptxConfig>("PptxConfig", &[], &[]),
+        SectionType::of::<NormalizationConfig>("NormalizationConfig", &[], &[]),
+        #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+        SectionType::of::<crate::keywords::KeywordConfig>("KeywordConfig", &[], &[]),
+        SectionType::of::<PostProcessorConfig>("PostProcessorConfig", &[], &[]),
+        SectionType::of::<AdaptiveConcurrencyConfig>("AdaptiveConcurrencyConfig", &[], &[]),
+        SectionType::of::<StageTimeoutConfig>("StageTimeoutConfig", &[], &[]),
+        SectionType::of::<RetryConfig>("RetryConfig", &[], &[]),
+        SectionType::of::<CacheConfig>("CacheConfig", &[], &[]),
+    ]
+}
+
+/// Schema of every config section, starting with `ExtractionConfig`.
+pub fn config_schema() -> Vec<ConfigSection> {
+    section_types().iter().map(SectionType::schema).collect()
+}
+
+/// Validate a config section and fill in the defaults of omitted fields.
+///
+/// Nested sections are validated recursively. Returns the section as the Rust struct
+/// serializes it, so callers see the values the extractor will actually use.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` for unknown sections, unknown fields and values
+/// of the wrong type.
+pub fn validate_config_section(section: &str, value: &Value) -> Result<Value> {
+    let types = section_types();
+    check_fields(&types, section, value)?;
+
+    let section_type = find_section(&types, section)?;
+    (section_type.normalize)(value.clone())
+        .map_err(|e| KreuzbergError::validation(format!("Invalid {}: {}", section, e)))
+}
+
+fn find_section<'a>(types: &'a [SectionType], section: &str) -> Result<&'a SectionType> {
+    types
+        .iter()
+        .find(|section_type| section_type.name == section)
+        .ok_or_else(|| KreuzbergError::validation(format!("Unknown config section '{}'", section)))
+}
+
+fn check_fields(types: &[SectionType], section: &str, value: &Value) -> Result<()> {
+    let section_type = find_section(types, section)?;
+    let Value::Object(map) = value else {
+        return Err(KreuzbergError::validation(format!(
+            "Invalid {}: expected an object, got {}",
+            section, value
+        )));
+    };
+
+    let fields = (section_type.fields)();
+    for (key, field_value) in map {
+        if !fields.contains(&key.as_str()) {
+            return Err(KreuzbergError::validation(format!(
+                "Unknown field '{}' in {} (expected one of {})",
+                key,
+                section,
+                fields.join(", ")
+            )));
+        }
+        if let Some(nested) = section_type.nested_section(key)
+            && !field_value.is_null()
+        {
+            check_fields(types, nested, field_value)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn normalize<T: DeserializeOwned + Serialize>(value: Value) -> serde_json::Result<Value> {
+    serde_json::to_value(serde_json::from_value::<T>(value)?)
+}
+
+/// Field names accepted by the derived `Deserialize` implementation of `T`.
+fn struct_fields<T: DeserializeOwned>() -> &'static [&'static str] {
+    let mut fields = None;
+    let _ = T::deserialize(FieldNames { fields: &mut fields });
+    fields.unwrap_or_default()
+}
+
+/// Deserializer that records the field list a derived `Deserialize` passes to
+/// `deserialize_struct` and then aborts.
+struct FieldNames<'a> {
+    fields: &'a mut Option<&'static [&'static str]>,
+}
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+        *self.fields = Some(fields);
+        Err(de::Error::custom("field names recorded"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn section(name: &str) -> ConfigSection {
+        config_schema()
+            .into_iter()
+            .find(|section| section.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_schema_lists_fields_with_defaults() {
+        let ocr = section("OcrConfig");
+        let names: Vec<&str> = ocr.fields.iter().map(|field| field.name).collect();
+        assert_eq!(
+            names,
+            vec!["backend", "language", "tesseract_config", "fallback", "vocabulary"]
+        );
+        assert_eq!(ocr.fields[0].default, json!("tesseract"));
+        assert_eq!(ocr.fields[3].section, Some("OcrFallbackConfig"));
+
+        let extraction = section("ExtractionConfig");
+        let fields = extraction.fields.iter().find(|field| field.name == "fields").unwrap();
+        assert_eq!(fields.default, Value::Null);
+        let images = extraction.fields.iter().find(|field| field.name == "images").unwrap();
+        assert_eq!(images.section, Some("ImageExtractionConfig"));
+    }
+
+    #[test]
+    fn test_required_fields() {
+        let fallback = section("OcrFallbackConfig");
+        let backend = fallback.fields.iter().find(|field| field.name == "backend").unwrap();
+        assert!(backend.required);
+        assert_eq!(backend.default, Value::Null);
+        let min_confidence = fallback
+            .fields
+            .iter()
+            .find(|field| field.name == "min_confidence")
+            .unwrap();
+        assert!(!min_confidence.required);
+        assert!(min_confidence.default.is_number());
+    }
+
+    #[test]
+    fn test_every_section_is_introspected() {
+        let schema = config_schema();
+        let names: Vec<&str> = schema.iter().map(|section| section.name).collect();
+        for section in &schema {
+            assert!(!section.fields.is_empty(), "{} has no fields", section.name);
+            for field in &section.fields {
+                if let Some(nested) = field.section {
+                    assert!(names.contains(&nested), "{}.{} -> {}", section.name, field.name, nested);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_fills_defaults() {
+        let value = validate_config_section("OcrConfig", &json!({"language": "deu"})).unwrap();
+        assert_eq!(value["backend"], json!("tesseract"));
+        assert_eq!(value["language"], json!("deu"));
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_and_mistyped_fields() {
+        let err = validate_config_section("OcrConfig", &json!({"langauge": "deu"})).unwrap_err();
+        assert!(err.to_string().contains("Unknown field 'langauge' in OcrConfig"));
+
+        let err = validate_config_section("OcrConfig", &json!({"fallback": {"backend": "x", "confidence": 0.5}}))
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unknown field 'confidence' in OcrFallbackConfig")
+        );
+
+        let err = validate_config_section("PptxConfig", &json!({"include_notes": "yes"})).unwrap_err();
+        assert!(err.to_string().contains("Invalid PptxConfig"));
+
+        assert!(validate_config_section("NoSuchConfig", &json!({})).is_err());
+        assert!(validate_config_section("PptxConfig", &json!([])).is_err());
+    }
+}
@@ -25,7 +25,9 @@
 //! assert!(validate_token_reduction_level("extreme").is_err());
 //! ```
 
+use crate::types::TesseractConfig;
 use crate::{KreuzbergError, Result};
+use std::path::Path;
 
 /// Valid binarization methods for image preprocessing.
 const VALID_BINARIZATION_METHODS: &[&str] = &["otsu", "adaptive", "sauvola"];
@@ -57,6 +59,28 @@ const VALID_TESSERACT_OEM: &[i32] = &[0, 1, 2, 3];
 /// Valid output formats for tesseract.
 const VALID_OUTPUT_FORMATS: &[&str] = &["text", "markdown"];
 
+/// Tesseract variables that `TesseractConfig` sets from a dedicated field, as (variable, field).
+const DEDICATED_TESSERACT_VARIABLES: &[(&str, &str)] = &[
+    ("tessedit_pageseg_mode", "psm"),
+    ("tessedit_ocr_engine_mode", "oem"),
+    (
+        "classify_use_pre_adapted_templates",
+        "classify_use_pre_adapted_templates",
+    ),
+    ("language_model_ngram_on", "language_model_ngram_on"),
+    ("tessedit_dont_blkrej_good_wds", "tessedit_dont_blkrej_good_wds"),
+    ("tessedit_dont_rowrej_good_wds", "tessedit_dont_rowrej_good_wds"),
+    ("tessedit_enable_dict_correction", "tessedit_enable_dict_correction"),
+    ("tessedit_char_whitelist", "tessedit_char_whitelist"),
+    ("tessedit_char_blacklist", "tessedit_char_blacklist"),
+    ("tessedit_use_primary_params_model", "tessedit_use_primary_params_model"),
+    ("textord_space_size_is_variable", "textord_space_size_is_variable"),
+    ("thresholding_method", "thresholding_method"),
+    ("preserve_interword_spaces", "preserve_interword_spaces"),
+    ("user_words_file", "user_words_file"),
+    ("user_patterns_file", "user_patterns_file"),
+];
+
 /// Validate a binarization method string.
 ///
 /// # Arguments
@@ -426,6 +450,118 @@ pub fn validate_chunking_params(max_chars: usize, max_overlap: usize) -> Result<
     Ok(())
 }
 
+/// Validate the Tesseract variables of a `TesseractConfig`.
+///
+/// Checks the character whitelist and blacklist, the user words and patterns files, and
+/// every entry of `variables`. Every error names the offending variable.
+///
+/// # Arguments
+///
+/// * `config` - The Tesseract configuration to validate
+///
+/// # Returns
+///
+/// `Ok(())` if the variables are valid, or a `ValidationError` naming the first invalid one:
+/// - a variable name that is not lowercase letters, digits and underscores
+/// - a variable in `variables` that has a dedicated field
+/// - a value containing a NUL byte or a line break
+/// - characters in both `tessedit_char_whitelist` and `tessedit_char_blacklist`
+/// - a `user_words_file` or `user_patterns_file` that does not exist
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::core::config_validation::validate_tesseract_variables;
+/// use kreuzberg::TesseractConfig;
+///
+/// let mut config = TesseractConfig::default();
+/// config.variables.insert("textord_min_xheight".to_string(), "10".to_string());
+/// assert!(validate_tesseract_variables(&config).is_ok());
+///
+/// config.variables.insert("tessedit_char_whitelist".to_string(), "0123456789".to_string());
+/// assert!(validate_tesseract_variables(&config).is_err()); // use the dedicated field
+/// ```
+pub fn validate_tesseract_variables(config: &TesseractConfig) -> Result<()> {
+    validate_tesseract_value("tessedit_char_whitelist", &config.tessedit_char_whitelist)?;
+    validate_tesseract_value("tessedit_char_blacklist", &config.tessedit_char_blacklist)?;
+
+    let mut conflicting: Vec<char> = config
+        .tessedit_char_whitelist
+        .chars()
+        .filter(|c| config.tessedit_char_blacklist.contains(*c))
+        .collect();
+    conflicting.dedup();
+    if !conflicting.is_empty() {
+        return Err(KreuzbergError::Validation {
+            message: format!(
+                "Characters '{}' are in both tessedit_char_whitelist and tessedit_char_blacklist",
+                conflicting.into_iter().collect::<String>()
+            ),
+            source: None,
+        });
+    }
+
+    for (name, path) in [
+        ("user_words_file", &config.user_words_file),
+        ("user_patterns_file", &config.user_patterns_file),
+    ] {
+        let Some(path) = path else { continue };
+        validate_tesseract_value(name, path)?;
+        if !Path::new(path).is_file() {
+            return Err(KreuzbergError::Validation {
+                message: format!("Tesseract variable '{}': file not found: {}", name, path),
+                source: None,
+            });
+        }
+    }
+
+    for (name, value) in &config.variables {
+        let valid_name = name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid_name {
+            return Err(KreuzbergError::Validation {
+                message: format!(
+                    "Invalid Tesseract variable name '{}'. Names are lowercase letters, digits and underscores",
+                    name
+                ),
+                source: None,
+            });
+        }
+
+        if let Some((_, field)) = DEDICATED_TESSERACT_VARIABLES
+            .iter()
+            .find(|(variable, _)| variable == name)
+        {
+            return Err(KreuzbergError::Validation {
+                message: format!(
+                    "Tesseract variable '{}' has a dedicated field; set tesseract_config.{} instead",
+                    name, field
+                ),
+                source: None,
+            });
+        }
+
+        validate_tesseract_value(name, value)?;
+    }
+
+    Ok(())
+}
+
+fn validate_tesseract_value(name: &str, value: &str) -> Result<()> {
+    if value.contains(['\0', '\n', '\r']) {
+        return Err(KreuzbergError::Validation {
+            message: format!(
+                "Tesseract variable '{}' must not contain NUL bytes or line breaks",
+                name
+            ),
+            source: None,
+        });
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -641,6 +777,54 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_tesseract_variables() {
+        let mut config = TesseractConfig {
+            tessedit_char_whitelist: "0123456789".to_string(),
+            preserve_interword_spaces: true,
+            ..Default::default()
+        };
+        config
+            .variables
+            .insert("textord_min_xheight".to_string(), "10".to_string());
+        assert!(validate_tesseract_variables(&config).is_ok());
+
+        let mut dedicated = config.clone();
+        dedicated
+            .variables
+            .insert("tessedit_pageseg_mode".to_string(), "6".to_string());
+        let err = validate_tesseract_variables(&dedicated).unwrap_err().to_string();
+        assert!(err.contains("'tessedit_pageseg_mode'"));
+        assert!(err.contains("tesseract_config.psm"));
+
+        let mut bad_name = config.clone();
+        bad_name.variables.insert("Bad-Name".to_string(), "1".to_string());
+        let err = validate_tesseract_variables(&bad_name).unwrap_err().to_string();
+        assert!(err.contains("'Bad-Name'"));
+
+        let mut bad_value = config.clone();
+        bad_value
+            .variables
+            .insert("textord_min_xheight".to_string(), "10\nfoo 1".to_string());
+        let err = validate_tesseract_variables(&bad_value).unwrap_err().to_string();
+        assert!(err.contains("'textord_min_xheight'"));
+
+        let conflicting = TesseractConfig {
+            tessedit_char_blacklist: "13x".to_string(),
+            ..config.clone()
+        };
+        let err = validate_tesseract_variables(&conflicting).unwrap_err().to_string();
+        assert!(err.contains("'13'"));
+        assert!(err.contains("tessedit_char_blacklist"));
+
+        let missing = TesseractConfig {
+            user_words_file: Some("/nonexistent/words.txt".to_string()),
+            ..config
+        };
+        let err = validate_tesseract_variables(&missing).unwrap_err().to_string();
+        assert!(err.contains("'user_words_file'"));
+    }
+
     #[test]
     fn test_error_messages_are_helpful() {
         // Binarization error includes valid options
@@ -0,0 +1,106 @@
+//! Process-wide counters of pipeline work.
+//!
+//! Relaxed atomics bumped by the pipeline where the work happens, so embedders can report
+//! them without wiring a logging callback (the FFI exposes them through `kreuzberg_stats()`):
+//!
+//! - OCR pages: page and image renders handed to an OCR backend (PDF pages, images,
+//!   embedded presentation images); a fallback retry of the same page is not counted again
+//! - Result cache lookups of extractions with `ExtractionConfig::cache` set, split into
+//!   hits and misses
+//!
+//! Counters start at zero when the process starts and are shared by all callers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::counters::{pipeline_counters, reset_pipeline_counters};
+//!
+//! let counters = pipeline_counters();
+//! println!("{} OCR pages, cache hit rate {:?}", counters.ocr_pages, counters.cache_hit_rate());
+//! reset_pipeline_counters();
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static OCR_PAGES: AtomicU64 = AtomicU64::new(0);
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+static CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Snapshot of the pipeline counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PipelineCounters {
+    /// Pages and images handed to an OCR backend
+    pub ocr_pages: u64,
+    /// Result cache lookups that returned a stored result
+    pub cache_hits: u64,
+    /// Result cache lookups that found nothing (or an unreadable entry)
+    pub cache_misses: u64,
+}
+
+impl PipelineCounters {
+    /// Share of result cache lookups that hit, `None` before the first lookup.
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let lookups = self.cache_hits + self.cache_misses;
+        (lookups > 0).then(|| self.cache_hits as f64 / lookups as f64)
+    }
+}
+
+/// Current values of the pipeline counters.
+pub fn pipeline_counters() -> PipelineCounters {
+    PipelineCounters {
+        ocr_pages: OCR_PAGES.load(Ordering::Relaxed),
+        cache_hits: CACHE_HITS.load(Ordering::Relaxed),
+        cache_misses: CACHE_MISSES.load(Ordering::Relaxed),
+    }
+}
+
+/// Set every pipeline counter back to zero.
+pub fn reset_pipeline_counters() {
+    OCR_PAGES.store(0, Ordering::Relaxed);
+    CACHE_HITS.store(0, Ordering::Relaxed);
+    CACHE_MISSES.store(0, Ordering::Relaxed);
+}
+
+#[cfg(feature = "ocr")]
+pub(crate) fn record_ocr_page() {
+    OCR_PAGES.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_cache_lookup(hit: bool) {
+    let counter = if hit { &CACHE_HITS } else { &CACHE_MISSES };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_hit_rate() {
+        assert_eq!(PipelineCounters::default().cache_hit_rate(), None);
+
+        let counters = PipelineCounters {
+            ocr_pages: 0,
+            cache_hits: 3,
+            cache_misses: 1,
+        };
+        assert_eq!(counters.cache_hit_rate(), Some(0.75));
+    }
+
+    #[test]
+    fn test_record_increments_counters() {
+        // Concurrent tests only ever add to the counters, so the lower bounds hold
+        let before = pipeline_counters();
+        #[cfg(feature = "ocr")]
+        record_ocr_page();
+        record_cache_lookup(true);
+        record_cache_lookup(false);
+
+        let after = pipeline_counters();
+        #[cfg(feature = "ocr")]
+        assert!(after.ocr_pages > before.ocr_pages);
+        assert!(after.cache_hits > before.cache_hits);
+        assert!(after.cache_misses > before.cache_misses);
+    }
+}
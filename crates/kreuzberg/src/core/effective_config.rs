@@ -0,0 +1,238 @@
+//! The effective configuration and where it came from.
+//!
+//! [`EffectiveConfig::resolve`] merges the configuration layers in order, later layers
+//! winning:
+//!
+//! 1. Built-in defaults
+//! 2. The config file named by `KREUZBERG_CONFIG_PATH`, or else the `kreuzberg.toml` found by
+//!    [`ExtractionConfig::discover`]
+//! 3. `KREUZBERG_OCR_BACKEND` and `KREUZBERG_OCR_LANGUAGE`, which enable OCR with the given
+//!    backend or language
+//! 4. Caller overrides, merged like per-MIME overrides: objects key by key, any other value
+//!    replaces the layer below
+//!
+//! Given a MIME type, the per-MIME [`overrides`](crate::core::overrides) of the merged
+//! configuration are resolved for it. Finally the [resource profile](crate::core::resource_profile)
+//! is applied, so the result shows what extraction actually runs with, e.g. OCR capped at
+//! 150 DPI in a low-memory container. Bindings expose this to debug why OCR did (or did not)
+//! run; the exported JSON or YAML loads back with [`ExtractionConfig::from_file`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::core::effective_config::EffectiveConfig;
+//! use serde_json::json;
+//!
+//! let effective = EffectiveConfig::resolve(Some(&json!({"force_ocr": true})), Some("application/pdf"))?;
+//! println!("loaded from {:?}", effective.config_file);
+//! println!("{}", effective.to_yaml()?);
+//! # Ok::<(), kreuzberg::KreuzbergError>(())
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::core::overrides::{merge, resolve_for_mime, restore_skipped_fields};
+use crate::core::resource_profile::apply_resource_profile;
+use crate::{KreuzbergError, Result};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Environment variable naming the config file to load instead of discovering one.
+pub const CONFIG_PATH_ENV: &str = "KREUZBERG_CONFIG_PATH";
+
+/// Environment variable selecting the OCR backend.
+pub const OCR_BACKEND_ENV: &str = "KREUZBERG_OCR_BACKEND";
+
+/// Environment variable selecting the OCR language.
+pub const OCR_LANGUAGE_ENV: &str = "KREUZBERG_OCR_LANGUAGE";
+
+/// A fully merged configuration and the layers it was built from.
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveConfig {
+    /// Configuration after every layer, per-MIME overrides and the resource profile
+    pub config: ExtractionConfig,
+
+    /// Config file the configuration was loaded from (`None` = built-in defaults)
+    pub config_file: Option<PathBuf>,
+
+    /// Environment variables that changed the configuration, with their values
+    pub environment: BTreeMap<String, String>,
+
+    /// Top-level fields set by the caller's overrides
+    pub overrides: Vec<String>,
+
+    /// MIME type the per-MIME overrides were resolved for
+    pub mime_type: Option<String>,
+}
+
+impl EffectiveConfig {
+    /// Merge the configuration layers from the process environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KreuzbergError::Validation`] when the config file cannot be loaded, the
+    /// overrides are not an object or do not produce a valid configuration.
+    pub fn resolve(overrides: Option<&Value>, mime_type: Option<&str>) -> Result<Self> {
+        Self::resolve_with(overrides, mime_type, |name| std::env::var(name).ok())
+    }
+
+    /// [`EffectiveConfig::resolve`] with environment variables read through `env`.
+    pub fn resolve_with(
+        overrides: Option<&Value>,
+        mime_type: Option<&str>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
+        let config_file = match env(CONFIG_PATH_ENV).filter(|path| !path.is_empty()) {
+            Some(path) => Some(PathBuf::from(path)),
+            None => ExtractionConfig::discover_path()?,
+        };
+        let mut config = match &config_file {
+            Some(path) => ExtractionConfig::from_file(path)?,
+            None => ExtractionConfig::default(),
+        };
+
+        let mut environment = BTreeMap::new();
+        let mut ocr = serde_json::Map::new();
+        for (name, field) in [(OCR_BACKEND_ENV, "backend"), (OCR_LANGUAGE_ENV, "language")] {
+            if let Some(value) = env(name).filter(|value| !value.is_empty()) {
+                ocr.insert(field.to_string(), Value::String(value.clone()));
+                environment.insert(name.to_string(), value);
+            }
+        }
+        if !ocr.is_empty() {
+            config = apply_layer(&config, &json!({ "ocr": ocr }), "environment")?;
+        }
+
+        let mut override_fields = Vec::new();
+        if let Some(overrides) = overrides {
+            let Some(fields) = overrides.as_object() else {
+                return Err(KreuzbergError::validation(
+                    "Configuration overrides must be an object of config fields",
+                ));
+            };
+            if !fields.is_empty() {
+                override_fields = fields.keys().cloned().collect();
+                config = apply_layer(&config, overrides, "overrides")?;
+            }
+        }
+
+        if let Some(mime_type) = mime_type {
+            config = resolve_for_mime(&config, mime_type)?.into_owned();
+        }
+        let config = apply_resource_profile(&config).into_owned();
+
+        Ok(Self {
+            config,
+            config_file,
+            environment,
+            overrides: override_fields,
+            mime_type: mime_type.map(str::to_string),
+        })
+    }
+
+    /// The configuration as pretty-printed JSON, loadable as a `.json` config file.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(&self.config)
+            .map_err(|e| KreuzbergError::serialization(format!("Failed to serialize configuration: {}", e)))
+    }
+
+    /// The configuration as YAML, loadable as a `.yaml` config file.
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml_ng::to_string(&self.config)
+            .map_err(|e| KreuzbergError::serialization(format!("Failed to serialize configuration: {}", e)))
+    }
+}
+
+/// Merge `patch` over `config`; `layer` names the patch in validation errors.
+fn apply_layer(config: &ExtractionConfig, patch: &Value, layer: &str) -> Result<ExtractionConfig> {
+    let mut document = serde_json::to_value(config)
+        .map_err(|e| KreuzbergError::validation(format!("Failed to serialize configuration: {}", e)))?;
+    merge(&mut document, patch);
+
+    let mut merged: ExtractionConfig = serde_json::from_value(document)
+        .map_err(|e| KreuzbergError::validation(format!("Invalid configuration {}: {}", layer, e)))?;
+    restore_skipped_fields(&mut merged, config);
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_layers_apply_in_order() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("kreuzberg.toml");
+        fs::write(
+            &path,
+            "use_cache = false\nforce_ocr = true\n\n[ocr]\nlanguage = \"eng\"\n",
+        )
+        .unwrap();
+        let file = path.to_string_lossy().to_string();
+
+        let env = |name: &str| match name {
+            CONFIG_PATH_ENV => Some(file.clone()),
+            OCR_LANGUAGE_ENV => Some("deu".to_string()),
+            _ => None,
+        };
+        let effective = EffectiveConfig::resolve_with(
+            Some(&json!({"force_ocr": false, "ocr": {"backend": "easyocr"}})),
+            None,
+            env,
+        )
+        .unwrap();
+
+        assert_eq!(effective.config_file.as_deref(), Some(path.as_path()));
+        assert!(!effective.config.use_cache);
+        assert!(!effective.config.force_ocr);
+        let ocr = effective.config.ocr.as_ref().unwrap();
+        assert_eq!(ocr.language, "deu");
+        assert_eq!(ocr.backend, "easyocr");
+        assert_eq!(
+            effective.environment.get(OCR_LANGUAGE_ENV).map(String::as_str),
+            Some("deu")
+        );
+        assert_eq!(effective.overrides, vec!["force_ocr", "ocr"]);
+    }
+
+    #[test]
+    fn test_resolves_per_mime_overrides() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(
+            &path,
+            json!({"force_ocr": true, "overrides": {"application/pdf": {"force_ocr": false}}}).to_string(),
+        )
+        .unwrap();
+        let file = path.to_string_lossy().to_string();
+        let env = |name: &str| (name == CONFIG_PATH_ENV).then(|| file.clone());
+
+        let pdf = EffectiveConfig::resolve_with(None, Some("application/pdf"), env).unwrap();
+        let image = EffectiveConfig::resolve_with(None, Some("image/png"), env).unwrap();
+
+        assert!(!pdf.config.force_ocr);
+        assert!(image.config.force_ocr);
+        assert!(pdf.environment.is_empty());
+    }
+
+    #[test]
+    fn test_export_round_trips() {
+        let effective = EffectiveConfig::resolve_with(Some(&json!({"force_ocr": true})), None, |_| None).unwrap();
+
+        let from_json: ExtractionConfig = serde_json::from_str(&effective.to_json().unwrap()).unwrap();
+        let from_yaml: ExtractionConfig = serde_yaml_ng::from_str(&effective.to_yaml().unwrap()).unwrap();
+        assert!(from_json.force_ocr);
+        assert!(from_yaml.force_ocr);
+    }
+
+    #[test]
+    fn test_rejects_invalid_overrides() {
+        let error = EffectiveConfig::resolve_with(Some(&json!({"force_ocr": "yes"})), None, |_| None).unwrap_err();
+        assert!(error.to_string().contains("Invalid configuration overrides"));
+
+        assert!(EffectiveConfig::resolve_with(Some(&json!([1, 2])), None, |_| None).is_err());
+    }
+}
@@ -0,0 +1,488 @@
+//! Dry-run cost estimates for routing documents to workers.
+//!
+//! [`estimate`] inspects a document without extracting it: the page count, how much of each
+//! page is covered by images and whether the pages carry a text layer. From that
+//! [`DocumentProfile`] and the configuration it predicts which pages extraction will OCR and
+//! how long and how much memory each [`ExtractionStage`] takes, so a scheduler can send
+//! scanned 400-page PDFs to a big worker and small text files to a small one.
+//!
+//! Inspection reads PDFs with PDFium but renders nothing, and reads only the header of images.
+//! Other formats are profiled by size. OCR is predicted the way the PDF extractor decides it:
+//! every page when `force_ocr` is set, otherwise every page when the text layer is too thin to
+//! use. The [resource profile](crate::core::resource_profile) is applied first, so a minimal
+//! profile lowers the OCR resolution in the estimate too.
+//!
+//! Stage costs come from a linear [`CostModel`]. The default coefficients are rough figures for
+//! a single core running Tesseract; calibrate them against your own workers (for example from
+//! `benchmark-harness` results) and pass them to [`estimate_with_model`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::core::config::ExtractionConfig;
+//! use kreuzberg::core::estimate::estimate;
+//!
+//! let estimate = estimate("scan.pdf", &ExtractionConfig::default())?;
+//! if estimate.peak_memory_bytes > 2 * 1024 * 1024 * 1024 || estimate.duration_ms > 60_000 {
+//!     println!("route to the large queue ({} OCR pages)", estimate.ocr_pages);
+//! }
+//! # Ok::<(), kreuzberg::KreuzbergError>(())
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::{PDF_MIME_TYPE, detect_or_validate};
+use crate::core::resource_profile::apply_resource_profile;
+use crate::error::ExtractionStage;
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Resolution PDF pages are rendered at for OCR unless the resource profile caps it.
+const OCR_RENDER_DPI: f64 = 300.0;
+
+/// Size of a US Letter page in points, used when a page size is unknown.
+const DEFAULT_PAGE_POINTS: (f64, f64) = (612.0, 792.0);
+
+const MIB: f64 = 1024.0 * 1024.0;
+
+/// What a cheap inspection found out about a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentProfile {
+    /// Detected or given MIME type
+    pub mime_type: String,
+
+    /// Size of the document in bytes
+    pub size_bytes: u64,
+
+    /// Number of pages (`None` for formats without pages)
+    pub page_count: Option<usize>,
+
+    /// Pages with at least some native text
+    pub text_pages: Option<usize>,
+
+    /// Share of the page area covered by images, from 0.0 to 1.0
+    pub image_coverage: f64,
+
+    /// Whether the document has text that extraction can read without OCR
+    pub has_text_layer: bool,
+
+    /// Characters of native text, or the expected text length for formats that are not inspected
+    pub text_chars: u64,
+
+    /// Pixel dimensions of the largest page at OCR resolution (images: their own size)
+    #[serde(skip)]
+    largest_page_pixels: Option<(u64, u64)>,
+}
+
+/// Predicted cost of one extraction stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageEstimate {
+    /// The stage
+    pub stage: ExtractionStage,
+
+    /// Expected wall-clock time in milliseconds
+    pub duration_ms: u64,
+
+    /// Memory the stage needs on top of the document itself, in bytes
+    pub memory_bytes: u64,
+}
+
+/// Predicted cost of extracting a document with a configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostEstimate {
+    /// What the inspection found
+    pub profile: DocumentProfile,
+
+    /// Pages extraction is expected to OCR
+    pub ocr_pages: usize,
+
+    /// Cost of each stage that runs, in pipeline order
+    pub stages: Vec<StageEstimate>,
+
+    /// Expected total wall-clock time in milliseconds
+    pub duration_ms: u64,
+
+    /// Expected peak resident memory in bytes
+    pub peak_memory_bytes: u64,
+}
+
+/// Linear per-stage cost model behind [`estimate`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostModel {
+    /// Fixed cost of any extraction (dispatch, MIME handling)
+    pub base_ms: f64,
+
+    /// Parsing cost per MiB of input
+    pub parse_ms_per_mib: f64,
+
+    /// Native text extraction cost per PDF page
+    pub text_ms_per_page: f64,
+
+    /// OCR cost per megapixel rendered at the OCR resolution
+    pub ocr_ms_per_megapixel: f64,
+
+    /// Post-processing cost (chunking, language detection) per 1,000 characters of text
+    pub postprocess_ms_per_kchar: f64,
+
+    /// Embedding cost per chunk
+    pub embedding_ms_per_chunk: f64,
+
+    /// Resident memory of the process before extraction
+    pub base_memory_bytes: u64,
+
+    /// Parsing memory per byte of input (document bytes plus parsed structures)
+    pub parse_memory_per_byte: f64,
+
+    /// OCR memory per pixel of the page being recognized (RGB copy, encoded image, engine buffers)
+    pub ocr_memory_per_pixel: f64,
+
+    /// Memory of a loaded embedding model
+    pub embedding_model_bytes: u64,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            base_ms: 5.0,
+            parse_ms_per_mib: 40.0,
+            text_ms_per_page: 6.0,
+            ocr_ms_per_megapixel: 120.0,
+            postprocess_ms_per_kchar: 0.5,
+            embedding_ms_per_chunk: 20.0,
+            base_memory_bytes: 64 * 1024 * 1024,
+            parse_memory_per_byte: 4.0,
+            ocr_memory_per_pixel: 12.0,
+            embedding_model_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+/// Estimate the cost of extracting the file at `path` with the default [`CostModel`].
+///
+/// # Errors
+///
+/// Returns an error when the file does not exist or cannot be read, its MIME type is not
+/// supported, or a PDF cannot be opened (including encrypted PDFs).
+pub fn estimate(path: impl AsRef<Path>, config: &ExtractionConfig) -> Result<CostEstimate> {
+    estimate_with_model(path, config, &CostModel::default())
+}
+
+/// [`estimate`] with a calibrated cost model.
+pub fn estimate_with_model(
+    path: impl AsRef<Path>,
+    config: &ExtractionConfig,
+    model: &CostModel,
+) -> Result<CostEstimate> {
+    let path = path.as_ref();
+    let mime_type = detect_or_validate(Some(path), None)?;
+    let content = std::fs::read(path).map_err(KreuzbergError::Io)?;
+    estimate_bytes_with_model(&content, &mime_type, config, model)
+}
+
+/// Estimate the cost of extracting `content` of type `mime_type` with the default [`CostModel`].
+pub fn estimate_bytes(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<CostEstimate> {
+    estimate_bytes_with_model(content, mime_type, config, &CostModel::default())
+}
+
+/// [`estimate_bytes`] with a calibrated cost model.
+pub fn estimate_bytes_with_model(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+    model: &CostModel,
+) -> Result<CostEstimate> {
+    let config = apply_resource_profile(config);
+    let dpi = config
+        .resource_profile
+        .ocr_dpi_cap()
+        .map_or(OCR_RENDER_DPI, |cap| OCR_RENDER_DPI.min(cap as f64));
+    let profile = inspect(content, mime_type, dpi)?;
+    Ok(estimate_profile(profile, &config, model))
+}
+
+/// Predict the stages extraction runs for `profile` and what they cost.
+fn estimate_profile(profile: DocumentProfile, config: &ExtractionConfig, model: &CostModel) -> CostEstimate {
+    let mut stages = Vec::new();
+    let size_mib = profile.size_bytes as f64 / MIB;
+    let parse_memory = (profile.size_bytes as f64 * model.parse_memory_per_byte) as u64;
+
+    let mut extraction_ms = model.base_ms + size_mib * model.parse_ms_per_mib;
+    if profile.mime_type == PDF_MIME_TYPE {
+        extraction_ms += profile.page_count.unwrap_or(0) as f64 * model.text_ms_per_page;
+    }
+    stages.push(StageEstimate {
+        stage: ExtractionStage::Extraction,
+        duration_ms: extraction_ms.round() as u64,
+        memory_bytes: parse_memory,
+    });
+
+    let ocr_pages = predicted_ocr_pages(&profile, config);
+    if ocr_pages > 0 {
+        let (width, height) = profile.largest_page_pixels.unwrap_or((0, 0));
+        let pixels = (width * height) as f64;
+        stages.push(StageEstimate {
+            stage: ExtractionStage::Ocr,
+            duration_ms: (ocr_pages as f64 * pixels / 1_000_000.0 * model.ocr_ms_per_megapixel).round() as u64,
+            // Every page is rendered to an RGBA bitmap up front, then recognized one at a time
+            memory_bytes: (pixels * (ocr_pages as f64 * 4.0 + model.ocr_memory_per_pixel)) as u64,
+        });
+    }
+
+    // OCR replaces the text layer; assume a typical 2,000 characters per recognized page
+    let text_chars = if ocr_pages > 0 {
+        profile.text_chars.max(ocr_pages as u64 * 2_000)
+    } else {
+        profile.text_chars
+    };
+    let mut postprocess_ms = text_chars as f64 / 1_000.0 * model.postprocess_ms_per_kchar;
+    let mut postprocess_memory = text_chars * 8;
+    if let Some(chunking) = &config.chunking
+        && chunking.embedding.is_some()
+    {
+        let chunks = text_chars.div_ceil(chunking.max_chars.max(1) as u64);
+        postprocess_ms += chunks as f64 * model.embedding_ms_per_chunk;
+        postprocess_memory += model.embedding_model_bytes;
+    }
+    stages.push(StageEstimate {
+        stage: ExtractionStage::PostProcessing,
+        duration_ms: postprocess_ms.round() as u64,
+        memory_bytes: postprocess_memory,
+    });
+
+    let duration_ms = stages.iter().map(|stage| stage.duration_ms).sum();
+    // The document stays loaded while the stages run one after another
+    let peak_memory_bytes =
+        model.base_memory_bytes + profile.size_bytes + stages.iter().map(|stage| stage.memory_bytes).max().unwrap_or(0);
+
+    CostEstimate {
+        profile,
+        ocr_pages,
+        stages,
+        duration_ms,
+        peak_memory_bytes,
+    }
+}
+
+/// Pages the extractor will OCR: all of them when forced or when the text layer is unusable.
+fn predicted_ocr_pages(profile: &DocumentProfile, config: &ExtractionConfig) -> usize {
+    if config.ocr.is_none() || !cfg!(feature = "ocr") {
+        return 0;
+    }
+
+    let is_image = profile.mime_type.starts_with("image/");
+    let is_pdf = profile.mime_type == PDF_MIME_TYPE;
+    if !(is_image || is_pdf) || (is_pdf && !config.force_ocr && profile.has_text_layer) {
+        return 0;
+    }
+    profile.page_count.unwrap_or(1)
+}
+
+/// Profile `content` without extracting it.
+fn inspect(content: &[u8], mime_type: &str, dpi: f64) -> Result<DocumentProfile> {
+    let mut profile = DocumentProfile {
+        mime_type: mime_type.to_string(),
+        size_bytes: content.len() as u64,
+        page_count: None,
+        text_pages: None,
+        image_coverage: 0.0,
+        has_text_layer: true,
+        // Compressed and binary formats hold roughly a quarter of their size in text
+        text_chars: if mime_type.starts_with("text/") {
+            content.len() as u64
+        } else {
+            content.len() as u64 / 4
+        },
+        largest_page_pixels: None,
+    };
+
+    if mime_type == PDF_MIME_TYPE {
+        #[cfg(feature = "pdf")]
+        inspect_pdf(content, dpi, &mut profile)?;
+    } else if mime_type.starts_with("image/") {
+        profile.page_count = Some(1);
+        profile.text_pages = Some(0);
+        profile.image_coverage = 1.0;
+        profile.has_text_layer = false;
+        profile.text_chars = 0;
+        #[cfg(feature = "ocr")]
+        {
+            profile.largest_page_pixels = image::ImageReader::new(std::io::Cursor::new(content))
+                .with_guessed_format()
+                .ok()
+                .and_then(|reader| reader.into_dimensions().ok())
+                .map(|(width, height)| (width as u64, height as u64));
+        }
+    }
+
+    if profile.largest_page_pixels.is_none() && profile.page_count.is_some() {
+        profile.largest_page_pixels = Some(page_pixels(DEFAULT_PAGE_POINTS, dpi));
+    }
+    Ok(profile)
+}
+
+/// Pixel size of a page of `points` rendered at `dpi`.
+fn page_pixels((width, height): (f64, f64), dpi: f64) -> (u64, u64) {
+    let scale = dpi / 72.0;
+    ((width * scale).ceil() as u64, (height * scale).ceil() as u64)
+}
+
+/// Count pages, text and image area of a PDF with PDFium, without rendering it.
+#[cfg(feature = "pdf")]
+fn inspect_pdf(content: &[u8], dpi: f64, profile: &mut DocumentProfile) -> Result<()> {
+    use crate::pdf::error::PdfError;
+    use pdfium_render::prelude::*;
+
+    let bindings = crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
+    let pdfium = Pdfium::new(bindings);
+    let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
+        let err_msg = e.to_string();
+        if err_msg.contains("password") || err_msg.contains("Password") {
+            PdfError::PasswordRequired
+        } else {
+            PdfError::InvalidPdf(err_msg)
+        }
+    })?;
+
+    let mut text = String::new();
+    let mut text_pages = 0;
+    let mut page_area = 0.0;
+    let mut image_area = 0.0;
+    let mut largest_page = (0.0, 0.0);
+    for page in document.pages().iter() {
+        let (width, height) = (page.width().value as f64, page.height().value as f64);
+        if width * height > largest_page.0 * largest_page.1 {
+            largest_page = (width, height);
+        }
+        page_area += width * height;
+
+        if let Ok(page_text) = page.text() {
+            let page_text = page_text.all();
+            if page_text.chars().any(|c| !c.is_whitespace()) {
+                text_pages += 1;
+            }
+            text.push_str(&page_text);
+            text.push('\n');
+        }
+
+        for object in page.objects().iter() {
+            if object.object_type() == PdfPageObjectType::Image
+                && let (Ok(object_width), Ok(object_height)) = (object.width(), object.height())
+            {
+                image_area += (object_width.value as f64 * object_height.value as f64).abs();
+            }
+        }
+    }
+
+    profile.page_count = Some(document.pages().len() as usize);
+    profile.text_pages = Some(text_pages);
+    profile.image_coverage = if page_area > 0.0 {
+        (image_area / page_area).min(1.0)
+    } else {
+        0.0
+    };
+    profile.text_chars = text.chars().filter(|c| !c.is_whitespace()).count() as u64;
+    // The same test the PDF extractor uses to fall back to OCR
+    #[cfg(feature = "ocr")]
+    {
+        profile.has_text_layer = !crate::extractors::pdf::evaluate_native_text_for_ocr(&text, None).fallback;
+    }
+    #[cfg(not(feature = "ocr"))]
+    {
+        profile.has_text_layer = text_pages > 0;
+    }
+    if largest_page.0 > 0.0 {
+        profile.largest_page_pixels = Some(page_pixels(largest_page, dpi));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::OcrConfig;
+
+    fn ocr_config() -> ExtractionConfig {
+        ExtractionConfig {
+            ocr: Some(serde_json::from_value::<OcrConfig>(serde_json::json!({})).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    fn scanned_pdf(pages: usize) -> DocumentProfile {
+        DocumentProfile {
+            mime_type: PDF_MIME_TYPE.to_string(),
+            size_bytes: 4 * 1024 * 1024,
+            page_count: Some(pages),
+            text_pages: Some(0),
+            image_coverage: 1.0,
+            has_text_layer: false,
+            text_chars: 0,
+            largest_page_pixels: Some(page_pixels(DEFAULT_PAGE_POINTS, OCR_RENDER_DPI)),
+        }
+    }
+
+    #[test]
+    fn test_text_file_has_no_ocr_stage() {
+        let estimate = estimate_bytes(&[b'a'; 10_000], "text/plain", &ocr_config()).unwrap();
+
+        assert_eq!(estimate.ocr_pages, 0);
+        assert_eq!(estimate.profile.text_chars, 10_000);
+        assert!(estimate.profile.page_count.is_none());
+        let stages: Vec<_> = estimate.stages.iter().map(|stage| stage.stage).collect();
+        assert_eq!(
+            stages,
+            vec![ExtractionStage::Extraction, ExtractionStage::PostProcessing]
+        );
+        assert_eq!(
+            estimate.duration_ms,
+            estimate.stages.iter().map(|s| s.duration_ms).sum::<u64>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ocr")]
+    fn test_scanned_pages_cost_more_than_text_pages() {
+        let model = CostModel::default();
+        let config = ocr_config();
+
+        let scanned = estimate_profile(scanned_pdf(10), &config, &model);
+        let mut text_profile = scanned_pdf(10);
+        text_profile.has_text_layer = true;
+        text_profile.text_chars = 20_000;
+        let text = estimate_profile(text_profile, &config, &model);
+
+        assert_eq!(scanned.ocr_pages, 10);
+        assert_eq!(text.ocr_pages, 0);
+        assert!(scanned.duration_ms > text.duration_ms * 10);
+        assert!(scanned.peak_memory_bytes > text.peak_memory_bytes);
+
+        let doubled = estimate_profile(scanned_pdf(20), &config, &model);
+        assert!(doubled.duration_ms > scanned.duration_ms);
+    }
+
+    #[test]
+    fn test_ocr_requires_ocr_config() {
+        let estimate = estimate_profile(scanned_pdf(3), &ExtractionConfig::default(), &CostModel::default());
+
+        assert_eq!(estimate.ocr_pages, 0);
+        assert!(estimate.stages.iter().all(|stage| stage.stage != ExtractionStage::Ocr));
+    }
+
+    #[test]
+    #[cfg(feature = "ocr")]
+    fn test_minimal_profile_lowers_ocr_resolution() {
+        use crate::core::config::ResourceProfile;
+
+        // Undecodable image bytes fall back to a Letter page at OCR resolution
+        let mut config = ocr_config();
+        let full = estimate_bytes(b"not an image", "image/png", &config).unwrap();
+        config.resource_profile = ResourceProfile::Minimal;
+        let minimal = estimate_bytes(b"not an image", "image/png", &config).unwrap();
+
+        assert_eq!(full.ocr_pages, 1);
+        assert_eq!(minimal.ocr_pages, 1);
+        assert!(minimal.duration_ms < full.duration_ms);
+        assert!(minimal.peak_memory_bytes < full.peak_memory_bytes);
+    }
+}
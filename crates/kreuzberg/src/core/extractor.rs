@@ -11,8 +11,13 @@
 //! - [`batch_extract_file`] - Extract content from multiple files concurrently
 //! - [`batch_extract_bytes`] - Extract content from multiple byte arrays concurrently
 
-use crate::core::config::ExtractionConfig;
+use crate::cache::backend::ResultCache;
+use crate::core::config::{ExtractionConfig, ResourceProfile};
 use crate::core::mime::{LEGACY_POWERPOINT_MIME_TYPE, LEGACY_WORD_MIME_TYPE};
+use crate::core::overrides::resolve_for_mime;
+use crate::core::resource_profile::apply_resource_profile;
+#[cfg(feature = "tokio-runtime")]
+use crate::core::watchdog;
 #[cfg(feature = "office")]
 use crate::extraction::libreoffice::{convert_doc_to_docx, convert_ppt_to_pptx};
 use crate::plugins::DocumentExtractor;
@@ -20,11 +25,10 @@ use crate::types::ExtractionResult;
 #[cfg(feature = "office")]
 use crate::types::LibreOfficeConversionResult;
 use crate::utils::{PoolSizeHint, estimate_pool_size, intern_mime_type};
-use crate::{KreuzbergError, Result};
-#[cfg(feature = "tokio-runtime")]
-use once_cell::sync::Lazy;
+use crate::{ExtractionStage, KreuzbergError, Result};
 #[cfg(feature = "office")]
 use serde_json::json;
+use std::borrow::Cow;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -87,31 +91,6 @@ fn sanitize_path(path: &Path) -> String {
         .to_string()
 }
 
-/// Global Tokio runtime for synchronous operations.
-///
-/// This runtime is lazily initialized on first use and shared across all sync wrappers.
-/// Using a global runtime instead of creating one per call provides 100x+ performance improvement.
-///
-/// # Safety
-///
-/// The `.expect()` here is justified because:
-/// 1. Runtime creation can only fail due to system resource exhaustion (OOM, thread limit)
-/// 2. If runtime creation fails, the process is already in a critical state
-/// 3. This is a one-time initialization - if it fails, nothing will work
-/// 4. Better to fail fast than return errors from every sync operation
-///
-/// # Availability
-///
-/// This static is only available when the `tokio-runtime` feature is enabled.
-/// For WASM targets, use the truly synchronous extraction functions instead.
-#[cfg(feature = "tokio-runtime")]
-static GLOBAL_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
-    tokio::runtime::Builder::new_multi_thread()
-        .enable_all()
-        .build()
-        .expect("Failed to create global Tokio runtime - system may be out of resources")
-});
-
 /// Get an extractor from the registry.
 ///
 /// This function acquires the registry read lock and retrieves the appropriate
@@ -119,14 +98,12 @@ static GLOBAL_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
 ///
 /// # Performance
 ///
-/// RwLock read + HashMap lookup is ~100ns, fast enough without caching.
+/// Snapshot load + HashMap lookup is ~100ns, fast enough without caching.
 /// Removed thread-local cache to avoid Tokio work-stealing scheduler issues.
 fn get_extractor(mime_type: &str) -> Result<Arc<dyn DocumentExtractor>> {
-    let registry = crate::plugins::registry::get_document_extractor_registry();
-    let registry_read = registry
-        .read()
-        .map_err(|e| KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
-    registry_read.get(mime_type)
+    crate::plugins::registry::get_document_extractor_registry()
+        .snapshot()
+        .get(mime_type)
 }
 
 /// Get optimal pool sizing hint for a document.
@@ -221,42 +198,27 @@ pub async fn extract_file(
         io::validate_file_exists(path)?;
 
         let detected_mime = mime::detect_or_validate(Some(path), mime_type)?;
+        let dispatched = dispatch_config(config, &detected_mime)?;
+        let config: &ExtractionConfig = &dispatched;
 
-        match detected_mime.as_str() {
-            #[cfg(feature = "office")]
-            LEGACY_WORD_MIME_TYPE => {
-                let original_bytes = tokio::fs::read(path).await?;
-                let conversion = convert_doc_to_docx(&original_bytes).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
-                apply_libreoffice_metadata(&mut result, LEGACY_WORD_MIME_TYPE, &conversion);
-                return Ok(result);
-            }
-            #[cfg(not(feature = "office"))]
-            LEGACY_WORD_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy Word conversion requires the `office` feature or LibreOffice support".to_string(),
-                ));
-            }
-            #[cfg(feature = "office")]
-            LEGACY_POWERPOINT_MIME_TYPE => {
-                let original_bytes = tokio::fs::read(path).await?;
-                let conversion = convert_ppt_to_pptx(&original_bytes).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
-                apply_libreoffice_metadata(&mut result, LEGACY_POWERPOINT_MIME_TYPE, &conversion);
-                return Ok(result);
-            }
-            #[cfg(not(feature = "office"))]
-            LEGACY_POWERPOINT_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy PowerPoint conversion requires the `office` feature or LibreOffice support".to_string(),
-                ));
-            }
-            _ => {}
+        if let Some(policy) = &config.input_policy {
+            crate::core::input_policy::check_file(path, &detected_mime, policy)?;
+        }
+
+        let result_cache = if ResultCache::is_enabled(config) {
+            ResultCache::for_extraction(config, &io::read_file_sync(path)?, &detected_mime)?
+        } else {
+            None
+        };
+        if let Some(cached) = result_cache.as_ref().and_then(ResultCache::get) {
+            return Ok(cached);
         }
 
-        extract_file_with_extractor(path, &detected_mime, config).await
+        let result = extract_file_by_mime(path, &detected_mime, config).await?;
+        if let Some(cache) = &result_cache {
+            cache.store(&result);
+        }
+        Ok(result)
     }
     .await;
 
@@ -281,40 +243,23 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
 
     let result = async {
         let validated_mime = mime::validate_mime_type(mime_type)?;
+        let dispatched = dispatch_config(config, &validated_mime)?;
+        let config: &ExtractionConfig = &dispatched;
 
-        match validated_mime.as_str() {
-            #[cfg(feature = "office")]
-            LEGACY_WORD_MIME_TYPE => {
-                let conversion = convert_doc_to_docx(content).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
-                apply_libreoffice_metadata(&mut result, LEGACY_WORD_MIME_TYPE, &conversion);
-                return Ok(result);
-            }
-            #[cfg(not(feature = "office"))]
-            LEGACY_WORD_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy Word conversion requires the `office` feature or LibreOffice support".to_string(),
-                ));
-            }
-            #[cfg(feature = "office")]
-            LEGACY_POWERPOINT_MIME_TYPE => {
-                let conversion = convert_ppt_to_pptx(content).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
-                apply_libreoffice_metadata(&mut result, LEGACY_POWERPOINT_MIME_TYPE, &conversion);
-                return Ok(result);
-            }
-            #[cfg(not(feature = "office"))]
-            LEGACY_POWERPOINT_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy PowerPoint conversion requires the `office` feature or LibreOffice support".to_string(),
-                ));
-            }
-            _ => {}
+        if let Some(policy) = &config.input_policy {
+            crate::core::input_policy::check_bytes(content, &validated_mime, policy)?;
         }
 
-        extract_bytes_with_extractor(content, &validated_mime, config).await
+        let result_cache = ResultCache::for_extraction(config, content, &validated_mime)?;
+        if let Some(cached) = result_cache.as_ref().and_then(ResultCache::get) {
+            return Ok(cached);
+        }
+
+        let result = extract_bytes_by_mime(content, &validated_mime, config).await?;
+        if let Some(cache) = &result_cache {
+            cache.store(&result);
+        }
+        Ok(result)
     }
     .await;
 
@@ -331,7 +276,8 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
 /// This function processes multiple files in parallel, automatically managing
 /// concurrency to prevent resource exhaustion. The concurrency limit can be
 /// configured via `ExtractionConfig::max_concurrent_extractions` or defaults
-/// to `num_cpus * 2`.
+/// to `num_cpus * 1.5`. Set `ExtractionConfig::adaptive_concurrency` to adjust the
+/// limit dynamically based on memory pressure and per-document latency.
 ///
 /// # Arguments
 ///
@@ -346,6 +292,7 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
 ///
 /// Individual file errors are captured in the result metadata. System errors
 /// (IO, RuntimeError equivalents) will bubble up and fail the entire batch.
+/// Set `ExtractionConfig::retry` to retry transient failures before giving up on a file.
 #[cfg(feature = "tokio-runtime")]
 #[cfg_attr(feature = "otel", tracing::instrument(
     skip(config, paths),
@@ -357,8 +304,8 @@ pub async fn batch_extract_file(
     paths: Vec<impl AsRef<Path>>,
     config: &ExtractionConfig,
 ) -> Result<Vec<ExtractionResult>> {
+    use crate::core::concurrency::ConcurrencyController;
     use std::sync::Arc;
-    use tokio::sync::Semaphore;
     use tokio::task::JoinSet;
 
     if paths.is_empty() {
@@ -367,26 +314,24 @@ pub async fn batch_extract_file(
 
     let config = Arc::new(config.clone());
 
-    // Conservative concurrency multiplier (1.5x instead of 2.0x) to reduce contention
-    // on external libraries (pdfium, tesseract) which have their own internal threading.
-    // Users can override via config.max_concurrent_extractions if needed.
-    let max_concurrent = config
-        .max_concurrent_extractions
-        .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
-    let semaphore = Arc::new(Semaphore::new(max_concurrent));
+    let controller = Arc::new(ConcurrencyController::for_config(&config));
 
     let mut tasks = JoinSet::new();
 
     for (index, path) in paths.into_iter().enumerate() {
         let path_buf = path.as_ref().to_path_buf();
         let config_clone = Arc::clone(&config);
-        let semaphore_clone = Arc::clone(&semaphore);
+        let controller_clone = Arc::clone(&controller);
 
         tasks.spawn(async move {
-            let _permit = semaphore_clone.acquire().await.unwrap();
-            let result =
-                crate::core::batch_mode::with_batch_mode(async { extract_file(&path_buf, None, &config_clone).await })
-                    .await;
+            let size = tokio::fs::metadata(&path_buf).await.map(|m| m.len()).unwrap_or(0);
+            let result = crate::core::batch_mode::with_batch_mode(async {
+                extract_with_retry(&config_clone, &controller_clone, size, || {
+                    extract_file(&path_buf, None, &config_clone)
+                })
+                .await
+            })
+            .await;
             (index, result)
         });
     }
@@ -399,30 +344,7 @@ pub async fn batch_extract_file(
                 results[index] = Some(result);
             }
             Ok((index, Err(e))) => {
-                // OSError/RuntimeError must bubble up - system errors need user reports ~keep
-                if matches!(e, KreuzbergError::Io(_)) {
-                    return Err(e);
-                }
-
-                use crate::types::{ErrorMetadata, Metadata};
-                let metadata = Metadata {
-                    error: Some(ErrorMetadata {
-                        error_type: format!("{:?}", e),
-                        message: e.to_string(),
-                    }),
-                    ..Default::default()
-                };
-
-                results[index] = Some(ExtractionResult {
-                    content: format!("Error: {}", e),
-                    mime_type: "text/plain".to_string(),
-                    metadata,
-                    tables: vec![],
-                    detected_languages: None,
-                    chunks: None,
-                    images: None,
-                    pages: None,
-                });
+                results[index] = Some(batch_error_result(e)?);
             }
             Err(join_err) => {
                 return Err(KreuzbergError::Other(format!("Task panicked: {}", join_err)));
@@ -434,12 +356,168 @@ pub async fn batch_extract_file(
     Ok(results.into_iter().map(|r| r.unwrap()).collect())
 }
 
+/// Extract content from multiple files concurrently, delivering results as they complete.
+///
+/// Unlike [`batch_extract_file`], which returns once every file is done, this returns a
+/// channel that yields `(index, result)` pairs in completion order, where `index` is the
+/// position of the file in `paths`. Concurrency is managed as in [`batch_extract_file`],
+/// and no new extraction starts while `buffer` results wait to be received, so callers can
+/// stream-process large batches without holding every result in memory. Dropping the
+/// receiver cancels the extractions that have not started yet.
+///
+/// Must be called from within a Tokio runtime.
+///
+/// # Errors
+///
+/// As in [`batch_extract_file`], individual file errors are delivered as results with
+/// `metadata.error` set, while system errors (IO, panics) are delivered as `Err` for the
+/// affected file; the remaining files are still extracted.
+#[cfg(feature = "tokio-runtime")]
+pub fn batch_extract_file_unordered(
+    paths: Vec<impl AsRef<Path>>,
+    config: &ExtractionConfig,
+    buffer: usize,
+) -> tokio::sync::mpsc::Receiver<(usize, Result<ExtractionResult>)> {
+    use crate::core::concurrency::ConcurrencyController;
+    use tokio::task::JoinSet;
+
+    let paths: Vec<_> = paths.iter().map(|path| path.as_ref().to_path_buf()).collect();
+    let config = Arc::new(config.clone());
+    let controller = Arc::new(ConcurrencyController::for_config(&config));
+    let (tx, rx) = tokio::sync::mpsc::channel(buffer.max(1));
+
+    tokio::spawn(async move {
+        let max_in_flight = controller.max_concurrent();
+        let mut pending = paths.into_iter().enumerate();
+        let mut tasks = JoinSet::new();
+
+        loop {
+            while tasks.len() < max_in_flight {
+                let Some((index, path_buf)) = pending.next() else {
+                    break;
+                };
+                let config_clone = Arc::clone(&config);
+                let controller_clone = Arc::clone(&controller);
+
+                // The extraction runs in its own task so a panic is reported for its file
+                let extraction = tokio::spawn(async move {
+                    let size = tokio::fs::metadata(&path_buf).await.map(|m| m.len()).unwrap_or(0);
+                    crate::core::batch_mode::with_batch_mode(async {
+                        extract_with_retry(&config_clone, &controller_clone, size, || {
+                            extract_file(&path_buf, None, &config_clone)
+                        })
+                        .await
+                    })
+                    .await
+                });
+                tasks.spawn(async move {
+                    let result = match extraction.await {
+                        Ok(result) => result.or_else(batch_error_result),
+                        Err(join_err) => Err(KreuzbergError::Other(format!("Task panicked: {}", join_err))),
+                    };
+                    (index, result)
+                });
+            }
+
+            let Some(task_result) = tasks.join_next().await else {
+                break;
+            };
+            let Ok(message) = task_result else {
+                continue;
+            };
+
+            // The receiver went away; dropping the JoinSet stops the remaining extractions
+            if tx.send(message).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    rx
+}
+
+/// Extract one document of a batch, retrying transient failures under `config.retry`.
+///
+/// Every attempt holds its own slot of `controller`, so backing off between attempts does not
+/// keep other documents waiting. With a retry policy the number of attempts is recorded in the
+/// result metadata, and a document that still fails, IO errors included, is turned into an
+/// error result as by [`error_result`].
+#[cfg(feature = "tokio-runtime")]
+async fn extract_with_retry<F, Fut>(
+    config: &ExtractionConfig,
+    controller: &Arc<crate::core::concurrency::ConcurrencyController>,
+    size: u64,
+    mut operation: F,
+) -> Result<ExtractionResult>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<ExtractionResult>>,
+{
+    use crate::core::retry::{record_attempts, with_retry};
+
+    let (outcome, attempts) = with_retry(config.retry.as_ref(), || {
+        let attempt = operation();
+        async move {
+            let _permit = controller.acquire(size).await;
+            attempt.await
+        }
+    })
+    .await;
+    if config.retry.is_none() {
+        return outcome;
+    }
+    let mut result = outcome.unwrap_or_else(error_result);
+    record_attempts(&mut result, attempts);
+    Ok(result)
+}
+
+/// Turn a per-document batch error into a result carrying the error in its metadata.
+///
+/// System errors (IO) are returned as `Err`.
+#[cfg(feature = "tokio-runtime")]
+fn batch_error_result(e: KreuzbergError) -> Result<ExtractionResult> {
+    // OSError/RuntimeError must bubble up - system errors need user reports ~keep
+    if matches!(e, KreuzbergError::Io(_)) {
+        return Err(e);
+    }
+    Ok(error_result(e))
+}
+
+/// Result carrying `e` in its `error` metadata.
+#[cfg(feature = "tokio-runtime")]
+fn error_result(e: KreuzbergError) -> ExtractionResult {
+    use crate::types::{ErrorMetadata, Metadata};
+    let metadata = Metadata {
+        error: Some(ErrorMetadata {
+            error_type: format!("{:?}", e),
+            message: e.to_string(),
+        }),
+        ..Default::default()
+    };
+
+    ExtractionResult {
+        content: format!("Error: {}", e),
+        mime_type: "text/plain".to_string(),
+        metadata,
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
+    }
+}
+
 /// Extract content from multiple byte arrays concurrently.
 ///
 /// This function processes multiple byte arrays in parallel, automatically managing
 /// concurrency to prevent resource exhaustion. The concurrency limit can be
 /// configured via `ExtractionConfig::max_concurrent_extractions` or defaults
-/// to `num_cpus * 2`.
+/// to `num_cpus * 1.5`. Set `ExtractionConfig::adaptive_concurrency` to adjust the
+/// limit dynamically based on memory pressure and per-document latency.
 ///
 /// # Arguments
 ///
@@ -460,8 +538,8 @@ pub async fn batch_extract_bytes(
     contents: Vec<(&[u8], &str)>,
     config: &ExtractionConfig,
 ) -> Result<Vec<ExtractionResult>> {
+    use crate::core::concurrency::ConcurrencyController;
     use std::sync::Arc;
-    use tokio::sync::Semaphore;
     use tokio::task::JoinSet;
 
     if contents.is_empty() {
@@ -471,13 +549,7 @@ pub async fn batch_extract_bytes(
     let batch_config = config.clone();
     let config = Arc::new(batch_config);
 
-    // Conservative concurrency multiplier (1.5x instead of 2.0x) to reduce contention
-    // on external libraries (pdfium, tesseract) which have their own internal threading.
-    // Users can override via config.max_concurrent_extractions if needed.
-    let max_concurrent = config
-        .max_concurrent_extractions
-        .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
-    let semaphore = Arc::new(Semaphore::new(max_concurrent));
+    let controller = Arc::new(ConcurrencyController::for_config(&config));
 
     let owned_contents: Vec<(Vec<u8>, String)> = contents
         .into_iter()
@@ -488,12 +560,14 @@ pub async fn batch_extract_bytes(
 
     for (index, (bytes, mime_type)) in owned_contents.into_iter().enumerate() {
         let config_clone = Arc::clone(&config);
-        let semaphore_clone = Arc::clone(&semaphore);
+        let controller_clone = Arc::clone(&controller);
 
         tasks.spawn(async move {
-            let _permit = semaphore_clone.acquire().await.unwrap();
             let result = crate::core::batch_mode::with_batch_mode(async {
-                extract_bytes(&bytes, &mime_type, &config_clone).await
+                extract_with_retry(&config_clone, &controller_clone, bytes.len() as u64, || {
+                    extract_bytes(&bytes, &mime_type, &config_clone)
+                })
+                .await
             })
             .await;
             (index, result)
@@ -508,30 +582,7 @@ pub async fn batch_extract_bytes(
                 results[index] = Some(result);
             }
             Ok((index, Err(e))) => {
-                // OSError/RuntimeError must bubble up - system errors need user reports ~keep
-                if matches!(e, KreuzbergError::Io(_)) {
-                    return Err(e);
-                }
-
-                use crate::types::{ErrorMetadata, Metadata};
-                let metadata = Metadata {
-                    error: Some(ErrorMetadata {
-                        error_type: format!("{:?}", e),
-                        message: e.to_string(),
-                    }),
-                    ..Default::default()
-                };
-
-                results[index] = Some(ExtractionResult {
-                    content: format!("Error: {}", e),
-                    mime_type: "text/plain".to_string(),
-                    metadata,
-                    tables: vec![],
-                    detected_languages: None,
-                    chunks: None,
-                    images: None,
-                    pages: None,
-                });
+                results[index] = Some(batch_error_result(e)?);
             }
             Err(join_err) => {
                 return Err(KreuzbergError::Other(format!("Task panicked: {}", join_err)));
@@ -559,7 +610,7 @@ pub fn extract_file_sync(
     mime_type: Option<&str>,
     config: &ExtractionConfig,
 ) -> Result<ExtractionResult> {
-    GLOBAL_RUNTIME.block_on(extract_file(path, mime_type, config))
+    crate::core::runtime::block_on(extract_file(path, mime_type, config))
 }
 
 /// Synchronous wrapper for `extract_bytes`.
@@ -571,7 +622,7 @@ pub fn extract_file_sync(
 /// Tokio runtime. Without it (WASM), this calls a truly synchronous implementation.
 #[cfg(feature = "tokio-runtime")]
 pub fn extract_bytes_sync(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-    GLOBAL_RUNTIME.block_on(extract_bytes(content, mime_type, config))
+    crate::core::runtime::block_on(extract_bytes(content, mime_type, config))
 }
 
 /// Synchronous wrapper for `extract_bytes` (WASM-compatible version).
@@ -595,7 +646,7 @@ pub fn batch_extract_file_sync(
     paths: Vec<impl AsRef<Path>>,
     config: &ExtractionConfig,
 ) -> Result<Vec<ExtractionResult>> {
-    GLOBAL_RUNTIME.block_on(batch_extract_file(paths, config))
+    crate::core::runtime::block_on(batch_extract_file(paths, config))
 }
 
 /// Synchronous wrapper for `batch_extract_bytes`.
@@ -611,7 +662,7 @@ pub fn batch_extract_bytes_sync(
     contents: Vec<(&[u8], &str)>,
     config: &ExtractionConfig,
 ) -> Result<Vec<ExtractionResult>> {
-    GLOBAL_RUNTIME.block_on(batch_extract_bytes(contents, config))
+    crate::core::runtime::block_on(batch_extract_bytes(contents, config))
 }
 
 /// Synchronous wrapper for `batch_extract_bytes` (WASM-compatible version).
@@ -643,6 +694,10 @@ pub fn batch_extract_bytes_sync(
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             }
         }));
     }
@@ -687,6 +742,11 @@ fn extract_bytes_sync_impl(
             source: None,
         });
     };
+    let config = dispatch_config(&config, &validated_mime)?;
+
+    if let Some(policy) = &config.input_policy {
+        crate::core::input_policy::check_bytes(&content, &validated_mime, policy)?;
+    }
 
     // Ensure extractors are initialized
     crate::extractors::ensure_initialized()?;
@@ -711,6 +771,94 @@ fn extract_bytes_sync_impl(
     Ok(result)
 }
 
+/// The configuration a document of type `mime_type` is extracted with: `config` with its
+/// MIME overrides merged in and its resource profile applied.
+fn dispatch_config<'a>(config: &'a ExtractionConfig, mime_type: &str) -> Result<Cow<'a, ExtractionConfig>> {
+    Ok(match resolve_for_mime(config, mime_type)? {
+        Cow::Borrowed(config) => apply_resource_profile(config),
+        Cow::Owned(config) if config.resource_profile.effective() == ResourceProfile::Minimal => {
+            Cow::Owned(apply_resource_profile(&config).into_owned())
+        }
+        Cow::Owned(config) => Cow::Owned(config),
+    })
+}
+
+/// Extract a file, converting legacy Office formats first.
+async fn extract_file_by_mime(path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    match mime_type {
+        #[cfg(feature = "office")]
+        LEGACY_WORD_MIME_TYPE => {
+            let original_bytes = tokio::fs::read(path).await?;
+            let conversion = convert_doc_to_docx(&original_bytes).await?;
+            let mut result =
+                extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+            apply_libreoffice_metadata(&mut result, LEGACY_WORD_MIME_TYPE, &conversion);
+            return Ok(result);
+        }
+        #[cfg(not(feature = "office"))]
+        LEGACY_WORD_MIME_TYPE => {
+            return Err(KreuzbergError::UnsupportedFormat(
+                "Legacy Word conversion requires the `office` feature or LibreOffice support".to_string(),
+            ));
+        }
+        #[cfg(feature = "office")]
+        LEGACY_POWERPOINT_MIME_TYPE => {
+            let original_bytes = tokio::fs::read(path).await?;
+            let conversion = convert_ppt_to_pptx(&original_bytes).await?;
+            let mut result =
+                extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+            apply_libreoffice_metadata(&mut result, LEGACY_POWERPOINT_MIME_TYPE, &conversion);
+            return Ok(result);
+        }
+        #[cfg(not(feature = "office"))]
+        LEGACY_POWERPOINT_MIME_TYPE => {
+            return Err(KreuzbergError::UnsupportedFormat(
+                "Legacy PowerPoint conversion requires the `office` feature or LibreOffice support".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    extract_file_with_extractor(path, mime_type, config).await
+}
+
+/// Extract bytes, converting legacy Office formats first.
+async fn extract_bytes_by_mime(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    match mime_type {
+        #[cfg(feature = "office")]
+        LEGACY_WORD_MIME_TYPE => {
+            let conversion = convert_doc_to_docx(content).await?;
+            let mut result =
+                extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+            apply_libreoffice_metadata(&mut result, LEGACY_WORD_MIME_TYPE, &conversion);
+            return Ok(result);
+        }
+        #[cfg(not(feature = "office"))]
+        LEGACY_WORD_MIME_TYPE => {
+            return Err(KreuzbergError::UnsupportedFormat(
+                "Legacy Word conversion requires the `office` feature or LibreOffice support".to_string(),
+            ));
+        }
+        #[cfg(feature = "office")]
+        LEGACY_POWERPOINT_MIME_TYPE => {
+            let conversion = convert_ppt_to_pptx(content).await?;
+            let mut result =
+                extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+            apply_libreoffice_metadata(&mut result, LEGACY_POWERPOINT_MIME_TYPE, &conversion);
+            return Ok(result);
+        }
+        #[cfg(not(feature = "office"))]
+        LEGACY_POWERPOINT_MIME_TYPE => {
+            return Err(KreuzbergError::UnsupportedFormat(
+                "Legacy PowerPoint conversion requires the `office` feature or LibreOffice support".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    extract_bytes_with_extractor(content, mime_type, config).await
+}
+
 async fn extract_file_with_extractor(
     path: &Path,
     mime_type: &str,
@@ -719,9 +867,28 @@ async fn extract_file_with_extractor(
     crate::extractors::ensure_initialized()?;
 
     let extractor = get_extractor(mime_type)?;
-    let mut result = extractor.extract_file(path, mime_type, config).await?;
-    result = crate::core::pipeline::run_pipeline(result, config).await?;
-    Ok(result)
+
+    #[cfg(all(feature = "pdf", feature = "tokio-runtime"))]
+    if config.incremental && mime_type == crate::core::mime::PDF_MIME_TYPE {
+        let mut result = crate::core::incremental::extract_file(extractor.as_ref(), path, mime_type, config).await?;
+        if !config.pages.as_ref().is_some_and(|pages| pages.extract_pages) {
+            result.pages = None;
+        }
+        return run_pipeline_with_budget(result, config).await;
+    }
+
+    let result = match stage_budget(config, ExtractionStage::Extraction) {
+        #[cfg(feature = "tokio-runtime")]
+        budget @ Some(_) => {
+            let (path, mime_type, stage_config) = (path.to_path_buf(), mime_type.to_string(), config.clone());
+            watchdog::run_isolated(ExtractionStage::Extraction, budget, async move {
+                extractor.extract_file(&path, &mime_type, &stage_config).await
+            })
+            .await?
+        }
+        _ => extractor.extract_file(path, mime_type, config).await?,
+    };
+    run_pipeline_with_budget(result, config).await
 }
 
 async fn extract_bytes_with_extractor(
@@ -732,9 +899,41 @@ async fn extract_bytes_with_extractor(
     crate::extractors::ensure_initialized()?;
 
     let extractor = get_extractor(mime_type)?;
-    let mut result = extractor.extract_bytes(content, mime_type, config).await?;
-    result = crate::core::pipeline::run_pipeline(result, config).await?;
-    Ok(result)
+    let result = match stage_budget(config, ExtractionStage::Extraction) {
+        #[cfg(feature = "tokio-runtime")]
+        budget @ Some(_) => {
+            let (content, mime_type, stage_config) = (content.to_vec(), mime_type.to_string(), config.clone());
+            watchdog::run_isolated(ExtractionStage::Extraction, budget, async move {
+                extractor.extract_bytes(&content, &mime_type, &stage_config).await
+            })
+            .await?
+        }
+        _ => extractor.extract_bytes(content, mime_type, config).await?,
+    };
+    run_pipeline_with_budget(result, config).await
+}
+
+/// Budget of `stage` in `config.stage_timeouts`, if one is set.
+fn stage_budget(config: &ExtractionConfig, stage: ExtractionStage) -> Option<std::time::Duration> {
+    config
+        .stage_timeouts
+        .as_ref()
+        .and_then(|timeouts| timeouts.budget(stage))
+}
+
+/// Run the post-processing pipeline under the `post_processing` stage budget.
+async fn run_pipeline_with_budget(result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    match stage_budget(config, ExtractionStage::PostProcessing) {
+        #[cfg(feature = "tokio-runtime")]
+        budget @ Some(_) => {
+            let stage_config = config.clone();
+            watchdog::run_isolated(ExtractionStage::PostProcessing, budget, async move {
+                crate::core::pipeline::run_pipeline(result, &stage_config).await
+            })
+            .await
+        }
+        _ => crate::core::pipeline::run_pipeline(result, config).await,
+    }
 }
 
 /// Convert a MIME type string to a pooled String for efficient deduplication.
@@ -832,6 +1031,52 @@ mod tests {
         assert_eq!(result.mime_type, "text/plain");
     }
 
+    #[tokio::test]
+    async fn test_extract_bytes_uses_result_cache() {
+        let config = ExtractionConfig {
+            cache: Some(crate::core::config::CacheConfig {
+                backend: crate::core::config::CacheBackendKind::Memory,
+                max_entries: Some(3),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let first = extract_bytes(b"cached content", "text/plain", &config).await.unwrap();
+        assert_text_content(&first.content, "cached content");
+
+        let slot = ResultCache::for_extraction(&config, b"cached content", "text/plain")
+            .unwrap()
+            .unwrap();
+        let mut stored = slot.get().expect("result should be cached");
+        stored.content = "from cache".to_string();
+        slot.store(&stored);
+
+        let second = extract_bytes(b"cached content", "text/plain", &config).await.unwrap();
+        assert_eq!(second.content, "from cache");
+
+        let uncached = ExtractionConfig {
+            use_cache: false,
+            ..config
+        };
+        let third = extract_bytes(b"cached content", "text/plain", &uncached).await.unwrap();
+        assert_text_content(&third.content, "cached content");
+    }
+
+    #[tokio::test]
+    async fn test_extract_bytes_applies_mime_overrides() {
+        let config: ExtractionConfig = serde_json::from_value(serde_json::json!({
+            "use_cache": false,
+            "overrides": {"text/html": {"input_policy": {"max_file_size_bytes": 1}}}
+        }))
+        .unwrap();
+
+        let text = extract_bytes(b"plain text", "text/plain", &config).await.unwrap();
+        assert_text_content(&text.content, "plain text");
+
+        let html = extract_bytes(b"<p>html</p>", "text/html", &config).await;
+        assert!(matches!(html, Err(KreuzbergError::InputRejected { .. })));
+    }
+
     #[tokio::test]
     async fn test_extract_bytes_invalid_mime() {
         let config = ExtractionConfig::default();
@@ -870,6 +1115,35 @@ mod tests {
         assert_eq!(results.unwrap().len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_batch_extract_file_unordered() {
+        let dir = tempdir().unwrap();
+        let paths: Vec<_> = (0..5)
+            .map(|i| {
+                let path = dir.path().join(format!("test{}.txt", i));
+                File::create(&path)
+                    .unwrap()
+                    .write_all(format!("content {}", i).as_bytes())
+                    .unwrap();
+                path
+            })
+            .collect();
+
+        let config = ExtractionConfig {
+            max_concurrent_extractions: Some(2),
+            ..Default::default()
+        };
+        let mut receiver = batch_extract_file_unordered(paths, &config, 1);
+
+        let mut received = Vec::new();
+        while let Some((index, result)) = receiver.recv().await {
+            assert_text_content(&result.unwrap().content, &format!("content {}", index));
+            received.push(index);
+        }
+        received.sort_unstable();
+        assert_eq!(received, vec![0, 1, 2, 3, 4]);
+    }
+
     #[tokio::test]
     async fn test_batch_extract_bytes() {
         let config = ExtractionConfig::default();
@@ -1027,6 +1301,80 @@ mod tests {
         assert!(results[1].metadata.error.is_some());
     }
 
+    #[tokio::test]
+    async fn test_extract_with_retry_records_attempts_of_io_errors() {
+        use crate::core::concurrency::ConcurrencyController;
+        use crate::core::config::{RetryConfig, RetryableError};
+
+        // IO errors are not retried under this policy, but still end in an error result
+        let config = ExtractionConfig {
+            retry: Some(RetryConfig {
+                retry_on: vec![RetryableError::Ocr],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let controller = Arc::new(ConcurrencyController::fixed(1));
+
+        let result = extract_with_retry(&config, &controller, 0, || async {
+            Err(KreuzbergError::Io(std::io::Error::other("stale NFS handle")))
+        })
+        .await
+        .unwrap();
+
+        assert!(result.metadata.error.is_some());
+        assert_eq!(result.metadata.additional["extraction_attempts"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_extract_with_retry_frees_slot_while_backing_off() {
+        use crate::core::concurrency::ConcurrencyController;
+        use crate::core::config::RetryConfig;
+        use std::sync::Mutex;
+
+        let config = Arc::new(ExtractionConfig {
+            retry: Some(RetryConfig {
+                initial_backoff_ms: 500,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let controller = Arc::new(ConcurrencyController::fixed(1));
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (first_attempt_tx, first_attempt_rx) = tokio::sync::oneshot::channel();
+
+        let retried = tokio::spawn({
+            let (config, controller, log) = (Arc::clone(&config), Arc::clone(&controller), Arc::clone(&log));
+            let mut first_attempt_tx = Some(first_attempt_tx);
+            async move {
+                extract_with_retry(&config, &controller, 0, || {
+                    log.lock().unwrap().push("retried");
+                    let first_attempt = first_attempt_tx.take().map(|tx| tx.send(()));
+                    async move {
+                        if first_attempt.is_some() {
+                            return Err(KreuzbergError::Io(std::io::Error::other("connection reset")));
+                        }
+                        extract_bytes(b"second attempt", "text/plain", &ExtractionConfig::default()).await
+                    }
+                })
+                .await
+            }
+        });
+
+        first_attempt_rx.await.unwrap();
+        let other = extract_with_retry(&config, &controller, 0, || {
+            log.lock().unwrap().push("other");
+            async { extract_bytes(b"other document", "text/plain", &ExtractionConfig::default()).await }
+        })
+        .await
+        .unwrap();
+        let retried = retried.await.unwrap().unwrap();
+
+        assert_eq!(*log.lock().unwrap(), vec!["retried", "other", "retried"]);
+        assert_eq!(other.metadata.additional["extraction_attempts"], 1);
+        assert_eq!(retried.metadata.additional["extraction_attempts"], 2);
+    }
+
     #[tokio::test]
     async fn test_batch_extract_bytes_mixed_valid_invalid() {
         let config = ExtractionConfig::default();
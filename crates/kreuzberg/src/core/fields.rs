@@ -0,0 +1,340 @@
+//! Result field selection for language bindings.
+//!
+//! `ExtractionConfig::fields` names the parts of an [`ExtractionResult`] a caller wants
+//! back, e.g. `["content", "metadata.title", "tables"]`. Bindings apply the selection
+//! before converting a result, so chunks, images and page structures nobody asked for
+//! are dropped instead of being serialized and copied across the FFI boundary.
+//!
+//! Selectors are top-level result fields (see [`RESULT_FIELDS`]) or `metadata.<key>`
+//! for a single metadata key. Selecting any metadata key implies `metadata`, but only
+//! the named keys are kept. `mime_type` is always returned.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::fields::FieldSelection;
+//!
+//! let fields = vec!["content".to_string(), "metadata.title".to_string()];
+//! let selection = FieldSelection::new(Some(&fields));
+//!
+//! assert!(selection.includes("content"));
+//! assert!(selection.includes("metadata"));
+//! assert!(selection.includes_metadata_key("title"));
+//! assert!(!selection.includes_metadata_key("authors"));
+//! assert!(!selection.includes("chunks"));
+//! ```
+
+use crate::types::{ExtractionResult, Metadata};
+use crate::{KreuzbergError, Result};
+
+/// Top-level result fields accepted by `ExtractionConfig::fields`.
+pub const RESULT_FIELDS: &[&str] = &[
+    "content",
+    "mime_type",
+    "metadata",
+    "tables",
+    "detected_languages",
+    "chunks",
+    "images",
+    "pages",
+    "term_offsets",
+    "barcodes",
+    "provenance",
+];
+
+/// Field selection parsed from `ExtractionConfig::fields` (None = every field).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FieldSelection<'a> {
+    fields: Option<&'a [String]>,
+}
+
+impl<'a> FieldSelection<'a> {
+    /// Create a selection from a list of selectors.
+    pub fn new(fields: Option<&'a [String]>) -> Self {
+        Self { fields }
+    }
+
+    /// Whether every field is selected.
+    pub fn is_all(&self) -> bool {
+        self.fields.is_none()
+    }
+
+    /// Whether the top-level result `field` is selected.
+    pub fn includes(&self, field: &str) -> bool {
+        match self.fields {
+            None => true,
+            Some(fields) => fields
+                .iter()
+                .any(|selector| selector.split_once('.').map_or(selector.as_str(), |(parent, _)| parent) == field),
+        }
+    }
+
+    /// Whether the metadata `key` is selected, either directly or through `metadata`.
+    pub fn includes_metadata_key(&self, key: &str) -> bool {
+        match self.fields {
+            None => true,
+            Some(fields) => fields
+                .iter()
+                .any(|selector| selector == "metadata" || selector.strip_prefix("metadata.") == Some(key)),
+        }
+    }
+
+    /// Check that every selector names a known result field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` for unknown fields, nested selectors on
+    /// anything other than `metadata`, and empty metadata keys.
+    pub fn validate(&self) -> Result<()> {
+        for selector in self.fields.unwrap_or_default() {
+            let valid = match selector.split_once('.') {
+                Some((parent, key)) => parent == "metadata" && !key.is_empty(),
+                None => RESULT_FIELDS.contains(&selector.as_str()),
+            };
+            if !valid {
+                return Err(KreuzbergError::validation(format!(
+                    "Unknown result field '{}' in fields (expected one of {} or metadata.<key>)",
+                    selector,
+                    RESULT_FIELDS.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop every unselected field from `result`.
+    ///
+    /// Typed metadata fields that were not requested are cleared here; format-specific and
+    /// additional metadata keys are filtered by [`FieldSelection::metadata_value`].
+    pub fn apply(&self, result: &mut ExtractionResult) {
+        if self.is_all() {
+            return;
+        }
+
+        if !self.includes("content") {
+            result.content = String::new();
+        }
+        if !self.includes("tables") {
+            result.tables = Vec::new();
+        }
+        if !self.includes("detected_languages") {
+            result.detected_languages = None;
+        }
+        if !self.includes("chunks") {
+            result.chunks = None;
+        }
+        if !self.includes("images") {
+            result.images = None;
+        }
+        if !self.includes("pages") {
+            result.pages = None;
+        }
+        if !self.includes("term_offsets") {
+            result.term_offsets = None;
+        }
+        if !self.includes("barcodes") {
+            result.barcodes = None;
+        }
+        if !self.includes("provenance") {
+            result.provenance = None;
+        }
+
+        if !self.includes("metadata") {
+            result.metadata = Metadata::default();
+            return;
+        }
+
+        let metadata = &mut result.metadata;
+        if !self.includes_metadata_key("title") {
+            metadata.title = None;
+        }
+        if !self.includes_metadata_key("subject") {
+            metadata.subject = None;
+        }
+        if !self.includes_metadata_key("authors") {
+            metadata.authors = None;
+        }
+        if !self.includes_metadata_key("keywords") {
+            metadata.keywords = None;
+        }
+        if !self.includes_metadata_key("language") {
+            metadata.language = None;
+        }
+        if !self.includes_metadata_key("created_at") {
+            metadata.created_at = None;
+        }
+        if !self.includes_metadata_key("modified_at") {
+            metadata.modified_at = None;
+        }
+        if !self.includes_metadata_key("created_by") {
+            metadata.created_by = None;
+        }
+        if !self.includes_metadata_key("modified_by") {
+            metadata.modified_by = None;
+        }
+        if !self.includes_metadata_key("pages") {
+            metadata.pages = None;
+        }
+        if !self.includes_metadata_key("outline") {
+            metadata.outline = None;
+        }
+        if !self.includes_metadata_key("key_values") {
+            metadata.key_values = None;
+        }
+        if !self.includes_metadata_key("watermarks") {
+            metadata.watermarks = None;
+        }
+        if !self.includes_metadata_key("xmp") {
+            metadata.xmp = None;
+        }
+        if !self.includes_metadata_key("image_preprocessing") {
+            metadata.image_preprocessing = None;
+        }
+        if !self.includes_metadata_key("json_schema") {
+            metadata.json_schema = None;
+        }
+        if !self.includes_metadata_key("error") {
+            metadata.error = None;
+        }
+        metadata.additional.retain(|key, _| self.includes_metadata_key(key));
+    }
+
+    /// Serialize `metadata` to JSON, keeping only the selected keys.
+    pub fn metadata_value(&self, metadata: &Metadata) -> serde_json::Result<serde_json::Value> {
+        if !self.includes("metadata") {
+            return Ok(serde_json::Value::Object(serde_json::Map::new()));
+        }
+        let mut value = serde_json::to_value(metadata)?;
+        if let serde_json::Value::Object(map) = &mut value {
+            map.retain(|key, _| self.includes_metadata_key(key));
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chunk, ChunkMetadata, PageStructure, PageUnitType, Table};
+
+    fn fields(selectors: &[&str]) -> Vec<String> {
+        selectors.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn sample_result() -> ExtractionResult {
+        let mut metadata = Metadata {
+            title: Some("Report".to_string()),
+            authors: Some(vec!["Ada".to_string()]),
+            pages: Some(PageStructure {
+                total_count: 1,
+                unit_type: PageUnitType::Page,
+                boundaries: None,
+                pages: None,
+            }),
+            ..Default::default()
+        };
+        metadata
+            .additional
+            .insert("quality_score".to_string(), serde_json::json!(0.9));
+
+        ExtractionResult {
+            content: "Report body".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata,
+            tables: vec![Table {
+                cells: vec![vec!["a".to_string()]],
+                markdown: "| a |".to_string(),
+                page_number: 1,
+                column_types: None,
+            }],
+            detected_languages: Some(vec!["eng".to_string()]),
+            chunks: Some(vec![Chunk {
+                content: "Report body".to_string(),
+                embedding: None,
+                metadata: ChunkMetadata {
+                    byte_start: 0,
+                    byte_end: 11,
+                    token_count: None,
+                    chunk_index: 0,
+                    total_chunks: 1,
+                    first_page: None,
+                    last_page: None,
+                    annotations: Default::default(),
+                },
+            }]),
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_no_selection_keeps_everything() {
+        let mut result = sample_result();
+        FieldSelection::default().apply(&mut result);
+
+        assert_eq!(result.content, "Report body");
+        assert!(result.chunks.is_some());
+        assert!(result.metadata.pages.is_some());
+    }
+
+    #[test]
+    fn test_apply_drops_unselected_fields() {
+        let selectors = fields(&["content", "metadata.title", "tables"]);
+        let selection = FieldSelection::new(Some(&selectors));
+        let mut result = sample_result();
+        selection.apply(&mut result);
+
+        assert_eq!(result.content, "Report body");
+        assert_eq!(result.tables.len(), 1);
+        assert!(result.chunks.is_none());
+        assert!(result.detected_languages.is_none());
+        assert_eq!(result.metadata.title.as_deref(), Some("Report"));
+        assert!(result.metadata.authors.is_none());
+        assert!(result.metadata.pages.is_none());
+        assert!(result.metadata.additional.is_empty());
+    }
+
+    #[test]
+    fn test_metadata_only_selection_clears_content() {
+        let selectors = fields(&["metadata"]);
+        let selection = FieldSelection::new(Some(&selectors));
+        let mut result = sample_result();
+        selection.apply(&mut result);
+
+        assert!(result.content.is_empty());
+        assert!(result.tables.is_empty());
+        assert_eq!(result.metadata.authors.as_deref(), Some(&["Ada".to_string()][..]));
+        assert!(result.metadata.pages.is_some());
+    }
+
+    #[test]
+    fn test_metadata_value_filters_keys() {
+        let selectors = fields(&["metadata.title", "metadata.quality_score"]);
+        let selection = FieldSelection::new(Some(&selectors));
+        let value = selection.metadata_value(&sample_result().metadata).unwrap();
+
+        assert_eq!(value, serde_json::json!({"title": "Report", "quality_score": 0.9}));
+
+        let selectors = fields(&["content"]);
+        let value = FieldSelection::new(Some(&selectors))
+            .metadata_value(&sample_result().metadata)
+            .unwrap();
+        assert_eq!(value, serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_fields() {
+        for valid in [&["content", "tables"][..], &["metadata.title"][..], &[][..]] {
+            let selectors = fields(valid);
+            assert!(FieldSelection::new(Some(&selectors)).validate().is_ok());
+        }
+        for invalid in ["contents", "chunks.content", "metadata."] {
+            let selectors = fields(&[invalid]);
+            assert!(FieldSelection::new(Some(&selectors)).validate().is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,737 @@
+//! Incremental re-extraction of PDFs that changed page by page.
+//!
+//! Growing scan bundles and log-style PDFs are extracted again every time pages are
+//! appended to them. With `incremental` enabled, every page of a PDF is hashed from its
+//! dictionary, content streams and resources, and the hashes are stored under
+//! `.kreuzberg/incremental` (or `$KREUZBERG_CACHE_DIR/incremental`) together with the
+//! extracted pages. The next extraction of the same file only extracts the new and
+//! changed pages, from a copy of the PDF without the unchanged ones, and merges them with
+//! the stored pages. Post-processing (chunking, language detection, ...) always runs on
+//! the merged result.
+//!
+//! The whole document is extracted again when nothing is stored for the file, when the
+//! configuration changed, when pages were removed, or when document-level data (info
+//! dictionary, outline, ...) changed.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use ahash::AHasher;
+use lopdf::{Dictionary, Document, Object, ObjectId};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::blob_store::blob_hash;
+use crate::core::config::{ExtractionConfig, PageConfig};
+use crate::plugins::DocumentExtractor;
+use crate::types::{ExtractionResult, PageBoundary, PageContent};
+use crate::{KreuzbergError, Result};
+
+/// Layout version of the stored state; state of another version is ignored.
+const STATE_VERSION: u32 = 1;
+
+/// What is stored between two extractions of a file.
+#[derive(Serialize, Deserialize)]
+struct IncrementalState {
+    version: u32,
+    config_hash: String,
+    document_hash: String,
+    page_hashes: Vec<String>,
+    /// Extractor output before post-processing, always with per-page content
+    result: ExtractionResult,
+}
+
+/// Hashes identifying the document-level data and every page of a PDF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfFingerprint {
+    /// Hash of the info dictionary and the catalog without its page tree
+    pub document_hash: String,
+    /// Hash of every page, in page order
+    pub page_hashes: Vec<String>,
+}
+
+/// Hash the document-level data and the pages of a PDF.
+///
+/// Hashes do not depend on object numbers, so a rewritten PDF with the same pages has
+/// the same fingerprint.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Parsing` for malformed PDFs and `KreuzbergError::Validation`
+/// for encrypted ones.
+pub fn fingerprint_pdf(content: &[u8]) -> Result<PdfFingerprint> {
+    let document = load_document(content)?;
+    let pages = document.get_pages();
+    let page_numbers: HashMap<ObjectId, u32> = pages.iter().map(|(&number, &id)| (id, number)).collect();
+
+    let page_hashes = pages
+        .values()
+        .map(|&id| {
+            let mut hasher = ObjectHasher::new(&document, &page_numbers);
+            if let Ok(page) = document.get_object(id) {
+                hasher.write(page, &[b"Parent"]);
+            }
+            hasher.finish()
+        })
+        .collect();
+
+    let mut hasher = ObjectHasher::new(&document, &page_numbers);
+    for key in [b"Info".as_slice(), b"Root"] {
+        let object = match document.trailer.get(key) {
+            Ok(Object::Reference(id)) => document.get_object(*id),
+            object => object,
+        };
+        if let Ok(object) = object {
+            hasher.write(object, &[b"Pages"]);
+        }
+    }
+
+    Ok(PdfFingerprint {
+        document_hash: hasher.finish(),
+        page_hashes,
+    })
+}
+
+/// Extract a PDF file, reusing the stored pages of an earlier extraction that did not change.
+///
+/// Returns the extractor output before post-processing, with `pages` filled in whether
+/// or not `config.pages` asks for them, and an `incremental` entry in the metadata
+/// counting reused and extracted pages.
+pub(crate) async fn extract_file(
+    extractor: &dyn DocumentExtractor,
+    path: &Path,
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Result<ExtractionResult> {
+    let content = tokio::fs::read(path).await?;
+    let Ok(fingerprint) = fingerprint_pdf(&content) else {
+        return extractor.extract_bytes(&content, mime_type, config).await;
+    };
+
+    let mut page_config = config.pages.clone().unwrap_or_default();
+    page_config.extract_pages = true;
+    let extraction_config = ExtractionConfig {
+        pages: Some(page_config.clone()),
+        ..config.clone()
+    };
+
+    let state_path = state_path(path);
+    let config_hash = blob_hash(&serde_json::to_vec(config)?);
+    let stored = read_state(&state_path).await.filter(|state| {
+        state.version == STATE_VERSION
+            && state.config_hash == config_hash
+            && state.document_hash == fingerprint.document_hash
+    });
+    let update = stored.and_then(|state| Some((changed_pages(&state.page_hashes, &fingerprint.page_hashes)?, state)));
+
+    let page_count = fingerprint.page_hashes.len();
+    let (result, extracted_pages) = match update {
+        Some((changed, state)) if changed.is_empty() => (state.result, 0),
+        Some((changed, state)) => {
+            let changed_pdf = pdf_with_pages(&content, &changed)?;
+            let update = extractor
+                .extract_bytes(&changed_pdf, mime_type, &extraction_config)
+                .await?;
+            match merge_results(state.result, update, &changed, page_count, &page_config) {
+                Some(merged) => (merged, changed.len()),
+                None => (
+                    extractor.extract_bytes(&content, mime_type, &extraction_config).await?,
+                    page_count,
+                ),
+            }
+        }
+        None => (
+            extractor.extract_bytes(&content, mime_type, &extraction_config).await?,
+            page_count,
+        ),
+    };
+
+    let state = IncrementalState {
+        version: STATE_VERSION,
+        config_hash,
+        document_hash: fingerprint.document_hash,
+        page_hashes: fingerprint.page_hashes,
+        result,
+    };
+    if extracted_pages > 0
+        && let Err(e) = write_state(&state_path, &state).await
+    {
+        tracing::warn!("Failed to store incremental extraction state: {}", e);
+    }
+
+    let mut result = state.result;
+    result.metadata.additional.insert(
+        "incremental".to_string(),
+        serde_json::json!({
+            "reused_pages": page_count - extracted_pages,
+            "extracted_pages": extracted_pages,
+        }),
+    );
+    Ok(result)
+}
+
+fn load_document(content: &[u8]) -> Result<Document> {
+    let document =
+        Document::load_mem(content).map_err(|e| KreuzbergError::parsing(format!("Failed to load PDF: {}", e)))?;
+    if document.is_encrypted() {
+        return Err(KreuzbergError::validation(
+            "Incremental extraction does not support encrypted PDFs",
+        ));
+    }
+    Ok(document)
+}
+
+/// Pages (1-indexed) of `current` that are new or differ from `stored`, or `None` when pages were removed.
+fn changed_pages(stored: &[String], current: &[String]) -> Option<Vec<usize>> {
+    if current.len() < stored.len() {
+        return None;
+    }
+
+    let changed = current
+        .iter()
+        .enumerate()
+        .filter(|(index, hash)| stored.get(*index) != Some(*hash))
+        .map(|(index, _)| index + 1)
+        .collect();
+    Some(changed)
+}
+
+/// Copy of a PDF that keeps only `pages` (1-indexed).
+fn pdf_with_pages(content: &[u8], pages: &[usize]) -> Result<Vec<u8>> {
+    let mut document = load_document(content)?;
+    let removed: Vec<u32> = document
+        .get_pages()
+        .into_keys()
+        .filter(|number| !pages.contains(&(*number as usize)))
+        .collect();
+    document.delete_pages(&removed);
+
+    let mut output = Vec::with_capacity(content.len());
+    document
+        .save_to(&mut output)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to write PDF: {}", e)))?;
+    Ok(output)
+}
+
+/// Text of every page, cut from `content` at the page boundaries.
+fn page_texts(result: &ExtractionResult) -> Option<Vec<String>> {
+    let boundaries = result.metadata.pages.as_ref()?.boundaries.as_ref()?;
+    boundaries
+        .iter()
+        .map(|boundary| {
+            result
+                .content
+                .get(boundary.byte_start..boundary.byte_end)
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// Merge the extraction of the `changed` pages into the stored result of the previous version.
+///
+/// `update` is the extraction of a PDF holding only the changed pages, so its page `n`
+/// is page `changed[n - 1]` of the document. Returns `None` when either result lacks
+/// page boundaries or per-page content.
+fn merge_results(
+    cached: ExtractionResult,
+    update: ExtractionResult,
+    changed: &[usize],
+    page_count: usize,
+    page_config: &PageConfig,
+) -> Option<ExtractionResult> {
+    let cached_texts = page_texts(&cached)?;
+    let update_texts = page_texts(&update)?;
+    let mut cached_pages: Vec<Option<PageContent>> = cached.pages?.into_iter().map(Some).collect();
+    let mut update_pages: Vec<Option<PageContent>> = update.pages?.into_iter().map(Some).collect();
+    if update_texts.len() != changed.len() || update_pages.len() != changed.len() {
+        return None;
+    }
+
+    let is_changed = |page: usize| changed.binary_search(&page).is_ok();
+    let document_page = |page: usize| page.checked_sub(1).and_then(|index| changed.get(index)).copied();
+
+    // Unchanged images keep their order; the images of changed pages take the place of the old ones
+    let mut images: Vec<(bool, _)> = cached
+        .images
+        .iter()
+        .flatten()
+        .filter(|image| !image.page_number.is_some_and(is_changed))
+        .map(|image| (false, image.clone()))
+        .collect();
+    for image in update.images.iter().flatten() {
+        if let Some(page) = image.page_number.and_then(document_page) {
+            let mut image = image.clone();
+            image.page_number = Some(page);
+            images.push((true, image));
+        }
+    }
+    images.sort_by_key(|(_, image)| image.page_number);
+
+    let mut image_indices = HashMap::new();
+    for (index, (from_update, image)) in images.iter_mut().enumerate() {
+        image_indices.insert((*from_update, image.image_index), index);
+        image.image_index = index;
+    }
+
+    let mut content = String::new();
+    let mut boundaries = Vec::with_capacity(page_count);
+    let mut pages = Vec::with_capacity(page_count);
+    let mut page_infos = Vec::with_capacity(page_count);
+    let cached_infos = cached
+        .metadata
+        .pages
+        .as_ref()
+        .and_then(|structure| structure.pages.as_ref());
+    let update_infos = update
+        .metadata
+        .pages
+        .as_ref()
+        .and_then(|structure| structure.pages.as_ref());
+    let mut next_update = 0;
+
+    for page_number in 1..=page_count {
+        let from_update = changed.get(next_update) == Some(&page_number);
+        let (text, page, info) = if from_update {
+            next_update += 1;
+            let index = next_update - 1;
+            (
+                &update_texts[index],
+                update_pages[index].take()?,
+                update_infos.and_then(|infos| infos.get(index)),
+            )
+        } else {
+            let index = page_number - 1;
+            (
+                cached_texts.get(index)?,
+                cached_pages.get_mut(index)?.take()?,
+                cached_infos.and_then(|infos| infos.get(index)),
+            )
+        };
+
+        if page_number > 1 {
+            if page_config.insert_page_markers {
+                content.push_str(
+                    &page_config
+                        .marker_format
+                        .replace("{page_num}", &page_number.to_string()),
+                );
+            } else {
+                content.push_str("\n\n");
+            }
+        }
+        let byte_start = content.len();
+        content.push_str(text);
+        boundaries.push(PageBoundary {
+            byte_start,
+            byte_end: content.len(),
+            page_number,
+        });
+
+        let mut page = page;
+        page.page_number = page_number;
+        for table in page.tables.iter_mut() {
+            Arc::make_mut(table).page_number = page_number;
+        }
+        for image in page.images.iter_mut() {
+            let image = Arc::make_mut(image);
+            image.page_number = Some(page_number);
+            if let Some(&index) = image_indices.get(&(from_update, image.image_index)) {
+                image.image_index = index;
+            }
+        }
+        pages.push(page);
+
+        if let Some(info) = info {
+            let mut info = info.clone();
+            info.number = page_number;
+            page_infos.push(info);
+        }
+    }
+
+    let mut tables: Vec<_> = cached
+        .tables
+        .into_iter()
+        .filter(|table| !is_changed(table.page_number))
+        .collect();
+    tables.extend(update.tables.into_iter().filter_map(|mut table| {
+        table.page_number = document_page(table.page_number)?;
+        Some(table)
+    }));
+    tables.sort_by_key(|table| table.page_number);
+
+    let barcodes = match (cached.barcodes, update.barcodes) {
+        (None, None) => None,
+        (cached_barcodes, update_barcodes) => {
+            let mut barcodes: Vec<_> = cached_barcodes
+                .into_iter()
+                .flatten()
+                .filter(|barcode| !is_changed(barcode.page))
+                .collect();
+            barcodes.extend(update_barcodes.into_iter().flatten().filter_map(|mut barcode| {
+                barcode.page = document_page(barcode.page)?;
+                Some(barcode)
+            }));
+            barcodes.sort_by_key(|barcode| barcode.page);
+            Some(barcodes)
+        }
+    };
+
+    let mut metadata = cached.metadata;
+    let mut structure = metadata.pages.take()?;
+    structure.total_count = page_count;
+    structure.boundaries = Some(boundaries);
+    structure.pages = (page_infos.len() == page_count).then_some(page_infos);
+    metadata.pages = Some(structure);
+
+    Some(ExtractionResult {
+        content,
+        mime_type: cached.mime_type,
+        metadata,
+        tables,
+        detected_languages: update.detected_languages,
+        chunks: None,
+        images: (cached.images.is_some() || update.images.is_some())
+            .then(|| images.into_iter().map(|(_, image)| image).collect()),
+        pages: Some(pages),
+        term_offsets: None,
+        barcodes,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
+    })
+}
+
+fn state_path(path: &Path) -> PathBuf {
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let cache_dir = std::env::var_os("KREUZBERG_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            std::env::current_dir()
+                .unwrap_or_else(|_| PathBuf::from("."))
+                .join(".kreuzberg")
+        });
+    cache_dir
+        .join("incremental")
+        .join(format!("{}.json", blob_hash(path.to_string_lossy().as_bytes())))
+}
+
+async fn read_state(path: &Path) -> Option<IncrementalState> {
+    let data = tokio::fs::read(path).await.ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+async fn write_state(path: &Path, state: &IncrementalState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    let temp_path = path.with_extension(format!("tmp.{}", std::process::id()));
+    tokio::fs::write(&temp_path, serde_json::to_vec(state)?).await?;
+    if let Err(e) = tokio::fs::rename(&temp_path, path).await {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Hashes a PDF object and everything it references, without depending on object numbers.
+struct ObjectHasher<'a> {
+    document: &'a Document,
+    /// Page number of every page object; references to pages hash as the page number
+    page_numbers: &'a HashMap<ObjectId, u32>,
+    visited: HashSet<ObjectId>,
+    hasher: AHasher,
+}
+
+impl<'a> ObjectHasher<'a> {
+    fn new(document: &'a Document, page_numbers: &'a HashMap<ObjectId, u32>) -> Self {
+        Self {
+            document,
+            page_numbers,
+            visited: HashSet::new(),
+            hasher: AHasher::default(),
+        }
+    }
+
+    /// Hash `root`, leaving out its `skip_keys` entries if it is a dictionary.
+    fn write(&mut self, root: &'a Object, skip_keys: &[&[u8]]) {
+        let mut pending = Vec::new();
+        match root {
+            Object::Dictionary(dictionary) => self.write_dictionary(dictionary, skip_keys, &mut pending),
+            object => pending.push(object),
+        }
+
+        // Iterative so deep outline and annotation chains cannot overflow the stack
+        while let Some(object) = pending.pop() {
+            match object {
+                Object::Null => self.hasher.write_u8(0),
+                Object::Boolean(value) => {
+                    self.hasher.write_u8(1);
+                    self.hasher.write_u8(u8::from(*value));
+                }
+                Object::Integer(value) => {
+                    self.hasher.write_u8(2);
+                    self.hasher.write_i64(*value);
+                }
+                Object::Real(value) => {
+                    self.hasher.write_u8(3);
+                    self.hasher.write(&value.to_le_bytes());
+                }
+                Object::Name(name) => {
+                    self.hasher.write_u8(4);
+                    self.write_bytes(name);
+                }
+                Object::String(bytes, _) => {
+                    self.hasher.write_u8(5);
+                    self.write_bytes(bytes);
+                }
+                Object::Array(items) => {
+                    self.hasher.write_u8(6);
+                    self.hasher.write_usize(items.len());
+                    pending.extend(items.iter().rev());
+                }
+                Object::Dictionary(dictionary) => self.write_dictionary(dictionary, &[b"Parent"], &mut pending),
+                Object::Stream(stream) => {
+                    self.hasher.write_u8(8);
+                    self.write_bytes(&stream.content);
+                    self.write_dictionary(&stream.dict, &[b"Parent"], &mut pending);
+                }
+                Object::Reference(id) => {
+                    if let Some(&page_number) = self.page_numbers.get(id) {
+                        self.hasher.write_u8(9);
+                        self.hasher.write_u32(page_number);
+                    } else if !self.visited.insert(*id) {
+                        self.hasher.write_u8(10);
+                    } else if let Ok(target) = self.document.get_object(*id) {
+                        pending.push(target);
+                    } else {
+                        self.hasher.write_u8(0);
+                    }
+                }
+            }
+        }
+    }
+
+    fn write_dictionary(&mut self, dictionary: &'a Dictionary, skip_keys: &[&[u8]], pending: &mut Vec<&'a Object>) {
+        let entries: Vec<_> = dictionary
+            .iter()
+            .filter(|(key, _)| !skip_keys.contains(&key.as_slice()))
+            .collect();
+
+        self.hasher.write_u8(7);
+        self.hasher.write_usize(entries.len());
+        for (key, _) in &entries {
+            self.write_bytes(key);
+        }
+        pending.extend(entries.into_iter().rev().map(|(_, value)| value));
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.hasher.write_usize(bytes.len());
+        self.hasher.write(bytes);
+    }
+
+    fn finish(&self) -> String {
+        format!("{:016x}", self.hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ExtractedImage, Metadata, PageStructure, PageUnitType, Table};
+    use lopdf::{Stream, dictionary};
+
+    fn pdf(pages: &[&str]) -> Vec<u8> {
+        let mut document = Document::with_version("1.5");
+        let pages_id = document.new_object_id();
+        let kids: Vec<Object> = pages
+            .iter()
+            .map(|text| {
+                let content = format!("BT /F1 12 Tf ({}) Tj ET", text).into_bytes();
+                let contents_id = document.add_object(Stream::new(dictionary! {}, content));
+                let page_id = document.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "Contents" => contents_id,
+                });
+                Object::Reference(page_id)
+            })
+            .collect();
+        document.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! { "Type" => "Pages", "Kids" => kids, "Count" => pages.len() as i64 }),
+        );
+        let catalog_id = document.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+        document.trailer.set("Root", catalog_id);
+
+        let mut output = Vec::new();
+        document.save_to(&mut output).unwrap();
+        output
+    }
+
+    fn table(page_number: usize) -> Table {
+        Table {
+            cells: vec![vec![format!("table on page {}", page_number)]],
+            markdown: String::new(),
+            page_number,
+            column_types: None,
+        }
+    }
+
+    fn image(image_index: usize, page_number: usize) -> ExtractedImage {
+        ExtractedImage {
+            data: vec![image_index as u8],
+            format: "png".to_string(),
+            image_index,
+            page_number: Some(page_number),
+            width: None,
+            height: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
+        }
+    }
+
+    /// Raw extractor output for pages holding `texts`, with one image and one table per page.
+    fn result(texts: &[&str]) -> ExtractionResult {
+        let images: Vec<_> = (1..=texts.len()).map(|page| image(page - 1, page)).collect();
+        let pages = texts
+            .iter()
+            .enumerate()
+            .map(|(index, text)| PageContent {
+                page_number: index + 1,
+                content: text.to_string(),
+                tables: vec![Arc::new(table(index + 1))],
+                images: vec![Arc::new(images[index].clone())],
+                content_blob: None,
+            })
+            .collect();
+
+        let mut content = String::new();
+        let mut boundaries = Vec::new();
+        for (index, text) in texts.iter().enumerate() {
+            if index > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(text);
+            boundaries.push(PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: index + 1,
+            });
+        }
+
+        ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: texts.len(),
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: (1..=texts.len()).map(table).collect(),
+            detected_languages: None,
+            chunks: None,
+            images: Some(images),
+            pages: Some(pages),
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_changed_pages() {
+        let hashes = |hashes: &[&str]| hashes.iter().map(|hash| hash.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(changed_pages(&hashes(&["a", "b"]), &hashes(&["a", "b"])), Some(vec![]));
+        assert_eq!(
+            changed_pages(&hashes(&["a", "b"]), &hashes(&["a", "c", "d"])),
+            Some(vec![2, 3])
+        );
+        assert_eq!(changed_pages(&hashes(&["a", "b"]), &hashes(&["a"])), None);
+    }
+
+    #[test]
+    fn test_fingerprint_detects_appended_and_modified_pages() {
+        let original = fingerprint_pdf(&pdf(&["one", "two"])).unwrap();
+        let appended = fingerprint_pdf(&pdf(&["one", "two", "three"])).unwrap();
+        let modified = fingerprint_pdf(&pdf(&["one", "TWO"])).unwrap();
+
+        assert_eq!(original.page_hashes.len(), 2);
+        assert_eq!(appended.document_hash, original.document_hash);
+        assert_eq!(appended.page_hashes[..2], original.page_hashes[..]);
+        assert_eq!(modified.page_hashes[0], original.page_hashes[0]);
+        assert_ne!(modified.page_hashes[1], original.page_hashes[1]);
+    }
+
+    #[test]
+    fn test_pdf_with_pages_keeps_only_requested_pages() {
+        let content = pdf(&["one", "two", "three"]);
+        let original = fingerprint_pdf(&content).unwrap();
+
+        let subset = fingerprint_pdf(&pdf_with_pages(&content, &[2, 3]).unwrap()).unwrap();
+
+        assert_eq!(subset.page_hashes, original.page_hashes[1..]);
+    }
+
+    #[test]
+    fn test_merge_results_replaces_changed_and_appends_new_pages() {
+        let cached = result(&["one", "two"]);
+        let update = result(&["TWO", "three"]);
+
+        let merged = merge_results(cached, update, &[2, 3], 3, &PageConfig::default()).unwrap();
+
+        assert_eq!(merged.content, "one\n\nTWO\n\nthree");
+        let structure = merged.metadata.pages.as_ref().unwrap();
+        assert_eq!(structure.total_count, 3);
+        let boundaries = structure.boundaries.as_ref().unwrap();
+        assert_eq!(
+            &merged.content[boundaries[2].byte_start..boundaries[2].byte_end],
+            "three"
+        );
+
+        let pages = merged.pages.as_ref().unwrap();
+        assert_eq!(
+            pages.iter().map(|page| page.page_number).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(pages[2].tables[0].page_number, 3);
+
+        let images = merged.images.as_ref().unwrap();
+        assert_eq!(
+            images
+                .iter()
+                .map(|image| (image.image_index, image.page_number, image.data[0]))
+                .collect::<Vec<_>>(),
+            vec![(0, Some(1), 0), (1, Some(2), 0), (2, Some(3), 1)]
+        );
+        assert_eq!(pages[2].images[0].image_index, 2);
+        assert_eq!(
+            merged.tables.iter().map(|table| table.page_number).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(merged.tables[1].cells[0][0], "table on page 1");
+    }
+
+    #[test]
+    fn test_merge_results_requires_page_boundaries() {
+        let mut update = result(&["three"]);
+        update.metadata.pages = None;
+
+        assert!(merge_results(result(&["one", "two"]), update, &[3], 3, &PageConfig::default()).is_none());
+    }
+}
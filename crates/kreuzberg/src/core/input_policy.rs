@@ -0,0 +1,433 @@
+//! Input checks applied before extractor dispatch.
+//!
+//! [`InputPolicyConfig`] declares which inputs a service accepts. [`extract_file`](crate::extract_file)
+//! and [`extract_bytes`](crate::extract_bytes) run the checks once the MIME type is known and
+//! before any extractor sees the input:
+//!
+//! 1. Size against `max_file_size_bytes` (files are measured without being read)
+//! 2. MIME type against `allowed_mime_types`
+//! 3. Magic bytes against the MIME type when `reject_mime_mismatch` is set
+//! 4. Encryption when `encrypted` is [`EncryptedInputPolicy::Reject`]
+//!
+//! Signatures are compared by container, so a `.docx` holding a spreadsheet passes the
+//! mismatch check while a PDF renamed to `.docx` does not. Text formats have no signature
+//! and only fail the check when their bytes carry a binary one.
+//!
+//! Encryption is detected for PDFs (an `/Encrypt` dictionary), ZIP containers (entries with
+//! the encryption flag) and password-protected OOXML files (OLE containers with an
+//! `EncryptionInfo` stream).
+//!
+//! Each failed check returns `KreuzbergError::InputRejected` with its own [`InputRejection`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::config::InputPolicyConfig;
+//! use kreuzberg::core::input_policy::check_bytes;
+//! use kreuzberg::{InputRejection, KreuzbergError};
+//!
+//! let policy = InputPolicyConfig {
+//!     max_file_size_bytes: Some(10 * 1024 * 1024),
+//!     allowed_mime_types: Some(vec!["application/pdf".to_string(), "image/*".to_string()]),
+//!     reject_mime_mismatch: true,
+//!     ..Default::default()
+//! };
+//!
+//! assert!(check_bytes(b"%PDF-1.7\n", "application/pdf", &policy).is_ok());
+//!
+//! let err = check_bytes(b"%PDF-1.7\n", "image/png", &policy).unwrap_err();
+//! assert!(matches!(err, KreuzbergError::InputRejected { reason: InputRejection::ContentMismatch, .. }));
+//! ```
+
+use crate::core::config::{EncryptedInputPolicy, InputPolicyConfig};
+use crate::core::mime::PDF_MIME_TYPE;
+use crate::error::InputRejection;
+use crate::{KreuzbergError, Result};
+use memchr::memmem;
+use std::path::Path;
+
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+const ZIP_CENTRAL_HEADER: &[u8] = b"PK\x01\x02";
+const ZIP_END_OF_CENTRAL_DIRECTORY: &[u8] = b"PK\x05\x06";
+const OLE_SIGNATURE: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// `EncryptionInfo` stream name as stored in an OLE directory (UTF-16LE)
+const OLE_ENCRYPTION_INFO: &[u8] = b"E\0n\0c\0r\0y\0p\0t\0i\0o\0n\0I\0n\0f\0o\0";
+
+/// Bytes searched for the `%PDF` header, which readers accept after leading garbage
+const PDF_HEADER_WINDOW: usize = 1024;
+
+const OLE_MIME_TYPES: &[&str] = &[
+    "application/msword",
+    "application/vnd.ms-excel",
+    "application/vnd.ms-powerpoint",
+    "application/vnd.ms-outlook",
+    "application/x-ole-storage",
+    "application/x-cfb",
+];
+
+/// Containers that always start with a signature
+const SIGNED_CONTAINERS: &[&str] = &[
+    "zip",
+    "ole",
+    PDF_MIME_TYPE,
+    "application/gzip",
+    "application/x-7z-compressed",
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "image/bmp",
+    "image/tiff",
+];
+
+/// Check a file before extraction.
+///
+/// The file is only read when the policy inspects content.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::InputRejected` for a failed check and `KreuzbergError::Io`
+/// if the file cannot be read.
+pub fn check_file(path: &Path, mime_type: &str, policy: &InputPolicyConfig) -> Result<()> {
+    check_size(std::fs::metadata(path)?.len(), policy)?;
+    check_mime_type(mime_type, policy)?;
+    if policy.inspects_content() {
+        check_content(&std::fs::read(path)?, mime_type, policy)?;
+    }
+    Ok(())
+}
+
+/// Check an in-memory input before extraction.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::InputRejected` for a failed check.
+pub fn check_bytes(content: &[u8], mime_type: &str, policy: &InputPolicyConfig) -> Result<()> {
+    check_size(content.len() as u64, policy)?;
+    check_mime_type(mime_type, policy)?;
+    check_content(content, mime_type, policy)
+}
+
+fn check_size(size: u64, policy: &InputPolicyConfig) -> Result<()> {
+    match policy.max_file_size_bytes {
+        Some(max) if size > max => Err(KreuzbergError::input_rejected(
+            InputRejection::FileTooLarge,
+            format!("Input is {} bytes, the limit is {} bytes", size, max),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_mime_type(mime_type: &str, policy: &InputPolicyConfig) -> Result<()> {
+    let Some(allowed) = &policy.allowed_mime_types else {
+        return Ok(());
+    };
+
+    if allowed.iter().any(|pattern| mime_matches(pattern, mime_type)) {
+        Ok(())
+    } else {
+        Err(KreuzbergError::input_rejected(
+            InputRejection::MimeTypeNotAllowed,
+            format!("MIME type '{}' is not allowed", mime_type),
+        ))
+    }
+}
+
+fn check_content(content: &[u8], mime_type: &str, policy: &InputPolicyConfig) -> Result<()> {
+    if policy.reject_mime_mismatch {
+        check_signature(content, mime_type)?;
+    }
+
+    if policy.encrypted == EncryptedInputPolicy::Reject
+        && let Some(kind) = encryption(content)
+    {
+        return Err(KreuzbergError::input_rejected(
+            InputRejection::Encrypted,
+            format!("Encrypted {} inputs are not accepted", kind),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Whether `mime_type` matches an allowlist entry: an exact type or a `type/*` family.
+fn mime_matches(pattern: &str, mime_type: &str) -> bool {
+    match pattern.strip_suffix("/*") {
+        Some("*") => true,
+        Some(family) => mime_type
+            .split_once('/')
+            .is_some_and(|(top_level, _)| top_level.eq_ignore_ascii_case(family)),
+        None => pattern.eq_ignore_ascii_case(mime_type),
+    }
+}
+
+fn check_signature(content: &[u8], mime_type: &str) -> Result<()> {
+    let declared = container(mime_type);
+    // HTML/XML sniffing is a guess about text, not a signature
+    let detected = infer::get(content)
+        .filter(|kind| kind.matcher_type() != infer::MatcherType::Text)
+        .map(|kind| kind.mime_type());
+
+    let matches = match detected {
+        Some(detected) => container(detected) == declared,
+        None => !SIGNED_CONTAINERS.contains(&declared.as_str()),
+    };
+    if matches || (declared == PDF_MIME_TYPE && is_pdf(content)) {
+        return Ok(());
+    }
+
+    let found = match detected {
+        Some(detected) => format!("content is '{}'", detected),
+        None => "content has no matching signature".to_string(),
+    };
+    Err(KreuzbergError::input_rejected(
+        InputRejection::ContentMismatch,
+        format!("Declared as '{}' but {}", mime_type, found),
+    ))
+}
+
+/// Signature family of a MIME type: formats stored in the same container share one.
+fn container(mime_type: &str) -> String {
+    let mime = mime_type.to_ascii_lowercase();
+    let is_zip = matches!(
+        mime.as_str(),
+        "application/zip" | "application/x-zip-compressed" | "application/epub+zip"
+    ) || mime.contains("openxmlformats-officedocument")
+        || mime.contains("macroenabled")
+        || mime.starts_with("application/vnd.oasis.opendocument");
+
+    if is_zip {
+        "zip".to_string()
+    } else if OLE_MIME_TYPES.contains(&mime.as_str()) {
+        "ole".to_string()
+    } else if mime == "image/jpg" {
+        "image/jpeg".to_string()
+    } else if mime == "application/x-gzip" {
+        "application/gzip".to_string()
+    } else {
+        mime
+    }
+}
+
+fn is_pdf(content: &[u8]) -> bool {
+    memmem::find(&content[..content.len().min(PDF_HEADER_WINDOW)], b"%PDF").is_some()
+}
+
+/// Kind of encryption found in `content`, if any.
+fn encryption(content: &[u8]) -> Option<&'static str> {
+    if is_pdf(content) {
+        memmem::find(content, b"/Encrypt").map(|_| "PDF")
+    } else if content.starts_with(ZIP_SIGNATURE) {
+        zip_is_encrypted(content).then_some("ZIP")
+    } else if content.starts_with(OLE_SIGNATURE) {
+        memmem::find(content, OLE_ENCRYPTION_INFO).map(|_| "Office")
+    } else {
+        None
+    }
+}
+
+/// Whether any entry of a ZIP archive has the encryption flag set.
+///
+/// Walks the central directory; archives without one fall back to the first local header.
+fn zip_is_encrypted(content: &[u8]) -> bool {
+    const ENCRYPTED_FLAG: u16 = 1;
+
+    // The end of central directory record is 22 bytes followed by a comment of up to 64 KiB
+    let search_from = content.len().saturating_sub(22 + u16::MAX as usize);
+    let Some(end) = memmem::rfind(&content[search_from..], ZIP_END_OF_CENTRAL_DIRECTORY).map(|i| search_from + i)
+    else {
+        return read_u16(content, 6).is_some_and(|flags| flags & ENCRYPTED_FLAG != 0);
+    };
+
+    let (Some(entries), Some(mut offset)) = (read_u16(content, end + 10), read_u32(content, end + 16)) else {
+        return false;
+    };
+    for _ in 0..entries {
+        if content.get(offset..offset + 4) != Some(ZIP_CENTRAL_HEADER) {
+            return false;
+        }
+        let (Some(flags), Some(name_len), Some(extra_len), Some(comment_len)) = (
+            read_u16(content, offset + 8),
+            read_u16(content, offset + 28),
+            read_u16(content, offset + 30),
+            read_u16(content, offset + 32),
+        ) else {
+            return false;
+        };
+        if flags & ENCRYPTED_FLAG != 0 {
+            return true;
+        }
+        offset += 46 + name_len as usize + extra_len as usize + comment_len as usize;
+    }
+    false
+}
+
+fn read_u16(content: &[u8], offset: usize) -> Option<u16> {
+    let bytes = content.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(content: &[u8], offset: usize) -> Option<usize> {
+    let bytes = content.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Single-entry stored ZIP archive whose entry has the given general purpose flags.
+    fn zip_with_flags(flags: u16) -> Vec<u8> {
+        let name = b"a.txt";
+        let mut zip = Vec::new();
+        zip.extend_from_slice(ZIP_SIGNATURE);
+        zip.extend_from_slice(&20u16.to_le_bytes());
+        zip.extend_from_slice(&flags.to_le_bytes());
+        zip.extend_from_slice(&[0; 18]);
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&0u16.to_le_bytes());
+        zip.extend_from_slice(name);
+
+        let central_offset = zip.len() as u32;
+        zip.extend_from_slice(ZIP_CENTRAL_HEADER);
+        zip.extend_from_slice(&20u16.to_le_bytes());
+        zip.extend_from_slice(&20u16.to_le_bytes());
+        zip.extend_from_slice(&flags.to_le_bytes());
+        zip.extend_from_slice(&[0; 18]);
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&[0; 16]);
+        zip.extend_from_slice(name);
+        let central_size = zip.len() as u32 - central_offset;
+
+        zip.extend_from_slice(ZIP_END_OF_CENTRAL_DIRECTORY);
+        zip.extend_from_slice(&[0; 4]);
+        zip.extend_from_slice(&1u16.to_le_bytes());
+        zip.extend_from_slice(&1u16.to_le_bytes());
+        zip.extend_from_slice(&central_size.to_le_bytes());
+        zip.extend_from_slice(&central_offset.to_le_bytes());
+        zip.extend_from_slice(&0u16.to_le_bytes());
+        zip
+    }
+
+    fn reason(result: Result<()>) -> Option<InputRejection> {
+        match result {
+            Err(KreuzbergError::InputRejected { reason, .. }) => Some(reason),
+            Err(other) => panic!("unexpected error: {}", other),
+            Ok(()) => None,
+        }
+    }
+
+    #[test]
+    fn test_size_and_mime_allowlist() {
+        let policy = InputPolicyConfig {
+            max_file_size_bytes: Some(8),
+            allowed_mime_types: Some(vec!["text/plain".to_string(), "image/*".to_string()]),
+            ..Default::default()
+        };
+
+        assert_eq!(reason(check_bytes(b"short", "text/plain", &policy)), None);
+        assert_eq!(reason(check_bytes(b"short", "IMAGE/png", &policy)), None);
+        assert_eq!(
+            reason(check_bytes(b"far too long", "text/plain", &policy)),
+            Some(InputRejection::FileTooLarge)
+        );
+        assert_eq!(
+            reason(check_bytes(b"short", "text/html", &policy)),
+            Some(InputRejection::MimeTypeNotAllowed)
+        );
+        assert_eq!(
+            reason(check_bytes(b"short", "text/html", &InputPolicyConfig::default())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_signature_mismatch() {
+        let policy = InputPolicyConfig {
+            reject_mime_mismatch: true,
+            ..Default::default()
+        };
+        let docx = "application/vnd.openxmlformats-officedocument.wordprocessingml.document";
+
+        assert_eq!(reason(check_bytes(b"%PDF-1.7\n", PDF_MIME_TYPE, &policy)), None);
+        assert_eq!(reason(check_bytes(b"\r\n\r\n%PDF-1.7\n", PDF_MIME_TYPE, &policy)), None);
+        assert_eq!(reason(check_bytes(&zip_with_flags(0), docx, &policy)), None);
+        assert_eq!(reason(check_bytes(b"# Notes\n", "text/markdown", &policy)), None);
+        assert_eq!(reason(check_bytes(b"<html></html>", "text/plain", &policy)), None);
+
+        assert_eq!(
+            reason(check_bytes(b"%PDF-1.7\n", docx, &policy)),
+            Some(InputRejection::ContentMismatch)
+        );
+        assert_eq!(
+            reason(check_bytes(b"plain text", PDF_MIME_TYPE, &policy)),
+            Some(InputRejection::ContentMismatch)
+        );
+        assert_eq!(
+            reason(check_bytes(&zip_with_flags(0), "text/plain", &policy)),
+            Some(InputRejection::ContentMismatch)
+        );
+    }
+
+    #[test]
+    fn test_encrypted_inputs() {
+        let reject = InputPolicyConfig {
+            encrypted: EncryptedInputPolicy::Reject,
+            ..Default::default()
+        };
+        let encrypted_pdf = b"%PDF-1.7\ntrailer << /Root 1 0 R /Encrypt 5 0 R >>\n%%EOF";
+        let mut encrypted_ole = OLE_SIGNATURE.to_vec();
+        encrypted_ole.extend_from_slice(&[0; 64]);
+        encrypted_ole.extend_from_slice(OLE_ENCRYPTION_INFO);
+
+        assert_eq!(
+            reason(check_bytes(encrypted_pdf, PDF_MIME_TYPE, &reject)),
+            Some(InputRejection::Encrypted)
+        );
+        assert_eq!(
+            reason(check_bytes(&zip_with_flags(1), "application/zip", &reject)),
+            Some(InputRejection::Encrypted)
+        );
+        assert_eq!(
+            reason(check_bytes(&encrypted_ole, "application/msword", &reject)),
+            Some(InputRejection::Encrypted)
+        );
+        assert_eq!(
+            reason(check_bytes(&zip_with_flags(0), "application/zip", &reject)),
+            None
+        );
+        assert_eq!(reason(check_bytes(b"%PDF-1.7\n%%EOF", PDF_MIME_TYPE, &reject)), None);
+        assert_eq!(
+            reason(check_bytes(encrypted_pdf, PDF_MIME_TYPE, &InputPolicyConfig::default())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_file_measures_before_reading() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.pdf");
+        std::fs::write(&path, b"%PDF-1.7\n%%EOF").unwrap();
+
+        let small = InputPolicyConfig {
+            max_file_size_bytes: Some(4),
+            ..Default::default()
+        };
+        assert_eq!(
+            reason(check_file(&path, PDF_MIME_TYPE, &small)),
+            Some(InputRejection::FileTooLarge)
+        );
+
+        let strict = InputPolicyConfig {
+            reject_mime_mismatch: true,
+            encrypted: EncryptedInputPolicy::Reject,
+            ..Default::default()
+        };
+        assert_eq!(reason(check_file(&path, PDF_MIME_TYPE, &strict)), None);
+        assert!(matches!(
+            check_file(&dir.path().join("missing.pdf"), PDF_MIME_TYPE, &strict),
+            Err(KreuzbergError::Io(_))
+        ));
+    }
+}
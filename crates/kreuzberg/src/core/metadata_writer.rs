@@ -0,0 +1,419 @@
+//! Document metadata write-back.
+//!
+//! Stamps descriptive metadata (title, author, subject, keywords) into documents so
+//! ingestion pipelines can mark files as processed or classified without external
+//! tools such as exiftool.
+//!
+//! Supported formats:
+//! - **PDF** (`pdf` feature): updates the document information dictionary
+//! - **DOCX/XLSX/PPTX** (`office` feature): rewrites `docProps/core.xml`, creating the
+//!   part (and its content-type and relationship entries) when missing
+//!
+//! Fields left as `None` are not touched. All other content is copied unchanged.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{MetadataUpdate, write_metadata};
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let update = MetadataUpdate {
+//!     title: Some("Quarterly Report".to_string()),
+//!     keywords: Some(vec!["processed".to_string(), "finance".to_string()]),
+//!     ..Default::default()
+//! };
+//! write_metadata("report.pdf", &update)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::mime::{
+    DOCX_MIME_TYPE, EXCEL_MACRO_MIME_TYPE, EXCEL_MIME_TYPE, PDF_MIME_TYPE, POWER_POINT_MIME_TYPE, detect_mime_type,
+};
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Metadata fields to write into a document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataUpdate {
+    /// Document title
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Document author (PDF `Author`, OOXML `dc:creator`)
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Document subject
+    #[serde(default)]
+    pub subject: Option<String>,
+    /// Keywords, stored comma-separated
+    #[serde(default)]
+    pub keywords: Option<Vec<String>>,
+}
+
+impl MetadataUpdate {
+    /// Whether the update sets no fields.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.author.is_none() && self.subject.is_none() && self.keywords.is_none()
+    }
+
+    #[cfg(any(feature = "pdf", feature = "office"))]
+    fn keywords_value(&self) -> Option<String> {
+        self.keywords.as_ref().map(|keywords| keywords.join(", "))
+    }
+}
+
+/// Write metadata into a document file in place.
+///
+/// The format is detected from the file extension. The updated document is written
+/// to a temporary file next to the original and renamed over it, so a failure never
+/// leaves a partially written file behind.
+///
+/// # Errors
+///
+/// - `KreuzbergError::Validation` if the file does not exist, the update is empty or the PDF is encrypted
+/// - `KreuzbergError::UnsupportedFormat` for formats other than PDF and OOXML
+/// - `KreuzbergError::MissingDependency` if the required feature is not enabled
+/// - `KreuzbergError::Parsing` if the document cannot be read or rewritten
+pub fn write_metadata(path: impl AsRef<Path>, update: &MetadataUpdate) -> Result<()> {
+    let path = path.as_ref();
+    let mime_type = detect_mime_type(path, true)?;
+    let content = std::fs::read(path)?;
+
+    let updated = write_metadata_to_bytes(&content, &mime_type, update)?;
+
+    let mut temp_name = path.file_name().map(|name| name.to_os_string()).unwrap_or_default();
+    temp_name.push(".kreuzberg-tmp");
+    let temp_path = path.with_file_name(temp_name);
+    std::fs::write(&temp_path, updated)?;
+    std::fs::rename(&temp_path, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&temp_path);
+    })?;
+
+    Ok(())
+}
+
+/// Write metadata into an in-memory document and return the updated bytes.
+///
+/// See [`write_metadata`] for supported formats and errors.
+pub fn write_metadata_to_bytes(content: &[u8], mime_type: &str, update: &MetadataUpdate) -> Result<Vec<u8>> {
+    if update.is_empty() {
+        return Err(KreuzbergError::validation(
+            "Metadata update must set at least one field",
+        ));
+    }
+
+    match mime_type {
+        PDF_MIME_TYPE => write_pdf_metadata(content, update),
+        DOCX_MIME_TYPE | EXCEL_MIME_TYPE | EXCEL_MACRO_MIME_TYPE | POWER_POINT_MIME_TYPE => {
+            write_ooxml_metadata(content, update)
+        }
+        other => Err(KreuzbergError::UnsupportedFormat(format!(
+            "Metadata write-back is not supported for {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(feature = "pdf")]
+fn write_pdf_metadata(content: &[u8], update: &MetadataUpdate) -> Result<Vec<u8>> {
+    use lopdf::{Dictionary, Document, Object, StringFormat};
+
+    fn pdf_text(value: &str) -> Object {
+        if value.is_ascii() {
+            Object::string_literal(value)
+        } else {
+            let mut bytes = vec![0xFE, 0xFF];
+            bytes.extend(value.encode_utf16().flat_map(|unit| unit.to_be_bytes()));
+            Object::String(bytes, StringFormat::Hexadecimal)
+        }
+    }
+
+    let mut document =
+        Document::load_mem(content).map_err(|e| KreuzbergError::parsing(format!("Failed to load PDF: {}", e)))?;
+    if document.is_encrypted() {
+        return Err(KreuzbergError::validation("Cannot write metadata to an encrypted PDF"));
+    }
+
+    let info_id = match document.trailer.get(b"Info") {
+        Ok(Object::Reference(id)) => *id,
+        Ok(Object::Dictionary(info)) => {
+            let info = info.clone();
+            document.add_object(info)
+        }
+        _ => document.add_object(Dictionary::new()),
+    };
+    document.trailer.set("Info", Object::Reference(info_id));
+
+    let info = document
+        .get_object_mut(info_id)
+        .and_then(Object::as_dict_mut)
+        .map_err(|e| KreuzbergError::parsing(format!("Invalid PDF info dictionary: {}", e)))?;
+    let fields = [
+        ("Title", update.title.clone()),
+        ("Author", update.author.clone()),
+        ("Subject", update.subject.clone()),
+        ("Keywords", update.keywords_value()),
+    ];
+    for (key, value) in fields {
+        if let Some(value) = value {
+            info.set(key, pdf_text(&value));
+        }
+    }
+
+    let mut output = Vec::with_capacity(content.len());
+    document
+        .save_to(&mut output)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to write PDF: {}", e)))?;
+    Ok(output)
+}
+
+#[cfg(not(feature = "pdf"))]
+fn write_pdf_metadata(_content: &[u8], _update: &MetadataUpdate) -> Result<Vec<u8>> {
+    Err(KreuzbergError::MissingDependency(
+        "PDF metadata write-back requires the 'pdf' feature".to_string(),
+    ))
+}
+
+#[cfg(feature = "office")]
+const CORE_PROPERTIES_PART: &str = "docProps/core.xml";
+
+#[cfg(feature = "office")]
+const EMPTY_CORE_PROPERTIES: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    "\n",
+    r#"<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" "#,
+    r#"xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" "#,
+    r#"xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"></cp:coreProperties>"#
+);
+
+#[cfg(feature = "office")]
+fn write_ooxml_metadata(content: &[u8], update: &MetadataUpdate) -> Result<Vec<u8>> {
+    use std::io::{Cursor, Read, Write};
+    use zip::write::SimpleFileOptions;
+    use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+    let zip_error =
+        |e: zip::result::ZipError| KreuzbergError::parsing(format!("Failed to rewrite OOXML package: {}", e));
+
+    let mut archive = ZipArchive::new(Cursor::new(content)).map_err(zip_error)?;
+    let has_core = archive.file_names().any(|name| name == CORE_PROPERTIES_PART);
+
+    let mut writer = ZipWriter::new(Cursor::new(Vec::with_capacity(content.len())));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(zip_error)?;
+        let name = entry.name().to_string();
+
+        let rewritten = match name.as_str() {
+            CORE_PROPERTIES_PART => {
+                let mut xml = String::new();
+                entry.read_to_string(&mut xml)?;
+                Some(update_core_properties_xml(&xml, update)?)
+            }
+            "[Content_Types].xml" if !has_core => {
+                let mut xml = String::new();
+                entry.read_to_string(&mut xml)?;
+                Some(insert_before_closing_tag(
+                    &xml,
+                    "</Types>",
+                    r#"<Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>"#,
+                )?)
+            }
+            "_rels/.rels" if !has_core => {
+                let mut xml = String::new();
+                entry.read_to_string(&mut xml)?;
+                Some(insert_before_closing_tag(
+                    &xml,
+                    "</Relationships>",
+                    r#"<Relationship Id="rIdKreuzbergCore" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/>"#,
+                )?)
+            }
+            _ => None,
+        };
+
+        match rewritten {
+            Some(xml) => {
+                drop(entry);
+                writer.start_file(name, options).map_err(zip_error)?;
+                writer.write_all(xml.as_bytes())?;
+            }
+            None => writer.raw_copy_file(entry).map_err(zip_error)?,
+        }
+    }
+
+    if !has_core {
+        writer.start_file(CORE_PROPERTIES_PART, options).map_err(zip_error)?;
+        writer.write_all(update_core_properties_xml(EMPTY_CORE_PROPERTIES, update)?.as_bytes())?;
+    }
+
+    Ok(writer.finish().map_err(zip_error)?.into_inner())
+}
+
+#[cfg(not(feature = "office"))]
+fn write_ooxml_metadata(_content: &[u8], _update: &MetadataUpdate) -> Result<Vec<u8>> {
+    Err(KreuzbergError::MissingDependency(
+        "Office metadata write-back requires the 'office' feature".to_string(),
+    ))
+}
+
+#[cfg(feature = "office")]
+fn insert_before_closing_tag(xml: &str, closing_tag: &str, element: &str) -> Result<String> {
+    let position = xml
+        .rfind(closing_tag)
+        .ok_or_else(|| KreuzbergError::parsing(format!("Malformed OOXML package part: missing {}", closing_tag)))?;
+    Ok(format!("{}{}{}", &xml[..position], element, &xml[position..]))
+}
+
+/// Replace or add `dc:title`, `dc:creator`, `dc:subject` and `cp:keywords` in core properties XML.
+#[cfg(feature = "office")]
+fn update_core_properties_xml(xml: &str, update: &MetadataUpdate) -> Result<String> {
+    use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+    use quick_xml::{Reader, Writer};
+
+    let xml_error = |e: &dyn std::fmt::Display| KreuzbergError::parsing(format!("Invalid core properties XML: {}", e));
+
+    let mut pending: Vec<(&str, String)> = [
+        ("dc:title", update.title.clone()),
+        ("dc:creator", update.author.clone()),
+        ("dc:subject", update.subject.clone()),
+        ("cp:keywords", update.keywords_value()),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.map(|value| (name, value)))
+    .collect();
+
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len() + 256));
+
+    let write_element = |writer: &mut Writer<Vec<u8>>, name: &str, value: &str| -> Result<()> {
+        writer
+            .write_event(Event::Start(BytesStart::new(name)))
+            .and_then(|_| writer.write_event(Event::Text(BytesText::new(value))))
+            .and_then(|_| writer.write_event(Event::End(BytesEnd::new(name))))
+            .map_err(|e| xml_error(&e))
+    };
+
+    loop {
+        let event = reader.read_event().map_err(|e| xml_error(&e))?;
+        match event {
+            Event::Start(ref start) | Event::Empty(ref start)
+                if pending.iter().any(|(name, _)| name.as_bytes() == start.name().as_ref()) =>
+            {
+                let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                if let Event::Start(ref start) = event {
+                    let end = start.to_end().into_owned();
+                    reader.read_to_end(end.name()).map_err(|e| xml_error(&e))?;
+                }
+                let position = pending.iter().position(|(pending_name, _)| *pending_name == name);
+                if let Some(position) = position {
+                    let (_, value) = pending.remove(position);
+                    write_element(&mut writer, &name, &value)?;
+                }
+            }
+            Event::End(ref end) if end.name().as_ref() == b"cp:coreProperties" => {
+                for (name, value) in pending.drain(..) {
+                    write_element(&mut writer, name, &value)?;
+                }
+                writer.write_event(event).map_err(|e| xml_error(&e))?;
+            }
+            Event::Eof => break,
+            other => writer.write_event(other).map_err(|e| xml_error(&e))?,
+        }
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(|e| xml_error(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update() -> MetadataUpdate {
+        MetadataUpdate {
+            title: Some("Processed Report".to_string()),
+            keywords: Some(vec!["processed".to_string(), "finance".to_string()]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_empty_update_rejected() {
+        let result = write_metadata_to_bytes(b"%PDF-1.4", PDF_MIME_TYPE, &MetadataUpdate::default());
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_unsupported_format_rejected() {
+        let result = write_metadata_to_bytes(b"hello", "text/plain", &update());
+        assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat(_))));
+    }
+
+    #[cfg(feature = "office")]
+    #[test]
+    fn test_update_core_properties_replaces_and_adds_fields() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><cp:coreProperties xmlns:cp="cp" xmlns:dc="dc"><dc:title>Old &amp; stale</dc:title><dc:creator>Jane</dc:creator></cp:coreProperties>"#;
+
+        let updated = update_core_properties_xml(xml, &update()).unwrap();
+
+        assert!(updated.contains("<dc:title>Processed Report</dc:title>"));
+        assert!(!updated.contains("stale"));
+        assert!(updated.contains("<dc:creator>Jane</dc:creator>"));
+        assert!(updated.contains("<cp:keywords>processed, finance</cp:keywords></cp:coreProperties>"));
+    }
+
+    #[cfg(feature = "office")]
+    #[test]
+    fn test_ooxml_round_trip_without_core_part() {
+        use crate::extraction::office_metadata::extract_core_properties;
+        use std::io::{Cursor, Write};
+        use zip::ZipArchive;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+        writer.start_file("[Content_Types].xml", options).unwrap();
+        writer.write_all(br#"<Types xmlns="t"></Types>"#).unwrap();
+        writer.start_file("_rels/.rels", options).unwrap();
+        writer
+            .write_all(br#"<Relationships xmlns="r"></Relationships>"#)
+            .unwrap();
+        writer.start_file("word/document.xml", options).unwrap();
+        writer.write_all(b"<w:document/>").unwrap();
+        let package = writer.finish().unwrap().into_inner();
+
+        let updated = write_metadata_to_bytes(&package, DOCX_MIME_TYPE, &update()).unwrap();
+
+        let mut archive = ZipArchive::new(Cursor::new(updated)).unwrap();
+        let core = extract_core_properties(&mut archive).unwrap();
+        assert_eq!(core.title.as_deref(), Some("Processed Report"));
+        assert_eq!(core.keywords.as_deref(), Some("processed, finance"));
+        assert!(archive.by_name("word/document.xml").is_ok());
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_pdf_round_trip_sets_info_dictionary() {
+        use lopdf::{Document, Object, dictionary};
+
+        let mut document = Document::with_version("1.5");
+        let pages_id =
+            document.add_object(dictionary! { "Type" => "Pages", "Kids" => Vec::<Object>::new(), "Count" => 0i64 });
+        let catalog_id = document.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+        document.trailer.set("Root", catalog_id);
+        let mut pdf = Vec::new();
+        document.save_to(&mut pdf).unwrap();
+
+        let mut update = update();
+        update.author = Some("Zoë".to_string());
+        let updated = write_metadata_to_bytes(&pdf, PDF_MIME_TYPE, &update).unwrap();
+
+        let reloaded = Document::load_mem(&updated).unwrap();
+        let info_id = reloaded.trailer.get(b"Info").and_then(Object::as_reference).unwrap();
+        let info = reloaded.get_dictionary(info_id).unwrap();
+        assert_eq!(info.get(b"Title").unwrap().as_str().unwrap(), b"Processed Report");
+        assert_eq!(info.get(b"Keywords").unwrap().as_str().unwrap(), b"processed, finance");
+        assert_eq!(&info.get(b"Author").unwrap().as_str().unwrap()[..2], &[0xFE, 0xFF]);
+    }
+}
@@ -20,6 +20,7 @@ pub const LEGACY_POWERPOINT_MIME_TYPE: &str = "application/vnd.ms-powerpoint";
 pub const EML_MIME_TYPE: &str = "message/rfc822";
 pub const MSG_MIME_TYPE: &str = "application/vnd.ms-outlook";
 pub const JSON_MIME_TYPE: &str = "application/json";
+pub const JUPYTER_MIME_TYPE: &str = "application/x-ipynb+json";
 pub const YAML_MIME_TYPE: &str = "application/x-yaml";
 pub const TOML_MIME_TYPE: &str = "application/toml";
 pub const XML_MIME_TYPE: &str = "application/xml";
@@ -101,9 +102,11 @@ static EXT_TO_MIME: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     m.insert("rst", "text/x-rst");
     m.insert("org", "text/x-org");
     m.insert("epub", "application/epub+zip");
+    m.insert("mobi", "application/x-mobipocket-ebook");
+    m.insert("azw", "application/vnd.amazon.ebook");
     m.insert("rtf", "application/rtf");
     m.insert("bib", "application/x-bibtex");
-    m.insert("ipynb", "application/x-ipynb+json");
+    m.insert("ipynb", JUPYTER_MIME_TYPE);
     m.insert("tex", "application/x-latex");
     m.insert("latex", "application/x-latex");
     m.insert("typst", "application/x-typst");
@@ -149,9 +152,11 @@ static SUPPORTED_MIME_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     set.insert("application/x-bibtex");
     set.insert("application/x-endnote+xml");
     set.insert("application/x-fictionbook+xml");
-    set.insert("application/x-ipynb+json");
+    set.insert(JUPYTER_MIME_TYPE);
     set.insert("application/x-jats+xml");
     set.insert("application/x-latex");
+    set.insert("application/x-mobipocket-ebook");
+    set.insert("application/vnd.amazon.ebook");
     set.insert("application/xml+opml");
     set.insert("application/x-opml+xml");
     set.insert("application/x-research-info-systems");
@@ -341,8 +346,11 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
         let trimmed = text.trim_start();
 
         if (trimmed.starts_with('{') || trimmed.starts_with('['))
-            && serde_json::from_str::<serde_json::Value>(text).is_ok()
+            && let Ok(value) = serde_json::from_str::<serde_json::Value>(text)
         {
+            if is_jupyter_notebook(&value) {
+                return Ok(JUPYTER_MIME_TYPE.to_string());
+            }
             return Ok(JSON_MIME_TYPE.to_string());
         }
 
@@ -366,6 +374,11 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
     ))
 }
 
+/// Whether a JSON document is a Jupyter notebook (top-level `cells` array and `nbformat`).
+fn is_jupyter_notebook(value: &serde_json::Value) -> bool {
+    value.get("cells").is_some_and(|cells| cells.is_array()) && value.get("nbformat").is_some()
+}
+
 /// Get file extensions for a given MIME type.
 ///
 /// Returns all known file extensions that map to the specified MIME type.
@@ -492,6 +505,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_mime_type_from_bytes_jupyter_notebook() {
+        let notebook = br#"{"cells": [], "metadata": {}, "nbformat": 4, "nbformat_minor": 5}"#;
+        assert_eq!(detect_mime_type_from_bytes(notebook).unwrap(), JUPYTER_MIME_TYPE);
+
+        let plain_json = br#"{"cells": [1, 2, 3]}"#;
+        assert_eq!(detect_mime_type_from_bytes(plain_json).unwrap(), JSON_MIME_TYPE);
+    }
+
     #[test]
     fn test_detect_mime_type_text_formats() {
         let dir = tempdir().unwrap();
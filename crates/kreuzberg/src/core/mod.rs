@@ -13,6 +13,18 @@
 //! - **Pipeline**: Orchestrating post-processing steps (chunking, quality, etc.)
 //! - **Configuration**: Loading and managing extraction configuration
 //! - **I/O**: File reading and validation utilities
+//! - **Metadata write-back**: Stamping title, author, subject and keywords into PDF/OOXML files
+//! - **Result schema**: Versioning and migrating serialized results stored by older releases
+//! - **Incremental extraction**: Re-extracting only the new and changed pages of a PDF
+//! - **Input policy**: Size, MIME type, signature and encryption checks before extraction
+//! - **Watchdog**: Time budgets that abort extraction stages running too long
+//! - **Retry**: Exponential backoff for batch documents that failed for a transient reason
+//! - **Runtime**: The shared Tokio runtime of the sync wrappers, with explicit init and shutdown
+//! - **Resource profiles**: Turning off expensive features in low-memory containers
+//! - **Overrides**: Configuration sections scoped to MIME types, merged at dispatch time
+//! - **Counters**: Process-wide OCR page and result cache counters for embedders
+//! - **Presets**: Complete configurations for common workloads (RAG ingestion, archival OCR, previews)
+//! - **Estimates**: Dry-run time and memory predictions for routing documents to workers
 //!
 //! # Example
 //!
@@ -32,28 +44,54 @@
 pub(crate) mod batch_mode;
 #[cfg(feature = "tokio-runtime")]
 pub mod batch_optimizations;
+#[cfg(feature = "tokio-runtime")]
+pub(crate) mod concurrency;
 pub mod config;
+pub mod config_schema;
 pub mod config_validation;
+pub mod counters;
+pub mod effective_config;
+pub mod estimate;
 pub mod extractor;
+pub mod fields;
 pub mod formats;
+#[cfg(all(feature = "pdf", feature = "tokio-runtime"))]
+pub mod incremental;
+pub mod input_policy;
 pub mod io;
+pub mod metadata_writer;
 pub mod mime;
+pub mod overrides;
 pub mod pipeline;
+pub mod presets;
+pub mod resource_profile;
+pub mod result_schema;
+#[cfg(feature = "tokio-runtime")]
+pub mod retry;
+#[cfg(feature = "tokio-runtime")]
+pub mod runtime;
+#[cfg(feature = "tokio-runtime")]
+pub mod watchdog;
 
 pub use config::{
-    ChunkingConfig, ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, OcrConfig, TokenReductionConfig,
+    CacheBackendKind, CacheConfig, ChunkingConfig, ClassificationConfig, CleanupConfig, DocumentTypeRule,
+    EncryptedInputPolicy, ExtractionConfig, ImageExtractionConfig, InputPolicyConfig, KeyValueExtractionConfig,
+    LanguageDetectionConfig, OcrConfig, OcrConfusionContext, OcrConfusionRule, OcrCorrectionConfig, OcrFallbackConfig,
+    OcrVocabularyConfig, PreviewConfig, ResourceProfile, ResultCompression, RetryConfig, RetryableError,
+    StageTimeoutConfig, TokenReductionConfig, WatermarkConfig,
 };
 pub use config_validation::{
     validate_binarization_method, validate_chunking_params, validate_confidence, validate_dpi, validate_language_code,
     validate_ocr_backend, validate_output_format, validate_tesseract_oem, validate_tesseract_psm,
-    validate_token_reduction_level,
+    validate_tesseract_variables, validate_token_reduction_level,
 };
 pub use formats::{KNOWN_FORMATS, is_valid_format_field};
+pub use presets::{ConfigPreset, preset_config};
 
 #[cfg(feature = "tokio-runtime")]
 pub use batch_optimizations::{BatchProcessor, BatchProcessorConfig};
 #[cfg(feature = "pdf")]
 pub use config::PdfConfig;
 #[cfg(feature = "tokio-runtime")]
-pub use extractor::{batch_extract_bytes, batch_extract_file};
+pub use extractor::{batch_extract_bytes, batch_extract_file, batch_extract_file_unordered};
 pub use extractor::{extract_bytes, extract_file};
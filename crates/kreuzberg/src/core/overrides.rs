@@ -0,0 +1,308 @@
+//! Per-format configuration overrides.
+//!
+//! [`ExtractionConfig::overrides`] maps MIME patterns to partial configurations that are
+//! merged over the base configuration when a document of a matching type is dispatched, so
+//! a mixed corpus can be processed in one batch instead of one pass per format:
+//!
+//! ```toml
+//! force_ocr = true
+//!
+//! [ocr]
+//! language = "eng"
+//!
+//! [overrides."application/pdf"]
+//! force_ocr = false
+//!
+//! [overrides."image/*"]
+//! ocr.language = "deu"
+//! ```
+//!
+//! Patterns are exact MIME types (`application/pdf`), type wildcards (`image/*`) or `*/*`.
+//! All matching overrides are applied, from the least to the most specific pattern, so an
+//! exact type wins over `image/*`, which wins over `*/*`. Objects are merged key by key and
+//! any other value (including arrays) replaces the base value; `null` disables an optional
+//! section. Overrides cannot be nested.
+//!
+//! [`extract_file`](crate::extract_file) and [`extract_bytes`](crate::extract_bytes) resolve
+//! the overrides right after MIME detection, before the resource profile, the input policy
+//! and the cache lookup, so cached results are keyed by the resolved configuration.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::config::ExtractionConfig;
+//! use kreuzberg::core::overrides::resolve_for_mime;
+//! use serde_json::json;
+//!
+//! let config: ExtractionConfig = serde_json::from_value(json!({
+//!     "force_ocr": true,
+//!     "overrides": {"application/pdf": {"force_ocr": false}}
+//! }))?;
+//!
+//! assert!(!resolve_for_mime(&config, "application/pdf")?.force_ocr);
+//! assert!(resolve_for_mime(&config, "image/png")?.force_ocr);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::{KreuzbergError, Result};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// The configuration to extract a document of type `mime_type` with.
+///
+/// Returns `config` unchanged when no override pattern matches. The resolved copy has no
+/// `overrides` of its own, so nested extractions (archive members) use it as given.
+///
+/// # Errors
+///
+/// Returns [`KreuzbergError::Validation`] when a matching override is not an object,
+/// nests `overrides`, or does not produce a valid configuration.
+pub fn resolve_for_mime<'a>(config: &'a ExtractionConfig, mime_type: &str) -> Result<Cow<'a, ExtractionConfig>> {
+    let Some(overrides) = config.overrides.as_ref() else {
+        return Ok(Cow::Borrowed(config));
+    };
+
+    let mut matching: Vec<(&String, &Value)> = overrides
+        .iter()
+        .filter(|(pattern, _)| pattern_matches(pattern, mime_type))
+        .collect();
+    if matching.is_empty() {
+        return Ok(Cow::Borrowed(config));
+    }
+    // Stable sort: patterns of equal specificity keep their (alphabetical) map order
+    matching.sort_by_key(|(pattern, _)| specificity(pattern));
+
+    let mut document = base_document(config)?;
+    for (pattern, section) in matching {
+        check_section(pattern, section)?;
+        merge(&mut document, section);
+    }
+
+    let mut resolved: ExtractionConfig = serde_json::from_value(document).map_err(|e| {
+        KreuzbergError::validation(format!("Invalid configuration overrides for '{}': {}", mime_type, e))
+    })?;
+    restore_skipped_fields(&mut resolved, config);
+
+    Ok(Cow::Owned(resolved))
+}
+
+/// Check that every override of `config` produces a valid configuration on its own.
+///
+/// Called by the config file loaders so a broken override is reported when the file is
+/// loaded rather than when the first matching document is extracted.
+///
+/// # Errors
+///
+/// Returns [`KreuzbergError::Validation`] naming the first invalid pattern or section.
+pub fn validate_overrides(config: &ExtractionConfig) -> Result<()> {
+    let Some(overrides) = config.overrides.as_ref() else {
+        return Ok(());
+    };
+    if overrides.is_empty() {
+        return Ok(());
+    }
+
+    let base = base_document(config)?;
+    for (pattern, section) in overrides {
+        check_pattern(pattern)?;
+        check_section(pattern, section)?;
+
+        let mut document = base.clone();
+        merge(&mut document, section);
+        serde_json::from_value::<ExtractionConfig>(document).map_err(|e| {
+            KreuzbergError::validation(format!("Invalid configuration override for '{}': {}", pattern, e))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Whether the MIME `pattern` (`type/subtype`, `type/*` or `*/*`) matches `mime_type`.
+///
+/// Matching ignores case and MIME parameters (`text/plain; charset=utf-8`).
+pub fn pattern_matches(pattern: &str, mime_type: &str) -> bool {
+    let pattern = essence(pattern);
+    let mime_type = essence(mime_type);
+
+    if pattern == "*/*" || pattern == "*" {
+        return true;
+    }
+    match pattern.strip_suffix("/*") {
+        Some(type_) => mime_type
+            .split_once('/')
+            .is_some_and(|(mime_type_, _)| mime_type_.eq_ignore_ascii_case(type_)),
+        None => pattern.eq_ignore_ascii_case(mime_type),
+    }
+}
+
+/// MIME type without parameters or surrounding whitespace.
+fn essence(mime_type: &str) -> &str {
+    mime_type.split(';').next().unwrap_or_default().trim()
+}
+
+/// 0 for `*/*`, 1 for `type/*`, 2 for an exact type.
+fn specificity(pattern: &str) -> u8 {
+    match essence(pattern) {
+        "*/*" | "*" => 0,
+        pattern if pattern.ends_with("/*") => 1,
+        _ => 2,
+    }
+}
+
+fn check_pattern(pattern: &str) -> Result<()> {
+    let valid = match essence(pattern).split_once('/') {
+        Some((type_, subtype)) => !type_.is_empty() && !subtype.is_empty() && (type_ != "*" || subtype == "*"),
+        None => essence(pattern) == "*",
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(KreuzbergError::validation(format!(
+            "Invalid override pattern '{}': expected 'type/subtype', 'type/*' or '*/*'",
+            pattern
+        )))
+    }
+}
+
+fn check_section(pattern: &str, section: &Value) -> Result<()> {
+    let Some(section) = section.as_object() else {
+        return Err(KreuzbergError::validation(format!(
+            "Configuration override for '{}' must be a table of config fields",
+            pattern
+        )));
+    };
+    if section.contains_key("overrides") {
+        return Err(KreuzbergError::validation(format!(
+            "Configuration override for '{}' cannot contain 'overrides'",
+            pattern
+        )));
+    }
+    Ok(())
+}
+
+/// `config` as a JSON document, without its overrides.
+fn base_document(config: &ExtractionConfig) -> Result<Value> {
+    let mut document = serde_json::to_value(config)
+        .map_err(|e| KreuzbergError::validation(format!("Failed to serialize configuration: {}", e)))?;
+    if let Some(object) = document.as_object_mut() {
+        object.remove("overrides");
+    }
+    Ok(document)
+}
+
+/// Merge `patch` into `target`: objects key by key, everything else replaced.
+pub(crate) fn merge(target: &mut Value, patch: &Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                merge(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+        (target, patch) => *target = patch.clone(),
+    }
+}
+
+/// Carry over the fields that do not survive a serde round trip.
+pub(crate) fn restore_skipped_fields(resolved: &mut ExtractionConfig, original: &ExtractionConfig) {
+    #[cfg(feature = "html")]
+    {
+        resolved.html_options = original.html_options.clone();
+    }
+    #[cfg(not(feature = "html"))]
+    let _ = original;
+
+    if let Some(postprocessor) = resolved.postprocessor.as_mut() {
+        postprocessor.build_lookup_sets();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(value: Value) -> ExtractionConfig {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_pattern_matches() {
+        assert!(pattern_matches("application/pdf", "application/pdf"));
+        assert!(pattern_matches("Application/PDF", "application/pdf"));
+        assert!(pattern_matches("image/*", "image/png"));
+        assert!(pattern_matches("*/*", "text/html"));
+        assert!(pattern_matches("text/plain", "text/plain; charset=utf-8"));
+        assert!(!pattern_matches("image/*", "application/pdf"));
+        assert!(!pattern_matches("application/pdf", "application/zip"));
+    }
+
+    #[test]
+    fn test_resolve_without_match_borrows_config() {
+        let base = config(json!({"overrides": {"application/pdf": {"force_ocr": true}}}));
+
+        let resolved = resolve_for_mime(&base, "text/plain").unwrap();
+
+        assert!(matches!(resolved, Cow::Borrowed(_)));
+        assert!(!resolved.force_ocr);
+    }
+
+    #[test]
+    fn test_resolve_merges_nested_sections() {
+        let base = config(json!({
+            "ocr": {"backend": "tesseract", "language": "eng"},
+            "overrides": {"image/*": {"ocr": {"language": "deu"}}}
+        }));
+
+        let resolved = resolve_for_mime(&base, "image/png").unwrap();
+
+        let ocr = resolved.ocr.as_ref().unwrap();
+        assert_eq!(ocr.language, "deu");
+        assert_eq!(ocr.backend, "tesseract");
+        assert!(resolved.overrides.is_none());
+    }
+
+    #[test]
+    fn test_more_specific_patterns_win() {
+        let base = config(json!({
+            "overrides": {
+                "*/*": {"chunking": {"max_chars": 500}},
+                "image/*": {"chunking": {"max_chars": 800}},
+                "image/png": {"chunking": {"max_chars": 1200}}
+            }
+        }));
+
+        let chunking = |mime: &str| resolve_for_mime(&base, mime).unwrap().chunking.clone().unwrap();
+
+        assert_eq!(chunking("image/png").max_chars, 1200);
+        assert_eq!(chunking("image/jpeg").max_chars, 800);
+        assert_eq!(chunking("text/plain").max_chars, 500);
+    }
+
+    #[test]
+    fn test_null_disables_section() {
+        let base = config(json!({
+            "chunking": {"max_chars": 1000},
+            "overrides": {"text/csv": {"chunking": null}}
+        }));
+
+        assert!(resolve_for_mime(&base, "text/csv").unwrap().chunking.is_none());
+    }
+
+    #[test]
+    fn test_invalid_overrides_are_validation_errors() {
+        let not_a_table = config(json!({"overrides": {"application/pdf": true}}));
+        let nested = config(json!({"overrides": {"*/*": {"overrides": {}}}}));
+        let bad_value = config(json!({"overrides": {"image/*": {"force_ocr": "yes"}}}));
+        let bad_pattern = config(json!({"overrides": {"pdf": {"force_ocr": true}}}));
+
+        assert!(resolve_for_mime(&not_a_table, "application/pdf").is_err());
+        assert!(resolve_for_mime(&nested, "text/plain").is_err());
+        assert!(resolve_for_mime(&bad_value, "image/png").is_err());
+        for invalid in [&not_a_table, &nested, &bad_value, &bad_pattern] {
+            let err = validate_overrides(invalid).unwrap_err();
+            assert!(matches!(err, KreuzbergError::Validation { .. }));
+        }
+        assert!(validate_overrides(&ExtractionConfig::default()).is_ok());
+    }
+}
@@ -3,38 +3,37 @@
 //! This module orchestrates the post-processing pipeline, executing validators,
 //! quality processing, chunking, and custom hooks in the correct order.
 
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{ExtractionConfig, PostProcessorConfig};
+use crate::plugins::registry::PostProcessorRegistry;
 use crate::plugins::{PostProcessor, ProcessingStage};
 use crate::types::ExtractionResult;
 use crate::{KreuzbergError, Result};
+use arc_swap::ArcSwapOption;
 use once_cell::sync::Lazy;
 use std::sync::Arc;
-use std::sync::RwLock as StdRwLock;
 
-/// Cached post-processors for each stage to reduce lock contention.
+/// Post-processors of one registry snapshot, split by stage.
 ///
-/// This cache is populated once during the first pipeline run and reused
-/// for all subsequent extractions, eliminating 3 of 4 registry lock acquisitions
-/// per extraction.
+/// Built once per registry snapshot and reused until a registration publishes a new one,
+/// so extractions against an unchanged registry skip re-sorting the processors.
 struct ProcessorCache {
+    registry: Arc<PostProcessorRegistry>,
     early: Arc<Vec<Arc<dyn PostProcessor>>>,
     middle: Arc<Vec<Arc<dyn PostProcessor>>>,
     late: Arc<Vec<Arc<dyn PostProcessor>>>,
+    chunk: Arc<Vec<Arc<dyn PostProcessor>>>,
 }
 
 impl ProcessorCache {
-    /// Create a new processor cache by fetching from the registry.
-    fn new() -> Result<Self> {
-        let processor_registry = crate::plugins::registry::get_post_processor_registry();
-        let registry = processor_registry
-            .read()
-            .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
-
-        Ok(Self {
+    /// Split the processors of `registry` by stage.
+    fn new(registry: Arc<PostProcessorRegistry>) -> Self {
+        Self {
             early: Arc::new(registry.get_for_stage(ProcessingStage::Early)),
             middle: Arc::new(registry.get_for_stage(ProcessingStage::Middle)),
             late: Arc::new(registry.get_for_stage(ProcessingStage::Late)),
-        })
+            chunk: Arc::new(registry.get_for_stage(ProcessingStage::Chunk)),
+            registry,
+        }
     }
 
     /// Get processors for a specific stage from cache.
@@ -44,30 +43,353 @@ impl ProcessorCache {
             ProcessingStage::Early => Arc::clone(&self.early),
             ProcessingStage::Middle => Arc::clone(&self.middle),
             ProcessingStage::Late => Arc::clone(&self.late),
+            ProcessingStage::Chunk => Arc::clone(&self.chunk),
         }
     }
 }
 
-/// Lazy processor cache - initialized on first use, then cached.
-static PROCESSOR_CACHE: Lazy<StdRwLock<Option<ProcessorCache>>> = Lazy::new(|| StdRwLock::new(None));
+/// Processors of the most recently seen post-processor registry snapshot.
+static PROCESSOR_CACHE: Lazy<ArcSwapOption<ProcessorCache>> = Lazy::new(ArcSwapOption::empty);
+
+/// The processors of the current post-processor registry, rebuilt when the registry changed.
+fn current_processors() -> Arc<ProcessorCache> {
+    let registry = crate::plugins::registry::get_post_processor_registry().snapshot();
+    if let Some(cache) = PROCESSOR_CACHE.load_full()
+        && Arc::ptr_eq(&cache.registry, &registry)
+    {
+        return cache;
+    }
+
+    let cache = Arc::new(ProcessorCache::new(registry));
+    PROCESSOR_CACHE.store(Some(Arc::clone(&cache)));
+    cache
+}
 
-/// Clear the processor cache (primarily for testing when registry changes).
+/// Clear the processor cache.
+///
+/// Registry changes are picked up automatically; this only drops the cached processors.
 #[allow(dead_code)]
 pub fn clear_processor_cache() -> Result<()> {
-    let mut cache = PROCESSOR_CACHE
-        .write()
-        .map_err(|e| crate::KreuzbergError::Other(format!("Processor cache lock poisoned: {}", e)))?;
-    *cache = None;
+    PROCESSOR_CACHE.store(None);
+    Ok(())
+}
+
+/// Names of the post-processors that ship with Kreuzberg and register themselves when their
+/// feature is enabled, as opposed to plugins registered by the application.
+pub const BUILTIN_POST_PROCESSORS: &[&str] = &[
+    "document-classification",
+    "key-value-extraction",
+    "document-preview",
+    "quality-processing",
+    "ocr-correction",
+    "keyword-extraction",
+    "language-detection",
+    "text-chunking",
+];
+
+/// Register the built-in post-processors that are not registered yet.
+///
+/// Checked on every pipeline run so the built-ins come back after a registry reset, but the
+/// registry is only updated when one is missing.
+fn ensure_builtin_processors() {
+    let mut builtins: Vec<(Arc<dyn PostProcessor>, i32)> = vec![
+        // Below OCR correction, so corrected text is classified
+        (Arc::new(crate::text::DocumentClassificationProcessor), 40),
+        // Between OCR correction and classification, so pairs are read from corrected text
+        (Arc::new(crate::text::KeyValueExtractionProcessor), 45),
+        (Arc::new(crate::text::PreviewProcessor), 10),
+    ];
+    #[cfg(feature = "quality")]
+    builtins.push((Arc::new(crate::text::QualityProcessor), 30));
+    #[cfg(feature = "ocr")]
+    builtins.push((Arc::new(crate::ocr::correction::OcrCorrectionProcessor), 50));
+
+    let registry = crate::plugins::registry::get_post_processor_registry();
+    let registered = registry.snapshot().list();
+    builtins.retain(|(processor, _)| !registered.iter().any(|name| name == processor.name()));
+    if builtins.is_empty() {
+        return;
+    }
+
+    let _ = registry.update(|reg| {
+        for (processor, priority) in builtins {
+            let _ = reg.register(processor, priority);
+        }
+        Ok(())
+    });
+}
+
+/// Perceptually hash extracted images and drop near-duplicates as configured in `config.images`.
+///
+/// Duplicates are removed from both the document-level images and the per-page images, and
+/// their count is recorded in `metadata.additional["duplicate_images_removed"]`.
+fn hash_images(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(images_config) = config.images.as_ref().filter(|images| images.hashes_images()) else {
+        return;
+    };
+
+    #[cfg(feature = "ocr")]
+    {
+        let Some(images) = result.images.as_mut() else {
+            return;
+        };
+
+        crate::image::assign_perceptual_hashes(images);
+        let removed = if images_config.deduplicate_images {
+            crate::image::deduplicate_images(images, images_config.duplicate_hash_distance)
+        } else {
+            Vec::new()
+        };
+
+        let hashes: std::collections::HashMap<usize, String> = images
+            .iter()
+            .filter_map(|image| Some((image.image_index, image.perceptual_hash.clone()?)))
+            .collect();
+
+        // Page images are copies of the document-level images, matched by image_index
+        for page in result.pages.iter_mut().flatten() {
+            page.images.retain(|image| !removed.contains(&image.image_index));
+            for image in page.images.iter_mut() {
+                if image.perceptual_hash.is_none()
+                    && let Some(hash) = hashes.get(&image.image_index)
+                {
+                    Arc::make_mut(image).perceptual_hash = Some(hash.clone());
+                }
+            }
+        }
+
+        if images_config.deduplicate_images {
+            result.metadata.additional.insert(
+                "duplicate_images_removed".to_string(),
+                serde_json::Value::from(removed.len()),
+            );
+        }
+    }
+
+    #[cfg(not(feature = "ocr"))]
+    {
+        let _ = images_config;
+        result.metadata.additional.insert(
+            "image_hash_error".to_string(),
+            serde_json::Value::String("Image hashing requires the ocr feature".to_string()),
+        );
+    }
+}
+
+/// Report watermarks and stamp words in `metadata.watermarks` when `config.watermarks` is
+/// set, before cleanup so stamps repeated on every page are still in the content.
+fn detect_watermarks(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if let Some(watermarks) = config.watermarks.as_ref() {
+        crate::text::detect_watermarks(result, watermarks);
+    }
+}
+
+/// Attach figure captions to the extracted images and list them in `metadata.figures` when
+/// `config.images` is set, after deduplication so captions go to the images that are kept.
+fn attach_figures(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if config.images.is_some() {
+        crate::text::attach_figure_captions(result);
+    }
+}
+
+/// Remove or tag the text repeated across pages when `config.cleanup` is set, before
+/// post-processors and chunking see the content.
+fn cleanup_content(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if let Some(cleanup) = config.cleanup.as_ref() {
+        crate::text::strip_repeated_text(result, cleanup);
+    }
+}
+
+/// Apply the `config.normalization` profile to the content before chunking.
+///
+/// Failures keep the content as extracted and are recorded in
+/// `metadata.additional["normalization_error"]`.
+fn normalize_content(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(normalization) = config.normalization.as_ref() else {
+        return;
+    };
+
+    if let Err(e) = crate::text::normalize_result(result, normalization) {
+        result.metadata.additional.insert(
+            "normalization_error".to_string(),
+            serde_json::Value::String(e.to_string()),
+        );
+    }
+}
+
+/// Record the byte offset and page of every term in the content when `config.emit_term_offsets` is set.
+fn emit_term_offsets(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if !config.emit_term_offsets {
+        return;
+    }
+
+    let boundaries = result
+        .metadata
+        .pages
+        .as_ref()
+        .and_then(|pages| pages.boundaries.as_deref());
+    result.term_offsets = Some(crate::text::compute_term_offsets(&result.content, boundaries));
+}
+
+/// Attribute every byte range of the content to its source when `config.emit_provenance` is set.
+fn emit_provenance(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if !config.emit_provenance {
+        return;
+    }
+
+    let known = crate::text::provenance::known_spans(result);
+    result.provenance = Some(crate::text::compute_provenance(&result.content, &known));
+}
+
+/// Re-encode and downscale extracted images as configured in `config.images`.
+///
+/// Decoding or encoding failures keep the original image and are recorded in
+/// `metadata.additional["image_transcode_error"]` instead of failing the extraction.
+fn transcode_images(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(images_config) = config.images.as_ref().filter(|images| images.transcodes_images()) else {
+        return;
+    };
+
+    #[cfg(feature = "ocr")]
+    {
+        let mut errors = Vec::new();
+        for image in result.images.iter_mut().flatten() {
+            if let Err(e) = crate::image::transcode_image(image, images_config) {
+                errors.push(format!("image {}: {}", image.image_index, e));
+            }
+        }
+
+        // Page images are copies of the document-level images, so their errors are already recorded
+        for page in result.pages.iter_mut().flatten() {
+            for image in page.images.iter_mut() {
+                let _ = crate::image::transcode_image(Arc::make_mut(image), images_config);
+            }
+        }
+
+        if !errors.is_empty() {
+            result.metadata.additional.insert(
+                "image_transcode_error".to_string(),
+                serde_json::Value::String(errors.join("; ")),
+            );
+        }
+    }
+
+    #[cfg(not(feature = "ocr"))]
+    {
+        let _ = images_config;
+        result.metadata.additional.insert(
+            "image_transcode_error".to_string(),
+            serde_json::Value::String("Image encoding requires the ocr feature".to_string()),
+        );
+    }
+}
+
+/// Move image bytes into the content-addressed blob store when `images.blob_store_dir` is set.
+fn store_image_blobs(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(dir) = config
+        .images
+        .as_ref()
+        .and_then(|images| images.blob_store_dir.as_deref())
+    else {
+        return;
+    };
+
+    let errors = crate::cache::blob_store::store_result_images(result, dir);
+    if !errors.is_empty() {
+        result.metadata.additional.insert(
+            "image_blob_error".to_string(),
+            serde_json::Value::String(errors.join("; ")),
+        );
+    }
+}
+
+/// Write page contents and images beyond `spill_threshold_bytes` to `spill_dir`.
+///
+/// Runs last so processors and validators still see the full result.
+fn spill_to_disk(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(dir) = config.spill_dir.as_deref() else {
+        return;
+    };
+    let threshold = config
+        .spill_threshold_bytes
+        .unwrap_or(crate::cache::blob_store::DEFAULT_SPILL_THRESHOLD_BYTES);
+
+    let errors = crate::cache::blob_store::spill_result(result, dir, threshold);
+    if !errors.is_empty() {
+        result
+            .metadata
+            .additional
+            .insert("spill_error".to_string(), serde_json::Value::String(errors.join("; ")));
+    }
+}
+
+/// Whether the post-processor configuration lets `processor_name` run.
+fn processor_enabled(pp_config: Option<&PostProcessorConfig>, processor_name: &str) -> bool {
+    let Some(config) = pp_config else {
+        return true;
+    };
+
+    // Use O(1) HashSet lookups if available
+    if let Some(ref enabled_set) = config.enabled_set {
+        enabled_set.contains(processor_name)
+    } else if let Some(ref disabled_set) = config.disabled_set {
+        !disabled_set.contains(processor_name)
+    } else if let Some(ref enabled) = config.enabled_processors {
+        // Fallback to O(n) Vec search if HashSet not built yet
+        enabled.iter().any(|name| name == processor_name)
+    } else if let Some(ref disabled) = config.disabled_processors {
+        // Fallback to O(n) Vec search if HashSet not built yet
+        !disabled.iter().any(|name| name == processor_name)
+    } else {
+        true
+    }
+}
+
+/// Run post-processors in order, recording non-fatal errors in metadata.
+async fn run_processors(
+    processors: &[Arc<dyn PostProcessor>],
+    result: &mut ExtractionResult,
+    config: &ExtractionConfig,
+) -> Result<()> {
+    for processor in processors {
+        let processor_name = processor.name();
+
+        if processor_enabled(config.postprocessor.as_ref(), processor_name) && processor.should_process(result, config)
+        {
+            match processor.process(result, config).await {
+                Ok(_) => {}
+                Err(err @ KreuzbergError::Io(_))
+                | Err(err @ KreuzbergError::LockPoisoned(_))
+                | Err(err @ KreuzbergError::Plugin { .. }) => {
+                    return Err(err);
+                }
+                Err(err) => {
+                    result.metadata.additional.insert(
+                        format!("processing_error_{processor_name}"),
+                        serde_json::Value::String(err.to_string()),
+                    );
+                }
+            }
+        }
+    }
     Ok(())
 }
 
 /// Run the post-processing pipeline on an extraction result.
 ///
 /// Executes post-processing in the following order:
-/// 1. Post-Processors - Execute by stage (Early, Middle, Late) to modify/enhance the result
-/// 2. Quality Processing - Text cleaning and quality scoring
-/// 3. Chunking - Text splitting if enabled
-/// 4. Validators - Run validation hooks on the processed result (can fail fast)
+/// 1. Watermarks - Watermark and stamp detection if configured
+/// 2. Cleanup - Repeated header, footer and page number removal if configured
+/// 3. Post-Processors - Execute by stage (Early, Middle, Late) to modify/enhance the result
+/// 4. Quality Processing - Text cleaning and quality scoring
+/// 5. Normalization - Deterministic content normalization if configured
+/// 6. Chunking - Text splitting if enabled, followed by Chunk-stage post-processors and
+///    chunk embeddings
+/// 7. Image Hashing - Perceptually hash and deduplicate extracted images if configured
+/// 8. Figure Captions - Attach captions to extracted images if image extraction is configured
+/// 9. Image Encoding - Re-encode and downscale extracted images if configured
+/// 10. Image Blob Store - Move image bytes to the content-addressed blob store if configured
+/// 11. Validators - Run validation hooks on the processed result (can fail fast)
 ///
 /// # Arguments
 ///
@@ -91,8 +413,12 @@ pub fn clear_processor_cache() -> Result<()> {
     )
 ))]
 pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
-    let pp_config = config.postprocessor.as_ref();
-    let postprocessing_enabled = pp_config.is_none_or(|c| c.enabled);
+    detect_watermarks(&mut result, config);
+    cleanup_content(&mut result, config);
+
+    let postprocessing_enabled = config.postprocessor.as_ref().is_none_or(|c| c.enabled);
+    #[cfg(feature = "chunking")]
+    let mut chunk_processors = None;
 
     if postprocessing_enabled {
         #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
@@ -110,87 +436,44 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
             let _ = crate::chunking::ensure_initialized();
         }
 
-        #[cfg(feature = "quality")]
-        {
-            let registry = crate::plugins::registry::get_post_processor_registry();
-            if let Ok(mut reg) = registry.write() {
-                let _ = reg.register(std::sync::Arc::new(crate::text::QualityProcessor), 30);
-            }
-        }
+        ensure_builtin_processors();
 
-        // Initialize cache if needed (only happens once, amortized over all extractions)
+        let cache = current_processors();
+        for processors in [&cache.early, &cache.middle, &cache.late] {
+            run_processors(processors, &mut result, config).await?;
+        }
+        #[cfg(feature = "chunking")]
         {
-            let mut cache_lock = PROCESSOR_CACHE
-                .write()
-                .map_err(|e| crate::KreuzbergError::Other(format!("Processor cache lock poisoned: {}", e)))?;
-            if cache_lock.is_none() {
-                *cache_lock = Some(ProcessorCache::new()?);
-            }
+            chunk_processors = Some(Arc::clone(&cache.chunk));
         }
+    }
 
-        // Get cached processors without acquiring registry locks
-        // Extract the Arc<Vec> pointers before the loop to avoid holding the lock across await points
-        let (early_processors, middle_processors, late_processors) = {
-            let cache_lock = PROCESSOR_CACHE
-                .read()
-                .map_err(|e| crate::KreuzbergError::Other(format!("Processor cache lock poisoned: {}", e)))?;
-            let cache = cache_lock
-                .as_ref()
-                .ok_or_else(|| crate::KreuzbergError::Other("Processor cache not initialized".to_string()))?;
-            (
-                Arc::clone(&cache.early),
-                Arc::clone(&cache.middle),
-                Arc::clone(&cache.late),
-            )
-        };
+    normalize_content(&mut result, config);
 
-        for (_stage, processors_arc) in [
-            (ProcessingStage::Early, early_processors),
-            (ProcessingStage::Middle, middle_processors),
-            (ProcessingStage::Late, late_processors),
-        ] {
-            for processor in processors_arc.iter() {
-                let processor_name = processor.name();
-
-                let should_run = if let Some(config) = pp_config {
-                    // Use O(1) HashSet lookups if available
-                    if let Some(ref enabled_set) = config.enabled_set {
-                        enabled_set.contains(processor_name)
-                    } else if let Some(ref disabled_set) = config.disabled_set {
-                        !disabled_set.contains(processor_name)
-                    } else if let Some(ref enabled) = config.enabled_processors {
-                        // Fallback to O(n) Vec search if HashSet not built yet
-                        enabled.iter().any(|name| name == processor_name)
-                    } else if let Some(ref disabled) = config.disabled_processors {
-                        // Fallback to O(n) Vec search if HashSet not built yet
-                        !disabled.iter().any(|name| name == processor_name)
-                    } else {
-                        true
-                    }
-                } else {
-                    true
-                };
-
-                if should_run && processor.should_process(&result, config) {
-                    match processor.process(&mut result, config).await {
-                        Ok(_) => {}
-                        Err(err @ KreuzbergError::Io(_))
-                        | Err(err @ KreuzbergError::LockPoisoned(_))
-                        | Err(err @ KreuzbergError::Plugin { .. }) => {
-                            return Err(err);
-                        }
-                        Err(err) => {
-                            result.metadata.additional.insert(
-                                format!("processing_error_{processor_name}"),
-                                serde_json::Value::String(err.to_string()),
-                            );
-                        }
-                    }
-                }
+    // Language detection runs first so sentence-aware chunking sees the detected language
+    #[cfg(feature = "language-detection")]
+    if let Some(ref lang_config) = config.language_detection {
+        match crate::language_detection::detect_languages(&result.content, lang_config) {
+            Ok(detected) => {
+                result.detected_languages = detected;
+            }
+            Err(e) => {
+                result.metadata.additional.insert(
+                    "language_detection_error".to_string(),
+                    serde_json::Value::String(e.to_string()),
+                );
             }
         }
     }
 
+    #[cfg(not(feature = "language-detection"))]
+    if config.language_detection.is_some() {
+        result.metadata.additional.insert(
+            "language_detection_error".to_string(),
+            serde_json::Value::String("Language detection feature not enabled".to_string()),
+        );
+    }
+
     #[cfg(feature = "chunking")]
     if let Some(ref chunking_config) = config.chunking {
         let chunk_config = crate::chunking::ChunkingConfig {
@@ -198,6 +481,8 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
             overlap: chunking_config.max_overlap,
             trim: true,
             chunker_type: crate::chunking::ChunkerType::Text,
+            respect_sentences: chunking_config.respect_sentences,
+            language: crate::chunking::sentences::result_language(&result),
         };
 
         let page_boundaries = result.metadata.pages.as_ref().and_then(|ps| ps.boundaries.as_deref());
@@ -213,6 +498,10 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
                     );
                 }
 
+                if let Some(ref processors) = chunk_processors {
+                    run_processors(processors, &mut result, config).await?;
+                }
+
                 #[cfg(feature = "embeddings")]
                 if let Some(ref embedding_config) = chunking_config.embedding
                     && let Some(ref mut chunks) = result.chunks
@@ -258,38 +547,16 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         );
     }
 
-    #[cfg(feature = "language-detection")]
-    if let Some(ref lang_config) = config.language_detection {
-        match crate::language_detection::detect_languages(&result.content, lang_config) {
-            Ok(detected) => {
-                result.detected_languages = detected;
-            }
-            Err(e) => {
-                result.metadata.additional.insert(
-                    "language_detection_error".to_string(),
-                    serde_json::Value::String(e.to_string()),
-                );
-            }
-        }
-    }
-
-    #[cfg(not(feature = "language-detection"))]
-    if config.language_detection.is_some() {
-        result.metadata.additional.insert(
-            "language_detection_error".to_string(),
-            serde_json::Value::String("Language detection feature not enabled".to_string()),
-        );
-    }
+    hash_images(&mut result, config);
+    attach_figures(&mut result, config);
+    transcode_images(&mut result, config);
+    store_image_blobs(&mut result, config);
+    emit_term_offsets(&mut result, config);
+    emit_provenance(&mut result, config);
 
     // Early exit: Skip validator execution if no validators registered
     {
-        let validator_registry = crate::plugins::registry::get_validator_registry();
-        let validators = {
-            let registry = validator_registry
-                .read()
-                .map_err(|e| crate::KreuzbergError::Other(format!("Validator registry lock poisoned: {}", e)))?;
-            registry.get_all()
-        };
+        let validators = crate::plugins::registry::get_validator_registry().snapshot().get_all();
 
         // Early exit optimization: Skip loop if validators list is empty
         if !validators.is_empty() {
@@ -301,6 +568,8 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         }
     }
 
+    spill_to_disk(&mut result, config);
+
     Ok(result)
 }
 
@@ -324,14 +593,47 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 /// This function is only available when the `tokio-runtime` feature is disabled.
 /// It handles:
 /// - Quality processing (if enabled)
-/// - Chunking (if enabled)
+/// - Watermark detection (if configured)
+/// - Repeated text cleanup (if configured)
+/// - Normalization (if configured)
 /// - Language detection (if enabled)
+/// - Chunking (if enabled)
+/// - Figure captions (if image extraction is configured)
+/// - Image encoding (if configured)
 ///
 /// It does NOT handle:
 /// - Async post-processors
 /// - Async validators
 #[cfg(not(feature = "tokio-runtime"))]
 pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    detect_watermarks(&mut result, config);
+    cleanup_content(&mut result, config);
+    normalize_content(&mut result, config);
+
+    // Language detection runs first so sentence-aware chunking sees the detected language
+    #[cfg(feature = "language-detection")]
+    if let Some(ref lang_config) = config.language_detection {
+        match crate::language_detection::detect_languages(&result.content, lang_config) {
+            Ok(detected) => {
+                result.detected_languages = detected;
+            }
+            Err(e) => {
+                result.metadata.additional.insert(
+                    "language_detection_error".to_string(),
+                    serde_json::Value::String(e.to_string()),
+                );
+            }
+        }
+    }
+
+    #[cfg(not(feature = "language-detection"))]
+    if config.language_detection.is_some() {
+        result.metadata.additional.insert(
+            "language_detection_error".to_string(),
+            serde_json::Value::String("Language detection feature not enabled".to_string()),
+        );
+    }
+
     // Chunking
     #[cfg(feature = "chunking")]
     if let Some(ref chunking_config) = config.chunking {
@@ -340,6 +642,8 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
             overlap: chunking_config.max_overlap,
             trim: true,
             chunker_type: crate::chunking::ChunkerType::Text,
+            respect_sentences: chunking_config.respect_sentences,
+            language: crate::chunking::sentences::result_language(&result),
         };
 
         match crate::chunking::chunk_text(&result.content, &chunk_config, None) {
@@ -398,29 +702,13 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
         );
     }
 
-    // Language detection
-    #[cfg(feature = "language-detection")]
-    if let Some(ref lang_config) = config.language_detection {
-        match crate::language_detection::detect_languages(&result.content, lang_config) {
-            Ok(detected) => {
-                result.detected_languages = detected;
-            }
-            Err(e) => {
-                result.metadata.additional.insert(
-                    "language_detection_error".to_string(),
-                    serde_json::Value::String(e.to_string()),
-                );
-            }
-        }
-    }
-
-    #[cfg(not(feature = "language-detection"))]
-    if config.language_detection.is_some() {
-        result.metadata.additional.insert(
-            "language_detection_error".to_string(),
-            serde_json::Value::String("Language detection feature not enabled".to_string()),
-        );
-    }
+    hash_images(&mut result, config);
+    attach_figures(&mut result, config);
+    transcode_images(&mut result, config);
+    store_image_blobs(&mut result, config);
+    emit_term_offsets(&mut result, config);
+    emit_provenance(&mut result, config);
+    spill_to_disk(&mut result, config);
 
     Ok(result)
 }
@@ -441,6 +729,40 @@ mod tests {
         static ref REGISTRY_TEST_GUARD: std::sync::Mutex<()> = std::sync::Mutex::new(());
     }
 
+    #[test]
+    fn test_builtin_post_processors_are_listed() {
+        #[allow(unused_mut)]
+        let mut builtins: Vec<Box<dyn PostProcessor>> = vec![
+            Box::new(crate::text::DocumentClassificationProcessor),
+            Box::new(crate::text::KeyValueExtractionProcessor),
+            Box::new(crate::text::PreviewProcessor),
+        ];
+        #[cfg(feature = "quality")]
+        builtins.push(Box::new(crate::text::QualityProcessor));
+        #[cfg(feature = "ocr")]
+        builtins.push(Box::new(crate::ocr::correction::OcrCorrectionProcessor));
+        #[cfg(feature = "chunking")]
+        builtins.push(Box::new(crate::chunking::ChunkingProcessor));
+
+        for processor in &builtins {
+            let name = processor.name();
+            assert!(BUILTIN_POST_PROCESSORS.contains(&name), "{name} is not listed");
+        }
+    }
+
+    #[test]
+    fn test_processor_cache_follows_registry_snapshots() {
+        let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
+        let registry = crate::plugins::registry::get_post_processor_registry();
+        let before = current_processors();
+
+        registry.update(|_| Ok(())).unwrap();
+        let after = current_processors();
+
+        assert!(!Arc::ptr_eq(&before, &after));
+        assert!(Arc::ptr_eq(&after.registry, &registry.snapshot()));
+    }
+
     #[tokio::test]
     async fn test_run_pipeline_basic() {
         let mut result = ExtractionResult {
@@ -452,6 +774,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         result.metadata.additional.insert(
             VALIDATION_MARKER_KEY.to_string(),
@@ -475,6 +801,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig {
             enable_quality_processing: true,
@@ -496,6 +826,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig {
             enable_quality_processing: false,
@@ -518,6 +852,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig {
             chunking: Some(crate::ChunkingConfig {
@@ -525,6 +863,7 @@ mod tests {
                 max_overlap: 50,
                 embedding: None,
                 preset: None,
+                respect_sentences: false,
             }),
             ..Default::default()
         };
@@ -546,6 +885,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig {
             chunking: None,
@@ -556,6 +899,44 @@ mod tests {
         assert!(!processed.metadata.additional.contains_key("chunk_count"));
     }
 
+    #[tokio::test]
+    #[cfg(feature = "chunking")]
+    async fn test_pipeline_normalizes_before_chunking() {
+        let result = ExtractionResult {
+            content: "\u{201C}Normal-\nization\u{201D}   runs \u{2013} first".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        };
+        let config = ExtractionConfig {
+            enable_quality_processing: false,
+            normalization: Some(crate::NormalizationConfig {
+                unicode: None,
+                ..Default::default()
+            }),
+            chunking: Some(crate::ChunkingConfig {
+                max_chars: 500,
+                max_overlap: 50,
+                embedding: None,
+                preset: None,
+                respect_sentences: false,
+            }),
+            ..Default::default()
+        };
+
+        let processed = run_pipeline(result, &config).await.unwrap();
+        assert_eq!(processed.content, "\"Normalization\" runs - first");
+        assert_eq!(processed.chunks.unwrap()[0].content, processed.content);
+    }
+
     #[tokio::test]
     async fn test_pipeline_preserves_metadata() {
         use std::collections::HashMap;
@@ -575,6 +956,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig::default();
 
@@ -597,6 +982,7 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 0,
+            column_types: None,
         };
 
         let result = ExtractionResult {
@@ -608,6 +994,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig::default();
 
@@ -622,11 +1012,11 @@ mod tests {
 
         {
             let registry = crate::plugins::registry::get_post_processor_registry();
-            registry.write().unwrap().shutdown_all().unwrap();
+            registry.update(|reg| reg.shutdown_all()).unwrap();
         }
         {
             let registry = crate::plugins::registry::get_validator_registry();
-            registry.write().unwrap().shutdown_all().unwrap();
+            registry.update(|reg| reg.shutdown_all()).unwrap();
         }
 
         let result = ExtractionResult {
@@ -638,6 +1028,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig::default();
 
@@ -659,6 +1053,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         let config = ExtractionConfig {
             enable_quality_processing: true,
@@ -667,6 +1065,7 @@ mod tests {
                 max_overlap: 50,
                 embedding: None,
                 preset: None,
+                respect_sentences: false,
             }),
             ..Default::default()
         };
@@ -681,14 +1080,10 @@ mod tests {
     async fn test_pipeline_with_keyword_extraction() {
         let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
         crate::plugins::registry::get_validator_registry()
-            .write()
-            .unwrap()
-            .shutdown_all()
+            .update(|reg| reg.shutdown_all())
             .unwrap();
         crate::plugins::registry::get_post_processor_registry()
-            .write()
-            .unwrap()
-            .shutdown_all()
+            .update(|reg| reg.shutdown_all())
             .unwrap();
 
         let _ = crate::keywords::register_keyword_processor();
@@ -708,6 +1103,10 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         #[cfg(feature = "keywords-yake")]
@@ -751,6 +1150,10 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig {
@@ -768,14 +1171,10 @@ Natural language processing enables computers to understand human language.
     async fn test_pipeline_keyword_extraction_short_content() {
         let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
         crate::plugins::registry::get_validator_registry()
-            .write()
-            .unwrap()
-            .shutdown_all()
+            .update(|reg| reg.shutdown_all())
             .unwrap();
         crate::plugins::registry::get_post_processor_registry()
-            .write()
-            .unwrap()
-            .shutdown_all()
+            .update(|reg| reg.shutdown_all())
             .unwrap();
 
         let result = ExtractionResult {
@@ -787,6 +1186,10 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         #[cfg(feature = "keywords-yake")]
@@ -896,16 +1299,16 @@ Natural language processing enables computers to understand human language.
 
         let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
         clear_processor_cache().unwrap(); // Clear cache before modifying registry
-        pp_registry.write().unwrap().shutdown_all().unwrap();
-        val_registry.write().unwrap().shutdown_all().unwrap();
+        pp_registry.update(|reg| reg.shutdown_all()).unwrap();
+        val_registry.update(|reg| reg.shutdown_all()).unwrap();
 
         {
-            let mut registry = pp_registry.write().unwrap();
+            let mut registry = pp_registry.edit();
             registry.register(Arc::new(TestPostProcessor), 0).unwrap();
         }
 
         {
-            let mut registry = val_registry.write().unwrap();
+            let mut registry = val_registry.edit();
             registry.register(Arc::new(TestValidator)).unwrap();
         }
 
@@ -918,6 +1321,10 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         result.metadata.additional.insert(
             VALIDATION_MARKER_KEY.to_string(),
@@ -929,8 +1336,8 @@ Natural language processing enables computers to understand human language.
 
         let processed = run_pipeline(result, &config).await;
 
-        pp_registry.write().unwrap().shutdown_all().unwrap();
-        val_registry.write().unwrap().shutdown_all().unwrap();
+        pp_registry.update(|reg| reg.shutdown_all()).unwrap();
+        val_registry.update(|reg| reg.shutdown_all()).unwrap();
 
         assert!(processed.is_ok(), "Validator should have seen post-processor metadata");
         let processed = processed.unwrap();
@@ -991,7 +1398,7 @@ Natural language processing enables computers to understand human language.
 
         let val_registry = crate::plugins::registry::get_validator_registry();
         {
-            let mut registry = val_registry.write().unwrap();
+            let mut registry = val_registry.edit();
             registry.register(Arc::new(QualityValidator)).unwrap();
         }
 
@@ -1004,6 +1411,10 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
         result.metadata.additional.insert(
             VALIDATION_MARKER_KEY.to_string(),
@@ -1020,7 +1431,7 @@ Natural language processing enables computers to understand human language.
         let processed = run_pipeline(result, &config).await;
 
         {
-            let mut registry = val_registry.write().unwrap();
+            let mut registry = val_registry.edit();
             registry.remove("quality-validator").unwrap();
         }
 
@@ -1173,17 +1584,17 @@ Natural language processing enables computers to understand human language.
         let val_registry = crate::plugins::registry::get_validator_registry();
         let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
 
-        pp_registry.write().unwrap().shutdown_all().unwrap();
-        val_registry.write().unwrap().shutdown_all().unwrap();
+        pp_registry.update(|reg| reg.shutdown_all()).unwrap();
+        val_registry.update(|reg| reg.shutdown_all()).unwrap();
 
         {
-            let mut registry = pp_registry.write().unwrap();
+            let mut registry = pp_registry.edit();
             registry.register(Arc::new(EarlyProcessor), 0).unwrap();
             registry.register(Arc::new(LateProcessor), 0).unwrap();
         }
 
         {
-            let mut registry = val_registry.write().unwrap();
+            let mut registry = val_registry.edit();
             registry.register(Arc::new(OrderValidator)).unwrap();
         }
 
@@ -1196,6 +1607,10 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -1203,9 +1618,103 @@ Natural language processing enables computers to understand human language.
 
         let processed = run_pipeline(result, &config).await;
 
-        pp_registry.write().unwrap().shutdown_all().unwrap();
-        val_registry.write().unwrap().shutdown_all().unwrap();
+        pp_registry.update(|reg| reg.shutdown_all()).unwrap();
+        val_registry.update(|reg| reg.shutdown_all()).unwrap();
 
         assert!(processed.is_ok(), "All processors should run before validator");
     }
+
+    #[tokio::test]
+    #[cfg(feature = "chunking")]
+    async fn test_chunk_stage_processor_annotates_chunks() {
+        use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+        use async_trait::async_trait;
+        use std::sync::Arc;
+
+        struct ChunkAnnotator;
+        impl Plugin for ChunkAnnotator {
+            fn name(&self) -> &str {
+                "chunk-annotator"
+            }
+            fn version(&self) -> String {
+                "1.0.0".to_string()
+            }
+            fn initialize(&self) -> Result<()> {
+                Ok(())
+            }
+            fn shutdown(&self) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        #[async_trait]
+        impl PostProcessor for ChunkAnnotator {
+            async fn process(&self, result: &mut ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+                if let Some(ref mut chunks) = result.chunks {
+                    for chunk in chunks.iter_mut() {
+                        let index = chunk.metadata.chunk_index;
+                        chunk
+                            .metadata
+                            .annotations
+                            .insert("position".to_string(), serde_json::json!(index));
+                    }
+                }
+                Ok(())
+            }
+
+            fn processing_stage(&self) -> ProcessingStage {
+                ProcessingStage::Chunk
+            }
+        }
+
+        let pp_registry = crate::plugins::registry::get_post_processor_registry();
+
+        let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
+        clear_processor_cache().unwrap();
+        pp_registry.update(|reg| reg.shutdown_all()).unwrap();
+        pp_registry
+            .update(|reg| reg.register(Arc::new(ChunkAnnotator), 0))
+            .unwrap();
+
+        let result = ExtractionResult {
+            content: "This is a long text that should be chunked. ".repeat(100),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        };
+        let config = ExtractionConfig {
+            chunking: Some(crate::ChunkingConfig {
+                max_chars: 500,
+                max_overlap: 50,
+                embedding: None,
+                preset: None,
+                respect_sentences: false,
+            }),
+            ..Default::default()
+        };
+        drop(_guard);
+
+        let processed = run_pipeline(result, &config).await;
+
+        pp_registry.update(|reg| reg.shutdown_all()).unwrap();
+        clear_processor_cache().unwrap();
+
+        let processed = processed.unwrap();
+        let chunks = processed.chunks.expect("chunking should produce chunks");
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert_eq!(
+                chunk.metadata.annotations.get("position"),
+                Some(&serde_json::json!(chunk.metadata.chunk_index))
+            );
+        }
+    }
 }
@@ -0,0 +1,224 @@
+//! Configuration presets for common workloads.
+//!
+//! A preset is a complete [`ExtractionConfig`] for a standard use case, defined once here so
+//! every binding hands out the same configuration for the same name:
+//!
+//! - **`rag_ingest`**: content for retrieval-augmented generation. Quality processing,
+//!   NFC normalization, removal of running headers and footers, language detection, pages,
+//!   OCR of scanned pages and 1000-character chunks with 200 characters of overlap, embedded
+//!   with the `balanced` embedding preset when the `embeddings` feature is compiled in.
+//! - **`archival_ocr`**: faithful text of scanned archives. OCR of every page at 400 DPI with
+//!   deskewing and auto-tuned preprocessing, provenance, pages and metadata, and the `full`
+//!   resource profile so nothing is degraded in small containers.
+//! - **`fast_preview`**: a quick look at a document. No OCR, images or quality processing,
+//!   a three-sentence preview, the `minimal` resource profile and a 5 second extraction budget.
+//!
+//! Presets are starting points: override individual fields after loading one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::presets::{ConfigPreset, preset_config};
+//!
+//! let mut config = ConfigPreset::RagIngest.config();
+//! config.chunking.as_mut().unwrap().max_chars = 500;
+//!
+//! let preview = preset_config("fast_preview")?;
+//! assert!(preview.ocr.is_none());
+//! # Ok::<(), kreuzberg::KreuzbergError>(())
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// A named configuration for a common workload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigPreset {
+    /// Chunked, normalized and (with the `embeddings` feature) embedded content for RAG pipelines
+    RagIngest,
+    /// High-DPI OCR of every page with provenance for scanned archives
+    ArchivalOcr,
+    /// Cheap extraction of a short preview without OCR or images
+    FastPreview,
+}
+
+impl ConfigPreset {
+    /// All presets, in documentation order.
+    pub const ALL: [ConfigPreset; 3] = [Self::RagIngest, Self::ArchivalOcr, Self::FastPreview];
+
+    /// Snake-case name of the preset (e.g. "rag_ingest").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RagIngest => "rag_ingest",
+            Self::ArchivalOcr => "archival_ocr",
+            Self::FastPreview => "fast_preview",
+        }
+    }
+
+    /// One-line description of the workload the preset is meant for.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::RagIngest => "Chunked, normalized content with embeddings for retrieval-augmented generation",
+            Self::ArchivalOcr => "OCR of every page at high resolution with provenance",
+            Self::FastPreview => "Short preview without OCR, images or quality processing",
+        }
+    }
+
+    /// The complete extraction configuration of the preset.
+    pub fn config(&self) -> ExtractionConfig {
+        // ~keep: the preset documents are static and covered by tests, so they always deserialize.
+        serde_json::from_value(self.document()).expect("configuration preset is a valid ExtractionConfig")
+    }
+
+    /// The preset as a configuration document; omitted fields take their serde defaults.
+    fn document(&self) -> Value {
+        match self {
+            Self::RagIngest => {
+                let mut chunking = json!({"max_chars": 1000, "max_overlap": 200});
+                if cfg!(feature = "embeddings") {
+                    chunking["embedding"] = json!({"model": {"type": "preset", "name": "balanced"}});
+                }
+                json!({
+                    "enable_quality_processing": true,
+                    "ocr": {"backend": "tesseract", "language": "eng"},
+                    "normalization": {"unicode": "nfc", "collapse_whitespace": true, "dehyphenate": true},
+                    "cleanup": {"strip_repeated": true},
+                    "language_detection": {"enabled": true, "detect_multiple": true},
+                    "pages": {"extract_pages": true},
+                    "chunking": chunking,
+                    "postprocessor": {"enabled": true}
+                })
+            }
+            Self::ArchivalOcr => json!({
+                "enable_quality_processing": true,
+                "force_ocr": true,
+                "ocr": {
+                    "backend": "tesseract",
+                    "language": "eng",
+                    "tesseract_config": {
+                        "preprocessing": {"target_dpi": 400, "auto_rotate": true, "deskew": true, "auto_tune": true}
+                    }
+                },
+                "pdf_options": {"extract_metadata": true},
+                "pages": {"extract_pages": true},
+                "emit_provenance": true,
+                "resource_profile": "full"
+            }),
+            Self::FastPreview => json!({
+                "enable_quality_processing": false,
+                "pdf_options": {"extract_images": false, "extract_metadata": true},
+                "preview": {"sentences": 3},
+                "stage_timeouts": {"extraction_ms": 5000},
+                "resource_profile": "minimal"
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ConfigPreset {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let normalized = value.to_ascii_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.as_str() == normalized)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|preset| preset.as_str()).collect();
+                format!(
+                    "Unknown configuration preset '{}': expected one of {}",
+                    value,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Load the configuration of the preset named `name` ("rag_ingest", "archival_ocr", "fast_preview").
+///
+/// # Errors
+///
+/// Returns [`KreuzbergError::Validation`] for unknown names.
+pub fn preset_config(name: &str) -> Result<ExtractionConfig> {
+    name.parse::<ConfigPreset>()
+        .map(|preset| preset.config())
+        .map_err(KreuzbergError::validation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::ResourceProfile;
+
+    #[test]
+    fn test_every_preset_deserializes() {
+        for preset in ConfigPreset::ALL {
+            let config = preset.config();
+            assert!(config.use_cache, "{} keeps the cache enabled", preset);
+        }
+    }
+
+    #[test]
+    fn test_rag_ingest_chunks_content() {
+        let config = ConfigPreset::RagIngest.config();
+
+        let chunking = config.chunking.expect("rag_ingest chunks");
+        assert_eq!(chunking.max_chars, 1000);
+        assert_eq!(chunking.max_overlap, 200);
+        assert_eq!(chunking.embedding.is_some(), cfg!(feature = "embeddings"));
+        assert!(config.language_detection.is_some_and(|detection| detection.enabled));
+        assert!(config.cleanup.is_some());
+    }
+
+    #[test]
+    fn test_archival_ocr_forces_high_dpi_ocr() {
+        let config = ConfigPreset::ArchivalOcr.config();
+
+        assert!(config.force_ocr);
+        assert!(config.emit_provenance);
+        assert_eq!(config.resource_profile, ResourceProfile::Full);
+        let preprocessing = config
+            .ocr
+            .and_then(|ocr| ocr.tesseract_config)
+            .and_then(|tesseract| tesseract.preprocessing)
+            .expect("archival_ocr preprocesses pages");
+        assert_eq!(preprocessing.target_dpi, 400);
+    }
+
+    #[test]
+    fn test_fast_preview_skips_expensive_work() {
+        let config = ConfigPreset::FastPreview.config();
+
+        assert!(config.ocr.is_none());
+        assert!(config.images.is_none());
+        assert!(!config.enable_quality_processing);
+        assert_eq!(config.preview.map(|preview| preview.sentences), Some(3));
+        assert_eq!(config.resource_profile, ResourceProfile::Minimal);
+    }
+
+    #[test]
+    fn test_preset_names_round_trip() {
+        for preset in ConfigPreset::ALL {
+            assert_eq!(preset.as_str().parse::<ConfigPreset>(), Ok(preset));
+            assert_eq!(serde_json::to_value(preset).unwrap(), preset.as_str());
+        }
+        assert_eq!("Fast-Preview".parse::<ConfigPreset>(), Ok(ConfigPreset::FastPreview));
+    }
+
+    #[test]
+    fn test_unknown_preset_is_a_validation_error() {
+        let err = preset_config("turbo").unwrap_err();
+
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("rag_ingest"));
+    }
+}
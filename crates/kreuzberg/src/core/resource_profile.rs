@@ -0,0 +1,199 @@
+//! Graceful degradation for low-resource environments.
+//!
+//! [`ResourceProfile`] decides how much of the configured work extraction may do.
+//! [`extract_file`](crate::extract_file) and [`extract_bytes`](crate::extract_bytes) apply
+//! it before the cache lookup, so cached results are keyed by the degraded configuration.
+//! The minimal profile turns off the features that dominate memory use:
+//!
+//! - Embeddings (`chunking.embedding`); chunks are still produced
+//! - Image extraction (`images`) and barcode detection (`detect_barcodes`)
+//! - Keyword extraction, document classification and key-value extraction
+//! - High-resolution OCR: PDF pages are rendered for OCR at no more than [`MINIMAL_OCR_DPI`],
+//!   and the OCR preprocessing `target_dpi` is capped at the same value
+//!
+//! The standard profile (the default) keeps the configuration as given unless the cgroup of
+//! the process has a memory limit of [`LOW_MEMORY_LIMIT_BYTES`] or less, in which case it
+//! behaves as minimal. The same binary therefore runs sensibly in a 256 MB container without
+//! a separate configuration. The full profile never degrades.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::config::{ExtractionConfig, ResourceProfile};
+//! use kreuzberg::core::resource_profile::apply_resource_profile;
+//!
+//! let config = ExtractionConfig {
+//!     detect_barcodes: true,
+//!     resource_profile: ResourceProfile::Minimal,
+//!     ..Default::default()
+//! };
+//!
+//! let effective = apply_resource_profile(&config);
+//! assert!(!effective.detect_barcodes);
+//! ```
+
+use crate::core::config::{ExtractionConfig, ResourceProfile};
+use once_cell::sync::Lazy;
+use std::borrow::Cow;
+
+/// Container memory limit at or below which the standard profile behaves as minimal (512 MiB).
+pub const LOW_MEMORY_LIMIT_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Highest DPI used for OCR under the minimal profile.
+pub const MINIMAL_OCR_DPI: i32 = 150;
+
+/// Memory limit files of cgroup v2 and cgroup v1, in lookup order.
+#[cfg(target_os = "linux")]
+const CGROUP_MEMORY_LIMIT_FILES: &[&str] = &[
+    "/sys/fs/cgroup/memory.max",
+    "/sys/fs/cgroup/memory/memory.limit_in_bytes",
+];
+
+/// cgroup v1 reports "no limit" as a page-aligned value close to `i64::MAX`.
+const CGROUP_V1_UNLIMITED: u64 = 1 << 60;
+
+static MEMORY_LIMIT: Lazy<Option<u64>> = Lazy::new(read_memory_limit);
+
+impl ResourceProfile {
+    /// The profile extraction runs with: standard becomes minimal under a low memory limit.
+    pub fn effective(self) -> Self {
+        match self {
+            Self::Standard if container_memory_limit().is_some_and(|limit| limit <= LOW_MEMORY_LIMIT_BYTES) => {
+                Self::Minimal
+            }
+            profile => profile,
+        }
+    }
+
+    /// Highest DPI OCR may use, `None` when unrestricted.
+    pub fn ocr_dpi_cap(self) -> Option<i32> {
+        (self.effective() == Self::Minimal).then_some(MINIMAL_OCR_DPI)
+    }
+}
+
+/// Memory limit of the cgroup the process runs in (`None` = unlimited or not in a cgroup).
+///
+/// Read once per process from cgroup v2 `memory.max`, falling back to cgroup v1
+/// `memory.limit_in_bytes`. Always `None` outside Linux.
+pub fn container_memory_limit() -> Option<u64> {
+    *MEMORY_LIMIT
+}
+
+/// Apply the effective resource profile of `config`.
+///
+/// Returns `config` unchanged unless the profile is (or turns) minimal, in which case the
+/// degraded copy has `resource_profile` set to [`ResourceProfile::Minimal`].
+pub fn apply_resource_profile(config: &ExtractionConfig) -> Cow<'_, ExtractionConfig> {
+    if config.resource_profile.effective() != ResourceProfile::Minimal {
+        return Cow::Borrowed(config);
+    }
+
+    let mut config = config.clone();
+    config.resource_profile = ResourceProfile::Minimal;
+    config.images = None;
+    config.detect_barcodes = false;
+    config.classification = None;
+    config.kv_extraction = None;
+    #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+    {
+        config.keywords = None;
+    }
+    if let Some(chunking) = config.chunking.as_mut() {
+        chunking.embedding = None;
+    }
+    if let Some(preprocessing) = config
+        .ocr
+        .as_mut()
+        .and_then(|ocr| ocr.tesseract_config.as_mut())
+        .and_then(|tesseract| tesseract.preprocessing.as_mut())
+    {
+        preprocessing.target_dpi = preprocessing.target_dpi.min(MINIMAL_OCR_DPI);
+    }
+
+    Cow::Owned(config)
+}
+
+#[cfg(target_os = "linux")]
+fn read_memory_limit() -> Option<u64> {
+    CGROUP_MEMORY_LIMIT_FILES
+        .iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| parse_memory_limit(&content))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_memory_limit() -> Option<u64> {
+    None
+}
+
+/// Parse a cgroup memory limit file; `max` and the v1 sentinel mean unlimited.
+fn parse_memory_limit(content: &str) -> Option<u64> {
+    content
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|&limit| limit < CGROUP_V1_UNLIMITED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_memory_limit() {
+        assert_eq!(parse_memory_limit("268435456\n"), Some(268_435_456));
+        assert_eq!(parse_memory_limit("max\n"), None);
+        assert_eq!(parse_memory_limit("9223372036854771712\n"), None);
+        assert_eq!(parse_memory_limit(""), None);
+    }
+
+    #[test]
+    fn test_minimal_profile_degrades_expensive_features() {
+        let config: ExtractionConfig = serde_json::from_value(json!({
+            "images": {},
+            "detect_barcodes": true,
+            "chunking": {"embedding": {"model": {"type": "preset", "name": "fast"}}},
+            "ocr": {"tesseract_config": {"preprocessing": {"target_dpi": 300}}},
+            "resource_profile": "minimal"
+        }))
+        .unwrap();
+        assert!(config.images.is_some());
+        assert!(config.chunking.as_ref().unwrap().embedding.is_some());
+
+        let degraded = apply_resource_profile(&config);
+
+        assert!(matches!(degraded, Cow::Owned(_)));
+        assert!(degraded.images.is_none());
+        assert!(!degraded.detect_barcodes);
+        assert!(degraded.chunking.as_ref().unwrap().embedding.is_none());
+        let tesseract = degraded.ocr.as_ref().unwrap().tesseract_config.as_ref().unwrap();
+        assert_eq!(tesseract.preprocessing.as_ref().unwrap().target_dpi, MINIMAL_OCR_DPI);
+        assert_eq!(degraded.resource_profile.ocr_dpi_cap(), Some(MINIMAL_OCR_DPI));
+    }
+
+    #[test]
+    fn test_full_profile_keeps_configuration() {
+        let config: ExtractionConfig =
+            serde_json::from_value(json!({"images": {}, "resource_profile": "full"})).unwrap();
+
+        let effective = apply_resource_profile(&config);
+
+        assert!(matches!(effective, Cow::Borrowed(_)));
+        assert!(effective.images.is_some());
+        assert_eq!(ResourceProfile::Full.ocr_dpi_cap(), None);
+    }
+
+    #[test]
+    fn test_standard_profile_follows_memory_limit() {
+        let low_memory = container_memory_limit().is_some_and(|limit| limit <= LOW_MEMORY_LIMIT_BYTES);
+        let expected = if low_memory {
+            ResourceProfile::Minimal
+        } else {
+            ResourceProfile::Standard
+        };
+
+        assert_eq!(ResourceProfile::Standard.effective(), expected);
+        assert_eq!(ExtractionConfig::default().resource_profile, ResourceProfile::Standard);
+    }
+}
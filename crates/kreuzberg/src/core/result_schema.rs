@@ -0,0 +1,300 @@
+//! Versioned layout of serialized extraction results.
+//!
+//! Results cached on disk or stored in databases outlive the release that wrote them.
+//! Every serialized [`ExtractionResult`] carries a `schema_version`, and [`migrate_result`]
+//! rewrites a stored result to another version so it can still be loaded after an
+//! upgrade, or handed back to an older reader.
+//!
+//! | Version | Layout |
+//! |---------|--------|
+//! | 1 | Chunk offsets are character offsets named `char_start`/`char_end` (before 4.0.0-rc.7) |
+//! | 2 | Chunk offsets are byte offsets named `byte_start`/`byte_end`; `schema_version` is serialized |
+//!
+//! Results written before `schema_version` existed have no version field; their version
+//! is inferred from their layout.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::ExtractionResult;
+//! use kreuzberg::core::result_schema::{deserialize_result, migrate_result};
+//! use serde_json::json;
+//!
+//! let stored = json!({
+//!     "content": "héllo world",
+//!     "mime_type": "text/plain",
+//!     "metadata": {},
+//!     "tables": [],
+//!     "chunks": [{
+//!         "content": "world",
+//!         "metadata": {"char_start": 6, "char_end": 11, "chunk_index": 0, "total_chunks": 1}
+//!     }]
+//! });
+//!
+//! let migrated = migrate_result(stored, ExtractionResult::SCHEMA_VERSION).unwrap();
+//! assert_eq!(migrated["chunks"][0]["metadata"]["byte_start"], 7);
+//!
+//! let result = deserialize_result(&migrated.to_string()).unwrap();
+//! assert_eq!(result.schema_version, ExtractionResult::SCHEMA_VERSION);
+//! ```
+
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use serde_json::{Map, Value};
+
+/// Change between a version and the next one, applied in place to the result object.
+struct Migration {
+    /// Version the upgrade starts from
+    from: u32,
+    upgrade: fn(&mut Map<String, Value>),
+    downgrade: fn(&mut Map<String, Value>),
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    upgrade: chunk_offsets_to_bytes,
+    downgrade: chunk_offsets_to_chars,
+}];
+
+/// Layout version of a serialized result.
+///
+/// Reads `schema_version`, or infers the version of results written before the field
+/// existed.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `value` is not an object, or if its
+/// `schema_version` is not a version this release knows.
+pub fn result_schema_version(value: &Value) -> Result<u32> {
+    let Value::Object(map) = value else {
+        return Err(KreuzbergError::validation("Serialized result must be a JSON object"));
+    };
+
+    match map.get("schema_version") {
+        None | Some(Value::Null) => Ok(infer_unversioned(map)),
+        Some(version) => {
+            let version = version
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| KreuzbergError::validation(format!("Invalid schema_version {}", version)))?;
+            check_known_version(version)?;
+            Ok(version)
+        }
+    }
+}
+
+/// Rewrite a serialized result to `target_version`.
+///
+/// Both upgrades and downgrades are supported; the result's `schema_version` is set to
+/// `target_version`.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the result or `target_version` is not a
+/// version this release knows.
+pub fn migrate_result(mut value: Value, target_version: u32) -> Result<Value> {
+    let mut version = result_schema_version(&value)?;
+    check_known_version(target_version)?;
+
+    let Value::Object(map) = &mut value else {
+        unreachable!("result_schema_version accepts objects only");
+    };
+
+    while version < target_version {
+        if let Some(migration) = MIGRATIONS.iter().find(|m| m.from == version) {
+            (migration.upgrade)(map);
+        }
+        version += 1;
+    }
+    while version > target_version {
+        version -= 1;
+        if let Some(migration) = MIGRATIONS.iter().find(|m| m.from == version) {
+            (migration.downgrade)(map);
+        }
+    }
+
+    map.insert("schema_version".to_string(), Value::from(target_version));
+    Ok(value)
+}
+
+/// Deserialize a stored result of any known version.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Serialization` for malformed JSON and
+/// `KreuzbergError::Validation` for unknown versions.
+pub fn deserialize_result(json: &str) -> Result<ExtractionResult> {
+    let value: Value = serde_json::from_str(json)?;
+    let migrated = migrate_result(value, ExtractionResult::SCHEMA_VERSION)?;
+    Ok(serde_json::from_value(migrated)?)
+}
+
+fn check_known_version(version: u32) -> Result<()> {
+    if (1..=ExtractionResult::SCHEMA_VERSION).contains(&version) {
+        Ok(())
+    } else {
+        Err(KreuzbergError::validation(format!(
+            "Unsupported result schema_version {} (supported: 1 to {})",
+            version,
+            ExtractionResult::SCHEMA_VERSION
+        )))
+    }
+}
+
+fn infer_unversioned(map: &Map<String, Value>) -> u32 {
+    let has_char_offsets = chunk_metadata(map).any(|metadata| metadata.contains_key("char_start"));
+    if has_char_offsets { 1 } else { 2 }
+}
+
+fn chunk_metadata(map: &Map<String, Value>) -> impl Iterator<Item = &Map<String, Value>> {
+    map.get("chunks")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|chunk| chunk.get("metadata")?.as_object())
+}
+
+/// Apply `convert` to the chunk offsets stored under `from_keys`, moving them to `to_keys`.
+fn rewrite_chunk_offsets(
+    map: &mut Map<String, Value>,
+    from_keys: [&str; 2],
+    to_keys: [&str; 2],
+    convert: fn(&str, u64) -> u64,
+) {
+    let content = map
+        .get("content")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let Some(chunks) = map.get_mut("chunks").and_then(Value::as_array_mut) else {
+        return;
+    };
+
+    for metadata in chunks
+        .iter_mut()
+        .filter_map(|chunk| chunk.get_mut("metadata")?.as_object_mut())
+    {
+        for (from, to) in from_keys.into_iter().zip(to_keys) {
+            if let Some(offset) = metadata.remove(from) {
+                let converted = offset.as_u64().map_or(offset, |o| Value::from(convert(&content, o)));
+                metadata.insert(to.to_string(), converted);
+            }
+        }
+    }
+}
+
+fn chunk_offsets_to_bytes(map: &mut Map<String, Value>) {
+    rewrite_chunk_offsets(
+        map,
+        ["char_start", "char_end"],
+        ["byte_start", "byte_end"],
+        char_to_byte_offset,
+    );
+}
+
+fn chunk_offsets_to_chars(map: &mut Map<String, Value>) {
+    rewrite_chunk_offsets(
+        map,
+        ["byte_start", "byte_end"],
+        ["char_start", "char_end"],
+        byte_to_char_offset,
+    );
+}
+
+fn char_to_byte_offset(content: &str, offset: u64) -> u64 {
+    let index = content.char_indices().nth(offset as usize).map(|(index, _)| index);
+    index.unwrap_or(content.len()) as u64
+}
+
+fn byte_to_char_offset(content: &str, offset: u64) -> u64 {
+    content
+        .char_indices()
+        .take_while(|(index, _)| (*index as u64) < offset)
+        .count() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn stored_v1() -> Value {
+        json!({
+            "content": "naïve café",
+            "mime_type": "text/plain",
+            "metadata": {},
+            "tables": [],
+            "chunks": [
+                {"content": "naïve", "metadata": {"char_start": 0, "char_end": 5, "chunk_index": 0, "total_chunks": 2}},
+                {"content": "café", "metadata": {"char_start": 6, "char_end": 10, "chunk_index": 1, "total_chunks": 2}}
+            ]
+        })
+    }
+
+    #[test]
+    fn test_infers_version_of_unversioned_results() {
+        assert_eq!(result_schema_version(&stored_v1()).unwrap(), 1);
+
+        let without_chunks = json!({"content": "", "mime_type": "text/plain", "metadata": {}, "tables": []});
+        assert_eq!(result_schema_version(&without_chunks).unwrap(), 2);
+
+        assert!(result_schema_version(&json!({"schema_version": 99})).is_err());
+        assert!(result_schema_version(&json!([])).is_err());
+    }
+
+    #[test]
+    fn test_upgrade_converts_char_offsets_to_bytes() {
+        let migrated = migrate_result(stored_v1(), 2).unwrap();
+
+        assert_eq!(migrated["schema_version"], 2);
+        let second = &migrated["chunks"][1]["metadata"];
+        assert_eq!(second["byte_start"], 7);
+        assert_eq!(second["byte_end"], 12);
+        assert!(second.get("char_start").is_none());
+
+        let content = migrated["content"].as_str().unwrap();
+        assert_eq!(&content[7..12], "café");
+    }
+
+    #[test]
+    fn test_downgrade_round_trips() {
+        let upgraded = migrate_result(stored_v1(), 2).unwrap();
+        let downgraded = migrate_result(upgraded, 1).unwrap();
+
+        let mut expected = stored_v1();
+        expected["schema_version"] = json!(1);
+        assert_eq!(downgraded, expected);
+    }
+
+    #[test]
+    fn test_deserialize_result_of_every_version() {
+        let result = deserialize_result(&stored_v1().to_string()).unwrap();
+        assert_eq!(result.schema_version, ExtractionResult::SCHEMA_VERSION);
+        let chunks = result.chunks.unwrap();
+        assert_eq!(chunks[0].metadata.byte_end, "naïve".len());
+
+        let current = serde_json::to_string(&ExtractionResult {
+            content: "text".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Default::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        })
+        .unwrap();
+        assert!(current.contains("\"schema_version\":2"));
+        assert_eq!(deserialize_result(&current).unwrap().content, "text");
+    }
+
+    #[test]
+    fn test_rejects_unknown_target_version() {
+        assert!(migrate_result(stored_v1(), 0).is_err());
+        assert!(migrate_result(stored_v1(), ExtractionResult::SCHEMA_VERSION + 1).is_err());
+    }
+}
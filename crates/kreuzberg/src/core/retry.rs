@@ -0,0 +1,182 @@
+//! Retries of batch extractions that failed for a transient reason.
+//!
+//! [`RetryConfig`] names the error classes worth another attempt (by default IO errors, such
+//! as a read from a network filesystem that timed out, and OCR errors, such as a crashed
+//! OCR subprocess) and how long to back off between attempts. Batch extraction runs every
+//! document through [`with_retry`] and records the number of attempts in the
+//! `extraction_attempts` metadata of its result, also when the last attempt failed (IO
+//! errors included), so bindings no longer need their own retry wrappers.
+//!
+//! Errors that say something about the document itself (validation, parsing, unsupported
+//! formats, rejected inputs) are never retried.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::retry::with_retry;
+//! use kreuzberg::{KreuzbergError, RetryConfig};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let policy = RetryConfig { initial_backoff_ms: 1, ..Default::default() };
+//! let mut failures = 2;
+//! let (result, attempts) = with_retry(Some(&policy), || {
+//!     let outcome = if failures > 0 {
+//!         failures -= 1;
+//!         Err(KreuzbergError::Io(std::io::Error::other("connection reset")))
+//!     } else {
+//!         Ok("content")
+//!     };
+//!     async move { outcome }
+//! })
+//! .await;
+//!
+//! assert_eq!(result.unwrap(), "content");
+//! assert_eq!(attempts, 3);
+//! # }
+//! ```
+
+use crate::core::config::{RetryConfig, RetryableError};
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use std::future::Future;
+use std::time::Duration;
+
+/// Metadata key holding the number of attempts a batch extraction took.
+pub const ATTEMPTS_METADATA_KEY: &str = "extraction_attempts";
+
+impl RetryableError {
+    /// Whether `error` belongs to this class.
+    pub fn matches(self, error: &KreuzbergError) -> bool {
+        matches!(
+            (self, error),
+            (Self::Io, KreuzbergError::Io(_))
+                | (Self::Ocr, KreuzbergError::Ocr { .. })
+                | (Self::Timeout, KreuzbergError::Timeout { .. })
+                | (Self::Cache, KreuzbergError::Cache { .. })
+                | (Self::Plugin, KreuzbergError::Plugin { .. })
+        )
+    }
+}
+
+impl RetryConfig {
+    /// Whether `error` is worth another attempt under this policy.
+    pub fn is_retryable(&self, error: &KreuzbergError) -> bool {
+        self.retry_on.iter().any(|class| class.matches(error))
+    }
+
+    /// Wait before retry number `retry` (1 for the first retry).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let millis = self.initial_backoff_ms as f64 * self.multiplier.max(1.0).powi(exponent);
+        Duration::from_millis(millis.min(self.max_backoff_ms as f64) as u64)
+    }
+}
+
+/// Run `operation` until it succeeds, fails with an error the policy does not retry, or
+/// used up `max_attempts`.
+///
+/// Without a policy `operation` runs once. Returns the last outcome and the number of
+/// attempts made.
+pub async fn with_retry<T, F, Fut>(policy: Option<&RetryConfig>, mut operation: F) -> (Result<T>, u32)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let max_attempts = policy.map_or(1, |policy| policy.max_attempts.max(1));
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        let outcome = operation().await;
+        let Some(policy) = policy else {
+            return (outcome, attempts);
+        };
+        match &outcome {
+            Err(error) if attempts < max_attempts && policy.is_retryable(error) => {
+                tracing::debug!("Extraction attempt {} failed, retrying: {}", attempts, error);
+                tokio::time::sleep(policy.backoff(attempts)).await;
+            }
+            _ => return (outcome, attempts),
+        }
+    }
+}
+
+/// Record the number of attempts in the result's `extraction_attempts` metadata.
+pub fn record_attempts(result: &mut ExtractionResult, attempts: u32) {
+    result
+        .metadata
+        .additional
+        .insert(ATTEMPTS_METADATA_KEY.to_string(), serde_json::json!(attempts));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn io_error() -> KreuzbergError {
+        KreuzbergError::Io(std::io::Error::other("stale NFS handle"))
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryConfig {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 1_000,
+            multiplier: 3.0,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(300));
+        assert_eq!(policy.backoff(3), Duration::from_millis(900));
+        assert_eq!(policy.backoff(4), Duration::from_millis(1_000));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn test_default_policy_retries_io_and_ocr_only() {
+        let policy = RetryConfig::default();
+
+        assert!(policy.is_retryable(&io_error()));
+        assert!(policy.is_retryable(&KreuzbergError::ocr("tesseract exited with signal 11")));
+        assert!(!policy.is_retryable(&KreuzbergError::parsing("truncated xref table")));
+        assert!(!policy.is_retryable(&KreuzbergError::UnsupportedFormat("application/x-foo".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_stops_after_max_attempts() {
+        let policy = RetryConfig {
+            max_attempts: 3,
+            initial_backoff_ms: 1,
+            ..Default::default()
+        };
+        let mut calls = 0;
+
+        let (result, attempts) = with_retry(Some(&policy), || {
+            calls += 1;
+            async { Err::<(), _>(io_error()) }
+        })
+        .await;
+
+        assert!(matches!(result, Err(KreuzbergError::Io(_))));
+        assert_eq!(attempts, 3);
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_does_not_retry_other_errors() {
+        let policy = RetryConfig::default();
+
+        let (result, attempts) = with_retry(Some(&policy), || async {
+            Err::<(), _>(KreuzbergError::parsing("bad file"))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+
+        let (result, attempts) = with_retry(None, || async { Err::<(), _>(io_error()) }).await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+}
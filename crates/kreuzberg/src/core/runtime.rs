@@ -0,0 +1,150 @@
+//! Process-wide Tokio runtime behind the synchronous extraction functions.
+//!
+//! `extract_file_sync` and the other blocking wrappers share one multi-threaded runtime
+//! instead of creating one per call, which makes them 100x+ faster. The runtime is created
+//! on first use with one worker per CPU core, or up front with [`init_runtime`] to choose the
+//! number of workers.
+//!
+//! [`shutdown_runtime`] stops the worker and blocking threads again. Hosts that load the
+//! library as a plugin and unload it later (`dlclose`, `AssemblyLoadContext.Unload`) must
+//! call it first: a worker thread still parked in code that was unmapped crashes the
+//! process. A synchronous call after shutdown creates a new runtime.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use kreuzberg::core::runtime::{init_runtime, runtime_active, shutdown_runtime};
+//!
+//! assert!(init_runtime(Some(2)).unwrap());
+//! assert!(runtime_active());
+//!
+//! shutdown_runtime(Duration::from_secs(5)).unwrap();
+//! assert!(!runtime_active());
+//! ```
+
+use crate::{KreuzbergError, Result};
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// The runtime, `None` before first use and after [`shutdown_runtime`].
+static RUNTIME: Mutex<Option<Arc<Runtime>>> = Mutex::new(None);
+
+fn runtime_slot() -> MutexGuard<'static, Option<Arc<Runtime>>> {
+    RUNTIME.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn build_runtime(worker_threads: Option<usize>) -> Result<Runtime> {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder.enable_all().thread_name("kreuzberg-worker");
+    if let Some(worker_threads) = worker_threads {
+        if worker_threads == 0 {
+            return Err(KreuzbergError::validation("worker_threads must be at least 1"));
+        }
+        builder.worker_threads(worker_threads);
+    }
+    builder
+        .build()
+        .map_err(|e| KreuzbergError::Other(format!("Failed to create Tokio runtime: {}", e)))
+}
+
+/// Create the shared runtime with `worker_threads` workers (None = one per CPU core).
+///
+/// Returns `false` without changing anything if the runtime already exists.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` for zero workers and `KreuzbergError::Other` if the
+/// operating system refuses to start the threads.
+pub fn init_runtime(worker_threads: Option<usize>) -> Result<bool> {
+    let mut slot = runtime_slot();
+    if slot.is_some() {
+        return Ok(false);
+    }
+    *slot = Some(Arc::new(build_runtime(worker_threads)?));
+    Ok(true)
+}
+
+/// Whether the shared runtime currently exists.
+pub fn runtime_active() -> bool {
+    runtime_slot().is_some()
+}
+
+/// Run `future` to completion on the shared runtime, creating it on first use.
+///
+/// # Panics
+///
+/// Panics if the runtime cannot be created (the process is out of threads or memory) or if
+/// called from within an async context.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let runtime = {
+        let mut slot = runtime_slot();
+        match slot.as_ref() {
+            Some(runtime) => Arc::clone(runtime),
+            None => {
+                let runtime = Arc::new(
+                    build_runtime(None)
+                        .expect("Failed to create global Tokio runtime - system may be out of resources"),
+                );
+                *slot = Some(Arc::clone(&runtime));
+                runtime
+            }
+        }
+    };
+    runtime.block_on(future)
+}
+
+/// Stop the shared runtime and join its threads, waiting at most `timeout` for blocking tasks.
+///
+/// Does nothing if the runtime does not exist.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` while a synchronous extraction is still running on
+/// another thread; the runtime is left running then.
+pub fn shutdown_runtime(timeout: Duration) -> Result<()> {
+    let mut slot = runtime_slot();
+    let Some(runtime) = slot.take() else {
+        return Ok(());
+    };
+    match Arc::try_unwrap(runtime) {
+        Ok(runtime) => {
+            drop(slot);
+            runtime.shutdown_timeout(timeout);
+            Ok(())
+        }
+        Err(runtime) => {
+            *slot = Some(runtime);
+            Err(KreuzbergError::validation(
+                "Cannot shut down the runtime while extractions are running",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shutdown_refuses_while_in_use() {
+        let runtime = {
+            init_runtime(Some(1)).unwrap();
+            runtime_slot().as_ref().map(Arc::clone).unwrap()
+        };
+
+        assert!(shutdown_runtime(Duration::from_secs(1)).is_err());
+        assert!(runtime_active());
+        assert!(!init_runtime(Some(1)).unwrap());
+        assert_eq!(block_on(async { 42 }), 42);
+
+        drop(runtime);
+    }
+
+    #[test]
+    fn test_init_rejects_zero_workers() {
+        assert!(build_runtime(Some(0)).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! Watchdog for extraction stages that run too long.
+//!
+//! [`StageTimeoutConfig`](crate::core::config::StageTimeoutConfig) assigns time budgets to
+//! the stages of an extraction. A stage still running when its budget elapses is abandoned
+//! and the extraction fails with `KreuzbergError::Timeout` naming the
+//! [`ExtractionStage`], so a pathological document cannot wedge the worker that submitted it.
+//!
+//! Parser code cannot be interrupted from the outside. The extraction and post-processing
+//! stages therefore run on a blocking thread of their own while the caller waits on a timer
+//! ([`run_isolated`]); an abandoned stage keeps its thread until the parser returns, and its
+//! result is discarded. OCR backends already wait on blocking threads, so page OCR is only
+//! raced against a timer ([`with_budget`]).
+//!
+//! Stages without a budget run in place, exactly as without the watchdog.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use kreuzberg::core::watchdog::run_isolated;
+//! use kreuzberg::{ExtractionStage, KreuzbergError};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let budget = Some(Duration::from_millis(50));
+//! let err = run_isolated(ExtractionStage::Extraction, budget, async {
+//!     // A parser stuck without ever yielding
+//!     std::thread::sleep(Duration::from_millis(500));
+//!     Ok(())
+//! })
+//! .await
+//! .unwrap_err();
+//!
+//! assert!(matches!(err, KreuzbergError::Timeout { stage: ExtractionStage::Extraction, budget_ms: 50 }));
+//! # }
+//! ```
+
+use crate::error::ExtractionStage;
+use crate::{KreuzbergError, Result};
+use std::future::Future;
+use std::time::Duration;
+
+/// Run `future` on a blocking thread of its own and abandon it once `budget` elapses.
+///
+/// Unlike [`with_budget`], the budget holds even when `future` never yields. Panics of the
+/// stage are resumed on the caller. Without a budget, `future` is awaited in place.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Timeout` when the budget elapses, otherwise the stage's result.
+pub async fn run_isolated<T, F>(stage: ExtractionStage, budget: Option<Duration>, future: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T>> + Send + 'static,
+{
+    let Some(budget) = budget else {
+        return future.await;
+    };
+
+    let runtime = tokio::runtime::Handle::current();
+    let task = tokio::task::spawn_blocking(move || runtime.block_on(future));
+
+    match tokio::time::timeout(budget, task).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(join_error)) if join_error.is_panic() => std::panic::resume_unwind(join_error.into_panic()),
+        Ok(Err(join_error)) => Err(KreuzbergError::Other(format!(
+            "{} stage task failed: {}",
+            stage, join_error
+        ))),
+        Err(_) => {
+            tracing::warn!("{} stage exceeded its budget of {:?} and was abandoned", stage, budget);
+            Err(KreuzbergError::timeout(stage, budget))
+        }
+    }
+}
+
+/// Await `future` and abandon it once `budget` elapses.
+///
+/// The timer can only fire while `future` is suspended, so this suits stages that wait on
+/// other threads, such as OCR backends. Without a budget, `future` is awaited as is.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Timeout` when the budget elapses, otherwise the stage's result.
+pub async fn with_budget<T>(
+    stage: ExtractionStage,
+    budget: Option<Duration>,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let Some(budget) = budget else {
+        return future.await;
+    };
+
+    tokio::time::timeout(budget, future)
+        .await
+        .unwrap_or_else(|_| Err(KreuzbergError::timeout(stage, budget)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_isolated_within_budget() {
+        let value = run_isolated(ExtractionStage::PostProcessing, Some(Duration::from_secs(5)), async {
+            Ok(42)
+        })
+        .await
+        .unwrap();
+        assert_eq!(value, 42);
+    }
+
+    #[tokio::test]
+    async fn test_run_isolated_propagates_stage_errors() {
+        let err = run_isolated::<(), _>(ExtractionStage::Extraction, Some(Duration::from_secs(5)), async {
+            Err(KreuzbergError::parsing("corrupt xref table"))
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, KreuzbergError::Parsing { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_run_isolated_abandons_blocking_stage() {
+        let started = std::time::Instant::now();
+        let err = run_isolated(ExtractionStage::Extraction, Some(Duration::from_millis(20)), async {
+            std::thread::sleep(Duration::from_millis(300));
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+
+        assert!(started.elapsed() < Duration::from_millis(250));
+        assert_eq!(err.to_string(), "Timeout in extraction stage after 20 ms");
+    }
+
+    #[tokio::test]
+    async fn test_with_budget() {
+        let err = with_budget(ExtractionStage::Ocr, Some(Duration::from_millis(20)), async {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            KreuzbergError::Timeout {
+                stage: ExtractionStage::Ocr,
+                budget_ms: 20
+            }
+        ));
+
+        let value = with_budget(ExtractionStage::Ocr, None, async { Ok("page text") })
+            .await
+            .unwrap();
+        assert_eq!(value, "page text");
+    }
+}
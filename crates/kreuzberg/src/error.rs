@@ -64,6 +64,8 @@ pub type Result<T> = std::result::Result<T, KreuzbergError>;
 /// - `Plugin` - Plugin-specific errors
 /// - `LockPoisoned` - Mutex/RwLock poisoning (should not happen in normal operation)
 /// - `UnsupportedFormat` - Unsupported MIME type or file format
+/// - `InputRejected` - Input refused by `ExtractionConfig::input_policy`
+/// - `Timeout` - Extraction stage exceeded its budget in `ExtractionConfig::stage_timeouts`
 /// - `Other` - Catch-all for uncommon errors
 #[derive(Debug, Error)]
 pub enum KreuzbergError {
@@ -124,10 +126,76 @@ pub enum KreuzbergError {
     #[error("Unsupported format: {0}")]
     UnsupportedFormat(String),
 
+    #[error("Input rejected ({reason}): {message}")]
+    InputRejected { reason: InputRejection, message: String },
+
+    #[error("Timeout in {stage} stage after {budget_ms} ms")]
+    Timeout { stage: ExtractionStage, budget_ms: u64 },
+
     #[error("{0}")]
     Other(String),
 }
 
+/// Policy check an input failed, see [`crate::core::input_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputRejection {
+    /// Larger than `max_file_size_bytes`
+    FileTooLarge,
+    /// MIME type missing from `allowed_mime_types`
+    MimeTypeNotAllowed,
+    /// Magic bytes contradict the declared or extension-derived MIME type
+    ContentMismatch,
+    /// Password-protected input while encrypted inputs are rejected
+    Encrypted,
+}
+
+impl InputRejection {
+    /// Stable identifier, also used as the FFI error code name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InputRejection::FileTooLarge => "file_too_large",
+            InputRejection::MimeTypeNotAllowed => "mime_type_not_allowed",
+            InputRejection::ContentMismatch => "content_mismatch",
+            InputRejection::Encrypted => "encrypted_input",
+        }
+    }
+}
+
+impl std::fmt::Display for InputRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Extraction stage with a time budget, see [`crate::core::watchdog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionStage {
+    /// The format extractor parsing the document, including the OCR it runs
+    Extraction,
+    /// OCR of one page or image
+    Ocr,
+    /// The post-processing pipeline: chunking, language detection and post-processors
+    PostProcessing,
+}
+
+impl ExtractionStage {
+    /// Stable identifier, used in timeout messages.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExtractionStage::Extraction => "extraction",
+            ExtractionStage::Ocr => "ocr",
+            ExtractionStage::PostProcessing => "post_processing",
+        }
+    }
+}
+
+impl std::fmt::Display for ExtractionStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[cfg(feature = "excel")]
 impl From<calamine::Error> for KreuzbergError {
     fn from(err: calamine::Error) -> Self {
@@ -207,6 +275,22 @@ impl KreuzbergError {
     error_constructor!(cache, Cache);
     error_constructor!(image_processing, ImageProcessing);
     error_constructor!(serialization, Serialization);
+
+    /// Create an InputRejected error
+    pub fn input_rejected<S: Into<String>>(reason: InputRejection, message: S) -> Self {
+        Self::InputRejected {
+            reason,
+            message: message.into(),
+        }
+    }
+
+    /// Create a Timeout error for `stage` running past `budget`
+    pub fn timeout(stage: ExtractionStage, budget: std::time::Duration) -> Self {
+        Self::Timeout {
+            stage,
+            budget_ms: u64::try_from(budget.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -255,6 +339,18 @@ mod tests {
         assert_eq!(err.to_string(), "Validation error: invalid input");
     }
 
+    #[test]
+    fn test_input_rejected_error() {
+        let err = KreuzbergError::input_rejected(InputRejection::FileTooLarge, "too big");
+        assert_eq!(err.to_string(), "Input rejected (file_too_large): too big");
+    }
+
+    #[test]
+    fn test_timeout_error() {
+        let err = KreuzbergError::timeout(ExtractionStage::PostProcessing, std::time::Duration::from_secs(2));
+        assert_eq!(err.to_string(), "Timeout in post_processing stage after 2000 ms");
+    }
+
     #[test]
     fn test_validation_error_with_source() {
         let source = std::io::Error::new(std::io::ErrorKind::InvalidInput, "bad param");
@@ -0,0 +1,520 @@
+//! Delimited text (CSV/TSV) parsing with dialect detection.
+//!
+//! Real-world CSV files rarely announce their dialect: European exports use `;`,
+//! database dumps use `|` or tabs, and whether the first row is a header has to be
+//! guessed. This module sniffs the delimiter, quote character and header row from a
+//! sample of the input, parses records (quoted fields, escaped quotes, embedded
+//! newlines, CRLF) and infers a type for every column.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::extraction::csv::{parse_delimited, sniff_dialect};
+//! use kreuzberg::types::CellType;
+//!
+//! let text = "name;price;updated\nApple;1.50;2024-01-02\nPear;0.75;2024-02-03";
+//! let dialect = sniff_dialect(text, None);
+//! assert_eq!(dialect.delimiter, ';');
+//! assert!(dialect.has_header);
+//!
+//! let table = parse_delimited(text, &dialect, None);
+//! assert_eq!(table.rows.len(), 3);
+//! assert_eq!(table.column_types, vec![CellType::String, CellType::Number, CellType::Date]);
+//! ```
+
+use crate::types::CellType;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Delimiters considered during detection, in order of preference on ties.
+pub const CANDIDATE_DELIMITERS: [char; 4] = [',', ';', '\t', '|'];
+
+/// Maximum number of bytes inspected when sniffing the dialect.
+const SNIFF_SAMPLE_BYTES: usize = 64 * 1024;
+
+/// Maximum number of records inspected when sniffing the dialect.
+const SNIFF_SAMPLE_RECORDS: usize = 100;
+
+static NUMBER_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[+-]?[$€£¥]?(?:\d{1,3}(?:,\d{3})+|\d+)?(?:\.\d+)?(?:[eE][+-]?\d+)?%?$")
+        .expect("Number regex pattern is valid and should compile")
+});
+static DATE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?:\d{4}-\d{1,2}-\d{1,2}(?:[T ]\d{1,2}:\d{2}(?::\d{2}(?:\.\d+)?)?(?:Z|[+-]\d{2}:?\d{2})?)?|\d{4}/\d{1,2}/\d{1,2}|\d{1,2}[/.-]\d{1,2}[/.-]\d{2,4})$",
+    )
+    .expect("Date regex pattern is valid and should compile")
+});
+
+/// Detected (or overridden) CSV dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvDialect {
+    /// Field delimiter
+    pub delimiter: char,
+    /// Quote character (None = fields are never quoted)
+    pub quote: Option<char>,
+    /// Whether the first record is a header row
+    pub has_header: bool,
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: Some('"'),
+            has_header: true,
+        }
+    }
+}
+
+/// Parsed delimited text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelimitedTable {
+    /// Dialect the text was parsed with
+    pub dialect: CsvDialect,
+    /// Records (including the header row, if any), padded to a rectangular shape
+    pub rows: Vec<Vec<String>>,
+    /// Inferred type of each column, based on data rows only
+    pub column_types: Vec<CellType>,
+    /// Whether parsing stopped early because of `max_rows`
+    pub truncated: bool,
+}
+
+impl DelimitedTable {
+    /// Number of data rows (excluding the header row).
+    pub fn data_row_count(&self) -> usize {
+        if self.dialect.has_header {
+            self.rows.len().saturating_sub(1)
+        } else {
+            self.rows.len()
+        }
+    }
+
+    /// Number of columns.
+    pub fn column_count(&self) -> usize {
+        self.column_types.len()
+    }
+}
+
+/// Detect the dialect of delimited text.
+///
+/// # Arguments
+///
+/// * `text` - The delimited text (only a leading sample is inspected)
+/// * `delimiter` - Delimiter override; when set, only quoting and header are detected
+pub fn sniff_dialect(text: &str, delimiter: Option<char>) -> CsvDialect {
+    let sample = sample_of(text);
+
+    let delimiter = delimiter.unwrap_or_else(|| detect_delimiter(sample));
+    let quote = detect_quote(sample, delimiter);
+
+    let records: Vec<Vec<String>> = RecordReader::new(sample, delimiter, quote)
+        .take(SNIFF_SAMPLE_RECORDS)
+        .collect();
+    let has_header = detect_header(&records);
+
+    CsvDialect {
+        delimiter,
+        quote,
+        has_header,
+    }
+}
+
+/// Parse delimited text with the given dialect.
+///
+/// Blank lines are skipped and ragged rows are padded with empty cells.
+///
+/// # Arguments
+///
+/// * `text` - The delimited text
+/// * `dialect` - Dialect to parse with (see [`sniff_dialect`])
+/// * `max_rows` - Maximum number of data rows to parse (None = all)
+pub fn parse_delimited(text: &str, dialect: &CsvDialect, max_rows: Option<usize>) -> DelimitedTable {
+    let header_rows = usize::from(dialect.has_header);
+    let limit = max_rows.map(|max| max.saturating_add(header_rows));
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut truncated = false;
+
+    for record in RecordReader::new(text, dialect.delimiter, dialect.quote) {
+        if limit.is_some_and(|limit| rows.len() >= limit) {
+            truncated = true;
+            break;
+        }
+        rows.push(record);
+    }
+
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(column_count, String::new());
+    }
+
+    let column_types = infer_column_types(rows.get(header_rows..).unwrap_or_default(), column_count);
+
+    DelimitedTable {
+        dialect: *dialect,
+        rows,
+        column_types,
+        truncated,
+    }
+}
+
+/// Infer the type of a single cell (None for empty cells).
+pub fn infer_cell_type(value: &str) -> Option<CellType> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    if DATE_PATTERN.is_match(value) {
+        Some(CellType::Date)
+    } else if value.bytes().any(|b| b.is_ascii_digit()) && NUMBER_PATTERN.is_match(value) {
+        Some(CellType::Number)
+    } else {
+        Some(CellType::String)
+    }
+}
+
+/// Infer column types from data rows; a column is only typed if all non-empty cells agree.
+fn infer_column_types(rows: &[Vec<String>], column_count: usize) -> Vec<CellType> {
+    (0..column_count)
+        .map(|col| {
+            let mut column_type: Option<CellType> = None;
+            for cell_type in rows
+                .iter()
+                .filter_map(|row| row.get(col))
+                .filter_map(|c| infer_cell_type(c))
+            {
+                match column_type {
+                    None => column_type = Some(cell_type),
+                    Some(existing) if existing != cell_type => return CellType::String,
+                    Some(_) => {}
+                }
+            }
+            column_type.unwrap_or(CellType::String)
+        })
+        .collect()
+}
+
+fn sample_of(text: &str) -> &str {
+    if text.len() <= SNIFF_SAMPLE_BYTES {
+        return text;
+    }
+
+    let mut end = SNIFF_SAMPLE_BYTES;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let sample = &text[..end];
+    match sample.rfind('\n') {
+        Some(pos) => &sample[..pos],
+        None => sample,
+    }
+}
+
+/// Pick the candidate delimiter that splits the sample into the most consistent field counts.
+fn detect_delimiter(sample: &str) -> char {
+    let mut best: Option<(char, f64, usize)> = None;
+
+    for &candidate in &CANDIDATE_DELIMITERS {
+        let counts: Vec<usize> = RecordReader::new(sample, candidate, Some('"'))
+            .take(SNIFF_SAMPLE_RECORDS)
+            .map(|record| record.len())
+            .collect();
+        if counts.is_empty() {
+            continue;
+        }
+
+        let mut frequencies: Vec<(usize, usize)> = Vec::new();
+        for &count in &counts {
+            match frequencies.iter_mut().find(|(c, _)| *c == count) {
+                Some((_, freq)) => *freq += 1,
+                None => frequencies.push((count, 1)),
+            }
+        }
+        let (mode, freq) = frequencies
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)))
+            .unwrap_or((1, 0));
+        if mode < 2 {
+            continue;
+        }
+
+        let consistency = freq as f64 / counts.len() as f64;
+        let is_better = match best {
+            None => true,
+            Some((_, best_consistency, best_mode)) => {
+                consistency > best_consistency || (consistency == best_consistency && mode > best_mode)
+            }
+        };
+        if is_better {
+            best = Some((candidate, consistency, mode));
+        }
+    }
+
+    best.map(|(delimiter, _, _)| delimiter)
+        .unwrap_or(CANDIDATE_DELIMITERS[0])
+}
+
+/// Detect the quote character by counting quotes that open a field.
+fn detect_quote(sample: &str, delimiter: char) -> Option<char> {
+    let mut double = 0usize;
+    let mut single = 0usize;
+
+    for line in sample.lines() {
+        let mut previous: Option<char> = None;
+        for c in line.chars() {
+            let at_field_start = previous.is_none_or(|p| p == delimiter);
+            if at_field_start {
+                match c {
+                    '"' => double += 1,
+                    '\'' => single += 1,
+                    _ => {}
+                }
+            }
+            previous = Some(c);
+        }
+    }
+
+    if double == 0 && single == 0 {
+        None
+    } else if double >= single {
+        Some('"')
+    } else {
+        Some('\'')
+    }
+}
+
+/// Decide whether the first record is a header row.
+///
+/// Each column votes: typed columns (number/date) vote for a header if the first
+/// cell has a different type, string columns vote by comparing the first cell's
+/// length against a fixed data-cell length. Undecided samples fall back to treating
+/// a first row of distinct, non-empty, non-numeric labels as a header.
+fn detect_header(records: &[Vec<String>]) -> bool {
+    let Some(first) = records.first() else {
+        return false;
+    };
+
+    let looks_like_labels = {
+        let mut seen = std::collections::HashSet::new();
+        first
+            .iter()
+            .all(|cell| infer_cell_type(cell) == Some(CellType::String) && seen.insert(cell.trim()))
+    };
+
+    let data = &records[1..];
+    if data.is_empty() {
+        return looks_like_labels;
+    }
+
+    let column_types = infer_column_types(data, first.len());
+    let mut votes: i32 = 0;
+
+    for (col, header_cell) in first.iter().enumerate() {
+        let column_type = column_types[col];
+        match column_type {
+            CellType::Number | CellType::Date => {
+                if infer_cell_type(header_cell) == Some(column_type) {
+                    votes -= 1;
+                } else {
+                    votes += 1;
+                }
+            }
+            CellType::String => {
+                let mut lengths = data.iter().filter_map(|row| row.get(col)).map(|c| c.chars().count());
+                let Some(length) = lengths.next() else {
+                    continue;
+                };
+                if lengths.all(|l| l == length) {
+                    if header_cell.chars().count() == length {
+                        votes -= 1;
+                    } else {
+                        votes += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    match votes {
+        v if v > 0 => true,
+        0 => looks_like_labels,
+        _ => false,
+    }
+}
+
+/// Streaming record reader over delimited text.
+struct RecordReader<'a> {
+    chars: Peekable<Chars<'a>>,
+    delimiter: char,
+    quote: Option<char>,
+}
+
+impl<'a> RecordReader<'a> {
+    fn new(text: &'a str, delimiter: char, quote: Option<char>) -> Self {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        Self {
+            chars: text.chars().peekable(),
+            delimiter,
+            quote,
+        }
+    }
+
+    fn read_record(&mut self) -> Option<Vec<String>> {
+        self.chars.peek()?;
+
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut field_quoted = false;
+
+        while let Some(c) = self.chars.next() {
+            if in_quotes {
+                if Some(c) == self.quote {
+                    if self.chars.peek() == Some(&c) {
+                        self.chars.next();
+                        field.push(c);
+                    } else {
+                        in_quotes = false;
+                    }
+                } else {
+                    field.push(c);
+                }
+                continue;
+            }
+
+            match c {
+                c if c == self.delimiter => {
+                    fields.push(std::mem::take(&mut field));
+                    field_quoted = false;
+                }
+                '\n' => break,
+                '\r' => {
+                    if self.chars.peek() == Some(&'\n') {
+                        self.chars.next();
+                    }
+                    break;
+                }
+                c if Some(c) == self.quote && field.is_empty() && !field_quoted => {
+                    in_quotes = true;
+                    field_quoted = true;
+                }
+                c => field.push(c),
+            }
+        }
+
+        fields.push(field);
+        Some(fields)
+    }
+}
+
+impl Iterator for RecordReader<'_> {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = self.read_record()?;
+            let is_blank = record.len() == 1 && record[0].trim().is_empty();
+            if !is_blank {
+                return Some(record);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_comma_with_header() {
+        let dialect = sniff_dialect("Name,Age,City\nAlice,30,NYC\nBob,25,LA", None);
+        assert_eq!(dialect.delimiter, ',');
+        assert!(dialect.has_header);
+        assert_eq!(dialect.quote, None);
+    }
+
+    #[test]
+    fn test_sniff_semicolon_and_tab() {
+        assert_eq!(sniff_dialect("a;b;c\n1;2;3\n4;5;6", None).delimiter, ';');
+        assert_eq!(sniff_dialect("a\tb\tc\n1\t2\t3", None).delimiter, '\t');
+        assert_eq!(sniff_dialect("a|b\n1|2\n3|4", None).delimiter, '|');
+    }
+
+    #[test]
+    fn test_sniff_ignores_delimiters_inside_quotes() {
+        let text = "name;comment\n\"Smith, John\";\"hi, there\"\n\"Doe, Jane\";\"ok, bye\"";
+        let dialect = sniff_dialect(text, None);
+        assert_eq!(dialect.delimiter, ';');
+        assert_eq!(dialect.quote, Some('"'));
+    }
+
+    #[test]
+    fn test_sniff_without_header() {
+        let dialect = sniff_dialect("1,2024-01-01,10.5\n2,2024-01-02,11.0\n3,2024-01-03,9.75", None);
+        assert!(!dialect.has_header);
+    }
+
+    #[test]
+    fn test_delimiter_override() {
+        let dialect = sniff_dialect("a,b;c\n1,2;3", Some(';'));
+        assert_eq!(dialect.delimiter, ';');
+    }
+
+    #[test]
+    fn test_parse_quoted_fields_and_newlines() {
+        let text = "id,note\r\n1,\"line one\nline two\"\r\n2,\"say \"\"hi\"\"\"\r\n";
+        let dialect = sniff_dialect(text, None);
+        let table = parse_delimited(text, &dialect, None);
+
+        assert_eq!(table.rows.len(), 3);
+        assert_eq!(table.rows[1][1], "line one\nline two");
+        assert_eq!(table.rows[2][1], "say \"hi\"");
+    }
+
+    #[test]
+    fn test_parse_pads_ragged_rows_and_skips_blank_lines() {
+        let dialect = CsvDialect::default();
+        let table = parse_delimited("a,b,c\n1,2\n\n3,4,5,6", &dialect, None);
+
+        assert_eq!(table.rows.len(), 3);
+        assert!(table.rows.iter().all(|row| row.len() == 4));
+        assert_eq!(table.rows[1], vec!["1", "2", "", ""]);
+    }
+
+    #[test]
+    fn test_parse_max_rows() {
+        let dialect = CsvDialect::default();
+        let table = parse_delimited("h\n1\n2\n3", &dialect, Some(2));
+
+        assert_eq!(table.rows.len(), 3);
+        assert_eq!(table.data_row_count(), 2);
+        assert!(table.truncated);
+    }
+
+    #[test]
+    fn test_column_types() {
+        let text = "product,price,released,code\nApple,\"1,200.50\",2024-01-02,A1\nPear,-3,02/03/2024,17";
+        let dialect = sniff_dialect(text, None);
+        let table = parse_delimited(text, &dialect, None);
+
+        assert_eq!(
+            table.column_types,
+            vec![CellType::String, CellType::Number, CellType::Date, CellType::String]
+        );
+    }
+
+    #[test]
+    fn test_infer_cell_type() {
+        assert_eq!(infer_cell_type(""), None);
+        assert_eq!(infer_cell_type("42"), Some(CellType::Number));
+        assert_eq!(infer_cell_type("3.5e-2"), Some(CellType::Number));
+        assert_eq!(infer_cell_type("$1,000"), Some(CellType::Number));
+        assert_eq!(infer_cell_type("2024-05-06T10:00:00Z"), Some(CellType::Date));
+        assert_eq!(infer_cell_type("-"), Some(CellType::String));
+        assert_eq!(infer_cell_type("hello"), Some(CellType::String));
+    }
+}
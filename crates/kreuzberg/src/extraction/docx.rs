@@ -8,8 +8,13 @@
 
 use crate::error::{KreuzbergError, Result};
 use crate::extraction::capacity;
+use crate::text::outline::Heading;
 use crate::types::PageBoundary;
-use std::io::Cursor;
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek};
+
+/// WordprocessingML main namespace used by `document.xml` and `styles.xml`.
+const WORDML_NAMESPACE: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
 
 /// Extract text from DOCX bytes using docx-lite.
 ///
@@ -197,6 +202,135 @@ fn map_page_breaks_to_boundaries(text: &str, page_breaks: Vec<usize>) -> Result<
     Ok(boundaries)
 }
 
+/// Collect heading paragraphs from a DOCX archive in document order.
+///
+/// A paragraph is a heading when its style is a "heading N" style or defines an outline
+/// level in `word/styles.xml`, or when the paragraph itself sets `<w:outlineLvl>`. Style
+/// names are resolved through `styles.xml`, so localized style IDs are recognised too.
+/// Returned headings carry no position; use `text::outline::locate_headings` to place them
+/// in the extracted text.
+///
+/// # Arguments
+/// * `archive` - The opened DOCX (ZIP) archive
+///
+/// # Returns
+/// * `Ok(Vec<Heading>)` - Headings in document order (empty if none or no document.xml)
+/// * `Err(KreuzbergError)` - If the XML cannot be read or parsed
+pub fn extract_headings<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Vec<Heading>> {
+    let style_levels = match read_archive_entry(archive, "word/styles.xml")? {
+        Some(styles_xml) => heading_style_levels(&styles_xml)?,
+        None => HashMap::new(),
+    };
+
+    match read_archive_entry(archive, "word/document.xml")? {
+        Some(document_xml) => headings_from_document_xml(&document_xml, &style_levels),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn read_archive_entry<R: Read + Seek>(archive: &mut zip::ZipArchive<R>, name: &str) -> Result<Option<String>> {
+    let mut file = match archive.by_name(name) {
+        Ok(file) => file,
+        Err(_) => return Ok(None),
+    };
+    let mut content = String::with_capacity(file.size() as usize);
+    file.read_to_string(&mut content)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read {}: {}", name, e)))?;
+    Ok(Some(content))
+}
+
+/// Map paragraph style IDs to heading levels (1-based) using `styles.xml`.
+fn heading_style_levels(styles_xml: &str) -> Result<HashMap<String, u8>> {
+    let doc = roxmltree::Document::parse(styles_xml)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse styles.xml: {}", e)))?;
+
+    let mut levels = HashMap::new();
+    for style in doc.descendants().filter(|n| is_wordml(n, "style")) {
+        if style.attribute((WORDML_NAMESPACE, "type")) != Some("paragraph") {
+            continue;
+        }
+        let Some(style_id) = style.attribute((WORDML_NAMESPACE, "styleId")) else {
+            continue;
+        };
+
+        let outline_level = style
+            .children()
+            .find(|n| is_wordml(n, "pPr"))
+            .and_then(paragraph_outline_level);
+        let name_level = style
+            .children()
+            .find(|n| is_wordml(n, "name"))
+            .and_then(|n| n.attribute((WORDML_NAMESPACE, "val")))
+            .and_then(builtin_heading_level);
+
+        if let Some(level) = outline_level.or(name_level) {
+            levels.insert(style_id.to_string(), level);
+        }
+    }
+
+    Ok(levels)
+}
+
+fn headings_from_document_xml(document_xml: &str, style_levels: &HashMap<String, u8>) -> Result<Vec<Heading>> {
+    let doc = roxmltree::Document::parse(document_xml)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse document.xml: {}", e)))?;
+
+    let mut headings = Vec::new();
+    for paragraph in doc.descendants().filter(|n| is_wordml(n, "p")) {
+        let Some(properties) = paragraph.children().find(|n| is_wordml(n, "pPr")) else {
+            continue;
+        };
+
+        let style_level = properties
+            .children()
+            .find(|n| is_wordml(n, "pStyle"))
+            .and_then(|n| n.attribute((WORDML_NAMESPACE, "val")))
+            .and_then(|style_id| {
+                style_levels
+                    .get(style_id)
+                    .copied()
+                    .or_else(|| builtin_heading_level(style_id))
+            });
+        let Some(level) = paragraph_outline_level(properties).or(style_level) else {
+            continue;
+        };
+
+        let title: String = paragraph
+            .descendants()
+            .filter(|n| is_wordml(n, "t"))
+            .filter_map(|n| n.text())
+            .collect();
+        let title = title.trim();
+        if !title.is_empty() {
+            headings.push(Heading::new(level, title));
+        }
+    }
+
+    Ok(headings)
+}
+
+fn is_wordml(node: &roxmltree::Node, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name && node.tag_name().namespace() == Some(WORDML_NAMESPACE)
+}
+
+/// Heading level from a `<w:pPr>` outline level (`0` is level 1; `9` means body text).
+fn paragraph_outline_level(properties: roxmltree::Node) -> Option<u8> {
+    properties
+        .children()
+        .find(|n| is_wordml(n, "outlineLvl"))
+        .and_then(|n| n.attribute((WORDML_NAMESPACE, "val")))
+        .and_then(|val| val.parse::<u8>().ok())
+        .filter(|&level| level < 9)
+        .map(|level| level + 1)
+}
+
+/// Heading level from a built-in style name or ID (`heading 2`, `Heading2`).
+fn builtin_heading_level(name: &str) -> Option<u8> {
+    let lower = name.to_ascii_lowercase();
+    let digits = lower.strip_prefix("heading")?.trim_start();
+    digits.parse::<u8>().ok().filter(|level| (1..=9).contains(level))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,4 +542,30 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_headings_from_document_xml() {
+        let styles_xml = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:style w:type="paragraph" w:styleId="berschrift1"><w:name w:val="heading 1"/></w:style>
+            <w:style w:type="paragraph" w:styleId="Chapter"><w:name w:val="Chapter"/><w:pPr><w:outlineLvl w:val="1"/></w:pPr></w:style>
+            <w:style w:type="paragraph" w:styleId="Normal"><w:name w:val="Normal"/></w:style>
+        </w:styles>"#;
+        let document_xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+            <w:p><w:pPr><w:pStyle w:val="berschrift1"/></w:pPr><w:r><w:t>Intro</w:t></w:r><w:r><w:t>duction</w:t></w:r></w:p>
+            <w:p><w:pPr><w:pStyle w:val="Normal"/></w:pPr><w:r><w:t>Body text</w:t></w:r></w:p>
+            <w:p><w:pPr><w:pStyle w:val="Chapter"/></w:pPr><w:r><w:t>Scope</w:t></w:r></w:p>
+            <w:p><w:pPr><w:pStyle w:val="Heading3"/></w:pPr><w:r><w:t>Details</w:t></w:r></w:p>
+            <w:p><w:pPr><w:outlineLvl w:val="0"/></w:pPr><w:r><w:t>Appendix</w:t></w:r></w:p>
+            <w:p><w:pPr><w:pStyle w:val="Heading2"/></w:pPr></w:p>
+        </w:body></w:document>"#;
+
+        let levels = heading_style_levels(styles_xml).unwrap();
+        let headings = headings_from_document_xml(document_xml, &levels).unwrap();
+
+        let found: Vec<(u8, &str)> = headings.iter().map(|h| (h.level, h.title.as_str())).collect();
+        assert_eq!(
+            found,
+            [(1, "Introduction"), (2, "Scope"), (3, "Details"), (1, "Appendix")]
+        );
+    }
 }
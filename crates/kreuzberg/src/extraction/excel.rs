@@ -7,7 +7,8 @@
 //! # Features
 //!
 //! - **Multiple formats**: XLSX, XLSM, XLS, XLSB, ODS
-//! - **Sheet extraction**: Reads all sheets from workbook
+//! - **Sheet extraction**: Reads all sheets from workbook, or a selection via [`SpreadsheetConfig`]
+//! - **Streaming**: XLSX/XLSB sheets can be read cell by cell with bounded memory
 //! - **Markdown conversion**: Converts spreadsheet data to Markdown tables
 //! - **Office metadata**: Extracts core properties, custom properties (when `office` feature enabled)
 //! - **Error handling**: Distinguishes between format errors and true I/O errors
@@ -27,12 +28,14 @@
 //! # Ok(())
 //! # }
 //! ```
-use calamine::{Data, Range, Reader, open_workbook_auto};
+use calamine::{Data, Range, Reader, Sheets, open_workbook_auto};
 use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
-use std::io::Cursor;
+use std::fs::File;
+use std::io::{BufReader, Cursor};
 use std::path::Path;
 
+use crate::core::config::{SheetSelector, SpreadsheetConfig};
 use crate::error::{KreuzbergError, Result};
 use crate::extraction::capacity;
 use crate::types::{ExcelSheet, ExcelWorkbook};
@@ -44,7 +47,25 @@ use crate::extraction::office_metadata::{
 #[cfg(feature = "office")]
 use serde_json::Value;
 
+/// A single worksheet row delivered by [`for_each_excel_row`].
+#[derive(Debug, Clone, Copy)]
+pub struct ExcelRow<'a> {
+    /// Name of the sheet the row belongs to
+    pub sheet_name: &'a str,
+    /// Zero-based position of the sheet in the workbook
+    pub sheet_index: usize,
+    /// Zero-based row number within the sheet (spreadsheet row 1 is 0)
+    pub row_index: usize,
+    /// Formatted cell values, indexed from column A
+    pub cells: &'a [String],
+}
+
 pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
+    read_excel_file_with_options(file_path, &SpreadsheetConfig::default())
+}
+
+/// Read a workbook from disk, honouring sheet selection, row limits and streaming.
+pub fn read_excel_file_with_options(file_path: &str, options: &SpreadsheetConfig) -> Result<ExcelWorkbook> {
     #[cfg(feature = "office")]
     let office_metadata = if file_path.to_lowercase().ends_with(".xlsx")
         || file_path.to_lowercase().ends_with(".xlsm")
@@ -59,9 +80,103 @@ pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
     #[cfg(not(feature = "office"))]
     let office_metadata: Option<HashMap<String, String>> = None;
 
+    let workbook = open_excel_file(file_path)?;
+
+    if !options.stream {
+        return process_workbook(workbook, office_metadata, options, load_sheet);
+    }
+
+    match workbook {
+        Sheets::Xlsx(workbook) => process_workbook(workbook, office_metadata, options, load_xlsx_sheet_streaming),
+        Sheets::Xlsb(workbook) => process_workbook(workbook, office_metadata, options, load_xlsb_sheet_streaming),
+        other => process_workbook(other, office_metadata, options, load_sheet),
+    }
+}
+
+/// Stream the rows of a workbook to `on_row` without building an [`ExcelWorkbook`].
+///
+/// XLSX and XLSB sheets are read cell by cell, so memory stays bounded by the
+/// size of a single row; other formats load one sheet at a time. Empty rows
+/// are skipped. `options.max_rows` limits the data rows per sheet (the first
+/// row of each sheet is treated as the header). Return `false` from `on_row`
+/// to stop early.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if a selected sheet does not exist and
+/// `KreuzbergError::Parsing` if the workbook cannot be read.
+pub fn for_each_excel_row<F>(file_path: &str, options: &SpreadsheetConfig, mut on_row: F) -> Result<()>
+where
+    F: FnMut(ExcelRow<'_>) -> bool,
+{
+    let mut workbook = open_excel_file(file_path)?;
+    let selected = select_sheets(&workbook.sheet_names(), options.sheets.as_deref())?;
+
+    for (sheet_index, sheet_name) in &selected {
+        let mut emit = |row_index: u32, cells: &[String]| {
+            on_row(ExcelRow {
+                sheet_name,
+                sheet_index: *sheet_index,
+                row_index: row_index as usize,
+                cells,
+            })
+        };
+
+        let keep_going = match &mut workbook {
+            Sheets::Xlsx(workbook) => {
+                let mut reader = workbook
+                    .worksheet_cells_reader(sheet_name)
+                    .map_err(|e| KreuzbergError::parsing(format!("Failed to read sheet '{}': {}", sheet_name, e)))?;
+                emit_cell_rows(
+                    || {
+                        reader
+                            .next_cell()
+                            .map(|cell| cell.map(|c| (c.get_position(), Data::from(c.get_value().clone()))))
+                            .map_err(|e| KreuzbergError::parsing(format!("Failed to read XLSX cell: {}", e)))
+                    },
+                    options.max_rows,
+                    &mut emit,
+                )?
+            }
+            Sheets::Xlsb(workbook) => {
+                let mut reader = workbook
+                    .worksheet_cells_reader(sheet_name)
+                    .map_err(|e| KreuzbergError::parsing(format!("Failed to read sheet '{}': {}", sheet_name, e)))?;
+                emit_cell_rows(
+                    || {
+                        reader
+                            .next_cell()
+                            .map(|cell| cell.map(|c| (c.get_position(), Data::from(c.get_value().clone()))))
+                            .map_err(|e| KreuzbergError::parsing(format!("Failed to read XLSB cell: {}", e)))
+                    },
+                    options.max_rows,
+                    &mut emit,
+                )?
+            }
+            other => {
+                let range = other
+                    .worksheet_range(sheet_name)
+                    .map_err(|e| KreuzbergError::parsing(format!("Failed to read sheet '{}': {}", sheet_name, e)))?;
+                let (start_row, start_col) = range.start().unwrap_or((0, 0));
+                let mut cells = range
+                    .used_cells()
+                    .map(|(row, col, value)| ((start_row + row as u32, start_col + col as u32), value.clone()));
+                emit_cell_rows(|| Ok(cells.next()), options.max_rows, &mut emit)?
+            }
+        };
+
+        if !keep_going {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn open_excel_file(file_path: &str) -> Result<Sheets<BufReader<File>>> {
     // We analyze the error and only wrap format errors, letting real IO errors bubble up ~keep
-    let workbook = match open_workbook_auto(Path::new(file_path)) {
-        Ok(wb) => wb,
+    match open_workbook_auto(Path::new(file_path)) {
+        Ok(wb) => Ok(wb),
         Err(calamine::Error::Io(io_err)) => {
             if io_err.kind() == std::io::ErrorKind::InvalidData {
                 return Err(KreuzbergError::parsing(format!(
@@ -70,15 +185,22 @@ pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
                 )));
             }
             // Real IO error - bubble up unchanged ~keep
-            return Err(io_err.into());
+            Err(io_err.into())
         }
-        Err(e) => return Err(KreuzbergError::parsing(format!("Failed to parse Excel file: {}", e))),
-    };
-
-    process_workbook(workbook, office_metadata)
+        Err(e) => Err(KreuzbergError::parsing(format!("Failed to parse Excel file: {}", e))),
+    }
 }
 
 pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbook> {
+    read_excel_bytes_with_options(data, file_extension, &SpreadsheetConfig::default())
+}
+
+/// Read a workbook from memory, honouring sheet selection, row limits and streaming.
+pub fn read_excel_bytes_with_options(
+    data: &[u8],
+    file_extension: &str,
+    options: &SpreadsheetConfig,
+) -> Result<ExcelWorkbook> {
     #[cfg(feature = "office")]
     let office_metadata = match file_extension.to_lowercase().as_str() {
         ".xlsx" | ".xlsm" | ".xlam" | ".xltm" => extract_xlsx_office_metadata_from_bytes(data).ok(),
@@ -94,22 +216,30 @@ pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbo
         ".xlsx" | ".xlsm" | ".xlam" | ".xltm" => {
             let workbook = calamine::Xlsx::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLSX: {}", e)))?;
-            process_workbook(workbook, office_metadata)
+            if options.stream {
+                process_workbook(workbook, office_metadata, options, load_xlsx_sheet_streaming)
+            } else {
+                process_workbook(workbook, office_metadata, options, load_sheet)
+            }
         }
         ".xls" | ".xla" => {
             let workbook = calamine::Xls::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLS: {}", e)))?;
-            process_workbook(workbook, office_metadata)
+            process_workbook(workbook, office_metadata, options, load_sheet)
         }
         ".xlsb" => {
             let workbook = calamine::Xlsb::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLSB: {}", e)))?;
-            process_workbook(workbook, office_metadata)
+            if options.stream {
+                process_workbook(workbook, office_metadata, options, load_xlsb_sheet_streaming)
+            } else {
+                process_workbook(workbook, office_metadata, options, load_sheet)
+            }
         }
         ".ods" => {
             let workbook = calamine::Ods::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse ODS: {}", e)))?;
-            process_workbook(workbook, office_metadata)
+            process_workbook(workbook, office_metadata, options, load_sheet)
         }
         _ => Err(KreuzbergError::parsing(format!(
             "Unsupported file extension: {}",
@@ -118,18 +248,25 @@ pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbo
     }
 }
 
-fn process_workbook<RS, R>(mut workbook: R, office_metadata: Option<HashMap<String, String>>) -> Result<ExcelWorkbook>
+fn process_workbook<RS, R, L>(
+    mut workbook: R,
+    office_metadata: Option<HashMap<String, String>>,
+    options: &SpreadsheetConfig,
+    mut load_sheet: L,
+) -> Result<ExcelWorkbook>
 where
     RS: std::io::Read + std::io::Seek,
     R: Reader<RS>,
+    L: FnMut(&mut R, &str, Option<usize>) -> Option<ExcelSheet>,
 {
     let sheet_names = workbook.sheet_names();
+    let selected = select_sheets(&sheet_names, options.sheets.as_deref())?;
 
-    let mut sheets = Vec::with_capacity(sheet_names.len());
+    let mut sheets = Vec::with_capacity(selected.len());
 
-    for name in &sheet_names {
-        if let Ok(range) = workbook.worksheet_range(name) {
-            sheets.push(process_sheet(name, &range));
+    for (_, name) in &selected {
+        if let Some(sheet) = load_sheet(&mut workbook, name, options.max_rows) {
+            sheets.push(sheet);
         }
     }
 
@@ -138,6 +275,231 @@ where
     Ok(ExcelWorkbook { sheets, metadata })
 }
 
+/// Resolve sheet selectors to `(index, name)` pairs in workbook order.
+fn select_sheets(sheet_names: &[String], selectors: Option<&[SheetSelector]>) -> Result<Vec<(usize, String)>> {
+    let Some(selectors) = selectors else {
+        return Ok(sheet_names.iter().cloned().enumerate().collect());
+    };
+
+    let mut indices = Vec::with_capacity(selectors.len());
+    for selector in selectors {
+        let index = match selector {
+            SheetSelector::Index(index) if *index < sheet_names.len() => *index,
+            SheetSelector::Index(index) => {
+                return Err(KreuzbergError::validation(format!(
+                    "Sheet index {} is out of range: workbook has {} sheets",
+                    index,
+                    sheet_names.len()
+                )));
+            }
+            SheetSelector::Name(name) => sheet_names.iter().position(|n| n == name).ok_or_else(|| {
+                KreuzbergError::validation(format!(
+                    "Sheet '{}' not found. Available sheets: {}",
+                    name,
+                    sheet_names.join(", ")
+                ))
+            })?,
+        };
+        indices.push(index);
+    }
+
+    indices.sort_unstable();
+    indices.dedup();
+
+    Ok(indices.into_iter().map(|i| (i, sheet_names[i].clone())).collect())
+}
+
+/// Whether `row` lies beyond the header plus `max_rows` data rows.
+#[inline]
+fn exceeds_row_limit(row: u32, first_row: u32, max_rows: Option<usize>) -> bool {
+    max_rows.is_some_and(|max| (row - first_row) as usize > max)
+}
+
+fn load_sheet<RS, R>(workbook: &mut R, name: &str, max_rows: Option<usize>) -> Option<ExcelSheet>
+where
+    RS: std::io::Read + std::io::Seek,
+    R: Reader<RS>,
+{
+    let range = workbook.worksheet_range(name).ok()?;
+    Some(process_sheet(name, &truncate_range(range, max_rows)))
+}
+
+/// Keep the header row plus at most `max_rows` data rows.
+fn truncate_range(range: Range<Data>, max_rows: Option<usize>) -> Range<Data> {
+    match (max_rows, range.start(), range.end()) {
+        (Some(max), Some((start_row, start_col)), Some((end_row, end_col))) if (end_row - start_row) as usize > max => {
+            range.range((start_row, start_col), (start_row + max as u32, end_col))
+        }
+        _ => range,
+    }
+}
+
+fn load_xlsx_sheet_streaming<RS>(
+    workbook: &mut calamine::Xlsx<RS>,
+    name: &str,
+    max_rows: Option<usize>,
+) -> Option<ExcelSheet>
+where
+    RS: std::io::Read + std::io::Seek,
+{
+    let mut reader = workbook.worksheet_cells_reader(name).ok()?;
+    stream_sheet(
+        name,
+        || {
+            reader
+                .next_cell()
+                .map(|cell| cell.map(|c| (c.get_position(), Data::from(c.get_value().clone()))))
+                .map_err(|e| KreuzbergError::parsing(format!("Failed to read XLSX cell: {}", e)))
+        },
+        max_rows,
+    )
+    .ok()
+}
+
+fn load_xlsb_sheet_streaming<RS>(
+    workbook: &mut calamine::Xlsb<RS>,
+    name: &str,
+    max_rows: Option<usize>,
+) -> Option<ExcelSheet>
+where
+    RS: std::io::Read + std::io::Seek,
+{
+    let mut reader = workbook.worksheet_cells_reader(name).ok()?;
+    stream_sheet(
+        name,
+        || {
+            reader
+                .next_cell()
+                .map(|cell| cell.map(|c| (c.get_position(), Data::from(c.get_value().clone()))))
+                .map_err(|e| KreuzbergError::parsing(format!("Failed to read XLSB cell: {}", e)))
+        },
+        max_rows,
+    )
+    .ok()
+}
+
+/// Build a sheet from a row-major cell stream, formatting each row as it arrives.
+///
+/// Only the formatted strings are kept, never the cells or a dense [`Range`] of them, and
+/// the result matches [`process_sheet`] on the equivalent range: the table starts at the
+/// first used row and column, and rows without cells become empty table rows.
+fn stream_sheet<F>(name: &str, next_cell: F, max_rows: Option<usize>) -> Result<ExcelSheet>
+where
+    F: FnMut() -> Result<Option<((u32, u32), Data)>>,
+{
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut first_row = None;
+    let mut first_col = usize::MAX;
+    let mut cell_count = 0;
+
+    emit_cell_rows(next_cell, max_rows, |row, cells| {
+        let first = *first_row.get_or_insert(row);
+        rows.resize((row - first) as usize, Vec::new());
+        if let Some(col) = cells.iter().position(|cell| !cell.is_empty()) {
+            first_col = first_col.min(col);
+        }
+        cell_count += cells.iter().filter(|cell| !cell.is_empty()).count();
+        rows.push(cells.to_vec());
+        true
+    })?;
+
+    let col_count = rows.iter().map(Vec::len).max().unwrap_or(0).saturating_sub(first_col);
+    if col_count == 0 {
+        return Ok(ExcelSheet {
+            name: name.to_owned(),
+            markdown: format!("## {}\n\n*Empty sheet*", name),
+            row_count: 0,
+            col_count: 0,
+            cell_count: 0,
+            table_cells: None,
+        });
+    }
+
+    for row in &mut rows {
+        row.drain(..first_col.min(row.len()));
+        row.resize(col_count, String::new());
+    }
+
+    let mut markdown = String::with_capacity(capacity::estimate_table_markdown_capacity(rows.len(), col_count));
+    write!(markdown, "## {}\n\n", name).unwrap();
+    for (index, row) in rows.iter().enumerate() {
+        markdown.push_str("| ");
+        for (i, cell) in row.iter().enumerate() {
+            if i > 0 {
+                markdown.push_str(" | ");
+            }
+            if cell.contains('|') || cell.contains('\\') {
+                escape_markdown_into(&mut markdown, cell);
+            } else {
+                markdown.push_str(cell);
+            }
+        }
+        markdown.push_str(" |\n");
+
+        if index == 0 {
+            markdown.push_str("| ");
+            markdown.push_str(&vec!["---"; col_count].join(" | "));
+            markdown.push_str(" |\n");
+        }
+    }
+
+    Ok(ExcelSheet {
+        name: name.to_owned(),
+        markdown,
+        row_count: rows.len(),
+        col_count,
+        cell_count,
+        table_cells: Some(rows),
+    })
+}
+
+/// Group a row-major cell stream into rows and pass each one to `emit`.
+///
+/// Returns `Ok(false)` if `emit` asked to stop.
+fn emit_cell_rows<F, G>(mut next_cell: F, max_rows: Option<usize>, mut emit: G) -> Result<bool>
+where
+    F: FnMut() -> Result<Option<((u32, u32), Data)>>,
+    G: FnMut(u32, &[String]) -> bool,
+{
+    let mut first_row = None;
+    let mut current_row = None;
+    let mut cells: Vec<String> = Vec::new();
+
+    while let Some(((row, col), value)) = next_cell()? {
+        if matches!(value, Data::Empty) {
+            continue;
+        }
+        let first = *first_row.get_or_insert(row);
+        if exceeds_row_limit(row, first, max_rows) {
+            break;
+        }
+
+        if current_row != Some(row) {
+            if let Some(previous) = current_row
+                && !emit(previous, &cells)
+            {
+                return Ok(false);
+            }
+            cells.clear();
+            current_row = Some(row);
+        }
+
+        let col = col as usize;
+        if cells.len() <= col {
+            cells.resize(col + 1, String::new());
+        }
+        cells[col] = format_cell_to_string(&value);
+    }
+
+    if let Some(previous) = current_row
+        && !emit(previous, &cells)
+    {
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
 #[inline]
 fn process_sheet(name: &str, range: &Range<Data>) -> ExcelSheet {
     let (rows, cols) = range.get_size();
@@ -708,4 +1070,176 @@ mod tests {
         assert_eq!(sheet.col_count, 5);
         assert_eq!(sheet.cell_count, 50);
     }
+
+    fn sheet_names() -> Vec<String> {
+        vec!["Summary".to_string(), "Data".to_string(), "Notes".to_string()]
+    }
+
+    #[test]
+    fn test_select_sheets_all_by_default() {
+        let selected = select_sheets(&sheet_names(), None).unwrap();
+        assert_eq!(selected.len(), 3);
+        assert_eq!(selected[2], (2, "Notes".to_string()));
+    }
+
+    #[test]
+    fn test_select_sheets_by_name_and_index() {
+        let selectors = vec![
+            SheetSelector::Index(2),
+            SheetSelector::Name("Summary".to_string()),
+            SheetSelector::Index(0),
+        ];
+        let selected = select_sheets(&sheet_names(), Some(&selectors)).unwrap();
+        assert_eq!(selected, vec![(0, "Summary".to_string()), (2, "Notes".to_string())]);
+    }
+
+    #[test]
+    fn test_select_sheets_unknown_sheet() {
+        let err = select_sheets(&sheet_names(), Some(&[SheetSelector::Name("Missing".to_string())])).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+
+        let err = select_sheets(&sheet_names(), Some(&[SheetSelector::Index(3)])).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_sheet_selector_deserializes_names_and_indices() {
+        let selectors: Vec<SheetSelector> = serde_json::from_str(r#"["Sheet1", 2]"#).unwrap();
+        assert_eq!(
+            selectors,
+            vec![SheetSelector::Name("Sheet1".to_string()), SheetSelector::Index(2)]
+        );
+    }
+
+    #[test]
+    fn test_truncate_range_keeps_header_and_max_rows() {
+        let mut range: Range<Data> = Range::new((0, 0), (9, 1));
+        for row in 0..10 {
+            range.set_value((row, 0), Data::Int(row as i64));
+            range.set_value((row, 1), Data::String(format!("row {}", row)));
+        }
+
+        let truncated = truncate_range(range.clone(), Some(3));
+        assert_eq!(truncated.get_size(), (4, 2));
+
+        let sheet = process_sheet("Data", &truncated);
+        assert_eq!(sheet.table_cells.unwrap().len(), 4);
+
+        assert_eq!(truncate_range(range, None).get_size(), (10, 2));
+    }
+
+    fn cell_stream(cells: Vec<((u32, u32), Data)>) -> impl FnMut() -> Result<Option<((u32, u32), Data)>> {
+        let mut cells = cells.into_iter();
+        move || Ok(cells.next())
+    }
+
+    #[test]
+    fn test_stream_sheet_stops_after_row_limit() {
+        let stream = cell_stream(vec![
+            ((1, 1), Data::String("id".to_string())),
+            ((2, 1), Data::Int(1)),
+            ((3, 1), Data::Empty),
+            ((3, 2), Data::Int(2)),
+            ((4, 1), Data::Int(3)),
+        ]);
+
+        let sheet = stream_sheet("Data", stream, Some(2)).unwrap();
+        assert_eq!(sheet.cell_count, 3);
+        assert_eq!((sheet.row_count, sheet.col_count), (3, 2));
+    }
+
+    #[test]
+    fn test_stream_sheet_matches_range_processing() {
+        let cells = vec![
+            ((1, 1), Data::String("name".to_string())),
+            ((1, 3), Data::String("a|b".to_string())),
+            ((2, 1), Data::String("Alice".to_string())),
+            ((2, 2), Data::Float(30.5)),
+            ((4, 3), Data::Bool(true)),
+        ];
+        let range = Range::from_sparse(
+            cells
+                .iter()
+                .map(|(position, value)| calamine::Cell::new(*position, value.clone()))
+                .collect(),
+        );
+
+        let expected = process_sheet("Data", &range);
+        let streamed = stream_sheet("Data", cell_stream(cells), None).unwrap();
+        assert_eq!(streamed.markdown, expected.markdown);
+        assert_eq!(streamed.table_cells, expected.table_cells);
+        assert_eq!(
+            (streamed.row_count, streamed.col_count, streamed.cell_count),
+            (expected.row_count, expected.col_count, expected.cell_count)
+        );
+
+        let empty = stream_sheet("Empty", cell_stream(Vec::new()), None).unwrap();
+        assert_eq!(empty.markdown, process_sheet("Empty", &Range::empty()).markdown);
+    }
+
+    #[test]
+    fn test_streamed_workbooks_match_loaded_workbooks() {
+        let documents = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test_documents");
+        for file in ["spreadsheets/stanley_cups.xlsx", "spreadsheets/excel_multi_sheet.xlsx"] {
+            let path = documents.join(file);
+            let path = path.to_str().unwrap();
+            let loaded = read_excel_file(path).unwrap();
+            let options = SpreadsheetConfig {
+                stream: true,
+                ..Default::default()
+            };
+            let streamed = read_excel_file_with_options(path, &options).unwrap();
+
+            assert_eq!(streamed.sheets.len(), loaded.sheets.len(), "{file}");
+            for (streamed, loaded) in streamed.sheets.iter().zip(&loaded.sheets) {
+                assert_eq!(streamed.markdown, loaded.markdown, "{file}");
+                assert_eq!(streamed.table_cells, loaded.table_cells, "{file}");
+                assert_eq!(streamed.cell_count, loaded.cell_count, "{file}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_emit_cell_rows_groups_by_row() {
+        let stream = cell_stream(vec![
+            ((0, 0), Data::String("name".to_string())),
+            ((0, 2), Data::String("age".to_string())),
+            ((2, 0), Data::String("Alice".to_string())),
+            ((2, 2), Data::Float(30.5)),
+        ]);
+
+        let mut rows = Vec::new();
+        let completed = emit_cell_rows(stream, None, |row, cells| {
+            rows.push((row, cells.to_vec()));
+            true
+        })
+        .unwrap();
+
+        assert!(completed);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], (0, vec!["name".to_string(), String::new(), "age".to_string()]));
+        assert_eq!(
+            rows[1],
+            (2, vec!["Alice".to_string(), String::new(), "30.5".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_emit_cell_rows_stops_when_callback_declines() {
+        let stream = cell_stream(vec![
+            ((0, 0), Data::Int(1)),
+            ((1, 0), Data::Int(2)),
+            ((2, 0), Data::Int(3)),
+        ]);
+
+        let mut seen = 0;
+        let completed = emit_cell_rows(stream, None, |_, _| {
+            seen += 1;
+            seen < 2
+        })
+        .unwrap();
+
+        assert!(!completed);
+        assert_eq!(seen, 2);
+    }
 }
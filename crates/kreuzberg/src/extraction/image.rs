@@ -4,7 +4,8 @@
 //! including support for multi-frame TIFF files.
 
 use crate::error::{KreuzbergError, Result};
-use exif::{In, Reader, Tag};
+use crate::types::{ExifCamera, ExifDetails, ExifTimestamps, GpsCoordinates};
+use exif::{Exif, In, Reader, Tag, Value};
 use image::ImageReader;
 use std::collections::HashMap;
 use std::io::Cursor;
@@ -20,6 +21,25 @@ pub struct ImageMetadata {
     pub format: String,
     /// EXIF data if available
     pub exif_data: HashMap<String, String>,
+    /// Structured EXIF data (camera, timestamps, GPS) if available
+    pub exif_details: Option<ExifDetails>,
+}
+
+impl ImageMetadata {
+    /// Remove GPS coordinates from both the raw and the structured EXIF data.
+    pub fn strip_gps(&mut self) {
+        self.exif_data.retain(|key, _| !key.starts_with("GPS"));
+        if let Some(details) = &mut self.exif_details {
+            details.gps = None;
+        }
+        if self
+            .exif_details
+            .as_ref()
+            .is_some_and(|details| details == &ExifDetails::default())
+        {
+            self.exif_details = None;
+        }
+    }
 }
 
 /// Extract metadata from image bytes.
@@ -42,27 +62,34 @@ pub fn extract_image_metadata(bytes: &[u8]) -> Result<ImageMetadata> {
     let height = image.height();
     let format_str = format!("{:?}", format).to_uppercase();
 
-    let exif_data = extract_exif_data(bytes);
+    let exif = read_exif(bytes);
+    let exif_data = exif.as_ref().map(exif_tag_map).unwrap_or_default();
+    let exif_details = exif.as_ref().and_then(exif_details);
 
     Ok(ImageMetadata {
         width,
         height,
         format: format_str,
         exif_data,
+        exif_details,
     })
 }
 
+fn read_exif(bytes: &[u8]) -> Option<Exif> {
+    Reader::new().read_from_container(&mut Cursor::new(bytes)).ok()
+}
+
 /// Extract EXIF data from image bytes.
 ///
 /// Returns a HashMap of EXIF tags and their values.
 /// If EXIF data is not available or cannot be parsed, returns an empty HashMap.
+#[cfg(test)]
 fn extract_exif_data(bytes: &[u8]) -> HashMap<String, String> {
-    let mut exif_map = HashMap::new();
+    read_exif(bytes).as_ref().map(exif_tag_map).unwrap_or_default()
+}
 
-    let exif_reader = match Reader::new().read_from_container(&mut Cursor::new(bytes)) {
-        Ok(reader) => reader,
-        Err(_) => return exif_map,
-    };
+fn exif_tag_map(exif: &Exif) -> HashMap<String, String> {
+    let mut exif_map = HashMap::new();
 
     let common_tags = [
         (Tag::Make, "Make"),
@@ -87,7 +114,7 @@ fn extract_exif_data(bytes: &[u8]) -> HashMap<String, String> {
     ];
 
     for (tag, field_name) in common_tags {
-        if let Some(field) = exif_reader.get_field(tag, In::PRIMARY) {
+        if let Some(field) = exif.get_field(tag, In::PRIMARY) {
             exif_map.insert(field_name.to_string(), field.display_value().to_string());
         }
     }
@@ -95,6 +122,90 @@ fn extract_exif_data(bytes: &[u8]) -> HashMap<String, String> {
     exif_map
 }
 
+/// Build structured EXIF metadata (camera, timestamps, GPS).
+///
+/// Returns `None` if none of the covered tags are present.
+fn exif_details(exif: &Exif) -> Option<ExifDetails> {
+    let camera = ExifCamera {
+        make: ascii_field(exif, Tag::Make),
+        model: ascii_field(exif, Tag::Model),
+        lens_model: ascii_field(exif, Tag::LensModel),
+        software: ascii_field(exif, Tag::Software),
+    };
+    let timestamps = ExifTimestamps {
+        original: timestamp_field(exif, Tag::DateTimeOriginal),
+        digitized: timestamp_field(exif, Tag::DateTimeDigitized),
+        modified: timestamp_field(exif, Tag::DateTime),
+    };
+    let gps = gps_coordinate(exif, Tag::GPSLatitude, Tag::GPSLatitudeRef, b'S')
+        .zip(gps_coordinate(exif, Tag::GPSLongitude, Tag::GPSLongitudeRef, b'W'))
+        .map(|(latitude, longitude)| GpsCoordinates {
+            latitude,
+            longitude,
+            altitude: gps_altitude(exif),
+        });
+
+    let details = ExifDetails {
+        camera: (camera != ExifCamera::default()).then_some(camera),
+        timestamps: (timestamps != ExifTimestamps::default()).then_some(timestamps),
+        gps,
+    };
+    (details != ExifDetails::default()).then_some(details)
+}
+
+fn ascii_field(exif: &Exif, tag: Tag) -> Option<String> {
+    let Value::Ascii(parts) = &exif.get_field(tag, In::PRIMARY)?.value else {
+        return None;
+    };
+    let text = String::from_utf8_lossy(parts.first()?);
+    let text = text.trim_matches(|c: char| c == '\0' || c.is_whitespace());
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Convert an EXIF `YYYY:MM:DD HH:MM:SS` timestamp to ISO 8601.
+fn timestamp_field(exif: &Exif, tag: Tag) -> Option<String> {
+    let raw = ascii_field(exif, tag)?;
+    let (date, time) = raw.split_once(' ')?;
+    let date = date.replace(':', "-");
+    let valid =
+        date.len() == 10 && date.chars().filter(|c| c.is_ascii_digit()).count() == 8 && !date.starts_with("0000");
+    valid.then(|| format!("{}T{}", date, time.trim()))
+}
+
+/// Convert a degrees/minutes/seconds GPS tag to signed decimal degrees.
+fn gps_coordinate(exif: &Exif, tag: Tag, ref_tag: Tag, negative_ref: u8) -> Option<f64> {
+    let Value::Rational(parts) = &exif.get_field(tag, In::PRIMARY)?.value else {
+        return None;
+    };
+    let degrees = parts
+        .iter()
+        .take(3)
+        .zip([1.0, 60.0, 3600.0])
+        .map(|(part, divisor)| part.to_f64() / divisor)
+        .sum::<f64>();
+
+    let negative = matches!(
+        exif.get_field(ref_tag, In::PRIMARY).map(|field| &field.value),
+        Some(Value::Ascii(refs)) if refs.first().and_then(|r| r.first()) == Some(&negative_ref)
+    );
+
+    (!parts.is_empty() && degrees.is_finite()).then_some(if negative { -degrees } else { degrees })
+}
+
+fn gps_altitude(exif: &Exif) -> Option<f64> {
+    let Value::Rational(parts) = &exif.get_field(Tag::GPSAltitude, In::PRIMARY)?.value else {
+        return None;
+    };
+    let altitude = parts.first()?.to_f64();
+    let below_sea_level = matches!(
+        exif.get_field(Tag::GPSAltitudeRef, In::PRIMARY).map(|field| &field.value),
+        Some(Value::Byte(refs)) if refs.first() == Some(&1)
+    );
+    altitude
+        .is_finite()
+        .then_some(if below_sea_level { -altitude } else { altitude })
+}
+
 /// Result of OCR extraction from an image with optional page tracking.
 #[derive(Debug, Clone)]
 pub struct ImageOcrResult {
@@ -205,6 +316,7 @@ pub fn extract_text_from_image_with_ocr(
             content: ocr_result[byte_offset..frame_end].to_string(),
             tables: vec![],
             images: vec![],
+            content_blob: None,
         });
 
         byte_offset = frame_end;
@@ -438,6 +550,109 @@ mod tests {
         assert!(exif_data.is_empty());
     }
 
+    /// Build a little-endian TIFF block with a GPS IFD at offset 8 followed by IFD0.
+    fn tiff_with_gps() -> Vec<u8> {
+        fn rationals(values: &[(u32, u32)]) -> Vec<u8> {
+            values
+                .iter()
+                .flat_map(|(num, denom)| num.to_le_bytes().into_iter().chain(denom.to_le_bytes()))
+                .collect()
+        }
+
+        fn write_ifd(out: &mut Vec<u8>, entries: &[(u16, u16, u32, Vec<u8>)]) {
+            let data_start = out.len() + 2 + 12 * entries.len() + 4;
+            let mut data = Vec::new();
+            out.extend((entries.len() as u16).to_le_bytes());
+            for (tag, field_type, count, bytes) in entries {
+                out.extend(tag.to_le_bytes());
+                out.extend(field_type.to_le_bytes());
+                out.extend(count.to_le_bytes());
+                if bytes.len() <= 4 {
+                    let mut inline = bytes.clone();
+                    inline.resize(4, 0);
+                    out.extend(inline);
+                } else {
+                    out.extend(((data_start + data.len()) as u32).to_le_bytes());
+                    data.extend(bytes);
+                    if data.len() % 2 == 1 {
+                        data.push(0);
+                    }
+                }
+            }
+            out.extend(0u32.to_le_bytes());
+            out.extend(data);
+        }
+
+        let mut out = b"II*\0".to_vec();
+        out.extend(0u32.to_le_bytes());
+
+        write_ifd(
+            &mut out,
+            &[
+                (0x0001, 2, 2, b"N\0".to_vec()),
+                (0x0002, 5, 3, rationals(&[(52, 1), (31, 1), (12, 1)])),
+                (0x0003, 2, 2, b"W\0".to_vec()),
+                (0x0004, 5, 3, rationals(&[(13, 1), (24, 1), (18, 1)])),
+                (0x0005, 1, 1, vec![0]),
+                (0x0006, 5, 1, rationals(&[(34, 1)])),
+            ],
+        );
+
+        let ifd0_offset = out.len() as u32;
+        out[4..8].copy_from_slice(&ifd0_offset.to_le_bytes());
+        write_ifd(
+            &mut out,
+            &[
+                (0x010F, 2, 6, b"Canon\0".to_vec()),
+                (0x0110, 2, 8, b"EOS R5 \0".to_vec()),
+                (0x0132, 2, 20, b"2024:03:01 09:30:00\0".to_vec()),
+                (0x8825, 4, 1, 8u32.to_le_bytes().to_vec()),
+            ],
+        );
+
+        out
+    }
+
+    #[test]
+    fn test_exif_details_camera_timestamps_and_gps() {
+        let exif = Reader::new().read_raw(tiff_with_gps()).expect("valid TIFF");
+        let details = exif_details(&exif).expect("details present");
+
+        let camera = details.camera.expect("camera present");
+        assert_eq!(camera.make.as_deref(), Some("Canon"));
+        assert_eq!(camera.model.as_deref(), Some("EOS R5"));
+        assert_eq!(
+            details.timestamps.and_then(|t| t.modified).as_deref(),
+            Some("2024-03-01T09:30:00")
+        );
+
+        let gps = details.gps.expect("gps present");
+        assert!((gps.latitude - 52.52).abs() < 1e-9);
+        assert!((gps.longitude + 13.405).abs() < 1e-9);
+        assert_eq!(gps.altitude, Some(34.0));
+        assert!(exif_tag_map(&exif).contains_key("GPSLatitude"));
+    }
+
+    #[test]
+    fn test_strip_gps_removes_coordinates() {
+        let exif = Reader::new().read_raw(tiff_with_gps()).expect("valid TIFF");
+        let mut metadata = ImageMetadata {
+            width: 1,
+            height: 1,
+            format: "JPEG".to_string(),
+            exif_data: exif_tag_map(&exif),
+            exif_details: exif_details(&exif),
+        };
+
+        metadata.strip_gps();
+
+        assert!(metadata.exif_data.keys().all(|key| !key.starts_with("GPS")));
+        assert_eq!(metadata.exif_data.get("Make").map(String::as_str), Some("Canon"));
+        let details = metadata.exif_details.expect("camera data is kept");
+        assert!(details.gps.is_none());
+        assert!(details.camera.is_some());
+    }
+
     #[test]
     fn test_extract_rectangular_image_portrait_orientation() {
         let bytes = create_test_image(400, 800, ImageFormat::Jpeg);
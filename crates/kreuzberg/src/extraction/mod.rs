@@ -1,3 +1,4 @@
+pub mod csv;
 pub mod structured;
 pub mod text;
 
@@ -41,9 +42,12 @@ pub mod table;
 #[cfg(feature = "xml")]
 pub mod xml;
 
-#[cfg(any(feature = "office", feature = "html"))]
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub mod xmp;
+
 pub mod markdown;
 
+pub use csv::{CsvDialect, DelimitedTable, parse_delimited, sniff_dialect};
 pub use structured::{JsonExtractionConfig, StructuredDataResult, parse_json, parse_toml, parse_yaml};
 pub use text::parse_text;
 
@@ -60,7 +64,10 @@ pub use archive::{
 pub use email::{build_email_text_output, extract_email_content, parse_eml_content, parse_msg_content};
 
 #[cfg(feature = "excel")]
-pub use excel::{excel_to_markdown, read_excel_bytes, read_excel_file};
+pub use excel::{
+    ExcelRow, excel_to_markdown, for_each_excel_row, read_excel_bytes, read_excel_bytes_with_options, read_excel_file,
+    read_excel_file_with_options,
+};
 
 #[cfg(feature = "html")]
 pub use html::{convert_html_to_markdown, process_html};
@@ -76,7 +83,10 @@ pub use office_metadata::{
 };
 
 #[cfg(feature = "office")]
-pub use pptx::{extract_pptx_from_bytes, extract_pptx_from_path};
+pub use pptx::{
+    extract_pptx_from_bytes, extract_pptx_from_bytes_with_options, extract_pptx_from_path,
+    extract_pptx_from_path_with_options,
+};
 
 #[cfg(feature = "excel")]
 pub use table::table_from_arrow_to_markdown;
@@ -84,7 +94,9 @@ pub use table::table_from_arrow_to_markdown;
 #[cfg(feature = "xml")]
 pub use xml::parse_xml;
 
-#[cfg(any(feature = "office", feature = "html"))]
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub use xmp::{extract_xmp_metadata, find_xmp_packets, parse_xmp_packet};
+
 pub use markdown::cells_to_markdown;
 
 pub use capacity::{
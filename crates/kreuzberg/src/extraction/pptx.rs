@@ -37,6 +37,7 @@
 //! # Ok(())
 //! # }
 //! ```
+use crate::core::config::{PageConfig, PptxConfig};
 use crate::error::{KreuzbergError, Result};
 use crate::text::utf8_validation;
 use crate::types::{ExtractedImage, PptxExtractionResult, PptxMetadata};
@@ -242,6 +243,7 @@ impl ContentBuilder {
                 content: slide_content,
                 tables: Vec::new(),
                 images: Vec::new(),
+                content_blob: None,
             });
         }
     }
@@ -314,10 +316,8 @@ impl ContentBuilder {
     }
 
     fn add_notes(&mut self, notes: &str) {
-        if !notes.trim().is_empty() {
-            self.content.push_str("\n\n### Notes:\n");
-            self.content.push_str(notes);
-            self.content.push('\n');
+        if let Some(section) = notes_section(notes) {
+            self.content.push_str(&section);
         }
     }
 
@@ -343,6 +343,13 @@ impl ContentBuilder {
     }
 }
 
+fn notes_section(notes: &str) -> Option<String> {
+    if notes.trim().is_empty() {
+        return None;
+    }
+    Some(format!("\n\n### Notes:\n{}\n", notes))
+}
+
 fn html_escape(text: &str) -> String {
     text.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -1129,13 +1136,33 @@ fn detect_image_format(data: &[u8]) -> String {
 pub fn extract_pptx_from_path(
     path: &str,
     extract_images: bool,
-    page_config: Option<&crate::core::config::PageConfig>,
+    page_config: Option<&PageConfig>,
+) -> Result<PptxExtractionResult> {
+    extract_pptx_from_path_with_options(path, extract_images, page_config, &PptxConfig::default())
+}
+
+/// Extract a presentation with explicit speaker-notes and slide-structure options.
+///
+/// With `slide_markers` enabled every slide is prefixed with a `<!-- Slide number: N -->`
+/// comment and one page per slide is returned, falling back to a default [`PageConfig`]
+/// with `extract_pages` set when `page_config` is `None`.
+pub fn extract_pptx_from_path_with_options(
+    path: &str,
+    extract_images: bool,
+    page_config: Option<&PageConfig>,
+    pptx_options: &PptxConfig,
 ) -> Result<PptxExtractionResult> {
     let config = ParserConfig {
         extract_images,
-        ..Default::default()
+        include_slide_comment: pptx_options.slide_markers,
     };
 
+    let slide_page_config = (pptx_options.slide_markers && page_config.is_none()).then(|| PageConfig {
+        extract_pages: true,
+        ..Default::default()
+    });
+    let page_config = page_config.or(slide_page_config.as_ref());
+
     let mut container = PptxContainer::open(path)?;
 
     let metadata = extract_metadata(&mut container.archive);
@@ -1162,15 +1189,20 @@ pub fn extract_pptx_from_path(
             0
         };
 
-        let slide_content = slide.to_markdown(&config);
+        let mut slide_content = slide.to_markdown(&config);
         content_builder.add_text(&slide_content);
 
-        if let Some(slide_notes) = notes.get(&slide.slide_number) {
+        if pptx_options.include_notes
+            && let Some(slide_notes) = notes.get(&slide.slide_number)
+        {
             content_builder.add_notes(slide_notes);
+            if let Some(section) = notes_section(slide_notes) {
+                slide_content.push_str(&section);
+            }
         }
 
         if page_config.is_some() {
-            content_builder.end_slide(slide.slide_number, byte_start, slide_content.clone());
+            content_builder.end_slide(slide.slide_number, byte_start, slide_content);
         }
 
         if config.extract_images
@@ -1192,6 +1224,10 @@ pub fn extract_pptx_from_path(
                     is_mask: false,
                     description: None,
                     ocr_result: None,
+                    perceptual_hash: None,
+                    blob: None,
+                    caption: None,
+                    figure_index: None,
                 });
             }
         }
@@ -1235,7 +1271,19 @@ pub fn extract_pptx_from_path(
 pub fn extract_pptx_from_bytes(
     data: &[u8],
     extract_images: bool,
-    page_config: Option<&crate::core::config::PageConfig>,
+    page_config: Option<&PageConfig>,
+) -> Result<PptxExtractionResult> {
+    extract_pptx_from_bytes_with_options(data, extract_images, page_config, &PptxConfig::default())
+}
+
+/// Extract a presentation from bytes with explicit speaker-notes and slide-structure options.
+///
+/// See [`extract_pptx_from_path_with_options`].
+pub fn extract_pptx_from_bytes_with_options(
+    data: &[u8],
+    extract_images: bool,
+    page_config: Option<&PageConfig>,
+    pptx_options: &PptxConfig,
 ) -> Result<PptxExtractionResult> {
     use std::sync::atomic::{AtomicU64, Ordering};
     static COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -1245,12 +1293,13 @@ pub fn extract_pptx_from_bytes(
     // IO errors must bubble up - temp file write issues need user reports ~keep
     std::fs::write(&temp_path, data)?;
 
-    let result = extract_pptx_from_path(
+    let result = extract_pptx_from_path_with_options(
         temp_path.to_str().ok_or_else(|| {
             crate::KreuzbergError::validation("Invalid temp path - contains invalid UTF-8".to_string())
         })?,
         extract_images,
         page_config,
+        pptx_options,
     );
 
     if let Err(e) = std::fs::remove_file(&temp_path) {
@@ -2980,8 +3029,7 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_integration_slide_notes_extraction_succeeds() {
+    fn create_pptx_with_notes() -> Vec<u8> {
         use std::io::Write;
         use zip::write::{SimpleFileOptions, ZipWriter};
 
@@ -3085,6 +3133,12 @@ mod tests {
             let _ = zip.finish().unwrap();
         }
 
+        buffer
+    }
+
+    #[test]
+    fn test_integration_slide_notes_extraction_succeeds() {
+        let buffer = create_pptx_with_notes();
         let result = extract_pptx_from_bytes(&buffer, false, None).unwrap();
 
         assert!(result.content.contains("Slide Content"), "Should contain slide content");
@@ -3095,6 +3149,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pptx_options_exclude_notes() {
+        let buffer = create_pptx_with_notes();
+        let options = PptxConfig {
+            include_notes: false,
+            ..Default::default()
+        };
+
+        let result = extract_pptx_from_bytes_with_options(&buffer, false, None, &options).unwrap();
+
+        assert!(result.content.contains("Slide Content"));
+        assert!(!result.content.contains("### Notes:"));
+        assert!(!result.content.contains("This is a speaker note for testing"));
+    }
+
+    #[test]
+    fn test_pptx_options_slide_markers_return_slide_pages() {
+        let buffer = create_pptx_with_notes();
+        let options = PptxConfig {
+            include_notes: true,
+            slide_markers: true,
+        };
+
+        let result = extract_pptx_from_bytes_with_options(&buffer, false, None, &options).unwrap();
+
+        assert!(result.content.starts_with("<!-- Slide number: 1 -->"));
+        let pages = result.page_contents.expect("slide markers should produce pages");
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].page_number, 1);
+        assert!(pages[0].content.contains("Slide Content"));
+        assert!(pages[0].content.contains("This is a speaker note for testing"));
+
+        let structure = result.page_structure.expect("page structure should be present");
+        assert_eq!(structure.unit_type, crate::types::PageUnitType::Slide);
+        assert_eq!(structure.total_count, 1);
+    }
+
     #[test]
     fn test_integration_metadata_extraction_complete() {
         let pptx_bytes = create_test_pptx_bytes(vec!["Content"]);
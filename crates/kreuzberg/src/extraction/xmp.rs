@@ -0,0 +1,285 @@
+//! XMP metadata packet parsing.
+//!
+//! XMP (Extensible Metadata Platform) packets are RDF/XML documents embedded in PDFs,
+//! JPEG APP1 segments, PNG `iTXt` chunks, TIFF tags and many other containers. Archival
+//! and digital asset management tools write their metadata there rather than into the
+//! format's native fields, so this module locates packets in raw bytes and maps the
+//! Dublin Core (`dc:`), XMP basic (`xmp:`), Adobe PDF (`pdf:`) and Photoshop
+//! (`photoshop:`) schemas onto [`XmpMetadata`].
+//!
+//! Properties may be written either as attributes of `rdf:Description` or as child
+//! elements. Language alternatives (`rdf:Alt`) resolve to the `x-default` entry (or the
+//! first entry), while ordered and unordered arrays (`rdf:Seq`, `rdf:Bag`) keep all items.
+
+use crate::types::XmpMetadata;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+
+const NS_RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const NS_XML: &str = "http://www.w3.org/XML/1998/namespace";
+const NS_DC: &str = "http://purl.org/dc/elements/1.1/";
+const NS_XMP: &str = "http://ns.adobe.com/xap/1.0/";
+const NS_PDF: &str = "http://ns.adobe.com/pdf/1.3/";
+const NS_PHOTOSHOP: &str = "http://ns.adobe.com/photoshop/1.0/";
+
+/// Start and end markers of an XMP packet, tried in order.
+///
+/// Packets are normally wrapped in `x:xmpmeta` (or the legacy `x:xapmeta`), but some
+/// writers embed a bare `rdf:RDF` element.
+const PACKET_MARKERS: &[(&[u8], &[u8])] = &[
+    (b"<x:xmpmeta", b"</x:xmpmeta>"),
+    (b"<x:xapmeta", b"</x:xapmeta>"),
+    (b"<rdf:RDF", b"</rdf:RDF>"),
+];
+
+/// Locate all XMP packets stored uncompressed in a byte buffer.
+///
+/// Returns the packets in file order. Packets that are not valid UTF-8 are decoded lossily.
+pub fn find_xmp_packets(bytes: &[u8]) -> Vec<String> {
+    for (start_marker, end_marker) in PACKET_MARKERS {
+        let mut packets = Vec::new();
+        let mut offset = 0;
+
+        while let Some(start) = find_bytes(&bytes[offset..], start_marker).map(|pos| pos + offset) {
+            let Some(end) = find_bytes(&bytes[start..], end_marker).map(|pos| pos + start + end_marker.len()) else {
+                break;
+            };
+            packets.push(String::from_utf8_lossy(&bytes[start..end]).into_owned());
+            offset = end;
+        }
+
+        if !packets.is_empty() {
+            return packets;
+        }
+    }
+
+    Vec::new()
+}
+
+/// Extract XMP metadata from the first packet found in a byte buffer.
+///
+/// Suitable for image formats, which carry at most one document-level packet.
+pub fn extract_xmp_metadata(bytes: &[u8]) -> Option<XmpMetadata> {
+    find_xmp_packets(bytes)
+        .iter()
+        .find_map(|packet| parse_xmp_packet(packet))
+}
+
+/// Parse a single XMP packet into structured metadata.
+///
+/// Returns `None` if the packet is not well-formed XML or none of the supported
+/// properties are present.
+pub fn parse_xmp_packet(packet: &str) -> Option<XmpMetadata> {
+    let document = Document::parse(packet.trim_matches(char::from(0))).ok()?;
+    let mut properties: HashMap<(&str, &str), Vec<String>> = HashMap::new();
+
+    for description in document
+        .descendants()
+        .filter(|node| node.has_tag_name((NS_RDF, "Description")))
+    {
+        for attribute in description.attributes() {
+            if let Some(namespace) = attribute.namespace() {
+                let value = attribute.value().trim();
+                if !value.is_empty() {
+                    properties
+                        .entry((namespace, attribute.name()))
+                        .or_default()
+                        .push(value.to_string());
+                }
+            }
+        }
+
+        for property in description.children().filter(Node::is_element) {
+            if let Some(namespace) = property.tag_name().namespace() {
+                let values = property_values(property);
+                if !values.is_empty() {
+                    properties
+                        .entry((namespace, property.tag_name().name()))
+                        .or_default()
+                        .extend(values);
+                }
+            }
+        }
+    }
+
+    let single = |namespace: &str, name: &str| {
+        properties
+            .get(&(namespace, name))
+            .and_then(|values| values.first())
+            .cloned()
+    };
+    let list = |namespace: &str, name: &str| properties.get(&(namespace, name)).cloned();
+
+    let metadata = XmpMetadata {
+        title: single(NS_DC, "title"),
+        creator: list(NS_DC, "creator"),
+        description: single(NS_DC, "description"),
+        subject: list(NS_DC, "subject"),
+        rights: single(NS_DC, "rights"),
+        publisher: list(NS_DC, "publisher"),
+        contributor: list(NS_DC, "contributor"),
+        language: list(NS_DC, "language"),
+        date: list(NS_DC, "date"),
+        identifier: single(NS_DC, "identifier"),
+        format: single(NS_DC, "format"),
+        create_date: single(NS_XMP, "CreateDate"),
+        modify_date: single(NS_XMP, "ModifyDate"),
+        metadata_date: single(NS_XMP, "MetadataDate"),
+        creator_tool: single(NS_XMP, "CreatorTool"),
+        rating: single(NS_XMP, "Rating"),
+        label: single(NS_XMP, "Label"),
+        producer: single(NS_PDF, "Producer"),
+        keywords: single(NS_PDF, "Keywords"),
+        pdf_version: single(NS_PDF, "PDFVersion"),
+        trapped: single(NS_PDF, "Trapped"),
+        headline: single(NS_PHOTOSHOP, "Headline"),
+        credit: single(NS_PHOTOSHOP, "Credit"),
+        source: single(NS_PHOTOSHOP, "Source"),
+        city: single(NS_PHOTOSHOP, "City"),
+        state: single(NS_PHOTOSHOP, "State"),
+        country: single(NS_PHOTOSHOP, "Country"),
+        authors_position: single(NS_PHOTOSHOP, "AuthorsPosition"),
+        caption_writer: single(NS_PHOTOSHOP, "CaptionWriter"),
+        instructions: single(NS_PHOTOSHOP, "Instructions"),
+        date_created: single(NS_PHOTOSHOP, "DateCreated"),
+        category: single(NS_PHOTOSHOP, "Category"),
+    };
+
+    (metadata != XmpMetadata::default()).then_some(metadata)
+}
+
+/// Resolve the values of a property element.
+///
+/// `rdf:Alt` yields the `x-default` (or first) item, `rdf:Seq`/`rdf:Bag` yield every item,
+/// and simple properties yield their text content.
+fn property_values(property: Node) -> Vec<String> {
+    let Some(container) = property.children().find(Node::is_element) else {
+        return text_value(property).into_iter().collect();
+    };
+
+    let items: Vec<Node> = container
+        .children()
+        .filter(|node| node.has_tag_name((NS_RDF, "li")))
+        .collect();
+
+    if container.has_tag_name((NS_RDF, "Alt")) {
+        items
+            .iter()
+            .find(|item| item.attribute((NS_XML, "lang")) == Some("x-default"))
+            .or_else(|| items.first())
+            .and_then(|item| text_value(*item))
+            .into_iter()
+            .collect()
+    } else if container.has_tag_name((NS_RDF, "Seq")) || container.has_tag_name((NS_RDF, "Bag")) {
+        items.into_iter().filter_map(text_value).collect()
+    } else {
+        Vec::new()
+    }
+}
+
+fn text_value(node: Node) -> Option<String> {
+    let text: String = node
+        .children()
+        .filter(Node::is_text)
+        .filter_map(|child| child.text())
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKET: &str = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about=""
+        xmlns:dc="http://purl.org/dc/elements/1.1/"
+        xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+        xmlns:pdf="http://ns.adobe.com/pdf/1.3/"
+        xmlns:photoshop="http://ns.adobe.com/photoshop/1.0/"
+        xmp:CreatorTool="Scanner Suite 4.2"
+        pdf:Producer="Archive Builder">
+      <dc:title>
+        <rdf:Alt>
+          <rdf:li xml:lang="de-DE">Jahresbericht</rdf:li>
+          <rdf:li xml:lang="x-default">Annual Report</rdf:li>
+        </rdf:Alt>
+      </dc:title>
+      <dc:creator>
+        <rdf:Seq>
+          <rdf:li>Ada Lovelace</rdf:li>
+          <rdf:li>Charles Babbage</rdf:li>
+        </rdf:Seq>
+      </dc:creator>
+      <dc:subject>
+        <rdf:Bag>
+          <rdf:li>archive</rdf:li>
+          <rdf:li>finance</rdf:li>
+        </rdf:Bag>
+      </dc:subject>
+      <xmp:CreateDate>2024-03-01T09:30:00Z</xmp:CreateDate>
+      <photoshop:City>Berlin</photoshop:City>
+      <photoshop:Credit>Staatsarchiv</photoshop:Credit>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#;
+
+    #[test]
+    fn test_parse_xmp_packet_namespaces() {
+        let metadata = parse_xmp_packet(PACKET).expect("packet should parse");
+
+        assert_eq!(metadata.title.as_deref(), Some("Annual Report"));
+        assert_eq!(
+            metadata.creator,
+            Some(vec!["Ada Lovelace".to_string(), "Charles Babbage".to_string()])
+        );
+        assert_eq!(
+            metadata.subject,
+            Some(vec!["archive".to_string(), "finance".to_string()])
+        );
+        assert_eq!(metadata.create_date.as_deref(), Some("2024-03-01T09:30:00Z"));
+        assert_eq!(metadata.creator_tool.as_deref(), Some("Scanner Suite 4.2"));
+        assert_eq!(metadata.producer.as_deref(), Some("Archive Builder"));
+        assert_eq!(metadata.city.as_deref(), Some("Berlin"));
+        assert_eq!(metadata.credit.as_deref(), Some("Staatsarchiv"));
+        assert_eq!(metadata.rights, None);
+    }
+
+    #[test]
+    fn test_find_xmp_packets_in_binary_container() {
+        let mut bytes = vec![0xFF, 0xD8, 0xFF, 0xE1, 0x00, 0x10];
+        bytes.extend_from_slice(b"http://ns.adobe.com/xap/1.0/\0");
+        bytes.extend_from_slice(PACKET.as_bytes());
+        bytes.extend_from_slice(&[0xFF, 0xD9]);
+
+        let packets = find_xmp_packets(&bytes);
+
+        assert_eq!(packets.len(), 1);
+        assert!(packets[0].starts_with("<x:xmpmeta"));
+        assert!(packets[0].ends_with("</x:xmpmeta>"));
+        assert_eq!(
+            extract_xmp_metadata(&bytes).and_then(|m| m.title),
+            Some("Annual Report".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_xmp_packet_without_supported_properties() {
+        let packet = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" xmlns:tiff="http://ns.adobe.com/tiff/1.0/" tiff:Orientation="1"/>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        assert!(parse_xmp_packet(packet).is_none());
+        assert!(parse_xmp_packet("<x:xmpmeta>not closed").is_none());
+        assert!(extract_xmp_metadata(b"no metadata here").is_none());
+    }
+}
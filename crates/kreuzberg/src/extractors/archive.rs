@@ -7,7 +7,7 @@ use crate::extraction::archive::{
     extract_tar_text_content, extract_zip_metadata, extract_zip_text_content,
 };
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ArchiveMetadata, ExtractionResult, Metadata};
+use crate::types::{ArchiveMetadata, ContentSource, ExtractionResult, Metadata, ProvenanceSpan};
 use async_trait::async_trait;
 use std::collections::HashMap;
 
@@ -20,6 +20,7 @@ fn build_archive_result(
     text_contents: HashMap<String, String>,
     format_name: &str,
     mime_type: &str,
+    config: &ExtractionConfig,
 ) -> ExtractionResult {
     let file_names: Vec<String> = extraction_metadata
         .file_list
@@ -58,10 +59,19 @@ fn build_archive_result(
         output.push_str(&format!("- {} ({} bytes)\n", entry.path, entry.size));
     }
 
+    let mut provenance = Vec::new();
     if !text_contents.is_empty() {
         output.push_str("\n\nText File Contents:\n\n");
         for (path, content) in text_contents {
-            output.push_str(&format!("=== {} ===\n{}\n\n", path, content));
+            output.push_str(&format!("=== {} ===\n", path));
+            let byte_start = output.len();
+            output.push_str(&content);
+            provenance.push(ProvenanceSpan {
+                byte_start,
+                byte_end: output.len(),
+                source: ContentSource::Attachment { name: path },
+            });
+            output.push_str("\n\n");
         }
     }
 
@@ -78,6 +88,10 @@ fn build_archive_result(
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: config.emit_provenance.then_some(provenance),
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     }
 }
 
@@ -128,7 +142,7 @@ impl Plugin for ZipExtractor {
 #[async_trait]
 impl DocumentExtractor for ZipExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -138,7 +152,7 @@ impl DocumentExtractor for ZipExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let extraction_metadata = extract_zip_metadata(content)?;
         let text_contents = extract_zip_text_content(content)?;
@@ -147,6 +161,7 @@ impl DocumentExtractor for ZipExtractor {
             text_contents,
             "ZIP",
             mime_type,
+            config,
         ))
     }
 
@@ -206,7 +221,7 @@ impl Plugin for TarExtractor {
 #[async_trait]
 impl DocumentExtractor for TarExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -216,7 +231,7 @@ impl DocumentExtractor for TarExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let extraction_metadata = extract_tar_metadata(content)?;
         let text_contents = extract_tar_text_content(content)?;
@@ -225,6 +240,7 @@ impl DocumentExtractor for TarExtractor {
             text_contents,
             "TAR",
             mime_type,
+            config,
         ))
     }
 
@@ -289,7 +305,7 @@ impl Plugin for SevenZExtractor {
 #[async_trait]
 impl DocumentExtractor for SevenZExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -299,7 +315,7 @@ impl DocumentExtractor for SevenZExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let extraction_metadata = extract_7z_metadata(content)?;
         let text_contents = extract_7z_text_content(content)?;
@@ -308,6 +324,7 @@ impl DocumentExtractor for SevenZExtractor {
             text_contents,
             "7Z",
             mime_type,
+            config,
         ))
     }
 
@@ -402,6 +419,41 @@ mod tests {
         assert_eq!(archive_meta.file_count, 1);
     }
 
+    #[tokio::test]
+    async fn test_zip_extractor_attachment_provenance() {
+        let extractor = ZipExtractor::new();
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            zip.start_file("notes.txt", FileOptions::<'_, ()>::default()).unwrap();
+            zip.write_all(b"Hello, World!").unwrap();
+            zip.finish().unwrap();
+        }
+
+        let config = ExtractionConfig {
+            emit_provenance: true,
+            ..Default::default()
+        };
+        let result = extractor
+            .extract_bytes(&cursor.into_inner(), "application/zip", &config)
+            .await
+            .unwrap();
+
+        let provenance = result.provenance.unwrap();
+        assert_eq!(provenance.len(), 1);
+        assert_eq!(
+            &result.content[provenance[0].byte_start..provenance[0].byte_end],
+            "Hello, World!"
+        );
+        assert_eq!(
+            provenance[0].source,
+            ContentSource::Attachment {
+                name: "notes.txt".to_string()
+            }
+        );
+    }
+
     #[tokio::test]
     async fn test_zip_extractor_invalid() {
         let extractor = ZipExtractor::new();
@@ -172,6 +172,10 @@ impl DocumentExtractor for BibtexExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -0,0 +1,269 @@
+//! CSV and TSV extractor.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::extraction::cells_to_markdown;
+use crate::extraction::csv::{parse_delimited, sniff_dialect};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
+use crate::text::encoding::{ENCODING_METADATA_KEY, decode_text};
+use crate::types::{ExtractionResult, FormatMetadata, Metadata, Table, TextMetadata};
+use async_trait::async_trait;
+
+/// Delimited text extractor.
+///
+/// Extracts CSV and TSV files. The raw text is returned as content, and the parsed
+/// records are returned as a single [`Table`] with inferred column types. The
+/// detected dialect is reported in the metadata (`delimiter`, `has_header`,
+/// `row_count`, `column_count`).
+pub struct CsvExtractor;
+
+impl CsvExtractor {
+    /// Create a new CSV extractor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CsvExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for CsvExtractor {
+    fn name(&self) -> &str {
+        "csv-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts tables from CSV and TSV files with dialect detection"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for CsvExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let (text, encoding) = match &config.text_options {
+            Some(options) => {
+                let decoded = decode_text(content, options)?;
+                (decoded.text, Some(decoded.encoding))
+            }
+            None => (String::from_utf8_lossy(content).into_owned(), None),
+        };
+        let text = text.trim_end_matches('\n').trim_end_matches('\r').to_string();
+
+        let csv_options = config.csv_options.as_ref();
+        let delimiter = csv_options
+            .and_then(|options| options.delimiter)
+            .or_else(|| (mime_type == "text/tab-separated-values").then_some('\t'));
+        let max_rows = csv_options.and_then(|options| options.max_rows);
+
+        let dialect = sniff_dialect(&text, delimiter);
+        let parsed = parse_delimited(&text, &dialect, max_rows);
+
+        let mut metadata = Metadata {
+            format: Some(FormatMetadata::Text(TextMetadata {
+                line_count: text.lines().count(),
+                word_count: text.split_whitespace().count(),
+                character_count: text.len(),
+                headers: None,
+                links: None,
+                code_blocks: None,
+            })),
+            ..Default::default()
+        };
+        metadata.additional.insert(
+            "delimiter".to_string(),
+            serde_json::Value::String(dialect.delimiter.to_string()),
+        );
+        metadata
+            .additional
+            .insert("has_header".to_string(), serde_json::json!(dialect.has_header));
+        metadata
+            .additional
+            .insert("row_count".to_string(), serde_json::json!(parsed.data_row_count()));
+        metadata
+            .additional
+            .insert("column_count".to_string(), serde_json::json!(parsed.column_count()));
+        if parsed.truncated {
+            metadata
+                .additional
+                .insert("truncated".to_string(), serde_json::Value::Bool(true));
+        }
+        if let Some(encoding) = encoding {
+            metadata
+                .additional
+                .insert(ENCODING_METADATA_KEY.to_string(), serde_json::Value::String(encoding));
+        }
+
+        let tables = if parsed.rows.is_empty() {
+            vec![]
+        } else {
+            vec![Table {
+                markdown: cells_to_markdown(&parsed.rows),
+                cells: parsed.rows,
+                page_number: 1,
+                column_types: Some(parsed.column_types),
+            }]
+        };
+
+        Ok(ExtractionResult {
+            content: text,
+            mime_type: mime_type.to_string(),
+            metadata,
+            pages: None,
+            tables,
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        })
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &["text/csv", "text/tab-separated-values"]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::CsvConfig;
+    use crate::types::CellType;
+
+    #[tokio::test]
+    async fn test_csv_extractor_emits_typed_table() {
+        let extractor = CsvExtractor::new();
+        let content = b"Name;Age;Joined\nAlice;30;2021-04-01\nBob;25;2022-09-15\n";
+        let config = ExtractionConfig::default();
+
+        let result = extractor.extract_bytes(content, "text/csv", &config).await.unwrap();
+
+        assert_eq!(
+            result.content,
+            "Name;Age;Joined\nAlice;30;2021-04-01\nBob;25;2022-09-15"
+        );
+        assert_eq!(result.tables.len(), 1);
+        let table = &result.tables[0];
+        assert_eq!(table.cells.len(), 3);
+        assert_eq!(table.cells[0], vec!["Name", "Age", "Joined"]);
+        assert_eq!(
+            table.column_types,
+            Some(vec![CellType::String, CellType::Number, CellType::Date])
+        );
+        assert!(table.markdown.contains("| Name | Age | Joined |"));
+        assert_eq!(
+            result.metadata.additional.get("delimiter"),
+            Some(&serde_json::json!(";"))
+        );
+        assert_eq!(
+            result.metadata.additional.get("has_header"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(result.metadata.additional.get("row_count"), Some(&serde_json::json!(2)));
+    }
+
+    #[tokio::test]
+    async fn test_tsv_defaults_to_tab_delimiter() {
+        let extractor = CsvExtractor::new();
+        let content = b"a\tb,c\n1\t2,3";
+        let config = ExtractionConfig::default();
+
+        let result = extractor
+            .extract_bytes(content, "text/tab-separated-values", &config)
+            .await
+            .unwrap();
+
+        assert_eq!(result.tables[0].cells[1], vec!["1", "2,3"]);
+    }
+
+    #[tokio::test]
+    async fn test_csv_options_override_and_limit() {
+        let extractor = CsvExtractor::new();
+        let content = b"id|name\n1|a\n2|b\n3|c";
+        let config = ExtractionConfig {
+            csv_options: Some(CsvConfig {
+                delimiter: Some('|'),
+                max_rows: Some(2),
+            }),
+            ..Default::default()
+        };
+
+        let result = extractor.extract_bytes(content, "text/csv", &config).await.unwrap();
+
+        assert_eq!(result.tables[0].cells.len(), 3);
+        assert_eq!(
+            result.metadata.additional.get("truncated"),
+            Some(&serde_json::json!(true))
+        );
+        assert!(result.content.contains("3|c"));
+    }
+
+    #[tokio::test]
+    async fn test_empty_csv_has_no_tables() {
+        let extractor = CsvExtractor::new();
+        let result = extractor
+            .extract_bytes(b"", "text/csv", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert!(result.tables.is_empty());
+        assert_eq!(result.content, "");
+    }
+
+    #[test]
+    fn test_csv_plugin_interface() {
+        let extractor = CsvExtractor::new();
+        assert_eq!(extractor.name(), "csv-extractor");
+        assert_eq!(
+            extractor.supported_mime_types(),
+            &["text/csv", "text/tab-separated-values"]
+        );
+        assert_eq!(extractor.priority(), 50);
+    }
+}
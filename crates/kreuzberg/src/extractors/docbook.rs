@@ -18,7 +18,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::cells_to_markdown;
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 use crate::types::{ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
 use quick_xml::Reader;
@@ -242,6 +242,7 @@ fn parse_docbook_single_pass(content: &str) -> Result<DocBookParseResult> {
                                 cells: current_table.clone(),
                                 markdown,
                                 page_number: table_index + 1,
+                                column_types: None,
                             });
                             table_index += 1;
                             current_table.clear();
@@ -405,6 +406,10 @@ impl DocumentExtractor for DocbookExtractor {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -430,6 +435,16 @@ impl DocumentExtractor for DocbookExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -7,7 +7,8 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::{cells_to_markdown, office_metadata};
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
+use crate::text::outline::{build_outline, locate_headings};
 use crate::types::{ExtractionResult, Metadata, PageBoundary, PageInfo, PageStructure, PageUnitType, Table};
 use async_trait::async_trait;
 use std::io::Cursor;
@@ -92,6 +93,7 @@ fn convert_docx_table_to_table(docx_table: &docx_lite::Table, table_index: usize
         cells,
         markdown,
         page_number: table_index + 1,
+        column_types: None,
     }
 }
 
@@ -268,6 +270,14 @@ impl DocumentExtractor for DocxExtractor {
             }
         }
 
+        let outline = match crate::extraction::docx::extract_headings(&mut archive) {
+            Ok(mut headings) if !headings.is_empty() => {
+                locate_headings(&text, &mut headings, page_boundaries.as_deref());
+                Some(build_outline(headings))
+            }
+            _ => None,
+        };
+
         let page_structure = if let Some(boundaries) = page_boundaries {
             let total_count = boundaries.len();
             Some(PageStructure {
@@ -296,6 +306,7 @@ impl DocumentExtractor for DocxExtractor {
             mime_type: mime_type.to_string(),
             metadata: Metadata {
                 pages: page_structure,
+                outline,
                 additional: metadata_map,
                 ..Default::default()
             },
@@ -304,6 +315,10 @@ impl DocumentExtractor for DocxExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -314,6 +329,16 @@ impl DocumentExtractor for DocxExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: true,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
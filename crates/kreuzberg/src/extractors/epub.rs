@@ -3,7 +3,8 @@
 //! This extractor provides native Rust-based EPUB extraction without GPL-licensed
 //! dependencies, extracting:
 //! - Metadata from OPF (Open Packaging Format) using Dublin Core standards
-//! - Content from XHTML files in spine order
+//! - Content from XHTML files in spine order, one chapter per spine document
+//! - Chapter boundaries as a [`PageStructure`] with [`PageUnitType::Chapter`]
 //! - Proper handling of EPUB2 and EPUB3 formats
 //!
 //! Uses only permissive-licensed crates:
@@ -12,10 +13,11 @@
 //! - `html-to-markdown-rs` (MIT) - for converting XHTML to plain text
 
 use crate::Result;
-use crate::core::config::ExtractionConfig;
-use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata};
+use crate::core::config::{ExtractionConfig, PageConfig};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
+use crate::types::{ExtractionResult, Metadata, PageBoundary, PageContent, PageInfo, PageStructure, PageUnitType};
 use async_trait::async_trait;
+use html_to_markdown_rs::ConversionOptions;
 use roxmltree;
 use std::collections::BTreeMap;
 use std::io::Cursor;
@@ -33,43 +35,41 @@ impl EpubExtractor {
         Self
     }
 
-    /// Extract text content from an EPUB document by reading in spine order
-    fn extract_content(
+    /// Extract one chapter per spine document, skipping documents without text
+    fn extract_chapters(
         archive: &mut ZipArchive<Cursor<Vec<u8>>>,
-        opf_path: &str,
+        spine_hrefs: &[String],
         manifest_dir: &str,
-    ) -> Result<String> {
-        let opf_xml = Self::read_file_from_zip(archive, opf_path)?;
-        let (_, spine_hrefs) = Self::parse_opf(&opf_xml)?;
+        html_options: Option<&ConversionOptions>,
+    ) -> Vec<EbookChapter> {
+        let mut chapters = Vec::new();
 
-        let mut content = String::new();
-
-        for (index, href) in spine_hrefs.iter().enumerate() {
+        for href in spine_hrefs {
             let file_path = Self::resolve_path(manifest_dir, href);
 
-            match Self::read_file_from_zip(archive, &file_path) {
-                Ok(xhtml_content) => {
-                    let text = Self::extract_text_from_xhtml(&xhtml_content);
-                    if !text.is_empty() {
-                        if index > 0 && !content.ends_with('\n') {
-                            content.push('\n');
-                        }
-                        content.push_str(&text);
-                        content.push('\n');
-                    }
-                }
-                Err(_) => {
-                    continue;
-                }
+            let Ok(xhtml_content) = Self::read_file_from_zip(archive, &file_path) else {
+                continue;
+            };
+
+            let text = Self::extract_text_from_xhtml(&xhtml_content, html_options);
+            if !text.is_empty() {
+                chapters.push(EbookChapter {
+                    title: Self::chapter_title(&xhtml_content),
+                    content: text,
+                });
             }
         }
 
-        Ok(content.trim().to_string())
+        chapters
     }
 
     /// Extract text from XHTML content using html-to-markdown-rs
-    fn extract_text_from_xhtml(xhtml: &str) -> String {
-        match crate::extraction::html::convert_html_to_markdown(xhtml, None) {
+    ///
+    /// With `html_options` set the converted markdown is kept as-is; otherwise it is
+    /// reduced to plain text.
+    pub(crate) fn extract_text_from_xhtml(xhtml: &str, html_options: Option<&ConversionOptions>) -> String {
+        match crate::extraction::html::convert_html_to_markdown(xhtml, html_options.cloned()) {
+            Ok(markdown) if html_options.is_some() => Self::remove_html_comments(&markdown).trim().to_string(),
             Ok(markdown) => {
                 let text = Self::markdown_to_plain_text(&markdown);
                 Self::remove_html_comments(&text)
@@ -78,6 +78,17 @@ impl EpubExtractor {
         }
     }
 
+    /// Text of the first `<h1>`-`<h6>` heading, used as the chapter title
+    pub(crate) fn chapter_title(xhtml: &str) -> Option<String> {
+        let lower = xhtml.to_ascii_lowercase();
+        let start = (1..=6).filter_map(|level| lower.find(&format!("<h{}", level))).min()?;
+        let open_end = start + lower[start..].find('>')? + 1;
+        let close = open_end + lower[open_end..].find("</h")?;
+
+        let title = Self::strip_html_tags(&xhtml[open_end..close]);
+        (!title.is_empty()).then_some(title)
+    }
+
     /// Remove HTML comments from text
     fn remove_html_comments(text: &str) -> String {
         let mut result = String::new();
@@ -292,19 +303,17 @@ impl EpubExtractor {
         result.trim().to_string()
     }
 
-    /// Extract metadata from EPUB OPF file
-    fn extract_metadata(opf_xml: &str) -> Result<BTreeMap<String, serde_json::Value>> {
+    /// Convert OPF metadata into the additional metadata map
+    fn extract_metadata(epub_metadata: OepbMetadata) -> BTreeMap<String, serde_json::Value> {
         let mut metadata = BTreeMap::new();
 
-        let (epub_metadata, _) = Self::parse_opf(opf_xml)?;
-
         if let Some(title) = epub_metadata.title {
             metadata.insert("title".to_string(), serde_json::json!(title));
         }
 
-        if let Some(creator) = epub_metadata.creator {
-            metadata.insert("creator".to_string(), serde_json::json!(creator.clone()));
-            metadata.insert("authors".to_string(), serde_json::json!(vec![creator]));
+        if let Some(creator) = epub_metadata.creators.first() {
+            metadata.insert("creator".to_string(), serde_json::json!(creator));
+            metadata.insert("authors".to_string(), serde_json::json!(epub_metadata.creators));
         }
 
         if let Some(date) = epub_metadata.date {
@@ -319,6 +328,10 @@ impl EpubExtractor {
             metadata.insert("identifier".to_string(), serde_json::json!(identifier));
         }
 
+        if let Some(isbn) = epub_metadata.isbn {
+            metadata.insert("isbn".to_string(), serde_json::json!(isbn));
+        }
+
         if let Some(publisher) = epub_metadata.publisher {
             metadata.insert("publisher".to_string(), serde_json::json!(publisher));
         }
@@ -335,7 +348,7 @@ impl EpubExtractor {
             metadata.insert("rights".to_string(), serde_json::json!(rights));
         }
 
-        Ok(metadata)
+        metadata
     }
 
     /// Parse container.xml to find the OPF file path
@@ -379,8 +392,8 @@ impl EpubExtractor {
                             }
                         }
                         "creator" => {
-                            if let Some(text) = node.text() {
-                                metadata.creator = Some(text.trim().to_string());
+                            if let Some(text) = node.text().map(str::trim).filter(|text| !text.is_empty()) {
+                                metadata.creators.push(text.to_string());
                             }
                         }
                         "date" => {
@@ -395,7 +408,15 @@ impl EpubExtractor {
                         }
                         "identifier" => {
                             if let Some(text) = node.text() {
-                                metadata.identifier = Some(text.trim().to_string());
+                                let text = text.trim();
+                                let scheme = node
+                                    .attributes()
+                                    .find(|attr| attr.name() == "scheme")
+                                    .map(|attr| attr.value());
+                                if metadata.isbn.is_none() {
+                                    metadata.isbn = parse_isbn(text, scheme);
+                                }
+                                metadata.identifier = Some(text.to_string());
                             }
                         }
                         "publisher" => {
@@ -483,16 +504,131 @@ impl EpubExtractor {
 #[derive(Debug, Default, Clone)]
 struct OepbMetadata {
     title: Option<String>,
-    creator: Option<String>,
+    creators: Vec<String>,
     date: Option<String>,
     language: Option<String>,
     identifier: Option<String>,
+    isbn: Option<String>,
     publisher: Option<String>,
     subject: Option<String>,
     description: Option<String>,
     rights: Option<String>,
 }
 
+/// Text of a single ebook chapter
+pub(crate) struct EbookChapter {
+    /// Chapter heading, if one was found
+    pub title: Option<String>,
+    /// Converted chapter text
+    pub content: String,
+}
+
+/// Ebook content joined from chapters, with chapter boundaries
+pub(crate) struct ChapterLayout {
+    pub content: String,
+    pub structure: Option<PageStructure>,
+    pub pages: Option<Vec<PageContent>>,
+}
+
+/// Join chapters into a single content string and record their boundaries
+///
+/// Chapter boundaries are always returned as a [`PageStructure`]; per-chapter
+/// [`PageContent`] is only produced when `page_config.extract_pages` is set, and
+/// page markers are inserted before each chapter when `insert_page_markers` is set.
+pub(crate) fn assemble_chapters(
+    chapters: Vec<EbookChapter>,
+    separator: &str,
+    page_config: Option<&PageConfig>,
+) -> ChapterLayout {
+    let extract_pages = page_config.is_some_and(|cfg| cfg.extract_pages);
+
+    let mut content = String::new();
+    let mut boundaries = Vec::with_capacity(chapters.len());
+    let mut infos = Vec::with_capacity(chapters.len());
+    let mut pages = Vec::new();
+
+    for (index, chapter) in chapters.into_iter().enumerate() {
+        let number = index + 1;
+        if index > 0 {
+            content.push_str(separator);
+        }
+
+        let byte_start = content.len();
+        if let Some(cfg) = page_config
+            && cfg.insert_page_markers
+        {
+            content.push_str(&cfg.marker_format.replace("{page_num}", &number.to_string()));
+        }
+        content.push_str(&chapter.content);
+
+        boundaries.push(PageBoundary {
+            byte_start,
+            byte_end: content.len(),
+            page_number: number,
+        });
+        infos.push(PageInfo {
+            number,
+            title: chapter.title,
+            dimensions: None,
+            image_count: None,
+            table_count: None,
+            hidden: None,
+        });
+        if extract_pages {
+            pages.push(PageContent {
+                page_number: number,
+                content: chapter.content,
+                tables: Vec::new(),
+                images: Vec::new(),
+                content_blob: None,
+            });
+        }
+    }
+
+    let structure = (!boundaries.is_empty()).then_some(PageStructure {
+        total_count: boundaries.len(),
+        unit_type: PageUnitType::Chapter,
+        boundaries: Some(boundaries),
+        pages: Some(infos),
+    });
+
+    ChapterLayout {
+        content,
+        structure,
+        pages: extract_pages.then_some(pages),
+    }
+}
+
+/// Normalize an identifier to an ISBN if it is declared or formatted as one
+///
+/// Accepts `opf:scheme="ISBN"` identifiers, `urn:isbn:`/`isbn:` prefixes and bare
+/// ISBN-10/ISBN-13 values (hyphens and spaces are removed).
+pub(crate) fn parse_isbn(identifier: &str, scheme: Option<&str>) -> Option<String> {
+    let lower = identifier.to_ascii_lowercase();
+    let prefixed = ["urn:isbn:", "isbn:", "isbn "]
+        .iter()
+        .find_map(|prefix| lower.strip_prefix(prefix).map(|_| &identifier[prefix.len()..]));
+    let declared = scheme.is_some_and(|scheme| scheme.eq_ignore_ascii_case("isbn"));
+
+    let candidate = prefixed.unwrap_or(identifier);
+    let digits: String = candidate
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let is_isbn = match digits.len() {
+        10 => {
+            digits[..9].chars().all(|c| c.is_ascii_digit())
+                && digits[9..].chars().all(|c| c.is_ascii_digit() || c == 'X')
+        }
+        13 => digits.chars().all(|c| c.is_ascii_digit()),
+        _ => false,
+    };
+
+    (is_isbn || ((declared || prefixed.is_some()) && !digits.is_empty())).then_some(digits)
+}
+
 impl Default for EpubExtractor {
     fn default() -> Self {
         Self::new()
@@ -531,7 +667,7 @@ impl DocumentExtractor for EpubExtractor {
     #[cfg_attr(
         feature = "otel",
         tracing::instrument(
-            skip(self, content, _config),
+            skip(self, content, config),
             fields(
                 extractor.name = self.name(),
                 content.size_bytes = content.len(),
@@ -542,7 +678,7 @@ impl DocumentExtractor for EpubExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let cursor = Cursor::new(content.to_vec());
 
@@ -561,24 +697,33 @@ impl DocumentExtractor for EpubExtractor {
         };
 
         let opf_xml = Self::read_file_from_zip(&mut archive, &opf_path)?;
+        let (epub_metadata, spine_hrefs) = Self::parse_opf(&opf_xml)?;
 
-        let extracted_content = Self::extract_content(&mut archive, &opf_path, &manifest_dir)?;
+        let html_options = config.html_options.as_ref();
+        let chapters = Self::extract_chapters(&mut archive, &spine_hrefs, &manifest_dir, html_options);
+        let separator = if html_options.is_some() { "\n\n" } else { "\n" };
+        let layout = assemble_chapters(chapters, separator, config.pages.as_ref());
 
-        let metadata_btree = Self::extract_metadata(&opf_xml)?;
+        let metadata_btree = Self::extract_metadata(epub_metadata);
         let metadata_map: std::collections::HashMap<String, serde_json::Value> = metadata_btree.into_iter().collect();
 
         Ok(ExtractionResult {
-            content: extracted_content,
+            content: layout.content,
             mime_type: mime_type.to_string(),
             metadata: Metadata {
+                pages: layout.structure,
                 additional: metadata_map,
                 ..Default::default()
             },
-            pages: None,
+            pages: layout.pages,
             tables: vec![],
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -593,6 +738,16 @@ impl DocumentExtractor for EpubExtractor {
     fn priority(&self) -> i32 {
         60
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: false,
+            images: false,
+            metadata: true,
+            pages: true,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(all(test, feature = "office"))]
@@ -697,6 +852,77 @@ mod tests {
         assert!(!result.contains("**"));
     }
 
+    #[test]
+    fn test_chapter_title_uses_first_heading() {
+        let xhtml = "<html><head><title>Ignored</title></head><body><h2 class=\"c\">Chapter <em>One</em></h2><h1>Later</h1></body></html>";
+        assert_eq!(EpubExtractor::chapter_title(xhtml), Some("Chapter One".to_string()));
+        assert_eq!(EpubExtractor::chapter_title("<p>No heading</p>"), None);
+    }
+
+    #[test]
+    fn test_parse_isbn() {
+        assert_eq!(
+            parse_isbn("urn:isbn:978-0-14-118776-1", None),
+            Some("9780141187761".to_string())
+        );
+        assert_eq!(parse_isbn("0-306-40615-x", None), Some("030640615X".to_string()));
+        assert_eq!(
+            parse_isbn("9780141187761", Some("ISBN")),
+            Some("9780141187761".to_string())
+        );
+        assert_eq!(parse_isbn("urn:uuid:bf73ac09-2ce7-4a11-8982-3a93f28a21dd", None), None);
+        assert_eq!(parse_isbn("code.google.com.epub-samples.wasteland-basic", None), None);
+    }
+
+    #[test]
+    fn test_assemble_chapters_records_boundaries() {
+        let chapters = vec![
+            EbookChapter {
+                title: Some("One".to_string()),
+                content: "First chapter".to_string(),
+            },
+            EbookChapter {
+                title: None,
+                content: "Second chapter".to_string(),
+            },
+        ];
+        let page_config = PageConfig {
+            extract_pages: true,
+            insert_page_markers: true,
+            marker_format: "[{page_num}] ".to_string(),
+        };
+
+        let layout = assemble_chapters(chapters, "\n", Some(&page_config));
+
+        assert_eq!(layout.content, "[1] First chapter\n[2] Second chapter");
+        let structure = layout.structure.expect("chapter structure");
+        assert_eq!(structure.total_count, 2);
+        assert_eq!(structure.unit_type, PageUnitType::Chapter);
+        let boundaries = structure.boundaries.expect("boundaries");
+        assert_eq!(
+            &layout.content[boundaries[1].byte_start..boundaries[1].byte_end],
+            "[2] Second chapter"
+        );
+        assert_eq!(structure.pages.expect("page info")[0].title.as_deref(), Some("One"));
+        let pages = layout.pages.expect("pages");
+        assert_eq!(pages[1].content, "Second chapter");
+    }
+
+    #[test]
+    fn test_assemble_chapters_without_page_config() {
+        let chapters = vec![EbookChapter {
+            title: None,
+            content: "Only".to_string(),
+        }];
+
+        let layout = assemble_chapters(chapters, "\n", None);
+
+        assert_eq!(layout.content, "Only");
+        assert!(layout.pages.is_none());
+        assert_eq!(layout.structure.map(|s| s.total_count), Some(1));
+        assert!(assemble_chapters(Vec::new(), "\n", None).structure.is_none());
+    }
+
     #[test]
     fn test_markdown_to_plain_text_removes_list_markers() {
         let markdown = "- Item 1\n- Item 2\n* Item 3";
@@ -2,7 +2,7 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 use crate::types::{ExcelMetadata, ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
 use std::path::Path;
@@ -45,6 +45,7 @@ impl ExcelExtractor {
                     cells: cells.clone(),
                     markdown: sheet.markdown.clone(),
                     page_number: sheet_index + 1,
+                    column_types: None,
                 });
             }
         }
@@ -74,7 +75,7 @@ impl Plugin for ExcelExtractor {
 #[async_trait]
 impl DocumentExtractor for ExcelExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -84,8 +85,9 @@ impl DocumentExtractor for ExcelExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
+        let options = config.spreadsheet_options.clone().unwrap_or_default();
         let extension = match mime_type {
             "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => ".xlsx",
             "application/vnd.ms-excel.sheet.macroEnabled.12" => ".xlsm",
@@ -104,12 +106,12 @@ impl DocumentExtractor for ExcelExtractor {
             let span = tracing::Span::current();
             tokio::task::spawn_blocking(move || {
                 let _guard = span.entered();
-                crate::extraction::excel::read_excel_bytes(&content_owned, &extension_owned)
+                crate::extraction::excel::read_excel_bytes_with_options(&content_owned, &extension_owned, &options)
             })
             .await
             .map_err(|e| crate::error::KreuzbergError::parsing(format!("Excel extraction task failed: {}", e)))??
         } else {
-            crate::extraction::excel::read_excel_bytes(content, extension)?
+            crate::extraction::excel::read_excel_bytes_with_options(content, extension, &options)?
         };
 
         let markdown = crate::extraction::excel::excel_to_markdown(&workbook);
@@ -141,21 +143,26 @@ impl DocumentExtractor for ExcelExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, path, _config),
+        skip(self, path, config),
         fields(
             extractor.name = self.name(),
         )
     ))]
-    async fn extract_file(&self, path: &Path, mime_type: &str, _config: &ExtractionConfig) -> Result<ExtractionResult> {
+    async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
         let path_str = path
             .to_str()
             .ok_or_else(|| crate::KreuzbergError::validation("Invalid file path".to_string()))?;
 
-        let workbook = crate::extraction::excel::read_excel_file(path_str)?;
+        let options = config.spreadsheet_options.clone().unwrap_or_default();
+        let workbook = crate::extraction::excel::read_excel_file_with_options(path_str, &options)?;
         let markdown = crate::extraction::excel::excel_to_markdown(&workbook);
         let tables = Self::sheets_to_tables(&workbook);
 
@@ -185,6 +192,10 @@ impl DocumentExtractor for ExcelExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -204,6 +215,16 @@ impl DocumentExtractor for ExcelExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -3,7 +3,9 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extractors::SyncExtractor;
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
+use crate::text::encoding::{ENCODING_METADATA_KEY, decode_text};
+use crate::text::outline::{build_outline, markdown_headings};
 use crate::text::utf8_validation;
 use crate::types::{ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
@@ -63,6 +65,7 @@ fn parse_markdown_tables(markdown: &str) -> Vec<Table> {
                 cells,
                 markdown: markdown_table,
                 page_number: table_index + 1,
+                column_types: None,
             });
             table_index += 1;
             i = end_idx;
@@ -198,9 +201,18 @@ impl Plugin for HtmlExtractor {
 
 impl SyncExtractor for HtmlExtractor {
     fn extract_sync(&self, content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let html = utf8_validation::from_utf8(content)
-            .map(|s| s.to_string())
-            .unwrap_or_else(|_| String::from_utf8_lossy(content).to_string());
+        let (html, encoding) = match &config.text_options {
+            Some(options) => {
+                let decoded = decode_text(content, options)?;
+                (decoded.text, Some(decoded.encoding))
+            }
+            None => (
+                utf8_validation::from_utf8(content)
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|_| String::from_utf8_lossy(content).to_string()),
+                None,
+            ),
+        };
 
         // Convert HTML to markdown once, then reuse for both table extraction and metadata parsing
         // This eliminates redundant conversion calls and improves performance by ~50%
@@ -210,18 +222,31 @@ impl SyncExtractor for HtmlExtractor {
 
         let (html_metadata, content_without_frontmatter) = crate::extraction::html::parse_html_metadata(&markdown)?;
 
+        let headings = markdown_headings(&content_without_frontmatter);
+        let mut metadata = Metadata {
+            format: html_metadata.map(|m| crate::types::FormatMetadata::Html(Box::new(m))),
+            outline: (!headings.is_empty()).then(|| build_outline(headings)),
+            ..Default::default()
+        };
+        if let Some(encoding) = encoding {
+            metadata
+                .additional
+                .insert(ENCODING_METADATA_KEY.to_string(), serde_json::Value::String(encoding));
+        }
+
         Ok(ExtractionResult {
             content: content_without_frontmatter,
             mime_type: mime_type.to_string(),
-            metadata: Metadata {
-                format: html_metadata.map(|m| crate::types::FormatMetadata::Html(Box::new(m))),
-                ..Default::default()
-            },
+            metadata,
             pages: None,
             tables,
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 }
@@ -265,6 +290,16 @@ impl DocumentExtractor for HtmlExtractor {
         50
     }
 
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
+
     fn as_sync_extractor(&self) -> Option<&dyn crate::extractors::SyncExtractor> {
         Some(self)
     }
@@ -417,4 +452,25 @@ mod tests {
         assert_eq!(table.cells[1], vec!["Alice", "30"]);
         assert_eq!(table.cells[2], vec!["Bob", "25"]);
     }
+
+    #[tokio::test]
+    async fn test_html_extractor_outline() {
+        let html = "<h1>Guide</h1><p>Intro</p><h2>Install</h2><p>Steps</p><h3>Linux</h3><h2>Usage</h2>";
+
+        let extractor = HtmlExtractor::new();
+        let result = extractor
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        let outline = result.metadata.outline.expect("headings should produce an outline");
+        assert_eq!(outline.len(), 1);
+        assert_eq!(outline[0].title, "Guide");
+        let sections: Vec<&str> = outline[0].children.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(sections, ["Install", "Usage"]);
+        assert_eq!(outline[0].children[0].children[0].title, "Linux");
+
+        let offset = outline[0].children[1].byte_offset.unwrap();
+        assert!(result.content[offset..].starts_with("Usage"));
+    }
 }
@@ -3,14 +3,15 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::image::extract_image_metadata;
-use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata};
+use crate::extraction::xmp::extract_xmp_metadata;
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
+use crate::types::{Barcode, ExtractionResult, Metadata};
 use async_trait::async_trait;
 
 /// Image extractor for various image formats.
 ///
 /// Supports: PNG, JPEG, WebP, BMP, TIFF, GIF.
-/// Extracts dimensions, format, and EXIF metadata.
+/// Extracts dimensions, format, EXIF and XMP metadata.
 /// Optionally runs OCR when configured.
 pub struct ImageExtractor;
 
@@ -28,6 +29,8 @@ impl ImageExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
+        use crate::ocr::correction::{OcrSpan, record_ocr_spans};
+        use crate::ocr::fallback::{process_image_with_fallback, record_ocr_pages};
         use crate::plugins::registry::get_ocr_backend_registry;
 
         let ocr_config = config.ocr.as_ref().ok_or_else(|| crate::KreuzbergError::Parsing {
@@ -36,15 +39,21 @@ impl ImageExtractor {
         })?;
 
         let backend = {
-            let registry = get_ocr_backend_registry();
-            let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
-                message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
-                plugin_name: "ocr-registry".to_string(),
-            })?;
+            let registry = get_ocr_backend_registry().snapshot();
             registry.get(&ocr_config.backend)?
         };
 
-        let ocr_result = backend.process_image(content, ocr_config).await?;
+        let ocr_future = process_image_with_fallback(&backend, content, ocr_config, 1);
+        #[cfg(feature = "tokio-runtime")]
+        let ocr_future = crate::core::watchdog::with_budget(
+            crate::ExtractionStage::Ocr,
+            config
+                .stage_timeouts
+                .as_ref()
+                .and_then(|timeouts| timeouts.budget(crate::ExtractionStage::Ocr)),
+            ocr_future,
+        );
+        let (ocr_result, page_backend) = ocr_future.await?;
 
         let ocr_text = ocr_result.content.clone();
         let ocr_extraction_result = crate::extraction::image::extract_text_from_image_with_ocr(
@@ -57,11 +66,34 @@ impl ImageExtractor {
         let mut result = ocr_result;
         result.content = ocr_extraction_result.content;
         result.pages = ocr_extraction_result.page_contents;
+        record_ocr_pages(&mut result.metadata, ocr_config, &[page_backend]);
+        let span = OcrSpan {
+            start: 0,
+            end: result.content.len(),
+        };
+        record_ocr_spans(&mut result.metadata, config, &[span]);
 
         Ok(result)
     }
 }
 
+/// Decode barcodes and QR codes from the image when `detect_barcodes` is enabled.
+///
+/// Multi-frame images are scanned on their first frame only.
+fn detect_barcodes(content: &[u8], config: &ExtractionConfig) -> Option<Vec<Barcode>> {
+    if !config.detect_barcodes {
+        return None;
+    }
+    match image::load_from_memory(content) {
+        Ok(decoded) => Some(crate::barcode::detect_barcodes(&decoded.to_luma8(), 1, 1.0)),
+        Err(_e) => {
+            #[cfg(feature = "otel")]
+            tracing::warn!("Barcode detection skipped, image could not be decoded: {}", _e);
+            Some(Vec::new())
+        }
+    }
+}
+
 impl Default for ImageExtractor {
     fn default() -> Self {
         Self::new()
@@ -109,14 +141,19 @@ impl DocumentExtractor for ImageExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let extraction_metadata = extract_image_metadata(content)?;
+        let mut extraction_metadata = extract_image_metadata(content)?;
+        if config.images.as_ref().is_some_and(|img| img.strip_gps) {
+            extraction_metadata.strip_gps();
+        }
 
         let image_metadata = crate::types::ImageMetadata {
             width: extraction_metadata.width,
             height: extraction_metadata.height,
             format: extraction_metadata.format.clone(),
             exif: extraction_metadata.exif_data,
+            exif_details: extraction_metadata.exif_details,
         };
+        let xmp = extract_xmp_metadata(content);
 
         if config.ocr.is_some() {
             #[cfg(feature = "ocr")]
@@ -124,7 +161,9 @@ impl DocumentExtractor for ImageExtractor {
                 let mut ocr_result = self.extract_with_ocr(content, mime_type, config).await?;
 
                 ocr_result.metadata.format = Some(crate::types::FormatMetadata::Image(image_metadata));
+                ocr_result.metadata.xmp = xmp;
                 ocr_result.mime_type = mime_type.to_string();
+                ocr_result.barcodes = detect_barcodes(content, config);
 
                 return Ok(ocr_result);
             }
@@ -140,6 +179,7 @@ impl DocumentExtractor for ImageExtractor {
                     mime_type: mime_type.to_string(),
                     metadata: Metadata {
                         format: Some(crate::types::FormatMetadata::Image(image_metadata)),
+                        xmp,
                         ..Default::default()
                     },
                     pages: None,
@@ -147,6 +187,10 @@ impl DocumentExtractor for ImageExtractor {
                     detected_languages: None,
                     chunks: None,
                     images: None,
+                    term_offsets: None,
+                    barcodes: None,
+                    provenance: None,
+                    schema_version: ExtractionResult::SCHEMA_VERSION,
                 });
             }
         }
@@ -159,6 +203,7 @@ impl DocumentExtractor for ImageExtractor {
             mime_type: mime_type.to_string(),
             metadata: Metadata {
                 format: Some(crate::types::FormatMetadata::Image(image_metadata)),
+                xmp,
                 ..Default::default()
             },
             pages: None,
@@ -166,6 +211,10 @@ impl DocumentExtractor for ImageExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: detect_barcodes(content, config),
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -184,6 +233,16 @@ impl DocumentExtractor for ImageExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -200,6 +259,30 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_image_extractor_barcodes_only_when_enabled() {
+        let blank = image::GrayImage::from_pixel(64, 64, image::Luma([255]));
+        let mut png = std::io::Cursor::new(Vec::new());
+        blank.write_to(&mut png, image::ImageFormat::Png).unwrap();
+        let extractor = ImageExtractor::new();
+
+        let result = extractor
+            .extract_bytes(png.get_ref(), "image/png", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert!(result.barcodes.is_none());
+
+        let config = ExtractionConfig {
+            detect_barcodes: true,
+            ..Default::default()
+        };
+        let result = extractor
+            .extract_bytes(png.get_ref(), "image/png", &config)
+            .await
+            .unwrap();
+        assert_eq!(result.barcodes, Some(vec![]));
+    }
+
     #[test]
     fn test_image_plugin_interface() {
         let extractor = ImageExtractor::new();
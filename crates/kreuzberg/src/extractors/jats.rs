@@ -16,7 +16,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::cells_to_markdown;
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 use crate::types::{ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
 use quick_xml::Reader;
@@ -376,6 +376,7 @@ fn extract_jats_all_in_one(content: &str) -> Result<(JatsMetadataExtracted, Stri
                                 cells: current_table.clone(),
                                 markdown,
                                 page_number: table_index + 1,
+                                column_types: None,
                             });
                             table_index += 1;
                             current_table.clear();
@@ -571,6 +572,10 @@ impl DocumentExtractor for JatsExtractor {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -597,6 +602,16 @@ impl DocumentExtractor for JatsExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
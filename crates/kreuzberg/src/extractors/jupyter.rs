@@ -3,8 +3,9 @@
 //! This extractor provides native Rust parsing of Jupyter notebooks,
 //! extracting:
 //! - Notebook metadata (kernelspec, language_info, nbformat)
-//! - Cell content (markdown and code cells in order)
-//! - Cell outputs (text, HTML, images)
+//! - Cell content (markdown cells as-is, code cells as fenced code blocks)
+//! - Cell outputs (text, HTML); image outputs are decoded into the images array
+//!   when image extraction is enabled, and never inlined as base64 text
 //! - Cell metadata (execution_count, tags)
 //!
 //! Requires the `office` feature.
@@ -14,16 +15,33 @@ use crate::Result;
 #[cfg(feature = "office")]
 use crate::core::config::ExtractionConfig;
 #[cfg(feature = "office")]
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 #[cfg(feature = "office")]
-use crate::types::{ExtractionResult, Metadata};
+use crate::types::{ExtractedImage, ExtractionResult, Metadata};
 #[cfg(feature = "office")]
 use async_trait::async_trait;
 #[cfg(feature = "office")]
+use base64::prelude::*;
+#[cfg(feature = "office")]
 use serde_json::{Value, json};
 #[cfg(feature = "office")]
 use std::collections::HashMap;
 
+/// Raster image MIME types in cell outputs and their image formats.
+#[cfg(feature = "office")]
+const OUTPUT_IMAGE_TYPES: &[(&str, &str)] = &[("image/png", "png"), ("image/jpeg", "jpeg"), ("image/gif", "gif")];
+
+/// Rendering state shared by all cells of a notebook.
+#[cfg(feature = "office")]
+struct NotebookState {
+    /// Language tag for fenced code blocks (from `language_info` or `kernelspec`)
+    code_language: String,
+    /// Whether image outputs are decoded into `images`
+    extract_images: bool,
+    /// Images decoded from cell outputs
+    images: Vec<ExtractedImage>,
+}
+
 /// Jupyter Notebook extractor.
 ///
 /// Extracts content from Jupyter notebook JSON files, including:
@@ -42,12 +60,20 @@ impl JupyterExtractor {
     }
 
     /// Extract content from a Jupyter notebook.
-    fn extract_notebook(content: &[u8]) -> Result<(String, HashMap<String, Value>)> {
+    fn extract_notebook(
+        content: &[u8],
+        extract_images: bool,
+    ) -> Result<(String, HashMap<String, Value>, Vec<ExtractedImage>)> {
         let notebook: Value = serde_json::from_slice(content)
             .map_err(|e| crate::KreuzbergError::parsing(format!("Failed to parse JSON: {}", e)))?;
 
         let mut extracted_content = String::new();
         let mut metadata = HashMap::new();
+        let mut state = NotebookState {
+            code_language: Self::code_language(&notebook),
+            extract_images,
+            images: Vec::new(),
+        };
 
         if let Some(notebook_metadata) = notebook.get("metadata").and_then(|m| m.as_object()) {
             if let Some(kernelspec) = notebook_metadata.get("kernelspec")
@@ -74,20 +100,41 @@ impl JupyterExtractor {
 
         if let Some(cells) = notebook.get("cells").and_then(|c| c.as_array()) {
             for (cell_idx, cell) in cells.iter().enumerate() {
-                Self::extract_cell(cell, cell_idx, &mut extracted_content, &mut metadata)?;
+                Self::extract_cell(cell, cell_idx, &mut extracted_content, &mut state)?;
             }
         }
 
-        Ok((extracted_content, metadata))
+        Ok((extracted_content, metadata, state.images))
+    }
+
+    /// Language of the notebook's code cells, used as the code fence info string.
+    fn code_language(notebook: &Value) -> String {
+        let metadata = notebook.get("metadata");
+        metadata
+            .and_then(|m| m.get("language_info"))
+            .and_then(|info| info.get("name"))
+            .or_else(|| {
+                metadata
+                    .and_then(|m| m.get("kernelspec"))
+                    .and_then(|spec| spec.get("language"))
+            })
+            .and_then(|name| name.as_str())
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "python".to_string())
+    }
+
+    /// Append text, making sure it ends with a newline so following fences and
+    /// dividers start on their own line.
+    fn push_block(content: &mut String, text: &str) {
+        content.push_str(text);
+        if !text.is_empty() && !text.ends_with('\n') {
+            content.push('\n');
+        }
     }
 
     /// Extract content from a single cell.
-    fn extract_cell(
-        cell: &Value,
-        cell_idx: usize,
-        content: &mut String,
-        _metadata: &mut HashMap<String, Value>,
-    ) -> Result<()> {
+    fn extract_cell(cell: &Value, cell_idx: usize, content: &mut String, state: &mut NotebookState) -> Result<()> {
         let cell_type = cell.get("cell_type").and_then(|t| t.as_str()).unwrap_or("unknown");
 
         let cell_id = cell.get("id").and_then(|id| id.as_str());
@@ -113,7 +160,7 @@ impl JupyterExtractor {
 
         match cell_type {
             "markdown" => Self::extract_markdown_cell(cell, content)?,
-            "code" => Self::extract_code_cell(cell, content)?,
+            "code" => Self::extract_code_cell(cell, content, state)?,
             "raw" => Self::extract_raw_cell(cell, content)?,
             _ => {
                 content.push_str(&format!("Unknown cell type: {}\n", cell_type));
@@ -128,13 +175,13 @@ impl JupyterExtractor {
     fn extract_markdown_cell(cell: &Value, content: &mut String) -> Result<()> {
         if let Some(source) = cell.get("source") {
             let cell_text = Self::extract_source(source);
-            content.push_str(&cell_text);
+            Self::push_block(content, &cell_text);
         }
         Ok(())
     }
 
     /// Extract code cell content and outputs.
-    fn extract_code_cell(cell: &Value, content: &mut String) -> Result<()> {
+    fn extract_code_cell(cell: &Value, content: &mut String, state: &mut NotebookState) -> Result<()> {
         if let Some(exec_count) = cell.get("execution_count")
             && !exec_count.is_null()
         {
@@ -143,14 +190,16 @@ impl JupyterExtractor {
 
         if let Some(source) = cell.get("source") {
             let cell_text = Self::extract_source(source);
-            content.push_str("```python\n");
-            content.push_str(&cell_text);
+            content.push_str("```");
+            content.push_str(&state.code_language);
+            content.push('\n');
+            Self::push_block(content, &cell_text);
             content.push_str("```\n");
         }
 
         if let Some(outputs) = cell.get("outputs").and_then(|o| o.as_array()) {
             for output in outputs {
-                Self::extract_output(output, content)?;
+                Self::extract_output(output, content, state)?;
             }
         }
 
@@ -161,7 +210,7 @@ impl JupyterExtractor {
     fn extract_raw_cell(cell: &Value, content: &mut String) -> Result<()> {
         if let Some(source) = cell.get("source") {
             let cell_text = Self::extract_source(source);
-            content.push_str(&cell_text);
+            Self::push_block(content, &cell_text);
         }
         Ok(())
     }
@@ -178,7 +227,7 @@ impl JupyterExtractor {
     }
 
     /// Extract output from a cell.
-    fn extract_output(output: &Value, content: &mut String) -> Result<()> {
+    fn extract_output(output: &Value, content: &mut String, state: &mut NotebookState) -> Result<()> {
         let output_type = output.get("output_type").and_then(|t| t.as_str()).unwrap_or("unknown");
 
         content.push_str(&format!("::: {{.output .{}", output_type));
@@ -193,7 +242,7 @@ impl JupyterExtractor {
 
         match output_type {
             "stream" => Self::extract_stream_output(output, content)?,
-            "execute_result" | "display_data" => Self::extract_data_output(output, content)?,
+            "execute_result" | "display_data" => Self::extract_data_output(output, content, state)?,
             "error" => Self::extract_error_output(output, content)?,
             _ => {
                 content.push_str(&format!("Unknown output type: {}\n", output_type));
@@ -219,7 +268,11 @@ impl JupyterExtractor {
     }
 
     /// Extract data output (execute_result or display_data).
-    fn extract_data_output(output: &Value, content: &mut String) -> Result<()> {
+    ///
+    /// Raster images are base64 payloads; they are decoded into `state.images` (and
+    /// referenced from the content) when image extraction is enabled, and otherwise
+    /// only their MIME type is recorded.
+    fn extract_data_output(output: &Value, content: &mut String, state: &mut NotebookState) -> Result<()> {
         if let Some(data) = output.get("data").and_then(|d| d.as_object()) {
             let mime_types = vec![
                 "text/markdown",
@@ -227,18 +280,34 @@ impl JupyterExtractor {
                 "image/svg+xml",
                 "image/png",
                 "image/jpeg",
+                "image/gif",
                 "application/json",
                 "text/plain",
             ];
 
             for mime_type in mime_types {
-                if let Some(mime_content) = data.get(mime_type) {
-                    content.push_str(&format!("MIME: {}\n", mime_type));
-                    let mime_text = Self::extract_source(mime_content);
-                    if !mime_text.is_empty() {
-                        content.push_str(&mime_text);
-                        content.push('\n');
+                let Some(mime_content) = data.get(mime_type) else {
+                    continue;
+                };
+                content.push_str(&format!("MIME: {}\n", mime_type));
+
+                if let Some((_, format)) = OUTPUT_IMAGE_TYPES
+                    .iter()
+                    .find(|(image_mime, _)| *image_mime == mime_type)
+                {
+                    if state.extract_images
+                        && let Some(image) = Self::decode_output_image(mime_content, format, state.images.len())
+                    {
+                        content.push_str(&format!("![](image_{}.{})\n", image.image_index, format));
+                        state.images.push(image);
                     }
+                    continue;
+                }
+
+                let mime_text = Self::extract_source(mime_content);
+                if !mime_text.is_empty() {
+                    content.push_str(&mime_text);
+                    content.push('\n');
                 }
             }
         }
@@ -246,6 +315,33 @@ impl JupyterExtractor {
         Ok(())
     }
 
+    /// Decode a base64 image payload from a cell output.
+    fn decode_output_image(payload: &Value, format: &str, image_index: usize) -> Option<ExtractedImage> {
+        let encoded: String = Self::extract_source(payload)
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+        let data = BASE64_STANDARD.decode(encoded).ok()?;
+
+        Some(ExtractedImage {
+            data,
+            format: format.to_string(),
+            image_index,
+            page_number: None,
+            width: None,
+            height: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
+        })
+    }
+
     /// Extract error output.
     fn extract_error_output(output: &Value, content: &mut String) -> Result<()> {
         if let Some(ename) = output.get("ename").and_then(|e| e.as_str()) {
@@ -309,7 +405,7 @@ impl DocumentExtractor for JupyterExtractor {
     #[cfg_attr(
         feature = "otel",
         tracing::instrument(
-            skip(self, content, _config),
+            skip(self, content, config),
             fields(
                 extractor.name = self.name(),
                 content.size_bytes = content.len(),
@@ -320,9 +416,10 @@ impl DocumentExtractor for JupyterExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let (extracted_content, additional_metadata) = Self::extract_notebook(content)?;
+        let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
+        let (extracted_content, additional_metadata, images) = Self::extract_notebook(content, extract_images)?;
 
         let mut metadata_additional = HashMap::new();
         for (key, value) in additional_metadata {
@@ -340,7 +437,11 @@ impl DocumentExtractor for JupyterExtractor {
             tables: vec![],
             detected_languages: None,
             chunks: None,
-            images: None,
+            images: extract_images.then_some(images),
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -351,6 +452,16 @@ impl DocumentExtractor for JupyterExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: false,
+            images: true,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -365,4 +476,52 @@ mod tests {
         assert_eq!(extractor.priority(), 50);
         assert!(extractor.supported_mime_types().contains(&"application/x-ipynb+json"));
     }
+
+    fn notebook_with_image_output() -> Vec<u8> {
+        json!({
+            "nbformat": 4,
+            "nbformat_minor": 5,
+            "metadata": {"language_info": {"name": "R"}},
+            "cells": [
+                {"cell_type": "markdown", "id": "intro", "metadata": {}, "source": ["# Analysis"]},
+                {
+                    "cell_type": "code",
+                    "id": "plot",
+                    "execution_count": 1,
+                    "metadata": {},
+                    "source": ["plot(x)"],
+                    "outputs": [{
+                        "output_type": "display_data",
+                        "metadata": {},
+                        "data": {"image/png": "iVBORw0K\nGgo=\n", "text/plain": ["<Figure>"]}
+                    }]
+                }
+            ]
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_code_cells_use_notebook_language_and_close_fences() {
+        let (content, _, images) = JupyterExtractor::extract_notebook(&notebook_with_image_output(), false).unwrap();
+
+        assert!(content.contains("# Analysis\n"));
+        assert!(content.contains("```r\nplot(x)\n```\n"));
+        assert!(content.contains("MIME: image/png\n"));
+        assert!(!content.contains("iVBORw0K"), "image base64 must not leak into content");
+        assert!(images.is_empty());
+    }
+
+    #[test]
+    fn test_output_images_extracted_when_enabled() {
+        let (content, _, images) = JupyterExtractor::extract_notebook(&notebook_with_image_output(), true).unwrap();
+
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].format, "png");
+        assert_eq!(images[0].image_index, 0);
+        assert_eq!(&images[0].data[..4], b"\x89PNG");
+        assert!(content.contains("![](image_0.png)"));
+        assert!(content.contains("<Figure>"));
+    }
 }
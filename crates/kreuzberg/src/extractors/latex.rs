@@ -15,7 +15,7 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 use crate::types::{ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
 
@@ -94,6 +94,10 @@ impl DocumentExtractor for LatexExtractor {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -104,6 +108,16 @@ impl DocumentExtractor for LatexExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 /// LaTeX parser
@@ -441,6 +455,7 @@ impl<'a> LatexParser<'a> {
                 cells: rows,
                 markdown: markdown.clone(),
                 page_number: 1,
+                column_types: None,
             };
             self.tables.push(table);
         }
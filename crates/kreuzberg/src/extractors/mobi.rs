@@ -0,0 +1,633 @@
+//! Native MOBI (Mobipocket) ebook extractor.
+//!
+//! Reads the PalmDB container directly, without external dependencies:
+//! - Text records are decompressed (uncompressed or PalmDOC LZ77)
+//! - Metadata comes from the MOBI header and EXTH records (title, authors, ISBN, ...)
+//! - The HTML is split into chapters at `<mbp:pagebreak/>` tags, converted with the
+//!   same XHTML pipeline as [`EpubExtractor`], and reported as a chapter structure
+//!
+//! HUFF/CDIC-compressed and DRM-protected files are rejected with a parsing error.
+
+use super::epub::{EbookChapter, EpubExtractor, assemble_chapters, parse_isbn};
+use crate::core::config::ExtractionConfig;
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
+use crate::types::{ExtractionResult, Metadata};
+use crate::{KreuzbergError, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+const PALMDB_HEADER_LEN: usize = 78;
+const PALMDOC_HEADER_LEN: usize = 16;
+
+const COMPRESSION_NONE: u16 = 1;
+const COMPRESSION_PALMDOC: u16 = 2;
+const COMPRESSION_HUFF_CDIC: u16 = 17480;
+/// A two-byte PalmDOC back-reference expands to at most ten bytes
+const PALMDOC_MAX_EXPANSION: usize = 5;
+
+const ENCODING_UTF8: u32 = 65001;
+
+const EXTH_AUTHOR: u32 = 100;
+const EXTH_PUBLISHER: u32 = 101;
+const EXTH_DESCRIPTION: u32 = 103;
+const EXTH_ISBN: u32 = 104;
+const EXTH_SUBJECT: u32 = 105;
+const EXTH_PUBLISHED: u32 = 106;
+const EXTH_RIGHTS: u32 = 109;
+const EXTH_UPDATED_TITLE: u32 = 503;
+const EXTH_LANGUAGE: u32 = 524;
+
+/// MOBI ebook extractor.
+///
+/// Extracts chapter-structured content and metadata from Mobipocket files
+/// (`.mobi`, `.azw`).
+pub struct MobiExtractor;
+
+impl MobiExtractor {
+    /// Create a new MOBI extractor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for MobiExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parsed MOBI book: decoded HTML plus metadata
+#[derive(Debug, Default)]
+struct MobiBook {
+    html: String,
+    title: Option<String>,
+    authors: Vec<String>,
+    publisher: Option<String>,
+    description: Option<String>,
+    isbn: Option<String>,
+    subjects: Vec<String>,
+    date: Option<String>,
+    rights: Option<String>,
+    language: Option<String>,
+}
+
+impl MobiBook {
+    fn into_metadata(self) -> HashMap<String, serde_json::Value> {
+        let mut metadata = HashMap::new();
+
+        if let Some(title) = self.title {
+            metadata.insert("title".to_string(), serde_json::json!(title));
+        }
+        if let Some(author) = self.authors.first() {
+            metadata.insert("creator".to_string(), serde_json::json!(author));
+            metadata.insert("authors".to_string(), serde_json::json!(self.authors));
+        }
+        if let Some(isbn) = self.isbn {
+            metadata.insert("isbn".to_string(), serde_json::json!(isbn));
+        }
+        if let Some(publisher) = self.publisher {
+            metadata.insert("publisher".to_string(), serde_json::json!(publisher));
+        }
+        if let Some(description) = self.description {
+            metadata.insert("description".to_string(), serde_json::json!(description));
+        }
+        if !self.subjects.is_empty() {
+            metadata.insert("subject".to_string(), serde_json::json!(self.subjects.join(", ")));
+        }
+        if let Some(date) = self.date {
+            metadata.insert("date".to_string(), serde_json::json!(date));
+        }
+        if let Some(rights) = self.rights {
+            metadata.insert("rights".to_string(), serde_json::json!(rights));
+        }
+        if let Some(language) = self.language {
+            metadata.insert("language".to_string(), serde_json::json!(language));
+        }
+
+        metadata
+    }
+}
+
+/// Parse a PalmDB/MOBI file into decoded HTML and metadata
+fn parse_mobi(data: &[u8]) -> Result<MobiBook> {
+    if data.len() < PALMDB_HEADER_LEN || (&data[60..68] != b"BOOKMOBI" && &data[60..68] != b"TEXtREAd") {
+        return Err(KreuzbergError::parsing("Not a MOBI file: missing BOOKMOBI header"));
+    }
+
+    let record_count = read_u16(data, 76)? as usize;
+    let mut offsets = Vec::with_capacity(record_count);
+    for index in 0..record_count {
+        offsets.push(read_u32(data, PALMDB_HEADER_LEN + index * 8)? as usize);
+    }
+
+    let header = record(data, &offsets, 0)?;
+    let compression = read_u16(header, 0)?;
+    let text_length = read_u32(header, 4)? as usize;
+    let text_record_count = read_u16(header, 8)? as usize;
+    let encryption = read_u16(header, 12)?;
+
+    if encryption != 0 {
+        return Err(KreuzbergError::parsing("DRM-protected MOBI files are not supported"));
+    }
+    if compression == COMPRESSION_HUFF_CDIC {
+        return Err(KreuzbergError::parsing(
+            "HUFF/CDIC-compressed MOBI files are not supported",
+        ));
+    }
+    if compression != COMPRESSION_NONE && compression != COMPRESSION_PALMDOC {
+        return Err(KreuzbergError::parsing(format!(
+            "Unknown MOBI compression type {}",
+            compression
+        )));
+    }
+
+    let mut book = MobiBook::default();
+    let mut encoding = 1252;
+    let mut extra_data_flags = 0u16;
+
+    let has_mobi_header = header.get(PALMDOC_HEADER_LEN..PALMDOC_HEADER_LEN + 4) == Some(&b"MOBI"[..]);
+    if has_mobi_header {
+        let mobi_header_len = read_u32(header, 20)? as usize;
+        encoding = read_u32(header, 28)?;
+
+        if mobi_header_len >= 0xE4 {
+            extra_data_flags = read_u16(header, 0xF2)?;
+        }
+
+        let name_offset = read_u32(header, 84)? as usize;
+        let name_len = read_u32(header, 88)? as usize;
+        book.title = header
+            .get(name_offset..name_offset + name_len)
+            .map(|name| decode(name, encoding))
+            .filter(|name| !name.trim().is_empty());
+
+        if read_u32(header, 128)? & 0x40 != 0 {
+            parse_exth(header, PALMDOC_HEADER_LEN + mobi_header_len, encoding, &mut book);
+        }
+    }
+
+    if book.title.is_none() {
+        let name = data[..32].split(|&b| b == 0).next().unwrap_or_default();
+        book.title = Some(decode(name, encoding).replace('_', " ")).filter(|name| !name.trim().is_empty());
+    }
+
+    // The header length is untrusted; never reserve more than the records could decompress to
+    let mut text = Vec::with_capacity(text_length.min(data.len().saturating_mul(PALMDOC_MAX_EXPANSION)));
+    for index in 1..=text_record_count.min(record_count.saturating_sub(1)) {
+        let raw = strip_trailing_entries(record(data, &offsets, index)?, extra_data_flags);
+        match compression {
+            COMPRESSION_PALMDOC => palmdoc_decompress(raw, &mut text),
+            _ => text.extend_from_slice(raw),
+        }
+    }
+    text.truncate(text_length);
+
+    book.html = decode(&text, encoding);
+    Ok(book)
+}
+
+/// Slice a PalmDB record by index
+fn record<'a>(data: &'a [u8], offsets: &[usize], index: usize) -> Result<&'a [u8]> {
+    let start = *offsets
+        .get(index)
+        .ok_or_else(|| KreuzbergError::parsing(format!("MOBI record {} is missing", index)))?;
+    let end = offsets.get(index + 1).copied().unwrap_or(data.len());
+    data.get(start..end)
+        .ok_or_else(|| KreuzbergError::parsing(format!("MOBI record {} is out of bounds", index)))
+}
+
+/// Read EXTH metadata records following the MOBI header
+fn parse_exth(header: &[u8], offset: usize, encoding: u32, book: &mut MobiBook) {
+    if header.get(offset..offset + 4) != Some(&b"EXTH"[..]) {
+        return;
+    }
+    let Ok(count) = read_u32(header, offset + 8) else {
+        return;
+    };
+
+    let mut position = offset + 12;
+    for _ in 0..count {
+        let (Ok(kind), Ok(length)) = (read_u32(header, position), read_u32(header, position + 4)) else {
+            break;
+        };
+        let length = length as usize;
+        let Some(value) = length
+            .checked_sub(8)
+            .and_then(|len| header.get(position + 8..position + 8 + len))
+        else {
+            break;
+        };
+        position += length;
+
+        let value = decode(value, encoding).trim().to_string();
+        if value.is_empty() {
+            continue;
+        }
+
+        match kind {
+            EXTH_AUTHOR => book.authors.push(value),
+            EXTH_PUBLISHER => book.publisher = Some(value),
+            EXTH_DESCRIPTION => book.description = Some(value),
+            EXTH_ISBN => book.isbn = parse_isbn(&value, Some("isbn")),
+            EXTH_SUBJECT => book.subjects.push(value),
+            EXTH_PUBLISHED => book.date = Some(value),
+            EXTH_RIGHTS => book.rights = Some(value),
+            EXTH_UPDATED_TITLE => book.title = Some(value),
+            EXTH_LANGUAGE => book.language = Some(value),
+            _ => {}
+        }
+    }
+}
+
+/// Remove the trailing entries appended to each text record
+///
+/// Every set bit above bit 0 of `flags` adds one entry whose size is stored as a
+/// backward-encoded integer at the end of the record; bit 0 adds multibyte
+/// overlap bytes.
+fn strip_trailing_entries(record: &[u8], flags: u16) -> &[u8] {
+    let mut data = record;
+
+    for _ in 0..(flags >> 1).count_ones() {
+        let tail = &data[data.len().saturating_sub(4)..];
+        let mut size = 0usize;
+        for &byte in tail {
+            if byte & 0x80 != 0 {
+                size = 0;
+            }
+            size = (size << 7) | (byte & 0x7F) as usize;
+        }
+        data = &data[..data.len().saturating_sub(size)];
+    }
+
+    if flags & 1 != 0
+        && let Some(&last) = data.last()
+    {
+        data = &data[..data.len().saturating_sub((last & 0x3) as usize + 1)];
+    }
+
+    data
+}
+
+/// Decompress a PalmDOC (LZ77 variant) record into `output`
+fn palmdoc_decompress(input: &[u8], output: &mut Vec<u8>) {
+    let mut position = 0;
+
+    while position < input.len() {
+        let byte = input[position];
+        position += 1;
+
+        match byte {
+            0x00 | 0x09..=0x7F => output.push(byte),
+            0x01..=0x08 => {
+                let end = (position + byte as usize).min(input.len());
+                output.extend_from_slice(&input[position..end]);
+                position = end;
+            }
+            0x80..=0xBF => {
+                let Some(&next) = input.get(position) else {
+                    break;
+                };
+                position += 1;
+
+                let pair = (((byte as usize) << 8) | next as usize) & 0x3FFF;
+                let distance = pair >> 3;
+                let length = (pair & 0x7) + 3;
+                if distance == 0 || distance > output.len() {
+                    continue;
+                }
+                let start = output.len() - distance;
+                for offset in 0..length {
+                    output.push(output[start + offset]);
+                }
+            }
+            0xC0..=0xFF => {
+                output.push(b' ');
+                output.push(byte ^ 0x80);
+            }
+        }
+    }
+}
+
+/// Decode MOBI text as UTF-8 or Windows-1252
+fn decode(bytes: &[u8], encoding: u32) -> String {
+    if encoding == ENCODING_UTF8 {
+        return String::from_utf8_lossy(bytes).into_owned();
+    }
+
+    const CP1252_HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}', '\u{90}', '‘',
+        '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+    ];
+    bytes
+        .iter()
+        .map(|&b| match b {
+            0x80..=0x9F => CP1252_HIGH[(b - 0x80) as usize],
+            _ => b as char,
+        })
+        .collect()
+}
+
+/// Split MOBI HTML into chapters at `<mbp:pagebreak>` tags
+fn split_chapters(html: &str) -> Vec<&str> {
+    let lower = html.to_ascii_lowercase();
+    let mut chapters = Vec::new();
+    let mut start = 0;
+
+    while let Some(found) = lower[start..].find("<mbp:pagebreak") {
+        let tag_start = start + found;
+        chapters.push(&html[start..tag_start]);
+        start = lower[tag_start..]
+            .find('>')
+            .map_or(html.len(), |end| tag_start + end + 1);
+    }
+    chapters.push(&html[start..]);
+
+    chapters
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| KreuzbergError::parsing("Truncated MOBI header"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| KreuzbergError::parsing("Truncated MOBI header"))
+}
+
+impl Plugin for MobiExtractor {
+    fn name(&self) -> &str {
+        "mobi-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts chapters and metadata from MOBI (Mobipocket) ebooks"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+#[cfg(feature = "office")]
+#[async_trait]
+impl DocumentExtractor for MobiExtractor {
+    #[cfg_attr(
+        feature = "otel",
+        tracing::instrument(
+            skip(self, content, config),
+            fields(
+                extractor.name = self.name(),
+                content.size_bytes = content.len(),
+            )
+        )
+    )]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let mut book = parse_mobi(content)?;
+        let html = std::mem::take(&mut book.html);
+
+        let html_options = config.html_options.as_ref();
+        let chapters: Vec<EbookChapter> = split_chapters(&html)
+            .into_iter()
+            .filter_map(|fragment| {
+                let text = EpubExtractor::extract_text_from_xhtml(fragment, html_options);
+                (!text.is_empty()).then(|| EbookChapter {
+                    title: EpubExtractor::chapter_title(fragment),
+                    content: text,
+                })
+            })
+            .collect();
+        let separator = if html_options.is_some() { "\n\n" } else { "\n" };
+        let layout = assemble_chapters(chapters, separator, config.pages.as_ref());
+
+        Ok(ExtractionResult {
+            content: layout.content,
+            mime_type: mime_type.to_string(),
+            metadata: Metadata {
+                pages: layout.structure,
+                additional: book.into_metadata(),
+                ..Default::default()
+            },
+            pages: layout.pages,
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        })
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &["application/x-mobipocket-ebook", "application/vnd.amazon.ebook"]
+    }
+
+    fn priority(&self) -> i32 {
+        60
+    }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: false,
+            images: false,
+            metadata: true,
+            pages: true,
+            ocr: false,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "office"))]
+mod tests {
+    use super::*;
+    use crate::types::PageUnitType;
+
+    /// Build an uncompressed single-record MOBI file with an EXTH block
+    fn build_mobi(html: &str, exth: &[(u32, &str)]) -> Vec<u8> {
+        let mobi_header_len = 232usize;
+        let mut exth_block = Vec::new();
+        for (kind, value) in exth {
+            exth_block.extend_from_slice(&kind.to_be_bytes());
+            exth_block.extend_from_slice(&(value.len() as u32 + 8).to_be_bytes());
+            exth_block.extend_from_slice(value.as_bytes());
+        }
+        let full_name = b"Full Name Title";
+
+        let mut record0 = vec![0u8; PALMDOC_HEADER_LEN + mobi_header_len];
+        record0[0..2].copy_from_slice(&COMPRESSION_NONE.to_be_bytes());
+        record0[4..8].copy_from_slice(&(html.len() as u32).to_be_bytes());
+        record0[8..10].copy_from_slice(&1u16.to_be_bytes());
+        record0[10..12].copy_from_slice(&4096u16.to_be_bytes());
+        record0[16..20].copy_from_slice(b"MOBI");
+        record0[20..24].copy_from_slice(&(mobi_header_len as u32).to_be_bytes());
+        record0[28..32].copy_from_slice(&ENCODING_UTF8.to_be_bytes());
+        record0[128..132].copy_from_slice(&0x40u32.to_be_bytes());
+
+        record0.extend_from_slice(b"EXTH");
+        record0.extend_from_slice(&(exth_block.len() as u32 + 12).to_be_bytes());
+        record0.extend_from_slice(&(exth.len() as u32).to_be_bytes());
+        record0.extend_from_slice(&exth_block);
+
+        let name_offset = record0.len() as u32;
+        record0.extend_from_slice(full_name);
+        record0[84..88].copy_from_slice(&name_offset.to_be_bytes());
+        record0[88..92].copy_from_slice(&(full_name.len() as u32).to_be_bytes());
+
+        let records = [record0, html.as_bytes().to_vec()];
+        let mut data = vec![0u8; PALMDB_HEADER_LEN];
+        data[..8].copy_from_slice(b"Sample\0\0");
+        data[60..68].copy_from_slice(b"BOOKMOBI");
+        data[76..78].copy_from_slice(&(records.len() as u16).to_be_bytes());
+
+        let mut offset = PALMDB_HEADER_LEN + records.len() * 8;
+        for record in &records {
+            data.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&[0u8; 4]);
+            offset += record.len();
+        }
+        for record in &records {
+            data.extend_from_slice(record);
+        }
+        data
+    }
+
+    #[tokio::test]
+    async fn test_mobi_extracts_chapters_and_metadata() {
+        let html = "<html><body><h1>Chapter One</h1><p>It was a dark night.</p><mbp:pagebreak/>\
+                    <h1>Chapter Two</h1><p>Morning came.</p></body></html>";
+        let data = build_mobi(
+            html,
+            &[
+                (EXTH_AUTHOR, "Jane Doe"),
+                (EXTH_AUTHOR, "John Roe"),
+                (EXTH_ISBN, "978-0-14-118776-1"),
+                (EXTH_PUBLISHER, "Example Press"),
+            ],
+        );
+
+        let result = MobiExtractor::new()
+            .extract_bytes(&data, "application/x-mobipocket-ebook", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert!(result.content.contains("dark night"));
+        assert!(result.content.contains("Morning came"));
+        let structure = result.metadata.pages.expect("chapter structure");
+        assert_eq!(structure.unit_type, PageUnitType::Chapter);
+        assert_eq!(structure.total_count, 2);
+        assert_eq!(structure.pages.unwrap()[1].title.as_deref(), Some("Chapter Two"));
+
+        let additional = &result.metadata.additional;
+        assert_eq!(additional.get("title"), Some(&serde_json::json!("Full Name Title")));
+        assert_eq!(
+            additional.get("authors"),
+            Some(&serde_json::json!(["Jane Doe", "John Roe"]))
+        );
+        assert_eq!(additional.get("isbn"), Some(&serde_json::json!("9780141187761")));
+        assert_eq!(additional.get("publisher"), Some(&serde_json::json!("Example Press")));
+    }
+
+    #[tokio::test]
+    async fn test_mobi_updated_title_overrides_full_name() {
+        let data = build_mobi("<p>Body</p>", &[(EXTH_UPDATED_TITLE, "Updated Title")]);
+
+        let result = MobiExtractor::new()
+            .extract_bytes(&data, "application/x-mobipocket-ebook", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.metadata.additional.get("title"),
+            Some(&serde_json::json!("Updated Title"))
+        );
+    }
+
+    #[test]
+    fn test_palmdoc_decompress() {
+        // "abcabcabc" as literals followed by a back-reference (distance 3, length 6),
+        // plus a space+char pair and a literal run
+        let pair = ((3u16 << 3) | (6 - 3)) | 0x8000;
+        let mut input = b"abc".to_vec();
+        input.extend_from_slice(&pair.to_be_bytes());
+        input.push(b'x' ^ 0x80);
+        input.extend_from_slice(&[0x02, 0xE9, 0x01]);
+
+        let mut output = Vec::new();
+        palmdoc_decompress(&input, &mut output);
+
+        assert_eq!(output, b"abcabcabc x\xE9\x01");
+    }
+
+    #[test]
+    fn test_strip_trailing_entries() {
+        // One trailing entry of size 3 (encoded as 0x83) followed by a multibyte byte count
+        let record = [b'a', b'b', b'c', 0x00, b'x', b'y', 0x83];
+        assert_eq!(strip_trailing_entries(&record, 0b11), b"abc");
+        assert_eq!(strip_trailing_entries(&record, 0), &record);
+    }
+
+    #[test]
+    fn test_split_chapters() {
+        let chapters = split_chapters("<p>a</p><MBP:PAGEBREAK /><p>b</p><mbp:pagebreak/>");
+        assert_eq!(chapters, vec!["<p>a</p>", "<p>b</p>", ""]);
+    }
+
+    #[test]
+    fn test_decode_cp1252() {
+        assert_eq!(decode(b"caf\xE9 \x93quoted\x94", 1252), "café “quoted”");
+    }
+
+    #[test]
+    fn test_rejects_invalid_and_unsupported_files() {
+        assert!(parse_mobi(b"not a mobi file").is_err());
+
+        let mut data = build_mobi("<p>x</p>", &[]);
+        let record0 = u32::from_be_bytes(data[78..82].try_into().unwrap()) as usize;
+        data[record0..record0 + 2].copy_from_slice(&COMPRESSION_HUFF_CDIC.to_be_bytes());
+        let err = parse_mobi(&data).unwrap_err();
+        assert!(err.to_string().contains("HUFF/CDIC"));
+    }
+
+    #[test]
+    fn test_untrusted_text_length_is_not_preallocated() {
+        let mut data = build_mobi("<p>short</p>", &[]);
+        let record0 = u32::from_be_bytes(data[78..82].try_into().unwrap()) as usize;
+        data[record0 + 4..record0 + 8].copy_from_slice(&u32::MAX.to_be_bytes());
+
+        let book = parse_mobi(&data).unwrap();
+        assert_eq!(book.html, "<p>short</p>");
+    }
+
+    #[test]
+    fn test_mobi_plugin_interface() {
+        let extractor = MobiExtractor::new();
+        assert_eq!(extractor.name(), "mobi-extractor");
+        assert_eq!(extractor.priority(), 60);
+        assert!(
+            extractor
+                .supported_mime_types()
+                .contains(&"application/x-mobipocket-ebook")
+        );
+    }
+}
@@ -5,7 +5,7 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::plugins::registry::get_document_extractor_registry;
+use crate::plugins::registry::{DocumentExtractorRegistry, get_document_extractor_registry};
 use crate::types::ExtractionResult;
 use once_cell::sync::Lazy;
 use std::sync::Arc;
@@ -61,6 +61,7 @@ pub trait SyncExtractor {
     fn extract_sync(&self, content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult>;
 }
 
+pub mod csv;
 pub mod structured;
 pub mod text;
 
@@ -97,6 +98,9 @@ pub mod fictionbook;
 #[cfg(feature = "office")]
 pub mod markdown;
 
+#[cfg(feature = "office")]
+pub mod mobi;
+
 #[cfg(feature = "office")]
 pub mod rst;
 
@@ -136,6 +140,7 @@ pub mod xml;
 #[cfg(feature = "xml")]
 pub mod docbook;
 
+pub use csv::CsvExtractor;
 pub use structured::StructuredExtractor;
 pub use text::{MarkdownExtractor, PlainTextExtractor};
 
@@ -169,6 +174,9 @@ pub use fictionbook::FictionBookExtractor;
 #[cfg(feature = "office")]
 pub use markdown::MarkdownExtractor as EnhancedMarkdownExtractor;
 
+#[cfg(feature = "office")]
+pub use mobi::MobiExtractor;
+
 #[cfg(feature = "office")]
 pub use rst::RstExtractor;
 
@@ -228,13 +236,7 @@ pub fn ensure_initialized() -> Result<()> {
             plugin_name: "built-in-extractors".to_string(),
         })?;
 
-    let registry = get_document_extractor_registry();
-    let registry_guard = registry
-        .read()
-        .map_err(|e| crate::KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
-
-    if registry_guard.list().is_empty() {
-        drop(registry_guard);
+    if get_document_extractor_registry().snapshot().list().is_empty() {
         register_default_extractors()?;
     }
 
@@ -260,12 +262,12 @@ pub fn ensure_initialized() -> Result<()> {
 /// # }
 /// ```
 pub fn register_default_extractors() -> Result<()> {
-    let registry = get_document_extractor_registry();
-    let mut registry = registry
-        .write()
-        .map_err(|e| crate::KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
+    get_document_extractor_registry().update(register_builtin_extractors)
+}
 
+fn register_builtin_extractors(registry: &mut DocumentExtractorRegistry) -> Result<()> {
     registry.register(Arc::new(PlainTextExtractor::new()))?;
+    registry.register(Arc::new(CsvExtractor::new()))?;
     registry.register(Arc::new(MarkdownExtractor::new()))?;
     registry.register(Arc::new(StructuredExtractor::new()))?;
 
@@ -287,6 +289,7 @@ pub fn register_default_extractors() -> Result<()> {
         registry.register(Arc::new(BibtexExtractor::new()))?;
         registry.register(Arc::new(EpubExtractor::new()))?;
         registry.register(Arc::new(FictionBookExtractor::new()))?;
+        registry.register(Arc::new(MobiExtractor::new()))?;
         registry.register(Arc::new(RtfExtractor::new()))?;
         registry.register(Arc::new(RstExtractor::new()))?;
         registry.register(Arc::new(LatexExtractor::new()))?;
@@ -326,23 +329,22 @@ mod tests {
     #[test]
     fn test_register_default_extractors() {
         let registry = get_document_extractor_registry();
-        {
-            let mut reg = registry
-                .write()
-                .expect("Failed to acquire write lock on registry in test");
-            *reg = crate::plugins::registry::DocumentExtractorRegistry::new();
-        }
+        registry
+            .update(|reg| {
+                *reg = DocumentExtractorRegistry::new();
+                Ok(())
+            })
+            .expect("Failed to reset registry in test");
 
         register_default_extractors().expect("Failed to register extractors");
 
-        let reg = registry
-            .read()
-            .expect("Failed to acquire read lock on registry in test");
+        let reg = registry.snapshot();
         let extractor_names = reg.list();
 
         #[allow(unused_mut)]
-        let mut expected_count = 3;
+        let mut expected_count = 4;
         assert!(extractor_names.contains(&"plain-text-extractor".to_string()));
+        assert!(extractor_names.contains(&"csv-extractor".to_string()));
         assert!(extractor_names.contains(&"markdown-extractor".to_string()));
         assert!(extractor_names.contains(&"structured-extractor".to_string()));
 
@@ -372,11 +374,12 @@ mod tests {
 
         #[cfg(feature = "office")]
         {
-            expected_count += 10;
+            expected_count += 11;
             assert!(extractor_names.contains(&"markdown-extractor".to_string()));
             assert!(extractor_names.contains(&"bibtex-extractor".to_string()));
             assert!(extractor_names.contains(&"epub-extractor".to_string()));
             assert!(extractor_names.contains(&"fictionbook-extractor".to_string()));
+            assert!(extractor_names.contains(&"mobi-extractor".to_string()));
             assert!(extractor_names.contains(&"rtf-extractor".to_string()));
             assert!(extractor_names.contains(&"rst-extractor".to_string()));
             assert!(extractor_names.contains(&"latex-extractor".to_string()));
@@ -7,7 +7,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::{cells_to_markdown, office_metadata};
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 use crate::types::{ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
 use roxmltree::Document;
@@ -402,6 +402,7 @@ fn parse_odt_table(table_node: roxmltree::Node, table_index: usize) -> Option<Ta
         cells,
         markdown,
         page_number: table_index + 1,
+        column_types: None,
     })
 }
 
@@ -567,6 +568,10 @@ impl DocumentExtractor for OdtExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -577,6 +582,16 @@ impl DocumentExtractor for OdtExtractor {
     fn priority(&self) -> i32 {
         60
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -19,7 +19,7 @@ use crate::Result;
 #[cfg(feature = "office")]
 use crate::core::config::ExtractionConfig;
 #[cfg(feature = "office")]
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 #[cfg(feature = "office")]
 use crate::types::{ExtractionResult, Metadata, Table};
 #[cfg(feature = "office")]
@@ -181,6 +181,7 @@ impl OrgModeExtractor {
                             cells: current_table.clone(),
                             markdown,
                             page_number: 1,
+                            column_types: None,
                         });
                         current_table.clear();
                     }
@@ -194,6 +195,7 @@ impl OrgModeExtractor {
                     cells: current_table,
                     markdown,
                     page_number: 1,
+                    column_types: None,
                 });
             }
         }
@@ -305,6 +307,10 @@ impl DocumentExtractor for OrgModeExtractor {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -315,6 +321,16 @@ impl DocumentExtractor for OrgModeExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(all(test, feature = "office"))]
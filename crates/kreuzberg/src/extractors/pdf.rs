@@ -2,18 +2,18 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata, PageContent};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
+use crate::types::{Barcode, ExtractionResult, Metadata, PageContent};
 use async_trait::async_trait;
 #[cfg(feature = "tokio-runtime")]
 use std::path::Path;
 
 #[cfg(feature = "pdf")]
 use crate::pdf::error::PdfError;
-#[cfg(feature = "ocr")]
+#[cfg(feature = "pdf")]
 use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
 #[cfg(feature = "pdf")]
-use crate::types::Table;
+use crate::types::{KeyValuePair, Table, Watermark};
 #[cfg(feature = "pdf")]
 use pdfium_render::prelude::*;
 
@@ -25,6 +25,13 @@ type PdfExtractionPhaseResult = (
     Option<Vec<PageContent>>,
 );
 
+/// Metadata key listing parts of an encrypted PDF that were skipped (e.g. `["images"]`)
+const ENCRYPTED_PARTS_SKIPPED_METADATA_KEY: &str = "encrypted_parts_skipped";
+
+/// Resolution pages are rendered at for barcode detection.
+#[cfg(feature = "pdf")]
+const BARCODE_RENDER_DPI: i32 = 200;
+
 #[cfg(feature = "ocr")]
 const MIN_TOTAL_NON_WHITESPACE: usize = 64;
 #[cfg(feature = "ocr")]
@@ -45,11 +52,11 @@ struct NativeTextStats {
 }
 
 #[cfg(feature = "ocr")]
-struct OcrFallbackDecision {
+pub(crate) struct OcrFallbackDecision {
     stats: NativeTextStats,
     avg_non_whitespace: f64,
     avg_alnum: f64,
-    fallback: bool,
+    pub(crate) fallback: bool,
 }
 
 #[cfg(feature = "ocr")]
@@ -95,7 +102,7 @@ impl NativeTextStats {
 }
 
 #[cfg(feature = "ocr")]
-fn evaluate_native_text_for_ocr(native_text: &str, page_count: Option<usize>) -> OcrFallbackDecision {
+pub(crate) fn evaluate_native_text_for_ocr(native_text: &str, page_count: Option<usize>) -> OcrFallbackDecision {
     let trimmed = native_text.trim();
 
     if trimmed.is_empty() {
@@ -179,6 +186,7 @@ fn extract_tables_from_document(
                 cells: table_cells,
                 markdown,
                 page_number: page_index + 1,
+                column_types: None,
             });
         }
     }
@@ -195,6 +203,127 @@ fn extract_tables_from_document(
     Ok(vec![])
 }
 
+/// Find label/value pairs from the native word positions of every page.
+///
+/// Bounding boxes are in PDF points with a top-left origin. Pages without a text layer
+/// yield no pairs; the `key-value-extraction` post-processor then scans the OCR text.
+#[cfg(all(feature = "pdf", feature = "ocr"))]
+fn extract_key_values_from_document(
+    document: &PdfDocument,
+    config: &crate::core::config::KeyValueExtractionConfig,
+) -> Result<Vec<KeyValuePair>> {
+    use crate::pdf::table::extract_words_from_page;
+    use crate::text::key_values::{KeyValueExtractor, LayoutWord};
+    use crate::types::BoundingBox;
+
+    let extractor = KeyValueExtractor::new(config)?;
+    let mut pairs = Vec::new();
+
+    for (page_index, page) in document.pages().iter().enumerate() {
+        let words: Vec<LayoutWord> = extract_words_from_page(&page, 0.0)?
+            .into_iter()
+            .map(|word| LayoutWord {
+                bbox: BoundingBox {
+                    x: f64::from(word.left),
+                    y: f64::from(word.top),
+                    width: f64::from(word.width),
+                    height: f64::from(word.height),
+                },
+                text: word.text,
+            })
+            .collect();
+        pairs.extend(extractor.extract_from_words(&words, page_index + 1));
+    }
+
+    Ok(pairs)
+}
+
+/// Fallback for when OCR feature is not enabled - pairs come from the text post-processor.
+#[cfg(all(feature = "pdf", not(feature = "ocr")))]
+fn extract_key_values_from_document(
+    _document: &PdfDocument,
+    _config: &crate::core::config::KeyValueExtractionConfig,
+) -> Result<Vec<KeyValuePair>> {
+    Ok(vec![])
+}
+
+/// Find text drawn diagonally across the pages, grouped into runs of consecutive characters.
+#[cfg(feature = "pdf")]
+fn extract_diagonal_text_from_document(document: &PdfDocument) -> Vec<Watermark> {
+    use crate::text::watermarks::{diagonal_text_watermarks, is_diagonal};
+
+    let pages = document.pages();
+    let mut runs = Vec::new();
+
+    for (page_index, page) in pages.iter().enumerate() {
+        let Ok(text) = page.text() else {
+            continue;
+        };
+        let mut run = String::new();
+        let mut run_degrees = 0.0;
+
+        for pdf_char in text.chars().iter() {
+            let degrees = pdf_char.angle_degrees().map(f64::from).unwrap_or(0.0);
+            if is_diagonal(degrees) {
+                if let Some(ch) = pdf_char.unicode_char() {
+                    if run.is_empty() {
+                        run_degrees = degrees;
+                    }
+                    run.push(ch);
+                }
+            } else if !run.is_empty() {
+                runs.push((page_index + 1, run.trim().to_string(), run_degrees));
+                run.clear();
+            }
+        }
+        if !run.is_empty() {
+            runs.push((page_index + 1, run.trim().to_string(), run_degrees));
+        }
+    }
+
+    diagonal_text_watermarks(runs, pages.len() as usize)
+}
+
+/// Find images placed on most pages, compared by a hash of their data.
+///
+/// Documents whose images cannot be read report no image watermarks.
+#[cfg(feature = "pdf")]
+fn detect_image_watermarks(content: &[u8], total_pages: usize) -> Vec<Watermark> {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let Ok(images) = crate::pdf::images::extract_images_from_pdf(content) else {
+        return Vec::new();
+    };
+    let hashes: Vec<(usize, u64)> = images
+        .iter()
+        .map(|image| {
+            let mut hasher = DefaultHasher::new();
+            image.data.hash(&mut hasher);
+            (image.page_number, hasher.finish())
+        })
+        .collect();
+
+    crate::text::watermarks::image_watermarks(&hashes, total_pages)
+}
+
+/// Render every page and decode its barcodes and QR codes, with bounding boxes in PDF points.
+#[cfg(feature = "pdf")]
+fn detect_barcodes(content: &[u8]) -> std::result::Result<Vec<Barcode>, PdfError> {
+    let options = PageRenderOptions {
+        target_dpi: BARCODE_RENDER_DPI,
+        auto_adjust_dpi: false,
+        ..Default::default()
+    };
+    let pages = PdfRenderer::new()?.render_all_pages(content, &options)?;
+    let scale = f64::from(BARCODE_RENDER_DPI) / 72.0;
+
+    Ok(pages
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, page)| crate::barcode::detect_barcodes(&page.to_luma8(), index + 1, scale))
+        .collect())
+}
+
 /// Helper function to assign tables and images to pages.
 ///
 /// If page_contents is None, returns None (no per-page tracking enabled).
@@ -276,21 +405,40 @@ impl PdfExtractor {
         // Unified extraction: text and metadata in single pass for 10-15% performance gain.
         // The document is borrowed immutably and safely used for read operations only.
         // This avoids redundant document tree traversal compared to separate text/metadata extraction.
-        let (native_text, _boundaries, page_contents, pdf_metadata) =
+        let (native_text, _boundaries, page_contents, mut pdf_metadata) =
             crate::pdf::text::extract_text_and_metadata_from_pdf_document(document, config.pages.as_ref())?;
 
         // Phase 2: Extract tables using the same document instance.
         // Both functions perform read-only operations on the shared document reference.
         let tables = extract_tables_from_document(document, &pdf_metadata)?;
 
+        if let Some(kv_config) = &config.kv_extraction {
+            pdf_metadata.key_values = extract_key_values_from_document(document, kv_config)?;
+        }
+
+        if config.watermarks.is_some() {
+            pdf_metadata.watermarks = extract_diagonal_text_from_document(document);
+        }
+
         Ok((pdf_metadata, native_text, tables, page_contents))
     }
 
     /// Extract text from PDF using OCR.
     ///
     /// Renders all pages to images and processes them with OCR.
+    ///
+    /// With the `otel` feature, each phase is recorded as a stage span so profilers can
+    /// attribute time to it: `stage.rasterize` (page rendering and PNG encoding),
+    /// `stage.ocr` (one span per page) and `stage.merge` (joining page texts).
+    ///
+    /// Returns the joined text and the OCR backend used for each page.
     #[cfg(feature = "ocr")]
-    async fn extract_with_ocr(&self, content: &[u8], config: &ExtractionConfig) -> Result<String> {
+    async fn extract_with_ocr(
+        &self,
+        content: &[u8],
+        config: &ExtractionConfig,
+    ) -> Result<(String, Vec<crate::ocr::OcrPageBackend>)> {
+        use crate::ocr::fallback::process_image_with_fallback;
         use crate::plugins::registry::get_ocr_backend_registry;
         use image::ImageEncoder;
         use image::codecs::png::PngEncoder;
@@ -302,16 +450,20 @@ impl PdfExtractor {
         })?;
 
         let backend = {
-            let registry = get_ocr_backend_registry();
-            let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
-                message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
-                plugin_name: "ocr-registry".to_string(),
-            })?;
+            let registry = get_ocr_backend_registry().snapshot();
             registry.get(&ocr_config.backend)?
         };
 
         let images = {
-            let render_options = PageRenderOptions::default();
+            #[cfg(feature = "otel")]
+            let _stage = tracing::info_span!("stage.rasterize").entered();
+
+            let mut render_options = PageRenderOptions::default();
+            if let Some(cap) = config.resource_profile.ocr_dpi_cap() {
+                render_options.target_dpi = render_options.target_dpi.min(cap);
+                render_options.max_dpi = render_options.max_dpi.min(cap);
+                render_options.min_dpi = render_options.min_dpi.min(cap);
+            }
             let renderer = PdfRenderer::new().map_err(|e| crate::KreuzbergError::Parsing {
                 message: format!("Failed to initialize PDF renderer: {}", e),
                 source: None,
@@ -325,29 +477,52 @@ impl PdfExtractor {
                 })?
         };
 
+        #[cfg(feature = "tokio-runtime")]
+        let ocr_budget = config
+            .stage_timeouts
+            .as_ref()
+            .and_then(|timeouts| timeouts.budget(crate::ExtractionStage::Ocr));
+
         let mut page_texts = Vec::with_capacity(images.len());
+        let mut page_backends = Vec::with_capacity(images.len());
 
-        for image in images {
-            let rgb_image = image.to_rgb8();
-            let (width, height) = rgb_image.dimensions();
+        for (index, image) in images.into_iter().enumerate() {
+            let image_data = {
+                #[cfg(feature = "otel")]
+                let _stage = tracing::info_span!("stage.rasterize", page = index + 1).entered();
+
+                let rgb_image = image.to_rgb8();
+                let (width, height) = rgb_image.dimensions();
+
+                let mut image_bytes = Cursor::new(Vec::new());
+                let encoder = PngEncoder::new(&mut image_bytes);
+                encoder
+                    .write_image(&rgb_image, width, height, image::ColorType::Rgb8.into())
+                    .map_err(|e| crate::KreuzbergError::Parsing {
+                        message: format!("Failed to encode image: {}", e),
+                        source: None,
+                    })?;
 
-            let mut image_bytes = Cursor::new(Vec::new());
-            let encoder = PngEncoder::new(&mut image_bytes);
-            encoder
-                .write_image(&rgb_image, width, height, image::ColorType::Rgb8.into())
-                .map_err(|e| crate::KreuzbergError::Parsing {
-                    message: format!("Failed to encode image: {}", e),
-                    source: None,
-                })?;
+                image_bytes.into_inner()
+            };
 
-            let image_data = image_bytes.into_inner();
+            let ocr_future = process_image_with_fallback(&backend, &image_data, ocr_config, index + 1);
+            #[cfg(feature = "otel")]
+            let ocr_future =
+                tracing::Instrument::instrument(ocr_future, tracing::info_span!("stage.ocr", page = index + 1));
+            #[cfg(feature = "tokio-runtime")]
+            let ocr_future = crate::core::watchdog::with_budget(crate::ExtractionStage::Ocr, ocr_budget, ocr_future);
 
-            let ocr_result = backend.process_image(&image_data, ocr_config).await?;
+            let (ocr_result, page_backend) = ocr_future.await?;
 
             page_texts.push(ocr_result.content);
+            page_backends.push(page_backend);
         }
 
-        Ok(page_texts.join("\n\n"))
+        #[cfg(feature = "otel")]
+        let _stage = tracing::info_span!("stage.merge", pages = page_texts.len()).entered();
+
+        Ok((page_texts.join("\n\n"), page_backends))
     }
 }
 
@@ -504,11 +679,11 @@ impl DocumentExtractor for PdfExtractor {
         };
 
         #[cfg(feature = "ocr")]
-        let text = if config.force_ocr {
+        let (text, ocr_pages) = if config.force_ocr {
             if config.ocr.is_some() {
                 self.extract_with_ocr(content, config).await?
             } else {
-                native_text
+                (native_text, Vec::new())
             }
         } else if config.ocr.is_some() {
             let decision = evaluate_native_text_for_ocr(&native_text, None);
@@ -530,10 +705,10 @@ impl DocumentExtractor for PdfExtractor {
             if decision.fallback {
                 self.extract_with_ocr(content, config).await?
             } else {
-                native_text
+                (native_text, Vec::new())
             }
         } else {
-            native_text
+            (native_text, Vec::new())
         };
 
         #[cfg(not(feature = "ocr"))]
@@ -556,6 +731,7 @@ impl DocumentExtractor for PdfExtractor {
         // Early exit: skip image extraction when neither OCR nor image extraction is needed
         // This optimization avoids wasteful image decompression/extraction for text-only workloads
         // Expected improvement: 5-10% CPU reduction on non-OCR, non-image-extraction configurations
+        let mut encrypted_parts_skipped = Vec::new();
         let images = if config.needs_image_processing() {
             if config.images.is_some() {
                 match crate::pdf::images::extract_images_from_pdf(content) {
@@ -577,10 +753,18 @@ impl DocumentExtractor for PdfExtractor {
                                     is_mask: false,
                                     description: None,
                                     ocr_result: None,
+                                    perceptual_hash: None,
+                                    blob: None,
+                                    caption: None,
+                                    figure_index: None,
                                 }
                             })
                             .collect(),
                     ),
+                    Err(PdfError::PasswordRequired | PdfError::InvalidPassword) => {
+                        encrypted_parts_skipped.push("images");
+                        None
+                    }
                     Err(_) => None,
                 }
             } else {
@@ -590,37 +774,124 @@ impl DocumentExtractor for PdfExtractor {
             None
         };
 
+        let barcodes = if config.detect_barcodes {
+            match detect_barcodes(content) {
+                Ok(barcodes) => Some(barcodes),
+                Err(PdfError::PasswordRequired | PdfError::InvalidPassword) => {
+                    encrypted_parts_skipped.push("barcodes");
+                    None
+                }
+                Err(_e) => {
+                    #[cfg(feature = "otel")]
+                    tracing::warn!("Barcode detection failed: {}", _e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         let final_pages = assign_tables_and_images_to_pages(page_contents, &tables, images.as_deref().unwrap_or(&[]));
 
+        // Bookmarks give the authored outline; without them fall back to numbered section headings.
+        #[cfg(feature = "pdf")]
+        let outline = {
+            let mut headings = if pdf_metadata.bookmarks.is_empty() {
+                crate::text::outline::detect_numbered_headings(&text)
+            } else {
+                pdf_metadata.bookmarks
+            };
+            let boundaries = pdf_metadata
+                .page_structure
+                .as_ref()
+                .and_then(|structure| structure.boundaries.as_deref());
+            crate::text::outline::locate_headings(&text, &mut headings, boundaries);
+            (!headings.is_empty()).then(|| crate::text::outline::build_outline(headings))
+        };
+
+        // An empty list is left to the post-processor, which reads the text of OCR'd pages
+        #[cfg(feature = "pdf")]
+        let key_values = (!pdf_metadata.key_values.is_empty()).then_some(pdf_metadata.key_values);
+
+        // Stamp words in the text are added by the watermark pipeline step
+        #[cfg(feature = "pdf")]
+        let watermarks = if config.watermarks.is_some() {
+            let total_pages = pdf_metadata
+                .page_structure
+                .as_ref()
+                .map_or(0, |structure| structure.total_count);
+            let mut watermarks = pdf_metadata.watermarks;
+            watermarks.extend(detect_image_watermarks(content, total_pages));
+            (!watermarks.is_empty()).then_some(watermarks)
+        } else {
+            None
+        };
+
+        let mut metadata = Metadata {
+            #[cfg(feature = "pdf")]
+            title: pdf_metadata.title.clone(),
+            #[cfg(feature = "pdf")]
+            subject: pdf_metadata.subject.clone(),
+            #[cfg(feature = "pdf")]
+            authors: pdf_metadata.authors.clone(),
+            #[cfg(feature = "pdf")]
+            keywords: pdf_metadata.keywords.clone(),
+            #[cfg(feature = "pdf")]
+            created_at: pdf_metadata.created_at.clone(),
+            #[cfg(feature = "pdf")]
+            modified_at: pdf_metadata.modified_at.clone(),
+            #[cfg(feature = "pdf")]
+            created_by: pdf_metadata.created_by.clone(),
+            #[cfg(feature = "pdf")]
+            pages: pdf_metadata.page_structure.clone(),
+            #[cfg(feature = "pdf")]
+            outline,
+            #[cfg(feature = "pdf")]
+            key_values,
+            #[cfg(feature = "pdf")]
+            watermarks,
+            #[cfg(feature = "pdf")]
+            format: Some(crate::types::FormatMetadata::Pdf(pdf_metadata.pdf_specific)),
+            #[cfg(feature = "pdf")]
+            xmp: crate::pdf::metadata::extract_xmp_metadata(content),
+            ..Default::default()
+        };
+
+        #[cfg(feature = "ocr")]
+        if let Some(ocr_config) = &config.ocr {
+            crate::ocr::fallback::record_ocr_pages(&mut metadata, ocr_config, &ocr_pages);
+        }
+
+        // Pages are OCR'd all together or not at all, so OCR text is the whole content
+        #[cfg(feature = "ocr")]
+        if !ocr_pages.is_empty() {
+            let span = crate::ocr::OcrSpan {
+                start: 0,
+                end: text.len(),
+            };
+            crate::ocr::correction::record_ocr_spans(&mut metadata, config, &[span]);
+        }
+
+        if !encrypted_parts_skipped.is_empty() {
+            metadata.additional.insert(
+                ENCRYPTED_PARTS_SKIPPED_METADATA_KEY.to_string(),
+                serde_json::json!(encrypted_parts_skipped),
+            );
+        }
+
         Ok(ExtractionResult {
             content: text,
             mime_type: mime_type.to_string(),
-            metadata: Metadata {
-                #[cfg(feature = "pdf")]
-                title: pdf_metadata.title.clone(),
-                #[cfg(feature = "pdf")]
-                subject: pdf_metadata.subject.clone(),
-                #[cfg(feature = "pdf")]
-                authors: pdf_metadata.authors.clone(),
-                #[cfg(feature = "pdf")]
-                keywords: pdf_metadata.keywords.clone(),
-                #[cfg(feature = "pdf")]
-                created_at: pdf_metadata.created_at.clone(),
-                #[cfg(feature = "pdf")]
-                modified_at: pdf_metadata.modified_at.clone(),
-                #[cfg(feature = "pdf")]
-                created_by: pdf_metadata.created_by.clone(),
-                #[cfg(feature = "pdf")]
-                pages: pdf_metadata.page_structure.clone(),
-                #[cfg(feature = "pdf")]
-                format: Some(crate::types::FormatMetadata::Pdf(pdf_metadata.pdf_specific)),
-                ..Default::default()
-            },
+            metadata,
             pages: final_pages,
             tables,
             detected_languages: None,
             chunks: None,
             images,
+            term_offsets: None,
+            barcodes,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -637,6 +908,16 @@ impl DocumentExtractor for PdfExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: true,
+            metadata: true,
+            pages: true,
+            ocr: true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 use crate::types::{ExtractionResult, Metadata};
 use async_trait::async_trait;
 use std::path::Path;
@@ -29,6 +29,8 @@ impl PptxExtractor {
     }
 
     /// Process extracted images with OCR if configured.
+    ///
+    /// Images rejected by `images.ocr_policy` are returned without an OCR result.
     #[cfg(feature = "ocr")]
     async fn process_images_with_ocr(
         &self,
@@ -41,8 +43,13 @@ impl PptxExtractor {
 
         let ocr_config = config.ocr.as_ref().unwrap();
         let tess_config = ocr_config.tesseract_config.as_ref().cloned().unwrap_or_default();
+        let policy = config.images.as_ref().and_then(|images| images.ocr_policy.as_ref());
 
         for image in &mut images {
+            if policy.is_some_and(|policy| !policy.should_ocr(image)) {
+                continue;
+            }
+
             let image_data = image.data.clone();
             let tess_config_clone = tess_config.clone();
             let span = tracing::Span::current();
@@ -53,6 +60,7 @@ impl PptxExtractor {
 
                 let proc = OcrProcessor::new(cache_dir)?;
                 let ocr_tess_config: crate::ocr::types::TesseractConfig = (&tess_config_clone).into();
+                crate::core::counters::record_ocr_page();
                 proc.process_image(&image_data, &ocr_tess_config)
             })
             .await
@@ -72,6 +80,10 @@ impl PptxExtractor {
                         chunks: None,
                         images: None,
                         pages: None,
+                        term_offsets: None,
+                        barcodes: None,
+                        provenance: None,
+                        schema_version: ExtractionResult::SCHEMA_VERSION,
                     };
                     image.ocr_result = Some(Box::new(extraction_result));
                 }
@@ -121,17 +133,28 @@ impl DocumentExtractor for PptxExtractor {
         let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
 
         let pages_config = config.pages.clone();
+        let pptx_options = config.pptx_options.clone().unwrap_or_default();
         let pptx_result = if crate::core::batch_mode::is_batch_mode() {
             let content_owned = content.to_vec();
             let span = tracing::Span::current();
             tokio::task::spawn_blocking(move || {
                 let _guard = span.entered();
-                crate::extraction::pptx::extract_pptx_from_bytes(&content_owned, extract_images, pages_config.as_ref())
+                crate::extraction::pptx::extract_pptx_from_bytes_with_options(
+                    &content_owned,
+                    extract_images,
+                    pages_config.as_ref(),
+                    &pptx_options,
+                )
             })
             .await
             .map_err(|e| crate::error::KreuzbergError::parsing(format!("PPTX extraction task failed: {}", e)))??
         } else {
-            crate::extraction::pptx::extract_pptx_from_bytes(content, extract_images, config.pages.as_ref())?
+            crate::extraction::pptx::extract_pptx_from_bytes_with_options(
+                content,
+                extract_images,
+                config.pages.as_ref(),
+                &pptx_options,
+            )?
         };
 
         let mut additional = std::collections::HashMap::new();
@@ -172,6 +195,10 @@ impl DocumentExtractor for PptxExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -188,8 +215,13 @@ impl DocumentExtractor for PptxExtractor {
 
         let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
 
-        let pptx_result =
-            crate::extraction::pptx::extract_pptx_from_path(path_str, extract_images, config.pages.as_ref())?;
+        let pptx_options = config.pptx_options.clone().unwrap_or_default();
+        let pptx_result = crate::extraction::pptx::extract_pptx_from_path_with_options(
+            path_str,
+            extract_images,
+            config.pages.as_ref(),
+            &pptx_options,
+        )?;
 
         let mut additional = std::collections::HashMap::new();
         additional.insert("slide_count".to_string(), serde_json::json!(pptx_result.slide_count));
@@ -229,6 +261,10 @@ impl DocumentExtractor for PptxExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -243,6 +279,16 @@ impl DocumentExtractor for PptxExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: false,
+            images: true,
+            metadata: true,
+            pages: true,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -12,7 +12,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::cells_to_markdown;
-use crate::plugins::{DocumentExtractor, Plugin};
+use crate::plugins::{DocumentExtractor, ExtractorCapabilities, Plugin};
 use crate::types::{ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
 use serde_json::Value;
@@ -172,6 +172,7 @@ fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
                     cells: state.rows,
                     markdown,
                     page_number: 1,
+                    column_types: None,
                 });
             }
         }
@@ -774,6 +775,10 @@ impl DocumentExtractor for RtfExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -784,6 +789,16 @@ impl DocumentExtractor for RtfExtractor {
     fn priority(&self) -> i32 {
         50
     }
+
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities {
+            tables: true,
+            images: false,
+            metadata: true,
+            pages: false,
+            ocr: false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@ use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::text::parse_text;
 use crate::plugins::{DocumentExtractor, Plugin};
+use crate::text::encoding::{ENCODING_METADATA_KEY, decode_text};
 use crate::types::ExtractionResult;
 use async_trait::async_trait;
 
@@ -54,7 +55,7 @@ impl Plugin for PlainTextExtractor {
 #[async_trait]
 impl DocumentExtractor for PlainTextExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -64,38 +65,55 @@ impl DocumentExtractor for PlainTextExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let text = String::from_utf8_lossy(content).into_owned();
+        let (text, encoding) = match &config.text_options {
+            Some(options) => {
+                let decoded = decode_text(content, options)?;
+                (decoded.text, Some(decoded.encoding))
+            }
+            None => (String::from_utf8_lossy(content).into_owned(), None),
+        };
         let text = text.trim_end_matches('\n').trim_end_matches('\r').to_string();
         let line_count = text.lines().count();
         let word_count = text.split_whitespace().count();
         let character_count = text.len();
 
+        let mut metadata = crate::types::Metadata {
+            format: Some(crate::types::FormatMetadata::Text(crate::types::TextMetadata {
+                line_count,
+                word_count,
+                character_count,
+                headers: None,
+                links: None,
+                code_blocks: None,
+            })),
+            ..Default::default()
+        };
+        if let Some(encoding) = encoding {
+            metadata
+                .additional
+                .insert(ENCODING_METADATA_KEY.to_string(), serde_json::Value::String(encoding));
+        }
+
         Ok(ExtractionResult {
             content: text,
             mime_type: mime_type.to_string(),
-            metadata: crate::types::Metadata {
-                format: Some(crate::types::FormatMetadata::Text(crate::types::TextMetadata {
-                    line_count,
-                    word_count,
-                    character_count,
-                    headers: None,
-                    links: None,
-                    code_blocks: None,
-                })),
-                ..Default::default()
-            },
+            metadata,
             pages: None,
             tables: vec![],
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
     fn supported_mime_types(&self) -> &[&str] {
-        &["text/plain", "text/csv", "text/tab-separated-values"]
+        &["text/plain"]
     }
 
     fn priority(&self) -> i32 {
@@ -184,6 +202,10 @@ impl DocumentExtractor for MarkdownExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -219,6 +241,28 @@ mod tests {
         assert_eq!(text_meta.word_count, 6);
     }
 
+    #[cfg(feature = "quality")]
+    #[tokio::test]
+    async fn test_plain_text_extractor_transcodes_legacy_encoding() {
+        let extractor = PlainTextExtractor::new();
+        let (content, _, _) = encoding_rs::WINDOWS_1251.encode("Привет, Москва");
+        let config = ExtractionConfig {
+            text_options: Some(crate::core::config::TextExtractionConfig {
+                encoding: "cp1251".to_string(),
+                normalize_unicode: None,
+            }),
+            ..Default::default()
+        };
+
+        let result = extractor.extract_bytes(&content, "text/plain", &config).await.unwrap();
+
+        assert_eq!(result.content, "Привет, Москва");
+        assert_eq!(
+            result.metadata.additional.get(ENCODING_METADATA_KEY),
+            Some(&serde_json::json!("windows-1251"))
+        );
+    }
+
     #[tokio::test]
     async fn test_markdown_extractor() {
         let extractor = MarkdownExtractor::new();
@@ -247,10 +291,7 @@ mod tests {
         let extractor = PlainTextExtractor::new();
         assert_eq!(extractor.name(), "plain-text-extractor");
         assert_eq!(extractor.version(), env!("CARGO_PKG_VERSION"));
-        assert_eq!(
-            extractor.supported_mime_types(),
-            &["text/plain", "text/csv", "text/tab-separated-values"]
-        );
+        assert_eq!(extractor.supported_mime_types(), &["text/plain"]);
         assert_eq!(extractor.priority(), 50);
     }
 
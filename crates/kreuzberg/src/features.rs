@@ -0,0 +1,179 @@
+//! Runtime report of the features this build of Kreuzberg supports.
+//!
+//! Bindings are built with different feature sets (the Windows MinGW FFI build has no
+//! embeddings, WASM has no OCR, a minimal build may have no PDF support). Instead of calling
+//! a function and failing with a missing symbol or a
+//! [`MissingDependency`](crate::KreuzbergError::MissingDependency) error, a host SDK can ask
+//! [`compiled_features`] up front and hide or adapt what is not there.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::features::compiled_features;
+//!
+//! let report = compiled_features();
+//! if report.is_enabled("embeddings") {
+//!     println!("semantic chunking available");
+//! }
+//! for backend in &report.ocr_backends {
+//!     println!("OCR backend {} {}", backend.name, backend.version);
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Cargo features reported by [`compiled_features`], in the order of `Cargo.toml`.
+const FEATURES: &[(&str, bool)] = &[
+    ("pdf", cfg!(feature = "pdf")),
+    ("excel", cfg!(feature = "excel")),
+    ("office", cfg!(feature = "office")),
+    ("email", cfg!(feature = "email")),
+    ("html", cfg!(feature = "html")),
+    ("xml", cfg!(feature = "xml")),
+    ("archives", cfg!(feature = "archives")),
+    ("ocr", cfg!(feature = "ocr")),
+    ("language-detection", cfg!(feature = "language-detection")),
+    ("chunking", cfg!(feature = "chunking")),
+    ("embeddings", cfg!(feature = "embeddings")),
+    ("quality", cfg!(feature = "quality")),
+    ("keywords-yake", cfg!(feature = "keywords-yake")),
+    ("keywords-rake", cfg!(feature = "keywords-rake")),
+    ("api", cfg!(feature = "api")),
+    ("mcp", cfg!(feature = "mcp")),
+    ("grpc-server", cfg!(feature = "grpc-server")),
+    ("redis-cache", cfg!(feature = "redis-cache")),
+    ("otel", cfg!(feature = "otel")),
+    ("tokio-runtime", cfg!(feature = "tokio-runtime")),
+];
+
+/// Features and component versions of this build.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompiledFeatures {
+    /// Kreuzberg version
+    pub version: String,
+    /// Whether each Cargo feature (`"pdf"`, `"ocr"`, `"embeddings"`, `"grpc-server"`, ...) is compiled in
+    pub features: BTreeMap<String, bool>,
+    /// PDF engine, `None` without the `pdf` feature
+    pub pdf_engine: Option<PdfEngine>,
+    /// Registered OCR backends, including custom backends registered at runtime
+    pub ocr_backends: Vec<ComponentVersion>,
+}
+
+impl CompiledFeatures {
+    /// Whether the Cargo feature `name` is compiled in (`false` for unknown names).
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.features.get(name).copied().unwrap_or(false)
+    }
+}
+
+/// The PDF engine and how it is linked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PdfEngine {
+    /// Engine name (`"pdfium"`)
+    pub name: String,
+    /// `"bundled"`, `"system"` or `"static"`
+    pub linking: String,
+    /// pdfium-binaries release downloaded at build time, `None` for system or prebuilt libraries
+    pub version: Option<String>,
+}
+
+/// Name and version of a pluggable component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentVersion {
+    /// Component name (`"tesseract"`, or the name a custom backend registered with)
+    pub name: String,
+    /// Version reported by the component
+    pub version: String,
+}
+
+/// Report the features this build supports.
+///
+/// Cheap enough to call on every SDK initialization: feature flags are compile-time
+/// constants and OCR backends are read from the registry.
+pub fn compiled_features() -> CompiledFeatures {
+    CompiledFeatures {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        features: FEATURES
+            .iter()
+            .map(|(name, enabled)| (name.to_string(), *enabled))
+            .collect(),
+        pdf_engine: pdf_engine(),
+        ocr_backends: ocr_backends(),
+    }
+}
+
+#[cfg(feature = "pdf")]
+fn pdf_engine() -> Option<PdfEngine> {
+    // Same precedence as the build script when several linking features are enabled
+    let linking = if cfg!(feature = "bundled-pdfium") {
+        "bundled"
+    } else if cfg!(feature = "system-pdfium") {
+        "system"
+    } else if cfg!(feature = "static-pdfium") {
+        "static"
+    } else {
+        "bundled"
+    };
+
+    Some(PdfEngine {
+        name: "pdfium".to_string(),
+        linking: linking.to_string(),
+        version: option_env!("KREUZBERG_PDFIUM_VERSION").map(str::to_string),
+    })
+}
+
+#[cfg(not(feature = "pdf"))]
+fn pdf_engine() -> Option<PdfEngine> {
+    None
+}
+
+fn ocr_backends() -> Vec<ComponentVersion> {
+    use crate::plugins::registry::get_ocr_backend_registry;
+
+    let registry = get_ocr_backend_registry().snapshot();
+
+    registry
+        .list()
+        .into_iter()
+        .filter_map(|name| registry.get(&name).ok())
+        .map(|backend| ComponentVersion {
+            name: backend.name().to_string(),
+            version: backend.version(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compiled_features_match_cfg() {
+        let report = compiled_features();
+
+        assert_eq!(report.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(report.is_enabled("pdf"), cfg!(feature = "pdf"));
+        assert_eq!(report.is_enabled("embeddings"), cfg!(feature = "embeddings"));
+        assert_eq!(report.is_enabled("grpc-server"), cfg!(feature = "grpc-server"));
+        assert!(!report.is_enabled("no-such-feature"));
+        assert_eq!(report.pdf_engine.is_some(), cfg!(feature = "pdf"));
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_compiled_features_lists_tesseract() {
+        let report = compiled_features();
+
+        let tesseract = report.ocr_backends.iter().find(|backend| backend.name == "tesseract");
+        assert!(tesseract.is_some_and(|backend| !backend.version.is_empty()));
+    }
+
+    #[test]
+    fn test_compiled_features_serialize() {
+        let json = serde_json::to_value(compiled_features()).unwrap();
+
+        assert!(json["features"]["html"].is_boolean());
+        assert!(json["ocr_backends"].is_array());
+    }
+}
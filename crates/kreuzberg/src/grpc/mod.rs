@@ -0,0 +1,52 @@
+//! gRPC server for out-of-process document extraction.
+//!
+//! This module provides a tonic-based gRPC service for languages without a good C FFI
+//! story (Erlang, PHP, ...). The service definition lives in
+//! `proto/kreuzberg/v1/kreuzberg.proto`; generate a client from it in any language with
+//! gRPC support.
+//!
+//! # Methods
+//!
+//! - `Extract` - Extract content from document bytes or a server-side path
+//! - `BatchExtract` - Extract many documents, streaming each result as it completes
+//! - `Ocr` - Run OCR on an image or scanned PDF
+//! - `Chunk` - Split text into chunks, streamed in order
+//! - `Health` - Server status and version
+//!
+//! Results carry the full `ExtractionResult` as JSON, and per-request configuration uses
+//! the `ExtractionConfig` JSON schema, so they match the REST API and FFI.
+//!
+//! # Examples
+//!
+//! ## Starting the server
+//!
+//! ```no_run
+//! use kreuzberg::grpc::serve_grpc;
+//!
+//! #[tokio::main]
+//! async fn main() -> kreuzberg::Result<()> {
+//!     serve_grpc("127.0.0.1", 50051).await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## grpcurl Examples
+//!
+//! ```bash
+//! grpcurl -plaintext -import-path proto -proto kreuzberg/v1/kreuzberg.proto \
+//!     -d '{"document": {"path": "/data/report.pdf"}}' \
+//!     localhost:50051 kreuzberg.v1.ExtractionService/Extract
+//!
+//! grpcurl -plaintext -import-path proto -proto kreuzberg/v1/kreuzberg.proto \
+//!     localhost:50051 kreuzberg.v1.ExtractionService/Health
+//! ```
+
+mod server;
+
+/// Generated protobuf messages and service traits for `kreuzberg.v1`.
+#[allow(clippy::all, missing_docs)]
+pub mod proto {
+    tonic::include_proto!("kreuzberg.v1");
+}
+
+pub use server::{KreuzbergGrpc, serve_grpc, serve_grpc_with_config};
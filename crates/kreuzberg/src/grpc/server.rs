@@ -0,0 +1,502 @@
+//! gRPC service implementation and server setup.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+use crate::core::concurrency::ConcurrencyController;
+use crate::error::KreuzbergError;
+use crate::{ExtractionConfig, ExtractionResult, OcrConfig, Result, extract_bytes, extract_file};
+
+use super::proto::{
+    self, BatchExtractRequest, BatchExtractResponse, ChunkRequest, ChunkResponse, Document, ExtractRequest,
+    ExtractResponse, HealthRequest, HealthResponse, OcrRequest,
+    batch_extract_response::Outcome,
+    document::Source,
+    extraction_service_server::{ExtractionService, ExtractionServiceServer},
+};
+
+/// Default maximum request message size (100 MB), matching the REST API upload limit.
+const DEFAULT_MAX_MESSAGE_BYTES: usize = 100 * 1024 * 1024;
+
+/// Batch results buffered per call before extraction waits for the client to read.
+const BATCH_STREAM_BUFFER: usize = 16;
+
+/// gRPC extraction service.
+///
+/// Requests without `config_json` use the default configuration given at construction.
+#[derive(Debug, Clone)]
+pub struct KreuzbergGrpc {
+    default_config: Arc<ExtractionConfig>,
+}
+
+impl KreuzbergGrpc {
+    /// Create a service with `config` as the default extraction configuration.
+    pub fn new(config: ExtractionConfig) -> Self {
+        Self {
+            default_config: Arc::new(config),
+        }
+    }
+
+    /// Wrap the service for `tonic::transport::Server::add_service`.
+    ///
+    /// The maximum request size defaults to 100 MB and can be changed with the
+    /// `KREUZBERG_MAX_REQUEST_BODY_BYTES` environment variable.
+    pub fn into_server(self) -> ExtractionServiceServer<Self> {
+        let max_message_bytes = std::env::var("KREUZBERG_MAX_REQUEST_BODY_BYTES")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .filter(|&bytes| bytes > 0)
+            .unwrap_or(DEFAULT_MAX_MESSAGE_BYTES);
+
+        ExtractionServiceServer::new(self).max_decoding_message_size(max_message_bytes)
+    }
+
+    fn request_config(&self, config_json: &str) -> std::result::Result<ExtractionConfig, Status> {
+        if config_json.trim().is_empty() {
+            return Ok((*self.default_config).clone());
+        }
+
+        serde_json::from_str(config_json)
+            .map_err(|e| Status::invalid_argument(format!("Invalid extraction configuration: {}", e)))
+    }
+}
+
+#[tonic::async_trait]
+impl ExtractionService for KreuzbergGrpc {
+    type BatchExtractStream = ReceiverStream<std::result::Result<BatchExtractResponse, Status>>;
+    type ChunkStream = tokio_stream::Iter<std::vec::IntoIter<std::result::Result<ChunkResponse, Status>>>;
+
+    async fn extract(
+        &self,
+        request: Request<ExtractRequest>,
+    ) -> std::result::Result<Response<ExtractResponse>, Status> {
+        let request = request.into_inner();
+        let config = self.request_config(&request.config_json)?;
+        let document = request
+            .document
+            .ok_or_else(|| Status::invalid_argument("No document provided for extraction"))?;
+
+        let result = extract_document(document, &config).await.map_err(status_from_error)?;
+        Ok(Response::new(extract_response(result)?))
+    }
+
+    async fn batch_extract(
+        &self,
+        request: Request<BatchExtractRequest>,
+    ) -> std::result::Result<Response<Self::BatchExtractStream>, Status> {
+        let request = request.into_inner();
+        let config = Arc::new(self.request_config(&request.config_json)?);
+        let controller = Arc::new(ConcurrencyController::for_config(&config));
+        let (tx, rx) = mpsc::channel(BATCH_STREAM_BUFFER);
+
+        tokio::spawn(async move {
+            let mut tasks = JoinSet::new();
+
+            for (index, document) in request.documents.into_iter().enumerate() {
+                let config = Arc::clone(&config);
+                let controller = Arc::clone(&controller);
+
+                tasks.spawn(async move {
+                    let _permit = controller.acquire(document_size(&document).await).await;
+                    let result = crate::core::batch_mode::with_batch_mode(extract_document(document, &config)).await;
+                    (index, result)
+                });
+            }
+
+            while let Some(task_result) = tasks.join_next().await {
+                let message = match task_result {
+                    Ok((index, result)) => batch_response(index, result),
+                    Err(join_err) => Err(Status::internal(format!("Task panicked: {}", join_err))),
+                };
+
+                // The client went away; dropping the JoinSet aborts the remaining extractions
+                if tx.send(message).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn ocr(&self, request: Request<OcrRequest>) -> std::result::Result<Response<ExtractResponse>, Status> {
+        let request = request.into_inner();
+        if request.mime_type.is_empty() {
+            return Err(Status::invalid_argument("mime_type is required for OCR"));
+        }
+
+        let mut config = (*self.default_config).clone();
+        let mut ocr = config.ocr.take().unwrap_or_else(|| OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
+        });
+        if !request.backend.is_empty() {
+            ocr.backend = request.backend;
+        }
+        if !request.language.is_empty() {
+            ocr.language = request.language;
+        }
+        config.ocr = Some(ocr);
+        config.force_ocr = true;
+
+        let result = extract_bytes(&request.data, &request.mime_type, &config)
+            .await
+            .map_err(status_from_error)?;
+        Ok(Response::new(extract_response(result)?))
+    }
+
+    async fn chunk(&self, request: Request<ChunkRequest>) -> std::result::Result<Response<Self::ChunkStream>, Status> {
+        let request = request.into_inner();
+
+        #[cfg(feature = "chunking")]
+        {
+            use crate::chunking::{ChunkerType, ChunkingConfig, chunk_text};
+
+            let defaults = ChunkingConfig::default();
+            let config = ChunkingConfig {
+                max_characters: match request.max_characters {
+                    0 => defaults.max_characters,
+                    max => max as usize,
+                },
+                overlap: request.overlap as usize,
+                trim: defaults.trim,
+                chunker_type: if request.markdown {
+                    ChunkerType::Markdown
+                } else {
+                    ChunkerType::Text
+                },
+                respect_sentences: false,
+                language: None,
+            };
+
+            let result = tokio::task::spawn_blocking(move || chunk_text(&request.text, &config, None))
+                .await
+                .map_err(|e| Status::internal(format!("Chunking task panicked: {}", e)))?
+                .map_err(status_from_error)?;
+
+            let chunks: Vec<_> = result
+                .chunks
+                .into_iter()
+                .map(|chunk| {
+                    Ok(ChunkResponse {
+                        content: chunk.content,
+                        byte_start: chunk.metadata.byte_start as u64,
+                        byte_end: chunk.metadata.byte_end as u64,
+                        chunk_index: chunk.metadata.chunk_index as u32,
+                        total_chunks: chunk.metadata.total_chunks as u32,
+                    })
+                })
+                .collect();
+
+            Ok(Response::new(tokio_stream::iter(chunks)))
+        }
+
+        #[cfg(not(feature = "chunking"))]
+        {
+            let _ = request;
+            Err(Status::unimplemented("Chunking requires the chunking feature"))
+        }
+    }
+
+    async fn health(&self, _request: Request<HealthRequest>) -> std::result::Result<Response<HealthResponse>, Status> {
+        Ok(Response::new(HealthResponse {
+            status: "healthy".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }))
+    }
+}
+
+async fn extract_document(document: Document, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    match document.source {
+        Some(Source::Data(data)) => {
+            if document.mime_type.is_empty() {
+                return Err(KreuzbergError::validation("mime_type is required for document data"));
+            }
+            extract_bytes(&data, &document.mime_type, config).await
+        }
+        Some(Source::Path(path)) => {
+            let mime_type = (!document.mime_type.is_empty()).then_some(document.mime_type.as_str());
+            extract_file(&path, mime_type, config).await
+        }
+        None => Err(KreuzbergError::validation("Document has neither data nor path")),
+    }
+}
+
+async fn document_size(document: &Document) -> u64 {
+    match &document.source {
+        Some(Source::Data(data)) => data.len() as u64,
+        Some(Source::Path(path)) => tokio::fs::metadata(path).await.map(|m| m.len()).unwrap_or(0),
+        None => 0,
+    }
+}
+
+fn extract_response(result: ExtractionResult) -> std::result::Result<ExtractResponse, Status> {
+    let result_json = serde_json::to_string(&result)
+        .map_err(|e| Status::internal(format!("Failed to serialize extraction result: {}", e)))?;
+
+    Ok(ExtractResponse {
+        content: result.content,
+        mime_type: result.mime_type,
+        result_json,
+    })
+}
+
+fn batch_response(index: usize, result: Result<ExtractionResult>) -> std::result::Result<BatchExtractResponse, Status> {
+    let outcome = match result {
+        Ok(result) => Outcome::Result(extract_response(result)?),
+        Err(e) => Outcome::Error(proto::Error {
+            error_type: error_type_name(&e).to_string(),
+            message: e.to_string(),
+        }),
+    };
+
+    Ok(BatchExtractResponse {
+        index: index as u32,
+        outcome: Some(outcome),
+    })
+}
+
+fn error_type_name(error: &KreuzbergError) -> &'static str {
+    match error {
+        KreuzbergError::Validation { .. } => "ValidationError",
+        KreuzbergError::Parsing { .. } => "ParsingError",
+        KreuzbergError::Ocr { .. } => "OCRError",
+        KreuzbergError::Io(_) => "IOError",
+        KreuzbergError::Cache { .. } => "CacheError",
+        KreuzbergError::ImageProcessing { .. } => "ImageProcessingError",
+        KreuzbergError::Serialization { .. } => "SerializationError",
+        KreuzbergError::MissingDependency(_) => "MissingDependencyError",
+        KreuzbergError::Plugin { .. } => "PluginError",
+        KreuzbergError::LockPoisoned(_) => "LockPoisonedError",
+        KreuzbergError::UnsupportedFormat(_) => "UnsupportedFormatError",
+        KreuzbergError::InputRejected { .. } => "InputRejectedError",
+        KreuzbergError::Timeout { .. } => "TimeoutError",
+        KreuzbergError::Other(_) => "Error",
+    }
+}
+
+/// Map extraction errors to gRPC status codes.
+///
+/// Problems with the submitted document are `INVALID_ARGUMENT`, mirroring the REST API's
+/// 400/422 responses, and stage timeouts are `DEADLINE_EXCEEDED`; everything else is `INTERNAL`.
+fn status_from_error(error: KreuzbergError) -> Status {
+    let message = format!("{}: {}", error_type_name(&error), error);
+    match &error {
+        KreuzbergError::Validation { .. }
+        | KreuzbergError::UnsupportedFormat(_)
+        | KreuzbergError::InputRejected { .. }
+        | KreuzbergError::Parsing { .. }
+        | KreuzbergError::Ocr { .. } => Status::invalid_argument(message),
+        KreuzbergError::MissingDependency(_) => Status::failed_precondition(message),
+        KreuzbergError::Timeout { .. } => Status::deadline_exceeded(message),
+        KreuzbergError::Io(io) if io.kind() == std::io::ErrorKind::NotFound => Status::not_found(message),
+        _ => Status::internal(message),
+    }
+}
+
+/// Start the gRPC server with config file discovery.
+///
+/// Searches for kreuzberg.toml/yaml/json in current and parent directories.
+/// If no config file is found, uses default configuration.
+///
+/// `Document.path` lets clients read any file the server process can, so bind to a
+/// loopback address unless the network is trusted.
+///
+/// # Arguments
+///
+/// * `host` - IP address to bind to (e.g., "127.0.0.1" or "0.0.0.0")
+/// * `port` - Port number to bind to (e.g., 50051)
+pub async fn serve_grpc(host: impl AsRef<str>, port: u16) -> Result<()> {
+    let config = match ExtractionConfig::discover()? {
+        Some(config) => {
+            tracing::info!("Loaded extraction config from discovered file");
+            config
+        }
+        None => {
+            tracing::info!("No config file found, using default configuration");
+            ExtractionConfig::default()
+        }
+    };
+
+    serve_grpc_with_config(host, port, config).await
+}
+
+/// Start the gRPC server with explicit config.
+///
+/// # Arguments
+///
+/// * `host` - IP address to bind to (e.g., "127.0.0.1" or "0.0.0.0")
+/// * `port` - Port number to bind to (e.g., 50051)
+/// * `config` - Default extraction configuration for all requests
+///
+/// # Examples
+///
+/// ```no_run
+/// use kreuzberg::{ExtractionConfig, grpc::serve_grpc_with_config};
+///
+/// #[tokio::main]
+/// async fn main() -> kreuzberg::Result<()> {
+///     let config = ExtractionConfig::from_toml_file("config/kreuzberg.toml")?;
+///     serve_grpc_with_config("127.0.0.1", 50051, config).await?;
+///     Ok(())
+/// }
+/// ```
+pub async fn serve_grpc_with_config(host: impl AsRef<str>, port: u16, config: ExtractionConfig) -> Result<()> {
+    let ip: IpAddr = host
+        .as_ref()
+        .parse()
+        .map_err(|e| KreuzbergError::validation(format!("Invalid host address: {}", e)))?;
+
+    let addr = SocketAddr::new(ip, port);
+
+    tracing::info!("Starting Kreuzberg gRPC server on {}", addr);
+
+    tonic::transport::Server::builder()
+        .add_service(KreuzbergGrpc::new(config).into_server())
+        .serve(addr)
+        .await
+        .map_err(|e| KreuzbergError::Other(e.to_string()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_stream::StreamExt;
+
+    fn text_document(text: &str) -> Document {
+        Document {
+            source: Some(Source::Data(text.as_bytes().to_vec())),
+            mime_type: "text/plain".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extract_returns_content_and_result_json() {
+        let service = KreuzbergGrpc::new(ExtractionConfig::default());
+
+        let response = service
+            .extract(Request::new(ExtractRequest {
+                document: Some(text_document("Hello gRPC")),
+                config_json: String::new(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert!(response.content.contains("Hello gRPC"));
+        assert_eq!(response.mime_type, "text/plain");
+        let result: serde_json::Value = serde_json::from_str(&response.result_json).unwrap();
+        assert_eq!(result["content"], serde_json::Value::String(response.content));
+    }
+
+    #[tokio::test]
+    async fn test_extract_rejects_invalid_requests() {
+        let service = KreuzbergGrpc::new(ExtractionConfig::default());
+
+        let missing_document = service
+            .extract(Request::new(ExtractRequest {
+                document: None,
+                config_json: String::new(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(missing_document.code(), tonic::Code::InvalidArgument);
+
+        let invalid_config = service
+            .extract(Request::new(ExtractRequest {
+                document: Some(text_document("text")),
+                config_json: "{not json".to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(invalid_config.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_batch_extract_streams_every_document() {
+        let service = KreuzbergGrpc::new(ExtractionConfig::default());
+        let documents = vec![
+            text_document("first"),
+            Document {
+                source: Some(Source::Path("/nonexistent/kreuzberg-grpc.txt".to_string())),
+                mime_type: String::new(),
+            },
+            text_document("third"),
+        ];
+
+        let mut stream = service
+            .batch_extract(Request::new(BatchExtractRequest {
+                documents,
+                config_json: String::new(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let mut responses = Vec::new();
+        while let Some(message) = stream.next().await {
+            responses.push(message.unwrap());
+        }
+        responses.sort_by_key(|response| response.index);
+
+        assert_eq!(responses.len(), 3);
+        assert!(matches!(&responses[0].outcome, Some(Outcome::Result(r)) if r.content.contains("first")));
+        assert!(matches!(&responses[1].outcome, Some(Outcome::Error(_))));
+        assert!(matches!(&responses[2].outcome, Some(Outcome::Result(r)) if r.content.contains("third")));
+    }
+
+    #[cfg(feature = "chunking")]
+    #[tokio::test]
+    async fn test_chunk_streams_chunks_in_order() {
+        let service = KreuzbergGrpc::new(ExtractionConfig::default());
+        let text = "word ".repeat(200);
+
+        let chunks: Vec<ChunkResponse> = service
+            .chunk(Request::new(ChunkRequest {
+                text: text.clone(),
+                max_characters: 100,
+                overlap: 0,
+                markdown: false,
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+
+        assert!(chunks.len() > 1);
+        for (index, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.chunk_index as usize, index);
+            assert_eq!(chunk.total_chunks as usize, chunks.len());
+            let source = &text[chunk.byte_start as usize..chunk.byte_end as usize];
+            assert_eq!(source.trim(), chunk.content.trim());
+        }
+    }
+
+    #[test]
+    fn test_status_from_error() {
+        assert_eq!(
+            status_from_error(KreuzbergError::validation("bad")).code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(
+            status_from_error(KreuzbergError::MissingDependency("tesseract".to_string())).code(),
+            tonic::Code::FailedPrecondition
+        );
+        assert_eq!(
+            status_from_error(KreuzbergError::Other("boom".to_string())).code(),
+            tonic::Code::Internal
+        );
+    }
+}
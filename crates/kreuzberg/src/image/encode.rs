@@ -0,0 +1,248 @@
+//! Re-encoding and downscaling of extracted images.
+//!
+//! Bindings hand image bytes to the host language as-is, so large embedded scans and
+//! photos can dominate the memory footprint of a result. [`transcode_image`] applies the
+//! `output_format`, `downscale_to` and `max_bytes` settings of [`ImageExtractionConfig`]
+//! on the Rust side, before results cross the language boundary.
+
+use crate::core::config::{ImageExtractionConfig, ImageOutputFormat};
+use crate::error::{KreuzbergError, Result};
+use crate::types::ExtractedImage;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageEncoder};
+
+/// JPEG quality used for the first encoding attempt.
+const INITIAL_JPEG_QUALITY: u8 = 85;
+
+/// Lowest JPEG quality tried before falling back to downscaling.
+const MIN_JPEG_QUALITY: u8 = 40;
+
+/// Quality reduction per attempt when an image exceeds `max_bytes`.
+const JPEG_QUALITY_STEP: u8 = 15;
+
+/// Scale factor applied per attempt when an image still exceeds `max_bytes`.
+const DOWNSCALE_STEP: f64 = 0.75;
+
+/// Images are not shrunk below this size (longest side, in pixels) to meet `max_bytes`.
+const MIN_DIMENSION: u32 = 16;
+
+/// Re-encode an extracted image according to the image extraction config.
+///
+/// Images that already satisfy every constraint (same format, within `downscale_to`
+/// and `max_bytes`) are left untouched. When `max_bytes` cannot be met even at the
+/// smallest size tried, the smallest encoding is kept.
+///
+/// # Errors
+///
+/// Returns a parsing error if the image bytes cannot be decoded or re-encoded. The
+/// image is left unchanged in that case.
+pub fn transcode_image(image: &mut ExtractedImage, config: &ImageExtractionConfig) -> Result<()> {
+    if !config.transcodes_images() {
+        return Ok(());
+    }
+
+    let source_format = image.format.parse::<ImageOutputFormat>().ok();
+    let target_format = config.output_format.or(source_format).unwrap_or(ImageOutputFormat::Png);
+
+    let format_matches = source_format == Some(target_format);
+    let fits_bytes = config.max_bytes.is_none_or(|max_bytes| image.data.len() <= max_bytes);
+    let fits_dimension = match (config.downscale_to, image.width, image.height) {
+        (Some(limit), Some(width), Some(height)) => width <= limit && height <= limit,
+        (Some(_), _, _) => false,
+        (None, _, _) => true,
+    };
+    if format_matches && fits_bytes && fits_dimension {
+        return Ok(());
+    }
+
+    let mut decoded = image::load_from_memory(&image.data)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to decode {} image: {}", image.format, e)))?;
+
+    if let Some(limit) = config.downscale_to.map(|limit| limit.max(1))
+        && (decoded.width() > limit || decoded.height() > limit)
+    {
+        decoded = decoded.resize(limit, limit, FilterType::Lanczos3);
+    }
+
+    let mut quality = INITIAL_JPEG_QUALITY;
+    let mut encoded = encode(&decoded, target_format, quality)?;
+
+    if let Some(max_bytes) = config.max_bytes {
+        while encoded.len() > max_bytes {
+            if target_format == ImageOutputFormat::Jpeg && quality > MIN_JPEG_QUALITY {
+                quality = quality.saturating_sub(JPEG_QUALITY_STEP).max(MIN_JPEG_QUALITY);
+            } else {
+                let width = (decoded.width() as f64 * DOWNSCALE_STEP) as u32;
+                let height = (decoded.height() as f64 * DOWNSCALE_STEP) as u32;
+                if width.max(height) < MIN_DIMENSION {
+                    break;
+                }
+                decoded = decoded.resize(width.max(1), height.max(1), FilterType::Triangle);
+            }
+            encoded = encode(&decoded, target_format, quality)?;
+        }
+    }
+
+    image.width = Some(decoded.width());
+    image.height = Some(decoded.height());
+    image.format = target_format.as_str().to_string();
+    image.data = encoded;
+
+    Ok(())
+}
+
+fn encode(image: &DynamicImage, format: ImageOutputFormat, quality: u8) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+
+    let result = match format {
+        ImageOutputFormat::Png => {
+            let rgba = image.to_rgba8();
+            PngEncoder::new(&mut buffer).write_image(
+                &rgba,
+                rgba.width(),
+                rgba.height(),
+                image::ExtendedColorType::Rgba8,
+            )
+        }
+        ImageOutputFormat::Jpeg => {
+            let rgb = image.to_rgb8();
+            JpegEncoder::new_with_quality(&mut buffer, quality).write_image(
+                &rgb,
+                rgb.width(),
+                rgb.height(),
+                image::ExtendedColorType::Rgb8,
+            )
+        }
+        ImageOutputFormat::Webp => {
+            let rgba = image.to_rgba8();
+            WebPEncoder::new_lossless(&mut buffer).write_image(
+                &rgba,
+                rgba.width(),
+                rgba.height(),
+                image::ExtendedColorType::Rgba8,
+            )
+        }
+    };
+
+    result.map_err(|e| KreuzbergError::parsing(format!("Failed to encode image as {}: {}", format.as_str(), e)))?;
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgb};
+
+    fn noisy_png(width: u32, height: u32) -> ExtractedImage {
+        let buffer = ImageBuffer::from_fn(width, height, |x, y| {
+            let value = (x.wrapping_mul(7919) ^ y.wrapping_mul(104729)) as u8;
+            Rgb([value, value.wrapping_mul(3), value.wrapping_add(y as u8)])
+        });
+        let mut data = Vec::new();
+        DynamicImage::ImageRgb8(buffer)
+            .write_to(&mut std::io::Cursor::new(&mut data), image::ImageFormat::Png)
+            .unwrap();
+
+        ExtractedImage {
+            data,
+            format: "png".to_string(),
+            image_index: 0,
+            page_number: Some(1),
+            width: Some(width),
+            height: Some(height),
+            colorspace: Some("RGB".to_string()),
+            bits_per_component: Some(8),
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
+        }
+    }
+
+    fn config() -> ImageExtractionConfig {
+        ImageExtractionConfig {
+            extract_images: true,
+            target_dpi: 300,
+            max_image_dimension: 4096,
+            auto_adjust_dpi: true,
+            min_dpi: 72,
+            max_dpi: 600,
+            strip_gps: false,
+            output_format: None,
+            downscale_to: None,
+            max_bytes: None,
+            perceptual_hash: false,
+            deduplicate_images: false,
+            duplicate_hash_distance: 5,
+            ocr_policy: None,
+            blob_store_dir: None,
+        }
+    }
+
+    #[test]
+    fn test_transcode_image_to_webp_with_downscale() {
+        let mut image = noisy_png(400, 200);
+        let config = ImageExtractionConfig {
+            output_format: Some(ImageOutputFormat::Webp),
+            downscale_to: Some(100),
+            ..config()
+        };
+
+        transcode_image(&mut image, &config).unwrap();
+
+        assert_eq!(image.format, "webp");
+        assert_eq!((image.width, image.height), (Some(100), Some(50)));
+        assert_eq!(image::guess_format(&image.data).unwrap(), image::ImageFormat::WebP);
+    }
+
+    #[test]
+    fn test_transcode_image_respects_max_bytes() {
+        let mut image = noisy_png(512, 512);
+        let original_len = image.data.len();
+        let config = ImageExtractionConfig {
+            output_format: Some(ImageOutputFormat::Jpeg),
+            max_bytes: Some(20_000),
+            ..config()
+        };
+
+        transcode_image(&mut image, &config).unwrap();
+
+        assert_eq!(image.format, "jpeg");
+        assert!(image.data.len() <= 20_000, "got {} bytes", image.data.len());
+        assert!(image.data.len() < original_len);
+    }
+
+    #[test]
+    fn test_transcode_image_keeps_conforming_image() {
+        let mut image = noisy_png(32, 32);
+        let original = image.data.clone();
+        let config = ImageExtractionConfig {
+            output_format: Some(ImageOutputFormat::Png),
+            downscale_to: Some(64),
+            max_bytes: Some(original.len()),
+            ..config()
+        };
+
+        transcode_image(&mut image, &config).unwrap();
+
+        assert_eq!(image.data, original);
+        assert!(transcode_image(&mut noisy_png(8, 8), &config()).is_ok());
+
+        let mut broken = ExtractedImage {
+            data: vec![0, 1, 2, 3],
+            ..noisy_png(8, 8)
+        };
+        let downscale = ImageExtractionConfig {
+            downscale_to: Some(4),
+            ..config()
+        };
+        assert!(transcode_image(&mut broken, &downscale).is_err());
+        assert_eq!(broken.data, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,752 @@
+//! OCR enhancement of images.
+//!
+//! Applies the enhancement steps of [`ImagePreprocessingConfig`] to an image: color
+//! inversion, contrast stretching, denoising, deskewing and binarization. The output is
+//! the black-and-white image handed to the OCR engine, so settings can be tuned by eye
+//! before running OCR over a large batch.
+//!
+//! `target_dpi` and `auto_rotate` are not applied here. The resolution of raw image bytes
+//! is unknown, and page orientation is detected by the OCR engine itself.
+//!
+//! With `auto_tune`, [`auto_tune`] measures the page instead (contrast, speckle noise,
+//! uneven illumination, skew and text line height) and picks the binarization method,
+//! denoising, contrast stretching and an upscale towards `target_dpi` from the
+//! resolution implied by the line height. The returned [`PreprocessingDecision`] is
+//! recorded in the OCR metadata.
+
+use crate::core::config_validation::validate_binarization_method;
+use crate::error::{KreuzbergError, Result};
+use crate::types::ImagePreprocessingConfig;
+use image::imageops::FilterType;
+use image::{DynamicImage, GrayImage, Luma};
+use serde::{Deserialize, Serialize};
+
+/// Largest skew corrected by deskewing, in degrees.
+const MAX_SKEW_DEGREES: f32 = 10.0;
+
+/// Side length of the image used to estimate skew.
+const SKEW_ESTIMATE_SIZE: u32 = 1024;
+
+/// Radius of the local window used by adaptive and Sauvola binarization.
+const WINDOW_RADIUS: i64 = 15;
+
+/// Offset subtracted from the local mean by adaptive binarization.
+const ADAPTIVE_OFFSET: f64 = 10.0;
+
+/// Sensitivity of Sauvola binarization to local contrast.
+const SAUVOLA_K: f64 = 0.34;
+
+/// Dynamic range of the standard deviation in Sauvola binarization.
+const SAUVOLA_R: f64 = 128.0;
+
+/// Side length of the grid of blocks whose backgrounds measure illumination.
+const ILLUMINATION_GRID: u32 = 8;
+
+/// Background spread above which Sauvola binarization is chosen.
+const SAUVOLA_ILLUMINATION: f32 = 0.2;
+
+/// Background spread above which adaptive binarization is chosen.
+const ADAPTIVE_ILLUMINATION: f32 = 0.08;
+
+/// Share of isolated dark pixels above which the page is denoised.
+const DENOISE_SPECKLE_RATIO: f32 = 0.02;
+
+/// Contrast below which intensities are stretched.
+const CONTRAST_ENHANCE_BELOW: f32 = 0.5;
+
+/// Share of dark pixels that makes a row part of a text line.
+const TEXT_ROW_DENSITY: f32 = 0.02;
+
+/// Body text size assumed when estimating resolution from line height.
+const ASSUMED_FONT_POINTS: f32 = 10.0;
+
+/// Largest upscale applied to reach `target_dpi`.
+const MAX_SCALE_FACTOR: f32 = 4.0;
+
+/// Largest side of an upscaled image.
+const MAX_SCALED_DIMENSION: f32 = 10_000.0;
+
+/// Image statistics measured by [`auto_tune`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageStatistics {
+    /// Spread between the 1st and 99th intensity percentiles, from 0 to 1
+    pub contrast: f32,
+    /// Share of dark pixels without a dark neighbour
+    pub speckle_ratio: f32,
+    /// Spread of the background intensity across the page, from 0 to 1
+    pub illumination_variation: f32,
+    /// Skew of the text lines in degrees
+    pub skew_degrees: f32,
+    /// Median height of the text lines in pixels, if lines were found
+    pub line_height: Option<f32>,
+}
+
+/// Preprocessing parameters chosen for a page by [`auto_tune`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreprocessingDecision {
+    /// Binarization method: "otsu", "adaptive" or "sauvola"
+    pub binarization_method: String,
+    /// Whether a median filter removes speckle noise
+    pub denoise: bool,
+    /// Whether intensities are stretched to the full range
+    pub contrast_enhance: bool,
+    /// Rotation applied to straighten the text lines, in degrees
+    pub deskew_degrees: f32,
+    /// Resolution implied by the text line height
+    pub estimated_dpi: Option<i32>,
+    /// Resolution the page is scaled towards
+    pub target_dpi: i32,
+    /// Scale applied to the page before binarization
+    pub scale_factor: f32,
+    /// Statistics the decision is based on
+    pub statistics: PageStatistics,
+}
+
+/// Apply OCR preprocessing to encoded image bytes and return the result as PNG.
+///
+/// # Errors
+///
+/// Returns a validation error for an unknown binarization method, and a parsing error if
+/// the bytes cannot be decoded or the result cannot be encoded.
+pub fn preprocess_image(data: &[u8], config: &ImagePreprocessingConfig) -> Result<Vec<u8>> {
+    validate_binarization_method(&config.binarization_method)?;
+
+    let image = image::load_from_memory(data)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to decode image for preprocessing: {}", e)))?;
+    let (processed, _) = preprocess_luma(image.to_luma8(), config);
+
+    let mut buffer = Vec::new();
+    DynamicImage::ImageLuma8(processed)
+        .write_to(&mut std::io::Cursor::new(&mut buffer), image::ImageFormat::Png)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to encode preprocessed image: {}", e)))?;
+    Ok(buffer)
+}
+
+/// Apply OCR preprocessing to a grayscale image.
+///
+/// `config.binarization_method` must be valid; unknown methods fall back to Otsu. With
+/// `config.auto_tune`, the parameters chosen for the page are returned alongside it.
+pub fn preprocess_luma(
+    mut image: GrayImage,
+    config: &ImagePreprocessingConfig,
+) -> (GrayImage, Option<PreprocessingDecision>) {
+    if config.invert_colors {
+        image::imageops::invert(&mut image);
+    }
+
+    if config.auto_tune {
+        let decision = auto_tune(&image, config);
+        if decision.contrast_enhance {
+            stretch_contrast(&mut image);
+        }
+        if decision.denoise {
+            image = median_filter(&image);
+        }
+        if decision.deskew_degrees != 0.0 {
+            image = rotate(&image, decision.deskew_degrees);
+        }
+        if decision.scale_factor > 1.0 {
+            let (width, height) = image.dimensions();
+            image = image::imageops::resize(
+                &image,
+                (width as f32 * decision.scale_factor).round() as u32,
+                (height as f32 * decision.scale_factor).round() as u32,
+                FilterType::CatmullRom,
+            );
+        }
+        let binary = binarize(&image, &decision.binarization_method);
+        return (binary, Some(decision));
+    }
+
+    if config.contrast_enhance {
+        stretch_contrast(&mut image);
+    }
+    if config.denoise {
+        image = median_filter(&image);
+    }
+    if config.deskew {
+        let angle = estimate_skew(&image);
+        if angle.abs() >= 0.05 {
+            image = rotate(&image, angle);
+        }
+    }
+
+    (binarize(&image, &config.binarization_method), None)
+}
+
+/// Choose preprocessing parameters for a grayscale page from its statistics.
+///
+/// Uneven backgrounds get local (adaptive or Sauvola) binarization, speckled pages a
+/// median filter and washed-out pages contrast stretching. Resolution is estimated from
+/// the text line height, assuming 10pt body text, and pages below `target_dpi` are
+/// upscaled by up to 4x. Deskewing follows `config.deskew`.
+pub fn auto_tune(image: &GrayImage, config: &ImagePreprocessingConfig) -> PreprocessingDecision {
+    let statistics = page_statistics(image);
+
+    let binarization_method = if statistics.illumination_variation >= SAUVOLA_ILLUMINATION {
+        "sauvola"
+    } else if statistics.illumination_variation >= ADAPTIVE_ILLUMINATION {
+        "adaptive"
+    } else {
+        "otsu"
+    };
+
+    let deskew_degrees = if config.deskew && statistics.skew_degrees.abs() >= 0.05 {
+        statistics.skew_degrees
+    } else {
+        0.0
+    };
+
+    let estimated_dpi = statistics
+        .line_height
+        .map(|height| (height * 72.0 / ASSUMED_FONT_POINTS).round() as i32)
+        .filter(|&dpi| dpi > 0);
+    let (width, height) = image.dimensions();
+    let scale_factor = match estimated_dpi {
+        Some(dpi) if config.target_dpi as f32 / dpi as f32 > 1.1 => (config.target_dpi as f32 / dpi as f32)
+            .min(MAX_SCALE_FACTOR)
+            .min(MAX_SCALED_DIMENSION / width.max(height).max(1) as f32)
+            .max(1.0),
+        _ => 1.0,
+    };
+
+    PreprocessingDecision {
+        binarization_method: binarization_method.to_string(),
+        denoise: statistics.speckle_ratio > DENOISE_SPECKLE_RATIO,
+        contrast_enhance: statistics.contrast < CONTRAST_ENHANCE_BELOW,
+        deskew_degrees,
+        estimated_dpi,
+        target_dpi: config.target_dpi,
+        scale_factor: round2(scale_factor),
+        statistics,
+    }
+}
+
+fn page_statistics(image: &GrayImage) -> PageStatistics {
+    let histogram = histogram(image);
+    let contrast = (percentile(&histogram, 0.99) - percentile(&histogram, 0.01)) as f32 / 255.0;
+
+    let threshold = otsu_threshold(image);
+    let binary = binarize_global(image, threshold);
+    let skew_degrees = estimate_skew(image);
+
+    PageStatistics {
+        contrast: round2(contrast),
+        speckle_ratio: round2(speckle_ratio(&binary)),
+        illumination_variation: round2(illumination_variation(image)),
+        skew_degrees: round2(skew_degrees),
+        line_height: line_height(image, skew_degrees).map(round2),
+    }
+}
+
+fn round2(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+/// Share of dark pixels in a binary image whose eight neighbours are all white.
+fn speckle_ratio(binary: &GrayImage) -> f32 {
+    let (width, height) = binary.dimensions();
+    let is_dark = |x: i64, y: i64| {
+        x >= 0
+            && y >= 0
+            && x < i64::from(width)
+            && y < i64::from(height)
+            && binary.get_pixel(x as u32, y as u32)[0] == 0
+    };
+
+    let mut dark = 0u64;
+    let mut isolated = 0u64;
+    for (x, y, pixel) in binary.enumerate_pixels() {
+        if pixel[0] != 0 {
+            continue;
+        }
+        dark += 1;
+        let (x, y) = (i64::from(x), i64::from(y));
+        let has_neighbour = (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .any(|(dx, dy)| (dx, dy) != (0, 0) && is_dark(x + dx, y + dy));
+        if !has_neighbour {
+            isolated += 1;
+        }
+    }
+
+    if dark == 0 { 0.0 } else { isolated as f32 / dark as f32 }
+}
+
+/// Spread of the 90th percentile intensity (the background) across a grid of blocks.
+fn illumination_variation(image: &GrayImage) -> f32 {
+    let (width, height) = image.dimensions();
+    let block_width = width.div_ceil(ILLUMINATION_GRID).max(1);
+    let block_height = height.div_ceil(ILLUMINATION_GRID).max(1);
+
+    let mut backgrounds = Vec::new();
+    for top in (0..height).step_by(block_height as usize) {
+        for left in (0..width).step_by(block_width as usize) {
+            let block = image::imageops::crop_imm(
+                image,
+                left,
+                top,
+                block_width.min(width - left),
+                block_height.min(height - top),
+            )
+            .to_image();
+            backgrounds.push(percentile(&histogram(&block), 0.9));
+        }
+    }
+
+    match (backgrounds.iter().min(), backgrounds.iter().max()) {
+        (Some(min), Some(max)) => f32::from(max - min) / 255.0,
+        _ => 0.0,
+    }
+}
+
+/// Median height of text lines, measured on the straightened page.
+///
+/// Text lines are runs of rows with at least 2% dark pixels. Runs shorter than 3 pixels
+/// or taller than a quarter of the page are ignored as rules and figures.
+fn line_height(image: &GrayImage, skew_degrees: f32) -> Option<f32> {
+    let (small, scale) = downscale(image);
+    let small = if skew_degrees.abs() >= 0.05 {
+        rotate(&small, skew_degrees)
+    } else {
+        small
+    };
+
+    let threshold = otsu_threshold(&small);
+    let (width, height) = small.dimensions();
+    let min_dark = (width as f32 * TEXT_ROW_DENSITY).max(1.0) as usize;
+    let text_rows =
+        (0..height).map(|y| (0..width).filter(|&x| small.get_pixel(x, y)[0] <= threshold).count() >= min_dark);
+
+    let mut runs = Vec::new();
+    let mut run = 0u32;
+    for is_text in text_rows.chain(std::iter::once(false)) {
+        if is_text {
+            run += 1;
+        } else {
+            if run >= 3 && run <= height / 4 {
+                runs.push(run);
+            }
+            run = 0;
+        }
+    }
+    if runs.is_empty() {
+        return None;
+    }
+
+    runs.sort_unstable();
+    Some(runs[runs.len() / 2] as f32 / scale)
+}
+
+/// Binarize with the named method, falling back to Otsu for unknown names.
+fn binarize(image: &GrayImage, method: &str) -> GrayImage {
+    match method.to_lowercase().as_str() {
+        "adaptive" => binarize_adaptive(image),
+        "sauvola" => binarize_sauvola(image),
+        _ => {
+            let threshold = otsu_threshold(image);
+            binarize_global(image, threshold)
+        }
+    }
+}
+
+/// Intensity below which `fraction` of the pixels of a histogram fall.
+fn percentile(histogram: &[u64; 256], fraction: f64) -> u8 {
+    let total: u64 = histogram.iter().sum();
+    let target = (total as f64 * fraction) as u64;
+    let mut cumulative = 0;
+    for (value, count) in histogram.iter().enumerate() {
+        cumulative += count;
+        if cumulative > target {
+            return value as u8;
+        }
+    }
+    255
+}
+
+/// Stretch the 1st to 99th percentile of intensities to the full range.
+fn stretch_contrast(image: &mut GrayImage) {
+    let histogram = histogram(image);
+    let low = f64::from(percentile(&histogram, 0.01));
+    let high = f64::from(percentile(&histogram, 0.99));
+    if high <= low {
+        return;
+    }
+
+    for pixel in image.pixels_mut() {
+        let stretched = (f64::from(pixel[0]) - low) * 255.0 / (high - low);
+        pixel[0] = stretched.clamp(0.0, 255.0) as u8;
+    }
+}
+
+/// 3x3 median filter, removing salt-and-pepper noise while keeping edges.
+fn median_filter(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    GrayImage::from_fn(width, height, |x, y| {
+        let mut window = [0u8; 9];
+        let offsets = (-1i64..=1).flat_map(|dy| (-1i64..=1).map(move |dx| (dx, dy)));
+        for (slot, (dx, dy)) in window.iter_mut().zip(offsets) {
+            let sx = (i64::from(x) + dx).clamp(0, i64::from(width) - 1) as u32;
+            let sy = (i64::from(y) + dy).clamp(0, i64::from(height) - 1) as u32;
+            *slot = image.get_pixel(sx, sy)[0];
+        }
+        let (_, median, _) = window.select_nth_unstable(4);
+        Luma([*median])
+    })
+}
+
+/// Estimate the skew of text lines in degrees (positive when lines slope downwards).
+///
+/// Searches for the angle whose projection profile of dark pixels is sharpest: when the
+/// projection follows the text lines, rows alternate between dense lines and empty gaps.
+fn estimate_skew(image: &GrayImage) -> f32 {
+    let (small, _) = downscale(image);
+
+    let threshold = otsu_threshold(&small);
+    let dark: Vec<(f32, f32)> = small
+        .enumerate_pixels()
+        .filter(|(_, _, pixel)| pixel[0] <= threshold)
+        .map(|(x, y, _)| (x as f32, y as f32))
+        .collect();
+    let (small_width, small_height) = small.dimensions();
+    let total = (small_width * small_height) as usize;
+    if dark.len() < 32 || dark.len() * 2 > total {
+        return 0.0;
+    }
+
+    let rows = (small_width + small_height) as usize * 2;
+    let score = |degrees: f32| {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let mut profile = vec![0u64; rows];
+        for &(x, y) in &dark {
+            let row = (y * cos - x * sin) as isize + rows as isize / 2;
+            if let Some(count) = profile.get_mut(row.max(0) as usize) {
+                *count += 1;
+            }
+        }
+        profile.iter().map(|count| count * count).sum::<u64>()
+    };
+
+    let search = |center: f32, range: f32, step: f32| {
+        let steps = (range / step).round() as i32;
+        (-steps..=steps)
+            .map(|i| center + i as f32 * step)
+            .map(|angle| (angle, score(angle)))
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.abs().total_cmp(&a.0.abs())))
+            .map(|(angle, _)| angle)
+            .unwrap_or(0.0)
+    };
+
+    let coarse = search(0.0, MAX_SKEW_DEGREES, 0.5);
+    search(coarse, 0.5, 0.1)
+}
+
+/// Shrink an image to at most `SKEW_ESTIMATE_SIZE` pixels per side, returning the scale.
+fn downscale(image: &GrayImage) -> (GrayImage, f32) {
+    let (width, height) = image.dimensions();
+    let scale = (SKEW_ESTIMATE_SIZE as f32 / width.max(height).max(1) as f32).min(1.0);
+    if scale < 1.0 {
+        let small = image::imageops::resize(
+            image,
+            ((width as f32 * scale) as u32).max(1),
+            ((height as f32 * scale) as u32).max(1),
+            FilterType::Triangle,
+        );
+        (small, scale)
+    } else {
+        (image.clone(), 1.0)
+    }
+}
+
+/// Rotate an image so that lines skewed by `degrees` become horizontal, filling with white.
+fn rotate(image: &GrayImage, degrees: f32) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let cx = (width as f32 - 1.0) / 2.0;
+    let cy = (height as f32 - 1.0) / 2.0;
+
+    GrayImage::from_fn(width, height, |x, y| {
+        let dx = x as f32 - cx;
+        let dy = y as f32 - cy;
+        let sx = dx * cos - dy * sin + cx;
+        let sy = dx * sin + dy * cos + cy;
+        Luma([sample_bilinear(image, sx, sy)])
+    })
+}
+
+fn sample_bilinear(image: &GrayImage, x: f32, y: f32) -> u8 {
+    let (width, height) = image.dimensions();
+    if x < 0.0 || y < 0.0 || x > (width - 1) as f32 || y > (height - 1) as f32 {
+        return 255;
+    }
+
+    let x0 = x.floor() as u32;
+    let y0 = y.floor() as u32;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+    let fx = x - x0 as f32;
+    let fy = y - y0 as f32;
+
+    let value = |px: u32, py: u32| f32::from(image.get_pixel(px, py)[0]);
+    let top = value(x0, y0) * (1.0 - fx) + value(x1, y0) * fx;
+    let bottom = value(x0, y1) * (1.0 - fx) + value(x1, y1) * fx;
+    (top * (1.0 - fy) + bottom * fy).round() as u8
+}
+
+fn histogram(image: &GrayImage) -> [u64; 256] {
+    let mut histogram = [0u64; 256];
+    for pixel in image.pixels() {
+        histogram[pixel[0] as usize] += 1;
+    }
+    histogram
+}
+
+/// Global threshold maximizing the between-class variance of the histogram.
+fn otsu_threshold(image: &GrayImage) -> u8 {
+    let histogram = histogram(image);
+    let total: u64 = histogram.iter().sum();
+    let weighted_total: f64 = histogram.iter().enumerate().map(|(v, &c)| v as f64 * c as f64).sum();
+
+    let mut background = 0u64;
+    let mut weighted_background = 0.0;
+    let mut best = (0u8, 0.0);
+    for (value, &count) in histogram.iter().enumerate() {
+        background += count;
+        if background == 0 {
+            continue;
+        }
+        let foreground = total - background;
+        if foreground == 0 {
+            break;
+        }
+
+        weighted_background += value as f64 * count as f64;
+        let mean_background = weighted_background / background as f64;
+        let mean_foreground = (weighted_total - weighted_background) / foreground as f64;
+        let variance = background as f64 * foreground as f64 * (mean_background - mean_foreground).powi(2);
+        if variance > best.1 {
+            best = (value as u8, variance);
+        }
+    }
+    best.0
+}
+
+fn binarize_global(image: &GrayImage, threshold: u8) -> GrayImage {
+    let (width, height) = image.dimensions();
+    GrayImage::from_fn(width, height, |x, y| {
+        Luma([if image.get_pixel(x, y)[0] <= threshold { 0 } else { 255 }])
+    })
+}
+
+/// Summed-area tables of intensities and squared intensities.
+struct IntegralImages {
+    width: usize,
+    height: usize,
+    sum: Vec<u64>,
+    sum_sq: Vec<u64>,
+}
+
+impl IntegralImages {
+    fn new(image: &GrayImage) -> Self {
+        let (width, height) = image.dimensions();
+        let stride = width as usize + 1;
+        let mut sum = vec![0u64; stride * (height as usize + 1)];
+        let mut sum_sq = vec![0u64; stride * (height as usize + 1)];
+
+        for y in 0..height as usize {
+            let mut row = 0u64;
+            let mut row_sq = 0u64;
+            for x in 0..width as usize {
+                let value = u64::from(image.get_pixel(x as u32, y as u32)[0]);
+                row += value;
+                row_sq += value * value;
+                sum[(y + 1) * stride + x + 1] = sum[y * stride + x + 1] + row;
+                sum_sq[(y + 1) * stride + x + 1] = sum_sq[y * stride + x + 1] + row_sq;
+            }
+        }
+
+        Self {
+            width: width as usize,
+            height: height as usize,
+            sum,
+            sum_sq,
+        }
+    }
+
+    /// Mean and standard deviation of the window around a pixel, clipped to the image.
+    fn window_stats(&self, x: u32, y: u32) -> (f64, f64) {
+        let stride = self.width + 1;
+        let x0 = (i64::from(x) - WINDOW_RADIUS).max(0) as usize;
+        let y0 = (i64::from(y) - WINDOW_RADIUS).max(0) as usize;
+        let x1 = (i64::from(x) + WINDOW_RADIUS + 1).min(self.width as i64) as usize;
+        let y1 = (i64::from(y) + WINDOW_RADIUS + 1).min(self.height as i64) as usize;
+
+        let area = |table: &[u64]| {
+            (table[y1 * stride + x1] + table[y0 * stride + x0]) as f64
+                - (table[y0 * stride + x1] + table[y1 * stride + x0]) as f64
+        };
+        let count = ((x1 - x0) * (y1 - y0)) as f64;
+        let mean = area(&self.sum) / count;
+        let variance = (area(&self.sum_sq) / count - mean * mean).max(0.0);
+        (mean, variance.sqrt())
+    }
+}
+
+/// Threshold each pixel against the mean of its neighbourhood.
+fn binarize_adaptive(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let integral = IntegralImages::new(image);
+    GrayImage::from_fn(width, height, |x, y| {
+        let (mean, _) = integral.window_stats(x, y);
+        let value = f64::from(image.get_pixel(x, y)[0]);
+        Luma([if value <= mean - ADAPTIVE_OFFSET { 0 } else { 255 }])
+    })
+}
+
+/// Sauvola binarization, which copes with uneven lighting and stained backgrounds.
+fn binarize_sauvola(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let integral = IntegralImages::new(image);
+    GrayImage::from_fn(width, height, |x, y| {
+        let (mean, deviation) = integral.window_stats(x, y);
+        let threshold = mean * (1.0 + SAUVOLA_K * (deviation / SAUVOLA_R - 1.0));
+        let value = f64::from(image.get_pixel(x, y)[0]);
+        Luma([if value <= threshold { 0 } else { 255 }])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// White page with dark horizontal text lines sloping by `degrees`.
+    fn skewed_page(degrees: f32) -> GrayImage {
+        let slope = degrees.to_radians().tan();
+        GrayImage::from_fn(400, 300, |x, y| {
+            let line_y = y as f32 - x as f32 * slope;
+            let in_line = line_y > 20.0 && line_y < 260.0 && (line_y as u32 % 24) < 8;
+            let in_margin = !(20..380).contains(&x);
+            Luma([if in_line && !in_margin { 20 } else { 235 }])
+        })
+    }
+
+    fn config(method: &str) -> ImagePreprocessingConfig {
+        ImagePreprocessingConfig {
+            binarization_method: method.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn encode(image: GrayImage) -> Vec<u8> {
+        let mut data = Vec::new();
+        DynamicImage::ImageLuma8(image)
+            .write_to(&mut std::io::Cursor::new(&mut data), image::ImageFormat::Png)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_estimate_skew_finds_line_angle() {
+        assert!(estimate_skew(&skewed_page(0.0)).abs() < 0.2);
+        assert!((estimate_skew(&skewed_page(3.0)) - 3.0).abs() < 0.3);
+        assert!((estimate_skew(&skewed_page(-4.0)) + 4.0).abs() < 0.3);
+    }
+
+    #[test]
+    fn test_deskew_straightens_lines() {
+        let page = skewed_page(3.0);
+        let straightened = rotate(&page, estimate_skew(&page));
+
+        assert!(estimate_skew(&straightened).abs() < 0.3);
+    }
+
+    #[test]
+    fn test_preprocess_image_outputs_binary_png() {
+        let data = encode(skewed_page(2.0));
+
+        for method in ["otsu", "adaptive", "sauvola"] {
+            let output = preprocess_image(&data, &config(method)).unwrap();
+            let image = image::load_from_memory(&output).unwrap().to_luma8();
+
+            assert_eq!(image.dimensions(), (400, 300));
+            assert!(image.pixels().all(|p| p[0] == 0 || p[0] == 255), "{}", method);
+            assert!(image.pixels().any(|p| p[0] == 0), "{}", method);
+        }
+    }
+
+    #[test]
+    fn test_invert_colors_restores_dark_text() {
+        let mut page = skewed_page(0.0);
+        image::imageops::invert(&mut page);
+        let config = ImagePreprocessingConfig {
+            invert_colors: true,
+            deskew: false,
+            ..Default::default()
+        };
+
+        let (output, decision) = preprocess_luma(page, &config);
+
+        assert!(decision.is_none());
+
+        assert_eq!(output.get_pixel(0, 0)[0], 255);
+        assert_eq!(output.get_pixel(100, 24)[0], 0);
+    }
+
+    #[test]
+    fn test_auto_tune_clean_page_upscales_and_deskews() {
+        let config = ImagePreprocessingConfig {
+            auto_tune: true,
+            ..Default::default()
+        };
+
+        let (output, decision) = preprocess_luma(skewed_page(2.0), &config);
+        let decision = decision.unwrap();
+
+        assert_eq!(decision.binarization_method, "otsu");
+        assert!(!decision.denoise);
+        assert!(!decision.contrast_enhance);
+        assert!((decision.deskew_degrees - 2.0).abs() < 0.3);
+        assert!(decision.estimated_dpi.unwrap() < 100);
+        assert_eq!(decision.scale_factor, 4.0);
+        assert_eq!(output.dimensions(), (1600, 1200));
+    }
+
+    #[test]
+    fn test_auto_tune_uneven_lighting_uses_sauvola() {
+        let page = skewed_page(0.0);
+        let shaded = GrayImage::from_fn(400, 300, |x, y| {
+            let shade = 100 + (x * 135 / 400) as u8;
+            let value = page.get_pixel(x, y)[0];
+            Luma([if value < 128 { value } else { shade }])
+        });
+
+        let decision = auto_tune(&shaded, &ImagePreprocessingConfig::default());
+
+        assert_eq!(decision.binarization_method, "sauvola");
+        assert!(decision.statistics.illumination_variation >= 0.2);
+    }
+
+    #[test]
+    fn test_auto_tune_speckled_page_denoises() {
+        let page = skewed_page(0.0);
+        let speckled = GrayImage::from_fn(400, 300, |x, y| {
+            let speck = (x * 7919 + y * 104_729) % 97 == 0;
+            Luma([if speck { 20 } else { page.get_pixel(x, y)[0] }])
+        });
+
+        let clean = auto_tune(&page, &ImagePreprocessingConfig::default());
+        let noisy = auto_tune(&speckled, &ImagePreprocessingConfig::default());
+
+        assert!(!clean.denoise);
+        assert!(noisy.denoise);
+        assert!(noisy.statistics.speckle_ratio > clean.statistics.speckle_ratio);
+    }
+
+    #[test]
+    fn test_preprocess_image_rejects_unknown_method() {
+        let data = encode(skewed_page(0.0));
+
+        assert!(preprocess_image(&data, &config("magic")).is_err());
+        assert!(preprocess_image(b"not an image", &config("otsu")).is_err());
+    }
+}
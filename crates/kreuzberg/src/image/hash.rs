@@ -0,0 +1,179 @@
+//! Perceptual hashing and near-duplicate suppression for extracted images.
+//!
+//! Logos, letterheads and watermarks are often embedded once per page, so a long report
+//! can yield hundreds of copies of the same picture. A difference hash (dHash) is robust
+//! to re-encoding and rescaling: the image is reduced to a 9x8 grayscale thumbnail and
+//! each bit records whether a pixel is brighter than its right-hand neighbour. Images
+//! whose 64-bit hashes differ in only a few bits are treated as duplicates.
+
+use crate::error::{KreuzbergError, Result};
+use crate::types::ExtractedImage;
+use image::DynamicImage;
+use image::imageops::FilterType;
+
+const HASH_WIDTH: u32 = 9;
+const HASH_HEIGHT: u32 = 8;
+
+/// Compute the 64-bit difference hash of an image.
+pub fn dhash(image: &DynamicImage) -> u64 {
+    let thumbnail = image
+        .resize_exact(HASH_WIDTH, HASH_HEIGHT, FilterType::Triangle)
+        .to_luma8();
+
+    let mut hash = 0u64;
+    for y in 0..HASH_HEIGHT {
+        for x in 0..HASH_WIDTH - 1 {
+            let left = thumbnail.get_pixel(x, y)[0];
+            let right = thumbnail.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | u64::from(left > right);
+        }
+    }
+    hash
+}
+
+/// Decode encoded image bytes and return their difference hash as 16 hex digits.
+///
+/// # Errors
+///
+/// Returns a parsing error if the bytes cannot be decoded.
+pub fn perceptual_hash(data: &[u8]) -> Result<String> {
+    let image = image::load_from_memory(data)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to decode image for hashing: {}", e)))?;
+    Ok(format!("{:016x}", dhash(&image)))
+}
+
+/// Number of differing bits between two hex-encoded perceptual hashes.
+///
+/// Returns `None` if either hash is not a valid 64-bit hex value.
+pub fn hamming_distance(a: &str, b: &str) -> Option<u32> {
+    let a = u64::from_str_radix(a, 16).ok()?;
+    let b = u64::from_str_radix(b, 16).ok()?;
+    Some((a ^ b).count_ones())
+}
+
+/// Fill in `perceptual_hash` for every image that does not have one yet.
+///
+/// Images that cannot be decoded are left without a hash.
+pub fn assign_perceptual_hashes(images: &mut [ExtractedImage]) {
+    for image in images.iter_mut().filter(|image| image.perceptual_hash.is_none()) {
+        image.perceptual_hash = perceptual_hash(&image.data).ok();
+    }
+}
+
+/// Drop images whose hash is within `max_distance` bits of an earlier image.
+///
+/// The first occurrence of each image is kept. Images without a hash are never
+/// considered duplicates. Returns the `image_index` of every removed image.
+pub fn deduplicate_images(images: &mut Vec<ExtractedImage>, max_distance: u32) -> Vec<usize> {
+    let mut kept: Vec<u64> = Vec::new();
+    let mut removed = Vec::new();
+
+    images.retain(|image| {
+        let Some(hash) = image
+            .perceptual_hash
+            .as_deref()
+            .and_then(|hash| u64::from_str_radix(hash, 16).ok())
+        else {
+            return true;
+        };
+
+        if kept.iter().any(|seen| (seen ^ hash).count_ones() <= max_distance) {
+            removed.push(image.image_index);
+            false
+        } else {
+            kept.push(hash);
+            true
+        }
+    });
+
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgb};
+
+    fn gradient(width: u32, height: u32, reversed: bool) -> DynamicImage {
+        DynamicImage::ImageRgb8(ImageBuffer::from_fn(width, height, |x, y| {
+            let ramp = (x * 255 / width) as u8;
+            let descending = (y * 8 / height) % 2 == 1;
+            let value = if descending != reversed { 255 - ramp } else { ramp };
+            Rgb([value, value, value])
+        }))
+    }
+
+    fn extracted(index: usize, image: &DynamicImage) -> ExtractedImage {
+        let mut data = Vec::new();
+        image
+            .write_to(&mut std::io::Cursor::new(&mut data), image::ImageFormat::Png)
+            .unwrap();
+
+        ExtractedImage {
+            data,
+            format: "png".to_string(),
+            image_index: index,
+            page_number: Some(index + 1),
+            width: Some(image.width()),
+            height: Some(image.height()),
+            colorspace: Some("RGB".to_string()),
+            bits_per_component: Some(8),
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
+        }
+    }
+
+    #[test]
+    fn test_dhash_stable_across_scaling() {
+        let original = gradient(256, 128, false);
+        let scaled = original.resize_exact(128, 64, FilterType::Lanczos3);
+
+        let distance = (dhash(&original) ^ dhash(&scaled)).count_ones();
+        assert!(distance <= 4, "distance {distance}");
+
+        let different = (dhash(&original) ^ dhash(&gradient(256, 128, true))).count_ones();
+        assert!(different > 16, "distance {different}");
+    }
+
+    #[test]
+    fn test_deduplicate_images_keeps_first_occurrence() {
+        let logo = gradient(120, 60, false);
+        let mut images = vec![
+            extracted(0, &logo),
+            extracted(1, &gradient(120, 60, true)),
+            extracted(2, &logo.resize_exact(60, 30, FilterType::Lanczos3)),
+            ExtractedImage {
+                data: vec![1, 2, 3],
+                ..extracted(3, &logo)
+            },
+        ];
+
+        assign_perceptual_hashes(&mut images);
+        let removed = deduplicate_images(&mut images, 5);
+
+        assert_eq!(removed, vec![2]);
+        assert_eq!(
+            images.iter().map(|image| image.image_index).collect::<Vec<_>>(),
+            vec![0, 1, 3]
+        );
+        assert!(
+            images[0]
+                .perceptual_hash
+                .as_deref()
+                .is_some_and(|hash| hash.len() == 16)
+        );
+        assert!(images[2].perceptual_hash.is_none());
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        assert_eq!(hamming_distance("00000000000000ff", "000000000000000f"), Some(4));
+        assert_eq!(hamming_distance("ffffffffffffffff", "ffffffffffffffff"), Some(0));
+        assert_eq!(hamming_distance("not-hex", "00"), None);
+    }
+}
@@ -1,6 +1,12 @@
 pub mod dpi;
+pub mod encode;
+pub mod enhance;
+pub mod hash;
 pub mod preprocessing;
 pub mod resize;
 
 pub use dpi::calculate_optimal_dpi;
+pub use encode::transcode_image;
+pub use enhance::preprocess_image;
+pub use hash::{assign_perceptual_hashes, deduplicate_images, hamming_distance, perceptual_hash};
 pub use preprocessing::{NormalizeResult, normalize_image_dpi};
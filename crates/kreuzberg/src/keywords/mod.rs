@@ -158,14 +158,7 @@ pub fn ensure_initialized() -> Result<()> {
 /// # fn main() {}
 /// ```
 pub fn register_keyword_processor() -> Result<()> {
-    let registry = get_post_processor_registry();
-    let mut registry = registry
-        .write()
-        .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
-
-    registry.register(Arc::new(KeywordExtractor), 50)?;
-
-    Ok(())
+    get_post_processor_registry().update(|registry| registry.register(Arc::new(KeywordExtractor), 50))
 }
 
 #[cfg(test)]
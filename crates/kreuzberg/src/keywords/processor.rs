@@ -114,6 +114,10 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -143,6 +147,10 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -168,6 +176,10 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -193,6 +205,10 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -229,6 +245,10 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config_with_keywords = ExtractionConfig {
@@ -254,6 +274,10 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let long_result = ExtractionResult {
@@ -265,6 +289,10 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -974,12 +974,8 @@ pub fn ensure_initialized() -> Result<()> {
 /// **Note:** This is called automatically on first use.
 /// Explicit calling is optional.
 pub fn register_language_detection_processor() -> Result<()> {
-    let registry = crate::plugins::registry::get_post_processor_registry();
-    let mut registry = registry
-        .write()
-        .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
-
-    registry.register(Arc::new(LanguageDetector), 40)?;
+    crate::plugins::registry::get_post_processor_registry()
+        .update(|registry| registry.register(Arc::new(LanguageDetector), 40))?;
 
     Ok(())
 }
@@ -110,6 +110,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -134,6 +138,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -169,6 +177,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config_with_lang = ExtractionConfig {
@@ -198,6 +210,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let long_result = ExtractionResult {
@@ -209,6 +225,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -39,6 +39,7 @@ pub mod core;
 pub mod error;
 pub mod extraction;
 pub mod extractors;
+pub mod features;
 pub mod panic_context;
 pub mod plugins;
 pub mod text;
@@ -51,6 +52,12 @@ pub mod api;
 #[cfg(feature = "mcp")]
 pub mod mcp;
 
+#[cfg(feature = "grpc-server")]
+pub mod grpc;
+
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub mod barcode;
+
 #[cfg(feature = "chunking")]
 pub mod chunking;
 
@@ -75,11 +82,11 @@ pub mod ocr;
 #[cfg(feature = "pdf")]
 pub mod pdf;
 
-pub use error::{KreuzbergError, Result};
+pub use error::{ExtractionStage, InputRejection, KreuzbergError, Result};
 pub use types::*;
 
 #[cfg(feature = "tokio-runtime")]
-pub use core::extractor::{batch_extract_bytes, batch_extract_file};
+pub use core::extractor::{batch_extract_bytes, batch_extract_file, batch_extract_file_unordered};
 pub use core::extractor::{extract_bytes, extract_file};
 
 // Available in WASM (bytes-based)
@@ -90,13 +97,22 @@ pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync};
 pub use core::extractor::{batch_extract_file_sync, extract_file_sync};
 
 pub use core::config::{
-    ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, PostProcessorConfig, TokenReductionConfig,
+    AdaptiveConcurrencyConfig, CacheBackendKind, CacheConfig, ChunkingConfig, ClassificationConfig, CleanupConfig,
+    CsvConfig, DocumentTypeRule, EmbeddingConfig, EmbeddingModelType, EncryptedInputPolicy, ExtractionConfig,
+    ImageExtractionConfig, ImageOcrPolicy, ImageOutputFormat, InputPolicyConfig, KeyValueExtractionConfig,
+    LanguageDetectionConfig, NormalizationConfig, OcrConfig, OcrConfusionContext, OcrConfusionRule,
+    OcrCorrectionConfig, OcrFallbackConfig, OcrVocabularyConfig, PostProcessorConfig, PptxConfig, PreviewConfig,
+    ResourceProfile, ResultCompression, RetryConfig, RetryableError, SheetSelector, SpreadsheetConfig,
+    StageTimeoutConfig, TextExtractionConfig, TokenReductionConfig, UnicodeNormalization, WatermarkConfig,
 };
 
 #[cfg(feature = "pdf")]
 pub use core::config::PdfConfig;
 
+pub use core::metadata_writer::{MetadataUpdate, write_metadata, write_metadata_to_bytes};
+
+pub use core::result_schema::{deserialize_result, migrate_result, result_schema_version};
+
 pub use core::mime::{
     DOCX_MIME_TYPE, EXCEL_MIME_TYPE, HTML_MIME_TYPE, JSON_MIME_TYPE, MARKDOWN_MIME_TYPE, PDF_MIME_TYPE,
     PLAIN_TEXT_MIME_TYPE, POWER_POINT_MIME_TYPE, XML_MIME_TYPE, detect_mime_type, detect_mime_type_from_bytes,
@@ -105,6 +121,10 @@ pub use core::mime::{
 
 pub use core::formats::{KNOWN_FORMATS, is_valid_format_field};
 
+pub use core::presets::{ConfigPreset, preset_config};
+
+pub use core::estimate::{CostEstimate, CostModel, estimate, estimate_bytes};
+
 pub use plugins::registry::{
     get_document_extractor_registry, get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
 };
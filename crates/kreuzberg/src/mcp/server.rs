@@ -113,6 +113,8 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             McpError::invalid_params(format!("Unsupported format: {}", mime_type), None)
         }
 
+        err @ KreuzbergError::InputRejected { .. } => McpError::invalid_params(err.to_string(), None),
+
         KreuzbergError::MissingDependency(dep) => McpError::invalid_params(
             format!(
                 "Missing required dependency: {}. Please install it to use this feature.",
@@ -170,6 +172,8 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
 
         KreuzbergError::LockPoisoned(msg) => McpError::internal_error(format!("Internal lock poisoned: {}", msg), None),
 
+        err @ KreuzbergError::Timeout { .. } => McpError::internal_error(err.to_string(), None),
+
         KreuzbergError::Other(msg) => McpError::internal_error(msg, None),
     }
 }
@@ -570,6 +574,8 @@ fn build_config(default_config: &ExtractionConfig, enable_ocr: bool, force_ocr:
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         })
     } else {
         None
@@ -857,6 +863,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: KreuzbergResult::SCHEMA_VERSION,
         };
 
         let formatted = format_extraction_result(&result);
@@ -880,6 +890,7 @@ mod tests {
                     ],
                     page_number: 1,
                     markdown: "| Col1 | Col2 |\n|------|------|\n| A    | B    |".to_string(),
+                    column_types: None,
                 },
                 crate::Table {
                     cells: vec![
@@ -888,12 +899,17 @@ mod tests {
                     ],
                     page_number: 2,
                     markdown: "| X | Y |\n|---|---|\n| 1 | 2 |".to_string(),
+                    column_types: None,
                 },
             ],
             detected_languages: None,
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: KreuzbergResult::SCHEMA_VERSION,
         };
 
         let formatted = format_extraction_result(&result);
@@ -916,6 +932,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: KreuzbergResult::SCHEMA_VERSION,
         };
 
         let formatted = format_extraction_result(&result);
@@ -935,6 +955,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: KreuzbergResult::SCHEMA_VERSION,
         };
 
         let formatted = format_extraction_result(&result);
@@ -1629,6 +1653,8 @@ mod tests {
                 backend: "tesseract".to_string(),
                 language: "spa".to_string(),
                 tesseract_config: None,
+                fallback: None,
+                vocabulary: None,
             }),
             ..Default::default()
         };
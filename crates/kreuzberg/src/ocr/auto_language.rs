@@ -0,0 +1,155 @@
+//! Per-page OCR language auto-detection.
+//!
+//! With `ocr.language = "auto"`, Tesseract's orientation and script detection (OSD) runs
+//! on each page image first, and the page is recognized with the first installed
+//! traineddata for the detected script. With the `language-detection` feature, the
+//! recognized text is then checked with `whatlang`, and the page is recognized again
+//! when the text is in another installed language (e.g. German on a Latin-script page).
+//!
+//! Detection needs `osd.traineddata` in the tessdata directory. Pages whose script
+//! cannot be detected are recognized with [`DEFAULT_LANGUAGE`]. The detected script and
+//! languages are reported in the OCR result metadata as `detected_script` and
+//! `detected_languages`.
+
+use std::path::Path;
+
+use image::RgbImage;
+use kreuzberg_tesseract::TessPageSegMode;
+
+use super::error::OcrError;
+use super::pool::acquire_engine;
+
+/// Language value that enables per-page auto-detection.
+pub const AUTO_LANGUAGE: &str = "auto";
+
+/// Language used when the script of a page cannot be detected.
+pub const DEFAULT_LANGUAGE: &str = "eng";
+
+/// Traineddata to try, in order, for a script name reported by Tesseract OSD.
+///
+/// Scripts without an entry are recognized with the `script/<Script>` model if installed.
+pub fn languages_for_script(script: &str) -> &'static [&'static str] {
+    match script {
+        "Latin" => &["eng"],
+        "Cyrillic" => &["rus", "ukr", "bul", "srp"],
+        "Greek" => &["ell"],
+        "Arabic" => &["ara", "fas", "urd"],
+        "Hebrew" => &["heb"],
+        "Han" => &["chi_sim", "chi_tra"],
+        "Japanese" | "Hiragana" | "Katakana" => &["jpn"],
+        "Hangul" | "Korean" => &["kor"],
+        "Devanagari" => &["hin", "mar", "nep"],
+        "Bengali" => &["ben"],
+        "Tamil" => &["tam"],
+        "Telugu" => &["tel"],
+        "Thai" => &["tha"],
+        "Georgian" => &["kat"],
+        "Armenian" => &["hye"],
+        "Ethiopic" => &["amh"],
+        _ => &[],
+    }
+}
+
+/// Whether `<tessdata_path>/<language>.traineddata` exists.
+pub(crate) fn traineddata_installed(tessdata_path: &str, language: &str) -> bool {
+    !tessdata_path.is_empty()
+        && Path::new(tessdata_path)
+            .join(format!("{}.traineddata", language))
+            .exists()
+}
+
+/// Pick the traineddata for a detected script from the installed languages.
+pub(crate) fn select_language(script: Option<&str>, tessdata_path: &str) -> String {
+    let Some(script) = script else {
+        return DEFAULT_LANGUAGE.to_string();
+    };
+
+    languages_for_script(script)
+        .iter()
+        .map(|language| (*language).to_string())
+        .chain(std::iter::once(format!("script/{}", script)))
+        .find(|language| traineddata_installed(tessdata_path, language))
+        .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string())
+}
+
+/// Detect the dominant script of a page image with Tesseract OSD.
+///
+/// Returns `None` when `osd.traineddata` is not installed or detection fails.
+pub(crate) fn detect_script(image: &RgbImage, tessdata_path: &str) -> Option<String> {
+    if !traineddata_installed(tessdata_path, "osd") {
+        tracing::debug!(
+            "osd.traineddata not found in '{}', skipping script detection",
+            tessdata_path
+        );
+        return None;
+    }
+
+    let api = acquire_engine(tessdata_path, "osd", &[], |api| {
+        api.init(tessdata_path, "osd")
+            .map_err(|e| OcrError::TesseractInitializationFailed(format!("Failed to initialize OSD: {}", e)))
+    })
+    .ok()?;
+
+    let (width, height) = image.dimensions();
+    api.set_page_seg_mode(TessPageSegMode::PSM_OSD_ONLY).ok()?;
+    api.set_image(image.as_raw(), width as i32, height as i32, 3, (width * 3) as i32)
+        .ok()?;
+
+    match api.detect_os() {
+        Ok((_, _, script, confidence)) if !script.is_empty() && confidence > 0.0 => Some(script),
+        Ok(_) => None,
+        Err(e) => {
+            tracing::debug!("Script detection failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Detect the language of recognized text, as a Tesseract language code.
+#[cfg(feature = "language-detection")]
+pub(crate) fn detect_text_language(text: &str) -> Option<String> {
+    let config = crate::core::config::LanguageDetectionConfig {
+        enabled: true,
+        min_confidence: 0.5,
+        detect_multiple: false,
+    };
+
+    crate::language_detection::detect_languages(text, &config)
+        .ok()
+        .flatten()
+        .and_then(|languages| languages.into_iter().next())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_select_language_prefers_installed_traineddata() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("ukr.traineddata"), b"").unwrap();
+        let tessdata = dir.path().to_str().unwrap();
+
+        assert_eq!(select_language(Some("Cyrillic"), tessdata), "ukr");
+    }
+
+    #[test]
+    fn test_select_language_uses_script_model() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("script")).unwrap();
+        std::fs::write(dir.path().join("script/Latin.traineddata"), b"").unwrap();
+        let tessdata = dir.path().to_str().unwrap();
+
+        assert_eq!(select_language(Some("Latin"), tessdata), "script/Latin");
+    }
+
+    #[test]
+    fn test_select_language_defaults_when_unknown() {
+        let dir = tempdir().unwrap();
+        let tessdata = dir.path().to_str().unwrap();
+
+        assert_eq!(select_language(None, tessdata), DEFAULT_LANGUAGE);
+        assert_eq!(select_language(Some("Cyrillic"), tessdata), DEFAULT_LANGUAGE);
+    }
+}
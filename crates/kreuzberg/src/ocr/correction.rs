@@ -0,0 +1,518 @@
+//! Correction of common OCR character confusions.
+//!
+//! OCR engines regularly read `0` as `O`, `1` as `l` or `rn` as `m`, which makes
+//! numbers and identifiers unsearchable. With `ocr_correction` configured, the
+//! [`OcrCorrectionProcessor`] post-processor rewrites such confusions using the
+//! context of each word instead of a language model: `2O24` becomes `2024`, `c0de`
+//! becomes `code` and `rnodem` becomes `modem`.
+//!
+//! Only OCR-derived text is corrected. Extractors record which byte ranges of the
+//! content came from OCR under [`OCR_SPANS_METADATA_KEY`]; the processor rewrites
+//! those ranges, updates the recorded offsets and stores the number of corrections
+//! under [`OCR_CORRECTIONS_METADATA_KEY`]. The OCR results of extracted images are
+//! corrected as a whole.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::OcrCorrectionConfig;
+//! use kreuzberg::ocr::correction::OcrCorrector;
+//!
+//! let corrector = OcrCorrector::new(&OcrCorrectionConfig::default()).unwrap();
+//!
+//! let (text, corrections) = corrector.correct("Invoice 2O24-l05 for rnodem F1LE");
+//! assert_eq!(text, "Invoice 2024-105 for modem FILE");
+//! assert_eq!(corrections, 4);
+//! ```
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::core::config::{OcrConfusionContext, OcrConfusionRule, OcrCorrectionConfig};
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::types::Metadata;
+use crate::{ExtractionConfig, ExtractionResult, KreuzbergError, Result};
+
+/// Metadata key listing the byte ranges of `content` that came from OCR.
+pub const OCR_SPANS_METADATA_KEY: &str = "ocr_spans";
+
+/// Metadata key holding the number of corrections made by [`OcrCorrectionProcessor`].
+pub const OCR_CORRECTIONS_METADATA_KEY: &str = "ocr_corrections";
+
+/// Byte range of extracted content that came from OCR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OcrSpan {
+    /// Start byte offset in `content`
+    pub start: usize,
+    /// End byte offset in `content` (exclusive)
+    pub end: usize,
+}
+
+/// Rules that rewrite the O/0, l/1 and rn/m confusions.
+const BUILTIN_RULES: &[(&str, &str, OcrConfusionContext)] = &[
+    ("O", "0", OcrConfusionContext::Numeric),
+    ("o", "0", OcrConfusionContext::Numeric),
+    ("l", "1", OcrConfusionContext::Numeric),
+    ("I", "1", OcrConfusionContext::Numeric),
+    ("0", "o", OcrConfusionContext::Alphabetic),
+    ("0", "O", OcrConfusionContext::Alphabetic),
+    ("1", "l", OcrConfusionContext::Alphabetic),
+    ("1", "I", OcrConfusionContext::Alphabetic),
+    ("rn", "m", OcrConfusionContext::WordStart),
+];
+
+/// Record the byte ranges of `content` that came from OCR, when `ocr_correction` or
+/// `emit_provenance` is configured.
+pub(crate) fn record_ocr_spans(metadata: &mut Metadata, config: &ExtractionConfig, spans: &[OcrSpan]) {
+    if (config.ocr_correction.is_none() && !config.emit_provenance) || spans.is_empty() {
+        return;
+    }
+    metadata
+        .additional
+        .insert(OCR_SPANS_METADATA_KEY.to_string(), serde_json::json!(spans));
+}
+
+/// Confusion rules grouped by context.
+#[derive(Debug, Clone, Default)]
+pub struct OcrCorrector {
+    numeric: Vec<OcrConfusionRule>,
+    alphabetic: Vec<OcrConfusionRule>,
+    word_start: Vec<OcrConfusionRule>,
+    anywhere: Vec<OcrConfusionRule>,
+}
+
+impl OcrCorrector {
+    /// Build the corrector for `config`: the built-in rules (unless disabled), then `config.rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` for a rule with an empty `from`.
+    pub fn new(config: &OcrCorrectionConfig) -> Result<Self> {
+        let builtin: &[_] = if config.builtin_rules { BUILTIN_RULES } else { &[] };
+        let builtin = builtin.iter().map(|(from, to, context)| OcrConfusionRule {
+            from: from.to_string(),
+            to: to.to_string(),
+            context: *context,
+        });
+
+        let mut corrector = Self::default();
+        for rule in builtin.chain(config.rules.iter().cloned()) {
+            if rule.from.is_empty() {
+                return Err(KreuzbergError::validation(format!(
+                    "OCR correction rule replacing '' with '{}' has an empty 'from'",
+                    rule.to
+                )));
+            }
+            match rule.context {
+                OcrConfusionContext::Numeric => corrector.numeric.push(rule),
+                OcrConfusionContext::Alphabetic => corrector.alphabetic.push(rule),
+                OcrConfusionContext::WordStart => corrector.word_start.push(rule),
+                OcrConfusionContext::Anywhere => corrector.anywhere.push(rule),
+            }
+        }
+
+        Ok(corrector)
+    }
+
+    /// Correct `text`, returning the corrected text and the number of replacements.
+    pub fn correct(&self, text: &str) -> (String, usize) {
+        let mut corrections = 0;
+        let mut text = text.to_string();
+        for rule in &self.anywhere {
+            let count = text.matches(rule.from.as_str()).count();
+            if count > 0 {
+                text = text.replace(rule.from.as_str(), &rule.to);
+                corrections += count;
+            }
+        }
+
+        let mut output = String::with_capacity(text.len());
+        let mut copied = 0;
+        for (start, end) in word_spans(&text) {
+            if let Some((word, count)) = self.correct_word(&text[start..end]) {
+                output.push_str(&text[copied..start]);
+                output.push_str(&word);
+                copied = end;
+                corrections += count;
+            }
+        }
+        output.push_str(&text[copied..]);
+
+        (output, corrections)
+    }
+
+    /// Corrected word and number of replacements, if any rule applies.
+    fn correct_word(&self, word: &str) -> Option<(String, usize)> {
+        if let Some(corrected) = self.correct_number(word) {
+            return Some(corrected);
+        }
+
+        let (word, word_start) = match self.correct_word_start(word) {
+            Some(word) => (word, 1),
+            None => (word.to_string(), 0),
+        };
+        match self.correct_letters(&word) {
+            Some((word, count)) => Some((word, count + word_start)),
+            None => (word_start > 0).then_some((word, word_start)),
+        }
+    }
+
+    /// Apply numeric rules to a word that contains a digit and nothing numeric rules cannot turn into one.
+    fn correct_number(&self, word: &str) -> Option<(String, usize)> {
+        if !word.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut output = String::with_capacity(word.len());
+        let mut count = 0;
+        let mut rest = word;
+        while let Some(c) = rest.chars().next() {
+            if c.is_ascii_digit() {
+                output.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+            let rule = self.numeric.iter().find(|rule| rest.starts_with(rule.from.as_str()))?;
+            output.push_str(&rule.to);
+            rest = &rest[rule.from.len()..];
+            count += 1;
+        }
+
+        (count > 0).then_some((output, count))
+    }
+
+    fn correct_word_start(&self, word: &str) -> Option<String> {
+        self.word_start.iter().find_map(|rule| {
+            let rest = word.strip_prefix(rule.from.as_str())?;
+            rest.chars()
+                .next()
+                .is_some_and(char::is_alphabetic)
+                .then(|| format!("{}{}", rule.to, rest))
+        })
+    }
+
+    /// Apply alphabetic rules between letters, if that leaves the word without digits.
+    fn correct_letters(&self, word: &str) -> Option<(String, usize)> {
+        if self.alphabetic.is_empty() || !word.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut output = String::with_capacity(word.len());
+        let mut count = 0;
+        let mut previous: Option<char> = None;
+        let mut rest = word;
+        while let Some(c) = rest.chars().next() {
+            let rule = self.alphabetic.iter().find(|rule| {
+                let Some(after) = rest.strip_prefix(rule.from.as_str()) else {
+                    return false;
+                };
+                let next = after.chars().next();
+                previous.is_some_and(char::is_alphabetic)
+                    && next.is_some_and(char::is_alphabetic)
+                    && next.is_some_and(|next| case_matches(&rule.to, next))
+            });
+            match rule {
+                Some(rule) => {
+                    output.push_str(&rule.to);
+                    rest = &rest[rule.from.len()..];
+                    previous = rule.to.chars().last();
+                    count += 1;
+                }
+                None => {
+                    output.push(c);
+                    rest = &rest[c.len_utf8()..];
+                    previous = Some(c);
+                }
+            }
+        }
+
+        (count > 0 && !output.chars().any(|c| c.is_ascii_digit())).then_some((output, count))
+    }
+}
+
+/// Whether `replacement` fits before a letter of `next`'s case; uncased replacements always fit.
+fn case_matches(replacement: &str, next: char) -> bool {
+    match replacement.chars().find(|c| c.is_alphabetic()) {
+        Some(first) if first.is_uppercase() => next.is_uppercase(),
+        Some(first) if first.is_lowercase() => next.is_lowercase(),
+        _ => true,
+    }
+}
+
+/// Byte ranges of words: runs of letters and digits.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(index);
+        } else if let Some(start) = start.take() {
+            spans.push((start, index));
+        }
+    }
+    if let Some(start) = start {
+        spans.push((start, text.len()));
+    }
+
+    spans
+}
+
+/// Post-processor that corrects OCR character confusions in OCR-derived text.
+///
+/// This processor:
+/// - Runs in the Early processing stage, before language detection and quality scoring
+/// - Only processes when `config.ocr_correction` is configured
+/// - Rewrites the content ranges listed under [`OCR_SPANS_METADATA_KEY`] and the OCR
+///   results of extracted images
+/// - Stores the number of corrections in `metadata.additional["ocr_corrections"]`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::plugins::{Plugin, PostProcessor};
+/// use kreuzberg::ocr::correction::OcrCorrectionProcessor;
+///
+/// let processor = OcrCorrectionProcessor;
+/// assert_eq!(processor.name(), "ocr-correction");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OcrCorrectionProcessor;
+
+impl Plugin for OcrCorrectionProcessor {
+    fn name(&self) -> &str {
+        "ocr-correction"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for OcrCorrectionProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(correction_config) = &config.ocr_correction else {
+            return Ok(());
+        };
+        let corrector = OcrCorrector::new(correction_config)?;
+        let mut corrections = 0;
+
+        let spans: Vec<OcrSpan> = result
+            .metadata
+            .additional
+            .get(OCR_SPANS_METADATA_KEY)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default();
+        if !spans.is_empty() {
+            let whole_content = spans.len() == 1 && spans[0].start == 0 && spans[0].end == result.content.len();
+            let (content, corrected_spans, count) = correct_spans(&corrector, &result.content, &spans);
+            result.content = content;
+            corrections += count;
+            result
+                .metadata
+                .additional
+                .insert(OCR_SPANS_METADATA_KEY.to_string(), serde_json::json!(corrected_spans));
+
+            // Pages of fully OCR'd content hold the same OCR text
+            if whole_content && let Some(pages) = &mut result.pages {
+                for page in pages {
+                    page.content = corrector.correct(&page.content).0;
+                }
+            }
+        }
+
+        for image in result.images.iter_mut().flatten() {
+            if let Some(ocr_result) = &mut image.ocr_result {
+                let (content, count) = corrector.correct(&ocr_result.content);
+                ocr_result.content = content;
+                corrections += count;
+            }
+        }
+
+        result.metadata.additional.insert(
+            OCR_CORRECTIONS_METADATA_KEY.to_string(),
+            serde_json::Value::from(corrections),
+        );
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, _result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config.ocr_correction.is_some()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        let text_length = result.content.len();
+        // Correction is a single pass over the OCR text: ~1ms per 100KB
+        (text_length / 102400).max(1) as u64
+    }
+}
+
+/// Correct the `spans` of `content`, returning the new content, the spans' new offsets
+/// and the number of corrections. Spans that overlap, or do not fall on character
+/// boundaries, are skipped.
+fn correct_spans(corrector: &OcrCorrector, content: &str, spans: &[OcrSpan]) -> (String, Vec<OcrSpan>, usize) {
+    let mut sorted = spans.to_vec();
+    sorted.sort_by_key(|span| span.start);
+
+    let mut output = String::with_capacity(content.len());
+    let mut corrected_spans = Vec::with_capacity(sorted.len());
+    let mut corrections = 0;
+    let mut copied = 0;
+    for span in sorted {
+        if span.start < copied
+            || span.end < span.start
+            || !content.is_char_boundary(span.start)
+            || !content.is_char_boundary(span.end)
+        {
+            continue;
+        }
+        output.push_str(&content[copied..span.start]);
+        let (text, count) = corrector.correct(&content[span.start..span.end]);
+        let start = output.len();
+        output.push_str(&text);
+        corrected_spans.push(OcrSpan {
+            start,
+            end: output.len(),
+        });
+        corrections += count;
+        copied = span.end;
+    }
+    output.push_str(&content[copied..]);
+
+    (output, corrected_spans, corrections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corrector() -> OcrCorrector {
+        OcrCorrector::new(&OcrCorrectionConfig::default()).unwrap()
+    }
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_builtin_rules_use_word_context() {
+        let (text, corrections) = corrector().correct("Total: 1O5.0O EUR, c0de Hel1o F1LE rnore");
+        assert_eq!(text, "Total: 105.00 EUR, code Hello FILE more");
+        assert_eq!(corrections, 6);
+    }
+
+    #[test]
+    fn test_builtin_rules_leave_mixed_identifiers() {
+        let text = "Win10 H1N1 MP3 COVID19 burn Office 1st IO";
+        assert_eq!(corrector().correct(text), (text.to_string(), 0));
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let config = OcrCorrectionConfig {
+            builtin_rules: false,
+            rules: vec![
+                OcrConfusionRule {
+                    from: "5".to_string(),
+                    to: "S".to_string(),
+                    context: OcrConfusionContext::Alphabetic,
+                },
+                OcrConfusionRule {
+                    from: "vv".to_string(),
+                    to: "w".to_string(),
+                    context: OcrConfusionContext::Anywhere,
+                },
+            ],
+        };
+        let corrector = OcrCorrector::new(&config).unwrap();
+
+        let (text, corrections) = corrector.correct("TE5T vvorks, 2O24");
+        assert_eq!(text, "TEST works, 2O24");
+        assert_eq!(corrections, 2);
+
+        let empty = OcrCorrectionConfig {
+            rules: vec![OcrConfusionRule {
+                from: String::new(),
+                to: "x".to_string(),
+                context: OcrConfusionContext::Anywhere,
+            }],
+            ..Default::default()
+        };
+        assert!(
+            OcrCorrector::new(&empty)
+                .unwrap_err()
+                .to_string()
+                .contains("empty 'from'")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_processor_only_corrects_ocr_spans() {
+        let native = "Native c0de 2O24\n\n";
+        let mut result = result(&format!("{}Scanned c0de 2O24", native));
+        let config = ExtractionConfig {
+            ocr_correction: Some(OcrCorrectionConfig::default()),
+            ..Default::default()
+        };
+        record_ocr_spans(
+            &mut result.metadata,
+            &config,
+            &[OcrSpan {
+                start: native.len(),
+                end: result.content.len(),
+            }],
+        );
+
+        OcrCorrectionProcessor.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(result.content, "Native c0de 2O24\n\nScanned code 2024");
+        assert_eq!(result.metadata.additional[OCR_CORRECTIONS_METADATA_KEY], 2);
+        assert_eq!(
+            result.metadata.additional[OCR_SPANS_METADATA_KEY],
+            serde_json::json!([{ "start": native.len(), "end": result.content.len() }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_processor_without_ocr_spans() {
+        let mut result = result("c0de 2O24");
+        let config = ExtractionConfig {
+            ocr_correction: Some(OcrCorrectionConfig::default()),
+            ..Default::default()
+        };
+
+        assert!(OcrCorrectionProcessor.should_process(&result, &config));
+        assert!(!OcrCorrectionProcessor.should_process(&result, &ExtractionConfig::default()));
+        OcrCorrectionProcessor.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(result.content, "c0de 2O24");
+        assert_eq!(result.metadata.additional[OCR_CORRECTIONS_METADATA_KEY], 0);
+    }
+}
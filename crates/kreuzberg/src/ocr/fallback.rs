@@ -0,0 +1,403 @@
+//! Confidence-gated fallback between OCR backends.
+//!
+//! With `ocr.fallback` configured, each page image is processed by the primary backend
+//! first. When the result reports a `confidence` metadata value (0.0-1.0) below
+//! `fallback.min_confidence`, the page is processed again with the fallback backend, and
+//! the fallback result is kept unless it reports an even lower confidence or fails.
+//! Results without a reported confidence are never retried.
+//!
+//! The backend used for each page is recorded in the extraction metadata under
+//! [`OCR_PAGES_METADATA_KEY`] as a list of [`OcrPageBackend`] entries. The same entries
+//! carry the languages detected on each page when `ocr.language` is `"auto"` (see
+//! [`super::auto_language`]), the preprocessing chosen for each page when
+//! `preprocessing.auto_tune` is enabled (see [`crate::image::enhance::auto_tune`]), and
+//! the number of words corrected against `ocr.vocabulary` (see [`super::vocabulary`]).
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::core::config::{OcrConfig, OcrFallbackConfig};
+use crate::image::enhance::PreprocessingDecision;
+use crate::ocr::auto_language::AUTO_LANGUAGE;
+use crate::ocr::vocabulary::Vocabulary;
+use crate::plugins::OcrBackend;
+use crate::plugins::registry::get_ocr_backend_registry;
+use crate::types::{ExtractionResult, Metadata};
+
+/// Metadata key OCR backends use to report result confidence (0.0-1.0).
+pub const CONFIDENCE_METADATA_KEY: &str = "confidence";
+
+/// Metadata key OCR backends use to report languages detected on a page.
+pub const DETECTED_LANGUAGES_METADATA_KEY: &str = "detected_languages";
+
+/// Metadata key OCR backends use to report auto-tuned preprocessing of a page.
+pub const PREPROCESSING_METADATA_KEY: &str = "preprocessing";
+
+/// Metadata key holding the per-page backend choices.
+pub const OCR_PAGES_METADATA_KEY: &str = "ocr_pages";
+
+/// OCR backend, languages and preprocessing chosen for one page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OcrPageBackend {
+    /// Page number (1-indexed)
+    pub page: usize,
+
+    /// Backend whose result was used
+    pub backend: String,
+
+    /// Confidence reported by that backend (0.0-1.0)
+    pub confidence: Option<f64>,
+
+    /// Whether the page was retried with the fallback backend
+    pub fallback_attempted: bool,
+
+    /// Languages detected on the page (with `ocr.language = "auto"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub languages: Option<Vec<String>>,
+
+    /// Preprocessing chosen for the page (with `preprocessing.auto_tune`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preprocessing: Option<PreprocessingDecision>,
+
+    /// Words replaced by vocabulary correction (with `ocr.vocabulary`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vocabulary_corrections: Option<usize>,
+}
+
+/// Confidence reported by an OCR backend, if any.
+pub fn result_confidence(result: &ExtractionResult) -> Option<f64> {
+    result
+        .metadata
+        .additional
+        .get(CONFIDENCE_METADATA_KEY)
+        .and_then(serde_json::Value::as_f64)
+}
+
+fn result_languages(result: &ExtractionResult) -> Option<Vec<String>> {
+    result
+        .metadata
+        .additional
+        .get(DETECTED_LANGUAGES_METADATA_KEY)
+        .and_then(|value| serde_json::from_value(value.clone()).ok())
+}
+
+fn result_preprocessing(result: &ExtractionResult) -> Option<PreprocessingDecision> {
+    result
+        .metadata
+        .additional
+        .get(PREPROCESSING_METADATA_KEY)
+        .and_then(|value| serde_json::from_value(value.clone()).ok())
+}
+
+fn auto_tune_enabled(config: &OcrConfig) -> bool {
+    config
+        .tesseract_config
+        .as_ref()
+        .and_then(|tesseract| tesseract.preprocessing.as_ref())
+        .is_some_and(|preprocessing| preprocessing.auto_tune)
+}
+
+fn needs_fallback(confidence: Option<f64>, fallback: &OcrFallbackConfig) -> bool {
+    confidence.is_some_and(|confidence| confidence < fallback.min_confidence)
+}
+
+fn prefer_fallback(primary: Option<f64>, fallback: Option<f64>) -> bool {
+    match (primary, fallback) {
+        (Some(primary), Some(fallback)) => fallback >= primary,
+        _ => true,
+    }
+}
+
+fn lookup_backend(name: &str) -> Result<Arc<dyn OcrBackend>> {
+    get_ocr_backend_registry().snapshot().get(name)
+}
+
+/// Run OCR on one page image, retrying with the fallback backend on low confidence.
+///
+/// Errors from the primary backend, or a fallback backend that is not registered,
+/// are returned. Errors from the fallback backend itself keep the primary result.
+/// With `ocr.vocabulary`, the text of the kept result is corrected against it.
+pub(crate) async fn process_image_with_fallback(
+    backend: &Arc<dyn OcrBackend>,
+    image_bytes: &[u8],
+    config: &OcrConfig,
+    page: usize,
+) -> Result<(ExtractionResult, OcrPageBackend)> {
+    crate::core::counters::record_ocr_page();
+
+    let Some(vocabulary_config) = &config.vocabulary else {
+        return recognize_with_fallback(backend, image_bytes, config, page).await;
+    };
+
+    let vocabulary = Vocabulary::load(vocabulary_config)?;
+    let config = vocabulary.apply_to(config)?;
+    let (mut result, mut page_backend) = recognize_with_fallback(backend, image_bytes, &config, page).await?;
+    if vocabulary_config.correct {
+        let (content, corrections) = vocabulary.correct(&result.content);
+        result.content = content;
+        page_backend.vocabulary_corrections = Some(corrections);
+    }
+
+    Ok((result, page_backend))
+}
+
+async fn recognize_with_fallback(
+    backend: &Arc<dyn OcrBackend>,
+    image_bytes: &[u8],
+    config: &OcrConfig,
+    page: usize,
+) -> Result<(ExtractionResult, OcrPageBackend)> {
+    let result = backend.process_image(image_bytes, config).await?;
+    let confidence = result_confidence(&result);
+
+    let fallback = match &config.fallback {
+        Some(fallback) if needs_fallback(confidence, fallback) => fallback,
+        _ => {
+            let languages = result_languages(&result);
+            let preprocessing = result_preprocessing(&result);
+            return Ok((
+                result,
+                OcrPageBackend {
+                    page,
+                    backend: config.backend.clone(),
+                    confidence,
+                    fallback_attempted: false,
+                    languages,
+                    preprocessing,
+                    vocabulary_corrections: None,
+                },
+            ));
+        }
+    };
+
+    let fallback_backend = lookup_backend(&fallback.backend)?;
+    let fallback_config = fallback.to_ocr_config(config);
+
+    match fallback_backend.process_image(image_bytes, &fallback_config).await {
+        Ok(fallback_result) if prefer_fallback(confidence, result_confidence(&fallback_result)) => {
+            let fallback_confidence = result_confidence(&fallback_result);
+            let languages = result_languages(&fallback_result);
+            let preprocessing = result_preprocessing(&fallback_result);
+            Ok((
+                fallback_result,
+                OcrPageBackend {
+                    page,
+                    backend: fallback.backend.clone(),
+                    confidence: fallback_confidence,
+                    fallback_attempted: true,
+                    languages,
+                    preprocessing,
+                    vocabulary_corrections: None,
+                },
+            ))
+        }
+        outcome => {
+            if let Err(e) = outcome {
+                tracing::warn!(
+                    "OCR fallback backend '{}' failed on page {}, keeping '{}' result: {}",
+                    fallback.backend,
+                    page,
+                    config.backend,
+                    e
+                );
+            }
+            let languages = result_languages(&result);
+            let preprocessing = result_preprocessing(&result);
+            Ok((
+                result,
+                OcrPageBackend {
+                    page,
+                    backend: config.backend.clone(),
+                    confidence,
+                    fallback_attempted: true,
+                    languages,
+                    preprocessing,
+                    vocabulary_corrections: None,
+                },
+            ))
+        }
+    }
+}
+
+/// Record per-page backend choices in metadata when a fallback, language auto-detection,
+/// auto-tuned preprocessing or vocabulary correction is configured.
+pub(crate) fn record_ocr_pages(metadata: &mut Metadata, config: &OcrConfig, pages: &[OcrPageBackend]) {
+    let recorded = config.fallback.is_some()
+        || config.language == AUTO_LANGUAGE
+        || auto_tune_enabled(config)
+        || config.vocabulary.as_ref().is_some_and(|vocabulary| vocabulary.correct);
+    if !recorded || pages.is_empty() {
+        return;
+    }
+    if let Ok(value) = serde_json::to_value(pages) {
+        metadata.additional.insert(OCR_PAGES_METADATA_KEY.to_string(), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fallback_config(min_confidence: f64) -> OcrFallbackConfig {
+        OcrFallbackConfig {
+            backend: "secondary".to_string(),
+            min_confidence,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_needs_fallback_only_below_threshold() {
+        let fallback = fallback_config(0.6);
+        assert!(needs_fallback(Some(0.3), &fallback));
+        assert!(!needs_fallback(Some(0.6), &fallback));
+        assert!(!needs_fallback(Some(0.9), &fallback));
+        assert!(!needs_fallback(None, &fallback));
+    }
+
+    #[test]
+    fn test_record_ocr_pages_with_auto_language() {
+        let pages = vec![OcrPageBackend {
+            page: 2,
+            backend: "tesseract".to_string(),
+            confidence: None,
+            fallback_attempted: false,
+            languages: Some(vec!["rus".to_string()]),
+            preprocessing: None,
+            vocabulary_corrections: None,
+        }];
+        let config = OcrConfig {
+            backend: "tesseract".to_string(),
+            language: AUTO_LANGUAGE.to_string(),
+            tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
+        };
+
+        let mut metadata = Metadata::default();
+        record_ocr_pages(&mut metadata, &config, &pages);
+        assert_eq!(
+            metadata.additional[OCR_PAGES_METADATA_KEY],
+            serde_json::json!([{ "page": 2, "backend": "tesseract", "confidence": null, "fallback_attempted": false, "languages": ["rus"] }])
+        );
+    }
+
+    #[test]
+    fn test_prefer_fallback_unless_less_confident() {
+        assert!(prefer_fallback(Some(0.3), Some(0.8)));
+        assert!(prefer_fallback(Some(0.3), None));
+        assert!(!prefer_fallback(Some(0.5), Some(0.2)));
+    }
+
+    #[test]
+    fn test_record_ocr_pages_requires_fallback_config() {
+        let pages = vec![OcrPageBackend {
+            page: 1,
+            backend: "secondary".to_string(),
+            confidence: Some(0.9),
+            fallback_attempted: true,
+            languages: None,
+            preprocessing: None,
+            vocabulary_corrections: None,
+        }];
+        let mut config = OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
+        };
+
+        let mut metadata = Metadata::default();
+        record_ocr_pages(&mut metadata, &config, &pages);
+        assert!(!metadata.additional.contains_key(OCR_PAGES_METADATA_KEY));
+
+        config.fallback = Some(fallback_config(0.6));
+        record_ocr_pages(&mut metadata, &config, &pages);
+        assert_eq!(
+            metadata.additional[OCR_PAGES_METADATA_KEY],
+            serde_json::json!([{ "page": 1, "backend": "secondary", "confidence": 0.9, "fallback_attempted": true }])
+        );
+    }
+
+    #[test]
+    fn test_record_ocr_pages_with_vocabulary_correction() {
+        let pages = vec![OcrPageBackend {
+            page: 1,
+            backend: "tesseract".to_string(),
+            confidence: None,
+            fallback_attempted: false,
+            languages: None,
+            preprocessing: None,
+            vocabulary_corrections: Some(3),
+        }];
+        let config = OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            tesseract_config: None,
+            fallback: None,
+            vocabulary: Some(crate::core::config::OcrVocabularyConfig {
+                words: vec!["Ibuprofen".to_string()],
+                ..Default::default()
+            }),
+        };
+
+        let mut metadata = Metadata::default();
+        record_ocr_pages(&mut metadata, &config, &pages);
+        assert_eq!(
+            metadata.additional[OCR_PAGES_METADATA_KEY],
+            serde_json::json!([{ "page": 1, "backend": "tesseract", "confidence": null, "fallback_attempted": false, "vocabulary_corrections": 3 }])
+        );
+    }
+
+    #[test]
+    fn test_record_ocr_pages_with_auto_tuned_preprocessing() {
+        let decision = PreprocessingDecision {
+            binarization_method: "sauvola".to_string(),
+            denoise: true,
+            contrast_enhance: false,
+            deskew_degrees: 1.5,
+            estimated_dpi: Some(150),
+            target_dpi: 300,
+            scale_factor: 2.0,
+            statistics: crate::image::enhance::PageStatistics {
+                contrast: 0.75,
+                speckle_ratio: 0.25,
+                illumination_variation: 0.5,
+                skew_degrees: 1.5,
+                line_height: Some(20.5),
+            },
+        };
+        let pages = vec![OcrPageBackend {
+            page: 1,
+            backend: "tesseract".to_string(),
+            confidence: None,
+            fallback_attempted: false,
+            languages: None,
+            preprocessing: Some(decision),
+            vocabulary_corrections: None,
+        }];
+        let config = OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            tesseract_config: Some(crate::types::TesseractConfig {
+                preprocessing: Some(crate::types::ImagePreprocessingConfig {
+                    auto_tune: true,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            fallback: None,
+            vocabulary: None,
+        };
+
+        let mut metadata = Metadata::default();
+        record_ocr_pages(&mut metadata, &config, &pages);
+        let recorded = &metadata.additional[OCR_PAGES_METADATA_KEY][0]["preprocessing"];
+        assert_eq!(recorded["binarization_method"], "sauvola");
+        assert_eq!(recorded["scale_factor"], 2.0);
+        assert_eq!(recorded["statistics"]["line_height"], 20.5);
+    }
+}
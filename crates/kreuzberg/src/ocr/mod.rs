@@ -10,7 +10,11 @@
 //! - **Table reconstruction**: Extract and reconstruct tables from hOCR/TSV output
 //! - **hOCR to Markdown**: Convert hOCR format to clean Markdown
 //! - **Batch processing**: Process multiple images efficiently
-//! - **Language support**: Validate and configure Tesseract languages
+//! - **Confidence-gated fallback**: Retry low-confidence pages with a secondary backend
+//! - **Engine pooling**: Reuse initialized Tesseract engines across calls (see [`pool`])
+//! - **Domain vocabulary**: Feed word lists to Tesseract and correct OCR output against them (see [`vocabulary`])
+//! - **Confusion correction**: Fix O/0, l/1 and rn/m misreads in OCR-derived text (see [`correction`])
+//! - **Language support**: Validate and configure Tesseract languages, or detect them per page (`"auto"`)
 //! - **PSM modes**: Support for all Tesseract Page Segmentation Modes
 //!
 //! # Example
@@ -37,24 +41,33 @@
 //! [dependencies]
 //! kreuzberg = { version = "4.0", features = ["ocr"] }
 //! ```
+pub mod auto_language;
 pub mod cache;
+pub mod correction;
 pub mod error;
+pub mod fallback;
 pub mod hocr;
 pub mod language_registry;
+pub mod pool;
 pub mod processor;
 pub mod table;
 pub mod tesseract_backend;
 pub mod types;
 pub mod utils;
 pub mod validation;
+pub mod vocabulary;
 
 pub use cache::{OcrCache, OcrCacheStats};
+pub use correction::{OcrCorrectionProcessor, OcrCorrector, OcrSpan};
 pub use error::OcrError;
+pub use fallback::OcrPageBackend;
 pub use hocr::convert_hocr_to_markdown;
 pub use language_registry::LanguageRegistry;
+pub use pool::{EnginePoolConfig, configure_engine_pool, engine_pool_config};
 pub use processor::OcrProcessor;
 pub use table::{HocrWord, extract_words_from_tsv, reconstruct_table, table_to_markdown};
 pub use tesseract_backend::TesseractBackend;
 pub use types::{BatchItemResult, ExtractionResult, PSMMode, Table, TesseractConfig};
 pub use utils::compute_hash;
 pub use validation::{validate_language_code, validate_tesseract_version};
+pub use vocabulary::Vocabulary;
@@ -0,0 +1,274 @@
+//! Process-wide pool of initialized Tesseract engines.
+//!
+//! Initializing Tesseract loads the traineddata for every requested language, which
+//! dominates latency when OCR runs synchronously per request on small images. With reuse
+//! enabled, engines are kept after each OCR call, keyed by tessdata path, language and
+//! the Tesseract variables they were configured with, and handed to the next call instead
+//! of being constructed again.
+//!
+//! Reuse is disabled by default. Enable it once per process:
+//!
+//! ```rust,no_run
+//! use kreuzberg::ocr::pool::{EnginePoolConfig, configure_engine_pool};
+//!
+//! configure_engine_pool(EnginePoolConfig { pool_size: 4, reuse: true });
+//! ```
+
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{LazyLock, Mutex};
+
+use kreuzberg_tesseract::TesseractAPI;
+
+use super::error::OcrError;
+
+/// Tesseract engine pool configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnginePoolConfig {
+    /// Maximum number of idle engines kept per tessdata path and language
+    pub pool_size: usize,
+
+    /// Keep engines after use instead of dropping them
+    pub reuse: bool,
+}
+
+impl Default for EnginePoolConfig {
+    fn default() -> Self {
+        Self {
+            pool_size: 4,
+            reuse: false,
+        }
+    }
+}
+
+type EngineKey = (String, String, Vec<(String, String)>);
+
+#[derive(Default)]
+struct PoolState {
+    config: EnginePoolConfig,
+    idle: HashMap<EngineKey, Vec<TesseractAPI>>,
+}
+
+/// Pool of initialized Tesseract engines.
+#[derive(Default)]
+pub(crate) struct EnginePool {
+    state: Mutex<PoolState>,
+}
+
+static ENGINE_POOL: LazyLock<EnginePool> = LazyLock::new(EnginePool::default);
+
+/// Configure the process-wide Tesseract engine pool.
+///
+/// Idle engines beyond the new `pool_size` are released immediately; disabling reuse
+/// releases all of them.
+pub fn configure_engine_pool(config: EnginePoolConfig) {
+    ENGINE_POOL.configure(config);
+}
+
+/// Current configuration of the process-wide Tesseract engine pool.
+pub fn engine_pool_config() -> EnginePoolConfig {
+    ENGINE_POOL.config()
+}
+
+/// Borrow an initialized engine from the process-wide pool.
+///
+/// `variables` are the Tesseract variables that outlive a call on the engine, such as
+/// files loaded at initialization; engines are only shared between calls with equal ones.
+pub(crate) fn acquire_engine<F>(
+    tessdata_path: &str,
+    language: &str,
+    variables: &[(String, String)],
+    init: F,
+) -> Result<PooledEngine<'static>, OcrError>
+where
+    F: FnOnce(&TesseractAPI) -> Result<(), OcrError>,
+{
+    ENGINE_POOL.acquire(tessdata_path, language, variables, init)
+}
+
+impl EnginePool {
+    fn configure(&self, config: EnginePoolConfig) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.config = config;
+        let keep = if config.reuse { config.pool_size } else { 0 };
+        state.idle.retain(|_, engines| {
+            engines.truncate(keep);
+            !engines.is_empty()
+        });
+    }
+
+    fn config(&self) -> EnginePoolConfig {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).config
+    }
+
+    /// Take an idle engine for `(tessdata_path, language, variables)`, or construct and
+    /// initialize one.
+    pub(crate) fn acquire<F>(
+        &self,
+        tessdata_path: &str,
+        language: &str,
+        variables: &[(String, String)],
+        init: F,
+    ) -> Result<PooledEngine<'_>, OcrError>
+    where
+        F: FnOnce(&TesseractAPI) -> Result<(), OcrError>,
+    {
+        let key = (tessdata_path.to_string(), language.to_string(), variables.to_vec());
+
+        let idle = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.idle.get_mut(&key).and_then(Vec::pop)
+        };
+
+        let api = match idle {
+            Some(api) => api,
+            None => {
+                let api = TesseractAPI::new();
+                init(&api)?;
+                api
+            }
+        };
+
+        Ok(PooledEngine {
+            pool: self,
+            key,
+            api: Some(api),
+        })
+    }
+
+    fn release(&self, key: EngineKey, api: TesseractAPI) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if !state.config.reuse {
+            return;
+        }
+        let pool_size = state.config.pool_size;
+        let engines = state.idle.entry(key).or_default();
+        if engines.len() < pool_size && api.clear().is_ok() {
+            engines.push(api);
+        }
+    }
+}
+
+/// An initialized engine borrowed from an [`EnginePool`], returned to it on drop.
+pub(crate) struct PooledEngine<'a> {
+    pool: &'a EnginePool,
+    key: EngineKey,
+    api: Option<TesseractAPI>,
+}
+
+impl Deref for PooledEngine<'_> {
+    type Target = TesseractAPI;
+
+    fn deref(&self) -> &Self::Target {
+        self.api.as_ref().expect("engine is present until drop")
+    }
+}
+
+impl Drop for PooledEngine<'_> {
+    fn drop(&mut self) {
+        if let Some(api) = self.api.take() {
+            self.pool.release(std::mem::take(&mut self.key), api);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn acquire_counting(pool: &EnginePool, language: &str, inits: &Cell<usize>) {
+        acquire_with_variables(pool, language, &[], inits);
+    }
+
+    fn acquire_with_variables(pool: &EnginePool, language: &str, variables: &[(String, String)], inits: &Cell<usize>) {
+        let engine = pool
+            .acquire("", language, variables, |_| {
+                inits.set(inits.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+        drop(engine);
+    }
+
+    #[test]
+    fn test_engines_are_reused_when_enabled() {
+        let pool = EnginePool::default();
+        pool.configure(EnginePoolConfig {
+            pool_size: 2,
+            reuse: true,
+        });
+        let inits = Cell::new(0);
+
+        acquire_counting(&pool, "eng", &inits);
+        acquire_counting(&pool, "eng", &inits);
+        assert_eq!(inits.get(), 1);
+
+        acquire_counting(&pool, "deu", &inits);
+        assert_eq!(inits.get(), 2);
+    }
+
+    #[test]
+    fn test_engines_are_not_shared_across_variables() {
+        let pool = EnginePool::default();
+        pool.configure(EnginePoolConfig {
+            pool_size: 2,
+            reuse: true,
+        });
+        let inits = Cell::new(0);
+        let words = vec![("user_words_file".to_string(), "/tmp/words.txt".to_string())];
+
+        acquire_counting(&pool, "eng", &inits);
+        acquire_with_variables(&pool, "eng", &words, &inits);
+        assert_eq!(inits.get(), 2);
+
+        acquire_with_variables(&pool, "eng", &words, &inits);
+        acquire_counting(&pool, "eng", &inits);
+        assert_eq!(inits.get(), 2);
+    }
+
+    #[test]
+    fn test_engines_are_constructed_per_call_by_default() {
+        let pool = EnginePool::default();
+        let inits = Cell::new(0);
+
+        acquire_counting(&pool, "eng", &inits);
+        acquire_counting(&pool, "eng", &inits);
+        assert_eq!(inits.get(), 2);
+    }
+
+    #[test]
+    fn test_disabling_reuse_releases_idle_engines() {
+        let pool = EnginePool::default();
+        pool.configure(EnginePoolConfig {
+            pool_size: 2,
+            reuse: true,
+        });
+        let inits = Cell::new(0);
+        acquire_counting(&pool, "eng", &inits);
+
+        pool.configure(EnginePoolConfig::default());
+        acquire_counting(&pool, "eng", &inits);
+
+        assert_eq!(inits.get(), 2);
+        assert!(pool.state.lock().unwrap().idle.is_empty());
+    }
+
+    #[test]
+    fn test_failed_initialization_is_not_pooled() {
+        let pool = EnginePool::default();
+        pool.configure(EnginePoolConfig {
+            pool_size: 2,
+            reuse: true,
+        });
+
+        let result = pool.acquire("", "eng", &[], |_| {
+            Err(OcrError::TesseractInitializationFailed(
+                "missing traineddata".to_string(),
+            ))
+        });
+
+        assert!(result.is_err());
+        assert!(pool.state.lock().unwrap().idle.is_empty());
+    }
+}
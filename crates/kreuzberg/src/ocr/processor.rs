@@ -5,11 +5,15 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use kreuzberg_tesseract::{TessPageSegMode, TesseractAPI};
 
+use super::auto_language::{AUTO_LANGUAGE, detect_script, select_language};
 use super::cache::OcrCache;
 use super::error::OcrError;
+use super::fallback::PREPROCESSING_METADATA_KEY;
 use super::hocr::convert_hocr_to_markdown;
+use super::pool::acquire_engine;
 use super::table::{extract_words_from_tsv, reconstruct_table, table_to_markdown};
 use super::types::{BatchItemResult, TesseractConfig};
+use crate::image::enhance::preprocess_luma;
 use crate::types::{OcrExtractionResult, OcrTable};
 
 fn strip_control_characters(text: &str) -> String {
@@ -41,6 +45,31 @@ where
     tracing::debug!("[ci-debug][ocr::processor::{stage}] {timestamp:.3}s {}", details());
 }
 
+const TESSDATA_FALLBACK_PATHS: [&str; 9] = [
+    "/opt/homebrew/share/tessdata",
+    "/opt/homebrew/opt/tesseract/share/tessdata",
+    "/usr/local/opt/tesseract/share/tessdata",
+    "/usr/share/tesseract-ocr/5/tessdata",
+    "/usr/share/tesseract-ocr/4/tessdata",
+    "/usr/share/tessdata",
+    "/usr/local/share/tessdata",
+    r#"C:\Program Files\Tesseract-OCR\tessdata"#,
+    r#"C:\ProgramData\Tesseract-OCR\tessdata"#,
+];
+
+/// Tessdata directory from `TESSDATA_PREFIX`, or the first well-known install location.
+fn resolve_tessdata_path() -> String {
+    env::var("TESSDATA_PREFIX")
+        .ok()
+        .or_else(|| {
+            TESSDATA_FALLBACK_PATHS
+                .iter()
+                .find(|p| Path::new(p).exists())
+                .map(|p| (*p).to_string())
+        })
+        .unwrap_or_default()
+}
+
 pub struct OcrProcessor {
     cache: OcrCache,
 }
@@ -149,14 +178,75 @@ impl OcrProcessor {
         config.tessedit_dont_rowrej_good_wds.hash(&mut hasher);
         config.tessedit_enable_dict_correction.hash(&mut hasher);
         config.tessedit_char_whitelist.hash(&mut hasher);
+        config.tessedit_char_blacklist.hash(&mut hasher);
         config.tessedit_use_primary_params_model.hash(&mut hasher);
         config.textord_space_size_is_variable.hash(&mut hasher);
         config.thresholding_method.hash(&mut hasher);
+        config.preserve_interword_spaces.hash(&mut hasher);
+        for path in [&config.user_words_file, &config.user_patterns_file]
+            .into_iter()
+            .flatten()
+        {
+            path.hash(&mut hasher);
+            // Hash the contents too, so editing the file invalidates cached results ~keep
+            std::fs::read(path).ok().hash(&mut hasher);
+        }
+        config.variables.hash(&mut hasher);
+        if let Some(preprocessing) = config.preprocessing.as_ref().filter(|p| p.auto_tune) {
+            preprocessing.hash(&mut hasher);
+        }
 
         format!("{:016x}", hasher.finish())
     }
 
+    /// OCR with `language = "auto"`: detect the script, then recognize with matching traineddata.
+    fn perform_ocr_auto_language(
+        &self,
+        image_bytes: &[u8],
+        config: &TesseractConfig,
+    ) -> Result<OcrExtractionResult, OcrError> {
+        let img = image::load_from_memory(image_bytes)
+            .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode image: {}", e)))?;
+        let tessdata_path = resolve_tessdata_path();
+
+        let script = detect_script(&img.to_rgb8(), &tessdata_path);
+        let mut resolved = config.clone();
+        resolved.language = select_language(script.as_deref(), &tessdata_path);
+        let mut result = self.perform_ocr(image_bytes, &resolved)?;
+
+        #[cfg(feature = "language-detection")]
+        if let Some(language) = super::auto_language::detect_text_language(&result.content)
+            && language != resolved.language
+            && super::auto_language::traineddata_installed(&tessdata_path, &language)
+        {
+            resolved.language = language;
+            result = self.perform_ocr(image_bytes, &resolved)?;
+        }
+
+        if let Some(script) = script {
+            result
+                .metadata
+                .insert("detected_script".to_string(), serde_json::Value::String(script));
+        }
+        result.metadata.insert(
+            "detected_languages".to_string(),
+            serde_json::Value::Array(
+                resolved
+                    .language
+                    .split('+')
+                    .map(|language| serde_json::Value::String(language.to_string()))
+                    .collect(),
+            ),
+        );
+
+        Ok(result)
+    }
+
     fn perform_ocr(&self, image_bytes: &[u8], config: &TesseractConfig) -> Result<OcrExtractionResult, OcrError> {
+        if config.language == AUTO_LANGUAGE {
+            return self.perform_ocr_auto_language(image_bytes, config);
+        }
+
         let ci_debug_enabled = env::var_os("KREUZBERG_CI_DEBUG").is_some();
         log_ci_debug(ci_debug_enabled, "perform_ocr:start", || {
             format!(
@@ -168,9 +258,17 @@ impl OcrProcessor {
             )
         });
 
-        let img = image::load_from_memory(image_bytes)
+        let mut img = image::load_from_memory(image_bytes)
             .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode image: {}", e)))?;
 
+        let mut preprocessing_decision = None;
+        if let Some(preprocessing) = config.preprocessing.as_ref().filter(|p| p.auto_tune) {
+            let (processed, decision) = preprocess_luma(img.to_luma8(), preprocessing);
+            log_ci_debug(ci_debug_enabled, "preprocessing", || format!("decision={:?}", decision));
+            img = image::DynamicImage::ImageLuma8(processed);
+            preprocessing_decision = decision;
+        }
+
         let rgb_image = img.to_rgb8();
         let (width, height) = rgb_image.dimensions();
         let bytes_per_pixel = 3;
@@ -183,29 +281,7 @@ impl OcrProcessor {
             )
         });
 
-        let api = TesseractAPI::new();
-
-        let tessdata_env = env::var("TESSDATA_PREFIX").ok();
-        let fallback_paths = [
-            "/opt/homebrew/share/tessdata",
-            "/opt/homebrew/opt/tesseract/share/tessdata",
-            "/usr/local/opt/tesseract/share/tessdata",
-            "/usr/share/tesseract-ocr/5/tessdata",
-            "/usr/share/tesseract-ocr/4/tessdata",
-            "/usr/share/tessdata",
-            "/usr/local/share/tessdata",
-            r#"C:\Program Files\Tesseract-OCR\tessdata"#,
-            r#"C:\ProgramData\Tesseract-OCR\tessdata"#,
-        ];
-        let tessdata_path = tessdata_env
-            .clone()
-            .or_else(|| {
-                fallback_paths
-                    .iter()
-                    .find(|p| Path::new(p).exists())
-                    .map(|p| (*p).to_string())
-            })
-            .unwrap_or_default();
+        let tessdata_path = resolve_tessdata_path();
 
         log_ci_debug(ci_debug_enabled, "tessdata", || {
             let path_preview = env::var_os("PATH").map(|paths| {
@@ -216,7 +292,7 @@ impl OcrProcessor {
                     .join(", ")
             });
             let resolved_exists = !tessdata_path.is_empty() && Path::new(&tessdata_path).exists();
-            let available_fallbacks = fallback_paths
+            let available_fallbacks = TESSDATA_FALLBACK_PATHS
                 .iter()
                 .filter(|p| Path::new(p).exists())
                 .map(|p| (*p).to_string())
@@ -224,7 +300,7 @@ impl OcrProcessor {
 
             format!(
                 "env={:?} resolved={} exists={} fallbacks_found={:?} path_preview={:?}",
-                tessdata_env,
+                env::var("TESSDATA_PREFIX").ok(),
                 if tessdata_path.is_empty() {
                     "unset"
                 } else {
@@ -266,20 +342,39 @@ impl OcrProcessor {
             }
         }
 
-        let init_result = api.init(&tessdata_path, &config.language);
-        log_ci_debug(ci_debug_enabled, "init", || match &init_result {
-            Ok(_) => format!("language={} datapath='{}'", config.language, tessdata_path),
-            Err(err) => format!(
-                "language={} datapath='{}' error={:?}",
-                config.language, tessdata_path, err
-            ),
-        });
+        let init_variables: Vec<(&str, &str)> = [
+            ("user_words_file", &config.user_words_file),
+            ("user_patterns_file", &config.user_patterns_file),
+        ]
+        .into_iter()
+        .filter_map(|(name, path)| path.as_deref().map(|path| (name, path)))
+        .collect();
+        let engine_variables: Vec<(String, String)> = init_variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .chain(config.variables.clone())
+            .collect();
+
+        let api = acquire_engine(&tessdata_path, &config.language, &engine_variables, |api| {
+            let init_result = if init_variables.is_empty() {
+                api.init(&tessdata_path, &config.language)
+            } else {
+                api.init_with_variables(&tessdata_path, &config.language, &init_variables)
+            };
+            log_ci_debug(ci_debug_enabled, "init", || match &init_result {
+                Ok(_) => format!("language={} datapath='{}'", config.language, tessdata_path),
+                Err(err) => format!(
+                    "language={} datapath='{}' error={:?}",
+                    config.language, tessdata_path, err
+                ),
+            });
 
-        init_result.map_err(|e| {
-            OcrError::TesseractInitializationFailed(format!(
-                "Failed to initialize language '{}': {}",
-                config.language, e
-            ))
+            init_result.map_err(|e| {
+                OcrError::TesseractInitializationFailed(format!(
+                    "Failed to initialize language '{}': {}",
+                    config.language, e
+                ))
+            })
         })?;
 
         if ci_debug_enabled {
@@ -335,10 +430,11 @@ impl OcrProcessor {
         )
         .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to set tessedit_enable_dict_correction: {}", e)))?;
 
-        if !config.tessedit_char_whitelist.is_empty() {
-            api.set_variable("tessedit_char_whitelist", &config.tessedit_char_whitelist)
-                .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to set tessedit_char_whitelist: {}", e)))?;
-        }
+        api.set_variable("tessedit_char_whitelist", &config.tessedit_char_whitelist)
+            .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to set tessedit_char_whitelist: {}", e)))?;
+
+        api.set_variable("tessedit_char_blacklist", &config.tessedit_char_blacklist)
+            .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to set tessedit_char_blacklist: {}", e)))?;
 
         api.set_variable(
             "tessedit_use_primary_params_model",
@@ -357,6 +453,21 @@ impl OcrProcessor {
         api.set_variable("thresholding_method", &config.thresholding_method.to_string())
             .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to set thresholding_method: {}", e)))?;
 
+        api.set_variable(
+            "preserve_interword_spaces",
+            &config.preserve_interword_spaces.to_string(),
+        )
+        .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to set preserve_interword_spaces: {}", e)))?;
+
+        for (name, value) in &config.variables {
+            api.set_variable(name, value).map_err(|e| {
+                OcrError::InvalidConfiguration(format!(
+                    "Tesseract rejected variable '{}' = '{}' (unknown or only settable at initialization): {}",
+                    name, value, e
+                ))
+            })?;
+        }
+
         api.set_image(
             rgb_image.as_raw(),
             width as i32,
@@ -378,6 +489,8 @@ impl OcrProcessor {
 
         log_ci_debug(ci_debug_enabled, "recognize", || "completed".to_string());
 
+        let confidence = api.mean_text_conf().ok().map(|conf| f64::from(conf) / 100.0);
+
         let tsv_data_for_tables = if config.enable_table_detection || config.output_format == "tsv" {
             Some(
                 api.get_tsv_text(0)
@@ -429,6 +542,9 @@ impl OcrProcessor {
             serde_json::Value::String(config.language.clone()),
         );
         metadata.insert("psm".to_string(), serde_json::Value::String(config.psm.to_string()));
+        if let Some(confidence) = confidence.and_then(serde_json::Number::from_f64) {
+            metadata.insert("confidence".to_string(), serde_json::Value::Number(confidence));
+        }
         metadata.insert(
             "output_format".to_string(),
             serde_json::Value::String(config.output_format.clone()),
@@ -438,6 +554,9 @@ impl OcrProcessor {
             "tables_detected".to_string(),
             serde_json::Value::String("0".to_string()),
         );
+        if let Some(decision) = preprocessing_decision.and_then(|d| serde_json::to_value(d).ok()) {
+            metadata.insert(PREPROCESSING_METADATA_KEY.to_string(), decision);
+        }
         if config.output_format == "markdown" {
             metadata.insert(
                 "source_format".to_string(),
@@ -5,6 +5,8 @@
 
 use crate::Result;
 use crate::core::config::OcrConfig;
+use crate::core::config_validation::validate_tesseract_variables;
+use crate::ocr::auto_language::AUTO_LANGUAGE;
 use crate::ocr::processor::OcrProcessor;
 use crate::plugins::{OcrBackend, OcrBackendType, Plugin};
 use crate::types::ExtractionResult;
@@ -76,15 +78,24 @@ impl TesseractBackend {
             tessedit_use_primary_params_model: public_config.tessedit_use_primary_params_model,
             textord_space_size_is_variable: public_config.textord_space_size_is_variable,
             thresholding_method: public_config.thresholding_method,
+            preserve_interword_spaces: public_config.preserve_interword_spaces,
+            user_words_file: public_config.user_words_file.clone(),
+            user_patterns_file: public_config.user_patterns_file.clone(),
+            variables: public_config.variables.clone(),
         }
     }
 
     /// Convert OcrConfig to internal TesseractConfig.
     ///
     /// Uses tesseract_config from OcrConfig if provided, otherwise uses defaults
-    /// with the language from OcrConfig.
+    /// with the language from OcrConfig. An OcrConfig language of `"auto"` always
+    /// enables per-page language detection.
     fn config_to_tesseract(&self, config: &OcrConfig) -> InternalTesseractConfig {
         match &config.tesseract_config {
+            Some(tess_config) if config.language == AUTO_LANGUAGE => InternalTesseractConfig {
+                language: AUTO_LANGUAGE.to_string(),
+                ..Self::convert_config(tess_config)
+            },
             Some(tess_config) => Self::convert_config(tess_config),
             None => InternalTesseractConfig {
                 language: config.language.clone(),
@@ -124,6 +135,9 @@ impl Plugin for TesseractBackend {
 #[async_trait]
 impl OcrBackend for TesseractBackend {
     async fn process_image(&self, image_bytes: &[u8], config: &OcrConfig) -> Result<ExtractionResult> {
+        if let Some(tesseract_config) = &config.tesseract_config {
+            validate_tesseract_variables(tesseract_config)?;
+        }
         let tess_config = self.config_to_tesseract(config);
         let tess_config_clone = tess_config.clone();
 
@@ -143,7 +157,11 @@ impl OcrBackend for TesseractBackend {
 
         let metadata = crate::types::Metadata {
             format: Some(crate::types::FormatMetadata::Ocr(crate::types::OcrMetadata {
-                language: tess_config.language.clone(),
+                language: ocr_result
+                    .metadata
+                    .get("language")
+                    .and_then(|language| language.as_str())
+                    .map_or_else(|| tess_config.language.clone(), str::to_string),
                 psm: tess_config.psm as i32,
                 output_format: tess_config.output_format.clone(),
                 table_count: ocr_result.tables.len(),
@@ -169,15 +187,23 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    column_types: None,
                 })
                 .collect(),
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
     async fn process_file(&self, path: &Path, config: &OcrConfig) -> Result<ExtractionResult> {
+        if let Some(tesseract_config) = &config.tesseract_config {
+            validate_tesseract_variables(tesseract_config)?;
+        }
         let tess_config = self.config_to_tesseract(config);
         let tess_config_clone = tess_config.clone();
 
@@ -197,7 +223,11 @@ impl OcrBackend for TesseractBackend {
 
         let metadata = crate::types::Metadata {
             format: Some(crate::types::FormatMetadata::Ocr(crate::types::OcrMetadata {
-                language: tess_config.language.clone(),
+                language: ocr_result
+                    .metadata
+                    .get("language")
+                    .and_then(|language| language.as_str())
+                    .map_or_else(|| tess_config.language.clone(), str::to_string),
                 psm: tess_config.psm as i32,
                 output_format: tess_config.output_format.clone(),
                 table_count: ocr_result.tables.len(),
@@ -223,11 +253,16 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    column_types: None,
                 })
                 .collect(),
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -271,6 +306,7 @@ impl OcrBackend for TesseractBackend {
                 | "lit"
                 | "lav"
                 | "est"
+                | AUTO_LANGUAGE
         )
     }
 
@@ -350,6 +386,8 @@ mod tests {
             backend: "tesseract".to_string(),
             language: "deu".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         };
 
         let tess_config = backend.config_to_tesseract(&ocr_config);
@@ -371,6 +409,8 @@ mod tests {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: Some(custom_tess_config),
+            fallback: None,
+            vocabulary: None,
         };
 
         let tess_config = backend.config_to_tesseract(&ocr_config);
@@ -379,6 +419,28 @@ mod tests {
         assert!(tess_config.enable_table_detection);
     }
 
+    #[test]
+    fn test_config_to_tesseract_auto_language_overrides_tesseract_config() {
+        let backend = TesseractBackend::new().unwrap();
+
+        let ocr_config = OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "auto".to_string(),
+            tesseract_config: Some(crate::types::TesseractConfig {
+                language: "fra".to_string(),
+                psm: 6,
+                ..Default::default()
+            }),
+            fallback: None,
+            vocabulary: None,
+        };
+
+        let tess_config = backend.config_to_tesseract(&ocr_config);
+        assert_eq!(tess_config.language, "auto");
+        assert_eq!(tess_config.psm, 6);
+        assert!(backend.supports_language("auto"));
+    }
+
     #[test]
     fn test_tesseract_backend_default() {
         let backend = TesseractBackend::default();
@@ -397,6 +459,7 @@ mod tests {
             contrast_enhance: true,
             binarization_method: "adaptive".to_string(),
             invert_colors: false,
+            auto_tune: false,
         };
 
         let custom_tess_config = crate::types::TesseractConfig {
@@ -414,6 +477,8 @@ mod tests {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: Some(custom_tess_config),
+            fallback: None,
+            vocabulary: None,
         };
 
         let tess_config = backend.config_to_tesseract(&ocr_config);
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub use crate::types::ImagePreprocessingConfig;
 
@@ -74,6 +74,10 @@ pub struct TesseractConfig {
     pub tessedit_use_primary_params_model: bool,
     pub textord_space_size_is_variable: bool,
     pub thresholding_method: bool,
+    pub preserve_interword_spaces: bool,
+    pub user_words_file: Option<String>,
+    pub user_patterns_file: Option<String>,
+    pub variables: BTreeMap<String, String>,
 }
 
 impl Default for TesseractConfig {
@@ -100,6 +104,10 @@ impl Default for TesseractConfig {
             tessedit_use_primary_params_model: true,
             textord_space_size_is_variable: true,
             thresholding_method: false,
+            preserve_interword_spaces: false,
+            user_words_file: None,
+            user_patterns_file: None,
+            variables: BTreeMap::new(),
         }
     }
 }
@@ -145,6 +153,10 @@ impl From<&crate::types::TesseractConfig> for TesseractConfig {
             tessedit_use_primary_params_model: config.tessedit_use_primary_params_model,
             textord_space_size_is_variable: config.textord_space_size_is_variable,
             thresholding_method: config.thresholding_method,
+            preserve_interword_spaces: config.preserve_interword_spaces,
+            user_words_file: config.user_words_file.clone(),
+            user_patterns_file: config.user_patterns_file.clone(),
+            variables: config.variables.clone(),
         }
     }
 }
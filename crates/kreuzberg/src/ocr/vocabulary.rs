@@ -0,0 +1,373 @@
+//! Domain vocabulary for OCR.
+//!
+//! With `ocr.vocabulary` configured, the vocabulary words are handed to Tesseract as a
+//! user words file before recognition, and the recognized text is corrected against them
+//! afterwards: an OCR word that is not in the vocabulary, but within `max_distance` edits
+//! of exactly one vocabulary word, is replaced with that word. Words without letters
+//! (numbers, dates) and words shorter than `min_word_length` are left alone.
+//!
+//! The number of corrections on each page is recorded in its
+//! [`OcrPageBackend`](super::OcrPageBackend) entry.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::OcrVocabularyConfig;
+//! use kreuzberg::ocr::vocabulary::Vocabulary;
+//!
+//! let config = OcrVocabularyConfig {
+//!     words: vec!["metformin".to_string(), "XJ-4410".to_string()],
+//!     ..Default::default()
+//! };
+//! let vocabulary = Vocabulary::load(&config).unwrap();
+//!
+//! let (text, corrections) = vocabulary.correct("Take METF0RMIN with part XJ-441O");
+//! assert_eq!(text, "Take METFORMIN with part XJ-4410");
+//! assert_eq!(corrections, 2);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use crate::core::config::{OcrConfig, OcrVocabularyConfig};
+use crate::types::TesseractConfig;
+use crate::{KreuzbergError, Result};
+
+/// Vocabulary words, indexed for correcting OCR output.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    /// Words in their configured spelling, without case-insensitive duplicates
+    words: Vec<String>,
+    /// Lowercased words
+    known: HashSet<String>,
+    /// Lowercased words as characters, with their index in `words`, by character count
+    by_length: HashMap<usize, Vec<(Vec<char>, usize)>>,
+    max_distance: usize,
+    min_word_length: usize,
+}
+
+impl Vocabulary {
+    /// Collect the words of `config.words` and `config.words_file`.
+    ///
+    /// Entries are split on whitespace, so each vocabulary word is a single OCR word.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if `words_file` cannot be read.
+    pub fn load(config: &OcrVocabularyConfig) -> Result<Self> {
+        let mut entries = config.words.clone();
+        if let Some(path) = &config.words_file {
+            entries.extend(read_word_lines(path, "words_file")?);
+        }
+
+        let mut vocabulary = Self {
+            words: Vec::new(),
+            known: HashSet::new(),
+            by_length: HashMap::new(),
+            max_distance: config.max_distance,
+            min_word_length: config.min_word_length,
+        };
+        for word in entries.iter().flat_map(|entry| entry.split_whitespace()) {
+            let lower = word.to_lowercase();
+            if vocabulary.known.insert(lower.clone()) {
+                let chars: Vec<char> = lower.chars().collect();
+                vocabulary
+                    .by_length
+                    .entry(chars.len())
+                    .or_default()
+                    .push((chars, vocabulary.words.len()));
+                vocabulary.words.push(word.to_string());
+            }
+        }
+
+        Ok(vocabulary)
+    }
+
+    /// Vocabulary words in their configured spelling.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Whether the vocabulary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Replace OCR words that are near misses of a vocabulary word.
+    ///
+    /// Returns the corrected text and the number of words replaced. A replacement is
+    /// upper-cased when the OCR word is all capitals, and capitalized when the OCR word
+    /// is capitalized but the vocabulary word is not.
+    pub fn correct(&self, text: &str) -> (String, usize) {
+        let mut output = String::with_capacity(text.len());
+        let mut corrections = 0;
+        let mut copied = 0;
+
+        for (start, end) in word_spans(text) {
+            if let Some(replacement) = self.suggest(&text[start..end]) {
+                output.push_str(&text[copied..start]);
+                output.push_str(&match_case(&text[start..end], replacement));
+                copied = end;
+                corrections += 1;
+            }
+        }
+        output.push_str(&text[copied..]);
+
+        (output, corrections)
+    }
+
+    /// The single closest vocabulary word within `max_distance`, if `word` is not known.
+    fn suggest(&self, word: &str) -> Option<&str> {
+        let lower = word.to_lowercase();
+        if self.known.contains(&lower) {
+            return None;
+        }
+        let chars: Vec<char> = lower.chars().collect();
+        if chars.len() < self.min_word_length || !chars.iter().any(|c| c.is_alphabetic()) {
+            return None;
+        }
+
+        let mut best: Option<(usize, usize)> = None;
+        let mut ambiguous = false;
+        let lengths = chars.len().saturating_sub(self.max_distance)..=chars.len() + self.max_distance;
+        for (candidate, index) in lengths.filter_map(|length| self.by_length.get(&length)).flatten() {
+            let Some(distance) = edit_distance(&chars, candidate, self.max_distance) else {
+                continue;
+            };
+            match best {
+                Some((best_distance, _)) if distance > best_distance => {}
+                Some((best_distance, _)) if distance == best_distance => ambiguous = true,
+                _ => {
+                    best = Some((distance, *index));
+                    ambiguous = false;
+                }
+            }
+        }
+
+        match best {
+            Some((_, index)) if !ambiguous => Some(&self.words[index]),
+            _ => None,
+        }
+    }
+
+    /// OCR configuration with the vocabulary added to Tesseract's user words.
+    ///
+    /// Words from an existing `tesseract_config.user_words_file` are kept. Configurations
+    /// that use Tesseract neither as backend nor as fallback are returned unchanged.
+    pub(crate) fn apply_to(&self, config: &OcrConfig) -> Result<OcrConfig> {
+        let mut config = config.clone();
+        let uses_tesseract = config.backend == "tesseract"
+            || config
+                .fallback
+                .as_ref()
+                .is_some_and(|fallback| fallback.backend == "tesseract");
+        if self.is_empty() || !uses_tesseract {
+            return Ok(config);
+        }
+
+        let mut tesseract = config.tesseract_config.take().unwrap_or_else(|| TesseractConfig {
+            language: config.language.clone(),
+            ..Default::default()
+        });
+        let mut lines = self.words.clone();
+        if let Some(path) = &tesseract.user_words_file {
+            lines.extend(read_word_lines(path, "user_words_file")?);
+        }
+        tesseract.user_words_file = Some(write_user_words(&lines)?);
+        config.tesseract_config = Some(tesseract);
+
+        Ok(config)
+    }
+}
+
+/// Non-empty lines of a word list, without `#` comment lines.
+fn read_word_lines(path: &str, field: &str) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        KreuzbergError::validation(format!("Failed to read OCR vocabulary {} '{}': {}", field, path, e))
+    })?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Write a Tesseract user words file named after its contents, so equal vocabularies
+/// share one file (and one pooled engine).
+fn write_user_words(words: &[String]) -> Result<String> {
+    use std::hash::{Hash, Hasher};
+
+    let contents = words.join("\n") + "\n";
+    let mut hasher = ahash::AHasher::default();
+    contents.hash(&mut hasher);
+
+    let dir = std::env::temp_dir().join("kreuzberg-vocabulary");
+    let path: PathBuf = dir.join(format!("{:016x}.user-words", hasher.finish()));
+    if !path.is_file() {
+        std::fs::create_dir_all(&dir)?;
+        let partial = dir.join(format!("{:016x}.{}.tmp", hasher.finish(), std::process::id()));
+        std::fs::write(&partial, contents)?;
+        std::fs::rename(&partial, &path)?;
+    }
+
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Byte ranges of words: runs of alphanumeric characters, joined by single hyphens.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let joins_word = c == '-' && start.is_some() && chars.peek().is_some_and(|(_, next)| next.is_alphanumeric());
+        if c.is_alphanumeric() || joins_word {
+            start.get_or_insert(index);
+        } else if let Some(start) = start.take() {
+            spans.push((start, index));
+        }
+    }
+    if let Some(start) = start {
+        spans.push((start, text.len()));
+    }
+
+    spans
+}
+
+/// Levenshtein distance between `a` and `b`, or `None` if it exceeds `max`.
+fn edit_distance(a: &[char], b: &[char], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        let mut row_min = current[0];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            row_min = row_min.min(current[j + 1]);
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[b.len()]).filter(|distance| *distance <= max)
+}
+
+fn match_case(word: &str, replacement: &str) -> String {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic()).peekable();
+    let all_caps = letters.clone().count() > 1 && letters.all(char::is_uppercase);
+    if all_caps {
+        return replacement.to_uppercase();
+    }
+
+    let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+    let mut chars = replacement.chars();
+    match chars.next() {
+        Some(first) if capitalized && first.is_lowercase() => first.to_uppercase().chain(chars).collect(),
+        _ => replacement.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocabulary(words: &[&str]) -> Vocabulary {
+        Vocabulary::load(&OcrVocabularyConfig {
+            words: words.iter().map(|word| word.to_string()).collect(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_correct_replaces_near_misses() {
+        let vocabulary = vocabulary(&["metformin", "Lisinopril", "XJ-4410"]);
+
+        let (text, corrections) = vocabulary.correct("Metfornin 500mg, lisinopri1 and XJ-441O.");
+        assert_eq!(text, "Metformin 500mg, Lisinopril and XJ-4410.");
+        assert_eq!(corrections, 3);
+    }
+
+    #[test]
+    fn test_correct_leaves_known_short_numeric_and_distant_words() {
+        let vocabulary = vocabulary(&["metformin", "dose"]);
+
+        let (text, corrections) = vocabulary.correct("METFORMIN dosa 2024 the metfxxmin");
+        assert_eq!(text, "METFORMIN dose 2024 the metfxxmin");
+        assert_eq!(corrections, 1);
+
+        let (text, corrections) = vocabulary.correct("dos 1234");
+        assert_eq!(text, "dos 1234");
+        assert_eq!(corrections, 0);
+    }
+
+    #[test]
+    fn test_correct_skips_ambiguous_words() {
+        let vocabulary = vocabulary(&["part", "port"]);
+
+        let (text, corrections) = vocabulary.correct("pert");
+        assert_eq!(text, "pert");
+        assert_eq!(corrections, 0);
+    }
+
+    #[test]
+    fn test_load_reads_words_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("drugs.txt");
+        std::fs::write(&path, "# formulary\nAmoxicillin\n\nIbuprofen Naproxen\n").unwrap();
+
+        let vocabulary = Vocabulary::load(&OcrVocabularyConfig {
+            words: vec!["ibuprofen".to_string()],
+            words_file: Some(path.to_string_lossy().into_owned()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(vocabulary.words(), ["ibuprofen", "Amoxicillin", "Naproxen"]);
+
+        let missing = Vocabulary::load(&OcrVocabularyConfig {
+            words_file: Some("/nonexistent/words.txt".to_string()),
+            ..Default::default()
+        });
+        assert!(missing.unwrap_err().to_string().contains("words_file"));
+    }
+
+    #[test]
+    fn test_apply_to_writes_tesseract_user_words() {
+        let config = OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "deu".to_string(),
+            tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
+        };
+
+        let applied = vocabulary(&["Ibuprofen", "Naproxen"]).apply_to(&config).unwrap();
+        let tesseract = applied.tesseract_config.unwrap();
+        assert_eq!(tesseract.language, "deu");
+        let words = std::fs::read_to_string(tesseract.user_words_file.unwrap()).unwrap();
+        assert_eq!(words, "Ibuprofen\nNaproxen\n");
+
+        let other_backend = OcrConfig {
+            backend: "paddleocr".to_string(),
+            ..config
+        };
+        let applied = vocabulary(&["Ibuprofen"]).apply_to(&other_backend).unwrap();
+        assert!(applied.tesseract_config.is_none());
+    }
+
+    #[test]
+    fn test_edit_distance_is_bounded() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(edit_distance(&chars("kitten"), &chars("sitting"), 3), Some(3));
+        assert_eq!(edit_distance(&chars("kitten"), &chars("sitting"), 2), None);
+        assert_eq!(edit_distance(&chars("abc"), &chars("abc"), 0), Some(0));
+    }
+}
@@ -1,6 +1,7 @@
 use super::bindings::bind_pdfium;
 use super::error::{PdfError, Result};
-use crate::types::{PageBoundary, PageInfo, PageStructure, PageUnitType};
+use crate::text::outline::Heading;
+use crate::types::{KeyValuePair, PageBoundary, PageInfo, PageStructure, PageUnitType, Watermark, XmpMetadata};
 use pdfium_render::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -73,6 +74,18 @@ pub struct PdfExtractionMetadata {
     /// Page structure with boundaries and optional per-page metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_structure: Option<PageStructure>,
+
+    /// Bookmarks (document outline) in document order, used to build `Metadata::outline`
+    #[serde(skip)]
+    pub bookmarks: Vec<Heading>,
+
+    /// Label/value pairs found from word positions, used to build `Metadata::key_values`
+    #[serde(skip)]
+    pub key_values: Vec<KeyValuePair>,
+
+    /// Diagonal text runs found in the text layer, used to build `Metadata::watermarks`
+    #[serde(skip)]
+    pub watermarks: Vec<Watermark>,
 }
 
 /// Extract PDF-specific metadata from raw bytes.
@@ -171,6 +184,9 @@ pub(crate) fn extract_metadata_from_document_impl(
         created_by: common.created_by,
         pdf_specific,
         page_structure,
+        bookmarks: extract_bookmarks(document),
+        key_values: Vec::new(),
+        watermarks: Vec::new(),
     })
 }
 
@@ -205,6 +221,55 @@ fn extract_pdf_specific_metadata(document: &PdfDocument<'_>) -> Result<PdfMetada
     Ok(metadata)
 }
 
+/// Upper bound on bookmarks read, guarding against cyclic outline trees in malformed PDFs.
+const MAX_BOOKMARKS: usize = 10_000;
+
+/// Flatten the bookmark tree into document-ordered headings.
+///
+/// Nesting depth becomes the heading level and the destination page (when the bookmark
+/// points at one) becomes the heading page. Bookmarks without a title are skipped.
+pub(crate) fn extract_bookmarks(document: &PdfDocument<'_>) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut pending = Vec::new();
+    if let Some(root) = document.bookmarks().root() {
+        pending.push((root, 1u8));
+    }
+
+    let mut visited = 0;
+    while let Some((bookmark, level)) = pending.pop() {
+        visited += 1;
+        if visited > MAX_BOOKMARKS {
+            break;
+        }
+
+        // Depth-first pre-order: the sibling is resumed after this bookmark's children.
+        if let Some(sibling) = bookmark.next_sibling() {
+            pending.push((sibling, level));
+        }
+        if let Some(child) = bookmark.first_child() {
+            pending.push((child, level.saturating_add(1)));
+        }
+
+        let Some(title) = bookmark.title().map(|title| title.trim().to_string()) else {
+            continue;
+        };
+        if title.is_empty() {
+            continue;
+        }
+        let page = bookmark
+            .destination()
+            .and_then(|destination| destination.page_index().ok())
+            .map(|index| index as usize + 1);
+
+        headings.push(Heading {
+            page,
+            ..Heading::new(level, title)
+        });
+    }
+
+    headings
+}
+
 /// Build a PageStructure from a document and page boundaries.
 ///
 /// Constructs a complete PageStructure including:
@@ -396,6 +461,33 @@ fn parse_pdf_date(date_str: &str) -> String {
     }
 }
 
+/// Extract XMP metadata from the document-level metadata stream.
+///
+/// PDF writers are asked to store XMP uncompressed, so a single packet found by scanning
+/// the raw bytes is used directly. When the file holds no packet in plain form or several
+/// (embedded images and fonts carry their own), the catalog `/Metadata` stream is resolved
+/// and decoded instead.
+pub fn extract_xmp_metadata(pdf_bytes: &[u8]) -> Option<XmpMetadata> {
+    use crate::extraction::xmp::{find_xmp_packets, parse_xmp_packet};
+
+    let packets = find_xmp_packets(pdf_bytes);
+    if let [packet] = packets.as_slice() {
+        return parse_xmp_packet(packet);
+    }
+
+    catalog_xmp_packet(pdf_bytes)
+        .and_then(|packet| parse_xmp_packet(&packet))
+        .or_else(|| packets.iter().rev().find_map(|packet| parse_xmp_packet(packet)))
+}
+
+fn catalog_xmp_packet(pdf_bytes: &[u8]) -> Option<String> {
+    let document = lopdf::Document::load_mem(pdf_bytes).ok()?;
+    let metadata_id = document.catalog().ok()?.get(b"Metadata").ok()?.as_reference().ok()?;
+    let stream = document.get_object(metadata_id).ok()?.as_stream().ok()?;
+    let data = stream.decompressed_content().unwrap_or_else(|_| stream.content.clone());
+    Some(String::from_utf8_lossy(&data).into_owned())
+}
+
 fn format_pdf_version(version: PdfDocumentVersion) -> Option<String> {
     match version {
         PdfDocumentVersion::Unset => None,
@@ -482,6 +574,40 @@ mod tests {
         assert_eq!(date, "2023-01-15T00:00:00Z");
     }
 
+    #[test]
+    fn test_extract_xmp_metadata_from_compressed_catalog_stream() {
+        use lopdf::{Document, Object, Stream, dictionary};
+
+        let packet = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:pdf="http://ns.adobe.com/pdf/1.3/">
+      <dc:title><rdf:Alt><rdf:li xml:lang="x-default">Archived Minutes</rdf:li></rdf:Alt></dc:title>
+      <pdf:Producer>Digitization Line 3</pdf:Producer>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        let mut document = Document::with_version("1.7");
+        let mut stream = Stream::new(
+            dictionary! { "Type" => "Metadata", "Subtype" => "XML" },
+            packet.as_bytes().to_vec(),
+        );
+        stream.compress().unwrap();
+        let metadata_id = document.add_object(stream);
+        let pages_id =
+            document.add_object(dictionary! { "Type" => "Pages", "Kids" => Vec::<Object>::new(), "Count" => 0i64 });
+        let catalog_id =
+            document.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id, "Metadata" => metadata_id });
+        document.trailer.set("Root", catalog_id);
+        let mut pdf = Vec::new();
+        document.save_to(&mut pdf).unwrap();
+
+        assert!(crate::extraction::xmp::find_xmp_packets(&pdf).is_empty());
+        let xmp = extract_xmp_metadata(&pdf).expect("catalog metadata stream should be parsed");
+        assert_eq!(xmp.title.as_deref(), Some("Archived Minutes"));
+        assert_eq!(xmp.producer.as_deref(), Some("Digitization Line 3"));
+    }
+
     #[test]
     fn test_extract_metadata_invalid_pdf() {
         let result = extract_metadata(b"not a pdf");
@@ -311,6 +311,7 @@ fn extract_text_lazy_with_tracking(document: &PdfDocument<'_>, config: &PageConf
                 content: page_text_ref.to_owned(),
                 tables: Vec::new(),
                 images: Vec::new(),
+                content_blob: None,
             });
         }
 
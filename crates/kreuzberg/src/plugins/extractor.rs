@@ -7,12 +7,54 @@ use crate::core::config::ExtractionConfig;
 use crate::plugins::Plugin;
 use crate::types::ExtractionResult;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::sync::Arc;
 
 #[cfg(not(feature = "tokio-runtime"))]
 use crate::KreuzbergError;
 
+/// Output an extractor can produce for the MIME types it supports.
+///
+/// Used to describe supported formats to applications (upload validators, UI hints);
+/// extraction itself never consults these flags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractorCapabilities {
+    /// Extracts tables into `ExtractionResult::tables`
+    pub tables: bool,
+    /// Extracts embedded images when image extraction is configured
+    pub images: bool,
+    /// Extracts document metadata such as title, authors or dimensions
+    pub metadata: bool,
+    /// Splits content into pages when page extraction is configured
+    pub pages: bool,
+    /// May need OCR to produce text (images, scanned PDFs)
+    pub ocr: bool,
+}
+
+impl ExtractorCapabilities {
+    /// Capabilities of an extractor that only extracts text and metadata.
+    pub const METADATA: Self = Self {
+        tables: false,
+        images: false,
+        metadata: true,
+        pages: false,
+        ocr: false,
+    };
+}
+
+/// A MIME type the extractor registry can handle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupportedFormat {
+    /// MIME type, possibly a prefix pattern such as `image/*`
+    pub mime_type: String,
+    /// Name of the highest-priority extractor for the MIME type
+    pub extractor: String,
+    /// What the extractor can produce
+    #[serde(flatten)]
+    pub capabilities: ExtractorCapabilities,
+}
+
 /// Trait for document extractor plugins.
 ///
 /// Implement this trait to add support for new document formats or to override
@@ -65,6 +107,10 @@ use crate::KreuzbergError;
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             term_offsets: None,
+///             barcodes: None,
+///             provenance: None,
+///             schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
 ///         })
 ///     }
 ///
@@ -144,6 +190,10 @@ pub trait DocumentExtractor: Plugin {
     ///         chunks: None,
     ///         images: None,
     ///         pages: None,
+    ///         term_offsets: None,
+    ///         barcodes: None,
+    ///         provenance: None,
+    ///         schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
     ///     })
     /// }
     /// # }
@@ -215,6 +265,10 @@ pub trait DocumentExtractor: Plugin {
     ///         chunks: None,
     ///         images: None,
     ///         pages: None,
+    ///         term_offsets: None,
+    ///         barcodes: None,
+    ///         provenance: None,
+    ///         schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
     ///     })
     /// }
     /// # }
@@ -326,6 +380,19 @@ pub trait DocumentExtractor: Plugin {
         50
     }
 
+    /// Get the output this extractor can produce.
+    ///
+    /// Reported by [`supported_formats`] so applications can tell which formats yield
+    /// tables, images, metadata or pages, and which may need OCR. Override when the
+    /// extractor produces more than text and metadata.
+    ///
+    /// # Returns
+    ///
+    /// Capability flags (default: [`ExtractorCapabilities::METADATA`])
+    fn capabilities(&self) -> ExtractorCapabilities {
+        ExtractorCapabilities::METADATA
+    }
+
     /// Optional: Check if this extractor can handle a specific file.
     ///
     /// Allows for more sophisticated detection beyond MIME types.
@@ -439,6 +506,10 @@ pub trait DocumentExtractor: Plugin {
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             term_offsets: None,
+///             barcodes: None,
+///             provenance: None,
+///             schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
 ///         })
 ///     }
 ///
@@ -456,12 +527,7 @@ pub trait DocumentExtractor: Plugin {
 pub fn register_extractor(extractor: Arc<dyn DocumentExtractor>) -> crate::Result<()> {
     use crate::plugins::registry::get_document_extractor_registry;
 
-    let registry = get_document_extractor_registry();
-    let mut registry = registry
-        .write()
-        .expect("~keep Failed to acquire write lock on extractor registry"); // ~keep
-
-    registry.register(extractor)
+    get_document_extractor_registry().update(|registry| registry.register(extractor))
 }
 
 /// Unregister a document extractor by name.
@@ -490,12 +556,7 @@ pub fn register_extractor(extractor: Arc<dyn DocumentExtractor>) -> crate::Resul
 pub fn unregister_extractor(name: &str) -> crate::Result<()> {
     use crate::plugins::registry::get_document_extractor_registry;
 
-    let registry = get_document_extractor_registry();
-    let mut registry = registry
-        .write()
-        .expect("~keep Failed to acquire write lock on extractor registry"); // ~keep
-
-    registry.remove(name)
+    get_document_extractor_registry().update(|registry| registry.remove(name))
 }
 
 /// List all registered extractors.
@@ -522,14 +583,42 @@ pub fn unregister_extractor(name: &str) -> crate::Result<()> {
 pub fn list_extractors() -> crate::Result<Vec<String>> {
     use crate::plugins::registry::get_document_extractor_registry;
 
-    let registry = get_document_extractor_registry();
-    let registry = registry
-        .read()
-        .expect("~keep Failed to acquire read lock on extractor registry"); // ~keep
+    let registry = get_document_extractor_registry().snapshot();
 
     Ok(registry.list())
 }
 
+/// List every MIME type the registered extractors support, with capability flags.
+///
+/// Each MIME type is reported once, with the capabilities of the extractor the registry
+/// would select for it (the highest priority one). Built-in extractors are registered
+/// first if no extractor is registered yet.
+///
+/// # Returns
+///
+/// Supported formats sorted by MIME type.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::supported_formats;
+///
+/// let formats = supported_formats()?;
+/// for format in formats.iter().filter(|format| format.capabilities.tables) {
+///     println!("{} ({}) extracts tables", format.mime_type, format.extractor);
+/// }
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+pub fn supported_formats() -> crate::Result<Vec<SupportedFormat>> {
+    use crate::plugins::registry::get_document_extractor_registry;
+
+    crate::extractors::ensure_initialized()?;
+
+    let registry = get_document_extractor_registry().snapshot();
+
+    Ok(registry.supported_formats())
+}
+
 /// Clear all extractors from the global registry.
 ///
 /// Removes all extractors and calls their `shutdown()` methods.
@@ -552,12 +641,7 @@ pub fn list_extractors() -> crate::Result<Vec<String>> {
 pub fn clear_extractors() -> crate::Result<()> {
     use crate::plugins::registry::get_document_extractor_registry;
 
-    let registry = get_document_extractor_registry();
-    let mut registry = registry
-        .write()
-        .expect("~keep Failed to acquire write lock on extractor registry"); // ~keep
-
-    registry.shutdown_all()
+    get_document_extractor_registry().update(|registry| registry.shutdown_all())
 }
 
 #[cfg(test)]
@@ -605,6 +689,10 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             })
         }
 
@@ -778,6 +866,10 @@ mod tests {
                     chunks: None,
                     images: None,
                     pages: None,
+                    term_offsets: None,
+                    barcodes: None,
+                    provenance: None,
+                    schema_version: ExtractionResult::SCHEMA_VERSION,
                 })
             }
 
@@ -983,6 +1075,10 @@ mod tests {
                     chunks: None,
                     images: None,
                     pages: None,
+                    term_offsets: None,
+                    barcodes: None,
+                    provenance: None,
+                    schema_version: ExtractionResult::SCHEMA_VERSION,
                 })
             }
 
@@ -1029,6 +1125,10 @@ mod tests {
                     chunks: None,
                     images: None,
                     pages: None,
+                    term_offsets: None,
+                    barcodes: None,
+                    provenance: None,
+                    schema_version: ExtractionResult::SCHEMA_VERSION,
                 })
             }
 
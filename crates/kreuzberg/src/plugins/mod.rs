@@ -48,6 +48,10 @@
 //! #             chunks: None,
 //! #             images: None,
 //! #             pages: None,
+//! #             term_offsets: None,
+//! #             barcodes: None,
+//! #             provenance: None,
+//! #             schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
 //! #         })
 //! #     }
 //! #     async fn extract_file(&self, _: &std::path::Path, _: &str, _: &kreuzberg::ExtractionConfig)
@@ -61,6 +65,10 @@
 //! #             chunks: None,
 //! #             images: None,
 //! #             pages: None,
+//! #             term_offsets: None,
+//! #             barcodes: None,
+//! #             provenance: None,
+//! #             schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
 //! #         })
 //! #     }
 //! #     fn supported_mime_types(&self) -> &[&str] { &[] }
@@ -73,9 +81,7 @@
 //! let plugin = Arc::new(plugin);
 //!
 //! // 3. Register with registry (calls initialize internally)
-//! let registry = get_document_extractor_registry();
-//! let mut registry = registry.write().unwrap();
-//! registry.register(plugin)?;
+//! get_document_extractor_registry().update(|registry| registry.register(plugin))?;
 //! # Ok::<(), kreuzberg::KreuzbergError>(())
 //! ```
 //!
@@ -123,6 +129,10 @@
 //!             chunks: None,
 //!             images: None,
 //!             pages: None,
+//!             term_offsets: None,
+//!             barcodes: None,
+//!             provenance: None,
+//!             schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
 //!         })
 //!     }
 //!
@@ -203,7 +213,10 @@ pub mod registry;
 mod traits;
 mod validator;
 
-pub use extractor::{DocumentExtractor, clear_extractors, list_extractors, register_extractor, unregister_extractor};
+pub use extractor::{
+    DocumentExtractor, ExtractorCapabilities, SupportedFormat, clear_extractors, list_extractors, register_extractor,
+    supported_formats, unregister_extractor,
+};
 pub use ocr::{
     OcrBackend, OcrBackendType, clear_ocr_backends, list_ocr_backends, register_ocr_backend, unregister_ocr_backend,
 };
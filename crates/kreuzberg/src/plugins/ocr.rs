@@ -68,6 +68,10 @@ pub enum OcrBackendType {
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             term_offsets: None,
+///             barcodes: None,
+///             provenance: None,
+///             schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
 ///         })
 ///     }
 ///
@@ -147,6 +151,10 @@ pub trait OcrBackend: Plugin {
     ///         chunks: None,
     ///         images: None,
     ///         pages: None,
+    ///         term_offsets: None,
+    ///         barcodes: None,
+    ///         provenance: None,
+    ///         schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
     ///     })
     /// }
     /// # }
@@ -320,6 +328,10 @@ pub trait OcrBackend: Plugin {
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             term_offsets: None,
+///             barcodes: None,
+///             provenance: None,
+///             schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
 ///         })
 ///     }
 ///     fn supports_language(&self, _: &str) -> bool { true }
@@ -335,13 +347,7 @@ pub trait OcrBackend: Plugin {
 pub fn register_ocr_backend(backend: Arc<dyn OcrBackend>) -> crate::Result<()> {
     use crate::plugins::registry::get_ocr_backend_registry;
 
-    let registry = get_ocr_backend_registry();
-    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-    let mut registry = registry
-        .write()
-        .expect("OCR backend registry lock poisoned - critical runtime error");
-
-    registry.register(backend)
+    get_ocr_backend_registry().update(|registry| registry.register(backend))
 }
 
 /// Unregister an OCR backend by name.
@@ -370,13 +376,7 @@ pub fn register_ocr_backend(backend: Arc<dyn OcrBackend>) -> crate::Result<()> {
 pub fn unregister_ocr_backend(name: &str) -> crate::Result<()> {
     use crate::plugins::registry::get_ocr_backend_registry;
 
-    let registry = get_ocr_backend_registry();
-    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-    let mut registry = registry
-        .write()
-        .expect("OCR backend registry lock poisoned - critical runtime error");
-
-    registry.remove(name)
+    get_ocr_backend_registry().update(|registry| registry.remove(name))
 }
 
 /// List all registered OCR backends.
@@ -403,11 +403,7 @@ pub fn unregister_ocr_backend(name: &str) -> crate::Result<()> {
 pub fn list_ocr_backends() -> crate::Result<Vec<String>> {
     use crate::plugins::registry::get_ocr_backend_registry;
 
-    let registry = get_ocr_backend_registry();
-    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-    let registry = registry
-        .read()
-        .expect("OCR backend registry lock poisoned - critical runtime error");
+    let registry = get_ocr_backend_registry().snapshot();
 
     Ok(registry.list())
 }
@@ -434,13 +430,7 @@ pub fn list_ocr_backends() -> crate::Result<Vec<String>> {
 pub fn clear_ocr_backends() -> crate::Result<()> {
     use crate::plugins::registry::get_ocr_backend_registry;
 
-    let registry = get_ocr_backend_registry();
-    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
-    let mut registry = registry
-        .write()
-        .expect("OCR backend registry lock poisoned - critical runtime error");
-
-    registry.shutdown_all()
+    get_ocr_backend_registry().update(|registry| registry.shutdown_all())
 }
 
 #[cfg(test)]
@@ -481,6 +471,10 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             })
         }
 
@@ -507,6 +501,8 @@ mod tests {
             backend: "mock".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         };
 
         let result = backend.process_image(b"fake image data", &config).await.unwrap();
@@ -594,6 +590,8 @@ mod tests {
             backend: "mock".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         };
 
         let result = backend.process_file(path, &config).await.unwrap();
@@ -631,6 +629,8 @@ mod tests {
             backend: "mock".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         };
 
         let result = backend.process_image(b"", &config).await;
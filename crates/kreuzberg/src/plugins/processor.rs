@@ -10,7 +10,7 @@ use async_trait::async_trait;
 
 /// Processing stages for post-processors.
 ///
-/// Post-processors are executed in stage order (Early → Middle → Late).
+/// Post-processors are executed in stage order (Early → Middle → Late → Chunk).
 /// Use stages to control the order of post-processing operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ProcessingStage {
@@ -40,6 +40,15 @@ pub enum ProcessingStage {
     /// - Final validation
     /// - Output formatting
     Late,
+
+    /// Chunk stage - per-chunk enrichment after chunking.
+    ///
+    /// Runs only when chunking produced `result.chunks`, before chunk embeddings are
+    /// generated. Use for:
+    /// - Adding section titles to chunks
+    /// - Classification labels in `chunk.metadata.annotations`
+    /// - Rewriting chunk text before it is embedded
+    Chunk,
 }
 
 /// Trait for post-processor plugins.
@@ -58,6 +67,7 @@ pub enum ProcessingStage {
 /// 1. **Early** - Language detection, entity extraction
 /// 2. **Middle** - Keyword extraction, token reduction
 /// 3. **Late** - Custom hooks, final validation
+/// 4. **Chunk** - Per-chunk enrichment, after chunking and before embeddings
 ///
 /// Within each stage, processors are executed in registration order.
 ///
@@ -201,7 +211,7 @@ pub trait PostProcessor: Plugin {
     ///
     /// # Returns
     ///
-    /// The `ProcessingStage` (Early, Middle, or Late).
+    /// The `ProcessingStage` (Early, Middle, Late, or Chunk).
     ///
     /// # Example
     ///
@@ -291,7 +301,6 @@ pub trait PostProcessor: Plugin {
 /// # Returns
 ///
 /// - `Ok(Vec<String>)` - Vector of post-processor names
-/// - `Err(...)` if the registry lock is poisoned
 ///
 /// # Example
 ///
@@ -309,10 +318,7 @@ pub trait PostProcessor: Plugin {
 pub fn list_post_processors() -> crate::Result<Vec<String>> {
     use crate::plugins::registry::get_post_processor_registry;
 
-    let registry = get_post_processor_registry();
-    let registry = registry
-        .read()
-        .expect("~keep Failed to acquire read lock on post-processor registry"); // ~keep
+    let registry = get_post_processor_registry().snapshot();
 
     Ok(registry.list())
 }
@@ -374,6 +380,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -390,6 +400,7 @@ mod tests {
     fn test_processing_stage_order() {
         assert!(ProcessingStage::Early < ProcessingStage::Middle);
         assert!(ProcessingStage::Middle < ProcessingStage::Late);
+        assert!(ProcessingStage::Late < ProcessingStage::Chunk);
     }
 
     #[test]
@@ -424,6 +435,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -491,6 +506,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -521,6 +540,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -548,6 +571,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         assert_eq!(processor.estimated_duration_ms(&result), 0);
@@ -599,6 +626,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let txt_result = ExtractionResult {
@@ -610,6 +641,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         assert!(processor.should_process(&pdf_result, &config));
@@ -628,6 +663,7 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 0,
+            column_types: None,
         };
 
         let mut result = ExtractionResult {
@@ -639,6 +675,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -3,13 +3,21 @@
 //! This module provides registries for managing plugins of different types.
 //! Each plugin type (OcrBackend, DocumentExtractor, etc.) has its own registry
 //! with type-safe registration and lookup.
+//!
+//! The global registries are [`PluginRegistry`] handles holding an immutable snapshot that
+//! is swapped atomically on every change. Extraction reads a snapshot without taking a lock,
+//! so it never waits for a registration in progress, and a plugin panicking during
+//! registration leaves the previous snapshot in place instead of poisoning the registry.
 
-use crate::plugins::{DocumentExtractor, OcrBackend, PostProcessor, ProcessingStage, Validator};
+use crate::plugins::{DocumentExtractor, OcrBackend, PostProcessor, ProcessingStage, SupportedFormat, Validator};
 use crate::{KreuzbergError, Result};
+use arc_swap::ArcSwap;
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
+use parking_lot::{Mutex, MutexGuard};
 use std::collections::{BTreeMap, HashMap};
-use std::sync::{Arc, RwLock};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 /// Validate a plugin name before registration.
 ///
@@ -58,6 +66,7 @@ fn validate_plugin_name(name: &str) -> Result<()> {
 /// // Register OCR backends
 /// // registry.register(Arc::new(TesseractBackend::new()));
 /// ```
+#[derive(Clone)]
 pub struct OcrBackendRegistry {
     backends: HashMap<String, Arc<dyn OcrBackend>>,
 }
@@ -205,6 +214,7 @@ impl Default for OcrBackendRegistry {
 /// # Thread Safety
 ///
 /// The registry is thread-safe and can be accessed concurrently from multiple threads.
+#[derive(Clone)]
 pub struct DocumentExtractorRegistry {
     extractors: HashMap<String, BTreeMap<i32, Arc<dyn DocumentExtractor>>>,
     name_index: HashMap<String, Vec<(String, i32)>>,
@@ -317,6 +327,24 @@ impl DocumentExtractorRegistry {
         self.name_index.keys().cloned().collect()
     }
 
+    /// List every registered MIME type with the capabilities of its highest priority extractor.
+    pub fn supported_formats(&self) -> Vec<SupportedFormat> {
+        let mut formats: Vec<SupportedFormat> = self
+            .extractors
+            .iter()
+            .filter_map(|(mime_type, priority_map)| {
+                let (_priority, extractor) = priority_map.iter().next_back()?;
+                Some(SupportedFormat {
+                    mime_type: mime_type.clone(),
+                    extractor: extractor.name().to_string(),
+                    capabilities: extractor.capabilities(),
+                })
+            })
+            .collect();
+        formats.sort_by(|a, b| a.mime_type.cmp(&b.mime_type));
+        formats
+    }
+
     /// Remove an extractor from the registry.
     pub fn remove(&mut self, name: &str) -> Result<()> {
         let index_entries = match self.name_index.remove(name) {
@@ -366,6 +394,7 @@ impl Default for DocumentExtractorRegistry {
 /// Registry for post-processor plugins.
 ///
 /// Manages post-processors organized by processing stage.
+#[derive(Clone)]
 pub struct PostProcessorRegistry {
     processors: HashMap<ProcessingStage, BTreeMap<i32, Vec<Arc<dyn PostProcessor>>>>,
     name_index: HashMap<String, (ProcessingStage, i32)>,
@@ -493,6 +522,7 @@ impl Default for PostProcessorRegistry {
 /// Registry for validator plugins.
 ///
 /// Manages validators with priority-based execution order.
+#[derive(Clone)]
 pub struct ValidatorRegistry {
     validators: BTreeMap<i32, IndexMap<String, Arc<dyn Validator>>>,
 }
@@ -586,39 +616,140 @@ impl Default for ValidatorRegistry {
     }
 }
 
+/// Copy-on-write handle to a plugin registry.
+///
+/// Readers take a [`snapshot`](Self::snapshot) of the current registry without locking.
+/// Writers go through [`update`](Self::update), which applies the change to a copy and
+/// publishes it only if the change succeeds; concurrent writers are serialized, readers
+/// holding an older snapshot keep using it until they drop it.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::KreuzbergError;
+/// use kreuzberg::plugins::registry::{PluginRegistry, ValidatorRegistry};
+/// use std::sync::Arc;
+///
+/// let registry = PluginRegistry::new(ValidatorRegistry::new());
+/// let before = registry.snapshot();
+///
+/// let rejected: kreuzberg::Result<()> = registry.update(|_| Err(KreuzbergError::validation("rejected")));
+/// assert!(rejected.is_err());
+/// assert!(Arc::ptr_eq(&before, &registry.snapshot()));
+/// ```
+pub struct PluginRegistry<R> {
+    current: ArcSwap<R>,
+    writer: Mutex<()>,
+}
+
+impl<R: Clone> PluginRegistry<R> {
+    /// Create a handle publishing `registry` as the first snapshot.
+    pub fn new(registry: R) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(registry),
+            writer: Mutex::new(()),
+        }
+    }
+
+    /// The current registry.
+    ///
+    /// The snapshot does not see changes made after it was taken, so take a new one per
+    /// lookup rather than caching it.
+    pub fn snapshot(&self) -> Arc<R> {
+        self.current.load_full()
+    }
+
+    /// Apply `change` to a copy of the registry and publish the copy if it returns `Ok`.
+    ///
+    /// On error (or panic) the current registry is left untouched. Plugin lifecycle hooks
+    /// called by `change` (`initialize`, `shutdown`) run before the copy is published.
+    pub fn update<T>(&self, change: impl FnOnce(&mut R) -> Result<T>) -> Result<T> {
+        let _writer = self.writer.lock();
+        let mut next = R::clone(&self.snapshot());
+        let value = change(&mut next)?;
+        self.current.store(Arc::new(next));
+        Ok(value)
+    }
+
+    /// Start a multi-step change to a copy of the registry, published when the guard drops.
+    ///
+    /// Unlike [`update`](Self::update), every step that succeeded is published even if a
+    /// later one failed; only a panic discards the copy. Other writers wait until the guard
+    /// is dropped, readers do not.
+    pub fn edit(&self) -> RegistryEdit<'_, R> {
+        let writer = self.writer.lock();
+        RegistryEdit {
+            next: Some(R::clone(&self.snapshot())),
+            registry: self,
+            _writer: writer,
+        }
+    }
+}
+
+/// Pending change to a [`PluginRegistry`], see [`PluginRegistry::edit`].
+pub struct RegistryEdit<'a, R: Clone> {
+    next: Option<R>,
+    registry: &'a PluginRegistry<R>,
+    _writer: MutexGuard<'a, ()>,
+}
+
+impl<R: Clone> Deref for RegistryEdit<'_, R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        self.next.as_ref().expect("registry edit is only emptied on drop")
+    }
+}
+
+impl<R: Clone> DerefMut for RegistryEdit<'_, R> {
+    fn deref_mut(&mut self) -> &mut R {
+        self.next.as_mut().expect("registry edit is only emptied on drop")
+    }
+}
+
+impl<R: Clone> Drop for RegistryEdit<'_, R> {
+    fn drop(&mut self) {
+        if let Some(next) = self.next.take()
+            && !std::thread::panicking()
+        {
+            self.registry.current.store(Arc::new(next));
+        }
+    }
+}
+
 /// Global OCR backend registry singleton.
-pub static OCR_BACKEND_REGISTRY: Lazy<Arc<RwLock<OcrBackendRegistry>>> =
-    Lazy::new(|| Arc::new(RwLock::new(OcrBackendRegistry::new())));
+pub static OCR_BACKEND_REGISTRY: Lazy<Arc<PluginRegistry<OcrBackendRegistry>>> =
+    Lazy::new(|| Arc::new(PluginRegistry::new(OcrBackendRegistry::new())));
 
 /// Global document extractor registry singleton.
-pub static DOCUMENT_EXTRACTOR_REGISTRY: Lazy<Arc<RwLock<DocumentExtractorRegistry>>> =
-    Lazy::new(|| Arc::new(RwLock::new(DocumentExtractorRegistry::new())));
+pub static DOCUMENT_EXTRACTOR_REGISTRY: Lazy<Arc<PluginRegistry<DocumentExtractorRegistry>>> =
+    Lazy::new(|| Arc::new(PluginRegistry::new(DocumentExtractorRegistry::new())));
 
 /// Global post-processor registry singleton.
-pub static POST_PROCESSOR_REGISTRY: Lazy<Arc<RwLock<PostProcessorRegistry>>> =
-    Lazy::new(|| Arc::new(RwLock::new(PostProcessorRegistry::new())));
+pub static POST_PROCESSOR_REGISTRY: Lazy<Arc<PluginRegistry<PostProcessorRegistry>>> =
+    Lazy::new(|| Arc::new(PluginRegistry::new(PostProcessorRegistry::new())));
 
 /// Global validator registry singleton.
-pub static VALIDATOR_REGISTRY: Lazy<Arc<RwLock<ValidatorRegistry>>> =
-    Lazy::new(|| Arc::new(RwLock::new(ValidatorRegistry::new())));
+pub static VALIDATOR_REGISTRY: Lazy<Arc<PluginRegistry<ValidatorRegistry>>> =
+    Lazy::new(|| Arc::new(PluginRegistry::new(ValidatorRegistry::new())));
 
 /// Get the global OCR backend registry.
-pub fn get_ocr_backend_registry() -> Arc<RwLock<OcrBackendRegistry>> {
+pub fn get_ocr_backend_registry() -> Arc<PluginRegistry<OcrBackendRegistry>> {
     OCR_BACKEND_REGISTRY.clone()
 }
 
 /// Get the global document extractor registry.
-pub fn get_document_extractor_registry() -> Arc<RwLock<DocumentExtractorRegistry>> {
+pub fn get_document_extractor_registry() -> Arc<PluginRegistry<DocumentExtractorRegistry>> {
     DOCUMENT_EXTRACTOR_REGISTRY.clone()
 }
 
 /// Get the global post-processor registry.
-pub fn get_post_processor_registry() -> Arc<RwLock<PostProcessorRegistry>> {
+pub fn get_post_processor_registry() -> Arc<PluginRegistry<PostProcessorRegistry>> {
     POST_PROCESSOR_REGISTRY.clone()
 }
 
 /// Get the global validator registry.
-pub fn get_validator_registry() -> Arc<RwLock<ValidatorRegistry>> {
+pub fn get_validator_registry() -> Arc<PluginRegistry<ValidatorRegistry>> {
     VALIDATOR_REGISTRY.clone()
 }
 
@@ -662,6 +793,10 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             })
         }
 
@@ -707,6 +842,10 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             })
         }
 
@@ -916,6 +1055,41 @@ mod tests {
         assert_eq!(retrieved.name(), "high-priority-pdf");
     }
 
+    #[test]
+    fn test_document_extractor_registry_supported_formats() {
+        let mut registry = DocumentExtractorRegistry::new();
+
+        registry
+            .register(Arc::new(MockExtractor {
+                name: "low-priority-pdf".to_string(),
+                mime_types: &["application/pdf", "text/plain"],
+                priority: 10,
+            }))
+            .unwrap();
+        registry
+            .register(Arc::new(MockExtractor {
+                name: "high-priority-pdf".to_string(),
+                mime_types: &["application/pdf"],
+                priority: 100,
+            }))
+            .unwrap();
+
+        let formats = registry.supported_formats();
+        let summary: Vec<(&str, &str)> = formats
+            .iter()
+            .map(|format| (format.mime_type.as_str(), format.extractor.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("application/pdf", "high-priority-pdf"),
+                ("text/plain", "low-priority-pdf")
+            ]
+        );
+        assert!(formats.iter().all(|format| format.capabilities.metadata));
+        assert!(!formats[0].capabilities.tables);
+    }
+
     #[test]
     fn test_document_extractor_registry_not_found() {
         let registry = DocumentExtractorRegistry::new();
@@ -1032,29 +1206,37 @@ mod tests {
 
     #[test]
     fn test_global_registry_access() {
-        let ocr_registry = get_ocr_backend_registry();
-        let _ = ocr_registry
-            .read()
-            .expect("Failed to acquire read lock on OCR registry in test")
-            .list();
-
-        let extractor_registry = get_document_extractor_registry();
-        let _ = extractor_registry
-            .read()
-            .expect("Failed to acquire read lock on extractor registry in test")
-            .list();
-
-        let processor_registry = get_post_processor_registry();
-        let _ = processor_registry
-            .read()
-            .expect("Failed to acquire read lock on processor registry in test")
-            .list();
-
-        let validator_registry = get_validator_registry();
-        let _ = validator_registry
-            .read()
-            .expect("Failed to acquire read lock on validator registry in test")
-            .list();
+        let _ = get_ocr_backend_registry().snapshot().list();
+        let _ = get_document_extractor_registry().snapshot().list();
+        let _ = get_post_processor_registry().snapshot().list();
+        let _ = get_validator_registry().snapshot().list();
+    }
+
+    #[test]
+    fn test_plugin_registry_copy_on_write() {
+        let registry = PluginRegistry::new(ValidatorRegistry::new());
+        let before = registry.snapshot();
+
+        registry
+            .update(|validators| {
+                validators.register(Arc::new(MockValidator {
+                    name: "cow-validator".to_string(),
+                    priority: 10,
+                }))
+            })
+            .unwrap();
+
+        assert!(before.list().is_empty());
+        assert_eq!(registry.snapshot().list(), vec!["cow-validator".to_string()]);
+
+        let published = registry.snapshot();
+        let failed = registry.update(|validators| {
+            validators.remove("cow-validator")?;
+            Err::<(), _>(KreuzbergError::validation("rejected"))
+        });
+
+        assert!(failed.is_err());
+        assert!(Arc::ptr_eq(&published, &registry.snapshot()));
     }
 
     #[test]
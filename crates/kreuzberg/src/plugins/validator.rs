@@ -334,12 +334,7 @@ pub trait Validator: Plugin {
 pub fn register_validator(validator: Arc<dyn Validator>) -> crate::Result<()> {
     use crate::plugins::registry::get_validator_registry;
 
-    let registry = get_validator_registry();
-    let mut registry = registry
-        .write()
-        .expect("~keep Failed to acquire write lock on validator registry"); // ~keep
-
-    registry.register(validator)
+    get_validator_registry().update(|registry| registry.register(validator))
 }
 
 /// Unregister a validator by name.
@@ -368,12 +363,7 @@ pub fn register_validator(validator: Arc<dyn Validator>) -> crate::Result<()> {
 pub fn unregister_validator(name: &str) -> crate::Result<()> {
     use crate::plugins::registry::get_validator_registry;
 
-    let registry = get_validator_registry();
-    let mut registry = registry
-        .write()
-        .expect("~keep Failed to acquire write lock on validator registry"); // ~keep
-
-    registry.remove(name)
+    get_validator_registry().update(|registry| registry.remove(name))
 }
 
 /// List all registered validators.
@@ -400,10 +390,7 @@ pub fn unregister_validator(name: &str) -> crate::Result<()> {
 pub fn list_validators() -> crate::Result<Vec<String>> {
     use crate::plugins::registry::get_validator_registry;
 
-    let registry = get_validator_registry();
-    let registry = registry
-        .read()
-        .expect("~keep Failed to acquire read lock on validator registry"); // ~keep
+    let registry = get_validator_registry().snapshot();
 
     Ok(registry.list())
 }
@@ -430,12 +417,7 @@ pub fn list_validators() -> crate::Result<Vec<String>> {
 pub fn clear_validators() -> crate::Result<()> {
     use crate::plugins::registry::get_validator_registry;
 
-    let registry = get_validator_registry();
-    let mut registry = registry
-        .write()
-        .expect("~keep Failed to acquire write lock on validator registry"); // ~keep
-
-    registry.shutdown_all()
+    get_validator_registry().update(|registry| registry.shutdown_all())
 }
 
 #[cfg(test)]
@@ -490,6 +472,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -509,6 +495,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -530,6 +520,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -566,6 +560,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -614,6 +612,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let txt_result = ExtractionResult {
@@ -625,6 +627,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         assert!(validator.should_validate(&pdf_result, &config));
@@ -709,6 +715,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -741,6 +751,10 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -757,6 +771,7 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 0,
+            column_types: None,
         };
 
         let result = ExtractionResult {
@@ -768,6 +783,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -797,6 +816,10 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             };
 
             assert!(validator.validate(&result, &config).await.is_ok());
@@ -816,6 +839,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config = ExtractionConfig::default();
@@ -0,0 +1,643 @@
+//! Document type classification.
+//!
+//! Ingestion pipelines often route documents by type: invoices to accounting, contracts
+//! to legal review. With `classification` configured, the [`DocumentClassificationProcessor`]
+//! post-processor labels every document with a type and a confidence, using keyword and
+//! layout features instead of a machine learning model:
+//!
+//! - every keyword of a type found in the content scores 1
+//! - every title keyword found in the first lines scores 3
+//! - the built-in types add layout features: several amounts (`invoice`), numbered
+//!   articles or sections (`contract`), headings and page count (`report`), a salutation
+//!   and a closing (`letter`)
+//!
+//! A type's confidence is its share of all scores, scaled down while the score is low, so
+//! a document with a single matching keyword is not classified with full confidence. The
+//! result is stored under [`DOCUMENT_TYPE_METADATA_KEY`]:
+//!
+//! ```json
+//! {
+//!   "label": "invoice",
+//!   "confidence": 0.92,
+//!   "scores": {"contract": 0.0, "invoice": 0.92, "letter": 0.08, "report": 0.0}
+//! }
+//! ```
+//!
+//! The label is `unknown` when no type reaches `min_confidence`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::ClassificationConfig;
+//! use kreuzberg::text::classification::DocumentClassifier;
+//!
+//! let classifier = DocumentClassifier::new(&ClassificationConfig::default()).unwrap();
+//!
+//! let classification = classifier.classify(
+//!     "INVOICE\nInvoice number: 2024-117\nBill to: Acme GmbH\n\
+//!      Consulting 10 h x 95.00 = 950.00\nSubtotal 950.00\nVAT 180.50\nAmount due: 1,130.50\n\
+//!      Payment terms: 30 days",
+//! );
+//! assert_eq!(classification.label, "invoice");
+//! assert!(classification.confidence > 0.9);
+//! ```
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::core::config::{ClassificationConfig, DocumentTypeRule};
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::{ExtractionConfig, ExtractionResult, KreuzbergError, Result};
+
+/// Metadata key holding the [`DocumentClassification`] of the document.
+pub const DOCUMENT_TYPE_METADATA_KEY: &str = "document_type";
+
+/// Label used when no document type reaches the minimum confidence.
+pub const UNKNOWN_DOCUMENT_TYPE: &str = "unknown";
+
+/// Bytes of content scanned for keywords; long documents are classified by their beginning.
+const MAX_SCAN_BYTES: usize = 256 * 1024;
+
+/// Non-empty lines at the start (and, for closings, the end) of a document searched for
+/// title keywords.
+const HEAD_LINES: usize = 10;
+
+/// Score at which a document type is classified with its full share of the scores.
+const FULL_CONFIDENCE_SCORE: f64 = 8.0;
+
+const TITLE_KEYWORD_SCORE: f64 = 3.0;
+
+const BUILTIN_TYPES: &[(&str, &[&str], &[&str])] = &[
+    (
+        "invoice",
+        &[
+            "invoice",
+            "invoice number",
+            "invoice date",
+            "bill to",
+            "ship to",
+            "amount due",
+            "balance due",
+            "total due",
+            "subtotal",
+            "due date",
+            "payment terms",
+            "unit price",
+            "qty",
+            "vat",
+        ],
+        &["invoice", "tax invoice", "bill"],
+    ),
+    (
+        "contract",
+        &[
+            "agreement",
+            "hereinafter",
+            "parties",
+            "whereas",
+            "hereby",
+            "governing law",
+            "termination",
+            "indemnify",
+            "liability",
+            "effective date",
+            "confidentiality",
+            "in witness whereof",
+            "obligations",
+        ],
+        &["agreement", "contract", "terms and conditions"],
+    ),
+    (
+        "report",
+        &[
+            "executive summary",
+            "introduction",
+            "methodology",
+            "findings",
+            "results",
+            "analysis",
+            "conclusion",
+            "conclusions",
+            "recommendations",
+            "table of contents",
+            "appendix",
+        ],
+        &["report", "annual report", "executive summary"],
+    ),
+    (
+        "letter",
+        &[
+            "dear",
+            "sincerely",
+            "yours faithfully",
+            "yours sincerely",
+            "kind regards",
+            "best regards",
+            "enclosure",
+        ],
+        &[],
+    ),
+];
+
+/// Amounts such as `$12`, `€ 1.200` or `1,130.50`.
+static AMOUNT_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[$€£¥]\s?\d|\b\d{1,3}(?:[,.]\d{3})*[.,]\d{2}\b").expect("Amount regex pattern is valid"));
+
+/// Lines opening a numbered article, section or clause.
+static CLAUSE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?im)^\s*(?:#+\s*)?(?:article|section|clause|§)\s*(?:\d|[ivx]+\b)")
+        .expect("Clause regex pattern is valid")
+});
+
+/// Markdown headings and short numbered headings such as `2.1 Results`.
+static HEADING_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^(?:#{1,6}\s+\S.*|\d+(?:\.\d+)*\.?[ \t]+\p{Lu}[^\n.]{0,60})$")
+        .expect("Heading regex pattern is valid")
+});
+
+const LETTER_CLOSINGS: &[&str] = &[
+    "sincerely",
+    "yours sincerely",
+    "yours faithfully",
+    "yours truly",
+    "kind regards",
+    "best regards",
+    "regards",
+    "best wishes",
+];
+
+/// Document type and confidence of a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentClassification {
+    /// Best scoring document type, or [`UNKNOWN_DOCUMENT_TYPE`]
+    pub label: String,
+
+    /// Confidence of the best scoring type (0.0-1.0), also when it is below the minimum
+    pub confidence: f64,
+
+    /// Confidence of every document type
+    pub scores: BTreeMap<String, f64>,
+}
+
+/// Built-in layout features of a document type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LayoutFeatures {
+    Invoice,
+    Contract,
+    Report,
+    Letter,
+}
+
+impl LayoutFeatures {
+    fn for_label(label: &str) -> Option<Self> {
+        match label {
+            "invoice" => Some(Self::Invoice),
+            "contract" => Some(Self::Contract),
+            "report" => Some(Self::Report),
+            "letter" => Some(Self::Letter),
+            _ => None,
+        }
+    }
+
+    fn score(self, document: &Document<'_>) -> f64 {
+        match self {
+            Self::Invoice => {
+                let amounts = if AMOUNT_PATTERN.find_iter(document.text).nth(2).is_some() {
+                    2.0
+                } else {
+                    0.0
+                };
+                amounts + if document.has_tables { 1.0 } else { 0.0 }
+            }
+            Self::Contract => {
+                if CLAUSE_PATTERN.find_iter(document.text).nth(2).is_some() {
+                    2.0
+                } else {
+                    0.0
+                }
+            }
+            Self::Report => {
+                let headings = if HEADING_PATTERN.find_iter(document.text).nth(2).is_some() {
+                    2.0
+                } else {
+                    0.0
+                };
+                headings + if document.page_count >= 5 { 1.0 } else { 0.0 }
+            }
+            Self::Letter => {
+                let salutation = document.head_lines.iter().any(|line| line.starts_with("dear "));
+                let closing = document.tail_lines.iter().any(|line| {
+                    LETTER_CLOSINGS
+                        .iter()
+                        .any(|closing| line.trim_end_matches([',', '.']) == *closing)
+                });
+                f64::from(u8::from(salutation) + u8::from(closing)) * TITLE_KEYWORD_SCORE
+            }
+        }
+    }
+}
+
+/// Document type with its keywords, as word sequences.
+#[derive(Debug, Clone)]
+struct DocumentType {
+    label: String,
+    keywords: Vec<String>,
+    title_keywords: Vec<String>,
+    layout: Option<LayoutFeatures>,
+}
+
+/// Text of a document prepared for matching.
+struct Document<'a> {
+    text: &'a str,
+    /// Lowercased words separated by single spaces, with a leading and trailing space
+    words: String,
+    head: String,
+    head_lines: Vec<String>,
+    tail_lines: Vec<String>,
+    page_count: usize,
+    has_tables: bool,
+}
+
+impl<'a> Document<'a> {
+    fn new(content: &'a str, page_count: usize, has_tables: bool) -> Self {
+        let mut end = content.len().min(MAX_SCAN_BYTES);
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        let text = &content[..end];
+
+        let lines: Vec<String> = content
+            .lines()
+            .map(normalize_words)
+            .filter(|line| !line.is_empty())
+            .collect();
+        let head_lines: Vec<String> = lines.iter().take(HEAD_LINES).cloned().collect();
+        let tail_lines = lines.iter().rev().take(HEAD_LINES).cloned().collect();
+
+        Self {
+            text,
+            words: format!(" {} ", normalize_words(text)),
+            head: format!(" {} ", head_lines.join(" ")),
+            head_lines,
+            tail_lines,
+            page_count,
+            has_tables,
+        }
+    }
+}
+
+/// Lowercase `text` and keep its alphanumeric words, separated by single spaces.
+fn normalize_words(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Keyword and layout classifier for document types.
+#[derive(Debug, Clone)]
+pub struct DocumentClassifier {
+    types: Vec<DocumentType>,
+    min_confidence: f64,
+}
+
+impl DocumentClassifier {
+    /// Build the classifier for `config`: the built-in types (unless disabled), extended or
+    /// followed by `config.types`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` for a type with an empty label or without
+    /// keywords, and for a `min_confidence` outside 0.0-1.0.
+    pub fn new(config: &ClassificationConfig) -> Result<Self> {
+        if !(0.0..=1.0).contains(&config.min_confidence) {
+            return Err(KreuzbergError::validation(format!(
+                "Classification min_confidence must be between 0.0 and 1.0, got {}",
+                config.min_confidence
+            )));
+        }
+
+        let builtin: &[_] = if config.builtin_types { BUILTIN_TYPES } else { &[] };
+        let builtin = builtin
+            .iter()
+            .map(|(label, keywords, title_keywords)| DocumentTypeRule {
+                label: label.to_string(),
+                keywords: keywords.iter().map(|keyword| keyword.to_string()).collect(),
+                title_keywords: title_keywords.iter().map(|keyword| keyword.to_string()).collect(),
+            });
+
+        let mut types: Vec<DocumentType> = Vec::new();
+        for rule in builtin.chain(config.types.iter().cloned()) {
+            let label = rule.label.trim();
+            if label.is_empty() {
+                return Err(KreuzbergError::validation("Document type rule has an empty label"));
+            }
+            let keywords: Vec<String> = rule.keywords.iter().map(|k| normalize_words(k)).collect();
+            let title_keywords: Vec<String> = rule.title_keywords.iter().map(|k| normalize_words(k)).collect();
+            if keywords.iter().chain(&title_keywords).all(String::is_empty) {
+                return Err(KreuzbergError::validation(format!(
+                    "Document type '{}' has no keywords",
+                    label
+                )));
+            }
+
+            let document_type = match types.iter_mut().find(|existing| existing.label == label) {
+                Some(existing) => existing,
+                None => {
+                    types.push(DocumentType {
+                        label: label.to_string(),
+                        keywords: Vec::new(),
+                        title_keywords: Vec::new(),
+                        layout: LayoutFeatures::for_label(label).filter(|_| config.builtin_types),
+                    });
+                    types.last_mut().expect("type was just pushed")
+                }
+            };
+            for keyword in keywords.into_iter().filter(|k| !k.is_empty()) {
+                if !document_type.keywords.contains(&keyword) {
+                    document_type.keywords.push(keyword);
+                }
+            }
+            for keyword in title_keywords.into_iter().filter(|k| !k.is_empty()) {
+                if !document_type.title_keywords.contains(&keyword) {
+                    document_type.title_keywords.push(keyword);
+                }
+            }
+        }
+
+        Ok(Self {
+            types,
+            min_confidence: config.min_confidence,
+        })
+    }
+
+    /// Classify a document by its text alone.
+    pub fn classify(&self, content: &str) -> DocumentClassification {
+        self.classify_document(&Document::new(content, 0, false))
+    }
+
+    /// Classify an extraction result, using its page count and tables as layout features.
+    pub fn classify_result(&self, result: &ExtractionResult) -> DocumentClassification {
+        let page_count = result.pages.as_ref().map_or(0, Vec::len);
+        self.classify_document(&Document::new(&result.content, page_count, !result.tables.is_empty()))
+    }
+
+    fn classify_document(&self, document: &Document<'_>) -> DocumentClassification {
+        let raw_scores: Vec<f64> = self
+            .types
+            .iter()
+            .map(|document_type| {
+                let keywords = document_type
+                    .keywords
+                    .iter()
+                    .filter(|keyword| document.words.contains(&format!(" {} ", keyword)))
+                    .count() as f64;
+                let title_keywords = document_type
+                    .title_keywords
+                    .iter()
+                    .filter(|keyword| document.head.contains(&format!(" {} ", keyword)))
+                    .count() as f64;
+                let layout = document_type.layout.map_or(0.0, |layout| layout.score(document));
+                keywords + title_keywords * TITLE_KEYWORD_SCORE + layout
+            })
+            .collect();
+        let total: f64 = raw_scores.iter().sum();
+
+        let mut best: Option<(&str, f64)> = None;
+        let mut scores = BTreeMap::new();
+        for (document_type, score) in self.types.iter().zip(raw_scores) {
+            let confidence = if total > 0.0 {
+                (score / total) * (score / FULL_CONFIDENCE_SCORE).min(1.0)
+            } else {
+                0.0
+            };
+            if best.is_none_or(|(_, best_confidence)| confidence > best_confidence) {
+                best = Some((&document_type.label, confidence));
+            }
+            scores.insert(document_type.label.clone(), confidence);
+        }
+
+        let (label, confidence) = best.unwrap_or((UNKNOWN_DOCUMENT_TYPE, 0.0));
+        let label = if confidence > 0.0 && confidence >= self.min_confidence {
+            label
+        } else {
+            UNKNOWN_DOCUMENT_TYPE
+        };
+
+        DocumentClassification {
+            label: label.to_string(),
+            confidence,
+            scores,
+        }
+    }
+}
+
+/// Post-processor that labels documents with their type.
+///
+/// This processor:
+/// - Runs in the Early processing stage, on the content before chunking and token reduction
+/// - Only processes when `config.classification` is configured
+/// - Stores the [`DocumentClassification`] in `metadata.additional["document_type"]`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::plugins::{Plugin, PostProcessor};
+/// use kreuzberg::text::classification::DocumentClassificationProcessor;
+///
+/// let processor = DocumentClassificationProcessor;
+/// assert_eq!(processor.name(), "document-classification");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DocumentClassificationProcessor;
+
+impl Plugin for DocumentClassificationProcessor {
+    fn name(&self) -> &str {
+        "document-classification"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for DocumentClassificationProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(classification_config) = &config.classification else {
+            return Ok(());
+        };
+        let classification = DocumentClassifier::new(classification_config)?.classify_result(result);
+
+        result.metadata.additional.insert(
+            DOCUMENT_TYPE_METADATA_KEY.to_string(),
+            serde_json::to_value(classification)?,
+        );
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, _result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config.classification.is_some()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        let text_length = result.content.len().min(MAX_SCAN_BYTES);
+        // One pass per keyword over at most 256KB: ~1ms per 50KB
+        (text_length / 51200).max(1) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Metadata;
+
+    fn classifier() -> DocumentClassifier {
+        DocumentClassifier::new(&ClassificationConfig::default()).unwrap()
+    }
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_classify_builtin_types() {
+        let invoice = classifier().classify(
+            "ACME Supplies\nINVOICE\nInvoice number: 4711\nBill to: Example Ltd\n\
+             Item Qty Unit price Total\nPaper 10 4.50 45.00\nSubtotal 45.00\nVAT 8.55\nAmount due: $53.55",
+        );
+        assert_eq!(invoice.label, "invoice");
+        assert!(invoice.confidence > 0.9, "{:?}", invoice);
+
+        let contract = classifier().classify(
+            "SERVICE AGREEMENT\n\nThis Agreement is made between the parties, hereinafter the Provider and \
+             the Client.\n\nWhereas the Client wishes to obtain services:\n\nArticle 1 Services\n\
+             Article 2 Termination\nArticle 3 Governing law\n\nIn witness whereof the parties sign.",
+        );
+        assert_eq!(contract.label, "contract");
+
+        let letter = classifier().classify(
+            "Jane Doe\n12 High Street\n\nDear Mr Smith,\n\nThank you for your visit last week. \
+             I am writing to confirm our meeting.\n\nKind regards,\nJane Doe",
+        );
+        assert_eq!(letter.label, "letter");
+
+        let report = classifier().classify(
+            "Annual Report 2024\n\n# Executive Summary\nRevenue grew.\n\n# Methodology\nWe surveyed users.\n\n\
+             # Findings\nUsers are happy.\n\n# Recommendations\nKeep going.",
+        );
+        assert_eq!(report.label, "report");
+        assert_eq!(report.scores.len(), 4);
+    }
+
+    #[test]
+    fn test_unknown_below_min_confidence() {
+        let classification = classifier().classify("The quick brown fox jumps over the lazy dog.");
+        assert_eq!(classification.label, UNKNOWN_DOCUMENT_TYPE);
+        assert_eq!(classification.confidence, 0.0);
+
+        // A single keyword is not enough for a confident label
+        let classification = classifier().classify("Please see the analysis below.");
+        assert_eq!(classification.label, UNKNOWN_DOCUMENT_TYPE);
+        assert!(classification.confidence > 0.0);
+    }
+
+    #[test]
+    fn test_custom_types() {
+        let config = ClassificationConfig {
+            builtin_types: false,
+            types: vec![
+                DocumentTypeRule {
+                    label: "resume".to_string(),
+                    keywords: vec![
+                        "work experience".to_string(),
+                        "education".to_string(),
+                        "skills".to_string(),
+                    ],
+                    title_keywords: vec!["curriculum vitae".to_string()],
+                },
+                DocumentTypeRule {
+                    label: "resume".to_string(),
+                    keywords: vec!["references".to_string()],
+                    title_keywords: vec![],
+                },
+            ],
+            min_confidence: 0.5,
+        };
+        let classifier = DocumentClassifier::new(&config).unwrap();
+
+        let classification = classifier
+            .classify("Curriculum Vitae\nJane Doe\n\nWork Experience\n...\nEducation\n...\nSkills\n...\nReferences");
+        assert_eq!(classification.label, "resume");
+        assert_eq!(classification.confidence, 7.0 / 8.0);
+        assert_eq!(classification.scores.keys().collect::<Vec<_>>(), vec!["resume"]);
+
+        let invalid = ClassificationConfig {
+            types: vec![DocumentTypeRule {
+                label: "empty".to_string(),
+                keywords: vec![" ".to_string()],
+                title_keywords: vec![],
+            }],
+            ..Default::default()
+        };
+        assert!(
+            DocumentClassifier::new(&invalid)
+                .unwrap_err()
+                .to_string()
+                .contains("no keywords")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_processor_writes_document_type() {
+        let mut result = result("Dear Ms Jones,\n\nPlease find the documents enclosed.\n\nYours sincerely,\nJ. Smith");
+        let config = ExtractionConfig {
+            classification: Some(ClassificationConfig::default()),
+            ..Default::default()
+        };
+
+        assert!(DocumentClassificationProcessor.should_process(&result, &config));
+        assert!(!DocumentClassificationProcessor.should_process(&result, &ExtractionConfig::default()));
+        DocumentClassificationProcessor
+            .process(&mut result, &config)
+            .await
+            .unwrap();
+
+        let document_type = &result.metadata.additional[DOCUMENT_TYPE_METADATA_KEY];
+        assert_eq!(document_type["label"], "letter");
+        assert!(document_type["confidence"].as_f64().unwrap() > 0.5);
+        assert!(document_type["scores"]["invoice"].is_number());
+    }
+}
@@ -0,0 +1,505 @@
+//! Removal of text repeated across pages.
+//!
+//! Running headers and footers, page numbers and watermarks recur on most pages of a
+//! document. Left in the content they split paragraphs in the middle of a chunk and
+//! skew embeddings and keyword scores. [`strip_repeated_text`] finds lines among the
+//! first and last lines of each page that recur on most pages and removes them, or with
+//! `tag_instead` records where they are in `metadata.additional["repeated_text"]`.
+//!
+//! Lines are compared case-insensitively with runs of digits masked, so `Page 3 of 12`
+//! on one page matches `Page 4 of 12` on the next. Pages are taken from the page
+//! boundaries of the result, or from the per-page content when no boundaries are known;
+//! documents with fewer than three pages are left unchanged.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::text::cleanup::strip_repeated_lines;
+//!
+//! let pages = [
+//!     "ACME Annual Report\nRevenue grew in every region.\nPage 1 of 3",
+//!     "ACME Annual Report\nCosts were flat.\nPage 2 of 3",
+//!     "ACME Annual Report\nWe expect further growth.\nPage 3 of 3",
+//! ];
+//! let cleaned = strip_repeated_lines(&pages);
+//! assert_eq!(cleaned[1], "Costs were flat.");
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use serde::Serialize;
+
+use crate::core::config::CleanupConfig;
+use crate::types::ExtractionResult;
+
+/// Key of the tagged repeated lines in `metadata.additional`.
+pub const REPEATED_TEXT_METADATA_KEY: &str = "repeated_text";
+
+/// Lines at the top and at the bottom of a page searched for repeated text.
+const EDGE_LINES: usize = 3;
+/// Fewest pages needed to tell repeated text from content.
+const MIN_PAGES: usize = 3;
+/// Share of the pages a line has to appear on to count as repeated.
+const MIN_PAGE_RATIO: f64 = 0.6;
+/// Longer lines are content, not headers or footers.
+const MAX_LINE_CHARS: usize = 120;
+
+/// An occurrence of repeated text, as stored in `metadata.additional["repeated_text"]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepeatedTextSpan {
+    /// The line as it appears on the page
+    pub text: String,
+    /// Page number (1-indexed)
+    pub page: usize,
+    /// Byte offset where the line starts in the content, when page boundaries are known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_start: Option<usize>,
+    /// Byte offset where the line ends in the content, when page boundaries are known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_end: Option<usize>,
+}
+
+/// Remove the lines repeated across most pages from every page.
+///
+/// Pages without repeated lines are returned unchanged.
+pub fn strip_repeated_lines(pages: &[&str]) -> Vec<String> {
+    match RepeatedLines::detect(pages) {
+        Some(repeated) => pages.iter().map(|page| repeated.strip(page)).collect(),
+        None => pages.iter().map(|page| page.to_string()).collect(),
+    }
+}
+
+/// Remove or tag the text repeated across pages of an extraction result.
+///
+/// When stripping, the content, the per-page content and the page boundaries are
+/// rewritten together so that page-aware chunking keeps working. When tagging, the
+/// content is left as extracted and each occurrence is recorded as a
+/// [`RepeatedTextSpan`].
+pub fn strip_repeated_text(result: &mut ExtractionResult, config: &CleanupConfig) {
+    if !config.strip_repeated {
+        return;
+    }
+
+    let page_ranges = content_page_ranges(result);
+    let repeated = match &page_ranges {
+        Some(ranges) => {
+            let texts: Vec<&str> = ranges.iter().map(|(range, _)| &result.content[range.clone()]).collect();
+            RepeatedLines::detect(&texts)
+        }
+        None => {
+            let texts: Vec<&str> = result
+                .pages
+                .iter()
+                .flatten()
+                .map(|page| page.content.as_str())
+                .collect();
+            RepeatedLines::detect(&texts)
+        }
+    };
+    let Some(repeated) = repeated else {
+        return;
+    };
+
+    if config.tag_instead {
+        let spans = match &page_ranges {
+            Some(ranges) => ranges
+                .iter()
+                .flat_map(|(range, page)| {
+                    repeated
+                        .find(&result.content[range.clone()])
+                        .into_iter()
+                        .map(|line| RepeatedTextSpan {
+                            text: result.content[range.start + line.start..range.start + line.end].to_string(),
+                            page: *page,
+                            byte_start: Some(range.start + line.start),
+                            byte_end: Some(range.start + line.end),
+                        })
+                })
+                .collect(),
+            None => result
+                .pages
+                .iter()
+                .flatten()
+                .flat_map(|page| {
+                    repeated.find(&page.content).into_iter().map(|line| RepeatedTextSpan {
+                        text: page.content[line].to_string(),
+                        page: page.page_number,
+                        byte_start: None,
+                        byte_end: None,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        if !spans.is_empty()
+            && let Ok(value) = serde_json::to_value(&spans)
+        {
+            result
+                .metadata
+                .additional
+                .insert(REPEATED_TEXT_METADATA_KEY.to_string(), value);
+        }
+        return;
+    }
+
+    if let Some(ranges) = page_ranges {
+        strip_paged_content(result, &ranges, &repeated);
+    }
+    for page in result.pages.iter_mut().flatten() {
+        page.content = repeated.strip(&page.content);
+    }
+}
+
+/// Remove the lines for which `is_removed` holds from the content and the per-page
+/// content, moving the page boundaries to the matching offsets.
+pub(crate) fn remove_lines(result: &mut ExtractionResult, is_removed: impl Fn(&str) -> bool) {
+    let (content, removed) = remove_matching_lines(&result.content, &is_removed);
+    if !removed.is_empty() {
+        if let Some(boundaries) = result
+            .metadata
+            .pages
+            .as_mut()
+            .and_then(|pages| pages.boundaries.as_mut())
+        {
+            for boundary in boundaries.iter_mut() {
+                boundary.byte_start = shift_offset(boundary.byte_start, &removed);
+                boundary.byte_end = shift_offset(boundary.byte_end, &removed);
+            }
+        }
+        result.content = content;
+    }
+
+    for page in result.pages.iter_mut().flatten() {
+        page.content = remove_matching_lines(&page.content, &is_removed).0;
+    }
+}
+
+/// The text without the matching lines, and the byte ranges of the removed lines
+/// including their line endings.
+fn remove_matching_lines(text: &str, is_removed: &impl Fn(&str) -> bool) -> (String, Vec<Range<usize>>) {
+    let mut kept = String::with_capacity(text.len());
+    let mut removed = Vec::new();
+    let mut start = 0;
+    for line in text.split_inclusive('\n') {
+        if is_removed(line.trim_end_matches(['\n', '\r'])) {
+            removed.push(start..start + line.len());
+        } else {
+            kept.push_str(line);
+        }
+        start += line.len();
+    }
+    (kept, removed)
+}
+
+/// Offset in the text after removing the ordered `removed` ranges; offsets inside a
+/// removed range move to its start.
+fn shift_offset(offset: usize, removed: &[Range<usize>]) -> usize {
+    let mut shifted = offset;
+    for range in removed.iter().take_while(|range| range.start < offset) {
+        shifted -= range.end.min(offset) - range.start;
+    }
+    shifted
+}
+
+/// Byte ranges of the pages in the content with their page numbers, when the page
+/// boundaries are ordered and fall on character boundaries.
+pub(crate) fn content_page_ranges(result: &ExtractionResult) -> Option<Vec<(Range<usize>, usize)>> {
+    let boundaries = result.metadata.pages.as_ref()?.boundaries.as_ref()?;
+    let content = &result.content;
+
+    let mut previous_end = 0;
+    let mut ranges = Vec::with_capacity(boundaries.len());
+    for boundary in boundaries {
+        let valid = previous_end <= boundary.byte_start
+            && boundary.byte_start <= boundary.byte_end
+            && boundary.byte_end <= content.len()
+            && content.is_char_boundary(boundary.byte_start)
+            && content.is_char_boundary(boundary.byte_end);
+        if !valid {
+            return None;
+        }
+        previous_end = boundary.byte_end;
+        ranges.push((boundary.byte_start..boundary.byte_end, boundary.page_number));
+    }
+
+    (!ranges.is_empty()).then_some(ranges)
+}
+
+/// Rebuild the content with the repeated lines removed from every page range and move
+/// the page boundaries to the matching offsets.
+fn strip_paged_content(result: &mut ExtractionResult, ranges: &[(Range<usize>, usize)], repeated: &RepeatedLines) {
+    let content = &result.content;
+    let mut stripped = String::with_capacity(content.len());
+    let mut offsets = HashMap::with_capacity(ranges.len() * 2);
+    let mut copied = 0;
+
+    for (range, _) in ranges {
+        stripped.push_str(&content[copied..range.start]);
+        offsets.insert(range.start, stripped.len());
+        stripped.push_str(&repeated.strip(&content[range.clone()]));
+        offsets.insert(range.end, stripped.len());
+        copied = range.end;
+    }
+    stripped.push_str(&content[copied..]);
+
+    if let Some(boundaries) = result
+        .metadata
+        .pages
+        .as_mut()
+        .and_then(|pages| pages.boundaries.as_mut())
+    {
+        for boundary in boundaries.iter_mut() {
+            boundary.byte_start = offsets.get(&boundary.byte_start).copied().unwrap_or(stripped.len());
+            boundary.byte_end = offsets.get(&boundary.byte_end).copied().unwrap_or(stripped.len());
+        }
+    }
+    result.content = stripped;
+}
+
+/// Keys of the lines found at the edges of most pages.
+struct RepeatedLines {
+    keys: HashSet<String>,
+}
+
+impl RepeatedLines {
+    fn detect(pages: &[&str]) -> Option<Self> {
+        if pages.len() < MIN_PAGES {
+            return None;
+        }
+
+        let mut page_counts: HashMap<String, usize> = HashMap::new();
+        for page in pages {
+            let keys: HashSet<String> = edge_lines(page)
+                .into_iter()
+                .filter_map(|line| line_key(&page[line]))
+                .collect();
+            for key in keys {
+                *page_counts.entry(key).or_default() += 1;
+            }
+        }
+
+        let min_pages = ((pages.len() as f64 * MIN_PAGE_RATIO).ceil() as usize).max(2);
+        let keys: HashSet<String> = page_counts
+            .into_iter()
+            .filter(|(_, count)| *count >= min_pages)
+            .map(|(key, _)| key)
+            .collect();
+
+        (!keys.is_empty()).then_some(Self { keys })
+    }
+
+    /// Byte ranges of the repeated lines at the edges of a page, without line endings.
+    fn find(&self, page: &str) -> Vec<Range<usize>> {
+        edge_lines(page)
+            .into_iter()
+            .filter(|line| line_key(&page[line.clone()]).is_some_and(|key| self.keys.contains(&key)))
+            .collect()
+    }
+
+    /// The page without its repeated lines and the blank lines they leave at its edges.
+    fn strip(&self, page: &str) -> String {
+        let lines = self.find(page);
+        if lines.is_empty() {
+            return page.to_string();
+        }
+
+        let mut stripped = String::with_capacity(page.len());
+        let mut copied = 0;
+        for line in lines {
+            stripped.push_str(&page[copied..line.start]);
+            copied = line_end(page, line.end);
+        }
+        stripped.push_str(&page[copied..]);
+
+        trim_blank_lines(&stripped).to_string()
+    }
+}
+
+/// Byte ranges of the first and last non-blank lines of a page, without line endings.
+fn edge_lines(page: &str) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in page.split_inclusive('\n') {
+        let text = line.trim_end_matches(['\n', '\r']);
+        if !text.trim().is_empty() {
+            lines.push(start..start + text.len());
+        }
+        start += line.len();
+    }
+
+    if lines.len() > EDGE_LINES * 2 {
+        lines.drain(EDGE_LINES..lines.len() - EDGE_LINES);
+    }
+    lines
+}
+
+/// Offset just past the line ending that follows `offset`.
+fn line_end(page: &str, offset: usize) -> usize {
+    match page[offset..].find('\n') {
+        Some(newline) => offset + newline + 1,
+        None => page.len(),
+    }
+}
+
+/// Comparison key of a line: lowercased, whitespace collapsed and runs of digits masked.
+///
+/// Long lines and lines without letters or digits (rules, table separators) get no key.
+fn line_key(line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.chars().count() > MAX_LINE_CHARS || !line.chars().any(char::is_alphanumeric) {
+        return None;
+    }
+
+    let mut key = String::with_capacity(line.len());
+    let mut previous = ' ';
+    for ch in line.chars().flat_map(char::to_lowercase) {
+        let ch = if ch.is_ascii_digit() {
+            '#'
+        } else if ch.is_whitespace() {
+            ' '
+        } else {
+            ch
+        };
+        if (ch == '#' || ch == ' ') && previous == ch {
+            continue;
+        }
+        key.push(ch);
+        previous = ch;
+    }
+    Some(key)
+}
+
+/// The text without leading and trailing blank lines.
+fn trim_blank_lines(text: &str) -> &str {
+    let Some(first) = text.find(|ch: char| !ch.is_whitespace()) else {
+        return "";
+    };
+    let start = text[..first].rfind('\n').map_or(0, |newline| newline + 1);
+    let last = text.rfind(|ch: char| !ch.is_whitespace()).unwrap_or(first);
+    let end = text[last..]
+        .find(['\r', '\n'])
+        .map_or(text.len(), |newline| last + newline);
+    &text[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageBoundary, PageContent, PageStructure, PageUnitType};
+
+    fn paged_result(pages: &[&str]) -> ExtractionResult {
+        let mut content = String::new();
+        let mut boundaries = Vec::new();
+        for (index, page) in pages.iter().enumerate() {
+            if index > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(page);
+            boundaries.push(PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: index + 1,
+            });
+        }
+
+        ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: pages.len(),
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: Some(
+                pages
+                    .iter()
+                    .enumerate()
+                    .map(|(index, page)| PageContent {
+                        page_number: index + 1,
+                        content: page.to_string(),
+                        tables: vec![],
+                        images: vec![],
+                        content_blob: None,
+                    })
+                    .collect(),
+            ),
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    const PAGES: [&str; 4] = [
+        "ACME Corp - Confidential\nIntroduction to the quarterly results.\n\n- 1 -",
+        "ACME Corp - Confidential\nRevenue grew by 12 percent.\n\n- 2 -",
+        "Appendix\nNo header on this page.",
+        "ACME CORP - CONFIDENTIAL\nOutlook for the next year.\n\n- 4 -",
+    ];
+
+    #[test]
+    fn test_strip_repeated_text_rewrites_content_and_boundaries() {
+        let mut result = paged_result(&PAGES);
+        strip_repeated_text(&mut result, &CleanupConfig::default());
+
+        assert_eq!(
+            result.content,
+            "Introduction to the quarterly results.\n\nRevenue grew by 12 percent.\n\n\
+             Appendix\nNo header on this page.\n\nOutlook for the next year."
+        );
+        let boundaries = result.metadata.pages.as_ref().unwrap().boundaries.as_ref().unwrap();
+        for (boundary, page) in boundaries.iter().zip(result.pages.as_ref().unwrap()) {
+            assert_eq!(&result.content[boundary.byte_start..boundary.byte_end], page.content);
+        }
+        assert_eq!(result.pages.as_ref().unwrap()[1].content, "Revenue grew by 12 percent.");
+    }
+
+    #[test]
+    fn test_strip_repeated_text_tags_instead_of_removing() {
+        let mut result = paged_result(&PAGES);
+        let content = result.content.clone();
+        let config = CleanupConfig {
+            tag_instead: true,
+            ..Default::default()
+        };
+        strip_repeated_text(&mut result, &config);
+
+        assert_eq!(result.content, content);
+        let spans = &result.metadata.additional[REPEATED_TEXT_METADATA_KEY];
+        assert_eq!(spans.as_array().unwrap().len(), 6);
+        assert_eq!(spans[1]["text"], "- 1 -");
+        assert_eq!(spans[1]["page"], 1);
+        let (start, end) = (
+            spans[5]["byte_start"].as_u64().unwrap() as usize,
+            spans[5]["byte_end"].as_u64().unwrap() as usize,
+        );
+        assert_eq!(&content[start..end], "- 4 -");
+    }
+
+    #[test]
+    fn test_strip_repeated_lines_keeps_short_documents_and_body_text() {
+        let two_pages = ["Header\nFirst page.", "Header\nSecond page."];
+        assert_eq!(
+            strip_repeated_lines(&two_pages),
+            vec!["Header\nFirst page.", "Header\nSecond page."]
+        );
+
+        let repeated_body = [
+            "Title\na\nb\nc\nTotal\nd\ne\nf",
+            "Title\ng\nh\ni\nTotal\nj\nk\nl",
+            "Title\nm\nn\no\nTotal\np\nq\nr",
+        ];
+        let cleaned = strip_repeated_lines(&repeated_body);
+        assert_eq!(cleaned[0], "a\nb\nc\nTotal\nd\ne\nf");
+    }
+}
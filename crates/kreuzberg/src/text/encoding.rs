@@ -0,0 +1,179 @@
+//! Encoding detection and transcoding for text-based formats.
+//!
+//! Legacy text, CSV and HTML files are frequently stored in single-byte or
+//! regional encodings (Windows-1251, Shift_JIS, ISO-8859-x, ...). Decoding
+//! them as UTF-8 produces mojibake, so [`decode_text`] transcodes the raw
+//! bytes according to [`TextExtractionConfig`] and reports which encoding
+//! was used.
+//!
+//! Detection and non-UTF-8 decoding require the `quality` feature. Without
+//! it, only UTF-8 input is supported.
+
+use crate::core::config::{TextExtractionConfig, UnicodeNormalization};
+use crate::text::utf8_validation;
+use crate::{KreuzbergError, Result};
+
+/// Metadata key under which the detected encoding is reported.
+pub const ENCODING_METADATA_KEY: &str = "encoding";
+
+/// Text decoded from raw bytes together with the encoding that was used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedText {
+    /// Decoded UTF-8 text
+    pub text: String,
+    /// Canonical (WHATWG) name of the source encoding, lowercase (e.g. "windows-1251")
+    pub encoding: String,
+}
+
+/// Decode raw bytes into UTF-8 text according to the text options.
+///
+/// With `encoding = "auto"`, a byte order mark wins, valid UTF-8 is taken
+/// as-is and anything else goes through statistical detection. An explicit
+/// label is always honoured, even if the bytes happen to be valid UTF-8.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the encoding label is unknown, or
+/// if detection/transcoding is requested without the `quality` feature.
+pub fn decode_text(bytes: &[u8], options: &TextExtractionConfig) -> Result<DecodedText> {
+    let (text, encoding) = if options.encoding.eq_ignore_ascii_case("auto") {
+        decode_auto(bytes)?
+    } else {
+        decode_with_label(bytes, &options.encoding)?
+    };
+
+    let text = match options.normalize_unicode {
+        Some(form) => normalize(text, form)?,
+        None => text,
+    };
+
+    Ok(DecodedText { text, encoding })
+}
+
+#[cfg(feature = "quality")]
+fn decode_auto(bytes: &[u8]) -> Result<(String, String)> {
+    use encoding_rs::{Encoding, UTF_8};
+
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        let (decoded, _) = encoding.decode_without_bom_handling(&bytes[bom_len..]);
+        return Ok((decoded.into_owned(), encoding.name().to_ascii_lowercase()));
+    }
+
+    if let Ok(text) = utf8_validation::from_utf8(bytes) {
+        return Ok((text.to_string(), UTF_8.name().to_ascii_lowercase()));
+    }
+
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(bytes, true);
+    let encoding = detector.guess(None, true);
+    let (decoded, _) = encoding.decode_without_bom_handling(bytes);
+    Ok((decoded.into_owned(), encoding.name().to_ascii_lowercase()))
+}
+
+#[cfg(not(feature = "quality"))]
+fn decode_auto(bytes: &[u8]) -> Result<(String, String)> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let text = utf8_validation::from_utf8(bytes).map_err(|_| {
+        KreuzbergError::validation("Input is not valid UTF-8 and encoding detection requires the 'quality' feature")
+    })?;
+    Ok((text.to_string(), "utf-8".to_string()))
+}
+
+#[cfg(feature = "quality")]
+fn decode_with_label(bytes: &[u8], label: &str) -> Result<(String, String)> {
+    let encoding = encoding_rs::Encoding::for_label(label.trim().as_bytes())
+        .ok_or_else(|| KreuzbergError::validation(format!("Unknown text encoding '{}'", label)))?;
+    let (decoded, used, _) = encoding.decode(bytes);
+    Ok((decoded.into_owned(), used.name().to_ascii_lowercase()))
+}
+
+#[cfg(not(feature = "quality"))]
+fn decode_with_label(bytes: &[u8], label: &str) -> Result<(String, String)> {
+    let normalized = label.trim().to_ascii_lowercase();
+    if normalized != "utf-8" && normalized != "utf8" {
+        return Err(KreuzbergError::validation(format!(
+            "Decoding '{}' requires the 'quality' feature",
+            label
+        )));
+    }
+    decode_auto(bytes)
+}
+
+#[cfg(feature = "quality")]
+pub(crate) fn normalize(text: String, form: UnicodeNormalization) -> Result<String> {
+    use unicode_normalization::UnicodeNormalization as _;
+
+    Ok(match form {
+        UnicodeNormalization::Nfc => text.nfc().collect(),
+        UnicodeNormalization::Nfd => text.nfd().collect(),
+        UnicodeNormalization::Nfkc => text.nfkc().collect(),
+        UnicodeNormalization::Nfkd => text.nfkd().collect(),
+    })
+}
+
+#[cfg(not(feature = "quality"))]
+pub(crate) fn normalize(_text: String, form: UnicodeNormalization) -> Result<String> {
+    Err(KreuzbergError::validation(format!(
+        "Unicode normalization '{}' requires the 'quality' feature",
+        form.as_str()
+    )))
+}
+
+#[cfg(all(test, feature = "quality"))]
+mod tests {
+    use super::*;
+
+    fn options(encoding: &str) -> TextExtractionConfig {
+        TextExtractionConfig {
+            encoding: encoding.to_string(),
+            normalize_unicode: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_utf8_auto() {
+        let decoded = decode_text("Grüße".as_bytes(), &options("auto")).unwrap();
+        assert_eq!(decoded.text, "Grüße");
+        assert_eq!(decoded.encoding, "utf-8");
+    }
+
+    #[test]
+    fn test_decode_utf8_bom_stripped() {
+        let decoded = decode_text(b"\xEF\xBB\xBFhello", &options("auto")).unwrap();
+        assert_eq!(decoded.text, "hello");
+        assert_eq!(decoded.encoding, "utf-8");
+    }
+
+    #[test]
+    fn test_decode_explicit_cp1251() {
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode("Привет, мир");
+        let decoded = decode_text(&bytes, &options("cp1251")).unwrap();
+        assert_eq!(decoded.text, "Привет, мир");
+        assert_eq!(decoded.encoding, "windows-1251");
+    }
+
+    #[test]
+    fn test_decode_auto_detects_cp1251() {
+        let sample = "Съешь же ещё этих мягких французских булок, да выпей чаю. ".repeat(8);
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode(&sample);
+        let decoded = decode_text(&bytes, &options("auto")).unwrap();
+        assert_eq!(decoded.encoding, "windows-1251");
+        assert_eq!(decoded.text, sample);
+    }
+
+    #[test]
+    fn test_decode_unknown_label() {
+        let err = decode_text(b"abc", &options("klingon-8")).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_decode_normalizes_nfc() {
+        let config = TextExtractionConfig {
+            encoding: "auto".to_string(),
+            normalize_unicode: Some(UnicodeNormalization::Nfc),
+        };
+        let decoded = decode_text("e\u{0301}".as_bytes(), &config).unwrap();
+        assert_eq!(decoded.text, "\u{00e9}");
+    }
+}
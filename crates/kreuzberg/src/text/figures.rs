@@ -0,0 +1,392 @@
+//! Figure captions.
+//!
+//! RAG systems answer questions about charts and diagrams by retrieving an image together
+//! with its caption. When image extraction is configured, [`attach_figure_captions`] finds
+//! caption paragraphs such as `Figure 3: Revenue by region` or `Fig. 2a. Test setup` in the
+//! content, lists them in `metadata.figures` and sets `caption` and `figure_index` on the
+//! extracted image each caption belongs to:
+//!
+//! - captions and images are paired in order on each page; when captions or images carry no
+//!   page numbers, they are paired in document order
+//! - masks and images smaller than 32 pixels on a side (bullets, rules, icons) are skipped
+//! - a caption without an image left to pair with (a figure drawn as vector graphics) is
+//!   still listed, without `image_index`
+//!
+//! A paragraph counts as a caption when it starts with a figure label followed by
+//! punctuation or a capitalised word, so references in running text ("Figure 3 shows ...")
+//! are not mistaken for captions. A caption ends at a blank line, after a line ending a
+//! sentence, or after three lines.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::text::figures::parse_caption;
+//!
+//! let (label, caption) = parse_caption("**Figure 3:** Revenue by region, 2024").unwrap();
+//! assert_eq!(label, "Figure 3");
+//! assert_eq!(caption, "Figure 3: Revenue by region, 2024");
+//!
+//! assert!(parse_caption("Figure 3 shows that revenue grew.").is_none());
+//! ```
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::text::cleanup::content_page_ranges;
+use crate::types::{ExtractedImage, ExtractionResult, Figure};
+
+/// Images smaller than this on either side are decoration, not figures.
+const MIN_FIGURE_PIXELS: u32 = 32;
+
+/// Most lines a caption paragraph is read for.
+const MAX_CAPTION_LINES: usize = 3;
+
+/// Longer captions are cut at a character boundary.
+const MAX_CAPTION_CHARS: usize = 500;
+
+static CAPTION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(figure|fig\.?|image|illustration|chart|diagram|graph|plate|exhibit|abbildung|abb\.|figura|graphique)\s*(\d+(?:[.\-]\d+)*[a-z]?|[ivxlc]+)\b\s*([.:\-–—|)]?)\s*(.*)$",
+    )
+    .expect("valid figure caption regex")
+});
+
+/// The label and full caption text when `line` starts a figure caption.
+///
+/// Markdown emphasis around the label is dropped and whitespace is collapsed.
+pub fn parse_caption(line: &str) -> Option<(String, String)> {
+    let text = collapse_whitespace(&strip_emphasis(line));
+    let captures = CAPTION.captures(&text)?;
+
+    let delimiter = captures.get(3).map_or("", |m| m.as_str());
+    let rest = captures.get(4).map_or("", |m| m.as_str());
+    let starts_title = rest.chars().next().is_none_or(|ch| !ch.is_lowercase());
+    if delimiter.is_empty() && !starts_title {
+        return None;
+    }
+
+    let label = format!("{} {}", &captures[1], &captures[2]);
+    Some((label, text))
+}
+
+/// Find figure captions in the content, list them in `metadata.figures` and attach each
+/// caption to the extracted image it belongs to.
+pub fn attach_figure_captions(result: &mut ExtractionResult) {
+    let captions = find_captions(result);
+    if captions.is_empty() {
+        return;
+    }
+
+    let candidates: Vec<&ExtractedImage> = result
+        .images
+        .iter()
+        .flatten()
+        .filter(|image| is_figure_image(image))
+        .collect();
+    let paged = captions.iter().all(|caption| caption.page_number.is_some())
+        && candidates.iter().all(|image| image.page_number.is_some());
+
+    let mut images_by_page: BTreeMap<Option<usize>, VecDeque<usize>> = BTreeMap::new();
+    let mut sorted = candidates;
+    sorted.sort_by_key(|image| image.image_index);
+    for image in sorted {
+        let page = if paged { image.page_number } else { None };
+        images_by_page.entry(page).or_default().push_back(image.image_index);
+    }
+
+    let figures: Vec<Figure> = captions
+        .into_iter()
+        .enumerate()
+        .map(|(figure_index, caption)| {
+            let page = if paged { caption.page_number } else { None };
+            Figure {
+                figure_index,
+                label: caption.label,
+                caption: caption.text,
+                page_number: caption.page_number,
+                image_index: images_by_page.get_mut(&page).and_then(VecDeque::pop_front),
+            }
+        })
+        .collect();
+
+    let by_image: HashMap<usize, &Figure> = figures
+        .iter()
+        .filter_map(|figure| Some((figure.image_index?, figure)))
+        .collect();
+    if !by_image.is_empty() {
+        for image in result.images.iter_mut().flatten() {
+            if let Some(figure) = by_image.get(&image.image_index) {
+                image.caption = Some(figure.caption.clone());
+                image.figure_index = Some(figure.figure_index);
+            }
+        }
+
+        // Page images are copies of the document-level images, matched by image_index
+        for page in result.pages.iter_mut().flatten() {
+            for image in page.images.iter_mut() {
+                if let Some(figure) = by_image.get(&image.image_index) {
+                    let image = Arc::make_mut(image);
+                    image.caption = Some(figure.caption.clone());
+                    image.figure_index = Some(figure.figure_index);
+                }
+            }
+        }
+    }
+
+    result.metadata.figures = Some(figures);
+}
+
+/// A caption found in the content.
+struct Caption {
+    label: String,
+    text: String,
+    page_number: Option<usize>,
+}
+
+/// Captions in document order with the page they are on.
+fn find_captions(result: &ExtractionResult) -> Vec<Caption> {
+    let mut captions = Vec::new();
+    for (page_number, text) in page_texts(result) {
+        let mut lines = text.lines().peekable();
+        let mut line_start = true;
+        while let Some(line) = lines.next() {
+            // A label wrapped into the middle of a sentence is a reference, not a caption
+            let starts_line = line_start;
+            line_start = line.trim().is_empty() || ends_sentence(line);
+            if !starts_line {
+                continue;
+            }
+            let Some((label, mut caption)) = parse_caption(line) else {
+                continue;
+            };
+
+            let mut line_count = 1;
+            while line_count < MAX_CAPTION_LINES && !ends_sentence(&caption) {
+                let Some(next) = lines.next_if(|next| !next.trim().is_empty() && parse_caption(next).is_none()) else {
+                    break;
+                };
+                caption.push(' ');
+                caption.push_str(&collapse_whitespace(&strip_emphasis(next)));
+                line_count += 1;
+            }
+
+            line_start = true;
+            captions.push(Caption {
+                label,
+                text: truncate(caption),
+                page_number,
+            });
+        }
+    }
+    captions
+}
+
+/// Page texts with their page numbers, or the whole content without one.
+fn page_texts(result: &ExtractionResult) -> Vec<(Option<usize>, &str)> {
+    if let Some(ranges) = content_page_ranges(result) {
+        return ranges
+            .into_iter()
+            .map(|(range, page)| (Some(page), &result.content[range]))
+            .collect();
+    }
+    match result.pages.as_deref() {
+        Some(pages) if !pages.is_empty() => pages
+            .iter()
+            .map(|page| (Some(page.page_number), page.content.as_str()))
+            .collect(),
+        _ => vec![(None, result.content.as_str())],
+    }
+}
+
+fn is_figure_image(image: &ExtractedImage) -> bool {
+    !image.is_mask
+        && image.width.is_none_or(|width| width >= MIN_FIGURE_PIXELS)
+        && image.height.is_none_or(|height| height >= MIN_FIGURE_PIXELS)
+}
+
+fn ends_sentence(text: &str) -> bool {
+    text.trim_end().ends_with(['.', '!', '?'])
+}
+
+/// Drop Markdown emphasis markers (`**Figure 1.**`, `_Fig. 2_`) and leading list or
+/// quote markers.
+fn strip_emphasis(line: &str) -> String {
+    line.trim()
+        .trim_start_matches(['>', ' '])
+        .replace("**", "")
+        .replace("__", "")
+        .trim_matches(['*', '_', ' '])
+        .to_string()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn truncate(mut caption: String) -> String {
+    if caption.len() > MAX_CAPTION_CHARS {
+        let mut end = MAX_CAPTION_CHARS;
+        while !caption.is_char_boundary(end) {
+            end -= 1;
+        }
+        caption.truncate(end);
+        caption.truncate(caption.trim_end().len());
+    }
+    caption
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageBoundary, PageContent, PageStructure, PageUnitType};
+
+    fn image(image_index: usize, page_number: Option<usize>, size: u32) -> ExtractedImage {
+        ExtractedImage {
+            data: vec![],
+            format: "png".to_string(),
+            image_index,
+            page_number,
+            width: Some(size),
+            height: Some(size),
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
+        }
+    }
+
+    fn result(pages: &[&str], images: Vec<ExtractedImage>) -> ExtractionResult {
+        let mut content = String::new();
+        let mut boundaries = Vec::new();
+        for (index, page) in pages.iter().enumerate() {
+            if index > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(page);
+            boundaries.push(PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: index + 1,
+            });
+        }
+
+        ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: pages.len(),
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: Some(images),
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_parse_caption_accepts_common_styles() {
+        for (line, label) in [
+            ("Figure 1: Revenue by quarter", "Figure 1"),
+            ("Fig. 2a. Test setup", "Fig. 2a"),
+            ("FIGURE 3 — System overview", "FIGURE 3"),
+            ("Figure 4.2 Network topology", "Figure 4.2"),
+            ("_Chart 5: Costs_", "Chart 5"),
+            ("Abbildung 6: Umsatz", "Abbildung 6"),
+            ("Plate IV. Coastal survey", "Plate IV"),
+        ] {
+            let (parsed, _) = parse_caption(line).unwrap_or_else(|| panic!("no caption in {line:?}"));
+            assert_eq!(parsed, label);
+        }
+    }
+
+    #[test]
+    fn test_parse_caption_rejects_references() {
+        assert!(parse_caption("Figure 3 shows that revenue grew.").is_none());
+        assert!(parse_caption("Figures are listed below").is_none());
+        assert!(parse_caption("Configuration file").is_none());
+        assert!(parse_caption("Image quality matters").is_none());
+    }
+
+    #[test]
+    fn test_captions_are_paired_with_images_on_their_page() {
+        let mut result = result(
+            &[
+                "Introduction text.\n\nFigure 1: Revenue by quarter\n\nMore text.",
+                "Figure 2: Costs by region, shown as a\nstacked bar chart.\nBody text continues here.",
+                "Figure 3. Architecture diagram drawn as vectors",
+            ],
+            vec![image(0, Some(1), 400), image(1, Some(2), 8), image(2, Some(2), 300)],
+        );
+
+        attach_figure_captions(&mut result);
+
+        let figures = result.metadata.figures.as_ref().unwrap();
+        assert_eq!(figures.len(), 3);
+        assert_eq!(figures[0].image_index, Some(0));
+        assert_eq!(figures[0].page_number, Some(1));
+        assert_eq!(
+            figures[1].caption,
+            "Figure 2: Costs by region, shown as a stacked bar chart."
+        );
+        assert_eq!(figures[1].image_index, Some(2));
+        assert_eq!(figures[2].label, "Figure 3");
+        assert_eq!(figures[2].image_index, None);
+
+        let images = result.images.as_ref().unwrap();
+        assert_eq!(images[0].caption.as_deref(), Some("Figure 1: Revenue by quarter"));
+        assert_eq!(images[0].figure_index, Some(0));
+        assert_eq!(images[1].caption, None);
+        assert_eq!(images[2].figure_index, Some(1));
+    }
+
+    #[test]
+    fn test_captions_without_pages_are_paired_in_order() {
+        let mut result = result(&["Figure 1: Logo\n\nText\n\nFigure 2: Chart"], vec![]);
+        result.metadata.pages = None;
+        result.images = Some(vec![image(0, None, 100), image(1, None, 100)]);
+        let page_image = Arc::new(image(1, None, 100));
+        result.pages = Some(vec![PageContent {
+            page_number: 1,
+            content: result.content.clone(),
+            tables: vec![],
+            images: vec![page_image],
+            content_blob: None,
+        }]);
+
+        attach_figure_captions(&mut result);
+
+        let figures = result.metadata.figures.as_ref().unwrap();
+        assert_eq!(figures[0].image_index, Some(0));
+        assert_eq!(figures[1].image_index, Some(1));
+        let page_images = &result.pages.as_ref().unwrap()[0].images;
+        assert_eq!(page_images[0].caption.as_deref(), Some("Figure 2: Chart"));
+    }
+
+    #[test]
+    fn test_no_captions_leaves_result_unchanged() {
+        let mut result = result(&["Plain text without figures."], vec![image(0, Some(1), 100)]);
+        attach_figure_captions(&mut result);
+        assert!(result.metadata.figures.is_none());
+        assert!(result.images.as_ref().unwrap()[0].caption.is_none());
+    }
+}
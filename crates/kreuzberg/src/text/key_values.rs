@@ -0,0 +1,662 @@
+//! Key-value pair extraction for semi-structured documents.
+//!
+//! Invoices, receipts, forms and shipping documents carry most of their information as
+//! labelled values: `Invoice number: 2024-117`, `Due date  2024-05-31`, `Total  1,130.50`.
+//! With `kv_extraction` configured, these pairs are written to `metadata.key_values`:
+//!
+//! - a label followed by a colon is paired with the rest of its column, with the next
+//!   column of the line, or, when nothing follows the colon, with the text below the label
+//! - a known label (the built-in business labels and `KeyValueExtractionConfig::labels`)
+//!   is also recognised without a colon, when its value on the same line contains a digit
+//!
+//! Lines are split into columns at wide gaps. For PDFs with a text layer the PDF extractor
+//! builds lines and columns from word positions ([`KeyValueExtractor::extract_from_words`]),
+//! so a value below its label is found by horizontal overlap and every pair carries the
+//! bounding boxes of its label and value. For other documents the
+//! [`KeyValueExtractionProcessor`] post-processor scans the text, where a tab or two spaces
+//! separate columns and a value below its label is on the next line.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::KeyValueExtractionConfig;
+//! use kreuzberg::text::key_values::KeyValueExtractor;
+//!
+//! let extractor = KeyValueExtractor::new(&KeyValueExtractionConfig::default()).unwrap();
+//! let pairs = extractor.extract_from_text(
+//!     "ACME GmbH\nInvoice number: 2024-117    Due date: 2024-05-31\nBill to:\nContoso Ltd\n\
+//!      Total    1,130.50",
+//!     None,
+//! );
+//!
+//! let pairs: Vec<_> = pairs.iter().map(|pair| (pair.key.as_str(), pair.value.as_str())).collect();
+//! assert_eq!(
+//!     pairs,
+//!     [
+//!         ("Invoice number", "2024-117"),
+//!         ("Due date", "2024-05-31"),
+//!         ("Bill to", "Contoso Ltd"),
+//!         ("Total", "1,130.50"),
+//!     ]
+//! );
+//! ```
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::core::config::KeyValueExtractionConfig;
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::text::term_offsets::page_for_offset;
+use crate::types::{BoundingBox, KeyValuePair, PageBoundary};
+use crate::{ExtractionConfig, ExtractionResult, KreuzbergError, Result};
+
+/// Labels recognised without a colon when `builtin_labels` is enabled.
+const BUILTIN_LABELS: &[&str] = &[
+    "invoice number",
+    "invoice no",
+    "invoice date",
+    "order number",
+    "order no",
+    "order date",
+    "po number",
+    "purchase order",
+    "customer number",
+    "customer no",
+    "customer id",
+    "account number",
+    "reference",
+    "date",
+    "due date",
+    "payment due",
+    "issue date",
+    "delivery date",
+    "subtotal",
+    "total",
+    "total due",
+    "amount due",
+    "balance due",
+    "tax",
+    "vat",
+    "vat id",
+    "tax id",
+    "iban",
+    "payment terms",
+];
+
+/// Most words in a value; longer text after a colon is prose, not a field.
+const MAX_VALUE_WORDS: usize = 12;
+
+/// Horizontal gap, in multiples of the text height, that separates two columns of a line.
+const COLUMN_GAP_EMS: f64 = 1.0;
+
+/// Vertical gap, in multiples of the label height, within which a value below its label is searched.
+const VALUE_BELOW_EMS: f64 = 1.5;
+
+/// A tab or a run of two or more spaces separates columns in text.
+static COLUMN_SEPARATOR: Lazy<Regex> = Lazy::new(|| Regex::new(r"\t+|\s{2,}").expect("valid column regex"));
+
+/// A word on a page with its bounding box (top-left origin).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutWord {
+    /// Word text
+    pub text: String,
+    /// Bounding box of the word
+    pub bbox: BoundingBox,
+}
+
+/// A word, positioned when it comes from a page layout.
+#[derive(Debug, Clone)]
+struct Token {
+    text: String,
+    bbox: Option<BoundingBox>,
+}
+
+/// Words of one line between two column gaps.
+#[derive(Debug)]
+struct Column {
+    tokens: Vec<Token>,
+}
+
+impl Column {
+    fn bbox(&self) -> Option<BoundingBox> {
+        union(&self.tokens)
+    }
+}
+
+#[derive(Debug)]
+struct Line {
+    columns: Vec<Column>,
+    page: Option<usize>,
+}
+
+/// A label found at the start of a column, with the tokens that follow it in the column.
+struct Label {
+    key: Vec<Token>,
+    rest: Vec<Token>,
+    colon: bool,
+}
+
+/// Finds label/value pairs in text or in positioned words.
+#[derive(Debug, Clone)]
+pub struct KeyValueExtractor {
+    /// Known labels as lowercase words, longest first
+    labels: Vec<Vec<String>>,
+    max_label_words: usize,
+}
+
+impl KeyValueExtractor {
+    /// Create an extractor from a configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error when `max_label_words` is 0 or a label is empty.
+    pub fn new(config: &KeyValueExtractionConfig) -> Result<Self> {
+        if config.max_label_words == 0 {
+            return Err(KreuzbergError::validation(
+                "kv_extraction.max_label_words must be at least 1",
+            ));
+        }
+
+        let builtin: &[&str] = if config.builtin_labels { BUILTIN_LABELS } else { &[] };
+
+        let mut labels = Vec::new();
+        for label in builtin
+            .iter()
+            .map(|label| label.to_string())
+            .chain(config.labels.iter().cloned())
+        {
+            let words: Vec<String> = label.split_whitespace().map(normalize_word).collect();
+            if words.iter().all(String::is_empty) {
+                return Err(KreuzbergError::validation(format!(
+                    "kv_extraction.labels contains an empty label: {:?}",
+                    label
+                )));
+            }
+            if !labels.contains(&words) {
+                labels.push(words);
+            }
+        }
+        labels.sort_by_key(|words| std::cmp::Reverse(words.len()));
+
+        Ok(Self {
+            labels,
+            max_label_words: config.max_label_words,
+        })
+    }
+
+    /// Find pairs in text, one line at a time.
+    ///
+    /// A tab or two spaces separate columns. Pairs get the page of their line when
+    /// `boundaries` are given, and no bounding boxes.
+    pub fn extract_from_text(&self, text: &str, boundaries: Option<&[PageBoundary]>) -> Vec<KeyValuePair> {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        for raw_line in text.split('\n') {
+            let columns = COLUMN_SEPARATOR
+                .split(raw_line.trim())
+                .filter(|column| !column.is_empty())
+                .map(|column| Column {
+                    tokens: column
+                        .split_whitespace()
+                        .map(|word| Token {
+                            text: word.to_string(),
+                            bbox: None,
+                        })
+                        .collect(),
+                })
+                .collect();
+            lines.push(Line {
+                columns,
+                page: boundaries.and_then(|boundaries| page_for_offset(boundaries, offset)),
+            });
+            offset += raw_line.len() + 1;
+        }
+
+        self.extract(&lines)
+    }
+
+    /// Find pairs among the words of a page.
+    ///
+    /// Words are grouped into lines by vertical position and into columns at horizontal
+    /// gaps wider than the text height. Every pair gets `page` and the bounding boxes of
+    /// its label and value, in the unit of the word boxes.
+    pub fn extract_from_words(&self, words: &[LayoutWord], page: usize) -> Vec<KeyValuePair> {
+        let mut words: Vec<&LayoutWord> = words.iter().filter(|word| !word.text.trim().is_empty()).collect();
+        words.sort_by(|a, b| center_y(&a.bbox).total_cmp(&center_y(&b.bbox)));
+
+        let mut rows: Vec<Vec<&LayoutWord>> = Vec::new();
+        for word in words {
+            match rows.last_mut() {
+                Some(row) if same_row(row[0], word) => row.push(word),
+                _ => rows.push(vec![word]),
+            }
+        }
+
+        let lines: Vec<Line> = rows
+            .into_iter()
+            .map(|mut row| {
+                row.sort_by(|a, b| a.bbox.x.total_cmp(&b.bbox.x));
+                let mut columns: Vec<Column> = Vec::new();
+                let mut previous: Option<&LayoutWord> = None;
+                for word in row {
+                    let token = Token {
+                        text: word.text.clone(),
+                        bbox: Some(word.bbox),
+                    };
+                    match (previous, columns.last_mut()) {
+                        (Some(previous), Some(column)) if !column_gap(previous, word) => column.tokens.push(token),
+                        _ => columns.push(Column { tokens: vec![token] }),
+                    }
+                    previous = Some(word);
+                }
+                Line {
+                    columns,
+                    page: Some(page),
+                }
+            })
+            .collect();
+
+        self.extract(&lines)
+    }
+
+    fn extract(&self, lines: &[Line]) -> Vec<KeyValuePair> {
+        let mut pairs = Vec::new();
+        let mut used: HashSet<(usize, usize)> = HashSet::new();
+
+        for (line_index, line) in lines.iter().enumerate() {
+            for (column_index, column) in line.columns.iter().enumerate() {
+                if used.contains(&(line_index, column_index)) {
+                    continue;
+                }
+                let Some(label) = self.find_label(&column.tokens) else {
+                    continue;
+                };
+
+                let mut value = label.rest;
+                if value.is_empty()
+                    && let Some(next) = line.columns.get(column_index + 1)
+                    && self.find_label(&next.tokens).is_none()
+                {
+                    value = next.tokens.clone();
+                    used.insert((line_index, column_index + 1));
+                }
+                if value.is_empty() && label.colon {
+                    let label_bbox = union(&label.key);
+                    if let Some((below_line, below_column)) = self.value_below(lines, line_index, label_bbox) {
+                        value = lines[below_line].columns[below_column].tokens.clone();
+                        used.insert((below_line, below_column));
+                    }
+                }
+
+                if let Some(pair) = make_pair(&label.key, &value, label.colon, line.page) {
+                    pairs.push(pair);
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Label at the start of a column: the words before a colon, or a known label.
+    fn find_label(&self, tokens: &[Token]) -> Option<Label> {
+        for (index, token) in tokens.iter().take(self.max_label_words).enumerate() {
+            let Some(colon) = label_colon(&token.text) else {
+                continue;
+            };
+            let colon_len = token.text[colon..].chars().next().map_or(1, char::len_utf8);
+            let (before, after) = split_token(token, colon, colon + colon_len);
+
+            let mut key = tokens[..index].to_vec();
+            key.extend(before);
+            let mut rest: Vec<Token> = after.into_iter().collect();
+            rest.extend(tokens[index + 1..].iter().cloned());
+
+            return is_label(&key).then_some(Label { key, rest, colon: true });
+        }
+
+        let normalized: Vec<String> = tokens.iter().map(|token| normalize_word(&token.text)).collect();
+        self.labels
+            .iter()
+            .find(|label| normalized.starts_with(label))
+            .map(|label| Label {
+                key: tokens[..label.len()].to_vec(),
+                rest: tokens[label.len()..].to_vec(),
+                colon: false,
+            })
+    }
+
+    /// Column holding the value below a label that ends its column with a colon.
+    fn value_below(
+        &self,
+        lines: &[Line],
+        line_index: usize,
+        label_bbox: Option<BoundingBox>,
+    ) -> Option<(usize, usize)> {
+        let below_index = line_index + 1;
+        let below = lines.get(below_index)?;
+        if below.page != lines[line_index].page {
+            return None;
+        }
+
+        let column_index = match label_bbox {
+            Some(label_bbox) => below.columns.iter().position(|column| {
+                column.bbox().is_some_and(|bbox| {
+                    let gap = bbox.y - (label_bbox.y + label_bbox.height);
+                    gap <= label_bbox.height * VALUE_BELOW_EMS
+                        && bbox.x < label_bbox.x + label_bbox.width
+                        && label_bbox.x < bbox.x + bbox.width
+                })
+            })?,
+            None => 0,
+        };
+
+        let column = below.columns.get(column_index)?;
+        self.find_label(&column.tokens)
+            .is_none()
+            .then_some((below_index, column_index))
+    }
+}
+
+/// Byte index of a colon ending a label in `word`, ignoring times (`10:30`) and URLs.
+fn label_colon(word: &str) -> Option<usize> {
+    let (index, colon) = word.char_indices().find(|(_, c)| matches!(c, ':' | '：'))?;
+    let before = word[..index].chars().next_back();
+    let after = &word[index + colon.len_utf8()..];
+    let between_digits = before.is_some_and(|c| c.is_ascii_digit()) && after.starts_with(|c: char| c.is_ascii_digit());
+
+    (!between_digits && !after.starts_with("//")).then_some(index)
+}
+
+/// Split a token around the byte range `start..end`, dividing its bounding box by characters.
+fn split_token(token: &Token, start: usize, end: usize) -> (Option<Token>, Option<Token>) {
+    let before = &token.text[..start];
+    let after = &token.text[end..];
+    let total = token.text.chars().count().max(1) as f64;
+    let before_chars = before.chars().count() as f64;
+    let after_chars = after.chars().count() as f64;
+
+    let part = |text: &str, from: f64, chars: f64| {
+        (!text.is_empty()).then(|| Token {
+            text: text.to_string(),
+            bbox: token.bbox.map(|bbox| BoundingBox {
+                x: bbox.x + bbox.width * from / total,
+                width: bbox.width * chars / total,
+                ..bbox
+            }),
+        })
+    };
+
+    (
+        part(before, 0.0, before_chars),
+        part(after, total - after_chars, after_chars),
+    )
+}
+
+fn is_label(key: &[Token]) -> bool {
+    let text = join(key);
+    clean_key(&text).starts_with(char::is_alphabetic)
+}
+
+fn make_pair(key: &[Token], value: &[Token], colon: bool, page: Option<usize>) -> Option<KeyValuePair> {
+    let key_text = clean_key(&join(key)).to_string();
+    let value_text = clean_value(&join(value)).to_string();
+
+    if key_text.is_empty() || value_text.is_empty() || value.len() > MAX_VALUE_WORDS {
+        return None;
+    }
+    if !colon && !value_text.contains(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(KeyValuePair {
+        key: key_text,
+        value: value_text,
+        page,
+        key_bbox: union(key),
+        value_bbox: union(value),
+    })
+}
+
+/// Trim list markers, Markdown emphasis and table pipes around a label.
+fn clean_key(text: &str) -> &str {
+    text.trim_matches(|c: char| c.is_whitespace() || matches!(c, '*' | '_' | '-' | '#' | '>' | '|'))
+}
+
+fn clean_value(text: &str) -> &str {
+    text.trim_matches(|c: char| c.is_whitespace() || matches!(c, '*' | '_' | '|'))
+}
+
+fn join(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(|token| token.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Lowercase a word and drop trailing `.`, `:` and `#` so "No." matches "no".
+fn normalize_word(word: &str) -> String {
+    word.trim_end_matches(['.', ':', '：', '#']).to_lowercase()
+}
+
+/// Smallest box containing the boxes of all tokens, when every token has one.
+fn union(tokens: &[Token]) -> Option<BoundingBox> {
+    let mut boxes = tokens.iter().map(|token| token.bbox);
+    let first = boxes.next()??;
+    boxes.try_fold(first, |acc, bbox| {
+        let bbox = bbox?;
+        let x = acc.x.min(bbox.x);
+        let y = acc.y.min(bbox.y);
+        Some(BoundingBox {
+            x,
+            y,
+            width: (acc.x + acc.width).max(bbox.x + bbox.width) - x,
+            height: (acc.y + acc.height).max(bbox.y + bbox.height) - y,
+        })
+    })
+}
+
+fn center_y(bbox: &BoundingBox) -> f64 {
+    bbox.y + bbox.height / 2.0
+}
+
+fn same_row(first: &LayoutWord, word: &LayoutWord) -> bool {
+    let height = first.bbox.height.min(word.bbox.height).max(1.0);
+    (center_y(&first.bbox) - center_y(&word.bbox)).abs() <= height / 2.0
+}
+
+fn column_gap(previous: &LayoutWord, word: &LayoutWord) -> bool {
+    let gap = word.bbox.x - (previous.bbox.x + previous.bbox.width);
+    gap > previous.bbox.height.max(word.bbox.height).max(1.0) * COLUMN_GAP_EMS
+}
+
+/// Post-processor writing the label/value pairs found in the content to `metadata.key_values`.
+///
+/// Runs when `kv_extraction` is configured and the extractor did not already find pairs
+/// from the page layout (PDFs with a text layer).
+pub struct KeyValueExtractionProcessor;
+
+impl Plugin for KeyValueExtractionProcessor {
+    fn name(&self) -> &str {
+        "key-value-extraction"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for KeyValueExtractionProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(kv_config) = &config.kv_extraction else {
+            return Ok(());
+        };
+        if result.metadata.key_values.is_some() {
+            return Ok(());
+        }
+
+        let extractor = KeyValueExtractor::new(kv_config)?;
+        let boundaries = result
+            .metadata
+            .pages
+            .as_ref()
+            .and_then(|structure| structure.boundaries.as_deref());
+        result.metadata.key_values = Some(extractor.extract_from_text(&result.content, boundaries));
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config.kv_extraction.is_some() && result.metadata.key_values.is_none()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        // One pass over the lines: ~1ms per 100KB
+        (result.content.len() / 102400).max(1) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extractor() -> KeyValueExtractor {
+        KeyValueExtractor::new(&KeyValueExtractionConfig::default()).unwrap()
+    }
+
+    fn word(text: &str, x: f64, y: f64) -> LayoutWord {
+        LayoutWord {
+            text: text.to_string(),
+            bbox: BoundingBox {
+                x,
+                y,
+                width: text.chars().count() as f64 * 5.0,
+                height: 10.0,
+            },
+        }
+    }
+
+    fn pairs(pairs: &[KeyValuePair]) -> Vec<(&str, &str)> {
+        pairs
+            .iter()
+            .map(|pair| (pair.key.as_str(), pair.value.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_text_pairs_skip_prose_times_and_urls() {
+        let text = "Meeting notes\nThe call started at 10:30 and ended late\nSee https://example.com for details\n\
+                    Customer No. 88412\nVAT ID: DE123456789\nTotal revenue grew strongly\nNote: the following \
+                    items were discussed during the quarterly planning meeting with the whole team present";
+
+        assert_eq!(
+            pairs(&extractor().extract_from_text(text, None)),
+            [("Customer No.", "88412"), ("VAT ID", "DE123456789")]
+        );
+    }
+
+    #[test]
+    fn test_text_pairs_get_pages_from_boundaries() {
+        let text = "Order number: A-17\nShip to:\n\nDue date: 2024-06-01";
+        let boundaries = [
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 19,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 19,
+                byte_end: text.len(),
+                page_number: 2,
+            },
+        ];
+
+        let found = extractor().extract_from_text(text, Some(&boundaries));
+        assert_eq!(pairs(&found), [("Order number", "A-17"), ("Due date", "2024-06-01")]);
+        assert_eq!(found[0].page, Some(1));
+        assert_eq!(found[1].page, Some(2));
+        assert!(found[0].key_bbox.is_none());
+    }
+
+    #[test]
+    fn test_layout_pairs_right_and_below() {
+        let words = [
+            word("Invoice", 50.0, 50.0),
+            word("number:", 90.0, 50.0),
+            word("2024-117", 200.0, 51.0),
+            word("Bill", 50.0, 80.0),
+            word("to:", 75.0, 80.0),
+            word("Issue", 300.0, 80.0),
+            word("date", 330.0, 80.0),
+            word("2024-05-02", 380.0, 80.0),
+            word("Contoso", 52.0, 93.0),
+            word("Ltd", 92.0, 93.0),
+        ];
+
+        let found = extractor().extract_from_words(&words, 3);
+        assert_eq!(
+            pairs(&found),
+            [
+                ("Invoice number", "2024-117"),
+                ("Bill to", "Contoso Ltd"),
+                ("Issue date", "2024-05-02")
+            ]
+        );
+
+        let invoice = &found[0];
+        assert_eq!(invoice.page, Some(3));
+        assert_eq!(
+            invoice.key_bbox,
+            Some(BoundingBox {
+                x: 50.0,
+                y: 50.0,
+                width: 70.0,
+                height: 10.0
+            })
+        );
+        assert_eq!(invoice.value_bbox.map(|bbox| bbox.x), Some(200.0));
+        assert_eq!(found[1].value_bbox.map(|bbox| (bbox.x, bbox.y)), Some((52.0, 93.0)));
+    }
+
+    #[test]
+    fn test_custom_labels_and_validation() {
+        let config = KeyValueExtractionConfig {
+            builtin_labels: false,
+            labels: vec!["Kundennummer".to_string()],
+            ..Default::default()
+        };
+        let found = KeyValueExtractor::new(&config)
+            .unwrap()
+            .extract_from_text("Kundennummer 4711\nTotal 99.00", None);
+        assert_eq!(pairs(&found), [("Kundennummer", "4711")]);
+
+        let empty_label = KeyValueExtractionConfig {
+            labels: vec![" ".to_string()],
+            ..Default::default()
+        };
+        assert!(KeyValueExtractor::new(&empty_label).is_err());
+
+        let no_words = KeyValueExtractionConfig {
+            max_label_words: 0,
+            ..Default::default()
+        };
+        assert!(KeyValueExtractor::new(&no_words).is_err());
+    }
+}
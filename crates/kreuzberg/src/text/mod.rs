@@ -1,4 +1,16 @@
+pub mod classification;
+pub mod cleanup;
+pub mod encoding;
+pub mod figures;
+pub mod key_values;
+pub mod normalization;
+pub mod outline;
+pub mod preview;
+pub mod provenance;
+pub mod similarity;
+pub mod term_offsets;
 pub mod utf8_validation;
+pub mod watermarks;
 
 #[cfg(feature = "quality")]
 pub mod quality;
@@ -12,6 +24,18 @@ pub mod token_reduction;
 #[cfg(feature = "quality")]
 pub mod quality_processor;
 
+pub use classification::{DocumentClassification, DocumentClassificationProcessor, DocumentClassifier};
+pub use cleanup::strip_repeated_text;
+pub use encoding::{DecodedText, decode_text};
+pub use figures::attach_figure_captions;
+pub use key_values::{KeyValueExtractionProcessor, KeyValueExtractor};
+pub use normalization::{normalize_result, normalize_text};
+pub use preview::{PreviewGenerator, PreviewProcessor};
+pub use provenance::compute_provenance;
+pub use similarity::{embedding_similarity, minhash_similarity};
+pub use term_offsets::compute_term_offsets;
+pub use watermarks::{WatermarkDetector, detect_watermarks};
+
 #[cfg(feature = "quality")]
 pub use quality::{calculate_quality_score, clean_extracted_text, normalize_spaces};
 
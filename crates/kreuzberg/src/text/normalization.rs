@@ -0,0 +1,292 @@
+//! Deterministic content normalization.
+//!
+//! Extractors and OCR backends disagree on typographic details: curly versus straight
+//! quotes, en dashes versus hyphens, words hyphenated at line ends, runs of spaces left
+//! over from column layout. [`normalize_text`] applies a [`NormalizationConfig`] profile
+//! so that the same document always yields the same text, and therefore the same chunks,
+//! embeddings and hashes.
+//!
+//! Steps run in a fixed order: Unicode normalization, hyphenation repair, quote and dash
+//! standardization, then whitespace collapsing. Unicode normalization requires the
+//! `quality` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::core::config::NormalizationConfig;
+//! use kreuzberg::text::normalization::normalize_text;
+//!
+//! let config = NormalizationConfig { unicode: None, ..Default::default() };
+//! let text = normalize_text("\u{201C}Data\u{201D} \u{2013} pre-\nprocessing   steps", &config).unwrap();
+//! assert_eq!(text, "\"Data\" - preprocessing steps");
+//! ```
+
+use std::collections::HashMap;
+
+use crate::Result;
+use crate::core::config::NormalizationConfig;
+use crate::text::encoding;
+use crate::types::{ExtractionResult, PageBoundary};
+
+const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// Normalize a text according to the profile.
+pub fn normalize_text(text: &str, config: &NormalizationConfig) -> Result<String> {
+    let mut text = match config.unicode {
+        Some(form) => encoding::normalize(text.to_string(), form)?,
+        None => text.to_string(),
+    };
+
+    if config.dehyphenate {
+        text = repair_hyphenation(&text);
+    }
+    if config.standardize_quotes || config.standardize_dashes {
+        text = text
+            .chars()
+            .map(|ch| match ch {
+                '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' if config.standardize_quotes => '\'',
+                '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' if config.standardize_quotes => '"',
+                '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE58}' | '\u{FE63}' | '\u{FF0D}'
+                    if config.standardize_dashes =>
+                {
+                    '-'
+                }
+                other => other,
+            })
+            .collect();
+    }
+    if config.collapse_whitespace {
+        text = collapse_whitespace(&text);
+    }
+
+    Ok(text)
+}
+
+/// Normalize the content and per-page content of an extraction result.
+///
+/// When the result has page boundaries, each page range is normalized on its own and
+/// the boundaries are moved to the matching offsets in the normalized content, so
+/// page-aware chunking and term offsets keep working. The result is left unchanged
+/// when normalization fails.
+pub fn normalize_result(result: &mut ExtractionResult, config: &NormalizationConfig) -> Result<()> {
+    let boundaries = result
+        .metadata
+        .pages
+        .as_mut()
+        .and_then(|pages| pages.boundaries.as_mut());
+
+    result.content = match boundaries {
+        Some(boundaries) if !boundaries.is_empty() => normalize_paged(&result.content, boundaries, config)?,
+        _ => normalize_text(&result.content, config)?,
+    };
+
+    for page in result.pages.iter_mut().flatten() {
+        page.content = normalize_text(&page.content, config)?;
+    }
+
+    Ok(())
+}
+
+fn normalize_paged(content: &str, boundaries: &mut [PageBoundary], config: &NormalizationConfig) -> Result<String> {
+    let mut cuts: Vec<usize> = boundaries
+        .iter()
+        .flat_map(|boundary| [boundary.byte_start, boundary.byte_end])
+        .filter(|&offset| content.is_char_boundary(offset))
+        .chain([0, content.len()])
+        .collect();
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    let mut normalized = String::with_capacity(content.len());
+    let mut offsets = HashMap::with_capacity(cuts.len());
+    offsets.insert(0, 0);
+    for range in cuts.windows(2) {
+        normalized.push_str(&normalize_text(&content[range[0]..range[1]], config)?);
+        offsets.insert(range[1], normalized.len());
+    }
+
+    for boundary in boundaries.iter_mut() {
+        boundary.byte_start = offsets.get(&boundary.byte_start).copied().unwrap_or(normalized.len());
+        boundary.byte_end = offsets.get(&boundary.byte_end).copied().unwrap_or(normalized.len());
+    }
+
+    Ok(normalized)
+}
+
+/// Join words split by a hyphen at a line break (`exam-\nple` becomes `example`) and drop
+/// soft hyphens. The break is only repaired when a lowercase letter continues the word.
+fn repair_hyphenation(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut repaired = String::with_capacity(text.len());
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let ch = chars[idx];
+        if matches!(ch, '-' | '\u{2010}' | SOFT_HYPHEN) && idx > 0 && chars[idx - 1].is_alphabetic() {
+            let skip_blanks = |mut pos: usize| {
+                while pos < chars.len() && matches!(chars[pos], ' ' | '\t') {
+                    pos += 1;
+                }
+                pos
+            };
+
+            let mut next = skip_blanks(idx + 1);
+            if next < chars.len() && chars[next] == '\r' {
+                next += 1;
+            }
+            if next < chars.len() && chars[next] == '\n' {
+                next = skip_blanks(next + 1);
+                if next < chars.len() && chars[next].is_lowercase() {
+                    idx = next;
+                    continue;
+                }
+            }
+        }
+
+        if ch != SOFT_HYPHEN {
+            repaired.push(ch);
+        }
+        idx += 1;
+    }
+
+    repaired
+}
+
+/// Collapse runs of whitespace within lines to a single space, strip trailing whitespace,
+/// unify line endings and squeeze consecutive blank lines into one. Leading indentation is
+/// kept so Markdown lists and code blocks survive.
+fn collapse_whitespace(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut collapsed = String::with_capacity(text.len());
+    let mut blank_lines = 0;
+
+    for (line_idx, line) in text.split('\n').enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank_lines += 1;
+            if line_idx > 0 && blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        if line_idx > 0 {
+            collapsed.push('\n');
+        }
+
+        let indent_len = line.len() - line.trim_start().len();
+        collapsed.push_str(&line[..indent_len]);
+
+        let mut previous_space = false;
+        for ch in line[indent_len..].chars() {
+            if matches!(ch, '\u{200B}' | '\u{FEFF}') {
+                continue;
+            }
+            if ch.is_whitespace() {
+                if !previous_space {
+                    collapsed.push(' ');
+                }
+                previous_space = true;
+            } else {
+                collapsed.push(ch);
+                previous_space = false;
+            }
+        }
+    }
+
+    collapsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageStructure, PageUnitType};
+
+    fn profile() -> NormalizationConfig {
+        NormalizationConfig {
+            unicode: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_repair_hyphenation() {
+        assert_eq!(repair_hyphenation("infor-\nmation"), "information");
+        assert_eq!(repair_hyphenation("infor- \r\n  mation"), "information");
+        assert_eq!(repair_hyphenation("co\u{00AD}operate"), "cooperate");
+        assert_eq!(repair_hyphenation("Jean-\nPaul"), "Jean-\nPaul");
+        assert_eq!(repair_hyphenation("items:\n-\nfirst"), "items:\n-\nfirst");
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        let text = "Title  \r\n\r\n\r\n  - item\u{00A0}\tone\u{200B}\n\n\nend";
+        assert_eq!(collapse_whitespace(text), "Title\n\n  - item one\n\nend");
+    }
+
+    #[test]
+    fn test_normalize_text_steps_can_be_disabled() {
+        let text = "\u{2018}a\u{2019} \u{2014} b";
+        assert_eq!(normalize_text(text, &profile()).unwrap(), "'a' - b");
+
+        let config = NormalizationConfig {
+            standardize_quotes: false,
+            standardize_dashes: false,
+            ..profile()
+        };
+        assert_eq!(normalize_text(text, &config).unwrap(), text);
+    }
+
+    #[test]
+    fn test_normalize_result_rewrites_page_boundaries() {
+        let first = "Page  one \u{2013} intro";
+        let second = "Page two";
+        let mut result = ExtractionResult {
+            content: format!("{}{}", first, second),
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: 2,
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(vec![
+                        PageBoundary {
+                            byte_start: 0,
+                            byte_end: first.len(),
+                            page_number: 1,
+                        },
+                        PageBoundary {
+                            byte_start: first.len(),
+                            byte_end: first.len() + second.len(),
+                            page_number: 2,
+                        },
+                    ]),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        };
+
+        normalize_result(&mut result, &profile()).unwrap();
+
+        assert_eq!(result.content, "Page one - introPage two");
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        assert_eq!((boundaries[0].byte_start, boundaries[0].byte_end), (0, 16));
+        assert_eq!((boundaries[1].byte_start, boundaries[1].byte_end), (16, 24));
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_normalize_text_unicode_form() {
+        let config = NormalizationConfig::default();
+        assert_eq!(normalize_text("Cafe\u{0301}", &config).unwrap(), "Café");
+    }
+}
@@ -0,0 +1,396 @@
+//! Document outline (heading hierarchy) construction.
+//!
+//! Extractors collect a flat, document-ordered list of [`Heading`]s from whatever the
+//! format offers (PDF bookmarks, DOCX heading styles, Markdown produced from HTML) and
+//! [`build_outline`] nests them into the `metadata.outline` tree.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::text::outline::{build_outline, markdown_headings};
+//!
+//! let outline = build_outline(markdown_headings("# Guide\n\n## Install\n\n## Usage\n"));
+//! assert_eq!(outline[0].title, "Guide");
+//! assert_eq!(outline[0].children.len(), 2);
+//! assert_eq!(outline[0].children[1].byte_offset, Some(24));
+//! ```
+
+use crate::text::term_offsets::page_for_offset;
+use crate::types::{OutlineEntry, PageBoundary};
+
+/// Deepest heading level kept in an outline.
+const MAX_LEVEL: u8 = 6;
+
+/// Longest line (in characters) considered by numbered-heading detection.
+const MAX_NUMBERED_HEADING_CHARS: usize = 80;
+
+/// Most words allowed in a detected numbered heading title.
+const MAX_NUMBERED_HEADING_WORDS: usize = 12;
+
+/// A heading found in a document, before nesting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heading {
+    /// Heading level (1 = top level)
+    pub level: u8,
+    /// Heading text
+    pub title: String,
+    /// Page the heading appears on (1-indexed), when known
+    pub page: Option<usize>,
+    /// Byte offset of the heading text in the extracted content, when known
+    pub byte_offset: Option<usize>,
+}
+
+impl Heading {
+    /// Create a heading with no known position.
+    pub fn new(level: u8, title: impl Into<String>) -> Self {
+        Self {
+            level: level.clamp(1, MAX_LEVEL),
+            title: title.into(),
+            page: None,
+            byte_offset: None,
+        }
+    }
+}
+
+/// Nest document-ordered headings into an outline tree.
+///
+/// Each heading becomes a child of the closest preceding heading with a lower level,
+/// so skipped levels (an `h1` followed directly by an `h3`) still nest under their parent.
+pub fn build_outline(headings: Vec<Heading>) -> Vec<OutlineEntry> {
+    let mut roots = Vec::new();
+    let mut open: Vec<OutlineEntry> = Vec::new();
+
+    for heading in headings {
+        while open.last().is_some_and(|entry| entry.level >= heading.level) {
+            let done = open.pop().expect("stack is not empty");
+            attach(&mut open, &mut roots, done);
+        }
+        open.push(OutlineEntry {
+            level: heading.level,
+            title: heading.title,
+            page: heading.page,
+            byte_offset: heading.byte_offset,
+            children: Vec::new(),
+        });
+    }
+
+    while let Some(done) = open.pop() {
+        attach(&mut open, &mut roots, done);
+    }
+
+    roots
+}
+
+fn attach(open: &mut [OutlineEntry], roots: &mut Vec<OutlineEntry>, entry: OutlineEntry) {
+    match open.last_mut() {
+        Some(parent) => parent.children.push(entry),
+        None => roots.push(entry),
+    }
+}
+
+/// Collect ATX (`## Title`) and setext (`Title` over `===`/`---`) headings from Markdown.
+///
+/// Headings inside fenced code blocks are ignored. Byte offsets point at the heading text.
+pub fn markdown_headings(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut in_fence = false;
+    let mut previous: Option<(usize, &str)> = None;
+    let mut line_start = 0;
+
+    for line in content.split_inclusive('\n') {
+        let offset = line_start;
+        line_start += line.len();
+        let line = line.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            previous = None;
+            continue;
+        }
+        if in_fence || indent > 3 {
+            previous = None;
+            continue;
+        }
+
+        if let Some((level, title, title_offset)) = atx_heading(trimmed) {
+            headings.push(Heading {
+                byte_offset: Some(offset + indent + title_offset),
+                ..Heading::new(level, title)
+            });
+            previous = None;
+            continue;
+        }
+
+        if let Some(level) = setext_underline(trimmed)
+            && let Some((previous_offset, previous_line)) = previous
+        {
+            let title = previous_line.trim();
+            let title_offset = previous_offset + (previous_line.len() - previous_line.trim_start().len());
+            headings.push(Heading {
+                byte_offset: Some(title_offset),
+                ..Heading::new(level, title)
+            });
+            previous = None;
+            continue;
+        }
+
+        previous = if is_setext_candidate(trimmed) {
+            Some((offset, line))
+        } else {
+            None
+        };
+    }
+
+    headings
+}
+
+/// Parse an ATX heading line, returning its level, title and the title's offset in `line`.
+fn atx_heading(line: &str) -> Option<(u8, &str, usize)> {
+    let hashes = line.bytes().take_while(|&b| b == b'#').count();
+    if hashes == 0 || hashes > MAX_LEVEL as usize {
+        return None;
+    }
+    let rest = &line[hashes..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    let title_start = hashes + (rest.len() - rest.trim_start().len());
+    let mut title = line[title_start..].trim_end();
+    let without_closing = title.trim_end_matches('#');
+    if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        title = without_closing.trim_end();
+    }
+
+    (!title.is_empty()).then_some((hashes as u8, title, title_start))
+}
+
+fn setext_underline(line: &str) -> Option<u8> {
+    let line = line.trim_end();
+    if line.len() < 3 {
+        return None;
+    }
+    if line.bytes().all(|b| b == b'=') {
+        Some(1)
+    } else if line.bytes().all(|b| b == b'-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Whether a line could be the text of a setext heading (not blank, a list item or a table row).
+fn is_setext_candidate(line: &str) -> bool {
+    !line.trim().is_empty()
+        && !line.starts_with(['|', '>', '-', '*', '+'])
+        && !line
+            .split_once(". ")
+            .is_some_and(|(number, _)| number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Detect numbered section headings (`1 Introduction`, `2.3. Results`) in plain text.
+///
+/// Used for PDFs without bookmarks. A line qualifies when it is short, starts with a
+/// dotted section number followed by a capitalised title and does not end like a
+/// sentence. Top-level numbers must increase by one and sub-sections must belong to
+/// the current top-level section, which filters out most numbered lists.
+pub fn detect_numbered_headings(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut current_section: Option<u32> = None;
+    let mut line_start = 0;
+
+    for line in content.split_inclusive('\n') {
+        let offset = line_start;
+        line_start += line.len();
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.chars().count() > MAX_NUMBERED_HEADING_CHARS {
+            continue;
+        }
+
+        let Some((numbers, title)) = split_section_number(trimmed) else {
+            continue;
+        };
+        let top = numbers[0];
+        let in_sequence = match (numbers.len(), current_section) {
+            (1, None) => top <= 1,
+            (1, Some(current)) => top == current + 1,
+            (_, current) => current == Some(top),
+        };
+        if !in_sequence {
+            continue;
+        }
+        if numbers.len() == 1 {
+            current_section = Some(top);
+        }
+
+        let title_offset = offset + (line.len() - line.trim_start().len()) + (trimmed.len() - title.len());
+        headings.push(Heading {
+            byte_offset: Some(title_offset),
+            ..Heading::new(numbers.len().min(MAX_LEVEL as usize) as u8, title)
+        });
+    }
+
+    headings
+}
+
+/// Split `2.3. Results` into `[2, 3]` and `Results` when the line looks like a heading.
+fn split_section_number(line: &str) -> Option<(Vec<u32>, &str)> {
+    let (number, title) = line.split_once([' ', '\t'])?;
+    let number = number.strip_suffix('.').unwrap_or(number);
+    let numbers = number
+        .split('.')
+        .map(|part| {
+            if part.is_empty() || part.len() > 3 {
+                None
+            } else {
+                part.parse::<u32>().ok()
+            }
+        })
+        .collect::<Option<Vec<u32>>>()?;
+
+    let title = title.trim();
+    let starts_capitalised = title.chars().next().is_some_and(char::is_uppercase);
+    let sentence_like = title.ends_with(['.', ',', ';', ':']);
+    let word_count = title.split_whitespace().count();
+    (starts_capitalised && !sentence_like && word_count <= MAX_NUMBERED_HEADING_WORDS).then_some((numbers, title))
+}
+
+/// Fill in missing byte offsets and pages for headings by searching `content`.
+///
+/// Headings are searched for in document order, so repeated titles resolve to successive
+/// occurrences. A heading whose page is known is searched for from the start of that page.
+/// Headings that cannot be found keep `byte_offset: None`. Pages are derived from offsets
+/// when `boundaries` are available.
+pub fn locate_headings(content: &str, headings: &mut [Heading], boundaries: Option<&[PageBoundary]>) {
+    let mut cursor = 0;
+
+    for heading in headings.iter_mut() {
+        if heading.byte_offset.is_none() && !heading.title.is_empty() {
+            let page_start = heading
+                .page
+                .zip(boundaries)
+                .and_then(|(page, boundaries)| boundaries.iter().find(|boundary| boundary.page_number == page))
+                .map(|boundary| boundary.byte_start);
+            let start = page_start.map_or(cursor, |page_start| page_start.max(cursor));
+
+            heading.byte_offset = find_from(content, &heading.title, start).or_else(|| {
+                if start > cursor {
+                    find_from(content, &heading.title, cursor)
+                } else {
+                    None
+                }
+            });
+        }
+
+        if let Some(offset) = heading.byte_offset {
+            cursor = cursor.max(offset + heading.title.len()).min(content.len());
+            if heading.page.is_none() {
+                heading.page = boundaries.and_then(|boundaries| page_for_offset(boundaries, offset));
+            }
+        }
+    }
+}
+
+fn find_from(content: &str, needle: &str, start: usize) -> Option<usize> {
+    content.get(start..)?.find(needle).map(|idx| start + idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles(entries: &[OutlineEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_build_outline_nests_by_level() {
+        let headings = vec![
+            Heading::new(1, "A"),
+            Heading::new(3, "A.1"),
+            Heading::new(2, "A.2"),
+            Heading::new(3, "A.2.1"),
+            Heading::new(1, "B"),
+        ];
+
+        let outline = build_outline(headings);
+        assert_eq!(titles(&outline), ["A", "B"]);
+        assert_eq!(titles(&outline[0].children), ["A.1", "A.2"]);
+        assert_eq!(titles(&outline[0].children[1].children), ["A.2.1"]);
+        assert!(outline[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_markdown_headings_atx_and_setext() {
+        let content = "Title\n=====\n\nIntro\n\n## Part *one* ##\n\n```\n# not a heading\n```\n\nSub\n---\n";
+        let headings = markdown_headings(content);
+
+        let found: Vec<(u8, &str)> = headings.iter().map(|h| (h.level, h.title.as_str())).collect();
+        assert_eq!(found, [(1, "Title"), (2, "Part *one*"), (2, "Sub")]);
+        for heading in &headings {
+            let offset = heading.byte_offset.unwrap();
+            assert!(content[offset..].starts_with(&heading.title));
+        }
+    }
+
+    #[test]
+    fn test_markdown_headings_ignores_lists_and_hashtags() {
+        let content = "#hashtag\n\n- item\n---\n\n1. step\n---\n";
+        assert!(markdown_headings(content).is_empty());
+    }
+
+    #[test]
+    fn test_detect_numbered_headings() {
+        let content = "1 Introduction\nSome text.\n1.1 Background\n2. Methods\n1. First step in a list\n2.1 Sampling\n\
+                       3 Results were good.\n3 Results\n";
+        let headings = detect_numbered_headings(content);
+
+        let found: Vec<(u8, &str)> = headings.iter().map(|h| (h.level, h.title.as_str())).collect();
+        assert_eq!(
+            found,
+            [
+                (1, "Introduction"),
+                (2, "Background"),
+                (1, "Methods"),
+                (2, "Sampling"),
+                (1, "Results")
+            ]
+        );
+        let last = headings.last().unwrap();
+        assert_eq!(&content[last.byte_offset.unwrap()..content.len() - 1], "Results");
+    }
+
+    #[test]
+    fn test_locate_headings_uses_pages() {
+        let content = "Summary\nintro text\nSummary\nmore";
+        let boundaries = [
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 19,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 19,
+                byte_end: content.len(),
+                page_number: 2,
+            },
+        ];
+        let mut headings = vec![
+            Heading {
+                page: Some(2),
+                ..Heading::new(1, "Summary")
+            },
+            Heading::new(1, "missing"),
+            Heading::new(2, "more"),
+        ];
+
+        locate_headings(content, &mut headings, Some(&boundaries));
+
+        assert_eq!(headings[0].byte_offset, Some(19));
+        assert_eq!(headings[1].byte_offset, None);
+        assert_eq!(headings[2].byte_offset, Some(27));
+        assert_eq!(headings[2].page, Some(2));
+    }
+}
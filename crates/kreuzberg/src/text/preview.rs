@@ -0,0 +1,515 @@
+//! Document preview snippets.
+//!
+//! Document lists and search results show a short snippet per document. With `preview`
+//! configured, the [`PreviewProcessor`] post-processor writes the first `sentences`
+//! sentences of the content to `metadata.preview`, so applications get a snippet without
+//! transferring the whole content to the host language and segmenting it there.
+//!
+//! The first sentences of a document are often not the ones worth showing. With
+//! `skip_boilerplate` (the default), the following lines are skipped before sentences are
+//! taken:
+//!
+//! - headings: Markdown headings, and short or all-caps lines without sentence punctuation
+//!   that start a paragraph (titles, section names, letterheads)
+//! - running headers and footers: short lines repeated three or more times
+//! - page numbers, table of contents entries, copyright and confidentiality notices
+//! - Markdown tables, code blocks, images and HTML comments
+//!
+//! and fragments with fewer than four words are not counted as sentences. Lines of a
+//! paragraph are joined, so sentences wrapped across lines by PDF extraction are kept whole.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::PreviewConfig;
+//! use kreuzberg::text::preview::PreviewGenerator;
+//!
+//! let generator = PreviewGenerator::new(&PreviewConfig { sentences: 2, ..Default::default() }).unwrap();
+//!
+//! let preview = generator.generate(
+//!     "ACME Research - Confidential\n\nQUARTERLY REPORT\n\n\
+//!      Revenue grew by 12% in the third quarter, driven by\nnew customers in Europe. \
+//!      Costs remained flat. Dr. Smith will present the details.\n\nPage 1 of 3",
+//! );
+//! assert_eq!(
+//!     preview.as_deref(),
+//!     Some(
+//!         "Revenue grew by 12% in the third quarter, driven by new customers in Europe. \
+//!          Dr. Smith will present the details."
+//!     )
+//! );
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::core::config::PreviewConfig;
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::{ExtractionConfig, ExtractionResult, KreuzbergError, Result};
+
+/// Metadata key holding the preview text.
+pub const PREVIEW_METADATA_KEY: &str = "preview";
+
+/// Only the start of the content is scanned, a preview never needs more.
+const MAX_SCAN_BYTES: usize = 256 * 1024;
+
+/// Longer sentences are cut at a word boundary and end with an ellipsis.
+const MAX_SENTENCE_CHARS: usize = 400;
+
+/// Fragments with fewer words are not counted as sentences when skipping boilerplate.
+const MIN_SENTENCE_WORDS: usize = 4;
+
+/// Fragments in scripts without spaces (Chinese, Japanese) need this many characters.
+const MIN_SENTENCE_CJK_CHARS: usize = 10;
+
+/// Lines without sentence punctuation and fewer words are headings when they start a paragraph.
+const MAX_HEADING_WORDS: usize = 6;
+
+/// All-caps lines with fewer words are headings when they start a paragraph.
+const MAX_CAPS_HEADING_WORDS: usize = 12;
+
+/// Lines repeated this many times are running headers or footers.
+const MIN_REPEATED_LINE_COUNT: usize = 3;
+
+/// Longer lines are never considered running headers or footers.
+const MAX_REPEATED_LINE_CHARS: usize = 80;
+
+/// Notices with more words are kept, they are likely prose mentioning copyright.
+const MAX_NOTICE_WORDS: usize = 20;
+
+/// Abbreviations whose trailing period does not end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "e.g", "i.e", "cf", "ca", "approx", "fig", "no", "al",
+    "dept", "corp", "ltd", "co", "u.s",
+];
+
+static PAGE_NUMBER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:(?:page|p\.|seite)\s*)?\d{1,4}(?:\s*(?:/|of|von)\s*\d{1,4})?$|^[-–—]\s*\d{1,4}\s*[-–—]$")
+        .expect("valid page number regex")
+});
+
+static TOC_ENTRY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:\.{4,}|…{2,}|(?:\. ){3,})\s*\d{1,4}$").expect("valid table of contents regex"));
+
+static NOTICE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)©|\(c\)\s*\d{4}|\bcopyright\b|\ball rights reserved\b|\bconfidential\b|\bproprietary\b")
+        .expect("valid notice regex")
+});
+
+/// Generates preview snippets from document content.
+#[derive(Debug, Clone)]
+pub struct PreviewGenerator {
+    sentences: usize,
+    skip_boilerplate: bool,
+}
+
+impl PreviewGenerator {
+    /// Create a generator from a configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error when `sentences` is 0.
+    pub fn new(config: &PreviewConfig) -> Result<Self> {
+        if config.sentences == 0 {
+            return Err(KreuzbergError::validation("preview.sentences must be at least 1"));
+        }
+
+        Ok(Self {
+            sentences: config.sentences,
+            skip_boilerplate: config.skip_boilerplate,
+        })
+    }
+
+    /// The first sentences of `content`, joined by spaces, or `None` when it has none.
+    pub fn generate(&self, content: &str) -> Option<String> {
+        let mut end = content.len().min(MAX_SCAN_BYTES);
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        let text = &content[..end];
+
+        let repeated = if self.skip_boilerplate {
+            repeated_lines(text)
+        } else {
+            HashSet::new()
+        };
+
+        let mut sentences = Vec::new();
+        for paragraph in self.paragraphs(text, &repeated) {
+            for sentence in split_sentences(&paragraph) {
+                if self.skip_boilerplate && !is_meaningful(sentence) {
+                    continue;
+                }
+                sentences.push(truncate_sentence(sentence));
+                if sentences.len() == self.sentences {
+                    return Some(sentences.join(" "));
+                }
+            }
+        }
+
+        (!sentences.is_empty()).then(|| sentences.join(" "))
+    }
+
+    /// Paragraphs of `text` with their lines joined, boilerplate lines removed.
+    fn paragraphs(&self, text: &str, repeated: &HashSet<&str>) -> Vec<String> {
+        let mut paragraphs = Vec::new();
+        let mut current = String::new();
+        let mut in_code_block = false;
+
+        let mut flush = |current: &mut String| {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(current));
+            }
+        };
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                flush(&mut current);
+                continue;
+            }
+
+            if self.skip_boilerplate {
+                if line.starts_with("```") || line.starts_with("~~~") {
+                    flush(&mut current);
+                    in_code_block = !in_code_block;
+                    continue;
+                }
+                if in_code_block || is_boilerplate_line(line, repeated) {
+                    flush(&mut current);
+                    continue;
+                }
+                if ends_sentence(&current) && is_heading_line(line) {
+                    flush(&mut current);
+                    continue;
+                }
+            } else if line.starts_with('#') {
+                flush(&mut current);
+                current.push_str(line.trim_start_matches('#').trim());
+                flush(&mut current);
+                continue;
+            }
+
+            append_line(&mut current, line);
+        }
+        flush(&mut current);
+
+        paragraphs
+    }
+}
+
+/// Short lines occurring at least [`MIN_REPEATED_LINE_COUNT`] times.
+fn repeated_lines(text: &str) -> HashSet<&str> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if !line.is_empty() && line.len() <= MAX_REPEATED_LINE_CHARS {
+            *counts.entry(line).or_default() += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count >= MIN_REPEATED_LINE_COUNT)
+        .map(|(line, _)| line)
+        .collect()
+}
+
+fn is_boilerplate_line(line: &str, repeated: &HashSet<&str>) -> bool {
+    line.starts_with('#')
+        || line.starts_with('|')
+        || line.starts_with("![")
+        || line.starts_with("<!--")
+        || repeated.contains(line)
+        || PAGE_NUMBER.is_match(line)
+        || TOC_ENTRY.is_match(line)
+        || (NOTICE.is_match(line) && line.split_whitespace().count() <= MAX_NOTICE_WORDS)
+}
+
+/// Titles, section names and letterheads: short lines without sentence punctuation, or all caps.
+fn is_heading_line(line: &str) -> bool {
+    if line.ends_with(['-', ',']) {
+        return false;
+    }
+
+    let words = line.split_whitespace().count();
+    let letters = line.chars().filter(|c| c.is_alphabetic()).count();
+    let all_caps = letters >= 2 && !line.chars().any(char::is_lowercase);
+
+    (words < MAX_HEADING_WORDS && !ends_sentence(line)) || (all_caps && words < MAX_CAPS_HEADING_WORDS)
+}
+
+/// Whether `text` is empty or ends with sentence punctuation, possibly followed by closing quotes.
+fn ends_sentence(text: &str) -> bool {
+    match text.trim_end_matches(is_closing).chars().next_back() {
+        None => true,
+        Some(c) => is_terminator(c) || matches!(c, ':' | ';'),
+    }
+}
+
+fn append_line(paragraph: &mut String, line: &str) {
+    if paragraph.is_empty() {
+        paragraph.push_str(line);
+        return;
+    }
+
+    // Join words hyphenated across a line break ("ex-\nample" → "example")
+    let hyphenated = paragraph.ends_with('-')
+        && paragraph[..paragraph.len() - 1]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphabetic)
+        && line.chars().next().is_some_and(char::is_lowercase);
+    if hyphenated {
+        paragraph.pop();
+    } else {
+        paragraph.push(' ');
+    }
+
+    let mut words = line.split_whitespace();
+    if let Some(first) = words.next() {
+        paragraph.push_str(first);
+    }
+    for word in words {
+        paragraph.push(' ');
+        paragraph.push_str(word);
+    }
+}
+
+fn is_terminator(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？')
+}
+
+fn is_closing(c: char) -> bool {
+    matches!(c, '"' | '\'' | '”' | '’' | ')' | ']' | '»' | '」')
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30FF}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{AC00}'..='\u{D7AF}')
+}
+
+/// Split a paragraph into sentences at terminators followed by a space and a non-lowercase
+/// character, except after abbreviations and initials.
+fn split_sentences(paragraph: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = paragraph.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        if !is_terminator(c) {
+            continue;
+        }
+
+        let mut end = index + c.len_utf8();
+        while let Some(&(next_index, next)) = chars.peek() {
+            if is_terminator(next) || is_closing(next) {
+                end = next_index + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let rest = &paragraph[end..];
+        let boundary = if matches!(c, '。' | '！' | '？') {
+            true
+        } else {
+            let next = rest.trim_start().chars().next();
+            rest.starts_with(char::is_whitespace)
+                && !next.is_some_and(char::is_lowercase)
+                && !(c == '.' && is_abbreviation(&paragraph[start..index]))
+        };
+
+        if boundary {
+            let sentence = paragraph[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+
+    let sentence = paragraph[start..].trim();
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+
+    sentences
+}
+
+/// Whether the last word of `text` is an abbreviation or an initial.
+fn is_abbreviation(text: &str) -> bool {
+    let word = text
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches(|c: char| !c.is_alphanumeric());
+    let mut chars = word.chars();
+    let initial = matches!((chars.next(), chars.next()), (Some(c), None) if c.is_uppercase());
+
+    initial || ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+/// Sentences, not fragments: enough words, and mostly letters.
+fn is_meaningful(sentence: &str) -> bool {
+    let visible = sentence.chars().filter(|c| !c.is_whitespace()).count();
+    let letters = sentence.chars().filter(|c| c.is_alphabetic()).count();
+    if letters * 2 < visible {
+        return false;
+    }
+
+    sentence.split_whitespace().count() >= MIN_SENTENCE_WORDS
+        || sentence.chars().filter(|&c| is_cjk(c)).count() >= MIN_SENTENCE_CJK_CHARS
+}
+
+fn truncate_sentence(sentence: &str) -> String {
+    let Some((cut, _)) = sentence.char_indices().nth(MAX_SENTENCE_CHARS) else {
+        return sentence.to_string();
+    };
+
+    let head = &sentence[..cut];
+    let head = head.rfind(char::is_whitespace).map_or(head, |space| &head[..space]);
+    format!("{}…", head.trim_end_matches(|c: char| c.is_whitespace() || c == ','))
+}
+
+/// Post-processor writing the preview to `metadata.preview`.
+///
+/// Runs in the late stage, so the preview is taken from the final content.
+pub struct PreviewProcessor;
+
+impl Plugin for PreviewProcessor {
+    fn name(&self) -> &str {
+        "document-preview"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for PreviewProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(preview_config) = &config.preview else {
+            return Ok(());
+        };
+
+        if let Some(preview) = PreviewGenerator::new(preview_config)?.generate(&result.content) {
+            result
+                .metadata
+                .additional
+                .insert(PREVIEW_METADATA_KEY.to_string(), serde_json::Value::String(preview));
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Late
+    }
+
+    fn should_process(&self, _result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config.preview.is_some()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        // One pass over at most 256KB of lines
+        (result.content.len().min(MAX_SCAN_BYTES) / 102400).max(1) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview(content: &str, sentences: usize) -> Option<String> {
+        PreviewGenerator::new(&PreviewConfig {
+            sentences,
+            ..Default::default()
+        })
+        .unwrap()
+        .generate(content)
+    }
+
+    #[test]
+    fn test_preview_skips_boilerplate() {
+        let content = "ACME Corp\nInternal memo\n\n# Project Update\n\n\
+                       | Task | Owner |\n|---|---|\n| Parser | Ana |\n\n\
+                       The parser rewrite is fin-\nished and merged. Benchmarks show a 3x speedup on large PDFs.\n\n\
+                       ACME Corp\n2\n\nWe will release it next week after the review.\n\nACME Corp\n3";
+
+        assert_eq!(
+            preview(content, 3).as_deref(),
+            Some(
+                "The parser rewrite is finished and merged. Benchmarks show a 3x speedup on large PDFs. \
+                 We will release it next week after the review."
+            )
+        );
+        assert_eq!(
+            preview(content, 1).as_deref(),
+            Some("The parser rewrite is finished and merged.")
+        );
+    }
+
+    #[test]
+    fn test_preview_keeps_abbreviations_and_initials() {
+        let sentences =
+            split_sentences("Dr. J. Smith met Mr. Jones at 3 p.m. today. They agreed, e.g. on prices. Done!");
+        assert_eq!(
+            sentences,
+            vec![
+                "Dr. J. Smith met Mr. Jones at 3 p.m. today.",
+                "They agreed, e.g. on prices.",
+                "Done!"
+            ]
+        );
+        assert_eq!(
+            split_sentences("日本語の文です。次の文です。"),
+            vec!["日本語の文です。", "次の文です。"]
+        );
+    }
+
+    #[test]
+    fn test_preview_without_skipping_boilerplate() {
+        let generator = PreviewGenerator::new(&PreviewConfig {
+            sentences: 2,
+            skip_boilerplate: false,
+        })
+        .unwrap();
+
+        assert_eq!(
+            generator
+                .generate("# Title\n\nShort one. Another sentence here.")
+                .as_deref(),
+            Some("Title Short one.")
+        );
+        assert_eq!(generator.generate("  \n\n"), None);
+        assert!(
+            PreviewGenerator::new(&PreviewConfig {
+                sentences: 0,
+                ..Default::default()
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_preview_truncates_long_sentences() {
+        let content = format!("{} end.", "word ".repeat(200));
+        let preview = preview(&content, 1).unwrap();
+        assert!(preview.ends_with('…'));
+        assert!(preview.chars().count() <= MAX_SENTENCE_CHARS + 1);
+    }
+}
@@ -0,0 +1,274 @@
+//! Content provenance for auditing and confidence-weighted indexing.
+//!
+//! Attributes every byte range of extracted content to the source it came from: the
+//! document's own text, OCR of a page, a reconstructed table or an attached file. Ranges
+//! no extractor claimed are attributed to the text layer.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::text::provenance::compute_provenance;
+//! use kreuzberg::{ContentSource, ProvenanceSpan};
+//!
+//! let content = "Native text\n\nScanned text";
+//! let ocr = ProvenanceSpan {
+//!     byte_start: 13,
+//!     byte_end: content.len(),
+//!     source: ContentSource::Ocr { page: Some(2) },
+//! };
+//!
+//! let spans = compute_provenance(content, &[ocr]);
+//! assert_eq!(spans.len(), 2);
+//! assert_eq!(spans[0].source, ContentSource::TextLayer);
+//! assert_eq!((spans[0].byte_start, spans[0].byte_end), (0, 13));
+//! ```
+
+use crate::types::{ContentSource, ExtractionResult, PageBoundary, ProvenanceSpan};
+
+use super::term_offsets::page_for_offset;
+
+/// Cover `content` with ordered, non-overlapping spans.
+///
+/// `known` lists the ranges whose source is known; where they overlap, the one listed
+/// first wins. Offsets are clamped to `content` and to character boundaries, gaps are
+/// attributed to [`ContentSource::TextLayer`] and adjacent spans with the same source
+/// are merged. Empty content has no spans.
+///
+/// # Arguments
+///
+/// * `content` - The extracted text
+/// * `known` - Spans recorded by extractors, in order of precedence
+pub fn compute_provenance(content: &str, known: &[ProvenanceSpan]) -> Vec<ProvenanceSpan> {
+    let known: Vec<(usize, usize, &ContentSource)> = known
+        .iter()
+        .map(|span| {
+            let end = char_boundary(content, span.byte_end);
+            (char_boundary(content, span.byte_start).min(end), end, &span.source)
+        })
+        .filter(|(start, end, _)| start < end)
+        .collect();
+
+    let mut cuts: Vec<usize> = known.iter().flat_map(|&(start, end, _)| [start, end]).collect();
+    cuts.extend([0, content.len()]);
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    let mut spans: Vec<ProvenanceSpan> = Vec::new();
+    for window in cuts.windows(2) {
+        let (byte_start, byte_end) = (window[0], window[1]);
+        let source = known
+            .iter()
+            .find(|&&(start, end, _)| start <= byte_start && byte_end <= end)
+            .map_or(ContentSource::TextLayer, |&(_, _, source)| source.clone());
+
+        match spans.last_mut() {
+            Some(last) if last.source == source => last.byte_end = byte_end,
+            _ => spans.push(ProvenanceSpan {
+                byte_start,
+                byte_end,
+                source,
+            }),
+        }
+    }
+
+    spans
+}
+
+/// Collect the spans whose source is known for `result`, in order of precedence.
+///
+/// These are the spans extractors stored in `result.provenance` (attachments), the
+/// markdown of each table found in the content, and the OCR ranges recorded in metadata,
+/// split at page boundaries.
+pub(crate) fn known_spans(result: &ExtractionResult) -> Vec<ProvenanceSpan> {
+    let mut spans = result.provenance.clone().unwrap_or_default();
+
+    let mut cursor = 0;
+    for (table_index, table) in result.tables.iter().enumerate() {
+        let markdown = table.markdown.trim();
+        if markdown.is_empty() {
+            continue;
+        }
+        let Some(offset) = result.content.get(cursor..).and_then(|rest| rest.find(markdown)) else {
+            continue;
+        };
+        let byte_start = cursor + offset;
+        cursor = byte_start + markdown.len();
+        spans.push(ProvenanceSpan {
+            byte_start,
+            byte_end: cursor,
+            source: ContentSource::Table {
+                table_index,
+                page: (table.page_number > 0).then_some(table.page_number),
+            },
+        });
+    }
+
+    let boundaries = result
+        .metadata
+        .pages
+        .as_ref()
+        .and_then(|pages| pages.boundaries.as_deref());
+    for (start, end) in ocr_ranges(result) {
+        spans.extend(split_at_pages(start, end, boundaries));
+    }
+
+    spans
+}
+
+#[cfg(feature = "ocr")]
+fn ocr_ranges(result: &ExtractionResult) -> Vec<(usize, usize)> {
+    use serde::Deserialize;
+
+    use crate::ocr::correction::{OCR_SPANS_METADATA_KEY, OcrSpan};
+
+    result
+        .metadata
+        .additional
+        .get(OCR_SPANS_METADATA_KEY)
+        .and_then(|value| Vec::<OcrSpan>::deserialize(value).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|span| (span.start, span.end))
+        .collect()
+}
+
+#[cfg(not(feature = "ocr"))]
+fn ocr_ranges(_result: &ExtractionResult) -> Vec<(usize, usize)> {
+    Vec::new()
+}
+
+fn split_at_pages(start: usize, end: usize, boundaries: Option<&[PageBoundary]>) -> Vec<ProvenanceSpan> {
+    let Some(boundaries) = boundaries else {
+        return vec![ocr_span(start, end, None)];
+    };
+
+    let mut cuts: Vec<usize> = boundaries
+        .iter()
+        .flat_map(|boundary| [boundary.byte_start, boundary.byte_end])
+        .filter(|&offset| start < offset && offset < end)
+        .collect();
+    cuts.extend([start, end]);
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    cuts.windows(2)
+        .map(|window| ocr_span(window[0], window[1], page_for_offset(boundaries, window[0])))
+        .collect()
+}
+
+fn ocr_span(byte_start: usize, byte_end: usize, page: Option<usize>) -> ProvenanceSpan {
+    ProvenanceSpan {
+        byte_start,
+        byte_end,
+        source: ContentSource::Ocr { page },
+    }
+}
+
+fn char_boundary(content: &str, offset: usize) -> usize {
+    let mut offset = offset.min(content.len());
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(byte_start: usize, byte_end: usize, source: ContentSource) -> ProvenanceSpan {
+        ProvenanceSpan {
+            byte_start,
+            byte_end,
+            source,
+        }
+    }
+
+    #[test]
+    fn test_compute_provenance_covers_content() {
+        let content = "intro | a | b | outro";
+        let table = ContentSource::Table {
+            table_index: 0,
+            page: Some(1),
+        };
+
+        let spans = compute_provenance(content, &[span(6, 15, table.clone())]);
+
+        assert_eq!(
+            spans,
+            [
+                span(0, 6, ContentSource::TextLayer),
+                span(6, 15, table),
+                span(15, content.len(), ContentSource::TextLayer),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_provenance_precedence_and_merge() {
+        let content = "0123456789";
+        let attachment = ContentSource::Attachment {
+            name: "a.txt".to_string(),
+        };
+
+        let spans = compute_provenance(
+            content,
+            &[
+                span(2, 5, attachment.clone()),
+                span(0, 8, ContentSource::Ocr { page: None }),
+                span(8, 40, ContentSource::Ocr { page: None }),
+            ],
+        );
+
+        assert_eq!(
+            spans,
+            [
+                span(0, 2, ContentSource::Ocr { page: None }),
+                span(2, 5, attachment),
+                span(5, 10, ContentSource::Ocr { page: None }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_provenance_char_boundaries() {
+        let content = "Größe";
+        let spans = compute_provenance(content, &[span(3, 5, ContentSource::Ocr { page: None })]);
+
+        assert_eq!(
+            spans,
+            [
+                span(0, 2, ContentSource::TextLayer),
+                span(2, 4, ContentSource::Ocr { page: None }),
+                span(4, content.len(), ContentSource::TextLayer),
+            ]
+        );
+        assert!(compute_provenance("", &[span(0, 4, ContentSource::TextLayer)]).is_empty());
+    }
+
+    #[test]
+    fn test_split_at_pages() {
+        let boundaries = [
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 10,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 10,
+                byte_end: 20,
+                page_number: 2,
+            },
+        ];
+
+        let spans = split_at_pages(5, 15, Some(&boundaries));
+
+        assert_eq!(
+            spans,
+            [
+                span(5, 10, ContentSource::Ocr { page: Some(1) }),
+                span(10, 15, ContentSource::Ocr { page: Some(2) }),
+            ]
+        );
+    }
+}
@@ -126,6 +126,10 @@ mod tests {
 	            chunks: None,
 	            images: None,
 	            pages: None,
+	            term_offsets: None,
+	            barcodes: None,
+	            provenance: None,
+	            schema_version: ExtractionResult::SCHEMA_VERSION,
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -152,6 +156,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         // When disabled, the processor should not run, so no quality_score should be added
@@ -187,6 +195,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let config_with_quality = ExtractionConfig {
@@ -215,6 +227,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let long_result = ExtractionResult {
@@ -226,6 +242,10 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -0,0 +1,195 @@
+//! Similarity scores between extracted documents.
+//!
+//! Two methods, both returning a score from 0.0 (unrelated) to 1.0 (identical):
+//!
+//! - **MinHash**: estimates the Jaccard similarity of the documents' word 3-shingles from
+//!   128-value signatures. It finds near-duplicates (re-scans, re-exports, documents with a
+//!   changed date or footer) and needs no model.
+//! - **Embedding**: cosine similarity of the mean chunk embeddings, which also scores
+//!   documents that say the same thing in different words.
+//!
+//! Signatures can be stored and compared later with [`signature_similarity`], so an archive
+//! can be deduplicated without keeping every document's content around.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::text::similarity::minhash_similarity;
+//!
+//! let original = "The quarterly report shows revenue growth of 12 percent across all regions.";
+//! let rescan = "The quarterly report shows revenue growth of 12 percent across all regions!";
+//! let other = "Meeting minutes: the committee approved the new parking policy.";
+//!
+//! assert!(minhash_similarity(original, rescan) > 0.9);
+//! assert!(minhash_similarity(original, other) < 0.1);
+//! ```
+
+use std::collections::HashSet;
+
+/// Number of values in a MinHash signature; the estimate's standard error is about 0.09.
+pub const MINHASH_PERMUTATIONS: usize = 128;
+
+/// Number of consecutive words in a shingle.
+pub const SHINGLE_SIZE: usize = 3;
+
+/// FNV-1a hash of a shingle, with a separator after every word.
+fn hash_shingle(words: &[String]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in words {
+        for byte in word.bytes().chain(std::iter::once(0x1f)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// SplitMix64 finalizer, used to derive the permuted hash values.
+fn mix(mut value: u64) -> u64 {
+    value = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+/// Hashes of the distinct word shingles of `text`, ignoring case and punctuation.
+fn shingle_hashes(text: &str) -> HashSet<u64> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    if words.len() < SHINGLE_SIZE {
+        return if words.is_empty() {
+            HashSet::new()
+        } else {
+            HashSet::from([hash_shingle(&words)])
+        };
+    }
+    words.windows(SHINGLE_SIZE).map(hash_shingle).collect()
+}
+
+/// MinHash signature of `text`, [`MINHASH_PERMUTATIONS`] values long.
+///
+/// Text without any words has an empty signature.
+pub fn minhash_signature(text: &str) -> Vec<u64> {
+    let hashes = shingle_hashes(text);
+    if hashes.is_empty() {
+        return Vec::new();
+    }
+
+    (0..MINHASH_PERMUTATIONS as u64)
+        .map(|permutation| {
+            let seed = mix(permutation);
+            hashes.iter().map(|&hash| mix(hash ^ seed)).min().unwrap_or(u64::MAX)
+        })
+        .collect()
+}
+
+/// Estimated Jaccard similarity of two signatures from [`minhash_signature`].
+///
+/// Two empty signatures (texts without words) are identical; an empty and a non-empty
+/// one share nothing.
+pub fn signature_similarity(a: &[u64], b: &[u64]) -> f64 {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return 1.0,
+        (true, false) | (false, true) => return 0.0,
+        (false, false) => {}
+    }
+    let matching = a.iter().zip(b).filter(|(x, y)| x == y).count();
+    matching as f64 / a.len().max(b.len()) as f64
+}
+
+/// Estimated Jaccard similarity of the word 3-shingles of two texts.
+pub fn minhash_similarity(a: &str, b: &str) -> f64 {
+    signature_similarity(&minhash_signature(a), &minhash_signature(b))
+}
+
+/// Cosine similarity of the mean of each document's chunk embeddings, clamped to 0.0-1.0.
+///
+/// Returns 0.0 if either document has no embeddings or they differ in dimension.
+pub fn embedding_similarity(a: &[Vec<f32>], b: &[Vec<f32>]) -> f64 {
+    let (Some(a), Some(b)) = (mean_vector(a), mean_vector(b)) else {
+        return 0.0;
+    };
+    if a.len() != b.len() {
+        return 0.0;
+    }
+
+    let dot: f64 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let magnitude = norm(&a) * norm(&b);
+    if magnitude == 0.0 {
+        return 0.0;
+    }
+    (dot / magnitude).clamp(0.0, 1.0)
+}
+
+fn mean_vector(vectors: &[Vec<f32>]) -> Option<Vec<f64>> {
+    let dimensions = vectors.first()?.len();
+    if dimensions == 0 || vectors.iter().any(|vector| vector.len() != dimensions) {
+        return None;
+    }
+
+    let mut mean = vec![0.0; dimensions];
+    for vector in vectors {
+        for (sum, &value) in mean.iter_mut().zip(vector) {
+            *sum += f64::from(value);
+        }
+    }
+    let count = vectors.len() as f64;
+    mean.iter_mut().for_each(|sum| *sum /= count);
+    Some(mean)
+}
+
+/// Chunk `content` and embed every chunk with `config`, for [`embedding_similarity`].
+///
+/// Results that were extracted with chunk embeddings can be compared directly instead.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Plugin` if the embedding model cannot be loaded.
+#[cfg(feature = "embeddings")]
+pub fn embed_content(content: &str, config: &crate::core::config::EmbeddingConfig) -> crate::Result<Vec<Vec<f32>>> {
+    let mut chunks = crate::chunking::chunk_text(content, &crate::chunking::ChunkingConfig::default(), None)?.chunks;
+    crate::embeddings::generate_embeddings_for_chunks(&mut chunks, config)?;
+    Ok(chunks.into_iter().filter_map(|chunk| chunk.embedding).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minhash_scores_near_duplicates_high() {
+        let base = "Invoice 2024-117 for consulting services rendered in March. Payment is due within \
+                    thirty days of receipt. Please include the invoice number with your transfer.";
+        let edited = base.replace("thirty", "forty-five");
+
+        assert_eq!(minhash_similarity(base, base), 1.0);
+        assert!(minhash_similarity(base, &edited) > 0.5);
+        assert!(minhash_similarity(base, "Completely unrelated minutes of a gardening club meeting.") < 0.1);
+    }
+
+    #[test]
+    fn test_minhash_ignores_case_punctuation_and_handles_short_text() {
+        assert_eq!(minhash_similarity("Hello, World!", "hello world"), 1.0);
+        assert_eq!(minhash_similarity("", "  ... "), 1.0);
+        assert_eq!(minhash_similarity("", "some words here"), 0.0);
+        assert_eq!(minhash_signature("one two three four").len(), MINHASH_PERMUTATIONS);
+    }
+
+    #[test]
+    fn test_embedding_similarity_of_mean_vectors() {
+        let a = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        let b = vec![vec![2.0, 2.0]];
+        let opposite = vec![vec![-1.0, -1.0]];
+
+        assert!((embedding_similarity(&a, &b) - 1.0).abs() < 1e-9);
+        assert!((embedding_similarity(&a, &[vec![1.0, 0.0]]) - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-9);
+        assert_eq!(embedding_similarity(&a, &opposite), 0.0);
+        assert_eq!(embedding_similarity(&a, &[]), 0.0);
+        assert_eq!(embedding_similarity(&a, &[vec![1.0, 0.0, 0.0]]), 0.0);
+    }
+}
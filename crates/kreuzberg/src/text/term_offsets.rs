@@ -0,0 +1,110 @@
+//! Term offset emission for position-aware indexing.
+//!
+//! Splits extracted content into terms (runs of alphanumeric characters) and records
+//! the byte range and page of each one, so search engines can build positional
+//! indexes without tokenizing the content again.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::text::term_offsets::compute_term_offsets;
+//!
+//! let offsets = compute_term_offsets("Hello, World", None);
+//! assert_eq!(offsets[1].term, "world");
+//! assert_eq!((offsets[1].byte_start, offsets[1].byte_end), (7, 12));
+//! ```
+
+use crate::types::{PageBoundary, TermOffset};
+
+/// Compute the offsets of every term in `content`.
+///
+/// Terms are maximal runs of alphanumeric characters, lowercased. Byte offsets refer to
+/// `content` as given, so `&content[byte_start..byte_end]` is the original spelling.
+///
+/// # Arguments
+///
+/// * `content` - The text to tokenize
+/// * `boundaries` - Page boundaries used to assign a page number to each term
+pub fn compute_term_offsets(content: &str, boundaries: Option<&[PageBoundary]>) -> Vec<TermOffset> {
+    let mut offsets = Vec::new();
+    let mut start = None;
+
+    for (idx, ch) in content.char_indices() {
+        match (ch.is_alphanumeric(), start) {
+            (true, None) => start = Some(idx),
+            (false, Some(begin)) => {
+                offsets.push(term_offset(content, begin, idx, boundaries));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(begin) = start {
+        offsets.push(term_offset(content, begin, content.len(), boundaries));
+    }
+
+    offsets
+}
+
+fn term_offset(content: &str, byte_start: usize, byte_end: usize, boundaries: Option<&[PageBoundary]>) -> TermOffset {
+    TermOffset {
+        term: content[byte_start..byte_end].to_lowercase(),
+        byte_start,
+        byte_end,
+        page: boundaries.and_then(|boundaries| page_for_offset(boundaries, byte_start)),
+    }
+}
+
+pub(crate) fn page_for_offset(boundaries: &[PageBoundary], byte_offset: usize) -> Option<usize> {
+    let idx = boundaries.partition_point(|boundary| boundary.byte_end <= byte_offset);
+    boundaries
+        .get(idx)
+        .filter(|boundary| boundary.byte_start <= byte_offset)
+        .map(|boundary| boundary.page_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_term_offsets_unicode() {
+        let content = "Größe: 42 Äpfel";
+        let offsets = compute_term_offsets(content, None);
+
+        let terms: Vec<&str> = offsets.iter().map(|offset| offset.term.as_str()).collect();
+        assert_eq!(terms, ["größe", "42", "äpfel"]);
+        for offset in &offsets {
+            assert_eq!(content[offset.byte_start..offset.byte_end].to_lowercase(), offset.term);
+            assert_eq!(offset.page, None);
+        }
+    }
+
+    #[test]
+    fn test_compute_term_offsets_pages() {
+        let content = "first page\n\nsecond page";
+        let boundaries = [
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 12,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 12,
+                byte_end: content.len(),
+                page_number: 2,
+            },
+        ];
+
+        let offsets = compute_term_offsets(content, Some(&boundaries));
+        let pages: Vec<Option<usize>> = offsets.iter().map(|offset| offset.page).collect();
+        assert_eq!(pages, [Some(1), Some(1), Some(2), Some(2)]);
+    }
+
+    #[test]
+    fn test_compute_term_offsets_empty() {
+        assert!(compute_term_offsets("", None).is_empty());
+        assert!(compute_term_offsets(" -- ", None).is_empty());
+    }
+}
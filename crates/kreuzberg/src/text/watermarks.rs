@@ -0,0 +1,430 @@
+//! Watermark and stamp detection.
+//!
+//! Legal and business pipelines need to know whether a document is marked DRAFT,
+//! CONFIDENTIAL or PRIVILEGED. With `watermarks` configured, the PDF extractor reports text
+//! drawn diagonally across the page and images placed on most pages, and
+//! [`detect_watermarks`] adds stamp words written in capitals on a line of their own, which
+//! covers every format and OCR output. Each watermark is written to `metadata.watermarks`
+//! with the pages it appears on and the share of pages it covers; with `exclude_text` the
+//! lines holding watermark text are removed from the content.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::WatermarkConfig;
+//! use kreuzberg::text::watermarks::WatermarkDetector;
+//!
+//! let detector = WatermarkDetector::new(&WatermarkConfig::default());
+//! assert_eq!(detector.stamp("D R A F T").as_deref(), Some("DRAFT"));
+//! assert_eq!(detector.stamp("*** CONFIDENTIAL ***").as_deref(), Some("CONFIDENTIAL"));
+//! assert_eq!(detector.stamp("Draft agreement"), None);
+//! ```
+
+use std::collections::HashSet;
+#[cfg(feature = "pdf")]
+use std::collections::{BTreeMap, HashMap};
+
+use crate::core::config::WatermarkConfig;
+use crate::text::cleanup::{content_page_ranges, remove_lines};
+use crate::types::{ExtractionResult, Watermark, WatermarkKind};
+
+/// Stamp words recognised on a line of their own.
+const STAMP_WORDS: &[&str] = &[
+    "draft",
+    "confidential",
+    "strictly confidential",
+    "highly confidential",
+    "privileged",
+    "privileged and confidential",
+    "privileged & confidential",
+    "attorney-client privileged",
+    "attorney client privileged",
+    "internal",
+    "internal use only",
+    "for internal use only",
+    "not for distribution",
+    "do not distribute",
+    "do not copy",
+    "copy",
+    "sample",
+    "specimen",
+    "void",
+    "approved",
+    "rejected",
+    "paid",
+    "cancelled",
+    "canceled",
+    "preliminary",
+    "for review",
+    "for discussion",
+    "obsolete",
+    "superseded",
+    "restricted",
+    "secret",
+    "top secret",
+];
+
+#[cfg(feature = "pdf")]
+/// Angle in degrees away from a multiple of 90 from which text counts as diagonal.
+const MIN_DIAGONAL_DEGREES: f64 = 15.0;
+#[cfg(feature = "pdf")]
+/// Fewest letters and digits in a diagonal text watermark.
+const MIN_DIAGONAL_CHARS: usize = 3;
+#[cfg(feature = "pdf")]
+/// Share of the pages an image has to appear on to count as a stamp.
+const MIN_IMAGE_PAGE_RATIO: f64 = 0.6;
+
+/// Recognises stamp words standing alone on a line.
+#[derive(Debug, Clone)]
+pub struct WatermarkDetector {
+    stamp_words: HashSet<String>,
+}
+
+impl WatermarkDetector {
+    /// Create a detector for the built-in stamp words and the configured ones.
+    pub fn new(config: &WatermarkConfig) -> Self {
+        let stamp_words = STAMP_WORDS
+            .iter()
+            .map(|word| word.to_string())
+            .chain(config.stamp_words.iter().map(|word| stamp_key(word)))
+            .filter(|word| !word.is_empty())
+            .collect();
+        Self { stamp_words }
+    }
+
+    /// The stamp written on the line, with letter spacing and surrounding punctuation
+    /// removed, when the line holds nothing but a stamp word in capitals.
+    pub fn stamp(&self, line: &str) -> Option<String> {
+        let text = collapse_letter_spacing(line);
+        let text = text.trim_matches(|ch: char| !ch.is_alphanumeric());
+        if !text.chars().any(char::is_alphabetic) || text.chars().any(char::is_lowercase) {
+            return None;
+        }
+        self.stamp_words.contains(&stamp_key(text)).then(|| text.to_string())
+    }
+}
+
+/// Add the stamp words found in the text to `metadata.watermarks`, next to the
+/// watermarks the extractor found, and remove watermark text from the content when
+/// `exclude_text` is set.
+///
+/// Pages are taken from the page boundaries or the per-page content; a result without
+/// either counts as a single page.
+pub fn detect_watermarks(result: &mut ExtractionResult, config: &WatermarkConfig) {
+    let detector = WatermarkDetector::new(config);
+    let pages = page_texts(result);
+    let page_count = pages.len();
+    let stamps: Vec<(String, usize)> = pages
+        .into_iter()
+        .flat_map(|(page, text)| {
+            text.lines()
+                .filter_map(|line| detector.stamp(line))
+                .map(move |stamp| (stamp, page))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut watermarks = result.metadata.watermarks.take().unwrap_or_default();
+    for (stamp, page) in stamps {
+        let key = stamp_key(&stamp);
+        let existing = watermarks
+            .iter_mut()
+            .find(|watermark| watermark.text.as_deref().is_some_and(|text| stamp_key(text) == key));
+        match existing {
+            Some(watermark) => watermark.pages.push(page),
+            None => watermarks.push(Watermark {
+                kind: WatermarkKind::Text,
+                text: Some(stamp),
+                pages: vec![page],
+                page_coverage: 0.0,
+                rotation: None,
+            }),
+        }
+    }
+
+    let total_pages = result
+        .metadata
+        .pages
+        .as_ref()
+        .map_or(0, |structure| structure.total_count)
+        .max(page_count)
+        .max(1);
+    for watermark in &mut watermarks {
+        watermark.pages.sort_unstable();
+        watermark.pages.dedup();
+        watermark.page_coverage = page_coverage(watermark.pages.len(), total_pages);
+    }
+
+    if config.exclude_text {
+        let keys: HashSet<String> = watermarks
+            .iter()
+            .filter_map(|watermark| watermark.text.as_deref())
+            .map(stamp_key)
+            .collect();
+        if !keys.is_empty() {
+            remove_lines(result, |line| keys.contains(&stamp_key(line)));
+        }
+    }
+
+    result.metadata.watermarks = (!watermarks.is_empty()).then_some(watermarks);
+}
+
+#[cfg(feature = "pdf")]
+/// Whether text drawn at `degrees` runs diagonally rather than along a page edge.
+pub(crate) fn is_diagonal(degrees: f64) -> bool {
+    let offset = degrees.rem_euclid(90.0);
+    (MIN_DIAGONAL_DEGREES..=90.0 - MIN_DIAGONAL_DEGREES).contains(&offset)
+}
+
+#[cfg(feature = "pdf")]
+/// Text watermarks from runs of diagonal text, given as page, text and angle in degrees.
+///
+/// Runs with the same text on several pages become one watermark; very short runs are
+/// ignored.
+pub(crate) fn diagonal_text_watermarks(runs: Vec<(usize, String, f64)>, total_pages: usize) -> Vec<Watermark> {
+    let mut watermarks: Vec<Watermark> = Vec::new();
+    let mut by_key: HashMap<String, usize> = HashMap::new();
+
+    for (page, text, degrees) in runs {
+        let text = collapse_letter_spacing(&text);
+        if text.chars().filter(|ch| ch.is_alphanumeric()).count() < MIN_DIAGONAL_CHARS {
+            continue;
+        }
+        let key = stamp_key(&text);
+        match by_key.get(&key) {
+            Some(&index) => watermarks[index].pages.push(page),
+            None => {
+                by_key.insert(key, watermarks.len());
+                watermarks.push(Watermark {
+                    kind: WatermarkKind::Text,
+                    text: Some(text),
+                    pages: vec![page],
+                    page_coverage: 0.0,
+                    rotation: Some((degrees.rem_euclid(360.0) * 10.0).round() / 10.0),
+                });
+            }
+        }
+    }
+
+    for watermark in &mut watermarks {
+        watermark.pages.sort_unstable();
+        watermark.pages.dedup();
+        watermark.page_coverage = page_coverage(watermark.pages.len(), total_pages);
+    }
+    watermarks
+}
+
+#[cfg(feature = "pdf")]
+/// Image watermarks from the content hashes of the images on each page.
+///
+/// An image counts as a stamp when the same image is placed on most pages of a
+/// document with at least two pages.
+pub(crate) fn image_watermarks(images: &[(usize, u64)], total_pages: usize) -> Vec<Watermark> {
+    if total_pages < 2 {
+        return Vec::new();
+    }
+
+    let mut pages_by_image: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+    for &(page, hash) in images {
+        pages_by_image.entry(hash).or_default().push(page);
+    }
+
+    let min_pages = ((total_pages as f64 * MIN_IMAGE_PAGE_RATIO).ceil() as usize).max(2);
+    let mut watermarks: Vec<Watermark> = pages_by_image
+        .into_values()
+        .filter_map(|mut pages| {
+            pages.sort_unstable();
+            pages.dedup();
+            (pages.len() >= min_pages).then(|| Watermark {
+                kind: WatermarkKind::Image,
+                text: None,
+                page_coverage: page_coverage(pages.len(), total_pages),
+                pages,
+                rotation: None,
+            })
+        })
+        .collect();
+    watermarks.sort_by(|a, b| a.pages.cmp(&b.pages));
+    watermarks
+}
+
+/// Page texts with their page numbers.
+fn page_texts(result: &ExtractionResult) -> Vec<(usize, &str)> {
+    if let Some(ranges) = content_page_ranges(result) {
+        return ranges
+            .into_iter()
+            .map(|(range, page)| (page, &result.content[range]))
+            .collect();
+    }
+    match result.pages.as_deref() {
+        Some(pages) if !pages.is_empty() => pages
+            .iter()
+            .map(|page| (page.page_number, page.content.as_str()))
+            .collect(),
+        _ => vec![(1, result.content.as_str())],
+    }
+}
+
+fn page_coverage(pages: usize, total_pages: usize) -> f64 {
+    (pages as f64 / total_pages.max(1) as f64).min(1.0)
+}
+
+/// Join letters spaced out for emphasis (`D R A F T`) and collapse whitespace.
+fn collapse_letter_spacing(text: &str) -> String {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    if tokens.len() >= 3 && tokens.iter().all(|token| token.chars().count() == 1) {
+        tokens.concat()
+    } else {
+        tokens.join(" ")
+    }
+}
+
+/// Comparison key of a stamp: lowercased, letter spacing and surrounding punctuation removed.
+fn stamp_key(text: &str) -> String {
+    collapse_letter_spacing(text)
+        .trim_matches(|ch: char| !ch.is_alphanumeric())
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageBoundary, PageStructure, PageUnitType};
+
+    fn paged_result(pages: &[&str]) -> ExtractionResult {
+        let mut content = String::new();
+        let mut boundaries = Vec::new();
+        for (index, page) in pages.iter().enumerate() {
+            if index > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(page);
+            boundaries.push(PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: index + 1,
+            });
+        }
+
+        ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: pages.len(),
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_stamp_words_and_custom_words() {
+        let config = WatermarkConfig {
+            stamp_words: vec!["Attorney Work Product".to_string()],
+            ..Default::default()
+        };
+        let detector = WatermarkDetector::new(&config);
+
+        assert_eq!(
+            detector.stamp("  PRIVILEGED & CONFIDENTIAL ").as_deref(),
+            Some("PRIVILEGED & CONFIDENTIAL")
+        );
+        assert_eq!(
+            detector.stamp("ATTORNEY WORK PRODUCT").as_deref(),
+            Some("ATTORNEY WORK PRODUCT")
+        );
+        assert_eq!(detector.stamp("Confidential"), None);
+        assert_eq!(detector.stamp("DRAFT AGREEMENT"), None);
+        assert_eq!(detector.stamp("- 3 -"), None);
+    }
+
+    #[test]
+    fn test_detect_watermarks_merges_extractor_findings_and_excludes_text() {
+        let mut result = paged_result(&[
+            "DRAFT\nThe parties agree as follows.",
+            "Payment is due in 30 days.\nD R A F T",
+            "Signed by both parties.\nCONFIDENTIAL",
+            "Annex",
+        ]);
+        result.metadata.watermarks = Some(vec![Watermark {
+            kind: WatermarkKind::Text,
+            text: Some("Draft".to_string()),
+            pages: vec![2],
+            page_coverage: 0.25,
+            rotation: Some(45.0),
+        }]);
+        let config = WatermarkConfig {
+            exclude_text: true,
+            ..Default::default()
+        };
+
+        detect_watermarks(&mut result, &config);
+
+        let watermarks = result.metadata.watermarks.as_ref().unwrap();
+        assert_eq!(watermarks.len(), 2);
+        assert_eq!(watermarks[0].text.as_deref(), Some("Draft"));
+        assert_eq!(watermarks[0].pages, vec![1, 2]);
+        assert_eq!(watermarks[0].page_coverage, 0.5);
+        assert_eq!(watermarks[0].rotation, Some(45.0));
+        assert_eq!(watermarks[1].text.as_deref(), Some("CONFIDENTIAL"));
+        assert_eq!(watermarks[1].pages, vec![3]);
+
+        assert_eq!(
+            result.content,
+            "The parties agree as follows.\n\nPayment is due in 30 days.\n\nSigned by both parties.\n\nAnnex"
+        );
+        let boundaries = result.metadata.pages.as_ref().unwrap().boundaries.as_ref().unwrap();
+        let pages: Vec<&str> = boundaries
+            .iter()
+            .map(|boundary| result.content[boundary.byte_start..boundary.byte_end].trim_end())
+            .collect();
+        assert_eq!(
+            pages,
+            vec![
+                "The parties agree as follows.",
+                "Payment is due in 30 days.",
+                "Signed by both parties.",
+                "Annex"
+            ]
+        );
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_diagonal_text_and_image_watermarks() {
+        assert!(is_diagonal(45.0));
+        assert!(is_diagonal(315.0));
+        assert!(!is_diagonal(90.0));
+        assert!(!is_diagonal(3.0));
+
+        let runs = vec![
+            (1, "COPY".to_string(), 54.7),
+            (3, "C O P Y".to_string(), 54.7),
+            (2, "ok".to_string(), 45.0),
+        ];
+        let watermarks = diagonal_text_watermarks(runs, 3);
+        assert_eq!(watermarks.len(), 1);
+        assert_eq!(watermarks[0].pages, vec![1, 3]);
+
+        let images = [(1, 7), (2, 7), (3, 7), (1, 9)];
+        let watermarks = image_watermarks(&images, 4);
+        assert_eq!(watermarks.len(), 1);
+        assert_eq!(watermarks[0].kind, WatermarkKind::Image);
+        assert_eq!(watermarks[0].page_coverage, 0.75);
+        assert!(image_watermarks(&[(1, 7)], 1).is_empty());
+    }
+}
@@ -177,6 +177,155 @@ pub struct ExtractionResult {
     /// with tables and images mapped to their respective pages.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pages: Option<Vec<PageContent>>,
+
+    /// Byte offsets of every term in `content` when `emit_term_offsets` is enabled.
+    ///
+    /// Lets search engines build position-aware indexes without tokenizing the
+    /// content again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term_offsets: Option<Vec<TermOffset>>,
+
+    /// Barcodes and QR codes found on the pages when `detect_barcodes` is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub barcodes: Option<Vec<Barcode>>,
+
+    /// Source of every byte range of `content` when `emit_provenance` is enabled.
+    ///
+    /// Spans are ordered, do not overlap and together cover the whole content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Vec<ProvenanceSpan>>,
+
+    /// Layout version of the serialized result, [`ExtractionResult::SCHEMA_VERSION`] for
+    /// results produced by this release.
+    ///
+    /// Results stored by older releases may use an earlier layout; load them with
+    /// [`crate::core::result_schema::migrate_result`] instead of deserializing directly.
+    #[serde(default = "ExtractionResult::current_schema_version")]
+    pub schema_version: u32,
+}
+
+impl ExtractionResult {
+    /// Current layout version of serialized results.
+    ///
+    /// See [`crate::core::result_schema`] for the changes between versions.
+    pub const SCHEMA_VERSION: u32 = 2;
+
+    fn current_schema_version() -> u32 {
+        Self::SCHEMA_VERSION
+    }
+}
+
+/// Position of a single term in the extracted content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TermOffset {
+    /// Lowercased term text
+    pub term: String,
+    /// Byte offset where the term starts in the content string (inclusive)
+    pub byte_start: usize,
+    /// Byte offset where the term ends in the content string (exclusive)
+    pub byte_end: usize,
+    /// Page number (1-indexed), when page boundaries are known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+}
+
+/// A byte range of the extracted content and the source it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvenanceSpan {
+    /// Byte offset where the span starts in the content string (inclusive)
+    pub byte_start: usize,
+    /// Byte offset where the span ends in the content string (exclusive)
+    pub byte_end: usize,
+    /// Where the text in this range came from
+    #[serde(flatten)]
+    pub source: ContentSource,
+}
+
+/// Source of a span of extracted content.
+///
+/// Serializes with a `source` discriminator field (`text_layer`, `ocr`, `table`, `attachment`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum ContentSource {
+    /// Text stored in the document itself, such as a PDF text layer or office markup
+    TextLayer,
+    /// Text recognized by OCR
+    Ocr {
+        /// Page number (1-indexed), when page boundaries are known
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        page: Option<usize>,
+    },
+    /// Table from [`ExtractionResult::tables`] rendered into the content as markdown
+    Table {
+        /// Index into [`ExtractionResult::tables`]
+        table_index: usize,
+        /// Page number (1-indexed), when the table reports one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        page: Option<usize>,
+    },
+    /// Text of a file attached to or embedded in the document, such as an archive member
+    Attachment {
+        /// Name or path of the attached file
+        name: String,
+    },
+}
+
+/// A barcode or QR code decoded from a page image.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Barcode {
+    /// Symbology of the decoded code
+    pub format: BarcodeFormat,
+    /// Decoded payload text
+    pub payload: String,
+    /// Page number (1-indexed) the code was found on
+    pub page: usize,
+    /// Location on the page (PDF points for PDFs, pixels for images)
+    pub bbox: BoundingBox,
+}
+
+/// Supported barcode symbologies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarcodeFormat {
+    /// QR code (all versions and error correction levels)
+    QrCode,
+    /// EAN-13 retail barcode
+    Ean13,
+    /// UPC-A retail barcode (EAN-13 with a leading zero)
+    UpcA,
+    /// EAN-8 retail barcode
+    Ean8,
+    /// Code 128 (code sets A, B and C)
+    Code128,
+    /// Code 39 without check character
+    Code39,
+}
+
+impl BarcodeFormat {
+    /// Snake case name of the format (e.g. "qr_code").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::QrCode => "qr_code",
+            Self::Ean13 => "ean13",
+            Self::UpcA => "upc_a",
+            Self::Ean8 => "ean8",
+            Self::Code128 => "code128",
+            Self::Code39 => "code39",
+        }
+    }
+}
+
+/// Axis-aligned rectangle with its origin at the top-left corner of the page.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    /// Distance from the left edge of the page
+    pub x: f64,
+    /// Distance from the top edge of the page
+    pub y: f64,
+    /// Width of the rectangle
+    pub width: f64,
+    /// Height of the rectangle
+    pub height: f64,
 }
 
 /// Format-specific metadata (discriminated union).
@@ -245,6 +394,33 @@ pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pages: Option<PageStructure>,
 
+    /// Heading hierarchy (table of contents)
+    ///
+    /// Built from PDF bookmarks or detected numbered headings, DOCX heading styles and
+    /// HTML `h1`–`h6` elements. Absent when the document has no headings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline: Option<Vec<OutlineEntry>>,
+
+    /// Label/value pairs such as invoice number, due date or total
+    ///
+    /// Present when `kv_extraction` is configured. Pairs found from PDF word positions carry
+    /// bounding boxes; pairs found in the text of other formats carry only a page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_values: Option<Vec<KeyValuePair>>,
+
+    /// Watermarks and stamps such as DRAFT or CONFIDENTIAL, with the pages they cover
+    ///
+    /// Present when `watermarks` is configured and at least one watermark was found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watermarks: Option<Vec<Watermark>>,
+
+    /// Figures found through their captions, in document order
+    ///
+    /// Present when image extraction is configured and at least one caption was found.
+    /// Figures drawn as vector graphics have a caption but no `image_index`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub figures: Option<Vec<Figure>>,
+
     /// Format-specific metadata (discriminated union)
     ///
     /// Contains detailed metadata specific to the document format.
@@ -252,6 +428,10 @@ pub struct Metadata {
     #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub format: Option<FormatMetadata>,
 
+    /// XMP packet metadata (Dublin Core, XMP basic, PDF and Photoshop schemas)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xmp: Option<XmpMetadata>,
+
     /// Image preprocessing metadata (when OCR preprocessing was applied)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_preprocessing: Option<ImagePreprocessingMetadata>,
@@ -297,9 +477,105 @@ pub struct PageStructure {
     pub pages: Option<Vec<PageInfo>>,
 }
 
+/// A heading in the document outline, with nested sub-headings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutlineEntry {
+    /// Heading level (1 = top level)
+    pub level: u8,
+
+    /// Heading text
+    pub title: String,
+
+    /// Page the heading appears on (1-indexed), when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+
+    /// Byte offset of the heading text in `content`, when it could be located
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_offset: Option<usize>,
+
+    /// Headings nested under this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<OutlineEntry>,
+}
+
+/// A label and its value, e.g. `Invoice number` and `2024-117`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyValuePair {
+    /// Label as written in the document, without the trailing colon
+    pub key: String,
+
+    /// Value as written in the document
+    pub value: String,
+
+    /// Page the pair appears on (1-indexed), when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+
+    /// Bounding box of the label in PDF points, when word positions are available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_bbox: Option<BoundingBox>,
+
+    /// Bounding box of the value in PDF points, when word positions are available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_bbox: Option<BoundingBox>,
+}
+
+/// A figure caption, with the extracted image it belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Figure {
+    /// Zero-indexed position of the figure in the document
+    pub figure_index: usize,
+
+    /// Caption label, e.g. "Figure 3" or "Fig. 2a"
+    pub label: String,
+
+    /// Full caption text, label included
+    pub caption: String,
+
+    /// Page the caption is on (1-indexed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<usize>,
+
+    /// `image_index` of the extracted image the caption belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_index: Option<usize>,
+}
+
+/// How a watermark is drawn on the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WatermarkKind {
+    /// Text drawn diagonally across the page or a stamp word on a line of its own
+    Text,
+    /// The same image placed on most pages
+    Image,
+}
+
+/// A watermark or stamp found in the document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Watermark {
+    /// How the watermark is drawn
+    pub kind: WatermarkKind,
+
+    /// Watermark text, for text watermarks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Pages the watermark appears on (1-indexed)
+    pub pages: Vec<usize>,
+
+    /// Share of the document's pages the watermark appears on (0.0-1.0)
+    pub page_coverage: f64,
+
+    /// Rotation of diagonal text in degrees (0-360)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<f64>,
+}
+
 /// Type of paginated unit in a document.
 ///
-/// Distinguishes between different types of "pages" (PDF pages, presentation slides, spreadsheet sheets).
+/// Distinguishes between different types of "pages" (PDF pages, presentation slides, spreadsheet sheets, ebook chapters).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PageUnitType {
@@ -309,6 +585,8 @@ pub enum PageUnitType {
     Slide,
     /// Spreadsheet sheets (XLSX, ODS)
     Sheet,
+    /// Ebook chapters (EPUB, MOBI)
+    Chapter,
 }
 
 /// Byte offset boundary for a page.
@@ -391,6 +669,13 @@ pub struct PageContent {
     /// Arc semantics in-memory for zero-copy sharing.
     #[serde(skip_serializing_if = "Vec::is_empty", default, with = "serde_vec_arc")]
     pub images: Vec<Arc<ExtractedImage>>,
+
+    /// Reference to the page text written to disk.
+    ///
+    /// Set when the page was spilled to `spill_dir`. `content` is then empty; read the text
+    /// on demand with [`crate::cache::blob_store::read_page_content`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_blob: Option<ImageBlobRef>,
 }
 
 /// Excel/spreadsheet metadata.
@@ -465,6 +750,173 @@ pub struct ImageMetadata {
     pub format: String,
     /// EXIF metadata tags
     pub exif: HashMap<String, String>,
+    /// Structured EXIF metadata (camera, timestamps, GPS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exif_details: Option<ExifDetails>,
+}
+
+/// Structured EXIF metadata of an image.
+///
+/// Unlike [`ImageMetadata::exif`], values are typed and normalized: timestamps are
+/// ISO 8601 and GPS coordinates are signed decimal degrees.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExifDetails {
+    /// Camera and lens that captured the image
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera: Option<ExifCamera>,
+    /// Capture and modification timestamps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamps: Option<ExifTimestamps>,
+    /// Location the image was captured at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gps: Option<GpsCoordinates>,
+}
+
+/// Camera information from EXIF tags.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExifCamera {
+    /// Camera manufacturer (`Make`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub make: Option<String>,
+    /// Camera model (`Model`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Lens model (`LensModel`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lens_model: Option<String>,
+    /// Firmware or editing software (`Software`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub software: Option<String>,
+}
+
+/// EXIF timestamps in ISO 8601 format (local time, as recorded by the camera).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExifTimestamps {
+    /// When the image was captured (`DateTimeOriginal`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original: Option<String>,
+    /// When the image was digitized (`DateTimeDigitized`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digitized: Option<String>,
+    /// When the file was last changed (`DateTime`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+}
+
+/// GPS position from EXIF tags.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GpsCoordinates {
+    /// Latitude in decimal degrees (negative south of the equator)
+    pub latitude: f64,
+    /// Longitude in decimal degrees (negative west of Greenwich)
+    pub longitude: f64,
+    /// Altitude in meters (negative below sea level)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f64>,
+}
+
+/// Metadata parsed from an embedded XMP packet.
+///
+/// Field names follow the XMP property names of the `dc:`, `xmp:`, `pdf:` and
+/// `photoshop:` namespaces. Dates are kept as written in the packet (usually ISO 8601).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct XmpMetadata {
+    /// `dc:title` (the `x-default` language alternative)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// `dc:creator` - ordered list of authors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Vec<String>>,
+    /// `dc:description` (the `x-default` language alternative)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `dc:subject` - keywords
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<Vec<String>>,
+    /// `dc:rights` (the `x-default` language alternative)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rights: Option<String>,
+    /// `dc:publisher`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<Vec<String>>,
+    /// `dc:contributor`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contributor: Option<Vec<String>>,
+    /// `dc:language`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Vec<String>>,
+    /// `dc:date`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<Vec<String>>,
+    /// `dc:identifier`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// `dc:format` (MIME type)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// `xmp:CreateDate`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_date: Option<String>,
+    /// `xmp:ModifyDate`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modify_date: Option<String>,
+    /// `xmp:MetadataDate`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_date: Option<String>,
+    /// `xmp:CreatorTool`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_tool: Option<String>,
+    /// `xmp:Rating`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<String>,
+    /// `xmp:Label`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// `pdf:Producer`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub producer: Option<String>,
+    /// `pdf:Keywords`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    /// `pdf:PDFVersion`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf_version: Option<String>,
+    /// `pdf:Trapped`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trapped: Option<String>,
+    /// `photoshop:Headline`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+    /// `photoshop:Credit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit: Option<String>,
+    /// `photoshop:Source`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// `photoshop:City`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// `photoshop:State`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// `photoshop:Country`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// `photoshop:AuthorsPosition`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authors_position: Option<String>,
+    /// `photoshop:CaptionWriter`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_writer: Option<String>,
+    /// `photoshop:Instructions`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// `photoshop:DateCreated`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+    /// `photoshop:Category`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
 }
 
 /// XML metadata extracted during XML parsing.
@@ -601,6 +1053,45 @@ pub struct ErrorMetadata {
     pub message: String,
 }
 
+/// Inferred data type of a table column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CellType {
+    /// Free-form text
+    String,
+    /// Integer or decimal number
+    Number,
+    /// Calendar date or timestamp
+    Date,
+}
+
+impl CellType {
+    /// Lowercase name of the cell type (e.g. "number").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Date => "date",
+        }
+    }
+}
+
+impl std::str::FromStr for CellType {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "string" => Ok(Self::String),
+            "number" => Ok(Self::Number),
+            "date" => Ok(Self::Date),
+            other => Err(format!(
+                "Invalid cell type '{}': expected one of string, number, date",
+                other
+            )),
+        }
+    }
+}
+
 /// Extracted table structure.
 ///
 /// Represents a table detected and extracted from a document (PDF, image, etc.).
@@ -613,6 +1104,9 @@ pub struct Table {
     pub markdown: String,
     /// Page number where the table was found (1-indexed)
     pub page_number: usize,
+    /// Inferred type of each column, if the extractor could determine it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column_types: Option<Vec<CellType>>,
 }
 
 /// A text chunk with optional embedding and metadata.
@@ -668,13 +1162,20 @@ pub struct ChunkMetadata {
     /// Only populated when page tracking is enabled in extraction configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_page: Option<usize>,
+
+    /// Annotations attached by chunk-stage post-processors.
+    ///
+    /// Empty unless a processor registered for `ProcessingStage::Chunk` adds entries.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub annotations: HashMap<String, serde_json::Value>,
 }
 
 /// Extracted image from a document.
 ///
 /// Contains raw image data, metadata, and optional nested OCR results.
 /// Raw bytes allow cross-language compatibility - users can convert to
-/// PIL.Image (Python), Sharp (Node.js), or other formats as needed.
+/// PIL.Image (Python), Sharp (Node.js), or other formats as needed. In blob-store
+/// mode the bytes live on disk and the image carries an [`ImageBlobRef`] instead.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractedImage {
     /// Raw image data (PNG, JPEG, WebP, etc. bytes)
@@ -720,6 +1221,49 @@ pub struct ExtractedImage {
     /// rather than in a separate collection, making the relationship explicit.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ocr_result: Option<Box<ExtractionResult>>,
+
+    /// Perceptual hash (64-bit dHash, 16 hex digits) of the image content
+    ///
+    /// Set when `images.perceptual_hash` or `images.deduplicate_images` is enabled.
+    /// Near-duplicate images differ in only a few bits, so hashes can be compared
+    /// across documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub perceptual_hash: Option<String>,
+
+    /// Location of the image bytes in the blob store
+    ///
+    /// Set when `images.blob_store_dir` is configured or the image was spilled to `spill_dir`.
+    /// `data` is then empty; read the bytes on demand with
+    /// [`crate::cache::blob_store::read_image_data`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<ImageBlobRef>,
+
+    /// Caption of the figure this image belongs to
+    ///
+    /// Set when a figure caption ("Figure 3: ...") is found next to the image; the figure
+    /// is listed in `metadata.figures` under `figure_index`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Zero-indexed position of the image's figure in `metadata.figures`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub figure_index: Option<usize>,
+}
+
+/// Reference to image bytes or spilled page text kept in a content-addressed blob store.
+///
+/// Identical images share one blob, however many documents, cache entries or
+/// serialized results refer to them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageBlobRef {
+    /// Content hash of the image bytes (16 hex digits)
+    pub hash: String,
+
+    /// Path of the blob file
+    pub path: String,
+
+    /// Size of the image bytes
+    pub size: usize,
 }
 
 /// Excel workbook representation.
@@ -917,7 +1461,7 @@ pub struct OcrTable {
 /// These settings control how images are preprocessed before OCR to improve
 /// text recognition quality. Different preprocessing strategies work better
 /// for different document types.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ImagePreprocessingConfig {
     /// Target DPI for the image (300 is standard, 600 for small text).
@@ -940,6 +1484,14 @@ pub struct ImagePreprocessingConfig {
 
     /// Invert colors (white text on black → black on white).
     pub invert_colors: bool,
+
+    /// Choose binarization, denoising, contrast and DPI per page from image statistics.
+    ///
+    /// When enabled, Tesseract pages are preprocessed before OCR and the chosen
+    /// parameters are recorded under `preprocessing` in the OCR metadata and in the
+    /// per-page `ocr_pages` entries. `binarization_method`, `denoise` and
+    /// `contrast_enhance` are ignored; `deskew`, `invert_colors` and `target_dpi` still apply.
+    pub auto_tune: bool,
 }
 
 impl Default for ImagePreprocessingConfig {
@@ -952,6 +1504,7 @@ impl Default for ImagePreprocessingConfig {
             contrast_enhance: false,
             binarization_method: "otsu".to_string(),
             invert_colors: false,
+            auto_tune: false,
         }
     }
 }
@@ -1042,6 +1595,29 @@ pub struct TesseractConfig {
 
     /// Use adaptive thresholding method
     pub thresholding_method: bool,
+
+    /// Keep runs of spaces between words instead of collapsing them to one
+    pub preserve_interword_spaces: bool,
+
+    /// Path to a file of extra dictionary words, one per line.
+    ///
+    /// Loaded when the engine is initialized, so engines in the pool are kept
+    /// apart per file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_words_file: Option<String>,
+
+    /// Path to a file of dictionary patterns (e.g. `\d\d\d-\d\d\d\d`), one per line.
+    ///
+    /// Loaded when the engine is initialized, like `user_words_file`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_patterns_file: Option<String>,
+
+    /// Other Tesseract variables, set by name after the engine is initialized.
+    ///
+    /// Names must be runtime parameters Tesseract knows (e.g. `textord_min_xheight`);
+    /// variables with a dedicated field above are rejected here. See
+    /// [`validate_tesseract_variables`](crate::core::config_validation::validate_tesseract_variables).
+    pub variables: std::collections::BTreeMap<String, String>,
 }
 
 impl Default for TesseractConfig {
@@ -1068,6 +1644,10 @@ impl Default for TesseractConfig {
             tessedit_use_primary_params_model: true,
             textord_space_size_is_variable: true,
             thresholding_method: false,
+            preserve_interword_spaces: false,
+            user_words_file: None,
+            user_patterns_file: None,
+            variables: std::collections::BTreeMap::new(),
         }
     }
 }
@@ -1172,6 +1752,26 @@ pub struct LibreOfficeConversionResult {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_barcode_serialization() {
+        let barcode = Barcode {
+            format: BarcodeFormat::UpcA,
+            payload: "036000291452".to_string(),
+            page: 2,
+            bbox: BoundingBox {
+                x: 10.0,
+                y: 20.5,
+                width: 100.0,
+                height: 40.0,
+            },
+        };
+
+        let json = serde_json::to_value(&barcode).unwrap();
+        assert_eq!(json["format"], BarcodeFormat::UpcA.as_str());
+        assert_eq!(json["bbox"]["y"], 20.5);
+        assert_eq!(serde_json::from_value::<Barcode>(json).unwrap(), barcode);
+    }
+
     #[test]
     fn test_metadata_serialization_with_format() {
         let mut metadata = Metadata {
@@ -1216,6 +1816,7 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |\n|---|---|\n".to_string(),
             page_number: 1,
+            column_types: None,
         };
 
         let json = serde_json::to_value(&table).unwrap();
@@ -1234,6 +1835,7 @@ mod tests {
             ],
             markdown: "| X | Y |\n|---|---|\n| 1 | 2 |\n".to_string(),
             page_number: 5,
+            column_types: None,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -1250,6 +1852,7 @@ mod tests {
             cells: vec![vec!["shared".to_string()]],
             markdown: "| shared |".to_string(),
             page_number: 1,
+            column_types: None,
         });
 
         let tables_before = vec![Arc::clone(&shared_table), Arc::clone(&shared_table)];
@@ -1265,11 +1868,13 @@ mod tests {
                 cells: vec![vec!["A".to_string()]],
                 markdown: "| A |".to_string(),
                 page_number: 1,
+                column_types: None,
             },
             Table {
                 cells: vec![vec!["B".to_string()]],
                 markdown: "| B |".to_string(),
                 page_number: 2,
+                column_types: None,
             },
         ];
 
@@ -1292,14 +1897,17 @@ mod tests {
                     cells: vec![vec!["Table1".to_string()]],
                     markdown: "| Table1 |".to_string(),
                     page_number: 3,
+                    column_types: None,
                 }),
                 Arc::new(Table {
                     cells: vec![vec!["Table2".to_string()]],
                     markdown: "| Table2 |".to_string(),
                     page_number: 3,
+                    column_types: None,
                 }),
             ],
             images: Vec::new(),
+            content_blob: None,
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -1326,6 +1934,10 @@ mod tests {
             is_mask: false,
             description: Some("Image 1".to_string()),
             ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
         });
 
         let image2 = Arc::new(ExtractedImage {
@@ -1340,6 +1952,10 @@ mod tests {
             is_mask: false,
             description: Some("Image 2".to_string()),
             ocr_result: None,
+            perceptual_hash: None,
+            blob: None,
+            caption: None,
+            figure_index: None,
         });
 
         let page = PageContent {
@@ -1347,6 +1963,7 @@ mod tests {
             content: "Page with images".to_string(),
             tables: Vec::new(),
             images: vec![image1, image2],
+            content_blob: None,
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -1365,6 +1982,7 @@ mod tests {
             cells: vec![vec!["shared across pages".to_string()]],
             markdown: "| shared across pages |".to_string(),
             page_number: 0,
+            column_types: None,
         });
 
         let page1 = PageContent {
@@ -1372,6 +1990,7 @@ mod tests {
             content: "Page 1".to_string(),
             tables: vec![Arc::clone(&shared_table)],
             images: Vec::new(),
+            content_blob: None,
         };
 
         let page2 = PageContent {
@@ -1379,6 +1998,7 @@ mod tests {
             content: "Page 2".to_string(),
             tables: vec![Arc::clone(&shared_table)],
             images: Vec::new(),
+            content_blob: None,
         };
 
         assert!(Arc::ptr_eq(&page1.tables[0], &page2.tables[0]));
@@ -1399,6 +2019,7 @@ mod tests {
             content: "No tables or images".to_string(),
             tables: Vec::new(),
             images: Vec::new(),
+            content_blob: None,
         };
 
         let json = serde_json::to_string(&page).unwrap();
@@ -1415,12 +2036,14 @@ mod tests {
             cells: vec![vec!["A".to_string()]],
             markdown: "| A |".to_string(),
             page_number: 1,
+            column_types: None,
         };
 
         let table2 = Table {
             cells: vec![vec!["B".to_string()]],
             markdown: "| B |".to_string(),
             page_number: 2,
+            column_types: None,
         };
 
         let json = serde_json::to_string(&vec![table1, table2]).unwrap();
@@ -179,6 +179,8 @@ impl MimeStringPool {
             "application/x-ipynb+json",
             "application/x-jats+xml",
             "application/x-latex",
+            "application/x-mobipocket-ebook",
+            "application/vnd.amazon.ebook",
             "application/xml+opml",
             "application/x-opml+xml",
             "application/x-research-info-systems",
@@ -0,0 +1,124 @@
+//! End-to-end barcode detection tests on rendered fixture images.
+//!
+//! The fixtures in test_documents/images/barcodes/ are full page-style images (quiet zones,
+//! gray rather than pure black and white) so the whole path is exercised: Otsu binarization,
+//! finder/scan-line location, sampling, Reed-Solomon or check-digit verification, and payload
+//! decoding. The damaged fixtures carry blots and scratches over the symbol data.
+
+#![cfg(any(feature = "pdf", feature = "ocr"))]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::barcode::detect_barcodes;
+use kreuzberg::types::{Barcode, BarcodeFormat};
+
+fn detect(relative_path: &str) -> Vec<Barcode> {
+    let image = image::open(get_test_file_path(relative_path))
+        .expect("fixture image should load")
+        .to_luma8();
+    detect_barcodes(&image, 1, 1.0)
+}
+
+fn payloads(barcodes: &[Barcode]) -> Vec<(BarcodeFormat, &str)> {
+    barcodes
+        .iter()
+        .map(|barcode| (barcode.format, barcode.payload.as_str()))
+        .collect()
+}
+
+#[test]
+fn test_decodes_qr_code_fixture() {
+    let barcodes = detect("images/barcodes/qr_url.png");
+    assert_eq!(
+        payloads(&barcodes),
+        vec![(BarcodeFormat::QrCode, "https://kreuzberg.dev")]
+    );
+
+    // 25 modules of 6 px behind a 4-module quiet zone
+    let bbox = &barcodes[0].bbox;
+    assert!((bbox.x - 24.0).abs() <= 6.0, "x = {}", bbox.x);
+    assert!((bbox.y - 24.0).abs() <= 6.0, "y = {}", bbox.y);
+    assert!((bbox.width - 150.0).abs() <= 12.0, "width = {}", bbox.width);
+    assert!((bbox.height - 150.0).abs() <= 12.0, "height = {}", bbox.height);
+    assert_eq!(barcodes[0].page, 1);
+}
+
+#[test]
+fn test_decodes_rotated_qr_code() {
+    let image = image::open(get_test_file_path("images/barcodes/qr_url.png"))
+        .unwrap()
+        .to_luma8();
+
+    for rotated in [
+        image::imageops::rotate90(&image),
+        image::imageops::rotate180(&image),
+        image::imageops::rotate270(&image),
+    ] {
+        let barcodes = detect_barcodes(&rotated, 1, 1.0);
+        assert_eq!(
+            payloads(&barcodes),
+            vec![(BarcodeFormat::QrCode, "https://kreuzberg.dev")]
+        );
+    }
+}
+
+#[test]
+fn test_decodes_qr_code_with_version_information() {
+    let barcodes = detect("images/barcodes/qr_version7.png");
+    assert_eq!(
+        payloads(&barcodes),
+        vec![(
+            BarcodeFormat::QrCode,
+            "Kreuzberg extracts text, tables and metadata from PDFs, Office documents, images and more than \
+             fifty other formats."
+        )]
+    );
+}
+
+#[test]
+fn test_repairs_damaged_qr_code_with_error_correction() {
+    let barcodes = detect("images/barcodes/qr_damaged.png");
+    assert_eq!(
+        payloads(&barcodes),
+        vec![(BarcodeFormat::QrCode, "KREUZBERG DOCUMENT INTELLIGENCE")]
+    );
+}
+
+#[test]
+fn test_rejects_qr_code_beyond_error_correction() {
+    let barcodes = detect("images/barcodes/qr_unrecoverable.png");
+    assert!(barcodes.is_empty(), "unexpected decode: {:?}", payloads(&barcodes));
+}
+
+#[test]
+fn test_decodes_linear_barcode_fixtures() {
+    for (fixture, format, payload) in [
+        ("images/barcodes/ean13.png", BarcodeFormat::Ean13, "4006381333931"),
+        ("images/barcodes/code128.png", BarcodeFormat::Code128, "KREUZBERG-2026"),
+        ("images/barcodes/code39.png", BarcodeFormat::Code39, "KREUZBERG"),
+    ] {
+        let barcodes = detect(fixture);
+        assert_eq!(payloads(&barcodes), vec![(format, payload)], "{fixture}");
+    }
+}
+
+#[test]
+fn test_decodes_damaged_ean13_from_clean_scan_lines() {
+    let barcodes = detect("images/barcodes/ean13_damaged.png");
+    assert_eq!(payloads(&barcodes), vec![(BarcodeFormat::Ean13, "4006381333931")]);
+}
+
+#[test]
+fn test_bounding_boxes_are_divided_by_scale() {
+    let image = image::open(get_test_file_path("images/barcodes/ean13.png"))
+        .unwrap()
+        .to_luma8();
+
+    let full = detect_barcodes(&image, 3, 1.0);
+    let halved = detect_barcodes(&image, 3, 2.0);
+    assert_eq!(full.len(), 1);
+    assert_eq!(halved[0].page, 3);
+    assert!((halved[0].bbox.x * 2.0 - full[0].bbox.x).abs() < 1e-9);
+    assert!((halved[0].bbox.width * 2.0 - full[0].bbox.width).abs() < 1e-9);
+}
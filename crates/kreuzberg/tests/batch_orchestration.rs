@@ -106,6 +106,39 @@ async fn test_batch_documents_concurrency_limiting() {
     assert_eq!(results.len(), 4);
 }
 
+/// Test adaptive concurrency in batch processing.
+///
+/// Validates that a byte budget smaller than any single document still lets every
+/// document run (one at a time) and keeps results in input order.
+#[tokio::test]
+async fn test_batch_documents_adaptive_concurrency() {
+    use helpers::get_test_file_path;
+    use kreuzberg::AdaptiveConcurrencyConfig;
+
+    let config = ExtractionConfig {
+        adaptive_concurrency: Some(AdaptiveConcurrencyConfig {
+            max_concurrent: Some(4),
+            max_in_flight_bytes: Some(1),
+            target_latency_ms: Some(5_000),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let paths = vec![
+        get_test_file_path("text/contract.txt"),
+        get_test_file_path("json/sample_document.json"),
+        get_test_file_path("xml/simple_note.xml"),
+        get_test_file_path("text/readme.md"),
+    ];
+
+    let results = batch_extract_file(paths, &config).await.unwrap();
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[1].mime_type, "application/json");
+    assert!(results.iter().all(|result| result.metadata.error.is_none()));
+}
+
 /// Test batch extraction with CPU-bound limit (default: num_cpus * 2).
 #[tokio::test]
 async fn test_batch_documents_default_concurrency() {
@@ -257,6 +290,8 @@ fn test_ocr_multipage_efficiency() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -316,3 +316,33 @@ fn test_batch_extract_bytes_sync_variant() {
     assert_text_content(&results[1].content, "content 2");
     assert!(results[2].content.contains("content 3"));
 }
+
+/// Test that batch results record their attempts under a retry policy.
+#[tokio::test]
+async fn test_batch_extract_records_retry_attempts() {
+    let config = ExtractionConfig {
+        retry: Some(kreuzberg::RetryConfig {
+            initial_backoff_ms: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let contents = vec![(b"retried content".as_slice(), "text/plain")];
+    let results = batch_extract_bytes(contents, &config).await.unwrap();
+    assert_eq!(results[0].metadata.additional["extraction_attempts"], 1);
+
+    // A missing file says nothing transient, so it is not retried
+    let paths = vec![get_test_documents_dir().join("nonexistent_retry.txt")];
+    let results = batch_extract_file(paths, &config).await.unwrap();
+    assert!(results[0].metadata.error.is_some());
+    assert_eq!(results[0].metadata.additional["extraction_attempts"], 1);
+
+    let results = batch_extract_bytes(
+        vec![(b"no policy".as_slice(), "text/plain")],
+        &ExtractionConfig::default(),
+    )
+    .await
+    .unwrap();
+    assert!(!results[0].metadata.additional.contains_key("extraction_attempts"));
+}
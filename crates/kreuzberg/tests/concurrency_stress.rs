@@ -189,6 +189,8 @@ async fn test_concurrent_ocr_processing() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -258,6 +260,8 @@ fn test_concurrent_ocr_cache_stress() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -343,7 +347,7 @@ async fn test_concurrent_pipeline_processing() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry.write().expect("Should acquire write lock");
+        let mut reg = registry.edit();
         let processor = Arc::new(ConcurrentTestProcessor);
         let _ = reg.remove("concurrent-test");
         reg.register(processor, 50).expect("Should register processor");
@@ -374,6 +378,10 @@ async fn test_concurrent_pipeline_processing() {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             };
 
             run_pipeline(result, &config).await
@@ -388,7 +396,7 @@ async fn test_concurrent_pipeline_processing() {
     }
 
     {
-        let mut reg = registry.write().expect("Should acquire write lock");
+        let mut reg = registry.edit();
         let _ = reg.remove("concurrent-test");
     }
 }
@@ -408,7 +416,7 @@ async fn test_concurrent_registry_reads() {
         handles.push(tokio::spawn(async move {
             let start = std::time::Instant::now();
 
-            let reg = registry_clone.read().expect("Should acquire read lock");
+            let reg = registry_clone.snapshot();
             let _extractor = reg.get("text/plain");
 
             start.elapsed()
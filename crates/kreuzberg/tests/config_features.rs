@@ -23,6 +23,7 @@ async fn test_chunking_enabled() {
             max_overlap: 10,
             embedding: None,
             preset: None,
+            respect_sentences: false,
         }),
         ..Default::default()
     };
@@ -66,6 +67,7 @@ async fn test_chunking_with_overlap() {
             max_overlap: 20,
             embedding: None,
             preset: None,
+            respect_sentences: false,
         }),
         ..Default::default()
     };
@@ -106,6 +108,7 @@ async fn test_chunking_custom_sizes() {
             max_overlap: 50,
             embedding: None,
             preset: None,
+            respect_sentences: false,
         }),
         ..Default::default()
     };
@@ -516,6 +519,7 @@ async fn test_chunking_with_embeddings() {
             max_overlap: 20,
             embedding: Some(EmbeddingConfig::default()),
             preset: None,
+            respect_sentences: false,
         }),
         ..Default::default()
     };
@@ -587,6 +591,7 @@ async fn test_chunking_with_fast_embeddings() {
                 ..Default::default()
             }),
             preset: None,
+            respect_sentences: false,
         }),
         ..Default::default()
     };
@@ -450,6 +450,8 @@ async fn test_extraction_with_ocr_config() {
             tesseract_config: None,
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -475,6 +477,7 @@ async fn test_extraction_with_chunking_config() {
             max_overlap: 20,
             embedding: None,
             preset: None,
+            respect_sentences: false,
         }),
         ..Default::default()
     };
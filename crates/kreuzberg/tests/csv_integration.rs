@@ -1,10 +1,11 @@
 //! CSV and spreadsheet integration tests.
 //!
 //! Tests for CSV and TSV extraction.
-//! Validates data extraction, custom delimiters, quoted fields, and edge cases.
+//! Validates data extraction, dialect detection, quoted fields, typed tables, and edge cases.
 
 use kreuzberg::core::config::ExtractionConfig;
 use kreuzberg::core::extractor::extract_bytes;
+use kreuzberg::types::CellType;
 
 mod helpers;
 
@@ -32,7 +33,7 @@ async fn test_csv_basic_extraction() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Name"), "Should contain 'Name' header");
     assert!(extraction.content.contains("Age"), "Should contain 'Age' header");
@@ -45,6 +46,18 @@ async fn test_csv_basic_extraction() {
     assert!(extraction.content.contains("Bob"), "Should contain Bob row");
     assert!(extraction.content.contains("25"), "Should contain Bob's age");
     assert!(extraction.content.contains("LA"), "Should contain Bob's city");
+
+    let table = &extraction.tables[0];
+    assert_eq!(table.cells.len(), 3, "Header plus two data rows");
+    assert_eq!(table.cells[0], vec!["Name", "Age", "City"]);
+    assert_eq!(
+        table.column_types,
+        Some(vec![CellType::String, CellType::Number, CellType::String])
+    );
+    assert_eq!(
+        extraction.metadata.additional.get("has_header"),
+        Some(&serde_json::json!(true))
+    );
 }
 
 /// Test CSV with headers - first row as headers.
@@ -70,7 +83,7 @@ async fn test_csv_with_headers() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Product"), "Should contain Product header");
     assert!(extraction.content.contains("Price"), "Should contain Price header");
@@ -119,13 +132,19 @@ async fn test_csv_custom_delimiter() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(!extraction.content.is_empty(), "Content should be extracted");
 
     assert!(extraction.content.contains("Alice"), "Should contain Alice");
     assert!(extraction.content.contains("30"), "Should contain age");
     assert!(extraction.content.contains("NYC"), "Should contain city");
+
+    assert_eq!(
+        extraction.metadata.additional.get("delimiter"),
+        Some(&serde_json::json!(";"))
+    );
+    assert_eq!(extraction.tables[0].cells[1], vec!["Alice", "30", "NYC"]);
 }
 
 /// Test TSV (Tab-Separated Values) file.
@@ -152,7 +171,7 @@ async fn test_tsv_file() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Name"), "Should contain Name header");
     assert!(extraction.content.contains("Age"), "Should contain Age header");
@@ -187,7 +206,7 @@ async fn test_csv_quoted_fields() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Smith"), "Should contain Smith");
     assert!(extraction.content.contains("John"), "Should contain John");
@@ -198,6 +217,10 @@ async fn test_csv_quoted_fields() {
     assert!(extraction.content.contains("Product B") || extraction.content.contains("standard"));
 
     assert!(extraction.content.contains("100") && extraction.content.contains("50"));
+
+    let table = &extraction.tables[0];
+    assert_eq!(table.cells[1], vec!["Smith, John", "Product A, premium", "100"]);
+    assert_eq!(table.cells[2], vec!["Doe, Jane", "Product B, standard", "50"]);
 }
 
 /// Test CSV with special characters - Unicode, newlines in fields.
@@ -223,7 +246,7 @@ async fn test_csv_special_characters() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(!extraction.content.is_empty(), "Special characters should be handled");
 
@@ -261,7 +284,7 @@ async fn test_csv_large_file() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(!extraction.content.is_empty(), "Large CSV should be processed");
 
@@ -331,7 +354,7 @@ async fn test_csv_headers_only() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(
         extraction.content.contains("Name") || !extraction.content.is_empty(),
@@ -362,7 +385,7 @@ async fn test_csv_blank_lines() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Alice") || extraction.content.contains("Bob"));
 }
@@ -390,7 +413,7 @@ async fn test_csv_numeric_data() {
         extraction.detected_languages.is_none(),
         "Language detection not enabled"
     );
-    assert!(extraction.tables.is_empty(), "CSV should not have table structures");
+    assert_eq!(extraction.tables.len(), 1, "CSV should produce a single table");
 
     assert!(extraction.content.contains("Price"), "Should contain Price header");
     assert!(
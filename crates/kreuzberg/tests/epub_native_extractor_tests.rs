@@ -8,9 +8,10 @@
 
 #![cfg(feature = "office")]
 
-use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::core::config::{ExtractionConfig, PageConfig};
 use kreuzberg::extractors::EpubExtractor;
 use kreuzberg::plugins::DocumentExtractor;
+use kreuzberg::types::PageUnitType;
 use std::path::PathBuf;
 
 /// Helper to resolve workspace root and construct test file paths
@@ -273,3 +274,60 @@ async fn test_native_epub_no_content_loss() {
 
     println!("✅ All EPUBs extracted successfully - no content loss!");
 }
+
+/// Test 7: Chapter structure - formatting.epub
+///
+/// Validates:
+/// - Every spine document becomes one chapter in the page structure
+/// - Chapter boundaries map back into the content string
+/// - Per-chapter pages are returned when page extraction is enabled
+/// - All creators are reported as authors
+#[tokio::test]
+async fn test_native_epub_chapter_structure() {
+    let test_file = get_test_epub_path("formatting.epub");
+    if !test_file.exists() {
+        println!("Skipping test: Test file not found at {:?}", test_file);
+        return;
+    }
+
+    let bytes = std::fs::read(&test_file).expect("Failed to read formatting.epub");
+    let extractor = EpubExtractor::new();
+    let config = ExtractionConfig {
+        pages: Some(PageConfig {
+            extract_pages: true,
+            insert_page_markers: false,
+            marker_format: "<!-- PAGE {page_num} -->".to_string(),
+        }),
+        ..Default::default()
+    };
+
+    let result = extractor
+        .extract_bytes(&bytes, "application/epub+zip", &config)
+        .await
+        .expect("Should extract formatting.epub successfully");
+
+    let structure = result.metadata.pages.expect("Should report chapter structure");
+    assert_eq!(structure.unit_type, PageUnitType::Chapter);
+    assert!(
+        structure.total_count >= 5,
+        "Should extract one chapter per spine document, got {}",
+        structure.total_count
+    );
+
+    let boundaries = structure.boundaries.expect("Should report chapter boundaries");
+    let pages = result.pages.expect("Should return chapter pages");
+    assert_eq!(pages.len(), structure.total_count);
+    for (boundary, page) in boundaries.iter().zip(&pages) {
+        assert_eq!(&result.content[boundary.byte_start..boundary.byte_end], page.content);
+    }
+
+    let authors = result
+        .metadata
+        .additional
+        .get("authors")
+        .and_then(|v| v.as_array())
+        .expect("Should extract authors");
+    assert_eq!(authors.len(), 6);
+
+    println!("✅ Chapter structure test passed ({} chapters)", structure.total_count);
+}
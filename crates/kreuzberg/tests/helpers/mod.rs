@@ -115,6 +115,8 @@ pub fn test_config_with_ocr() -> kreuzberg::core::config::ExtractionConfig {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -32,6 +32,8 @@ fn test_ocr_language_english() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -57,6 +59,8 @@ fn test_ocr_language_german() {
             backend: "tesseract".to_string(),
             language: "deu".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -95,6 +99,8 @@ fn test_ocr_language_multiple() {
             backend: "tesseract".to_string(),
             language: "eng+kor".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -136,6 +142,8 @@ fn test_ocr_psm_auto() {
                 psm: 3,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -164,6 +172,8 @@ fn test_ocr_psm_single_block() {
                 psm: 6,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -192,6 +202,8 @@ fn test_ocr_psm_single_line() {
                 psm: 7,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -218,6 +230,8 @@ fn test_force_ocr_on_text_pdf() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -248,6 +262,8 @@ fn test_force_ocr_disabled() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -283,6 +299,8 @@ fn test_table_detection_enabled() {
                 table_row_threshold_ratio: 0.5,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -311,6 +329,8 @@ fn test_table_detection_disabled() {
                 enable_table_detection: false,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -339,6 +359,8 @@ fn test_language_model_ngram_configuration() {
                 language_model_ngram_on: true,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -368,6 +390,8 @@ fn test_dictionary_correction_enabled() {
                 tessedit_enable_dict_correction: true,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -397,6 +421,8 @@ fn test_character_whitelist() {
                 tessedit_char_whitelist: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz ".to_string(),
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -425,6 +451,8 @@ fn test_ocr_cache_enabled() {
                 use_cache: true,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -464,6 +492,8 @@ fn test_ocr_cache_disabled() {
                 use_cache: false,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: false,
@@ -498,6 +528,8 @@ fn test_complex_configuration_combination() {
                 use_cache: true,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
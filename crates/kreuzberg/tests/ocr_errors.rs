@@ -34,6 +34,8 @@ fn test_ocr_invalid_language_code() {
             backend: "tesseract".to_string(),
             language: "invalid_lang_99999".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -74,6 +76,8 @@ fn test_ocr_invalid_psm_mode() {
                 psm: 999,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -112,6 +116,8 @@ fn test_ocr_invalid_backend_name() {
             backend: "nonexistent_ocr_backend_xyz".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -147,6 +153,8 @@ fn test_ocr_corrupted_image_data() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -177,6 +185,8 @@ fn test_ocr_empty_image() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -207,6 +217,8 @@ fn test_ocr_non_image_data() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -245,6 +257,8 @@ fn test_ocr_extreme_table_threshold() {
                 table_row_threshold_ratio: 10.0,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -281,6 +295,8 @@ fn test_ocr_negative_psm() {
                 psm: -5,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -313,6 +329,8 @@ fn test_ocr_empty_whitelist() {
                 tessedit_char_whitelist: "".to_string(),
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -349,6 +367,8 @@ fn test_ocr_conflicting_whitelist_blacklist() {
                 tessedit_char_blacklist: "abc".to_string(),
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -381,6 +401,8 @@ fn test_ocr_empty_language() {
             backend: "tesseract".to_string(),
             language: "".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -413,6 +435,8 @@ fn test_ocr_malformed_multi_language() {
             backend: "tesseract".to_string(),
             language: "eng++deu++fra".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -446,6 +470,8 @@ fn test_ocr_cache_disabled_then_enabled() {
                 use_cache: false,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: false,
@@ -466,6 +492,8 @@ fn test_ocr_cache_disabled_then_enabled() {
                 use_cache: true,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -497,6 +525,8 @@ fn test_ocr_concurrent_same_file() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -563,6 +593,8 @@ fn test_ocr_concurrent_different_files() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: true,
@@ -629,9 +661,12 @@ fn test_ocr_with_preprocessing_extreme_dpi() {
                     contrast_enhance: false,
                     binarization_method: "otsu".to_string(),
                     invert_colors: false,
+                    auto_tune: false,
                 }),
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
@@ -674,9 +709,12 @@ fn test_ocr_with_invalid_binarization_method() {
                     contrast_enhance: false,
                     binarization_method: "invalid_method_xyz".to_string(),
                     invert_colors: false,
+                    auto_tune: false,
                 }),
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         ..Default::default()
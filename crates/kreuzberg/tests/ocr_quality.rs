@@ -167,6 +167,8 @@ fn test_ocr_quality_simple_text_high_accuracy() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -241,6 +243,8 @@ fn test_ocr_quality_numeric_accuracy() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -306,6 +310,8 @@ fn test_ocr_quality_layout_preservation() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -365,6 +371,8 @@ fn test_ocr_quality_technical_document() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -409,6 +417,8 @@ fn test_ocr_consistency_across_runs() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         use_cache: false,
@@ -474,6 +484,8 @@ fn test_ocr_consistency_with_different_psm() {
                 psm: 3,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -487,6 +499,8 @@ fn test_ocr_consistency_with_different_psm() {
                 psm: 6,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -557,6 +571,8 @@ fn test_ocr_quality_multi_page_consistency() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -608,6 +624,8 @@ fn test_ocr_quality_with_tables() {
                 table_min_confidence: 0.5,
                 ..Default::default()
             }),
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: true,
         ..Default::default()
@@ -198,6 +198,8 @@ fn test_tesseract_api_thread_safety() {
             backend: "tesseract".to_string(),
             language: "eng".to_string(),
             tesseract_config: None,
+            fallback: None,
+            vocabulary: None,
         }),
         force_ocr: false,
         use_cache: false,
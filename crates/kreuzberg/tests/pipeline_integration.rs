@@ -119,9 +119,7 @@ impl PostProcessor for FailingProcessor {
 
 fn clear_processor_registry() {
     let registry = get_post_processor_registry();
-    let mut reg = registry
-        .write()
-        .expect("Failed to acquire write lock on registry in test");
+    let mut reg = registry.edit();
     let _ = reg.shutdown_all();
     drop(reg);
     let _ = clear_processor_cache();
@@ -141,6 +139,10 @@ async fn test_pipeline_empty_no_processors() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -155,9 +157,7 @@ async fn test_pipeline_single_processor_per_stage() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let early = Arc::new(OrderTrackingProcessor {
             name: "early".to_string(),
@@ -186,6 +186,10 @@ async fn test_pipeline_single_processor_per_stage() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -200,9 +204,7 @@ async fn test_pipeline_multiple_processors_per_stage() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let early_high = Arc::new(OrderTrackingProcessor {
             name: "early-high".to_string(),
@@ -231,6 +233,10 @@ async fn test_pipeline_multiple_processors_per_stage() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -245,9 +251,7 @@ async fn test_pipeline_all_stages_enabled() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for stage in [ProcessingStage::Early, ProcessingStage::Middle, ProcessingStage::Late] {
             let processor = Arc::new(OrderTrackingProcessor {
@@ -267,6 +271,10 @@ async fn test_pipeline_all_stages_enabled() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -281,9 +289,7 @@ async fn test_pipeline_postprocessing_disabled() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let processor = Arc::new(OrderTrackingProcessor {
             name: "processor".to_string(),
@@ -301,6 +307,10 @@ async fn test_pipeline_postprocessing_disabled() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -324,9 +334,7 @@ async fn test_pipeline_early_stage_runs_first() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let late = Arc::new(OrderTrackingProcessor {
             name: "late".to_string(),
@@ -350,6 +358,10 @@ async fn test_pipeline_early_stage_runs_first() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -364,9 +376,7 @@ async fn test_pipeline_middle_stage_runs_second() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let early = Arc::new(OrderTrackingProcessor {
             name: "early".to_string(),
@@ -390,6 +400,10 @@ async fn test_pipeline_middle_stage_runs_second() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -404,9 +418,7 @@ async fn test_pipeline_late_stage_runs_last() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for stage in [ProcessingStage::Late, ProcessingStage::Early, ProcessingStage::Middle] {
             let processor = Arc::new(OrderTrackingProcessor {
@@ -426,6 +438,10 @@ async fn test_pipeline_late_stage_runs_last() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -440,9 +456,7 @@ async fn test_pipeline_within_stage_priority_order() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for (name, priority) in [("p1", 100), ("p2", 10), ("p3", 50), ("p4", 75)] {
             let processor = Arc::new(OrderTrackingProcessor {
@@ -462,6 +476,10 @@ async fn test_pipeline_within_stage_priority_order() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -476,9 +494,7 @@ async fn test_pipeline_cross_stage_data_flow() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let early = Arc::new(MetadataAddingProcessor {
             name: "early".to_string(),
@@ -527,6 +543,10 @@ async fn test_pipeline_cross_stage_data_flow() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -569,9 +589,7 @@ async fn test_pipeline_early_stage_error_recorded() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
         reg.register(Arc::new(EarlyFailingProcessor), 50).unwrap();
     }
 
@@ -584,6 +602,10 @@ async fn test_pipeline_early_stage_error_recorded() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -605,9 +627,7 @@ async fn test_pipeline_middle_stage_error_propagation() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let failing = Arc::new(FailingProcessor {
             name: "middle-failing".to_string(),
@@ -626,6 +646,10 @@ async fn test_pipeline_middle_stage_error_propagation() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -675,9 +699,7 @@ async fn test_pipeline_late_stage_error_doesnt_affect_earlier_stages() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let early = Arc::new(OrderTrackingProcessor {
             name: "early".to_string(),
@@ -698,6 +720,10 @@ async fn test_pipeline_late_stage_error_doesnt_affect_earlier_stages() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -719,9 +745,7 @@ async fn test_pipeline_processor_error_doesnt_stop_other_processors() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let p1 = Arc::new(OrderTrackingProcessor {
             name: "p1".to_string(),
@@ -786,6 +810,10 @@ async fn test_pipeline_processor_error_doesnt_stop_other_processors() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -838,9 +866,7 @@ async fn test_pipeline_multiple_processor_errors() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for (name, stage) in [
             ("fail1", ProcessingStage::Early),
@@ -864,6 +890,10 @@ async fn test_pipeline_multiple_processor_errors() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -885,9 +915,7 @@ async fn test_pipeline_error_context_preservation() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let failing = Arc::new(FailingProcessor {
             name: "context-test".to_string(),
@@ -906,6 +934,10 @@ async fn test_pipeline_error_context_preservation() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -927,9 +959,7 @@ async fn test_pipeline_metadata_added_in_early_visible_in_middle() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let early = Arc::new(MetadataAddingProcessor {
             name: "early".to_string(),
@@ -978,6 +1008,10 @@ async fn test_pipeline_metadata_added_in_early_visible_in_middle() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -1001,9 +1035,7 @@ async fn test_pipeline_content_modified_in_middle_visible_in_late() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         let middle = Arc::new(OrderTrackingProcessor {
             name: "middle-content".to_string(),
@@ -1049,6 +1081,10 @@ async fn test_pipeline_content_modified_in_middle_visible_in_late() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -1064,9 +1100,7 @@ async fn test_pipeline_multiple_processors_modifying_same_metadata() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for i in 1..=3 {
             struct MetadataOverwritingProcessor {
@@ -1118,6 +1152,10 @@ async fn test_pipeline_multiple_processors_modifying_same_metadata() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -1141,9 +1179,7 @@ async fn test_pipeline_processors_reading_previous_output() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         struct CountingProcessor {
             name: String,
@@ -1206,6 +1242,10 @@ async fn test_pipeline_processors_reading_previous_output() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -1246,9 +1286,7 @@ async fn test_pipeline_large_content_modification() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
         reg.register(Arc::new(LargeContentProcessor), 50).unwrap();
     }
 
@@ -1261,6 +1299,10 @@ async fn test_pipeline_large_content_modification() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -1275,9 +1317,7 @@ async fn test_pipeline_enabled_processors_whitelist() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for name in ["proc1", "proc2", "proc3"] {
             let processor = Arc::new(OrderTrackingProcessor {
@@ -1297,6 +1337,10 @@ async fn test_pipeline_enabled_processors_whitelist() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -1322,9 +1366,7 @@ async fn test_pipeline_disabled_processors_blacklist() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for name in ["proc1", "proc2", "proc3"] {
             let processor = Arc::new(OrderTrackingProcessor {
@@ -1344,6 +1386,10 @@ async fn test_pipeline_disabled_processors_blacklist() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -1369,9 +1415,7 @@ async fn test_pipeline_no_filtering_runs_all() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for name in ["proc1", "proc2", "proc3"] {
             let processor = Arc::new(OrderTrackingProcessor {
@@ -1391,6 +1435,10 @@ async fn test_pipeline_no_filtering_runs_all() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig::default();
 
@@ -1407,9 +1455,7 @@ async fn test_pipeline_empty_whitelist_runs_none() {
 
     let registry = get_post_processor_registry();
     {
-        let mut reg = registry
-            .write()
-            .expect("Failed to acquire write lock on registry in test");
+        let mut reg = registry.edit();
 
         for name in ["proc1", "proc2"] {
             let processor = Arc::new(OrderTrackingProcessor {
@@ -1429,6 +1475,10 @@ async fn test_pipeline_empty_whitelist_runs_none() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -63,6 +63,10 @@ impl OcrBackend for MockOcrBackend {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -159,6 +163,10 @@ impl OcrBackend for ValidatingOcrBackend {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -216,6 +224,10 @@ impl OcrBackend for MetadataOcrBackend {
             chunks: None,
             images: None,
             pages: None,
+            term_offsets: None,
+            barcodes: None,
+            provenance: None,
+            schema_version: ExtractionResult::SCHEMA_VERSION,
         })
     }
 
@@ -234,7 +246,7 @@ fn test_register_custom_ocr_backend() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -247,7 +259,7 @@ fn test_register_custom_ocr_backend() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         let result = reg.register(Arc::clone(&backend) as Arc<dyn OcrBackend>);
         assert!(result.is_ok(), "Failed to register OCR backend: {:?}", result.err());
     }
@@ -258,14 +270,14 @@ fn test_register_custom_ocr_backend() {
     );
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
     assert!(list.contains(&"test-ocr".to_string()));
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -277,7 +289,7 @@ fn test_ocr_backend_used_for_image_extraction() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -290,7 +302,7 @@ fn test_ocr_backend_used_for_image_extraction() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&backend) as Arc<dyn OcrBackend>).unwrap();
     }
 
@@ -298,6 +310,8 @@ fn test_ocr_backend_used_for_image_extraction() {
         backend: "extraction-test-ocr".to_string(),
         language: "eng".to_string(),
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     let config = ExtractionConfig {
@@ -324,7 +338,7 @@ fn test_ocr_backend_used_for_image_extraction() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -336,7 +350,7 @@ fn test_ocr_backend_receives_correct_parameters() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -349,7 +363,7 @@ fn test_ocr_backend_receives_correct_parameters() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&backend) as Arc<dyn OcrBackend>).unwrap();
     }
 
@@ -357,6 +371,8 @@ fn test_ocr_backend_receives_correct_parameters() {
         backend: "param-test-ocr".to_string(),
         language: "deu".to_string(),
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     let config = ExtractionConfig {
@@ -376,7 +392,7 @@ fn test_ocr_backend_receives_correct_parameters() {
     assert!(extraction_result.content.contains("(lang: deu)"));
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -388,7 +404,7 @@ fn test_ocr_backend_returns_correct_format() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -397,7 +413,7 @@ fn test_ocr_backend_returns_correct_format() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(backend as Arc<dyn OcrBackend>).unwrap();
     }
 
@@ -405,6 +421,8 @@ fn test_ocr_backend_returns_correct_format() {
         backend: "format-test-ocr".to_string(),
         language: "eng".to_string(),
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     let config = ExtractionConfig {
@@ -426,7 +444,7 @@ fn test_ocr_backend_returns_correct_format() {
     assert!(extraction_result.metadata.additional.contains_key("ocr_language"));
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -438,7 +456,7 @@ fn test_ocr_backend_error_handling() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -447,7 +465,7 @@ fn test_ocr_backend_error_handling() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(backend as Arc<dyn OcrBackend>).unwrap();
     }
 
@@ -455,6 +473,8 @@ fn test_ocr_backend_error_handling() {
         backend: "failing-ocr".to_string(),
         language: "eng".to_string(),
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     let config = ExtractionConfig {
@@ -475,7 +495,7 @@ fn test_ocr_backend_error_handling() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -487,7 +507,7 @@ fn test_ocr_backend_validation_error() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -497,7 +517,7 @@ fn test_ocr_backend_validation_error() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(backend as Arc<dyn OcrBackend>).unwrap();
     }
 
@@ -505,6 +525,8 @@ fn test_ocr_backend_validation_error() {
         backend: "validating-ocr".to_string(),
         language: "eng".to_string(),
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     let config = ExtractionConfig {
@@ -525,7 +547,7 @@ fn test_ocr_backend_validation_error() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -537,7 +559,7 @@ fn test_switching_between_ocr_backends() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -558,7 +580,7 @@ fn test_switching_between_ocr_backends() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&backend1) as Arc<dyn OcrBackend>).unwrap();
         reg.register(Arc::clone(&backend2) as Arc<dyn OcrBackend>).unwrap();
     }
@@ -567,6 +589,8 @@ fn test_switching_between_ocr_backends() {
         backend: "backend-1".to_string(),
         language: "eng".to_string(),
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     let config1 = ExtractionConfig {
@@ -585,6 +609,8 @@ fn test_switching_between_ocr_backends() {
         backend: "backend-2".to_string(),
         language: "eng".to_string(),
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     let config2 = ExtractionConfig {
@@ -600,7 +626,7 @@ fn test_switching_between_ocr_backends() {
     assert_eq!(backend2.call_count.load(Ordering::SeqCst), 1);
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -611,7 +637,7 @@ fn test_ocr_backend_language_support() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -624,7 +650,7 @@ fn test_ocr_backend_language_support() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&backend) as Arc<dyn OcrBackend>).unwrap();
     }
 
@@ -640,7 +666,7 @@ fn test_ocr_backend_language_support() {
     assert!(supported.contains(&"fra".to_string()));
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -665,7 +691,7 @@ fn test_ocr_backend_invalid_name() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -678,7 +704,7 @@ fn test_ocr_backend_invalid_name() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         let result = reg.register(backend);
 
         assert!(result.is_err());
@@ -686,7 +712,7 @@ fn test_ocr_backend_invalid_name() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -697,7 +723,7 @@ fn test_ocr_backend_initialization_lifecycle() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -715,7 +741,7 @@ fn test_ocr_backend_initialization_lifecycle() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&backend) as Arc<dyn OcrBackend>).unwrap();
     }
 
@@ -725,7 +751,7 @@ fn test_ocr_backend_initialization_lifecycle() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -741,7 +767,7 @@ fn test_unregister_ocr_backend() {
     let registry = get_ocr_backend_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -754,17 +780,17 @@ fn test_unregister_ocr_backend() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&backend) as Arc<dyn OcrBackend>).unwrap();
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.remove("unregister-ocr").unwrap();
     }
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
@@ -167,9 +167,7 @@ impl PostProcessor for FailingProcessor {
 
 fn clear_processor_registry_and_cache() {
     let registry = get_post_processor_registry();
-    let mut reg = registry
-        .write()
-        .expect("Failed to acquire write lock on registry in test");
+    let mut reg = registry.edit();
     let _ = reg.shutdown_all();
     drop(reg);
     let _ = clear_processor_cache();
@@ -187,25 +185,25 @@ fn test_register_custom_postprocessor() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         let result = reg.register(Arc::clone(&processor) as Arc<dyn PostProcessor>, 100);
         assert!(result.is_ok(), "Failed to register processor: {:?}", result.err());
     }
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
     assert!(list.contains(&"test-appender".to_string()));
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -224,7 +222,7 @@ fn test_postprocessor_called_during_extraction() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&processor) as Arc<dyn PostProcessor>, 100)
             .unwrap();
     }
@@ -248,7 +246,7 @@ fn test_postprocessor_called_during_extraction() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -265,7 +263,7 @@ fn test_postprocessor_modifies_content() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(processor as Arc<dyn PostProcessor>, 100).unwrap();
     }
 
@@ -280,7 +278,7 @@ fn test_postprocessor_modifies_content() {
     assert!(!has_lowercase, "Content was not fully uppercased");
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -298,7 +296,7 @@ fn test_postprocessor_adds_metadata() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&processor) as Arc<dyn PostProcessor>, 100)
             .unwrap();
     }
@@ -328,7 +326,7 @@ fn test_postprocessor_adds_metadata() {
     assert_eq!(processed_by.as_str().unwrap(), "metadata-adder");
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -350,18 +348,18 @@ fn test_unregister_postprocessor() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&processor) as Arc<dyn PostProcessor>, 100)
             .unwrap();
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.remove("unregister-test").unwrap();
     }
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
@@ -382,7 +380,7 @@ fn test_unregister_postprocessor() {
     assert_eq!(processor.call_count.load(Ordering::SeqCst), 0);
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -393,7 +391,7 @@ fn test_clear_all_postprocessors() {
     let registry = get_post_processor_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -410,18 +408,18 @@ fn test_clear_all_postprocessors() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(processor1 as Arc<dyn PostProcessor>, 100).unwrap();
         reg.register(processor2 as Arc<dyn PostProcessor>, 100).unwrap();
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
@@ -440,7 +438,7 @@ fn test_postprocessor_error_handling() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(failing_processor as Arc<dyn PostProcessor>, 100).unwrap();
     }
 
@@ -462,7 +460,7 @@ fn test_postprocessor_error_handling() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -473,7 +471,7 @@ fn test_postprocessor_invalid_name() {
     let registry = get_post_processor_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -484,7 +482,7 @@ fn test_postprocessor_invalid_name() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         let result = reg.register(processor, 100);
 
         assert!(result.is_err());
@@ -492,7 +490,7 @@ fn test_postprocessor_invalid_name() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -520,7 +518,7 @@ fn test_multiple_postprocessors_execution_order() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(early_processor as Arc<dyn PostProcessor>, 100).unwrap();
         reg.register(middle_processor as Arc<dyn PostProcessor>, 100).unwrap();
         reg.register(late_processor as Arc<dyn PostProcessor>, 100).unwrap();
@@ -538,7 +536,7 @@ fn test_multiple_postprocessors_execution_order() {
     assert!(extraction_result.content.contains("[LATE]"));
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -557,7 +555,7 @@ fn test_postprocessor_preserves_mime_type() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&processor) as Arc<dyn PostProcessor>, 100)
             .unwrap();
     }
@@ -571,7 +569,7 @@ fn test_postprocessor_preserves_mime_type() {
     assert_eq!(extraction_result.mime_type, "text/plain");
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -59,6 +59,10 @@ impl DocumentExtractor for FailingExtractor {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             })
         }
     }
@@ -235,10 +239,11 @@ fn test_extractor_duplicate_registration() {
 
 #[test]
 fn test_extractor_concurrent_registration() {
-    use std::sync::{Arc as StdArc, RwLock};
+    use kreuzberg::plugins::registry::PluginRegistry;
+    use std::sync::Arc as StdArc;
     use std::thread;
 
-    let registry = StdArc::new(RwLock::new(DocumentExtractorRegistry::new()));
+    let registry = StdArc::new(PluginRegistry::new(DocumentExtractorRegistry::new()));
     let mut handles = vec![];
 
     for i in 0..10 {
@@ -250,10 +255,7 @@ fn test_extractor_concurrent_registration() {
                 should_fail_extract: false,
             });
 
-            let mut reg = registry_clone
-                .write()
-                .expect("Failed to acquire write lock on registry in test");
-            reg.register(extractor).unwrap();
+            registry_clone.update(|reg| reg.register(extractor)).unwrap();
         });
         handles.push(handle);
     }
@@ -262,9 +264,7 @@ fn test_extractor_concurrent_registration() {
         handle.join().unwrap();
     }
 
-    let reg = registry
-        .read()
-        .expect("Failed to acquire read lock on registry in test");
+    let reg = registry.snapshot();
     assert_eq!(reg.list().len(), 10);
 }
 
@@ -304,6 +304,10 @@ fn test_extractor_priority_ordering_complex() {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: ExtractionResult::SCHEMA_VERSION,
             })
         }
         fn supported_mime_types(&self) -> &[&str] {
@@ -464,6 +468,10 @@ async fn test_processor_execution_order_within_stage() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
 
     let config = ExtractionConfig::default();
@@ -496,6 +504,10 @@ async fn test_processor_error_propagation() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
 
     let config = ExtractionConfig::default();
@@ -668,6 +680,10 @@ async fn test_validator_content_validation() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
 
     let validation = validators[0].validate(&short_result, &config).await;
@@ -682,6 +698,10 @@ async fn test_validator_content_validation() {
         chunks: None,
         images: None,
         pages: None,
+        term_offsets: None,
+        barcodes: None,
+        provenance: None,
+        schema_version: ExtractionResult::SCHEMA_VERSION,
     };
 
     let validation = validators[0].validate(&long_result, &config).await;
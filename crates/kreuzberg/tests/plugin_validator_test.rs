@@ -240,7 +240,7 @@ fn test_register_custom_validator() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -250,7 +250,7 @@ fn test_register_custom_validator() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         let result = reg.register(Arc::clone(&validator) as Arc<dyn Validator>);
         assert!(result.is_ok(), "Failed to register validator: {:?}", result.err());
     }
@@ -261,14 +261,14 @@ fn test_register_custom_validator() {
     );
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
     assert!(list.contains(&"test-validator".to_string()));
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -280,7 +280,7 @@ fn test_validator_called_during_extraction() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -291,7 +291,7 @@ fn test_validator_called_during_extraction() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&validator) as Arc<dyn Validator>).unwrap();
     }
 
@@ -307,7 +307,7 @@ fn test_validator_called_during_extraction() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -319,7 +319,7 @@ fn test_validator_can_reject_invalid_input() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -330,7 +330,7 @@ fn test_validator_can_reject_invalid_input() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(validator as Arc<dyn Validator>).unwrap();
     }
 
@@ -347,7 +347,7 @@ fn test_validator_can_reject_invalid_input() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -359,7 +359,7 @@ fn test_validator_can_pass_valid_input() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -370,7 +370,7 @@ fn test_validator_can_pass_valid_input() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(validator as Arc<dyn Validator>).unwrap();
     }
 
@@ -380,7 +380,7 @@ fn test_validator_can_pass_valid_input() {
     assert!(result.is_ok(), "Validation should have passed: {:?}", result.err());
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -392,7 +392,7 @@ fn test_validator_receives_correct_parameters() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -402,7 +402,7 @@ fn test_validator_receives_correct_parameters() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(validator as Arc<dyn Validator>).unwrap();
     }
 
@@ -415,7 +415,7 @@ fn test_validator_receives_correct_parameters() {
     assert_eq!(extraction_result.mime_type, "text/plain");
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -427,7 +427,7 @@ fn test_validator_rejects_wrong_mime_type() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -437,7 +437,7 @@ fn test_validator_rejects_wrong_mime_type() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(validator as Arc<dyn Validator>).unwrap();
     }
 
@@ -455,7 +455,7 @@ fn test_validator_rejects_wrong_mime_type() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -466,7 +466,7 @@ fn test_unregister_validator() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -475,17 +475,17 @@ fn test_unregister_validator() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(validator as Arc<dyn Validator>).unwrap();
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.remove("unregister-test").unwrap();
     }
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
@@ -501,7 +501,7 @@ fn test_unregister_validator() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -512,7 +512,7 @@ fn test_clear_all_validators() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -525,18 +525,18 @@ fn test_clear_all_validators() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(validator1 as Arc<dyn Validator>).unwrap();
         reg.register(validator2 as Arc<dyn Validator>).unwrap();
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
     let list = {
-        let reg = registry.read().unwrap();
+        let reg = registry.snapshot();
         reg.list()
     };
 
@@ -555,7 +555,7 @@ fn test_validator_invalid_name() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -565,7 +565,7 @@ fn test_validator_invalid_name() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         let result = reg.register(validator);
 
         assert!(result.is_err());
@@ -573,7 +573,7 @@ fn test_validator_invalid_name() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -584,7 +584,7 @@ fn test_validator_initialization_lifecycle() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -599,7 +599,7 @@ fn test_validator_initialization_lifecycle() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&validator) as Arc<dyn Validator>).unwrap();
     }
 
@@ -609,7 +609,7 @@ fn test_validator_initialization_lifecycle() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -626,7 +626,7 @@ fn test_multiple_validators_execution() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -642,7 +642,7 @@ fn test_multiple_validators_execution() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::clone(&validator1) as Arc<dyn Validator>).unwrap();
         reg.register(validator2 as Arc<dyn Validator>).unwrap();
     }
@@ -654,7 +654,7 @@ fn test_multiple_validators_execution() {
     assert_eq!(validator1.call_count.load(Ordering::SeqCst), 1);
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -666,7 +666,7 @@ fn test_validator_priority_execution_order() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -681,7 +681,7 @@ fn test_validator_priority_execution_order() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(high_priority as Arc<dyn Validator>).unwrap();
         reg.register(low_priority as Arc<dyn Validator>).unwrap();
     }
@@ -699,7 +699,7 @@ fn test_validator_priority_execution_order() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -711,7 +711,7 @@ fn test_validator_always_fails() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -720,7 +720,7 @@ fn test_validator_always_fails() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(validator as Arc<dyn Validator>).unwrap();
     }
 
@@ -737,7 +737,7 @@ fn test_validator_always_fails() {
     }
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -749,7 +749,7 @@ fn test_validator_registration_order_preserved_for_same_priority() {
     let registry = get_validator_registry();
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 
@@ -759,7 +759,7 @@ fn test_validator_registration_order_preserved_for_same_priority() {
     });
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.register(Arc::new(FailingValidator {
             name: "order-first".to_string(),
         }) as Arc<dyn Validator>)
@@ -777,7 +777,7 @@ fn test_validator_registration_order_preserved_for_same_priority() {
     );
 
     {
-        let mut reg = registry.write().unwrap();
+        let mut reg = registry.edit();
         reg.shutdown_all().unwrap();
     }
 }
@@ -82,6 +82,17 @@ namespace Kreuzberg.E2E.Office {
             TestHelpers.AssertTableCount(result, 1, null);
         }
 
+        [Fact]
+        public void OfficeEpubChapters()
+        {
+            TestHelpers.SkipIfLegacyOfficeDisabled("epub/formatting.epub");
+            var result = TestHelpers.RunExtraction("epub/formatting.epub", null);
+            TestHelpers.AssertExpectedMime(result, new[] { "application/epub+zip" });
+            TestHelpers.AssertMinContentLength(result, 200);
+            var metadataNode = TestHelpers.MetadataToJson(result.Metadata);
+            TestHelpers.AssertMetadata(metadataNode, "pages.total_count", @"{""gte"": 5}");
+        }
+
         [Fact]
         public void OfficePptLegacy()
         {
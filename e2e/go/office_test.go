@@ -55,6 +55,12 @@ func TestOfficeOfficeDocxTables(t *testing.T) {
 	assertTableCount(t, result, intPtr(1), nil)
 }
 
+func TestOfficeOfficeEpubChapters(t *testing.T) {
+	result := runExtraction(t, "epub/formatting.epub", nil)
+	assertExpectedMime(t, result, []string{"application/epub+zip"})
+	assertMinContentLength(t, result, 200)
+}
+
 func TestOfficeOfficePptLegacy(t *testing.T) {
 	result := runExtraction(t, "legacy_office/simple.ppt", nil)
 	assertExpectedMime(t, result, []string{"application/vnd.ms-powerpoint"})
@@ -155,6 +155,24 @@ public class OfficeTest {
         );
     }
 
+    @Test
+    public void officeEpubChapters() throws Exception {
+        JsonNode config = null;
+        E2EHelpers.runFixture(
+            "office_epub_chapters",
+            "epub/formatting.epub",
+            config,
+            Collections.emptyList(),
+            null,
+            true,
+            result -> {
+                E2EHelpers.Assertions.assertExpectedMime(result, Arrays.asList("application/epub+zip"));
+                E2EHelpers.Assertions.assertMinContentLength(result, 200);
+                E2EHelpers.Assertions.assertMetadataExpectation(result, "pages.total_count", Map.of("gte", 5));
+            }
+        );
+    }
+
     @Test
     public void officePptLegacy() throws Exception {
         JsonNode config = null;
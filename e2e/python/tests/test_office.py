@@ -130,6 +130,22 @@ def test_office_docx_tables() -> None:
     helpers.assert_table_count(result, 1, None)
 
 
+def test_office_epub_chapters() -> None:
+    """EPUB ebook should report one chapter per spine document."""
+
+    document_path = helpers.resolve_document("epub/formatting.epub")
+    if not document_path.exists():
+        pytest.skip(f"Skipping office_epub_chapters: missing document at {document_path}")
+
+    config = helpers.build_config(None)
+
+    result = extract_file_sync(document_path, None, config)
+
+    helpers.assert_expected_mime(result, ["application/epub+zip"])
+    helpers.assert_min_content_length(result, 200)
+    helpers.assert_metadata_expectation(result, "pages.total_count", {"gte": 5})
+
+
 def test_office_ppt_legacy() -> None:
     """Legacy PowerPoint .ppt file requiring LibreOffice conversion."""
 
@@ -144,6 +144,24 @@ RSpec.describe 'office fixtures' do
     end
   end
 
+  it 'office_epub_chapters' do
+    E2ERuby.run_fixture(
+      'office_epub_chapters',
+      'epub/formatting.epub',
+      nil,
+      requirements: [],
+      notes: nil,
+      skip_if_missing: true
+    ) do |result|
+      E2ERuby::Assertions.assert_expected_mime(
+        result,
+        ['application/epub+zip']
+      )
+      E2ERuby::Assertions.assert_min_content_length(result, 200)
+      E2ERuby::Assertions.assert_metadata_expectation(result, 'pages.total_count', { gte: 5 })
+    end
+  end
+
   it 'office_ppt_legacy' do
     E2ERuby.run_fixture(
       'office_ppt_legacy',
@@ -225,6 +225,30 @@ fn test_office_docx_tables() {
     assertions::assert_table_count(&result, Some(1), None);
 }
 
+#[test]
+fn test_office_epub_chapters() {
+    // EPUB ebook should report one chapter per spine document.
+
+    let document_path = resolve_document("epub/formatting.epub");
+    if !document_path.exists() {
+        println!(
+            "Skipping office_epub_chapters: missing document at {}",
+            document_path.display()
+        );
+        return;
+    }
+    let config = ExtractionConfig::default();
+
+    let result = match kreuzberg::extract_file_sync(&document_path, None, &config) {
+        Err(err) => panic!("Extraction failed for office_epub_chapters: {err:?}"),
+        Ok(result) => result,
+    };
+
+    assertions::assert_expected_mime(&result, &["application/epub+zip"]);
+    assertions::assert_min_content_length(&result, 200);
+    assertions::assert_metadata_expectation(&result, "pages.total_count", &serde_json::json!({"gte":5}));
+}
+
 #[test]
 fn test_office_ppt_legacy() {
     // Legacy PowerPoint .ppt file requiring LibreOffice conversion.
@@ -151,10 +151,9 @@ fn test_post_processors_clear() {
     // Clear all post-processors and verify list is empty
 
     // Clear post-processors via registry (no helper function)
-    let registry = kreuzberg::plugins::registry::get_post_processor_registry();
-    let mut registry = registry.write().expect("Failed to acquire write lock");
-    registry.shutdown_all().expect("Failed to clear registry");
-    drop(registry);
+    kreuzberg::plugins::registry::get_post_processor_registry()
+        .update(|registry| registry.shutdown_all())
+        .expect("Failed to clear registry");
 
     let result = list_post_processors().expect("Failed to list registry");
     assert!(result.is_empty());
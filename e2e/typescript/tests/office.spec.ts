@@ -254,6 +254,34 @@ describe("office fixtures", () => {
 		TEST_TIMEOUT_MS,
 	);
 
+	it(
+		"office_epub_chapters",
+		() => {
+			const documentPath = resolveDocument("epub/formatting.epub");
+			if (!existsSync(documentPath)) {
+				console.warn("Skipping office_epub_chapters: missing document at", documentPath);
+				return;
+			}
+			const config = buildConfig(undefined);
+			let result: ExtractionResult | null = null;
+			try {
+				result = extractFileSync(documentPath, null, config);
+			} catch (error) {
+				if (shouldSkipFixture(error, "office_epub_chapters", [], undefined)) {
+					return;
+				}
+				throw error;
+			}
+			if (result === null) {
+				return;
+			}
+			assertions.assertExpectedMime(result, ["application/epub+zip"]);
+			assertions.assertMinContentLength(result, 200);
+			assertions.assertMetadataExpectation(result, "pages.total_count", { gte: 5 });
+		},
+		TEST_TIMEOUT_MS,
+	);
+
 	it(
 		"office_ppt_legacy",
 		() => {
@@ -191,6 +191,26 @@ Deno.test("office_docx_tables", { permissions: { read: true } }, async () => {
 	assertions.assertTableCount(result, 1, null);
 });
 
+Deno.test("office_epub_chapters", { permissions: { read: true } }, async () => {
+	const documentBytes = await resolveDocument("epub/formatting.epub");
+	const config = buildConfig(undefined);
+	let result: ExtractionResult | null = null;
+	try {
+		result = await extractBytes(documentBytes, "application/epub+zip", config);
+	} catch (error) {
+		if (shouldSkipFixture(error, "office_epub_chapters", [], undefined)) {
+			return;
+		}
+		throw error;
+	}
+	if (result === null) {
+		return;
+	}
+	assertions.assertExpectedMime(result, ["application/epub+zip"]);
+	assertions.assertMinContentLength(result, 200);
+	assertions.assertMetadataExpectation(result, "pages.total_count", { gte: 5 });
+});
+
 Deno.test("office_ppt_legacy", { permissions: { read: true } }, async () => {
 	const documentBytes = await resolveDocument("legacy_office/simple.ppt");
 	const config = buildConfig(undefined);
@@ -230,11 +230,7 @@ async fn main() -> kreuzberg::Result<()> {
     println!("=== Registering Custom CSV Extractor ===");
     let csv_extractor = Arc::new(CSVExtractor::new().with_headers(true)) as Arc<dyn DocumentExtractor>;
 
-    let registry = get_document_extractor_registry();
-    {
-        let mut registry = registry.write().unwrap();
-        registry.register(csv_extractor)?;
-    }
+    get_document_extractor_registry().update(|registry| registry.register(csv_extractor))?;
     println!("✓ Registered CSV extractor with priority 100");
 
     println!("\n=== Extracting CSV File ===");
@@ -31,6 +31,8 @@ public enum KreuzbergErrorKind
     UnsupportedFormat,
     /// <summary>Runtime error (lock poisoning, unsupported operation, etc.).</summary>
     Runtime,
+    /// <summary>An extraction stage exceeded its time budget.</summary>
+    Timeout,
 }
 
 /// <summary>
@@ -228,6 +230,29 @@ public class KreuzbergUnsupportedFormatException : KreuzbergException
     }
 }
 
+/// <summary>
+/// Exception thrown when an extraction stage exceeds its budget in <see cref="ExtractionConfig.StageTimeouts"/>.
+/// </summary>
+public class KreuzbergTimeoutException : KreuzbergException
+{
+    /// <summary>
+    /// Gets the stage that timed out: "extraction", "ocr" or "post_processing".
+    /// </summary>
+    public string Stage { get; }
+
+    /// <summary>
+    /// Initializes a new instance of the KreuzbergTimeoutException class.
+    /// </summary>
+    /// <param name="stage">The stage that exceeded its budget.</param>
+    /// <param name="message">The error message naming the stage and budget.</param>
+    /// <param name="inner">The inner exception that caused this error, if any.</param>
+    public KreuzbergTimeoutException(string stage, string message, Exception? inner = null)
+        : base(KreuzbergErrorKind.Timeout, ErrorMapper.PrefixMessage(message, "Timeout in"), inner)
+    {
+        Stage = stage;
+    }
+}
+
 /// <summary>
 /// Exception thrown when an input/output error occurs (file access, network, etc.).
 /// Inherits from IOException for compatibility with standard .NET I/O exception handling.
@@ -341,6 +366,12 @@ internal static class ErrorMapper
             return new KreuzbergUnsupportedFormatException(format, trimmed);
         }
 
+        if (trimmed.StartsWith("Timeout in ", StringComparison.OrdinalIgnoreCase))
+        {
+            var stage = trimmed["Timeout in ".Length..].Split(' ')[0];
+            return new KreuzbergTimeoutException(stage, trimmed);
+        }
+
         if (trimmed.StartsWith("IO error:", StringComparison.OrdinalIgnoreCase))
         {
             return new KreuzbergIOException(trimmed);
@@ -39,6 +39,7 @@ namespace Kreuzberg;
 [JsonSerializable(typeof(HtmlPreprocessingOptions))]
 [JsonSerializable(typeof(KeywordConfig))]
 [JsonSerializable(typeof(PageConfig))]
+[JsonSerializable(typeof(CsvConfig))]
 [JsonSerializable(typeof(Metadata))]
 [JsonSerializable(typeof(Table))]
 [JsonSerializable(typeof(Chunk))]
@@ -116,6 +116,12 @@ public sealed class Table
     /// </summary>
     [JsonPropertyName("page_number")]
     public int PageNumber { get; set; }
+
+    /// <summary>
+    /// Inferred type of each column ("string", "number" or "date"), if the extractor provides it.
+    /// </summary>
+    [JsonPropertyName("column_types")]
+    public List<string>? ColumnTypes { get; set; }
 }
 
 /// <summary>
@@ -260,6 +266,12 @@ public sealed class ExtractedImage
     /// </summary>
     [JsonPropertyName("ocr_result")]
     public ExtractionResult? OcrResult { get; set; }
+
+    /// <summary>
+    /// Perceptual hash (16 hex digits) when image hashing is enabled.
+    /// </summary>
+    [JsonPropertyName("perceptual_hash")]
+    public string? PerceptualHash { get; set; }
 }
 
 public enum FormatType
@@ -779,6 +791,18 @@ public sealed class ExtractionConfig
     [JsonPropertyName("force_ocr")]
     public bool? ForceOcr { get; set; }
 
+    /// <summary>
+    /// Correction of common OCR confusions in OCR-derived text. Disabled when null.
+    /// </summary>
+    [JsonPropertyName("ocr_correction")]
+    public OcrCorrectionConfig? OcrCorrection { get; set; }
+
+    /// <summary>
+    /// Whether to re-extract only the appended or modified pages of a PDF extracted before. Default is false.
+    /// </summary>
+    [JsonPropertyName("incremental")]
+    public bool? Incremental { get; set; }
+
     /// <summary>
     /// Text chunking configuration for splitting long documents. If null, chunking is disabled.
     /// </summary>
@@ -809,6 +833,30 @@ public sealed class ExtractionConfig
     [JsonPropertyName("language_detection")]
     public LanguageDetectionConfig? LanguageDetection { get; set; }
 
+    /// <summary>
+    /// Document type classification written to <c>metadata["document_type"]</c>. Disabled when null.
+    /// </summary>
+    [JsonPropertyName("classification")]
+    public ClassificationConfig? Classification { get; set; }
+
+    /// <summary>
+    /// Key-value pair extraction written to <c>metadata["key_values"]</c>. Disabled when null.
+    /// </summary>
+    [JsonPropertyName("kv_extraction")]
+    public KeyValueExtractionConfig? KvExtraction { get; set; }
+
+    /// <summary>
+    /// Removal of headers, footers and page numbers repeated across pages. Disabled when null.
+    /// </summary>
+    [JsonPropertyName("cleanup")]
+    public CleanupConfig? Cleanup { get; set; }
+
+    /// <summary>
+    /// Watermark and stamp detection written to <c>metadata["watermarks"]</c>. Disabled when null.
+    /// </summary>
+    [JsonPropertyName("watermarks")]
+    public WatermarkConfig? Watermarks { get; set; }
+
     /// <summary>
     /// Post-processor configuration for controlling which processors are enabled/disabled.
     /// </summary>
@@ -833,11 +881,29 @@ public sealed class ExtractionConfig
     [JsonPropertyName("pages")]
     public PageConfig? Pages { get; set; }
 
+    /// <summary>
+    /// CSV/TSV parsing configuration (delimiter override, row limit).
+    /// </summary>
+    [JsonPropertyName("csv_options")]
+    public CsvConfig? CsvOptions { get; set; }
+
     /// <summary>
     /// Maximum number of concurrent extractions in batch operations. Default is null.
     /// </summary>
     [JsonPropertyName("max_concurrent_extractions")]
     public int? MaxConcurrentExtractions { get; set; }
+
+    /// <summary>
+    /// Time budgets of extraction stages. Stages exceeding their budget fail with <see cref="KreuzbergTimeoutException"/>.
+    /// </summary>
+    [JsonPropertyName("stage_timeouts")]
+    public StageTimeoutConfig? StageTimeouts { get; set; }
+
+    /// <summary>
+    /// Retries of batch documents that failed for a transient reason. Default is null (one attempt).
+    /// </summary>
+    [JsonPropertyName("retry")]
+    public RetryConfig? Retry { get; set; }
 }
 
 /// <summary>
@@ -862,6 +928,152 @@ public sealed class OcrConfig
     /// </summary>
     [JsonPropertyName("tesseract_config")]
     public TesseractConfig? TesseractConfig { get; set; }
+
+    /// <summary>
+    /// Domain vocabulary added to Tesseract's user words and used to correct OCR output.
+    /// </summary>
+    [JsonPropertyName("vocabulary")]
+    public OcrVocabularyConfig? Vocabulary { get; set; }
+}
+
+/// <summary>
+/// Domain word list for OCR, such as drug names or part numbers. OCR words within
+/// <see cref="MaxDistance"/> edits of exactly one vocabulary word are replaced with it.
+/// </summary>
+public sealed class OcrVocabularyConfig
+{
+    /// <summary>
+    /// Inline vocabulary words.
+    /// </summary>
+    [JsonPropertyName("words")]
+    public List<string>? Words { get; set; }
+
+    /// <summary>
+    /// Word list file with one entry per line; lines starting with '#' are comments.
+    /// </summary>
+    [JsonPropertyName("words_file")]
+    public string? WordsFile { get; set; }
+
+    /// <summary>
+    /// Replace near-miss OCR words with vocabulary words (default true).
+    /// </summary>
+    [JsonPropertyName("correct")]
+    public bool? Correct { get; set; }
+
+    /// <summary>
+    /// Maximum edit distance for a correction (default 1).
+    /// </summary>
+    [JsonPropertyName("max_distance")]
+    public int? MaxDistance { get; set; }
+
+    /// <summary>
+    /// Minimum length of an OCR word to correct (default 4).
+    /// </summary>
+    [JsonPropertyName("min_word_length")]
+    public int? MinWordLength { get; set; }
+}
+
+/// <summary>
+/// Correction of characters OCR engines commonly confuse, such as O/0, l/1 and rn/m.
+/// </summary>
+public sealed class OcrCorrectionConfig
+{
+    /// <summary>
+    /// Apply the built-in confusion rules (default true).
+    /// </summary>
+    [JsonPropertyName("builtin_rules")]
+    public bool? BuiltinRules { get; set; }
+
+    /// <summary>
+    /// Additional confusion rules, applied after the built-in ones.
+    /// </summary>
+    [JsonPropertyName("rules")]
+    public List<OcrConfusionRule>? Rules { get; set; }
+}
+
+/// <summary>
+/// Time budgets of extraction stages, in milliseconds. Stages without a budget run unbounded.
+/// </summary>
+public sealed class StageTimeoutConfig
+{
+    /// <summary>
+    /// Budget of the format extractor, including the OCR it runs.
+    /// </summary>
+    [JsonPropertyName("extraction_ms")]
+    public ulong? ExtractionMs { get; set; }
+
+    /// <summary>
+    /// Budget of OCR for one PDF page or image.
+    /// </summary>
+    [JsonPropertyName("ocr_ms")]
+    public ulong? OcrMs { get; set; }
+
+    /// <summary>
+    /// Budget of the post-processing pipeline.
+    /// </summary>
+    [JsonPropertyName("post_processing_ms")]
+    public ulong? PostProcessingMs { get; set; }
+}
+
+/// <summary>
+/// Retry policy for batch documents whose extraction failed with a transient error. The
+/// number of attempts is reported in the <c>extraction_attempts</c> metadata.
+/// </summary>
+public sealed class RetryConfig
+{
+    /// <summary>
+    /// Attempts per document including the first one; 1 disables retries (default 3).
+    /// </summary>
+    [JsonPropertyName("max_attempts")]
+    public uint? MaxAttempts { get; set; }
+
+    /// <summary>
+    /// Wait before the first retry, in milliseconds (default 200).
+    /// </summary>
+    [JsonPropertyName("initial_backoff_ms")]
+    public ulong? InitialBackoffMs { get; set; }
+
+    /// <summary>
+    /// Upper bound of the wait before a retry, in milliseconds (default 5000).
+    /// </summary>
+    [JsonPropertyName("max_backoff_ms")]
+    public ulong? MaxBackoffMs { get; set; }
+
+    /// <summary>
+    /// Factor the wait grows by after every retry (default 2.0).
+    /// </summary>
+    [JsonPropertyName("multiplier")]
+    public double? Multiplier { get; set; }
+
+    /// <summary>
+    /// Retried error classes: "io", "ocr", "timeout", "cache" and "plugin" (default "io" and "ocr").
+    /// </summary>
+    [JsonPropertyName("retry_on")]
+    public List<string>? RetryOn { get; set; }
+}
+
+/// <summary>
+/// Replacement of a character sequence OCR misreads.
+/// </summary>
+public sealed class OcrConfusionRule
+{
+    /// <summary>
+    /// The sequence as misread by OCR.
+    /// </summary>
+    [JsonPropertyName("from")]
+    public string From { get; set; } = string.Empty;
+
+    /// <summary>
+    /// The intended sequence.
+    /// </summary>
+    [JsonPropertyName("to")]
+    public string To { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Where the rule applies: "numeric", "alphabetic", "word_start" or "anywhere" (default).
+    /// </summary>
+    [JsonPropertyName("context")]
+    public string? Context { get; set; }
 }
 
 /// <summary>
@@ -1042,6 +1254,13 @@ public sealed class ImagePreprocessingConfig
     /// </summary>
     [JsonPropertyName("invert_colors")]
     public bool? InvertColors { get; set; }
+
+    /// <summary>
+    /// Whether to choose binarization, denoising, contrast and DPI per page from image statistics.
+    /// The choices are recorded in the "preprocessing" and "ocr_pages" metadata.
+    /// </summary>
+    [JsonPropertyName("auto_tune")]
+    public bool? AutoTune { get; set; }
 }
 
 /// <summary>
@@ -1090,6 +1309,12 @@ public sealed class ChunkingConfig
     /// </summary>
     [JsonPropertyName("enabled")]
     public bool? Enabled { get; set; }
+
+    /// <summary>
+    /// Keep whole sentences together, split with the rules of the document language.
+    /// </summary>
+    [JsonPropertyName("respect_sentences")]
+    public bool? RespectSentences { get; set; }
 }
 
 /// <summary>
@@ -1132,6 +1357,72 @@ public sealed class ImageExtractionConfig
     /// </summary>
     [JsonPropertyName("max_dpi")]
     public int? MaxDpi { get; set; }
+
+    /// <summary>
+    /// Whether to compute a perceptual hash for each extracted image.
+    /// </summary>
+    [JsonPropertyName("perceptual_hash")]
+    public bool? PerceptualHash { get; set; }
+
+    /// <summary>
+    /// Whether to drop near-duplicate images such as repeated logos and watermarks.
+    /// </summary>
+    [JsonPropertyName("deduplicate_images")]
+    public bool? DeduplicateImages { get; set; }
+
+    /// <summary>
+    /// Maximum number of differing hash bits for two images to count as duplicates.
+    /// </summary>
+    [JsonPropertyName("duplicate_hash_distance")]
+    public int? DuplicateHashDistance { get; set; }
+
+    /// <summary>
+    /// Policy selecting which extracted images are sent to OCR. All images are OCR'd when null.
+    /// </summary>
+    [JsonPropertyName("ocr_policy")]
+    public ImageOcrPolicy? OcrPolicy { get; set; }
+}
+
+/// <summary>
+/// Filters applied to extracted images before OCR. An image is OCR'd only if it passes every filter.
+/// </summary>
+public sealed class ImageOcrPolicy
+{
+    /// <summary>
+    /// Skip images narrower than this many pixels.
+    /// </summary>
+    [JsonPropertyName("min_width")]
+    public int? MinWidth { get; set; }
+
+    /// <summary>
+    /// Skip images shorter than this many pixels.
+    /// </summary>
+    [JsonPropertyName("min_height")]
+    public int? MinHeight { get; set; }
+
+    /// <summary>
+    /// Skip images whose encoded data is smaller than this many bytes.
+    /// </summary>
+    [JsonPropertyName("min_bytes")]
+    public long? MinBytes { get; set; }
+
+    /// <summary>
+    /// Only OCR images on these pages (1-indexed).
+    /// </summary>
+    [JsonPropertyName("pages")]
+    public List<int>? Pages { get; set; }
+
+    /// <summary>
+    /// Skip mask and transparency-layer images (default true).
+    /// </summary>
+    [JsonPropertyName("skip_masks")]
+    public bool? SkipMasks { get; set; }
+
+    /// <summary>
+    /// Skip decorative images such as rules, borders and spacers.
+    /// </summary>
+    [JsonPropertyName("skip_decorative")]
+    public bool? SkipDecorative { get; set; }
 }
 
 /// <summary>
@@ -1200,6 +1491,118 @@ public sealed class LanguageDetectionConfig
     public bool? DetectMultiple { get; set; }
 }
 
+/// <summary>
+/// Document type classification into invoice, contract, report, letter or custom types,
+/// from keyword and layout features.
+/// </summary>
+public sealed class ClassificationConfig
+{
+    /// <summary>
+    /// Include the built-in invoice, contract, report and letter types (default true).
+    /// </summary>
+    [JsonPropertyName("builtin_types")]
+    public bool? BuiltinTypes { get; set; }
+
+    /// <summary>
+    /// Additional document types. A type with a built-in label adds to its keywords.
+    /// </summary>
+    [JsonPropertyName("types")]
+    public List<DocumentTypeRule>? Types { get; set; }
+
+    /// <summary>
+    /// Confidence (0.0-1.0) below which the document is labelled "unknown" (default 0.3).
+    /// </summary>
+    [JsonPropertyName("min_confidence")]
+    public double? MinConfidence { get; set; }
+}
+
+/// <summary>
+/// Document type recognised by keywords, matched case-insensitively on word boundaries.
+/// </summary>
+public sealed class DocumentTypeRule
+{
+    /// <summary>
+    /// Label written to <c>metadata["document_type"]["label"]</c>.
+    /// </summary>
+    [JsonPropertyName("label")]
+    public string Label { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Keywords typical of the type anywhere in the document.
+    /// </summary>
+    [JsonPropertyName("keywords")]
+    public List<string>? Keywords { get; set; }
+
+    /// <summary>
+    /// Keywords that identify the type when they appear in the first lines, e.g. a title.
+    /// </summary>
+    [JsonPropertyName("title_keywords")]
+    public List<string>? TitleKeywords { get; set; }
+}
+
+/// <summary>
+/// Key-value pair extraction of labels and values such as "Invoice number: 2024-117".
+/// PDFs with a text layer are read from word positions, so pairs get bounding boxes.
+/// </summary>
+public sealed class KeyValueExtractionConfig
+{
+    /// <summary>
+    /// Recognise common business labels (invoice number, due date, total, ...) without a colon (default true).
+    /// </summary>
+    [JsonPropertyName("builtin_labels")]
+    public bool? BuiltinLabels { get; set; }
+
+    /// <summary>
+    /// Additional labels recognised without a colon, matched case-insensitively by whole words.
+    /// </summary>
+    [JsonPropertyName("labels")]
+    public List<string>? Labels { get; set; }
+
+    /// <summary>
+    /// Most words in a label followed by a colon (default 5).
+    /// </summary>
+    [JsonPropertyName("max_label_words")]
+    public int? MaxLabelWords { get; set; }
+}
+
+/// <summary>
+/// Cleanup of running headers and footers, page numbers and watermarks repeated across most pages.
+/// PDFs need <see cref="ExtractionConfig.Pages"/> so that page boundaries are known.
+/// </summary>
+public sealed class CleanupConfig
+{
+    /// <summary>
+    /// Detect lines repeated across most pages (default true).
+    /// </summary>
+    [JsonPropertyName("strip_repeated")]
+    public bool? StripRepeated { get; set; }
+
+    /// <summary>
+    /// Keep repeated lines in the content and record them in <c>metadata["repeated_text"]</c> (default false).
+    /// </summary>
+    [JsonPropertyName("tag_instead")]
+    public bool? TagInstead { get; set; }
+}
+
+/// <summary>
+/// Detection of diagonal text and images repeated on most pages of PDFs, and of stamp words such as
+/// DRAFT or CONFIDENTIAL on a line of their own, reported with the pages they cover.
+/// </summary>
+public sealed class WatermarkConfig
+{
+    /// <summary>
+    /// Remove the lines holding watermark text from the content (default false).
+    /// </summary>
+    [JsonPropertyName("exclude_text")]
+    public bool? ExcludeText { get; set; }
+
+    /// <summary>
+    /// Stamp words recognised in addition to the built-in ones, matched case-insensitively.
+    /// </summary>
+    [JsonPropertyName("stamp_words")]
+    public List<string>? StampWords { get; set; }
+}
+
 /// <summary>
 /// Configuration for custom post-processor execution.
 /// </summary>
@@ -1544,6 +1947,24 @@ public sealed class PageConfig
     public string? MarkerFormat { get; set; }
 }
 
+/// <summary>
+/// Configuration for CSV/TSV parsing. The dialect is detected automatically unless overridden.
+/// </summary>
+public sealed class CsvConfig
+{
+    /// <summary>
+    /// Single-character field delimiter. Null to auto-detect.
+    /// </summary>
+    [JsonPropertyName("delimiter")]
+    public string? Delimiter { get; set; }
+
+    /// <summary>
+    /// Maximum number of data rows to parse. Null for no limit.
+    /// </summary>
+    [JsonPropertyName("max_rows")]
+    public int? MaxRows { get; set; }
+}
+
 /// <summary>
 /// Represents a document as bytes with its MIME type, used for batch extraction from in-memory data.
 /// </summary>
@@ -57,10 +57,39 @@ internal static partial class NativeMethods
         public IntPtr ImagesJson;
         /// <summary>JSON object of page structure pointer.</summary>
         public IntPtr PageStructureJson;
+        /// <summary>JSON object of structured EXIF data (image inputs) pointer.</summary>
+        public IntPtr ExifJson;
+
+        /// <summary>JSON array of term byte offsets pointer (when emit_term_offsets is enabled).</summary>
+        public IntPtr TermOffsetsJson;
+
+        /// <summary>JSON array of detected barcodes and QR codes pointer (when detect_barcodes is enabled).</summary>
+        public IntPtr BarcodesJson;
+
+        /// <summary>JSON array of label/value pairs pointer (when kv_extraction is configured).</summary>
+        public IntPtr KeyValuesJson;
+
+        /// <summary>JSON array of watermarks and stamps pointer (when watermarks is configured).</summary>
+        public IntPtr WatermarksJson;
 
         /// <summary>Whether extraction succeeded.</summary>
         [MarshalAs(UnmanagedType.I1)]
         public bool Success;
+
+        /// <summary>Fills the native 7-byte padding after Success so the fields below keep their offsets.</summary>
+        private uint _padding1;
+
+        /// <summary>Encoding of ChunksJson, ImagesJson and PageStructureJson (0 = none, 1 = gzip, 2 = zstd).</summary>
+        public uint JsonFieldEncoding;
+
+        /// <summary>Length of ChunksJson in bytes.</summary>
+        public UIntPtr ChunksJsonLen;
+
+        /// <summary>Length of ImagesJson in bytes.</summary>
+        public UIntPtr ImagesJsonLen;
+
+        /// <summary>Length of PageStructureJson in bytes.</summary>
+        public UIntPtr PageStructureJsonLen;
     }
 
     /// <summary>
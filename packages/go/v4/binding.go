@@ -26,6 +26,11 @@ char *kreuzberg_validate_mime_type(const char *mime_type);
 char *kreuzberg_load_extraction_config_from_file(const char *path);
 char *kreuzberg_list_embedding_presets(void);
 char *kreuzberg_get_embedding_preset(const char *name);
+char *kreuzberg_memory_stats(void);
+void kreuzberg_memory_reset_peak(void);
+char *kreuzberg_features(void);
+char *kreuzberg_stats(void);
+void kreuzberg_stats_reset(void);
 
 // Validation FFI functions
 int32_t kreuzberg_validate_binarization_method(const char *method);
@@ -625,3 +630,191 @@ func GetEmbeddingPreset(name string) (*EmbeddingPreset, error) {
 	}
 	return &preset, nil
 }
+
+// AllocatorStats reports native allocator usage. Counters are nil unless the
+// library was built with the memory-tracking feature.
+type AllocatorStats struct {
+	TrackingEnabled    bool    `json:"tracking_enabled"`
+	CurrentBytes       *uint64 `json:"current_bytes"`
+	PeakBytes          *uint64 `json:"peak_bytes"`
+	TotalAllocations   *uint64 `json:"total_allocations"`
+	TotalDeallocations *uint64 `json:"total_deallocations"`
+}
+
+// CacheMemoryStats reports memory held by native in-process caches.
+type CacheMemoryStats struct {
+	StringInternBytes   uint64 `json:"string_intern_bytes"`
+	StringInternEntries uint64 `json:"string_intern_entries"`
+}
+
+// NativeMemoryStats describes memory used by the native library, which is not
+// visible to the Go heap profiler.
+type NativeMemoryStats struct {
+	Allocator AllocatorStats   `json:"allocator"`
+	Caches    CacheMemoryStats `json:"caches"`
+}
+
+// MemoryStats returns native memory usage.
+func MemoryStats() (*NativeMemoryStats, error) {
+	ptr := C.kreuzberg_memory_stats()
+	if ptr == nil {
+		return nil, lastError()
+	}
+	defer C.kreuzberg_free_string(ptr)
+
+	var stats NativeMemoryStats
+	if err := json.Unmarshal([]byte(C.GoString(ptr)), &stats); err != nil {
+		return nil, newSerializationErrorWithContext("failed to decode memory stats", err, ErrorCodeValidation, nil)
+	}
+	return &stats, nil
+}
+
+// ResetMemoryPeak resets the native allocator peak to the current usage.
+func ResetMemoryPeak() {
+	C.kreuzberg_memory_reset_peak()
+}
+
+// PdfEngine describes the PDF engine of the native library.
+type PdfEngine struct {
+	Name    string  `json:"name"`
+	Linking string  `json:"linking"`
+	Version *string `json:"version"`
+}
+
+// ComponentVersion is the name and version of a pluggable component such as an OCR backend.
+type ComponentVersion struct {
+	Name    string `json:"name"`
+	Version string `json:"version"`
+}
+
+// CompiledFeatures describes the features the native library was built with.
+type CompiledFeatures struct {
+	Version     string             `json:"version"`
+	Features    map[string]bool    `json:"features"`
+	PdfEngine   *PdfEngine         `json:"pdf_engine"`
+	OcrBackends []ComponentVersion `json:"ocr_backends"`
+}
+
+// IsEnabled reports whether the Cargo feature name (e.g. "embeddings", "grpc-server")
+// is compiled into the native library.
+func (f *CompiledFeatures) IsEnabled(name string) bool {
+	return f.Features[name]
+}
+
+// Features returns the compiled-in features of the native library, so callers can
+// check for optional functionality before using it.
+func Features() (*CompiledFeatures, error) {
+	ptr := C.kreuzberg_features()
+	if ptr == nil {
+		return nil, lastError()
+	}
+	defer C.kreuzberg_free_string(ptr)
+
+	var features CompiledFeatures
+	if err := json.Unmarshal([]byte(C.GoString(ptr)), &features); err != nil {
+		return nil, newSerializationErrorWithContext("failed to decode features", err, ErrorCodeValidation, nil)
+	}
+	return &features, nil
+}
+
+// EnginePoolOptions configures the native Tesseract engine pool.
+type EnginePoolOptions struct {
+	PoolSize *int  `json:"pool_size,omitempty"`
+	Reuse    *bool `json:"reuse,omitempty"`
+}
+
+// PreloadOptions lists work Init does up front instead of during the first extraction.
+type PreloadOptions struct {
+	Extractors        *bool    `json:"extractors,omitempty"`
+	EmbeddingPresets  []string `json:"embedding_presets,omitempty"`
+	EmbeddingCacheDir string   `json:"embedding_cache_dir,omitempty"`
+}
+
+// InitOptions configures the native library in Init. Nil fields keep their defaults.
+type InitOptions struct {
+	WorkerThreads *int               `json:"worker_threads,omitempty"`
+	LogLevel      string             `json:"log_level,omitempty"`
+	OcrEnginePool *EnginePoolOptions `json:"ocr_engine_pool,omitempty"`
+	Preload       *PreloadOptions    `json:"preload,omitempty"`
+}
+
+// Init sets up the native library (logging, runtime threads, OCR engine pool and
+// preloads) instead of lazily on first use. It is optional and must not be called
+// again before Shutdown; a nil opts uses the defaults.
+func Init(opts *InitOptions) error {
+	if opts == nil {
+		opts = &InitOptions{}
+	}
+	data, err := json.Marshal(opts)
+	if err != nil {
+		return newSerializationErrorWithContext("failed to encode init options", err, ErrorCodeValidation, nil)
+	}
+
+	cOptions := C.CString(string(data))
+	defer C.free(unsafe.Pointer(cOptions))
+
+	if ok := C.kreuzberg_init(cOptions); !bool(ok) {
+		return lastError()
+	}
+	return nil
+}
+
+// Shutdown joins the native runtime threads, drops pooled OCR engines and releases
+// cache backends, e.g. before a plugin host unloads the library. It fails while an
+// extraction is still running.
+func Shutdown() error {
+	if ok := C.kreuzberg_shutdown(); !bool(ok) {
+		return lastError()
+	}
+	return nil
+}
+
+// DocumentStats counts successfully extracted documents.
+type DocumentStats struct {
+	Total      uint64            `json:"total"`
+	ByMimeType map[string]uint64 `json:"by_mime_type"`
+}
+
+// CacheStats counts result cache lookups.
+type CacheStats struct {
+	Hits    uint64   `json:"hits"`
+	Misses  uint64   `json:"misses"`
+	HitRate *float64 `json:"hit_rate"`
+}
+
+// ErrorStats counts failed extractions by error code name (e.g. "parsing", "timeout").
+type ErrorStats struct {
+	Total  uint64            `json:"total"`
+	ByCode map[string]uint64 `json:"by_code"`
+}
+
+// ExtractionStats are the counters accumulated by the native library across all calls.
+type ExtractionStats struct {
+	Documents   DocumentStats `json:"documents"`
+	Pages       uint64        `json:"pages"`
+	OcrPages    uint64        `json:"ocr_pages"`
+	Cache       CacheStats    `json:"cache"`
+	Errors      ErrorStats    `json:"errors"`
+	ElapsedSecs float64       `json:"elapsed_secs"`
+}
+
+// Stats returns the extraction statistics accumulated since the library was loaded or
+// since the last ResetStats call.
+func Stats() (*ExtractionStats, error) {
+	ptr := C.kreuzberg_stats()
+	if ptr == nil {
+		return nil, lastError()
+	}
+	defer C.kreuzberg_free_string(ptr)
+
+	var stats ExtractionStats
+	if err := json.Unmarshal([]byte(C.GoString(ptr)), &stats); err != nil {
+		return nil, newSerializationErrorWithContext("failed to decode extraction stats", err, ErrorCodeValidation, nil)
+	}
+	return &stats, nil
+}
+
+// ResetStats sets every extraction statistic back to zero.
+func ResetStats() {
+	C.kreuzberg_stats_reset()
+}
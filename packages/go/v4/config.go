@@ -44,6 +44,16 @@ type ExtractionConfig struct {
 	OCR *OCRConfig `json:"ocr,omitempty"`
 	// ForceOCR forces OCR processing even for text-based documents.
 	ForceOCR *bool `json:"force_ocr,omitempty"`
+	// OCRCorrection fixes common OCR confusions (O/0, l/1, rn/m) in OCR-derived text.
+	OCRCorrection *OCRCorrectionConfig `json:"ocr_correction,omitempty"`
+	// Incremental re-extracts only the appended or modified pages of a PDF extracted before.
+	Incremental *bool `json:"incremental,omitempty"`
+	// InputPolicy rejects inputs by size, MIME type, signature or encryption before extraction.
+	InputPolicy *InputPolicyConfig `json:"input_policy,omitempty"`
+	// StageTimeouts fails extraction stages that exceed their time budget with ErrorCodeTimeout.
+	StageTimeouts *StageTimeoutConfig `json:"stage_timeouts,omitempty"`
+	// Retry retries batch documents that failed with a transient error, with exponential backoff.
+	Retry *RetryConfig `json:"retry,omitempty"`
 	// Chunking configures text chunking for RAG/retrieval workflows.
 	Chunking *ChunkingConfig `json:"chunking,omitempty"`
 	// Images configures image extraction from documents.
@@ -54,6 +64,14 @@ type ExtractionConfig struct {
 	TokenReduction *TokenReductionConfig `json:"token_reduction,omitempty"`
 	// LanguageDetection enables automatic language detection.
 	LanguageDetection *LanguageDetectionConfig `json:"language_detection,omitempty"`
+	// Classification labels documents with their type in metadata["document_type"].
+	Classification *ClassificationConfig `json:"classification,omitempty"`
+	// KvExtraction finds label/value pairs and stores them in metadata["key_values"].
+	KvExtraction *KeyValueExtractionConfig `json:"kv_extraction,omitempty"`
+	// Cleanup removes headers, footers and page numbers repeated across pages.
+	Cleanup *CleanupConfig `json:"cleanup,omitempty"`
+	// Watermarks reports watermarks and stamps in metadata["watermarks"].
+	Watermarks *WatermarkConfig `json:"watermarks,omitempty"`
 	// Keywords configures keyword extraction.
 	Keywords *KeywordConfig `json:"keywords,omitempty"`
 	// Postprocessor configures post-processing steps.
@@ -62,8 +80,15 @@ type ExtractionConfig struct {
 	HTMLOptions *HTMLConversionOptions `json:"html_options,omitempty"`
 	// Pages configures page-level extraction and tracking.
 	Pages *PageConfig `json:"pages,omitempty"`
+	// CSVOptions configures CSV/TSV parsing.
+	CSVOptions *CSVConfig `json:"csv_options,omitempty"`
+	// SpreadsheetOptions configures sheet selection and row limits for spreadsheets.
+	SpreadsheetOptions *SpreadsheetConfig `json:"spreadsheet_options,omitempty"`
 	// MaxConcurrentExtractions limits the number of concurrent extraction operations.
 	MaxConcurrentExtractions *int `json:"max_concurrent_extractions,omitempty"`
+	// Overrides merges partial configurations (JSON field names) over this one for documents of
+	// matching MIME types, keyed by "type/subtype", "type/*" or "*/*"; more specific patterns win.
+	Overrides map[string]map[string]any `json:"overrides,omitempty"`
 }
 
 // OCRConfig selects and configures OCR backends.
@@ -74,6 +99,42 @@ type OCRConfig struct {
 	Language *string `json:"language,omitempty"`
 	// Tesseract contains Tesseract-specific configuration options.
 	Tesseract *TesseractConfig `json:"tesseract_config,omitempty"`
+	// Vocabulary supplies domain words for Tesseract and for correcting OCR output.
+	Vocabulary *OCRVocabularyConfig `json:"vocabulary,omitempty"`
+}
+
+// OCRVocabularyConfig lists domain words such as drug names or part numbers. They are added to
+// Tesseract's user words, and OCR words within MaxDistance edits of exactly one of them are corrected.
+type OCRVocabularyConfig struct {
+	// Words are inline vocabulary words.
+	Words []string `json:"words,omitempty"`
+	// WordsFile is a word list with one entry per line; lines starting with "#" are comments.
+	WordsFile *string `json:"words_file,omitempty"`
+	// Correct replaces near-miss OCR words with vocabulary words (default true).
+	Correct *bool `json:"correct,omitempty"`
+	// MaxDistance is the maximum edit distance for a correction (default 1).
+	MaxDistance *int `json:"max_distance,omitempty"`
+	// MinWordLength is the minimum length of an OCR word to correct (default 4).
+	MinWordLength *int `json:"min_word_length,omitempty"`
+}
+
+// OCRCorrectionConfig configures the correction of characters OCR engines commonly confuse.
+// Only text that came from OCR is corrected.
+type OCRCorrectionConfig struct {
+	// BuiltinRules applies the built-in O/0, l/1 and rn/m rules (default true).
+	BuiltinRules *bool `json:"builtin_rules,omitempty"`
+	// Rules are additional rules, applied after the built-in ones.
+	Rules []OCRConfusionRule `json:"rules,omitempty"`
+}
+
+// OCRConfusionRule replaces a sequence OCR misreads with the intended one.
+type OCRConfusionRule struct {
+	// From is the sequence as misread by OCR.
+	From string `json:"from"`
+	// To is the intended sequence.
+	To string `json:"to"`
+	// Context is "numeric", "alphabetic", "word_start" or "anywhere" (default).
+	Context string `json:"context,omitempty"`
 }
 
 // TesseractConfig exposes fine-grained controls for the Tesseract backend.
@@ -138,6 +199,9 @@ type ImagePreprocessingConfig struct {
 	BinarizationMode string `json:"binarization_method,omitempty"`
 	// InvertColors inverts black and white in images.
 	InvertColors *bool `json:"invert_colors,omitempty"`
+	// AutoTune chooses binarization, denoising, contrast and DPI per page from image
+	// statistics and records the choices in the "preprocessing" and "ocr_pages" metadata.
+	AutoTune *bool `json:"auto_tune,omitempty"`
 }
 
 // ChunkingConfig configures text chunking for downstream RAG/Retrieval workloads.
@@ -156,6 +220,8 @@ type ChunkingConfig struct {
 	Embedding *EmbeddingConfig `json:"embedding,omitempty"`
 	// Enabled enables or disables chunking.
 	Enabled *bool `json:"enabled,omitempty"`
+	// RespectSentences keeps whole sentences together, split with the rules of the document language.
+	RespectSentences *bool `json:"respect_sentences,omitempty"`
 }
 
 // ImageExtractionConfig controls inline image extraction from PDFs/Office docs.
@@ -172,6 +238,30 @@ type ImageExtractionConfig struct {
 	MinDPI *int `json:"min_dpi,omitempty"`
 	// MaxDPI is the maximum DPI for extracted images.
 	MaxDPI *int `json:"max_dpi,omitempty"`
+	// PerceptualHash computes a perceptual hash for each extracted image.
+	PerceptualHash *bool `json:"perceptual_hash,omitempty"`
+	// DeduplicateImages drops near-duplicate images such as repeated logos and watermarks.
+	DeduplicateImages *bool `json:"deduplicate_images,omitempty"`
+	// DuplicateHashDistance is the maximum number of differing hash bits for two images to count as duplicates.
+	DuplicateHashDistance *int `json:"duplicate_hash_distance,omitempty"`
+	// OCRPolicy selects which extracted images are sent to OCR (all images when nil).
+	OCRPolicy *ImageOCRPolicy `json:"ocr_policy,omitempty"`
+}
+
+// ImageOCRPolicy filters extracted images before OCR. An image is OCR'd only if it passes every filter.
+type ImageOCRPolicy struct {
+	// MinWidth skips images narrower than this many pixels.
+	MinWidth *int `json:"min_width,omitempty"`
+	// MinHeight skips images shorter than this many pixels.
+	MinHeight *int `json:"min_height,omitempty"`
+	// MinBytes skips images whose encoded data is smaller than this many bytes.
+	MinBytes *int `json:"min_bytes,omitempty"`
+	// Pages restricts OCR to images on these pages (1-indexed).
+	Pages []int `json:"pages,omitempty"`
+	// SkipMasks skips mask and transparency-layer images (default true).
+	SkipMasks *bool `json:"skip_masks,omitempty"`
+	// SkipDecorative skips rules, borders and spacers with extreme aspect ratios.
+	SkipDecorative *bool `json:"skip_decorative,omitempty"`
 }
 
 // FontConfig exposes font provider configuration for PDF extraction.
@@ -212,6 +302,57 @@ type LanguageDetectionConfig struct {
 	DetectMultiple *bool `json:"detect_multiple,omitempty"`
 }
 
+// ClassificationConfig labels documents as invoice, contract, report, letter or a
+// custom type from keyword and layout features.
+type ClassificationConfig struct {
+	// BuiltinTypes includes the built-in invoice, contract, report and letter types (default true).
+	BuiltinTypes *bool `json:"builtin_types,omitempty"`
+	// Types adds document types; a type with a built-in label adds to its keywords.
+	Types []DocumentTypeRule `json:"types,omitempty"`
+	// MinConfidence is the confidence (0.0-1.0) below which the document is labelled "unknown".
+	MinConfidence *float64 `json:"min_confidence,omitempty"`
+}
+
+// DocumentTypeRule describes a document type by keywords, matched case-insensitively
+// on word boundaries.
+type DocumentTypeRule struct {
+	// Label is written to metadata["document_type"]["label"].
+	Label string `json:"label"`
+	// Keywords are typical of the type anywhere in the document.
+	Keywords []string `json:"keywords,omitempty"`
+	// TitleKeywords identify the type when they appear in the first lines, e.g. a title.
+	TitleKeywords []string `json:"title_keywords,omitempty"`
+}
+
+// KeyValueExtractionConfig finds label/value pairs such as "Invoice number: 2024-117".
+// PDFs with a text layer are read from word positions, so pairs get bounding boxes.
+type KeyValueExtractionConfig struct {
+	// BuiltinLabels recognises common business labels without a colon (default true).
+	BuiltinLabels *bool `json:"builtin_labels,omitempty"`
+	// Labels adds labels recognised without a colon, matched case-insensitively by whole words.
+	Labels []string `json:"labels,omitempty"`
+	// MaxLabelWords is the most words in a label followed by a colon (default 5).
+	MaxLabelWords *int `json:"max_label_words,omitempty"`
+}
+
+// CleanupConfig removes running headers and footers, page numbers and watermarks
+// repeated across most pages. PDFs need Pages set so that page boundaries are known.
+type CleanupConfig struct {
+	// StripRepeated detects lines repeated across most pages (default true).
+	StripRepeated *bool `json:"strip_repeated,omitempty"`
+	// TagInstead keeps repeated lines and records them in metadata["repeated_text"] (default false).
+	TagInstead *bool `json:"tag_instead,omitempty"`
+}
+
+// WatermarkConfig reports diagonal text and images repeated on most pages of PDFs, and stamp
+// words such as DRAFT or CONFIDENTIAL on a line of their own, with the pages they cover.
+type WatermarkConfig struct {
+	// ExcludeText removes the lines holding watermark text from the content (default false).
+	ExcludeText *bool `json:"exclude_text,omitempty"`
+	// StampWords adds stamp words to the built-in ones, matched case-insensitively.
+	StampWords []string `json:"stamp_words,omitempty"`
+}
+
 // PostProcessorConfig determines which post processors run.
 type PostProcessorConfig struct {
 	// Enabled enables post-processing.
@@ -370,6 +511,64 @@ type PageConfig struct {
 	MarkerFormat *string `json:"marker_format,omitempty"`
 }
 
+// CSVConfig configures CSV/TSV parsing. The dialect is auto-detected unless overridden.
+type CSVConfig struct {
+	// Delimiter overrides the detected field delimiter (a single character).
+	Delimiter *string `json:"delimiter,omitempty"`
+	// MaxRows limits the number of data rows parsed into the table.
+	MaxRows *int `json:"max_rows,omitempty"`
+}
+
+// SpreadsheetConfig configures spreadsheet (XLSX, XLS, ODS) extraction.
+type SpreadsheetConfig struct {
+	// Sheets selects sheets by name (string) or zero-based index (int). Nil extracts all sheets.
+	Sheets []any `json:"sheets,omitempty"`
+	// MaxRows limits the number of data rows read per sheet (the header row is not counted).
+	MaxRows *int `json:"max_rows,omitempty"`
+	// Stream reads XLSX/XLSB sheets cell by cell to bound memory on very large workbooks.
+	Stream *bool `json:"stream,omitempty"`
+}
+
+// InputPolicyConfig declares which inputs are accepted for extraction.
+// Rejected inputs fail with ErrorCodeFileTooLarge, ErrorCodeMimeTypeNotAllowed,
+// ErrorCodeContentMismatch or ErrorCodeEncryptedInput.
+type InputPolicyConfig struct {
+	// MaxFileSizeBytes rejects larger inputs.
+	MaxFileSizeBytes *uint64 `json:"max_file_size_bytes,omitempty"`
+	// AllowedMimeTypes lists accepted MIME types; "image/*" accepts a whole family.
+	AllowedMimeTypes []string `json:"allowed_mime_types,omitempty"`
+	// RejectMimeMismatch rejects inputs whose magic bytes contradict their MIME type.
+	RejectMimeMismatch *bool `json:"reject_mime_mismatch,omitempty"`
+	// Encrypted is "allow" (default) or "reject" for password-protected inputs.
+	Encrypted *string `json:"encrypted,omitempty"`
+}
+
+// StageTimeoutConfig assigns time budgets in milliseconds to extraction stages.
+// A stage exceeding its budget is abandoned and fails with ErrorCodeTimeout.
+type StageTimeoutConfig struct {
+	// ExtractionMs bounds the format extractor, including the OCR it runs.
+	ExtractionMs *uint64 `json:"extraction_ms,omitempty"`
+	// OcrMs bounds OCR of a single PDF page or image.
+	OcrMs *uint64 `json:"ocr_ms,omitempty"`
+	// PostProcessingMs bounds the post-processing pipeline.
+	PostProcessingMs *uint64 `json:"post_processing_ms,omitempty"`
+}
+
+// RetryConfig retries documents of a batch whose extraction failed with a transient error.
+// The number of attempts is reported in the extraction_attempts metadata.
+type RetryConfig struct {
+	// MaxAttempts counts the first attempt too; 1 disables retries (default 3).
+	MaxAttempts *uint32 `json:"max_attempts,omitempty"`
+	// InitialBackoffMs is the wait before the first retry (default 200).
+	InitialBackoffMs *uint64 `json:"initial_backoff_ms,omitempty"`
+	// MaxBackoffMs caps the wait before a retry (default 5000).
+	MaxBackoffMs *uint64 `json:"max_backoff_ms,omitempty"`
+	// Multiplier is the factor the wait grows by after every retry (default 2.0).
+	Multiplier *float64 `json:"multiplier,omitempty"`
+	// RetryOn lists the retried error classes: "io", "ocr", "timeout", "cache" and "plugin" (default io and ocr).
+	RetryOn []string `json:"retry_on,omitempty"`
+}
+
 // ConfigFromJSON parses an ExtractionConfig from a JSON string via FFI.
 // This is the primary method for converting JSON to a config structure.
 func ConfigFromJSON(jsonStr string) (*ExtractionConfig, error) {
@@ -508,6 +707,9 @@ func ConfigMerge(base, override *ExtractionConfig) error {
 	if override.ForceOCR != nil {
 		base.ForceOCR = override.ForceOCR
 	}
+	if override.OCRCorrection != nil {
+		base.OCRCorrection = override.OCRCorrection
+	}
 	if override.Chunking != nil {
 		base.Chunking = override.Chunking
 	}
@@ -523,6 +725,18 @@ func ConfigMerge(base, override *ExtractionConfig) error {
 	if override.LanguageDetection != nil {
 		base.LanguageDetection = override.LanguageDetection
 	}
+	if override.Classification != nil {
+		base.Classification = override.Classification
+	}
+	if override.KvExtraction != nil {
+		base.KvExtraction = override.KvExtraction
+	}
+	if override.Cleanup != nil {
+		base.Cleanup = override.Cleanup
+	}
+	if override.Watermarks != nil {
+		base.Watermarks = override.Watermarks
+	}
 	if override.Keywords != nil {
 		base.Keywords = override.Keywords
 	}
@@ -535,9 +749,27 @@ func ConfigMerge(base, override *ExtractionConfig) error {
 	if override.Pages != nil {
 		base.Pages = override.Pages
 	}
+	if override.CSVOptions != nil {
+		base.CSVOptions = override.CSVOptions
+	}
+	if override.SpreadsheetOptions != nil {
+		base.SpreadsheetOptions = override.SpreadsheetOptions
+	}
 	if override.MaxConcurrentExtractions != nil {
 		base.MaxConcurrentExtractions = override.MaxConcurrentExtractions
 	}
+	if override.Incremental != nil {
+		base.Incremental = override.Incremental
+	}
+	if override.InputPolicy != nil {
+		base.InputPolicy = override.InputPolicy
+	}
+	if override.StageTimeouts != nil {
+		base.StageTimeouts = override.StageTimeouts
+	}
+	if override.Retry != nil {
+		base.Retry = override.Retry
+	}
 
 	return nil
 }
@@ -0,0 +1,55 @@
+package kreuzberg
+
+/*
+#include "internal/ffi/kreuzberg.h"
+
+extern void kreuzbergGoCrashDump(char *dump_json, void *user_data);
+*/
+import "C"
+
+import (
+	"sync"
+	"unsafe"
+)
+
+// CrashDumpHandler receives the JSON crash dump produced when a panic is caught
+// in native code. It runs on the goroutine whose call panicked, before that call
+// returns its error, and must not block for long.
+//
+// The dump holds the backtrace, the native function that panicked ("stage"), a
+// fingerprint and size of the document being extracted and a hash of the
+// effective configuration. Document contents and file paths are never included.
+type CrashDumpHandler func(dumpJSON string)
+
+var (
+	crashDumpMu      sync.RWMutex
+	crashDumpHandler CrashDumpHandler
+)
+
+// SetCrashDumpHandler registers handler to receive crash dumps for panics caught
+// at the native boundary, replacing any previous handler. Passing nil unregisters it.
+//
+// The failing call still returns an error carrying a PanicContext, so crash
+// reporting works alongside regular error handling.
+func SetCrashDumpHandler(handler CrashDumpHandler) {
+	crashDumpMu.Lock()
+	crashDumpHandler = handler
+	crashDumpMu.Unlock()
+
+	if handler == nil {
+		C.kreuzberg_set_crash_dump_callback(nil, nil)
+		return
+	}
+	C.kreuzberg_set_crash_dump_callback(C.CrashDumpCallback(unsafe.Pointer(C.kreuzbergGoCrashDump)), nil)
+}
+
+//export kreuzbergGoCrashDump
+func kreuzbergGoCrashDump(dumpJSON *C.char, _ unsafe.Pointer) {
+	crashDumpMu.RLock()
+	handler := crashDumpHandler
+	crashDumpMu.RUnlock()
+
+	if handler != nil && dumpJSON != nil {
+		handler(C.GoString(dumpJSON))
+	}
+}
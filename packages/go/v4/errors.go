@@ -46,6 +46,15 @@ const (
 	ErrorCodePlugin            ErrorCode = 5
 	ErrorCodeUnsupportedFormat ErrorCode = 6
 	ErrorCodeInternal          ErrorCode = 7
+
+	// Input policy rejections, see InputPolicyConfig
+	ErrorCodeFileTooLarge       ErrorCode = 8
+	ErrorCodeMimeTypeNotAllowed ErrorCode = 9
+	ErrorCodeContentMismatch    ErrorCode = 10
+	ErrorCodeEncryptedInput     ErrorCode = 11
+
+	// Extraction stage exceeded its budget, see StageTimeoutConfig
+	ErrorCodeTimeout ErrorCode = 12
 )
 
 // String returns the string representation of an ErrorCode.
@@ -294,6 +303,10 @@ func classifyNativeError(message string, code ErrorCode, panicCtx *PanicContext)
 		return newUnsupportedFormatErrorWithContext(format, trimmed, nil, code, panicCtx)
 	case ErrorCodeInternal:
 		return newRuntimeErrorWithContext(trimmed, nil, code, panicCtx)
+	case ErrorCodeFileTooLarge, ErrorCodeMimeTypeNotAllowed, ErrorCodeContentMismatch, ErrorCodeEncryptedInput:
+		return newValidationErrorWithContext(trimmed, nil, code, panicCtx)
+	case ErrorCodeTimeout:
+		return newRuntimeErrorWithContext(trimmed, nil, code, panicCtx)
 	default:
 		// Fallback for unknown codes
 		return newRuntimeErrorWithContext(trimmed, nil, code, panicCtx)
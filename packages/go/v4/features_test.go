@@ -0,0 +1,22 @@
+package kreuzberg
+
+import "testing"
+
+func TestFeatures(t *testing.T) {
+	features, err := Features()
+	if err != nil {
+		t.Fatalf("features: %v", err)
+	}
+	if features.Version != LibraryVersion() {
+		t.Fatalf("expected version %q, got %q", LibraryVersion(), features.Version)
+	}
+	if _, ok := features.Features["pdf"]; !ok {
+		t.Fatalf("pdf feature missing: %+v", features.Features)
+	}
+	if features.IsEnabled("pdf") != (features.PdfEngine != nil) {
+		t.Fatalf("pdf feature and engine disagree: %+v", features)
+	}
+	if features.IsEnabled("no-such-feature") {
+		t.Fatal("unknown feature reported as enabled")
+	}
+}
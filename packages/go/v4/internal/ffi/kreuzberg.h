@@ -17,12 +17,51 @@
  */
 typedef struct ExtractionConfig ExtractionConfig;
 
+/**
+ * Opaque type for extraction result.
+ * This is an opaque pointer type - callers should not access its internals.
+ * Use the kreuzberg_result_* accessor functions to extract data.
+ */
+typedef struct ExtractionResult ExtractionResult;
+
+
+/**
+ * Configuration of one tenant, used by the `kreuzberg_context_*` functions.
+ *
+ * Created by `kreuzberg_context_new` and released with `kreuzberg_context_free`.
+ * Immutable after creation, so a context can be used from several threads at once.
+ */
+typedef struct KreuzbergContext KreuzbergContext;
+
+typedef struct Option_ErrorCallback Option_ErrorCallback;
+
+/**
+ * Memory pool for ExtractionResult objects.
+ *
+ * Pre-allocates storage and reuses memory across multiple extractions.
+ * Thread-safe with internal synchronization.
+ *
+ * # Memory Model
+ *
+ * - Results are owned by the pool until reset or freed
+ * - Pool grows automatically if capacity is exceeded
+ * - Reset clears all results but retains capacity
+ * - Free releases all memory and destroys pool
+ *
+ * # Thread Safety
+ *
+ * Pool uses internal Mutex for synchronization. Safe for concurrent access
+ * but may serialize extractions. For parallel processing, consider using
+ * separate pools per thread.
+ */
+typedef struct ResultPool ResultPool;
 
 /**
  * C-compatible extraction result structure
  *
  * Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
- * Field order: 11 pointers (8 bytes each) + 1 bool + 7 bytes padding = 96 bytes total
+ * Field order: 16 pointers (8 bytes each) + 1 bool + 7 bytes padding + 1 u32 + 4 bytes padding
+ * + 3 lengths (8 bytes each) = 168 bytes total
  */
 typedef struct CExtractionResult {
   /**
@@ -58,17 +97,37 @@ typedef struct CExtractionResult {
    */
   char *metadata_json;
   /**
-   * Text chunks as JSON array (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   * Text chunks as JSON array (null-terminated string, or compressed per `json_field_encoding`; NULL if not available, freed with kreuzberg_free_result)
    */
   char *chunks_json;
   /**
-   * Extracted images as JSON array (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   * Extracted images as JSON array (null-terminated string, or compressed per `json_field_encoding`; NULL if not available, freed with kreuzberg_free_result)
    */
   char *images_json;
   /**
-   * Page structure as JSON object (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   * Page structure as JSON object (null-terminated string, or compressed per `json_field_encoding`; NULL if not available, freed with kreuzberg_free_result)
    */
   char *page_structure_json;
+  /**
+   * Structured EXIF data (camera, timestamps, GPS) as JSON object for image inputs (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   */
+  char *exif_json;
+  /**
+   * Term byte offsets as JSON array when `emit_term_offsets` is enabled (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   */
+  char *term_offsets_json;
+  /**
+   * Detected barcodes and QR codes (format, payload, page, bbox) as JSON array when `detect_barcodes` is enabled (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   */
+  char *barcodes_json;
+  /**
+   * Label/value pairs (key, value, page, key_bbox, value_bbox) as JSON array when `kv_extraction` is configured (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   */
+  char *key_values_json;
+  /**
+   * Watermarks and stamps (kind, text, pages, page_coverage, rotation) as JSON array when `watermarks` is configured (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
+   */
+  char *watermarks_json;
   /**
    * Whether extraction was successful
    */
@@ -77,6 +136,27 @@ typedef struct CExtractionResult {
    * Padding to match Java MemoryLayout (7 bytes padding to align to 8-byte boundary)
    */
   uint8_t _padding1[7];
+  /**
+   * Encoding of `chunks_json`, `images_json` and `page_structure_json`: 0 = null-terminated JSON,
+   * 1 = gzip, 2 = zstd (set by `result_compression`; decode with kreuzberg_decompress_field)
+   */
+  uint32_t json_field_encoding;
+  /**
+   * Padding to align the lengths to an 8-byte boundary
+   */
+  uint8_t _padding2[4];
+  /**
+   * Length of `chunks_json` in bytes, excluding any null terminator
+   */
+  uintptr_t chunks_json_len;
+  /**
+   * Length of `images_json` in bytes, excluding any null terminator
+   */
+  uintptr_t images_json_len;
+  /**
+   * Length of `page_structure_json` in bytes, excluding any null terminator
+   */
+  uintptr_t page_structure_json_len;
 } CExtractionResult;
 
 /**
@@ -220,6 +300,167 @@ typedef char *(*DocumentExtractorCallback)(const uint8_t *content,
  */
 typedef char *(*ValidatorCallback)(const char *result_json);
 
+/**
+ * Zero-copy view into an ExtractionResult.
+ *
+ * Provides direct pointers to string data without allocation or copying.
+ * All pointers are valid UTF-8 byte slices (not null-terminated).
+ *
+ * # Lifetime Safety
+ *
+ * This structure contains borrowed pointers. The caller MUST ensure:
+ * - The source `ExtractionResult` outlives this view
+ * - No use after the source result is freed with `kreuzberg_result_free()`
+ *
+ * # Memory Layout
+ *
+ * Field order: 6 ptr+len pairs (96 bytes) + 5 counts (40 bytes) = 136 bytes on 64-bit systems
+ * All pointers are either valid UTF-8 data or NULL (with corresponding len=0).
+ *
+ * # Thread Safety
+ *
+ * Views are NOT thread-safe. External synchronization required for concurrent access.
+ */
+typedef struct CExtractionResultView {
+  /**
+   * Direct pointer to content bytes (UTF-8, not null-terminated)
+   */
+  const uint8_t *content_ptr;
+  /**
+   * Length of content in bytes
+   */
+  uintptr_t content_len;
+  /**
+   * Direct pointer to MIME type bytes (UTF-8, not null-terminated)
+   */
+  const uint8_t *mime_type_ptr;
+  /**
+   * Length of MIME type in bytes
+   */
+  uintptr_t mime_type_len;
+  /**
+   * Direct pointer to language bytes (UTF-8, not null-terminated), or NULL
+   */
+  const uint8_t *language_ptr;
+  /**
+   * Length of language in bytes (0 if NULL)
+   */
+  uintptr_t language_len;
+  /**
+   * Direct pointer to date bytes (UTF-8, not null-terminated), or NULL
+   */
+  const uint8_t *date_ptr;
+  /**
+   * Length of date in bytes (0 if NULL)
+   */
+  uintptr_t date_len;
+  /**
+   * Direct pointer to subject bytes (UTF-8, not null-terminated), or NULL
+   */
+  const uint8_t *subject_ptr;
+  /**
+   * Length of subject in bytes (0 if NULL)
+   */
+  uintptr_t subject_len;
+  /**
+   * Direct pointer to title bytes (UTF-8, not null-terminated), or NULL
+   */
+  const uint8_t *title_ptr;
+  /**
+   * Length of title in bytes (0 if NULL)
+   */
+  uintptr_t title_len;
+  /**
+   * Number of tables extracted
+   */
+  uintptr_t table_count;
+  /**
+   * Number of chunks (0 if chunking not enabled)
+   */
+  uintptr_t chunk_count;
+  /**
+   * Number of detected languages (0 if language detection not enabled)
+   */
+  uintptr_t detected_language_count;
+  /**
+   * Number of extracted images (0 if no images)
+   */
+  uintptr_t image_count;
+  /**
+   * Total page count (0 if not applicable)
+   */
+  uintptr_t page_count;
+} CExtractionResultView;
+
+/**
+ * Callback function invoked for each successfully extracted result.
+ *
+ * # Arguments
+ *
+ * * `result` - Borrowed pointer to extraction result (valid only during callback)
+ * * `file_index` - Zero-based index of the file in the batch
+ * * `user_data` - User-provided context pointer
+ *
+ * # Returns
+ *
+ * - `0` to continue processing remaining files
+ * - Non-zero to cancel batch processing (no further callbacks)
+ *
+ * # Safety
+ *
+ * - `result` pointer is valid only during the callback execution
+ * - `result` is automatically freed after callback returns
+ * - Caller must copy/serialize data if needed beyond callback scope
+ * - `user_data` is passed through opaquely (caller manages lifetime)
+ */
+typedef int (*ResultCallback)(const struct CExtractionResultView *result,
+                              uintptr_t file_index,
+                              void *user_data);
+
+/**
+ * Callback function invoked for each non-empty spreadsheet row.
+ *
+ * # Arguments
+ *
+ * * `sheet_name` - Null-terminated UTF-8 sheet name (valid only during callback)
+ * * `sheet_index` - Zero-based position of the sheet in the workbook
+ * * `row_index` - Zero-based row number within the sheet (spreadsheet row 1 is 0)
+ * * `cells` - Array of `cell_count` null-terminated UTF-8 cell values, indexed from column A
+ * * `cell_count` - Number of cells in the row
+ * * `user_data` - User-provided context pointer
+ *
+ * # Returns
+ *
+ * - `0` to continue with the next row
+ * - Non-zero to stop reading (no further callbacks)
+ *
+ * # Safety
+ *
+ * - `sheet_name`, `cells` and every cell string are valid only during the callback
+ * - `user_data` is passed through opaquely (caller manages lifetime)
+ */
+typedef int (*RowCallback)(const char *sheet_name,
+                           uintptr_t sheet_index,
+                           uintptr_t row_index,
+                           const char *const *cells,
+                           uintptr_t cell_count,
+                           void *user_data);
+
+/**
+ * Callback invoked with a crash dump after a panic was caught at the FFI boundary.
+ *
+ * # Arguments
+ *
+ * * `dump_json` - Null-terminated JSON crash dump, valid only for the duration of the call
+ * * `user_data` - The pointer passed to `kreuzberg_set_crash_dump_callback`
+ *
+ * # Safety
+ *
+ * The callback runs on the thread that panicked, before the failing FFI call returns. It must
+ * copy `dump_json` if it needs it afterwards and must not unwind across the FFI boundary.
+ */
+typedef void (*CrashDumpCallback)(const char *dump_json, void *user_data);
+
 /**
  * C-compatible structured error details returned by `kreuzberg_get_error_details()`.
  *
@@ -233,7 +474,7 @@ typedef struct CErrorDetails {
    */
   char *message;
   /**
-   * Numeric error code (0-7 for Kreuzberg errors, 1-7 for panic_shield codes)
+   * Numeric error code (0-12 for Kreuzberg errors, 1-7 for panic_shield codes)
    */
   uint32_t error_code;
   /**
@@ -282,6 +523,117 @@ typedef struct CMetadataField {
   int32_t is_null;
 } CMetadataField;
 
+/**
+ * Statistics for result pool allocation tracking.
+ *
+ * Provides insight into pool efficiency and memory usage patterns.
+ */
+typedef struct CResultPoolStats {
+  /**
+   * Current number of results stored in pool
+   */
+  uintptr_t current_count;
+  /**
+   * Maximum capacity of pool (before automatic growth)
+   */
+  uintptr_t capacity;
+  /**
+   * Total number of allocations (successful extractions)
+   */
+  uintptr_t total_allocations;
+  /**
+   * Number of times pool capacity was exceeded (triggered growth)
+   */
+  uintptr_t growth_events;
+  /**
+   * Estimated memory used by results in bytes
+   */
+  uintptr_t estimated_memory_bytes;
+} CResultPoolStats;
+
+/**
+ * Statistics for string interning efficiency tracking.
+ */
+typedef struct CStringInternStats {
+  /**
+   * Number of unique strings currently interned
+   */
+  uintptr_t unique_count;
+  /**
+   * Total number of intern requests
+   */
+  uintptr_t total_requests;
+  /**
+   * Number of cache hits (string already interned)
+   */
+  uintptr_t cache_hits;
+  /**
+   * Number of cache misses (new string added)
+   */
+  uintptr_t cache_misses;
+  /**
+   * Estimated memory saved by deduplication (bytes)
+   */
+  uintptr_t estimated_memory_saved;
+  /**
+   * Total memory used by interned strings (bytes)
+   */
+  uintptr_t total_memory_bytes;
+} CStringInternStats;
+
+/**
+ * Location of one extracted image in a shared-memory segment.
+ *
+ * Entries are in the same order as the images in `images_json`.
+ */
+typedef struct CSharedImage {
+  /**
+   * Byte offset of the image data in the segment
+   */
+  uintptr_t offset;
+  /**
+   * Length of the image data in bytes
+   */
+  uintptr_t len;
+} CSharedImage;
+
+/**
+ * Extraction result whose content and images live in a shared-memory segment.
+ *
+ * `result` carries everything else: its `content` is an empty string and the images in
+ * its `images_json` have empty `data` arrays.
+ */
+typedef struct CSharedExtractionResult {
+  /**
+   * Result without content and image bytes
+   */
+  CExtractionResult *result;
+  /**
+   * Path of the segment file (null-terminated UTF-8)
+   */
+  char *segment_path;
+  /**
+   * Total size of the segment in bytes
+   */
+  uintptr_t segment_size;
+  /**
+   * Byte offset of the UTF-8 content (not null-terminated)
+   */
+  uintptr_t content_offset;
+  /**
+   * Length of the content in bytes
+   */
+  uintptr_t content_len;
+  /**
+   * Image locations, or NULL if there are no images
+   */
+  CSharedImage *images;
+  /**
+   * Number of entries in `images`
+   */
+  uintptr_t image_count;
+} CSharedExtractionResult;
+
 /**
  * Extract text and metadata from a file (synchronous).
  *
@@ -486,6 +838,9 @@ char *kreuzberg_load_extraction_config_from_file(const char *file_path);
  * - `batch_result` can be NULL (no-op)
  * - `batch_result` must not be used after this call
  * - All results and strings within the batch result will be freed automatically
+ *
+ * Results of one batch share their `mime_type` and `language` strings; the shared strings
+ * are freed together once the batch and every result referencing them have been freed.
  */
 void kreuzberg_free_batch_result(struct CBatchResult *batch_result);
 
@@ -627,109 +982,267 @@ int32_t kreuzberg_last_error_code(void);
 char *kreuzberg_last_panic_context(void);
 
 /**
- * Get the library version string.
+ * Register a callback receiving a JSON crash dump whenever a panic is caught at the FFI boundary.
+ *
+ * The dump is a JSON object with `version`, `kreuzberg_version`, `stage` (the FFI function that
+ * panicked), `message`, `location`, `guard_location`, `thread`, `timestamp_ms`, `backtrace`,
+ * `document` (`fingerprint`, `size_bytes`, `mime_type`, or null) and `config_hash`. Registering a
+ * callback replaces the previous one; passing NULL unregisters it.
+ *
+ * The failing call still returns its usual error value and `kreuzberg_last_error_code` reports
+ * a panic, so crash reporting is independent of regular error handling.
  *
  * # Safety
  *
- * - Returns a static string that does not need to be freed
- * - The returned string is always valid
+ * * `callback` must remain callable, and `user_data` valid, until it is unregistered
+ * * `callback` may be invoked from any thread that calls into the library
  *
  * # Example (C)
  *
  * ```c
- * const char* version = kreuzberg_version();
- * printf("Kreuzberg version: %s\n", version);
+ * void on_crash(const char* dump_json, void* user_data) {
+ *     report_to_crash_service(dump_json);
+ * }
+ *
+ * kreuzberg_set_crash_dump_callback(on_crash, NULL);
  * ```
  */
-const char *kreuzberg_version(void);
+void kreuzberg_set_crash_dump_callback(CrashDumpCallback callback, void *user_data);
 
 /**
- * Register a custom OCR backend via FFI callback.
+ * Cache key of the result of extracting a file with a configuration.
  *
- * # Safety
+ * The key is a hex digest of the file's bytes, detected MIME type, configuration and
+ * Kreuzberg version, identical to the one used by `kreuzberg_extract_file_sync_with_config`
+ * with the same arguments. The configured cache backend is created if needed, so
+ * `kreuzberg_cache_invalidate` reaches it afterwards.
  *
- * - `name` must be a valid null-terminated C string
- * - `callback` must be a valid function pointer that:
- *   - Does not store the image_bytes pointer
- *   - Returns a null-terminated UTF-8 string or NULL on error
- *   - The returned string must be freeable by kreuzberg_free_string
- * - Returns true on success, false on error (check kreuzberg_last_error)
+ * # Returns
  *
- * # Example (C)
+ * The key, or NULL on error (check `kreuzberg_last_error`), including when the
+ * configuration has no `cache` section or sets `use_cache` to false.
  *
- * ```c
- * char* my_ocr_backend(const uint8_t* image_bytes, size_t image_length, const char* config_json) {
- *     // Implement OCR logic here
- *     // Return allocated string with result, or NULL on error
- *     return strdup("Extracted text");
- * }
+ * # Safety
  *
- * bool success = kreuzberg_register_ocr_backend("my-ocr", my_ocr_backend);
- * if (!success) {
- *     const char* error = kreuzberg_last_error();
- *     printf("Failed to register: %s\n", error);
- * }
- * ```
+ * - `file_path` must be a valid null-terminated C string
+ * - `config_json` must be a valid null-terminated C string containing JSON
+ * - Returned string must be freed with `kreuzberg_free_string`
  */
-bool kreuzberg_register_ocr_backend(const char *name, OcrBackendCallback callback);
+char *kreuzberg_cache_key_for(const char *file_path, const char *config_json);
 
 /**
- * Register a custom OCR backend with explicit language support via FFI callback.
+ * Remove the cached result stored under `key`.
+ *
+ * The key is removed from every cache backend this process has used, whether through an
+ * extraction or `kreuzberg_cache_key_for`. A key that is not cached is not an error.
+ *
+ * # Returns
+ *
+ * true on success, false on error (check `kreuzberg_last_error`).
  *
  * # Safety
  *
- * - `languages_json` must be a null-terminated JSON array of language codes or NULL
- * - See `kreuzberg_register_ocr_backend` for additional safety notes.
+ * - `key` must be a valid null-terminated C string returned by `kreuzberg_cache_key_for`
  */
-bool kreuzberg_register_ocr_backend_with_languages(const char *name,
-                                                   OcrBackendCallback callback,
-                                                   const char *languages_json);
+bool kreuzberg_cache_invalidate(const char *key);
 
 /**
- * Register a custom PostProcessor via FFI callback.
+ * Estimate the cost of extracting a file with a configuration, as JSON.
  *
- * # Safety
+ * The object has `profile` (`mime_type`, `size_bytes`, `page_count`, `text_pages`,
+ * `image_coverage`, `has_text_layer`, `text_chars`), `ocr_pages`, `stages` (`stage` is
+ * `extraction`, `ocr` or `post_processing`, with `duration_ms` and `memory_bytes`),
+ * `duration_ms` and `peak_memory_bytes`. The file is read but not extracted.
  *
- * - `name` must be a valid null-terminated C string
- * - `callback` must be a valid function pointer that:
- *   - Does not store the result_json pointer
- *   - Returns a null-terminated UTF-8 JSON string or NULL on error
- *   - The returned string must be freeable by kreuzberg_free_string
- * - `priority` determines the order of execution (higher priority runs first)
- * - Returns true on success, false on error (check kreuzberg_last_error)
+ * # Returns
  *
- * # Example (C)
+ * JSON object string, or NULL on error (check `kreuzberg_last_error`).
  *
- * ```c
- * char* my_post_processor(const char* result_json) {
- *     // Parse result_json, modify it, return JSON string
- *     return strdup("{\"content\":\"PROCESSED\"}");
- * }
+ * # Safety
  *
- * bool success = kreuzberg_register_post_processor("my-processor", my_post_processor, 100);
- * if (!success) {
- *     const char* error = kreuzberg_last_error();
- *     printf("Failed to register: %s\n", error);
- * }
- * ```
+ * - `file_path` must be a valid null-terminated C string
+ * - `config_json` must be a valid null-terminated C string containing JSON
+ * - Returned string must be freed with `kreuzberg_free_string`
  */
-bool kreuzberg_register_post_processor(const char *name,
-                                       PostProcessorCallback callback,
-                                       int32_t priority);
+char *kreuzberg_estimate(const char *file_path, const char *config_json);
 
 /**
- * Register a custom PostProcessor with an explicit processing stage.
+ * Create a context with its own configuration, cache namespace and post-processor set.
+ *
+ * # Returns
+ *
+ * The context, or NULL on error (check `kreuzberg_last_error`), including for an invalid
+ * configuration or cache namespace.
  *
  * # Safety
  *
- * - `name` must be a valid null-terminated C string
- * - `stage` must be a valid null-terminated C string containing "early", "middle", or "late"
- * - `callback` must be a valid function pointer that:
- *   - Does not store the result_json pointer
- *   - Returns a null-terminated UTF-8 JSON string or NULL on error
- *   - The returned string must be freeable by kreuzberg_free_string
- * - `priority` determines the order of execution within the stage (higher priority runs first)
- * - Returns true on success, false on error (check kreuzberg_last_error)
+ * - `config_json` must be a valid null-terminated C string containing JSON, or NULL for
+ *   the default configuration
+ * - The returned context must be freed with `kreuzberg_context_free`
+ */
+struct KreuzbergContext *kreuzberg_context_new(const char *config_json);
+
+/**
+ * Free a context.
+ *
+ * Results extracted with the context stay valid.
+ *
+ * # Safety
+ *
+ * - `context` must be a pointer returned by `kreuzberg_context_new`, or NULL
+ * - `context` must not be used after this call
+ */
+void kreuzberg_context_free(struct KreuzbergContext *context);
+
+/**
+ * Extract a file with the configuration of a context (synchronous).
+ *
+ * # Safety
+ *
+ * - `context` must be a valid pointer returned by `kreuzberg_context_new`
+ * - `file_path` must be a valid null-terminated C string
+ * - The returned pointer must be freed with `kreuzberg_free_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ */
+struct CExtractionResult *kreuzberg_context_extract_file_sync(const struct KreuzbergContext *context,
+                                                              const char *file_path);
+
+/**
+ * Extract a byte array with the configuration of a context (synchronous).
+ *
+ * # Safety
+ *
+ * - `context` must be a valid pointer returned by `kreuzberg_context_new`
+ * - `data` must be a valid pointer to a byte array of length `data_len`
+ * - `mime_type` must be a valid null-terminated C string
+ * - The returned pointer must be freed with `kreuzberg_free_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ */
+struct CExtractionResult *kreuzberg_context_extract_bytes_sync(const struct KreuzbergContext *context,
+                                                               const uint8_t *data,
+                                                               uintptr_t data_len,
+                                                               const char *mime_type);
+
+/**
+ * Remove every cached result of a context.
+ *
+ * Only the context's cache namespace is cleared; other contexts keep their results.
+ *
+ * # Returns
+ *
+ * The number of removed results, 0 when the context does not cache results, or -1 on
+ * error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `context` must be a valid pointer returned by `kreuzberg_context_new`
+ */
+int64_t kreuzberg_context_clear_cache(const struct KreuzbergContext *context);
+
+/**
+ * Get the library version string.
+ *
+ * # Safety
+ *
+ * - Returns a static string that does not need to be freed
+ * - The returned string is always valid
+ *
+ * # Example (C)
+ *
+ * ```c
+ * const char* version = kreuzberg_version();
+ * printf("Kreuzberg version: %s\n", version);
+ * ```
+ */
+const char *kreuzberg_version(void);
+
+/**
+ * Register a custom OCR backend via FFI callback.
+ *
+ * # Safety
+ *
+ * - `name` must be a valid null-terminated C string
+ * - `callback` must be a valid function pointer that:
+ *   - Does not store the image_bytes pointer
+ *   - Returns a null-terminated UTF-8 string or NULL on error
+ *   - The returned string must be freeable by kreuzberg_free_string
+ * - Returns true on success, false on error (check kreuzberg_last_error)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char* my_ocr_backend(const uint8_t* image_bytes, size_t image_length, const char* config_json) {
+ *     // Implement OCR logic here
+ *     // Return allocated string with result, or NULL on error
+ *     return strdup("Extracted text");
+ * }
+ *
+ * bool success = kreuzberg_register_ocr_backend("my-ocr", my_ocr_backend);
+ * if (!success) {
+ *     const char* error = kreuzberg_last_error();
+ *     printf("Failed to register: %s\n", error);
+ * }
+ * ```
+ */
+bool kreuzberg_register_ocr_backend(const char *name, OcrBackendCallback callback);
+
+/**
+ * Register a custom OCR backend with explicit language support via FFI callback.
+ *
+ * # Safety
+ *
+ * - `languages_json` must be a null-terminated JSON array of language codes or NULL
+ * - See `kreuzberg_register_ocr_backend` for additional safety notes.
+ */
+bool kreuzberg_register_ocr_backend_with_languages(const char *name,
+                                                   OcrBackendCallback callback,
+                                                   const char *languages_json);
+
+/**
+ * Register a custom PostProcessor via FFI callback.
+ *
+ * # Safety
+ *
+ * - `name` must be a valid null-terminated C string
+ * - `callback` must be a valid function pointer that:
+ *   - Does not store the result_json pointer
+ *   - Returns a null-terminated UTF-8 JSON string or NULL on error
+ *   - The returned string must be freeable by kreuzberg_free_string
+ * - `priority` determines the order of execution (higher priority runs first)
+ * - Returns true on success, false on error (check kreuzberg_last_error)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char* my_post_processor(const char* result_json) {
+ *     // Parse result_json, modify it, return JSON string
+ *     return strdup("{\"content\":\"PROCESSED\"}");
+ * }
+ *
+ * bool success = kreuzberg_register_post_processor("my-processor", my_post_processor, 100);
+ * if (!success) {
+ *     const char* error = kreuzberg_last_error();
+ *     printf("Failed to register: %s\n", error);
+ * }
+ * ```
+ */
+bool kreuzberg_register_post_processor(const char *name,
+                                       PostProcessorCallback callback,
+                                       int32_t priority);
+
+/**
+ * Register a custom PostProcessor with an explicit processing stage.
+ *
+ * # Safety
+ *
+ * - `name` must be a valid null-terminated C string
+ * - `stage` must be a valid null-terminated C string containing "early", "middle", or "late"
+ * - `callback` must be a valid function pointer that:
+ *   - Does not store the result_json pointer
+ *   - Returns a null-terminated UTF-8 JSON string or NULL on error
+ *   - The returned string must be freeable by kreuzberg_free_string
+ * - `priority` determines the order of execution within the stage (higher priority runs first)
+ * - Returns true on success, false on error (check kreuzberg_last_error)
  */
 bool kreuzberg_register_post_processor_with_stage(const char *name,
                                                   PostProcessorCallback callback,
@@ -1223,6 +1736,155 @@ int32_t kreuzberg_is_language_supported(const char *backend, const char *languag
  */
 char *kreuzberg_list_ocr_backends_with_languages(void);
 
+/**
+ * Extract multiple files in streaming mode with callback-based result delivery.
+ *
+ * Processes files one at a time without accumulating results in memory.
+ * Each result is passed to the callback and then freed automatically.
+ *
+ * # Arguments
+ *
+ * * `files` - Array of null-terminated file path strings
+ * * `count` - Number of files in the array
+ * * `config_json` - Optional JSON configuration string (NULL for defaults)
+ * * `result_callback` - Callback invoked for each successful extraction
+ * * `user_data` - Optional user context passed to callbacks
+ * * `error_callback` - Optional callback invoked for extraction failures
+ *
+ * # Returns
+ *
+ * - `0` on success (all files processed or cancelled by callback)
+ * - `-1` on error (invalid arguments, configuration parsing failure)
+ *
+ * # Error Handling
+ *
+ * - Individual file failures invoke `error_callback` but don't stop processing
+ * - Callback can return non-zero to cancel remaining files
+ * - Invalid arguments or config parsing errors return `-1` immediately
+ *
+ * # Safety
+ *
+ * - `files` must point to valid array of `count` C string pointers
+ * - All file path strings must be valid null-terminated UTF-8
+ * - `config_json` must be valid null-terminated UTF-8 if not NULL
+ * - `result_callback` must be a valid function pointer
+ * - `error_callback` must be a valid function pointer if not NULL
+ * - Result pointers passed to callbacks are valid only during callback
+ * - Callbacks must not store result pointers for later use
+ *
+ * # Example (C)
+ *
+ * ```c
+ * int process_result(const CExtractionResultView* result, size_t index, void* data) {
+ *     // Copy data needed beyond callback scope
+ *     char content[1024];
+ *     size_t copy_len = result->content_len < 1024 ? result->content_len : 1023;
+ *     memcpy(content, result->content_ptr, copy_len);
+ *     content[copy_len] = '\0';
+ *     return 0; // Continue
+ * }
+ *
+ * void handle_error(size_t index, const char* msg, void* data) {
+ *     fprintf(stderr, "File %zu failed: %s\n", index, msg);
+ * }
+ *
+ * const char* files[] = {"a.pdf", "b.txt", "c.docx"};
+ * kreuzberg_extract_batch_streaming(files, 3, NULL, process_result, NULL, handle_error);
+ * ```
+ */
+int kreuzberg_extract_batch_streaming(const char *const *files,
+                                      uintptr_t count,
+                                      const char *config_json,
+                                      ResultCallback result_callback,
+                                      void *user_data,
+                                      struct Option_ErrorCallback error_callback);
+
+/**
+ * Extract multiple files in parallel streaming mode.
+ *
+ * Similar to `kreuzberg_extract_batch_streaming` but processes files in parallel
+ * using a thread pool. Results are delivered via callback as they complete.
+ *
+ * # Arguments
+ *
+ * * `files` - Array of null-terminated file path strings
+ * * `count` - Number of files in the array
+ * * `config_json` - Optional JSON configuration string (NULL for defaults)
+ * * `result_callback` - Thread-safe callback invoked for each successful extraction
+ * * `user_data` - Optional user context passed to callbacks (must be thread-safe)
+ * * `error_callback` - Optional thread-safe callback invoked for failures
+ * * `max_parallel` - Maximum number of parallel extractions (0 = number of CPUs)
+ *
+ * # Returns
+ *
+ * - `0` on success (all files processed or cancelled)
+ * - `-1` on error (invalid arguments, configuration parsing failure)
+ *
+ * # Thread Safety
+ *
+ * - Both callbacks may be invoked concurrently from multiple threads
+ * - `user_data` must be thread-safe (e.g., synchronized with mutex)
+ * - Callback can set atomic flag to signal cancellation
+ *
+ * # Safety
+ *
+ * Same requirements as `kreuzberg_extract_batch_streaming`, plus:
+ * - Callbacks must be thread-safe
+ * - `user_data` must support concurrent access
+ *
+ * # Example (C)
+ *
+ * ```c
+ * typedef struct {
+ *     pthread_mutex_t lock;
+ *     atomic_int cancel_flag;
+ * } BatchContext;
+ *
+ * int process_result(const CExtractionResultView* result, size_t index, void* data) {
+ *     BatchContext* ctx = (BatchContext*)data;
+ *     pthread_mutex_lock(&ctx->lock);
+ *     // Process result with thread safety
+ *     pthread_mutex_unlock(&ctx->lock);
+ *     return atomic_load(&ctx->cancel_flag);
+ * }
+ * ```
+ */
+int kreuzberg_extract_batch_parallel(const char *const *files,
+                                     uintptr_t count,
+                                     const char *config_json,
+                                     ResultCallback result_callback,
+                                     void *user_data,
+                                     struct Option_ErrorCallback error_callback,
+                                     uintptr_t max_parallel);
+
+/**
+ * Stream the rows of a spreadsheet to a callback.
+ *
+ * # Arguments
+ *
+ * * `file_path` - Null-terminated path to an XLSX/XLSM/XLSB/XLS/ODS file
+ * * `config_json` - Optional JSON configuration string (NULL for defaults); only
+ *   `spreadsheet_options.sheets` and `spreadsheet_options.max_rows` are used
+ * * `row_callback` - Callback invoked for each non-empty row
+ * * `user_data` - Optional user context passed to the callback
+ *
+ * # Returns
+ *
+ * - `0` on success (all rows delivered or cancelled by callback)
+ * - `-1` on error (invalid arguments, unknown sheet, unreadable workbook); see `kreuzberg_last_error`
+ *
+ * # Safety
+ *
+ * - `file_path` must be a valid null-terminated UTF-8 string
+ * - `config_json` must be valid null-terminated UTF-8 if not NULL
+ * - `row_callback` must be a valid function pointer
+ * - Pointers passed to the callback must not be stored for later use
+ */
+int kreuzberg_extract_spreadsheet_rows(const char *file_path,
+                                       const char *config_json,
+                                       RowCallback row_callback,
+                                       void *user_data);
+
 /**
  * Parse an ExtractionConfig from a JSON string.
  *
@@ -1542,10 +2204,65 @@ uint32_t kreuzberg_error_code_unsupported_format(void);
  */
 uint32_t kreuzberg_error_code_internal(void);
 
+/**
+ * Returns the file too large error code (8).
+ *
+ * # C Signature
+ *
+ * ```c
+ * uint32_t kreuzberg_error_code_file_too_large(void);
+ * ```
+ */
+uint32_t kreuzberg_error_code_file_too_large(void);
+
+/**
+ * Returns the MIME type not allowed error code (9).
+ *
+ * # C Signature
+ *
+ * ```c
+ * uint32_t kreuzberg_error_code_mime_type_not_allowed(void);
+ * ```
+ */
+uint32_t kreuzberg_error_code_mime_type_not_allowed(void);
+
+/**
+ * Returns the content mismatch error code (10).
+ *
+ * # C Signature
+ *
+ * ```c
+ * uint32_t kreuzberg_error_code_content_mismatch(void);
+ * ```
+ */
+uint32_t kreuzberg_error_code_content_mismatch(void);
+
+/**
+ * Returns the encrypted input error code (11).
+ *
+ * # C Signature
+ *
+ * ```c
+ * uint32_t kreuzberg_error_code_encrypted_input(void);
+ * ```
+ */
+uint32_t kreuzberg_error_code_encrypted_input(void);
+
+/**
+ * Returns the timeout error code (12).
+ *
+ * # C Signature
+ *
+ * ```c
+ * uint32_t kreuzberg_error_code_timeout(void);
+ * ```
+ */
+uint32_t kreuzberg_error_code_timeout(void);
+
 /**
  * Returns the total count of valid error codes.
  *
- * Currently 8 error codes (0-7). This helps bindings validate error codes.
+ * Currently 13 error codes (0-12). This helps bindings validate error codes.
  *
  * # C Signature
  *
@@ -1560,7 +2277,7 @@ uint32_t kreuzberg_error_code_count(void);
  *
  * # Arguments
  *
- * - `code`: Numeric error code (0-7)
+ * - `code`: Numeric error code (0-12)
  *
  * # Returns
  *
@@ -1589,7 +2306,7 @@ const char *kreuzberg_error_code_name(uint32_t code);
  *
  * # Arguments
  *
- * - `code`: Numeric error code (0-7)
+ * - `code`: Numeric error code (0-12)
  *
  * # Returns
  *
@@ -1618,7 +2335,7 @@ const char *kreuzberg_error_code_description(uint32_t code);
  * A `CErrorDetails` structure with the following characteristics:
  * - All non-NULL string pointers must be freed with `kreuzberg_free_string()`
  * - NULL pointers indicate the field is not available
- * - `error_code` is a numeric code (0-7)
+ * - `error_code` is a numeric code (0-12)
  * - `source_line` is 0 if unknown
  * - `is_panic` is 1 if error originated from a panic, 0 otherwise
  *
@@ -1664,7 +2381,7 @@ struct CErrorDetails kreuzberg_get_error_details(void);
  * Classifies an error based on the error message string.
  *
  * Analyzes an error message and attempts to classify it into one of the standard
- * Kreuzberg error codes (0-7). This is useful for converting error messages from
+ * Kreuzberg error codes (0-12). This is useful for converting error messages from
  * external libraries or system calls into Kreuzberg error categories.
  *
  * # Arguments
@@ -1673,12 +2390,14 @@ struct CErrorDetails kreuzberg_get_error_details(void);
  *
  * # Returns
  *
- * Numeric error code (0-7) indicating the most likely error classification.
+ * Numeric error code (0-12) indicating the most likely error classification.
  * Returns 7 (Internal) if the message cannot be reliably classified.
  *
  * # Classification Rules
  *
- * The classifier looks for common keywords and patterns:
+ * Messages of input policy rejections (`Input rejected (<reason>): ...`) map to their
+ * reason's code (8-11) and stage timeouts (`Timeout in <stage> stage after <n> ms`) to 12.
+ * Otherwise the classifier looks for common keywords and patterns:
  * - **0 (Validation)**: "invalid", "validation", "parameter", "constraint", "format mismatch"
  * - **1 (Parsing)**: "parse", "parsing", "corrupt", "unexpected", "malformed", "invalid format"
  * - **2 (OCR)**: "ocr", "tesseract", "recognition", "optical"
@@ -1744,7 +2463,7 @@ uint32_t kreuzberg_classify_error(const char *error_message);
  * }
  * ```
  */
-int32_t kreuzberg_result_get_page_count(const CExtractionResult *result);
+int32_t kreuzberg_result_get_page_count(const ExtractionResult *result);
 
 /**
  * Get chunk count from extraction result.
@@ -1778,7 +2497,7 @@ int32_t kreuzberg_result_get_page_count(const CExtractionResult *result);
  * }
  * ```
  */
-int32_t kreuzberg_result_get_chunk_count(const CExtractionResult *result);
+int32_t kreuzberg_result_get_chunk_count(const ExtractionResult *result);
 
 /**
  * Get detected language from extraction result.
@@ -1817,7 +2536,7 @@ int32_t kreuzberg_result_get_chunk_count(const CExtractionResult *result);
  * }
  * ```
  */
-char *kreuzberg_result_get_detected_language(const CExtractionResult *result);
+char *kreuzberg_result_get_detected_language(const ExtractionResult *result);
 
 /**
  * Get a metadata field by name.
@@ -1866,9 +2585,795 @@ char *kreuzberg_result_get_detected_language(const CExtractionResult *result);
  * }
  * ```
  */
-struct CMetadataField kreuzberg_result_get_metadata_field(const CExtractionResult *result,
+struct CMetadataField kreuzberg_result_get_metadata_field(const ExtractionResult *result,
                                                           const char *field_name);
 
+/**
+ * Get a structured EXIF field by name.
+ *
+ * Image inputs carry structured EXIF metadata with `camera`, `timestamps` and `gps`
+ * sections. This accessor returns a value from that object as a JSON string, using
+ * dot notation for nested fields (e.g., "camera.model", "gps.latitude"). Pass an
+ * empty string to get the whole EXIF object.
+ *
+ * GPS data is absent when extraction ran with `images.strip_gps` enabled.
+ *
+ * # Arguments
+ *
+ * * `result` - Pointer to an ExtractionResult structure
+ * * `field_name` - Null-terminated C string with the field path
+ *
+ * # Returns
+ *
+ * A CMetadataField structure containing:
+ * - `name`: The field name (caller should not free)
+ * - `json_value`: Pointer to field value as JSON string (must free with `kreuzberg_free_string`),
+ *   or NULL if the result has no EXIF data or the field doesn't exist
+ * - `is_null`: 1 if field doesn't exist, 0 if it does
+ *
+ * # Safety
+ *
+ * - `result` must be a valid pointer to an ExtractionResult
+ * - `field_name` must be a valid null-terminated C string
+ * - Neither parameter can be NULL
+ * - The returned `json_value` (if non-NULL) must be freed with `kreuzberg_free_string`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionResult* result = kreuzberg_extract_file("photo.jpg", NULL);
+ * if (result != NULL) {
+ *     CMetadataField lat = kreuzberg_result_get_exif_field(result, "gps.latitude");
+ *     if (!lat.is_null) {
+ *         printf("Latitude: %s\n", lat.json_value);
+ *         kreuzberg_free_string(lat.json_value);
+ *     }
+ *     kreuzberg_result_free(result);
+ * }
+ * ```
+ */
+struct CMetadataField kreuzberg_result_get_exif_field(const ExtractionResult *result, const char *field_name);
+
+/**
+ * Migrate a serialized extraction result to another schema version.
+ *
+ * Stored results written by older releases can be upgraded before loading them,
+ * and current results downgraded for readers that only understand an older layout.
+ * Results without a `schema_version` field have their version inferred.
+ *
+ * # Arguments
+ *
+ * * `result_json` - Null-terminated JSON string of a serialized ExtractionResult
+ * * `target_version` - Schema version to migrate to
+ *
+ * # Returns
+ *
+ * A pointer to the migrated result as a JSON string, or NULL on error
+ * (check `kreuzberg_last_error`), e.g. for malformed JSON or unknown versions.
+ *
+ * The returned pointer must be freed with `kreuzberg_free_string()`.
+ *
+ * # Safety
+ *
+ * - `result_json` must be a valid null-terminated C string
+ * - `result_json` cannot be NULL
+ * - The returned pointer (if non-NULL) must be freed with `kreuzberg_free_string`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char* migrated = kreuzberg_result_migrate(stored_json, 2);
+ * if (migrated != NULL) {
+ *     // Load the migrated result...
+ *     kreuzberg_free_string(migrated);
+ * } else {
+ *     printf("Migration failed: %s\n", kreuzberg_last_error());
+ * }
+ * ```
+ */
+char *kreuzberg_result_migrate(const char *result_json, uint32_t target_version);
+
+/**
+ * Verify the internal consistency of an extraction result.
+ *
+ * Checks chunk offsets against the content bounds, chunk indices, page number
+ * ordering, term offsets and UTF-8 validity, so bindings can detect corruption
+ * introduced by faulty plugins before persisting a result.
+ *
+ * # Arguments
+ *
+ * * `result` - Pointer to an ExtractionResult structure
+ *
+ * # Returns
+ *
+ * JSON object string `{"valid": bool, "issues": [{"check", "location", "message"}]}`,
+ * or NULL on error (check `kreuzberg_last_error`). An inconsistent result is not an
+ * error: it is reported with `"valid": false`.
+ *
+ * # Safety
+ *
+ * - `result` must be a valid pointer to an ExtractionResult
+ * - `result` cannot be NULL
+ * - Returned string must be freed with `kreuzberg_free_string`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionResult* result = kreuzberg_extract_file("document.pdf", config);
+ * if (result != NULL) {
+ *     char* report = kreuzberg_result_verify(result);
+ *     if (report != NULL && strstr(report, "\"valid\":false") != NULL) {
+ *         fprintf(stderr, "Inconsistent result: %s\n", report);
+ *     }
+ *     kreuzberg_free_string(report);
+ *     kreuzberg_result_free(result);
+ * }
+ * ```
+ */
+char *kreuzberg_result_verify(const ExtractionResult *result);
+
+/**
+ * Read the bytes of an image stored in the blob store.
+ *
+ * # Arguments
+ *
+ * * `blob_json` - The `blob` object of an extracted image as a JSON string
+ * * `out_len` - Pointer to receive the number of bytes
+ *
+ * # Returns
+ *
+ * Pointer to the image bytes, or NULL on error (check `kreuzberg_last_error`).
+ * Fails when the blob is missing or its bytes no longer match the reference.
+ *
+ * # Safety
+ *
+ * - `blob_json` must be a valid null-terminated C string
+ * - `out_len` must be a valid writable pointer
+ * - Returned bytes must be freed with `kreuzberg_free_bytes` using the length written to `out_len`
+ */
+uint8_t *kreuzberg_image_blob_read(const char *blob_json, uintptr_t *out_len);
+
+/**
+ * Free a byte buffer returned by `kreuzberg_image_blob_read`.
+ *
+ * # Safety
+ *
+ * - `ptr` must have been returned by `kreuzberg_image_blob_read`, or be NULL
+ * - `len` must be the length written to `out_len` by that call
+ * - `ptr` must not be used after this call
+ */
+void kreuzberg_free_bytes(uint8_t *ptr, uintptr_t len);
+
+/**
+ * Extract a file, placing the content and image bytes in a shared-memory segment.
+ *
+ * # Arguments
+ *
+ * * `file_path` - Path to the file to extract
+ * * `config_json` - Extraction configuration as JSON, or NULL for defaults
+ *
+ * # Returns
+ *
+ * Pointer to a CSharedExtractionResult, or NULL on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `file_path` must be a valid null-terminated C string
+ * - `config_json` must be a valid null-terminated C string or NULL
+ * - The returned pointer must be freed with `kreuzberg_free_shared_result`
+ */
+CSharedExtractionResult *kreuzberg_extract_file_shared(const char *file_path,
+                                                       const char *config_json);
+
+/**
+ * Free a shared result and remove its segment.
+ *
+ * Mappings created from the segment stay valid on Linux and macOS. On Windows, unmap
+ * the segment before calling this function, otherwise the segment file is left behind.
+ *
+ * # Safety
+ *
+ * - `shared` must have been returned by `kreuzberg_extract_file_shared`, or be NULL
+ * - `shared` must not be used after this call
+ */
+void kreuzberg_free_shared_result(CSharedExtractionResult *shared);
+
+/**
+ * Create a new result pool with specified initial capacity.
+ *
+ * Pre-allocates storage for `capacity` results to reduce allocation overhead.
+ * Pool automatically grows if capacity is exceeded.
+ *
+ * # Arguments
+ *
+ * * `capacity` - Initial capacity (number of results to pre-allocate storage for)
+ *
+ * # Returns
+ *
+ * Pointer to allocated pool, or NULL on allocation failure (check `kreuzberg_last_error`).
+ *
+ * # Memory Management
+ *
+ * Caller must free the returned pool with `kreuzberg_result_pool_free()`.
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CResultPool* pool = kreuzberg_result_pool_new(100);
+ * if (pool == NULL) {
+ *     fprintf(stderr, "Failed to create pool: %s\n", kreuzberg_last_error());
+ *     return;
+ * }
+ * // Use pool...
+ * kreuzberg_result_pool_free(pool);
+ * ```
+ */
+struct ResultPool *kreuzberg_result_pool_new(uintptr_t capacity);
+
+/**
+ * Reset pool by clearing all results.
+ *
+ * Removes all results from the pool but retains allocated capacity.
+ * After reset, pool can be reused for new extractions.
+ *
+ * # Arguments
+ *
+ * * `pool` - Pointer to result pool
+ *
+ * # Safety
+ *
+ * - `pool` must be a valid pointer returned by `kreuzberg_result_pool_new()`
+ * - `pool` cannot be NULL
+ * - All result pointers obtained from this pool become invalid after reset
+ * - Must not be called concurrently with extractions using same pool
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CResultPool* pool = kreuzberg_result_pool_new(100);
+ *
+ * // Process batch 1
+ * for (int i = 0; i < 50; i++) {
+ *     kreuzberg_extract_file_into_pool(files[i], NULL, pool);
+ * }
+ *
+ * // Reset and reuse
+ * kreuzberg_result_pool_reset(pool);
+ *
+ * // Process batch 2
+ * for (int i = 0; i < 50; i++) {
+ *     kreuzberg_extract_file_into_pool(other_files[i], NULL, pool);
+ * }
+ *
+ * kreuzberg_result_pool_free(pool);
+ * ```
+ */
+void kreuzberg_result_pool_reset(struct ResultPool *pool);
+
+/**
+ * Free result pool and all contained results.
+ *
+ * Releases all memory associated with the pool. All result pointers
+ * obtained from this pool become invalid.
+ *
+ * # Arguments
+ *
+ * * `pool` - Pointer to result pool
+ *
+ * # Safety
+ *
+ * - `pool` must be a valid pointer returned by `kreuzberg_result_pool_new()`
+ * - `pool` can be NULL (no-op)
+ * - All result pointers from this pool become invalid after free
+ * - Must not be called twice on same pool (double-free)
+ * - Must not be called concurrently with other pool operations
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CResultPool* pool = kreuzberg_result_pool_new(100);
+ * // Use pool...
+ * kreuzberg_result_pool_free(pool);
+ * pool = NULL; // Prevent double-free
+ * ```
+ */
+void kreuzberg_result_pool_free(struct ResultPool *pool);
+
+/**
+ * Get statistics about pool usage and efficiency.
+ *
+ * Returns metrics about current pool state, allocation counts, and memory usage.
+ *
+ * # Arguments
+ *
+ * * `pool` - Pointer to result pool
+ *
+ * # Returns
+ *
+ * Statistics structure with current metrics, or zeroed structure on error.
+ *
+ * # Safety
+ *
+ * - `pool` must be a valid pointer returned by `kreuzberg_result_pool_new()`
+ * - `pool` cannot be NULL
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CResultPoolStats stats = kreuzberg_result_pool_stats(pool);
+ * printf("Pool: %zu/%zu results, %zu allocations, %zu bytes\n",
+ *        stats.current_count, stats.capacity,
+ *        stats.total_allocations, stats.estimated_memory_bytes);
+ *
+ * if (stats.growth_events > 0) {
+ *     printf("Warning: Pool grew %zu times (consider larger initial capacity)\n",
+ *            stats.growth_events);
+ * }
+ * ```
+ */
+struct CResultPoolStats kreuzberg_result_pool_stats(const struct ResultPool *pool);
+
+/**
+ * Extract file and store result in pool.
+ *
+ * Extracts document content and adds result to pool. Returns borrowed reference
+ * to result that remains valid until pool is reset or freed.
+ *
+ * # Arguments
+ *
+ * * `file_path` - Null-terminated UTF-8 file path
+ * * `config_json` - Optional JSON configuration string (NULL for defaults)
+ * * `pool` - Pointer to result pool
+ *
+ * # Returns
+ *
+ * Borrowed pointer to extraction result view, or NULL on error (check `kreuzberg_last_error`).
+ * Result remains valid until pool is reset or freed.
+ *
+ * # Safety
+ *
+ * - `file_path` must be valid null-terminated UTF-8 string
+ * - `config_json` must be valid null-terminated UTF-8 if not NULL
+ * - `pool` must be valid pointer returned by `kreuzberg_result_pool_new()`
+ * - None can be NULL (except config_json which is optional)
+ * - Returned pointer is borrowed from pool (do not free separately)
+ * - Returned pointer becomes invalid when pool is reset or freed
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CResultPool* pool = kreuzberg_result_pool_new(100);
+ *
+ * const CExtractionResultView* result = kreuzberg_extract_file_into_pool(
+ *     "document.pdf", NULL, pool
+ * );
+ *
+ * if (result != NULL) {
+ *     // Access result fields
+ *     printf("Content length: %zu\n", result->content_len);
+ *     printf("MIME type: %.*s\n",
+ *            (int)result->mime_type_len,
+ *            result->mime_type_ptr);
+ * }
+ *
+ * // Result remains valid until pool is reset/freed
+ * kreuzberg_result_pool_free(pool);
+ * ```
+ */
+const struct CExtractionResultView *kreuzberg_extract_file_into_pool(const char *file_path,
+                                                                     const char *config_json,
+                                                                     struct ResultPool *pool);
+
+/**
+ * Extract file into pool and get zero-copy view.
+ *
+ * Convenience function that combines extraction and view creation.
+ * Equivalent to `kreuzberg_extract_file_into_pool()` followed by
+ * `kreuzberg_get_result_view()`.
+ *
+ * # Arguments
+ *
+ * Same as `kreuzberg_extract_file_into_pool()`
+ *
+ * # Returns
+ *
+ * Zero-copy view of result, or zeroed view on error.
+ *
+ * # Safety
+ *
+ * Same requirements as `kreuzberg_extract_file_into_pool()`.
+ * View is valid until pool is reset or freed.
+ */
+struct CExtractionResultView kreuzberg_extract_file_into_pool_view(const char *file_path,
+                                                                   const char *config_json,
+                                                                   struct ResultPool *pool);
+
+/**
+ * Get a zero-copy view of an extraction result.
+ *
+ * Creates a view structure with direct pointers to result data without allocation.
+ * The view is valid only while the source `result` remains valid.
+ *
+ * # Arguments
+ *
+ * * `result` - Pointer to an ExtractionResult structure
+ * * `out_view` - Pointer to a CExtractionResultView structure to populate
+ *
+ * # Returns
+ *
+ * 0 on success, -1 on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `result` must be a valid pointer to an ExtractionResult
+ * - `out_view` must be a valid pointer to writable memory
+ * - Neither parameter can be NULL
+ * - The returned view is valid ONLY while `result` is not freed
+ * - Caller MUST NOT use the view after calling `kreuzberg_result_free(result)`
+ *
+ * # Lifetime Safety
+ *
+ * ```text
+ * ExtractionResult lifetime: |-------------------------------------|
+ * View lifetime:              |----------------------|
+ *                                   SAFE             FREE → INVALID
+ * ```
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionResult* result = kreuzberg_extract_file("document.pdf", NULL);
+ * if (result != NULL) {
+ *     CExtractionResultView view;
+ *     if (kreuzberg_get_result_view(result, &view) == 0) {
+ *         // Direct access to content without copying
+ *         printf("Content length: %zu bytes\n", view.content_len);
+ *         printf("MIME type: %.*s\n", (int)view.mime_type_len, view.mime_type_ptr);
+ *         printf("Tables: %zu, Chunks: %zu\n", view.table_count, view.chunk_count);
+ *
+ *         // No need to free the view (no allocations)
+ *     }
+ *
+ *     kreuzberg_result_free(result); // After this, view is INVALID
+ * }
+ * ```
+ */
+int32_t kreuzberg_get_result_view(const ExtractionResult *result,
+                                  struct CExtractionResultView *out_view);
+
+/**
+ * Get direct access to content from a result view.
+ *
+ * Helper function to retrieve content as a slice without copying.
+ *
+ * # Arguments
+ *
+ * * `view` - Pointer to a CExtractionResultView structure
+ * * `out_ptr` - Pointer to receive the content pointer
+ * * `out_len` - Pointer to receive the content length
+ *
+ * # Returns
+ *
+ * 0 on success, -1 on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `view` must be a valid pointer to a CExtractionResultView
+ * - `out_ptr` and `out_len` must be valid writable pointers
+ * - The returned content pointer is valid only while the source ExtractionResult is valid
+ *
+ * # Example (C)
+ *
+ * ```c
+ * const uint8_t* content;
+ * size_t content_len;
+ * if (kreuzberg_view_get_content(&view, &content, &content_len) == 0) {
+ *     // Process content directly without copying
+ *     fwrite(content, 1, content_len, stdout);
+ * }
+ * ```
+ */
+int32_t kreuzberg_view_get_content(const struct CExtractionResultView *view,
+                                   const uint8_t **out_ptr,
+                                   uintptr_t *out_len);
+
+/**
+ * Get direct access to MIME type from a result view.
+ *
+ * # Arguments
+ *
+ * * `view` - Pointer to a CExtractionResultView structure
+ * * `out_ptr` - Pointer to receive the MIME type pointer
+ * * `out_len` - Pointer to receive the MIME type length
+ *
+ * # Returns
+ *
+ * 0 on success, -1 on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `view` must be a valid pointer to a CExtractionResultView
+ * - `out_ptr` and `out_len` must be valid writable pointers
+ * - The returned MIME type pointer is valid only while the source ExtractionResult is valid
+ *
+ * # Example (C)
+ *
+ * ```c
+ * const uint8_t* mime_type;
+ * size_t mime_len;
+ * if (kreuzberg_view_get_mime_type(&view, &mime_type, &mime_len) == 0) {
+ *     printf("MIME: %.*s\n", (int)mime_len, mime_type);
+ * }
+ * ```
+ */
+int32_t kreuzberg_view_get_mime_type(const struct CExtractionResultView *view,
+                                     const uint8_t **out_ptr,
+                                     uintptr_t *out_len);
+
+/**
+ * Intern a string and return pointer to shared C string.
+ *
+ * If the string has already been interned, returns pointer to existing allocation.
+ * Otherwise, creates new allocation. Pointer remains valid until all references
+ * are freed with `kreuzberg_free_interned_string()`.
+ *
+ * # Arguments
+ *
+ * * `s` - Null-terminated UTF-8 string to intern
+ *
+ * # Returns
+ *
+ * Pointer to interned C string, or NULL on error (invalid UTF-8, allocation failure).
+ * Caller must eventually free with `kreuzberg_free_interned_string()`.
+ *
+ * # Reference Counting
+ *
+ * Multiple calls with the same string return the same pointer but increment
+ * an internal reference count. The string is freed only when all references
+ * are released.
+ *
+ * # Thread Safety
+ *
+ * Thread-safe. Multiple threads can call concurrently.
+ *
+ * # Safety
+ *
+ * - `s` must be valid null-terminated UTF-8 string
+ * - `s` cannot be NULL
+ * - Returned pointer must not be modified
+ * - Caller must call `kreuzberg_free_interned_string()` for each `kreuzberg_intern_string()` call
+ *
+ * # Example (C)
+ *
+ * ```c
+ * const char* mime1 = kreuzberg_intern_string("application/pdf");
+ * const char* mime2 = kreuzberg_intern_string("application/pdf");
+ *
+ * // Same string = same pointer (memory shared)
+ * assert(mime1 == mime2);
+ *
+ * // Free each reference
+ * kreuzberg_free_interned_string(mime1);
+ * kreuzberg_free_interned_string(mime2);
+ * ```
+ */
+const char *kreuzberg_intern_string(const char *s);
+
+/**
+ * Free an interned string reference.
+ *
+ * Decrements reference count for the interned string. If reference count
+ * reaches zero, the string is freed from the intern table.
+ *
+ * # Arguments
+ *
+ * * `s` - Pointer returned by `kreuzberg_intern_string()`
+ *
+ * # Safety
+ *
+ * - `s` must be a pointer returned by `kreuzberg_intern_string()`
+ * - `s` can be NULL (no-op)
+ * - Must not be called twice on same pointer (double-free)
+ * - Pointer becomes invalid after last reference is freed
+ *
+ * # Example (C)
+ *
+ * ```c
+ * const char* mime = kreuzberg_intern_string("application/pdf");
+ * // Use mime...
+ * kreuzberg_free_interned_string(mime);
+ * // Don't use mime after this point
+ * ```
+ */
+void kreuzberg_free_interned_string(const char *s);
+
+/**
+ * Get statistics about string interning efficiency.
+ *
+ * Returns metrics about unique strings, cache hits/misses, and memory savings.
+ *
+ * # Returns
+ *
+ * Statistics structure with current metrics.
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CStringInternStats stats = kreuzberg_string_intern_stats();
+ * printf("Interned: %zu unique strings\n", stats.unique_count);
+ * printf("Requests: %zu total (%zu hits, %zu misses)\n",
+ *        stats.total_requests, stats.cache_hits, stats.cache_misses);
+ * printf("Memory saved: %zu bytes\n", stats.estimated_memory_saved);
+ * printf("Hit rate: %.1f%%\n",
+ *        100.0 * stats.cache_hits / stats.total_requests);
+ * ```
+ */
+struct CStringInternStats kreuzberg_string_intern_stats(void);
+
+/**
+ * Reset the intern table, freeing all interned strings.
+ *
+ * **WARNING**: This invalidates all pointers returned by `kreuzberg_intern_string()`.
+ * Only use during shutdown or testing.
+ *
+ * # Safety
+ *
+ * - Must not be called while any interned string pointers are in use
+ * - All existing interned pointers become invalid
+ * - Thread-safe but can race with concurrent intern operations
+ */
+void kreuzberg_string_intern_reset(void);
+
+/**
+ * Get native memory usage as JSON.
+ *
+ * Reports allocator usage (current and peak bytes, allocation counts) when built with
+ * the `memory-tracking` feature, and memory held by in-process caches.
+ *
+ * # Returns
+ *
+ * JSON object string, or NULL on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - Returned string must be freed with `kreuzberg_free_string`
+ */
+char *kreuzberg_memory_stats(void);
+
+/**
+ * Reset the allocator peak to the current usage.
+ *
+ * Lets embedders report the peak per interval (e.g. per scrape) instead of since startup.
+ * Does nothing unless built with the `memory-tracking` feature.
+ */
+void kreuzberg_memory_reset_peak(void);
+
+/**
+ * Get the compiled-in features and component versions as JSON.
+ *
+ * The object has `version` (Kreuzberg version), `features` (Cargo feature name to
+ * boolean, e.g. `embeddings`, `html`, `ocr`, `grpc-server`), `pdf_engine` (`null`
+ * without PDF support) and `ocr_backends` (registered OCR backends with versions).
+ *
+ * # Returns
+ *
+ * JSON object string, or NULL on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - Returned string must be freed with `kreuzberg_free_string`
+ */
+char *kreuzberg_features(void);
+
+/**
+ * Decompress a JSON field of a `CExtractionResult` into a null-terminated string.
+ *
+ * `encoding` is the result's `json_field_encoding`: 0 (none), 1 (gzip) or 2 (zstd). With
+ * encoding 0 the first `len` bytes of `data` are copied, so hosts can call this for every
+ * field regardless of the configured compression.
+ *
+ * # Returns
+ *
+ * The JSON string, or NULL on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `data` must point to at least `len` readable bytes, e.g. `chunks_json` and `chunks_json_len`
+ * - `data` stays owned by the result; free it with `kreuzberg_free_result`
+ * - Returned string must be freed with `kreuzberg_free_string`
+ */
+char *kreuzberg_decompress_field(const char *data, uintptr_t len, uint32_t encoding);
+
+/**
+ * Set up the library: logging, the runtime thread pool, the OCR engine pool and preloads.
+ *
+ * `options_json` is a JSON object with the optional fields `worker_threads` (runtime
+ * worker threads, default one per CPU core), `log_level` (a filter such as `warn` or
+ * `kreuzberg=debug` for log messages written to stderr, default no logging),
+ * `ocr_engine_pool` (`pool_size` and `reuse`, as in the OCR engine pool) and `preload`
+ * (`extractors`, default true; `embedding_presets`, preset names whose models are loaded
+ * now; `embedding_cache_dir`).
+ *
+ * Calling `kreuzberg_init` is optional. It must not be called again before
+ * `kreuzberg_shutdown`.
+ *
+ * # Returns
+ *
+ * true on success, false on error (check `kreuzberg_last_error`), including for unknown
+ * options and a second call without `kreuzberg_shutdown` in between.
+ *
+ * # Safety
+ *
+ * - `options_json` must be a valid null-terminated C string containing JSON, or NULL for
+ *   the default options
+ */
+bool kreuzberg_init(const char *options_json);
+
+/**
+ * Tear the library down so it can be unloaded.
+ *
+ * Joins the runtime threads (waiting up to 10 seconds for running file reads and OCR),
+ * drops pooled OCR engines and releases cache backends, closing Redis connections and
+ * freeing in-memory caches. Disk cache entries are kept. Works whether or not
+ * `kreuzberg_init` was called; the library can be used or initialized again afterwards.
+ *
+ * # Returns
+ *
+ * true on success, false on error (check `kreuzberg_last_error`). Fails without tearing
+ * anything down while a synchronous extraction is still running on another thread.
+ *
+ * # Safety
+ *
+ * - No other thread may be inside a Kreuzberg call that is about to start an extraction
+ */
+bool kreuzberg_shutdown(void);
+
+/**
+ * Get the extraction statistics accumulated since the library was loaded (or last reset) as JSON.
+ *
+ * The object has `documents` (`total` and `by_mime_type`), `pages` (pages of documents
+ * with a known page count), `ocr_pages`, `cache` (`hits`, `misses` and `hit_rate`, `null`
+ * before the first lookup), `errors` (`total` and `by_code`, keyed by error code name such
+ * as `parsing` or `timeout`) and `elapsed_secs` (seconds covered by the counters).
+ *
+ * # Returns
+ *
+ * JSON object string, or NULL on error (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - Returned string must be freed with `kreuzberg_free_string`
+ */
+char *kreuzberg_stats(void);
+
+/**
+ * Reset every extraction statistic to zero and restart `elapsed_secs`.
+ *
+ * Lets embedders report per-interval counts (e.g. per scrape) instead of totals.
+ */
+void kreuzberg_stats_reset(void);
+
+/**
+ * Run the gRPC server, blocking the calling thread until it stops.
+ *
+ * # Arguments
+ *
+ * * `host` - IP address to bind to (e.g., "127.0.0.1")
+ * * `port` - Port to bind to (e.g., 50051)
+ * * `config_json` - Default extraction configuration as JSON, or NULL to discover
+ *   kreuzberg.toml/yaml/json from the working directory
+ *
+ * # Returns
+ *
+ * `false` if the server could not start or failed (check `kreuzberg_last_error`).
+ *
+ * # Safety
+ *
+ * - `host` must be a valid null-terminated C string
+ * - `config_json` must be a valid null-terminated C string or NULL
+ */
+bool kreuzberg_grpc_serve(const char *host, uint16_t port, const char *config_json);
+
 /**
  * Validates a binarization method string.
  *
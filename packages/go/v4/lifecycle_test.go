@@ -0,0 +1,41 @@
+package kreuzberg
+
+import "testing"
+
+func TestInitAndShutdown(t *testing.T) {
+	if err := Shutdown(); err != nil {
+		t.Fatalf("shutdown before init: %v", err)
+	}
+
+	workers := 2
+	if err := Init(&InitOptions{WorkerThreads: &workers, LogLevel: "warn"}); err != nil {
+		t.Fatalf("init: %v", err)
+	}
+	if err := Init(nil); err == nil {
+		t.Fatal("expected second init without shutdown to fail")
+	}
+
+	result, err := ExtractBytesSync([]byte("lifecycle"), "text/plain", nil)
+	if err != nil {
+		t.Fatalf("extract after init: %v", err)
+	}
+	if result.Content == "" {
+		t.Fatal("expected content after init")
+	}
+
+	if err := Shutdown(); err != nil {
+		t.Fatalf("shutdown: %v", err)
+	}
+	if _, err := ExtractBytesSync([]byte("after shutdown"), "text/plain", nil); err != nil {
+		t.Fatalf("extract after shutdown: %v", err)
+	}
+}
+
+func TestInitRejectsUnknownPreset(t *testing.T) {
+	defer func() { _ = Shutdown() }()
+
+	err := Init(&InitOptions{Preload: &PreloadOptions{EmbeddingPresets: []string{"no-such-preset"}}})
+	if err == nil {
+		t.Fatal("expected unknown embedding preset to fail")
+	}
+}
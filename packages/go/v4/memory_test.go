@@ -0,0 +1,18 @@
+package kreuzberg
+
+import "testing"
+
+func TestMemoryStats(t *testing.T) {
+	ResetMemoryPeak()
+
+	stats, err := MemoryStats()
+	if err != nil {
+		t.Fatalf("memory stats: %v", err)
+	}
+	if stats.Allocator.TrackingEnabled && stats.Allocator.CurrentBytes == nil {
+		t.Fatalf("tracking enabled but current bytes missing: %+v", stats.Allocator)
+	}
+	if !stats.Allocator.TrackingEnabled && stats.Allocator.PeakBytes != nil {
+		t.Fatalf("tracking disabled but peak bytes reported: %+v", stats.Allocator)
+	}
+}
@@ -0,0 +1,25 @@
+package kreuzberg
+
+import "testing"
+
+func TestStatsCountExtractions(t *testing.T) {
+	ResetStats()
+
+	if _, err := ExtractBytesSync([]byte("hello stats"), "text/plain", nil); err != nil {
+		t.Fatalf("extract: %v", err)
+	}
+
+	stats, err := Stats()
+	if err != nil {
+		t.Fatalf("stats: %v", err)
+	}
+	if stats.Documents.ByMimeType["text/plain"] < 1 {
+		t.Fatalf("expected a text/plain document, got %+v", stats.Documents)
+	}
+	if stats.Documents.Total < stats.Documents.ByMimeType["text/plain"] {
+		t.Fatalf("total below per-type count: %+v", stats.Documents)
+	}
+	if stats.ElapsedSecs < 0 {
+		t.Fatalf("negative elapsed time: %v", stats.ElapsedSecs)
+	}
+}
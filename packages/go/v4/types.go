@@ -32,6 +32,8 @@ type Table struct {
 	Markdown string `json:"markdown"`
 	// PageNumber is the page number where the table was found (1-indexed).
 	PageNumber int `json:"page_number"`
+	// ColumnTypes holds the inferred type of each column ("string", "number" or "date"), when available.
+	ColumnTypes []string `json:"column_types,omitempty"`
 }
 
 // Chunk contains chunked content plus optional embeddings and metadata.
@@ -86,6 +88,8 @@ type ExtractedImage struct {
 	Description *string `json:"description,omitempty"`
 	// OCRResult contains OCR extraction results if OCR was applied to this image.
 	OCRResult *ExtractionResult `json:"ocr_result,omitempty"`
+	// PerceptualHash is the image's perceptual hash (16 hex digits) when image hashing is enabled.
+	PerceptualHash *string `json:"perceptual_hash,omitempty"`
 }
 
 // Metadata aggregates document metadata and format-specific payloads.
@@ -432,9 +436,10 @@ type ErrorMetadata struct {
 type PageUnitType string
 
 const (
-	PageUnitTypePage  PageUnitType = "page"
-	PageUnitTypeSlide PageUnitType = "slide"
-	PageUnitTypeSheet PageUnitType = "sheet"
+	PageUnitTypePage    PageUnitType = "page"
+	PageUnitTypeSlide   PageUnitType = "slide"
+	PageUnitTypeSheet   PageUnitType = "sheet"
+	PageUnitTypeChapter PageUnitType = "chapter"
 )
 
 // PageBoundary marks byte offset boundaries for a page in the extracted content.
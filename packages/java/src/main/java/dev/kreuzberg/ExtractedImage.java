@@ -21,6 +21,7 @@ public final class ExtractedImage {
     private final boolean mask;
     private final String description;
     private final ExtractionResult ocrResult;
+    private final String perceptualHash;
 
     @JsonCreator
     public ExtractedImage(
@@ -34,7 +35,8 @@ public final class ExtractedImage {
         @JsonProperty("bits_per_component") Integer bitsPerComponent,
         @JsonProperty("is_mask") boolean mask,
         @JsonProperty("description") String description,
-        @JsonProperty("ocr_result") ExtractionResult ocrResult
+        @JsonProperty("ocr_result") ExtractionResult ocrResult,
+        @JsonProperty("perceptual_hash") String perceptualHash
     ) {
         this.data = Objects.requireNonNull(data, "data must not be null").clone();
         this.format = Objects.requireNonNull(format, "format must not be null");
@@ -47,6 +49,7 @@ public final class ExtractedImage {
         this.mask = mask;
         this.description = description;
         this.ocrResult = ocrResult;
+        this.perceptualHash = perceptualHash;
     }
 
     public byte[] getData() {
@@ -93,6 +96,10 @@ public final class ExtractedImage {
         return Optional.ofNullable(ocrResult);
     }
 
+    public Optional<String> getPerceptualHash() {
+        return Optional.ofNullable(perceptualHash);
+    }
+
     @Override
     public boolean equals(Object obj) {
         if (this == obj) {
@@ -112,7 +119,8 @@ public final class ExtractedImage {
             && Objects.equals(colorspace, other.colorspace)
             && Objects.equals(bitsPerComponent, other.bitsPerComponent)
             && Objects.equals(description, other.description)
-            && Objects.equals(ocrResult, other.ocrResult);
+            && Objects.equals(ocrResult, other.ocrResult)
+            && Objects.equals(perceptualHash, other.perceptualHash);
     }
 
     @Override
@@ -127,7 +135,8 @@ public final class ExtractedImage {
             bitsPerComponent,
             mask,
             description,
-            ocrResult
+            ocrResult,
+            perceptualHash
         );
         result = 31 * result + Arrays.hashCode(data);
         return result;
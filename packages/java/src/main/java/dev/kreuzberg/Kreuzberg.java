@@ -20,6 +20,7 @@ import java.util.Map;
 import java.util.Objects;
 import java.util.concurrent.CompletableFuture;
 import java.util.concurrent.ConcurrentHashMap;
+import java.util.function.Consumer;
 import java.util.Optional;
 
 /**
@@ -64,6 +65,12 @@ public final class Kreuzberg {
     private static final Map<String, CallbackHandle> POST_PROCESSOR_CALLBACKS = new ConcurrentHashMap<>();
     private static final Map<String, CallbackHandle> VALIDATOR_CALLBACKS = new ConcurrentHashMap<>();
     private static final Map<String, CallbackHandle> OCR_CALLBACKS = new ConcurrentHashMap<>();
+    private static final FunctionDescriptor CRASH_DUMP_CALLBACK = FunctionDescriptor.ofVoid(
+        ValueLayout.ADDRESS,
+        ValueLayout.ADDRESS
+    );
+    private static volatile Consumer<String> crashDumpHandler;
+    private static MemorySegment crashDumpStub;
 
     private Kreuzberg() {
     }
@@ -953,6 +960,41 @@ public final class Kreuzberg {
         }
     }
 
+    /**
+     * Register a handler receiving a JSON crash dump whenever a panic is caught in native code.
+     *
+     * <p>The dump holds the backtrace, the native function that panicked ({@code stage}), a
+     * fingerprint and size of the document being extracted and a hash of the effective
+     * configuration; document contents and file paths are never included. The handler runs on the
+     * thread whose call panicked, before that call throws, and must not block for long. The failing
+     * call still throws a {@link KreuzbergException} as usual.</p>
+     *
+     * @param handler crash dump handler, or {@code null} to unregister the current one
+     * @throws KreuzbergException if the handler cannot be registered
+     */
+    public static synchronized void setCrashDumpHandler(Consumer<String> handler) throws KreuzbergException {
+        try {
+            crashDumpHandler = handler;
+            if (handler == null) {
+                KreuzbergFFI.KREUZBERG_SET_CRASH_DUMP_CALLBACK.invoke(MemorySegment.NULL, MemorySegment.NULL);
+                return;
+            }
+            if (crashDumpStub == null) {
+                MethodHandle handle = MethodHandles.lookup().findStatic(
+                    Kreuzberg.class,
+                    "crashDumpCallback",
+                    MethodType.methodType(void.class, MemorySegment.class, MemorySegment.class)
+                );
+                crashDumpStub = LINKER.upcallStub(handle, CRASH_DUMP_CALLBACK, Arena.global());
+            }
+            KreuzbergFFI.KREUZBERG_SET_CRASH_DUMP_CALLBACK.invoke(crashDumpStub, MemorySegment.NULL);
+        } catch (NoSuchMethodException | IllegalAccessException e) {
+            throw new KreuzbergException("Failed to create crash dump callback", e);
+        } catch (Throwable e) {
+            throw new KreuzbergException("Unexpected error registering crash dump handler", e);
+        }
+    }
+
     /**
      * Get the Kreuzberg library version.
      *
@@ -1051,6 +1093,18 @@ public final class Kreuzberg {
         }
     }
 
+    @SuppressWarnings("PMD.UnusedPrivateMethod")
+    private static void crashDumpCallback(MemorySegment dumpPtr, MemorySegment userData) {
+        Consumer<String> handler = crashDumpHandler;
+        if (handler == null) {
+            return;
+        }
+        try {
+            handler.accept(KreuzbergFFI.readCString(dumpPtr));
+        } catch (Throwable ignored) {
+        }
+    }
+
     @SuppressWarnings("PMD.UnusedPrivateMethod")
     private static MemorySegment validatorCallback(MemorySegment jsonPtr, Validator validator) {
         try {
@@ -1123,14 +1177,15 @@ public final class Kreuzberg {
             String metadataJson = KreuzbergFFI.readCString(
                 result.get(ValueLayout.ADDRESS, KreuzbergFFI.METADATA_OFFSET)
             );
-            String chunksJson = KreuzbergFFI.readCString(
-                result.get(ValueLayout.ADDRESS, KreuzbergFFI.CHUNKS_OFFSET)
+            int encoding = result.get(ValueLayout.JAVA_INT, KreuzbergFFI.JSON_FIELD_ENCODING_OFFSET);
+            String chunksJson = readJsonField(
+                result, KreuzbergFFI.CHUNKS_OFFSET, KreuzbergFFI.CHUNKS_LEN_OFFSET, encoding
             );
-            String imagesJson = KreuzbergFFI.readCString(
-                result.get(ValueLayout.ADDRESS, KreuzbergFFI.IMAGES_OFFSET)
+            String imagesJson = readJsonField(
+                result, KreuzbergFFI.IMAGES_OFFSET, KreuzbergFFI.IMAGES_LEN_OFFSET, encoding
             );
-            String pageStructureJson = KreuzbergFFI.readCString(
-                result.get(ValueLayout.ADDRESS, KreuzbergFFI.PAGE_STRUCTURE_OFFSET)
+            String pageStructureJson = readJsonField(
+                result, KreuzbergFFI.PAGE_STRUCTURE_OFFSET, KreuzbergFFI.PAGE_STRUCTURE_LEN_OFFSET, encoding
             );
             boolean success = result.get(ValueLayout.JAVA_BOOLEAN, KreuzbergFFI.SUCCESS_OFFSET);
 
@@ -1150,6 +1205,28 @@ public final class Kreuzberg {
         }
     }
 
+    /**
+     * Read a JSON field that may be compressed (see {@code ExtractionConfig.Builder#resultCompression}).
+     */
+    private static String readJsonField(MemorySegment result, long offset, long lenOffset, int encoding)
+            throws Throwable {
+        MemorySegment field = result.get(ValueLayout.ADDRESS, offset);
+        if (encoding == 0 || field == null || field.address() == 0) {
+            return KreuzbergFFI.readCString(field);
+        }
+
+        long len = result.get(ValueLayout.JAVA_LONG, lenOffset);
+        MemorySegment json = (MemorySegment) KreuzbergFFI.KREUZBERG_DECOMPRESS_FIELD.invoke(field, len, encoding);
+        if (json == null || json.address() == 0) {
+            throw new KreuzbergException("Failed to decompress result field: " + getLastError());
+        }
+        try {
+            return KreuzbergFFI.readCString(json);
+        } finally {
+            KreuzbergFFI.KREUZBERG_FREE_STRING.invoke(json);
+        }
+    }
+
     private static List<ExtractionResult> parseAndFreeBatch(MemorySegment batchPtr) throws Throwable {
         try {
             MemorySegment batch = batchPtr.reinterpret(KreuzbergFFI.C_BATCH_RESULT_LAYOUT.byteSize());
@@ -52,6 +52,7 @@ public final class KreuzbergFFI {
     public static final MethodHandle KREUZBERG_LAST_ERROR;
     public static final MethodHandle KREUZBERG_LAST_ERROR_CODE;
     public static final MethodHandle KREUZBERG_LAST_PANIC_CONTEXT;
+    public static final MethodHandle KREUZBERG_SET_CRASH_DUMP_CALLBACK;
     public static final MethodHandle KREUZBERG_VERSION;
     public static final MethodHandle KREUZBERG_CLONE_STRING;
     public static final MethodHandle KREUZBERG_REGISTER_POST_PROCESSOR;
@@ -105,6 +106,7 @@ public final class KreuzbergFFI {
     public static final MethodHandle KREUZBERG_CLASSIFY_ERROR;
     public static final MethodHandle KREUZBERG_ERROR_CODE_NAME;
     public static final MethodHandle KREUZBERG_ERROR_CODE_DESCRIPTION;
+    public static final MethodHandle KREUZBERG_DECOMPRESS_FIELD;
 
     public static final StructLayout C_EXTRACTION_RESULT_LAYOUT = MemoryLayout.structLayout(
         ValueLayout.ADDRESS.withName("content"),
@@ -118,8 +120,18 @@ public final class KreuzbergFFI {
         ValueLayout.ADDRESS.withName("chunks_json"),
         ValueLayout.ADDRESS.withName("images_json"),
         ValueLayout.ADDRESS.withName("page_structure_json"),
+        ValueLayout.ADDRESS.withName("exif_json"),
+        ValueLayout.ADDRESS.withName("term_offsets_json"),
+        ValueLayout.ADDRESS.withName("barcodes_json"),
+        ValueLayout.ADDRESS.withName("key_values_json"),
+        ValueLayout.ADDRESS.withName("watermarks_json"),
         ValueLayout.JAVA_BOOLEAN.withName("success"),
-        MemoryLayout.paddingLayout(7)
+        MemoryLayout.paddingLayout(7),
+        ValueLayout.JAVA_INT.withName("json_field_encoding"),
+        MemoryLayout.paddingLayout(4),
+        ValueLayout.JAVA_LONG.withName("chunks_json_len"),
+        ValueLayout.JAVA_LONG.withName("images_json_len"),
+        ValueLayout.JAVA_LONG.withName("page_structure_json_len")
     );
 
     public static final long CONTENT_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
@@ -144,8 +156,26 @@ public final class KreuzbergFFI {
         MemoryLayout.PathElement.groupElement("images_json"));
     public static final long PAGE_STRUCTURE_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
         MemoryLayout.PathElement.groupElement("page_structure_json"));
+    public static final long EXIF_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("exif_json"));
+    public static final long TERM_OFFSETS_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("term_offsets_json"));
+    public static final long BARCODES_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("barcodes_json"));
+    public static final long KEY_VALUES_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("key_values_json"));
+    public static final long WATERMARKS_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("watermarks_json"));
     public static final long SUCCESS_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
         MemoryLayout.PathElement.groupElement("success"));
+    public static final long JSON_FIELD_ENCODING_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("json_field_encoding"));
+    public static final long CHUNKS_LEN_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("chunks_json_len"));
+    public static final long IMAGES_LEN_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("images_json_len"));
+    public static final long PAGE_STRUCTURE_LEN_OFFSET = C_EXTRACTION_RESULT_LAYOUT.byteOffset(
+        MemoryLayout.PathElement.groupElement("page_structure_json_len"));
 
     public static final StructLayout C_BATCH_RESULT_LAYOUT = MemoryLayout.structLayout(
         ValueLayout.ADDRESS.withName("results"),
@@ -270,6 +300,11 @@ public final class KreuzbergFFI {
                 FunctionDescriptor.of(ValueLayout.ADDRESS)
             );
 
+            KREUZBERG_SET_CRASH_DUMP_CALLBACK = linkFunction(
+                "kreuzberg_set_crash_dump_callback",
+                FunctionDescriptor.ofVoid(ValueLayout.ADDRESS, ValueLayout.ADDRESS)
+            );
+
             KREUZBERG_VERSION = linkFunction(
                 "kreuzberg_version",
                 FunctionDescriptor.of(ValueLayout.ADDRESS)
@@ -550,6 +585,12 @@ public final class KreuzbergFFI {
                 "kreuzberg_error_code_description",
                 FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.JAVA_LONG)
             );
+
+            KREUZBERG_DECOMPRESS_FIELD = linkFunction(
+                "kreuzberg_decompress_field",
+                FunctionDescriptor.of(ValueLayout.ADDRESS, ValueLayout.ADDRESS, ValueLayout.JAVA_LONG,
+                    ValueLayout.JAVA_INT)
+            );
         } catch (Exception e) {
             throw new ExceptionInInitializerError(e);
         }
@@ -21,7 +21,12 @@ public enum PageUnitType {
     /**
      * Spreadsheet sheets (XLSX, ODS).
      */
-    SHEET("Sheet");
+    SHEET("Sheet"),
+
+    /**
+     * Ebook chapters (EPUB, MOBI).
+     */
+    CHAPTER("Chapter");
 
     private final String wireValue;
 
@@ -16,11 +16,13 @@ import java.util.Objects;
  * @param cells the table cells as a 2D list (rows × columns)
  * @param markdown the Markdown representation of the table
  * @param pageNumber the page number where the table was found (1-indexed)
+ * @param columnTypes the inferred type of each column ("string", "number", "date"), or null if unknown
  */
 public record Table(
     @JsonProperty("cells") List<List<String>> cells,
     @JsonProperty("markdown") String markdown,
-    @JsonProperty("page_number") int pageNumber
+    @JsonProperty("page_number") int pageNumber,
+    @JsonProperty("column_types") List<String> columnTypes
 ) {
     /**
      * Creates a new Table.
@@ -28,6 +30,7 @@ public record Table(
      * @param cells the table cells (must not be null)
      * @param markdown the Markdown representation (must not be null)
      * @param pageNumber the page number (0 for non-paginated documents, >= 1 for paginated documents)
+     * @param columnTypes the inferred column types (may be null)
      * @throws NullPointerException if cells or markdown is null
      * @throws IllegalArgumentException if pageNumber is negative
      */
@@ -35,7 +38,8 @@ public record Table(
     public Table(
         @JsonProperty("cells") List<List<String>> cells,
         @JsonProperty("markdown") String markdown,
-        @JsonProperty("page_number") int pageNumber
+        @JsonProperty("page_number") int pageNumber,
+        @JsonProperty("column_types") List<String> columnTypes
     ) {
         Objects.requireNonNull(cells, "cells must not be null");
         Objects.requireNonNull(markdown, "markdown must not be null");
@@ -45,6 +49,18 @@ public record Table(
         this.cells = deepCopyTable(cells);
         this.markdown = markdown;
         this.pageNumber = pageNumber;
+        this.columnTypes = columnTypes != null ? List.copyOf(columnTypes) : null;
+    }
+
+    /**
+     * Creates a new Table without column type information.
+     *
+     * @param cells the table cells (must not be null)
+     * @param markdown the Markdown representation (must not be null)
+     * @param pageNumber the page number
+     */
+    public Table(List<List<String>> cells, String markdown, int pageNumber) {
+        this(cells, markdown, pageNumber, null);
     }
 
     /**
@@ -14,6 +14,7 @@ public final class ChunkingConfig {
   private final String preset;
   private final Map<String, Object> embedding;
   private final Boolean enabled;
+  private final boolean respectSentences;
 
   private ChunkingConfig(Builder builder) {
     this.maxChars = builder.maxChars;
@@ -21,6 +22,7 @@ public final class ChunkingConfig {
     this.preset = builder.preset;
     this.embedding = builder.embedding;
     this.enabled = builder.enabled;
+    this.respectSentences = builder.respectSentences;
   }
 
   public static Builder builder() {
@@ -47,6 +49,10 @@ public final class ChunkingConfig {
     return enabled;
   }
 
+  public boolean isRespectSentences() {
+    return respectSentences;
+  }
+
   public Map<String, Object> toMap() {
     Map<String, Object> map = new HashMap<>();
     map.put("max_chars", maxChars);
@@ -60,6 +66,9 @@ public final class ChunkingConfig {
     if (enabled != null) {
       map.put("enabled", enabled);
     }
+    if (respectSentences) {
+      map.put("respect_sentences", true);
+    }
     return map;
   }
 
@@ -69,6 +78,7 @@ public final class ChunkingConfig {
     private String preset;
     private Map<String, Object> embedding;
     private Boolean enabled = true;
+    private boolean respectSentences;
 
     private Builder() {
     }
@@ -98,6 +108,11 @@ public final class ChunkingConfig {
       return this;
     }
 
+    public Builder respectSentences(boolean respectSentences) {
+      this.respectSentences = respectSentences;
+      return this;
+    }
+
     public ChunkingConfig build() {
       return new ChunkingConfig(this);
     }
@@ -133,6 +148,10 @@ public final class ChunkingConfig {
         builder.enabled((Boolean) enabledValue);
       }
     }
+    Object respectSentencesValue = map.get("respect_sentences");
+    if (respectSentencesValue instanceof Boolean) {
+      builder.respectSentences((Boolean) respectSentencesValue);
+    }
     return builder.build();
   }
 }
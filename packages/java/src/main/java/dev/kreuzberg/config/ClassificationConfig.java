@@ -0,0 +1,155 @@
+package dev.kreuzberg.config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Document type classification.
+ *
+ * <p>Labels documents as {@code invoice}, {@code contract}, {@code report}, {@code letter} or a
+ * custom type from keyword and layout features. The label, its confidence and the confidence of
+ * every type are written to {@code metadata.document_type}; the label is {@code unknown} below
+ * the minimum confidence.
+ *
+ * @since 4.0.0
+ */
+public final class ClassificationConfig {
+  private final boolean builtinTypes;
+  private final List<DocumentType> types;
+  private final double minConfidence;
+
+  private ClassificationConfig(Builder builder) {
+    this.builtinTypes = builder.builtinTypes;
+    this.types = Collections.unmodifiableList(new ArrayList<>(builder.types));
+    this.minConfidence = builder.minConfidence;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public boolean isBuiltinTypes() {
+    return builtinTypes;
+  }
+
+  public List<DocumentType> getTypes() {
+    return types;
+  }
+
+  public double getMinConfidence() {
+    return minConfidence;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    map.put("builtin_types", builtinTypes);
+    List<Map<String, Object>> typeMaps = new ArrayList<>();
+    for (DocumentType type : types) {
+      typeMaps.add(type.toMap());
+    }
+    map.put("types", typeMaps);
+    map.put("min_confidence", minConfidence);
+    return map;
+  }
+
+  /**
+   * Document type recognised by keywords, matched case-insensitively on word boundaries.
+   *
+   * @param label the label written to {@code metadata.document_type.label}; a built-in label
+   *     adds to that type's keywords
+   * @param keywords keywords typical of the type anywhere in the document
+   * @param titleKeywords keywords that identify the type when they appear in the first lines
+   */
+  public record DocumentType(String label, List<String> keywords, List<String> titleKeywords) {
+    public DocumentType {
+      keywords = keywords != null ? List.copyOf(keywords) : List.of();
+      titleKeywords = titleKeywords != null ? List.copyOf(titleKeywords) : List.of();
+    }
+
+    public DocumentType(String label, List<String> keywords) {
+      this(label, keywords, List.of());
+    }
+
+    Map<String, Object> toMap() {
+      Map<String, Object> map = new HashMap<>();
+      map.put("label", label);
+      map.put("keywords", keywords);
+      map.put("title_keywords", titleKeywords);
+      return map;
+    }
+  }
+
+  public static final class Builder {
+    private boolean builtinTypes = true;
+    private List<DocumentType> types = new ArrayList<>();
+    private double minConfidence = 0.3;
+
+    private Builder() {
+    }
+
+    public Builder builtinTypes(boolean builtinTypes) {
+      this.builtinTypes = builtinTypes;
+      return this;
+    }
+
+    public Builder types(List<DocumentType> types) {
+      this.types = types != null ? new ArrayList<>(types) : new ArrayList<>();
+      return this;
+    }
+
+    public Builder addType(DocumentType type) {
+      this.types.add(type);
+      return this;
+    }
+
+    public Builder minConfidence(double minConfidence) {
+      this.minConfidence = minConfidence;
+      return this;
+    }
+
+    public ClassificationConfig build() {
+      return new ClassificationConfig(this);
+    }
+  }
+
+  static ClassificationConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    if (map.get("builtin_types") instanceof Boolean) {
+      builder.builtinTypes((Boolean) map.get("builtin_types"));
+    }
+    if (map.get("min_confidence") instanceof Number) {
+      builder.minConfidence(((Number) map.get("min_confidence")).doubleValue());
+    }
+    Object typesValue = map.get("types");
+    if (typesValue instanceof Iterable) {
+      @SuppressWarnings("unchecked")
+      Iterable<Object> iterable = (Iterable<Object>) typesValue;
+      for (Object entry : iterable) {
+        if (entry instanceof Map) {
+          Map<?, ?> type = (Map<?, ?>) entry;
+          builder.addType(new DocumentType(
+              String.valueOf(type.get("label")),
+              asStringList(type.get("keywords")),
+              asStringList(type.get("title_keywords"))));
+        }
+      }
+    }
+    return builder.build();
+  }
+
+  private static List<String> asStringList(Object value) {
+    List<String> strings = new ArrayList<>();
+    if (value instanceof Iterable) {
+      for (Object entry : (Iterable<?>) value) {
+        strings.add(String.valueOf(entry));
+      }
+    }
+    return strings;
+  }
+}
@@ -0,0 +1,79 @@
+package dev.kreuzberg.config;
+
+import java.util.HashMap;
+import java.util.Map;
+
+/**
+ * Cleanup of text repeated across pages.
+ *
+ * <p>Lines among the first and last lines of each page that recur on most pages, such as running
+ * headers and footers, page numbers and watermarks, are removed before post-processing and
+ * chunking. With {@code tagInstead} they are kept and recorded in {@code metadata.repeated_text}.
+ * PDFs need a {@link PageConfig} so that page boundaries are known.
+ *
+ * @since 4.0.0
+ */
+public final class CleanupConfig {
+  private final boolean stripRepeated;
+  private final boolean tagInstead;
+
+  private CleanupConfig(Builder builder) {
+    this.stripRepeated = builder.stripRepeated;
+    this.tagInstead = builder.tagInstead;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public boolean isStripRepeated() {
+    return stripRepeated;
+  }
+
+  public boolean isTagInstead() {
+    return tagInstead;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    map.put("strip_repeated", stripRepeated);
+    map.put("tag_instead", tagInstead);
+    return map;
+  }
+
+  public static final class Builder {
+    private boolean stripRepeated = true;
+    private boolean tagInstead = false;
+
+    private Builder() {
+    }
+
+    public Builder stripRepeated(boolean stripRepeated) {
+      this.stripRepeated = stripRepeated;
+      return this;
+    }
+
+    public Builder tagInstead(boolean tagInstead) {
+      this.tagInstead = tagInstead;
+      return this;
+    }
+
+    public CleanupConfig build() {
+      return new CleanupConfig(this);
+    }
+  }
+
+  static CleanupConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    if (map.get("strip_repeated") instanceof Boolean) {
+      builder.stripRepeated((Boolean) map.get("strip_repeated"));
+    }
+    if (map.get("tag_instead") instanceof Boolean) {
+      builder.tagInstead((Boolean) map.get("tag_instead"));
+    }
+    return builder.build();
+  }
+}
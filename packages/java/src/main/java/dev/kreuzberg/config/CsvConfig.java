@@ -0,0 +1,173 @@
+package dev.kreuzberg.config;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.HashMap;
+import java.util.Map;
+import java.util.Objects;
+
+/**
+ * Configuration for CSV/TSV parsing.
+ *
+ * The delimiter, quoting and header row are detected automatically. These options
+ * override the detected delimiter and limit how many rows are parsed into the table.
+ *
+ * @since 4.0.0
+ */
+public final class CsvConfig {
+    private final Character delimiter;
+    private final Integer maxRows;
+
+    private CsvConfig(Builder builder) {
+        this.delimiter = builder.delimiter;
+        this.maxRows = builder.maxRows;
+    }
+
+    @JsonCreator
+    public CsvConfig(
+        @JsonProperty("delimiter") Character delimiter,
+        @JsonProperty("max_rows") Integer maxRows
+    ) {
+        this.delimiter = delimiter;
+        this.maxRows = maxRows;
+    }
+
+    /**
+     * Create a new builder for CsvConfig.
+     *
+     * @return a new builder instance
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Get the delimiter override.
+     *
+     * @return delimiter character, or null to auto-detect
+     */
+    public Character getDelimiter() {
+        return delimiter;
+    }
+
+    /**
+     * Get the maximum number of data rows to parse.
+     *
+     * @return row limit, or null for unlimited
+     */
+    public Integer getMaxRows() {
+        return maxRows;
+    }
+
+    /**
+     * Convert to a map representation for serialization.
+     *
+     * @return map of configuration values
+     */
+    public Map<String, Object> toMap() {
+        Map<String, Object> map = new HashMap<>();
+        if (delimiter != null) {
+            map.put("delimiter", String.valueOf(delimiter));
+        }
+        if (maxRows != null) {
+            map.put("max_rows", maxRows);
+        }
+        return map;
+    }
+
+    /**
+     * Create CsvConfig from a map representation.
+     *
+     * @param map configuration map
+     * @return parsed CsvConfig
+     */
+    public static CsvConfig fromMap(Map<String, Object> map) {
+        if (map == null) {
+            return new CsvConfig(null, null);
+        }
+        Character delimiter = null;
+        Object rawDelimiter = map.get("delimiter");
+        if (rawDelimiter instanceof String && ((String) rawDelimiter).length() == 1) {
+            delimiter = ((String) rawDelimiter).charAt(0);
+        } else if (rawDelimiter instanceof Character) {
+            delimiter = (Character) rawDelimiter;
+        }
+        Integer maxRows = null;
+        Object rawMaxRows = map.get("max_rows");
+        if (rawMaxRows instanceof Number) {
+            maxRows = ((Number) rawMaxRows).intValue();
+        }
+        return new CsvConfig(delimiter, maxRows);
+    }
+
+    @Override
+    public boolean equals(Object obj) {
+        if (this == obj) {
+            return true;
+        }
+        if (!(obj instanceof CsvConfig)) {
+            return false;
+        }
+        CsvConfig other = (CsvConfig) obj;
+        return Objects.equals(delimiter, other.delimiter)
+            && Objects.equals(maxRows, other.maxRows);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(delimiter, maxRows);
+    }
+
+    @Override
+    public String toString() {
+        return "CsvConfig{"
+            + "delimiter=" + delimiter
+            + ", maxRows=" + maxRows
+            + '}';
+    }
+
+    /**
+     * Builder for CsvConfig with fluent interface.
+     */
+    public static final class Builder {
+        private Character delimiter;
+        private Integer maxRows;
+
+        private Builder() {
+        }
+
+        /**
+         * Override the detected field delimiter.
+         *
+         * @param delimiter delimiter character
+         * @return this builder for chaining
+         */
+        public Builder delimiter(char delimiter) {
+            this.delimiter = delimiter;
+            return this;
+        }
+
+        /**
+         * Limit the number of data rows parsed into the table.
+         *
+         * @param maxRows maximum number of data rows
+         * @return this builder for chaining
+         */
+        public Builder maxRows(int maxRows) {
+            if (maxRows <= 0) {
+                throw new IllegalArgumentException("maxRows must be positive");
+            }
+            this.maxRows = maxRows;
+            return this;
+        }
+
+        /**
+         * Build the CsvConfig instance.
+         *
+         * @return configured CsvConfig
+         */
+        public CsvConfig build() {
+            return new CsvConfig(this);
+        }
+    }
+}
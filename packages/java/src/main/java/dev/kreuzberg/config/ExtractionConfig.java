@@ -31,6 +31,8 @@ public final class ExtractionConfig {
   private final boolean useCacheSet;
   private final boolean enableQualityProcessingSet;
   private final boolean forceOcrSet;
+  private final boolean incremental;
+  private final boolean incrementalSet;
   private final OcrConfig ocr;
   private final ChunkingConfig chunking;
   private final LanguageDetectionConfig languageDetection;
@@ -42,7 +44,16 @@ public final class ExtractionConfig {
   private final HtmlOptions htmlOptions;
   private final KeywordConfig keywords;
   private final PageConfig pages;
+  private final CsvConfig csvOptions;
+  private final OcrCorrectionConfig ocrCorrection;
+  private final ClassificationConfig classification;
+  private final KeyValueExtractionConfig kvExtraction;
+  private final CleanupConfig cleanup;
+  private final WatermarkConfig watermarks;
+  private final StageTimeoutConfig stageTimeouts;
+  private final RetryConfig retry;
   private final Integer maxConcurrentExtractions;
+  private final String resultCompression;
   private final Map<String, Object> rawConfigOverride;
 
   private ExtractionConfig(Builder builder) {
@@ -52,6 +63,8 @@ public final class ExtractionConfig {
     this.useCacheSet = builder.useCacheSet;
     this.enableQualityProcessingSet = builder.enableQualityProcessingSet;
     this.forceOcrSet = builder.forceOcrSet;
+    this.incremental = builder.incremental;
+    this.incrementalSet = builder.incrementalSet;
     this.ocr = builder.ocr;
     this.chunking = builder.chunking;
     this.languageDetection = builder.languageDetection;
@@ -63,7 +76,16 @@ public final class ExtractionConfig {
     this.htmlOptions = builder.htmlOptions;
     this.keywords = builder.keywords;
     this.pages = builder.pages;
+    this.csvOptions = builder.csvOptions;
+    this.ocrCorrection = builder.ocrCorrection;
+    this.classification = builder.classification;
+    this.kvExtraction = builder.kvExtraction;
+    this.cleanup = builder.cleanup;
+    this.watermarks = builder.watermarks;
+    this.stageTimeouts = builder.stageTimeouts;
+    this.retry = builder.retry;
     this.maxConcurrentExtractions = builder.maxConcurrentExtractions;
+    this.resultCompression = builder.resultCompression;
     this.rawConfigOverride = builder.rawConfigOverride != null
         ? Collections.unmodifiableMap(new LinkedHashMap<>(builder.rawConfigOverride))
         : null;
@@ -85,6 +107,10 @@ public final class ExtractionConfig {
     return forceOcr;
   }
 
+  public boolean isIncremental() {
+    return incremental;
+  }
+
   public OcrConfig getOcr() {
     return ocr;
   }
@@ -129,10 +155,46 @@ public final class ExtractionConfig {
     return pages;
   }
 
+  public CsvConfig getCsvOptions() {
+    return csvOptions;
+  }
+
+  public OcrCorrectionConfig getOcrCorrection() {
+    return ocrCorrection;
+  }
+
+  public ClassificationConfig getClassification() {
+    return classification;
+  }
+
+  public KeyValueExtractionConfig getKvExtraction() {
+    return kvExtraction;
+  }
+
+  public CleanupConfig getCleanup() {
+    return cleanup;
+  }
+
+  public WatermarkConfig getWatermarks() {
+    return watermarks;
+  }
+
+  public StageTimeoutConfig getStageTimeouts() {
+    return stageTimeouts;
+  }
+
+  public RetryConfig getRetry() {
+    return retry;
+  }
+
   public Integer getMaxConcurrentExtractions() {
     return maxConcurrentExtractions;
   }
 
+  public String getResultCompression() {
+    return resultCompression;
+  }
+
   /**
    * Parse configuration from JSON produced by the Rust core.
    *
@@ -354,6 +416,9 @@ public final class ExtractionConfig {
     if (includeDefaults || forceOcrSet) {
       map.put("force_ocr", forceOcr);
     }
+    if (includeDefaults || incrementalSet) {
+      map.put("incremental", incremental);
+    }
     if (ocr != null) {
       map.put("ocr", ocr.toMap());
     }
@@ -387,9 +452,36 @@ public final class ExtractionConfig {
     if (pages != null) {
       map.put("pages", pages.toMap());
     }
+    if (csvOptions != null) {
+      map.put("csv_options", csvOptions.toMap());
+    }
+    if (ocrCorrection != null) {
+      map.put("ocr_correction", ocrCorrection.toMap());
+    }
+    if (classification != null) {
+      map.put("classification", classification.toMap());
+    }
+    if (kvExtraction != null) {
+      map.put("kv_extraction", kvExtraction.toMap());
+    }
+    if (cleanup != null) {
+      map.put("cleanup", cleanup.toMap());
+    }
+    if (watermarks != null) {
+      map.put("watermarks", watermarks.toMap());
+    }
+    if (stageTimeouts != null) {
+      map.put("stage_timeouts", stageTimeouts.toMap());
+    }
+    if (retry != null) {
+      map.put("retry", retry.toMap());
+    }
     if (maxConcurrentExtractions != null) {
       map.put("max_concurrent_extractions", maxConcurrentExtractions);
     }
+    if (resultCompression != null) {
+      map.put("result_compression", resultCompression);
+    }
     return map;
   }
 
@@ -407,6 +499,9 @@ public final class ExtractionConfig {
     if (raw.containsKey("force_ocr")) {
       builder.forceOcr(asBoolean(raw.get("force_ocr"), builder.forceOcr));
     }
+    if (raw.containsKey("incremental")) {
+      builder.incremental(asBoolean(raw.get("incremental"), builder.incremental));
+    }
     Map<String, Object> ocrMap = asMap(raw.get("ocr"));
     if (ocrMap != null) {
       builder.ocr(OcrConfig.fromMap(ocrMap));
@@ -452,9 +547,44 @@ public final class ExtractionConfig {
     if (pageMap != null) {
       builder.pages(PageConfig.fromMap(pageMap));
     }
+    Map<String, Object> csvMap = asMap(raw.get("csv_options"));
+    if (csvMap != null) {
+      builder.csvOptions(CsvConfig.fromMap(csvMap));
+    }
+    Map<String, Object> ocrCorrectionMap = asMap(raw.get("ocr_correction"));
+    if (ocrCorrectionMap != null) {
+      builder.ocrCorrection(OcrCorrectionConfig.fromMap(ocrCorrectionMap));
+    }
+    Map<String, Object> classificationMap = asMap(raw.get("classification"));
+    if (classificationMap != null) {
+      builder.classification(ClassificationConfig.fromMap(classificationMap));
+    }
+    Map<String, Object> kvExtractionMap = asMap(raw.get("kv_extraction"));
+    if (kvExtractionMap != null) {
+      builder.kvExtraction(KeyValueExtractionConfig.fromMap(kvExtractionMap));
+    }
+    Map<String, Object> cleanupMap = asMap(raw.get("cleanup"));
+    if (cleanupMap != null) {
+      builder.cleanup(CleanupConfig.fromMap(cleanupMap));
+    }
+    Map<String, Object> watermarksMap = asMap(raw.get("watermarks"));
+    if (watermarksMap != null) {
+      builder.watermarks(WatermarkConfig.fromMap(watermarksMap));
+    }
+    Map<String, Object> stageTimeoutsMap = asMap(raw.get("stage_timeouts"));
+    if (stageTimeoutsMap != null) {
+      builder.stageTimeouts(StageTimeoutConfig.fromMap(stageTimeoutsMap));
+    }
+    Map<String, Object> retryMap = asMap(raw.get("retry"));
+    if (retryMap != null) {
+      builder.retry(RetryConfig.fromMap(retryMap));
+    }
     if (raw.containsKey("max_concurrent_extractions")) {
       builder.maxConcurrentExtractions(asInteger(raw.get("max_concurrent_extractions")));
     }
+    if (raw.get("result_compression") instanceof String) {
+      builder.resultCompression((String) raw.get("result_compression"));
+    }
   }
 
   private static boolean asBoolean(Object value, boolean defaultValue) {
@@ -499,6 +629,8 @@ public final class ExtractionConfig {
     private boolean useCacheSet = false;
     private boolean enableQualityProcessingSet = false;
     private boolean forceOcrSet = false;
+    private boolean incremental = false;
+    private boolean incrementalSet = false;
     private OcrConfig ocr;
     private ChunkingConfig chunking;
     private LanguageDetectionConfig languageDetection;
@@ -510,7 +642,16 @@ public final class ExtractionConfig {
     private HtmlOptions htmlOptions;
     private KeywordConfig keywords;
     private PageConfig pages;
+    private CsvConfig csvOptions;
+    private OcrCorrectionConfig ocrCorrection;
+    private ClassificationConfig classification;
+    private KeyValueExtractionConfig kvExtraction;
+    private CleanupConfig cleanup;
+    private WatermarkConfig watermarks;
+    private StageTimeoutConfig stageTimeouts;
+    private RetryConfig retry;
     private Integer maxConcurrentExtractions;
+    private String resultCompression;
     private Map<String, Object> rawConfigOverride;
 
     private Builder() {
@@ -534,6 +675,18 @@ public final class ExtractionConfig {
       return this;
     }
 
+    /**
+     * Re-extract only the appended or modified pages of a PDF extracted before.
+     *
+     * <p>Page hashes and the previous result are stored per file under the cache
+     * directory; other formats are always extracted in full.
+     */
+    public Builder incremental(boolean incremental) {
+      this.incremental = incremental;
+      this.incrementalSet = true;
+      return this;
+    }
+
     public Builder ocr(OcrConfig ocr) {
       this.ocr = ocr;
       return this;
@@ -589,11 +742,83 @@ public final class ExtractionConfig {
       return this;
     }
 
+    public Builder csvOptions(CsvConfig csvOptions) {
+      this.csvOptions = csvOptions;
+      return this;
+    }
+
+    /**
+     * Fix common OCR confusions (O/0, l/1, rn/m) in text that came from OCR.
+     */
+    public Builder ocrCorrection(OcrCorrectionConfig ocrCorrection) {
+      this.ocrCorrection = ocrCorrection;
+      return this;
+    }
+
+    /**
+     * Label documents with their type (invoice, contract, report, letter or custom) in
+     * {@code metadata.document_type}.
+     */
+    public Builder classification(ClassificationConfig classification) {
+      this.classification = classification;
+      return this;
+    }
+
+    /**
+     * Extract label/value pairs such as {@code Invoice number: 2024-117} into
+     * {@code metadata.key_values}.
+     */
+    public Builder kvExtraction(KeyValueExtractionConfig kvExtraction) {
+      this.kvExtraction = kvExtraction;
+      return this;
+    }
+
+    /**
+     * Remove running headers and footers, page numbers and watermarks repeated across pages.
+     */
+    public Builder cleanup(CleanupConfig cleanup) {
+      this.cleanup = cleanup;
+      return this;
+    }
+
+    /**
+     * Report watermarks and stamps in metadata.watermarks.
+     */
+    public Builder watermarks(WatermarkConfig watermarks) {
+      this.watermarks = watermarks;
+      return this;
+    }
+
+    /**
+     * Abandon extraction stages that exceed their time budget with a timeout error.
+     */
+    public Builder stageTimeouts(StageTimeoutConfig stageTimeouts) {
+      this.stageTimeouts = stageTimeouts;
+      return this;
+    }
+
+    /**
+     * Retry batch documents that failed with a transient error, with exponential backoff.
+     */
+    public Builder retry(RetryConfig retry) {
+      this.retry = retry;
+      return this;
+    }
+
     public Builder maxConcurrentExtractions(Integer maxConcurrentExtractions) {
       this.maxConcurrentExtractions = maxConcurrentExtractions;
       return this;
     }
 
+    /**
+     * Compress the chunks, images and page structure JSON passed back from the native library
+     * ({@code "gzip"} or {@code "zstd"}); saves copies and string churn for very large documents.
+     */
+    public Builder resultCompression(String resultCompression) {
+      this.resultCompression = resultCompression;
+      return this;
+    }
+
     Builder rawConfigOverride(Map<String, Object> rawConfigOverride) {
       if (rawConfigOverride != null) {
         this.rawConfigOverride = new LinkedHashMap<>(rawConfigOverride);
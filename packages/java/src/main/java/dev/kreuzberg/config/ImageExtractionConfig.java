@@ -15,6 +15,10 @@ public final class ImageExtractionConfig {
   private final boolean autoAdjustDpi;
   private final int minDpi;
   private final int maxDpi;
+  private final boolean perceptualHash;
+  private final boolean deduplicateImages;
+  private final int duplicateHashDistance;
+  private final ImageOcrPolicy ocrPolicy;
 
   private ImageExtractionConfig(Builder builder) {
     this.extractImages = builder.extractImages;
@@ -23,6 +27,10 @@ public final class ImageExtractionConfig {
     this.autoAdjustDpi = builder.autoAdjustDpi;
     this.minDpi = builder.minDpi;
     this.maxDpi = builder.maxDpi;
+    this.perceptualHash = builder.perceptualHash;
+    this.deduplicateImages = builder.deduplicateImages;
+    this.duplicateHashDistance = builder.duplicateHashDistance;
+    this.ocrPolicy = builder.ocrPolicy;
   }
 
   public static Builder builder() {
@@ -53,6 +61,22 @@ public final class ImageExtractionConfig {
     return maxDpi;
   }
 
+  public boolean isPerceptualHash() {
+    return perceptualHash;
+  }
+
+  public boolean isDeduplicateImages() {
+    return deduplicateImages;
+  }
+
+  public int getDuplicateHashDistance() {
+    return duplicateHashDistance;
+  }
+
+  public ImageOcrPolicy getOcrPolicy() {
+    return ocrPolicy;
+  }
+
   public Map<String, Object> toMap() {
     Map<String, Object> map = new HashMap<>();
     map.put("extract_images", extractImages);
@@ -61,6 +85,12 @@ public final class ImageExtractionConfig {
     map.put("auto_adjust_dpi", autoAdjustDpi);
     map.put("min_dpi", minDpi);
     map.put("max_dpi", maxDpi);
+    map.put("perceptual_hash", perceptualHash);
+    map.put("deduplicate_images", deduplicateImages);
+    map.put("duplicate_hash_distance", duplicateHashDistance);
+    if (ocrPolicy != null) {
+      map.put("ocr_policy", ocrPolicy.toMap());
+    }
     return map;
   }
 
@@ -71,6 +101,10 @@ public final class ImageExtractionConfig {
     private boolean autoAdjustDpi = true;
     private int minDpi = 150;
     private int maxDpi = 600;
+    private boolean perceptualHash = false;
+    private boolean deduplicateImages = false;
+    private int duplicateHashDistance = 5;
+    private ImageOcrPolicy ocrPolicy;
 
     private Builder() {
     }
@@ -105,6 +139,26 @@ public final class ImageExtractionConfig {
       return this;
     }
 
+    public Builder perceptualHash(boolean perceptualHash) {
+      this.perceptualHash = perceptualHash;
+      return this;
+    }
+
+    public Builder deduplicateImages(boolean deduplicateImages) {
+      this.deduplicateImages = deduplicateImages;
+      return this;
+    }
+
+    public Builder duplicateHashDistance(int duplicateHashDistance) {
+      this.duplicateHashDistance = duplicateHashDistance;
+      return this;
+    }
+
+    public Builder ocrPolicy(ImageOcrPolicy ocrPolicy) {
+      this.ocrPolicy = ocrPolicy;
+      return this;
+    }
+
     public ImageExtractionConfig build() {
       return new ImageExtractionConfig(this);
     }
@@ -133,6 +187,22 @@ public final class ImageExtractionConfig {
     if (map.get("max_dpi") instanceof Number) {
       builder.maxDpi(((Number) map.get("max_dpi")).intValue());
     }
+    if (map.get("perceptual_hash") instanceof Boolean) {
+      builder.perceptualHash((Boolean) map.get("perceptual_hash"));
+    }
+    if (map.get("deduplicate_images") instanceof Boolean) {
+      builder.deduplicateImages((Boolean) map.get("deduplicate_images"));
+    }
+    if (map.get("duplicate_hash_distance") instanceof Number) {
+      builder.duplicateHashDistance(((Number) map.get("duplicate_hash_distance")).intValue());
+    }
+    @SuppressWarnings("unchecked")
+    Map<String, Object> ocrPolicyMap = map.get("ocr_policy") instanceof Map
+        ? (Map<String, Object>) map.get("ocr_policy")
+        : null;
+    if (ocrPolicyMap != null) {
+      builder.ocrPolicy(ImageOcrPolicy.fromMap(ocrPolicyMap));
+    }
     return builder.build();
   }
 }
@@ -0,0 +1,166 @@
+package dev.kreuzberg.config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Policy deciding which extracted images are sent to OCR.
+ *
+ * <p>An image is OCR'd only if it passes every configured filter. Unset filters accept all
+ * images.
+ *
+ * @since 4.0.0
+ */
+public final class ImageOcrPolicy {
+  private final Integer minWidth;
+  private final Integer minHeight;
+  private final Long minBytes;
+  private final List<Integer> pages;
+  private final boolean skipMasks;
+  private final boolean skipDecorative;
+
+  private ImageOcrPolicy(Builder builder) {
+    this.minWidth = builder.minWidth;
+    this.minHeight = builder.minHeight;
+    this.minBytes = builder.minBytes;
+    this.pages =
+        builder.pages != null ? Collections.unmodifiableList(new ArrayList<>(builder.pages)) : null;
+    this.skipMasks = builder.skipMasks;
+    this.skipDecorative = builder.skipDecorative;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public Integer getMinWidth() {
+    return minWidth;
+  }
+
+  public Integer getMinHeight() {
+    return minHeight;
+  }
+
+  public Long getMinBytes() {
+    return minBytes;
+  }
+
+  public List<Integer> getPages() {
+    return pages;
+  }
+
+  public boolean isSkipMasks() {
+    return skipMasks;
+  }
+
+  public boolean isSkipDecorative() {
+    return skipDecorative;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    if (minWidth != null) {
+      map.put("min_width", minWidth);
+    }
+    if (minHeight != null) {
+      map.put("min_height", minHeight);
+    }
+    if (minBytes != null) {
+      map.put("min_bytes", minBytes);
+    }
+    if (pages != null) {
+      map.put("pages", pages);
+    }
+    map.put("skip_masks", skipMasks);
+    map.put("skip_decorative", skipDecorative);
+    return map;
+  }
+
+  public static final class Builder {
+    private Integer minWidth;
+    private Integer minHeight;
+    private Long minBytes;
+    private List<Integer> pages;
+    private boolean skipMasks = true;
+    private boolean skipDecorative = false;
+
+    private Builder() {
+    }
+
+    public Builder minWidth(Integer minWidth) {
+      this.minWidth = minWidth;
+      return this;
+    }
+
+    public Builder minHeight(Integer minHeight) {
+      this.minHeight = minHeight;
+      return this;
+    }
+
+    public Builder minBytes(Long minBytes) {
+      this.minBytes = minBytes;
+      return this;
+    }
+
+    public Builder pages(List<Integer> pages) {
+      if (pages == null) {
+        this.pages = null;
+      } else {
+        this.pages = new ArrayList<>(pages);
+      }
+      return this;
+    }
+
+    public Builder skipMasks(boolean skipMasks) {
+      this.skipMasks = skipMasks;
+      return this;
+    }
+
+    public Builder skipDecorative(boolean skipDecorative) {
+      this.skipDecorative = skipDecorative;
+      return this;
+    }
+
+    public ImageOcrPolicy build() {
+      return new ImageOcrPolicy(this);
+    }
+  }
+
+  static ImageOcrPolicy fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    if (map.get("min_width") instanceof Number) {
+      builder.minWidth(((Number) map.get("min_width")).intValue());
+    }
+    if (map.get("min_height") instanceof Number) {
+      builder.minHeight(((Number) map.get("min_height")).intValue());
+    }
+    if (map.get("min_bytes") instanceof Number) {
+      builder.minBytes(((Number) map.get("min_bytes")).longValue());
+    }
+    Object pagesValue = map.get("pages");
+    if (pagesValue instanceof Iterable) {
+      @SuppressWarnings("unchecked")
+      Iterable<Object> iterable = (Iterable<Object>) pagesValue;
+      List<Integer> pages = new ArrayList<>();
+      for (Object entry : iterable) {
+        if (entry instanceof Number) {
+          pages.add(((Number) entry).intValue());
+        }
+      }
+      builder.pages(pages);
+    }
+    if (map.get("skip_masks") instanceof Boolean) {
+      builder.skipMasks((Boolean) map.get("skip_masks"));
+    }
+    if (map.get("skip_decorative") instanceof Boolean) {
+      builder.skipDecorative((Boolean) map.get("skip_decorative"));
+    }
+    return builder.build();
+  }
+}
@@ -16,6 +16,7 @@ public final class ImagePreprocessingConfig {
   private final boolean contrastEnhance;
   private final String binarizationMethod;
   private final boolean invertColors;
+  private final boolean autoTune;
 
   private ImagePreprocessingConfig(Builder builder) {
     this.targetDpi = builder.targetDpi;
@@ -25,6 +26,7 @@ public final class ImagePreprocessingConfig {
     this.contrastEnhance = builder.contrastEnhance;
     this.binarizationMethod = builder.binarizationMethod;
     this.invertColors = builder.invertColors;
+    this.autoTune = builder.autoTune;
   }
 
   public static Builder builder() {
@@ -59,6 +61,17 @@ public final class ImagePreprocessingConfig {
     return invertColors;
   }
 
+  /**
+   * Whether binarization, denoising, contrast and DPI are chosen per page from image statistics.
+   *
+   * <p>The choices are recorded in the {@code preprocessing} and {@code ocr_pages} metadata.
+   *
+   * @return true if preprocessing is auto-tuned
+   */
+  public boolean isAutoTune() {
+    return autoTune;
+  }
+
   public Map<String, Object> toMap() {
     Map<String, Object> map = new HashMap<>();
     map.put("target_dpi", targetDpi);
@@ -68,6 +81,7 @@ public final class ImagePreprocessingConfig {
     map.put("contrast_enhance", contrastEnhance);
     map.put("binarization_method", binarizationMethod);
     map.put("invert_colors", invertColors);
+    map.put("auto_tune", autoTune);
     return map;
   }
 
@@ -79,6 +93,7 @@ public final class ImagePreprocessingConfig {
     private boolean contrastEnhance = true;
     private String binarizationMethod = "otsu";
     private boolean invertColors = false;
+    private boolean autoTune = false;
 
     private Builder() {
     }
@@ -118,6 +133,11 @@ public final class ImagePreprocessingConfig {
       return this;
     }
 
+    public Builder autoTune(boolean autoTune) {
+      this.autoTune = autoTune;
+      return this;
+    }
+
     public ImagePreprocessingConfig build() {
       return new ImagePreprocessingConfig(this);
     }
@@ -149,6 +169,9 @@ public final class ImagePreprocessingConfig {
     if (map.get("invert_colors") instanceof Boolean) {
       builder.invertColors((Boolean) map.get("invert_colors"));
     }
+    if (map.get("auto_tune") instanceof Boolean) {
+      builder.autoTune((Boolean) map.get("auto_tune"));
+    }
     return builder.build();
   }
 }
@@ -0,0 +1,106 @@
+package dev.kreuzberg.config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Key-value pair extraction.
+ *
+ * <p>Finds label/value pairs such as {@code Invoice number: 2024-117} in the document text. A
+ * value is taken from the rest of the line, the next column or the line below the label. Pairs
+ * are written to {@code metadata.key_values}; for PDFs with a text layer they carry the page and
+ * the bounding boxes of the label and the value.
+ *
+ * @since 4.0.0
+ */
+public final class KeyValueExtractionConfig {
+  private final boolean builtinLabels;
+  private final List<String> labels;
+  private final int maxLabelWords;
+
+  private KeyValueExtractionConfig(Builder builder) {
+    this.builtinLabels = builder.builtinLabels;
+    this.labels = Collections.unmodifiableList(new ArrayList<>(builder.labels));
+    this.maxLabelWords = builder.maxLabelWords;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public boolean isBuiltinLabels() {
+    return builtinLabels;
+  }
+
+  public List<String> getLabels() {
+    return labels;
+  }
+
+  public int getMaxLabelWords() {
+    return maxLabelWords;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    map.put("builtin_labels", builtinLabels);
+    map.put("labels", labels);
+    map.put("max_label_words", maxLabelWords);
+    return map;
+  }
+
+  public static final class Builder {
+    private boolean builtinLabels = true;
+    private List<String> labels = new ArrayList<>();
+    private int maxLabelWords = 5;
+
+    private Builder() {
+    }
+
+    public Builder builtinLabels(boolean builtinLabels) {
+      this.builtinLabels = builtinLabels;
+      return this;
+    }
+
+    public Builder labels(List<String> labels) {
+      this.labels = labels != null ? new ArrayList<>(labels) : new ArrayList<>();
+      return this;
+    }
+
+    public Builder addLabel(String label) {
+      this.labels.add(label);
+      return this;
+    }
+
+    public Builder maxLabelWords(int maxLabelWords) {
+      this.maxLabelWords = maxLabelWords;
+      return this;
+    }
+
+    public KeyValueExtractionConfig build() {
+      return new KeyValueExtractionConfig(this);
+    }
+  }
+
+  static KeyValueExtractionConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    if (map.get("builtin_labels") instanceof Boolean) {
+      builder.builtinLabels((Boolean) map.get("builtin_labels"));
+    }
+    if (map.get("max_label_words") instanceof Number) {
+      builder.maxLabelWords(((Number) map.get("max_label_words")).intValue());
+    }
+    Object labelsValue = map.get("labels");
+    if (labelsValue instanceof Iterable) {
+      for (Object entry : (Iterable<?>) labelsValue) {
+        builder.addLabel(String.valueOf(entry));
+      }
+    }
+    return builder.build();
+  }
+}
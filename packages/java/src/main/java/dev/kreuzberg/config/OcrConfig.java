@@ -18,11 +18,13 @@ public final class OcrConfig {
   private final String backend;
   private final String language;
   private final TesseractConfig tesseractConfig;
+  private final OcrVocabularyConfig vocabulary;
 
   private OcrConfig(Builder builder) {
     this.backend = builder.backend;
     this.language = builder.language;
     this.tesseractConfig = builder.tesseractConfig;
+    this.vocabulary = builder.vocabulary;
   }
 
   /**
@@ -61,6 +63,15 @@ public final class OcrConfig {
     return tesseractConfig;
   }
 
+  /**
+   * Gets the domain vocabulary.
+   *
+   * @return the vocabulary, or null if not set
+   */
+  public OcrVocabularyConfig getVocabulary() {
+    return vocabulary;
+  }
+
   /**
    * Converts this configuration to a map for FFI.
    *
@@ -77,6 +88,9 @@ public final class OcrConfig {
     if (tesseractConfig != null) {
       map.put("tesseract_config", tesseractConfig.toMap());
     }
+    if (vocabulary != null) {
+      map.put("vocabulary", vocabulary.toMap());
+    }
     return map;
   }
 
@@ -97,6 +111,9 @@ public final class OcrConfig {
     if (tesseractMap != null) {
       builder.tesseractConfig(TesseractConfig.fromMap(tesseractMap));
     }
+    if (map.get("vocabulary") instanceof Map) {
+      builder.vocabulary(OcrVocabularyConfig.fromMap(toMap(map.get("vocabulary"))));
+    }
     return builder.build();
   }
 
@@ -107,6 +124,7 @@ public final class OcrConfig {
     private String backend = "tesseract";
     private String language = "eng";
     private TesseractConfig tesseractConfig;
+    private OcrVocabularyConfig vocabulary;
 
     private Builder() {
     }
@@ -154,6 +172,17 @@ public final class OcrConfig {
       return this;
     }
 
+    /**
+     * Sets the domain vocabulary used for Tesseract user words and OCR correction.
+     *
+     * @param vocabulary the vocabulary config
+     * @return this builder
+     */
+    public Builder vocabulary(OcrVocabularyConfig vocabulary) {
+      this.vocabulary = vocabulary;
+      return this;
+    }
+
     /**
      * Builds the OCR configuration.
      *
@@ -0,0 +1,124 @@
+package dev.kreuzberg.config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Correction of characters OCR engines commonly confuse.
+ *
+ * <p>Only text that came from OCR is corrected. The built-in rules read {@code O} as {@code 0}
+ * and {@code l} as {@code 1} in numbers, {@code 0} as {@code o} and {@code 1} as {@code l} inside
+ * words, and a word-initial {@code rn} as {@code m}.
+ *
+ * @since 4.0.0
+ */
+public final class OcrCorrectionConfig {
+  private final boolean builtinRules;
+  private final List<Rule> rules;
+
+  private OcrCorrectionConfig(Builder builder) {
+    this.builtinRules = builder.builtinRules;
+    this.rules = Collections.unmodifiableList(new ArrayList<>(builder.rules));
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public boolean isBuiltinRules() {
+    return builtinRules;
+  }
+
+  public List<Rule> getRules() {
+    return rules;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    map.put("builtin_rules", builtinRules);
+    List<Map<String, Object>> ruleMaps = new ArrayList<>();
+    for (Rule rule : rules) {
+      ruleMaps.add(rule.toMap());
+    }
+    map.put("rules", ruleMaps);
+    return map;
+  }
+
+  /**
+   * Replacement of a sequence OCR misreads.
+   *
+   * @param from the sequence as misread by OCR
+   * @param to the intended sequence
+   * @param context {@code "numeric"}, {@code "alphabetic"}, {@code "word_start"} or
+   *     {@code "anywhere"}
+   */
+  public record Rule(String from, String to, String context) {
+    public Rule(String from, String to) {
+      this(from, to, "anywhere");
+    }
+
+    Map<String, Object> toMap() {
+      Map<String, Object> map = new HashMap<>();
+      map.put("from", from);
+      map.put("to", to);
+      map.put("context", context);
+      return map;
+    }
+  }
+
+  public static final class Builder {
+    private boolean builtinRules = true;
+    private List<Rule> rules = new ArrayList<>();
+
+    private Builder() {
+    }
+
+    public Builder builtinRules(boolean builtinRules) {
+      this.builtinRules = builtinRules;
+      return this;
+    }
+
+    public Builder rules(List<Rule> rules) {
+      this.rules = rules != null ? new ArrayList<>(rules) : new ArrayList<>();
+      return this;
+    }
+
+    public Builder addRule(Rule rule) {
+      this.rules.add(rule);
+      return this;
+    }
+
+    public OcrCorrectionConfig build() {
+      return new OcrCorrectionConfig(this);
+    }
+  }
+
+  static OcrCorrectionConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    if (map.get("builtin_rules") instanceof Boolean) {
+      builder.builtinRules((Boolean) map.get("builtin_rules"));
+    }
+    Object rulesValue = map.get("rules");
+    if (rulesValue instanceof Iterable) {
+      @SuppressWarnings("unchecked")
+      Iterable<Object> iterable = (Iterable<Object>) rulesValue;
+      for (Object entry : iterable) {
+        if (entry instanceof Map) {
+          Map<?, ?> rule = (Map<?, ?>) entry;
+          Object context = rule.get("context");
+          builder.addRule(new Rule(
+              String.valueOf(rule.get("from")),
+              String.valueOf(rule.get("to")),
+              context instanceof String ? (String) context : "anywhere"));
+        }
+      }
+    }
+    return builder.build();
+  }
+}
@@ -0,0 +1,140 @@
+package dev.kreuzberg.config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Domain vocabulary for OCR, such as drug names or part numbers.
+ *
+ * <p>Words from {@code words} and {@code wordsFile} (one entry per line, lines starting with
+ * {@code #} are comments) are added to Tesseract's user words. Unless {@code correct} is false,
+ * OCR words within {@code maxDistance} edits of exactly one vocabulary word are replaced with it.
+ *
+ * @since 4.0.0
+ */
+public final class OcrVocabularyConfig {
+  private final List<String> words;
+  private final String wordsFile;
+  private final boolean correct;
+  private final int maxDistance;
+  private final int minWordLength;
+
+  private OcrVocabularyConfig(Builder builder) {
+    this.words = Collections.unmodifiableList(new ArrayList<>(builder.words));
+    this.wordsFile = builder.wordsFile;
+    this.correct = builder.correct;
+    this.maxDistance = builder.maxDistance;
+    this.minWordLength = builder.minWordLength;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public List<String> getWords() {
+    return words;
+  }
+
+  public String getWordsFile() {
+    return wordsFile;
+  }
+
+  public boolean isCorrect() {
+    return correct;
+  }
+
+  public int getMaxDistance() {
+    return maxDistance;
+  }
+
+  public int getMinWordLength() {
+    return minWordLength;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    map.put("words", words);
+    if (wordsFile != null) {
+      map.put("words_file", wordsFile);
+    }
+    map.put("correct", correct);
+    map.put("max_distance", maxDistance);
+    map.put("min_word_length", minWordLength);
+    return map;
+  }
+
+  public static final class Builder {
+    private List<String> words = new ArrayList<>();
+    private String wordsFile;
+    private boolean correct = true;
+    private int maxDistance = 1;
+    private int minWordLength = 4;
+
+    private Builder() {
+    }
+
+    public Builder words(List<String> words) {
+      this.words = words != null ? new ArrayList<>(words) : new ArrayList<>();
+      return this;
+    }
+
+    public Builder wordsFile(String wordsFile) {
+      this.wordsFile = wordsFile;
+      return this;
+    }
+
+    public Builder correct(boolean correct) {
+      this.correct = correct;
+      return this;
+    }
+
+    public Builder maxDistance(int maxDistance) {
+      this.maxDistance = maxDistance;
+      return this;
+    }
+
+    public Builder minWordLength(int minWordLength) {
+      this.minWordLength = minWordLength;
+      return this;
+    }
+
+    public OcrVocabularyConfig build() {
+      return new OcrVocabularyConfig(this);
+    }
+  }
+
+  static OcrVocabularyConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    Object wordsValue = map.get("words");
+    if (wordsValue instanceof Iterable) {
+      @SuppressWarnings("unchecked")
+      Iterable<Object> iterable = (Iterable<Object>) wordsValue;
+      List<String> words = new ArrayList<>();
+      for (Object entry : iterable) {
+        if (entry instanceof String) {
+          words.add((String) entry);
+        }
+      }
+      builder.words(words);
+    }
+    if (map.get("words_file") instanceof String) {
+      builder.wordsFile((String) map.get("words_file"));
+    }
+    if (map.get("correct") instanceof Boolean) {
+      builder.correct((Boolean) map.get("correct"));
+    }
+    if (map.get("max_distance") instanceof Number) {
+      builder.maxDistance(((Number) map.get("max_distance")).intValue());
+    }
+    if (map.get("min_word_length") instanceof Number) {
+      builder.minWordLength(((Number) map.get("min_word_length")).intValue());
+    }
+    return builder.build();
+  }
+}
@@ -0,0 +1,150 @@
+package dev.kreuzberg.config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Retries of batch documents that failed for a transient reason.
+ *
+ * <p>Errors of the classes in {@code retryOn} ({@code "io"} and {@code "ocr"} by default; also
+ * {@code "timeout"}, {@code "cache"} and {@code "plugin"}) are retried with exponential backoff:
+ * the n-th retry waits {@code initialBackoffMs * multiplier^(n-1)}, capped at
+ * {@code maxBackoffMs}. The number of attempts is reported in the {@code extraction_attempts}
+ * metadata of every batch result. Unset fields use the core defaults.
+ *
+ * @since 4.0.0
+ */
+public final class RetryConfig {
+  private final Integer maxAttempts;
+  private final Long initialBackoffMs;
+  private final Long maxBackoffMs;
+  private final Double multiplier;
+  private final List<String> retryOn;
+
+  private RetryConfig(Builder builder) {
+    this.maxAttempts = builder.maxAttempts;
+    this.initialBackoffMs = builder.initialBackoffMs;
+    this.maxBackoffMs = builder.maxBackoffMs;
+    this.multiplier = builder.multiplier;
+    this.retryOn = builder.retryOn != null ? Collections.unmodifiableList(new ArrayList<>(builder.retryOn)) : null;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public Integer getMaxAttempts() {
+    return maxAttempts;
+  }
+
+  public Long getInitialBackoffMs() {
+    return initialBackoffMs;
+  }
+
+  public Long getMaxBackoffMs() {
+    return maxBackoffMs;
+  }
+
+  public Double getMultiplier() {
+    return multiplier;
+  }
+
+  public List<String> getRetryOn() {
+    return retryOn;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    if (maxAttempts != null) {
+      map.put("max_attempts", maxAttempts);
+    }
+    if (initialBackoffMs != null) {
+      map.put("initial_backoff_ms", initialBackoffMs);
+    }
+    if (maxBackoffMs != null) {
+      map.put("max_backoff_ms", maxBackoffMs);
+    }
+    if (multiplier != null) {
+      map.put("multiplier", multiplier);
+    }
+    if (retryOn != null) {
+      map.put("retry_on", retryOn);
+    }
+    return map;
+  }
+
+  public static final class Builder {
+    private Integer maxAttempts;
+    private Long initialBackoffMs;
+    private Long maxBackoffMs;
+    private Double multiplier;
+    private List<String> retryOn;
+
+    private Builder() {
+    }
+
+    public Builder maxAttempts(Integer maxAttempts) {
+      this.maxAttempts = maxAttempts;
+      return this;
+    }
+
+    public Builder initialBackoffMs(Long initialBackoffMs) {
+      this.initialBackoffMs = initialBackoffMs;
+      return this;
+    }
+
+    public Builder maxBackoffMs(Long maxBackoffMs) {
+      this.maxBackoffMs = maxBackoffMs;
+      return this;
+    }
+
+    public Builder multiplier(Double multiplier) {
+      this.multiplier = multiplier;
+      return this;
+    }
+
+    public Builder retryOn(List<String> retryOn) {
+      this.retryOn = retryOn != null ? new ArrayList<>(retryOn) : null;
+      return this;
+    }
+
+    public RetryConfig build() {
+      return new RetryConfig(this);
+    }
+  }
+
+  static RetryConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    if (map.get("max_attempts") instanceof Number) {
+      builder.maxAttempts(((Number) map.get("max_attempts")).intValue());
+    }
+    if (map.get("initial_backoff_ms") instanceof Number) {
+      builder.initialBackoffMs(((Number) map.get("initial_backoff_ms")).longValue());
+    }
+    if (map.get("max_backoff_ms") instanceof Number) {
+      builder.maxBackoffMs(((Number) map.get("max_backoff_ms")).longValue());
+    }
+    if (map.get("multiplier") instanceof Number) {
+      builder.multiplier(((Number) map.get("multiplier")).doubleValue());
+    }
+    Object retryOnValue = map.get("retry_on");
+    if (retryOnValue instanceof Iterable) {
+      @SuppressWarnings("unchecked")
+      Iterable<Object> iterable = (Iterable<Object>) retryOnValue;
+      List<String> retryOn = new ArrayList<>();
+      for (Object entry : iterable) {
+        if (entry instanceof String) {
+          retryOn.add((String) entry);
+        }
+      }
+      builder.retryOn(retryOn);
+    }
+    return builder.build();
+  }
+}
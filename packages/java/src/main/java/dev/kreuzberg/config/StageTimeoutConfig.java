@@ -0,0 +1,98 @@
+package dev.kreuzberg.config;
+
+import java.util.HashMap;
+import java.util.Map;
+
+/**
+ * Time budgets of extraction stages, in milliseconds.
+ *
+ * <p>A stage running past its budget is abandoned and the extraction fails with a timeout error
+ * naming the stage, so a pathological document cannot wedge a worker. {@code ocrMs} applies to
+ * each PDF page or image separately. Stages without a budget run unbounded.
+ *
+ * @since 4.0.0
+ */
+public final class StageTimeoutConfig {
+  private final Long extractionMs;
+  private final Long ocrMs;
+  private final Long postProcessingMs;
+
+  private StageTimeoutConfig(Builder builder) {
+    this.extractionMs = builder.extractionMs;
+    this.ocrMs = builder.ocrMs;
+    this.postProcessingMs = builder.postProcessingMs;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public Long getExtractionMs() {
+    return extractionMs;
+  }
+
+  public Long getOcrMs() {
+    return ocrMs;
+  }
+
+  public Long getPostProcessingMs() {
+    return postProcessingMs;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    if (extractionMs != null) {
+      map.put("extraction_ms", extractionMs);
+    }
+    if (ocrMs != null) {
+      map.put("ocr_ms", ocrMs);
+    }
+    if (postProcessingMs != null) {
+      map.put("post_processing_ms", postProcessingMs);
+    }
+    return map;
+  }
+
+  public static final class Builder {
+    private Long extractionMs;
+    private Long ocrMs;
+    private Long postProcessingMs;
+
+    private Builder() {
+    }
+
+    public Builder extractionMs(Long extractionMs) {
+      this.extractionMs = extractionMs;
+      return this;
+    }
+
+    public Builder ocrMs(Long ocrMs) {
+      this.ocrMs = ocrMs;
+      return this;
+    }
+
+    public Builder postProcessingMs(Long postProcessingMs) {
+      this.postProcessingMs = postProcessingMs;
+      return this;
+    }
+
+    public StageTimeoutConfig build() {
+      return new StageTimeoutConfig(this);
+    }
+  }
+
+  static StageTimeoutConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    return builder()
+        .extractionMs(asLong(map.get("extraction_ms")))
+        .ocrMs(asLong(map.get("ocr_ms")))
+        .postProcessingMs(asLong(map.get("post_processing_ms")))
+        .build();
+  }
+
+  private static Long asLong(Object value) {
+    return value instanceof Number ? ((Number) value).longValue() : null;
+  }
+}
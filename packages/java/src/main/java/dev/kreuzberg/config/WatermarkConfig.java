@@ -0,0 +1,92 @@
+package dev.kreuzberg.config;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Watermark and stamp detection.
+ *
+ * <p>In PDFs, text drawn diagonally across the page and images placed on most pages are reported.
+ * In every format, stamp words written in capitals on a line of their own, such as DRAFT or
+ * CONFIDENTIAL, are reported too. Each watermark is written to {@code metadata.watermarks} with its
+ * pages and page coverage; with {@code excludeText} the lines holding watermark text are removed
+ * from the content.
+ *
+ * @since 4.0.0
+ */
+public final class WatermarkConfig {
+  private final boolean excludeText;
+  private final List<String> stampWords;
+
+  private WatermarkConfig(Builder builder) {
+    this.excludeText = builder.excludeText;
+    this.stampWords = Collections.unmodifiableList(new ArrayList<>(builder.stampWords));
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  public boolean isExcludeText() {
+    return excludeText;
+  }
+
+  public List<String> getStampWords() {
+    return stampWords;
+  }
+
+  public Map<String, Object> toMap() {
+    Map<String, Object> map = new HashMap<>();
+    map.put("exclude_text", excludeText);
+    map.put("stamp_words", stampWords);
+    return map;
+  }
+
+  public static final class Builder {
+    private boolean excludeText = false;
+    private List<String> stampWords = new ArrayList<>();
+
+    private Builder() {
+    }
+
+    public Builder excludeText(boolean excludeText) {
+      this.excludeText = excludeText;
+      return this;
+    }
+
+    public Builder stampWords(List<String> stampWords) {
+      this.stampWords = stampWords != null ? new ArrayList<>(stampWords) : new ArrayList<>();
+      return this;
+    }
+
+    public WatermarkConfig build() {
+      return new WatermarkConfig(this);
+    }
+  }
+
+  static WatermarkConfig fromMap(Map<String, Object> map) {
+    if (map == null) {
+      return null;
+    }
+    Builder builder = builder();
+    if (map.get("exclude_text") instanceof Boolean) {
+      builder.excludeText((Boolean) map.get("exclude_text"));
+    }
+    Object stampWordsValue = map.get("stamp_words");
+    if (stampWordsValue instanceof Iterable) {
+      @SuppressWarnings("unchecked")
+      Iterable<Object> iterable = (Iterable<Object>) stampWordsValue;
+      List<String> stampWords = new ArrayList<>();
+      for (Object entry : iterable) {
+        if (entry instanceof String) {
+          stampWords.add((String) entry);
+        }
+      }
+      builder.stampWords(stampWords);
+    }
+    return builder.build();
+  }
+}
@@ -43,6 +43,10 @@ from typing import TYPE_CHECKING, Any
 from kreuzberg import _setup_lib_path  # noqa: F401
 from kreuzberg._internal_bindings import (
     ChunkingConfig,
+    ClassificationConfig,
+    CleanupConfig,
+    CsvConfig,
+    DocumentTypeRule,
     EmbeddingConfig,
     EmbeddingModelType,
     EmbeddingPreset,
@@ -51,16 +55,19 @@ from kreuzberg._internal_bindings import (
     ExtractionResult,
     ImageExtractionConfig,
     ImagePreprocessingConfig,
+    KeyValueExtractionConfig,
     KeywordAlgorithm,
     KeywordConfig,
     LanguageDetectionConfig,
     OcrConfig,
+    OcrVocabularyConfig,
     PageConfig,
     PdfConfig,
     PostProcessorConfig,
     RakeParams,
     TesseractConfig,
     TokenReductionConfig,
+    WatermarkConfig,
     YakeParams,
     clear_document_extractors,
     clear_ocr_backends,
@@ -137,6 +144,9 @@ from kreuzberg._internal_bindings import (
 from kreuzberg._internal_bindings import (
     get_last_error_code as _get_last_error_code_impl,
 )
+from kreuzberg._internal_bindings import (
+    read_image_blob as _read_image_blob_impl,
+)
 from kreuzberg._internal_bindings import (
     register_ocr_backend as _register_ocr_backend_impl,
 )
@@ -149,6 +159,7 @@ from kreuzberg._internal_bindings import (
 from kreuzberg.exceptions import (
     CacheError,
     ErrorCode,
+    ExtractionTimeoutError,
     ImageProcessingError,
     KreuzbergError,
     MissingDependencyError,
@@ -159,7 +170,7 @@ from kreuzberg.exceptions import (
     ValidationError,
 )
 from kreuzberg.postprocessors.protocol import PostProcessorProtocol
-from kreuzberg.types import Chunk, ChunkMetadata, ExtractedImage, Metadata
+from kreuzberg.types import Chunk, ChunkMetadata, ExtractedImage, ImageBlobRef, Metadata
 
 if TYPE_CHECKING:
     from pathlib import Path
@@ -174,6 +185,10 @@ __all__ = [
     "Chunk",
     "ChunkMetadata",
     "ChunkingConfig",
+    "ClassificationConfig",
+    "CleanupConfig",
+    "CsvConfig",
+    "DocumentTypeRule",
     "EmbeddingConfig",
     "EmbeddingModelType",
     "EmbeddingPreset",
@@ -182,9 +197,12 @@ __all__ = [
     "ExtractedTable",
     "ExtractionConfig",
     "ExtractionResult",
+    "ExtractionTimeoutError",
+    "ImageBlobRef",
     "ImageExtractionConfig",
     "ImagePreprocessingConfig",
     "ImageProcessingError",
+    "KeyValueExtractionConfig",
     "KeywordAlgorithm",
     "KeywordConfig",
     "KreuzbergError",
@@ -193,6 +211,7 @@ __all__ = [
     "MissingDependencyError",
     "OCRError",
     "OcrConfig",
+    "OcrVocabularyConfig",
     "PageConfig",
     "PanicContext",
     "ParsingError",
@@ -204,6 +223,7 @@ __all__ = [
     "TesseractConfig",
     "TokenReductionConfig",
     "ValidationError",
+    "WatermarkConfig",
     "YakeParams",
     "__version__",
     "batch_extract_bytes",
@@ -239,6 +259,7 @@ __all__ = [
     "list_ocr_backends",
     "list_post_processors",
     "list_validators",
+    "read_image_data",
     "register_ocr_backend",
     "register_post_processor",
     "register_validator",
@@ -624,6 +645,34 @@ def detect_mime_type_from_path(path: str | Path) -> str:
     return _detect_mime_type_from_path_impl(str(path))
 
 
+def read_image_data(image: ExtractedImage) -> bytes:
+    """Return the bytes of an extracted image.
+
+    With ``ImageExtractionConfig(blob_store_dir=...)`` images carry a ``blob`` reference
+    and empty ``data``; the bytes are then read from the blob store and verified.
+
+    Args:
+        image: Extracted image from ``ExtractionResult.images``
+
+    Returns:
+        Image data
+
+    Raises:
+        OSError: If the blob file cannot be read
+        CacheError: If the blob no longer matches its reference
+
+    Example:
+        >>> from kreuzberg import ExtractionConfig, ImageExtractionConfig, extract_file_sync, read_image_data
+        >>> config = ExtractionConfig(images=ImageExtractionConfig(blob_store_dir="/tmp/kreuzberg-images"))
+        >>> result = extract_file_sync("document.pdf", config=config)
+        >>> data = read_image_data(result.images[0])
+    """
+    blob = image.get("blob")
+    if blob is None or image.get("data"):
+        return image.get("data", b"")
+    return _read_image_blob_impl(blob["hash"], blob["path"], blob["size"])
+
+
 def register_ocr_backend(backend: Any) -> None:
     """Register a Python OCR backend with the Rust core.
 
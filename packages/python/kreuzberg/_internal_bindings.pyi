@@ -4,6 +4,10 @@ from typing import Any, Literal, Protocol, TypedDict, overload
 
 __all__ = [
     "ChunkingConfig",
+    "ClassificationConfig",
+    "CleanupConfig",
+    "CsvConfig",
+    "DocumentTypeRule",
     "EmbeddingConfig",
     "EmbeddingModelType",
     "EmbeddingPreset",
@@ -12,12 +16,14 @@ __all__ = [
     "ExtractionResult",
     "ImageExtractionConfig",
     "ImagePreprocessingConfig",
+    "KeyValueExtractionConfig",
     "KeywordAlgorithm",
     "KeywordConfig",
     "LanguageDetectionConfig",
     "OcrBackendProtocol",
     "OcrConfig",
     "OcrResult",
+    "OcrVocabularyConfig",
     "PageConfig",
     "PageContent",
     "PdfConfig",
@@ -27,6 +33,7 @@ __all__ = [
     "TesseractConfig",
     "TokenReductionConfig",
     "ValidatorProtocol",
+    "WatermarkConfig",
     "YakeParams",
     "batch_extract_bytes",
     "batch_extract_bytes_sync",
@@ -61,6 +68,7 @@ __all__ = [
     "list_ocr_backends",
     "list_post_processors",
     "list_validators",
+    "read_image_blob",
     "register_ocr_backend",
     "register_post_processor",
     "register_validator",
@@ -156,9 +164,36 @@ class ExtractionConfig:
             configuration for identifying the language(s) in documents.
             None = no language detection. Default: None
 
+        classification (ClassificationConfig | None): Document type classification
+            written to metadata["document_type"] as a label, a confidence and the
+            confidence of every type. None = no classification. Default: None
+
+        kv_extraction (KeyValueExtractionConfig | None): Extraction of label/value
+            pairs such as "Invoice number: 2024-117" into metadata["key_values"].
+            None = no key-value extraction. Default: None
+
+        cleanup (CleanupConfig | None): Removal of running headers and footers,
+            page numbers and watermarks repeated across pages, before post-processing
+            and chunking. None = content kept as extracted. Default: None
+
+        watermarks (WatermarkConfig | None): Watermark and stamp detection written to
+            metadata["watermarks"]. None = no watermark detection. Default: None
+
         pages (PageConfig | None): Page extraction configuration for tracking and
             extracting page boundaries. None = no page tracking. Default: None
 
+        csv_options (CsvConfig | None): CSV/TSV parsing options such as a delimiter
+            override and row limit. None = auto-detect the dialect. Default: None
+
+        incremental (bool): Re-extract only the new and changed pages of a PDF that
+            was extracted before, reusing the stored pages under .kreuzberg/incremental.
+            Default: False
+
+        overrides (dict[str, dict[str, Any]] | None): Partial configurations merged over
+            this one for documents of matching MIME types, keyed by "type/subtype",
+            "type/*" or "*/*", e.g. {"image/*": {"ocr": {"language": "deu"}}}.
+            More specific patterns win. Default: None
+
         keywords (KeywordConfig | None): Keyword extraction configuration for
             identifying important terms and phrases in content.
             None = no keyword extraction. Default: None
@@ -196,11 +231,18 @@ class ExtractionConfig:
     pdf_options: PdfConfig | None
     token_reduction: TokenReductionConfig | None
     language_detection: LanguageDetectionConfig | None
+    classification: ClassificationConfig | None
+    kv_extraction: KeyValueExtractionConfig | None
+    cleanup: CleanupConfig | None
+    watermarks: WatermarkConfig | None
     keywords: KeywordConfig | None
     postprocessor: PostProcessorConfig | None
     max_concurrent_extractions: int | None
     html_options: dict[str, Any] | None
     pages: PageConfig | None
+    csv_options: CsvConfig | None
+    incremental: bool
+    overrides: dict[str, dict[str, Any]] | None
 
     def __init__(
         self,
@@ -214,11 +256,18 @@ class ExtractionConfig:
         pdf_options: PdfConfig | None = None,
         token_reduction: TokenReductionConfig | None = None,
         language_detection: LanguageDetectionConfig | None = None,
+        classification: ClassificationConfig | None = None,
+        kv_extraction: KeyValueExtractionConfig | None = None,
+        cleanup: CleanupConfig | None = None,
+        watermarks: WatermarkConfig | None = None,
         keywords: KeywordConfig | None = None,
         postprocessor: PostProcessorConfig | None = None,
         max_concurrent_extractions: int | None = None,
         html_options: dict[str, Any] | None = None,
         pages: PageConfig | None = None,
+        csv_options: CsvConfig | None = None,
+        incremental: bool | None = None,
+        overrides: dict[str, dict[str, Any]] | None = None,
     ) -> None: ...
     @staticmethod
     def from_file(path: str | Path) -> ExtractionConfig: ...
@@ -238,6 +287,9 @@ class OcrConfig:
             for fine-tuning OCR behavior. Only used when backend="tesseract".
             Default: None
 
+        vocabulary (OcrVocabularyConfig | None): Domain word list added to Tesseract's
+            user words and used to correct near-miss OCR words. Default: None
+
     Example:
         Using Tesseract with German language:
             >>> from kreuzberg import OcrConfig
@@ -253,6 +305,7 @@ class OcrConfig:
     backend: str
     language: str
     tesseract_config: TesseractConfig | None
+    vocabulary: OcrVocabularyConfig | None
 
     def __init__(
         self,
@@ -260,6 +313,45 @@ class OcrConfig:
         backend: str | None = None,
         language: str | None = None,
         tesseract_config: TesseractConfig | None = None,
+        vocabulary: OcrVocabularyConfig | None = None,
+    ) -> None: ...
+
+class OcrVocabularyConfig:
+    """Domain vocabulary for OCR, such as drug names or part numbers.
+
+    The words are added to Tesseract's user words before recognition. Afterwards, an OCR
+    word that is not in the vocabulary but within `max_distance` edits of exactly one
+    vocabulary word is replaced with it. Words without letters and words shorter than
+    `min_word_length` are never changed.
+
+    Attributes:
+        words (list[str]): Inline vocabulary words. Default: []
+        words_file (str | None): Path to a word list, one entry per line. Lines starting
+            with "#" are comments. Default: None
+        correct (bool): Correct OCR output against the vocabulary. Default: True
+        max_distance (int): Maximum edit distance for a correction. Default: 1
+        min_word_length (int): Minimum length of an OCR word to correct. Default: 4
+
+    Example:
+        >>> from kreuzberg import OcrConfig, OcrVocabularyConfig
+        >>> vocabulary = OcrVocabularyConfig(words_file="formulary.txt", words=["XJ-4410"])
+        >>> config = OcrConfig(vocabulary=vocabulary)
+    """
+
+    words: list[str]
+    words_file: str | None
+    correct: bool
+    max_distance: int
+    min_word_length: int
+
+    def __init__(
+        self,
+        *,
+        words: list[str] | None = None,
+        words_file: str | None = None,
+        correct: bool | None = None,
+        max_distance: int | None = None,
+        min_word_length: int | None = None,
     ) -> None: ...
 
 class EmbeddingModelType:
@@ -485,6 +577,23 @@ class ImageExtractionConfig:
         max_dpi (int): Maximum DPI threshold. Images with higher DPI are downscaled.
             Default: 600
 
+        strip_gps (bool): Omit GPS coordinates from the EXIF metadata of image inputs,
+            for privacy-sensitive deployments. Default: False
+
+        perceptual_hash (bool): Compute a perceptual hash for each extracted image
+            and store it in the image's ``perceptual_hash`` field. Default: False
+
+        deduplicate_images (bool): Drop images whose perceptual hash is within
+            ``duplicate_hash_distance`` bits of an earlier image (repeated logos,
+            watermarks). Implies perceptual_hash. Default: False
+
+        duplicate_hash_distance (int): Maximum number of differing hash bits for two
+            images to count as duplicates. Default: 5
+
+        blob_store_dir (str | None): Write image bytes to this content-addressed
+            directory instead of inlining them. Images then carry a ``blob`` reference
+            and empty ``data``; read the bytes with ``read_image_blob``. Default: None
+
     Example:
         Basic image extraction:
             >>> from kreuzberg import ExtractionConfig, ImageExtractionConfig
@@ -504,6 +613,11 @@ class ImageExtractionConfig:
     auto_adjust_dpi: bool
     min_dpi: int
     max_dpi: int
+    strip_gps: bool
+    perceptual_hash: bool
+    deduplicate_images: bool
+    duplicate_hash_distance: int
+    blob_store_dir: str | None
 
     def __init__(
         self,
@@ -514,6 +628,11 @@ class ImageExtractionConfig:
         auto_adjust_dpi: bool | None = None,
         min_dpi: int | None = None,
         max_dpi: int | None = None,
+        strip_gps: bool | None = None,
+        perceptual_hash: bool | None = None,
+        deduplicate_images: bool | None = None,
+        duplicate_hash_distance: int | None = None,
+        blob_store_dir: str | None = None,
     ) -> None: ...
 
 class PdfConfig:
@@ -582,6 +701,31 @@ class PageConfig:
         marker_format: str | None = None,
     ) -> None: ...
 
+class CsvConfig:
+    """CSV/TSV parsing configuration.
+
+    The delimiter, quoting and header row are detected automatically. These options
+    override detection and bound the amount of data parsed into the table.
+
+    Attributes:
+        delimiter (str | None): Single-character field delimiter. None = auto-detect. Default: None
+        max_rows (int | None): Maximum number of data rows to parse. None = unlimited. Default: None
+
+    Example:
+        >>> from kreuzberg import CsvConfig, ExtractionConfig
+        >>> config = ExtractionConfig(csv_options=CsvConfig(delimiter=";", max_rows=1000))
+    """
+
+    delimiter: str | None
+    max_rows: int | None
+
+    def __init__(
+        self,
+        *,
+        delimiter: str | None = None,
+        max_rows: int | None = None,
+    ) -> None: ...
+
 class KeywordAlgorithm:
     Yake: KeywordAlgorithm
     Rake: KeywordAlgorithm
@@ -727,6 +871,184 @@ class LanguageDetectionConfig:
         detect_multiple: bool | None = None,
     ) -> None: ...
 
+class ClassificationConfig:
+    """Configuration for labelling documents with their type.
+
+    The built-in types are "invoice", "contract", "report" and "letter". Each type is
+    scored by its keywords, its title keywords in the first lines and, for built-in
+    types, layout features such as amounts or a salutation. The best type is written to
+    metadata["document_type"], or "unknown" below min_confidence.
+
+    Attributes:
+        builtin_types (bool): Include the built-in document types. Default: True
+
+        types (list[DocumentTypeRule]): Additional document types. A type with a
+            built-in label adds to its keywords. Default: []
+
+        min_confidence (float): Confidence (0.0-1.0) below which the document is
+            labelled "unknown". Default: 0.3
+
+    Example:
+        Route documents by type:
+            >>> from kreuzberg import ClassificationConfig, ExtractionConfig, extract_file_sync
+            >>> config = ExtractionConfig(classification=ClassificationConfig())
+            >>> result = extract_file_sync("scan.pdf", config=config)
+            >>> result.metadata["document_type"]["label"]
+            'invoice'
+    """
+
+    builtin_types: bool
+    types: list[DocumentTypeRule]
+    min_confidence: float
+
+    def __init__(
+        self,
+        *,
+        builtin_types: bool | None = None,
+        types: list[DocumentTypeRule] | None = None,
+        min_confidence: float | None = None,
+    ) -> None: ...
+
+class KeyValueExtractionConfig:
+    """Configuration for extracting label/value pairs.
+
+    A label is a short phrase followed by a colon, or a known label such as "Total"
+    or "Invoice number" followed by a value containing a digit. The value is the rest
+    of the line, the next column or, when the label ends its line, the line below.
+    Pairs are written to metadata["key_values"]; for PDFs with a text layer each pair
+    carries its page and the bounding boxes of the label and the value.
+
+    Attributes:
+        builtin_labels (bool): Recognise common invoice, form and receipt labels
+            without a colon. Default: True
+
+        labels (list[str]): Additional labels recognised without a colon, matched
+            case-insensitively. Default: []
+
+        max_label_words (int): Maximum number of words in a label. Default: 5
+
+    Example:
+        Read invoice fields:
+            >>> from kreuzberg import ExtractionConfig, KeyValueExtractionConfig, extract_file_sync
+            >>> config = ExtractionConfig(kv_extraction=KeyValueExtractionConfig(labels=["PO number"]))
+            >>> result = extract_file_sync("invoice.pdf", config=config)
+            >>> result.metadata["key_values"][0]["key"]
+            'Invoice number'
+    """
+
+    builtin_labels: bool
+    labels: list[str]
+    max_label_words: int
+
+    def __init__(
+        self,
+        *,
+        builtin_labels: bool | None = None,
+        labels: list[str] | None = None,
+        max_label_words: int | None = None,
+    ) -> None: ...
+
+class CleanupConfig:
+    """Configuration for removing text repeated across pages.
+
+    Lines among the first and last three lines of each page that recur on most
+    pages, such as running headers and footers, page numbers and watermarks, are
+    removed from the content, the per-page content and the page boundaries. Lines
+    are compared case-insensitively with digits masked, so "Page 3 of 12" matches
+    "Page 4 of 12". Needs page boundaries, so PDFs require a PageConfig.
+
+    Attributes:
+        strip_repeated (bool): Detect lines repeated across most pages. Default: True
+
+        tag_instead (bool): Keep repeated lines in the content and record each
+            occurrence with its page and byte offsets in metadata["repeated_text"].
+            Default: False
+
+    Example:
+        Remove running headers before chunking:
+            >>> from kreuzberg import ChunkingConfig, CleanupConfig, ExtractionConfig, PageConfig
+            >>> config = ExtractionConfig(
+            ...     cleanup=CleanupConfig(),
+            ...     pages=PageConfig(),
+            ...     chunking=ChunkingConfig(max_chars=1000),
+            ... )
+    """
+
+    strip_repeated: bool
+    tag_instead: bool
+
+    def __init__(
+        self,
+        *,
+        strip_repeated: bool | None = None,
+        tag_instead: bool | None = None,
+    ) -> None: ...
+
+class WatermarkConfig:
+    """Configuration for watermark and stamp detection.
+
+    In PDFs, text drawn diagonally across the page and images placed on most pages
+    are reported. In every format, stamp words written in capitals on a line of
+    their own, such as DRAFT, CONFIDENTIAL or PRIVILEGED, are reported too, also
+    when spaced out as "D R A F T". Each watermark is written to
+    metadata["watermarks"] with its pages and page_coverage, the share of pages it
+    appears on.
+
+    Attributes:
+        exclude_text (bool): Remove the lines holding watermark text from the
+            content, the per-page content and the page boundaries. Default: False
+
+        stamp_words (list[str]): Stamp words recognised in addition to the built-in
+            ones, matched case-insensitively. Default: []
+
+    Example:
+        Drop DRAFT stamps from the text:
+            >>> from kreuzberg import ExtractionConfig, WatermarkConfig
+            >>> config = ExtractionConfig(watermarks=WatermarkConfig(exclude_text=True))
+    """
+
+    exclude_text: bool
+    stamp_words: list[str]
+
+    def __init__(
+        self,
+        *,
+        exclude_text: bool | None = None,
+        stamp_words: list[str] | None = None,
+    ) -> None: ...
+
+class DocumentTypeRule:
+    """Document type recognised by its keywords.
+
+    Keywords are words or phrases matched case-insensitively on word boundaries.
+
+    Attributes:
+        label (str): Label written to metadata["document_type"]["label"].
+
+        keywords (list[str]): Keywords typical of the type anywhere in the document.
+            Default: []
+
+        title_keywords (list[str]): Keywords that identify the type when they appear
+            in the first lines, e.g. a title. Default: []
+
+    Example:
+        >>> from kreuzberg import ClassificationConfig, DocumentTypeRule
+        >>> rule = DocumentTypeRule("resume", keywords=["work experience", "skills"], title_keywords=["curriculum vitae"])
+        >>> config = ClassificationConfig(types=[rule])
+    """
+
+    label: str
+    keywords: list[str]
+    title_keywords: list[str]
+
+    def __init__(
+        self,
+        label: str,
+        *,
+        keywords: list[str] | None = None,
+        title_keywords: list[str] | None = None,
+    ) -> None: ...
+
 class PostProcessorConfig:
     """Configuration for post-processors in the extraction pipeline.
 
@@ -813,6 +1135,11 @@ class ImagePreprocessingConfig:
         invert_colors (bool): Invert colors (white text on black background).
             Useful for certain document types. Default: False
 
+        auto_tune (bool): Choose binarization, denoising, contrast enhancement and
+            upscaling per page from image statistics. The chosen parameters are
+            recorded under "preprocessing" in the OCR metadata and in each
+            "ocr_pages" entry. Default: False
+
     Example:
         Basic preprocessing for OCR:
             >>> from kreuzberg import TesseractConfig, ImagePreprocessingConfig
@@ -833,6 +1160,7 @@ class ImagePreprocessingConfig:
     contrast_enhance: bool
     binarization_method: str
     invert_colors: bool
+    auto_tune: bool
 
     def __init__(
         self,
@@ -844,6 +1172,7 @@ class ImagePreprocessingConfig:
         contrast_enhance: bool | None = None,
         binarization_method: str | None = None,
         invert_colors: bool | None = None,
+        auto_tune: bool | None = None,
     ) -> None: ...
 
 class TesseractConfig:
@@ -1050,6 +1379,7 @@ class ImageMetadata(TypedDict, total=False):
     height: int
     format: str
     exif: dict[str, str]
+    exif_details: dict[str, Any]
 
 class XmlMetadata(TypedDict, total=False):
     element_count: int
@@ -1108,6 +1438,107 @@ class ImagePreprocessingMetadata(TypedDict, total=False):
     skipped_resize: bool
     resize_error: str
 
+class PageStatisticsMetadata(TypedDict, total=False):
+    contrast: float
+    speckle_ratio: float
+    illumination_variation: float
+    skew_degrees: float
+    line_height: float | None
+
+class PreprocessingDecisionMetadata(TypedDict, total=False):
+    binarization_method: Literal["otsu", "adaptive", "sauvola"]
+    denoise: bool
+    contrast_enhance: bool
+    deskew_degrees: float
+    estimated_dpi: int | None
+    target_dpi: int
+    scale_factor: float
+    statistics: PageStatisticsMetadata
+
+class OcrPageMetadata(TypedDict, total=False):
+    page: int
+    backend: str
+    confidence: float | None
+    fallback_attempted: bool
+    languages: list[str]
+    preprocessing: PreprocessingDecisionMetadata
+
+class OutlineEntry(TypedDict, total=False):
+    """A heading in the document outline, with nested sub-headings."""
+
+    level: int
+    title: str
+    page: int
+    byte_offset: int
+    children: list[OutlineEntry]
+
+class KeyValueBoundingBox(TypedDict):
+    """Rectangle on a page, in PDF points with the origin at the top-left corner."""
+
+    x: float
+    y: float
+    width: float
+    height: float
+
+class KeyValuePair(TypedDict, total=False):
+    """A label and its value, e.g. ``Invoice number`` and ``2024-117``."""
+
+    key: str
+    value: str
+    page: int
+    key_bbox: KeyValueBoundingBox
+    value_bbox: KeyValueBoundingBox
+
+class Watermark(TypedDict, total=False):
+    """A watermark or stamp with the pages it appears on.
+
+    ``kind`` is ``"text"`` for diagonal text and stamp words, ``"image"`` for an image
+    repeated on most pages; ``rotation`` is set for diagonal text, in degrees.
+    """
+
+    kind: Literal["text", "image"]
+    text: str | None
+    pages: list[int]
+    page_coverage: float
+    rotation: float | None
+
+class XmpMetadata(TypedDict, total=False):
+    """Metadata parsed from an embedded XMP packet (dc, xmp, pdf and photoshop namespaces)."""
+
+    title: str
+    creator: list[str]
+    description: str
+    subject: list[str]
+    rights: str
+    publisher: list[str]
+    contributor: list[str]
+    language: list[str]
+    date: list[str]
+    identifier: str
+    format: str
+    create_date: str
+    modify_date: str
+    metadata_date: str
+    creator_tool: str
+    rating: str
+    label: str
+    producer: str
+    keywords: str
+    pdf_version: str
+    trapped: str
+    headline: str
+    credit: str
+    source: str
+    city: str
+    state: str
+    country: str
+    authors_position: str
+    caption_writer: str
+    instructions: str
+    date_created: str
+    category: str
+
+
 class ErrorMetadata(TypedDict, total=False):
     error_type: str
     message: str
@@ -1155,6 +1586,7 @@ class Metadata(TypedDict, total=False):
     compressed_size: int
 
     exif: dict[str, str]
+    exif_details: dict[str, Any]
 
     element_count: int
     unique_elements: list[str]
@@ -1191,6 +1623,12 @@ class Metadata(TypedDict, total=False):
     table_cols: int
 
     image_preprocessing: ImagePreprocessingMetadata
+    preprocessing: PreprocessingDecisionMetadata
+    ocr_pages: list[OcrPageMetadata]
+    xmp: XmpMetadata
+    outline: list[OutlineEntry]
+    key_values: list[KeyValuePair]
+    watermarks: list[Watermark]
     json_schema: dict[str, Any]
     error: ErrorMetadata
 
@@ -1206,6 +1644,13 @@ class ExtractedImage(TypedDict, total=False):
     is_mask: bool
     description: str
     ocr_result: ExtractionResult
+    perceptual_hash: str
+    blob: ImageBlobRef
+
+class ImageBlobRef(TypedDict):
+    hash: str
+    path: str
+    size: int
 
 class Chunk(TypedDict, total=False):
     content: str
@@ -1236,6 +1681,7 @@ class ExtractedTable:
     cells: list[list[str]]
     markdown: str
     page_number: int
+    column_types: list[Literal["string", "number", "date"]] | None
 
 # Extraction Functions
 
@@ -1310,6 +1756,7 @@ def clear_document_extractors() -> None: ...
 def clear_ocr_backends() -> None: ...
 def detect_mime_type_from_bytes(data: bytes) -> str: ...
 def detect_mime_type_from_path(path: str | Path) -> str: ...
+def read_image_blob(hash: str, path: str, size: int) -> bytes: ...
 def validate_mime_type(mime_type: str) -> str: ...
 def get_extensions_for_mime(mime_type: str) -> list[str]: ...
 def list_document_extractors() -> list[str]: ...
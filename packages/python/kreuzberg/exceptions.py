@@ -252,3 +252,15 @@ class PluginError(KreuzbergError):
         >>> raise PluginError("Plugin initialization failed", context={"plugin_name": "pdf-extractor"})
 
     """
+
+
+class ExtractionTimeoutError(KreuzbergError):
+    """Raised when an extraction stage exceeds its time budget.
+
+    Budgets are set with ``ExtractionConfig.stage_timeouts``; the message names the stage
+    (``extraction``, ``ocr`` or ``post_processing``) and the budget that elapsed.
+
+    Example:
+        >>> raise ExtractionTimeoutError("Timeout in ocr stage after 30000 ms")
+
+    """
@@ -47,6 +47,7 @@ class ImageMetadata(TypedDict, total=False):
     height: int
     format: str
     exif: dict[str, str]
+    exif_details: dict[str, Any]
 
 
 class XmlMetadata(TypedDict, total=False):
@@ -145,6 +146,120 @@ class ImagePreprocessingMetadata(TypedDict, total=False):
     resize_error: str | None
 
 
+class PageStatisticsMetadata(TypedDict, total=False):
+    """Page image statistics measured by auto-tuned preprocessing."""
+
+    contrast: float
+    speckle_ratio: float
+    illumination_variation: float
+    skew_degrees: float
+    line_height: float | None
+
+
+class PreprocessingDecisionMetadata(TypedDict, total=False):
+    """Preprocessing chosen for a page when ``ImagePreprocessingConfig.auto_tune`` is enabled."""
+
+    binarization_method: Literal["otsu", "adaptive", "sauvola"]
+    denoise: bool
+    contrast_enhance: bool
+    deskew_degrees: float
+    estimated_dpi: int | None
+    target_dpi: int
+    scale_factor: float
+    statistics: PageStatisticsMetadata
+
+
+class OcrPageMetadata(TypedDict, total=False):
+    """OCR backend, languages and preprocessing chosen for one page."""
+
+    page: int
+    backend: str
+    confidence: float | None
+    fallback_attempted: bool
+    languages: list[str]
+    preprocessing: PreprocessingDecisionMetadata
+
+
+class OutlineEntry(TypedDict, total=False):
+    """A heading in the document outline, with nested sub-headings."""
+
+    level: int
+    title: str
+    page: int
+    byte_offset: int
+    children: list[OutlineEntry]
+
+
+class KeyValueBoundingBox(TypedDict):
+    """Rectangle on a page, in PDF points with the origin at the top-left corner."""
+
+    x: float
+    y: float
+    width: float
+    height: float
+
+
+class KeyValuePair(TypedDict, total=False):
+    """A label and its value, e.g. ``Invoice number`` and ``2024-117``."""
+
+    key: str
+    value: str
+    page: int
+    key_bbox: KeyValueBoundingBox
+    value_bbox: KeyValueBoundingBox
+
+
+class Watermark(TypedDict, total=False):
+    """A watermark or stamp with the pages it appears on.
+
+    ``kind`` is ``"text"`` for diagonal text and stamp words, ``"image"`` for an image
+    repeated on most pages; ``rotation`` is set for diagonal text, in degrees.
+    """
+
+    kind: Literal["text", "image"]
+    text: str | None
+    pages: list[int]
+    page_coverage: float
+    rotation: float | None
+
+
+class XmpMetadata(TypedDict, total=False):
+    """Metadata parsed from an embedded XMP packet (dc, xmp, pdf and photoshop namespaces)."""
+
+    title: str
+    creator: list[str]
+    description: str
+    subject: list[str]
+    rights: str
+    publisher: list[str]
+    contributor: list[str]
+    language: list[str]
+    date: list[str]
+    identifier: str
+    format: str
+    create_date: str
+    modify_date: str
+    metadata_date: str
+    creator_tool: str
+    rating: str
+    label: str
+    producer: str
+    keywords: str
+    pdf_version: str
+    trapped: str
+    headline: str
+    credit: str
+    source: str
+    city: str
+    state: str
+    country: str
+    authors_position: str
+    caption_writer: str
+    instructions: str
+    date_created: str
+    category: str
+
+
 class ErrorMetadata(TypedDict, total=False):
     """Error metadata for batch operations."""
 
@@ -180,6 +295,13 @@ class PageConfig(TypedDict, total=False):
     marker_format: str
 
 
+class CsvConfig(TypedDict, total=False):
+    """CSV/TSV parsing configuration."""
+
+    delimiter: str | None
+    max_rows: int | None
+
+
 class PageInfo(TypedDict, total=False):
     """Metadata for an individual page/slide/sheet.
 
@@ -195,13 +317,14 @@ class PageInfo(TypedDict, total=False):
     hidden: bool | None
 
 
-PageUnitType = Literal["page", "slide", "sheet"]
+PageUnitType = Literal["page", "slide", "sheet", "chapter"]
 """Type of paginated unit in a document.
 
 Distinguishes between different types of "pages":
 - "page": Standard document pages (PDF, DOCX, images)
 - "slide": Presentation slides (PPTX, ODP)
 - "sheet": Spreadsheet sheets (XLSX, ODS)
+- "chapter": Ebook chapters (EPUB, MOBI)
 """
 
 
@@ -253,6 +376,16 @@ class ExtractedImage(TypedDict, total=False):
     is_mask: bool
     description: str | None
     ocr_result: ExtractionResult | None
+    perceptual_hash: str | None
+    blob: ImageBlobRef | None
+
+
+class ImageBlobRef(TypedDict):
+    """Reference to image bytes kept in the content-addressed blob store."""
+
+    hash: str
+    path: str
+    size: int
 
 
 class Metadata(TypedDict, total=False):
@@ -298,7 +431,7 @@ class Metadata(TypedDict, total=False):
             format, file_count, file_list, total_size, compressed_size
 
         Image fields (when format_type == "image"):
-            exif
+            exif, exif_details
 
         XML fields (when format_type == "xml"):
             element_count, unique_elements
@@ -317,6 +450,12 @@ class Metadata(TypedDict, total=False):
 
     Processing metadata:
         image_preprocessing: Image preprocessing metadata dict
+        preprocessing: Auto-tuned preprocessing of an OCR'd image
+        ocr_pages: Per-page OCR backend, language and preprocessing choices
+        xmp: XMP packet metadata dict (PDF and image formats)
+        outline: Heading tree (PDF bookmarks, DOCX heading styles, HTML h1-h6)
+        key_values: Label/value pairs found when ``kv_extraction`` is configured
+        watermarks: Watermarks and stamps found when ``watermarks`` is configured
 
     Structured data:
         json_schema: JSON schema dict for structured extraction
@@ -380,6 +519,7 @@ class Metadata(TypedDict, total=False):
     compressed_size: int
 
     exif: dict[str, str]
+    exif_details: dict[str, Any]
 
     element_count: int
     unique_elements: list[str]
@@ -416,16 +556,27 @@ class Metadata(TypedDict, total=False):
     table_cols: int
 
     image_preprocessing: ImagePreprocessingMetadata
+    preprocessing: PreprocessingDecisionMetadata
+    ocr_pages: list[OcrPageMetadata]
+    xmp: XmpMetadata
+    outline: list[OutlineEntry]
+    key_values: list[KeyValuePair]
+    watermarks: list[Watermark]
     json_schema: dict[str, Any]
     error: ErrorMetadata
 
 
+CellType = Literal["string", "number", "date"]
+"""Inferred type of a table column."""
+
+
 class Table(TypedDict):
     """Extracted table structure."""
 
     cells: list[list[str]]
     markdown: str
     page_number: int
+    column_types: list[CellType] | None
 
 
 class ExtractionResult(TypedDict):
@@ -454,8 +605,10 @@ class ExtractionResult(TypedDict):
 
 __all__ = [
     "ArchiveMetadata",
+    "CellType",
     "Chunk",
     "ChunkMetadata",
+    "CsvConfig",
     "EmailMetadata",
     "ErrorMetadata",
     "ExcelMetadata",
@@ -464,17 +617,25 @@ __all__ = [
     "HtmlMetadata",
     "ImageMetadata",
     "ImagePreprocessingMetadata",
+    "KeyValueBoundingBox",
+    "KeyValuePair",
     "Metadata",
     "OcrMetadata",
+    "OcrPageMetadata",
+    "OutlineEntry",
     "PageBoundary",
     "PageConfig",
     "PageContent",
     "PageInfo",
     "PageStructure",
+    "PageStatisticsMetadata",
     "PageUnitType",
     "PdfMetadata",
     "PptxMetadata",
+    "PreprocessingDecisionMetadata",
     "Table",
     "TextMetadata",
+    "Watermark",
     "XmlMetadata",
+    "XmpMetadata",
 ]
@@ -147,3 +147,27 @@ def test_from_file_auto_detects_yaml_extension() -> None:
 
     assert config is not None
     assert config.use_cache
+
+
+def test_from_file_toml_with_mime_overrides(tmp_path: Path) -> None:
+    config_path = tmp_path / "kreuzberg.toml"
+    config_path.write_text(
+        'force_ocr = true\n\n[overrides."application/pdf"]\nforce_ocr = false\n\n'
+        '[overrides."image/*"]\nocr.language = "deu"\n'
+    )
+
+    config = ExtractionConfig.from_file(str(config_path))
+
+    assert config.overrides == {
+        "application/pdf": {"force_ocr": False},
+        "image/*": {"ocr": {"language": "deu"}},
+    }
+
+
+def test_overrides_keyword_round_trips() -> None:
+    config = ExtractionConfig(overrides={"text/*": {"chunking": {"max_chars": 500}}})
+
+    assert config.overrides == {"text/*": {"chunking": {"max_chars": 500}}}
+
+    config.overrides = None
+    assert config.overrides is None
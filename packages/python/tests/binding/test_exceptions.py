@@ -4,6 +4,7 @@ import json
 
 from kreuzberg.exceptions import (
     CacheError,
+    ExtractionTimeoutError,
     ImageProcessingError,
     KreuzbergError,
     MissingDependencyError,
@@ -185,6 +186,15 @@ def test_plugin_error() -> None:
     assert "Plugin initialization failed" in str(error)
 
 
+def test_extraction_timeout_error() -> None:
+    from kreuzberg import ExtractionTimeoutError as ExtractionTimeoutErrorImport
+
+    error = ExtractionTimeoutError("Timeout in ocr stage after 30000 ms")
+    assert isinstance(error, KreuzbergError)
+    assert ExtractionTimeoutErrorImport is ExtractionTimeoutError
+    assert "Timeout in ocr stage" in str(error)
+
+
 def test_new_errors_importable_from_root() -> None:
     from kreuzberg import CacheError as CacheErrorImport
     from kreuzberg import ImageProcessingError as ImageProcessingErrorImport
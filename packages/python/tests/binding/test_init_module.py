@@ -55,6 +55,20 @@ def test_ocr_config_import_and_usage() -> None:
     assert config.language == "eng"
 
 
+def test_ocr_vocabulary_config_defaults() -> None:
+    """Test that OcrVocabularyConfig takes the core defaults and attaches to OcrConfig."""
+    vocabulary = kreuzberg.OcrVocabularyConfig(words=["Metformin", "XJ-4410"])
+    assert vocabulary.words == ["Metformin", "XJ-4410"]
+    assert vocabulary.words_file is None
+    assert vocabulary.correct is True
+    assert vocabulary.max_distance == 1
+    assert vocabulary.min_word_length == 4
+
+    config = kreuzberg.OcrConfig(vocabulary=vocabulary)
+    assert config.vocabulary is not None
+    assert config.vocabulary.words == ["Metformin", "XJ-4410"]
+
+
 def test_extraction_config_with_ocr() -> None:
     """Test that ExtractionConfig can accept ocr parameter."""
     ocr_config = kreuzberg.OcrConfig(backend="tesseract", language="eng")
@@ -10,15 +10,21 @@ use html_to_markdown_rs::options::{
     WhitespaceMode,
 };
 use kreuzberg::core::config::PageConfig;
+use kreuzberg::core::config_schema::{config_schema, validate_config_section};
+use kreuzberg::core::config_validation::validate_tesseract_variables;
+use kreuzberg::core::effective_config::EffectiveConfig;
+use kreuzberg::core::fields::FieldSelection;
 use kreuzberg::keywords::{
     KeywordAlgorithm as RustKeywordAlgorithm, KeywordConfig as RustKeywordConfig, RakeParams as RustRakeParams,
     YakeParams as RustYakeParams,
 };
+use kreuzberg::ocr::{configure_engine_pool, engine_pool_config};
 use kreuzberg::types::TesseractConfig as RustTesseractConfig;
 use kreuzberg::{
-    ChunkingConfig, EmbeddingConfig, ExtractionConfig, ExtractionResult as RustExtractionResult, ImageExtractionConfig,
-    ImagePreprocessingConfig, KreuzbergError, LanguageDetectionConfig, OcrConfig, PdfConfig, PostProcessorConfig,
-    TokenReductionConfig,
+    ChunkingConfig, ContentSource, CsvConfig, EmbeddingConfig, ExtractionConfig,
+    ExtractionResult as RustExtractionResult, ImageBlobRef, ImageExtractionConfig, ImageOutputFormat,
+    ImagePreprocessingConfig, KreuzbergError, LanguageDetectionConfig, NormalizationConfig, OcrConfig, PdfConfig,
+    PostProcessorConfig, PptxConfig, ResourceProfile, TextExtractionConfig, TokenReductionConfig, UnicodeNormalization,
 };
 use magnus::exception::ExceptionClass;
 use magnus::r_hash::ForEach;
@@ -26,8 +32,13 @@ use magnus::value::ReprValue;
 use magnus::{
     Error, IntoValue, RArray, RHash, RString, Ruby, Symbol, TryConvert, Value, function, scan_args::scan_args,
 };
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 /// Keeps Ruby values alive across plugin registrations by informing the GC.
 struct GcGuardedValue {
@@ -54,6 +65,237 @@ impl Drop for GcGuardedValue {
     }
 }
 
+/// Event emitted for each timed stage of an extraction call.
+const STAGE_EVENT: &str = "extraction.stage";
+
+/// Event emitted once per single-document extraction call.
+const DOCUMENT_EVENT: &str = "extraction.document";
+
+/// Event emitted once per batch extraction call.
+const BATCH_EVENT: &str = "extraction.batch";
+
+/// Host callback installed with `Kreuzberg.instrumentation=`.
+struct InstrumentationHook {
+    callback: GcGuardedValue,
+}
+
+// SAFETY: the hook is only invoked from Ruby threads holding the GVL.
+unsafe impl Send for InstrumentationHook {}
+unsafe impl Sync for InstrumentationHook {}
+
+/// Checked before taking any timings so extraction pays nothing when no hook is set.
+static INSTRUMENTATION_ENABLED: AtomicBool = AtomicBool::new(false);
+static INSTRUMENTATION_HOOK: Mutex<Option<InstrumentationHook>> = Mutex::new(None);
+
+/// Install or remove (with `nil`) the instrumentation callback.
+fn set_instrumentation(callback: Value) -> Result<(), Error> {
+    let hook = if callback.is_nil() {
+        None
+    } else {
+        if !callback.respond_to("call", true)? {
+            return Err(runtime_error(
+                "Instrumentation hook must be a Proc or respond to 'call'",
+            ));
+        }
+        Some(InstrumentationHook {
+            callback: GcGuardedValue::new(callback),
+        })
+    };
+
+    let mut slot = INSTRUMENTATION_HOOK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    INSTRUMENTATION_ENABLED.store(hook.is_some(), Ordering::Release);
+    *slot = hook;
+    Ok(())
+}
+
+/// Call the instrumentation hook with `(event, payload)`.
+///
+/// Exceptions raised by the hook are discarded: monitoring must never fail an extraction.
+fn emit_instrumentation_event(ruby: &Ruby, event: &str, payload: RHash) {
+    let callback = {
+        let slot = INSTRUMENTATION_HOOK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match slot.as_ref() {
+            Some(hook) => hook.callback.value(),
+            None => return,
+        }
+    };
+    let _ = callback.funcall::<_, _, Value>("call", (ruby.str_new(event), payload));
+}
+
+/// Wall-clock timings of one extraction call, collected only while a hook is installed.
+struct ExtractionTimer {
+    operation: &'static str,
+    state: Option<TimerState>,
+}
+
+struct TimerState {
+    started: Instant,
+    last_mark: Instant,
+    stages: Vec<(&'static str, Duration)>,
+    mime_type: Option<String>,
+    count: Option<usize>,
+}
+
+impl ExtractionTimer {
+    fn start(operation: &'static str) -> Self {
+        let state = INSTRUMENTATION_ENABLED.load(Ordering::Acquire).then(|| {
+            let now = Instant::now();
+            TimerState {
+                started: now,
+                last_mark: now,
+                stages: Vec::with_capacity(3),
+                mime_type: None,
+                count: None,
+            }
+        });
+        Self { operation, state }
+    }
+
+    /// Attribute the time since the previous mark to `stage`.
+    fn mark(&mut self, stage: &'static str) {
+        if let Some(state) = self.state.as_mut() {
+            let now = Instant::now();
+            state.stages.push((stage, now - state.last_mark));
+            state.last_mark = now;
+        }
+    }
+
+    fn set_mime_type(&mut self, mime_type: &str) {
+        if let Some(state) = self.state.as_mut() {
+            state.mime_type = Some(mime_type.to_string());
+        }
+    }
+
+    fn set_count(&mut self, count: usize) {
+        if let Some(state) = self.state.as_mut() {
+            state.count = Some(count);
+        }
+    }
+
+    fn finish<T>(self, ruby: &Ruby, event: &str, outcome: &Result<T, Error>) {
+        if let Some(state) = self.state {
+            let _ = emit_timings(ruby, self.operation, event, state, outcome.as_ref().err());
+        }
+    }
+}
+
+/// Emit one stage event per mark, then a summary `event` carrying the outcome.
+fn emit_timings(
+    ruby: &Ruby,
+    operation: &str,
+    event: &str,
+    state: TimerState,
+    error: Option<&Error>,
+) -> Result<(), Error> {
+    let total = state.started.elapsed();
+    let operation = ruby.to_symbol(operation);
+    let stages = ruby.hash_new();
+
+    for (stage, duration) in &state.stages {
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+        stages.aset(ruby.to_symbol(stage), duration_ms)?;
+
+        let payload = ruby.hash_new();
+        payload.aset(ruby.to_symbol("operation"), operation)?;
+        payload.aset(ruby.to_symbol("stage"), ruby.to_symbol(stage))?;
+        payload.aset(ruby.to_symbol("duration_ms"), duration_ms)?;
+        emit_instrumentation_event(ruby, STAGE_EVENT, payload);
+    }
+
+    let payload = ruby.hash_new();
+    payload.aset(ruby.to_symbol("operation"), operation)?;
+    payload.aset(ruby.to_symbol("duration_ms"), total.as_secs_f64() * 1000.0)?;
+    payload.aset(ruby.to_symbol("stages"), stages)?;
+    payload.aset(ruby.to_symbol("success"), error.is_none())?;
+    if let Some(mime_type) = state.mime_type {
+        payload.aset(ruby.to_symbol("mime_type"), mime_type)?;
+    }
+    if let Some(count) = state.count {
+        payload.aset(ruby.to_symbol("count"), count)?;
+    }
+    if let Some(error) = error {
+        payload.aset(ruby.to_symbol("error"), error.to_string())?;
+    }
+    emit_instrumentation_event(ruby, event, payload);
+    Ok(())
+}
+
+/// Maximum nesting of Ruby plugin calls on one thread.
+///
+/// A plugin that extracts documents from its callback re-enters the pipeline, which may run
+/// the same plugin again. Past this depth the plugin call fails with a `PluginError` naming
+/// the chain of plugins, instead of recursing until the stack overflows.
+const MAX_PLUGIN_DEPTH: usize = 8;
+
+thread_local! {
+    /// Names of the Ruby plugins running on this thread, outermost first.
+    static ACTIVE_PLUGINS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Execution context of one Ruby plugin call, left when dropped.
+///
+/// Nested extractions started by the plugin run on the same thread, so the stack of active
+/// plugins is the re-entrancy chain of the current call.
+struct PluginCall;
+
+impl PluginCall {
+    fn enter(name: &str) -> kreuzberg::Result<Self> {
+        ACTIVE_PLUGINS.with(|active| {
+            let mut active = active.borrow_mut();
+            if active.len() >= MAX_PLUGIN_DEPTH {
+                let chain: Vec<&str> = active.iter().map(String::as_str).chain([name]).collect();
+                return Err(KreuzbergError::Plugin {
+                    message: format!(
+                        "Re-entrant extraction exceeded the plugin depth limit of {} ({})",
+                        MAX_PLUGIN_DEPTH,
+                        chain.join(" -> ")
+                    ),
+                    plugin_name: name.to_string(),
+                });
+            }
+            active.push(name.to_string());
+            Ok(Self)
+        })
+    }
+}
+
+impl Drop for PluginCall {
+    fn drop(&mut self) {
+        ACTIVE_PLUGINS.with(|active| {
+            active.borrow_mut().pop();
+        });
+    }
+}
+
+/// Handle to Ruby for running plugin `name` on the current thread.
+///
+/// Batch extraction and parallel OCR may reach a plugin from a Tokio worker thread. Ruby
+/// cannot run there: the thread that owns the GVL is blocked waiting for the extraction,
+/// so the call would deadlock. It fails with a `PluginError` instead.
+fn plugin_ruby(name: &str) -> kreuzberg::Result<Ruby> {
+    Ruby::get().map_err(|_| KreuzbergError::Plugin {
+        message: "Ruby plugin called from a non-Ruby thread (batch extraction or parallel OCR); \
+                  calling into Ruby there would deadlock on the GVL"
+            .to_string(),
+        plugin_name: name.to_string(),
+    })
+}
+
+/// Names of the Ruby plugins running on the current thread, outermost first.
+///
+/// @return [Array<String>] Empty outside plugin callbacks
+///
+/// @example Skip work in extractions started by a plugin
+///   next result unless Kreuzberg.plugin_stack.one?
+///
+fn plugin_stack() -> Vec<String> {
+    ACTIVE_PLUGINS.with(|active| active.borrow().clone())
+}
+
 use std::ffi::c_char;
 
 /// C struct for error details from FFI (Phase 2)
@@ -216,6 +458,20 @@ fn kreuzberg_error(err: KreuzbergError) -> Error {
                 )
             }
         }
+        rejected @ KreuzbergError::InputRejected { .. } => {
+            if let Some(class) = fetch_error_class("ValidationError") {
+                Error::new(class, rejected.to_string())
+            } else {
+                Error::new(ruby.exception_arg_error(), rejected.to_string())
+            }
+        }
+        timeout @ KreuzbergError::Timeout { .. } => {
+            if let Some(class) = fetch_error_class("TimeoutError") {
+                Error::new(class, timeout.to_string())
+            } else {
+                Error::new(ruby.exception_runtime_error(), timeout.to_string())
+            }
+        }
         other => Error::new(ruby.exception_runtime_error(), other.to_string()),
     }
 }
@@ -242,11 +498,61 @@ fn get_kw(ruby: &Ruby, hash: RHash, name: &str) -> Option<Value> {
     })
 }
 
+/// Read the Ruby-only `frozen` option
+fn frozen_option(ruby: &Ruby, opts: Option<RHash>) -> Result<bool, Error> {
+    match opts.and_then(|hash| get_kw(ruby, hash, "frozen")) {
+        Some(val) if !val.is_nil() => bool::try_convert(val),
+        _ => Ok(false),
+    }
+}
+
+/// Deeply freeze a converted result when `frozen` is set
+///
+/// Results are built fresh for every call, so freezing them in place lets Ruby share them
+/// across threads and Ractors without copying.
+fn freeze_result<T: ReprValue>(value: T, frozen: bool) -> Result<T, Error> {
+    if frozen {
+        deep_freeze(value.as_value())?;
+    }
+    Ok(value)
+}
+
+fn deep_freeze(value: Value) -> Result<(), Error> {
+    if value.is_frozen() {
+        return Ok(());
+    }
+    if let Some(hash) = RHash::from_value(value) {
+        hash.foreach(|key: Value, val: Value| {
+            deep_freeze(key)?;
+            deep_freeze(val)?;
+            Ok(ForEach::Continue)
+        })?;
+    } else if let Some(array) = RArray::from_value(value) {
+        for item in array.into_iter() {
+            deep_freeze(item)?;
+        }
+    }
+    value.freeze();
+    Ok(())
+}
+
 fn set_hash_entry(_ruby: &Ruby, hash: &RHash, key: &str, value: Value) -> Result<(), Error> {
     hash.aset(key, value)?;
     Ok(())
 }
 
+fn image_blob_to_ruby(ruby: &Ruby, blob: Option<&ImageBlobRef>) -> Result<Value, Error> {
+    let Some(blob) = blob else {
+        return Ok(ruby.qnil().as_value());
+    };
+
+    let hash = ruby.hash_new();
+    hash.aset("hash", blob.hash.as_str())?;
+    hash.aset("path", blob.path.as_str())?;
+    hash.aset("size", blob.size as u64)?;
+    Ok(hash.as_value())
+}
+
 fn ocr_config_to_ruby_hash(ruby: &Ruby, config: &kreuzberg::OcrConfig) -> Result<RHash, Error> {
     let value =
         serde_json::to_value(config).map_err(|e| runtime_error(format!("Failed to serialize OCR config: {}", e)))?;
@@ -391,6 +697,27 @@ fn ruby_value_to_json(value: Value) -> Result<serde_json::Value, Error> {
     Err(runtime_error("Unsupported Ruby value for JSON conversion"))
 }
 
+/// Check a Ruby Hash against one section of the Rust config schema
+///
+/// Unknown keys and mistyped values raise `Kreuzberg::Errors::ValidationError`; the
+/// returned JSON has every default filled in and deserializes into the section's struct.
+fn validated_section_json(section: &str, val: Value) -> Result<serde_json::Value, Error> {
+    let json = ruby_value_to_json(val)?;
+    validate_config_section(section, &json).map_err(kreuzberg_error)
+}
+
+/// Parse TesseractConfig from a Ruby Hash
+///
+/// Besides the schema check, rejects invalid Tesseract variables with a
+/// `Kreuzberg::Errors::ValidationError` that names the variable.
+fn parse_tesseract_config(val: Value) -> Result<RustTesseractConfig, Error> {
+    let json = validated_section_json("TesseractConfig", val)?;
+    let config: RustTesseractConfig =
+        serde_json::from_value(json).map_err(|e| runtime_error(format!("Invalid tesseract_config: {}", e)))?;
+    validate_tesseract_variables(&config).map_err(kreuzberg_error)?;
+    Ok(config)
+}
+
 /// Parse OcrConfig from Ruby Hash
 fn parse_ocr_config(ruby: &Ruby, hash: RHash) -> Result<OcrConfig, Error> {
     let backend = if let Some(val) = get_kw(ruby, hash, "backend") {
@@ -409,20 +736,64 @@ fn parse_ocr_config(ruby: &Ruby, hash: RHash) -> Result<OcrConfig, Error> {
         backend,
         language,
         tesseract_config: None,
+        fallback: None,
+        vocabulary: None,
     };
 
     if let Some(val) = get_kw(ruby, hash, "tesseract_config")
         && !val.is_nil()
     {
-        let tc_json = ruby_value_to_json(val)?;
-        let parsed: RustTesseractConfig =
-            serde_json::from_value(tc_json).map_err(|e| runtime_error(format!("Invalid tesseract_config: {}", e)))?;
-        config.tesseract_config = Some(parsed);
+        config.tesseract_config = Some(parse_tesseract_config(val)?);
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "fallback")
+        && !val.is_nil()
+    {
+        let fallback_json = validated_section_json("OcrFallbackConfig", val)?;
+        config.fallback = Some(
+            serde_json::from_value(fallback_json).map_err(|e| runtime_error(format!("Invalid OCR fallback: {}", e)))?,
+        );
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "vocabulary")
+        && !val.is_nil()
+    {
+        let vocabulary_json = validated_section_json("OcrVocabularyConfig", val)?;
+        config.vocabulary = Some(
+            serde_json::from_value(vocabulary_json)
+                .map_err(|e| runtime_error(format!("Invalid OCR vocabulary: {}", e)))?,
+        );
     }
 
     Ok(config)
 }
 
+/// Apply `pool_size` / `reuse` from a Ruby OCR Hash to the process-wide Tesseract engine pool
+///
+/// Engines outlive a single call, so these keys configure the pool for every later
+/// extraction in the process rather than being stored on `OcrConfig`.
+fn apply_ocr_engine_pool(ruby: &Ruby, hash: RHash) -> Result<(), Error> {
+    let pool_size = get_kw(ruby, hash, "pool_size").filter(|val| !val.is_nil());
+    let reuse = get_kw(ruby, hash, "reuse").filter(|val| !val.is_nil());
+    if pool_size.is_none() && reuse.is_none() {
+        return Ok(());
+    }
+
+    let mut pool = engine_pool_config();
+    if let Some(val) = pool_size {
+        pool.pool_size = usize::try_convert(val)?;
+        if pool.pool_size == 0 {
+            return Err(runtime_error("ocr pool_size must be greater than 0"));
+        }
+    }
+    if let Some(val) = reuse {
+        pool.reuse = bool::try_convert(val)?;
+    }
+    configure_engine_pool(pool);
+
+    Ok(())
+}
+
 /// Parse ChunkingConfig from Ruby Hash
 fn parse_chunking_config(ruby: &Ruby, hash: RHash) -> Result<ChunkingConfig, Error> {
     let max_chars = if let Some(val) = get_kw(ruby, hash, "max_chars") {
@@ -456,11 +827,20 @@ fn parse_chunking_config(ruby: &Ruby, hash: RHash) -> Result<ChunkingConfig, Err
         None
     };
 
+    let respect_sentences = if let Some(val) = get_kw(ruby, hash, "respect_sentences")
+        && !val.is_nil()
+    {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
     let config = ChunkingConfig {
         max_chars,
         max_overlap,
         embedding,
         preset,
+        respect_sentences,
     };
 
     Ok(config)
@@ -567,6 +947,72 @@ fn parse_image_extraction_config(ruby: &Ruby, hash: RHash) -> Result<ImageExtrac
         600
     };
 
+    let strip_gps = if let Some(val) = get_kw(ruby, hash, "strip_gps") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let output_format = if let Some(val) = get_kw(ruby, hash, "output_format")
+        && !val.is_nil()
+    {
+        let format = symbol_to_string(val)?;
+        Some(format.parse::<ImageOutputFormat>().map_err(runtime_error)?)
+    } else {
+        None
+    };
+
+    let downscale_to = if let Some(val) = get_kw(ruby, hash, "downscale_to")
+        && !val.is_nil()
+    {
+        Some(u32::try_convert(val)?)
+    } else {
+        None
+    };
+
+    let max_bytes = if let Some(val) = get_kw(ruby, hash, "max_bytes")
+        && !val.is_nil()
+    {
+        Some(usize::try_convert(val)?)
+    } else {
+        None
+    };
+
+    let perceptual_hash = if let Some(val) = get_kw(ruby, hash, "perceptual_hash") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let deduplicate_images = if let Some(val) = get_kw(ruby, hash, "deduplicate_images") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let duplicate_hash_distance = if let Some(val) = get_kw(ruby, hash, "duplicate_hash_distance") {
+        u32::try_convert(val)?
+    } else {
+        5
+    };
+
+    let blob_store_dir = if let Some(val) = get_kw(ruby, hash, "blob_store_dir")
+        && !val.is_nil()
+    {
+        Some(PathBuf::from(String::try_convert(val)?))
+    } else {
+        None
+    };
+
+    let ocr_policy = if let Some(val) = get_kw(ruby, hash, "ocr_policy")
+        && !val.is_nil()
+    {
+        let policy_json = validated_section_json("ImageOcrPolicy", val)?;
+        Some(serde_json::from_value(policy_json).map_err(|e| runtime_error(format!("Invalid ocr_policy: {}", e)))?)
+    } else {
+        None
+    };
+
     let config = ImageExtractionConfig {
         extract_images,
         target_dpi,
@@ -574,6 +1020,15 @@ fn parse_image_extraction_config(ruby: &Ruby, hash: RHash) -> Result<ImageExtrac
         auto_adjust_dpi,
         min_dpi,
         max_dpi,
+        strip_gps,
+        output_format,
+        downscale_to,
+        max_bytes,
+        perceptual_hash,
+        deduplicate_images,
+        duplicate_hash_distance,
+        ocr_policy,
+        blob_store_dir,
     };
 
     Ok(config)
@@ -581,9 +1036,7 @@ fn parse_image_extraction_config(ruby: &Ruby, hash: RHash) -> Result<ImageExtrac
 
 /// Parse ImagePreprocessingConfig from Ruby Hash
 ///
-/// Note: Currently not used in ExtractionConfig but provided for completeness.
-/// ImagePreprocessingConfig is typically used in OCR operations.
-#[allow(dead_code)]
+/// Used by `preview_preprocessing`; OCR configs carry preprocessing settings separately.
 fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<ImagePreprocessingConfig, Error> {
     let target_dpi = if let Some(val) = get_kw(ruby, hash, "target_dpi") {
         i32::try_convert(val)?
@@ -627,6 +1080,12 @@ fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<ImagePre
         false
     };
 
+    let auto_tune = if let Some(val) = get_kw(ruby, hash, "auto_tune") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
     let config = ImagePreprocessingConfig {
         target_dpi,
         auto_rotate,
@@ -635,6 +1094,7 @@ fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<ImagePre
         contrast_enhance,
         binarization_method,
         invert_colors,
+        auto_tune,
     };
 
     Ok(config)
@@ -1152,6 +1612,103 @@ fn parse_page_config(ruby: &Ruby, hash: RHash) -> Result<PageConfig, Error> {
     Ok(config)
 }
 
+/// Parse TextExtractionConfig from Ruby Hash
+fn parse_text_options(ruby: &Ruby, hash: RHash) -> Result<TextExtractionConfig, Error> {
+    let mut config = TextExtractionConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "encoding")
+        && !val.is_nil()
+    {
+        config.encoding = symbol_to_string(val)?;
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "normalize_unicode")
+        && !val.is_nil()
+    {
+        let form = symbol_to_string(val)?;
+        config.normalize_unicode = Some(form.parse::<UnicodeNormalization>().map_err(runtime_error)?);
+    }
+
+    Ok(config)
+}
+
+/// Parse NormalizationConfig from Ruby Hash
+fn parse_normalization_config(ruby: &Ruby, hash: RHash) -> Result<NormalizationConfig, Error> {
+    let mut config = NormalizationConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "unicode") {
+        config.unicode = if val.is_nil() {
+            None
+        } else {
+            let form = symbol_to_string(val)?;
+            Some(form.parse::<UnicodeNormalization>().map_err(runtime_error)?)
+        };
+    }
+
+    for (key, flag) in [
+        ("collapse_whitespace", &mut config.collapse_whitespace),
+        ("standardize_quotes", &mut config.standardize_quotes),
+        ("standardize_dashes", &mut config.standardize_dashes),
+        ("dehyphenate", &mut config.dehyphenate),
+    ] {
+        if let Some(val) = get_kw(ruby, hash, key)
+            && !val.is_nil()
+        {
+            *flag = bool::try_convert(val)?;
+        }
+    }
+
+    Ok(config)
+}
+
+/// Parse CsvConfig from Ruby Hash
+fn parse_csv_options(ruby: &Ruby, hash: RHash) -> Result<CsvConfig, Error> {
+    let mut config = CsvConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "delimiter")
+        && !val.is_nil()
+    {
+        let delimiter = symbol_to_string(val)?;
+        let mut chars = delimiter.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => config.delimiter = Some(c),
+            _ => {
+                return Err(runtime_error(format!(
+                    "Invalid delimiter '{}': must be a single character",
+                    delimiter
+                )));
+            }
+        }
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "max_rows")
+        && !val.is_nil()
+    {
+        config.max_rows = Some(usize::try_convert(val)?);
+    }
+
+    Ok(config)
+}
+
+/// Parse PptxConfig from Ruby Hash
+fn parse_pptx_options(ruby: &Ruby, hash: RHash) -> Result<PptxConfig, Error> {
+    let mut config = PptxConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "include_notes")
+        && !val.is_nil()
+    {
+        config.include_notes = bool::try_convert(val)?;
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "slide_markers")
+        && !val.is_nil()
+    {
+        config.slide_markers = bool::try_convert(val)?;
+    }
+
+    Ok(config)
+}
+
 /// Parse ExtractionConfig from Ruby Hash
 fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<ExtractionConfig, Error> {
     let mut config = ExtractionConfig::default();
@@ -1169,11 +1726,47 @@ fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extractio
             config.force_ocr = bool::try_convert(val)?;
         }
 
+        if let Some(val) = get_kw(ruby, hash, "emit_term_offsets") {
+            config.emit_term_offsets = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "emit_provenance") {
+            config.emit_provenance = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "detect_barcodes") {
+            config.detect_barcodes = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "incremental") {
+            config.incremental = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "resource_profile")
+            && !val.is_nil()
+        {
+            let profile = symbol_to_string(val)?;
+            config.resource_profile = match profile.to_lowercase().as_str() {
+                "minimal" => ResourceProfile::Minimal,
+                "standard" => ResourceProfile::Standard,
+                "full" => ResourceProfile::Full,
+                other => return Err(runtime_error(format!("Invalid resource_profile '{}'", other))),
+            };
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "fields")
+            && !val.is_nil()
+        {
+            let arr = RArray::try_convert(val)?;
+            config.fields = Some(arr.to_vec::<String>()?);
+        }
+
         if let Some(val) = get_kw(ruby, hash, "ocr")
             && !val.is_nil()
         {
             let ocr_hash = RHash::try_convert(val)?;
             config.ocr = Some(parse_ocr_config(ruby, ocr_hash)?);
+            apply_ocr_engine_pool(ruby, ocr_hash)?;
         }
 
         if let Some(val) = get_kw(ruby, hash, "chunking")
@@ -1197,7 +1790,8 @@ fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extractio
             config.pdf_options = Some(parse_pdf_config(ruby, pdf_hash)?);
         }
 
-        if let Some(val) = get_kw(ruby, hash, "images")
+        // `Config::Extraction#to_h` emits `image_extraction`; plain hashes use `images`
+        if let Some(val) = get_kw(ruby, hash, "images").or_else(|| get_kw(ruby, hash, "image_extraction"))
             && !val.is_nil()
         {
             let images_hash = RHash::try_convert(val)?;
@@ -1239,12 +1833,161 @@ fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extractio
             config.pages = Some(parse_page_config(ruby, pages_hash)?);
         }
 
-        if let Some(val) = get_kw(ruby, hash, "max_concurrent_extractions") {
-            let value = usize::try_convert(val)?;
-            config.max_concurrent_extractions = Some(value);
+        if let Some(val) = get_kw(ruby, hash, "text_options")
+            && !val.is_nil()
+        {
+            let text_hash = RHash::try_convert(val)?;
+            config.text_options = Some(parse_text_options(ruby, text_hash)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "normalization")
+            && !val.is_nil()
+        {
+            let normalization_hash = RHash::try_convert(val)?;
+            config.normalization = Some(parse_normalization_config(ruby, normalization_hash)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "csv_options")
+            && !val.is_nil()
+        {
+            let csv_hash = RHash::try_convert(val)?;
+            config.csv_options = Some(parse_csv_options(ruby, csv_hash)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "pptx_options")
+            && !val.is_nil()
+        {
+            let pptx_hash = RHash::try_convert(val)?;
+            config.pptx_options = Some(parse_pptx_options(ruby, pptx_hash)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "spreadsheet_options")
+            && !val.is_nil()
+        {
+            let spreadsheet_json = validated_section_json("SpreadsheetConfig", val)?;
+            config.spreadsheet_options = Some(
+                serde_json::from_value(spreadsheet_json)
+                    .map_err(|e| runtime_error(format!("Invalid spreadsheet_options: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "adaptive_concurrency")
+            && !val.is_nil()
+        {
+            let adaptive_json = validated_section_json("AdaptiveConcurrencyConfig", val)?;
+            config.adaptive_concurrency = Some(
+                serde_json::from_value(adaptive_json)
+                    .map_err(|e| runtime_error(format!("Invalid adaptive_concurrency: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "stage_timeouts")
+            && !val.is_nil()
+        {
+            let timeouts_json = validated_section_json("StageTimeoutConfig", val)?;
+            config.stage_timeouts = Some(
+                serde_json::from_value(timeouts_json)
+                    .map_err(|e| runtime_error(format!("Invalid stage_timeouts: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "retry")
+            && !val.is_nil()
+        {
+            let retry_json = validated_section_json("RetryConfig", val)?;
+            config.retry =
+                Some(serde_json::from_value(retry_json).map_err(|e| runtime_error(format!("Invalid retry: {}", e)))?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "cache")
+            && !val.is_nil()
+        {
+            let cache_json = validated_section_json("CacheConfig", val)?;
+            config.cache =
+                Some(serde_json::from_value(cache_json).map_err(|e| runtime_error(format!("Invalid cache: {}", e)))?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "classification")
+            && !val.is_nil()
+        {
+            let classification_json = validated_section_json("ClassificationConfig", val)?;
+            config.classification = Some(
+                serde_json::from_value(classification_json)
+                    .map_err(|e| runtime_error(format!("Invalid classification: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "kv_extraction")
+            && !val.is_nil()
+        {
+            let kv_extraction_json = validated_section_json("KeyValueExtractionConfig", val)?;
+            config.kv_extraction = Some(
+                serde_json::from_value(kv_extraction_json)
+                    .map_err(|e| runtime_error(format!("Invalid kv_extraction: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "preview")
+            && !val.is_nil()
+        {
+            let preview_json = validated_section_json("PreviewConfig", val)?;
+            config.preview = Some(
+                serde_json::from_value(preview_json).map_err(|e| runtime_error(format!("Invalid preview: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "cleanup")
+            && !val.is_nil()
+        {
+            let cleanup_json = validated_section_json("CleanupConfig", val)?;
+            config.cleanup = Some(
+                serde_json::from_value(cleanup_json).map_err(|e| runtime_error(format!("Invalid cleanup: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "watermarks")
+            && !val.is_nil()
+        {
+            let watermarks_json = validated_section_json("WatermarkConfig", val)?;
+            config.watermarks = Some(
+                serde_json::from_value(watermarks_json)
+                    .map_err(|e| runtime_error(format!("Invalid watermarks: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "max_concurrent_extractions") {
+            let value = usize::try_convert(val)?;
+            config.max_concurrent_extractions = Some(value);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "spill_dir")
+            && !val.is_nil()
+        {
+            config.spill_dir = Some(PathBuf::from(String::try_convert(val)?));
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "spill_threshold_bytes")
+            && !val.is_nil()
+        {
+            config.spill_threshold_bytes = Some(usize::try_convert(val)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "overrides")
+            && !val.is_nil()
+        {
+            let overrides_json = ruby_value_to_json(val)?;
+            config.overrides = Some(
+                serde_json::from_value(overrides_json)
+                    .map_err(|e| runtime_error(format!("Invalid overrides: {}", e)))?,
+            );
         }
     }
 
+    FieldSelection::new(config.fields.as_deref())
+        .validate()
+        .map_err(kreuzberg_error)?;
+    kreuzberg::core::overrides::validate_overrides(&config).map_err(kreuzberg_error)?;
+
     Ok(config)
 }
 
@@ -1285,6 +2028,57 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
             ruby.qfalse().as_value()
         },
     )?;
+    set_hash_entry(
+        ruby,
+        &hash,
+        "emit_term_offsets",
+        if config.emit_term_offsets {
+            ruby.qtrue().as_value()
+        } else {
+            ruby.qfalse().as_value()
+        },
+    )?;
+    set_hash_entry(
+        ruby,
+        &hash,
+        "emit_provenance",
+        if config.emit_provenance {
+            ruby.qtrue().as_value()
+        } else {
+            ruby.qfalse().as_value()
+        },
+    )?;
+    set_hash_entry(
+        ruby,
+        &hash,
+        "detect_barcodes",
+        if config.detect_barcodes {
+            ruby.qtrue().as_value()
+        } else {
+            ruby.qfalse().as_value()
+        },
+    )?;
+    set_hash_entry(
+        ruby,
+        &hash,
+        "incremental",
+        if config.incremental {
+            ruby.qtrue().as_value()
+        } else {
+            ruby.qfalse().as_value()
+        },
+    )?;
+    let resource_profile = match config.resource_profile {
+        ResourceProfile::Minimal => "minimal",
+        ResourceProfile::Standard => "standard",
+        ResourceProfile::Full => "full",
+    };
+    set_hash_entry(
+        ruby,
+        &hash,
+        "resource_profile",
+        ruby.to_symbol(resource_profile).into_value_with(ruby),
+    )?;
 
     if let Some(ocr) = config.ocr {
         let ocr_hash = ruby.hash_new();
@@ -1306,6 +2100,21 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
             let tc_ruby = json_value_to_ruby(ruby, &tc_json)?;
             set_hash_entry(ruby, &ocr_hash, "tesseract_config", tc_ruby)?;
         }
+        if let Some(fallback) = ocr.fallback {
+            let fallback_json = serde_json::to_value(&fallback)
+                .map_err(|e| runtime_error(format!("Failed to serialize OCR fallback: {}", e)))?;
+            set_hash_entry(ruby, &ocr_hash, "fallback", json_value_to_ruby(ruby, &fallback_json)?)?;
+        }
+        if let Some(vocabulary) = ocr.vocabulary {
+            let vocabulary_json = serde_json::to_value(&vocabulary)
+                .map_err(|e| runtime_error(format!("Failed to serialize OCR vocabulary: {}", e)))?;
+            set_hash_entry(
+                ruby,
+                &ocr_hash,
+                "vocabulary",
+                json_value_to_ruby(ruby, &vocabulary_json)?,
+            )?;
+        }
         set_hash_entry(ruby, &hash, "ocr", ocr_hash.into_value_with(ruby))?;
     }
 
@@ -1337,6 +2146,9 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
             let embedding_value = json_value_to_ruby(ruby, &embedding_json)?;
             set_hash_entry(ruby, &chunking_hash, "embedding", embedding_value)?;
         }
+        if chunking.respect_sentences {
+            set_hash_entry(ruby, &chunking_hash, "respect_sentences", ruby.qtrue().as_value())?;
+        }
         set_hash_entry(ruby, &hash, "chunking", chunking_hash.into_value_with(ruby))?;
     }
 
@@ -1447,6 +2259,75 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
             "max_dpi",
             ruby.integer_from_i64(images.max_dpi as i64).into_value_with(ruby),
         )?;
+        if let Some(output_format) = images.output_format {
+            set_hash_entry(
+                ruby,
+                &images_hash,
+                "output_format",
+                ruby.str_new(output_format.as_str()).into_value_with(ruby),
+            )?;
+        }
+        if let Some(downscale_to) = images.downscale_to {
+            set_hash_entry(
+                ruby,
+                &images_hash,
+                "downscale_to",
+                ruby.integer_from_i64(downscale_to as i64).into_value_with(ruby),
+            )?;
+        }
+        if let Some(max_bytes) = images.max_bytes {
+            set_hash_entry(
+                ruby,
+                &images_hash,
+                "max_bytes",
+                ruby.integer_from_u64(max_bytes as u64).into_value_with(ruby),
+            )?;
+        }
+        set_hash_entry(
+            ruby,
+            &images_hash,
+            "perceptual_hash",
+            if images.perceptual_hash {
+                ruby.qtrue().as_value()
+            } else {
+                ruby.qfalse().as_value()
+            },
+        )?;
+        set_hash_entry(
+            ruby,
+            &images_hash,
+            "deduplicate_images",
+            if images.deduplicate_images {
+                ruby.qtrue().as_value()
+            } else {
+                ruby.qfalse().as_value()
+            },
+        )?;
+        set_hash_entry(
+            ruby,
+            &images_hash,
+            "duplicate_hash_distance",
+            ruby.integer_from_i64(images.duplicate_hash_distance as i64)
+                .into_value_with(ruby),
+        )?;
+        if let Some(blob_store_dir) = &images.blob_store_dir {
+            set_hash_entry(
+                ruby,
+                &images_hash,
+                "blob_store_dir",
+                ruby.str_new(&blob_store_dir.to_string_lossy()).into_value_with(ruby),
+            )?;
+        }
+        if let Some(ocr_policy) = &images.ocr_policy {
+            let policy_json = serde_json::to_value(ocr_policy)
+                .map_err(|e| runtime_error(format!("Failed to serialize ocr_policy: {}", e)))?;
+            set_hash_entry(
+                ruby,
+                &images_hash,
+                "ocr_policy",
+                json_value_to_ruby(ruby, &policy_json)?,
+            )?;
+        }
         set_hash_entry(ruby, &hash, "image_extraction", images_hash.into_value_with(ruby))?;
     }
 
@@ -1514,6 +2395,102 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
         set_hash_entry(ruby, &hash, "html_options", html_hash.into_value_with(ruby))?;
     }
 
+    if let Some(text_options) = config.text_options {
+        let text_hash = ruby.hash_new();
+        set_hash_entry(
+            ruby,
+            &text_hash,
+            "encoding",
+            ruby.str_new(&text_options.encoding).into_value_with(ruby),
+        )?;
+        if let Some(form) = text_options.normalize_unicode {
+            set_hash_entry(
+                ruby,
+                &text_hash,
+                "normalize_unicode",
+                ruby.str_new(form.as_str()).into_value_with(ruby),
+            )?;
+        }
+        set_hash_entry(ruby, &hash, "text_options", text_hash.into_value_with(ruby))?;
+    }
+
+    if let Some(normalization) = config.normalization {
+        let normalization_hash = ruby.hash_new();
+        set_hash_entry(
+            ruby,
+            &normalization_hash,
+            "unicode",
+            match normalization.unicode {
+                Some(form) => ruby.str_new(form.as_str()).into_value_with(ruby),
+                None => ruby.qnil().as_value(),
+            },
+        )?;
+        for (key, flag) in [
+            ("collapse_whitespace", normalization.collapse_whitespace),
+            ("standardize_quotes", normalization.standardize_quotes),
+            ("standardize_dashes", normalization.standardize_dashes),
+            ("dehyphenate", normalization.dehyphenate),
+        ] {
+            set_hash_entry(
+                ruby,
+                &normalization_hash,
+                key,
+                if flag {
+                    ruby.qtrue().as_value()
+                } else {
+                    ruby.qfalse().as_value()
+                },
+            )?;
+        }
+        set_hash_entry(ruby, &hash, "normalization", normalization_hash.into_value_with(ruby))?;
+    }
+
+    if let Some(csv_options) = config.csv_options {
+        let csv_hash = ruby.hash_new();
+        if let Some(delimiter) = csv_options.delimiter {
+            set_hash_entry(
+                ruby,
+                &csv_hash,
+                "delimiter",
+                ruby.str_new(&delimiter.to_string()).into_value_with(ruby),
+            )?;
+        }
+        if let Some(max_rows) = csv_options.max_rows {
+            set_hash_entry(
+                ruby,
+                &csv_hash,
+                "max_rows",
+                ruby.integer_from_u64(max_rows as u64).into_value_with(ruby),
+            )?;
+        }
+        set_hash_entry(ruby, &hash, "csv_options", csv_hash.into_value_with(ruby))?;
+    }
+
+    if let Some(pptx_options) = config.pptx_options {
+        let pptx_hash = ruby.hash_new();
+        set_hash_entry(
+            ruby,
+            &pptx_hash,
+            "include_notes",
+            if pptx_options.include_notes {
+                ruby.qtrue().as_value()
+            } else {
+                ruby.qfalse().as_value()
+            },
+        )?;
+        set_hash_entry(
+            ruby,
+            &pptx_hash,
+            "slide_markers",
+            if pptx_options.slide_markers {
+                ruby.qtrue().as_value()
+            } else {
+                ruby.qfalse().as_value()
+            },
+        )?;
+        set_hash_entry(ruby, &hash, "pptx_options", pptx_hash.into_value_with(ruby))?;
+    }
+
     if let Some(max_concurrent) = config.max_concurrent_extractions {
         set_hash_entry(
             ruby,
@@ -1523,6 +2500,101 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
         )?;
     }
 
+    if let Some(spreadsheet_options) = config.spreadsheet_options {
+        let value = serde_json::to_value(&spreadsheet_options)
+            .map_err(|e| runtime_error(format!("Failed to serialize spreadsheet_options: {}", e)))?;
+        set_hash_entry(ruby, &hash, "spreadsheet_options", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(adaptive_concurrency) = config.adaptive_concurrency {
+        let value = serde_json::to_value(&adaptive_concurrency)
+            .map_err(|e| runtime_error(format!("Failed to serialize adaptive_concurrency: {}", e)))?;
+        set_hash_entry(ruby, &hash, "adaptive_concurrency", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(stage_timeouts) = config.stage_timeouts {
+        let value = serde_json::to_value(&stage_timeouts)
+            .map_err(|e| runtime_error(format!("Failed to serialize stage_timeouts: {}", e)))?;
+        set_hash_entry(ruby, &hash, "stage_timeouts", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(retry) = config.retry {
+        let value =
+            serde_json::to_value(&retry).map_err(|e| runtime_error(format!("Failed to serialize retry: {}", e)))?;
+        set_hash_entry(ruby, &hash, "retry", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(cache) = config.cache {
+        let value =
+            serde_json::to_value(&cache).map_err(|e| runtime_error(format!("Failed to serialize cache: {}", e)))?;
+        set_hash_entry(ruby, &hash, "cache", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(classification) = config.classification {
+        let value = serde_json::to_value(&classification)
+            .map_err(|e| runtime_error(format!("Failed to serialize classification: {}", e)))?;
+        set_hash_entry(ruby, &hash, "classification", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(kv_extraction) = config.kv_extraction {
+        let value = serde_json::to_value(&kv_extraction)
+            .map_err(|e| runtime_error(format!("Failed to serialize kv_extraction: {}", e)))?;
+        set_hash_entry(ruby, &hash, "kv_extraction", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(preview) = config.preview {
+        let value =
+            serde_json::to_value(&preview).map_err(|e| runtime_error(format!("Failed to serialize preview: {}", e)))?;
+        set_hash_entry(ruby, &hash, "preview", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(pages) = config.pages {
+        let value =
+            serde_json::to_value(&pages).map_err(|e| runtime_error(format!("Failed to serialize pages: {}", e)))?;
+        set_hash_entry(ruby, &hash, "pages", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(cleanup) = config.cleanup {
+        let value =
+            serde_json::to_value(&cleanup).map_err(|e| runtime_error(format!("Failed to serialize cleanup: {}", e)))?;
+        set_hash_entry(ruby, &hash, "cleanup", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(watermarks) = config.watermarks {
+        let value = serde_json::to_value(&watermarks)
+            .map_err(|e| runtime_error(format!("Failed to serialize watermarks: {}", e)))?;
+        set_hash_entry(ruby, &hash, "watermarks", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(overrides) = config.overrides {
+        let value = serde_json::to_value(&overrides)
+            .map_err(|e| runtime_error(format!("Failed to serialize overrides: {}", e)))?;
+        set_hash_entry(ruby, &hash, "overrides", json_value_to_ruby(ruby, &value)?)?;
+    }
+
+    if let Some(spill_dir) = &config.spill_dir {
+        set_hash_entry(
+            ruby,
+            &hash,
+            "spill_dir",
+            ruby.str_new(&spill_dir.to_string_lossy()).into_value_with(ruby),
+        )?;
+    }
+
+    if let Some(spill_threshold_bytes) = config.spill_threshold_bytes {
+        set_hash_entry(
+            ruby,
+            &hash,
+            "spill_threshold_bytes",
+            ruby.integer_from_u64(spill_threshold_bytes as u64)
+                .into_value_with(ruby),
+        )?;
+    }
+
+    if let Some(fields) = config.fields {
+        set_hash_entry(ruby, &hash, "fields", ruby.ary_from_vec(fields).into_value_with(ruby))?;
+    }
+
     Ok(hash)
 }
 
@@ -1564,22 +2636,75 @@ fn config_from_file(path: String) -> Result<RHash, Error> {
     extraction_config_to_ruby_hash(&ruby, config)
 }
 
-/// Discover configuration file in current or parent directories.
-///
-/// Searches for kreuzberg.toml, kreuzberg.yaml, or kreuzberg.json in the current
-/// directory and parent directories. Returns nil if no config file is found.
+/// Load the configuration of a preset defined in Rust.
 ///
-/// @return [Hash, nil] Configuration hash or nil if not found
+/// @param name [String] Preset name ("rag_ingest", "archival_ocr", "fast_preview")
+/// @return [Hash] Configuration hash
 ///
 /// @example
-///   hash = Kreuzberg._config_discover_native
-///   # => {...config hash...} or nil
+///   hash = Kreuzberg._config_preset_native("rag_ingest")
 ///
-fn config_discover() -> Result<Value, Error> {
+fn config_preset(name: String) -> Result<RHash, Error> {
     let ruby = Ruby::get().expect("Ruby not initialized");
 
-    let maybe_config = ExtractionConfig::discover().map_err(kreuzberg_error)?;
-
+    let config = kreuzberg::preset_config(&name).map_err(kreuzberg_error)?;
+    extraction_config_to_ruby_hash(&ruby, config)
+}
+
+/// List the configuration presets defined in Rust.
+///
+/// @return [Array<Array(String, String)>] Preset names and descriptions
+///
+fn config_presets() -> Result<RArray, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+
+    let presets = ruby.ary_new();
+    for preset in kreuzberg::ConfigPreset::ALL {
+        presets.push(ruby.ary_from_vec(vec![preset.as_str(), preset.description()]))?;
+    }
+    Ok(presets)
+}
+
+/// Resolve the configuration extraction runs with: config file, environment and overrides.
+///
+/// @param overrides [Hash] Config fields layered over the file and environment
+/// @param mime_type [String, nil] MIME type to resolve per-MIME overrides for
+/// @return [Hash] "config", "config_file", "environment", "overrides", "mime_type", plus the
+///   configuration exported as "json" and "yaml"
+///
+/// @example
+///   hash = Kreuzberg._effective_config_native({ "force_ocr" => true }, "application/pdf")
+///
+fn effective_config(overrides: RHash, mime_type: Option<String>) -> Result<RHash, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+
+    let overrides = ruby_value_to_json(overrides.as_value())?;
+    let effective = EffectiveConfig::resolve(Some(&overrides), mime_type.as_deref()).map_err(kreuzberg_error)?;
+    let value = serde_json::to_value(&effective)
+        .map_err(|e| runtime_error(format!("Failed to serialize effective configuration: {}", e)))?;
+
+    let hash = RHash::try_convert(json_value_to_ruby(&ruby, &value)?)?;
+    hash.aset("json", effective.to_json().map_err(kreuzberg_error)?)?;
+    hash.aset("yaml", effective.to_yaml().map_err(kreuzberg_error)?)?;
+    Ok(hash)
+}
+
+/// Discover configuration file in current or parent directories.
+///
+/// Searches for kreuzberg.toml, kreuzberg.yaml, or kreuzberg.json in the current
+/// directory and parent directories. Returns nil if no config file is found.
+///
+/// @return [Hash, nil] Configuration hash or nil if not found
+///
+/// @example
+///   hash = Kreuzberg._config_discover_native
+///   # => {...config hash...} or nil
+///
+fn config_discover() -> Result<Value, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+
+    let maybe_config = ExtractionConfig::discover().map_err(kreuzberg_error)?;
+
     match maybe_config {
         Some(config) => {
             let hash = extraction_config_to_ruby_hash(&ruby, config)?;
@@ -1590,7 +2715,52 @@ fn config_discover() -> Result<Value, Error> {
 }
 
 /// Convert Rust ExtractionResult to Ruby Hash
-fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Result<RHash, Error> {
+/// Convert a chunk to a Ruby hash, as exposed in `result["chunks"]` and to chunk processors.
+fn chunk_to_ruby(ruby: &Ruby, chunk: kreuzberg::types::Chunk) -> Result<RHash, Error> {
+    let chunk_hash = ruby.hash_new();
+    chunk_hash.aset("content", chunk.content)?;
+    chunk_hash.aset("byte_start", chunk.metadata.byte_start)?;
+    chunk_hash.aset("byte_end", chunk.metadata.byte_end)?;
+    if let Some(token_count) = chunk.metadata.token_count {
+        chunk_hash.aset("token_count", token_count)?;
+    } else {
+        chunk_hash.aset("token_count", ruby.qnil().as_value())?;
+    }
+    chunk_hash.aset("chunk_index", chunk.metadata.chunk_index)?;
+    chunk_hash.aset("total_chunks", chunk.metadata.total_chunks)?;
+    if let Some(first_page) = chunk.metadata.first_page {
+        chunk_hash.aset("first_page", first_page as i64)?;
+    } else {
+        chunk_hash.aset("first_page", ruby.qnil().as_value())?;
+    }
+    if let Some(last_page) = chunk.metadata.last_page {
+        chunk_hash.aset("last_page", last_page as i64)?;
+    } else {
+        chunk_hash.aset("last_page", ruby.qnil().as_value())?;
+    }
+    if let Some(embedding) = chunk.embedding {
+        let embedding_array = ruby.ary_new();
+        for value in embedding {
+            embedding_array.push(ruby.float_from_f64(value as f64).into_value_with(ruby))?;
+        }
+        chunk_hash.aset("embedding", embedding_array)?;
+    } else {
+        chunk_hash.aset("embedding", ruby.qnil().as_value())?;
+    }
+    let annotations_hash = ruby.hash_new();
+    for (key, value) in &chunk.metadata.annotations {
+        annotations_hash.aset(key.as_str(), json_value_to_ruby(ruby, value)?)?;
+    }
+    chunk_hash.aset("annotations", annotations_hash)?;
+    Ok(chunk_hash)
+}
+
+fn extraction_result_to_ruby(
+    ruby: &Ruby,
+    mut result: RustExtractionResult,
+    fields: FieldSelection<'_>,
+) -> Result<RHash, Error> {
+    fields.apply(&mut result);
     let hash = ruby.hash_new();
 
     let content_value = ruby.str_new(result.content.as_str()).into_value_with(ruby);
@@ -1599,12 +2769,17 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
     let mime_value = ruby.str_new(result.mime_type.as_str()).into_value_with(ruby);
     set_hash_entry(ruby, &hash, "mime_type", mime_value)?;
 
-    let metadata_json = serde_json::to_string(&result.metadata)
+    let metadata_value = fields
+        .metadata_value(&result.metadata)
         .map_err(|e| runtime_error(format!("Failed to serialize metadata: {}", e)))?;
+    let metadata_json = if fields.is_all() {
+        serde_json::to_string(&result.metadata)
+    } else {
+        serde_json::to_string(&metadata_value)
+    }
+    .map_err(|e| runtime_error(format!("Failed to serialize metadata: {}", e)))?;
     let metadata_json_value = ruby.str_new(&metadata_json).into_value_with(ruby);
     set_hash_entry(ruby, &hash, "metadata_json", metadata_json_value)?;
-    let metadata_value = serde_json::to_value(&result.metadata)
-        .map_err(|e| runtime_error(format!("Failed to serialize metadata: {}", e)))?;
     let metadata_hash = json_value_to_ruby(ruby, &metadata_value)?;
     set_hash_entry(ruby, &hash, "metadata", metadata_hash)?;
 
@@ -1623,6 +2798,11 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
 
         table_hash.aset("page_number", table.page_number)?;
 
+        if let Some(column_types) = table.column_types {
+            let types: Vec<&str> = column_types.iter().map(|t| t.as_str()).collect();
+            table_hash.aset("column_types", ruby.ary_from_vec(types))?;
+        }
+
         tables_array.push(table_hash)?;
     }
     let tables_value = tables_array.into_value_with(ruby);
@@ -1639,37 +2819,7 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
     if let Some(chunks) = result.chunks {
         let chunks_array = ruby.ary_new();
         for chunk in chunks {
-            let chunk_hash = ruby.hash_new();
-            chunk_hash.aset("content", chunk.content)?;
-            chunk_hash.aset("byte_start", chunk.metadata.byte_start)?;
-            chunk_hash.aset("byte_end", chunk.metadata.byte_end)?;
-            if let Some(token_count) = chunk.metadata.token_count {
-                chunk_hash.aset("token_count", token_count)?;
-            } else {
-                chunk_hash.aset("token_count", ruby.qnil().as_value())?;
-            }
-            chunk_hash.aset("chunk_index", chunk.metadata.chunk_index)?;
-            chunk_hash.aset("total_chunks", chunk.metadata.total_chunks)?;
-            if let Some(first_page) = chunk.metadata.first_page {
-                chunk_hash.aset("first_page", first_page as i64)?;
-            } else {
-                chunk_hash.aset("first_page", ruby.qnil().as_value())?;
-            }
-            if let Some(last_page) = chunk.metadata.last_page {
-                chunk_hash.aset("last_page", last_page as i64)?;
-            } else {
-                chunk_hash.aset("last_page", ruby.qnil().as_value())?;
-            }
-            if let Some(embedding) = chunk.embedding {
-                let embedding_array = ruby.ary_new();
-                for value in embedding {
-                    embedding_array.push(ruby.float_from_f64(value as f64).into_value_with(ruby))?;
-                }
-                chunk_hash.aset("embedding", embedding_array)?;
-            } else {
-                chunk_hash.aset("embedding", ruby.qnil().as_value())?;
-            }
-            chunks_array.push(chunk_hash)?;
+            chunks_array.push(chunk_to_ruby(ruby, chunk)?)?;
         }
         let chunks_value = chunks_array.into_value_with(ruby);
         set_hash_entry(ruby, &hash, "chunks", chunks_value)?;
@@ -1724,11 +2874,27 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
                 image_hash.aset("description", ruby.qnil().as_value())?;
             }
             if let Some(ocr_result) = image.ocr_result {
-                let nested = extraction_result_to_ruby(ruby, *ocr_result)?;
+                let nested = extraction_result_to_ruby(ruby, *ocr_result, FieldSelection::default())?;
                 image_hash.aset("ocr_result", nested.into_value_with(ruby))?;
             } else {
                 image_hash.aset("ocr_result", ruby.qnil().as_value())?;
             }
+            if let Some(perceptual_hash) = image.perceptual_hash {
+                image_hash.aset("perceptual_hash", perceptual_hash)?;
+            } else {
+                image_hash.aset("perceptual_hash", ruby.qnil().as_value())?;
+            }
+            image_hash.aset("blob", image_blob_to_ruby(ruby, image.blob.as_ref())?)?;
+            if let Some(caption) = image.caption {
+                image_hash.aset("caption", caption)?;
+            } else {
+                image_hash.aset("caption", ruby.qnil().as_value())?;
+            }
+            if let Some(figure_index) = image.figure_index {
+                image_hash.aset("figure_index", figure_index)?;
+            } else {
+                image_hash.aset("figure_index", ruby.qnil().as_value())?;
+            }
             images_array.push(image_hash)?;
         }
         set_hash_entry(ruby, &hash, "images", images_array.into_value_with(ruby))?;
@@ -1755,6 +2921,10 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
                 table_hash.aset("cells", cells_array)?;
                 table_hash.aset("markdown", table.markdown.clone())?;
                 table_hash.aset("page_number", table.page_number as i64)?;
+                if let Some(column_types) = &table.column_types {
+                    let types: Vec<&str> = column_types.iter().map(|t| t.as_str()).collect();
+                    table_hash.aset("column_types", ruby.ary_from_vec(types))?;
+                }
 
                 tables_array.push(table_hash)?;
             }
@@ -1806,14 +2976,34 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
                     image_hash.aset("description", ruby.qnil().as_value())?;
                 }
                 if let Some(ocr_result) = &image.ocr_result {
-                    let nested = extraction_result_to_ruby(ruby, (**ocr_result).clone())?;
+                    let nested = extraction_result_to_ruby(ruby, (**ocr_result).clone(), FieldSelection::default())?;
                     image_hash.aset("ocr_result", nested.into_value_with(ruby))?;
                 } else {
                     image_hash.aset("ocr_result", ruby.qnil().as_value())?;
                 }
+                if let Some(perceptual_hash) = &image.perceptual_hash {
+                    image_hash.aset("perceptual_hash", perceptual_hash.clone())?;
+                } else {
+                    image_hash.aset("perceptual_hash", ruby.qnil().as_value())?;
+                }
+                image_hash.aset("blob", image_blob_to_ruby(ruby, image.blob.as_ref())?)?;
+                if let Some(caption) = &image.caption {
+                    image_hash.aset("caption", caption.clone())?;
+                } else {
+                    image_hash.aset("caption", ruby.qnil().as_value())?;
+                }
+                if let Some(figure_index) = image.figure_index {
+                    image_hash.aset("figure_index", figure_index)?;
+                } else {
+                    image_hash.aset("figure_index", ruby.qnil().as_value())?;
+                }
                 images_array.push(image_hash)?;
             }
             page_hash.aset("images", images_array)?;
+            page_hash.aset(
+                "content_blob",
+                image_blob_to_ruby(ruby, page_content.content_blob.as_ref())?,
+            )?;
 
             pages_array.push(page_hash)?;
         }
@@ -1822,6 +3012,77 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
         set_hash_entry(ruby, &hash, "pages", ruby.qnil().as_value())?;
     }
 
+    if let Some(term_offsets) = result.term_offsets {
+        let offsets_array = ruby.ary_new();
+        for offset in term_offsets {
+            let offset_hash = ruby.hash_new();
+            offset_hash.aset("term", offset.term)?;
+            offset_hash.aset("byte_start", offset.byte_start as i64)?;
+            offset_hash.aset("byte_end", offset.byte_end as i64)?;
+            if let Some(page) = offset.page {
+                offset_hash.aset("page", page as i64)?;
+            } else {
+                offset_hash.aset("page", ruby.qnil().as_value())?;
+            }
+            offsets_array.push(offset_hash)?;
+        }
+        set_hash_entry(ruby, &hash, "term_offsets", offsets_array.into_value_with(ruby))?;
+    } else {
+        set_hash_entry(ruby, &hash, "term_offsets", ruby.qnil().as_value())?;
+    }
+
+    if let Some(barcodes) = result.barcodes {
+        let barcodes_array = ruby.ary_new();
+        for barcode in barcodes {
+            let bbox_hash = ruby.hash_new();
+            bbox_hash.aset("x", barcode.bbox.x)?;
+            bbox_hash.aset("y", barcode.bbox.y)?;
+            bbox_hash.aset("width", barcode.bbox.width)?;
+            bbox_hash.aset("height", barcode.bbox.height)?;
+
+            let barcode_hash = ruby.hash_new();
+            barcode_hash.aset("format", barcode.format.as_str())?;
+            barcode_hash.aset("payload", barcode.payload)?;
+            barcode_hash.aset("page", barcode.page as i64)?;
+            barcode_hash.aset("bbox", bbox_hash)?;
+            barcodes_array.push(barcode_hash)?;
+        }
+        set_hash_entry(ruby, &hash, "barcodes", barcodes_array.into_value_with(ruby))?;
+    } else {
+        set_hash_entry(ruby, &hash, "barcodes", ruby.qnil().as_value())?;
+    }
+
+    if let Some(provenance) = result.provenance {
+        let provenance_array = ruby.ary_new();
+        for span in provenance {
+            let span_hash = ruby.hash_new();
+            span_hash.aset("byte_start", span.byte_start as i64)?;
+            span_hash.aset("byte_end", span.byte_end as i64)?;
+            match span.source {
+                ContentSource::TextLayer => {
+                    span_hash.aset("source", "text_layer")?;
+                }
+                ContentSource::Ocr { page } => {
+                    span_hash.aset("source", "ocr")?;
+                    span_hash.aset("page", page.map(|page| page as i64))?;
+                }
+                ContentSource::Table { table_index, page } => {
+                    span_hash.aset("source", "table")?;
+                    span_hash.aset("table_index", table_index as i64)?;
+                    span_hash.aset("page", page.map(|page| page as i64))?;
+                }
+                ContentSource::Attachment { name } => {
+                    span_hash.aset("source", "attachment")?;
+                    span_hash.aset("name", name)?;
+                }
+            }
+            provenance_array.push(span_hash)?;
+        }
+        set_hash_entry(ruby, &hash, "provenance", provenance_array.into_value_with(ruby))?;
+    } else {
+        set_hash_entry(ruby, &hash, "provenance", ruby.qnil().as_value())?;
+    }
+
     Ok(hash)
 }
 
@@ -1846,11 +3107,45 @@ fn extract_file_sync(args: &[Value]) -> Result<RHash, Error> {
     let (mime_type,) = args.optional;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
+    let mut timer = ExtractionTimer::start("extract_file_sync");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
+
+        let result = kreuzberg::extract_file_sync(&path, mime_type.as_deref(), &config).map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_mime_type(&result.mime_type);
 
-    let result = kreuzberg::extract_file_sync(&path, mime_type.as_deref(), &config).map_err(kreuzberg_error)?;
+        let hash = extraction_result_to_ruby(&ruby, result, FieldSelection::new(config.fields.as_deref()))
+            .and_then(|hash| freeze_result(hash, frozen));
+        timer.mark("convert_result");
+        hash
+    })();
 
-    extraction_result_to_ruby(&ruby, result)
+    timer.finish(&ruby, DOCUMENT_EVENT, &outcome);
+    outcome
+}
+
+/// Estimate the cost of extracting a file without extracting it.
+///
+/// @param path [String] Path to the file
+/// @param opts [Hash] Extraction configuration
+/// @return [Hash] "profile", "ocr_pages", "stages", "duration_ms" and "peak_memory_bytes"
+///
+/// @example
+///   hash = Kreuzberg._estimate_native("scan.pdf", ocr: { backend: "tesseract" })
+///
+fn estimate_native(args: &[Value]) -> Result<RHash, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+    let args = scan_args::<(String,), (), (), (), RHash, ()>(args)?;
+    let (path,) = args.required;
+
+    let config = parse_extraction_config(&ruby, Some(args.keywords))?;
+    let estimate = kreuzberg::core::estimate::estimate(&path, &config).map_err(kreuzberg_error)?;
+    let value =
+        serde_json::to_value(&estimate).map_err(|e| runtime_error(format!("Failed to serialize estimate: {}", e)))?;
+    RHash::try_convert(json_value_to_ruby(&ruby, &value)?)
 }
 
 /// Extract content from bytes (synchronous).
@@ -1870,14 +3165,27 @@ fn extract_bytes_sync(args: &[Value]) -> Result<RHash, Error> {
     let (data, mime_type) = args.required;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
-
-    // SAFETY: we hold `data` for the duration of the call and do not re-enter Ruby while
-    // borrowing its bytes, so Ruby cannot mutate/free this string during extraction.
-    let bytes = unsafe { data.as_slice() };
-    let result = kreuzberg::extract_bytes_sync(bytes, &mime_type, &config).map_err(kreuzberg_error)?;
-
-    extraction_result_to_ruby(&ruby, result)
+    let mut timer = ExtractionTimer::start("extract_bytes_sync");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
+
+        // SAFETY: we hold `data` for the duration of the call and do not re-enter Ruby while
+        // borrowing its bytes, so Ruby cannot mutate/free this string during extraction.
+        let bytes = unsafe { data.as_slice() };
+        let result = kreuzberg::extract_bytes_sync(bytes, &mime_type, &config).map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_mime_type(&result.mime_type);
+
+        let hash = extraction_result_to_ruby(&ruby, result, FieldSelection::new(config.fields.as_deref()))
+            .and_then(|hash| freeze_result(hash, frozen));
+        timer.mark("convert_result");
+        hash
+    })();
+
+    timer.finish(&ruby, DOCUMENT_EVENT, &outcome);
+    outcome
 }
 
 /// Batch extract content from multiple files (synchronous).
@@ -1897,18 +3205,31 @@ fn batch_extract_files_sync(args: &[Value]) -> Result<RArray, Error> {
     let (paths_array,) = args.required;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
+    let mut timer = ExtractionTimer::start("batch_extract_files_sync");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
 
-    let paths: Vec<String> = paths_array.to_vec::<String>()?;
+        let paths: Vec<String> = paths_array.to_vec::<String>()?;
 
-    let results = kreuzberg::batch_extract_file_sync(paths, &config).map_err(kreuzberg_error)?;
+        let results = kreuzberg::batch_extract_file_sync(paths, &config).map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_count(results.len());
 
-    let results_array = ruby.ary_new();
-    for result in results {
-        results_array.push(extraction_result_to_ruby(&ruby, result)?)?;
-    }
+        let fields = FieldSelection::new(config.fields.as_deref());
+        let results_array = ruby.ary_new();
+        for result in results {
+            results_array.push(extraction_result_to_ruby(&ruby, result, fields)?)?;
+        }
 
-    Ok(results_array)
+        let results_array = freeze_result(results_array, frozen)?;
+        timer.mark("convert_result");
+        Ok(results_array)
+    })();
+
+    timer.finish(&ruby, BATCH_EVENT, &outcome);
+    outcome
 }
 
 /// Extract content from a file (asynchronous).
@@ -1928,16 +3249,29 @@ fn extract_file(args: &[Value]) -> Result<RHash, Error> {
     let (mime_type,) = args.optional;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
-
-    let runtime =
-        tokio::runtime::Runtime::new().map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
-
-    let result = runtime
-        .block_on(async { kreuzberg::extract_file(&path, mime_type.as_deref(), &config).await })
-        .map_err(kreuzberg_error)?;
-
-    extraction_result_to_ruby(&ruby, result)
+    let mut timer = ExtractionTimer::start("extract_file");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
+
+        let runtime = tokio::runtime::Runtime::new()
+            .map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
+
+        let result = runtime
+            .block_on(async { kreuzberg::extract_file(&path, mime_type.as_deref(), &config).await })
+            .map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_mime_type(&result.mime_type);
+
+        let hash = extraction_result_to_ruby(&ruby, result, FieldSelection::new(config.fields.as_deref()))
+            .and_then(|hash| freeze_result(hash, frozen));
+        timer.mark("convert_result");
+        hash
+    })();
+
+    timer.finish(&ruby, DOCUMENT_EVENT, &outcome);
+    outcome
 }
 
 /// Extract content from bytes (asynchronous).
@@ -1953,19 +3287,32 @@ fn extract_bytes(args: &[Value]) -> Result<RHash, Error> {
     let (data, mime_type) = args.required;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
-
-    let runtime =
-        tokio::runtime::Runtime::new().map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
-
-    // SAFETY: we hold `data` for the duration of the call and do not re-enter Ruby while
-    // borrowing its bytes, so Ruby cannot mutate/free this string during extraction.
-    let bytes = unsafe { data.as_slice() };
-    let result = runtime
-        .block_on(async { kreuzberg::extract_bytes(bytes, &mime_type, &config).await })
-        .map_err(kreuzberg_error)?;
-
-    extraction_result_to_ruby(&ruby, result)
+    let mut timer = ExtractionTimer::start("extract_bytes");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
+
+        let runtime = tokio::runtime::Runtime::new()
+            .map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
+
+        // SAFETY: we hold `data` for the duration of the call and do not re-enter Ruby while
+        // borrowing its bytes, so Ruby cannot mutate/free this string during extraction.
+        let bytes = unsafe { data.as_slice() };
+        let result = runtime
+            .block_on(async { kreuzberg::extract_bytes(bytes, &mime_type, &config).await })
+            .map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_mime_type(&result.mime_type);
+
+        let hash = extraction_result_to_ruby(&ruby, result, FieldSelection::new(config.fields.as_deref()))
+            .and_then(|hash| freeze_result(hash, frozen));
+        timer.mark("convert_result");
+        hash
+    })();
+
+    timer.finish(&ruby, DOCUMENT_EVENT, &outcome);
+    outcome
 }
 
 /// Batch extract content from multiple files (asynchronous).
@@ -1980,23 +3327,606 @@ fn batch_extract_files(args: &[Value]) -> Result<RArray, Error> {
     let (paths_array,) = args.required;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
+    let mut timer = ExtractionTimer::start("batch_extract_files");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
+
+        let paths: Vec<String> = paths_array.to_vec::<String>()?;
+
+        let runtime = tokio::runtime::Runtime::new()
+            .map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
+
+        let results = runtime
+            .block_on(async { kreuzberg::batch_extract_file(paths, &config).await })
+            .map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_count(results.len());
+
+        let fields = FieldSelection::new(config.fields.as_deref());
+        let results_array = ruby.ary_new();
+        for result in results {
+            results_array.push(extraction_result_to_ruby(&ruby, result, fields)?)?;
+        }
+
+        let results_array = freeze_result(results_array, frozen)?;
+        timer.mark("convert_result");
+        Ok(results_array)
+    })();
+
+    timer.finish(&ruby, BATCH_EVENT, &outcome);
+    outcome
+}
+
+/// Number of finished results a batch stream holds before pausing extraction.
+const BATCH_STREAM_BUFFER: usize = 16;
+
+/// A batch extraction delivering results in completion order.
+struct BatchStream {
+    runtime: Option<tokio::runtime::Runtime>,
+    receiver: tokio::sync::mpsc::Receiver<(usize, kreuzberg::Result<RustExtractionResult>)>,
+    fields: Option<Vec<String>>,
+    frozen: bool,
+}
+
+impl Drop for BatchStream {
+    fn drop(&mut self) {
+        // Do not block the Ruby thread on extractions that are still running
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+static NEXT_BATCH_STREAM_ID: AtomicU64 = AtomicU64::new(1);
+static BATCH_STREAMS: Mutex<BTreeMap<u64, BatchStream>> = Mutex::new(BTreeMap::new());
+
+fn batch_streams() -> std::sync::MutexGuard<'static, BTreeMap<u64, BatchStream>> {
+    BATCH_STREAMS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Start a batch extraction whose results are fetched one at a time.
+///
+/// @param paths [Array<String>] List of file paths
+/// @param options [Hash] Extraction configuration
+/// @return [Integer] Stream id for `_batch_stream_next_native` and `_batch_stream_close_native`
+///
+fn batch_stream_open(args: &[Value]) -> Result<u64, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+    let args = scan_args::<(RArray,), (), (), (), RHash, ()>(args)?;
+    let (paths_array,) = args.required;
+    let opts = Some(args.keywords);
 
+    let config = parse_extraction_config(&ruby, opts)?;
+    let frozen = frozen_option(&ruby, opts)?;
     let paths: Vec<String> = paths_array.to_vec::<String>()?;
 
     let runtime =
         tokio::runtime::Runtime::new().map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
+    let receiver = {
+        let _guard = runtime.enter();
+        kreuzberg::batch_extract_file_unordered(paths, &config, BATCH_STREAM_BUFFER)
+    };
 
-    let results = runtime
-        .block_on(async { kreuzberg::batch_extract_file(paths, &config).await })
-        .map_err(kreuzberg_error)?;
+    let id = NEXT_BATCH_STREAM_ID.fetch_add(1, Ordering::Relaxed);
+    batch_streams().insert(
+        id,
+        BatchStream {
+            runtime: Some(runtime),
+            receiver,
+            fields: config.fields,
+            frozen,
+        },
+    );
+    Ok(id)
+}
+
+/// Wait for the next finished result of a batch stream.
+///
+/// @param id [Integer] Stream id returned by `_batch_stream_open_native`
+/// @return [Array(Integer, Hash), nil] Input index and extraction result, or nil once
+///   every file was delivered (the stream is closed then)
+///
+fn batch_stream_next(id: u64) -> Result<Value, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+
+    // Take the stream out of the registry so the lock is not held while waiting
+    let Some(mut stream) = batch_streams().remove(&id) else {
+        return Ok(ruby.qnil().as_value());
+    };
+    let Some(runtime) = stream.runtime.as_ref() else {
+        return Ok(ruby.qnil().as_value());
+    };
+    let Some((index, result)) = runtime.block_on(stream.receiver.recv()) else {
+        return Ok(ruby.qnil().as_value());
+    };
+    let fields = stream.fields.clone();
+    let frozen = stream.frozen;
+    batch_streams().insert(id, stream);
+
+    let hash = extraction_result_to_ruby(
+        &ruby,
+        result.map_err(kreuzberg_error)?,
+        FieldSelection::new(fields.as_deref()),
+    )?;
+    let hash = freeze_result(hash, frozen)?;
+    let pair = ruby.ary_new();
+    pair.push(index)?;
+    pair.push(hash)?;
+    Ok(pair.as_value())
+}
+
+/// Stop a batch stream; extractions that have not started yet are cancelled.
+///
+/// @param id [Integer] Stream id returned by `_batch_stream_open_native`
+///
+fn batch_stream_close(id: u64) {
+    let stream = batch_streams().remove(&id);
+    drop(stream);
+}
+
+/// Number of finished results a folder watcher holds before pausing extraction.
+const WATCHER_BUFFER: usize = 16;
+
+/// A folder watcher extracting new and changed files in the background.
+struct FolderWatcher {
+    runtime: Option<tokio::runtime::Runtime>,
+    // Dropping the notify watcher ends the debounce task and with it the extractions
+    watcher: Option<notify::RecommendedWatcher>,
+    receiver: tokio::sync::mpsc::Receiver<(PathBuf, kreuzberg::Result<RustExtractionResult>)>,
+    failed_path: Option<PathBuf>,
+    fields: Option<Vec<String>>,
+    frozen: bool,
+}
+
+impl Drop for FolderWatcher {
+    fn drop(&mut self) {
+        drop(self.watcher.take());
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+static NEXT_WATCHER_ID: AtomicU64 = AtomicU64::new(1);
+static WATCHERS: Mutex<BTreeMap<u64, FolderWatcher>> = Mutex::new(BTreeMap::new());
+
+fn watchers() -> std::sync::MutexGuard<'static, BTreeMap<u64, FolderWatcher>> {
+    WATCHERS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Files whose creation or content change triggers an extraction.
+///
+/// Metadata-only changes are ignored, as are hidden files such as the `.part` and
+/// `.~lock` files uploads and editors write next to the document.
+fn watched_paths(event: notify::Event) -> Vec<PathBuf> {
+    use notify::EventKind;
+    use notify::event::ModifyKind;
+
+    match event.kind {
+        EventKind::Create(_) => {}
+        EventKind::Modify(ModifyKind::Metadata(_)) => return Vec::new(),
+        EventKind::Modify(_) => {}
+        _ => return Vec::new(),
+    }
+    event
+        .paths
+        .into_iter()
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| !name.starts_with('.'))
+        })
+        .collect()
+}
+
+/// Wait until a path saw no event for `debounce`, then extract it with at most
+/// `concurrency` extractions running at once.
+async fn debounce_and_extract(
+    mut events: tokio::sync::mpsc::UnboundedReceiver<PathBuf>,
+    sender: tokio::sync::mpsc::Sender<(PathBuf, kreuzberg::Result<RustExtractionResult>)>,
+    config: std::sync::Arc<ExtractionConfig>,
+    debounce: Duration,
+    concurrency: usize,
+) {
+    use tokio::time::Instant as TokioInstant;
+
+    let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency));
+    let mut pending: BTreeMap<PathBuf, TokioInstant> = BTreeMap::new();
+
+    loop {
+        let deadline = pending
+            .values()
+            .min()
+            .map(|last_event| *last_event + debounce)
+            .unwrap_or_else(|| TokioInstant::now() + Duration::from_secs(3600));
+
+        tokio::select! {
+            event = events.recv() => match event {
+                Some(path) => {
+                    pending.insert(path, TokioInstant::now());
+                }
+                None => break,
+            },
+            _ = tokio::time::sleep_until(deadline) => {
+                let now = TokioInstant::now();
+                let settled: Vec<PathBuf> = pending
+                    .iter()
+                    .filter(|(_, last_event)| now.duration_since(**last_event) >= debounce)
+                    .map(|(path, _)| path.clone())
+                    .collect();
+                for path in settled {
+                    pending.remove(&path);
+                    // Directories and files deleted again before they settled
+                    if !path.is_file() {
+                        continue;
+                    }
+                    let permits = permits.clone();
+                    let sender = sender.clone();
+                    let config = config.clone();
+                    tokio::spawn(async move {
+                        let Ok(_permit) = permits.acquire_owned().await else {
+                            return;
+                        };
+                        let result = kreuzberg::extract_file(&path, None, &config).await;
+                        let _ = sender.send((path, result)).await;
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Start watching a directory for new and changed files.
+///
+/// @param dir [String] Directory to watch
+/// @param debounce [Float] Seconds a file must be left alone before it is extracted
+/// @param concurrency [Integer] Maximum number of extractions running at once
+/// @param recursive [Boolean] Whether subdirectories are watched too
+/// @param options [Hash] Extraction configuration
+/// @return [Integer] Watcher id for `_watcher_next_native` and `_watcher_close_native`
+///
+fn watcher_open(args: &[Value]) -> Result<u64, Error> {
+    use notify::Watcher;
+
+    let ruby = Ruby::get().expect("Ruby not initialized");
+    let args = scan_args::<(String, f64, usize, bool), (), (), (), RHash, ()>(args)?;
+    let (dir, debounce, concurrency, recursive) = args.required;
+    let opts = Some(args.keywords);
+
+    if !debounce.is_finite() || debounce < 0.0 {
+        return Err(Error::new(
+            ruby.exception_arg_error(),
+            format!("debounce must be a non-negative number of seconds, got {}", debounce),
+        ));
+    }
+    if concurrency == 0 {
+        return Err(Error::new(ruby.exception_arg_error(), "concurrency must be at least 1"));
+    }
+    let dir = PathBuf::from(dir);
+    if !dir.is_dir() {
+        return Err(Error::new(
+            ruby.exception_arg_error(),
+            format!("{} is not a directory", dir.display()),
+        ));
+    }
+
+    let config = parse_extraction_config(&ruby, opts)?;
+    let frozen = frozen_option(&ruby, opts)?;
+    let fields = config.fields.clone();
+
+    let runtime =
+        tokio::runtime::Runtime::new().map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
+    let (event_sender, event_receiver) = tokio::sync::mpsc::unbounded_channel();
+    let (sender, receiver) = tokio::sync::mpsc::channel(WATCHER_BUFFER);
+
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        // Errors of the platform watcher are not fatal; the next event is processed as usual
+        if let Ok(event) = event {
+            for path in watched_paths(event) {
+                let _ = event_sender.send(path);
+            }
+        }
+    })
+    .map_err(|e| runtime_error(format!("Failed to create folder watcher: {}", e)))?;
+    let mode = if recursive {
+        notify::RecursiveMode::Recursive
+    } else {
+        notify::RecursiveMode::NonRecursive
+    };
+    watcher
+        .watch(&dir, mode)
+        .map_err(|e| runtime_error(format!("Failed to watch {}: {}", dir.display(), e)))?;
+
+    runtime.spawn(debounce_and_extract(
+        event_receiver,
+        sender,
+        std::sync::Arc::new(config),
+        Duration::from_secs_f64(debounce),
+        concurrency,
+    ));
+
+    let id = NEXT_WATCHER_ID.fetch_add(1, Ordering::Relaxed);
+    watchers().insert(
+        id,
+        FolderWatcher {
+            runtime: Some(runtime),
+            watcher: Some(watcher),
+            receiver,
+            failed_path: None,
+            fields,
+            frozen,
+        },
+    );
+    Ok(id)
+}
+
+/// Take the next extracted file of a watcher without waiting.
+///
+/// Never blocks, so the GVL is not held while no file is ready; `Watcher#run` sleeps in Ruby
+/// between empty polls. A failed extraction raises its error; `_watcher_failed_path_native`
+/// then returns the file.
+///
+/// @param id [Integer] Watcher id returned by `_watcher_open_native`
+/// @return [Array(String, Hash), nil] Path and extraction result, or nil when no file is
+///   ready or the watcher is closed
+///
+fn watcher_next(id: u64) -> Result<Value, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+
+    let mut registry = watchers();
+    let Some(watcher) = registry.get_mut(&id) else {
+        return Ok(ruby.qnil().as_value());
+    };
+    // Only the file of the error raised by this call may be reported
+    watcher.failed_path = None;
+    let Ok((path, result)) = watcher.receiver.try_recv() else {
+        return Ok(ruby.qnil().as_value());
+    };
+    watcher.failed_path = result.is_err().then(|| path.clone());
+    let fields = watcher.fields.clone();
+    let frozen = watcher.frozen;
+    drop(registry);
+
+    let hash = extraction_result_to_ruby(
+        &ruby,
+        result.map_err(kreuzberg_error)?,
+        FieldSelection::new(fields.as_deref()),
+    )?;
+    let hash = freeze_result(hash, frozen)?;
+    let pair = ruby.ary_new();
+    pair.push(path.to_string_lossy().into_owned())?;
+    pair.push(hash)?;
+    Ok(pair.as_value())
+}
+
+/// Path of the file whose extraction error `_watcher_next_native` raised last.
+///
+/// @param id [Integer] Watcher id returned by `_watcher_open_native`
+/// @return [String, nil]
+///
+fn watcher_failed_path(id: u64) -> Option<String> {
+    watchers()
+        .get(&id)
+        .and_then(|watcher| watcher.failed_path.as_ref())
+        .map(|path| path.to_string_lossy().into_owned())
+}
+
+/// Stop a watcher; extractions that have not finished yet are cancelled.
+///
+/// @param id [Integer] Watcher id returned by `_watcher_open_native`
+///
+fn watcher_close(id: u64) {
+    let watcher = watchers().remove(&id);
+    drop(watcher);
+}
+
+/// Read image bytes or spilled page text from the blob store.
+///
+/// @param blob [Hash] Blob reference of an extracted image or page (`hash`, `path`, `size`)
+/// @return [String] Binary blob data
+///
+fn image_blob_read(blob: RHash) -> Result<RString, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+
+    let field =
+        |name: &str| get_kw(&ruby, blob, name).ok_or_else(|| runtime_error(format!("Blob is missing {}", name)));
+    let blob = ImageBlobRef {
+        hash: String::try_convert(field("hash")?)?,
+        path: String::try_convert(field("path")?)?,
+        size: usize::try_convert(field("size")?)?,
+    };
+
+    let data = kreuzberg::cache::blob_store::read_blob(&blob).map_err(kreuzberg_error)?;
+    Ok(ruby.str_from_slice(&data))
+}
+
+/// Remove every result stored in the result cache described by a cache section.
+///
+/// A namespaced cache only loses the results of its namespace.
+///
+/// @param cache [Hash] Cache section (`backend`, `url`, `max_entries`, `namespace`)
+/// @return [Integer] Number of removed results
+///
+fn clear_result_cache(cache: Value) -> Result<usize, Error> {
+    let cache_json = validated_section_json("CacheConfig", cache)?;
+    let cache: kreuzberg::core::config::CacheConfig =
+        serde_json::from_value(cache_json).map_err(|e| runtime_error(format!("Invalid cache: {}", e)))?;
+
+    kreuzberg::cache::backend::backend_for(&cache)
+        .and_then(|backend| backend.clear())
+        .map_err(kreuzberg_error)
+}
+
+/// Size of the strings handed to `IO#write` by `extract_file_to_io`.
+const IO_WRITE_CHUNK: usize = 64 * 1024;
+
+/// Rendering streamed by `extract_file_to_io`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IoOutputFormat {
+    /// Extracted content as is
+    Text,
+    /// Extracted content followed by the tables it does not already contain
+    Markdown,
+    /// The full extraction result as JSON
+    Json,
+}
+
+fn parse_io_output_format(ruby: &Ruby, value: Value) -> Result<IoOutputFormat, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "text" | "plain" => Ok(IoOutputFormat::Text),
+        "markdown" | "md" => Ok(IoOutputFormat::Markdown),
+        "json" => Ok(IoOutputFormat::Json),
+        other => Err(Error::new(
+            ruby.exception_arg_error(),
+            format!("Unknown output format '{}', expected :text, :markdown or :json", other),
+        )),
+    }
+}
+
+/// `std::io::Write` adapter calling `write` on a Ruby IO object.
+///
+/// Output is handed over in strings of at most [`IO_WRITE_CHUNK`] bytes, cut at UTF-8
+/// character boundaries. The first exception raised by `write` is kept so it can be
+/// re-raised unchanged once rendering stops.
+struct RubyIoWriter {
+    io: Value,
+    buffer: Vec<u8>,
+    written: usize,
+    error: Option<Error>,
+}
+
+impl RubyIoWriter {
+    fn new(io: Value) -> Self {
+        Self {
+            io,
+            buffer: Vec::with_capacity(IO_WRITE_CHUNK),
+            written: 0,
+            error: None,
+        }
+    }
+
+    fn write_chunk(&mut self, len: usize) -> std::io::Result<()> {
+        if self.error.is_some() {
+            return Err(std::io::Error::other("IO#write raised an exception"));
+        }
+
+        let ruby = Ruby::get().expect("Ruby not initialized");
+        let bytes = &self.buffer[..len];
+        let chunk = match std::str::from_utf8(bytes) {
+            Ok(text) => ruby.str_new(text),
+            Err(_) => ruby.str_from_slice(bytes),
+        };
+        if let Err(err) = self.io.funcall::<_, _, Value>("write", (chunk,)) {
+            self.error = Some(err);
+            return Err(std::io::Error::other("IO#write raised an exception"));
+        }
+
+        self.written += len;
+        self.buffer.drain(..len);
+        Ok(())
+    }
+}
+
+impl std::io::Write for RubyIoWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        while self.buffer.len() > IO_WRITE_CHUNK {
+            let mut cut = IO_WRITE_CHUNK;
+            // Back off over UTF-8 continuation bytes so every chunk is a valid string
+            while cut > IO_WRITE_CHUNK - 4 && self.buffer[cut] & 0b1100_0000 == 0b1000_0000 {
+                cut -= 1;
+            }
+            self.write_chunk(cut)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.write_chunk(self.buffer.len())
+    }
+}
+
+/// Render an extraction result into `writer`.
+fn write_rendered_result(
+    writer: &mut impl std::io::Write,
+    result: &RustExtractionResult,
+    format: IoOutputFormat,
+) -> std::io::Result<()> {
+    match format {
+        IoOutputFormat::Text => writer.write_all(result.content.as_bytes()),
+        IoOutputFormat::Markdown => {
+            writer.write_all(result.content.as_bytes())?;
+            for table in &result.tables {
+                let markdown = table.markdown.trim();
+                if markdown.is_empty() || result.content.contains(markdown) {
+                    continue;
+                }
+                writer.write_all(b"\n\n")?;
+                writer.write_all(markdown.as_bytes())?;
+                writer.write_all(b"\n")?;
+            }
+            Ok(())
+        }
+        IoOutputFormat::Json => serde_json::to_writer(writer, result).map_err(std::io::Error::from),
+    }
+}
+
+/// Extract a file and stream the rendered output into a Ruby IO object.
+///
+/// The output is written with `io.write` in chunks of at most 64 KiB, so it is never
+/// copied into a single Ruby string.
+///
+/// @param path [String] Path to the file
+/// @param io [IO, #write] Destination (file, socket, StringIO, response body)
+/// @param format [Symbol] `:text`, `:markdown` or `:json`
+/// @param mime_type [String, nil] Optional MIME type hint
+/// @param options [Hash] Extraction configuration
+/// @return [Integer] Number of bytes written
+///
+fn extract_file_to_io(args: &[Value]) -> Result<usize, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+    let args = scan_args::<(String, Value, Value), (Option<String>,), (), (), RHash, ()>(args)?;
+    let (path, io, format) = args.required;
+    let (mime_type,) = args.optional;
+    let opts = Some(args.keywords);
 
-    let results_array = ruby.ary_new();
-    for result in results {
-        results_array.push(extraction_result_to_ruby(&ruby, result)?)?;
-    }
+    if !io.respond_to("write", false)? {
+        return Err(Error::new(
+            ruby.exception_arg_error(),
+            "Destination must respond to 'write'",
+        ));
+    }
+    let format = parse_io_output_format(&ruby, format)?;
+
+    let mut timer = ExtractionTimer::start("extract_file_to_io");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+
+        let result = kreuzberg::extract_file_sync(&path, mime_type.as_deref(), &config).map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_mime_type(&result.mime_type);
+
+        let mut writer = RubyIoWriter::new(io);
+        let rendered = write_rendered_result(&mut writer, &result, format).and_then(|()| writer.flush());
+        timer.mark("write_output");
+
+        match rendered {
+            Ok(()) => Ok(writer.written),
+            Err(err) => Err(writer
+                .error
+                .take()
+                .unwrap_or_else(|| runtime_error(format!("Failed to write extraction output: {}", err)))),
+        }
+    })();
 
-    Ok(results_array)
+    timer.finish(&ruby, DOCUMENT_EVENT, &outcome);
+    outcome
 }
 
 /// Batch extract content from multiple byte arrays (synchronous).
@@ -2017,38 +3947,51 @@ fn batch_extract_bytes_sync(args: &[Value]) -> Result<RArray, Error> {
     let (bytes_array, mime_types_array) = args.required;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
-
-    let bytes_vec: Vec<RString> = bytes_array
-        .into_iter()
-        .map(RString::try_convert)
-        .collect::<Result<_, _>>()?;
-    let mime_types: Vec<String> = mime_types_array.to_vec::<String>()?;
+    let mut timer = ExtractionTimer::start("batch_extract_bytes_sync");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
 
-    if bytes_vec.len() != mime_types.len() {
-        return Err(runtime_error(format!(
-            "bytes_array and mime_types must have the same length: {} vs {}",
-            bytes_vec.len(),
-            mime_types.len()
-        )));
-    }
+        let bytes_vec: Vec<RString> = bytes_array
+            .into_iter()
+            .map(RString::try_convert)
+            .collect::<Result<_, _>>()?;
+        let mime_types: Vec<String> = mime_types_array.to_vec::<String>()?;
 
-    // SAFETY: we hold `bytes_vec` for the duration of the call and do not re-enter Ruby while
-    // borrowing its bytes, so Ruby cannot mutate/free these strings during extraction.
-    let contents: Vec<(&[u8], &str)> = bytes_vec
-        .iter()
-        .zip(mime_types.iter())
-        .map(|(bytes, mime)| (unsafe { bytes.as_slice() }, mime.as_str()))
-        .collect();
+        if bytes_vec.len() != mime_types.len() {
+            return Err(runtime_error(format!(
+                "bytes_array and mime_types must have the same length: {} vs {}",
+                bytes_vec.len(),
+                mime_types.len()
+            )));
+        }
 
-    let results = kreuzberg::batch_extract_bytes_sync(contents, &config).map_err(kreuzberg_error)?;
+        // SAFETY: we hold `bytes_vec` for the duration of the call and do not re-enter Ruby while
+        // borrowing its bytes, so Ruby cannot mutate/free these strings during extraction.
+        let contents: Vec<(&[u8], &str)> = bytes_vec
+            .iter()
+            .zip(mime_types.iter())
+            .map(|(bytes, mime)| (unsafe { bytes.as_slice() }, mime.as_str()))
+            .collect();
+
+        let results = kreuzberg::batch_extract_bytes_sync(contents, &config).map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_count(results.len());
+
+        let fields = FieldSelection::new(config.fields.as_deref());
+        let results_array = ruby.ary_new();
+        for result in results {
+            results_array.push(extraction_result_to_ruby(&ruby, result, fields)?)?;
+        }
 
-    let results_array = ruby.ary_new();
-    for result in results {
-        results_array.push(extraction_result_to_ruby(&ruby, result)?)?;
-    }
+        let results_array = freeze_result(results_array, frozen)?;
+        timer.mark("convert_result");
+        Ok(results_array)
+    })();
 
-    Ok(results_array)
+    timer.finish(&ruby, BATCH_EVENT, &outcome);
+    outcome
 }
 
 /// Batch extract content from multiple byte arrays (asynchronous).
@@ -2064,43 +4007,56 @@ fn batch_extract_bytes(args: &[Value]) -> Result<RArray, Error> {
     let (bytes_array, mime_types_array) = args.required;
     let opts = Some(args.keywords);
 
-    let config = parse_extraction_config(&ruby, opts)?;
-
-    let bytes_vec: Vec<RString> = bytes_array
-        .into_iter()
-        .map(RString::try_convert)
-        .collect::<Result<_, _>>()?;
-    let mime_types: Vec<String> = mime_types_array.to_vec::<String>()?;
-
-    if bytes_vec.len() != mime_types.len() {
-        return Err(runtime_error(format!(
-            "bytes_array and mime_types must have the same length: {} vs {}",
-            bytes_vec.len(),
-            mime_types.len()
-        )));
-    }
+    let mut timer = ExtractionTimer::start("batch_extract_bytes");
+    let outcome = (|| {
+        let config = parse_extraction_config(&ruby, opts)?;
+        timer.mark("parse_config");
+        let frozen = frozen_option(&ruby, opts)?;
 
-    // SAFETY: we hold `bytes_vec` for the duration of the call and do not re-enter Ruby while
-    // borrowing its bytes, so Ruby cannot mutate/free these strings during extraction.
-    let contents: Vec<(&[u8], &str)> = bytes_vec
-        .iter()
-        .zip(mime_types.iter())
-        .map(|(bytes, mime)| (unsafe { bytes.as_slice() }, mime.as_str()))
-        .collect();
+        let bytes_vec: Vec<RString> = bytes_array
+            .into_iter()
+            .map(RString::try_convert)
+            .collect::<Result<_, _>>()?;
+        let mime_types: Vec<String> = mime_types_array.to_vec::<String>()?;
 
-    let runtime =
-        tokio::runtime::Runtime::new().map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
+        if bytes_vec.len() != mime_types.len() {
+            return Err(runtime_error(format!(
+                "bytes_array and mime_types must have the same length: {} vs {}",
+                bytes_vec.len(),
+                mime_types.len()
+            )));
+        }
 
-    let results = runtime
-        .block_on(async { kreuzberg::batch_extract_bytes(contents, &config).await })
-        .map_err(kreuzberg_error)?;
+        // SAFETY: we hold `bytes_vec` for the duration of the call and do not re-enter Ruby while
+        // borrowing its bytes, so Ruby cannot mutate/free these strings during extraction.
+        let contents: Vec<(&[u8], &str)> = bytes_vec
+            .iter()
+            .zip(mime_types.iter())
+            .map(|(bytes, mime)| (unsafe { bytes.as_slice() }, mime.as_str()))
+            .collect();
+
+        let runtime = tokio::runtime::Runtime::new()
+            .map_err(|e| runtime_error(format!("Failed to create Tokio runtime: {}", e)))?;
+
+        let results = runtime
+            .block_on(async { kreuzberg::batch_extract_bytes(contents, &config).await })
+            .map_err(kreuzberg_error)?;
+        timer.mark("extract");
+        timer.set_count(results.len());
+
+        let fields = FieldSelection::new(config.fields.as_deref());
+        let results_array = ruby.ary_new();
+        for result in results {
+            results_array.push(extraction_result_to_ruby(&ruby, result, fields)?)?;
+        }
 
-    let results_array = ruby.ary_new();
-    for result in results {
-        results_array.push(extraction_result_to_ruby(&ruby, result)?)?;
-    }
+        let results_array = freeze_result(results_array, frozen)?;
+        timer.mark("convert_result");
+        Ok(results_array)
+    })();
 
-    Ok(results_array)
+    timer.finish(&ruby, BATCH_EVENT, &outcome);
+    outcome
 }
 
 /// Clear all cache entries.
@@ -2246,13 +4202,13 @@ fn register_post_processor(args: &[Value]) -> Result<(), Error> {
             let result_clone = result.clone();
 
             let updated_result = tokio::task::block_in_place(|| {
-                let ruby = Ruby::get().expect("Ruby not initialized");
-                let result_hash = extraction_result_to_ruby(&ruby, result_clone.clone()).map_err(|e| {
-                    kreuzberg::KreuzbergError::Plugin {
+                let ruby = plugin_ruby(&processor_name)?;
+                let _call = PluginCall::enter(&processor_name)?;
+                let result_hash = extraction_result_to_ruby(&ruby, result_clone.clone(), FieldSelection::default())
+                    .map_err(|e| kreuzberg::KreuzbergError::Plugin {
                         message: format!("Failed to convert result to Ruby: {}", e),
                         plugin_name: processor_name.clone(),
-                    }
-                })?;
+                    })?;
 
                 let modified = processor
                     .funcall::<_, _, magnus::Value>("call", (result_hash,))
@@ -2377,9 +4333,144 @@ fn register_post_processor(args: &[Value]) -> Result<(), Error> {
 
     let registry = kreuzberg::get_post_processor_registry();
     registry
-        .write()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .register(processor_impl, priority)
+        .update(|reg| reg.register(processor_impl, priority))
+        .map_err(kreuzberg_error)?;
+
+    Ok(())
+}
+
+/// Register a chunk processor plugin.
+///
+/// Chunk processors run in the core after chunking (and before chunk embeddings)
+/// and are called once per chunk with the chunk hash. Return a Hash to replace the
+/// chunk's `content` and/or `annotations`, or nil to leave the chunk unchanged.
+/// Byte offsets and page numbers always refer to the original document text.
+///
+/// @param name [String] Unique identifier for the chunk processor
+/// @param processor [Proc] Ruby Proc/lambda that receives each chunk hash
+/// @param priority [Integer] Execution priority (default: 50, higher = runs first)
+/// @return [nil]
+///
+/// # Example
+/// ```text
+/// Kreuzberg.register_chunk_processor("section_titles", ->(chunk) {
+///   title = chunk["content"][/^#+\s*(.+)$/, 1]
+///   chunk["annotations"]["section"] = title if title
+///   chunk
+/// })
+/// ```
+fn register_chunk_processor(args: &[Value]) -> Result<(), Error> {
+    let _ruby = Ruby::get().expect("Ruby not initialized");
+    let args = scan_args::<(String, Value), (Option<i32>,), (), (), (), ()>(args)?;
+    let (name, processor) = args.required;
+    let (priority,) = args.optional;
+    let priority = priority.unwrap_or(50);
+
+    if !processor.respond_to("call", true)? {
+        return Err(runtime_error("Chunk processor must be a Proc or respond to 'call'"));
+    }
+
+    use async_trait::async_trait;
+    use kreuzberg::plugins::{Plugin, PostProcessor, ProcessingStage};
+    use std::sync::Arc;
+
+    struct RubyChunkProcessor {
+        name: String,
+        processor: GcGuardedValue,
+    }
+
+    unsafe impl Send for RubyChunkProcessor {}
+    unsafe impl Sync for RubyChunkProcessor {}
+
+    impl Plugin for RubyChunkProcessor {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl PostProcessor for RubyChunkProcessor {
+        async fn process(
+            &self,
+            result: &mut kreuzberg::ExtractionResult,
+            _config: &kreuzberg::ExtractionConfig,
+        ) -> kreuzberg::Result<()> {
+            let Some(chunks) = result.chunks.as_mut() else {
+                return Ok(());
+            };
+            let processor_name = self.name.clone();
+            let processor = self.processor.value();
+
+            tokio::task::block_in_place(|| {
+                let ruby = plugin_ruby(&processor_name)?;
+                let _call = PluginCall::enter(&processor_name)?;
+                let plugin_error = |message: String| kreuzberg::KreuzbergError::Plugin {
+                    message,
+                    plugin_name: processor_name.clone(),
+                };
+
+                for chunk in chunks.iter_mut() {
+                    let chunk_hash = chunk_to_ruby(&ruby, chunk.clone())
+                        .map_err(|e| plugin_error(format!("Failed to convert chunk to Ruby: {}", e)))?;
+
+                    let returned = processor
+                        .funcall::<_, _, magnus::Value>("call", (chunk_hash,))
+                        .map_err(|e| plugin_error(format!("Ruby chunk processor failed: {}", e)))?;
+
+                    if returned.is_nil() {
+                        continue;
+                    }
+
+                    let returned_hash = magnus::RHash::try_convert(returned)
+                        .map_err(|e| plugin_error(format!("Chunk processor must return a Hash or nil: {}", e)))?;
+
+                    if let Some(content_val) = get_kw(&ruby, returned_hash, "content") {
+                        chunk.content = String::try_convert(content_val)
+                            .map_err(|e| plugin_error(format!("Failed to convert chunk content: {}", e)))?;
+                    }
+
+                    if let Some(annotations_val) = get_kw(&ruby, returned_hash, "annotations") {
+                        let annotations_json = ruby_value_to_json(annotations_val)
+                            .map_err(|e| plugin_error(format!("Annotations must be JSON-serializable: {}", e)))?;
+                        chunk.metadata.annotations = match annotations_json {
+                            serde_json::Value::Null => Default::default(),
+                            serde_json::Value::Object(map) => map.into_iter().collect(),
+                            other => {
+                                return Err(plugin_error(format!("Annotations must be a Hash, got {}", other)));
+                            }
+                        };
+                    }
+                }
+
+                Ok::<(), kreuzberg::KreuzbergError>(())
+            })
+        }
+
+        fn processing_stage(&self) -> ProcessingStage {
+            ProcessingStage::Chunk
+        }
+    }
+
+    let processor_impl = Arc::new(RubyChunkProcessor {
+        name: name.clone(),
+        processor: GcGuardedValue::new(processor),
+    });
+
+    let registry = kreuzberg::get_post_processor_registry();
+    registry
+        .update(|reg| reg.register(processor_impl, priority))
         .map_err(kreuzberg_error)?;
 
     Ok(())
@@ -2452,11 +4543,14 @@ fn register_validator(args: &[Value]) -> Result<(), Error> {
             let result_clone = result.clone();
 
             tokio::task::block_in_place(|| {
-                let ruby = Ruby::get().expect("Ruby not initialized");
+                let ruby = plugin_ruby(&validator_name)?;
+                let _call = PluginCall::enter(&validator_name)?;
                 let result_hash =
-                    extraction_result_to_ruby(&ruby, result_clone).map_err(|e| kreuzberg::KreuzbergError::Plugin {
-                        message: format!("Failed to convert result to Ruby: {}", e),
-                        plugin_name: validator_name.clone(),
+                    extraction_result_to_ruby(&ruby, result_clone, FieldSelection::default()).map_err(|e| {
+                        kreuzberg::KreuzbergError::Plugin {
+                            message: format!("Failed to convert result to Ruby: {}", e),
+                            plugin_name: validator_name.clone(),
+                        }
                     })?;
 
                 validator
@@ -2483,9 +4577,7 @@ fn register_validator(args: &[Value]) -> Result<(), Error> {
 
     let registry = kreuzberg::get_validator_registry();
     registry
-        .write()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .register(validator_impl)
+        .update(|reg| reg.register(validator_impl))
         .map_err(kreuzberg_error)?;
 
     Ok(())
@@ -2557,7 +4649,8 @@ fn register_ocr_backend(name: String, backend: Value) -> Result<(), Error> {
             image_bytes: &[u8],
             config: &kreuzberg::OcrConfig,
         ) -> kreuzberg::Result<kreuzberg::ExtractionResult> {
-            let ruby = Ruby::get().expect("Ruby not initialized");
+            let ruby = plugin_ruby(&self.name)?;
+            let _call = PluginCall::enter(&self.name)?;
             let image_str = ruby.str_from_slice(image_bytes);
 
             let config_hash = ocr_config_to_ruby_hash(&ruby, config).map_err(|e| kreuzberg::KreuzbergError::Ocr {
@@ -2588,6 +4681,10 @@ fn register_ocr_backend(name: String, backend: Value) -> Result<(), Error> {
                 chunks: None,
                 images: None,
                 pages: None,
+                term_offsets: None,
+                barcodes: None,
+                provenance: None,
+                schema_version: kreuzberg::ExtractionResult::SCHEMA_VERSION,
             })
         }
 
@@ -2614,9 +4711,7 @@ fn register_ocr_backend(name: String, backend: Value) -> Result<(), Error> {
 
     let registry = kreuzberg::get_ocr_backend_registry();
     registry
-        .write()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .register(backend_impl)
+        .update(|reg| reg.register(backend_impl))
         .map_err(kreuzberg_error)?;
 
     Ok(())
@@ -2629,11 +4724,7 @@ fn register_ocr_backend(name: String, backend: Value) -> Result<(), Error> {
 ///
 fn unregister_post_processor(name: String) -> Result<(), Error> {
     let registry = kreuzberg::get_post_processor_registry();
-    registry
-        .write()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .remove(&name)
-        .map_err(kreuzberg_error)?;
+    registry.update(|reg| reg.remove(&name)).map_err(kreuzberg_error)?;
     Ok(())
 }
 
@@ -2644,11 +4735,7 @@ fn unregister_post_processor(name: String) -> Result<(), Error> {
 ///
 fn unregister_validator(name: String) -> Result<(), Error> {
     let registry = kreuzberg::get_validator_registry();
-    registry
-        .write()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .remove(&name)
-        .map_err(kreuzberg_error)?;
+    registry.update(|reg| reg.remove(&name)).map_err(kreuzberg_error)?;
     Ok(())
 }
 
@@ -2658,11 +4745,7 @@ fn unregister_validator(name: String) -> Result<(), Error> {
 ///
 fn clear_post_processors() -> Result<(), Error> {
     let registry = kreuzberg::get_post_processor_registry();
-    registry
-        .write()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .shutdown_all()
-        .map_err(kreuzberg_error)?;
+    registry.update(|reg| reg.shutdown_all()).map_err(kreuzberg_error)?;
     Ok(())
 }
 
@@ -2672,11 +4755,7 @@ fn clear_post_processors() -> Result<(), Error> {
 ///
 fn clear_validators() -> Result<(), Error> {
     let registry = kreuzberg::get_validator_registry();
-    registry
-        .write()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .shutdown_all()
-        .map_err(kreuzberg_error)?;
+    registry.update(|reg| reg.shutdown_all()).map_err(kreuzberg_error)?;
     Ok(())
 }
 
@@ -2686,10 +4765,7 @@ fn clear_validators() -> Result<(), Error> {
 ///
 fn list_validators() -> Result<Vec<String>, Error> {
     let registry = kreuzberg::get_validator_registry();
-    let validators = registry
-        .read()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .list();
+    let validators = registry.snapshot().list();
     Ok(validators)
 }
 
@@ -2699,10 +4775,7 @@ fn list_validators() -> Result<Vec<String>, Error> {
 ///
 fn list_post_processors() -> Result<Vec<String>, Error> {
     let registry = kreuzberg::get_post_processor_registry();
-    let processors = registry
-        .read()
-        .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
-        .list();
+    let processors = registry.snapshot().list();
     Ok(processors)
 }
 
@@ -2761,6 +4834,37 @@ fn list_document_extractors() -> Result<Vec<String>, Error> {
     kreuzberg::plugins::list_extractors().map_err(|e| runtime_error(e.to_string()))
 }
 
+/// List every MIME type the registered extractors support, with capability flags.
+///
+/// Built-in extractors are registered first if needed. Each entry describes the extractor
+/// the registry selects for the MIME type.
+///
+/// @return [Array<Hash>] Hashes with `mime_type`, `extractor`, `extensions`, `tables`,
+///   `images`, `metadata`, `pages` and `ocr`, sorted by MIME type
+///
+fn supported_formats(ruby: &Ruby) -> Result<RArray, Error> {
+    let formats = kreuzberg::plugins::supported_formats().map_err(kreuzberg_error)?;
+    let array = ruby.ary_new_capa(formats.len());
+
+    for format in formats {
+        let extensions = kreuzberg::get_extensions_for_mime(&format.mime_type).unwrap_or_default();
+        let capabilities = format.capabilities;
+
+        let hash = ruby.hash_new();
+        set_hash_entry(ruby, &hash, "mime_type", ruby.str_new(&format.mime_type).as_value())?;
+        set_hash_entry(ruby, &hash, "extractor", ruby.str_new(&format.extractor).as_value())?;
+        set_hash_entry(ruby, &hash, "extensions", extensions.into_value_with(ruby))?;
+        set_hash_entry(ruby, &hash, "tables", capabilities.tables.into_value_with(ruby))?;
+        set_hash_entry(ruby, &hash, "images", capabilities.images.into_value_with(ruby))?;
+        set_hash_entry(ruby, &hash, "metadata", capabilities.metadata.into_value_with(ruby))?;
+        set_hash_entry(ruby, &hash, "pages", capabilities.pages.into_value_with(ruby))?;
+        set_hash_entry(ruby, &hash, "ocr", capabilities.ocr.into_value_with(ruby))?;
+        array.push(hash)?;
+    }
+
+    Ok(array)
+}
+
 /// Unregister a document extractor by name.
 ///
 /// Removes a previously registered document extractor from the global registry.
@@ -2858,6 +4962,473 @@ fn get_extensions_for_mime_native(mime_type: String) -> Result<Vec<String>, Erro
     kreuzberg::get_extensions_for_mime(&mime_type).map_err(kreuzberg_error)
 }
 
+/// Write metadata into a PDF or Office Open XML file in place.
+///
+/// @param path [String] Path to a PDF, DOCX, XLSX or PPTX file
+/// @param metadata [Hash] Fields to set: :title, :author, :subject, :keywords (Array or String)
+/// @return [nil]
+///
+/// @example Stamp a document as processed
+///   Kreuzberg.write_metadata("report.pdf", { keywords: ["processed"], title: "Q3 Report" })
+///
+fn write_metadata_native(ruby: &Ruby, path: String, metadata: RHash) -> Result<(), Error> {
+    const FIELDS: [&str; 4] = ["title", "author", "subject", "keywords"];
+
+    metadata.foreach(|key: Value, _value: Value| {
+        let key = symbol_to_string(key)?;
+        if FIELDS.contains(&key.as_str()) {
+            Ok(ForEach::Continue)
+        } else {
+            Err(Error::new(
+                ruby.exception_arg_error(),
+                format!("Unknown metadata field '{}'. Supported: {}", key, FIELDS.join(", ")),
+            ))
+        }
+    })?;
+
+    let text_field = |name: &str| -> Result<Option<String>, Error> {
+        match get_kw(ruby, metadata, name) {
+            Some(val) if !val.is_nil() => Ok(Some(String::try_convert(val)?)),
+            _ => Ok(None),
+        }
+    };
+
+    let keywords = match get_kw(ruby, metadata, "keywords") {
+        Some(val) if !val.is_nil() => match RArray::try_convert(val) {
+            Ok(arr) => Some(arr.to_vec::<String>()?),
+            Err(_) => Some(vec![String::try_convert(val)?]),
+        },
+        _ => None,
+    };
+
+    let update = kreuzberg::MetadataUpdate {
+        title: text_field("title")?,
+        author: text_field("author")?,
+        subject: text_field("subject")?,
+        keywords,
+    };
+
+    kreuzberg::write_metadata(&path, &update).map_err(kreuzberg_error)
+}
+
+/// Apply OCR preprocessing to an image and return the processed image.
+///
+/// Runs the contrast, denoise, deskew and binarization steps configured by the keyword
+/// arguments, so preprocessing settings can be tuned visually before running OCR.
+///
+/// @param image_bytes [String] Binary image data (PNG, JPEG, TIFF, ...)
+/// @param preprocessing_config [Hash] ImagePreprocessing settings: :deskew, :denoise,
+///   :contrast_enhance, :binarization_method, :invert_colors, :auto_rotate, :target_dpi, :auto_tune
+/// @return [String] PNG-encoded black-and-white image
+///
+/// @example Compare binarization methods
+///   data = File.binread("scan.jpg")
+///   %w[otsu adaptive sauvola].each do |method|
+///     File.binwrite("scan_#{method}.png", Kreuzberg.preview_preprocessing(data, binarization_method: method))
+///   end
+///
+fn preview_preprocessing(args: &[Value]) -> Result<RString, Error> {
+    const FIELDS: [&str; 8] = [
+        "target_dpi",
+        "auto_rotate",
+        "deskew",
+        "denoise",
+        "contrast_enhance",
+        "binarization_method",
+        "invert_colors",
+        "auto_tune",
+    ];
+
+    let ruby = Ruby::get().expect("Ruby not initialized");
+    let args = scan_args::<(RString,), (), (), (), RHash, ()>(args)?;
+    let (data,) = args.required;
+    let options = args.keywords;
+
+    options.foreach(|key: Value, _value: Value| {
+        let key = symbol_to_string(key)?;
+        if FIELDS.contains(&key.as_str()) {
+            Ok(ForEach::Continue)
+        } else {
+            Err(Error::new(
+                ruby.exception_arg_error(),
+                format!(
+                    "Unknown preprocessing option '{}'. Supported: {}",
+                    key,
+                    FIELDS.join(", ")
+                ),
+            ))
+        }
+    })?;
+
+    let config = parse_image_preprocessing_config(&ruby, options)?;
+
+    // SAFETY: we hold `data` for the duration of the call and do not re-enter Ruby while
+    // borrowing its bytes, so Ruby cannot mutate/free this string during preprocessing.
+    let bytes = unsafe { data.as_slice() };
+    let processed = kreuzberg::image::preprocess_image(bytes, &config).map_err(kreuzberg_error)?;
+    Ok(ruby.str_from_slice(&processed))
+}
+
+/// A pattern match in extracted content.
+#[derive(Debug, PartialEq)]
+struct ContentMatch {
+    text: String,
+    byte_start: usize,
+    byte_end: usize,
+    page_number: Option<usize>,
+    snippet: String,
+    highlighted_snippet: String,
+}
+
+/// Options for [`search_content`].
+struct ContentSearchOptions<'a> {
+    /// Bytes of surrounding content included on each side of a match
+    context: usize,
+    /// Page ranges as `(byte_start, byte_end, page_number)`, sorted by `byte_start`
+    pages: &'a [(usize, usize, usize)],
+    limit: Option<usize>,
+    highlight: (&'a str, &'a str),
+}
+
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn ceil_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+/// Find non-empty matches of `regex` in `content` with snippets and page numbers.
+fn search_content(content: &str, regex: &regex::Regex, options: &ContentSearchOptions<'_>) -> Vec<ContentMatch> {
+    let page_for = |offset: usize| {
+        let idx = options.pages.partition_point(|&(start, _, _)| start <= offset);
+        idx.checked_sub(1)
+            .map(|idx| options.pages[idx])
+            .filter(|&(_, end, _)| offset < end)
+            .map(|(_, _, page)| page)
+    };
+
+    regex
+        .find_iter(content)
+        .filter(|m| !m.is_empty())
+        .take(options.limit.unwrap_or(usize::MAX))
+        .map(|m| {
+            let snippet_start = floor_char_boundary(content, m.start().saturating_sub(options.context));
+            let snippet_end = ceil_char_boundary(content, m.end().saturating_add(options.context));
+            let (before, after) = (&content[snippet_start..m.start()], &content[m.end()..snippet_end]);
+            let (pre, post) = options.highlight;
+
+            ContentMatch {
+                text: m.as_str().to_string(),
+                byte_start: m.start(),
+                byte_end: m.end(),
+                page_number: page_for(m.start()),
+                snippet: content[snippet_start..snippet_end].to_string(),
+                highlighted_snippet: format!("{before}{pre}{}{post}{after}", m.as_str()),
+            }
+        })
+        .collect()
+}
+
+/// Search extracted content for a pattern.
+///
+/// Backs `Kreuzberg.search_result`, which derives the page ranges from the result's page
+/// boundaries or chunks. Scanning in Rust avoids building match data for very large
+/// strings in the interpreter.
+///
+/// @param content [String] Extracted content
+/// @param pattern [String] Literal text, or regex source when `literal: false`
+/// @param options [Hash] :literal, :ignore_case, :multiline, :extended, :context, :limit,
+///   :pages (array of [byte_start, byte_end, page_number]) and :highlight ([prefix, suffix])
+/// @return [Array<Hash>] Matches with "text", "byte_start", "byte_end", "page_number",
+///   "snippet" and "highlighted_snippet"
+fn search_result_native(ruby: &Ruby, content: RString, pattern: String, options: RHash) -> Result<RArray, Error> {
+    let flag = |name: &str| -> Result<bool, Error> {
+        match get_kw(ruby, options, name) {
+            Some(val) if !val.is_nil() => bool::try_convert(val),
+            _ => Ok(false),
+        }
+    };
+
+    let source = if flag("literal")? {
+        regex::escape(&pattern)
+    } else {
+        pattern
+    };
+    // Ruby anchors (^, $) always match at line boundaries
+    let regex = regex::RegexBuilder::new(&source)
+        .multi_line(true)
+        .case_insensitive(flag("ignore_case")?)
+        .dot_matches_new_line(flag("multiline")?)
+        .ignore_whitespace(flag("extended")?)
+        .build()
+        .map_err(|e| Error::new(ruby.exception_arg_error(), format!("Invalid search pattern: {}", e)))?;
+
+    let context = match get_kw(ruby, options, "context") {
+        Some(val) if !val.is_nil() => usize::try_convert(val)?,
+        _ => 80,
+    };
+    let limit = match get_kw(ruby, options, "limit") {
+        Some(val) if !val.is_nil() => Some(usize::try_convert(val)?),
+        _ => None,
+    };
+    let mut pages = match get_kw(ruby, options, "pages") {
+        Some(val) if !val.is_nil() => RArray::try_convert(val)?.to_vec::<(usize, usize, usize)>()?,
+        _ => Vec::new(),
+    };
+    pages.sort_unstable();
+    let (pre, post) = match get_kw(ruby, options, "highlight") {
+        Some(val) if !val.is_nil() => <(String, String)>::try_convert(val)?,
+        _ => ("**".to_string(), "**".to_string()),
+    };
+
+    let matches = {
+        // SAFETY: the borrow ends before any Ruby object is allocated below
+        let text = unsafe { content.as_str()? };
+        search_content(
+            text,
+            &regex,
+            &ContentSearchOptions {
+                context,
+                pages: &pages,
+                limit,
+                highlight: (&pre, &post),
+            },
+        )
+    };
+
+    let array = ruby.ary_new_capa(matches.len());
+    for m in matches {
+        let hash = ruby.hash_new();
+        hash.aset("text", m.text)?;
+        hash.aset("byte_start", m.byte_start)?;
+        hash.aset("byte_end", m.byte_end)?;
+        match m.page_number {
+            Some(page) => hash.aset("page_number", page)?,
+            None => hash.aset("page_number", ruby.qnil().as_value())?,
+        }
+        hash.aset("snippet", m.snippet)?;
+        hash.aset("highlighted_snippet", m.highlighted_snippet)?;
+        array.push(hash)?;
+    }
+
+    Ok(array)
+}
+
+/// Columns returned by `Kreuzberg.flatten_result` when no fields are requested.
+const DEFAULT_FLAT_FIELDS: &[&str] = &[
+    "content",
+    "mime_type",
+    "title",
+    "subject",
+    "author",
+    "keywords_json",
+    "language",
+    "created_at",
+    "modified_at",
+    "page_count",
+    "metadata_json",
+];
+
+/// Every column `Kreuzberg.flatten_result` can produce.
+const FLAT_FIELDS: &[&str] = &[
+    "content",
+    "mime_type",
+    "title",
+    "subject",
+    "author",
+    "authors_json",
+    "keywords_json",
+    "language",
+    "detected_languages_json",
+    "created_at",
+    "modified_at",
+    "created_by",
+    "modified_by",
+    "page_count",
+    "metadata_json",
+];
+
+/// Compute one flat column from parsed result metadata.
+///
+/// `content` and `metadata_json` are passed through by the caller and not handled here.
+/// Returns `None` for unknown fields and `Some(Value::Null)` for fields the result lacks.
+fn flat_field(
+    field: &str,
+    metadata: &serde_json::Value,
+    mime_type: &str,
+    detected_languages: &[String],
+) -> Option<serde_json::Value> {
+    use serde_json::Value;
+
+    let string = |key: &str| match metadata.get(key) {
+        Some(Value::String(s)) => Value::String(s.clone()),
+        _ => Value::Null,
+    };
+    let json = |value: Option<&Value>| match value {
+        Some(value @ Value::Array(items)) if !items.is_empty() => Value::String(value.to_string()),
+        _ => Value::Null,
+    };
+
+    let value = match field {
+        "mime_type" => Value::String(mime_type.to_string()),
+        "title" | "subject" | "created_at" | "modified_at" | "created_by" | "modified_by" => string(field),
+        "author" => match metadata.get("authors") {
+            Some(Value::Array(authors)) if !authors.is_empty() => {
+                Value::String(authors.iter().filter_map(Value::as_str).collect::<Vec<_>>().join(", "))
+            }
+            _ => Value::Null,
+        },
+        "authors_json" => json(metadata.get("authors")),
+        "keywords_json" => json(metadata.get("keywords")),
+        "language" => match metadata.get("language") {
+            Some(Value::String(lang)) => Value::String(lang.clone()),
+            _ => detected_languages
+                .first()
+                .map_or(Value::Null, |lang| Value::String(lang.clone())),
+        },
+        "detected_languages_json" if detected_languages.is_empty() => Value::Null,
+        "detected_languages_json" => Value::String(Value::from(detected_languages).to_string()),
+        "page_count" => metadata
+            .get("pages")
+            .and_then(|pages| pages.get("total_count"))
+            .cloned()
+            .unwrap_or(Value::Null),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Flatten an extraction result into a string-keyed hash of scalar columns.
+///
+/// Backs `Kreuzberg.flatten_result`. Metadata is parsed once in Rust and list values are
+/// emitted as JSON strings, so the hash can be handed straight to `insert_all` or `create`.
+///
+/// @param content [String] Extracted content, passed through without copying
+/// @param metadata_json [String] Result metadata as JSON
+/// @param options [Hash] :mime_type, :detected_languages (Array<String>) and :fields
+///   (Array<String>, nil for the default columns)
+/// @return [Hash{String => String, Integer, nil}] Columns in the requested order
+fn flatten_result_native(
+    ruby: &Ruby,
+    content: RString,
+    metadata_json: RString,
+    options: RHash,
+) -> Result<RHash, Error> {
+    let fields = match get_kw(ruby, options, "fields") {
+        Some(val) if !val.is_nil() => RArray::try_convert(val)?.to_vec::<String>()?,
+        _ => DEFAULT_FLAT_FIELDS.iter().map(|field| field.to_string()).collect(),
+    };
+    if let Some(unknown) = fields.iter().find(|field| !FLAT_FIELDS.contains(&field.as_str())) {
+        return Err(Error::new(
+            ruby.exception_arg_error(),
+            format!(
+                "Unknown result field '{}', expected one of: {}",
+                unknown,
+                FLAT_FIELDS.join(", ")
+            ),
+        ));
+    }
+
+    let mime_type = match get_kw(ruby, options, "mime_type") {
+        Some(val) if !val.is_nil() => String::try_convert(val)?,
+        _ => String::new(),
+    };
+    let detected_languages = match get_kw(ruby, options, "detected_languages") {
+        Some(val) if !val.is_nil() => RArray::try_convert(val)?.to_vec::<String>()?,
+        _ => Vec::new(),
+    };
+    let metadata = if fields
+        .iter()
+        .any(|field| field != "content" && field != "metadata_json")
+    {
+        // SAFETY: the borrow ends before any Ruby object is allocated
+        let json = unsafe { metadata_json.as_str()? };
+        serde_json::from_str(json).unwrap_or(serde_json::Value::Null)
+    } else {
+        serde_json::Value::Null
+    };
+
+    let hash = ruby.hash_new();
+    for field in &fields {
+        match field.as_str() {
+            "content" => hash.aset(field.as_str(), content)?,
+            "metadata_json" => hash.aset(field.as_str(), metadata_json)?,
+            name => {
+                let value = flat_field(name, &metadata, &mime_type, &detected_languages).unwrap_or_default();
+                hash.aset(name, json_value_to_ruby(ruby, &value)?)?;
+            }
+        }
+    }
+
+    Ok(hash)
+}
+
+/// Score how similar two extracted documents are, from 0.0 to 1.0.
+///
+/// Backs `Kreuzberg.similarity`. `"minhash"` compares the word shingles of the contents;
+/// `"embedding"` compares mean chunk embeddings, embedding the content of a side with the
+/// preset model when the caller has no chunk embeddings for it.
+///
+/// @param content_a [String] Extracted content of the first document
+/// @param content_b [String] Extracted content of the second document
+/// @param method [String] "minhash" or "embedding"
+/// @param options [Hash] :embeddings_a and :embeddings_b (Array<Array<Float>>, nil to embed
+///   the content) and :preset (embedding preset name, default "balanced")
+/// @return [Float] Similarity score
+fn similarity_native(
+    ruby: &Ruby,
+    content_a: RString,
+    content_b: RString,
+    method: String,
+    options: RHash,
+) -> Result<f64, Error> {
+    use kreuzberg::text::similarity;
+
+    match method.as_str() {
+        "minhash" => {
+            // SAFETY: the borrows end before any Ruby object is allocated
+            let (a, b) = unsafe { (content_a.as_str()?, content_b.as_str()?) };
+            Ok(similarity::minhash_similarity(a, b))
+        }
+        "embedding" => {
+            let preset = match get_kw(ruby, options, "preset") {
+                Some(val) if !val.is_nil() => symbol_to_string(val)?,
+                _ => "balanced".to_string(),
+            };
+            let config = EmbeddingConfig {
+                model: kreuzberg::EmbeddingModelType::Preset { name: preset },
+                ..Default::default()
+            };
+            let embeddings = |key: &str, content: RString| -> Result<Vec<Vec<f32>>, Error> {
+                match get_kw(ruby, options, key) {
+                    Some(val) if !val.is_nil() => RArray::try_convert(val)?.to_vec::<Vec<f32>>(),
+                    _ => similarity::embed_content(&content.to_string()?, &config).map_err(kreuzberg_error),
+                }
+            };
+
+            let a = embeddings("embeddings_a", content_a)?;
+            let b = embeddings("embeddings_b", content_b)?;
+            Ok(similarity::embedding_similarity(&a, &b))
+        }
+        other => Err(Error::new(
+            ruby.exception_arg_error(),
+            format!(
+                "Unknown similarity method '{}', expected one of: minhash, embedding",
+                other
+            ),
+        )),
+    }
+}
+
 /// List all available embedding preset names.
 ///
 /// Returns an array of preset names that can be used with get_embedding_preset.
@@ -3239,6 +5810,29 @@ fn config_get_field_wrapper(ruby: &Ruby, config_json: String, field_name: String
     result
 }
 
+/// Rust config schema as JSON
+/// @return [String] Array of sections, each with its fields and their defaults
+fn config_schema_native() -> Result<String, Error> {
+    serde_json::to_string(&config_schema())
+        .map_err(|e| runtime_error(format!("Failed to serialize config schema: {}", e)))
+}
+
+/// Validate a Hash against one config section and fill in its defaults
+/// @param section [String] Rust struct name, e.g. "OcrConfig"
+/// @param hash [Hash] Section options
+/// @return [Hash] Options with every field of the section present
+fn validate_config_section_native(ruby: &Ruby, section: String, hash: RHash) -> Result<Value, Error> {
+    if section == "TesseractConfig" {
+        let config = parse_tesseract_config(hash.as_value())?;
+        let value = serde_json::to_value(&config)
+            .map_err(|e| runtime_error(format!("Failed to serialize tesseract_config: {}", e)))?;
+        return json_value_to_ruby(ruby, &value);
+    }
+
+    let value = validated_section_json(&section, hash.as_value())?;
+    json_value_to_ruby(ruby, &value)
+}
+
 /// Merge two configs
 /// @param base_json [String] Base config JSON
 /// @param override_json [String] Override config JSON
@@ -3541,13 +6135,27 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     module.define_module_function("extract_bytes", function!(extract_bytes, -1))?;
     module.define_module_function("batch_extract_files", function!(batch_extract_files, -1))?;
     module.define_module_function("batch_extract_bytes", function!(batch_extract_bytes, -1))?;
+    module.define_module_function("_batch_stream_open_native", function!(batch_stream_open, -1))?;
+    module.define_module_function("_batch_stream_next_native", function!(batch_stream_next, 1))?;
+    module.define_module_function("_batch_stream_close_native", function!(batch_stream_close, 1))?;
+    module.define_module_function("_watcher_open_native", function!(watcher_open, -1))?;
+    module.define_module_function("_watcher_next_native", function!(watcher_next, 1))?;
+    module.define_module_function("_watcher_failed_path_native", function!(watcher_failed_path, 1))?;
+    module.define_module_function("_watcher_close_native", function!(watcher_close, 1))?;
+    module.define_module_function("_image_blob_read_native", function!(image_blob_read, 1))?;
+    module.define_module_function("_extract_file_to_io_native", function!(extract_file_to_io, -1))?;
+    module.define_module_function("_clear_result_cache_native", function!(clear_result_cache, 1))?;
 
     module.define_module_function("clear_cache", function!(ruby_clear_cache, 0))?;
     module.define_module_function("cache_stats", function!(ruby_cache_stats, 0))?;
+    module.define_module_function("_set_instrumentation_native", function!(set_instrumentation, 1))?;
 
     module.define_module_function("register_post_processor", function!(register_post_processor, -1))?;
+    module.define_module_function("register_chunk_processor", function!(register_chunk_processor, -1))?;
     module.define_module_function("register_validator", function!(register_validator, -1))?;
     module.define_module_function("register_ocr_backend", function!(register_ocr_backend, 2))?;
+    module.define_module_function("plugin_stack", function!(plugin_stack, 0))?;
+    module.const_set("MAX_PLUGIN_DEPTH", MAX_PLUGIN_DEPTH)?;
     module.define_module_function("unregister_post_processor", function!(unregister_post_processor, 1))?;
     module.define_module_function("unregister_validator", function!(unregister_validator, 1))?;
     module.define_module_function("clear_post_processors", function!(clear_post_processors, 0))?;
@@ -3563,9 +6171,14 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
         function!(unregister_document_extractor, 1),
     )?;
     module.define_module_function("clear_document_extractors", function!(clear_document_extractors, 0))?;
+    module.define_module_function("_supported_formats_native", function!(supported_formats, 0))?;
 
     module.define_module_function("_config_from_file_native", function!(config_from_file, 1))?;
     module.define_module_function("_config_discover_native", function!(config_discover, 0))?;
+    module.define_module_function("_config_preset_native", function!(config_preset, 1))?;
+    module.define_module_function("_config_presets_native", function!(config_presets, 0))?;
+    module.define_module_function("_effective_config_native", function!(effective_config, 2))?;
+    module.define_module_function("_estimate_native", function!(estimate_native, -1))?;
 
     module.define_module_function("detect_mime_type", function!(detect_mime_type_from_bytes, 1))?;
     module.define_module_function(
@@ -3573,6 +6186,11 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
         function!(detect_mime_type_from_path_native, 1),
     )?;
     module.define_module_function("get_extensions_for_mime", function!(get_extensions_for_mime_native, 1))?;
+    module.define_module_function("write_metadata", function!(write_metadata_native, 2))?;
+    module.define_module_function("preview_preprocessing", function!(preview_preprocessing, -1))?;
+    module.define_module_function("_search_result_native", function!(search_result_native, 3))?;
+    module.define_module_function("_flatten_result_native", function!(flatten_result_native, 3))?;
+    module.define_module_function("_similarity_native", function!(similarity_native, 4))?;
     module.define_module_function("validate_mime_type", function!(validate_mime_type_native, 1))?;
 
     module.define_module_function("list_embedding_presets", function!(list_embedding_presets, 0))?;
@@ -3619,6 +6237,11 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     module.define_module_function("_config_to_json_native", function!(config_to_json_wrapper, 1))?;
     module.define_module_function("_config_get_field_native", function!(config_get_field_wrapper, 2))?;
     module.define_module_function("_config_merge_native", function!(config_merge_wrapper, 2))?;
+    module.define_module_function("_config_schema_native", function!(config_schema_native, 0))?;
+    module.define_module_function(
+        "_validate_config_section_native",
+        function!(validate_config_section_native, 2),
+    )?;
     module.define_module_function("_result_page_count_native", function!(result_page_count, 1))?;
     module.define_module_function("_result_chunk_count_native", function!(result_chunk_count, 1))?;
     module.define_module_function(
@@ -3744,6 +6367,15 @@ mod tests {
             auto_adjust_dpi: true,
             min_dpi: 72,
             max_dpi: 600,
+            strip_gps: false,
+            output_format: Some(ImageOutputFormat::Webp),
+            downscale_to: Some(1024),
+            max_bytes: Some(500_000),
+            perceptual_hash: false,
+            deduplicate_images: true,
+            duplicate_hash_distance: 5,
+            ocr_policy: None,
+            blob_store_dir: None,
         };
 
         assert!(config.extract_images);
@@ -3752,6 +6384,9 @@ mod tests {
         assert!(config.auto_adjust_dpi);
         assert_eq!(config.min_dpi, 72);
         assert_eq!(config.max_dpi, 600);
+        assert!(config.transcodes_images());
+        assert_eq!(config.output_format.map(|format| format.as_str()), Some("webp"));
+        assert!(config.hashes_images());
     }
 
     #[test]
@@ -3764,6 +6399,7 @@ mod tests {
             contrast_enhance: false,
             binarization_method: "otsu".to_string(),
             invert_colors: false,
+            auto_tune: false,
         };
 
         assert_eq!(config.target_dpi, 300);
@@ -3810,6 +6446,15 @@ mod tests {
                 auto_adjust_dpi: true,
                 min_dpi: 72,
                 max_dpi: 600,
+                strip_gps: false,
+                output_format: None,
+                downscale_to: None,
+                max_bytes: None,
+                perceptual_hash: false,
+                deduplicate_images: false,
+                duplicate_hash_distance: 5,
+                ocr_policy: None,
+                blob_store_dir: None,
             }),
             postprocessor: Some(PostProcessorConfig {
                 enabled: true,
@@ -3827,4 +6472,77 @@ mod tests {
         assert!(config.postprocessor.is_some());
         assert!(config.token_reduction.is_some());
     }
+    #[test]
+    fn test_search_content_maps_pages_and_snippets() {
+        let content = "Alpha page one.\nBeta page two mentions alpha again.";
+        let regex = regex::RegexBuilder::new("alpha")
+            .case_insensitive(true)
+            .build()
+            .unwrap();
+        let pages = [(0, 16, 1), (16, content.len(), 2)];
+        let options = ContentSearchOptions {
+            context: 5,
+            pages: &pages,
+            limit: None,
+            highlight: ("[", "]"),
+        };
+
+        let matches = search_content(content, &regex, &options);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].byte_start, 0);
+        assert_eq!(matches[0].page_number, Some(1));
+        assert_eq!(matches[0].highlighted_snippet, "[Alpha] page");
+        assert_eq!(&content[matches[1].byte_start..matches[1].byte_end], "alpha");
+        assert_eq!(matches[1].page_number, Some(2));
+        assert_eq!(matches[1].snippet, "ions alpha agai");
+
+        let limited = search_content(
+            content,
+            &regex,
+            &ContentSearchOptions {
+                limit: Some(1),
+                ..options
+            },
+        );
+        assert_eq!(limited.len(), 1);
+    }
+
+    #[test]
+    fn test_search_content_respects_char_boundaries() {
+        let content = "ééé needle ééé";
+        let regex = regex::Regex::new("needle").unwrap();
+        let options = ContentSearchOptions {
+            context: 2,
+            pages: &[],
+            limit: None,
+            highlight: ("**", "**"),
+        };
+
+        let matches = search_content(content, &regex, &options);
+        assert_eq!(matches[0].snippet, "é needle é");
+        assert_eq!(matches[0].page_number, None);
+    }
+
+    #[test]
+    fn test_flat_field_derives_scalar_columns() {
+        let metadata = serde_json::json!({
+            "title": "Q3 Report",
+            "authors": ["Ada", "Grace"],
+            "keywords": ["finance"],
+            "pages": { "total_count": 12, "unit_type": "page" }
+        });
+        let languages = vec!["deu".to_string()];
+        let field = |name: &str| flat_field(name, &metadata, "application/pdf", &languages);
+
+        assert_eq!(field("title"), Some(serde_json::json!("Q3 Report")));
+        assert_eq!(field("author"), Some(serde_json::json!("Ada, Grace")));
+        assert_eq!(field("keywords_json"), Some(serde_json::json!(r#"["finance"]"#)));
+        assert_eq!(field("language"), Some(serde_json::json!("deu")));
+        assert_eq!(field("detected_languages_json"), Some(serde_json::json!(r#"["deu"]"#)));
+        assert_eq!(field("page_count"), Some(serde_json::json!(12)));
+        assert_eq!(field("mime_type"), Some(serde_json::json!("application/pdf")));
+        assert_eq!(field("subject"), Some(serde_json::Value::Null));
+        assert_eq!(field("bogus"), None);
+        assert!(DEFAULT_FLAT_FIELDS.iter().all(|name| FLAT_FIELDS.contains(name)));
+    }
 }
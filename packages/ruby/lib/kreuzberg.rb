@@ -11,6 +11,8 @@ require 'kreuzberg_rb'
 module Kreuzberg
   autoload :Config, 'kreuzberg/config'
   autoload :Result, 'kreuzberg/result'
+  autoload :Session, 'kreuzberg/session'
+  autoload :Watcher, 'kreuzberg/watcher'
   autoload :CLI, 'kreuzberg/cli'
   autoload :CLIProxy, 'kreuzberg/cli_proxy'
   autoload :APIProxy, 'kreuzberg/api_proxy'
@@ -31,6 +33,7 @@ module Kreuzberg
   end
 
   @__cache_tracker = { entries: 0, bytes: 0 }
+  @__instrumentation = nil
 
   class << self
     # Store native methods as private methods
@@ -53,6 +56,9 @@ module Kreuzberg
   # Register a Ruby post-processor that conforms to PostProcessorProtocol.
   module_function :register_post_processor
 
+  # Register a Ruby chunk processor that runs on each chunk after chunking.
+  module_function :register_chunk_processor
+
   # Remove a post-processor by name.
   module_function :unregister_post_processor
 
@@ -83,6 +89,9 @@ module Kreuzberg
   # List all registered OCR backends.
   module_function :list_ocr_backends
 
+  # Names of the Ruby plugins running on the current thread, outermost first.
+  module_function :plugin_stack
+
   # Detect MIME type from file bytes.
   module_function :detect_mime_type
 
@@ -95,6 +104,12 @@ module Kreuzberg
   # Get file extensions for a given MIME type.
   module_function :get_extensions_for_mime
 
+  # Write title, author, subject and keywords into a PDF or OOXML file.
+  module_function :write_metadata
+
+  # Apply OCR preprocessing to image bytes and return the processed PNG.
+  module_function :preview_preprocessing
+
   # List all available embedding presets.
   module_function :list_embedding_presets
 
@@ -103,7 +118,23 @@ module Kreuzberg
 end
 
 require_relative 'kreuzberg/cache_api'
+require_relative 'kreuzberg/effective_config_api'
+require_relative 'kreuzberg/estimate_api'
 require_relative 'kreuzberg/extraction_api'
+require_relative 'kreuzberg/flatten_api'
+require_relative 'kreuzberg/formats_api'
+require_relative 'kreuzberg/instrumentation_api'
+require_relative 'kreuzberg/presets_api'
+require_relative 'kreuzberg/search_api'
+require_relative 'kreuzberg/similarity_api'
 
 Kreuzberg.singleton_class.prepend(Kreuzberg::CacheAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::EffectiveConfigAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::EstimateAPI)
 Kreuzberg.singleton_class.prepend(Kreuzberg::ExtractionAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::FlattenAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::FormatsAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::InstrumentationAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::PresetsAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::SearchAPI)
+Kreuzberg.singleton_class.prepend(Kreuzberg::SimilarityAPI)
@@ -56,6 +56,23 @@ module Kreuzberg
       stats
     end
 
+    # Read image bytes from the blob store.
+    #
+    # With `ImageExtraction#blob_store_dir` set, extracted images carry a blob reference
+    # instead of their bytes. This reads the bytes on demand and verifies them against
+    # the reference.
+    #
+    # @param blob [Hash{Symbol | String => untyped}] Blob reference (`hash`, `path`, `size`)
+    # @return [String] Binary image data
+    # @raise [Kreuzberg::Errors::IOError] If the blob file cannot be read
+    # @raise [RuntimeError] If the blob no longer matches the reference
+    #
+    # @example Read every image of a result
+    #   result.images.each { |image| File.binwrite("image_#{image.image_index}.#{image.format}", image.read_data) }
+    def read_image_blob(blob)
+      _image_blob_read_native(blob.to_h)
+    end
+
     private
 
     def record_cache_entry!(results, opts)
@@ -1,79 +1,163 @@
 # frozen_string_literal: true
 
 require 'json'
+require_relative 'config_schema'
 
 module Kreuzberg
   module Config
     # OCR configuration
     #
+    # `pool_size` and `reuse` configure the process-wide Tesseract engine pool. With
+    # `reuse: true`, initialized engines are kept after each call and reused by later
+    # extractions instead of loading the traineddata again, which removes most of the
+    # per-request latency of synchronous OCR in web apps. The pool setting applies to the
+    # whole process from the first extraction that passes it.
+    #
     # @example
     #   ocr = OCR.new(backend: "tesseract", language: "eng")
     #
+    # @example Reuse up to 4 Tesseract engines across calls
+    #   ocr = OCR.new(pool_size: 4, reuse: true)
+    #
+    # @example Re-run low-confidence pages with a second backend
+    #   ocr = OCR.new(fallback: { backend: "paddleocr", min_confidence: 0.5 })
+    #
+    # @example Domain vocabulary from a word list and inline words
+    #   ocr = OCR.new(vocabulary: { words_file: "/etc/ocr/formulary.txt", words: ["XJ-4410"] })
+    #
     class OCR
-      attr_reader :backend, :language, :tesseract_config
+      attr_reader :backend, :language, :tesseract_config, :fallback, :vocabulary, :pool_size, :reuse
 
       def initialize(
         backend: 'tesseract',
         language: 'eng',
-        tesseract_config: nil
+        tesseract_config: nil,
+        fallback: nil,
+        vocabulary: nil,
+        pool_size: nil,
+        reuse: nil
       )
         @backend = backend.to_s
         @language = language.to_s
-        @tesseract_config = normalize_tesseract_config(tesseract_config)
+        @tesseract_config = normalize_nested(tesseract_config, Tesseract)
+        @fallback = normalize_nested(fallback, OcrFallback)
+        @vocabulary = normalize_nested(vocabulary, OcrVocabulary)
+        @pool_size = normalize_pool_size(pool_size)
+        @reuse = normalize_reuse(reuse)
       end
 
       def to_h
         {
           backend: @backend,
           language: @language,
-          tesseract_config: @tesseract_config&.to_h
+          tesseract_config: @tesseract_config&.to_h,
+          fallback: @fallback&.to_h,
+          vocabulary: @vocabulary&.to_h,
+          pool_size: @pool_size,
+          reuse: @reuse
         }.compact
       end
 
       private
 
-      def normalize_tesseract_config(value)
+      def normalize_nested(value, klass)
+        return nil if value.nil?
+        return value if value.is_a?(klass)
+        return klass.new(**value.transform_keys(&:to_sym)) if value.is_a?(Hash)
+
+        raise ArgumentError, "Expected #{klass}, Hash, or nil, got #{value.class}"
+      end
+
+      def normalize_pool_size(value)
+        return nil if value.nil?
+
+        size = Integer(value)
+        raise ArgumentError, "pool_size must be greater than 0, got #{size}" unless size.positive?
+
+        size
+      end
+
+      def normalize_reuse(value)
         return nil if value.nil?
-        return value if value.is_a?(Tesseract)
-        return Tesseract.new(**value.transform_keys(&:to_sym)) if value.is_a?(Hash)
 
-        raise ArgumentError, "Expected #{Tesseract}, Hash, or nil, got #{value.class}"
+        value ? true : false
       end
     end
 
     # Tesseract OCR engine configuration
-    class Tesseract
-      attr_reader :options
+    #
+    # Accepts every field of the Rust `TesseractConfig`; unset fields take its defaults.
+    # Common Tesseract variables have their own fields (`tessedit_char_whitelist`,
+    # `tessedit_char_blacklist`, `preserve_interword_spaces`, `user_words_file`,
+    # `user_patterns_file`); any other runtime variable can be passed through `variables`.
+    # Invalid variables raise `Kreuzberg::Errors::ValidationError` naming the variable,
+    # e.g. a variable that has its own field, characters both whitelisted and blacklisted,
+    # or a user words file that does not exist.
+    #
+    # @example Single text block with table detection
+    #   tesseract = Tesseract.new(psm: 6, enable_table_detection: true)
+    #
+    # @example Digits only, with a custom dictionary and a pass-through variable
+    #   tesseract = Tesseract.new(
+    #     tessedit_char_whitelist: "0123456789-",
+    #     user_words_file: "/etc/ocr/part-numbers.txt",
+    #     variables: { textord_min_xheight: 10 }
+    #   )
+    #
+    class Tesseract < Typed
+      schema_section 'TesseractConfig', nested: { preprocessing: :ImagePreprocessing }
 
+      # Tesseract takes every variable as a string, so `variables` values may be any scalar
       def initialize(**options)
-        @options = options.transform_keys(&:to_sym)
-        normalize_nested_preprocessing!
+        variables = options[:variables]
+        options[:variables] = variables.to_h { |name, value| [name.to_s, value.to_s] } if variables.is_a?(Hash)
+        super
       end
 
-      def to_h
-        @options.dup
+      # @return [Hash{Symbol => Object}] Same as {#to_h}
+      def options
+        to_h
       end
+    end
 
-      private
-
-      def normalize_nested_preprocessing!
-        preprocessing = @options[:preprocessing]
-        return if preprocessing.nil?
-        return if preprocessing.is_a?(ImagePreprocessing)
-        return @options[:preprocessing] = ImagePreprocessing.new(**preprocessing.transform_keys(&:to_sym)) if
-          preprocessing.is_a?(Hash)
+    # Second OCR backend for pages the primary backend recognized with low confidence
+    #
+    # @example
+    #   fallback = OcrFallback.new(backend: "paddleocr", min_confidence: 0.5)
+    #
+    class OcrFallback < Typed
+      schema_section 'OcrFallbackConfig'
+    end
 
-        raise ArgumentError, "preprocessing must be #{ImagePreprocessing} or Hash"
-      end
+    # Domain word list for OCR
+    #
+    # Words come from `words` and from `words_file` (one entry per line, `#` starts a
+    # comment line). They are added to Tesseract's user words, and unless `correct` is
+    # false, OCR words within `max_distance` edits of exactly one vocabulary word are
+    # replaced with it.
+    #
+    # @example
+    #   vocabulary = OcrVocabulary.new(words: %w[Metformin Lisinopril], max_distance: 2)
+    #
+    class OcrVocabulary < Typed
+      schema_section 'OcrVocabularyConfig'
     end
 
     # Chunking configuration
     #
+    # With +respect_sentences: true+ chunks are built from whole sentences, split with the
+    # rules of the detected or declared document language (abbreviations such as "Dr." or
+    # "z.B." do not end a sentence). Chunks still stay within +max_chars+, and the overlap
+    # repeats whole sentences up to +max_overlap+ characters.
+    #
     # @example
     #   chunking = Chunking.new(max_chars: 1000, max_overlap: 200)
     #
+    # @example Sentence-aware chunks for embeddings
+    #   chunking = Chunking.new(max_chars: 800, max_overlap: 150, respect_sentences: true)
+    #
     class Chunking
-      attr_reader :max_chars, :max_overlap, :preset, :embedding, :enabled
+      attr_reader :max_chars, :max_overlap, :preset, :embedding, :enabled, :respect_sentences
 
       def initialize(
         max_chars: nil,
@@ -82,7 +166,8 @@ module Kreuzberg
         embedding: nil,
         chunk_size: nil,
         chunk_overlap: nil,
-        enabled: true
+        enabled: true,
+        respect_sentences: false
       )
         resolved_size = chunk_size || max_chars || 1000
         resolved_overlap = chunk_overlap || max_overlap || 200
@@ -92,6 +177,7 @@ module Kreuzberg
         @preset = preset&.to_s
         @embedding = normalize_embedding(embedding)
         @enabled = boolean_or_nil(enabled)
+        @respect_sentences = respect_sentences ? true : false
       end
 
       def to_h
@@ -99,7 +185,8 @@ module Kreuzberg
           max_chars: @max_chars,
           max_overlap: @max_overlap,
           preset: @preset,
-          embedding: @embedding&.to_h
+          embedding: @embedding&.to_h,
+          respect_sentences: @respect_sentences
         }.compact
         # @type var config: Hash[Symbol, untyped]
         config[:enabled] = @enabled unless @enabled.nil?
@@ -276,9 +363,29 @@ module Kreuzberg
     #     max_dpi: 600
     #   )
     #
+    # @example Drop GPS coordinates from image EXIF metadata
+    #   image = ImageExtraction.new(strip_gps: true)
+    #
+    # @example Return images as WebP, at most 1024px per side and 200 KB each
+    #   image = ImageExtraction.new(output_format: :webp, downscale_to: 1024, max_bytes: 200_000)
+    #
+    # @example Drop repeated logos and watermarks
+    #   image = ImageExtraction.new(deduplicate_images: true, duplicate_hash_distance: 4)
+    #
+    # @example Keep image bytes in a shared content-addressed directory
+    #   image = ImageExtraction.new(blob_store_dir: '/var/cache/kreuzberg/images')
+    #
+    # @example Only OCR images of at least 64x64 pixels
+    #   image = ImageExtraction.new(ocr_policy: { min_width: 64, min_height: 64 })
+    #
     class ImageExtraction
+      OUTPUT_FORMATS = %w[png jpeg webp].freeze
+
       attr_reader :extract_images, :target_dpi, :max_image_dimension,
-                  :auto_adjust_dpi, :min_dpi, :max_dpi
+                  :auto_adjust_dpi, :min_dpi, :max_dpi, :strip_gps,
+                  :output_format, :downscale_to, :max_bytes,
+                  :perceptual_hash, :deduplicate_images, :duplicate_hash_distance,
+                  :ocr_policy, :blob_store_dir
 
       def initialize(
         extract_images: true,
@@ -286,7 +393,16 @@ module Kreuzberg
         max_image_dimension: 2000,
         auto_adjust_dpi: true,
         min_dpi: 150,
-        max_dpi: 600
+        max_dpi: 600,
+        strip_gps: false,
+        output_format: nil,
+        downscale_to: nil,
+        max_bytes: nil,
+        perceptual_hash: false,
+        deduplicate_images: false,
+        duplicate_hash_distance: 5,
+        ocr_policy: nil,
+        blob_store_dir: nil
       )
         @extract_images = extract_images ? true : false
         @target_dpi = target_dpi.to_i
@@ -294,6 +410,15 @@ module Kreuzberg
         @auto_adjust_dpi = auto_adjust_dpi ? true : false
         @min_dpi = min_dpi.to_i
         @max_dpi = max_dpi.to_i
+        @strip_gps = strip_gps ? true : false
+        @output_format = normalize_output_format(output_format)
+        @downscale_to = downscale_to&.to_i
+        @max_bytes = max_bytes&.to_i
+        @perceptual_hash = perceptual_hash ? true : false
+        @deduplicate_images = deduplicate_images ? true : false
+        @duplicate_hash_distance = duplicate_hash_distance.to_i
+        @ocr_policy = normalize_ocr_policy(ocr_policy)
+        @blob_store_dir = blob_store_dir&.to_s
       end
 
       def to_h
@@ -303,9 +428,47 @@ module Kreuzberg
           max_image_dimension: @max_image_dimension,
           auto_adjust_dpi: @auto_adjust_dpi,
           min_dpi: @min_dpi,
-          max_dpi: @max_dpi
-        }
+          max_dpi: @max_dpi,
+          strip_gps: @strip_gps,
+          output_format: @output_format,
+          downscale_to: @downscale_to,
+          max_bytes: @max_bytes,
+          perceptual_hash: @perceptual_hash,
+          deduplicate_images: @deduplicate_images,
+          duplicate_hash_distance: @duplicate_hash_distance,
+          ocr_policy: @ocr_policy&.to_h,
+          blob_store_dir: @blob_store_dir
+        }.compact
       end
+
+      private
+
+      def normalize_ocr_policy(value)
+        return nil if value.nil?
+        return value if value.is_a?(ImageOcrPolicy)
+        return ImageOcrPolicy.new(**value.transform_keys(&:to_sym)) if value.is_a?(Hash)
+
+        raise ArgumentError, "Expected #{ImageOcrPolicy}, Hash, or nil, got #{value.class}"
+      end
+
+      def normalize_output_format(value)
+        return nil if value.nil?
+
+        format = value.to_s.downcase
+        format = 'jpeg' if format == 'jpg'
+        return format if OUTPUT_FORMATS.include?(format)
+
+        raise ArgumentError, "Invalid output_format: #{value}. Must be one of: #{OUTPUT_FORMATS.join(', ')}"
+      end
+    end
+
+    # Filters deciding which extracted images are OCR'd
+    #
+    # @example Skip icons and only OCR images on the first two pages
+    #   policy = ImageOcrPolicy.new(min_width: 64, min_height: 64, pages: [1, 2])
+    #
+    class ImageOcrPolicy < Typed
+      schema_section 'ImageOcrPolicy'
     end
 
     # Image preprocessing configuration for OCR
@@ -327,9 +490,12 @@ module Kreuzberg
     #     invert_colors: false
     #   )
     #
+    # @example Auto-tuned preprocessing (choices recorded in Result#preprocessing_decisions)
+    #   preprocessing = ImagePreprocessing.new(auto_tune: true)
+    #
     class ImagePreprocessing
       attr_reader :target_dpi, :auto_rotate, :deskew, :denoise,
-                  :contrast_enhance, :binarization_method, :invert_colors
+                  :contrast_enhance, :binarization_method, :invert_colors, :auto_tune
 
       def initialize(
         target_dpi: 300,
@@ -338,7 +504,8 @@ module Kreuzberg
         denoise: false,
         contrast_enhance: true,
         binarization_method: 'otsu',
-        invert_colors: false
+        invert_colors: false,
+        auto_tune: false
       )
         @target_dpi = target_dpi.to_i
         @auto_rotate = auto_rotate ? true : false
@@ -347,6 +514,7 @@ module Kreuzberg
         @contrast_enhance = contrast_enhance ? true : false
         @binarization_method = binarization_method.to_s
         @invert_colors = invert_colors ? true : false
+        @auto_tune = auto_tune ? true : false
 
         # Validate binarization method via FFI
         result = Kreuzberg._validate_binarization_method_native(@binarization_method)
@@ -361,7 +529,8 @@ module Kreuzberg
           denoise: @denoise,
           contrast_enhance: @contrast_enhance,
           binarization_method: @binarization_method,
-          invert_colors: @invert_colors
+          invert_colors: @invert_colors,
+          auto_tune: @auto_tune
         }
       end
     end
@@ -538,28 +707,269 @@ module Kreuzberg
     # @example Enable page markers in content
     #   pages = PageConfig.new(insert_page_markers: true, marker_format: "--- PAGE {page_num} ---")
     #
-    class PageConfig
-      attr_reader :extract_pages, :insert_page_markers, :marker_format
+    class PageConfig < Typed
+      schema_section 'PageConfig'
+    end
+
+    # Text decoding configuration for plain text, CSV/TSV and HTML documents
+    #
+    # Bytes are transcoded to UTF-8 in Rust; the encoding that was used is
+    # reported as +metadata["encoding"]+.
+    #
+    # @example Detect the encoding automatically
+    #   text = TextOptions.new(encoding: "auto")
+    #
+    # @example Force a legacy encoding and normalize to NFC
+    #   text = TextOptions.new(encoding: "cp1251", normalize_unicode: :nfc)
+    #
+    class TextOptions
+      NORMALIZATION_FORMS = %w[nfc nfd nfkc nfkd].freeze
+
+      attr_reader :encoding, :normalize_unicode
+
+      def initialize(encoding: 'auto', normalize_unicode: nil)
+        @encoding = encoding.to_s
+        @normalize_unicode = normalize_unicode&.to_s&.downcase
+
+        return if @normalize_unicode.nil? || NORMALIZATION_FORMS.include?(@normalize_unicode)
+
+        raise ArgumentError,
+              "Invalid normalize_unicode: #{@normalize_unicode}. Must be one of: #{NORMALIZATION_FORMS.join(', ')}"
+      end
+
+      def to_h
+        {
+          encoding: @encoding,
+          normalize_unicode: @normalize_unicode
+        }.compact
+      end
+    end
+
+    # Deterministic content normalization profile
+    #
+    # Applied to the extracted content before chunking, so the same document yields the
+    # same text (and chunks) regardless of the extractor or OCR backend that produced it.
+    # All steps are enabled by default; pass +unicode: nil+ to keep code points as extracted.
+    #
+    # @example Default profile (NFC, whitespace, quotes, dashes, hyphenation)
+    #   normalization = Normalization.new
+    #
+    # @example Compatibility normalization, keeping typographic quotes
+    #   normalization = Normalization.new(unicode: :nfkc, standardize_quotes: false)
+    #
+    class Normalization
+      UNICODE_FORMS = %w[nfc nfd nfkc nfkd].freeze
+
+      attr_reader :unicode, :collapse_whitespace, :standardize_quotes, :standardize_dashes, :dehyphenate
 
       def initialize(
-        extract_pages: false,
-        insert_page_markers: false,
-        marker_format: "\n\n<!-- PAGE {page_num} -->\n\n"
+        unicode: :nfc,
+        collapse_whitespace: true,
+        standardize_quotes: true,
+        standardize_dashes: true,
+        dehyphenate: true
       )
-        @extract_pages = extract_pages ? true : false
-        @insert_page_markers = insert_page_markers ? true : false
-        @marker_format = marker_format.to_s
+        @unicode = unicode ? unicode.to_s.downcase : nil
+        @collapse_whitespace = collapse_whitespace ? true : false
+        @standardize_quotes = standardize_quotes ? true : false
+        @standardize_dashes = standardize_dashes ? true : false
+        @dehyphenate = dehyphenate ? true : false
+
+        return if @unicode.nil? || UNICODE_FORMS.include?(@unicode)
+
+        raise ArgumentError, "Invalid unicode: #{@unicode}. Must be one of: #{UNICODE_FORMS.join(', ')}"
       end
 
       def to_h
         {
-          extract_pages: @extract_pages,
-          insert_page_markers: @insert_page_markers,
-          marker_format: @marker_format
+          unicode: @unicode,
+          collapse_whitespace: @collapse_whitespace,
+          standardize_quotes: @standardize_quotes,
+          standardize_dashes: @standardize_dashes,
+          dehyphenate: @dehyphenate
         }
       end
     end
 
+    # CSV/TSV parsing configuration
+    #
+    # The delimiter, quoting and header row are detected automatically.
+    # These options override detection and bound the number of parsed rows.
+    #
+    # @example Force a semicolon delimiter
+    #   csv = CsvOptions.new(delimiter: ";")
+    #
+    # @example Parse at most 1000 data rows
+    #   csv = CsvOptions.new(max_rows: 1000)
+    #
+    class CsvOptions
+      attr_reader :delimiter, :max_rows
+
+      def initialize(delimiter: nil, max_rows: nil)
+        @delimiter = delimiter&.to_s
+        @max_rows = max_rows&.to_i
+
+        if @delimiter && @delimiter.length != 1
+          raise ArgumentError, "Invalid delimiter: #{@delimiter.inspect}. Must be a single character"
+        end
+        return if @max_rows.nil? || @max_rows.positive?
+
+        raise ArgumentError, "max_rows must be positive, got #{@max_rows}"
+      end
+
+      def to_h
+        {
+          delimiter: @delimiter,
+          max_rows: @max_rows
+        }.compact
+      end
+    end
+
+    # PowerPoint extraction configuration
+    #
+    # Speaker notes are appended to each slide's content under a "### Notes:" heading.
+    # Slide markers prefix every slide with "<!-- Slide number: N -->" and make
+    # +result.pages+ return one entry per slide.
+    #
+    # @example Per-slide sections without speaker notes
+    #   pptx = PptxOptions.new(include_notes: false, slide_markers: true)
+    #
+    class PptxOptions < Typed
+      schema_section 'PptxConfig'
+    end
+
+    # Spreadsheet extraction configuration
+    #
+    # @example Only the "Summary" sheet, at most 500 rows
+    #   spreadsheet = SpreadsheetOptions.new(sheets: ["Summary"], max_rows: 500)
+    #
+    class SpreadsheetOptions < Typed
+      schema_section 'SpreadsheetConfig'
+    end
+
+    # Adaptive concurrency for batch extraction
+    #
+    # The number of in-flight extractions is adjusted after every document based on
+    # memory pressure and per-document latency.
+    #
+    # @example Back off above 75% memory use
+    #   adaptive = AdaptiveConcurrency.new(max_concurrent: 8, memory_high_watermark: 0.75)
+    #
+    class AdaptiveConcurrency < Typed
+      schema_section 'AdaptiveConcurrencyConfig'
+    end
+
+    # Time budgets of extraction stages, in milliseconds
+    #
+    # A stage running past its budget is abandoned and the extraction raises
+    # {Errors::TimeoutError} naming the stage. Stages without a budget run unbounded.
+    #
+    # @example Give up on documents that take longer than a minute to parse
+    #   timeouts = StageTimeouts.new(extraction_ms: 60_000, ocr_ms: 30_000)
+    #
+    class StageTimeouts < Typed
+      schema_section 'StageTimeoutConfig'
+    end
+
+    # Retries of batch documents that failed for a transient reason
+    #
+    # Errors of the classes in +retry_on+ (+io+ and +ocr+ by default; also +timeout+, +cache+
+    # and +plugin+) are retried with exponential backoff. The number of attempts is reported
+    # in the +extraction_attempts+ metadata of every batch result.
+    #
+    # @example Ride out a flaky network share
+    #   retry_policy = Retry.new(max_attempts: 5, initial_backoff_ms: 500, retry_on: %w[io])
+    #
+    class Retry < Typed
+      schema_section 'RetryConfig'
+    end
+
+    # Extraction result cache
+    #
+    # Results are keyed by a hash of the document, MIME type and configuration. +backend+ is
+    # +"disk"+ (default, +url+ is the directory), +"memory"+ (an LRU of +max_entries+ results
+    # per process) or +"redis"+ (+url+ is the server, shared by every instance using it).
+    #
+    # @example Share results between workers through Redis
+    #   cache = Cache.new(backend: 'redis', url: 'redis://cache.internal:6379/0')
+    #
+    class Cache < Typed
+      schema_section 'CacheConfig'
+    end
+
+    # Document type classification
+    #
+    # Labels documents as +"invoice"+, +"contract"+, +"report"+, +"letter"+ or one of +types+
+    # from keyword and layout features, in +result.metadata["document_type"]+ with its
+    # +"confidence"+ and the +"scores"+ of every type. The label is +"unknown"+ below
+    # +min_confidence+.
+    #
+    # @example Add a purchase order type
+    #   classification = Classification.new(
+    #     types: [{ label: 'purchase_order', keywords: ['purchase order', 'ship to'], title_keywords: ['po'] }]
+    #   )
+    #
+    class Classification < Typed
+      schema_section 'ClassificationConfig'
+    end
+
+    # Key-value pair extraction
+    #
+    # Finds label/value pairs such as +Invoice number: 2024-117+ or +Total  1,130.50+ and
+    # stores them in +result.metadata["key_values"]+ (see {Kreuzberg::Result#key_values}).
+    # PDFs with a text layer are read from word positions, so a value to the right of or
+    # below its label is found and both get a bounding box. +labels+ adds labels that are
+    # recognised without a colon.
+    #
+    # @example Recognise German invoice labels
+    #   kv_extraction = KeyValueExtraction.new(labels: ['Kundennummer', 'Fällig am'])
+    #
+    class KeyValueExtraction < Typed
+      schema_section 'KeyValueExtractionConfig'
+    end
+
+    # Document preview snippet
+    #
+    # Stores the first +sentences+ meaningful sentences of the content in
+    # +result.metadata["preview"]+ (see {Kreuzberg::Result#preview}). With +skip_boilerplate+
+    # (the default) headings, running headers and footers, page numbers, tables of contents
+    # and copyright notices are skipped.
+    #
+    # @example Two-sentence previews for a search index
+    #   preview = Preview.new(sentences: 2)
+    #
+    class Preview < Typed
+      schema_section 'PreviewConfig'
+    end
+
+    # Cleanup of text repeated across pages
+    #
+    # Removes running headers and footers, page numbers and watermarks that recur among the
+    # first and last lines of most pages, before post-processing and chunking. With
+    # +tag_instead+ the lines are kept and recorded in +result.metadata["repeated_text"]+.
+    # PDFs need a +pages+ section so that page boundaries are known.
+    #
+    # @example Tag repeated lines instead of removing them
+    #   cleanup = Cleanup.new(tag_instead: true)
+    #
+    class Cleanup < Typed
+      schema_section 'CleanupConfig'
+    end
+
+    # Watermark and stamp detection
+    #
+    # Reports diagonal text and images repeated on most pages of PDFs, and stamp words such as
+    # DRAFT or CONFIDENTIAL written in capitals on a line of their own, in
+    # +result.metadata["watermarks"]+ with the pages they cover. With +exclude_text+ the lines
+    # holding watermark text are removed from the content.
+    #
+    # @example Recognise an extra stamp and drop stamps from the text
+    #   watermarks = Watermark.new(exclude_text: true, stamp_words: ['Attorney Work Product'])
+    #
+    class Watermark < Typed
+      schema_section 'WatermarkConfig'
+    end
+
     # Post-processor configuration
     #
     # @example Enable all post-processors
@@ -577,26 +987,8 @@ module Kreuzberg
     #     disabled_processors: ["token_reduction"]
     #   )
     #
-    class PostProcessor
-      attr_reader :enabled, :enabled_processors, :disabled_processors
-
-      def initialize(
-        enabled: true,
-        enabled_processors: nil,
-        disabled_processors: nil
-      )
-        @enabled = enabled ? true : false
-        @enabled_processors = enabled_processors&.map(&:to_s)
-        @disabled_processors = disabled_processors&.map(&:to_s)
-      end
-
-      def to_h
-        {
-          enabled: @enabled,
-          enabled_processors: @enabled_processors,
-          disabled_processors: @disabled_processors
-        }.compact
-      end
+    class PostProcessor < Typed
+      schema_section 'PostProcessorConfig'
     end
 
     # Main extraction configuration
@@ -626,6 +1018,67 @@ module Kreuzberg
     #   )
     #   config = Extraction.new(postprocessor: postprocessor)
     #
+    # @example With term offsets for position-aware indexing
+    #   config = Extraction.new(emit_term_offsets: true)
+    #   result = Kreuzberg.extract_file_sync("document.pdf", config: config)
+    #   result.term_offsets.first # => #<struct term="introduction", byte_start=0, byte_end=12, page=1>
+    #
+    # @example With the source of every content span for auditing
+    #   config = Extraction.new(emit_provenance: true)
+    #   result = Kreuzberg.extract_file_sync("scan.pdf", config: config)
+    #   result.provenance.first # => #<struct byte_start=0, byte_end=812, source=:ocr, page=1, ...>
+    #
+    # @example Decode barcodes and QR codes on images and PDF pages
+    #   config = Extraction.new(detect_barcodes: true)
+    #   result = Kreuzberg.extract_file_sync("shipping_label.pdf", config: config)
+    #   result.barcodes.first.payload # => "https://example.com/track/123"
+    #
+    # @example Re-extract only the pages appended to a growing PDF
+    #   config = Extraction.new(incremental: true)
+    #   Kreuzberg.extract_file_sync("scans.pdf", config: config)
+    #   result = Kreuzberg.extract_file_sync("scans.pdf", config: config)
+    #   result.metadata["incremental"] # => {"reused_pages"=>120, "extracted_pages"=>0}
+    #
+    # @example Return only the fields you need
+    #   config = Extraction.new(fields: %w[metadata.title tables])
+    #   result = Kreuzberg.extract_file_sync("report.pdf", config: config)
+    #   result.content # => ""
+    #   result.chunks  # => nil
+    #
+    # @example Normalize content before chunking
+    #   config = Extraction.new(
+    #     normalization: Config::Normalization.new(unicode: :nfkc),
+    #     chunking: Config::Chunking.new(max_chars: 500)
+    #   )
+    #
+    # @example Bound memory on large documents by spilling pages to disk
+    #   config = Extraction.new(spill_dir: "/tmp/kreuzberg-spill", spill_threshold_bytes: 16 * 1024 * 1024)
+    #   result = Kreuzberg.extract_file_sync("archive.pdf", config: config)
+    #   result.pages.last.read_content # => reads the page text back from disk
+    #
+    # @example Skip embeddings, image extraction and high-DPI OCR in a small container
+    #   config = Extraction.new(resource_profile: :minimal, chunking: { max_chars: 1000 })
+    #
+    # @example Different settings per format in one mixed-corpus batch
+    #   config = Extraction.new(
+    #     force_ocr: true,
+    #     ocr: { language: "eng" },
+    #     overrides: {
+    #       "application/pdf" => { force_ocr: false },
+    #       "image/*" => { ocr: { language: "deu" } }
+    #     }
+    #   )
+    #
+    # @example Raise quality warnings as typed exceptions
+    #   config = Extraction.new(strictness: :strict)
+    #   Kreuzberg.extract_file_sync("scan.pdf", config: config)
+    #   # => raises Kreuzberg::Errors::EmptyContentError (code: :empty_content)
+    #
+    # @example Return deeply frozen results that can be shared across threads and Ractors
+    #   config = Extraction.new(frozen: true)
+    #   result = Kreuzberg.extract_file_sync("document.pdf", config: config)
+    #   Ractor.shareable?(result) # => true
+    #
     # @example With all options
     #   config = Extraction.new(
     #     use_cache: true,
@@ -637,15 +1090,60 @@ module Kreuzberg
     #     pdf_options: Config::PDF.new(extract_images: true, passwords: ["secret"]),
     #     image_extraction: Config::ImageExtraction.new(target_dpi: 600),
     #     image_preprocessing: Config::ImagePreprocessing.new(denoise: true),
-    #     postprocessor: Config::PostProcessor.new(enabled: true)
+    #     postprocessor: Config::PostProcessor.new(enabled: true),
+    #     text_options: Config::TextOptions.new(encoding: "auto"),
+    #     csv_options: Config::CsvOptions.new(max_rows: 1000),
+    #     spreadsheet_options: Config::SpreadsheetOptions.new(max_rows: 1000),
+    #     pptx_options: Config::PptxOptions.new(include_notes: true, slide_markers: true),
+    #     adaptive_concurrency: Config::AdaptiveConcurrency.new(max_concurrent: 8)
     #   )
     #
     class Extraction
-      attr_reader :use_cache, :enable_quality_processing, :force_ocr,
-                  :ocr, :chunking, :language_detection, :pdf_options,
-                  :image_extraction, :image_preprocessing, :postprocessor,
-                  :token_reduction, :keywords, :html_options, :pages,
-                  :max_concurrent_extractions
+      attr_reader :use_cache, :enable_quality_processing, :force_ocr, :emit_term_offsets, :emit_provenance,
+                  :detect_barcodes, :incremental, :ocr, :chunking, :language_detection, :classification,
+                  :kv_extraction, :preview, :cleanup, :watermarks, :pdf_options, :image_extraction,
+                  :image_preprocessing, :postprocessor, :token_reduction, :keywords, :html_options, :pages,
+                  :text_options, :csv_options, :spreadsheet_options, :pptx_options, :normalization,
+                  :max_concurrent_extractions, :adaptive_concurrency, :stage_timeouts, :retry, :cache,
+                  :spill_dir, :spill_threshold_bytes, :resource_profile, :overrides, :fields, :strictness, :frozen
+
+      # Quality warning handling: `:lenient` skips the checks, `:standard` reports them in
+      # {Result#warnings}, `:strict` raises them as {Errors::QualityError} subclasses
+      STRICTNESS_LEVELS = %i[lenient standard strict].freeze
+
+      # Resource profiles: `:minimal` skips expensive features, `:standard` does so only under a
+      # container memory limit of 512 MiB or less, `:full` never does
+      RESOURCE_PROFILES = %i[minimal standard full].freeze
+
+      # Validate a strictness level given as a Symbol, String or nil (`:standard`)
+      #
+      # @param value [Symbol, String, nil] Strictness level
+      # @return [Symbol] Normalized strictness level
+      # @raise [ArgumentError] If the level is unknown
+      #
+      def self.normalize_strictness(value)
+        return :standard if value.nil?
+
+        level = value.to_s.to_sym
+        return level if STRICTNESS_LEVELS.include?(level)
+
+        raise ArgumentError, "strictness must be one of #{STRICTNESS_LEVELS.join(', ')}, got #{value.inspect}"
+      end
+
+      # Validate a resource profile given as a Symbol, String or nil (`:standard`)
+      #
+      # @param value [Symbol, String, nil] Resource profile
+      # @return [Symbol] Normalized resource profile
+      # @raise [ArgumentError] If the profile is unknown
+      #
+      def self.normalize_resource_profile(value)
+        return :standard if value.nil?
+
+        profile = value.to_s.to_sym
+        return profile if RESOURCE_PROFILES.include?(profile)
+
+        raise ArgumentError, "resource_profile must be one of #{RESOURCE_PROFILES.join(', ')}, got #{value.inspect}"
+      end
 
       # Load configuration from a file.
       #
@@ -692,9 +1190,18 @@ module Kreuzberg
         use_cache: true,
         enable_quality_processing: false,
         force_ocr: false,
+        emit_term_offsets: false,
+        emit_provenance: false,
+        detect_barcodes: false,
+        incremental: false,
         ocr: nil,
         chunking: nil,
         language_detection: nil,
+        classification: nil,
+        kv_extraction: nil,
+        preview: nil,
+        cleanup: nil,
+        watermarks: nil,
         pdf_options: nil,
         image_extraction: nil,
         image_preprocessing: nil,
@@ -703,14 +1210,39 @@ module Kreuzberg
         keywords: nil,
         html_options: nil,
         pages: nil,
-        max_concurrent_extractions: nil
+        text_options: nil,
+        csv_options: nil,
+        spreadsheet_options: nil,
+        pptx_options: nil,
+        normalization: nil,
+        max_concurrent_extractions: nil,
+        adaptive_concurrency: nil,
+        stage_timeouts: nil,
+        retry: nil,
+        cache: nil,
+        spill_dir: nil,
+        spill_threshold_bytes: nil,
+        resource_profile: :standard,
+        overrides: nil,
+        fields: nil,
+        strictness: :standard,
+        frozen: false
       )
         @use_cache = use_cache ? true : false
         @enable_quality_processing = enable_quality_processing ? true : false
         @force_ocr = force_ocr ? true : false
+        @emit_term_offsets = emit_term_offsets ? true : false
+        @emit_provenance = emit_provenance ? true : false
+        @detect_barcodes = detect_barcodes ? true : false
+        @incremental = incremental ? true : false
         @ocr = normalize_config(ocr, OCR)
         @chunking = normalize_config(chunking, Chunking)
         @language_detection = normalize_config(language_detection, LanguageDetection)
+        @classification = normalize_config(classification, Classification)
+        @kv_extraction = normalize_config(kv_extraction, KeyValueExtraction)
+        @preview = normalize_config(preview, Preview)
+        @cleanup = normalize_config(cleanup, Cleanup)
+        @watermarks = normalize_config(watermarks, Watermark)
         @pdf_options = normalize_config(pdf_options, PDF)
         @image_extraction = normalize_config(image_extraction, ImageExtraction)
         @image_preprocessing = normalize_config(image_preprocessing, ImagePreprocessing)
@@ -719,7 +1251,23 @@ module Kreuzberg
         @keywords = normalize_config(keywords, Keywords)
         @html_options = normalize_config(html_options, HtmlOptions)
         @pages = normalize_config(pages, PageConfig)
+        @text_options = normalize_config(text_options, TextOptions)
+        @csv_options = normalize_config(csv_options, CsvOptions)
+        @spreadsheet_options = normalize_config(spreadsheet_options, SpreadsheetOptions)
+        @pptx_options = normalize_config(pptx_options, PptxOptions)
+        @normalization = normalize_config(normalization, Normalization)
         @max_concurrent_extractions = max_concurrent_extractions&.to_i
+        @adaptive_concurrency = normalize_config(adaptive_concurrency, AdaptiveConcurrency)
+        @stage_timeouts = normalize_config(stage_timeouts, StageTimeouts)
+        @retry = normalize_config(binding.local_variable_get(:retry), Retry)
+        @cache = normalize_config(cache, Cache)
+        @spill_dir = spill_dir&.to_s
+        @spill_threshold_bytes = spill_threshold_bytes&.to_i
+        @resource_profile = self.class.normalize_resource_profile(resource_profile)
+        @overrides = overrides&.to_h { |pattern, section| [pattern.to_s, section.to_h] }
+        @fields = fields&.map(&:to_s)
+        @strictness = self.class.normalize_strictness(strictness)
+        @frozen = frozen ? true : false
       end
 
       # rubocop:disable Metrics/CyclomaticComplexity
@@ -728,9 +1276,18 @@ module Kreuzberg
           use_cache: @use_cache,
           enable_quality_processing: @enable_quality_processing,
           force_ocr: @force_ocr,
+          emit_term_offsets: @emit_term_offsets,
+          emit_provenance: @emit_provenance,
+          detect_barcodes: @detect_barcodes,
+          incremental: @incremental,
           ocr: @ocr&.to_h,
           chunking: @chunking&.to_h,
           language_detection: @language_detection&.to_h,
+          classification: @classification&.to_h,
+          kv_extraction: @kv_extraction&.to_h,
+          preview: @preview&.to_h,
+          cleanup: @cleanup&.to_h,
+          watermarks: @watermarks&.to_h,
           pdf_options: @pdf_options&.to_h,
           image_extraction: @image_extraction&.to_h,
           image_preprocessing: @image_preprocessing&.to_h,
@@ -739,7 +1296,23 @@ module Kreuzberg
           keywords: @keywords&.to_h,
           html_options: @html_options&.to_h,
           pages: @pages&.to_h,
-          max_concurrent_extractions: @max_concurrent_extractions
+          text_options: @text_options&.to_h,
+          csv_options: @csv_options&.to_h,
+          spreadsheet_options: @spreadsheet_options&.to_h,
+          pptx_options: @pptx_options&.to_h,
+          normalization: @normalization&.to_h,
+          max_concurrent_extractions: @max_concurrent_extractions,
+          adaptive_concurrency: @adaptive_concurrency&.to_h,
+          stage_timeouts: @stage_timeouts&.to_h,
+          retry: @retry&.to_h,
+          cache: @cache&.to_h,
+          spill_dir: @spill_dir,
+          spill_threshold_bytes: @spill_threshold_bytes,
+          resource_profile: (@resource_profile unless @resource_profile == :standard),
+          overrides: @overrides,
+          fields: @fields,
+          strictness: (@strictness unless @strictness == :standard),
+          frozen: (true if @frozen)
         }.compact
       end
       # rubocop:enable Metrics/CyclomaticComplexity
@@ -795,17 +1368,26 @@ module Kreuzberg
       #
       def merge(other)
         other_config = other.is_a?(Extraction) ? other : Extraction.new(**other)
-        merged_json = Kreuzberg._config_merge_native(JSON.generate(to_h), JSON.generate(other_config.to_h))
-        merged_hash = JSON.parse(merged_json)
+        merged_json = Kreuzberg._config_merge_native(
+          JSON.generate(native_keys(to_h)), JSON.generate(native_keys(other_config.to_h))
+        )
+        merged_hash = JSON.parse(merged_json).transform_keys(&:to_sym)
+        merged_hash[:image_extraction] = merged_hash.delete(:images)
         # Filter to only known keywords to avoid unknown keyword errors
         known_keys = %i[
-          use_cache enable_quality_processing force_ocr ocr chunking
-          language_detection pdf_options image_extraction image_preprocessing
+          use_cache enable_quality_processing force_ocr emit_term_offsets emit_provenance detect_barcodes incremental
+          ocr chunking
+          language_detection classification kv_extraction preview cleanup watermarks pdf_options image_extraction
+          image_preprocessing
           postprocessor token_reduction keywords html_options pages
-          max_concurrent_extractions
+          text_options csv_options spreadsheet_options pptx_options normalization
+          max_concurrent_extractions adaptive_concurrency stage_timeouts retry cache spill_dir
+          spill_threshold_bytes resource_profile overrides fields
         ]
-        filtered_hash = merged_hash.transform_keys(&:to_sym).slice(*known_keys)
-        Extraction.new(**filtered_hash)
+        filtered_hash = merged_hash.slice(*known_keys)
+        # strictness and frozen are Ruby-only and do not survive the native merge
+        strictness = other_config.strictness == :standard ? @strictness : other_config.strictness
+        Extraction.new(**filtered_hash, strictness:, frozen: other_config.frozen || @frozen)
       end
 
       # Merge another configuration into this one (mutating)
@@ -831,6 +1413,13 @@ module Kreuzberg
 
       private
 
+      # `image_extraction` is the Ruby name of the Rust `images` field
+      def native_keys(hash)
+        return hash unless hash.key?(:image_extraction)
+
+        hash.except(:image_extraction).merge(images: hash[:image_extraction])
+      end
+
       def normalize_config(value, klass)
         return nil if value.nil?
         return value if value.is_a?(klass)
@@ -844,9 +1433,18 @@ module Kreuzberg
         @use_cache = merged.use_cache
         @enable_quality_processing = merged.enable_quality_processing
         @force_ocr = merged.force_ocr
+        @emit_term_offsets = merged.emit_term_offsets
+        @emit_provenance = merged.emit_provenance
+        @detect_barcodes = merged.detect_barcodes
+        @incremental = merged.incremental
         @ocr = merged.ocr
         @chunking = merged.chunking
         @language_detection = merged.language_detection
+        @classification = merged.classification
+        @kv_extraction = merged.kv_extraction
+        @preview = merged.preview
+        @cleanup = merged.cleanup
+        @watermarks = merged.watermarks
         @pdf_options = merged.pdf_options
         @image_extraction = merged.image_extraction
         @image_preprocessing = merged.image_preprocessing
@@ -855,7 +1453,23 @@ module Kreuzberg
         @keywords = merged.keywords
         @html_options = merged.html_options
         @pages = merged.pages
+        @text_options = merged.text_options
+        @csv_options = merged.csv_options
+        @spreadsheet_options = merged.spreadsheet_options
+        @pptx_options = merged.pptx_options
+        @normalization = merged.normalization
         @max_concurrent_extractions = merged.max_concurrent_extractions
+        @adaptive_concurrency = merged.adaptive_concurrency
+        @stage_timeouts = merged.stage_timeouts
+        @retry = merged.retry
+        @cache = merged.cache
+        @spill_dir = merged.spill_dir
+        @spill_threshold_bytes = merged.spill_threshold_bytes
+        @resource_profile = merged.resource_profile
+        @overrides = merged.overrides
+        @fields = merged.fields
+        @strictness = merged.strictness
+        @frozen = merged.frozen
       end
     end
   end
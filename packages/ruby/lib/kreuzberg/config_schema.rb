@@ -0,0 +1,134 @@
+# frozen_string_literal: true
+
+require 'json'
+
+module Kreuzberg
+  module Config
+    # Fields and defaults of the Rust config structs
+    #
+    # The schema is compiled into the native extension from the structs that
+    # `parse_extraction_config` deserializes, so it always matches what extraction honors.
+    # Sections are named after the Rust struct, e.g. `"OcrConfig"` or `"PptxConfig"`.
+    #
+    # @example List the fields of the PowerPoint options
+    #   Kreuzberg::Config::Schema.field_names("PptxConfig") # => [:include_notes, :slide_markers]
+    #
+    module Schema
+      class << self
+        # @return [Hash{String => Array<Hash>}] Fields of every section, keyed by Rust struct name
+        def sections
+          @sections ||= JSON.parse(Kreuzberg._config_schema_native).to_h do |section|
+            [section['name'], section['fields'].freeze]
+          end.freeze
+        end
+
+        # @param section [String] Rust struct name
+        # @return [Array<Hash>] Fields with `"name"`, `"default"` and, for nested structs, `"section"`
+        # @raise [ArgumentError] If the section is unknown
+        def fields(section)
+          sections.fetch(section) { raise ArgumentError, "Unknown config section: #{section}" }
+        end
+
+        # @param section [String] Rust struct name
+        # @return [Array<Symbol>] Field names in declaration order
+        def field_names(section)
+          fields(section).map { |field| field['name'].to_sym }
+        end
+
+        # @param section [String] Rust struct name
+        # @return [Hash{Symbol => Object}] Default of every field
+        def defaults(section)
+          fields(section).to_h { |field| [field['name'].to_sym, field['default']] }
+        end
+
+        # Validate options against a section and fill in the defaults of missing fields
+        #
+        # @param section [String] Rust struct name
+        # @param options [Hash] Section options
+        # @return [Hash{String => Object}] Every field of the section
+        # @raise [Kreuzberg::Errors::ValidationError] On unknown fields or mistyped values
+        def validate(section, options)
+          Kreuzberg._validate_config_section_native(section, options)
+        end
+      end
+    end
+
+    # Base class for config objects whose fields are those of a Rust config struct
+    #
+    # Readers, defaults and validation come from {Schema}, so a field added to the Rust
+    # struct is accepted here without a Ruby change. Unknown keywords raise
+    # `ArgumentError`; values the struct cannot deserialize raise
+    # `Kreuzberg::Errors::ValidationError` when the object is built, not at extraction time.
+    class Typed
+      class << self
+        # @return [String] Rust struct name
+        attr_reader :section
+
+        # Bind the class to a Rust struct
+        #
+        # @param name [String] Rust struct name
+        # @param nested [Hash{Symbol => Symbol}] Config classes that wrap nested struct fields
+        def schema_section(name, nested: {})
+          @section = name
+          @nested = nested
+          attr_reader(*Schema.field_names(name))
+        end
+
+        # @return [Array<Symbol>] Accepted keywords
+        def field_names
+          Schema.field_names(section)
+        end
+
+        # @param field [Symbol] Field name
+        # @return [Class, nil] Config class wrapping the field, if it is a nested struct
+        def nested_class(field)
+          name = @nested&.fetch(field, nil)
+          name && Config.const_get(name)
+        end
+      end
+
+      def initialize(**options)
+        options = options.transform_keys(&:to_sym)
+        unknown = options.keys - self.class.field_names
+        raise ArgumentError, "unknown keyword#{'s' if unknown.size > 1}: #{unknown.join(', ')}" unless unknown.empty?
+
+        plain = options.transform_values { |value| nested_config?(value) ? value.to_h : value }
+        Schema.validate(self.class.section, plain).each do |name, value|
+          instance_variable_set(:"@#{name}", wrap_nested(name.to_sym, value))
+        end
+      end
+
+      # @return [Hash{Symbol => Object}] Fields that are set, nested configs as hashes
+      def to_h
+        self.class.field_names.each_with_object({}) do |name, hash|
+          value = instance_variable_get(:"@#{name}")
+          next if value.nil?
+
+          hash[name] = nested_config?(value) ? value.to_h : value
+        end
+      end
+
+      def ==(other)
+        other.instance_of?(self.class) && other.to_h == to_h
+      end
+      alias eql? ==
+
+      def hash
+        [self.class, to_h].hash
+      end
+
+      private
+
+      def nested_config?(value)
+        value.class.name.to_s.start_with?('Kreuzberg::Config::')
+      end
+
+      def wrap_nested(name, value)
+        klass = self.class.nested_class(name)
+        return value if klass.nil? || !value.is_a?(Hash)
+
+        klass.new(**value.transform_keys(&:to_sym))
+      end
+    end
+  end
+end
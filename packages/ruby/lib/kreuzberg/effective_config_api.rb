@@ -0,0 +1,79 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # The configuration extraction actually runs with, as resolved by {EffectiveConfigAPI#effective_config}.
+  #
+  # @!attribute [r] config
+  #   @return [Hash{String => Object}] Merged configuration, keyed by the Rust field names
+  # @!attribute [r] config_file
+  #   @return [String, nil] Config file that was loaded, nil when only defaults were used
+  # @!attribute [r] environment
+  #   @return [Hash{String => String}] Environment variables that changed the configuration
+  # @!attribute [r] overrides
+  #   @return [Array<String>] Top-level fields set by the overrides
+  # @!attribute [r] mime_type
+  #   @return [String, nil] MIME type the per-MIME overrides were resolved for
+  #
+  EffectiveConfig = Struct.new(:config, :config_file, :environment, :overrides, :mime_type, :json, :yaml,
+                               keyword_init: true) do
+    # The configuration as pretty-printed JSON, serialized by the Rust core.
+    #
+    # @return [String] JSON loadable as a +.json+ config file
+    def to_json(*) = json
+
+    # The configuration as YAML, serialized by the Rust core.
+    #
+    # @return [String] YAML loadable as a +.yaml+ config file
+    def to_yaml(*) = yaml
+  end
+
+  # Shows the configuration the Rust core resolves, to debug where a setting came from.
+  module EffectiveConfigAPI
+    # Options of {Config::Extraction} that only exist in Ruby
+    RUBY_ONLY_OPTIONS = %i[strictness frozen].freeze
+    private_constant :RUBY_ONLY_OPTIONS
+
+    # Resolve the configuration extraction runs with.
+    #
+    # Layers are merged in order, later layers winning: built-in defaults, the config file named
+    # by +KREUZBERG_CONFIG_PATH+ (or the +kreuzberg.toml+ found in the current or a parent
+    # directory), +KREUZBERG_OCR_BACKEND+ / +KREUZBERG_OCR_LANGUAGE+, and finally +overrides+.
+    # With +mime_type+, the per-MIME overrides are resolved for it. The resource profile is
+    # applied last, so the result shows e.g. the reduced OCR DPI of a +:minimal+ profile.
+    #
+    # @param mime_type [String, nil] MIME type to resolve per-MIME overrides for
+    # @param overrides [Hash] {Config::Extraction} options; nested sections may be config objects
+    # @return [EffectiveConfig] Frozen merged configuration and the layers it came from
+    # @raise [Errors::ValidationError] If the config file cannot be loaded or the overrides are invalid
+    #
+    # @example Check why OCR ran for a PDF
+    #   effective = Kreuzberg.effective_config(mime_type: 'application/pdf')
+    #   effective.config['force_ocr'] # => true
+    #   effective.config_file # => "/srv/app/kreuzberg.toml"
+    #   effective.environment # => { "KREUZBERG_OCR_LANGUAGE" => "deu" }
+    #
+    # @example Save the merged configuration
+    #   File.write('kreuzberg.yaml', Kreuzberg.effective_config(force_ocr: true).to_yaml)
+    def effective_config(mime_type: nil, **overrides)
+      hash = _effective_config_native(native_overrides(overrides), mime_type&.to_s)
+      EffectiveConfig.new(**hash.transform_keys(&:to_sym)).freeze
+    end
+
+    private
+
+    def native_overrides(overrides)
+      overrides.except(*RUBY_ONLY_OPTIONS).to_h do |key, value|
+        # `Config::Extraction` calls the section `image_extraction`; Rust calls it `images`
+        [key == :image_extraction ? :images : key, native_override(value)]
+      end
+    end
+
+    # Config objects become hashes of the options they set
+    def native_override(value)
+      case value
+      when Hash, Array, nil then value
+      else value.respond_to?(:to_h) ? value.to_h.compact : value
+      end
+    end
+  end
+end
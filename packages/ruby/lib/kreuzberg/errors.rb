@@ -114,5 +114,36 @@ module Kreuzberg
 
     # Raised when an unsupported file format or MIME type is encountered
     class UnsupportedFormatError < Error; end
+
+    # Raised when an extraction stage exceeds its budget in +stage_timeouts+
+    class TimeoutError < Error; end
+
+    # Raised in strict mode when extraction succeeds but the result has a quality warning
+    #
+    # @example Choose failure semantics by code instead of message
+    #   begin
+    #     Kreuzberg.extract_file_sync("scan.pdf", config: { strictness: :strict })
+    #   rescue Kreuzberg::Errors::QualityError => e
+    #     retry_with_ocr if e.code == :empty_content
+    #   end
+    class QualityError < Error
+      attr_reader :code, :details, :result
+
+      def initialize(message, code:, details: {}, result: nil)
+        super(message)
+        @code = code
+        @details = details
+        @result = result
+      end
+    end
+
+    # Raised in strict mode when the extracted content is empty
+    class EmptyContentError < QualityError; end
+
+    # Raised in strict mode when OCR reports a confidence below the threshold
+    class LowOcrConfidenceError < QualityError; end
+
+    # Raised in strict mode when encrypted parts of a document were skipped
+    class EncryptedContentSkippedError < QualityError; end
   end
 end
@@ -0,0 +1,97 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # What a cheap inspection found out about a document, see {EstimateAPI#estimate}.
+  #
+  # @!attribute [r] mime_type
+  #   @return [String] Detected MIME type
+  # @!attribute [r] size_bytes
+  #   @return [Integer] Size of the document in bytes
+  # @!attribute [r] page_count
+  #   @return [Integer, nil] Number of pages, nil for formats without pages
+  # @!attribute [r] text_pages
+  #   @return [Integer, nil] Pages with native text
+  # @!attribute [r] image_coverage
+  #   @return [Float] Share of the page area covered by images, from 0.0 to 1.0
+  # @!attribute [r] has_text_layer
+  #   @return [Boolean] Whether the text can be read without OCR
+  # @!attribute [r] text_chars
+  #   @return [Integer] Characters of native text (expected text length for other formats)
+  #
+  DocumentProfile = Struct.new(
+    :mime_type,
+    :size_bytes,
+    :page_count,
+    :text_pages,
+    :image_coverage,
+    :has_text_layer,
+    :text_chars,
+    keyword_init: true
+  ) do
+    def text_layer? = has_text_layer
+  end
+
+  # Predicted cost of one extraction stage.
+  #
+  # @!attribute [r] stage
+  #   @return [Symbol] +:extraction+, +:ocr+ or +:post_processing+
+  # @!attribute [r] duration_ms
+  #   @return [Integer] Expected wall-clock time in milliseconds
+  # @!attribute [r] memory_bytes
+  #   @return [Integer] Memory the stage needs on top of the document, in bytes
+  #
+  StageEstimate = Struct.new(:stage, :duration_ms, :memory_bytes, keyword_init: true)
+
+  # Predicted cost of extracting a document, as returned by {EstimateAPI#estimate}.
+  #
+  # @!attribute [r] profile
+  #   @return [DocumentProfile] What the inspection found
+  # @!attribute [r] ocr_pages
+  #   @return [Integer] Pages extraction is expected to OCR
+  # @!attribute [r] stages
+  #   @return [Array<StageEstimate>] Cost of each stage that runs, in pipeline order
+  # @!attribute [r] duration_ms
+  #   @return [Integer] Expected total wall-clock time in milliseconds
+  # @!attribute [r] peak_memory_bytes
+  #   @return [Integer] Expected peak resident memory in bytes
+  #
+  CostEstimate = Struct.new(:profile, :ocr_pages, :stages, :duration_ms, :peak_memory_bytes, keyword_init: true)
+
+  # Dry-run cost estimates, computed by the Rust core without extracting the document.
+  module EstimateAPI
+    # Predict how long extracting a file takes and how much memory it needs.
+    #
+    # The document is inspected, not extracted: PDFs are opened to count pages, text and image
+    # area without rendering anything, images only have their header read. OCR is predicted the
+    # way extraction decides it (+force_ocr+, or a text layer too thin to use), and the resource
+    # profile of +config+ is applied. Use it to route heavy documents to bigger workers; the
+    # numbers come from a linear per-stage model and are estimates, not limits.
+    #
+    # @param path [String, Pathname] Path to the document
+    # @param config [Config::Extraction, Hash, nil] Configuration extraction would run with
+    # @return [CostEstimate] Frozen estimate
+    # @raise [Errors::ValidationError] If the file does not exist or its type is not supported
+    # @raise [Errors::ParsingError] If a PDF cannot be opened
+    #
+    # @example Route scanned documents to the OCR workers
+    #   estimate = Kreuzberg.estimate('upload.pdf', config: { ocr: { backend: 'tesseract' } })
+    #   queue = estimate.ocr_pages.positive? || estimate.peak_memory_bytes > 1 << 30 ? :heavy : :light
+    #   ExtractJob.set(queue:).perform_later('upload.pdf')
+    def estimate(path, config: nil)
+      hash = _estimate_native(path.to_s, **normalize_config(config))
+      CostEstimate.new(
+        profile: DocumentProfile.new(**hash['profile'].transform_keys(&:to_sym)).freeze,
+        ocr_pages: hash['ocr_pages'],
+        stages: hash['stages'].map do |stage|
+          StageEstimate.new(
+            stage: stage['stage'].to_sym,
+            duration_ms: stage['duration_ms'],
+            memory_bytes: stage['memory_bytes']
+          ).freeze
+        end.freeze,
+        duration_ms: hash['duration_ms'],
+        peak_memory_bytes: hash['peak_memory_bytes']
+      ).freeze
+    end
+  end
+end
@@ -27,6 +27,7 @@ module Kreuzberg
     # @raise [Errors::UnsupportedFormatError] If the file format is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
     #
     # @example Extract a PDF file
     #   result = Kreuzberg.extract_file_sync("document.pdf")
@@ -43,13 +44,16 @@ module Kreuzberg
     #   result = Kreuzberg.extract_file_sync("scanned.pdf", config: config)
     def extract_file_sync(path, mime_type: nil, config: nil)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
       hash = if mime_type
                native_extract_file_sync(path.to_s, mime_type.to_s, **opts)
              else
                native_extract_file_sync(path.to_s, **opts)
              end
-      result = Result.new(hash)
+      result = Result.new(hash, strictness:, frozen:)
       record_cache_entry!(result, opts)
+      enforce_strictness!(result, strictness)
       result
     end
 
@@ -70,6 +74,7 @@ module Kreuzberg
     # @raise [Errors::UnsupportedFormatError] If the MIME type is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
     #
     # @example Extract PDF from memory
     #   pdf_data = File.read("document.pdf", binmode: true)
@@ -81,9 +86,12 @@ module Kreuzberg
     #   result = Kreuzberg.extract_bytes_sync(response.body, "application/vnd.openxmlformats-officedocument.wordprocessingml.document")
     def extract_bytes_sync(data, mime_type, config: nil)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
       hash = native_extract_bytes_sync(data.to_s, mime_type.to_s, **opts)
-      result = Result.new(hash)
+      result = Result.new(hash, strictness:, frozen:)
       record_cache_entry!(result, opts)
+      enforce_strictness!(result, strictness)
       result
     end
 
@@ -106,6 +114,7 @@ module Kreuzberg
     # @raise [Errors::UnsupportedFormatError] If any file format is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails on any document
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
     #
     # @example Batch extract multiple PDFs
     #   paths = ["doc1.pdf", "doc2.pdf", "doc3.pdf"]
@@ -120,9 +129,12 @@ module Kreuzberg
     #   results = Kreuzberg.batch_extract_files_sync(paths, config: config)
     def batch_extract_files_sync(paths, config: nil)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
       hashes = native_batch_extract_files_sync(paths.map(&:to_s), **opts)
-      results = hashes.map { |hash| Result.new(hash) }
+      results = hashes.map { |hash| Result.new(hash, strictness:, frozen:) }
       record_cache_entry!(results, opts)
+      enforce_strictness!(results, strictness)
       results
     end
 
@@ -146,6 +158,7 @@ module Kreuzberg
     # @raise [Errors::UnsupportedFormatError] If the file format is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
     #
     # @example Extract a PDF file asynchronously
     #   result = Kreuzberg.extract_file("large_document.pdf")
@@ -158,13 +171,16 @@ module Kreuzberg
     #   result = Kreuzberg.extract_file("document.pdf", config: config)
     def extract_file(path, mime_type: nil, config: nil)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
       hash = if mime_type
                native_extract_file(path.to_s, mime_type.to_s, **opts)
              else
                native_extract_file(path.to_s, **opts)
              end
-      result = Result.new(hash)
+      result = Result.new(hash, strictness:, frozen:)
       record_cache_entry!(result, opts)
+      enforce_strictness!(result, strictness)
       result
     end
 
@@ -186,6 +202,7 @@ module Kreuzberg
     # @raise [Errors::UnsupportedFormatError] If the MIME type is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
     #
     # @example Extract PDF from memory asynchronously
     #   pdf_data = File.read("document.pdf", binmode: true)
@@ -200,9 +217,12 @@ module Kreuzberg
     #   result = Kreuzberg.extract_bytes(data, "application/vnd.openxmlformats-officedocument.wordprocessingml.document", config: config)
     def extract_bytes(data, mime_type, config: nil)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
       hash = native_extract_bytes(data.to_s, mime_type.to_s, **opts)
-      result = Result.new(hash)
+      result = Result.new(hash, strictness:, frozen:)
       record_cache_entry!(result, opts)
+      enforce_strictness!(result, strictness)
       result
     end
 
@@ -216,15 +236,22 @@ module Kreuzberg
     #   is converted to a string and MIME type is auto-detected from extension.
     # @param config [Config::Extraction, Hash, nil] Extraction configuration applied to all files.
     #   Accepts either a {Config::Extraction} object or a configuration hash.
+    # @param lazy [Boolean] Return a lazy enumerator that yields `[path, result]` pairs in
+    #   completion order as the native layer finishes each file, instead of waiting for the
+    #   whole batch. Only a few finished results are buffered, so large batches can be
+    #   stream-processed in constant memory. Every enumeration runs the batch again, and
+    #   stopping early (e.g. `first(10)`) cancels the files that have not started yet.
     #
-    # @return [Array<Result>] Array of extraction results in the same order as input paths.
-    #   Array length matches the input paths length.
+    # @return [Array<Result>, Enumerator::Lazy] Array of extraction results in the same order
+    #   as input paths, or with `lazy: true` a lazy enumerator of `[path, result]` pairs.
     #
     # @raise [Errors::IOError] If any file cannot be read
     # @raise [Errors::ParsingError] If any document parsing fails
     # @raise [Errors::UnsupportedFormatError] If any file format is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails on any document
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
+    #   (with `lazy: true`, these are raised while enumerating)
     #
     # @example Batch extract multiple files asynchronously
     #   paths = ["invoice_1.pdf", "invoice_2.pdf", "invoice_3.pdf"]
@@ -239,11 +266,22 @@ module Kreuzberg
     #     chunking: Kreuzberg::Config::Chunking.new(max_chars: 1000, max_overlap: 200)
     #   )
     #   results = Kreuzberg.batch_extract_files(paths, config: config)
-    def batch_extract_files(paths, config: nil)
+    #
+    # @example Stream-process a large batch as files finish
+    #   paths = Dir.glob("archive/**/*.pdf")
+    #   Kreuzberg.batch_extract_files(paths, lazy: true).each do |path, result|
+    #     index_document(path, result.content)
+    #   end
+    def batch_extract_files(paths, config: nil, lazy: false)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
+      return stream_batch_files(paths.map(&:to_s), opts, strictness, frozen) if lazy
+
       hashes = native_batch_extract_files(paths.map(&:to_s), **opts)
-      results = hashes.map { |hash| Result.new(hash) }
+      results = hashes.map { |hash| Result.new(hash, strictness:, frozen:) }
       record_cache_entry!(results, opts)
+      enforce_strictness!(results, strictness)
       results
     end
 
@@ -268,6 +306,7 @@ module Kreuzberg
     # @raise [Errors::UnsupportedFormatError] If any MIME type is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails on any document
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
     #
     # @example Batch extract binary documents
     #   pdf_data_1 = File.read("doc1.pdf", binmode: true)
@@ -280,9 +319,12 @@ module Kreuzberg
     #   results.each { |r| puts r.content }
     def batch_extract_bytes_sync(data_array, mime_types, config: nil)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
       hashes = native_batch_extract_bytes_sync(data_array.map(&:to_s), mime_types.map(&:to_s), **opts)
-      results = hashes.map { |hash| Result.new(hash) }
+      results = hashes.map { |hash| Result.new(hash, strictness:, frozen:) }
       record_cache_entry!(results, opts)
+      enforce_strictness!(results, strictness)
       results
     end
 
@@ -307,6 +349,7 @@ module Kreuzberg
     # @raise [Errors::UnsupportedFormatError] If any MIME type is not supported
     # @raise [Errors::OCRError] If OCR is enabled and fails on any document
     # @raise [Errors::MissingDependencyError] If a required dependency is missing
+    # @raise [Errors::QualityError] If `strictness: :strict` and a result has a quality warning
     #
     # @example Batch extract uploaded documents asynchronously
     #   # From a web request with multiple file uploads
@@ -324,17 +367,107 @@ module Kreuzberg
     #   results = Kreuzberg.batch_extract_bytes(data, types, config: config)
     def batch_extract_bytes(data_array, mime_types, config: nil)
       opts = normalize_config(config)
+      strictness = strictness_option(opts)
+      frozen = frozen_option(opts)
       hashes = native_batch_extract_bytes(data_array.map(&:to_s), mime_types.map(&:to_s), **opts)
-      results = hashes.map { |hash| Result.new(hash) }
+      results = hashes.map { |hash| Result.new(hash, strictness:, frozen:) }
       record_cache_entry!(results, opts)
+      enforce_strictness!(results, strictness)
       results
     end
 
+    # Extract a file and stream the rendered output into an IO object.
+    #
+    # The output is rendered in Rust and handed to `io.write` in chunks of at most 64 KiB,
+    # so large documents can be served (e.g., as an HTTP response body) without building
+    # the whole output as one Ruby string.
+    #
+    # @param path [String, Pathname] Path to the document file to extract
+    # @param io [IO, #write] Destination: a file, socket, StringIO or response body
+    # @param format [Symbol] `:markdown` (content followed by tables it does not already
+    #   contain), `:text` (content as is) or `:json` (the full result)
+    # @param mime_type [String, nil] Optional MIME type for the file
+    # @param config [Config::Extraction, Hash, nil] Extraction configuration
+    # @param options [Hash] Extraction options merged over `config`
+    #
+    # @return [Integer] Number of bytes written
+    #
+    # @raise [ArgumentError] If the format is unknown or `io` does not respond to `write`
+    # @raise [Errors::IOError] If the file cannot be read or access is denied
+    # @raise [Errors::ParsingError] If document parsing fails
+    # @raise [Errors::UnsupportedFormatError] If the file format is not supported
+    #
+    # @example Stream markdown into a Rails response
+    #   response.headers["Content-Type"] = "text/markdown"
+    #   Kreuzberg.extract_file_to_io("report.pdf", response.stream)
+    #   response.stream.close
+    #
+    # @example Write JSON to a file
+    #   File.open("report.json", "w") do |file|
+    #     Kreuzberg.extract_file_to_io("report.pdf", file, format: :json, use_cache: false)
+    #   end
+    def extract_file_to_io(path, io, format: :markdown, mime_type: nil, config: nil, **options)
+      opts = normalize_config(config).merge(options)
+      args = [path.to_s, io, format]
+      args << mime_type.to_s if mime_type
+      Kreuzberg._extract_file_to_io_native(*args, **opts)
+    end
+
+    # Lazy enumerator over a native batch stream, yielding `[path, result]` in completion order
+    def stream_batch_files(paths, opts, strictness, frozen)
+      Enumerator.new(paths.length) do |yielder|
+        stream = Kreuzberg._batch_stream_open_native(paths, **opts)
+        begin
+          while (entry = Kreuzberg._batch_stream_next_native(stream))
+            index, hash = entry
+            result = Result.new(hash, strictness:, frozen:)
+            record_cache_entry!(result, opts)
+            enforce_strictness!(result, strictness)
+            yielder << [paths[index], result]
+          end
+        ensure
+          Kreuzberg._batch_stream_close_native(stream)
+        end
+      end.lazy
+    end
+
     def normalize_config(config)
       return {} if config.nil?
       return config if config.is_a?(Hash)
 
       config.to_h
     end
+
+    def strictness_option(opts)
+      Config::Extraction.normalize_strictness(opts[:strictness] || opts['strictness'])
+    end
+
+    def frozen_option(opts)
+      opts[:frozen] || opts['frozen'] ? true : false
+    end
+
+    # Raise the first quality warning of any result under `strictness: :strict`
+    def enforce_strictness!(results, strictness)
+      return unless strictness == :strict
+
+      results_array = results.is_a?(Array) ? results : [results]
+      results_array.each do |result|
+        warning = result.warnings.first
+        next unless warning
+
+        raise quality_error_class(warning.code).new(
+          warning.message, code: warning.code, details: warning.details, result:
+        )
+      end
+    end
+
+    def quality_error_class(code)
+      case code
+      when :empty_content then Errors::EmptyContentError
+      when :low_ocr_confidence then Errors::LowOcrConfidenceError
+      when :encrypted_content_skipped then Errors::EncryptedContentSkippedError
+      else Errors::QualityError
+      end
+    end
   end
 end
@@ -0,0 +1,46 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Flattens extraction results into rows for database insertion.
+  module FlattenAPI
+    # Columns returned by {#flatten_result} when +fields+ is not given.
+    DEFAULT_FLAT_FIELDS = %w[
+      content mime_type title subject author keywords_json language
+      created_at modified_at page_count metadata_json
+    ].freeze
+
+    # Every column {#flatten_result} can produce.
+    FLAT_FIELDS = %w[
+      content mime_type title subject author authors_json keywords_json language
+      detected_languages_json created_at modified_at created_by modified_by page_count metadata_json
+    ].freeze
+
+    # Flatten a result into a string-keyed hash of scalar columns.
+    #
+    # Values are Strings, Integers or nil, so the hash can be passed straight to
+    # +insert_all+, +create+ or a raw +INSERT+. Metadata is read in native code from the
+    # result's metadata JSON; lists such as keywords are emitted as JSON strings and
+    # +author+ joins all authors with ", ". +language+ falls back to the first detected
+    # language. Fields the document does not provide are nil.
+    #
+    # @param result [Result] Extraction result to flatten
+    # @param fields [Array<String, Symbol>, nil] Columns to include, in order; defaults to
+    #   {DEFAULT_FLAT_FIELDS}. See {FLAT_FIELDS} for all columns.
+    # @return [Hash{String => String, Integer, nil}] One row per document
+    #
+    # @raise [ArgumentError] If a field is unknown
+    #
+    # @example Store a row per document
+    #   result = Kreuzberg.extract_file_sync('report.pdf')
+    #   Document.insert_all([Kreuzberg.flatten_result(result, fields: %i[content title page_count])])
+    def flatten_result(result, fields: nil)
+      options = {
+        mime_type: result.mime_type.to_s,
+        detected_languages: Array(result.detected_languages).map(&:to_s),
+        fields: fields&.map(&:to_s)
+      }
+
+      _flatten_result_native(result.content.to_s, (result.metadata_json || '{}').to_s, options)
+    end
+  end
+end
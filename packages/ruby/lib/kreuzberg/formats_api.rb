@@ -0,0 +1,67 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # A MIME type Kreuzberg can extract, as listed by {FormatsAPI#supported_formats}.
+  #
+  # @!attribute [r] mime_type
+  #   @return [String] MIME type
+  # @!attribute [r] extractor
+  #   @return [String] Name of the extractor that handles the MIME type
+  # @!attribute [r] extensions
+  #   @return [Array<String>] File extensions of the MIME type (without dots), may be empty
+  # @!attribute [r] tables
+  #   @return [Boolean] Whether tables are extracted into {Result#tables}
+  # @!attribute [r] images
+  #   @return [Boolean] Whether embedded images are extracted when image extraction is configured
+  # @!attribute [r] metadata
+  #   @return [Boolean] Whether document metadata (title, authors, dimensions, ...) is extracted
+  # @!attribute [r] pages
+  #   @return [Boolean] Whether content is split into pages when page extraction is configured
+  # @!attribute [r] ocr
+  #   @return [Boolean] Whether text may only be available through OCR (images, scanned PDFs)
+  #
+  SupportedFormat = Struct.new(
+    :mime_type,
+    :extractor,
+    :extensions,
+    :tables,
+    :images,
+    :metadata,
+    :pages,
+    :ocr,
+    keyword_init: true
+  ) do
+    def tables? = tables
+    def images? = images
+    def metadata? = metadata
+    def pages? = pages
+    def ocr? = ocr
+  end
+
+  # Lists the formats the extractor registry supports.
+  module FormatsAPI
+    # List every supported MIME type with its capabilities.
+    #
+    # The list comes from the extractor registry, so it reflects the features the native
+    # extension was built with and any custom extractors registered since. Build upload
+    # validators and UI hints from it instead of hardcoding format lists.
+    #
+    # @return [Hash{String => SupportedFormat}] Formats keyed by MIME type, sorted by MIME type
+    #
+    # @example Accept only formats that yield tables
+    #   formats = Kreuzberg.supported_formats
+    #   accept = formats.values.select(&:tables?).flat_map(&:extensions).map { |ext| ".#{ext}" }
+    #
+    # @example Warn about OCR before uploading
+    #   format = Kreuzberg.supported_formats[upload.content_type]
+    #   raise 'Unsupported file type' unless format
+    #   notice = 'Scanned documents may take longer' if format.ocr?
+    def supported_formats
+      _supported_formats_native.to_h do |entry|
+        format = SupportedFormat.new(**entry.transform_keys(&:to_sym))
+        format.extensions.freeze
+        [format.mime_type, format.freeze]
+      end.freeze
+    end
+  end
+end
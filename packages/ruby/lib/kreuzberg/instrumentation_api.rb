@@ -0,0 +1,49 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Timing hooks for host-side monitoring of extraction performance.
+  #
+  # Once a callback is installed, the native layer reports the wall time of every
+  # extraction call and of its stages. Events are emitted synchronously on the calling
+  # thread after the extraction finishes. When no callback is installed, no timings are
+  # taken at all.
+  #
+  # Events:
+  #   - "extraction.stage" - once per stage (:parse_config, :extract, :convert_result)
+  #     with :operation, :stage and :duration_ms
+  #   - "extraction.document" - once per single-document call with :operation,
+  #     :duration_ms, :stages, :success, :mime_type and :error (on failure)
+  #   - "extraction.batch" - once per batch call with :operation, :duration_ms, :stages,
+  #     :success, :count and :error (on failure)
+  #
+  # Exceptions raised by the callback are ignored so monitoring never fails an extraction.
+  module InstrumentationAPI
+    # Install a callback receiving extraction timing events.
+    #
+    # @param callback [#call, nil] Callable invoked with +(event, payload)+, or nil to
+    #   remove the current callback
+    # @return [#call, nil] The installed callback
+    #
+    # @raise [ArgumentError] If the callback does not respond to +call+
+    #
+    # @example Report timings to StatsD
+    #   Kreuzberg.instrumentation = lambda do |event, payload|
+    #     StatsD.timing("kreuzberg.#{event}", payload[:duration_ms], tags: { operation: payload[:operation] })
+    #   end
+    def instrumentation=(callback)
+      unless callback.nil? || callback.respond_to?(:call)
+        raise ArgumentError, 'instrumentation callback must respond to #call'
+      end
+
+      _set_instrumentation_native(callback)
+      @__instrumentation = callback
+    end
+
+    # The currently installed instrumentation callback.
+    #
+    # @return [#call, nil]
+    def instrumentation
+      @__instrumentation
+    end
+  end
+end
@@ -49,6 +49,12 @@ module Kreuzberg
   #     result
   #   })
   #
+  # @example Annotating each chunk after chunking
+  #   Kreuzberg.register_chunk_processor("labels", ->(chunk) {
+  #     chunk["annotations"]["has_table"] = chunk["content"].include?("|")
+  #     chunk
+  #   })
+  #
   module PostProcessorProtocol
     # Process and enrich an extraction result.
     #
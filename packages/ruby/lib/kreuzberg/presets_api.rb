@@ -0,0 +1,46 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Configuration presets for common workloads, defined in the Rust core so every binding
+  # hands out the same configuration for the same name.
+  module PresetsAPI
+    # Complete configuration of a preset.
+    #
+    # - +:rag_ingest+ - normalized, cleaned-up content split into 1000-character chunks with
+    #   200 characters of overlap, embedded with the +balanced+ embedding preset, plus pages,
+    #   language detection and OCR of scanned pages
+    # - +:archival_ocr+ - OCR of every page at 400 DPI with deskewing and auto-tuned
+    #   preprocessing, provenance, pages and metadata, never degraded by the resource profile
+    # - +:fast_preview+ - a three-sentence preview without OCR, images or quality processing,
+    #   with a 5 second extraction budget
+    #
+    # Each call returns a new Hash, so it can be modified before use.
+    #
+    # @param name [Symbol, String] Preset name (+-+ and +_+ are interchangeable)
+    # @return [Hash{Symbol => Object}] {Config::Extraction} options
+    # @raise [Errors::ValidationError] If the preset is unknown
+    #
+    # @example Ingest documents for retrieval
+    #   result = Kreuzberg.extract_file_sync('handbook.pdf', config: Kreuzberg.preset(:rag_ingest))
+    #   result.chunks.each { |chunk| index.add(chunk.content, chunk.embedding) }
+    #
+    # @example Start from a preset and override a section
+    #   options = Kreuzberg.preset(:archival_ocr)
+    #   options[:ocr] = options[:ocr].merge(language: 'deu')
+    #   config = Kreuzberg::Config::Extraction.new(**options)
+    def preset(name)
+      hash = _config_preset_native(name.to_s)
+      Config::Extraction.new(**hash.transform_keys(&:to_sym)).to_h
+    end
+
+    # Names and descriptions of the available presets.
+    #
+    # @return [Hash{Symbol => String}] Descriptions keyed by preset name
+    #
+    # @example
+    #   Kreuzberg.presets.keys # => [:rag_ingest, :archival_ocr, :fast_preview]
+    def presets
+      _config_presets_native.to_h { |name, description| [name.to_sym, description] }
+    end
+  end
+end
@@ -18,10 +18,18 @@ module Kreuzberg
   #   puts "Metadata: #{result.metadata.inspect}"
   #   result.tables.each { |table| puts table.inspect }
   #
+  # @example Share a deeply frozen result with a Ractor
+  #   result = Kreuzberg.extract_file_sync("document.pdf", config: { frozen: true })
+  #   Ractor.new(result) { |shared| shared.content.split.size }.take
+  #
   # rubocop:disable Metrics/ClassLength
   class Result
     attr_reader :content, :mime_type, :metadata, :metadata_json, :tables,
-                :detected_languages, :chunks, :images, :pages
+                :detected_languages, :chunks, :images, :pages, :term_offsets, :barcodes, :provenance,
+                :warnings
+
+    # OCR confidence (0.0-1.0) below which a `:low_ocr_confidence` warning is reported
+    LOW_OCR_CONFIDENCE_THRESHOLD = 0.5
 
     # Table structure
     #
@@ -31,10 +39,12 @@ module Kreuzberg
     #   @return [String] Markdown representation
     # @!attribute [r] page_number
     #   @return [Integer] Page number where table was found
+    # @!attribute [r] column_types
+    #   @return [Array<String>, nil] Inferred column types ("string", "number", "date")
     #
-    Table = Struct.new(:cells, :markdown, :page_number, keyword_init: true) do
+    Table = Struct.new(:cells, :markdown, :page_number, :column_types, keyword_init: true) do
       def to_h
-        { cells: cells, markdown: markdown, page_number: page_number }
+        { cells: cells, markdown: markdown, page_number: page_number, column_types: column_types }.compact
       end
     end
 
@@ -52,6 +62,8 @@ module Kreuzberg
     #   @return [Integer, nil] First page number (1-indexed)
     # @!attribute [r] last_page
     #   @return [Integer, nil] Last page number (1-indexed)
+    # @!attribute [r] annotations
+    #   @return [Hash] Annotations added by chunk processors (empty when none ran)
     #
     Chunk = Struct.new(
       :content,
@@ -63,6 +75,7 @@ module Kreuzberg
       :first_page,
       :last_page,
       :embedding,
+      :annotations,
       keyword_init: true
     ) do
       def to_h
@@ -75,7 +88,8 @@ module Kreuzberg
           total_chunks: total_chunks,
           first_page: first_page,
           last_page: last_page,
-          embedding: embedding
+          embedding: embedding,
+          annotations: annotations
         }
       end
     end
@@ -92,8 +106,21 @@ module Kreuzberg
       :is_mask,
       :description,
       :ocr_result,
+      :perceptual_hash,
+      :blob,
+      :caption,
+      :figure_index,
       keyword_init: true
     ) do
+      # Image bytes, read from the blob store when they are not inlined.
+      #
+      # @return [String] Binary image data
+      def read_data
+        return data if blob.nil? || !data.to_s.empty?
+
+        Kreuzberg.read_image_blob(blob)
+      end
+
       def to_h
         {
           data: data,
@@ -106,7 +133,11 @@ module Kreuzberg
           bits_per_component: bits_per_component,
           is_mask: is_mask,
           description: description,
-          ocr_result: ocr_result&.to_h
+          ocr_result: ocr_result&.to_h,
+          perceptual_hash: perceptual_hash,
+          blob: blob,
+          caption: caption,
+          figure_index: figure_index
         }
       end
     end
@@ -121,33 +152,171 @@ module Kreuzberg
     #   @return [Array<Table>] Tables on this page
     # @!attribute [r] images
     #   @return [Array<Image>] Images on this page
+    # @!attribute [r] content_blob
+    #   @return [Hash, nil] Location of the page text when it was spilled to `spill_dir`
     #
-    PageContent = Struct.new(:page_number, :content, :tables, :images, keyword_init: true) do
+    PageContent = Struct.new(:page_number, :content, :tables, :images, :content_blob, keyword_init: true) do
+      # Page text, read from disk when the page was spilled.
+      #
+      # @return [String] Text content for this page
+      def read_content
+        return content if content_blob.nil? || !content.to_s.empty?
+
+        Kreuzberg.read_image_blob(content_blob).force_encoding(Encoding::UTF_8)
+      end
+
       def to_h
         {
           page_number: page_number,
           content: content,
           tables: tables.map(&:to_h),
-          images: images.map(&:to_h)
+          images: images.map(&:to_h),
+          content_blob: content_blob
         }
       end
     end
 
+    # Term position in the content
+    #
+    # @!attribute [r] term
+    #   @return [String] Lowercased term text
+    # @!attribute [r] byte_start
+    #   @return [Integer] Starting byte offset in the content (UTF-8)
+    # @!attribute [r] byte_end
+    #   @return [Integer] Ending byte offset in the content (UTF-8, exclusive)
+    # @!attribute [r] page
+    #   @return [Integer, nil] Page number (1-indexed), or nil when page boundaries are unknown
+    #
+    TermOffset = Struct.new(:term, :byte_start, :byte_end, :page, keyword_init: true) do
+      def to_h
+        { term: term, byte_start: byte_start, byte_end: byte_end, page: page }
+      end
+    end
+
+    # Barcode or QR code decoded from a page image
+    #
+    # @!attribute [r] format
+    #   @return [Symbol] Symbology (`:qr_code`, `:ean13`, `:upc_a`, `:ean8`, `:code128`, `:code39`)
+    # @!attribute [r] payload
+    #   @return [String] Decoded payload
+    # @!attribute [r] page
+    #   @return [Integer] Page number (1-indexed)
+    # @!attribute [r] bbox
+    #   @return [Hash] Bounding box with `:x`, `:y`, `:width`, `:height` (pixels for images, points for PDFs)
+    #
+    Barcode = Struct.new(:format, :payload, :page, :bbox, keyword_init: true) do
+      def to_h
+        { format: format, payload: payload, page: page, bbox: bbox }
+      end
+    end
+
+    # Source of a byte range of the content
+    #
+    # @!attribute [r] byte_start
+    #   @return [Integer] Starting byte offset in the content (UTF-8)
+    # @!attribute [r] byte_end
+    #   @return [Integer] Ending byte offset in the content (UTF-8, exclusive)
+    # @!attribute [r] source
+    #   @return [Symbol] Where the text came from (`:text_layer`, `:ocr`, `:table`, `:attachment`)
+    # @!attribute [r] page
+    #   @return [Integer, nil] Page number (1-indexed) of OCR and table spans, when known
+    # @!attribute [r] table_index
+    #   @return [Integer, nil] Index into {#tables} for `:table` spans
+    # @!attribute [r] name
+    #   @return [String, nil] Attached file name for `:attachment` spans
+    #
+    ProvenanceSpan = Struct.new(:byte_start, :byte_end, :source, :page, :table_index, :name, keyword_init: true) do
+      def to_h
+        {
+          byte_start: byte_start,
+          byte_end: byte_end,
+          source: source,
+          page: page,
+          table_index: table_index,
+          name: name
+        }.compact
+      end
+    end
+
+    # Heading in the document outline
+    #
+    # @!attribute [r] level
+    #   @return [Integer] Heading level (1 = top level)
+    # @!attribute [r] title
+    #   @return [String] Heading text
+    # @!attribute [r] page
+    #   @return [Integer, nil] Page the heading appears on (1-indexed)
+    # @!attribute [r] byte_offset
+    #   @return [Integer, nil] Byte offset of the heading text in {#content}
+    # @!attribute [r] children
+    #   @return [Array<OutlineEntry>] Nested sub-headings
+    #
+    OutlineEntry = Struct.new(:level, :title, :page, :byte_offset, :children, keyword_init: true) do
+      def to_h
+        { level: level, title: title, page: page, byte_offset: byte_offset, children: children.map(&:to_h) }
+      end
+    end
+
+    # Label/value pair found by key-value extraction
+    #
+    # @!attribute [r] key
+    #   @return [String] Label as written in the document, without the colon
+    # @!attribute [r] value
+    #   @return [String] Value as written in the document
+    # @!attribute [r] page
+    #   @return [Integer, nil] Page the pair appears on (1-indexed)
+    # @!attribute [r] key_bbox
+    #   @return [Hash, nil] Bounding box of the label with `:x`, `:y`, `:width`, `:height` (PDF points)
+    # @!attribute [r] value_bbox
+    #   @return [Hash, nil] Bounding box of the value (PDF points)
+    #
+    KeyValue = Struct.new(:key, :value, :page, :key_bbox, :value_bbox, keyword_init: true) do
+      def to_h
+        { key: key, value: value, page: page, key_bbox: key_bbox, value_bbox: value_bbox }
+      end
+    end
+
+    # Quality warning about a successful extraction
+    #
+    # Under `strictness: :strict` the first warning is raised as the matching
+    # {Errors::QualityError} subclass instead.
+    #
+    # @!attribute [r] code
+    #   @return [Symbol] Machine-readable code (`:empty_content`, `:low_ocr_confidence`,
+    #     `:encrypted_content_skipped`)
+    # @!attribute [r] message
+    #   @return [String] Human-readable description
+    # @!attribute [r] details
+    #   @return [Hash] Code-specific details (e.g. `confidence`, `pages`, `parts`)
+    #
+    QualityWarning = Struct.new(:code, :message, :details, keyword_init: true) do
+      def to_h
+        { code: code, message: message, details: details }
+      end
+    end
+
     # Initialize from native hash result
     #
     # @param hash [Hash] Hash returned from native extension
+    # @param strictness [Symbol] `:lenient` skips quality checks, leaving {#warnings} empty
+    # @param frozen [Boolean] Deeply freeze the result, making it Ractor-shareable
     #
-    def initialize(hash)
+    def initialize(hash, strictness: :standard, frozen: false)
       # Handle both string and symbol keys for flexibility
       @content = get_value(hash, 'content', '')
       @mime_type = get_value(hash, 'mime_type', '')
       @metadata_json = get_value(hash, 'metadata_json', '{}')
-      @metadata = parse_metadata(@metadata_json)
+      @metadata = parse_metadata(@metadata_json, frozen)
       @tables = parse_tables(get_value(hash, 'tables'))
       @detected_languages = parse_detected_languages(get_value(hash, 'detected_languages'))
       @chunks = parse_chunks(get_value(hash, 'chunks'))
       @images = parse_images(get_value(hash, 'images'))
       @pages = parse_pages(get_value(hash, 'pages'))
+      @term_offsets = parse_term_offsets(get_value(hash, 'term_offsets'))
+      @barcodes = parse_barcodes(get_value(hash, 'barcodes'))
+      @provenance = parse_provenance(get_value(hash, 'provenance'))
+      @warnings = strictness == :lenient ? [] : detect_warnings
+      Ractor.make_shareable(self) if frozen
     end
 
     # Convert to hash
@@ -163,7 +332,11 @@ module Kreuzberg
         detected_languages: @detected_languages,
         chunks: serialize_chunks,
         images: serialize_images,
-        pages: serialize_pages
+        pages: serialize_pages,
+        term_offsets: @term_offsets&.map(&:to_h),
+        barcodes: @barcodes&.map(&:to_h),
+        provenance: @provenance&.map(&:to_h),
+        warnings: @warnings.map(&:to_h)
       }
     end
 
@@ -223,6 +396,60 @@ module Kreuzberg
       nil
     end
 
+    # Heading hierarchy from `metadata["outline"]`
+    #
+    # Built from PDF bookmarks (or numbered section headings), DOCX heading
+    # styles and HTML `h1`-`h6` elements.
+    #
+    # @return [Array<OutlineEntry>] Top-level headings (empty when the document has none)
+    #
+    # @example Print a table of contents
+    #   walk = lambda do |entries|
+    #     entries.each do |entry|
+    #       puts "#{'  ' * (entry.level - 1)}#{entry.title} (p. #{entry.page})"
+    #       walk.call(entry.children)
+    #     end
+    #   end
+    #   walk.call(result.outline)
+    #
+    def outline
+      entries = @metadata['outline'] if @metadata.is_a?(Hash)
+      parse_outline(entries)
+    end
+
+    # Label/value pairs from `metadata["key_values"]`
+    #
+    # Present when extracting with `kv_extraction` configured. Pairs read from PDF
+    # word positions carry bounding boxes; pairs read from text carry only a page.
+    #
+    # @return [Array<KeyValue>] Pairs in reading order (empty without `kv_extraction`)
+    #
+    # @example Read invoice fields
+    #   config = Config::Extraction.new(kv_extraction: Config::KeyValueExtraction.new)
+    #   result = Kreuzberg.extract_file_sync("invoice.pdf", config: config)
+    #   result.key_values.find { |pair| pair.key == "Invoice number" }&.value # => "2024-117"
+    #
+    def key_values
+      pairs = @metadata['key_values'] if @metadata.is_a?(Hash)
+      parse_key_values(pairs)
+    end
+
+    # Preview snippet from `metadata["preview"]`
+    #
+    # Present when extracting with `preview` configured: the first meaningful sentences
+    # of the content, with headings and running headers/footers skipped.
+    #
+    # @return [String, nil] Preview text, or nil without `preview`
+    #
+    # @example Show a search result snippet
+    #   config = Config::Extraction.new(preview: Config::Preview.new(sentences: 2))
+    #   result = Kreuzberg.extract_file_sync("report.pdf", config: config)
+    #   puts result.preview
+    #
+    def preview
+      @metadata['preview'] if @metadata.is_a?(Hash)
+    end
+
     # Get a metadata field by name
     #
     # Supports dot notation for nested fields (e.g., "format.pages").
@@ -253,8 +480,84 @@ module Kreuzberg
       value
     end
 
+    # Get the preprocessing chosen for each OCR'd page
+    #
+    # Populated when OCR runs with `ImagePreprocessing.new(auto_tune: true)`.
+    #
+    # @return [Hash{Integer, nil => Hash}] Decisions keyed by page number (nil for a single image)
+    #
+    # @example
+    #   result.preprocessing_decisions.each do |page, decision|
+    #     puts "Page #{page}: #{decision['binarization_method']}, scaled #{decision['scale_factor']}x"
+    #   end
+    #
+    def preprocessing_decisions
+      return {} unless @metadata.is_a?(Hash)
+
+      pages = @metadata['ocr_pages']
+      if pages.is_a?(Array)
+        return pages.filter_map do |page|
+          [page['page'], page['preprocessing']] if page.is_a?(Hash) && page['preprocessing'].is_a?(Hash)
+        end.to_h
+      end
+
+      decision = @metadata['preprocessing']
+      decision.is_a?(Hash) ? { nil => decision } : {}
+    end
+
     private
 
+    def detect_warnings
+      [empty_content_warning, low_ocr_confidence_warning, encrypted_content_warning].compact
+    end
+
+    def empty_content_warning
+      return unless @content.to_s.strip.empty?
+
+      QualityWarning.new(code: :empty_content, message: 'Extraction produced no text content', details: {})
+    end
+
+    def low_ocr_confidence_warning
+      return unless @metadata.is_a?(Hash)
+
+      scores = ocr_confidence_scores
+      low = scores.select { |_page, confidence| confidence < LOW_OCR_CONFIDENCE_THRESHOLD }
+      return if low.empty?
+
+      confidence = low.map(&:last).min
+      QualityWarning.new(
+        code: :low_ocr_confidence,
+        message: "OCR confidence #{confidence.round(2)} is below #{LOW_OCR_CONFIDENCE_THRESHOLD}",
+        details: { confidence: confidence, threshold: LOW_OCR_CONFIDENCE_THRESHOLD, pages: low.map(&:first).compact }
+      )
+    end
+
+    # [page, confidence] pairs from `ocr_pages`, or the result-level `confidence`
+    def ocr_confidence_scores
+      pages = @metadata['ocr_pages']
+      if pages.is_a?(Array)
+        return pages.filter_map do |page|
+          [page['page'], page['confidence']] if page.is_a?(Hash) && page['confidence'].is_a?(Numeric)
+        end
+      end
+
+      confidence = @metadata['confidence']
+      confidence.is_a?(Numeric) ? [[nil, confidence]] : []
+    end
+
+    def encrypted_content_warning
+      return unless @metadata.is_a?(Hash)
+
+      parts = @metadata['encrypted_parts_skipped']
+      return unless parts.is_a?(Array) && parts.any?
+
+      QualityWarning.new(
+        code: :encrypted_content_skipped,
+        message: "Encrypted #{parts.join(', ')} skipped",
+        details: { parts: parts }
+      )
+    end
+
     def serialize_tables
       @tables.map(&:to_h)
     end
@@ -275,8 +578,8 @@ module Kreuzberg
       hash[key] || hash[key.to_sym] || default
     end
 
-    def parse_metadata(metadata_json)
-      JSON.parse(metadata_json)
+    def parse_metadata(metadata_json, frozen)
+      JSON.parse(metadata_json, freeze: frozen)
     rescue JSON::ParserError
       {}
     end
@@ -288,7 +591,8 @@ module Kreuzberg
         Table.new(
           cells: table_hash['cells'] || [],
           markdown: table_hash['markdown'] || '',
-          page_number: table_hash['page_number'] || 0
+          page_number: table_hash['page_number'] || 0,
+          column_types: table_hash['column_types']
         )
       end
     end
@@ -313,7 +617,8 @@ module Kreuzberg
           total_chunks: chunk_hash['total_chunks'],
           first_page: chunk_hash['first_page'],
           last_page: chunk_hash['last_page'],
-          embedding: chunk_hash['embedding']
+          embedding: chunk_hash['embedding'],
+          annotations: chunk_hash['annotations'] || {}
         )
       end
     end
@@ -323,7 +628,10 @@ module Kreuzberg
 
       images_data.map do |image_hash|
         data = image_hash['data']
-        data = data.dup.force_encoding(Encoding::BINARY) if data.respond_to?(:force_encoding)
+        # Native image data is already binary; only re-encode (on a copy) data from elsewhere
+        if data.respond_to?(:force_encoding) && data.encoding != Encoding::BINARY
+          data = data.dup.force_encoding(Encoding::BINARY)
+        end
         Image.new(
           data: data,
           format: image_hash['format'],
@@ -335,11 +643,21 @@ module Kreuzberg
           bits_per_component: image_hash['bits_per_component'],
           is_mask: image_hash['is_mask'],
           description: image_hash['description'],
-          ocr_result: image_hash['ocr_result'] ? Result.new(image_hash['ocr_result']) : nil
+          ocr_result: image_hash['ocr_result'] ? Result.new(image_hash['ocr_result']) : nil,
+          perceptual_hash: image_hash['perceptual_hash'],
+          blob: parse_image_blob(image_hash['blob']),
+          caption: image_hash['caption'],
+          figure_index: image_hash['figure_index']
         )
       end
     end
 
+    def parse_image_blob(blob_data)
+      return nil if blob_data.nil?
+
+      { hash: blob_data['hash'], path: blob_data['path'], size: blob_data['size'] }
+    end
+
     def parse_pages(pages_data)
       return nil if pages_data.nil?
 
@@ -348,7 +666,84 @@ module Kreuzberg
           page_number: page_hash['page_number'],
           content: page_hash['content'],
           tables: parse_tables(page_hash['tables']),
-          images: parse_images(page_hash['images'])
+          images: parse_images(page_hash['images']),
+          content_blob: parse_image_blob(page_hash['content_blob'])
+        )
+      end
+    end
+
+    def parse_term_offsets(offsets_data)
+      return nil if offsets_data.nil?
+
+      offsets_data.map do |offset_hash|
+        TermOffset.new(
+          term: offset_hash['term'],
+          byte_start: offset_hash['byte_start'],
+          byte_end: offset_hash['byte_end'],
+          page: offset_hash['page']
+        )
+      end
+    end
+
+    def parse_outline(entries)
+      return [] unless entries.is_a?(Array)
+
+      entries.map do |entry|
+        OutlineEntry.new(
+          level: entry['level'],
+          title: entry['title'],
+          page: entry['page'],
+          byte_offset: entry['byte_offset'],
+          children: parse_outline(entry['children'])
+        )
+      end
+    end
+
+    def parse_key_values(pairs)
+      return [] unless pairs.is_a?(Array)
+
+      pairs.map do |pair|
+        KeyValue.new(
+          key: pair['key'],
+          value: pair['value'],
+          page: pair['page'],
+          key_bbox: parse_bbox(pair['key_bbox']),
+          value_bbox: parse_bbox(pair['value_bbox'])
+        )
+      end
+    end
+
+    def parse_bbox(bbox)
+      return nil unless bbox.is_a?(Hash)
+
+      { x: bbox['x'], y: bbox['y'], width: bbox['width'], height: bbox['height'] }
+    end
+
+    def parse_barcodes(barcodes_data)
+      return nil if barcodes_data.nil?
+
+      barcodes_data.map do |barcode_hash|
+        bbox = barcode_hash['bbox'] || {}
+        Barcode.new(
+          format: barcode_hash['format']&.to_sym,
+          payload: barcode_hash['payload'],
+          page: barcode_hash['page'],
+          bbox: { x: bbox['x'], y: bbox['y'], width: bbox['width'], height: bbox['height'] }
+        )
+      end
+    end
+
+    def parse_provenance(provenance_data)
+      return nil if provenance_data.nil?
+
+      provenance_data.map do |span_hash|
+        ProvenanceSpan.new(
+          byte_start: span_hash['byte_start'],
+          byte_end: span_hash['byte_end'],
+          source: span_hash['source']&.to_sym,
+          page: span_hash['page'],
+          table_index: span_hash['table_index'],
+          name: span_hash['name']
         )
       end
     end
@@ -0,0 +1,100 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # A match found by {SearchAPI#search_result}.
+  #
+  # @!attribute [r] text
+  #   @return [String] Matched text
+  # @!attribute [r] byte_start
+  #   @return [Integer] Starting byte offset in the result content (UTF-8)
+  # @!attribute [r] byte_end
+  #   @return [Integer] Ending byte offset in the result content (UTF-8, exclusive)
+  # @!attribute [r] page_number
+  #   @return [Integer, nil] Page number (1-indexed), or nil when the result has no page information
+  # @!attribute [r] snippet
+  #   @return [String] Match with surrounding context
+  # @!attribute [r] highlighted_snippet
+  #   @return [String] Snippet with the match wrapped in highlight markers
+  #
+  SearchMatch = Struct.new(
+    :text,
+    :byte_start,
+    :byte_end,
+    :page_number,
+    :snippet,
+    :highlighted_snippet,
+    keyword_init: true
+  )
+
+  # Searches extraction results in native code.
+  #
+  # Scanning happens in Rust, so searching multi-hundred-megabyte results does not build
+  # match data for the whole string in the interpreter.
+  module SearchAPI
+    # Find occurrences of a pattern in a result's content.
+    #
+    # Strings match literally. Regexps are compiled with Rust regex syntax, which covers
+    # common Ruby patterns but not look-around or backreferences; the +i+, +m+ and +x+
+    # flags are honored. Page numbers come from the page boundaries in the result
+    # metadata, falling back to chunk page ranges.
+    #
+    # @param result [Result] Extraction result to search
+    # @param pattern [String, Regexp] Text or pattern to find
+    # @param context [Integer] Bytes of surrounding content to include on each side
+    # @param limit [Integer, nil] Maximum number of matches to return
+    # @param highlight [Array(String, String)] Markers placed around the match in
+    #   +highlighted_snippet+
+    # @return [Array<SearchMatch>] Matches in content order
+    #
+    # @raise [ArgumentError] If the pattern is not a String or Regexp, or is invalid
+    #
+    # @example Find mentions of an invoice number
+    #   result = Kreuzberg.extract_file_sync('archive.pdf')
+    #   Kreuzberg.search_result(result, /invoice\s+#\d+/i, context: 40).each do |match|
+    #     puts "p.#{match.page_number}: #{match.highlighted_snippet}"
+    #   end
+    def search_result(result, pattern, context: 80, limit: nil, highlight: %w[** **])
+      options = search_pattern_options(pattern).merge(
+        context: context,
+        limit: limit,
+        highlight: highlight,
+        pages: search_page_ranges(result)
+      )
+      source = pattern.is_a?(Regexp) ? pattern.source : pattern
+
+      _search_result_native(result.content.to_s, source, options).map do |match|
+        SearchMatch.new(**match.transform_keys(&:to_sym))
+      end
+    end
+
+    private
+
+    def search_pattern_options(pattern)
+      case pattern
+      when String
+        { literal: true }
+      when Regexp
+        {
+          literal: false,
+          ignore_case: pattern.options.anybits?(Regexp::IGNORECASE),
+          multiline: pattern.options.anybits?(Regexp::MULTILINE),
+          extended: pattern.options.anybits?(Regexp::EXTENDED)
+        }
+      else
+        raise ArgumentError, "search pattern must be a String or Regexp, got #{pattern.class}"
+      end
+    end
+
+    def search_page_ranges(result)
+      pages = result.metadata['pages'] if result.metadata.is_a?(Hash)
+      boundaries = pages['boundaries'] if pages.is_a?(Hash)
+      if boundaries.is_a?(Array) && !boundaries.empty?
+        return boundaries.map { |b| [b['byte_start'], b['byte_end'], b['page_number']] }
+      end
+
+      Array(result.chunks).filter_map do |chunk|
+        [chunk.byte_start, chunk.byte_end, chunk.first_page] if chunk.first_page
+      end
+    end
+  end
+end
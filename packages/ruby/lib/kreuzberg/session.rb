@@ -0,0 +1,127 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Extraction session with its own configuration, post-processor set and result cache
+  #
+  # Post-processors are registered process-wide and cached results are shared by every
+  # extraction with the same configuration, so tenants of a multi-tenant Rails app can
+  # see each other's plugins and results. A session pins the configuration of one tenant,
+  # the same way the C API's +kreuzberg_context_new+ does:
+  #
+  # - +namespace+ becomes +cache.namespace+, so results of different sessions are never
+  #   shared, even for identical documents and configurations
+  # - +plugins+ lists the application-registered post-processors the session runs (default:
+  #   all of them, +[]+ runs none); built-in post-processors such as classification or preview
+  #   keep running as configured
+  # - +cache_dir+ stores the session's results in a disk cache under that directory
+  #
+  # Any other keyword is an {Config::Extraction} option. Sessions are frozen, so one session
+  # can be shared by the threads of a tenant.
+  #
+  # @example One session per tenant
+  #   session = Kreuzberg::Session.new(
+  #     namespace: "tenant-#{tenant.id}",
+  #     plugins: ['redact_pii'],
+  #     cache_dir: Rails.root.join('tmp/kreuzberg'),
+  #     ocr: { language: tenant.ocr_language }
+  #   )
+  #   result = session.extract_file(upload.path)
+  #   session.clear_cache
+  #
+  class Session
+    # Post-processors shipped with Kreuzberg, which +plugins+ never disables
+    # (kept in sync with +kreuzberg::core::pipeline::BUILTIN_POST_PROCESSORS+)
+    BUILTIN_POST_PROCESSORS = %w[
+      document-classification
+      key-value-extraction
+      document-preview
+      quality-processing
+      ocr-correction
+      keyword-extraction
+      language-detection
+      text-chunking
+    ].freeze
+
+    # @return [String, nil] Cache namespace of the session
+    attr_reader :namespace
+
+    # @return [Array<String>, nil] Names of the application-registered post-processors the session runs
+    attr_reader :plugins
+
+    # @return [Config::Extraction] Configuration every extraction of the session uses
+    attr_reader :config
+
+    # @param namespace [String, Symbol, nil] Cache namespace (ASCII letters, digits, +-+ and +_+)
+    # @param plugins [Array<String, Symbol>, nil] Registered post-processors to run
+    # @param cache_dir [String, Pathname, nil] Directory of the session's disk cache
+    # @param config [Hash] {Config::Extraction} options
+    #
+    # @raise [ArgumentError] If an option is unknown
+    # @raise [Errors::ValidationError] If the cache section is invalid
+    def initialize(namespace: nil, plugins: nil, cache_dir: nil, **config)
+      @namespace = namespace&.to_s&.freeze
+      @plugins = plugins&.map(&:to_s)&.freeze
+      @config = build_config(config, cache_dir&.to_s)
+      freeze
+    end
+
+    # @return [String, nil] Directory of the session's disk cache
+    def cache_dir
+      cache = @config.cache
+      cache.url if cache && (cache.backend.nil? || cache.backend.to_s == 'disk')
+    end
+
+    # Extract a file with the session's configuration.
+    #
+    # @param path [String, Pathname] Path to the document
+    # @param mime_type [String, nil] MIME type, detected from the path when omitted
+    # @return [Result]
+    def extract_file(path, mime_type: nil)
+      Kreuzberg.extract_file_sync(path, mime_type:, config: @config)
+    end
+
+    # Extract in-memory document bytes with the session's configuration.
+    #
+    # @param data [String] Binary document data
+    # @param mime_type [String] MIME type of the data
+    # @return [Result]
+    def extract_bytes(data, mime_type)
+      Kreuzberg.extract_bytes_sync(data, mime_type, config: @config)
+    end
+
+    # Extract several files with the session's configuration.
+    #
+    # @param paths [Array<String, Pathname>] Paths to the documents
+    # @return [Array<Result>] Results in the order of +paths+
+    def batch_extract_files(paths)
+      Kreuzberg.batch_extract_files_sync(paths, config: @config)
+    end
+
+    # Remove the cached results of this session; other namespaces keep theirs.
+    #
+    # @return [Integer] Number of removed results, 0 when the session does not cache results
+    def clear_cache
+      return 0 unless @config.use_cache && @config.cache
+
+      Kreuzberg._clear_result_cache_native(@config.cache.to_h)
+    end
+
+    private
+
+    def build_config(options, cache_dir)
+      hash = Config::Extraction.new(**options).to_h
+
+      cache = hash[:cache]&.dup
+      cache = (cache || {}).merge(backend: 'disk', url: cache_dir) if cache_dir
+      cache[:namespace] = @namespace if cache && @namespace
+      hash[:cache] = cache if cache
+
+      if @plugins
+        enabled = BUILTIN_POST_PROCESSORS + @plugins
+        hash[:postprocessor] = (hash[:postprocessor] || {}).merge(enabled_processors: enabled)
+      end
+
+      Config::Extraction.new(**hash).freeze
+    end
+  end
+end
@@ -0,0 +1,59 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Scores how similar two extraction results are.
+  module SimilarityAPI
+    # Methods accepted by {#similarity}.
+    SIMILARITY_METHODS = %i[minhash embedding].freeze
+
+    # Score the similarity of two results from 0.0 (unrelated) to 1.0 (identical).
+    #
+    # +:minhash+ estimates the overlap of the word 3-shingles of both contents, ignoring
+    # case and punctuation. It is fast, needs no model and is meant for finding
+    # near-duplicates such as re-scans or re-exports of the same document.
+    #
+    # +:embedding+ compares the mean chunk embeddings of both results, so documents that
+    # say the same thing in different words also score high. Results extracted with
+    # +chunking: { embedding: ... }+ are compared directly; for the others the content is
+    # chunked and embedded with +preset+ first, which downloads the model on first use.
+    # Both sides must use the same model.
+    #
+    # Scoring runs in native code.
+    #
+    # @param result_a [Result] First extraction result
+    # @param result_b [Result] Second extraction result
+    # @param method [Symbol, String] +:minhash+ or +:embedding+
+    # @param preset [String] Embedding preset for results without chunk embeddings
+    # @return [Float] Similarity score between 0.0 and 1.0
+    #
+    # @raise [ArgumentError] If the method is unknown
+    # @raise [Errors::PluginError] If the embedding model cannot be loaded
+    #
+    # @example Skip near-duplicate uploads
+    #   incoming = Kreuzberg.extract_file_sync('upload.pdf')
+    #   duplicate = archive.find { |stored| Kreuzberg.similarity(incoming, stored) > 0.9 }
+    def similarity(result_a, result_b, method: :minhash, preset: 'balanced')
+      unless SIMILARITY_METHODS.include?(method.to_s.to_sym)
+        raise ArgumentError,
+              "Unknown similarity method '#{method}', expected one of: #{SIMILARITY_METHODS.join(', ')}"
+      end
+
+      options = {
+        embeddings_a: similarity_embeddings(result_a),
+        embeddings_b: similarity_embeddings(result_b),
+        preset: preset.to_s
+      }
+      _similarity_native(result_a.content.to_s, result_b.content.to_s, method.to_s, options)
+    end
+
+    private
+
+    # Chunk embeddings of a result, or nil unless every chunk has one.
+    def similarity_embeddings(result)
+      embeddings = Array(result.chunks).map(&:embedding)
+      return nil if embeddings.empty? || embeddings.any?(&:nil?)
+
+      embeddings
+    end
+  end
+end
@@ -0,0 +1,119 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Drop-folder ingestion: extracts files as they appear in a directory
+  #
+  # The watcher uses the operating system's file notifications (inotify, FSEvents,
+  # ReadDirectoryChangesW) from the Rust core, so no polling gem or thread pool is needed:
+  #
+  # - a file is extracted once no event was seen for it for +debounce+ seconds, so files
+  #   that are still being copied are not picked up half-written
+  # - at most +concurrency+ extractions run at once; further files wait their turn
+  # - hidden files (names starting with +.+) and metadata-only changes are ignored
+  #
+  # Watching starts when the watcher is created, so files dropped before {#run} is called
+  # are not missed. Files already in the directory are left alone. Any other keyword is an
+  # {Config::Extraction} option.
+  #
+  # @example Ingestion service
+  #   watcher = Kreuzberg::Watcher.new('inbox', debounce: 1.0, concurrency: 4, ocr: { language: 'deu' })
+  #   trap('TERM') { watcher.stop }
+  #   watcher.run do |path, result, error|
+  #     next warn("#{path}: #{error.message}") if error
+  #
+  #     Document.create!(path:, content: result.content)
+  #     File.delete(path)
+  #   end
+  #
+  class Watcher
+    # Seconds {#run} sleeps when no file is ready before polling again
+    POLL_INTERVAL = 0.1
+
+    # @return [String] Watched directory
+    attr_reader :dir
+
+    # @return [Float] Seconds a file must be left alone before it is extracted
+    attr_reader :debounce
+
+    # @return [Integer] Maximum number of extractions running at once
+    attr_reader :concurrency
+
+    # @return [Config::Extraction] Configuration every extraction uses
+    attr_reader :config
+
+    # @param dir [String, Pathname] Directory to watch
+    # @param debounce [Numeric] Seconds a file must be left alone before it is extracted
+    # @param concurrency [Integer] Maximum number of extractions running at once
+    # @param recursive [Boolean] Whether files in subdirectories are extracted too
+    # @param config [Hash] {Config::Extraction} options
+    #
+    # @raise [ArgumentError] If +dir+ is not a directory or an option is invalid
+    def initialize(dir, debounce: 0.5, concurrency: 2, recursive: true, **config)
+      @dir = dir.to_s
+      @debounce = Float(debounce)
+      @concurrency = Integer(concurrency)
+      @config = Config::Extraction.new(**config)
+      @stopped = false
+      @id = Kreuzberg._watcher_open_native(@dir, @debounce, @concurrency, recursive ? true : false, **@config.to_h)
+    end
+
+    # Yield every extracted file until {#stop} is called, then close the watcher.
+    #
+    # Failed extractions do not stop the watcher; they are yielded with a +nil+ result.
+    #
+    # @yieldparam path [String] Path of the file
+    # @yieldparam result [Result, nil] Extraction result, nil when the extraction failed
+    # @yieldparam error [StandardError, nil] Error of a failed extraction
+    # @return [self]
+    def run
+      raise ArgumentError, 'Watcher#run requires a block' unless block_given?
+
+      begin
+        until @stopped
+          entry = next_entry
+          yield(*entry) if entry
+        end
+      ensure
+        close
+      end
+      self
+    end
+
+    # Ask {#run} to return; safe to call from a signal handler, another thread or the block.
+    #
+    # @return [void]
+    def stop
+      @stopped = true
+    end
+
+    # @return [Boolean] Whether {#stop} or {#close} was called
+    def stopped?
+      @stopped
+    end
+
+    # Stop watching and cancel extractions that have not finished yet.
+    #
+    # @return [void]
+    def close
+      @stopped = true
+      Kreuzberg._watcher_close_native(@id)
+    end
+
+    private
+
+    def next_entry
+      path, hash = Kreuzberg._watcher_next_native(@id)
+      unless path
+        # Sleeping in Ruby releases the GVL, so other threads run while the watcher is idle
+        sleep(POLL_INTERVAL)
+        return nil
+      end
+
+      [path, Result.new(hash, strictness: @config.strictness, frozen: @config.frozen), nil]
+    rescue StandardError => e
+      raise unless (failed_path = Kreuzberg._watcher_failed_path_native(@id))
+
+      [failed_path, nil, e]
+    end
+  end
+end
@@ -0,0 +1,38 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'json'
+
+RSpec.describe 'auto-tuned OCR preprocessing' do
+  let(:decision) do
+    { 'binarization_method' => 'sauvola', 'denoise' => true, 'scale_factor' => 2.0, 'statistics' => {} }
+  end
+
+  it 'includes the auto_tune toggle in the preprocessing config' do
+    config = Kreuzberg::Config::ImagePreprocessing.new(auto_tune: true)
+
+    expect(config.auto_tune).to be(true)
+    expect(config.to_h).to include(auto_tune: true)
+    expect(Kreuzberg::Config::ImagePreprocessing.new.auto_tune).to be(false)
+  end
+
+  it 'reads per-page decisions from ocr_pages' do
+    metadata = { 'ocr_pages' => [{ 'page' => 2, 'backend' => 'tesseract', 'preprocessing' => decision }] }
+    result = Kreuzberg::Result.new('content' => 'text', 'metadata_json' => JSON.generate(metadata))
+
+    expect(result.preprocessing_decisions).to eq(2 => decision)
+  end
+
+  it 'reads the decision of a single OCR image' do
+    result = Kreuzberg::Result.new('content' => 'text', 'metadata_json' => JSON.generate('preprocessing' => decision))
+
+    expect(result.preprocessing_decisions).to eq(nil => decision)
+    expect(Kreuzberg::Result.new('content' => 'text').preprocessing_decisions).to be_empty
+  end
+
+  it 'previews auto-tuned preprocessing' do
+    image_bytes = File.binread(test_document_path('images/ocr_image.jpg'))
+
+    expect(Kreuzberg.preview_preprocessing(image_bytes, auto_tune: true)).not_to be_empty
+  end
+end
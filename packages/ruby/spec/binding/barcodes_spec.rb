@@ -0,0 +1,40 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'barcode detection' do
+  it 'is nil unless enabled' do
+    result = Kreuzberg.extract_bytes_sync('Hello world', 'text/plain')
+    expect(result.barcodes).to be_nil
+  end
+
+  it 'reports an empty list for images without barcodes' do
+    config = Kreuzberg::Config::Extraction.new(use_cache: false, detect_barcodes: true)
+    result = Kreuzberg.extract_file_sync(test_document_path('images/flower_no_text.jpg'), config: config)
+
+    expect(result.barcodes).to eq([])
+  end
+
+  it 'round-trips through the config and result hashes' do
+    config = Kreuzberg::Config::Extraction.new(detect_barcodes: true)
+    expect(config.to_h[:detect_barcodes]).to be true
+    expect(config.merge(force_ocr: true).detect_barcodes).to be true
+
+    result = Kreuzberg::Result.new(
+      'content' => '',
+      'barcodes' => [{
+        'format' => 'qr_code',
+        'payload' => 'https://example.com',
+        'page' => 1,
+        'bbox' => { 'x' => 10.0, 'y' => 20.0, 'width' => 50.0, 'height' => 50.0 }
+      }]
+    )
+    barcode = result.barcodes.first
+    expect(barcode).to be_a(Kreuzberg::Result::Barcode)
+    expect(barcode.format).to eq(:qr_code)
+    expect(result.to_h[:barcodes]).to eq(
+      [{ format: :qr_code, payload: 'https://example.com', page: 1,
+         bbox: { x: 10.0, y: 20.0, width: 50.0, height: 50.0 } }]
+    )
+  end
+end
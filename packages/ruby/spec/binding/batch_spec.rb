@@ -164,6 +164,29 @@ RSpec.describe Kreuzberg do
     ensure
       paths.each { |p| FileUtils.rm_f(p) }
     end
+
+    it 'streams [path, result] pairs lazily with lazy: true' do
+      paths = []
+      4.times do |i|
+        file = Tempfile.new("lazy_batch_#{i}.txt")
+        file.write("Lazy content #{i}")
+        file.close
+        paths << file.path
+      end
+
+      stream = described_class.batch_extract_files(paths, lazy: true)
+      expect(stream).to be_a(Enumerator::Lazy)
+
+      contents = stream.to_h { |path, result| [path, result.content] }
+      expect(contents.keys).to match_array(paths)
+      paths.each_with_index do |path, idx|
+        expect(contents[path]).to include("Lazy content #{idx}")
+      end
+
+      expect(stream.first(1).length).to eq(1)
+    ensure
+      paths.each { |p| FileUtils.rm_f(p) }
+    end
   end
 
   describe '#batch_extract_bytes_sync' do
@@ -0,0 +1,34 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'result cache backends' do
+  it 'builds a cache section from a hash' do
+    config = Kreuzberg::Config::Extraction.new(cache: { backend: 'memory', max_entries: 64 })
+
+    expect(config.cache).to be_a(Kreuzberg::Config::Cache)
+    expect(config.cache.url).to be_nil
+    expect(config.to_h[:cache]).to eq(backend: 'memory', max_entries: 64)
+  end
+
+  it 'survives a merge' do
+    base = Kreuzberg::Config::Extraction.new(use_cache: true)
+    merged = base.merge(Kreuzberg::Config::Extraction.new(cache: { backend: 'disk', url: '/tmp/kreuzberg-results' }))
+
+    expect(merged.cache.backend).to eq('disk')
+    expect(merged.cache.url).to eq('/tmp/kreuzberg-results')
+  end
+
+  it 'rejects unknown backends' do
+    expect { Kreuzberg::Config::Cache.new(backend: 'memcached') }.to raise_error(Kreuzberg::Errors::ValidationError)
+  end
+
+  it 'returns cached results for repeated extractions' do
+    config = Kreuzberg::Config::Extraction.new(cache: { backend: 'memory', max_entries: 8 })
+    first = Kreuzberg.extract_bytes_sync('Cache me', 'text/plain', config: config)
+    second = Kreuzberg.extract_bytes_sync('Cache me', 'text/plain', config: config)
+
+    expect(second.content).to eq(first.content)
+    expect(second.content).to include('Cache me')
+  end
+end
@@ -0,0 +1,40 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'per-MIME config overrides' do
+  let(:text) { 'The parser rewrite is finished. Benchmarks show a 3x speedup. We release next week.' }
+  let(:config) do
+    Kreuzberg::Config::Extraction.new(
+      use_cache: false,
+      overrides: { 'text/plain' => { preview: { sentences: 1 } } }
+    )
+  end
+
+  it 'applies the override matching the MIME type' do
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain', config:)
+
+    expect(result.preview).to eq('The parser rewrite is finished.')
+  end
+
+  it 'leaves other MIME types alone' do
+    result = Kreuzberg.extract_bytes_sync(text, 'text/markdown', config:)
+
+    expect(result.preview).to be_nil
+  end
+
+  it 'round-trips overrides through to_h and merge' do
+    merged = config.merge(overrides: { 'image/*' => { force_ocr: true } })
+
+    expect(config.to_h[:overrides]).to eq('text/plain' => { preview: { sentences: 1 } })
+    expect(merged.overrides.keys).to contain_exactly('text/plain', 'image/*')
+  end
+
+  it 'rejects overrides that are not valid configuration' do
+    invalid = Kreuzberg::Config::Extraction.new(overrides: { 'image/*' => { force_ocr: 'sometimes' } })
+
+    expect do
+      Kreuzberg.extract_bytes_sync(text, 'text/plain', config: invalid)
+    end.to raise_error(Kreuzberg::Errors::ValidationError, %r{image/\*})
+  end
+end
@@ -0,0 +1,141 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe Kreuzberg::Config::Schema do
+  # Hand-written config classes and the Rust struct each one is parsed into
+  hand_written = {
+    Kreuzberg::Config::OCR => 'OcrConfig',
+    Kreuzberg::Config::Chunking => 'ChunkingConfig',
+    Kreuzberg::Config::Embedding => 'EmbeddingConfig',
+    Kreuzberg::Config::LanguageDetection => 'LanguageDetectionConfig',
+    Kreuzberg::Config::PDF => 'PdfConfig',
+    Kreuzberg::Config::ImageExtraction => 'ImageExtractionConfig',
+    Kreuzberg::Config::ImagePreprocessing => 'ImagePreprocessingConfig',
+    Kreuzberg::Config::TokenReduction => 'TokenReductionConfig',
+    Kreuzberg::Config::Keywords => 'KeywordConfig',
+    Kreuzberg::Config::TextOptions => 'TextExtractionConfig',
+    Kreuzberg::Config::Normalization => 'NormalizationConfig',
+    Kreuzberg::Config::CsvOptions => 'CsvConfig',
+    Kreuzberg::Config::Extraction => 'ExtractionConfig'
+  }
+
+  # Keywords handled on the Ruby side instead of being deserialized into the struct
+  ruby_only = {
+    Kreuzberg::Config::OCR => %i[pool_size reuse],
+    Kreuzberg::Config::Chunking => %i[chunk_size chunk_overlap enabled],
+    Kreuzberg::Config::PDF => %i[font_config],
+    Kreuzberg::Config::Extraction => %i[image_extraction image_preprocessing html_options strictness frozen]
+  }
+
+  # Rust fields exposed under a different Ruby keyword
+  renamed = {
+    Kreuzberg::Config::Extraction => %i[images]
+  }
+
+  hand_written.each do |klass, section|
+    it "keeps #{klass.name.split('::').last} in sync with #{section}" do
+      skip "#{section} is not compiled into this build" unless described_class.sections.key?(section)
+
+      keywords = klass.instance_method(:initialize).parameters.filter_map { |type, name| name if type == :key }
+
+      expect(keywords - ruby_only.fetch(klass, [])).to match_array(
+        described_class.field_names(section) - renamed.fetch(klass, [])
+      )
+    end
+  end
+
+  it 'lists every field with its Rust default' do
+    expect(described_class.defaults('PptxConfig')).to eq(include_notes: true, slide_markers: false)
+    expect(described_class.defaults('OcrFallbackConfig')).to include(min_confidence: 0.6)
+  end
+
+  it 'raises ArgumentError for unknown sections' do
+    expect { described_class.fields('NoSuchConfig') }.to raise_error(ArgumentError, /NoSuchConfig/)
+  end
+
+  describe Kreuzberg::Config::Typed do
+    it 'fills in Rust defaults' do
+      policy = Kreuzberg::Config::ImageOcrPolicy.new(min_width: 64)
+
+      expect(policy.min_width).to eq(64)
+      expect(policy.skip_masks).to be true
+      expect(policy.to_h).to eq(min_width: 64, skip_masks: true, skip_decorative: false)
+    end
+
+    it 'rejects unknown keywords' do
+      expect { Kreuzberg::Config::PptxOptions.new(slide_marker: true) }
+        .to raise_error(ArgumentError, /unknown keyword: slide_marker/)
+    end
+
+    it 'rejects values the Rust struct cannot hold' do
+      expect { Kreuzberg::Config::AdaptiveConcurrency.new(min_concurrent: 'two') }
+        .to raise_error(Kreuzberg::Errors::ValidationError, /AdaptiveConcurrencyConfig/)
+    end
+
+    it 'requires fields without a Rust default' do
+      expect { Kreuzberg::Config::OcrFallback.new(min_confidence: 0.5) }
+        .to raise_error(Kreuzberg::Errors::ValidationError, /backend/)
+    end
+
+    it 'wraps nested structs in their config classes' do
+      tesseract = Kreuzberg::Config::Tesseract.new(psm: 6, preprocessing: { denoise: true })
+
+      expect(tesseract.preprocessing).to be_a(Kreuzberg::Config::ImagePreprocessing)
+      expect(tesseract.preprocessing.denoise).to be true
+      expect(tesseract.to_h[:preprocessing]).to include(denoise: true)
+    end
+  end
+
+  describe Kreuzberg::Config::Tesseract do
+    it 'passes other Tesseract variables through as strings' do
+      tesseract = described_class.new(preserve_interword_spaces: true, variables: { textord_min_xheight: 10 })
+
+      expect(tesseract.preserve_interword_spaces).to be true
+      expect(tesseract.variables).to eq('textord_min_xheight' => '10')
+    end
+
+    it 'names the offending variable in validation errors' do
+      expect { described_class.new(variables: { tessedit_char_whitelist: '0123456789' }) }
+        .to raise_error(Kreuzberg::Errors::ValidationError, /'tessedit_char_whitelist' has a dedicated field/)
+      expect { described_class.new(tessedit_char_whitelist: 'abc', tessedit_char_blacklist: 'c') }
+        .to raise_error(Kreuzberg::Errors::ValidationError, /Characters 'c'/)
+      expect { described_class.new(user_words_file: '/nonexistent/words.txt') }
+        .to raise_error(Kreuzberg::Errors::ValidationError, /'user_words_file'/)
+    end
+  end
+
+  describe Kreuzberg::Config::OcrVocabulary do
+    it 'takes schema defaults and round-trips through OCR config' do
+      ocr = Kreuzberg::Config::OCR.new(vocabulary: { words: %w[Metformin XJ-4410] })
+
+      expect(ocr.vocabulary.words).to eq(%w[Metformin XJ-4410])
+      expect(ocr.vocabulary.correct).to be true
+      expect(ocr.vocabulary.max_distance).to eq(1)
+      expect(ocr.to_h[:vocabulary]).to include(words: %w[Metformin XJ-4410], min_word_length: 4)
+    end
+
+    it 'rejects values of the wrong type' do
+      expect { described_class.new(max_distance: 'two') }.to raise_error(Kreuzberg::Errors::ValidationError)
+    end
+  end
+
+  describe 'options parse_extraction_config previously dropped' do
+    it 'round-trips spreadsheet, adaptive concurrency, OCR fallback and image OCR policy options' do
+      config = Kreuzberg::Config::Extraction.new(
+        ocr: { fallback: { backend: 'tesseract', language: 'deu' } },
+        image_extraction: { ocr_policy: { min_bytes: 1024 } },
+        spreadsheet_options: { max_rows: 10, stream: true },
+        adaptive_concurrency: { max_concurrent: 4 }
+      )
+
+      merged = config.merge(Kreuzberg::Config::Extraction.new(force_ocr: true))
+
+      expect(merged.force_ocr).to be true
+      expect(merged.ocr.fallback.language).to eq('deu')
+      expect(merged.image_extraction.ocr_policy.min_bytes).to eq(1024)
+      expect(merged.spreadsheet_options.max_rows).to eq(10)
+      expect(merged.adaptive_concurrency.max_concurrent).to eq(4)
+    end
+  end
+end
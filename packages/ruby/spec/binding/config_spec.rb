@@ -28,6 +28,26 @@ RSpec.describe Kreuzberg::Config do
       expect(hash[:backend]).to eq('tesseract')
       expect(hash[:language]).to eq('fra')
     end
+
+    it 'includes engine pool settings only when given' do
+      expect(described_class.new.to_h).not_to include(:pool_size, :reuse)
+
+      hash = described_class.new(pool_size: 4, reuse: true).to_h
+
+      expect(hash[:pool_size]).to eq(4)
+      expect(hash[:reuse]).to be true
+    end
+
+    it 'rejects a non-positive pool size' do
+      expect { described_class.new(pool_size: 0) }.to raise_error(ArgumentError, /pool_size/)
+    end
+
+    it 'accepts engine pool settings during extraction' do
+      config = Kreuzberg::Config::Extraction.new(ocr: { pool_size: 2, reuse: true })
+      result = Kreuzberg.extract_bytes_sync('pooled', 'text/plain', config: config)
+
+      expect(result.content).to include('pooled')
+    end
   end
 
   describe Kreuzberg::Config::Chunking do
@@ -38,6 +58,7 @@ RSpec.describe Kreuzberg::Config do
       expect(chunking.max_overlap).to eq(200)
       expect(chunking.preset).to be_nil
       expect(chunking.embedding).to be_nil
+      expect(chunking.respect_sentences).to be false
     end
 
     it 'creates with custom values' do
@@ -59,6 +80,19 @@ RSpec.describe Kreuzberg::Config do
       expect(hash).to be_a(Hash)
       expect(hash[:max_chars]).to eq(750)
     end
+
+    it 'chunks whole sentences with respect_sentences' do
+      chunking = described_class.new(max_chars: 60, max_overlap: 0, respect_sentences: true)
+      config = Kreuzberg::Config::Extraction.new(chunking: chunking)
+      text = 'Dr. Smith arrived at noon. The meeting started late. Everyone stayed until the end.'
+
+      result = Kreuzberg.extract_bytes_sync(text, 'text/plain', config: config)
+
+      expect(chunking.to_h).to include(respect_sentences: true)
+      expect(result.chunks.map(&:content)).to eq(
+        ['Dr. Smith arrived at noon. The meeting started late.', 'Everyone stayed until the end.']
+      )
+    end
   end
 
   describe Kreuzberg::Config::LanguageDetection do
@@ -86,6 +120,113 @@ RSpec.describe Kreuzberg::Config do
     end
   end
 
+  describe Kreuzberg::Config::TextOptions do
+    it 'creates with default values' do
+      text = described_class.new
+
+      expect(text.encoding).to eq('auto')
+      expect(text.normalize_unicode).to be_nil
+    end
+
+    it 'accepts a symbol normalization form' do
+      text = described_class.new(encoding: 'cp1251', normalize_unicode: :nfc)
+
+      expect(text.to_h).to eq(encoding: 'cp1251', normalize_unicode: 'nfc')
+    end
+
+    it 'rejects unknown normalization forms' do
+      expect { described_class.new(normalize_unicode: :nfx) }.to raise_error(ArgumentError, /normalize_unicode/)
+    end
+
+    it 'is accepted as a hash by Extraction' do
+      config = Kreuzberg::Config::Extraction.new(text_options: { encoding: 'shift_jis' })
+
+      expect(config.text_options).to be_a(described_class)
+      expect(config.to_h[:text_options]).to eq(encoding: 'shift_jis')
+    end
+  end
+
+  describe Kreuzberg::Config::ImageExtraction do
+    it 'leaves image encoding untouched by default' do
+      image = described_class.new
+
+      expect(image.output_format).to be_nil
+      expect(image.to_h).not_to include(:output_format, :downscale_to, :max_bytes)
+    end
+
+    it 'accepts output encoding and size limits' do
+      image = described_class.new(output_format: :webp, downscale_to: 1024, max_bytes: 200_000)
+
+      expect(image.to_h).to include(output_format: 'webp', downscale_to: 1024, max_bytes: 200_000)
+    end
+
+    it 'rejects unknown output formats' do
+      expect { described_class.new(output_format: :gif) }.to raise_error(ArgumentError, /output_format/)
+    end
+
+    it 'disables image hashing by default' do
+      image = described_class.new
+
+      expect(image.to_h).to include(perceptual_hash: false, deduplicate_images: false, duplicate_hash_distance: 5)
+    end
+
+    it 'accepts duplicate suppression settings' do
+      image = described_class.new(deduplicate_images: true, duplicate_hash_distance: '3')
+
+      expect(image.deduplicate_images).to be true
+      expect(image.duplicate_hash_distance).to eq(3)
+    end
+  end
+
+  describe Kreuzberg::Config::CsvOptions do
+    it 'creates with default values' do
+      csv = described_class.new
+
+      expect(csv.delimiter).to be_nil
+      expect(csv.max_rows).to be_nil
+      expect(csv.to_h).to eq({})
+    end
+
+    it 'creates with custom values' do
+      csv = described_class.new(delimiter: ';', max_rows: 100)
+
+      expect(csv.to_h).to eq(delimiter: ';', max_rows: 100)
+    end
+
+    it 'rejects multi-character delimiters' do
+      expect { described_class.new(delimiter: ';;') }.to raise_error(ArgumentError, /delimiter/)
+    end
+
+    it 'is accepted as a hash by Extraction' do
+      config = Kreuzberg::Config::Extraction.new(csv_options: { delimiter: "\t" })
+
+      expect(config.csv_options).to be_a(described_class)
+      expect(config.to_h[:csv_options]).to eq(delimiter: "\t")
+    end
+  end
+
+  describe Kreuzberg::Config::PptxOptions do
+    it 'creates with default values' do
+      pptx = described_class.new
+
+      expect(pptx.include_notes).to be true
+      expect(pptx.slide_markers).to be false
+    end
+
+    it 'creates with custom values' do
+      pptx = described_class.new(include_notes: false, slide_markers: true)
+
+      expect(pptx.to_h).to eq(include_notes: false, slide_markers: true)
+    end
+
+    it 'is accepted as a hash by Extraction' do
+      config = Kreuzberg::Config::Extraction.new(pptx_options: { slide_markers: true })
+
+      expect(config.pptx_options).to be_a(described_class)
+      expect(config.to_h[:pptx_options]).to eq(include_notes: true, slide_markers: true)
+    end
+  end
+
   describe Kreuzberg::Config::FontConfig do
     it 'creates with default values' do
       font_config = described_class.new
@@ -365,6 +365,19 @@ RSpec.describe 'Configuration Validation' do
       expect(result).to be_a(Kreuzberg::Result)
     end
 
+    it 'returns one page per slide with pptx slide markers' do
+      path = test_document_path('presentations/simple.pptx')
+      config = Kreuzberg::Config::Extraction.new(
+        use_cache: false,
+        pptx_options: { include_notes: true, slide_markers: true }
+      )
+
+      result = Kreuzberg.extract_file_sync(path, config: config)
+      expect(result.content).to include('<!-- Slide number: 1 -->')
+      expect(result.pages).not_to be_nil
+      expect(result.pages.first.page_number).to eq(1)
+    end
+
     it 'works with combined configs' do
       path = create_test_file('Combined config test')
       config = Kreuzberg::Config::Extraction.new(
@@ -0,0 +1,79 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'json'
+require 'tmpdir'
+require 'yaml'
+
+RSpec.describe 'Kreuzberg.effective_config' do
+  let(:env_names) { %w[KREUZBERG_CONFIG_PATH KREUZBERG_OCR_BACKEND KREUZBERG_OCR_LANGUAGE] }
+
+  around do |example|
+    saved = env_names.to_h { |name| [name, ENV.fetch(name, nil)] }
+    Dir.mktmpdir do |dir|
+      config_path = File.join(dir, 'kreuzberg.toml')
+      File.write(config_path, <<~TOML)
+        use_cache = false
+        force_ocr = true
+
+        [overrides."application/pdf"]
+        force_ocr = false
+      TOML
+      env_names.each { |name| ENV.delete(name) }
+      ENV['KREUZBERG_CONFIG_PATH'] = config_path
+      example.run
+    end
+  ensure
+    saved.each { |name, value| ENV[name] = value }
+  end
+
+  it 'layers overrides over the config file' do
+    effective = Kreuzberg.effective_config(chunking: { max_chars: 500 })
+
+    expect(effective.config_file).to eq(ENV.fetch('KREUZBERG_CONFIG_PATH'))
+    expect(effective.config).to include('use_cache' => false, 'force_ocr' => true)
+    expect(effective.config['chunking']).to include('max_chars' => 500)
+    expect(effective.overrides).to eq(['chunking'])
+    expect(effective).to be_frozen
+  end
+
+  it 'reports the environment variables that changed the configuration' do
+    ENV['KREUZBERG_OCR_LANGUAGE'] = 'deu'
+
+    effective = Kreuzberg.effective_config
+
+    expect(effective.environment).to eq('KREUZBERG_OCR_LANGUAGE' => 'deu')
+    expect(effective.config.dig('ocr', 'language')).to eq('deu')
+  end
+
+  it 'resolves per-MIME overrides for a MIME type' do
+    pdf = Kreuzberg.effective_config(mime_type: 'application/pdf')
+    image = Kreuzberg.effective_config(mime_type: 'image/png')
+
+    expect(pdf.config['force_ocr']).to be(false)
+    expect(pdf.mime_type).to eq('application/pdf')
+    expect(image.config['force_ocr']).to be(true)
+  end
+
+  it 'accepts config objects as overrides' do
+    ocr = Kreuzberg::Config::OCR.new(backend: 'tesseract', language: 'fra')
+
+    effective = Kreuzberg.effective_config(ocr:, strictness: :strict)
+
+    expect(effective.config.dig('ocr', 'language')).to eq('fra')
+    expect(effective.overrides).to eq(['ocr'])
+  end
+
+  it 'exports the configuration as JSON and YAML' do
+    effective = Kreuzberg.effective_config(force_ocr: false)
+
+    expect(JSON.parse(effective.to_json)).to eq(effective.config)
+    expect(YAML.safe_load(effective.to_yaml)).to include('force_ocr' => false, 'use_cache' => false)
+  end
+
+  it 'raises a ValidationError for invalid overrides' do
+    expect do
+      Kreuzberg.effective_config(force_ocr: 'yes')
+    end.to raise_error(Kreuzberg::Errors::ValidationError, /Invalid configuration overrides/)
+  end
+end
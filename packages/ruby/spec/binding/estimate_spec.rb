@@ -0,0 +1,41 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Kreuzberg.estimate' do
+  let(:ocr_config) { { ocr: { backend: 'tesseract', language: 'eng' } } }
+
+  it 'profiles a PDF with a text layer without planning OCR' do
+    estimate = Kreuzberg.estimate(test_document_path('pdfs/fake_memo.pdf'), config: ocr_config)
+
+    expect(estimate.profile.mime_type).to eq('application/pdf')
+    expect(estimate.profile.page_count).to be_positive
+    expect(estimate.profile).to be_text_layer
+    expect(estimate.ocr_pages).to eq(0)
+    expect(estimate.stages.map(&:stage)).to eq(%i[extraction post_processing])
+    expect(estimate).to be_frozen
+  end
+
+  it 'plans OCR for images and forced OCR' do
+    image = Kreuzberg.estimate(test_document_path('images/ocr_image.jpg'), config: ocr_config)
+    forced = Kreuzberg.estimate(test_document_path('pdfs/fake_memo.pdf'),
+                                config: Kreuzberg::Config::Extraction.new(force_ocr: true, **ocr_config))
+
+    expect(image.profile.image_coverage).to eq(1.0)
+    expect(image.ocr_pages).to eq(1)
+    expect(image.stages.map(&:stage)).to include(:ocr)
+    expect(forced.ocr_pages).to eq(forced.profile.page_count)
+  end
+
+  it 'sums the stage durations' do
+    estimate = Kreuzberg.estimate(test_document_path('text/contract_test.txt'))
+
+    expect(estimate.profile.page_count).to be_nil
+    expect(estimate.duration_ms).to eq(estimate.stages.sum(&:duration_ms))
+    expect(estimate.peak_memory_bytes).to be > estimate.profile.size_bytes
+  end
+
+  it 'raises for a missing file' do
+    expect { Kreuzberg.estimate('/nonexistent/scan.pdf') }.to raise_error(Kreuzberg::Errors::Error)
+  end
+end
@@ -0,0 +1,69 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'stringio'
+require 'tempfile'
+require 'json'
+
+RSpec.describe 'Kreuzberg.extract_file_to_io' do
+  let(:text) { "Quarterly report\n\nRevenue grew by 12% — driven by exports.\n" }
+  let(:file) do
+    Tempfile.new(['report', '.txt']).tap do |f|
+      f.write(text)
+      f.close
+    end
+  end
+
+  after { file.unlink }
+
+  it 'writes the extracted content and returns the byte count' do
+    io = StringIO.new
+    written = Kreuzberg.extract_file_to_io(file.path, io, format: :text, use_cache: false)
+
+    expect(io.string.force_encoding(Encoding::UTF_8)).to eq(Kreuzberg.extract_file_sync(file.path).content)
+    expect(written).to eq(io.string.bytesize)
+  end
+
+  it 'streams large output in chunks split at character boundaries' do
+    large = Tempfile.new(['large', '.txt'])
+    large.write('é' * 100_000)
+    large.close
+
+    chunks = []
+    writer = Object.new
+    writer.define_singleton_method(:write) do |chunk|
+      chunks << chunk
+      chunk.bytesize
+    end
+
+    Kreuzberg.extract_file_to_io(large.path, writer, format: :text, config: { use_cache: false })
+
+    expect(chunks.size).to be > 1
+    expect(chunks.map(&:bytesize).max).to be <= 64 * 1024
+    expect(chunks).to all(be_valid_encoding)
+    expect(chunks.join).to start_with('é' * 1000)
+  ensure
+    large&.unlink
+  end
+
+  it 'writes the full result as JSON' do
+    io = StringIO.new
+    Kreuzberg.extract_file_to_io(file.path, io, format: :json, mime_type: 'text/plain')
+
+    json = JSON.parse(io.string)
+    expect(json['mime_type']).to eq('text/plain')
+    expect(json['content']).to include('Quarterly report')
+  end
+
+  it 'rejects unknown formats and destinations without write' do
+    expect { Kreuzberg.extract_file_to_io(file.path, StringIO.new, format: :pdf) }.to raise_error(ArgumentError)
+    expect { Kreuzberg.extract_file_to_io(file.path, Object.new) }.to raise_error(ArgumentError)
+  end
+
+  it 're-raises exceptions from the destination' do
+    writer = Object.new
+    writer.define_singleton_method(:write) { |_chunk| raise IOError, 'closed stream' }
+
+    expect { Kreuzberg.extract_file_to_io(file.path, writer) }.to raise_error(IOError, 'closed stream')
+  end
+end
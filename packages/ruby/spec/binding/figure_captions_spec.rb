@@ -0,0 +1,40 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'json'
+
+RSpec.describe 'figure captions' do
+  it 'exposes the caption and figure index of extracted images' do
+    result = Kreuzberg::Result.new(
+      'content' => "Figure 1: Revenue by quarter\n",
+      'mime_type' => 'application/pdf',
+      'metadata_json' => JSON.generate(
+        figures: [
+          { figure_index: 0, label: 'Figure 1', caption: 'Figure 1: Revenue by quarter',
+            page_number: 1, image_index: 0 }
+        ]
+      ),
+      'images' => [
+        { 'data' => 'png', 'format' => 'png', 'image_index' => 0, 'page_number' => 1,
+          'caption' => 'Figure 1: Revenue by quarter', 'figure_index' => 0 }
+      ]
+    )
+
+    image = result.images.first
+    expect(image.caption).to eq('Figure 1: Revenue by quarter')
+    expect(image.figure_index).to eq(0)
+    expect(image.to_h).to include(caption: 'Figure 1: Revenue by quarter', figure_index: 0)
+    expect(result.metadata['figures'].first['label']).to eq('Figure 1')
+  end
+
+  it 'leaves images without a caption unset' do
+    result = Kreuzberg::Result.new(
+      'content' => '',
+      'mime_type' => 'image/png',
+      'images' => [{ 'data' => 'png', 'format' => 'png', 'image_index' => 0 }]
+    )
+
+    expect(result.images.first.caption).to be_nil
+    expect(result.images.first.figure_index).to be_nil
+  end
+end
@@ -0,0 +1,65 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Kreuzberg.flatten_result' do
+  let(:metadata) do
+    {
+      'title' => 'Q3 Report',
+      'authors' => %w[Ada Grace],
+      'keywords' => %w[finance quarterly],
+      'pages' => { 'total_count' => 12, 'unit_type' => 'page' }
+    }
+  end
+  let(:result) do
+    Kreuzberg::Result.new(
+      'content' => 'Revenue grew.',
+      'mime_type' => 'application/pdf',
+      'metadata_json' => JSON.generate(metadata),
+      'detected_languages' => ['eng']
+    )
+  end
+
+  it 'returns the default columns as scalars' do
+    row = Kreuzberg.flatten_result(result)
+
+    expect(row.keys).to eq(Kreuzberg::FlattenAPI::DEFAULT_FLAT_FIELDS)
+    expect(row).to include(
+      'content' => 'Revenue grew.',
+      'mime_type' => 'application/pdf',
+      'title' => 'Q3 Report',
+      'subject' => nil,
+      'author' => 'Ada, Grace',
+      'language' => 'eng',
+      'page_count' => 12
+    )
+    expect(JSON.parse(row['keywords_json'])).to eq(%w[finance quarterly])
+    expect(JSON.parse(row['metadata_json'])).to eq(metadata)
+  end
+
+  it 'selects and orders the requested fields' do
+    row = Kreuzberg.flatten_result(result, fields: %i[page_count authors_json title])
+
+    expect(row).to eq('page_count' => 12, 'authors_json' => '["Ada","Grace"]', 'title' => 'Q3 Report')
+  end
+
+  it 'supports every documented field' do
+    row = Kreuzberg.flatten_result(result, fields: Kreuzberg::FlattenAPI::FLAT_FIELDS)
+
+    expect(row.keys).to eq(Kreuzberg::FlattenAPI::FLAT_FIELDS)
+    expect(row.values).to all(be_a(String).or(be_a(Integer)).or(be_nil))
+  end
+
+  it 'rejects unknown fields' do
+    expect { Kreuzberg.flatten_result(result, fields: %w[title body]) }
+      .to raise_error(ArgumentError, /Unknown result field 'body'/)
+  end
+
+  it 'flattens a real extraction' do
+    extracted = Kreuzberg.extract_bytes_sync('Hello world', 'text/plain')
+    row = Kreuzberg.flatten_result(extracted, fields: %w[content mime_type page_count])
+
+    expect(row['content']).to include('Hello world')
+    expect(row['mime_type']).to eq('text/plain')
+  end
+end
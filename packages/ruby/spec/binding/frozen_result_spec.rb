@@ -0,0 +1,48 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'frozen results' do
+  let(:text) { 'Frozen results can be shared across Ractors. ' * 20 }
+  let(:config) { { frozen: true, chunking: { max_chars: 200, max_overlap: 0 } } }
+
+  it 'is off by default' do
+    expect(Kreuzberg::Config::Extraction.new.frozen).to be(false)
+    expect(Kreuzberg::Config::Extraction.new.to_h).not_to include(:frozen)
+    expect(Kreuzberg::Config::Extraction.new(frozen: true).to_h[:frozen]).to be(true)
+
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain')
+    expect(result).not_to be_frozen
+    expect(result.content).not_to be_frozen
+  end
+
+  it 'deeply freezes the result' do
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain', config:)
+
+    expect(result).to be_frozen
+    expect(Ractor.shareable?(result)).to be(true)
+    expect(result.content).to be_frozen
+    expect(result.metadata).to be_frozen
+    expect(result.chunks).to all(be_frozen)
+    expect(result.chunks.map(&:content)).to all(be_frozen)
+    expect { result.content << 'x' }.to raise_error(FrozenError)
+  end
+
+  it 'freezes every result of a batch' do
+    results = Kreuzberg.batch_extract_bytes_sync([text, text], %w[text/plain text/plain], config:)
+
+    expect(results).to all(satisfy { |result| Ractor.shareable?(result) })
+  end
+
+  it 'survives a config merge' do
+    merged = Kreuzberg::Config::Extraction.new(frozen: true).merge(use_cache: false)
+    expect(merged.frozen).to be(true)
+  end
+
+  it 'freezes results built from a hash' do
+    result = Kreuzberg::Result.new({ 'content' => 'text', 'metadata_json' => '{"title":"Doc"}' }, frozen: true)
+
+    expect(Ractor.shareable?(result)).to be(true)
+    expect(result.metadata['title']).to be_frozen
+  end
+end
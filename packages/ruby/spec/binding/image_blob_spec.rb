@@ -0,0 +1,40 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'pathname'
+require 'tmpdir'
+
+RSpec.describe 'image blob store' do
+  it 'accepts a blob store directory' do
+    image = Kreuzberg::Config::ImageExtraction.new(blob_store_dir: Pathname.new('/tmp/blobs'))
+
+    expect(image.blob_store_dir).to eq('/tmp/blobs')
+    expect(Kreuzberg::Config::ImageExtraction.new.to_h).not_to include(:blob_store_dir)
+  end
+
+  it 'replaces image bytes with blob references read on demand' do
+    Dir.mktmpdir do |dir|
+      path = test_document_path('presentations/powerpoint_with_image.pptx')
+      inline = Kreuzberg.extract_file_sync(path, config: { image_extraction: { extract_images: true } })
+      config = Kreuzberg::Config::Extraction.new(
+        use_cache: false,
+        image_extraction: { extract_images: true, blob_store_dir: dir }
+      )
+      result = Kreuzberg.extract_file_sync(path, config: config)
+
+      expect(result.images).not_to be_empty
+      result.images.zip(inline.images).each do |image, inline_image|
+        expect(image.data).to be_empty
+        expect(image.blob[:path]).to start_with(dir)
+        expect(image.blob[:size]).to eq(inline_image.data.bytesize)
+        expect(image.read_data).to eq(inline_image.data)
+      end
+    end
+  end
+
+  it 'raises when a blob is missing' do
+    blob = { hash: '0123456789abcdef', path: '/nonexistent/blob.png', size: 4 }
+
+    expect { Kreuzberg.read_image_blob(blob) }.to raise_error(StandardError)
+  end
+end
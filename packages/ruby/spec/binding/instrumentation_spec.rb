@@ -0,0 +1,57 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Kreuzberg.instrumentation' do
+  let(:events) { [] }
+
+  before do
+    Kreuzberg.instrumentation = ->(event, payload) { events << [event, payload] }
+  end
+
+  after do
+    Kreuzberg.instrumentation = nil
+  end
+
+  it 'emits stage and document events for single extractions' do
+    Kreuzberg.extract_bytes_sync('Hello instrumentation', 'text/plain')
+
+    stages = events.select { |event, _| event == 'extraction.stage' }.map { |_, payload| payload[:stage] }
+    expect(stages).to eq(%i[parse_config extract convert_result])
+
+    _, document = events.find { |event, _| event == 'extraction.document' }
+    expect(document[:operation]).to eq(:extract_bytes_sync)
+    expect(document[:success]).to be(true)
+    expect(document[:mime_type]).to eq('text/plain')
+    expect(document[:duration_ms]).to be >= document[:stages].values.sum * 0.99
+  end
+
+  it 'emits a batch event with the document count' do
+    Kreuzberg.batch_extract_bytes_sync(%w[one two], %w[text/plain text/plain])
+
+    _, batch = events.find { |event, _| event == 'extraction.batch' }
+    expect(batch).to include(operation: :batch_extract_bytes_sync, count: 2, success: true)
+  end
+
+  it 'reports failures and ignores errors raised by the callback' do
+    expect do
+      Kreuzberg.extract_file_sync('/nonexistent/instrumentation.pdf')
+    end.to raise_error(StandardError)
+
+    _, document = events.find { |event, _| event == 'extraction.document' }
+    expect(document[:success]).to be(false)
+    expect(document[:error]).to be_a(String)
+
+    Kreuzberg.instrumentation = ->(_event, _payload) { raise 'metrics backend down' }
+    expect(Kreuzberg.extract_bytes_sync('still works', 'text/plain').content).to include('still works')
+  end
+
+  it 'is disabled with nil and rejects non-callables' do
+    Kreuzberg.instrumentation = nil
+    Kreuzberg.extract_bytes_sync('quiet', 'text/plain')
+
+    expect(events).to be_empty
+    expect(Kreuzberg.instrumentation).to be_nil
+    expect { Kreuzberg.instrumentation = 'not callable' }.to raise_error(ArgumentError)
+  end
+end
@@ -0,0 +1,35 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'key-value extraction' do
+  it 'finds label/value pairs in text' do
+    text = "ACME GmbH\nInvoice number: 2024-117    Due date: 2024-05-31\nBill to:\nContoso Ltd\nTotal    1,130.50"
+    config = Kreuzberg::Config::Extraction.new(
+      use_cache: false,
+      kv_extraction: Kreuzberg::Config::KeyValueExtraction.new
+    )
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain', config: config)
+
+    expect(result.metadata['key_values']).to be_an(Array)
+    expect(result.key_values.map { |pair| [pair.key, pair.value] }).to eq(
+      [['Invoice number', '2024-117'], ['Due date', '2024-05-31'], ['Bill to', 'Contoso Ltd'], %w[Total 1,130.50]]
+    )
+    expect(result.key_values.first.key_bbox).to be_nil
+  end
+
+  it 'is empty without kv_extraction' do
+    result = Kreuzberg.extract_bytes_sync('Total: 5', 'text/plain')
+    expect(result.key_values).to eq([])
+  end
+
+  it 'converts pairs with bounding boxes to hashes' do
+    bbox = { x: 50.0, y: 50.0, width: 70.0, height: 10.0 }
+    metadata = { key_values: [{ key: 'Total', value: '5.00', page: 1, key_bbox: bbox }] }
+    result = Kreuzberg::Result.new('content' => 'Total 5.00', 'metadata_json' => JSON.generate(metadata))
+
+    expect(result.key_values.map(&:to_h)).to eq(
+      [{ key: 'Total', value: '5.00', page: 1, key_bbox: bbox, value_bbox: nil }]
+    )
+  end
+end
@@ -0,0 +1,44 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Metadata write-back' do
+  let(:work_dir) { Dir.mktmpdir('kreuzberg-metadata') }
+  let(:docx_path) do
+    path = File.join(work_dir, 'lorem_ipsum.docx')
+    FileUtils.cp(test_document_path('documents/lorem_ipsum.docx'), path)
+    path
+  end
+
+  after do
+    FileUtils.rm_rf(work_dir)
+  end
+
+  it 'stamps title and keywords into a DOCX file' do
+    result = Kreuzberg.write_metadata(docx_path, { title: 'Processed', keywords: %w[processed finance] })
+
+    expect(result).to be_nil
+    extracted = Kreuzberg.extract_file_sync(docx_path, config: { use_cache: false })
+    expect(extracted.metadata['title']).to eq('Processed')
+    expect(extracted.metadata['keywords']).to include('processed')
+  end
+
+  it 'accepts a single keyword string' do
+    Kreuzberg.write_metadata(docx_path, { 'keywords' => 'classified' })
+
+    extracted = Kreuzberg.extract_file_sync(docx_path, config: { use_cache: false })
+    expect(extracted.metadata['keywords']).to include('classified')
+  end
+
+  it 'rejects unknown fields' do
+    expect { Kreuzberg.write_metadata(docx_path, { colour: 'red' }) }.to raise_error(ArgumentError, /colour/)
+  end
+
+  it 'rejects unsupported formats' do
+    text_path = create_test_file('plain text', filename: 'metadata_test.txt')
+
+    expect do
+      Kreuzberg.write_metadata(text_path, { title: 'x' })
+    end.to raise_error(Kreuzberg::Errors::UnsupportedFormatError)
+  end
+end
@@ -0,0 +1,46 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'content normalization' do
+  let(:text) { "“Smart” quotes – and hyphen-\nated   words" }
+
+  it 'enables every step by default' do
+    normalization = Kreuzberg::Config::Normalization.new
+
+    expect(normalization.to_h).to eq(
+      unicode: 'nfc',
+      collapse_whitespace: true,
+      standardize_quotes: true,
+      standardize_dashes: true,
+      dehyphenate: true
+    )
+    expect { Kreuzberg::Config::Normalization.new(unicode: :nfx) }.to raise_error(ArgumentError, /unicode/)
+  end
+
+  it 'leaves content untouched unless configured' do
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain')
+    expect(result.content).to eq(text)
+  end
+
+  it 'normalizes content before chunking' do
+    config = Kreuzberg::Config::Extraction.new(
+      normalization: { unicode: :nfkc },
+      chunking: { max_chars: 500 }
+    )
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain', config: config)
+
+    expect(result.content).to eq('"Smart" quotes - and hyphenated words')
+    expect(result.chunks.first.content).to eq(result.content)
+  end
+
+  it 'skips disabled steps' do
+    config = Kreuzberg::Config::Extraction.new(
+      normalization: Kreuzberg::Config::Normalization.new(unicode: nil, standardize_quotes: false)
+    )
+    expect(config.to_h[:normalization]).to include(unicode: nil, standardize_quotes: false)
+
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain', config: config)
+    expect(result.content).to eq("“Smart” quotes - and hyphenated words")
+  end
+end
@@ -0,0 +1,36 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'document outline' do
+  it 'nests HTML headings into the outline' do
+    html = '<h1>Guide</h1><p>Intro</p><h2>Install</h2><h3>Linux</h3><h2>Usage</h2>'
+    config = Kreuzberg::Config::Extraction.new(use_cache: false)
+    result = Kreuzberg.extract_bytes_sync(html, 'text/html', config: config)
+
+    expect(result.metadata['outline']).to be_an(Array)
+    guide = result.outline.first
+    expect(guide.title).to eq('Guide')
+    expect(guide.children.map(&:title)).to eq(%w[Install Usage])
+    expect(guide.children.first.children.first.level).to eq(3)
+
+    usage = guide.children.last
+    expect(result.content.byteslice(usage.byte_offset, 'Usage'.bytesize)).to eq('Usage')
+  end
+
+  it 'is empty for documents without headings' do
+    result = Kreuzberg.extract_bytes_sync('Hello world', 'text/plain')
+    expect(result.outline).to eq([])
+  end
+
+  it 'converts outline entries to hashes' do
+    metadata = { outline: [{ level: 1, title: 'Intro', page: 1, byte_offset: 0,
+                             children: [{ level: 2, title: 'Scope', page: 2 }] }] }
+    result = Kreuzberg::Result.new('content' => 'Intro', 'metadata_json' => JSON.generate(metadata))
+
+    expect(result.outline.map(&:to_h)).to eq(
+      [{ level: 1, title: 'Intro', page: 1, byte_offset: 0,
+         children: [{ level: 2, title: 'Scope', page: 2, byte_offset: nil, children: [] }] }]
+    )
+  end
+end
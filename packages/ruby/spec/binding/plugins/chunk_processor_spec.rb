@@ -0,0 +1,78 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Chunk processor plugins' do
+  let(:content) { "# Introduction\n\n#{'Plain sentence about the topic. ' * 40}" }
+  let(:config) do
+    Kreuzberg::Config::Extraction.new(
+      use_cache: false,
+      chunking: Kreuzberg::Config::Chunking.new(max_chars: 200, max_overlap: 20)
+    )
+  end
+
+  after do
+    Kreuzberg.clear_post_processors
+  end
+
+  it 'calls the processor once per chunk and keeps annotations' do
+    seen = []
+    Kreuzberg.register_chunk_processor('positions', lambda do |chunk|
+      seen << chunk['chunk_index']
+      chunk['annotations']['position'] = chunk['chunk_index']
+      chunk
+    end)
+
+    result = Kreuzberg.extract_bytes_sync(content, 'text/plain', config: config)
+
+    expect(result.chunks.length).to be > 1
+    expect(seen).to eq(result.chunks.map(&:chunk_index))
+    result.chunks.each do |chunk|
+      expect(chunk.annotations).to eq('position' => chunk.chunk_index)
+    end
+  end
+
+  it 'leaves chunks unchanged when the processor returns nil' do
+    Kreuzberg.register_chunk_processor('noop', ->(_chunk) {})
+
+    result = Kreuzberg.extract_bytes_sync(content, 'text/plain', config: config)
+
+    expect(result.chunks).not_to be_empty
+    expect(result.chunks.map(&:annotations)).to all(eq({}))
+  end
+
+  it 'applies content and annotations from a returned hash' do
+    Kreuzberg.register_chunk_processor('label', lambda do |chunk|
+      { content: chunk['content'].strip.downcase, annotations: { 'label' => 'body' } }
+    end)
+
+    result = Kreuzberg.extract_bytes_sync(content, 'text/plain', config: config)
+
+    result.chunks.each do |chunk|
+      expect(chunk.content).to eq(chunk.content.downcase)
+      expect(chunk.annotations).to eq('label' => 'body')
+    end
+  end
+
+  it 'is not called when chunking is disabled' do
+    called = false
+    Kreuzberg.register_chunk_processor('unused', lambda do |chunk|
+      called = true
+      chunk
+    end)
+
+    plain_config = Kreuzberg::Config::Extraction.new(use_cache: false)
+    result = Kreuzberg.extract_bytes_sync(content, 'text/plain', config: plain_config)
+
+    expect(called).to be false
+    expect(result.chunks).to be_empty
+  end
+
+  it 'raises when the processor returns something other than a hash' do
+    Kreuzberg.register_chunk_processor('broken', ->(_chunk) { 'not a hash' })
+
+    expect do
+      Kreuzberg.extract_bytes_sync(content, 'text/plain', config: config)
+    end.to raise_error(StandardError, /Chunk processor must return a Hash or nil/)
+  end
+end
@@ -0,0 +1,57 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Re-entrant plugins' do
+  let(:config) { Kreuzberg::Config::Extraction.new(use_cache: false) }
+
+  after do
+    Kreuzberg.clear_post_processors
+  end
+
+  it 'allows a post-processor to run a nested extraction' do
+    nested_config = config
+    processor = lambda do |result|
+      if Kreuzberg.plugin_stack.one?
+        nested = Kreuzberg.extract_bytes_sync('nested text', 'text/plain', config: nested_config)
+        result['metadata']['nested_content'] = nested.content
+      end
+      result
+    end
+
+    Kreuzberg.register_post_processor('nested', processor)
+    result = Kreuzberg.extract_bytes_sync('outer text', 'text/plain', config:)
+
+    expect(result.content).to include('outer text')
+    expect(result.metadata['nested_content']).to include('nested text')
+  end
+
+  it 'reports the running plugins through plugin_stack' do
+    seen = nil
+    processor = lambda do |result|
+      seen = Kreuzberg.plugin_stack
+      result
+    end
+
+    Kreuzberg.register_post_processor('spy', processor)
+    Kreuzberg.extract_bytes_sync('some text', 'text/plain', config:)
+
+    expect(seen).to eq(['spy'])
+    expect(Kreuzberg.plugin_stack).to be_empty
+  end
+
+  it 'raises a PluginError when nesting exceeds the depth limit' do
+    nested_config = config
+    processor = lambda do |result|
+      Kreuzberg.extract_bytes_sync('again', 'text/plain', config: nested_config)
+      result
+    end
+
+    Kreuzberg.register_post_processor('recursive', processor)
+
+    expect do
+      Kreuzberg.extract_bytes_sync('start', 'text/plain', config:)
+    end.to raise_error(Kreuzberg::Errors::PluginError, /depth limit of #{Kreuzberg::MAX_PLUGIN_DEPTH}/)
+    expect(Kreuzberg.plugin_stack).to be_empty
+  end
+end
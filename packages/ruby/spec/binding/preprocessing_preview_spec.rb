@@ -0,0 +1,30 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'OCR preprocessing preview' do
+  let(:image_bytes) { File.binread(test_document_path('images/ocr_image.jpg')) }
+
+  it 'returns a black-and-white PNG for each binarization method' do
+    %w[otsu adaptive sauvola].each do |method|
+      preview = Kreuzberg.preview_preprocessing(image_bytes, binarization_method: method, deskew: true)
+
+      expect(preview.byteslice(0, 8).bytes).to eq([0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A])
+    end
+  end
+
+  it 'accepts settings from an ImagePreprocessing config' do
+    config = Kreuzberg::Config::ImagePreprocessing.new(denoise: true, binarization_method: 'sauvola')
+
+    expect(Kreuzberg.preview_preprocessing(image_bytes, **config.to_h)).not_to be_empty
+  end
+
+  it 'rejects unknown options and invalid methods' do
+    expect { Kreuzberg.preview_preprocessing(image_bytes, sharpen: true) }.to raise_error(ArgumentError, /sharpen/)
+    expect { Kreuzberg.preview_preprocessing(image_bytes, binarization_method: 'magic') }.to raise_error(StandardError)
+  end
+
+  it 'raises for data that is not an image' do
+    expect { Kreuzberg.preview_preprocessing('not an image') }.to raise_error(StandardError)
+  end
+end
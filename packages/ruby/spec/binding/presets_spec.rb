@@ -0,0 +1,47 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Kreuzberg.preset' do
+  it 'lists the presets defined in Rust' do
+    expect(Kreuzberg.presets.keys).to eq(%i[rag_ingest archival_ocr fast_preview])
+    expect(Kreuzberg.presets.values).to all(be_a(String))
+  end
+
+  it 'returns fully populated rag_ingest options' do
+    options = Kreuzberg.preset(:rag_ingest)
+
+    expect(options[:chunking]).to include(max_chars: 1000, max_overlap: 200)
+    expect(options[:language_detection]).to include(enabled: true)
+    expect(options[:pages]).to include(extract_pages: true)
+    expect(options).to include(:normalization, :cleanup, :ocr)
+  end
+
+  it 'returns archival_ocr and fast_preview options' do
+    archival = Kreuzberg.preset('archival_ocr')
+    preview = Kreuzberg.preset('fast-preview')
+
+    expect(archival).to include(force_ocr: true, emit_provenance: true, resource_profile: :full)
+    expect(archival.dig(:ocr, :tesseract_config, :preprocessing, :target_dpi)).to eq(400)
+    expect(preview).to include(resource_profile: :minimal)
+    expect(preview).not_to include(:ocr)
+    expect(preview[:preview]).to include(sentences: 3)
+  end
+
+  it 'returns a new hash on every call' do
+    Kreuzberg.preset(:fast_preview)[:use_cache] = false
+
+    expect(Kreuzberg.preset(:fast_preview)[:use_cache]).to be(true)
+  end
+
+  it 'builds a valid configuration' do
+    config = Kreuzberg::Config::Extraction.new(**Kreuzberg.preset(:fast_preview))
+    result = Kreuzberg.extract_bytes_sync('A short note. It has two sentences.', 'text/plain', config:)
+
+    expect(result.content).to include('A short note')
+  end
+
+  it 'rejects unknown presets' do
+    expect { Kreuzberg.preset(:turbo) }.to raise_error(Kreuzberg::Errors::ValidationError, /rag_ingest/)
+  end
+end
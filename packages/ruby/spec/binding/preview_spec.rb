@@ -0,0 +1,30 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'document preview' do
+  let(:text) do
+    "ACME Corp\n\n# Project Update\n\nThe parser rewrite is finished and merged. " \
+      "Benchmarks show a 3x speedup on large PDFs.\n\nACME Corp\n2\n\nWe will release it next week.\n\nACME Corp\n3"
+  end
+
+  it 'stores the first meaningful sentences in metadata' do
+    config = Kreuzberg::Config::Extraction.new(use_cache: false, preview: Kreuzberg::Config::Preview.new(sentences: 2))
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain', config: config)
+
+    expect(result.preview).to eq(
+      'The parser rewrite is finished and merged. Benchmarks show a 3x speedup on large PDFs.'
+    )
+    expect(result.metadata['preview']).to eq(result.preview)
+  end
+
+  it 'is nil without preview' do
+    result = Kreuzberg.extract_bytes_sync(text, 'text/plain')
+    expect(result.preview).to be_nil
+  end
+
+  it 'round-trips the preview section through to_h' do
+    config = Kreuzberg::Config::Extraction.new(preview: { sentences: 1, skip_boilerplate: false })
+    expect(config.to_h[:preview]).to eq(sentences: 1, skip_boilerplate: false)
+  end
+end
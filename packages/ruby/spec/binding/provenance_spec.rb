@@ -0,0 +1,40 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'content provenance' do
+  it 'is nil unless enabled' do
+    result = Kreuzberg.extract_bytes_sync('Hello world', 'text/plain')
+    expect(result.provenance).to be_nil
+  end
+
+  it 'attributes the whole content of a text file to the text layer' do
+    config = Kreuzberg::Config::Extraction.new(emit_provenance: true)
+    result = Kreuzberg.extract_bytes_sync('Größe: 42 Äpfel', 'text/plain', config: config)
+
+    expect(result.provenance).to all(be_a(Kreuzberg::Result::ProvenanceSpan))
+    expect(result.provenance.map(&:source)).to eq([:text_layer])
+    expect(result.provenance.first.byte_start).to eq(0)
+    expect(result.provenance.first.byte_end).to eq(result.content.bytesize)
+  end
+
+  it 'round-trips through the config and result hashes' do
+    config = Kreuzberg::Config::Extraction.new(emit_provenance: true)
+    expect(config.to_h[:emit_provenance]).to be true
+
+    result = Kreuzberg::Result.new(
+      'content' => 'native scanned',
+      'provenance' => [
+        { 'byte_start' => 0, 'byte_end' => 7, 'source' => 'text_layer' },
+        { 'byte_start' => 7, 'byte_end' => 14, 'source' => 'ocr', 'page' => 2 }
+      ]
+    )
+    expect(result.provenance.last.page).to eq(2)
+    expect(result.to_h[:provenance]).to eq(
+      [
+        { byte_start: 0, byte_end: 7, source: :text_layer },
+        { byte_start: 7, byte_end: 14, source: :ocr, page: 2 }
+      ]
+    )
+  end
+end
@@ -0,0 +1,29 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'resource_profile' do
+  it 'defaults to standard and validates the profile' do
+    expect(Kreuzberg::Config::Extraction.new.resource_profile).to eq(:standard)
+    expect(Kreuzberg::Config::Extraction.new(resource_profile: 'minimal').to_h[:resource_profile]).to eq(:minimal)
+    expect(Kreuzberg::Config::Extraction.new.to_h).not_to include(:resource_profile)
+    expect do
+      Kreuzberg::Config::Extraction.new(resource_profile: :tiny)
+    end.to raise_error(ArgumentError, /resource_profile/)
+  end
+
+  it 'survives a merge' do
+    base = Kreuzberg::Config::Extraction.new(resource_profile: :full)
+    merged = base.merge(use_cache: false)
+
+    expect(merged.resource_profile).to eq(:full)
+    expect(merged.use_cache).to be(false)
+  end
+
+  it 'still extracts text under the minimal profile' do
+    config = Kreuzberg::Config::Extraction.new(resource_profile: :minimal, use_cache: false)
+    result = Kreuzberg.extract_bytes_sync('Hello from a small container', 'text/plain', config:)
+
+    expect(result.content).to include('Hello from a small container')
+  end
+end
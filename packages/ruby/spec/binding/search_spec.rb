@@ -0,0 +1,50 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Kreuzberg.search_result' do
+  let(:content) { "Invoice 1042 was issued in March.\nA second invoice followed in April." }
+  let(:result) { Kreuzberg::Result.new('content' => content) }
+
+  it 'finds literal matches with byte offsets and snippets' do
+    matches = Kreuzberg.search_result(result, 'March', context: 10)
+
+    expect(matches.size).to eq(1)
+    match = matches.first
+    expect(match).to be_a(Kreuzberg::SearchMatch)
+    expect(result.content.byteslice(match.byte_start, match.byte_end - match.byte_start)).to eq('March')
+    expect(match.snippet).to eq("issued in March.\nA second")
+    expect(match.highlighted_snippet).to eq("issued in **March**.\nA second")
+  end
+
+  it 'honors regexp flags, limits and highlight markers' do
+    matches = Kreuzberg.search_result(result, /invoice/i, highlight: %w[<mark> </mark>])
+    expect(matches.map(&:text)).to eq(%w[Invoice invoice])
+    expect(matches.first.highlighted_snippet).to start_with('<mark>Invoice</mark> 1042')
+
+    expect(Kreuzberg.search_result(result, /invoice/i, limit: 1).size).to eq(1)
+  end
+
+  it 'treats string patterns literally' do
+    expect(Kreuzberg.search_result(result, 'March.')).to be_one
+    expect(Kreuzberg.search_result(result, 'M.rch')).to be_empty
+  end
+
+  it 'maps matches to pages using chunk page ranges' do
+    page_result = Kreuzberg::Result.new(
+      'content' => "page one text\fpage two text",
+      'chunks' => [
+        { 'content' => 'page one text', 'byte_start' => 0, 'byte_end' => 14, 'first_page' => 1, 'last_page' => 1 },
+        { 'content' => 'page two text', 'byte_start' => 14, 'byte_end' => 27, 'first_page' => 2, 'last_page' => 2 }
+      ]
+    )
+
+    expect(Kreuzberg.search_result(page_result, 'two').map(&:page_number)).to eq([2])
+    expect(Kreuzberg.search_result(result, 'March').first.page_number).to be_nil
+  end
+
+  it 'rejects unsupported patterns' do
+    expect { Kreuzberg.search_result(result, 42) }.to raise_error(ArgumentError)
+    expect { Kreuzberg.search_result(result, /(?<=a)b/) }.to raise_error(ArgumentError, /Invalid search pattern/)
+  end
+end
@@ -0,0 +1,42 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'selected result fields' do
+  let(:html) do
+    '<html><head><title>Quarterly Report</title></head><body><h1>Summary</h1><p>Revenue grew.</p></body></html>'
+  end
+  let(:chunking) { Kreuzberg::Config::Chunking.new(max_chars: 50, max_overlap: 5) }
+
+  it 'returns only the requested fields' do
+    config = Kreuzberg::Config::Extraction.new(use_cache: false, chunking: chunking, fields: %w[metadata.title tables])
+    result = Kreuzberg.extract_bytes_sync(html, 'text/html', config: config)
+
+    expect(result.metadata).to eq('title' => 'Quarterly Report')
+    expect(result.content).to eq('')
+    expect(result.chunks).to be_nil
+    expect(result.mime_type).to eq('text/html')
+  end
+
+  it 'returns every field when no selection is given' do
+    config = Kreuzberg::Config::Extraction.new(use_cache: false, chunking: chunking)
+    result = Kreuzberg.extract_bytes_sync(html, 'text/html', config: config)
+
+    expect(result.content).to include('Revenue grew.')
+    expect(result.chunks).not_to be_empty
+  end
+
+  it 'rejects unknown fields' do
+    config = Kreuzberg::Config::Extraction.new(fields: %w[contents])
+
+    expect do
+      Kreuzberg.extract_bytes_sync(html, 'text/html', config: config)
+    end.to raise_error(Kreuzberg::Errors::ValidationError, /Unknown result field 'contents'/)
+  end
+
+  it 'accepts symbols and survives merges' do
+    config = Kreuzberg::Config::Extraction.new(fields: %i[content tables])
+    expect(config.to_h[:fields]).to eq(%w[content tables])
+    expect(config.merge(force_ocr: true).fields).to eq(%w[content tables])
+  end
+end
@@ -0,0 +1,54 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'tmpdir'
+
+RSpec.describe Kreuzberg::Session do
+  it 'pins the namespace, plugins and cache directory in its configuration' do
+    session = described_class.new(
+      namespace: :tenant_a,
+      plugins: [:quality],
+      cache_dir: Pathname.new('/tmp/kreuzberg-sessions'),
+      force_ocr: true
+    )
+
+    expect(session).to be_frozen
+    expect(session.config.force_ocr).to be(true)
+    expect(session.config.cache.to_h).to eq(backend: 'disk', url: '/tmp/kreuzberg-sessions', namespace: 'tenant_a')
+    expect(session.config.postprocessor.enabled_processors)
+      .to eq(described_class::BUILTIN_POST_PROCESSORS + ['quality'])
+    expect(session.cache_dir).to eq('/tmp/kreuzberg-sessions')
+  end
+
+  it 'leaves caching and post-processors alone when not scoped' do
+    session = described_class.new(namespace: 'tenant-b')
+
+    expect(session.config.cache).to be_nil
+    expect(session.config.postprocessor).to be_nil
+    expect(session.clear_cache).to eq(0)
+  end
+
+  it 'keeps the cached results of sessions apart' do
+    tenant_a = described_class.new(namespace: 'session-spec-a', cache: { backend: 'memory' })
+    tenant_b = described_class.new(namespace: 'session-spec-b', cache: { backend: 'memory' })
+
+    result = tenant_a.extract_bytes('Tenant document', 'text/plain')
+
+    expect(result.content).to include('Tenant document')
+    expect(tenant_b.clear_cache).to eq(0)
+    expect(tenant_a.clear_cache).to eq(1)
+  end
+
+  it 'keeps built-in post-processors running without plugins' do
+    session = described_class.new(plugins: [], classification: {}, use_cache: false)
+
+    letter = "Dear Ms Jones,\n\nPlease find the documents enclosed.\n\nYours sincerely,\nJ. Smith"
+    result = session.extract_bytes(letter, 'text/plain')
+
+    expect(result.metadata['document_type']['label']).to eq('letter')
+  end
+
+  it 'rejects unknown options' do
+    expect { described_class.new(unknown_option: true) }.to raise_error(ArgumentError)
+  end
+end
@@ -0,0 +1,41 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Kreuzberg.similarity' do
+  def result(content, chunks: nil)
+    Kreuzberg::Result.new('content' => content, 'mime_type' => 'text/plain', 'chunks' => chunks)
+  end
+
+  let(:invoice) do
+    'Invoice 2024-117 for consulting services rendered in March. Payment is due within ' \
+      'thirty days of receipt. Please include the invoice number with your transfer.'
+  end
+
+  it 'scores identical and near-duplicate content high with minhash' do
+    expect(Kreuzberg.similarity(result(invoice), result(invoice))).to eq(1.0)
+    expect(Kreuzberg.similarity(result(invoice), result(invoice.upcase.delete('.')))).to eq(1.0)
+    expect(Kreuzberg.similarity(result(invoice), result(invoice.sub('thirty', 'sixty')))).to be > 0.5
+  end
+
+  it 'scores unrelated content low with minhash' do
+    other = result('Minutes of the gardening club meeting: the committee approved new raised beds.')
+
+    expect(Kreuzberg.similarity(result(invoice), other, method: :minhash)).to be < 0.1
+  end
+
+  it 'compares existing chunk embeddings without loading a model' do
+    chunk = ->(embedding) { { 'content' => 'text', 'embedding' => embedding } }
+    a = result('a', chunks: [chunk.call([1.0, 0.0]), chunk.call([0.0, 1.0])])
+    b = result('b', chunks: [chunk.call([2.0, 2.0])])
+    c = result('c', chunks: [chunk.call([1.0, 0.0])])
+
+    expect(Kreuzberg.similarity(a, b, method: :embedding)).to be_within(1e-6).of(1.0)
+    expect(Kreuzberg.similarity(a, c, method: 'embedding')).to be_within(1e-6).of(Math.sqrt(0.5))
+  end
+
+  it 'rejects unknown methods' do
+    expect { Kreuzberg.similarity(result(invoice), result(invoice), method: :levenshtein) }
+      .to raise_error(ArgumentError, /minhash, embedding/)
+  end
+end
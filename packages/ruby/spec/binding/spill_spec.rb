@@ -0,0 +1,45 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'pathname'
+require 'tmpdir'
+
+RSpec.describe 'spill to disk' do
+  it 'accepts a spill directory and threshold' do
+    config = Kreuzberg::Config::Extraction.new(spill_dir: Pathname.new('/tmp/spill'), spill_threshold_bytes: '1024')
+
+    expect(config.spill_dir).to eq('/tmp/spill')
+    expect(config.spill_threshold_bytes).to eq(1024)
+    expect(config.to_h).to include(spill_dir: '/tmp/spill', spill_threshold_bytes: 1024)
+    expect(Kreuzberg::Config::Extraction.new.to_h).not_to include(:spill_dir, :spill_threshold_bytes)
+    expect(config.merge(Kreuzberg::Config::Extraction.new(force_ocr: true)).spill_dir).to eq('/tmp/spill')
+  end
+
+  it 'writes pages beyond the threshold to disk and reads them back on demand' do
+    Dir.mktmpdir do |dir|
+      path = test_document_path('pdfs/fake_memo.pdf')
+      pages = { extract_pages: true }
+      inline = Kreuzberg.extract_file_sync(path, config: { use_cache: false, pages: pages })
+      config = Kreuzberg::Config::Extraction.new(
+        use_cache: false, pages: pages, spill_dir: dir, spill_threshold_bytes: 0
+      )
+      result = Kreuzberg.extract_file_sync(path, config: config)
+
+      expect(result.content).to eq(inline.content)
+      result.pages.zip(inline.pages).each do |page, inline_page|
+        next if inline_page.content.empty?
+
+        expect(page.content).to be_empty
+        expect(page.content_blob[:path]).to start_with(dir)
+        expect(page.read_content).to eq(inline_page.content)
+        expect(page.read_content.encoding).to eq(Encoding::UTF_8)
+      end
+    end
+  end
+
+  it 'keeps pages in memory without a spill directory' do
+    page = Kreuzberg::Result::PageContent.new(page_number: 1, content: 'text', tables: [], images: [])
+
+    expect(page.read_content).to eq('text')
+  end
+end
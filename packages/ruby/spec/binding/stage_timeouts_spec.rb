@@ -0,0 +1,33 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'stage timeouts' do
+  it 'builds budgets from a hash' do
+    config = Kreuzberg::Config::Extraction.new(stage_timeouts: { extraction_ms: 60_000, ocr_ms: 30_000 })
+
+    expect(config.stage_timeouts).to be_a(Kreuzberg::Config::StageTimeouts)
+    expect(config.stage_timeouts.post_processing_ms).to be_nil
+    expect(config.to_h[:stage_timeouts]).to eq(extraction_ms: 60_000, ocr_ms: 30_000)
+  end
+
+  it 'survives a merge' do
+    base = Kreuzberg::Config::Extraction.new(use_cache: false)
+    merged = base.merge(Kreuzberg::Config::Extraction.new(stage_timeouts: { post_processing_ms: 5_000 }))
+
+    expect(merged.stage_timeouts.post_processing_ms).to eq(5_000)
+  end
+
+  it 'extracts normally within generous budgets' do
+    config = Kreuzberg::Config::Extraction.new(
+      stage_timeouts: { extraction_ms: 60_000, post_processing_ms: 60_000 }
+    )
+    result = Kreuzberg.extract_bytes_sync('Hello world', 'text/plain', config: config)
+
+    expect(result.content).to include('Hello world')
+  end
+
+  it 'exposes a dedicated error class' do
+    expect(Kreuzberg::Errors::TimeoutError.ancestors).to include(Kreuzberg::Errors::Error)
+  end
+end
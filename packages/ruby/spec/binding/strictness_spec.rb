@@ -0,0 +1,58 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'strictness' do
+  let(:low_confidence_metadata) do
+    JSON.generate(
+      'ocr_pages' => [
+        { 'page' => 1, 'backend' => 'tesseract', 'confidence' => 0.92, 'fallback_attempted' => false },
+        { 'page' => 2, 'backend' => 'tesseract', 'confidence' => 0.31, 'fallback_attempted' => false }
+      ]
+    )
+  end
+
+  it 'defaults to standard and validates the level' do
+    expect(Kreuzberg::Config::Extraction.new.strictness).to eq(:standard)
+    expect(Kreuzberg::Config::Extraction.new(strictness: 'strict').to_h[:strictness]).to eq(:strict)
+    expect(Kreuzberg::Config::Extraction.new.to_h).not_to include(:strictness)
+    expect { Kreuzberg::Config::Extraction.new(strictness: :paranoid) }.to raise_error(ArgumentError, /strictness/)
+  end
+
+  it 'reports quality warnings with machine-readable codes' do
+    result = Kreuzberg::Result.new('content' => 'scanned text', 'metadata_json' => low_confidence_metadata)
+
+    expect(result.warnings.map(&:code)).to eq([:low_ocr_confidence])
+    expect(result.warnings.first.details).to include(confidence: 0.31, pages: [2])
+  end
+
+  it 'skips the checks when lenient' do
+    result = Kreuzberg::Result.new({ 'content' => '' }, strictness: :lenient)
+    expect(result.warnings).to be_empty
+  end
+
+  it 'reports skipped encrypted parts' do
+    result = Kreuzberg::Result.new(
+      'content' => 'text',
+      'metadata_json' => JSON.generate('encrypted_parts_skipped' => ['images'])
+    )
+
+    expect(result.warnings.map(&:code)).to eq([:encrypted_content_skipped])
+    expect(result.warnings.first.details).to eq(parts: ['images'])
+  end
+
+  it 'returns the result with warnings in standard mode' do
+    result = Kreuzberg.extract_bytes_sync('   ', 'text/plain')
+    expect(result.warnings.map(&:code)).to include(:empty_content)
+  end
+
+  it 'raises typed exceptions in strict mode' do
+    expect do
+      Kreuzberg.extract_bytes_sync('   ', 'text/plain', config: { strictness: :strict })
+    end.to raise_error(Kreuzberg::Errors::EmptyContentError) { |error|
+      expect(error).to be_a(Kreuzberg::Errors::QualityError)
+      expect(error.code).to eq(:empty_content)
+      expect(error.result).to be_a(Kreuzberg::Result)
+    }
+  end
+end
@@ -0,0 +1,35 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'Kreuzberg.supported_formats' do
+  subject(:formats) { Kreuzberg.supported_formats }
+
+  it 'keys formats by MIME type in sorted order' do
+    expect(formats).to be_frozen
+    expect(formats.keys).to eq(formats.keys.sort)
+    expect(formats.values).to all(be_a(Kreuzberg::SupportedFormat))
+    expect(formats.values.map(&:mime_type)).to eq(formats.keys)
+  end
+
+  it 'describes PDF capabilities' do
+    pdf = formats.fetch('application/pdf')
+
+    expect(pdf.extractor).to eq('pdf-extractor')
+    expect(pdf.extensions).to include('pdf')
+    expect(pdf).to have_attributes(tables?: true, images?: true, metadata?: true, pages?: true, ocr?: true)
+  end
+
+  it 'flags images as needing OCR and plain text as not' do
+    expect(formats.fetch('image/png')).to be_ocr
+    expect(formats.fetch('text/plain')).not_to be_ocr
+    expect(formats.fetch('text/plain')).not_to be_tables
+  end
+
+  it 'returns frozen entries' do
+    format = formats.fetch('text/plain')
+
+    expect(format).to be_frozen
+    expect(format.extensions).to be_frozen
+  end
+end
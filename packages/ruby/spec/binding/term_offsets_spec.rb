@@ -0,0 +1,33 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+
+RSpec.describe 'term offsets' do
+  it 'is nil unless enabled' do
+    result = Kreuzberg.extract_bytes_sync('Hello world', 'text/plain')
+    expect(result.term_offsets).to be_nil
+  end
+
+  it 'emits lowercased terms with byte offsets into the content' do
+    config = Kreuzberg::Config::Extraction.new(emit_term_offsets: true)
+    result = Kreuzberg.extract_bytes_sync('Größe: 42 Äpfel', 'text/plain', config: config)
+
+    expect(result.term_offsets).to all(be_a(Kreuzberg::Result::TermOffset))
+    expect(result.term_offsets.map(&:term)).to eq(%w[größe 42 äpfel])
+    result.term_offsets.each do |offset|
+      term = result.content.byteslice(offset.byte_start, offset.byte_end - offset.byte_start)
+      expect(term.downcase).to eq(offset.term)
+    end
+  end
+
+  it 'round-trips through the config and result hashes' do
+    config = Kreuzberg::Config::Extraction.new(emit_term_offsets: true)
+    expect(config.to_h[:emit_term_offsets]).to be true
+
+    result = Kreuzberg::Result.new(
+      'content' => 'one two',
+      'term_offsets' => [{ 'term' => 'one', 'byte_start' => 0, 'byte_end' => 3, 'page' => 1 }]
+    )
+    expect(result.to_h[:term_offsets]).to eq([{ term: 'one', byte_start: 0, byte_end: 3, page: 1 }])
+  end
+end
@@ -0,0 +1,73 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'timeout'
+require 'tmpdir'
+
+RSpec.describe Kreuzberg::Watcher do
+  def collect(watcher, count)
+    entries = []
+    Timeout.timeout(10) do
+      watcher.run do |path, result, error|
+        entries << [File.basename(path), result, error]
+        watcher.stop if entries.length == count
+      end
+    end
+    entries
+  end
+
+  it 'extracts files dropped into the directory once they settle' do
+    Dir.mktmpdir do |dir|
+      watcher = described_class.new(dir, debounce: 0.2, concurrency: 2, use_cache: false)
+      File.write(File.join(dir, 'first.txt'), 'First dropped document')
+      File.write(File.join(dir, 'second.txt'), 'Second dropped document')
+
+      entries = collect(watcher, 2)
+
+      expect(entries.map(&:first)).to contain_exactly('first.txt', 'second.txt')
+      expect(entries.map { |_, result, _| result.content }).to all(include('dropped document'))
+      expect(entries.map(&:last)).to all(be_nil)
+      expect(watcher).to be_stopped
+    end
+  end
+
+  it 'yields failed extractions with their error and keeps watching' do
+    Dir.mktmpdir do |dir|
+      watcher = described_class.new(dir, debounce: 0.2, use_cache: false)
+      File.binwrite(File.join(dir, 'broken.unknownext'), "\x00\x01\x02")
+      File.write(File.join(dir, 'notes.txt'), 'Still extracted')
+
+      entries = collect(watcher, 2).to_h { |name, result, error| [name, [result, error]] }
+
+      expect(entries['broken.unknownext'].first).to be_nil
+      expect(entries['broken.unknownext'].last).to be_a(StandardError)
+      expect(entries['notes.txt'].first.content).to include('Still extracted')
+    end
+  end
+
+  it 'ignores hidden files' do
+    Dir.mktmpdir do |dir|
+      watcher = described_class.new(dir, debounce: 0.1, use_cache: false)
+      File.write(File.join(dir, '.upload.part'), 'Partial upload')
+      File.write(File.join(dir, 'done.txt'), 'Finished upload')
+
+      expect(collect(watcher, 1).map(&:first)).to eq(['done.txt'])
+    end
+  end
+
+  it 'rejects missing directories and invalid options' do
+    expect { described_class.new('/nonexistent/kreuzberg-inbox') }.to raise_error(ArgumentError)
+    Dir.mktmpdir do |dir|
+      expect { described_class.new(dir, concurrency: 0) }.to raise_error(ArgumentError)
+      expect { described_class.new(dir, unknown_option: true) }.to raise_error(ArgumentError)
+    end
+  end
+
+  it 'requires a block to run' do
+    Dir.mktmpdir do |dir|
+      watcher = described_class.new(dir)
+      expect { watcher.run }.to raise_error(ArgumentError)
+      watcher.close
+    end
+  end
+end
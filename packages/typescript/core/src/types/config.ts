@@ -15,10 +15,26 @@ export interface TesseractConfig {
 	tesseditCharWhitelist?: string;
 }
 
+/**
+ * Domain vocabulary for OCR, such as drug names or part numbers.
+ *
+ * Words from `words` and `wordsFile` (one entry per line, `#` starts a comment line) are
+ * added to Tesseract's user words. Unless `correct` is false, OCR words within
+ * `maxDistance` edits of exactly one vocabulary word are replaced with it.
+ */
+export interface OcrVocabularyConfig {
+	words?: string[];
+	wordsFile?: string;
+	correct?: boolean;
+	maxDistance?: number;
+	minWordLength?: number;
+}
+
 export interface OcrConfig {
 	backend: string;
 	language?: string;
 	tesseractConfig?: TesseractConfig;
+	vocabulary?: OcrVocabularyConfig;
 }
 
 // ============================================================================
@@ -73,6 +89,10 @@ export interface ImageExtractionConfig {
 	autoAdjustDpi?: boolean;
 	minDpi?: number;
 	maxDpi?: number;
+	stripGps?: boolean;
+	perceptualHash?: boolean;
+	deduplicateImages?: boolean;
+	duplicateHashDistance?: number;
 }
 
 // ============================================================================
@@ -155,6 +175,50 @@ export interface KeywordConfig {
 	rakeParams?: RakeParams;
 }
 
+// ============================================================================
+// Document Classification Configuration
+// ============================================================================
+
+export interface DocumentTypeRule {
+	label: string;
+	keywords?: string[];
+	titleKeywords?: string[];
+}
+
+export interface ClassificationConfig {
+	builtinTypes?: boolean;
+	types?: DocumentTypeRule[];
+	minConfidence?: number;
+}
+
+// ============================================================================
+// Key-Value Extraction Configuration
+// ============================================================================
+
+export interface KeyValueExtractionConfig {
+	builtinLabels?: boolean;
+	labels?: string[];
+	maxLabelWords?: number;
+}
+
+// ============================================================================
+// Cleanup Configuration
+// ============================================================================
+
+export interface CleanupConfig {
+	stripRepeated?: boolean;
+	tagInstead?: boolean;
+}
+
+// ============================================================================
+// Watermark Configuration
+// ============================================================================
+
+export interface WatermarkConfig {
+	excludeText?: boolean;
+	stampWords?: string[];
+}
+
 // ============================================================================
 // Main Extraction Configuration
 // ============================================================================
@@ -169,10 +233,21 @@ export interface ExtractionConfig {
 	pdfOptions?: PdfConfig;
 	tokenReduction?: TokenReductionConfig;
 	languageDetection?: LanguageDetectionConfig;
+	classification?: ClassificationConfig;
+	kvExtraction?: KeyValueExtractionConfig;
+	cleanup?: CleanupConfig;
+	watermarks?: WatermarkConfig;
 	postprocessor?: PostProcessorConfig;
 	htmlOptions?: HtmlConversionOptions;
 	keywords?: KeywordConfig;
 	maxConcurrentExtractions?: number;
+	incremental?: boolean;
+	/**
+	 * Partial configurations merged over this one for documents of matching MIME types,
+	 * keyed by an exact MIME type, a `type/*` wildcard or the catch-all `*\/*`. Section keys
+	 * use the Rust (snake_case) names, e.g. `{ "image/*": { ocr: { language: "deu" } } }`.
+	 */
+	overrides?: Record<string, Record<string, unknown>>;
 
 	/**
 	 * Serialize the configuration to a JSON string.
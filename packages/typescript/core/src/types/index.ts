@@ -14,20 +14,26 @@
 // Re-export configuration types
 export type {
 	ChunkingConfig,
+	ClassificationConfig,
+	CleanupConfig,
+	DocumentTypeRule,
 	ExtractionConfig,
 	FontConfig,
 	HtmlConversionOptions,
 	HtmlPreprocessingOptions,
 	ImageExtractionConfig,
+	KeyValueExtractionConfig,
 	KeywordAlgorithm,
 	KeywordConfig,
 	LanguageDetectionConfig,
 	OcrConfig,
+	OcrVocabularyConfig,
 	PdfConfig,
 	PostProcessorConfig,
 	RakeParams,
 	TesseractConfig,
 	TokenReductionConfig,
+	WatermarkConfig,
 	YakeParams,
 } from "./config.js";
 
@@ -49,12 +55,17 @@ export type {
 	HtmlMetadata,
 	ImageMetadata,
 	ImagePreprocessingMetadata,
+	KeyValueBoundingBox,
+	KeyValuePair,
 	Metadata,
 	OcrMetadata,
+	OutlineEntry,
 	PdfMetadata,
 	PptxMetadata,
 	TextMetadata,
+	Watermark,
 	XmlMetadata,
+	XmpMetadata,
 } from "./metadata.js";
 
 // Re-export protocol types
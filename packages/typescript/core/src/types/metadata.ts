@@ -37,6 +37,7 @@ export interface ImageMetadata {
 	height?: number;
 	format?: string;
 	exif?: Record<string, string>;
+	exif_details?: Record<string, unknown> | null;
 }
 
 export interface XmlMetadata {
@@ -125,6 +126,88 @@ export interface ImagePreprocessingMetadata {
 	resizeError?: string | null;
 }
 
+// ============================================================================
+// Document Outline
+// ============================================================================
+
+/** A heading in the document outline, with nested sub-headings. */
+export interface OutlineEntry {
+	level: number;
+	title: string;
+	page?: number | null;
+	byte_offset?: number | null;
+	children?: OutlineEntry[];
+}
+
+// ============================================================================
+// Key-Value Pairs
+// ============================================================================
+
+/** Rectangle with its origin at the top-left corner of the page, in PDF points. */
+export interface KeyValueBoundingBox {
+	x: number;
+	y: number;
+	width: number;
+	height: number;
+}
+
+/** A label and its value found by key-value extraction, e.g. `Invoice number` and `2024-117`. */
+export interface KeyValuePair {
+	key: string;
+	value: string;
+	page?: number | null;
+	key_bbox?: KeyValueBoundingBox | null;
+	value_bbox?: KeyValueBoundingBox | null;
+}
+
+/** A watermark or stamp with the pages it appears on; `rotation` is set for diagonal text, in degrees. */
+export interface Watermark {
+	kind: "text" | "image";
+	text?: string | null;
+	pages: number[];
+	page_coverage: number;
+	rotation?: number | null;
+}
+
+// ============================================================================
+// XMP Metadata
+// ============================================================================
+
+export interface XmpMetadata {
+	title?: string | null;
+	creator?: string[] | null;
+	description?: string | null;
+	subject?: string[] | null;
+	rights?: string | null;
+	publisher?: string[] | null;
+	contributor?: string[] | null;
+	language?: string[] | null;
+	date?: string[] | null;
+	identifier?: string | null;
+	format?: string | null;
+	createDate?: string | null;
+	modifyDate?: string | null;
+	metadataDate?: string | null;
+	creatorTool?: string | null;
+	rating?: string | null;
+	label?: string | null;
+	producer?: string | null;
+	keywords?: string | null;
+	pdfVersion?: string | null;
+	trapped?: string | null;
+	headline?: string | null;
+	credit?: string | null;
+	source?: string | null;
+	city?: string | null;
+	state?: string | null;
+	country?: string | null;
+	authorsPosition?: string | null;
+	captionWriter?: string | null;
+	instructions?: string | null;
+	dateCreated?: string | null;
+	category?: string | null;
+}
+
 // ============================================================================
 // Error Metadata
 // ============================================================================
@@ -193,6 +276,7 @@ export interface Metadata {
 	width?: number;
 	height?: number;
 	exif?: Record<string, string>;
+	exif_details?: Record<string, unknown> | null;
 
 	// XML-specific metadata
 	element_count?: number;
@@ -235,6 +319,18 @@ export interface Metadata {
 	// Image preprocessing metadata
 	image_preprocessing?: ImagePreprocessingMetadata | null;
 
+	// XMP packet metadata (PDF and image formats)
+	xmp?: XmpMetadata | null;
+
+	// Heading tree (PDF bookmarks, DOCX heading styles, HTML h1-h6)
+	outline?: OutlineEntry[] | null;
+
+	// Label/value pairs (kv_extraction)
+	key_values?: KeyValuePair[] | null;
+
+	// Watermarks and stamps (watermarks)
+	watermarks?: Watermark[] | null;
+
 	// JSON schema
 	json_schema?: Record<string, unknown> | null;
 
@@ -51,6 +51,7 @@ export interface ExtractedImage {
 	isMask: boolean;
 	description?: string | null;
 	ocrResult?: ExtractionResult | null;
+	perceptualHash?: string | null;
 }
 
 // ============================================================================
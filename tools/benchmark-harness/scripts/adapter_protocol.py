@@ -0,0 +1,125 @@
+"""Adapter side of the benchmark harness JSON lines protocol.
+
+The harness writes one request frame to stdin and reads result, error and progress frames
+from stdout, one JSON object per line. The specification lives in
+`tools/benchmark-harness/src/adapter/protocol.rs`; third-party adapters written in Python
+can copy this module.
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+import time
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+PROTOCOL_VERSION = 1
+
+
+def read_request() -> dict[str, Any]:
+    """Read the request frame the harness wrote to stdin."""
+    line = sys.stdin.readline()
+    if not line:
+        raise SystemExit("Expected a request frame on stdin")
+
+    frame = json.loads(line)
+    if frame.get("type") != "request":
+        raise SystemExit(f"Expected a request frame, got {frame.get('type')!r}")
+    if frame.get("protocol") != PROTOCOL_VERSION:
+        raise SystemExit(f"Unsupported adapter protocol version {frame.get('protocol')!r}")
+    return frame
+
+
+def _emit(line: str) -> None:
+    sys.stdout.write(line + "\n")
+    sys.stdout.flush()
+
+
+def _header(frame_type: str, request: dict[str, Any]) -> str:
+    return f'{{"type": "{frame_type}", "protocol": {PROTOCOL_VERSION}, "id": {request["id"]}'
+
+
+def emit_result(
+    request: dict[str, Any], file_path: str, output: dict[str, Any], *, report_serialization_time: bool = False
+) -> None:
+    """Write the result frame for one file.
+
+    With `report_serialization_time`, the time spent encoding `output` is added to it as
+    `_serialization_time_ms`.
+    """
+    start = time.perf_counter()
+    encoded = json.dumps(output)
+    if report_serialization_time:
+        serialization_ms = (time.perf_counter() - start) * 1000.0
+        # Splice the encoding time into the object instead of encoding the payload twice
+        encoded = f'{encoded[:-1]}, "_serialization_time_ms": {serialization_ms!r}}}'
+    _emit(f'{_header("result", request)}, "file": {json.dumps(file_path)}, "output": {encoded}}}')
+
+
+def error_code(error: BaseException) -> str | None:
+    """Classify an exception for the harness failure taxonomy, or None when it has no known class."""
+    name = type(error).__name__.lower()
+    if isinstance(error, UnicodeError) or "encoding" in name:
+        return "encoding_error"
+    if isinstance(error, TimeoutError) or "timeout" in name:
+        return "timeout"
+    if "unsupportedformat" in name:
+        return "unsupported_format"
+    return None
+
+
+def emit_error(
+    request: dict[str, Any], message: str, file_path: str | None = None, *, code: str | None = None
+) -> None:
+    """Write an error frame for one file, or for the whole request when `file_path` is None.
+
+    `code` classifies the failure (`timeout`, `crash`, `unsupported_format`, `empty_output`,
+    `encoding_error` or a Kreuzberg FFI error code name), see `error_code`.
+    """
+    frame: dict[str, Any] = {"type": "error", "protocol": PROTOCOL_VERSION, "id": request["id"], "message": message}
+    if file_path is not None:
+        frame["file"] = file_path
+    if code is not None:
+        frame["code"] = code
+    _emit(json.dumps(frame))
+
+
+def emit_progress(request: dict[str, Any], completed: int, total: int, message: str | None = None) -> None:
+    """Write a progress frame."""
+    frame: dict[str, Any] = {
+        "type": "progress",
+        "protocol": PROTOCOL_VERSION,
+        "id": request["id"],
+        "completed": completed,
+        "total": total,
+    }
+    if message is not None:
+        frame["message"] = message
+    _emit(json.dumps(frame))
+
+
+def serve(
+    extract: Callable[[str], dict[str, Any]],
+    *,
+    request: dict[str, Any] | None = None,
+    report_serialization_time: bool = False,
+) -> None:
+    """Answer a request by extracting its files one at a time.
+
+    Failures of individual files are reported as error frames, so one bad document does not
+    fail the rest of the request.
+    """
+    request = request if request is not None else read_request()
+    files = request["files"]
+    for index, file_path in enumerate(files):
+        try:
+            output = extract(file_path)
+        except Exception as e:
+            emit_error(request, str(e), file_path, code=error_code(e))
+        else:
+            emit_result(request, file_path, output, report_serialization_time=report_serialization_time)
+        if len(files) > 1:
+            emit_progress(request, index + 1, len(files), file_path)
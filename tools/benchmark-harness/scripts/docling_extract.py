@@ -3,15 +3,17 @@
 Supports two modes:
 - sync: convert() - synchronous single-file extraction
 - batch: convert_all() - batch extraction for multiple files
+
+Files arrive as a request frame on stdin; see adapter_protocol.py.
 """
 
 from __future__ import annotations
 
-import json
 import sys
 import time
 from typing import Any
 
+from adapter_protocol import emit_error, emit_result, error_code, read_request, serve
 from docling.document_converter import DocumentConverter
 
 
@@ -69,41 +71,31 @@ def extract_batch(file_paths: list[str]) -> list[dict[str, Any]]:
 
 
 def main() -> None:
-    if len(sys.argv) < 3:
-        print("Usage: docling_extract.py <mode> <file_path> [additional_files...]", file=sys.stderr)
+    if len(sys.argv) != 2:
+        print("Usage: docling_extract.py <mode> < request.jsonl", file=sys.stderr)
         print("Modes: sync, batch", file=sys.stderr)
         sys.exit(1)
 
     mode = sys.argv[1]
-    file_paths = sys.argv[2:]
-
-    try:
-        if mode == "sync":
-            if len(file_paths) != 1:
-                print("Error: sync mode requires exactly one file", file=sys.stderr)
-                sys.exit(1)
-            payload = extract_sync(file_paths[0])
-            print(json.dumps(payload), end="")
-
-        elif mode == "batch":
-            if len(file_paths) < 1:
-                print("Error: batch mode requires at least one file", file=sys.stderr)
-                sys.exit(1)
-
-            if len(file_paths) == 1:
-                results = extract_batch(file_paths)
-                print(json.dumps(results[0]), end="")
-            else:
-                results = extract_batch(file_paths)
-                print(json.dumps(results), end="")
+    if mode not in ("sync", "batch"):
+        print(f"Error: Unknown mode '{mode}'. Use sync or batch", file=sys.stderr)
+        sys.exit(1)
 
-        else:
-            print(f"Error: Unknown mode '{mode}'. Use sync or batch", file=sys.stderr)
-            sys.exit(1)
+    request = read_request()
 
-    except Exception as e:
-        print(f"Error extracting with Docling: {e}", file=sys.stderr)
-        sys.exit(1)
+    if mode == "sync":
+        serve(extract_sync, request=request)
+
+    else:
+        file_paths = request["files"]
+        try:
+            results = extract_batch(file_paths)
+        except Exception as e:
+            emit_error(request, f"Error extracting with Docling: {e}", code=error_code(e))
+            return
+
+        for file_path, payload in zip(file_paths, results, strict=True):
+            emit_result(request, file_path, payload)
 
 
 if __name__ == "__main__":
@@ -0,0 +1,163 @@
+#!/usr/bin/env python3
+"""Generate the synthetic workbooks used by the spreadsheet benchmark suite.
+
+The workbooks are written directly as SpreadsheetML (no third-party dependencies) so the
+cell counts and formula results asserted in ``fixtures/spreadsheets/*.json`` stay exact:
+
+- ``wide_columns.xlsx``   - 50 rows x 1,000 columns of numbers
+- ``formulas.xlsx``       - ledger with per-row ``=B*C`` formulas and a ``SUM`` grand total
+- ``merged_cells.xlsx``   - report with a merged title row and vertically merged region labels
+- ``million_rows.xlsx``   - 1,000,000 data rows x 5 columns (large file, ~25MB)
+
+Usage:
+    python3 generate_spreadsheet_fixtures.py [output_dir]
+
+The default output directory is ``test_documents/spreadsheets/generated`` at the repository root.
+"""
+
+from __future__ import annotations
+
+import sys
+import zipfile
+from pathlib import Path
+from typing import Iterable, Iterator
+from xml.sax.saxutils import escape
+
+REPO_ROOT = Path(__file__).resolve().parents[3]
+DEFAULT_OUTPUT_DIR = REPO_ROOT / "test_documents" / "spreadsheets" / "generated"
+
+CONTENT_TYPES = """<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
+<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>
+</Types>"""
+
+ROOT_RELS = """<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>
+</Relationships>"""
+
+WORKBOOK_RELS = """<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>
+</Relationships>"""
+
+WORKBOOK_TEMPLATE = """<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+<sheets><sheet name="{name}" sheetId="1" r:id="rId1"/></sheets>
+</workbook>"""
+
+SHEET_HEADER = (
+    '<?xml version="1.0" encoding="UTF-8" standalone="yes"?>\n'
+    '<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>'
+)
+
+# A cell is a plain value (str or number) or a (formula, cached_value) tuple.
+Cell = object
+
+
+def column_name(index: int) -> str:
+    """Convert a zero-based column index to its letter name (0 -> A, 26 -> AA)."""
+    name = ""
+    index += 1
+    while index:
+        index, remainder = divmod(index - 1, 26)
+        name = chr(ord("A") + remainder) + name
+    return name
+
+
+def cell_xml(ref: str, value: Cell) -> str:
+    if isinstance(value, tuple):
+        formula, cached = value
+        return f'<c r="{ref}"><f>{escape(formula)}</f><v>{cached}</v></c>'
+    if isinstance(value, (int, float)):
+        return f'<c r="{ref}"><v>{value}</v></c>'
+    return f'<c r="{ref}" t="inlineStr"><is><t>{escape(str(value))}</t></is></c>'
+
+
+def write_workbook(
+    path: Path,
+    sheet_name: str,
+    rows: Iterable[list[Cell | None]],
+    merged: list[str] | None = None,
+) -> None:
+    """Write a single-sheet workbook, streaming rows into the archive."""
+    path.parent.mkdir(parents=True, exist_ok=True)
+    with zipfile.ZipFile(path, "w", compression=zipfile.ZIP_DEFLATED) as archive:
+        archive.writestr("[Content_Types].xml", CONTENT_TYPES)
+        archive.writestr("_rels/.rels", ROOT_RELS)
+        archive.writestr("xl/_rels/workbook.xml.rels", WORKBOOK_RELS)
+        archive.writestr("xl/workbook.xml", WORKBOOK_TEMPLATE.format(name=escape(sheet_name)))
+
+        with archive.open("xl/worksheets/sheet1.xml", "w", force_zip64=True) as sheet:
+            sheet.write(SHEET_HEADER.encode())
+            for row_number, row in enumerate(rows, start=1):
+                cells = "".join(
+                    cell_xml(f"{column_name(col)}{row_number}", value)
+                    for col, value in enumerate(row)
+                    if value is not None
+                )
+                sheet.write(f'<row r="{row_number}">{cells}</row>'.encode())
+            sheet.write(b"</sheetData>")
+            if merged:
+                refs = "".join(f'<mergeCell ref="{ref}"/>' for ref in merged)
+                sheet.write(f'<mergeCells count="{len(merged)}">{refs}</mergeCells>'.encode())
+            sheet.write(b"</worksheet>")
+
+
+def wide_rows() -> Iterator[list[Cell | None]]:
+    columns = 1000
+    yield [f"col_{col}" for col in range(columns)]
+    for row in range(1, 50):
+        yield [row * columns + col for col in range(columns)]
+
+
+def formula_rows() -> Iterator[list[Cell | None]]:
+    yield ["Item", "Quantity", "Unit Price", "Total"]
+    for item in range(1, 101):
+        row_number = item + 1
+        yield [f"Item {item}", item, 10, (f"B{row_number}*C{row_number}", item * 10)]
+    yield ["Grand Total", None, None, ("SUM(D2:D101)", 50500)]
+
+
+def merged_rows() -> Iterator[list[Cell | None]]:
+    yield ["Quarterly Report", None, None, None]
+    yield ["Region", "Q1", "Q2", "Q3"]
+    for index, region in enumerate(["North", "South", "East", "West"]):
+        for offset in range(2):
+            base = (index * 2 + offset + 1) * 100
+            label = region if offset == 0 else None
+            yield [label, base + 1, base + 2, base + 3]
+
+
+MERGED_RANGES = ["A1:D1", "A3:A4", "A5:A6", "A7:A8", "A9:A10"]
+
+
+def million_rows() -> Iterator[list[Cell | None]]:
+    yield ["id", "customer", "amount", "quantity", "region"]
+    regions = ["north", "south", "east", "west"]
+    for row in range(1, 1_000_001):
+        yield [row, f"customer-{row % 5000}", row % 1000, row % 17, regions[row % 4]]
+
+
+def main() -> int:
+    output_dir = Path(sys.argv[1]) if len(sys.argv) > 1 else DEFAULT_OUTPUT_DIR
+
+    workbooks = [
+        ("wide_columns.xlsx", "Wide", wide_rows(), None),
+        ("formulas.xlsx", "Ledger", formula_rows(), None),
+        ("merged_cells.xlsx", "Report", merged_rows(), MERGED_RANGES),
+        ("million_rows.xlsx", "Transactions", million_rows(), None),
+    ]
+    for filename, sheet_name, rows, merged in workbooks:
+        path = output_dir / filename
+        write_workbook(path, sheet_name, rows, merged)
+        print(f"Wrote {path} ({path.stat().st_size} bytes)")
+
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -4,42 +4,83 @@ Supports three modes:
 - sync: extract_file_sync() - synchronous extraction
 - async: extract_file() - asynchronous extraction
 - batch: batch_extract_files_sync() - synchronous batch extraction
+
+Files arrive as a request frame on stdin; see adapter_protocol.py.
 """
 
 from __future__ import annotations
 
-import asyncio
-import json
-import sys
 import time
-from typing import Any
 
-from kreuzberg import batch_extract_files_sync, extract_file, extract_file_sync
+# Captured before any other import so the harness can separate spawn and import time
+PROCESS_START_EPOCH_MS = time.time() * 1000.0
+IMPORT_START = time.perf_counter()
+
+import asyncio  # noqa: E402
+import os  # noqa: E402
+import sys  # noqa: E402
+from typing import Any  # noqa: E402
+
+from adapter_protocol import emit_error, emit_result, error_code, read_request, serve  # noqa: E402
+from kreuzberg import batch_extract_files_sync, extract_file, extract_file_sync  # noqa: E402
+
+IMPORT_TIME_MS = (time.perf_counter() - IMPORT_START) * 1000.0
+
+
+def open_fd_count() -> int | None:
+    """Count descriptors open on files on disk (Linux only), for the harness leak check."""
+    try:
+        fds = os.listdir("/proc/self/fd")
+    except OSError:
+        return None
+    count = 0
+    for fd in fds:
+        try:
+            target = os.readlink(f"/proc/self/fd/{fd}")
+        except OSError:
+            continue
+        if target.startswith("/") and not target.startswith(("/dev", "/proc")):
+            count += 1
+    return count
 
 
 def extract_sync(file_path: str) -> dict[str, Any]:
     """Extract using synchronous API."""
+    fds_before = open_fd_count()
     start = time.perf_counter()
     result = extract_file_sync(file_path)
     duration_ms = (time.perf_counter() - start) * 1000.0
+    fds_after = open_fd_count()
 
     return {
         "content": result.content,
         "metadata": result.metadata or {},
+        "_table_count": len(result.tables),
         "_extraction_time_ms": duration_ms,
+        "_process_start_epoch_ms": PROCESS_START_EPOCH_MS,
+        "_import_time_ms": IMPORT_TIME_MS,
+        "_open_fds_before": fds_before,
+        "_open_fds_after": fds_after,
     }
 
 
 async def extract_async(file_path: str) -> dict[str, Any]:
     """Extract using asynchronous API."""
+    fds_before = open_fd_count()
     start = time.perf_counter()
     result = await extract_file(file_path)
     duration_ms = (time.perf_counter() - start) * 1000.0
+    fds_after = open_fd_count()
 
     return {
         "content": result.content,
         "metadata": result.metadata or {},
+        "_table_count": len(result.tables),
         "_extraction_time_ms": duration_ms,
+        "_process_start_epoch_ms": PROCESS_START_EPOCH_MS,
+        "_import_time_ms": IMPORT_TIME_MS,
+        "_open_fds_before": fds_before,
+        "_open_fds_after": fds_after,
     }
 
 
@@ -63,49 +104,35 @@ def extract_batch_sync(file_paths: list[str]) -> list[dict[str, Any]]:
 
 
 def main() -> None:
-    if len(sys.argv) < 3:
-        print("Usage: kreuzberg_extract.py <mode> <file_path> [additional_files...]", file=sys.stderr)
+    if len(sys.argv) != 2:
+        print("Usage: kreuzberg_extract.py <mode> < request.jsonl", file=sys.stderr)
         print("Modes: sync, async, batch", file=sys.stderr)
         sys.exit(1)
 
     mode = sys.argv[1]
-    file_paths = sys.argv[2:]
-
-    try:
-        if mode == "sync":
-            if len(file_paths) != 1:
-                print("Error: sync mode requires exactly one file", file=sys.stderr)
-                sys.exit(1)
-            payload = extract_sync(file_paths[0])
-            print(json.dumps(payload), end="")
-
-        elif mode == "async":
-            if len(file_paths) != 1:
-                print("Error: async mode requires exactly one file", file=sys.stderr)
-                sys.exit(1)
-            payload = asyncio.run(extract_async(file_paths[0]))
-            print(json.dumps(payload), end="")
-
-        elif mode == "batch":
-            if len(file_paths) < 1:
-                print("Error: batch mode requires at least one file", file=sys.stderr)
-                sys.exit(1)
-
-            if len(file_paths) == 1:
-                results = extract_batch_sync(file_paths)
-                print(json.dumps(results[0]), end="")
-            else:
-                results = extract_batch_sync(file_paths)
-                print(json.dumps(results), end="")
-
-        else:
-            print(f"Error: Unknown mode '{mode}'. Use sync, async, or batch", file=sys.stderr)
-            sys.exit(1)
-
-    except Exception as e:
-        print(f"Error extracting with Kreuzberg: {e}", file=sys.stderr)
+    if mode not in ("sync", "async", "batch"):
+        print(f"Error: Unknown mode '{mode}'. Use sync, async, or batch", file=sys.stderr)
         sys.exit(1)
 
+    request = read_request()
+
+    if mode == "sync":
+        serve(extract_sync, request=request, report_serialization_time=True)
+
+    elif mode == "async":
+        serve(lambda file_path: asyncio.run(extract_async(file_path)), request=request, report_serialization_time=True)
+
+    else:
+        file_paths = request["files"]
+        try:
+            results = extract_batch_sync(file_paths)
+        except Exception as e:
+            emit_error(request, f"Error extracting with Kreuzberg: {e}", code=error_code(e))
+            return
+
+        for file_path, payload in zip(file_paths, results, strict=True):
+            emit_result(request, file_path, payload)
+
 
 if __name__ == "__main__":
     main()
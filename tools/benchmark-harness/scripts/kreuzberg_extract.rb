@@ -9,6 +9,10 @@
 #
 # Debug output is written to stderr to avoid interfering with JSON output on stdout.
 
+# Captured before any require so the harness can separate spawn and import time
+PROCESS_START_EPOCH_MS = Process.clock_gettime(Process::CLOCK_REALTIME, :float_millisecond)
+IMPORT_START = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond)
+
 require 'json'
 
 DEBUG = ENV.fetch('KREUZBERG_BENCHMARK_DEBUG', 'false') == 'true'
@@ -58,14 +62,28 @@ rescue LoadError => e
 
   raise
 end
+IMPORT_TIME_MS = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond) - IMPORT_START
 debug_log "=== Initialization Complete ===" if DEBUG
 
+# Count descriptors open on files on disk (Linux only), for the harness leak check
+def open_fd_count
+  Dir.children('/proc/self/fd').count do |fd|
+    target = File.readlink("/proc/self/fd/#{fd}")
+    target.start_with?('/') && !target.start_with?('/dev', '/proc')
+  rescue SystemCallError
+    false
+  end
+rescue SystemCallError
+  nil
+end
+
 def extract_sync(file_path)
   debug_log "=== SYNC EXTRACTION START ==="
   debug_log "Input: file_path=#{file_path}"
   debug_log "File exists: #{File.exist?(file_path)}"
   debug_log "File size: #{File.size(file_path)} bytes" if File.exist?(file_path)
 
+  fds_before = open_fd_count
   start_monotonic = Process.clock_gettime(Process::CLOCK_MONOTONIC)
   start_wall = Time.now
   debug_log "Timing start (monotonic): #{start_monotonic.round(6)}, wall: #{start_wall.iso8601(6)}"
@@ -73,6 +91,7 @@ def extract_sync(file_path)
   result = Kreuzberg.extract_file(file_path)
 
   end_monotonic = Process.clock_gettime(Process::CLOCK_MONOTONIC)
+  fds_after = open_fd_count
   end_wall = Time.now
   duration_s = end_monotonic - start_monotonic
   duration_ms = duration_s * 1000.0
@@ -89,7 +108,12 @@ def extract_sync(file_path)
   payload = {
     content: result.content,
     metadata: result.metadata || {},
-    _extraction_time_ms: duration_ms
+    _table_count: (result.tables || []).length,
+    _extraction_time_ms: duration_ms,
+    _process_start_epoch_ms: PROCESS_START_EPOCH_MS,
+    _import_time_ms: IMPORT_TIME_MS,
+    _open_fds_before: fds_before,
+    _open_fds_after: fds_after
   }
 
   debug_log "Output JSON size: #{JSON.generate(payload).bytesize} bytes"
@@ -147,6 +171,15 @@ rescue StandardError => e
   raise
 end
 
+# Generate JSON for payload including the time spent encoding it
+def generate_with_serialization_time(payload)
+  start = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond)
+  encoded = JSON.generate(payload)
+  serialization_ms = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond) - start
+  # Splice the encoding time into the object instead of encoding the payload twice
+  "#{encoded[0..-2]},\"_serialization_time_ms\":#{serialization_ms}}"
+end
+
 def main
   debug_log "Ruby script started"
   debug_log "ARGV: #{ARGV.inspect}"
@@ -173,7 +206,7 @@ def main
     end
     debug_log "Executing sync mode with file: #{file_paths[0]}"
     payload = extract_sync(file_paths[0])
-    output = JSON.generate(payload)
+    output = generate_with_serialization_time(payload)
     debug_log "Output JSON: #{output}"
     puts output
 
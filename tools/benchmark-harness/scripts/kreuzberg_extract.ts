@@ -7,30 +7,82 @@
  * - batch: batchExtractFile() - batch extraction for multiple files
  */
 
-import { batchExtractFile, extractFile } from "@kreuzberg/node";
+import { readdirSync, readlinkSync } from "node:fs";
+
+// Captured before the binding is loaded so the harness can separate spawn and import time
+const processStartEpochMs = Date.now();
+
+// Loaded dynamically in main() so the import can be timed
+let kreuzberg: typeof import("@kreuzberg/node");
+let importTimeMs = 0;
 
 interface ExtractionOutput {
 	content: string;
 	metadata: Record<string, unknown>;
+	_table_count?: number;
 	_extraction_time_ms: number;
 	_batch_total_ms?: number;
+	_process_start_epoch_ms?: number;
+	_import_time_ms?: number;
+	_open_fds_before?: number | null;
+	_open_fds_after?: number | null;
+}
+
+/** Count descriptors open on files on disk (Linux only), for the harness leak check. */
+function openFdCount(): number | null {
+	let fds: string[];
+	try {
+		fds = readdirSync("/proc/self/fd");
+	} catch {
+		return null;
+	}
+	return fds.filter((fd) => {
+		try {
+			const target = readlinkSync(`/proc/self/fd/${fd}`);
+			return target.startsWith("/") && !target.startsWith("/dev") && !target.startsWith("/proc");
+		} catch {
+			return false;
+		}
+	}).length;
+}
+
+async function loadKreuzberg(): Promise<void> {
+	const start = performance.now();
+	kreuzberg = await import("@kreuzberg/node");
+	importTimeMs = performance.now() - start;
+}
+
+/** Print payload as JSON including the time spent encoding it. */
+function emitWithSerializationTime(payload: ExtractionOutput): void {
+	const start = performance.now();
+	const encoded = JSON.stringify(payload);
+	const serializationTimeMs = performance.now() - start;
+	// Splice the encoding time into the object instead of encoding the payload twice
+	console.log(`${encoded.slice(0, -1)},"_serialization_time_ms":${serializationTimeMs}}`);
 }
 
 async function extractAsync(filePath: string): Promise<ExtractionOutput> {
+	const fdsBefore = openFdCount();
 	const start = performance.now();
-	const result = await extractFile(filePath);
+	const result = await kreuzberg.extractFile(filePath);
 	const durationMs = performance.now() - start;
+	const fdsAfter = openFdCount();
 
 	return {
 		content: result.content,
 		metadata: result.metadata || {},
+		_table_count: result.tables.length,
 		_extraction_time_ms: durationMs,
+		_process_start_epoch_ms: processStartEpochMs,
+		_import_time_ms: importTimeMs,
+		_open_fds_before: fdsBefore,
+		_open_fds_after: fdsAfter,
 	};
 }
 
 async function extractBatch(filePaths: string[]): Promise<ExtractionOutput[]> {
 	const start = performance.now();
-	const results = await batchExtractFile(filePaths);
+	const results = await kreuzberg.batchExtractFile(filePaths);
 	const totalDurationMs = performance.now() - start;
 
 	const perFileDurationMs = filePaths.length > 0 ? totalDurationMs / filePaths.length : 0;
@@ -68,13 +120,15 @@ async function main(): Promise<void> {
 	const filePaths = args.slice(1);
 
 	try {
+		await loadKreuzberg();
+
 		if (mode === "async") {
 			if (filePaths.length !== 1) {
 				console.error("Error: async mode requires exactly one file");
 				process.exit(1);
 			}
 			const payload = await extractAsync(filePaths[0]);
-			console.log(JSON.stringify(payload));
+			emitWithSerializationTime(payload);
 		} else if (mode === "batch") {
 			if (filePaths.length < 1) {
 				console.error("Error: batch mode requires at least one file");
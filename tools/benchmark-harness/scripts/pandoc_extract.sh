@@ -15,15 +15,18 @@ if [ ! -f "$FILE_PATH" ]; then
 	exit 1
 fi
 
+# Output format: plain text by default, PANDOC_TO=gfm for markdown (structure benchmarks)
+PANDOC_TO="${PANDOC_TO:-plain}"
+
 # Measure extraction time
 START=$(date +%s%N)
 
 # Extract text using pandoc
-# --to=plain: plain text output
+# --to: plain text (or $PANDOC_TO) output
 # --wrap=none: no line wrapping
 # --strip-comments: remove HTML comments
 # 2>/dev/null: suppress warnings
-CONTENT=$(pandoc "$FILE_PATH" --to=plain --wrap=none --strip-comments 2>/dev/null || echo "")
+CONTENT=$(pandoc "$FILE_PATH" --to="$PANDOC_TO" --wrap=none --strip-comments 2>/dev/null || echo "")
 
 END=$(date +%s%N)
 DURATION_MS=$(((END - START) / 1000000))
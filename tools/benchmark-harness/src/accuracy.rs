@@ -0,0 +1,406 @@
+//! Provenance-aware accuracy scoring
+//!
+//! A PDF's text can come from its embedded text layer or from OCR of the rendered pages.
+//! A framework that OCRs a born-digital PDF is slower and loses accuracy compared to one
+//! that reads the text layer, but a single accuracy average cannot tell the two apart.
+//! `BenchmarkRunner` therefore scores every result against the fixture's ground truth and
+//! records where the text came from; [`AccuracyReport`] splits the scores by provenance and
+//! lists the born-digital fixtures each framework OCR'd.
+//!
+//! ## Fixture Format
+//!
+//! ```json
+//! {
+//!   "document": "pdfs/invoice.pdf",
+//!   "file_type": "pdf",
+//!   "file_size": 48213,
+//!   "text_layer": true,
+//!   "ground_truth": {
+//!     "text_file": "ground_truth/invoice.txt",
+//!     "source": "pdf_text_layer"
+//!   }
+//! }
+//! ```
+//!
+//! `text_layer` declares whether the document is born-digital (`true`) or scanned
+//! (`false`). Without it, a ground truth taken from the PDF text layer implies a text layer.
+//!
+//! Where the text came from is read from the adapter output: subprocess wrappers may report
+//! `_text_provenance` (`"text_layer"`, `"ocr"` or `"mixed"`); otherwise Kreuzberg's OCR
+//! metadata (`format_type: "ocr"`, `ocr_pages`, `ocr_spans`) marks the text as OCR. Results
+//! with neither are scored under `unknown` provenance.
+
+use crate::types::{BenchmarkResult, QualityMetrics};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Metadata keys Kreuzberg sets when the content came from OCR
+const KREUZBERG_OCR_METADATA_KEYS: &[&str] = &["ocr_pages", "ocr_spans"];
+
+/// Where extracted text came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextProvenance {
+    /// Embedded text layer of the document
+    TextLayer,
+    /// OCR of rendered pages or images
+    Ocr,
+    /// Text layer for some pages, OCR for others
+    Mixed,
+}
+
+impl TextProvenance {
+    /// Read the provenance from a subprocess wrapper's JSON payload
+    ///
+    /// Returns `None` when the payload does not report it and carries no OCR metadata.
+    pub fn from_wrapper_output(parsed: &serde_json::Value) -> Option<Self> {
+        if let Some(reported) = parsed.get("_text_provenance") {
+            return serde_json::from_value(reported.clone()).ok();
+        }
+
+        let metadata = parsed.get("metadata")?;
+        let ocr = metadata.get("format_type").and_then(|v| v.as_str()) == Some("ocr")
+            || KREUZBERG_OCR_METADATA_KEYS
+                .iter()
+                .any(|key| metadata.get(key).is_some_and(|v| !v.is_null()));
+        ocr.then_some(Self::Ocr)
+    }
+
+    /// Read the provenance from the metadata of a native Kreuzberg extraction
+    pub fn from_kreuzberg_metadata(metadata: &kreuzberg::Metadata) -> Option<Self> {
+        let ocr = matches!(metadata.format, Some(kreuzberg::FormatMetadata::Ocr(_)))
+            || KREUZBERG_OCR_METADATA_KEYS
+                .iter()
+                .any(|key| metadata.additional.contains_key(*key));
+        ocr.then_some(Self::Ocr)
+    }
+
+    /// Name used in reports
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TextLayer => "text_layer",
+            Self::Ocr => "ocr",
+            Self::Mixed => "mixed",
+        }
+    }
+}
+
+/// Provenance of one result's text, as reported for its fixture
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResultProvenance {
+    /// Whether the fixture document is born-digital, if the fixture says so
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub born_digital: Option<bool>,
+
+    /// Where the framework's text came from, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extracted: Option<TextProvenance>,
+}
+
+impl ResultProvenance {
+    /// Whether the framework OCR'd a document whose text layer it could have read
+    pub fn is_unneeded_ocr(&self) -> bool {
+        self.born_digital == Some(true) && matches!(self.extracted, Some(TextProvenance::Ocr | TextProvenance::Mixed))
+    }
+}
+
+/// Score extracted text against ground truth
+///
+/// Text and numeric F1 compare the bags of word and number tokens (words are compared
+/// case-insensitively), layout F1 compares the bags of whitespace-normalized non-empty
+/// lines. A category that is absent from both texts scores 1.0. The quality score weights
+/// text 0.6, numeric 0.25 and layout 0.15.
+pub fn score_text(extracted: &str, ground_truth: &str) -> QualityMetrics {
+    let (extracted_words, extracted_numbers) = tokens(extracted);
+    let (truth_words, truth_numbers) = tokens(ground_truth);
+
+    let f1_score_text = bag_f1(&extracted_words, &truth_words);
+    let f1_score_numeric = bag_f1(&extracted_numbers, &truth_numbers);
+    let f1_score_layout = bag_f1(&lines(extracted), &lines(ground_truth));
+
+    QualityMetrics {
+        f1_score_text,
+        f1_score_numeric,
+        f1_score_layout,
+        quality_score: 0.6 * f1_score_text + 0.25 * f1_score_numeric + 0.15 * f1_score_layout,
+    }
+}
+
+/// Split text into lowercase word tokens and number tokens
+fn tokens(text: &str) -> (Vec<String>, Vec<String>) {
+    let mut words = Vec::new();
+    let mut numbers = Vec::new();
+
+    for raw in text.split_whitespace() {
+        let token = raw.trim_matches(|c: char| !c.is_alphanumeric());
+        if token.is_empty() {
+            continue;
+        }
+        if token
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '/'))
+        {
+            numbers.push(token.to_string());
+        } else {
+            words.push(token.to_lowercase());
+        }
+    }
+
+    (words, numbers)
+}
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// F1 score of two bags of tokens
+fn bag_f1(extracted: &[String], truth: &[String]) -> f64 {
+    if extracted.is_empty() && truth.is_empty() {
+        return 1.0;
+    }
+
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    for token in truth {
+        *remaining.entry(token.as_str()).or_default() += 1;
+    }
+
+    let mut overlap = 0;
+    for token in extracted {
+        if let Some(count) = remaining.get_mut(token.as_str())
+            && *count > 0
+        {
+            *count -= 1;
+            overlap += 1;
+        }
+    }
+    if overlap == 0 {
+        return 0.0;
+    }
+
+    let precision = overlap as f64 / extracted.len() as f64;
+    let recall = overlap as f64 / truth.len() as f64;
+    2.0 * precision * recall / (precision + recall)
+}
+
+/// Mean accuracy of one framework over results of one provenance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceAccuracy {
+    /// Number of scored results
+    pub files: usize,
+
+    /// Mean scores over those results
+    pub mean: QualityMetrics,
+}
+
+/// Accuracy of one framework, split by text provenance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameworkAccuracy {
+    /// Framework name
+    pub framework: String,
+
+    /// Mean scores keyed by provenance (`text_layer`, `ocr`, `mixed` or `unknown`)
+    pub by_provenance: BTreeMap<String, ProvenanceAccuracy>,
+
+    /// Born-digital fixture documents the framework extracted with OCR
+    pub unneeded_ocr: Vec<PathBuf>,
+}
+
+/// Accuracy summary of a benchmark run, split by text provenance
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccuracyReport {
+    /// Number of results scored against ground truth
+    pub scored: usize,
+
+    /// Per-framework accuracy, sorted by framework name
+    pub frameworks: Vec<FrameworkAccuracy>,
+}
+
+impl AccuracyReport {
+    /// Collect scores and provenance from benchmark results
+    pub fn from_results(results: &[BenchmarkResult]) -> Self {
+        let mut scores: BTreeMap<&str, BTreeMap<&str, Vec<&QualityMetrics>>> = BTreeMap::new();
+        let mut unneeded_ocr: BTreeMap<&str, Vec<PathBuf>> = BTreeMap::new();
+        let mut scored = 0;
+
+        for result in results {
+            let provenance = result.provenance.unwrap_or_default();
+
+            if provenance.is_unneeded_ocr() {
+                unneeded_ocr
+                    .entry(result.framework.as_str())
+                    .or_default()
+                    .push(result.file_path.clone());
+            }
+
+            if let Some(quality) = &result.quality {
+                scored += 1;
+                let key = provenance.extracted.map_or("unknown", TextProvenance::as_str);
+                scores
+                    .entry(result.framework.as_str())
+                    .or_default()
+                    .entry(key)
+                    .or_default()
+                    .push(quality);
+            }
+        }
+
+        let mut frameworks: Vec<&str> = scores.keys().chain(unneeded_ocr.keys()).copied().collect();
+        frameworks.sort_unstable();
+        frameworks.dedup();
+
+        let frameworks = frameworks
+            .into_iter()
+            .map(|framework| FrameworkAccuracy {
+                framework: framework.to_string(),
+                by_provenance: scores
+                    .remove(framework)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(key, metrics)| {
+                        let accuracy = ProvenanceAccuracy {
+                            files: metrics.len(),
+                            mean: mean_quality(&metrics),
+                        };
+                        (key.to_string(), accuracy)
+                    })
+                    .collect(),
+                unneeded_ocr: unneeded_ocr.remove(framework).unwrap_or_default(),
+            })
+            .collect();
+
+        Self { scored, frameworks }
+    }
+
+    /// Whether the run produced nothing to report
+    pub fn is_empty(&self) -> bool {
+        self.frameworks.is_empty()
+    }
+}
+
+fn mean_quality(metrics: &[&QualityMetrics]) -> QualityMetrics {
+    let mean = |value: fn(&QualityMetrics) -> f64| metrics.iter().map(|m| value(m)).sum::<f64>() / metrics.len() as f64;
+
+    QualityMetrics {
+        f1_score_text: mean(|m| m.f1_score_text),
+        f1_score_numeric: mean(|m| m.f1_score_numeric),
+        f1_score_layout: mean(|m| m.f1_score_layout),
+        quality_score: mean(|m| m.quality_score),
+    }
+}
+
+/// Write the accuracy report as JSON
+pub fn write_accuracy_report(report: &AccuracyReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize accuracy report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_text() {
+        let truth = "Invoice 2024-001\nTotal due: 1,250.00 EUR";
+
+        let exact = score_text(truth, truth);
+        assert_eq!(exact.quality_score, 1.0);
+
+        let ocr = score_text("Inv0ice 2024-001\nTotal due: 1,25O.00 EUR", truth);
+        assert!((ocr.f1_score_text - 2.0 / 3.0).abs() < 1e-9);
+        assert!((ocr.f1_score_numeric - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(ocr.f1_score_layout, 0.0);
+        assert!(ocr.quality_score < exact.quality_score);
+
+        let empty = score_text("", truth);
+        assert_eq!(empty.quality_score, 0.0);
+    }
+
+    #[test]
+    fn test_text_provenance_from_wrapper_output() {
+        let reported = serde_json::json!({ "content": "text", "_text_provenance": "mixed" });
+        assert_eq!(
+            TextProvenance::from_wrapper_output(&reported),
+            Some(TextProvenance::Mixed)
+        );
+
+        let image = serde_json::json!({ "content": "text", "metadata": { "format_type": "ocr" } });
+        assert_eq!(TextProvenance::from_wrapper_output(&image), Some(TextProvenance::Ocr));
+
+        let scanned = serde_json::json!({ "content": "text", "metadata": { "ocr_pages": [{ "page": 1 }] } });
+        assert_eq!(TextProvenance::from_wrapper_output(&scanned), Some(TextProvenance::Ocr));
+
+        let native = serde_json::json!({ "content": "text", "metadata": { "format_type": "pdf" } });
+        assert_eq!(TextProvenance::from_wrapper_output(&native), None);
+    }
+
+    #[test]
+    fn test_accuracy_report_splits_by_provenance() {
+        let result = |framework: &str, file: &str, extracted, quality_score| BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from(file),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: std::time::Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: Default::default(),
+            quality: Some(QualityMetrics {
+                f1_score_text: quality_score,
+                f1_score_numeric: quality_score,
+                f1_score_layout: quality_score,
+                quality_score,
+            }),
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: Some(ResultProvenance {
+                born_digital: Some(true),
+                extracted,
+            }),
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        };
+        let results = vec![
+            result("fast", "a.pdf", Some(TextProvenance::TextLayer), 1.0),
+            result("fast", "b.pdf", Some(TextProvenance::TextLayer), 0.8),
+            result("slow", "a.pdf", Some(TextProvenance::Ocr), 0.7),
+            result("slow", "b.pdf", None, 0.9),
+        ];
+
+        let report = AccuracyReport::from_results(&results);
+
+        assert_eq!(report.scored, 4);
+        let fast = &report.frameworks[0];
+        assert_eq!(fast.framework, "fast");
+        assert_eq!(fast.by_provenance["text_layer"].files, 2);
+        assert!((fast.by_provenance["text_layer"].mean.quality_score - 0.9).abs() < 1e-9);
+        assert!(fast.unneeded_ocr.is_empty());
+
+        let slow = &report.frameworks[1];
+        assert_eq!(slow.by_provenance["ocr"].files, 1);
+        assert_eq!(slow.by_provenance["unknown"].files, 1);
+        assert_eq!(slow.unneeded_ocr, vec![PathBuf::from("a.pdf")]);
+    }
+}
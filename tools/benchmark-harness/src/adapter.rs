@@ -3,6 +3,11 @@
 //! Adapters provide a unified interface for extracting content across different
 //! extraction frameworks (both Kreuzberg language bindings and open source alternatives).
 //! This allows benchmarking any extraction framework against the same test fixtures.
+//!
+//! Subprocess adapters exchange requests and results with the harness over the
+//! line-delimited JSON [`protocol`], so frameworks can be added without Rust code.
+
+pub mod protocol;
 
 use crate::{Result, types::BenchmarkResult};
 use async_trait::async_trait;
@@ -0,0 +1,419 @@
+//! Line-delimited JSON protocol between the harness and subprocess adapters
+//!
+//! Adapters that speak this protocol can be plugged into the harness without any Rust code:
+//! the harness starts the adapter program, writes one request frame to its stdin and reads
+//! frames back from its stdout until the program exits. Every frame is a single JSON object
+//! on its own line, tagged with its `type` and the `protocol` version the sender speaks.
+//!
+//! ## Frames
+//!
+//! The harness sends exactly one `request` frame and then closes stdin:
+//!
+//! ```json
+//! {"type": "request", "protocol": 1, "id": 7, "files": ["/abs/a.pdf", "/abs/b.docx"], "batch": true, "timeout_ms": 1800000}
+//! ```
+//!
+//! `files` are absolute paths. `batch` asks the adapter to use its framework's batch API when
+//! it has one; either way the adapter must answer for every file. `timeout_ms` is the time the
+//! harness waits before killing the adapter.
+//!
+//! The adapter answers with one `result` or `error` frame per file, in any order, optionally
+//! interleaved with `progress` frames:
+//!
+//! ```json
+//! {"type": "progress", "protocol": 1, "id": 7, "completed": 1, "total": 2, "message": "a.pdf"}
+//! {"type": "result", "protocol": 1, "id": 7, "file": "/abs/a.pdf", "output": {"content": "...", "_extraction_time_ms": 41.5}}
+//! {"type": "error", "protocol": 1, "id": 7, "file": "/abs/b.docx", "message": "unsupported format", "code": "unsupported_format"}
+//! ```
+//!
+//! `output` is the object wrapper scripts have always printed: `content` is required, and
+//! `metadata` plus the underscore-prefixed timing and resource fields (`_extraction_time_ms`,
+//! `_import_time_ms`, `_process_start_epoch_ms`, `_serialization_time_ms`, `_open_fds_before`,
+//! `_open_fds_after`) are optional, as is `_text_provenance` (`"text_layer"`, `"ocr"` or
+//! `"mixed"`, see [`crate::accuracy`]). An `error` frame without `file` fails the whole request.
+//! Its optional `code` classifies the failure for the failure taxonomy (`timeout`, `crash`,
+//! `unsupported_format`, `empty_output`, `encoding_error` or a Kreuzberg FFI error code name,
+//! see [`crate::failures`]).
+//!
+//! The adapter exits with status 0 once it has answered; a non-zero exit status fails the
+//! request regardless of the frames written. Lines that do not start with `{` are ignored, so
+//! libraries that print to stdout do not break the stream, but a line that starts with `{`
+//! and is not a valid frame is a protocol error.
+//!
+//! ## Versioning
+//!
+//! [`PROTOCOL_VERSION`] is incremented for changes old adapters cannot follow. Adapters must
+//! reject requests with a version they do not know, and the harness rejects frames with a
+//! version other than its own. Unknown fields are ignored in both directions, so fields can
+//! be added without a version change.
+
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Protocol version spoken by this harness
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// One line of the protocol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Frame {
+    /// Protocol version of the sender
+    pub protocol: u32,
+    /// Frame contents, tagged by `type`
+    #[serde(flatten)]
+    pub body: FrameBody,
+}
+
+/// Frame contents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrameBody {
+    /// Harness to adapter: files to extract
+    Request(RequestFrame),
+    /// Adapter to harness: informational progress
+    Progress(ProgressFrame),
+    /// Adapter to harness: extraction output for one file
+    Result(ResultFrame),
+    /// Adapter to harness: extraction failure for one file or the whole request
+    Error(ErrorFrame),
+}
+
+/// Files the adapter should extract
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestFrame {
+    /// Request identifier, echoed in every answering frame
+    pub id: u64,
+    /// Absolute paths of the documents to extract
+    pub files: Vec<PathBuf>,
+    /// Whether the adapter should use its framework's batch API
+    #[serde(default)]
+    pub batch: bool,
+    /// Time the harness waits before killing the adapter
+    pub timeout_ms: u64,
+}
+
+/// Progress report, logged by the harness
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressFrame {
+    /// Identifier of the request being answered
+    pub id: u64,
+    /// Number of files finished so far
+    pub completed: usize,
+    /// Number of files in the request
+    pub total: usize,
+    /// Optional human-readable status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Extraction output for one file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultFrame {
+    /// Identifier of the request being answered
+    pub id: u64,
+    /// Requested path this output belongs to
+    pub file: PathBuf,
+    /// Wrapper output object with `content` and optional metadata and timing fields
+    pub output: serde_json::Value,
+}
+
+/// Extraction failure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorFrame {
+    /// Identifier of the request being answered
+    pub id: u64,
+    /// Requested path that failed, or `None` when the whole request failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+    /// Error description
+    pub message: String,
+    /// Structured error code classifying the failure, see [`crate::failures`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+}
+
+impl Frame {
+    /// Wrap a frame body with the harness protocol version
+    pub fn new(body: FrameBody) -> Self {
+        Self {
+            protocol: PROTOCOL_VERSION,
+            body,
+        }
+    }
+
+    /// Serialize the frame as one newline-terminated line
+    pub fn to_line(&self) -> Result<String> {
+        let mut line = serde_json::to_string(self)?;
+        line.push('\n');
+        Ok(line)
+    }
+
+    /// Parse one line of adapter output
+    ///
+    /// Returns `Ok(None)` for lines that are not JSON objects (blank lines, stray library output).
+    pub fn parse_line(line: &str) -> Result<Option<Self>> {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            return Ok(None);
+        }
+
+        let frame: Self = serde_json::from_str(line)
+            .map_err(|e| Error::Protocol(format!("Invalid frame {}: {}", truncate(line, 200), e)))?;
+        if frame.protocol != PROTOCOL_VERSION {
+            return Err(Error::Protocol(format!(
+                "Adapter speaks protocol version {}, harness speaks version {}",
+                frame.protocol, PROTOCOL_VERSION
+            )));
+        }
+        Ok(Some(frame))
+    }
+}
+
+impl RequestFrame {
+    /// Build a request for `files`, which must already be absolute
+    pub fn new(id: u64, files: Vec<PathBuf>, batch: bool, timeout: Duration) -> Self {
+        Self {
+            id,
+            files,
+            batch,
+            timeout_ms: u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// Serialize the request as the line written to the adapter's stdin
+    pub fn to_line(&self) -> Result<String> {
+        Frame::new(FrameBody::Request(self.clone())).to_line()
+    }
+}
+
+/// Frames an adapter wrote in answer to one request
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Response {
+    /// Progress frames, in arrival order
+    pub progress: Vec<ProgressFrame>,
+    /// Result frames, in arrival order
+    pub results: Vec<ResultFrame>,
+    /// Error frames, in arrival order
+    pub errors: Vec<ErrorFrame>,
+}
+
+impl Response {
+    /// Parse and validate everything an adapter wrote to stdout for `request`
+    ///
+    /// Fails when a frame is malformed, speaks another protocol version, answers another
+    /// request, names a file that was not requested, or when a requested file got neither a
+    /// result nor an error.
+    pub fn parse(request: &RequestFrame, stdout: &str) -> Result<Self> {
+        let mut response = Self::default();
+
+        for line in stdout.lines() {
+            let Some(frame) = Frame::parse_line(line)? else {
+                continue;
+            };
+
+            match frame.body {
+                FrameBody::Request(_) => {
+                    return Err(Error::Protocol("Adapter sent a request frame".to_string()));
+                }
+                FrameBody::Progress(progress) => {
+                    check_id(request, progress.id)?;
+                    tracing::debug!(
+                        "Adapter progress {}/{}: {}",
+                        progress.completed,
+                        progress.total,
+                        progress.message.as_deref().unwrap_or("")
+                    );
+                    response.progress.push(progress);
+                }
+                FrameBody::Result(result) => {
+                    check_id(request, result.id)?;
+                    check_requested(request, &result.file)?;
+                    if result.output.get("content").and_then(|v| v.as_str()).is_none() {
+                        return Err(Error::Protocol(format!(
+                            "Result for {} has no string content field",
+                            result.file.display()
+                        )));
+                    }
+                    response.results.push(result);
+                }
+                FrameBody::Error(error) => {
+                    check_id(request, error.id)?;
+                    if let Some(file) = &error.file {
+                        check_requested(request, file)?;
+                    }
+                    response.errors.push(error);
+                }
+            }
+        }
+
+        if response.errors.iter().all(|error| error.file.is_some()) {
+            let answered = |file: &PathBuf| {
+                response.results.iter().any(|result| &result.file == file)
+                    || response.errors.iter().any(|error| error.file.as_ref() == Some(file))
+            };
+            if let Some(missing) = request.files.iter().find(|file| !answered(file)) {
+                return Err(Error::Protocol(format!(
+                    "Adapter sent no answer for {}",
+                    missing.display()
+                )));
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Return the first error the adapter reported, if any
+    pub fn first_error(&self) -> Option<Error> {
+        self.errors.first().map(|error| Error::AdapterFailed {
+            code: error.code.clone(),
+            message: match &error.file {
+                Some(file) => format!("Adapter failed on {}: {}", file.display(), error.message),
+                None => format!("Adapter failed: {}", error.message),
+            },
+        })
+    }
+
+    /// Take the output for `file`, or the error the adapter reported for it
+    pub fn into_output(mut self, file: &Path) -> Result<serde_json::Value> {
+        if let Some(error) = self.first_error() {
+            return Err(error);
+        }
+
+        let index = self
+            .results
+            .iter()
+            .position(|result| result.file == file)
+            .ok_or_else(|| Error::Protocol(format!("Adapter sent no result for {}", file.display())))?;
+        Ok(self.results.swap_remove(index).output)
+    }
+}
+
+fn check_id(request: &RequestFrame, id: u64) -> Result<()> {
+    if id == request.id {
+        Ok(())
+    } else {
+        Err(Error::Protocol(format!(
+            "Adapter answered request {} while handling request {}",
+            id, request.id
+        )))
+    }
+}
+
+fn check_requested(request: &RequestFrame, file: &Path) -> Result<()> {
+    if request.files.iter().any(|requested| requested == file) {
+        Ok(())
+    } else {
+        Err(Error::Protocol(format!(
+            "Adapter answered for {}, which was not requested",
+            file.display()
+        )))
+    }
+}
+
+fn truncate(line: &str, max_chars: usize) -> &str {
+    line.char_indices().nth(max_chars).map_or(line, |(end, _)| &line[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> RequestFrame {
+        RequestFrame::new(
+            3,
+            vec![PathBuf::from("/docs/a.pdf"), PathBuf::from("/docs/b.docx")],
+            true,
+            Duration::from_secs(60),
+        )
+    }
+
+    #[test]
+    fn test_request_line_format() {
+        let line = request().to_line().unwrap();
+        assert!(line.ends_with('\n'));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["type"], "request");
+        assert_eq!(value["protocol"], PROTOCOL_VERSION);
+        assert_eq!(value["id"], 3);
+        assert_eq!(value["files"][1], "/docs/b.docx");
+        assert_eq!(value["batch"], true);
+        assert_eq!(value["timeout_ms"], 60_000);
+
+        let parsed = Frame::parse_line(&line).unwrap().unwrap();
+        assert_eq!(parsed.body, FrameBody::Request(request()));
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let stdout = concat!(
+            "loading model...\n",
+            r#"{"type": "progress", "protocol": 1, "id": 3, "completed": 1, "total": 2}"#,
+            "\n",
+            r#"{"type": "result", "protocol": 1, "id": 3, "file": "/docs/a.pdf", "output": {"content": "text", "_extraction_time_ms": 12.5}}"#,
+            "\n\n",
+            r#"{"type": "error", "protocol": 1, "id": 3, "file": "/docs/b.docx", "message": "unsupported", "code": "unsupported_format"}"#,
+            "\n",
+        );
+
+        let response = Response::parse(&request(), stdout).unwrap();
+        assert_eq!(response.progress.len(), 1);
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results[0].output["_extraction_time_ms"], 12.5);
+        assert_eq!(response.errors[0].message, "unsupported");
+        assert_eq!(response.errors[0].code.as_deref(), Some("unsupported_format"));
+
+        let error = response.into_output(Path::new("/docs/a.pdf")).unwrap_err();
+        assert!(error.to_string().contains("unsupported"));
+        assert!(matches!(error, Error::AdapterFailed { code: Some(code), .. } if code == "unsupported_format"));
+    }
+
+    #[test]
+    fn test_parse_response_rejects_violations() {
+        let result = |id: u64, file: &str, protocol: u32| {
+            format!(
+                r#"{{"type": "result", "protocol": {}, "id": {}, "file": "{}", "output": {{"content": ""}}}}"#,
+                protocol, id, file
+            )
+        };
+        let both = |first: String| format!("{}\n{}\n", first, result(3, "/docs/b.docx", 1));
+
+        let cases = [
+            both(result(3, "/docs/a.pdf", 2)),
+            both(result(4, "/docs/a.pdf", 1)),
+            both(result(3, "/docs/c.pdf", 1)),
+            result(3, "/docs/a.pdf", 1),
+            both(r#"{"type": "result", "protocol": 1, "id": 3, "file": "/docs/a.pdf", "output": {}}"#.to_string()),
+            both("{not json".to_string()),
+        ];
+        for stdout in cases {
+            assert!(
+                matches!(Response::parse(&request(), &stdout), Err(Error::Protocol(_))),
+                "accepted {}",
+                stdout
+            );
+        }
+
+        let valid = both(result(3, "/docs/a.pdf", 1));
+        let output = Response::parse(&request(), &valid)
+            .unwrap()
+            .into_output(Path::new("/docs/b.docx"))
+            .unwrap();
+        assert_eq!(output["content"], "");
+    }
+
+    #[test]
+    fn test_request_level_error() {
+        let stdout = r#"{"type": "error", "protocol": 1, "id": 3, "message": "framework not installed"}"#;
+
+        let response = Response::parse(&request(), stdout).unwrap();
+        assert!(
+            response
+                .first_error()
+                .unwrap()
+                .to_string()
+                .contains("framework not installed")
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{adapters::subprocess::SubprocessAdapter, error::Result};
+use crate::{
+    adapters::subprocess::{AdapterProtocol, SubprocessAdapter},
+    error::Result,
+};
 use std::{env, path::PathBuf};
 
 /// Creates a subprocess adapter for Docling (open source extraction framework, single-file mode)
@@ -8,7 +11,9 @@ pub fn create_docling_adapter() -> Result<SubprocessAdapter> {
     args.push(script_path.to_string_lossy().to_string());
     args.push("sync".to_string());
 
-    Ok(SubprocessAdapter::new("docling", command, args, vec![]))
+    let mut adapter = SubprocessAdapter::new("docling", command, args, vec![]);
+    adapter.set_protocol(AdapterProtocol::JsonLines);
+    Ok(adapter)
 }
 
 /// Creates a subprocess adapter for Docling (open source extraction framework, batch mode)
@@ -18,12 +23,9 @@ pub fn create_docling_batch_adapter() -> Result<SubprocessAdapter> {
     args.push(script_path.to_string_lossy().to_string());
     args.push("batch".to_string());
 
-    Ok(SubprocessAdapter::with_batch_support(
-        "docling-batch",
-        command,
-        args,
-        vec![],
-    ))
+    let mut adapter = SubprocessAdapter::with_batch_support("docling-batch", command, args, vec![]);
+    adapter.set_protocol(AdapterProtocol::JsonLines);
+    Ok(adapter)
 }
 
 /// Creates a subprocess adapter for Unstructured (open source extraction framework)
@@ -32,7 +34,9 @@ pub fn create_unstructured_adapter() -> Result<SubprocessAdapter> {
     let (command, mut args) = find_python_with_framework("unstructured")?;
     args.push(script_path.to_string_lossy().to_string());
 
-    Ok(SubprocessAdapter::new("unstructured", command, args, vec![]))
+    let mut adapter = SubprocessAdapter::new("unstructured", command, args, vec![]);
+    adapter.set_protocol(AdapterProtocol::JsonLines);
+    Ok(adapter)
 }
 
 /// Creates a subprocess adapter for MarkItDown (open source extraction framework)
@@ -41,7 +45,9 @@ pub fn create_markitdown_adapter() -> Result<SubprocessAdapter> {
     let (command, mut args) = find_python_with_framework("markitdown")?;
     args.push(script_path.to_string_lossy().to_string());
 
-    Ok(SubprocessAdapter::new("markitdown", command, args, vec![]))
+    let mut adapter = SubprocessAdapter::new("markitdown", command, args, vec![]);
+    adapter.set_protocol(AdapterProtocol::JsonLines);
+    Ok(adapter)
 }
 
 /// Creates a subprocess adapter for Pandoc (universal document converter)
@@ -59,6 +65,25 @@ pub fn create_pandoc_adapter() -> Result<SubprocessAdapter> {
     Ok(SubprocessAdapter::new("pandoc", command, args, vec![]))
 }
 
+/// Creates a subprocess adapter for Pandoc writing GitHub-flavoured markdown
+///
+/// Used where structure matters (headings, fenced code), since the default adapter
+/// converts to plain text.
+pub fn create_pandoc_markdown_adapter() -> Result<SubprocessAdapter> {
+    which::which("pandoc").map_err(|_| {
+        crate::Error::Config(
+            "pandoc not found. Install with: brew install pandoc (macOS) or apt install pandoc (Linux)".to_string(),
+        )
+    })?;
+
+    let script_path = get_script_path("pandoc_extract.sh")?;
+    let command = PathBuf::from("bash");
+    let args = vec![script_path.to_string_lossy().to_string()];
+    let env = vec![("PANDOC_TO".to_string(), "gfm".to_string())];
+
+    Ok(SubprocessAdapter::new("pandoc-markdown", command, args, env))
+}
+
 /// Helper function to get the path to a wrapper script
 fn get_script_path(script_name: &str) -> Result<PathBuf> {
     if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
@@ -210,6 +235,7 @@ mod tests {
         let _ = create_unstructured_adapter();
         let _ = create_markitdown_adapter();
         let _ = create_pandoc_adapter();
+        let _ = create_pandoc_markdown_adapter();
         let _ = create_tika_sync_adapter();
         let _ = create_tika_batch_adapter();
     }
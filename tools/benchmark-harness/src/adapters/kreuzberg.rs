@@ -6,7 +6,7 @@
 //! - Ruby: sync, batch
 
 use crate::Result;
-use crate::adapters::subprocess::SubprocessAdapter;
+use crate::adapters::subprocess::{AdapterProtocol, SubprocessAdapter};
 use std::env;
 use std::path::PathBuf;
 
@@ -192,7 +192,9 @@ pub fn create_python_sync_adapter() -> Result<SubprocessAdapter> {
     args.push(script_path.to_string_lossy().to_string());
     args.push("sync".to_string());
 
-    Ok(SubprocessAdapter::new("kreuzberg-python-sync", command, args, vec![]))
+    let mut adapter = SubprocessAdapter::new("kreuzberg-python-sync", command, args, vec![]);
+    adapter.set_protocol(AdapterProtocol::JsonLines);
+    Ok(adapter)
 }
 
 /// Create Python async adapter (extract_file_async)
@@ -203,7 +205,9 @@ pub fn create_python_async_adapter() -> Result<SubprocessAdapter> {
     args.push(script_path.to_string_lossy().to_string());
     args.push("async".to_string());
 
-    Ok(SubprocessAdapter::new("kreuzberg-python-async", command, args, vec![]))
+    let mut adapter = SubprocessAdapter::new("kreuzberg-python-async", command, args, vec![]);
+    adapter.set_protocol(AdapterProtocol::JsonLines);
+    Ok(adapter)
 }
 
 /// Create Python batch adapter (batch_extract_file)
@@ -214,12 +218,9 @@ pub fn create_python_batch_adapter() -> Result<SubprocessAdapter> {
     args.push(script_path.to_string_lossy().to_string());
     args.push("batch".to_string());
 
-    Ok(SubprocessAdapter::with_batch_support(
-        "kreuzberg-python-batch",
-        command,
-        args,
-        vec![],
-    ))
+    let mut adapter = SubprocessAdapter::with_batch_support("kreuzberg-python-batch", command, args, vec![]);
+    adapter.set_protocol(AdapterProtocol::JsonLines);
+    Ok(adapter)
 }
 
 /// Create Node async adapter (extractFile)
@@ -10,7 +10,7 @@ pub mod subprocess;
 
 pub use external::{
     create_docling_adapter, create_docling_batch_adapter, create_markitdown_adapter, create_pandoc_adapter,
-    create_tika_batch_adapter, create_tika_sync_adapter, create_unstructured_adapter,
+    create_pandoc_markdown_adapter, create_tika_batch_adapter, create_tika_sync_adapter, create_unstructured_adapter,
 };
 pub use kreuzberg::{
     create_csharp_sync_adapter, create_go_batch_adapter, create_go_sync_adapter, create_java_sync_adapter,
@@ -22,4 +22,4 @@ pub use native::NativeAdapter;
 pub use node::NodeAdapter;
 pub use python::PythonAdapter;
 pub use ruby::RubyAdapter;
-pub use subprocess::SubprocessAdapter;
+pub use subprocess::{AdapterProtocol, SubprocessAdapter};
@@ -3,14 +3,20 @@
 //! This adapter uses the Kreuzberg Rust core library directly for maximum performance.
 //! It serves as the baseline for comparing language bindings.
 
+use crate::accuracy::TextProvenance;
 use crate::adapter::FrameworkAdapter;
+use crate::assertions::ExtractedOutput;
+use crate::failures::FailureKind;
+use crate::leaks::{ResourceLeaks, open_fd_count};
 use crate::monitoring::ResourceMonitor;
+use crate::stages::{StageRecorder, StageTimings};
 use crate::types::{BenchmarkResult, FrameworkCapabilities, PerformanceMetrics};
 use crate::{Error, Result};
 use async_trait::async_trait;
 use kreuzberg::{ExtractionConfig, batch_extract_file, extract_file};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
+use tracing::Instrument;
 
 /// Native Rust adapter using kreuzberg crate directly
 pub struct NativeAdapter {
@@ -63,6 +69,27 @@ impl NativeAdapter {
     pub fn with_config(config: ExtractionConfig) -> Self {
         Self { config }
     }
+
+    /// Extract a file and report the time spent in each instrumented extraction stage
+    ///
+    /// Stage timings come from the `stage.*` spans Kreuzberg emits and are `None` when the
+    /// stage recorder could not be installed because another global tracing subscriber is
+    /// active.
+    pub async fn extract_with_stages(
+        &self,
+        file_path: &Path,
+        timeout: Duration,
+    ) -> Result<(BenchmarkResult, Option<StageTimings>)> {
+        let Some(recorder) = StageRecorder::install() else {
+            return Ok((self.extract(file_path, timeout).await?, None));
+        };
+
+        let (run_id, span) = recorder.begin_run();
+        let result = self.extract(file_path, timeout).instrument(span).await;
+        let timings = recorder.finish_run(run_id);
+
+        Ok((result?, Some(timings)))
+    }
 }
 
 impl Default for NativeAdapter {
@@ -112,22 +139,35 @@ impl FrameworkAdapter for NativeAdapter {
     async fn extract(&self, file_path: &Path, timeout: Duration) -> Result<BenchmarkResult> {
         let file_size = std::fs::metadata(file_path).map_err(Error::Io)?.len();
 
+        // Counted outside the monitor so its /proc reads don't show up as leaks
+        let fds_before = open_fd_count();
+
         let monitor = ResourceMonitor::new();
         let sampling_interval_ms = Self::calculate_adaptive_sampling_interval(file_size);
         monitor.start(Duration::from_millis(sampling_interval_ms)).await;
 
         let start = Instant::now();
 
-        let extraction_result = tokio::time::timeout(timeout, extract_file(file_path, None, &self.config))
-            .await
-            .map_err(|_| Error::Timeout(format!("Extraction exceeded {:?}", timeout)))?
-            .map_err(|e| Error::Benchmark(format!("Extraction failed: {}", e)));
+        let extraction_result = match tokio::time::timeout(timeout, extract_file(file_path, None, &self.config)).await {
+            Ok(Ok(result)) => Ok(result),
+            Ok(Err(e)) => Err((
+                Error::Benchmark(format!("Extraction failed: {}", e)),
+                FailureKind::from_kreuzberg(&e),
+            )),
+            Err(_) => Err((
+                Error::Timeout(format!("Extraction exceeded {:?}", timeout)),
+                FailureKind::Timeout,
+            )),
+        };
 
         let duration = start.elapsed();
 
         let samples = monitor.stop().await;
         let snapshots = monitor.get_snapshots().await;
         let resource_stats = ResourceMonitor::calculate_stats(&samples, &snapshots);
+        let leaked_fds = fds_before
+            .zip(open_fd_count())
+            .map(|(before, after)| after.saturating_sub(before));
 
         let throughput = if duration.as_secs_f64() > 0.0 {
             file_size as f64 / duration.as_secs_f64()
@@ -135,37 +175,47 @@ impl FrameworkAdapter for NativeAdapter {
             0.0
         };
 
-        if let Err(e) = extraction_result {
-            return Ok(BenchmarkResult {
-                framework: self.name().to_string(),
-                file_path: file_path.to_path_buf(),
-                file_size,
-                success: false,
-                error_message: Some(e.to_string()),
-                duration,
-                extraction_duration: None,
-                subprocess_overhead: None,
-                metrics: PerformanceMetrics {
-                    peak_memory_bytes: resource_stats.peak_memory_bytes,
-                    avg_cpu_percent: resource_stats.avg_cpu_percent,
-                    throughput_bytes_per_sec: 0.0,
-                    p50_memory_bytes: resource_stats.p50_memory_bytes,
-                    p95_memory_bytes: resource_stats.p95_memory_bytes,
-                    p99_memory_bytes: resource_stats.p99_memory_bytes,
-                },
-                quality: None,
-                iterations: vec![],
-                statistics: None,
-                cold_start_duration: None,
-                file_extension: file_path
-                    .extension()
-                    .and_then(|e| e.to_str())
-                    .unwrap_or("unknown")
-                    .to_lowercase(),
-                framework_capabilities: FrameworkCapabilities::default(),
-                pdf_metadata: None,
-            });
-        }
+        let extraction_result = match extraction_result {
+            Ok(extraction_result) => extraction_result,
+            Err((e, failure)) => {
+                return Ok(BenchmarkResult {
+                    framework: self.name().to_string(),
+                    file_path: file_path.to_path_buf(),
+                    file_size,
+                    success: false,
+                    error_message: Some(e.to_string()),
+                    failure: Some(failure),
+                    duration,
+                    extraction_duration: None,
+                    subprocess_overhead: None,
+                    overhead_breakdown: None,
+                    metrics: PerformanceMetrics {
+                        peak_memory_bytes: resource_stats.peak_memory_bytes,
+                        avg_cpu_percent: resource_stats.avg_cpu_percent,
+                        throughput_bytes_per_sec: 0.0,
+                        p50_memory_bytes: resource_stats.p50_memory_bytes,
+                        p95_memory_bytes: resource_stats.p95_memory_bytes,
+                        p99_memory_bytes: resource_stats.p99_memory_bytes,
+                    },
+                    quality: None,
+                    iterations: vec![],
+                    statistics: None,
+                    cold_start_duration: None,
+                    warmup: None,
+                    file_extension: file_path
+                        .extension()
+                        .and_then(|e| e.to_str())
+                        .unwrap_or("unknown")
+                        .to_lowercase(),
+                    framework_capabilities: FrameworkCapabilities::default(),
+                    pdf_metadata: None,
+                    provenance: None,
+                    assertions: None,
+                    resource_leaks: None,
+                    extracted_output: None,
+                });
+            }
+        };
 
         let metrics = PerformanceMetrics {
             peak_memory_bytes: resource_stats.peak_memory_bytes,
@@ -182,14 +232,17 @@ impl FrameworkAdapter for NativeAdapter {
             file_size,
             success: true,
             error_message: None,
+            failure: None,
             duration,
             extraction_duration: None,
             subprocess_overhead: None,
+            overhead_breakdown: None,
             metrics,
             quality: None,
             iterations: vec![],
             statistics: None,
             cold_start_duration: None,
+            warmup: None,
             file_extension: file_path
                 .extension()
                 .and_then(|e| e.to_str())
@@ -197,6 +250,18 @@ impl FrameworkAdapter for NativeAdapter {
                 .to_lowercase(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: Some(ResourceLeaks {
+                leaked_fds,
+                leaked_temp_files: Vec::new(),
+            }),
+            extracted_output: Some(ExtractedOutput {
+                table_count: Some(extraction_result.tables.len()),
+                page_count: extraction_result.metadata.pages.as_ref().map(|pages| pages.total_count),
+                text_provenance: TextProvenance::from_kreuzberg_metadata(&extraction_result.metadata),
+                content: extraction_result.content,
+            }),
         })
     }
 
@@ -216,10 +281,17 @@ impl FrameworkAdapter for NativeAdapter {
 
         let paths: Vec<PathBuf> = file_paths.iter().map(|p| p.to_path_buf()).collect();
 
-        let batch_result = tokio::time::timeout(timeout, batch_extract_file(paths.clone(), &self.config))
-            .await
-            .map_err(|_| Error::Timeout(format!("Batch extraction exceeded {:?}", timeout)))?
-            .map_err(|e| Error::Benchmark(format!("Batch extraction failed: {}", e)));
+        let batch_result = match tokio::time::timeout(timeout, batch_extract_file(paths.clone(), &self.config)).await {
+            Ok(Ok(results)) => Ok(results),
+            Ok(Err(e)) => Err((
+                Error::Benchmark(format!("Batch extraction failed: {}", e)),
+                FailureKind::from_kreuzberg(&e),
+            )),
+            Err(_) => Err((
+                Error::Timeout(format!("Batch extraction exceeded {:?}", timeout)),
+                FailureKind::Timeout,
+            )),
+        };
 
         let total_duration = start.elapsed();
 
@@ -227,24 +299,31 @@ impl FrameworkAdapter for NativeAdapter {
         let snapshots = monitor.get_snapshots().await;
         let resource_stats = ResourceMonitor::calculate_stats(&samples, &snapshots);
 
-        if let Err(e) = batch_result {
+        if let Err((e, failure)) = batch_result {
             return Ok(vec![BenchmarkResult {
                 framework: self.name().to_string(),
                 file_path: PathBuf::from(format!("batch-{}-files", paths.len())),
                 file_size: total_file_size,
                 success: false,
                 error_message: Some(e.to_string()),
+                failure: Some(failure),
                 duration: total_duration,
                 extraction_duration: None,
                 subprocess_overhead: None,
+                overhead_breakdown: None,
                 metrics: PerformanceMetrics::default(),
                 quality: None,
                 iterations: vec![],
                 statistics: None,
                 cold_start_duration: None,
+                warmup: None,
                 file_extension: "batch".to_string(),
                 framework_capabilities: FrameworkCapabilities::default(),
                 pdf_metadata: None,
+                provenance: None,
+                assertions: None,
+                resource_leaks: None,
+                extracted_output: None,
             }]);
         }
 
@@ -269,17 +348,24 @@ impl FrameworkAdapter for NativeAdapter {
             file_size: total_file_size,
             success: true,
             error_message: None,
+            failure: None,
             duration: total_duration,
             extraction_duration: None,
             subprocess_overhead: None,
+            overhead_breakdown: None,
             metrics,
             quality: None,
             iterations: vec![],
             statistics: None,
             cold_start_duration: None,
+            warmup: None,
             file_extension: "batch".to_string(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
         }])
     }
 
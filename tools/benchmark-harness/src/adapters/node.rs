@@ -24,20 +24,30 @@ impl NodeAdapter {
         }
 
         let script = r#"
+const processStartEpochMs = Date.now();
+const importStart = performance.now();
 const { extractFile } = require('kreuzberg');
+const importTimeMs = performance.now() - importStart;
 
 const filePath = process.argv[2];
 const start = performance.now();
 
 extractFile(filePath)
     .then(result => {
-        const duration = (performance.now() - start) / 1000;
+        const extractionTimeMs = performance.now() - start;
         const output = {
             content: result.content,
             metadata: result.metadata,
-            duration: duration
+            _process_start_epoch_ms: processStartEpochMs,
+            _import_time_ms: importTimeMs,
+            _extraction_time_ms: extractionTimeMs
         };
-        console.log(JSON.stringify(output));
+
+        const serializeStart = performance.now();
+        const encoded = JSON.stringify(output);
+        const serializationTimeMs = performance.now() - serializeStart;
+        // Splice the encoding time into the object instead of encoding the payload twice
+        console.log(`${encoded.slice(0, -1)},"_serialization_time_ms":${serializationTimeMs}}`);
     })
     .catch(err => {
         console.error(err);
@@ -24,23 +24,35 @@ impl PythonAdapter {
         }
 
         let script = r#"
+import time
+process_start_epoch_ms = time.time() * 1000.0
+import_start = time.perf_counter()
+
 import sys
 import json
-import time
 from kreuzberg import extract_file
 
+import_time_ms = (time.perf_counter() - import_start) * 1000.0
+
 if __name__ == '__main__':
     file_path = sys.argv[1]
     start = time.perf_counter()
     result = extract_file(file_path)
-    duration = time.perf_counter() - start
+    extraction_time_ms = (time.perf_counter() - start) * 1000.0
 
     output = {
         'content': result.content,
         'metadata': result.metadata,
-        'duration': duration
+        '_process_start_epoch_ms': process_start_epoch_ms,
+        '_import_time_ms': import_time_ms,
+        '_extraction_time_ms': extraction_time_ms,
     }
-    print(json.dumps(output))
+
+    serialize_start = time.perf_counter()
+    encoded = json.dumps(output)
+    serialization_time_ms = (time.perf_counter() - serialize_start) * 1000.0
+    # Splice the encoding time into the object instead of encoding the payload twice
+    print(encoded[:-1] + ', "_serialization_time_ms": ' + repr(serialization_time_ms) + '}')
 "#;
 
         let inner = SubprocessAdapter::new(
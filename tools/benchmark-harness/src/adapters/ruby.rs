@@ -25,22 +25,31 @@ impl RubyAdapter {
         }
 
         let script = r#"
+process_start_epoch_ms = Process.clock_gettime(Process::CLOCK_REALTIME, :float_millisecond)
+import_start = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond)
 require 'kreuzberg'
 require 'json'
+import_time_ms = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond) - import_start
 
 file_path = ARGV[0]
-start = Process.clock_gettime(Process::CLOCK_MONOTONIC)
+start = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond)
 
 result = Kreuzberg.extract_file(file_path)
-duration = Process.clock_gettime(Process::CLOCK_MONOTONIC) - start
+extraction_time_ms = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond) - start
 
 output = {
   content: result.content,
   metadata: result.metadata,
-  duration: duration
+  _process_start_epoch_ms: process_start_epoch_ms,
+  _import_time_ms: import_time_ms,
+  _extraction_time_ms: extraction_time_ms
 }
 
-puts JSON.generate(output)
+serialize_start = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond)
+encoded = JSON.generate(output)
+serialization_time_ms = Process.clock_gettime(Process::CLOCK_MONOTONIC, :float_millisecond) - serialize_start
+# Splice the encoding time into the object instead of encoding the payload twice
+puts encoded[0..-2] + ',"_serialization_time_ms":' + serialization_time_ms.to_s + '}'
 "#;
 
         let inner = SubprocessAdapter::new(
@@ -3,16 +3,38 @@
 //! This adapter provides a base for running extraction via subprocess.
 //! It's used by Python, Node.js, and Ruby adapters to execute extraction
 //! in separate processes while monitoring resource usage.
+//!
+//! Wrappers either take the files as trailing arguments and print one JSON document
+//! ([`AdapterProtocol::Arguments`]), or speak the line-delimited JSON
+//! [adapter protocol](crate::adapter::protocol) on stdin/stdout ([`AdapterProtocol::JsonLines`]).
 
 use crate::adapter::FrameworkAdapter;
+use crate::adapter::protocol::{RequestFrame, Response};
+use crate::assertions::ExtractedOutput;
+use crate::failures::FailureKind;
+use crate::leaks::{PrivateTempDir, ResourceLeaks};
 use crate::monitoring::ResourceMonitor;
-use crate::types::{BenchmarkResult, FrameworkCapabilities, PerformanceMetrics};
+use crate::types::{BenchmarkResult, FrameworkCapabilities, OverheadBreakdown, PerformanceMetrics};
 use crate::{Error, Result};
 use async_trait::async_trait;
 use std::path::{Path, PathBuf};
-use std::process::Stdio;
-use std::time::{Duration, Instant};
-use tokio::process::Command;
+use std::process::{ExitStatus, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+use tokio::process::{Child, Command};
+
+/// How a subprocess adapter receives files and reports results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdapterProtocol {
+    /// Files are appended to the command line; the wrapper prints one JSON object
+    /// (or an array in batch mode) to stdout
+    #[default]
+    Arguments,
+    /// A request frame is written to stdin; the wrapper answers with
+    /// [protocol](crate::adapter::protocol) frames on stdout
+    JsonLines,
+}
 
 /// Base adapter for subprocess-based extraction
 ///
@@ -26,6 +48,8 @@ pub struct SubprocessAdapter {
     env: Vec<(String, String)>,
     supports_batch: bool,
     working_dir: Option<PathBuf>,
+    protocol: AdapterProtocol,
+    next_request_id: AtomicU64,
 }
 
 impl SubprocessAdapter {
@@ -49,6 +73,8 @@ impl SubprocessAdapter {
             env,
             supports_batch: false,
             working_dir: None,
+            protocol: AdapterProtocol::default(),
+            next_request_id: AtomicU64::new(1),
         }
     }
 
@@ -75,6 +101,8 @@ impl SubprocessAdapter {
             env,
             supports_batch: true,
             working_dir: None,
+            protocol: AdapterProtocol::default(),
+            next_request_id: AtomicU64::new(1),
         }
     }
 
@@ -86,29 +114,114 @@ impl SubprocessAdapter {
         self.working_dir = Some(dir);
     }
 
+    /// Set how files are passed to the subprocess and results read back
+    ///
+    /// # Arguments
+    /// * `protocol` - Command-line arguments (default) or the JSON lines adapter protocol
+    pub fn set_protocol(&mut self, protocol: AdapterProtocol) {
+        self.protocol = protocol;
+    }
+
+    /// Create an adapter for a third-party program speaking the adapter protocol
+    ///
+    /// The spec has the form `name=program [args...]`, with arguments separated by
+    /// whitespace (e.g. `my-parser=python3 /opt/adapters/my_parser.py`).
+    ///
+    /// # Returns
+    /// * `Err(Error::Config)` - The spec has no name or no program
+    pub fn from_spec(spec: &str) -> Result<Self> {
+        let (name, command_line) = spec
+            .split_once('=')
+            .ok_or_else(|| Error::Config(format!("Adapter spec '{}' is not of the form name=program", spec)))?;
+        let name = name.trim();
+        let mut words = command_line.split_whitespace();
+        let command = words
+            .next()
+            .filter(|_| !name.is_empty())
+            .ok_or_else(|| Error::Config(format!("Adapter spec '{}' is not of the form name=program", spec)))?;
+
+        let mut adapter = Self::new(name, command, words.map(str::to_string).collect(), vec![]);
+        adapter.set_protocol(AdapterProtocol::JsonLines);
+        Ok(adapter)
+    }
+
+    /// Build the request for `file_paths`, resolving relative paths against the current directory
+    fn request(&self, file_paths: &[&Path], batch: bool, timeout: Duration) -> Result<RequestFrame> {
+        let cwd = std::env::current_dir().map_err(Error::Io)?;
+        let files = file_paths
+            .iter()
+            .map(|path| {
+                if path.is_absolute() {
+                    path.to_path_buf()
+                } else {
+                    cwd.join(path)
+                }
+            })
+            .collect();
+
+        Ok(RequestFrame::new(
+            self.next_request_id.fetch_add(1, Ordering::Relaxed),
+            files,
+            batch,
+            timeout,
+        ))
+    }
+
+    /// Pass the request's files to `cmd` the way the protocol expects
+    fn attach_request(&self, cmd: &mut Command, request: &RequestFrame) {
+        match self.protocol {
+            AdapterProtocol::Arguments => {
+                cmd.args(request.files.iter().map(|file| file.as_os_str()));
+                cmd.stdin(Stdio::null());
+            }
+            AdapterProtocol::JsonLines => {
+                cmd.stdin(Stdio::piped());
+                // Killed on timeout instead of lingering with the request half-answered
+                cmd.kill_on_drop(true);
+            }
+        }
+    }
+
+    /// Write the request to the child's stdin (JSON lines protocol only) and wait for it to exit
+    async fn wait_for_output(&self, mut child: Child, request: &RequestFrame) -> Result<Output> {
+        if let Some(mut stdin) = child.stdin.take() {
+            let line = request.to_line()?;
+            match stdin.write_all(line.as_bytes()).await {
+                // An adapter that exits without reading is reported through its exit status
+                Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => {}
+                result => result.map_err(Error::Io)?,
+            }
+        }
+
+        child.wait_with_output().await.map_err(Error::Io)
+    }
+
     /// Execute the extraction subprocess
-    async fn execute_subprocess(&self, file_path: &Path, timeout: Duration) -> Result<(String, String, Duration)> {
+    ///
+    /// With a `temp_dir`, the subprocess's temp directory environment variables point at it
+    /// unless the adapter's own environment overrides them.
+    async fn execute_subprocess(
+        &self,
+        request: &RequestFrame,
+        timeout: Duration,
+        temp_dir: Option<&PrivateTempDir>,
+    ) -> Result<(String, String, Duration)> {
         let start = Instant::now();
 
-        // Convert relative paths to absolute to ensure proper working directory handling
-        let absolute_path = if file_path.is_absolute() {
-            file_path.to_path_buf()
-        } else {
-            std::env::current_dir().map_err(Error::Io)?.join(file_path)
-        };
-
         let mut cmd = Command::new(&self.command);
         if let Some(dir) = &self.working_dir {
             cmd.current_dir(dir);
         }
         cmd.args(&self.args);
-        cmd.arg(absolute_path.to_string_lossy().as_ref());
+        self.attach_request(&mut cmd, request);
 
+        for (key, value) in temp_dir.map(PrivateTempDir::env).unwrap_or_default() {
+            cmd.env(key, value);
+        }
         for (key, value) in &self.env {
             cmd.env(key, value);
         }
 
-        cmd.stdin(Stdio::null());
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
@@ -121,7 +234,7 @@ impl SubprocessAdapter {
             ))
         })?;
 
-        let output = match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        let output = match tokio::time::timeout(timeout, self.wait_for_output(child, request)).await {
             Ok(Ok(output)) => output,
             Ok(Err(e)) => {
                 return Err(Error::Benchmark(format!("Failed to wait for subprocess: {}", e)));
@@ -145,7 +258,11 @@ impl SubprocessAdapter {
             if !stdout.is_empty() && stdout.len() < 500 {
                 error_msg.push_str(&format!("\nstdout: {}", stdout));
             }
-            return Err(Error::Benchmark(error_msg));
+            return Err(if is_crash(output.status) {
+                Error::Crash(error_msg)
+            } else {
+                Error::Benchmark(error_msg)
+            });
         }
 
         Ok((stdout, stderr, duration))
@@ -154,8 +271,9 @@ impl SubprocessAdapter {
     /// Execute batch extraction subprocess with multiple files
     async fn execute_subprocess_batch(
         &self,
-        file_paths: &[&Path],
+        request: &RequestFrame,
         timeout: Duration,
+        temp_dir: Option<&PrivateTempDir>,
     ) -> Result<(String, String, Duration)> {
         let start = Instant::now();
 
@@ -164,23 +282,15 @@ impl SubprocessAdapter {
             cmd.current_dir(dir);
         }
         cmd.args(&self.args);
+        self.attach_request(&mut cmd, request);
 
-        // Convert all relative paths to absolute for proper working directory handling
-        let cwd = std::env::current_dir().map_err(Error::Io)?;
-        for path in file_paths {
-            let absolute_path = if path.is_absolute() {
-                path.to_path_buf()
-            } else {
-                cwd.join(path)
-            };
-            cmd.arg(absolute_path.to_string_lossy().as_ref());
+        for (key, value) in temp_dir.map(PrivateTempDir::env).unwrap_or_default() {
+            cmd.env(key, value);
         }
-
         for (key, value) in &self.env {
             cmd.env(key, value);
         }
 
-        cmd.stdin(Stdio::null());
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
@@ -188,7 +298,7 @@ impl SubprocessAdapter {
             .spawn()
             .map_err(|e| Error::Benchmark(format!("Failed to spawn batch subprocess: {}", e)))?;
 
-        let output = match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        let output = match tokio::time::timeout(timeout, self.wait_for_output(child, request)).await {
             Ok(Ok(output)) => output,
             Ok(Err(e)) => {
                 return Err(Error::Benchmark(format!("Failed to wait for batch subprocess: {}", e)));
@@ -204,22 +314,120 @@ impl SubprocessAdapter {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
 
         if !output.status.success() {
-            return Err(Error::Benchmark(format!(
+            let error_msg = format!(
                 "Batch subprocess failed with exit code {:?}\nstderr: {}",
                 output.status.code(),
                 stderr
-            )));
+            );
+            return Err(if is_crash(output.status) {
+                Error::Crash(error_msg)
+            } else {
+                Error::Benchmark(error_msg)
+            });
+        }
+
+        if self.protocol == AdapterProtocol::JsonLines
+            && let Some(error) = Response::parse(request, &stdout)?.first_error()
+        {
+            return Err(error);
         }
 
         Ok((stdout, stderr, duration))
     }
 
-    /// Parse extraction result from subprocess output
+    /// Run the wrapper for a single file and return the extracted `content`
     ///
-    /// Expected output format: JSON with `content` and optional `metadata` fields
-    fn parse_output(&self, stdout: &str) -> Result<serde_json::Value> {
-        serde_json::from_str(stdout).map_err(|e| Error::Benchmark(format!("Failed to parse subprocess output: {}", e)))
+    /// The returned duration is the wrapper-reported extraction time when available,
+    /// otherwise the wall-clock time of the subprocess.
+    pub async fn extract_content(&self, file_path: &Path, timeout: Duration) -> Result<(String, Duration)> {
+        let request = self.request(&[file_path], false, timeout)?;
+        let (stdout, _stderr, duration) = self.execute_subprocess(&request, timeout, None).await?;
+        let parsed = self.parse_output(&request, &stdout)?;
+
+        let content = parsed
+            .get("content")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| Error::Benchmark("Subprocess output has no content field".to_string()))?
+            .to_string();
+        let extraction_duration = parsed
+            .get("_extraction_time_ms")
+            .and_then(|v| v.as_f64())
+            .map(|ms| Duration::from_secs_f64(ms / 1000.0));
+
+        Ok((content, extraction_duration.unwrap_or(duration)))
+    }
+
+    /// Parse the extraction result for the request's single file from subprocess output
+    ///
+    /// Expected output format: JSON with `content` and optional `metadata` fields, either
+    /// printed directly or as the `output` of a protocol result frame
+    fn parse_output(&self, request: &RequestFrame, stdout: &str) -> Result<serde_json::Value> {
+        match self.protocol {
+            AdapterProtocol::Arguments => serde_json::from_str(stdout)
+                .map_err(|e| Error::Benchmark(format!("Failed to parse subprocess output: {}", e))),
+            AdapterProtocol::JsonLines => Response::parse(request, stdout)?.into_output(&request.files[0]),
+        }
+    }
+}
+
+/// Whether a failed subprocess crashed rather than exiting with an error
+///
+/// A process killed by a signal has no exit code; shells report it as 128 + the signal
+/// number. Rust programs exit with 101 when they panic.
+fn is_crash(status: ExitStatus) -> bool {
+    status
+        .code()
+        .is_none_or(|code| code == 101 || (129..=159).contains(&code))
+}
+
+/// Split the wall-clock `duration` of a subprocess run, plus the harness-side
+/// `parse_duration`, into phases
+///
+/// Wrappers report `_process_start_epoch_ms` (wall clock when the script began running),
+/// `_import_time_ms`, `_serialization_time_ms` and `_extraction_time_ms`. Returns `None`
+/// when the wrapper reports none of the overhead phases.
+fn parse_overhead_breakdown(
+    parsed: &serde_json::Value,
+    spawned_at: SystemTime,
+    parse_duration: Duration,
+    duration: Duration,
+) -> Option<OverheadBreakdown> {
+    let millis = |key: &str| {
+        parsed
+            .get(key)
+            .and_then(|v| v.as_f64())
+            .filter(|ms| ms.is_finite() && *ms >= 0.0)
+            .map(|ms| Duration::from_secs_f64(ms / 1000.0))
+    };
+
+    let spawn = parsed
+        .get("_process_start_epoch_ms")
+        .and_then(|v| v.as_f64())
+        .zip(spawned_at.duration_since(UNIX_EPOCH).ok())
+        .map(|(started_ms, spawned)| {
+            // Clocks can disagree by a few microseconds; never report a negative spawn time
+            Duration::from_secs_f64(((started_ms - spawned.as_secs_f64() * 1000.0) / 1000.0).max(0.0))
+        });
+    let import = millis("_import_time_ms");
+    let serialization = millis("_serialization_time_ms").map(|encode| encode + parse_duration);
+    let extraction = millis("_extraction_time_ms");
+
+    if spawn.is_none() && import.is_none() && serialization.is_none() {
+        return None;
     }
+
+    let attributed = [spawn, import, serialization, extraction]
+        .into_iter()
+        .flatten()
+        .fold(Duration::ZERO, |total, phase| total + phase);
+
+    Some(OverheadBreakdown {
+        spawn,
+        import,
+        serialization,
+        extraction,
+        other: Some((duration + parse_duration).saturating_sub(attributed)),
+    })
 }
 
 #[async_trait]
@@ -263,10 +471,15 @@ impl FrameworkAdapter for SubprocessAdapter {
     async fn extract(&self, file_path: &Path, timeout: Duration) -> Result<BenchmarkResult> {
         let file_size = std::fs::metadata(file_path).map_err(Error::Io)?.len();
 
+        let temp_dir = PrivateTempDir::create()?;
+
+        let request = self.request(&[file_path], false, timeout)?;
+
         let monitor = ResourceMonitor::new();
         monitor.start(Duration::from_millis(10)).await;
 
-        let (stdout, _stderr, duration) = match self.execute_subprocess(file_path, timeout).await {
+        let spawned_at = SystemTime::now();
+        let (stdout, _stderr, duration) = match self.execute_subprocess(&request, timeout, Some(&temp_dir)).await {
             Ok(result) => result,
             Err(e) => {
                 let samples = monitor.stop().await;
@@ -279,9 +492,11 @@ impl FrameworkAdapter for SubprocessAdapter {
                     file_size,
                     success: false,
                     error_message: Some(e.to_string()),
+                    failure: Some(FailureKind::from_error(&e)),
                     duration: Duration::from_secs(0),
                     extraction_duration: None,
                     subprocess_overhead: None,
+                    overhead_breakdown: None,
                     metrics: PerformanceMetrics {
                         peak_memory_bytes: resource_stats.peak_memory_bytes,
                         avg_cpu_percent: resource_stats.avg_cpu_percent,
@@ -294,6 +509,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                     iterations: vec![],
                     statistics: None,
                     cold_start_duration: None,
+                    warmup: None,
                     file_extension: file_path
                         .extension()
                         .and_then(|e| e.to_str())
@@ -301,6 +517,10 @@ impl FrameworkAdapter for SubprocessAdapter {
                         .to_lowercase(),
                     framework_capabilities: FrameworkCapabilities::default(),
                     pdf_metadata: None,
+                    provenance: None,
+                    assertions: None,
+                    resource_leaks: None,
+                    extracted_output: None,
                 });
             }
         };
@@ -309,7 +529,8 @@ impl FrameworkAdapter for SubprocessAdapter {
         let snapshots = monitor.get_snapshots().await;
         let resource_stats = ResourceMonitor::calculate_stats(&samples, &snapshots);
 
-        let parsed = match self.parse_output(&stdout) {
+        let parse_start = Instant::now();
+        let parsed = match self.parse_output(&request, &stdout) {
             Ok(value) => value,
             Err(e) => {
                 return Ok(BenchmarkResult {
@@ -318,9 +539,11 @@ impl FrameworkAdapter for SubprocessAdapter {
                     file_size,
                     success: false,
                     error_message: Some(e.to_string()),
+                    failure: Some(FailureKind::from_error(&e)),
                     duration,
                     extraction_duration: None,
                     subprocess_overhead: None,
+                    overhead_breakdown: None,
                     metrics: PerformanceMetrics {
                         peak_memory_bytes: resource_stats.peak_memory_bytes,
                         avg_cpu_percent: resource_stats.avg_cpu_percent,
@@ -333,6 +556,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                     iterations: vec![],
                     statistics: None,
                     cold_start_duration: None,
+                    warmup: None,
                     file_extension: file_path
                         .extension()
                         .and_then(|e| e.to_str())
@@ -340,9 +564,14 @@ impl FrameworkAdapter for SubprocessAdapter {
                         .to_lowercase(),
                     framework_capabilities: FrameworkCapabilities::default(),
                     pdf_metadata: None,
+                    provenance: None,
+                    assertions: None,
+                    resource_leaks: None,
+                    extracted_output: None,
                 });
             }
         };
+        let parse_duration = parse_start.elapsed();
 
         let extraction_duration = parsed
             .get("_extraction_time_ms")
@@ -350,6 +579,7 @@ impl FrameworkAdapter for SubprocessAdapter {
             .map(|ms| Duration::from_secs_f64(ms / 1000.0));
 
         let subprocess_overhead = extraction_duration.map(|ext| duration.saturating_sub(ext));
+        let overhead_breakdown = parse_overhead_breakdown(&parsed, spawned_at, parse_duration, duration);
 
         let throughput = if duration.as_secs_f64() > 0.0 {
             file_size as f64 / duration.as_secs_f64()
@@ -372,14 +602,17 @@ impl FrameworkAdapter for SubprocessAdapter {
             file_size,
             success: true,
             error_message: None,
+            failure: None,
             duration,
             extraction_duration,
             subprocess_overhead,
+            overhead_breakdown,
             metrics,
             quality: None,
             iterations: vec![],
             statistics: None,
             cold_start_duration: None,
+            warmup: None,
             file_extension: file_path
                 .extension()
                 .and_then(|e| e.to_str())
@@ -387,6 +620,13 @@ impl FrameworkAdapter for SubprocessAdapter {
                 .to_lowercase(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: Some(ResourceLeaks {
+                leaked_fds: ResourceLeaks::fds_from_wrapper_output(&parsed),
+                leaked_temp_files: temp_dir.leftovers(),
+            }),
+            extracted_output: ExtractedOutput::from_wrapper_output(&parsed),
         })
     }
 
@@ -412,10 +652,14 @@ impl FrameworkAdapter for SubprocessAdapter {
             .filter_map(|p| std::fs::metadata(p).ok().map(|m| m.len()))
             .sum();
 
+        let temp_dir = PrivateTempDir::create()?;
+        let request = self.request(file_paths, true, timeout)?;
+
         let monitor = ResourceMonitor::new();
         monitor.start(Duration::from_millis(10)).await;
 
-        let (_stdout, _stderr, duration) = match self.execute_subprocess_batch(file_paths, timeout).await {
+        let (_stdout, _stderr, duration) = match self.execute_subprocess_batch(&request, timeout, Some(&temp_dir)).await
+        {
             Ok(result) => result,
             Err(e) => {
                 let samples = monitor.stop().await;
@@ -428,9 +672,11 @@ impl FrameworkAdapter for SubprocessAdapter {
                     file_size: total_file_size,
                     success: false,
                     error_message: Some(e.to_string()),
+                    failure: Some(FailureKind::from_error(&e)),
                     duration: Duration::from_secs(0),
                     extraction_duration: None,
                     subprocess_overhead: None,
+                    overhead_breakdown: None,
                     metrics: PerformanceMetrics {
                         peak_memory_bytes: resource_stats.peak_memory_bytes,
                         avg_cpu_percent: resource_stats.avg_cpu_percent,
@@ -443,9 +689,14 @@ impl FrameworkAdapter for SubprocessAdapter {
                     iterations: vec![],
                     statistics: None,
                     cold_start_duration: None,
+                    warmup: None,
                     file_extension: "batch".to_string(),
                     framework_capabilities: FrameworkCapabilities::default(),
                     pdf_metadata: None,
+                    provenance: None,
+                    assertions: None,
+                    resource_leaks: None,
+                    extracted_output: None,
                 }]);
             }
         };
@@ -466,9 +717,11 @@ impl FrameworkAdapter for SubprocessAdapter {
             file_size: total_file_size,
             success: true,
             error_message: None,
+            failure: None,
             duration,
             extraction_duration: None,
             subprocess_overhead: None,
+            overhead_breakdown: None,
             metrics: PerformanceMetrics {
                 peak_memory_bytes: resource_stats.peak_memory_bytes,
                 avg_cpu_percent: resource_stats.avg_cpu_percent,
@@ -481,9 +734,17 @@ impl FrameworkAdapter for SubprocessAdapter {
             iterations: vec![],
             statistics: None,
             cold_start_duration: None,
+            warmup: None,
             file_extension: "batch".to_string(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: Some(ResourceLeaks {
+                leaked_fds: None,
+                leaked_temp_files: temp_dir.leftovers(),
+            }),
+            extracted_output: None,
         }])
     }
 
@@ -522,6 +783,18 @@ mod tests {
         assert_eq!(adapter.name(), "test-adapter");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_is_crash() {
+        use std::os::unix::process::ExitStatusExt;
+
+        // Raw wait statuses: a bare signal number, or the exit code shifted left by 8
+        assert!(is_crash(ExitStatus::from_raw(11)));
+        assert!(is_crash(ExitStatus::from_raw(101 << 8)));
+        assert!(is_crash(ExitStatus::from_raw(139 << 8)));
+        assert!(!is_crash(ExitStatus::from_raw(1 << 8)));
+    }
+
     #[test]
     fn test_supports_format() {
         let adapter = SubprocessAdapter::new("test", "echo", vec![], vec![]);
@@ -529,4 +802,88 @@ mod tests {
         assert!(adapter.supports_format("docx"));
         assert!(!adapter.supports_format("unknown"));
     }
+
+    #[test]
+    fn test_adapter_from_spec() {
+        let adapter = SubprocessAdapter::from_spec("my-parser=python3 /opt/adapters/my_parser.py --fast").unwrap();
+        assert_eq!(adapter.name(), "my-parser");
+        assert_eq!(adapter.command, PathBuf::from("python3"));
+        assert_eq!(adapter.args, vec!["/opt/adapters/my_parser.py", "--fast"]);
+        assert_eq!(adapter.protocol, AdapterProtocol::JsonLines);
+
+        for spec in ["python3 parser.py", "=python3", "my-parser=", "my-parser=   "] {
+            assert!(
+                matches!(SubprocessAdapter::from_spec(spec), Err(Error::Config(_))),
+                "{}",
+                spec
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_lines_protocol_round_trip() {
+        if which::which("sh").is_err() {
+            return;
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("doc.txt");
+        std::fs::write(&file, "hello").unwrap();
+
+        // Answers request 1 with the request line itself as content, proving it arrived on stdin
+        let script = format!(
+            r#"read -r request; printf 'warming up\n{{"type": "result", "protocol": 1, "id": 1, "file": "{}", "output": {{"content": "%s"}}}}\n' "$(printf '%s' "$request" | sed 's/"/\\"/g')""#,
+            file.display()
+        );
+        let mut adapter = SubprocessAdapter::new("sh-adapter", "sh", vec!["-c".to_string(), script], vec![]);
+        adapter.set_protocol(AdapterProtocol::JsonLines);
+
+        let (content, _) = adapter.extract_content(&file, Duration::from_secs(10)).await.unwrap();
+        let request: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(request["type"], "request");
+        assert_eq!(request["files"][0], file.to_string_lossy().as_ref());
+
+        // The next request has id 2, which the script does not answer
+        let error = adapter
+            .extract_content(&file, Duration::from_secs(10))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Protocol(_)), "{}", error);
+    }
+
+    #[test]
+    fn test_overhead_breakdown() {
+        let spawned_at = UNIX_EPOCH + Duration::from_secs(1_000);
+        let parsed = serde_json::json!({
+            "content": "text",
+            "_process_start_epoch_ms": 1_000_050.0,
+            "_import_time_ms": 200.0,
+            "_extraction_time_ms": 500.0,
+            "_serialization_time_ms": 5.0,
+        });
+
+        let parse_duration = Duration::from_millis(5);
+        let duration = Duration::from_millis(800);
+        let breakdown = parse_overhead_breakdown(&parsed, spawned_at, parse_duration, duration).unwrap();
+        assert_eq!(breakdown.spawn, Some(Duration::from_millis(50)));
+        assert_eq!(breakdown.import, Some(Duration::from_millis(200)));
+        assert_eq!(breakdown.serialization, Some(Duration::from_millis(10)));
+        assert_eq!(breakdown.extraction, Some(Duration::from_millis(500)));
+
+        // Whatever the attributed phases leave over is reported as `other`, so the parts sum to the wall time
+        let attributed = [
+            breakdown.spawn,
+            breakdown.import,
+            breakdown.serialization,
+            breakdown.extraction,
+        ]
+        .into_iter()
+        .flatten()
+        .sum::<Duration>();
+        assert_eq!(breakdown.other, Some(duration + parse_duration - attributed));
+        assert_eq!(attributed + breakdown.other.unwrap(), duration + parse_duration);
+
+        let legacy = serde_json::json!({ "content": "text", "_extraction_time_ms": 500.0 });
+        assert!(parse_overhead_breakdown(&legacy, spawned_at, Duration::ZERO, Duration::from_millis(800)).is_none());
+    }
 }
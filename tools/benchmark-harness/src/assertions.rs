@@ -0,0 +1,318 @@
+//! Fixture-level expected-output assertions
+//!
+//! Fixtures may declare minimal expectations on the extracted output in an `assertions`
+//! section. `BenchmarkRunner` evaluates them against every adapter that extracts the
+//! fixture, so a run doubles as a cross-framework conformance suite: a framework that
+//! gets faster by dropping text or tables shows up as a correctness regression rather
+//! than as a performance win.
+//!
+//! ## Fixture Format
+//!
+//! ```json
+//! {
+//!   "document": "pdfs/annual_report.pdf",
+//!   "file_type": "pdf",
+//!   "file_size": 1024000,
+//!   "assertions": {
+//!     "contains": ["Consolidated Balance Sheet", "Total assets"],
+//!     "min_tables": 3,
+//!     "page_count": 12
+//!   }
+//! }
+//! ```
+//!
+//! Table and page counts are only checked for adapters that report them (the native
+//! adapter, and subprocess wrappers that emit `_table_count` and `metadata.pages` or
+//! `metadata.page_count`); otherwise the assertion is listed as unchecked.
+//!
+//! Assertions are evaluated per document, so aggregated batch results (batch mode with
+//! an adapter that supports batch extraction) are not checked.
+
+use crate::accuracy::TextProvenance;
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Expected-output assertions declared by a fixture
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputAssertions {
+    /// Strings the extracted content must contain (case-sensitive)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contains: Vec<String>,
+
+    /// Minimum number of extracted tables
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_tables: Option<usize>,
+
+    /// Exact number of pages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<usize>,
+}
+
+impl OutputAssertions {
+    /// Whether the section declares no assertions at all
+    pub fn is_empty(&self) -> bool {
+        self.contains.is_empty() && self.min_tables.is_none() && self.page_count.is_none()
+    }
+}
+
+/// Output of a single extraction, kept on the result only until assertions are evaluated
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExtractedOutput {
+    /// Extracted text content
+    pub content: String,
+
+    /// Number of extracted tables, if the framework reports it
+    pub table_count: Option<usize>,
+
+    /// Number of pages, if the framework reports it
+    pub page_count: Option<usize>,
+
+    /// Where the text came from, if the framework reports it
+    pub text_provenance: Option<TextProvenance>,
+}
+
+impl ExtractedOutput {
+    /// Read the output from a subprocess wrapper's JSON payload
+    ///
+    /// Returns `None` when the payload has no `content` field.
+    pub fn from_wrapper_output(parsed: &serde_json::Value) -> Option<Self> {
+        let content = parsed.get("content")?.as_str()?.to_string();
+
+        let count = |value: Option<&serde_json::Value>| value.and_then(|v| v.as_u64()).map(|n| n as usize);
+        let metadata = parsed.get("metadata");
+        let page_count = count(metadata.and_then(|m| m.pointer("/pages/total_count")))
+            .or_else(|| count(metadata.and_then(|m| m.get("page_count"))));
+
+        Some(Self {
+            content,
+            table_count: count(parsed.get("_table_count")),
+            page_count,
+            text_provenance: TextProvenance::from_wrapper_output(parsed),
+        })
+    }
+}
+
+/// A single assertion that did not hold
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssertionFailure {
+    /// Assertion that failed (e.g., `contains`, `min_tables`)
+    pub assertion: String,
+
+    /// Expected value as declared by the fixture
+    pub expected: String,
+
+    /// Value found in the extracted output
+    pub actual: String,
+}
+
+/// Result of evaluating a fixture's assertions against one adapter's output
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssertionOutcome {
+    /// Number of assertions that held
+    pub passed: usize,
+
+    /// Assertions that did not hold
+    pub failures: Vec<AssertionFailure>,
+
+    /// Assertions that could not be checked because the framework does not report the value
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unchecked: Vec<String>,
+}
+
+impl AssertionOutcome {
+    /// Whether any assertion failed
+    pub fn is_regression(&self) -> bool {
+        !self.failures.is_empty()
+    }
+}
+
+/// Evaluate a fixture's assertions against an extracted output
+pub fn evaluate_assertions(assertions: &OutputAssertions, output: &ExtractedOutput) -> AssertionOutcome {
+    let mut outcome = AssertionOutcome::default();
+
+    for needle in &assertions.contains {
+        if output.content.contains(needle.as_str()) {
+            outcome.passed += 1;
+        } else {
+            outcome.failures.push(AssertionFailure {
+                assertion: "contains".to_string(),
+                expected: needle.clone(),
+                actual: "not found".to_string(),
+            });
+        }
+    }
+
+    if let Some(min_tables) = assertions.min_tables {
+        match output.table_count {
+            Some(count) if count >= min_tables => outcome.passed += 1,
+            Some(count) => outcome.failures.push(AssertionFailure {
+                assertion: "min_tables".to_string(),
+                expected: format!(">= {}", min_tables),
+                actual: count.to_string(),
+            }),
+            None => outcome.unchecked.push("min_tables".to_string()),
+        }
+    }
+
+    if let Some(page_count) = assertions.page_count {
+        match output.page_count {
+            Some(count) if count == page_count => outcome.passed += 1,
+            Some(count) => outcome.failures.push(AssertionFailure {
+                assertion: "page_count".to_string(),
+                expected: page_count.to_string(),
+                actual: count.to_string(),
+            }),
+            None => outcome.unchecked.push("page_count".to_string()),
+        }
+    }
+
+    outcome
+}
+
+/// Assertion failures of one framework on one fixture document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorrectnessRegression {
+    /// Framework that produced the output
+    pub framework: String,
+
+    /// Path to the fixture document
+    pub file_path: PathBuf,
+
+    /// Assertions that did not hold
+    pub failures: Vec<AssertionFailure>,
+}
+
+/// Conformance summary of a benchmark run, reported separately from performance
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorrectnessReport {
+    /// Number of results whose assertions were evaluated
+    pub evaluated: usize,
+
+    /// Number of evaluated results where every assertion held
+    pub passed: usize,
+
+    /// Results with at least one failed assertion
+    pub regressions: Vec<CorrectnessRegression>,
+}
+
+impl CorrectnessReport {
+    /// Collect assertion outcomes from benchmark results
+    pub fn from_results(results: &[BenchmarkResult]) -> Self {
+        let mut report = Self::default();
+
+        for result in results {
+            let Some(outcome) = &result.assertions else {
+                continue;
+            };
+
+            report.evaluated += 1;
+            if outcome.is_regression() {
+                report.regressions.push(CorrectnessRegression {
+                    framework: result.framework.clone(),
+                    file_path: result.file_path.clone(),
+                    failures: outcome.failures.clone(),
+                });
+            } else {
+                report.passed += 1;
+            }
+        }
+
+        report
+    }
+}
+
+/// Write the correctness report as JSON
+pub fn write_correctness_report(report: &CorrectnessReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize correctness report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_assertions() {
+        let assertions = OutputAssertions {
+            contains: vec!["Total assets".to_string(), "Net income".to_string()],
+            min_tables: Some(2),
+            page_count: Some(3),
+        };
+        let output = ExtractedOutput {
+            content: "Balance sheet\nTotal assets: 100".to_string(),
+            table_count: Some(2),
+            page_count: Some(4),
+            text_provenance: None,
+        };
+
+        let outcome = evaluate_assertions(&assertions, &output);
+
+        assert_eq!(outcome.passed, 2);
+        assert!(outcome.is_regression());
+        assert_eq!(
+            outcome.failures,
+            vec![
+                AssertionFailure {
+                    assertion: "contains".to_string(),
+                    expected: "Net income".to_string(),
+                    actual: "not found".to_string(),
+                },
+                AssertionFailure {
+                    assertion: "page_count".to_string(),
+                    expected: "3".to_string(),
+                    actual: "4".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unreported_counts_are_unchecked() {
+        let assertions = OutputAssertions {
+            contains: vec![],
+            min_tables: Some(1),
+            page_count: Some(1),
+        };
+        let output = ExtractedOutput {
+            content: "text".to_string(),
+            table_count: None,
+            page_count: None,
+            text_provenance: None,
+        };
+
+        let outcome = evaluate_assertions(&assertions, &output);
+
+        assert!(!outcome.is_regression());
+        assert_eq!(outcome.unchecked, vec!["min_tables", "page_count"]);
+    }
+
+    #[test]
+    fn test_extracted_output_from_wrapper_output() {
+        let kreuzberg = serde_json::json!({
+            "content": "text",
+            "metadata": { "pages": { "total_count": 5, "unit_type": "page" } },
+            "_table_count": 2
+        });
+        let output = ExtractedOutput::from_wrapper_output(&kreuzberg).unwrap();
+        assert_eq!(output.table_count, Some(2));
+        assert_eq!(output.page_count, Some(5));
+
+        let other = serde_json::json!({ "content": "text", "metadata": { "page_count": 3 } });
+        let output = ExtractedOutput::from_wrapper_output(&other).unwrap();
+        assert_eq!(output.table_count, None);
+        assert_eq!(output.page_count, Some(3));
+
+        assert!(ExtractedOutput::from_wrapper_output(&serde_json::json!({ "metadata": {} })).is_none());
+    }
+}
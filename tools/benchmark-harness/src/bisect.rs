@@ -0,0 +1,616 @@
+//! Performance regression bisection across git revisions
+//!
+//! Locates the commit that made the native adapter slower (or hungrier) on a fixture set.
+//! Every measured revision is checked out into its own git worktree, the harness of that
+//! revision is built in release mode and `run` benchmarks `kreuzberg-native` on the fixture
+//! set of the current checkout, so the workload stays the same while the code under test
+//! changes. All revisions share one cargo target directory, which keeps rebuilds incremental.
+//!
+//! The good revision is measured first as the baseline. A revision regressed when its metric
+//! is worse than the baseline by more than `threshold` (a fraction, `0.10` = 10%). The bad
+//! revision must have regressed; the commits between them are then bisected along the
+//! ancestry path. Revisions that fail to build or benchmark are skipped, like
+//! `git bisect skip`, and listed as untestable when they border the first bad commit.
+
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Framework benchmarked at every revision
+pub const BISECT_FRAMEWORK: &str = "kreuzberg-native";
+
+/// Default relative slowdown above which a revision counts as regressed
+pub const DEFAULT_BISECT_THRESHOLD: f64 = 0.10;
+
+/// Metric compared between revisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BisectMetric {
+    /// Mean extraction duration across fixtures, in milliseconds
+    MeanDuration,
+    /// Median extraction duration across fixtures, in milliseconds
+    MedianDuration,
+    /// 95th percentile extraction duration across fixtures, in milliseconds
+    P95Duration,
+    /// Peak resident memory across fixtures, in bytes
+    PeakMemory,
+    /// Mean throughput across fixtures, in bytes per second
+    Throughput,
+}
+
+impl BisectMetric {
+    /// Whether larger values are improvements
+    pub fn higher_is_better(self) -> bool {
+        matches!(self, Self::Throughput)
+    }
+
+    /// Unit of the metric values
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::MeanDuration | Self::MedianDuration | Self::P95Duration => "ms",
+            Self::PeakMemory => "bytes",
+            Self::Throughput => "bytes/s",
+        }
+    }
+
+    /// Aggregate the metric over the successful `kreuzberg-native` entries of a results.json
+    ///
+    /// Durations and throughput are averaged over fixtures, peak memory is the maximum.
+    /// Results are read as plain JSON, so older revisions with fewer result fields still load.
+    pub fn measure(self, results: &[Value]) -> Option<f64> {
+        let samples: Vec<f64> = results
+            .iter()
+            .filter(|result| result["framework"] == BISECT_FRAMEWORK && result["success"] == true)
+            .filter_map(|result| self.sample(result))
+            .collect();
+
+        if samples.is_empty() {
+            return None;
+        }
+
+        match self {
+            Self::PeakMemory => samples.into_iter().reduce(f64::max),
+            _ => Some(samples.iter().sum::<f64>() / samples.len() as f64),
+        }
+    }
+
+    /// Whether `value` is worse than `baseline` by more than `threshold`
+    pub fn regressed(self, baseline: f64, value: f64, threshold: f64) -> bool {
+        if self.higher_is_better() {
+            value < baseline * (1.0 - threshold)
+        } else {
+            value > baseline * (1.0 + threshold)
+        }
+    }
+
+    fn sample(self, result: &Value) -> Option<f64> {
+        let statistic = |name: &str| result["statistics"].get(name).and_then(duration_ms);
+        match self {
+            Self::MeanDuration => statistic("mean").or_else(|| duration_ms(&result["duration"])),
+            Self::MedianDuration => statistic("median").or_else(|| duration_ms(&result["duration"])),
+            Self::P95Duration => statistic("p95").or_else(|| duration_ms(&result["duration"])),
+            Self::PeakMemory => result["metrics"]["peak_memory_bytes"].as_f64(),
+            Self::Throughput => result["metrics"]["throughput_bytes_per_sec"].as_f64(),
+        }
+    }
+}
+
+impl fmt::Display for BisectMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MeanDuration => "mean duration",
+            Self::MedianDuration => "median duration",
+            Self::P95Duration => "p95 duration",
+            Self::PeakMemory => "peak memory",
+            Self::Throughput => "throughput",
+        })
+    }
+}
+
+/// Serialized `std::time::Duration` (`{"secs": .., "nanos": ..}`) in milliseconds
+fn duration_ms(value: &Value) -> Option<f64> {
+    let secs = value.get("secs")?.as_f64()?;
+    let nanos = value.get("nanos")?.as_f64()?;
+    Some(secs * 1000.0 + nanos / 1_000_000.0)
+}
+
+/// Settings of a bisection
+#[derive(Debug, Clone)]
+pub struct BisectConfig {
+    /// Git repository containing the revisions
+    pub repo: PathBuf,
+
+    /// Revision known to perform well
+    pub good: String,
+
+    /// Revision known to perform badly (a descendant of `good`)
+    pub bad: String,
+
+    /// Fixture file or directory benchmarked at every revision
+    pub fixtures: PathBuf,
+
+    /// Metric compared between revisions
+    pub metric: BisectMetric,
+
+    /// Relative change against the good revision above which a revision counts as regressed
+    pub threshold: f64,
+
+    /// Benchmark iterations per fixture
+    pub iterations: usize,
+
+    /// Warmup iterations per fixture
+    pub warmup: usize,
+
+    /// Timeout in seconds per extraction
+    pub timeout: u64,
+
+    /// Directory for worktrees, the shared target directory and per-revision results
+    pub work_dir: PathBuf,
+}
+
+/// Measurement of one revision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BisectStep {
+    /// Full commit hash
+    pub commit: String,
+
+    /// Aggregated metric value, `None` when the revision could not be measured
+    pub value: Option<f64>,
+
+    /// Whether the revision regressed against the baseline
+    pub regressed: bool,
+
+    /// Build or benchmark error of an untestable revision
+    pub error: Option<String>,
+}
+
+/// Complete bisection report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BisectReport {
+    /// Metric compared between revisions
+    pub metric: BisectMetric,
+
+    /// Unit of the metric values
+    pub unit: String,
+
+    /// Fixture file or directory that was benchmarked
+    pub fixtures: PathBuf,
+
+    /// Full hash of the good revision
+    pub good: String,
+
+    /// Full hash of the bad revision
+    pub bad: String,
+
+    /// Relative regression threshold
+    pub threshold: f64,
+
+    /// Metric value of the good revision
+    pub baseline: f64,
+
+    /// Number of commits on the ancestry path from good (exclusive) to bad (inclusive)
+    pub commits_in_range: usize,
+
+    /// Measured revisions in measurement order, starting with the bad revision
+    pub steps: Vec<BisectStep>,
+
+    /// First commit whose metric regressed, `None` when the bad revision did not regress
+    pub first_bad_commit: Option<String>,
+
+    /// Subject line of the first bad commit
+    pub first_bad_subject: Option<String>,
+
+    /// Unmeasurable commits right before the first bad commit, any of which may be the culprit
+    pub untestable: Vec<String>,
+}
+
+/// Outcome of bisecting an ordered commit range
+#[derive(Debug, Clone)]
+pub struct BisectOutcome {
+    /// Measured revisions in measurement order
+    pub steps: Vec<BisectStep>,
+
+    /// Index of the first bad commit
+    pub first_bad: Option<usize>,
+
+    /// Indices of unmeasurable commits between the last good and the first bad commit
+    pub untestable: Vec<usize>,
+}
+
+/// Bisect `commits` (oldest first, the last one being the bad revision) against `baseline`
+///
+/// The last commit is measured first; when it does not regress there is nothing to bisect.
+/// Errors of `measure` on the last commit are returned, errors on other commits skip them.
+pub fn bisect_commits<F>(
+    commits: &[String],
+    baseline: f64,
+    metric: BisectMetric,
+    threshold: f64,
+    mut measure: F,
+) -> Result<BisectOutcome>
+where
+    F: FnMut(&str) -> Result<f64>,
+{
+    let Some(last) = commits.len().checked_sub(1) else {
+        return Err(Error::Config(
+            "No commits between the good and bad revisions".to_string(),
+        ));
+    };
+
+    let mut steps = Vec::new();
+    let value = measure(&commits[last])?;
+    let regressed = metric.regressed(baseline, value, threshold);
+    steps.push(BisectStep {
+        commit: commits[last].clone(),
+        value: Some(value),
+        regressed,
+        error: None,
+    });
+    if !regressed {
+        return Ok(BisectOutcome {
+            steps,
+            first_bad: None,
+            untestable: Vec::new(),
+        });
+    }
+
+    // Everything before `low` is good, `high` is bad.
+    let mut low = 0;
+    let mut high = last;
+    let mut skipped = HashSet::new();
+
+    loop {
+        let middle = low + (high - low) / 2;
+        let Some(index) = (low..high)
+            .filter(|index| !skipped.contains(index))
+            .min_by_key(|index| index.abs_diff(middle))
+        else {
+            break;
+        };
+
+        match measure(&commits[index]) {
+            Ok(value) => {
+                let regressed = metric.regressed(baseline, value, threshold);
+                steps.push(BisectStep {
+                    commit: commits[index].clone(),
+                    value: Some(value),
+                    regressed,
+                    error: None,
+                });
+                if regressed {
+                    high = index;
+                } else {
+                    low = index + 1;
+                }
+            }
+            Err(err) => {
+                steps.push(BisectStep {
+                    commit: commits[index].clone(),
+                    value: None,
+                    regressed: false,
+                    error: Some(err.to_string()),
+                });
+                skipped.insert(index);
+            }
+        }
+    }
+
+    Ok(BisectOutcome {
+        steps,
+        first_bad: Some(high),
+        untestable: (low..high).collect(),
+    })
+}
+
+/// Bisect the commits between `config.good` and `config.bad`
+pub fn run_bisect(config: &BisectConfig) -> Result<BisectReport> {
+    if !(0.0..1.0).contains(&config.threshold) {
+        return Err(Error::Config(format!(
+            "Bisect threshold must be in [0, 1), got {}",
+            config.threshold
+        )));
+    }
+
+    let repo = fs::canonicalize(&config.repo)?;
+    let fixtures = fs::canonicalize(&config.fixtures)?;
+    fs::create_dir_all(&config.work_dir)?;
+    let work_dir = fs::canonicalize(&config.work_dir)?;
+
+    let good = git(
+        &repo,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", config.good)],
+    )?;
+    let bad = git(&repo, &["rev-parse", "--verify", &format!("{}^{{commit}}", config.bad)])?;
+    let commits: Vec<String> = git(
+        &repo,
+        &[
+            "rev-list",
+            "--ancestry-path",
+            "--reverse",
+            &format!("{}..{}", good, bad),
+        ],
+    )?
+    .lines()
+    .map(str::to_string)
+    .collect();
+    if commits.is_empty() {
+        return Err(Error::Config(format!(
+            "{} is not a descendant of {}",
+            config.bad, config.good
+        )));
+    }
+
+    let measure = |commit: &str| {
+        eprintln!("[bisect] measuring {}", short(commit));
+        let value = measure_revision(&repo, &work_dir, &fixtures, commit, config);
+        match &value {
+            Ok(value) => eprintln!("[bisect] {}: {:.2} {}", short(commit), value, config.metric.unit()),
+            Err(err) => eprintln!("[bisect] {}: skipped ({})", short(commit), err),
+        }
+        value
+    };
+
+    let baseline = measure(&good)?;
+    let outcome = bisect_commits(&commits, baseline, config.metric, config.threshold, measure)?;
+
+    let first_bad_commit = outcome.first_bad.map(|index| commits[index].clone());
+    let first_bad_subject = match &first_bad_commit {
+        Some(commit) => Some(git(&repo, &["log", "-1", "--format=%s", commit])?),
+        None => None,
+    };
+
+    Ok(BisectReport {
+        metric: config.metric,
+        unit: config.metric.unit().to_string(),
+        fixtures,
+        good,
+        bad,
+        threshold: config.threshold,
+        baseline,
+        commits_in_range: commits.len(),
+        steps: outcome.steps,
+        first_bad_commit,
+        first_bad_subject,
+        untestable: outcome
+            .untestable
+            .into_iter()
+            .map(|index| commits[index].clone())
+            .collect(),
+    })
+}
+
+/// Build the harness of `commit` in a worktree and benchmark the fixture set with it
+fn measure_revision(repo: &Path, work_dir: &Path, fixtures: &Path, commit: &str, config: &BisectConfig) -> Result<f64> {
+    let worktree = work_dir.join("worktrees").join(short(commit));
+    if worktree.exists() {
+        git(repo, &["worktree", "remove", "--force", &worktree.to_string_lossy()])?;
+    }
+    git(
+        repo,
+        &[
+            "worktree",
+            "add",
+            "--detach",
+            "--force",
+            &worktree.to_string_lossy(),
+            commit,
+        ],
+    )?;
+
+    let result = build_and_run(&worktree, work_dir, fixtures, commit, config);
+
+    git(repo, &["worktree", "remove", "--force", &worktree.to_string_lossy()])?;
+
+    result
+}
+
+fn build_and_run(
+    worktree: &Path,
+    work_dir: &Path,
+    fixtures: &Path,
+    commit: &str,
+    config: &BisectConfig,
+) -> Result<f64> {
+    let target_dir = work_dir.join("target");
+
+    let build = Command::new("cargo")
+        .args([
+            "build",
+            "--release",
+            "--package",
+            "benchmark-harness",
+            "--manifest-path",
+        ])
+        .arg(worktree.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .output()?;
+    if !build.status.success() {
+        return Err(Error::Benchmark(format!("Build failed: {}", last_line(&build.stderr))));
+    }
+
+    let output_dir = work_dir.join("runs").join(short(commit));
+    if output_dir.exists() {
+        fs::remove_dir_all(&output_dir)?;
+    }
+
+    let run = Command::new(target_dir.join("release").join("benchmark-harness"))
+        .arg("run")
+        .arg("--fixtures")
+        .arg(fixtures)
+        .args(["--frameworks", BISECT_FRAMEWORK, "--mode", "single-file"])
+        .args(["--iterations", &config.iterations.to_string()])
+        .args(["--warmup", &config.warmup.to_string()])
+        .args(["--timeout", &config.timeout.to_string()])
+        .arg("--output")
+        .arg(&output_dir)
+        .current_dir(worktree)
+        .output()?;
+    if !run.status.success() {
+        return Err(Error::Benchmark(format!(
+            "Benchmark failed: {}",
+            last_line(&run.stderr)
+        )));
+    }
+
+    let json = fs::read_to_string(output_dir.join("results.json"))?;
+    let results: Vec<Value> = serde_json::from_str(&json)?;
+    config.metric.measure(&results).ok_or_else(|| {
+        Error::Benchmark(format!(
+            "No successful {} results to measure {}",
+            BISECT_FRAMEWORK, config.metric
+        ))
+    })
+}
+
+pub(crate) fn git(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git").arg("-C").arg(repo).args(args).output()?;
+    if !output.status.success() {
+        return Err(Error::Config(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub(crate) fn short(commit: &str) -> &str {
+    &commit[..commit.len().min(12)]
+}
+
+pub(crate) fn last_line(output: &[u8]) -> String {
+    String::from_utf8_lossy(output)
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+/// Write a bisection report to a JSON file
+pub fn write_bisect_report(report: &BisectReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize bisect report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn commits(count: usize) -> Vec<String> {
+        (0..count).map(|index| format!("{:040x}", index)).collect()
+    }
+
+    fn index_of(commit: &str) -> usize {
+        usize::from_str_radix(commit, 16).unwrap()
+    }
+
+    #[test]
+    fn test_measure_reads_native_results() {
+        let results = vec![
+            json!({
+                "framework": "kreuzberg-native",
+                "success": true,
+                "duration": {"secs": 0, "nanos": 300_000_000},
+                "statistics": {"mean": {"secs": 0, "nanos": 100_000_000}},
+                "metrics": {"peak_memory_bytes": 1000, "throughput_bytes_per_sec": 50.0}
+            }),
+            json!({
+                "framework": "kreuzberg-native",
+                "success": true,
+                "duration": {"secs": 0, "nanos": 200_000_000},
+                "metrics": {"peak_memory_bytes": 3000, "throughput_bytes_per_sec": 150.0}
+            }),
+            json!({"framework": "kreuzberg-native", "success": false, "duration": {"secs": 9, "nanos": 0}}),
+            json!({"framework": "docling", "success": true, "duration": {"secs": 9, "nanos": 0}}),
+        ];
+
+        assert_eq!(BisectMetric::MeanDuration.measure(&results), Some(150.0));
+        assert_eq!(BisectMetric::PeakMemory.measure(&results), Some(3000.0));
+        assert_eq!(BisectMetric::Throughput.measure(&results), Some(100.0));
+        assert_eq!(BisectMetric::MeanDuration.measure(&results[2..]), None);
+    }
+
+    #[test]
+    fn test_regressed_respects_direction() {
+        assert!(BisectMetric::MeanDuration.regressed(100.0, 111.0, 0.10));
+        assert!(!BisectMetric::MeanDuration.regressed(100.0, 109.0, 0.10));
+        assert!(BisectMetric::Throughput.regressed(100.0, 89.0, 0.10));
+        assert!(!BisectMetric::Throughput.regressed(100.0, 150.0, 0.10));
+    }
+
+    #[test]
+    fn test_bisect_finds_first_bad_commit() {
+        let commits = commits(20);
+        let mut measured = 0;
+        let outcome = bisect_commits(&commits, 100.0, BisectMetric::MeanDuration, 0.10, |commit| {
+            measured += 1;
+            Ok(if index_of(commit) >= 13 { 150.0 } else { 101.0 })
+        })
+        .unwrap();
+
+        assert_eq!(outcome.first_bad, Some(13));
+        assert!(outcome.untestable.is_empty());
+        assert!(measured <= 6, "measured {} revisions", measured);
+    }
+
+    #[test]
+    fn test_bisect_skips_unmeasurable_commits() {
+        let commits = commits(10);
+        let outcome = bisect_commits(
+            &commits,
+            100.0,
+            BisectMetric::MeanDuration,
+            0.10,
+            |commit| match index_of(commit) {
+                4 => Err(Error::Benchmark("Build failed".to_string())),
+                index if index >= 6 => Ok(200.0),
+                _ => Ok(100.0),
+            },
+        )
+        .unwrap();
+        assert_eq!(outcome.first_bad, Some(6));
+        assert!(outcome.untestable.is_empty());
+
+        let outcome = bisect_commits(
+            &commits,
+            100.0,
+            BisectMetric::MeanDuration,
+            0.10,
+            |commit| match index_of(commit) {
+                4 => Err(Error::Benchmark("Build failed".to_string())),
+                index if index >= 5 => Ok(200.0),
+                _ => Ok(100.0),
+            },
+        )
+        .unwrap();
+        assert_eq!(outcome.first_bad, Some(5));
+        assert_eq!(outcome.untestable, vec![4]);
+    }
+
+    #[test]
+    fn test_bisect_without_regression() {
+        let commits = commits(5);
+        let outcome = bisect_commits(&commits, 100.0, BisectMetric::MeanDuration, 0.10, |_| Ok(102.0)).unwrap();
+
+        assert_eq!(outcome.first_bad, None);
+        assert_eq!(outcome.steps.len(), 1);
+        assert!(bisect_commits(&[], 100.0, BisectMetric::MeanDuration, 0.10, |_| Ok(0.0)).is_err());
+    }
+}
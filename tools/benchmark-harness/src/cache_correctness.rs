@@ -0,0 +1,541 @@
+//! Result cache correctness under configuration permutations
+//!
+//! Benchmarks that enable Kreuzberg's result cache only measure what they claim if a cached
+//! result is never served for a configuration it was not produced with. This suite extracts
+//! every fixture under a grid of configuration permutations twice: once with caching
+//! disabled (the reference) and once with a shared in-memory result cache. The cached pass
+//! runs every permutation of a document back to back, so a cache key that ignores part of
+//! the configuration makes a later permutation pick up the entry stored by an earlier one.
+//! Each permutation is then extracted a second time to check the result actually served
+//! from the cache.
+//!
+//! Results are compared by [`ResultFingerprint`], which covers content, tables, chunks,
+//! pages and term offsets but not timing-dependent metadata. A cached result that differs
+//! from its reference is a mismatch; when it equals the reference of another permutation,
+//! that permutation is reported as the one it was served for. Independently, permutations
+//! whose references differ must have different cache keys.
+
+use crate::fixture::Fixture;
+use crate::{Error, Result};
+use kreuzberg::cache::backend::{backend_for, result_cache_key_for_file};
+use kreuzberg::cache::blob_store::blob_hash;
+use kreuzberg::core::config::PageConfig;
+use kreuzberg::{
+    CacheBackendKind, CacheConfig, ChunkingConfig, ExtractionConfig, ExtractionResult, NormalizationConfig,
+    TokenReductionConfig, extract_file,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Named extraction configuration in the permutation grid
+#[derive(Debug, Clone)]
+pub struct ConfigPermutation {
+    /// Label used in the report
+    pub name: String,
+
+    /// Configuration without a cache section
+    pub config: ExtractionConfig,
+}
+
+impl ConfigPermutation {
+    fn new(name: &str, config: ExtractionConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            config: ExtractionConfig {
+                use_cache: false,
+                cache: None,
+                ..config
+            },
+        }
+    }
+
+    fn with_cache(&self, cache: &CacheConfig) -> ExtractionConfig {
+        ExtractionConfig {
+            use_cache: true,
+            cache: Some(cache.clone()),
+            ..self.config.clone()
+        }
+    }
+}
+
+/// Configuration permutations that change extraction output for most documents
+pub fn permutation_grid() -> Vec<ConfigPermutation> {
+    let chunking = |max_chars, max_overlap| ChunkingConfig {
+        max_chars,
+        max_overlap,
+        embedding: None,
+        preset: None,
+        respect_sentences: false,
+    };
+
+    vec![
+        ConfigPermutation::new("default", ExtractionConfig::default()),
+        ConfigPermutation::new(
+            "no-quality-processing",
+            ExtractionConfig {
+                enable_quality_processing: false,
+                ..Default::default()
+            },
+        ),
+        ConfigPermutation::new(
+            "chunking-512",
+            ExtractionConfig {
+                chunking: Some(chunking(512, 0)),
+                ..Default::default()
+            },
+        ),
+        ConfigPermutation::new(
+            "chunking-1024-overlap-128",
+            ExtractionConfig {
+                chunking: Some(chunking(1024, 128)),
+                ..Default::default()
+            },
+        ),
+        ConfigPermutation::new(
+            "page-markers",
+            ExtractionConfig {
+                pages: Some(PageConfig {
+                    extract_pages: true,
+                    insert_page_markers: true,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ),
+        ConfigPermutation::new(
+            "term-offsets",
+            ExtractionConfig {
+                emit_term_offsets: true,
+                ..Default::default()
+            },
+        ),
+        ConfigPermutation::new(
+            "normalization",
+            ExtractionConfig {
+                normalization: Some(NormalizationConfig::default()),
+                ..Default::default()
+            },
+        ),
+        ConfigPermutation::new(
+            "token-reduction-moderate",
+            ExtractionConfig {
+                token_reduction: Some(TokenReductionConfig {
+                    mode: "moderate".to_string(),
+                    preserve_important_words: true,
+                }),
+                ..Default::default()
+            },
+        ),
+    ]
+}
+
+/// Parts of an extraction result that a configuration can change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResultFingerprint {
+    /// Hash of the extracted content
+    pub content_hash: String,
+
+    /// Extracted content length in characters
+    pub content_chars: usize,
+
+    /// MIME type reported by the extraction
+    pub mime_type: String,
+
+    /// Number of tables
+    pub tables: usize,
+
+    /// Hash of the chunk contents, when chunking ran
+    pub chunks_hash: Option<String>,
+
+    /// Number of pages, when pages were extracted
+    pub pages: Option<usize>,
+
+    /// Number of term offsets, when they were emitted
+    pub term_offsets: Option<usize>,
+}
+
+impl ResultFingerprint {
+    /// Fingerprint an extraction result
+    pub fn of(result: &ExtractionResult) -> Self {
+        let chunks_hash = result.chunks.as_ref().map(|chunks| {
+            let joined: Vec<&str> = chunks.iter().map(|chunk| chunk.content.as_str()).collect();
+            blob_hash(joined.join("\0").as_bytes())
+        });
+
+        Self {
+            content_hash: blob_hash(result.content.as_bytes()),
+            content_chars: result.content.chars().count(),
+            mime_type: result.mime_type.clone(),
+            tables: result.tables.len(),
+            chunks_hash,
+            pages: result.pages.as_ref().map(Vec::len),
+            term_offsets: result.term_offsets.as_ref().map(Vec::len),
+        }
+    }
+}
+
+/// Outcome of one document under one permutation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheCheck {
+    /// Path to the fixture JSON file
+    pub fixture: PathBuf,
+
+    /// Path to the extracted document
+    pub document: PathBuf,
+
+    /// Permutation name
+    pub permutation: String,
+
+    /// Result cache key of the document under this permutation
+    pub key: Option<String>,
+
+    /// Result of the extraction with caching disabled
+    pub fresh: Option<ResultFingerprint>,
+
+    /// Result of the first extraction with caching enabled
+    pub first: Option<ResultFingerprint>,
+
+    /// Result of the second extraction with caching enabled, served from the cache
+    pub repeat: Option<ResultFingerprint>,
+
+    /// Error message when an extraction or the key lookup failed
+    pub error_message: Option<String>,
+
+    /// Whether a cached extraction differed from the fresh one
+    pub mismatch: bool,
+
+    /// Permutation whose fresh result the mismatching cached result equals
+    pub served_from: Option<String>,
+}
+
+/// Two permutations with different results that share a cache key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyCollision {
+    /// Path to the extracted document
+    pub document: PathBuf,
+
+    /// The shared cache key
+    pub key: String,
+
+    /// Names of the colliding permutations
+    pub permutations: (String, String),
+}
+
+/// Complete cache correctness report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheCorrectnessReport {
+    /// Permutation names, in run order
+    pub permutations: Vec<String>,
+
+    /// One entry per document and permutation
+    pub checks: Vec<CacheCheck>,
+
+    /// Cache keys shared by permutations with different results
+    pub key_collisions: Vec<KeyCollision>,
+
+    /// Fixtures whose documents were not found
+    pub skipped: Vec<PathBuf>,
+}
+
+impl CacheCorrectnessReport {
+    /// Number of checks whose cached result differed from the fresh one
+    pub fn mismatches(&self) -> usize {
+        self.checks.iter().filter(|check| check.mismatch).count()
+    }
+
+    /// Whether the cache never served a wrong result and no keys collided
+    pub fn is_clean(&self) -> bool {
+        self.mismatches() == 0 && self.key_collisions.is_empty()
+    }
+}
+
+/// Run the cache correctness suite
+///
+/// Every fixture whose document exists is extracted under every permutation. Extraction
+/// failures are recorded and do not abort the run; a permutation that fails both fresh
+/// and cached is not a mismatch.
+pub async fn run_cache_correctness_suite(
+    fixtures: &[(PathBuf, Fixture)],
+    permutations: &[ConfigPermutation],
+    timeout: Duration,
+) -> Result<CacheCorrectnessReport> {
+    let cache = CacheConfig {
+        backend: CacheBackendKind::Memory,
+        url: None,
+        max_entries: Some(fixtures.len().max(1) * permutations.len().max(1)),
+        namespace: None,
+    };
+    let backend = backend_for(&cache).map_err(|e| Error::Benchmark(format!("Failed to create result cache: {}", e)))?;
+    backend
+        .clear()
+        .map_err(|e| Error::Benchmark(format!("Failed to clear result cache: {}", e)))?;
+
+    let mut checks = Vec::new();
+    let mut key_collisions = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (fixture_path, fixture) in fixtures {
+        let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+        let document = fixture.resolve_document_path(fixture_dir);
+        if !document.exists() {
+            skipped.push(fixture_path.clone());
+            continue;
+        }
+
+        let mut document_checks: Vec<CacheCheck> = permutations
+            .iter()
+            .map(|permutation| CacheCheck {
+                fixture: fixture_path.clone(),
+                document: document.clone(),
+                permutation: permutation.name.clone(),
+                key: None,
+                fresh: None,
+                first: None,
+                repeat: None,
+                error_message: None,
+                mismatch: false,
+                served_from: None,
+            })
+            .collect();
+
+        for (check, permutation) in document_checks.iter_mut().zip(permutations) {
+            match extract(&document, &permutation.config, timeout).await {
+                Ok(fingerprint) => check.fresh = Some(fingerprint),
+                Err(e) => check.error_message = Some(e),
+            }
+            match result_cache_key_for_file(&document, None, &permutation.with_cache(&cache)) {
+                Ok(key) => check.key = key,
+                Err(e) => check.error_message = Some(format!("Cache key lookup failed: {}", e)),
+            }
+        }
+
+        // All permutations populate the cache before any of them is repeated, so an entry
+        // stored under a key shared with an earlier permutation is picked up here.
+        for (check, permutation) in document_checks.iter_mut().zip(permutations) {
+            match extract(&document, &permutation.with_cache(&cache), timeout).await {
+                Ok(fingerprint) => check.first = Some(fingerprint),
+                Err(e) => check.error_message = check.error_message.take().or(Some(e)),
+            }
+        }
+        for (check, permutation) in document_checks.iter_mut().zip(permutations) {
+            match extract(&document, &permutation.with_cache(&cache), timeout).await {
+                Ok(fingerprint) => check.repeat = Some(fingerprint),
+                Err(e) => check.error_message = check.error_message.take().or(Some(e)),
+            }
+        }
+
+        evaluate_checks(&mut document_checks);
+        key_collisions.extend(find_key_collisions(&document, &document_checks));
+        checks.extend(document_checks);
+    }
+
+    Ok(CacheCorrectnessReport {
+        permutations: permutations
+            .iter()
+            .map(|permutation| permutation.name.clone())
+            .collect(),
+        checks,
+        key_collisions,
+        skipped,
+    })
+}
+
+async fn extract(
+    document: &Path,
+    config: &ExtractionConfig,
+    timeout: Duration,
+) -> std::result::Result<ResultFingerprint, String> {
+    match tokio::time::timeout(timeout, extract_file(document, None, config)).await {
+        Ok(Ok(result)) => Ok(ResultFingerprint::of(&result)),
+        Ok(Err(e)) => Err(format!("Extraction failed: {}", e)),
+        Err(_) => Err(format!("Extraction exceeded {:?}", timeout)),
+    }
+}
+
+/// Flag cached results that differ from the fresh result of their permutation
+///
+/// Checks without a fresh result are skipped, since there is nothing to compare against.
+pub fn evaluate_checks(checks: &mut [CacheCheck]) {
+    let references: Vec<(String, Option<ResultFingerprint>)> = checks
+        .iter()
+        .map(|check| (check.permutation.clone(), check.fresh.clone()))
+        .collect();
+
+    for check in checks.iter_mut() {
+        let Some(fresh) = &check.fresh else {
+            continue;
+        };
+
+        let wrong = [&check.first, &check.repeat]
+            .into_iter()
+            .flatten()
+            .find(|cached| *cached != fresh)
+            .cloned();
+
+        check.mismatch = wrong.is_some();
+        check.served_from = wrong.and_then(|wrong| {
+            references
+                .iter()
+                .find(|(name, reference)| *name != check.permutation && reference.as_ref() == Some(&wrong))
+                .map(|(name, _)| name.clone())
+        });
+    }
+}
+
+/// Find permutations of one document that share a cache key but not a fresh result
+pub fn find_key_collisions(document: &Path, checks: &[CacheCheck]) -> Vec<KeyCollision> {
+    let mut collisions = Vec::new();
+
+    for (i, a) in checks.iter().enumerate() {
+        for b in &checks[i + 1..] {
+            let (Some(key), Some(other_key)) = (&a.key, &b.key) else {
+                continue;
+            };
+            if key == other_key && a.fresh.is_some() && b.fresh.is_some() && a.fresh != b.fresh {
+                collisions.push(KeyCollision {
+                    document: document.to_path_buf(),
+                    key: key.clone(),
+                    permutations: (a.permutation.clone(), b.permutation.clone()),
+                });
+            }
+        }
+    }
+
+    collisions
+}
+
+/// Write a cache correctness report to a JSON file
+pub fn write_cache_correctness_report(report: &CacheCorrectnessReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize cache correctness report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(content: &str) -> ResultFingerprint {
+        ResultFingerprint {
+            content_hash: blob_hash(content.as_bytes()),
+            content_chars: content.chars().count(),
+            mime_type: "text/plain".to_string(),
+            tables: 0,
+            chunks_hash: None,
+            pages: None,
+            term_offsets: None,
+        }
+    }
+
+    fn check(permutation: &str, key: &str, fresh: &str, cached: &str) -> CacheCheck {
+        CacheCheck {
+            fixture: PathBuf::from("fixture.json"),
+            document: PathBuf::from("notes.txt"),
+            permutation: permutation.to_string(),
+            key: Some(key.to_string()),
+            fresh: Some(fingerprint(fresh)),
+            first: Some(fingerprint(cached)),
+            repeat: Some(fingerprint(cached)),
+            error_message: None,
+            mismatch: false,
+            served_from: None,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_checks_attributes_mismatch() {
+        let mut checks = vec![
+            check("default", "aa", "plain text", "plain text"),
+            check("chunking-512", "bb", "chunked text", "plain text"),
+            check("page-markers", "cc", "paged text", "paged text"),
+        ];
+
+        evaluate_checks(&mut checks);
+
+        assert!(!checks[0].mismatch);
+        assert!(checks[1].mismatch);
+        assert_eq!(checks[1].served_from.as_deref(), Some("default"));
+        assert!(!checks[2].mismatch);
+    }
+
+    #[test]
+    fn test_evaluate_checks_skips_failed_reference() {
+        let mut failed = check("default", "aa", "", "stale");
+        failed.fresh = None;
+        let mut checks = vec![failed];
+
+        evaluate_checks(&mut checks);
+
+        assert!(!checks[0].mismatch);
+    }
+
+    #[test]
+    fn test_find_key_collisions() {
+        let checks = vec![
+            check("default", "aa", "plain text", "plain text"),
+            check("normalization", "aa", "plain text", "plain text"),
+            check("chunking-512", "aa", "chunked text", "chunked text"),
+            check("page-markers", "cc", "paged text", "paged text"),
+        ];
+
+        let collisions = find_key_collisions(Path::new("notes.txt"), &checks);
+
+        assert_eq!(collisions.len(), 2);
+        assert_eq!(
+            collisions[0].permutations,
+            ("default".to_string(), "chunking-512".to_string())
+        );
+        assert_eq!(
+            collisions[1].permutations,
+            ("normalization".to_string(), "chunking-512".to_string())
+        );
+    }
+
+    #[test]
+    fn test_permutation_grid_names_are_unique_and_uncached() {
+        let grid = permutation_grid();
+        let mut names: Vec<&str> = grid.iter().map(|permutation| permutation.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        assert_eq!(names.len(), grid.len());
+        assert!(
+            grid.iter()
+                .all(|permutation| !permutation.config.use_cache && permutation.config.cache.is_none())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_correctness_suite_on_text_fixture() {
+        let dir = tempfile::tempdir().unwrap();
+        let document = dir.path().join("notes.txt");
+        let text = "Result caching must respect the configuration. ".repeat(40);
+        fs::write(&document, text).unwrap();
+        let fixture_path = dir.path().join("notes.json");
+        let fixture: Fixture =
+            serde_json::from_str(r#"{"document": "notes.txt", "file_type": "txt", "file_size": 1920}"#).unwrap();
+
+        let report =
+            run_cache_correctness_suite(&[(fixture_path, fixture)], &permutation_grid(), Duration::from_secs(60))
+                .await
+                .unwrap();
+
+        assert_eq!(report.checks.len(), report.permutations.len());
+        assert!(
+            report
+                .checks
+                .iter()
+                .all(|check| check.fresh.is_some() && check.key.is_some())
+        );
+        assert!(report.is_clean(), "{:#?}", report);
+    }
+}
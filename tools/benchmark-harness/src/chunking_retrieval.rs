@@ -0,0 +1,586 @@
+//! Chunking strategy retrieval experiment
+//!
+//! RAG pipelines are tuned by picking a chunk size, an overlap and a splitter, but the
+//! best combination depends on the documents. This experiment extracts each fixture once,
+//! re-chunks the content under every configuration in a grid, embeds chunks and questions
+//! with an embedding preset, and measures how often the chunk containing the answer is
+//! retrieved in the top `k`. Results are aggregated per document category so the report
+//! shows which settings maximize retrieval quality for each kind of document.
+//!
+//! ## Fixture Format
+//!
+//! ```json
+//! {
+//!   "document": "../../../../test_documents/text/wkey_am.md",
+//!   "file_type": "md",
+//!   "file_size": 42488,
+//!   "metadata": { "category": "retrieval-markdown" },
+//!   "retrieval": {
+//!     "questions": [
+//!       {"question": "When did WKEY begin broadcasting?", "answer": "began broadcasting on May 23, 1941"}
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! A question is a hit when a retrieved chunk contains its `answer` (compared
+//! case-insensitively with whitespace collapsed). Fixtures without a `category` in their
+//! metadata are grouped by file type.
+
+use crate::fixture::Fixture;
+use crate::{Error, Result};
+use kreuzberg::chunking::{ChunkerType, ChunkingConfig, chunk_text};
+use kreuzberg::types::{Chunk, ChunkMetadata};
+use kreuzberg::{EmbeddingConfig, EmbeddingModelType, ExtractionConfig, extract_file};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Question with the text span that answers it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetrievalQuestion {
+    /// Natural-language query embedded with the preset model
+    pub question: String,
+
+    /// Text that must appear in a retrieved chunk for the question to count as a hit
+    pub answer: String,
+}
+
+/// Question/answer set for a retrieval fixture
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetrievalExpectations {
+    /// Questions answered by the document
+    #[serde(default)]
+    pub questions: Vec<RetrievalQuestion>,
+}
+
+/// How chunk boundaries are chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChunkingMode {
+    /// Split on text boundaries (paragraphs, sentences, words) up to the size limit
+    Fixed,
+
+    /// Split on markdown structure (headings, blocks) before falling back to text boundaries
+    Structural,
+
+    /// Pack whole sentences, found with language-specific rules, up to the size limit
+    Sentences,
+}
+
+impl ChunkingMode {
+    /// Name used in strategy labels and on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Fixed => "fixed",
+            Self::Structural => "structural",
+            Self::Sentences => "sentences",
+        }
+    }
+
+    fn chunker_type(self) -> ChunkerType {
+        match self {
+            Self::Fixed | Self::Sentences => ChunkerType::Text,
+            Self::Structural => ChunkerType::Markdown,
+        }
+    }
+}
+
+impl std::str::FromStr for ChunkingMode {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "fixed" => Ok(Self::Fixed),
+            "structural" => Ok(Self::Structural),
+            "sentences" => Ok(Self::Sentences),
+            other => Err(Error::Config(format!(
+                "Unknown chunking mode: {} (expected fixed, structural or sentences)",
+                other
+            ))),
+        }
+    }
+}
+
+/// One chunking configuration in the experiment grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ChunkingStrategy {
+    /// Splitter used to find chunk boundaries
+    pub mode: ChunkingMode,
+
+    /// Maximum characters per chunk
+    pub max_chars: usize,
+
+    /// Characters shared between consecutive chunks
+    pub overlap: usize,
+}
+
+impl ChunkingStrategy {
+    /// Label such as `structural-1024-100`
+    pub fn label(&self) -> String {
+        format!("{}-{}-{}", self.mode.as_str(), self.max_chars, self.overlap)
+    }
+
+    pub(crate) fn chunk(&self, content: &str) -> Result<Vec<Chunk>> {
+        let config = ChunkingConfig {
+            max_characters: self.max_chars,
+            overlap: self.overlap,
+            trim: true,
+            chunker_type: self.mode.chunker_type(),
+            respect_sentences: self.mode == ChunkingMode::Sentences,
+            language: None,
+        };
+        chunk_text(content, &config, None)
+            .map(|result| result.chunks)
+            .map_err(|e| Error::Benchmark(format!("Chunking with {} failed: {}", self.label(), e)))
+    }
+}
+
+/// Every combination of mode, size and overlap, skipping overlaps not smaller than the size
+pub fn strategy_grid(modes: &[ChunkingMode], sizes: &[usize], overlaps: &[usize]) -> Vec<ChunkingStrategy> {
+    let mut strategies: Vec<ChunkingStrategy> = modes
+        .iter()
+        .flat_map(|&mode| {
+            sizes.iter().flat_map(move |&max_chars| {
+                overlaps
+                    .iter()
+                    .filter(move |&&overlap| max_chars > 0 && overlap < max_chars)
+                    .map(move |&overlap| ChunkingStrategy {
+                        mode,
+                        max_chars,
+                        overlap,
+                    })
+            })
+        })
+        .collect();
+    strategies.sort();
+    strategies.dedup();
+    strategies
+}
+
+/// Retrieval scores for one strategy on one fixture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyResult {
+    /// Strategy label (see [`ChunkingStrategy::label`])
+    pub strategy: String,
+
+    /// Path to the fixture JSON file
+    pub fixture: PathBuf,
+
+    /// Document category (fixture `metadata.category`, or the file type)
+    pub category: String,
+
+    /// Number of chunks the document was split into
+    pub chunk_count: usize,
+
+    /// Number of questions asked
+    pub questions: usize,
+
+    /// Questions whose answer was found in the top `k` chunks
+    pub hits: usize,
+
+    /// Mean reciprocal rank of the first chunk containing the answer (0 when not retrieved)
+    pub mrr: f64,
+}
+
+/// Aggregated scores for one strategy on one document category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategySummary {
+    /// Document category
+    pub category: String,
+
+    /// Strategy label
+    pub strategy: String,
+
+    /// Number of fixtures in the category
+    pub fixtures: usize,
+
+    /// Number of questions across those fixtures
+    pub questions: usize,
+
+    /// Fraction of questions answered in the top `k` chunks
+    pub hit_rate: f64,
+
+    /// Mean reciprocal rank across questions
+    pub mrr: f64,
+
+    /// Mean number of chunks per document
+    pub mean_chunk_count: f64,
+}
+
+/// Complete chunking retrieval experiment report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkingRetrievalReport {
+    /// Embedding preset used for chunks and questions
+    pub preset: String,
+
+    /// Number of chunks retrieved per question
+    pub top_k: usize,
+
+    /// Strategies evaluated
+    pub strategies: Vec<ChunkingStrategy>,
+
+    /// Per-strategy, per-fixture results
+    pub results: Vec<StrategyResult>,
+
+    /// Per-category, per-strategy aggregation, ordered by category then strategy
+    pub summaries: Vec<StrategySummary>,
+
+    /// Best strategy for each category (highest hit rate, then MRR, then fewest chunks)
+    pub best_by_category: Vec<StrategySummary>,
+
+    /// Fixtures whose documents were not found
+    pub skipped: Vec<PathBuf>,
+
+    /// Fixtures that failed to extract, with the error message
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// Run the chunking retrieval experiment for every fixture with a `retrieval` section
+///
+/// Each document is extracted once with Kreuzberg and then re-chunked for every strategy.
+/// Extraction failures are recorded in the report; chunking and embedding failures abort
+/// the run, since they affect every fixture alike.
+pub async fn run_chunking_retrieval_experiment(
+    fixtures: &[(PathBuf, Fixture)],
+    strategies: &[ChunkingStrategy],
+    preset: &str,
+    top_k: usize,
+    timeout: Duration,
+) -> Result<ChunkingRetrievalReport> {
+    if kreuzberg::get_preset(preset).is_none() {
+        return Err(Error::Config(format!(
+            "Unknown embedding preset: {} (available: {})",
+            preset,
+            kreuzberg::list_presets().join(", ")
+        )));
+    }
+
+    let top_k = top_k.max(1);
+    let embedding = EmbeddingConfig {
+        model: EmbeddingModelType::Preset {
+            name: preset.to_string(),
+        },
+        normalize: true,
+        ..Default::default()
+    };
+    let extraction = ExtractionConfig {
+        use_cache: false,
+        ..Default::default()
+    };
+
+    let mut results = Vec::new();
+    let mut skipped = Vec::new();
+    let mut failed = Vec::new();
+
+    for (fixture_path, fixture) in fixtures {
+        let Some(expectations) = &fixture.retrieval else {
+            continue;
+        };
+
+        let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+        let document = fixture.resolve_document_path(fixture_dir);
+        if !document.exists() {
+            skipped.push(fixture_path.clone());
+            continue;
+        }
+
+        let content = match tokio::time::timeout(timeout, extract_file(&document, None, &extraction)).await {
+            Ok(Ok(result)) => result.content,
+            Ok(Err(e)) => {
+                failed.push((fixture_path.clone(), format!("Extraction failed: {}", e)));
+                continue;
+            }
+            Err(_) => {
+                failed.push((fixture_path.clone(), format!("Extraction exceeded {:?}", timeout)));
+                continue;
+            }
+        };
+
+        let category = fixture
+            .metadata
+            .get("category")
+            .and_then(|value| value.as_str())
+            .unwrap_or(&fixture.file_type)
+            .to_string();
+        let question_embeddings = embed(
+            expectations.questions.iter().map(|q| q.question.clone()).collect(),
+            &embedding,
+        )?;
+
+        for strategy in strategies {
+            let mut chunks = strategy.chunk(&content)?;
+            kreuzberg::embeddings::generate_embeddings_for_chunks(&mut chunks, &embedding)
+                .map_err(|e| Error::Benchmark(format!("Embedding chunks failed: {}", e)))?;
+
+            let mut hits = 0;
+            let mut reciprocal_ranks = 0.0;
+            for (question, query) in expectations.questions.iter().zip(&question_embeddings) {
+                if let Some(rank) = answer_rank(&chunks, query, &question.answer, top_k) {
+                    hits += 1;
+                    reciprocal_ranks += 1.0 / rank as f64;
+                }
+            }
+
+            let questions = expectations.questions.len();
+            results.push(StrategyResult {
+                strategy: strategy.label(),
+                fixture: fixture_path.clone(),
+                category: category.clone(),
+                chunk_count: chunks.len(),
+                questions,
+                hits,
+                mrr: if questions == 0 {
+                    0.0
+                } else {
+                    reciprocal_ranks / questions as f64
+                },
+            });
+        }
+    }
+
+    let summaries = summarize_results(&results);
+    Ok(ChunkingRetrievalReport {
+        preset: preset.to_string(),
+        top_k,
+        strategies: strategies.to_vec(),
+        best_by_category: best_by_category(&summaries),
+        results,
+        summaries,
+        skipped,
+        failed,
+    })
+}
+
+/// 1-based rank of the first top-`k` chunk (by cosine similarity) that contains the answer
+pub fn answer_rank(chunks: &[Chunk], query: &[f32], answer: &str, top_k: usize) -> Option<usize> {
+    let answer = normalize_text(answer);
+    let mut scored: Vec<(f32, &Chunk)> = chunks
+        .iter()
+        .filter_map(|chunk| Some((cosine_similarity(chunk.embedding.as_deref()?, query), chunk)))
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    scored
+        .iter()
+        .take(top_k)
+        .position(|(_, chunk)| normalize_text(&chunk.content).contains(&answer))
+        .map(|index| index + 1)
+}
+
+/// Aggregate results per category and strategy
+pub fn summarize_results(results: &[StrategyResult]) -> Vec<StrategySummary> {
+    let mut groups: BTreeMap<(&str, &str), Vec<&StrategyResult>> = BTreeMap::new();
+    for result in results {
+        groups
+            .entry((result.category.as_str(), result.strategy.as_str()))
+            .or_default()
+            .push(result);
+    }
+
+    groups
+        .into_iter()
+        .map(|((category, strategy), group)| {
+            let questions: usize = group.iter().map(|result| result.questions).sum();
+            let hits: usize = group.iter().map(|result| result.hits).sum();
+            let reciprocal_ranks: f64 = group.iter().map(|result| result.mrr * result.questions as f64).sum();
+            let chunks: usize = group.iter().map(|result| result.chunk_count).sum();
+
+            StrategySummary {
+                category: category.to_string(),
+                strategy: strategy.to_string(),
+                fixtures: group.len(),
+                questions,
+                hit_rate: ratio(hits as f64, questions),
+                mrr: ratio(reciprocal_ranks, questions),
+                mean_chunk_count: ratio(chunks as f64, group.len()),
+            }
+        })
+        .collect()
+}
+
+/// Pick the best strategy per category: highest hit rate, then MRR, then fewest chunks
+pub fn best_by_category(summaries: &[StrategySummary]) -> Vec<StrategySummary> {
+    let mut best: BTreeMap<&str, &StrategySummary> = BTreeMap::new();
+    for summary in summaries {
+        let entry = best.entry(summary.category.as_str()).or_insert(summary);
+        let better = summary
+            .hit_rate
+            .total_cmp(&entry.hit_rate)
+            .then(summary.mrr.total_cmp(&entry.mrr))
+            .then(entry.mean_chunk_count.total_cmp(&summary.mean_chunk_count))
+            .is_gt();
+        if better {
+            *entry = summary;
+        }
+    }
+    best.into_values().cloned().collect()
+}
+
+/// Write a chunking retrieval report to a JSON file
+pub fn write_chunking_retrieval_report(report: &ChunkingRetrievalReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize chunking retrieval report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Embed free-standing texts (questions) through the chunk embedding API
+pub(crate) fn embed(texts: Vec<String>, config: &EmbeddingConfig) -> Result<Vec<Vec<f32>>> {
+    let total_chunks = texts.len();
+    let mut chunks: Vec<Chunk> = texts
+        .into_iter()
+        .enumerate()
+        .map(|(chunk_index, content)| Chunk {
+            metadata: ChunkMetadata {
+                byte_start: 0,
+                byte_end: content.len(),
+                token_count: None,
+                chunk_index,
+                total_chunks,
+                first_page: None,
+                last_page: None,
+                annotations: Default::default(),
+            },
+            content,
+            embedding: None,
+        })
+        .collect();
+
+    kreuzberg::embeddings::generate_embeddings_for_chunks(&mut chunks, config)
+        .map_err(|e| Error::Benchmark(format!("Embedding questions failed: {}", e)))?;
+
+    Ok(chunks
+        .into_iter()
+        .map(|chunk| chunk.embedding.unwrap_or_default())
+        .collect())
+}
+
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+pub(crate) fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn ratio(numerator: f64, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator / denominator as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(content: &str, embedding: Vec<f32>) -> Chunk {
+        Chunk {
+            content: content.to_string(),
+            embedding: Some(embedding),
+            metadata: ChunkMetadata {
+                byte_start: 0,
+                byte_end: content.len(),
+                token_count: None,
+                chunk_index: 0,
+                total_chunks: 1,
+                first_page: None,
+                last_page: None,
+                annotations: Default::default(),
+            },
+        }
+    }
+
+    fn result(category: &str, strategy: &str, hits: usize, mrr: f64, chunk_count: usize) -> StrategyResult {
+        StrategyResult {
+            strategy: strategy.to_string(),
+            fixture: PathBuf::from("fixture.json"),
+            category: category.to_string(),
+            chunk_count,
+            questions: 4,
+            hits,
+            mrr,
+        }
+    }
+
+    #[test]
+    fn test_strategy_grid_skips_oversized_overlaps() {
+        let grid = strategy_grid(&[ChunkingMode::Structural, ChunkingMode::Fixed], &[256, 100], &[0, 100]);
+
+        let labels: Vec<String> = grid.iter().map(ChunkingStrategy::label).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "fixed-100-0",
+                "fixed-256-0",
+                "fixed-256-100",
+                "structural-100-0",
+                "structural-256-0",
+                "structural-256-100",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_answer_rank_uses_similarity_order_and_top_k() {
+        let chunks = vec![
+            chunk("Unrelated text", vec![1.0, 0.0]),
+            chunk("The station began\nbroadcasting in 1941", vec![0.6, 0.8]),
+            chunk("Programming details", vec![0.0, 1.0]),
+        ];
+        let query = [0.0, 1.0];
+
+        assert_eq!(answer_rank(&chunks, &query, "Began broadcasting in 1941", 3), Some(2));
+        assert_eq!(answer_rank(&chunks, &query, "began broadcasting in 1941", 1), None);
+        assert_eq!(answer_rank(&chunks, &query, "not in any chunk", 3), None);
+    }
+
+    #[test]
+    fn test_summaries_and_best_strategy_per_category() {
+        let results = vec![
+            result("markdown", "fixed-512-0", 2, 0.5, 40),
+            result("markdown", "structural-512-0", 3, 0.5, 30),
+            result("markdown", "structural-1024-0", 3, 0.5, 15),
+            result("text", "fixed-512-0", 4, 1.0, 10),
+            result("text", "fixed-512-0", 2, 0.25, 20),
+        ];
+
+        let summaries = summarize_results(&results);
+        let text = summaries.iter().find(|s| s.category == "text").unwrap();
+        assert_eq!(text.fixtures, 2);
+        assert_eq!(text.questions, 8);
+        assert!((text.hit_rate - 0.75).abs() < 1e-9);
+        assert!((text.mrr - 0.625).abs() < 1e-9);
+        assert!((text.mean_chunk_count - 15.0).abs() < 1e-9);
+
+        let best = best_by_category(&summaries);
+        assert_eq!(best.len(), 2);
+        assert_eq!(best[0].category, "markdown");
+        assert_eq!(best[0].strategy, "structural-1024-0");
+        assert_eq!(best[1].strategy, "fixed-512-0");
+    }
+
+    #[test]
+    fn test_chunking_mode_parsing() {
+        assert_eq!("structural".parse::<ChunkingMode>().unwrap(), ChunkingMode::Structural);
+        assert_eq!("sentences".parse::<ChunkingMode>().unwrap(), ChunkingMode::Sentences);
+        assert!("semantic".parse::<ChunkingMode>().is_err());
+    }
+}
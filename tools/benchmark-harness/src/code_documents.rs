@@ -0,0 +1,597 @@
+//! Code-document benchmark suite
+//!
+//! Developer search products index notebooks, READMEs and lightweight-markup docs
+//! (`.ipynb`, `.md`, `.rst`, `.org`, `.adoc`), where the value of an extraction lies in
+//! its structure: headings must keep their hierarchy and code must stay in fenced
+//! blocks with its language. This suite runs Kreuzberg head-to-head with external
+//! converters on fixtures that carry a `code_document` section, timing each framework
+//! and scoring the structural fidelity of its markdown output.
+//!
+//! ## Fixture Format
+//!
+//! ```json
+//! {
+//!   "document": "../../../../test_documents/code_documents/pipeline_guide.md",
+//!   "file_type": "md",
+//!   "file_size": 450,
+//!   "expected_frameworks": ["kreuzberg", "markitdown", "pandoc-markdown"],
+//!   "code_document": {
+//!     "headings": [{"level": 1, "text": "Data Pipeline Guide"}],
+//!     "code_blocks": [{"language": "bash", "snippet": "pip install data-pipeline"}]
+//!   }
+//! }
+//! ```
+//!
+//! `expected_frameworks` selects which of the requested frameworks run a fixture.
+
+use crate::adapter::FrameworkAdapter;
+use crate::adapters::SubprocessAdapter;
+use crate::fixture::Fixture;
+use crate::{Error, Result};
+use kreuzberg::{ExtractionConfig, extract_file};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// File types the code-document suite accepts
+pub const CODE_DOCUMENT_FILE_TYPES: &[&str] = &["ipynb", "md", "markdown", "rst", "org", "adoc", "asciidoc"];
+
+/// Heading that must appear in the output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedHeading {
+    /// Markdown heading level (1 for `#`, 2 for `##`, ...)
+    pub level: usize,
+
+    /// Heading text
+    pub text: String,
+}
+
+/// Code block that must appear fenced in the output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedCodeBlock {
+    /// Expected fence info string (e.g. "python"); not checked when absent
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// Line of code that must appear inside the fenced block
+    pub snippet: String,
+}
+
+/// Structural expectations for a code-document fixture
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CodeDocumentExpectations {
+    /// Headings in document order
+    #[serde(default)]
+    pub headings: Vec<ExpectedHeading>,
+
+    /// Code blocks in document order
+    #[serde(default)]
+    pub code_blocks: Vec<ExpectedCodeBlock>,
+}
+
+/// Fenced code block found in extracted markdown
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FencedBlock {
+    /// First word of the fence info string, if any
+    pub language: Option<String>,
+
+    /// Block body without the fences
+    pub code: String,
+}
+
+/// Headings and fenced code blocks of extracted markdown
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentStructure {
+    /// ATX headings outside code blocks
+    pub headings: Vec<ExpectedHeading>,
+
+    /// Fenced code blocks (backtick or tilde fences)
+    pub code_blocks: Vec<FencedBlock>,
+}
+
+impl DocumentStructure {
+    /// Parse ATX headings and fenced code blocks from markdown content
+    pub fn parse(content: &str) -> Self {
+        let mut structure = Self::default();
+        let mut open_fence: Option<(String, Option<String>, Vec<&str>)> = None;
+
+        for line in content.lines() {
+            let trimmed = line.trim_start();
+
+            if let Some((fence, language, body)) = &mut open_fence {
+                if closes_fence(trimmed, fence) {
+                    structure.code_blocks.push(FencedBlock {
+                        language: language.take(),
+                        code: body.join("\n"),
+                    });
+                    open_fence = None;
+                } else {
+                    body.push(line);
+                }
+                continue;
+            }
+
+            if let Some(fence) = fence_marker(trimmed) {
+                let language = trimmed[fence.len()..]
+                    .split_whitespace()
+                    .next()
+                    .map(|info| info.trim_start_matches(['{', '.']).trim_end_matches('}').to_lowercase())
+                    .filter(|info| !info.is_empty());
+                open_fence = Some((fence, language, Vec::new()));
+                continue;
+            }
+
+            let level = trimmed.chars().take_while(|&c| c == '#').count();
+            if (1..=6).contains(&level) && trimmed[level..].starts_with([' ', '\t']) {
+                let text = trimmed[level..].trim().trim_end_matches('#').trim();
+                if !text.is_empty() {
+                    structure.headings.push(ExpectedHeading {
+                        level,
+                        text: text.to_string(),
+                    });
+                }
+            }
+        }
+
+        structure
+    }
+}
+
+/// Structural fidelity scores, each in the range 0.0-1.0
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StructuralFidelity {
+    /// Fraction of expected headings present as headings (matched in document order)
+    pub heading_recall: f64,
+
+    /// Fraction of matched headings at the expected level
+    pub heading_level_accuracy: f64,
+
+    /// Fraction of expected code snippets found inside fenced blocks
+    pub code_fence_recall: f64,
+
+    /// Fraction of matched code blocks tagged with the expected language
+    pub code_language_accuracy: f64,
+
+    /// Mean of the four scores above
+    pub score: f64,
+}
+
+/// Score extracted markdown against the fixture expectations
+///
+/// Categories without expectations score 1.0. Accuracy scores are relative to matched
+/// items, so a framework that drops every heading scores 0.0 recall but is not also
+/// penalised on level accuracy.
+pub fn evaluate_structure(expectations: &CodeDocumentExpectations, content: &str) -> StructuralFidelity {
+    let structure = DocumentStructure::parse(content);
+
+    let mut matched_headings = 0;
+    let mut correct_levels = 0;
+    let mut cursor = 0;
+    for expected in &expectations.headings {
+        let wanted = normalize_heading(&expected.text);
+        if let Some(offset) = structure.headings[cursor..]
+            .iter()
+            .position(|heading| normalize_heading(&heading.text) == wanted)
+        {
+            let found = &structure.headings[cursor + offset];
+            matched_headings += 1;
+            if found.level == expected.level {
+                correct_levels += 1;
+            }
+            cursor += offset + 1;
+        }
+    }
+
+    let mut matched_blocks = 0;
+    let mut correct_languages = 0;
+    for expected in &expectations.code_blocks {
+        if let Some(found) = structure
+            .code_blocks
+            .iter()
+            .find(|block| block.code.contains(expected.snippet.as_str()))
+        {
+            matched_blocks += 1;
+            let language_ok = expected
+                .language
+                .as_deref()
+                .is_none_or(|language| found.language.as_deref() == Some(language.to_lowercase().as_str()));
+            if language_ok {
+                correct_languages += 1;
+            }
+        }
+    }
+
+    let heading_recall = ratio(matched_headings, expectations.headings.len());
+    let heading_level_accuracy = ratio(correct_levels, matched_headings);
+    let code_fence_recall = ratio(matched_blocks, expectations.code_blocks.len());
+    let code_language_accuracy = ratio(correct_languages, matched_blocks);
+
+    StructuralFidelity {
+        heading_recall,
+        heading_level_accuracy,
+        code_fence_recall,
+        code_language_accuracy,
+        score: (heading_recall + heading_level_accuracy + code_fence_recall + code_language_accuracy) / 4.0,
+    }
+}
+
+/// Framework competing in the code-document suite
+pub enum CodeDocumentFramework {
+    /// In-process Kreuzberg extraction with the given configuration
+    Kreuzberg(ExtractionConfig),
+
+    /// External converter run through its wrapper script (reads `content` from its JSON output)
+    External(SubprocessAdapter),
+}
+
+impl CodeDocumentFramework {
+    /// Framework name, matched against fixture `expected_frameworks`
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Kreuzberg(_) => "kreuzberg",
+            Self::External(adapter) => adapter.name(),
+        }
+    }
+
+    async fn extract(&self, document: &Path, timeout: Duration) -> Result<(String, Duration)> {
+        match self {
+            Self::Kreuzberg(config) => {
+                let start = Instant::now();
+                let result = tokio::time::timeout(timeout, extract_file(document, None, config))
+                    .await
+                    .map_err(|_| Error::Timeout(format!("Extraction exceeded {:?}", timeout)))?
+                    .map_err(|e| Error::Benchmark(format!("Extraction failed: {}", e)))?;
+                Ok((result.content, start.elapsed()))
+            }
+            Self::External(adapter) => adapter.extract_content(document, timeout).await,
+        }
+    }
+}
+
+/// Benchmark and fidelity result for one framework on one fixture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeDocumentResult {
+    /// Framework that performed the extraction
+    pub framework: String,
+
+    /// Path to the fixture JSON file
+    pub fixture: PathBuf,
+
+    /// Path to the extracted document
+    pub document: PathBuf,
+
+    /// Document file type (e.g. "ipynb")
+    pub file_type: String,
+
+    /// Whether every iteration extracted successfully
+    pub success: bool,
+
+    /// Error message of the first failed iteration
+    pub error_message: Option<String>,
+
+    /// Mean extraction duration across iterations
+    pub duration: Duration,
+
+    /// Structural fidelity of the last extraction (absent if extraction failed)
+    pub fidelity: Option<StructuralFidelity>,
+}
+
+/// Aggregated results for one framework on one file type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeDocumentSummary {
+    /// Framework name
+    pub framework: String,
+
+    /// Document file type
+    pub file_type: String,
+
+    /// Number of fixtures run
+    pub fixtures: usize,
+
+    /// Number of fixtures extracted successfully
+    pub succeeded: usize,
+
+    /// Mean extraction duration in milliseconds (successful fixtures only)
+    pub mean_duration_ms: f64,
+
+    /// Mean structural fidelity score (failed extractions count as 0.0)
+    pub mean_fidelity: f64,
+}
+
+/// Complete code-document suite report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeDocumentReport {
+    /// Number of timed iterations per fixture and framework
+    pub iterations: usize,
+
+    /// Per-framework, per-fixture results
+    pub results: Vec<CodeDocumentResult>,
+
+    /// Per-framework, per-file-type aggregation, ordered by framework then file type
+    pub summaries: Vec<CodeDocumentSummary>,
+
+    /// Fixtures whose documents were not found
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Run the code-document suite for every fixture with a `code_document` section
+///
+/// Each fixture runs with the requested frameworks listed in its `expected_frameworks`.
+/// Extraction failures (including unsupported formats) are recorded in the results and
+/// do not abort the run.
+pub async fn run_code_document_suite(
+    fixtures: &[(PathBuf, Fixture)],
+    frameworks: &[CodeDocumentFramework],
+    iterations: usize,
+    timeout: Duration,
+) -> Result<CodeDocumentReport> {
+    let iterations = iterations.max(1);
+    let mut results = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (fixture_path, fixture) in fixtures {
+        let Some(expectations) = &fixture.code_document else {
+            continue;
+        };
+
+        let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+        let document = fixture.resolve_document_path(fixture_dir);
+        if !document.exists() {
+            skipped.push(fixture_path.clone());
+            continue;
+        }
+
+        for framework in frameworks
+            .iter()
+            .filter(|framework| fixture.expected_frameworks.iter().any(|name| name == framework.name()))
+        {
+            let mut durations = Vec::with_capacity(iterations);
+            let mut error_message = None;
+            let mut last_content = None;
+
+            for _ in 0..iterations {
+                match framework.extract(&document, timeout).await {
+                    Ok((content, duration)) => {
+                        durations.push(duration);
+                        last_content = Some(content);
+                    }
+                    Err(e) => {
+                        error_message = Some(e.to_string());
+                        break;
+                    }
+                }
+            }
+
+            let success = error_message.is_none();
+            let duration = if durations.is_empty() {
+                Duration::ZERO
+            } else {
+                durations.iter().sum::<Duration>() / durations.len() as u32
+            };
+
+            results.push(CodeDocumentResult {
+                framework: framework.name().to_string(),
+                fixture: fixture_path.clone(),
+                document: document.clone(),
+                file_type: fixture.file_type.clone(),
+                success,
+                error_message,
+                duration,
+                fidelity: last_content
+                    .filter(|_| success)
+                    .map(|content| evaluate_structure(expectations, &content)),
+            });
+        }
+    }
+
+    Ok(CodeDocumentReport {
+        iterations,
+        summaries: summarize_results(&results),
+        results,
+        skipped,
+    })
+}
+
+/// Aggregate results per framework and file type
+pub fn summarize_results(results: &[CodeDocumentResult]) -> Vec<CodeDocumentSummary> {
+    let mut groups: BTreeMap<(&str, &str), Vec<&CodeDocumentResult>> = BTreeMap::new();
+    for result in results {
+        groups
+            .entry((result.framework.as_str(), result.file_type.as_str()))
+            .or_default()
+            .push(result);
+    }
+
+    groups
+        .into_iter()
+        .map(|((framework, file_type), group)| {
+            let successful: Vec<_> = group.iter().filter(|result| result.success).collect();
+            let total_seconds: f64 = successful.iter().map(|result| result.duration.as_secs_f64()).sum();
+            let total_fidelity: f64 = group
+                .iter()
+                .filter_map(|result| result.fidelity.as_ref())
+                .map(|fidelity| fidelity.score)
+                .sum();
+
+            CodeDocumentSummary {
+                framework: framework.to_string(),
+                file_type: file_type.to_string(),
+                fixtures: group.len(),
+                succeeded: successful.len(),
+                mean_duration_ms: if successful.is_empty() {
+                    0.0
+                } else {
+                    total_seconds * 1000.0 / successful.len() as f64
+                },
+                mean_fidelity: total_fidelity / group.len() as f64,
+            }
+        })
+        .collect()
+}
+
+/// Write a code-document suite report to a JSON file
+pub fn write_code_document_report(report: &CodeDocumentReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize code-document report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Opening fence (three or more backticks or tildes) at the start of a line
+fn fence_marker(line: &str) -> Option<String> {
+    let marker = line.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let length = line.chars().take_while(|&c| c == marker).count();
+    (length >= 3).then(|| marker.to_string().repeat(length))
+}
+
+/// Closing fence: only the opening marker character, at least as long as the opening fence
+fn closes_fence(line: &str, fence: &str) -> bool {
+    let line = line.trim_end();
+    let marker = fence.chars().next();
+    line.len() >= fence.len() && line.chars().all(|c| Some(c) == marker)
+}
+
+fn normalize_heading(text: &str) -> String {
+    text.trim_matches(|c: char| c == '*' || c == '_' || c.is_whitespace())
+        .to_lowercase()
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        1.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUIDE: &str = "# Guide\n\nIntro\n\n## Install\n\n```bash\npip install tool\n```\n\n~~~~ Python\n# not a heading\ndef run():\n~~~~\n";
+
+    fn expectations() -> CodeDocumentExpectations {
+        CodeDocumentExpectations {
+            headings: vec![
+                ExpectedHeading {
+                    level: 1,
+                    text: "Guide".to_string(),
+                },
+                ExpectedHeading {
+                    level: 2,
+                    text: "Install".to_string(),
+                },
+            ],
+            code_blocks: vec![
+                ExpectedCodeBlock {
+                    language: Some("bash".to_string()),
+                    snippet: "pip install tool".to_string(),
+                },
+                ExpectedCodeBlock {
+                    language: Some("python".to_string()),
+                    snippet: "def run():".to_string(),
+                },
+            ],
+        }
+    }
+
+    fn result(framework: &str, duration_ms: u64, score: Option<f64>) -> CodeDocumentResult {
+        CodeDocumentResult {
+            framework: framework.to_string(),
+            fixture: PathBuf::from("fixture.json"),
+            document: PathBuf::from("guide.md"),
+            file_type: "md".to_string(),
+            success: score.is_some(),
+            error_message: None,
+            duration: Duration::from_millis(duration_ms),
+            fidelity: score.map(|score| StructuralFidelity {
+                score,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_parse_structure_ignores_headings_in_fences() {
+        let structure = DocumentStructure::parse(GUIDE);
+
+        assert_eq!(
+            structure.headings,
+            vec![
+                ExpectedHeading {
+                    level: 1,
+                    text: "Guide".to_string()
+                },
+                ExpectedHeading {
+                    level: 2,
+                    text: "Install".to_string()
+                },
+            ]
+        );
+        assert_eq!(structure.code_blocks.len(), 2);
+        assert_eq!(structure.code_blocks[0].language.as_deref(), Some("bash"));
+        assert_eq!(structure.code_blocks[1].language.as_deref(), Some("python"));
+        assert_eq!(structure.code_blocks[1].code, "# not a heading\ndef run():");
+    }
+
+    #[test]
+    fn test_evaluate_structure_perfect_output() {
+        let fidelity = evaluate_structure(&expectations(), GUIDE);
+
+        assert_eq!(fidelity.heading_recall, 1.0);
+        assert_eq!(fidelity.heading_level_accuracy, 1.0);
+        assert_eq!(fidelity.code_fence_recall, 1.0);
+        assert_eq!(fidelity.code_language_accuracy, 1.0);
+        assert_eq!(fidelity.score, 1.0);
+    }
+
+    #[test]
+    fn test_evaluate_structure_flattened_output() {
+        let content = "Guide\n\n### Install\n\npip install tool\n\n```\ndef run():\n```\n";
+
+        let fidelity = evaluate_structure(&expectations(), content);
+
+        assert_eq!(fidelity.heading_recall, 0.5);
+        assert_eq!(fidelity.heading_level_accuracy, 0.0);
+        assert_eq!(fidelity.code_fence_recall, 0.5);
+        assert_eq!(fidelity.code_language_accuracy, 0.0);
+        assert_eq!(fidelity.score, 0.25);
+    }
+
+    #[test]
+    fn test_summarize_results_per_framework() {
+        let results = vec![
+            result("pandoc-markdown", 30, Some(0.5)),
+            result("kreuzberg", 10, Some(1.0)),
+            result("kreuzberg", 30, None),
+        ];
+
+        let summaries = summarize_results(&results);
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].framework, "kreuzberg");
+        assert_eq!(summaries[0].fixtures, 2);
+        assert_eq!(summaries[0].succeeded, 1);
+        assert!((summaries[0].mean_duration_ms - 10.0).abs() < 1e-9);
+        assert!((summaries[0].mean_fidelity - 0.5).abs() < 1e-9);
+        assert_eq!(summaries[1].framework, "pandoc-markdown");
+        assert!((summaries[1].mean_fidelity - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_expectations_deserialize_with_defaults() {
+        let expectations: CodeDocumentExpectations =
+            serde_json::from_str(r#"{"code_blocks": [{"snippet": "x = 1"}]}"#).unwrap();
+
+        assert!(expectations.headings.is_empty());
+        assert_eq!(expectations.code_blocks[0].language, None);
+    }
+}
@@ -1,6 +1,8 @@
 //! Benchmark configuration
 
+use crate::scenario::BenchmarkScenario;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::time::Duration;
 
@@ -8,8 +10,10 @@ use std::time::Duration;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BenchmarkMode {
     /// Single-file mode: Sequential execution (max_concurrent=1) for fair latency comparison
+    #[serde(alias = "single-file")]
     SingleFile,
     /// Batch mode: Concurrent execution to measure throughput
+    #[serde(alias = "batch")]
     Batch,
 }
 
@@ -171,6 +175,9 @@ pub struct BenchmarkConfig {
     /// Number of warmup iterations (discarded from statistics)
     pub warmup_iterations: usize,
 
+    /// Warmup iterations per adapter, overriding `warmup_iterations` for JIT-heavy frameworks
+    pub adapter_warmup: BTreeMap<String, usize>,
+
     /// Number of benchmark iterations for statistical analysis
     pub benchmark_iterations: usize,
 
@@ -190,6 +197,7 @@ impl Default for BenchmarkConfig {
             sample_interval_ms: 10,
             benchmark_mode: BenchmarkMode::Batch,
             warmup_iterations: 1,
+            adapter_warmup: BTreeMap::new(),
             benchmark_iterations: 3,
             profiling: ProfilingConfig::default(),
         }
@@ -230,4 +238,49 @@ impl BenchmarkConfig {
 
         Ok(())
     }
+
+    /// Number of warmup iterations for an adapter
+    pub fn warmup_iterations_for(&self, adapter: &str) -> usize {
+        self.adapter_warmup
+            .get(adapter)
+            .copied()
+            .unwrap_or(self.warmup_iterations)
+    }
+
+    /// Configuration for one mode of a scenario
+    ///
+    /// Values the scenario sets replace those of `self`, and reports are written to
+    /// `<output_dir>/<mode>`. Single-file mode always runs with max_concurrent=1.
+    pub fn with_scenario(&self, scenario: &BenchmarkScenario, mode: BenchmarkMode) -> Self {
+        let max_concurrent = match mode {
+            BenchmarkMode::SingleFile => 1,
+            BenchmarkMode::Batch => scenario.max_concurrent.unwrap_or(self.max_concurrent),
+        };
+
+        Self {
+            max_file_size: scenario.max_file_size.or(self.max_file_size),
+            file_types: scenario.file_types.clone().or_else(|| self.file_types.clone()),
+            timeout: scenario.timeout_secs.map(Duration::from_secs).unwrap_or(self.timeout),
+            max_concurrent,
+            output_dir: self.output_dir.join(scenario_mode_dir(mode)),
+            benchmark_mode: mode,
+            warmup_iterations: scenario.warmup_iterations.unwrap_or(self.warmup_iterations),
+            adapter_warmup: self
+                .adapter_warmup
+                .iter()
+                .chain(&scenario.adapter_warmup)
+                .map(|(adapter, iterations)| (adapter.clone(), *iterations))
+                .collect(),
+            benchmark_iterations: scenario.iterations.unwrap_or(self.benchmark_iterations),
+            ..self.clone()
+        }
+    }
+}
+
+/// Output subdirectory of a scenario mode
+fn scenario_mode_dir(mode: BenchmarkMode) -> &'static str {
+    match mode {
+        BenchmarkMode::SingleFile => "single-file",
+        BenchmarkMode::Batch => "batch",
+    }
 }
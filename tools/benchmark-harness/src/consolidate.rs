@@ -761,9 +761,11 @@ mod tests {
             file_size: 1024,
             success,
             error_message: None,
+            failure: None,
             duration: Duration::from_millis(duration_ms),
             extraction_duration: None,
             subprocess_overhead: None,
+            overhead_breakdown: None,
             metrics: PerformanceMetrics {
                 peak_memory_bytes: 10_000_000,
                 avg_cpu_percent: 50.0,
@@ -776,9 +778,14 @@ mod tests {
             iterations: vec![],
             statistics: None,
             cold_start_duration: None,
+            warmup: None,
             file_extension: "pdf".to_string(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
         }
     }
 
@@ -0,0 +1,361 @@
+//! Cloud cost modeling for benchmark results
+//!
+//! This module converts measured resource usage (wall-clock time, CPU utilization and
+//! peak memory) into an estimated cloud bill per 1,000 documents. The pricing model is
+//! deliberately simple (vCPU-hours + GB-hours + a flat per-request fee) so that platform
+//! teams can plug in their own negotiated rates via a JSON pricing file.
+//!
+//! ## Pricing File Format
+//!
+//! ```json
+//! {
+//!   "name": "aws-fargate-us-east-1",
+//!   "vcpu_hour_usd": 0.04048,
+//!   "gb_hour_usd": 0.004445,
+//!   "per_request_usd": 0.0
+//! }
+//! ```
+
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Number of documents the headline cost figure is normalized to
+pub const DOCUMENTS_PER_UNIT: f64 = 1000.0;
+
+const SECONDS_PER_HOUR: f64 = 3600.0;
+const BYTES_PER_GB: f64 = 1_073_741_824.0;
+
+/// Cloud pricing model used to convert resource usage into dollars
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudPricing {
+    /// Human-readable name of the pricing model (e.g., "aws-fargate-us-east-1")
+    pub name: String,
+
+    /// Price of one vCPU for one hour in USD
+    pub vcpu_hour_usd: f64,
+
+    /// Price of one GB of memory for one hour in USD
+    pub gb_hour_usd: f64,
+
+    /// Flat fee charged per processed document in USD (e.g., serverless invocation cost)
+    #[serde(default)]
+    pub per_request_usd: f64,
+}
+
+impl Default for CloudPricing {
+    /// AWS Fargate on-demand (Linux/x86, us-east-1) list prices
+    fn default() -> Self {
+        Self {
+            name: "aws-fargate-us-east-1".to_string(),
+            vcpu_hour_usd: 0.04048,
+            gb_hour_usd: 0.004445,
+            per_request_usd: 0.0,
+        }
+    }
+}
+
+impl CloudPricing {
+    /// Load a pricing model from a JSON file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let contents = fs::read_to_string(path.as_ref()).map_err(Error::Io)?;
+        let pricing: CloudPricing = serde_json::from_str(&contents)?;
+        pricing.validate()?;
+        Ok(pricing)
+    }
+
+    /// Validate the pricing model
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::Config`] if any price is negative or not finite
+    pub fn validate(&self) -> Result<()> {
+        let prices = [
+            ("vcpu_hour_usd", self.vcpu_hour_usd),
+            ("gb_hour_usd", self.gb_hour_usd),
+            ("per_request_usd", self.per_request_usd),
+        ];
+
+        for (field, value) in prices {
+            if !value.is_finite() || value < 0.0 {
+                return Err(Error::Config(format!(
+                    "{} must be a non-negative number, got {}",
+                    field, value
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Estimated cost for a single framework/configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameworkCost {
+    /// Framework (and configuration variant) name
+    pub framework: String,
+
+    /// Number of successful extractions the estimate is based on
+    pub documents: usize,
+
+    /// Mean wall-clock seconds per document
+    pub wall_seconds_per_doc: f64,
+
+    /// Mean CPU-seconds per document (wall-clock × CPU utilization)
+    pub cpu_seconds_per_doc: f64,
+
+    /// Mean memory GB-seconds per document (peak memory × wall-clock)
+    pub memory_gb_seconds_per_doc: f64,
+
+    /// CPU component of the cost per 1k documents in USD
+    pub cpu_cost_per_1k_usd: f64,
+
+    /// Memory component of the cost per 1k documents in USD
+    pub memory_cost_per_1k_usd: f64,
+
+    /// Per-request component of the cost per 1k documents in USD
+    pub request_cost_per_1k_usd: f64,
+
+    /// Total estimated cost per 1k documents in USD
+    pub total_cost_per_1k_usd: f64,
+}
+
+/// Cost estimates for all frameworks under a single pricing model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostReport {
+    /// Pricing model the estimates were computed with
+    pub pricing: CloudPricing,
+
+    /// Per-framework estimates, sorted by total cost (cheapest first)
+    pub frameworks: Vec<FrameworkCost>,
+}
+
+/// Estimate per-1k-document costs for each framework
+///
+/// Only successful extractions are taken into account. Frameworks without any
+/// successful extraction are omitted from the report.
+///
+/// # Arguments
+/// * `results` - Benchmark results to analyze
+/// * `pricing` - Cloud pricing model
+pub fn estimate_costs(results: &[BenchmarkResult], pricing: &CloudPricing) -> CostReport {
+    let mut by_framework: BTreeMap<&str, Vec<&BenchmarkResult>> = BTreeMap::new();
+
+    for result in results.iter().filter(|r| r.success) {
+        by_framework.entry(result.framework.as_str()).or_default().push(result);
+    }
+
+    let mut frameworks: Vec<FrameworkCost> = by_framework
+        .into_iter()
+        .map(|(framework, results)| estimate_framework_cost(framework, &results, pricing))
+        .collect();
+
+    frameworks.sort_by(|a, b| {
+        a.total_cost_per_1k_usd
+            .partial_cmp(&b.total_cost_per_1k_usd)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    CostReport {
+        pricing: pricing.clone(),
+        frameworks,
+    }
+}
+
+/// Estimate cost for a single framework's successful results
+fn estimate_framework_cost(framework: &str, results: &[&BenchmarkResult], pricing: &CloudPricing) -> FrameworkCost {
+    let documents = results.len();
+    let count = documents.max(1) as f64;
+
+    let mut wall_seconds = 0.0;
+    let mut cpu_seconds = 0.0;
+    let mut memory_gb_seconds = 0.0;
+
+    for result in results {
+        let wall = result.duration.as_secs_f64();
+        wall_seconds += wall;
+        cpu_seconds += wall * (result.metrics.avg_cpu_percent.max(0.0) / 100.0);
+        memory_gb_seconds += wall * (result.metrics.peak_memory_bytes as f64 / BYTES_PER_GB);
+    }
+
+    let wall_seconds_per_doc = wall_seconds / count;
+    let cpu_seconds_per_doc = cpu_seconds / count;
+    let memory_gb_seconds_per_doc = memory_gb_seconds / count;
+
+    let cpu_cost_per_1k_usd = cpu_seconds_per_doc / SECONDS_PER_HOUR * pricing.vcpu_hour_usd * DOCUMENTS_PER_UNIT;
+    let memory_cost_per_1k_usd =
+        memory_gb_seconds_per_doc / SECONDS_PER_HOUR * pricing.gb_hour_usd * DOCUMENTS_PER_UNIT;
+    let request_cost_per_1k_usd = pricing.per_request_usd * DOCUMENTS_PER_UNIT;
+
+    FrameworkCost {
+        framework: framework.to_string(),
+        documents,
+        wall_seconds_per_doc,
+        cpu_seconds_per_doc,
+        memory_gb_seconds_per_doc,
+        cpu_cost_per_1k_usd,
+        memory_cost_per_1k_usd,
+        request_cost_per_1k_usd,
+        total_cost_per_1k_usd: cpu_cost_per_1k_usd + memory_cost_per_1k_usd + request_cost_per_1k_usd,
+    }
+}
+
+/// Write cost estimates to a JSON file
+///
+/// # Arguments
+/// * `results` - Benchmark results to analyze
+/// * `pricing` - Cloud pricing model
+/// * `output_path` - Path to output JSON file (e.g., "cost.json")
+pub fn write_cost_report(results: &[BenchmarkResult], pricing: &CloudPricing, output_path: &Path) -> Result<()> {
+    let report = estimate_costs(results, pricing);
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(&report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize cost report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FrameworkCapabilities, PerformanceMetrics};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn make_result(framework: &str, duration_ms: u64, cpu_percent: f64, peak_memory_bytes: u64) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("/tmp/test.pdf"),
+            file_size: 1000,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(duration_ms),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics {
+                peak_memory_bytes,
+                avg_cpu_percent: cpu_percent,
+                throughput_bytes_per_sec: 0.0,
+                p50_memory_bytes: 0,
+                p95_memory_bytes: 0,
+                p99_memory_bytes: 0,
+            },
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: FrameworkCapabilities::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        }
+    }
+
+    #[test]
+    fn test_estimate_costs_single_framework() {
+        let pricing = CloudPricing {
+            name: "test".to_string(),
+            vcpu_hour_usd: 3.6,
+            gb_hour_usd: 0.0,
+            per_request_usd: 0.0,
+        };
+        // 1s wall-clock at 100% CPU = 1 CPU-second = $0.001 per doc = $1 per 1k docs
+        let results = vec![make_result("fw", 1000, 100.0, 0)];
+
+        let report = estimate_costs(&results, &pricing);
+        assert_eq!(report.frameworks.len(), 1);
+
+        let cost = &report.frameworks[0];
+        assert_eq!(cost.documents, 1);
+        assert!((cost.cpu_seconds_per_doc - 1.0).abs() < 1e-9);
+        assert!((cost.total_cost_per_1k_usd - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimate_costs_memory_component() {
+        let pricing = CloudPricing {
+            name: "test".to_string(),
+            vcpu_hour_usd: 0.0,
+            gb_hour_usd: 3.6,
+            per_request_usd: 0.0,
+        };
+        // 2s wall-clock holding 1 GB = 2 GB-seconds
+        let results = vec![make_result("fw", 2000, 0.0, BYTES_PER_GB as u64)];
+
+        let report = estimate_costs(&results, &pricing);
+        let cost = &report.frameworks[0];
+        assert!((cost.memory_gb_seconds_per_doc - 2.0).abs() < 1e-9);
+        assert!((cost.memory_cost_per_1k_usd - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimate_costs_sorted_and_skips_failures() {
+        let pricing = CloudPricing::default();
+        let mut failed = make_result("broken", 1000, 100.0, 1_000_000);
+        failed.success = false;
+
+        let results = vec![
+            make_result("slow", 5000, 100.0, 100_000_000),
+            make_result("fast", 100, 50.0, 10_000_000),
+            failed,
+        ];
+
+        let report = estimate_costs(&results, &pricing);
+        let names: Vec<&str> = report.frameworks.iter().map(|f| f.framework.as_str()).collect();
+        assert_eq!(names, vec!["fast", "slow"]);
+    }
+
+    #[test]
+    fn test_per_request_fee() {
+        let pricing = CloudPricing {
+            name: "serverless".to_string(),
+            vcpu_hour_usd: 0.0,
+            gb_hour_usd: 0.0,
+            per_request_usd: 0.0002,
+        };
+        let report = estimate_costs(&[make_result("fw", 10, 10.0, 0)], &pricing);
+        assert!((report.frameworks[0].request_cost_per_1k_usd - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pricing_validation() {
+        let mut pricing = CloudPricing::default();
+        assert!(pricing.validate().is_ok());
+
+        pricing.gb_hour_usd = -1.0;
+        assert!(pricing.validate().is_err());
+
+        pricing.gb_hour_usd = f64::NAN;
+        assert!(pricing.validate().is_err());
+    }
+
+    #[test]
+    fn test_pricing_from_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("pricing.json");
+        fs::write(
+            &path,
+            r#"{"name": "custom", "vcpu_hour_usd": 0.05, "gb_hour_usd": 0.005}"#,
+        )
+        .unwrap();
+
+        let pricing = CloudPricing::from_file(&path).unwrap();
+        assert_eq!(pricing.name, "custom");
+        assert_eq!(pricing.per_request_usd, 0.0);
+    }
+}
@@ -0,0 +1,560 @@
+//! Side-by-side output diffs between frameworks
+//!
+//! Two frameworks can reach similar F1 scores on a fixture while dropping different
+//! paragraphs, reordering columns or mangling tables. To make such differences
+//! inspectable, `BenchmarkRunner` saves each framework's extracted text under the run's
+//! output directory, and the HTML report renders one side-by-side page per fixture and
+//! framework pair, with changed words highlighted inline.
+//!
+//! ## Layout
+//!
+//! ```text
+//! results/
+//! ├── index.html
+//! ├── outputs/
+//! │   ├── kreuzberg-native/
+//! │   │   └── annual_report-3f2a9c1d8e4b7a60.txt
+//! │   └── docling/
+//! │       └── annual_report-3f2a9c1d8e4b7a60.txt
+//! └── diffs/
+//!     └── annual_report-3f2a9c1d8e4b7a60--kreuzberg-native-vs-docling.html
+//! ```
+//!
+//! Each fixture is compared against a baseline framework: `kreuzberg-native` when it
+//! extracted the fixture, otherwise the first framework in alphabetical order.
+
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use minijinja::context;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory (relative to a run's output directory) holding extracted texts
+pub const OUTPUTS_DIR: &str = "outputs";
+
+/// Directory (relative to the report) holding the rendered diff pages
+pub const DIFFS_DIR: &str = "diffs";
+
+/// Framework preferred as the left-hand side of every diff
+const BASELINE_FRAMEWORK: &str = "kreuzberg-native";
+
+/// Upper bound on the LCS table size; larger inputs are diffed as a single replacement
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// Link from the HTML report to one rendered diff page
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FixtureDiff {
+    /// Fixture document name
+    pub fixture: String,
+
+    /// Framework shown on the left
+    pub baseline: String,
+
+    /// Framework shown on the right
+    pub framework: String,
+
+    /// Share of identical lines between both outputs (0.0-1.0)
+    pub similarity: f64,
+
+    /// Path of the diff page, relative to the report
+    pub path: String,
+}
+
+/// A run of text within a diff line
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Segment {
+    text: String,
+    changed: bool,
+}
+
+/// One row of the side-by-side view
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct DiffRow {
+    /// `equal`, `changed`, `removed` or `added`
+    kind: &'static str,
+    left_number: Option<usize>,
+    right_number: Option<usize>,
+    left: Vec<Segment>,
+    right: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// File name of a fixture's extracted text
+///
+/// The document stem keeps the name readable; the hash of the full path keeps documents
+/// with the same name in different fixture directories apart.
+pub fn output_file_name(file_path: &Path) -> String {
+    let stem = file_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    let stem: String = stem
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("{}-{:016x}.txt", stem, fnv1a(file_path.to_string_lossy().as_bytes()))
+}
+
+/// FNV-1a hash, stable across runs and platforms (unlike `DefaultHasher`)
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Write the extracted text of every successful result to `<run_dir>/outputs/<framework>/`
+///
+/// Returns the number of files written.
+pub fn write_extracted_outputs(results: &[BenchmarkResult], run_dir: &Path) -> Result<usize> {
+    let mut written = 0;
+
+    for result in results {
+        let Some(output) = result.extracted_output.as_ref().filter(|_| result.success) else {
+            continue;
+        };
+
+        let framework_dir = run_dir.join(OUTPUTS_DIR).join(&result.framework);
+        fs::create_dir_all(&framework_dir).map_err(Error::Io)?;
+        fs::write(framework_dir.join(output_file_name(&result.file_path)), &output.content).map_err(Error::Io)?;
+        written += 1;
+    }
+
+    Ok(written)
+}
+
+/// Find a saved extracted text in the first run directory that has it
+fn read_extracted_output(run_dirs: &[PathBuf], framework: &str, file_name: &str) -> Option<String> {
+    run_dirs
+        .iter()
+        .map(|dir| dir.join(OUTPUTS_DIR).join(framework).join(file_name))
+        .find_map(|path| fs::read_to_string(path).ok())
+}
+
+/// Render side-by-side diff pages for every fixture extracted by more than one framework
+///
+/// Extracted texts are looked up in the `outputs` directory of each of `run_dirs`; fixtures
+/// without saved texts for at least two frameworks are skipped. Pages are written to
+/// `<report_dir>/diffs/`.
+pub fn write_diff_pages(
+    results: &[BenchmarkResult],
+    run_dirs: &[PathBuf],
+    report_dir: &Path,
+) -> Result<Vec<FixtureDiff>> {
+    let mut fixtures: BTreeMap<&Path, Vec<&str>> = BTreeMap::new();
+    for result in results.iter().filter(|result| result.success) {
+        let frameworks = fixtures.entry(result.file_path.as_path()).or_default();
+        if !frameworks.contains(&result.framework.as_str()) {
+            frameworks.push(&result.framework);
+        }
+    }
+
+    let mut diffs = Vec::new();
+    for (file_path, mut frameworks) in fixtures {
+        let file_name = output_file_name(file_path);
+        let mut outputs: Vec<(&str, String)> = Vec::new();
+        frameworks.sort_by_key(|&framework| (framework != BASELINE_FRAMEWORK, framework));
+        for framework in frameworks {
+            if let Some(text) = read_extracted_output(run_dirs, framework, &file_name) {
+                outputs.push((framework, text));
+            }
+        }
+
+        let Some(((baseline, baseline_text), others)) = outputs.split_first() else {
+            continue;
+        };
+        let fixture = file_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| file_path.display().to_string());
+
+        for (framework, text) in others {
+            let rows = diff_lines(baseline_text, text);
+            let similarity = similarity(&rows);
+            let page_name = format!(
+                "{}--{}-vs-{}.html",
+                file_name.trim_end_matches(".txt"),
+                baseline,
+                framework
+            );
+
+            let html = render_diff_page(&fixture, baseline, framework, similarity, &rows)?;
+            let diffs_dir = report_dir.join(DIFFS_DIR);
+            fs::create_dir_all(&diffs_dir).map_err(Error::Io)?;
+            fs::write(diffs_dir.join(&page_name), html).map_err(Error::Io)?;
+
+            diffs.push(FixtureDiff {
+                fixture: fixture.clone(),
+                baseline: baseline.to_string(),
+                framework: framework.to_string(),
+                similarity,
+                path: format!("{}/{}", DIFFS_DIR, page_name),
+            });
+        }
+    }
+
+    Ok(diffs)
+}
+
+/// Render a single diff page
+fn render_diff_page(
+    fixture: &str,
+    baseline: &str,
+    framework: &str,
+    similarity: f64,
+    rows: &[DiffRow],
+) -> Result<String> {
+    let env = crate::html::get_template_env();
+    let template = env
+        .get_template("diff.html.jinja")
+        .map_err(|e| Error::Benchmark(format!("Diff template not found: {}", e)))?;
+
+    template
+        .render(context! {
+            fixture => fixture,
+            baseline => baseline,
+            framework => framework,
+            similarity => similarity,
+            rows => rows,
+        })
+        .map_err(|e| Error::Benchmark(format!("Diff template render failed: {}", e)))
+}
+
+/// Share of lines present unchanged in both outputs
+fn similarity(rows: &[DiffRow]) -> f64 {
+    let equal = rows.iter().filter(|row| row.kind == "equal").count();
+    let left = rows.iter().filter(|row| row.left_number.is_some()).count();
+    let right = rows.iter().filter(|row| row.right_number.is_some()).count();
+
+    if left + right == 0 {
+        1.0
+    } else {
+        (2 * equal) as f64 / (left + right) as f64
+    }
+}
+
+/// Shortest edit script between two sequences, via the longest common subsequence
+///
+/// Common prefixes and suffixes are trimmed first; when the remaining middle part is
+/// too large for the LCS table it is reported as deleted and re-inserted.
+fn diff_ops<T: PartialEq>(left: &[T], right: &[T]) -> Vec<Op> {
+    let prefix = left.iter().zip(right).take_while(|(a, b)| a == b).count();
+    let suffix = left[prefix..]
+        .iter()
+        .rev()
+        .zip(right[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &left[prefix..left.len() - suffix];
+    let b = &right[prefix..right.len() - suffix];
+
+    let mut ops = vec![Op::Equal; prefix];
+    if a.len().saturating_mul(b.len()) > MAX_DIFF_CELLS {
+        ops.extend(std::iter::repeat_n(Op::Delete, a.len()));
+        ops.extend(std::iter::repeat_n(Op::Insert, b.len()));
+    } else {
+        let width = b.len() + 1;
+        let mut lengths = vec![0usize; (a.len() + 1) * width];
+        for i in (0..a.len()).rev() {
+            for j in (0..b.len()).rev() {
+                lengths[i * width + j] = if a[i] == b[j] {
+                    lengths[(i + 1) * width + j + 1] + 1
+                } else {
+                    lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            if a[i] == b[j] {
+                ops.push(Op::Equal);
+                i += 1;
+                j += 1;
+            } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+                ops.push(Op::Delete);
+                i += 1;
+            } else {
+                ops.push(Op::Insert);
+                j += 1;
+            }
+        }
+        ops.extend(std::iter::repeat_n(Op::Delete, a.len() - i));
+        ops.extend(std::iter::repeat_n(Op::Insert, b.len() - j));
+    }
+    ops.extend(std::iter::repeat_n(Op::Equal, suffix));
+
+    ops
+}
+
+/// Split a line into alternating runs of whitespace and non-whitespace
+fn tokenize(line: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut previous_space = None;
+
+    for (idx, ch) in line.char_indices() {
+        let space = ch.is_whitespace();
+        if previous_space.is_some_and(|previous| previous != space) {
+            tokens.push(&line[start..idx]);
+            start = idx;
+        }
+        previous_space = Some(space);
+    }
+    if start < line.len() {
+        tokens.push(&line[start..]);
+    }
+
+    tokens
+}
+
+/// Append a token to a segment list, merging it with the previous segment when possible
+fn push_segment(segments: &mut Vec<Segment>, text: &str, changed: bool) {
+    match segments.last_mut() {
+        Some(last) if last.changed == changed => last.text.push_str(text),
+        _ => segments.push(Segment {
+            text: text.to_string(),
+            changed,
+        }),
+    }
+}
+
+/// Word-level diff of two lines that replace each other
+fn diff_words(left: &str, right: &str) -> (Vec<Segment>, Vec<Segment>) {
+    let left_tokens = tokenize(left);
+    let right_tokens = tokenize(right);
+    let (mut left_segments, mut right_segments) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+
+    for op in diff_ops(&left_tokens, &right_tokens) {
+        match op {
+            Op::Equal => {
+                push_segment(&mut left_segments, left_tokens[i], false);
+                push_segment(&mut right_segments, right_tokens[j], false);
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => {
+                push_segment(&mut left_segments, left_tokens[i], true);
+                i += 1;
+            }
+            Op::Insert => {
+                push_segment(&mut right_segments, right_tokens[j], true);
+                j += 1;
+            }
+        }
+    }
+
+    (left_segments, right_segments)
+}
+
+fn whole_line(text: &str, changed: bool) -> Vec<Segment> {
+    vec![Segment {
+        text: text.to_string(),
+        changed,
+    }]
+}
+
+/// Line-level side-by-side diff
+///
+/// Runs of deleted and inserted lines are paired up row by row and diffed word by word;
+/// unpaired lines are shown as removed or added.
+fn diff_lines(left: &str, right: &str) -> Vec<DiffRow> {
+    let left_lines: Vec<&str> = left.lines().collect();
+    let right_lines: Vec<&str> = right.lines().collect();
+    let ops = diff_ops(&left_lines, &right_lines);
+
+    let mut rows = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut idx = 0;
+    while idx < ops.len() {
+        if ops[idx] == Op::Equal {
+            rows.push(DiffRow {
+                kind: "equal",
+                left_number: Some(i + 1),
+                right_number: Some(j + 1),
+                left: whole_line(left_lines[i], false),
+                right: whole_line(right_lines[j], false),
+            });
+            i += 1;
+            j += 1;
+            idx += 1;
+            continue;
+        }
+
+        let run_end = ops[idx..]
+            .iter()
+            .position(|&op| op == Op::Equal)
+            .map_or(ops.len(), |offset| idx + offset);
+        let deleted = ops[idx..run_end].iter().filter(|&&op| op == Op::Delete).count();
+        let inserted = run_end - idx - deleted;
+
+        for offset in 0..deleted.max(inserted) {
+            let left_line = (offset < deleted).then(|| left_lines[i + offset]);
+            let right_line = (offset < inserted).then(|| right_lines[j + offset]);
+            let row = match (left_line, right_line) {
+                (Some(left_line), Some(right_line)) => {
+                    let (left, right) = diff_words(left_line, right_line);
+                    DiffRow {
+                        kind: "changed",
+                        left_number: Some(i + offset + 1),
+                        right_number: Some(j + offset + 1),
+                        left,
+                        right,
+                    }
+                }
+                (Some(left_line), None) => DiffRow {
+                    kind: "removed",
+                    left_number: Some(i + offset + 1),
+                    right_number: None,
+                    left: whole_line(left_line, true),
+                    right: vec![],
+                },
+                (None, Some(right_line)) => DiffRow {
+                    kind: "added",
+                    left_number: None,
+                    right_number: Some(j + offset + 1),
+                    left: vec![],
+                    right: whole_line(right_line, true),
+                },
+                (None, None) => unreachable!("offset is below deleted or inserted"),
+            };
+            rows.push(row);
+        }
+
+        i += deleted;
+        j += inserted;
+        idx = run_end;
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assertions::ExtractedOutput;
+    use crate::types::{FrameworkCapabilities, PerformanceMetrics};
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn result(framework: &str, content: &str) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("fixtures/report.pdf"),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: FrameworkCapabilities::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: Some(ExtractedOutput {
+                content: content.to_string(),
+                table_count: None,
+                page_count: None,
+                text_provenance: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_diff_lines_pairs_changed_lines() {
+        let rows = diff_lines(
+            "Title\nTotal assets 100\nFooter",
+            "Title\nTotal assets 120\nExtra\nFooter",
+        );
+        let kinds: Vec<&str> = rows.iter().map(|row| row.kind).collect();
+        assert_eq!(kinds, vec!["equal", "changed", "added", "equal"]);
+
+        assert_eq!(
+            rows[1].left,
+            vec![
+                Segment {
+                    text: "Total assets ".to_string(),
+                    changed: false,
+                },
+                Segment {
+                    text: "100".to_string(),
+                    changed: true,
+                },
+            ]
+        );
+        assert_eq!((rows[2].left_number, rows[2].right_number), (None, Some(3)));
+        assert_eq!((rows[3].left_number, rows[3].right_number), (Some(3), Some(4)));
+        assert!((similarity(&rows) - 4.0 / 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_diff_ops_identical_and_empty() {
+        assert_eq!(diff_ops(&["a", "b"], &["a", "b"]), vec![Op::Equal, Op::Equal]);
+        assert_eq!(diff_ops::<&str>(&[], &["a"]), vec![Op::Insert]);
+        assert_eq!(similarity(&diff_lines("", "")), 1.0);
+    }
+
+    #[test]
+    fn test_tokenize_round_trips() {
+        let line = "  Net\tincome  2024 ";
+        assert_eq!(tokenize(line), vec!["  ", "Net", "\t", "income", "  ", "2024", " "]);
+        assert_eq!(tokenize(line).concat(), line);
+    }
+
+    #[test]
+    fn test_write_diff_pages_against_baseline() {
+        let run_dir = TempDir::new().unwrap();
+        let results = [
+            result("docling", "Quarterly revenue"),
+            result("kreuzberg-native", "Quarterly revenue grew"),
+            result("unstructured", "Quarterly revenue grew"),
+        ];
+        assert_eq!(write_extracted_outputs(&results, run_dir.path()).unwrap(), 3);
+
+        let report_dir = TempDir::new().unwrap();
+        let diffs = write_diff_pages(&results, &[run_dir.path().to_path_buf()], report_dir.path()).unwrap();
+
+        assert_eq!(diffs.len(), 2);
+        assert!(diffs.iter().all(|diff| diff.baseline == "kreuzberg-native"));
+        assert_eq!(diffs[0].framework, "docling");
+        assert_eq!(diffs[0].similarity, 0.0);
+        assert_eq!(diffs[1].similarity, 1.0);
+
+        let page = fs::read_to_string(report_dir.path().join(&diffs[0].path)).unwrap();
+        assert!(page.contains("report.pdf"));
+        assert!(page.contains("grew"));
+    }
+
+    #[test]
+    fn test_output_file_name_is_stable() {
+        let name = output_file_name(Path::new("fixtures/pdfs/annual report.pdf"));
+        assert!(name.starts_with("annual_report-"));
+        assert_eq!(name, output_file_name(Path::new("fixtures/pdfs/annual report.pdf")));
+        assert_ne!(name, output_file_name(Path::new("fixtures/other/annual report.pdf")));
+    }
+}
@@ -37,6 +37,18 @@ pub enum Error {
         message: String,
     },
 
+    /// Downloaded or cached file does not match its manifest entry
+    #[error("Checksum mismatch for {path}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+
+    /// Corpus download error
+    #[error("Fetch error: {0}")]
+    Fetch(String),
+
     /// Configuration error
     #[error("Configuration error: {0}")]
     Config(String),
@@ -45,6 +57,18 @@ pub enum Error {
     #[error("Benchmark error: {0}")]
     Benchmark(String),
 
+    /// Adapter reported an extraction failure, optionally with a structured error code
+    #[error("{message}")]
+    AdapterFailed { code: Option<String>, message: String },
+
+    /// Adapter process crashed (killed by a signal, panicked or aborted)
+    #[error("Crash: {0}")]
+    Crash(String),
+
+    /// Subprocess adapter violated the adapter protocol
+    #[error("Adapter protocol error: {0}")]
+    Protocol(String),
+
     /// Timeout error
     #[error("Timeout: {0}")]
     Timeout(String),
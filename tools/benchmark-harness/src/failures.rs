@@ -0,0 +1,422 @@
+//! Failure taxonomy
+//!
+//! A single failure count hides why a framework failed: a framework that times out on large
+//! PDFs needs a different fix than one that crashes on malformed input or silently returns
+//! nothing. Every failed [`BenchmarkResult`] is therefore classified into a [`FailureKind`],
+//! and [`FailureTaxonomy`] breaks the failures down per framework and file extension.
+//!
+//! ## Classification
+//!
+//! Adapters classify failures from structured error codes wherever they have one:
+//!
+//! - the native adapter maps `KreuzbergError` variants
+//! - subprocess adapters may set `code` on protocol `error` frames (see
+//!   [`crate::adapter::protocol`]); Kreuzberg's FFI error code names (`timeout`,
+//!   `unsupported_format`, `panic`, ...) and the failure kind names below are understood
+//! - the harness itself reports `timeout` when it kills an adapter and `crash` when an
+//!   adapter process dies from a signal or a Rust panic
+//!
+//! Successful extractions that produce no text are reclassified as `empty_output` failures by
+//! `BenchmarkRunner`. Failures without a code, including results written by older harness
+//! versions, are classified from their error message.
+
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Why an extraction failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// The extraction exceeded its time budget
+    Timeout,
+    /// The framework or its process crashed (signal, panic, abort)
+    Crash,
+    /// The framework does not support the document's format
+    UnsupportedFormat,
+    /// The extraction succeeded but produced no text
+    EmptyOutput,
+    /// The document or the framework output could not be decoded
+    EncodingError,
+    /// Any other failure
+    Other,
+}
+
+/// Message fragments that identify a failure kind when no error code is available
+const MESSAGE_PATTERNS: &[(FailureKind, &[&str])] = &[
+    (
+        FailureKind::Timeout,
+        &["timeout", "timed out", "exceeded", "time budget"],
+    ),
+    (
+        FailureKind::Crash,
+        &[
+            "panic",
+            "segmentation fault",
+            "sigsegv",
+            "sigabrt",
+            "core dumped",
+            "killed by signal",
+        ],
+    ),
+    (
+        FailureKind::UnsupportedFormat,
+        &[
+            "unsupported format",
+            "unsupported file",
+            "not supported",
+            "unknown file type",
+            "mime type not allowed",
+        ],
+    ),
+    (
+        FailureKind::EncodingError,
+        &[
+            "utf-8",
+            "utf8",
+            "encoding",
+            "codec can't decode",
+            "unicodedecodeerror",
+            "invalid byte sequence",
+        ],
+    ),
+    (
+        FailureKind::EmptyOutput,
+        &["no content", "empty output", "produced no text"],
+    ),
+];
+
+impl FailureKind {
+    /// Every kind, in report column order
+    pub const ALL: [FailureKind; 6] = [
+        Self::Timeout,
+        Self::Crash,
+        Self::UnsupportedFormat,
+        Self::EmptyOutput,
+        Self::EncodingError,
+        Self::Other,
+    ];
+
+    /// Classify a structured error code
+    ///
+    /// Accepts the failure kind names and Kreuzberg's FFI error code names; unknown codes
+    /// are `Other`.
+    pub fn from_code(code: &str) -> Self {
+        match code.trim().to_ascii_lowercase().as_str() {
+            "timeout" => Self::Timeout,
+            "crash" | "panic" | "internal" => Self::Crash,
+            "unsupported_format" | "mime_type_not_allowed" => Self::UnsupportedFormat,
+            "empty_output" => Self::EmptyOutput,
+            "encoding_error" | "encoding" => Self::EncodingError,
+            _ => Self::Other,
+        }
+    }
+
+    /// Classify an error message by the fragments it contains
+    pub fn from_message(message: &str) -> Self {
+        let message = message.to_ascii_lowercase();
+        MESSAGE_PATTERNS
+            .iter()
+            .find(|(_, fragments)| fragments.iter().any(|fragment| message.contains(fragment)))
+            .map_or(Self::Other, |(kind, _)| *kind)
+    }
+
+    /// Classify a harness error
+    pub fn from_error(error: &Error) -> Self {
+        match error {
+            Error::Timeout(_) => Self::Timeout,
+            Error::Crash(_) => Self::Crash,
+            Error::AdapterFailed { code: Some(code), .. } => Self::from_code(code),
+            other => Self::from_message(&other.to_string()),
+        }
+    }
+
+    /// Classify an error returned by the Kreuzberg library
+    pub fn from_kreuzberg(error: &kreuzberg::KreuzbergError) -> Self {
+        use kreuzberg::{InputRejection, KreuzbergError};
+
+        match error {
+            KreuzbergError::Timeout { .. } => Self::Timeout,
+            KreuzbergError::UnsupportedFormat(_) => Self::UnsupportedFormat,
+            KreuzbergError::InputRejected {
+                reason: InputRejection::MimeTypeNotAllowed,
+                ..
+            } => Self::UnsupportedFormat,
+            KreuzbergError::LockPoisoned(_) => Self::Crash,
+            other => match Self::from_message(&other.to_string()) {
+                Self::EncodingError => Self::EncodingError,
+                _ => Self::Other,
+            },
+        }
+    }
+
+    /// Classify a benchmark result, `None` when it succeeded
+    pub fn of(result: &BenchmarkResult) -> Option<Self> {
+        if result.success {
+            return None;
+        }
+        Some(
+            result
+                .failure
+                .unwrap_or_else(|| result.error_message.as_deref().map_or(Self::Other, Self::from_message)),
+        )
+    }
+
+    /// Name used in reports
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Timeout => "timeout",
+            Self::Crash => "crash",
+            Self::UnsupportedFormat => "unsupported_format",
+            Self::EmptyOutput => "empty_output",
+            Self::EncodingError => "encoding_error",
+            Self::Other => "other",
+        }
+    }
+
+    /// Column header used in Markdown reports
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Timeout => "Timeout",
+            Self::Crash => "Crash",
+            Self::UnsupportedFormat => "Unsupported format",
+            Self::EmptyOutput => "Empty output",
+            Self::EncodingError => "Encoding error",
+            Self::Other => "Other",
+        }
+    }
+}
+
+/// Failure counts of one framework, or of one framework on one file extension
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FailureBreakdown {
+    /// Number of results
+    pub total: usize,
+
+    /// Number of failed results
+    pub failed: usize,
+
+    /// Failed results per kind; kinds without failures are omitted
+    pub by_kind: BTreeMap<FailureKind, usize>,
+}
+
+impl FailureBreakdown {
+    fn record(&mut self, failure: Option<FailureKind>) {
+        self.total += 1;
+        if let Some(kind) = failure {
+            self.failed += 1;
+            *self.by_kind.entry(kind).or_default() += 1;
+        }
+    }
+
+    /// Number of failures of `kind`
+    pub fn count(&self, kind: FailureKind) -> usize {
+        self.by_kind.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Failure counts as `timeout 2, crash 1`, empty when nothing failed
+    pub fn summary(&self) -> String {
+        self.by_kind
+            .iter()
+            .map(|(kind, count)| format!("{} {}", kind.as_str(), count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Failure taxonomy of one framework
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameworkFailures {
+    /// Framework name
+    pub framework: String,
+
+    /// Failures across all extensions
+    pub overall: FailureBreakdown,
+
+    /// Failures per file extension
+    pub by_extension: BTreeMap<String, FailureBreakdown>,
+}
+
+/// Failure taxonomy of a benchmark run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FailureTaxonomy {
+    /// Number of failed results
+    pub failed: usize,
+
+    /// Per-framework failures, sorted by framework name
+    pub frameworks: Vec<FrameworkFailures>,
+}
+
+impl FailureTaxonomy {
+    /// Classify the failures of benchmark results
+    pub fn from_results(results: &[BenchmarkResult]) -> Self {
+        let mut frameworks: BTreeMap<&str, FrameworkFailures> = BTreeMap::new();
+        let mut failed = 0;
+
+        for result in results {
+            let failure = FailureKind::of(result);
+            failed += usize::from(failure.is_some());
+
+            let framework = frameworks
+                .entry(result.framework.as_str())
+                .or_insert_with(|| FrameworkFailures {
+                    framework: result.framework.clone(),
+                    overall: FailureBreakdown::default(),
+                    by_extension: BTreeMap::new(),
+                });
+            framework.overall.record(failure);
+            framework
+                .by_extension
+                .entry(result.file_extension.clone())
+                .or_default()
+                .record(failure);
+        }
+
+        Self {
+            failed,
+            frameworks: frameworks.into_values().collect(),
+        }
+    }
+
+    /// Whether the run had no failures
+    pub fn is_empty(&self) -> bool {
+        self.failed == 0
+    }
+
+    /// Failures of `framework` on files with `extension`
+    pub fn breakdown(&self, framework: &str, extension: &str) -> Option<&FailureBreakdown> {
+        self.frameworks
+            .iter()
+            .find(|f| f.framework == framework)
+            .and_then(|f| f.by_extension.get(extension))
+    }
+}
+
+/// Write the failure taxonomy as JSON
+pub fn write_failure_report(taxonomy: &FailureTaxonomy, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(taxonomy)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize failure report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn result(
+        framework: &str,
+        extension: &str,
+        failure: Option<FailureKind>,
+        message: Option<&str>,
+    ) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from(format!("doc.{}", extension)),
+            file_size: 1024,
+            success: failure.is_none() && message.is_none(),
+            error_message: message.map(str::to_string),
+            failure,
+            duration: std::time::Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: Default::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: extension.to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        }
+    }
+
+    #[test]
+    fn test_classify_codes_and_errors() {
+        assert_eq!(FailureKind::from_code("timeout"), FailureKind::Timeout);
+        assert_eq!(FailureKind::from_code("panic"), FailureKind::Crash);
+        assert_eq!(
+            FailureKind::from_code("UNSUPPORTED_FORMAT"),
+            FailureKind::UnsupportedFormat
+        );
+        assert_eq!(FailureKind::from_code("encoding_error"), FailureKind::EncodingError);
+        assert_eq!(FailureKind::from_code("parsing"), FailureKind::Other);
+
+        assert_eq!(
+            FailureKind::from_error(&Error::Timeout("Subprocess exceeded 30s".to_string())),
+            FailureKind::Timeout
+        );
+        assert_eq!(
+            FailureKind::from_error(&Error::Crash("killed by signal 11".to_string())),
+            FailureKind::Crash
+        );
+        assert_eq!(
+            FailureKind::from_error(&Error::AdapterFailed {
+                code: Some("empty_output".to_string()),
+                message: "Adapter failed: nothing extracted".to_string(),
+            }),
+            FailureKind::EmptyOutput
+        );
+        assert_eq!(
+            FailureKind::from_error(&Error::AdapterFailed {
+                code: None,
+                message: "'utf-8' codec can't decode byte 0xff".to_string(),
+            }),
+            FailureKind::EncodingError
+        );
+        assert_eq!(
+            FailureKind::from_kreuzberg(&kreuzberg::KreuzbergError::UnsupportedFormat(
+                "application/x-foo".into()
+            )),
+            FailureKind::UnsupportedFormat
+        );
+    }
+
+    #[test]
+    fn test_taxonomy_per_framework_and_extension() {
+        let results = vec![
+            result("fast", "pdf", None, None),
+            result(
+                "fast",
+                "pdf",
+                Some(FailureKind::Timeout),
+                Some("Subprocess exceeded 30s"),
+            ),
+            result("fast", "docx", Some(FailureKind::Crash), Some("Subprocess crashed")),
+            result("slow", "pdf", None, Some("Unsupported format: application/pdf")),
+            result("slow", "pdf", None, None),
+        ];
+
+        let taxonomy = FailureTaxonomy::from_results(&results);
+
+        assert_eq!(taxonomy.failed, 3);
+        let fast = &taxonomy.frameworks[0];
+        assert_eq!(fast.framework, "fast");
+        assert_eq!(fast.overall.total, 3);
+        assert_eq!(fast.overall.failed, 2);
+        assert_eq!(fast.by_extension["pdf"].count(FailureKind::Timeout), 1);
+        assert_eq!(fast.by_extension["docx"].count(FailureKind::Crash), 1);
+        assert_eq!(fast.overall.summary(), "timeout 1, crash 1");
+
+        let slow = &taxonomy.frameworks[1];
+        assert_eq!(slow.overall.count(FailureKind::UnsupportedFormat), 1);
+        assert_eq!(taxonomy.breakdown("slow", "pdf").unwrap().failed, 1);
+        assert!(taxonomy.breakdown("slow", "docx").is_none());
+    }
+}
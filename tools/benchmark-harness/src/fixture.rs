@@ -21,7 +21,28 @@
 //!   }
 //! }
 //! ```
+//!
+//! Spreadsheet fixtures may additionally carry a `spreadsheet` section with correctness
+//! expectations; see [`crate::spreadsheet`]. Notebook and markup fixtures may carry a
+//! `code_document` section with structural expectations; see [`crate::code_documents`].
+//! Image-heavy PDF fixtures may carry an `image_heavy` section to run in the stage
+//! attribution suite; see [`crate::image_heavy`]. Fixtures with a `retrieval` question set
+//! run in the chunking retrieval experiment; see [`crate::chunking_retrieval`]. Any
+//! fixture may carry an `assertions` section with expected-output assertions checked for
+//! every framework; see [`crate::assertions`]. Results are scored against the ground truth
+//! separately for text-layer and OCR text, with `text_layer` declaring whether the document
+//! is born-digital; see [`crate::accuracy`].
+//!
+//! Public corpora too large to ship with the repository are downloaded from a manifest
+//! and turned into fixtures by [`fetch`].
 
+pub mod fetch;
+
+use crate::assertions::OutputAssertions;
+use crate::chunking_retrieval::RetrievalExpectations;
+use crate::code_documents::{CODE_DOCUMENT_FILE_TYPES, CodeDocumentExpectations};
+use crate::image_heavy::{IMAGE_HEAVY_FILE_TYPES, ImageHeavyExpectations};
+use crate::spreadsheet::{SPREADSHEET_FILE_TYPES, SpreadsheetExpectations};
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -51,6 +72,30 @@ pub struct Fixture {
     /// Ground truth for quality assessment (optional)
     #[serde(default)]
     pub ground_truth: Option<GroundTruth>,
+
+    /// Whether the document is born-digital (`true`) or scanned (`false`); see [`crate::accuracy`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_layer: Option<bool>,
+
+    /// Spreadsheet correctness expectations (only for spreadsheet file types)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spreadsheet: Option<SpreadsheetExpectations>,
+
+    /// Structural expectations (only for notebook and markup file types)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_document: Option<CodeDocumentExpectations>,
+
+    /// Stage attribution expectations (only for image-heavy PDFs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_heavy: Option<ImageHeavyExpectations>,
+
+    /// Question/answer set for the chunking retrieval experiment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retrieval: Option<RetrievalExpectations>,
+
+    /// Expected-output assertions checked for every framework
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertions: Option<OutputAssertions>,
 }
 
 /// Ground truth data for quality assessment
@@ -105,6 +150,54 @@ impl Fixture {
             }
         }
 
+        if self.spreadsheet.is_some() && !SPREADSHEET_FILE_TYPES.contains(&self.file_type.as_str()) {
+            return Err(Error::InvalidFixture {
+                path: fixture_path.to_path_buf(),
+                reason: format!(
+                    "spreadsheet expectations require a spreadsheet file_type, got {}",
+                    self.file_type
+                ),
+            });
+        }
+
+        if self.code_document.is_some() && !CODE_DOCUMENT_FILE_TYPES.contains(&self.file_type.as_str()) {
+            return Err(Error::InvalidFixture {
+                path: fixture_path.to_path_buf(),
+                reason: format!(
+                    "code_document expectations require a notebook or markup file_type, got {}",
+                    self.file_type
+                ),
+            });
+        }
+
+        if self.image_heavy.is_some() && !IMAGE_HEAVY_FILE_TYPES.contains(&self.file_type.as_str()) {
+            return Err(Error::InvalidFixture {
+                path: fixture_path.to_path_buf(),
+                reason: format!(
+                    "image_heavy expectations require a pdf file_type, got {}",
+                    self.file_type
+                ),
+            });
+        }
+
+        if self
+            .retrieval
+            .as_ref()
+            .is_some_and(|retrieval| retrieval.questions.is_empty())
+        {
+            return Err(Error::InvalidFixture {
+                path: fixture_path.to_path_buf(),
+                reason: "retrieval section must declare at least one question".to_string(),
+            });
+        }
+
+        if self.assertions.as_ref().is_some_and(OutputAssertions::is_empty) {
+            return Err(Error::InvalidFixture {
+                path: fixture_path.to_path_buf(),
+                reason: "assertions section must declare at least one assertion".to_string(),
+            });
+        }
+
         Ok(())
     }
 
@@ -117,6 +210,17 @@ impl Fixture {
     pub fn resolve_ground_truth_path(&self, fixture_dir: &Path) -> Option<PathBuf> {
         self.ground_truth.as_ref().map(|gt| fixture_dir.join(&gt.text_file))
     }
+
+    /// Whether the document is born-digital, as declared by `text_layer` or implied by a
+    /// ground truth taken from the PDF text layer
+    pub fn born_digital(&self) -> Option<bool> {
+        self.text_layer.or_else(|| {
+            self.ground_truth
+                .as_ref()
+                .is_some_and(|gt| gt.source == "pdf_text_layer")
+                .then_some(true)
+        })
+    }
 }
 
 /// Manages loading and accessing fixtures
@@ -278,6 +382,17 @@ impl FixtureManager {
             .cloned()
             .collect()
     }
+
+    /// Keep only fixtures of the given file types
+    pub fn retain_types(&mut self, file_types: &[String]) {
+        self.fixtures
+            .retain(|(_, fixture)| file_types.contains(&fixture.file_type));
+    }
+
+    /// Keep only fixtures whose document is at most `max_file_size` bytes
+    pub fn retain_max_size(&mut self, max_file_size: u64) {
+        self.fixtures.retain(|(_, fixture)| fixture.file_size <= max_file_size);
+    }
 }
 
 impl Default for FixtureManager {
@@ -304,6 +419,12 @@ mod tests {
             expected_frameworks: vec!["kreuzberg".to_string()],
             metadata: HashMap::new(),
             ground_truth: None,
+            text_layer: None,
+            spreadsheet: None,
+            code_document: None,
+            image_heavy: None,
+            retrieval: None,
+            assertions: None,
         };
 
         assert!(fixture.validate(Path::new("fixture.json")).is_ok());
@@ -323,9 +444,122 @@ mod tests {
             expected_frameworks: vec![],
             metadata: HashMap::new(),
             ground_truth: None,
+            text_layer: None,
+            spreadsheet: None,
+            code_document: None,
+            image_heavy: None,
+            retrieval: None,
+            assertions: None,
+        };
+
+        assert!(fixture.validate(Path::new("fixture.json")).is_err());
+    }
+
+    #[test]
+    fn test_spreadsheet_expectations_require_spreadsheet_type() {
+        let mut fixture = Fixture {
+            document: PathBuf::from("test.pdf"),
+            file_type: "pdf".to_string(),
+            file_size: 1024,
+            expected_frameworks: vec![],
+            metadata: HashMap::new(),
+            ground_truth: None,
+            text_layer: None,
+            spreadsheet: Some(SpreadsheetExpectations::default()),
+            code_document: None,
+            image_heavy: None,
+            retrieval: None,
+            assertions: None,
         };
 
         assert!(fixture.validate(Path::new("fixture.json")).is_err());
+
+        fixture.document = PathBuf::from("test.xlsx");
+        fixture.file_type = "xlsx".to_string();
+        assert!(fixture.validate(Path::new("fixture.json")).is_ok());
+    }
+
+    #[test]
+    fn test_code_document_expectations_require_markup_type() {
+        let mut fixture = Fixture {
+            document: PathBuf::from("test.xlsx"),
+            file_type: "xlsx".to_string(),
+            file_size: 1024,
+            expected_frameworks: vec![],
+            metadata: HashMap::new(),
+            ground_truth: None,
+            text_layer: None,
+            spreadsheet: None,
+            code_document: Some(CodeDocumentExpectations::default()),
+            image_heavy: None,
+            retrieval: None,
+            assertions: None,
+        };
+
+        assert!(fixture.validate(Path::new("fixture.json")).is_err());
+
+        fixture.document = PathBuf::from("test.ipynb");
+        fixture.file_type = "ipynb".to_string();
+        assert!(fixture.validate(Path::new("fixture.json")).is_ok());
+    }
+
+    #[test]
+    fn test_image_heavy_expectations_require_pdf_type() {
+        let mut fixture = Fixture {
+            document: PathBuf::from("test.docx"),
+            file_type: "docx".to_string(),
+            file_size: 1024,
+            expected_frameworks: vec![],
+            metadata: HashMap::new(),
+            ground_truth: None,
+            text_layer: None,
+            spreadsheet: None,
+            code_document: None,
+            image_heavy: Some(ImageHeavyExpectations::default()),
+            assertions: None,
+        };
+
+        assert!(fixture.validate(Path::new("fixture.json")).is_err());
+
+        fixture.document = PathBuf::from("test.pdf");
+        fixture.file_type = "pdf".to_string();
+        assert!(fixture.validate(Path::new("fixture.json")).is_ok());
+    }
+
+    #[test]
+    fn test_empty_assertions_rejected() {
+        let mut fixture = Fixture {
+            document: PathBuf::from("test.pdf"),
+            file_type: "pdf".to_string(),
+            file_size: 1024,
+            expected_frameworks: vec![],
+            metadata: HashMap::new(),
+            ground_truth: None,
+            text_layer: None,
+            spreadsheet: None,
+            code_document: None,
+            image_heavy: None,
+            retrieval: None,
+            assertions: Some(OutputAssertions::default()),
+        };
+
+        assert!(fixture.validate(Path::new("fixture.json")).is_err());
+
+        fixture.assertions = Some(OutputAssertions {
+            page_count: Some(1),
+            ..Default::default()
+        });
+        assert!(fixture.validate(Path::new("fixture.json")).is_ok());
+    }
+
+    #[test]
+    fn test_empty_retrieval_questions_rejected() {
+        let fixture: Fixture = serde_json::from_str(
+            r#"{"document": "doc.md", "file_type": "md", "file_size": 10, "retrieval": {"questions": []}}"#,
+        )
+        .unwrap();
+
+        assert!(fixture.validate(Path::new("fixture.json")).is_err());
     }
 
     #[test]
@@ -340,6 +574,12 @@ mod tests {
             expected_frameworks: vec![],
             metadata: HashMap::new(),
             ground_truth: None,
+            text_layer: None,
+            spreadsheet: None,
+            code_document: None,
+            image_heavy: None,
+            retrieval: None,
+            assertions: None,
         };
 
         std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
@@ -365,6 +605,12 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                text_layer: None,
+                spreadsheet: None,
+                code_document: None,
+                image_heavy: None,
+                retrieval: None,
+                assertions: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
@@ -417,6 +663,12 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                text_layer: None,
+                spreadsheet: None,
+                code_document: None,
+                image_heavy: None,
+                retrieval: None,
+                assertions: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
@@ -451,6 +703,12 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                text_layer: None,
+                spreadsheet: None,
+                code_document: None,
+                image_heavy: None,
+                retrieval: None,
+                assertions: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
@@ -491,6 +749,12 @@ mod tests {
                 expected_frameworks: vec![],
                 metadata: HashMap::new(),
                 ground_truth: None,
+                text_layer: None,
+                spreadsheet: None,
+                code_document: None,
+                image_heavy: None,
+                retrieval: None,
+                assertions: None,
             };
             std::fs::write(&fixture_path, serde_json::to_string(&fixture).unwrap()).unwrap();
         }
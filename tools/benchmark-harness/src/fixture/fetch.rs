@@ -0,0 +1,523 @@
+//! Download of public benchmark corpora
+//!
+//! Large corpora (Govdocs1 subsets, arXiv PDFs) are not checked into the repository.
+//! Instead, a corpus manifest lists where each document can be downloaded and its
+//! SHA-256 checksum, and [`fetch_corpus`] downloads the documents into a local cache.
+//! Cached documents are re-verified on every fetch, so a run always uses exactly the
+//! bytes the manifest describes. [`write_fixtures`] then writes a fixture for each
+//! document so the corpus can be benchmarked like any other fixture directory.
+//!
+//! ## Manifest Format
+//!
+//! ```json
+//! {
+//!   "name": "arxiv-sample",
+//!   "description": "arXiv PDFs with mathematical notation",
+//!   "files": [
+//!     {
+//!       "url": "https://arxiv.org/pdf/1706.03762v7",
+//!       "sha256": "<64 hex characters>",
+//!       "path": "arxiv/1706.03762v7.pdf",
+//!       "size": 2215244,
+//!       "file_type": "pdf",
+//!       "expected_frameworks": ["kreuzberg", "docling"],
+//!       "metadata": { "category": "academic" }
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `path` is where the document is stored, relative to `<cache_dir>/<name>/`. `size`,
+//! `file_type` (defaults to the extension of `path`), `expected_frameworks` and `metadata`
+//! are optional. Downloads use `curl`; checksums use `sha256sum` or `shasum -a 256`.
+
+use super::Fixture;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+/// Environment variable overriding the default corpus cache directory
+pub const CORPUS_CACHE_ENV: &str = "KREUZBERG_CORPUS_CACHE";
+
+/// A manifest describing a downloadable benchmark corpus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorpusManifest {
+    /// Corpus name, used as the cache subdirectory
+    pub name: String,
+
+    /// Human-readable description of the corpus
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Documents in the corpus
+    pub files: Vec<CorpusFile>,
+}
+
+/// A single downloadable document in a corpus manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorpusFile {
+    /// Download URL
+    pub url: String,
+
+    /// Expected SHA-256 checksum (hex-encoded)
+    pub sha256: String,
+
+    /// Storage path relative to the corpus cache directory
+    pub path: PathBuf,
+
+    /// Expected file size in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// File type for the generated fixture (defaults to the extension of `path`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_type: Option<String>,
+
+    /// Frameworks listed in the generated fixture
+    #[serde(default)]
+    pub expected_frameworks: Vec<String>,
+
+    /// Metadata copied into the generated fixture
+    #[serde(default)]
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// How a corpus document was obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FetchStatus {
+    /// A verified copy was already in the cache
+    Cached,
+    /// The document was downloaded and verified
+    Downloaded,
+}
+
+/// A verified corpus document on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchedFile {
+    /// Absolute path of the document
+    pub path: PathBuf,
+
+    /// File size in bytes
+    pub size: u64,
+
+    /// How the document was obtained
+    pub status: FetchStatus,
+}
+
+/// Result of fetching a corpus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchSummary {
+    /// Directory holding the corpus documents
+    pub corpus_dir: PathBuf,
+
+    /// Fetched documents, in manifest order
+    pub files: Vec<FetchedFile>,
+}
+
+impl FetchSummary {
+    /// Number of documents downloaded during this fetch
+    pub fn downloaded(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.status == FetchStatus::Downloaded)
+            .count()
+    }
+
+    /// Number of documents served from the cache
+    pub fn cached(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.status == FetchStatus::Cached)
+            .count()
+    }
+}
+
+impl CorpusManifest {
+    /// Load a corpus manifest from a JSON file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(Error::Io)?;
+        let manifest: CorpusManifest = serde_json::from_str(&contents)?;
+        manifest.validate(path)?;
+        Ok(manifest)
+    }
+
+    /// Validate the manifest
+    fn validate(&self, manifest_path: &Path) -> Result<()> {
+        let invalid = |reason: String| Error::InvalidFixture {
+            path: manifest_path.to_path_buf(),
+            reason,
+        };
+
+        if !is_safe_relative_path(Path::new(&self.name)) || self.name.contains(['/', '\\']) {
+            return Err(invalid(format!("invalid corpus name: {:?}", self.name)));
+        }
+
+        let mut seen = HashSet::new();
+        for file in &self.files {
+            if file.url.is_empty() {
+                return Err(invalid(format!("missing url for {}", file.path.display())));
+            }
+
+            if file.sha256.len() != 64 || !file.sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(invalid(format!(
+                    "sha256 for {} must be 64 hex characters",
+                    file.path.display()
+                )));
+            }
+
+            if !is_safe_relative_path(&file.path) {
+                return Err(invalid(format!(
+                    "path must be relative and stay inside the corpus directory: {}",
+                    file.path.display()
+                )));
+            }
+
+            if !seen.insert(&file.path) {
+                return Err(invalid(format!("duplicate path: {}", file.path.display())));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether a path is relative, non-empty and has no `..` components
+fn is_safe_relative_path(path: &Path) -> bool {
+    path.components().next().is_some() && path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
+/// Default corpus cache directory
+///
+/// Uses `KREUZBERG_CORPUS_CACHE` when set, then `$XDG_CACHE_HOME/kreuzberg/benchmark-corpora`,
+/// then `~/.cache/kreuzberg/benchmark-corpora`, falling back to the system temp directory.
+pub fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(CORPUS_CACHE_ENV).filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+
+    let cache_root = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+
+    cache_root.join("kreuzberg").join("benchmark-corpora")
+}
+
+/// Download every document of a corpus into `cache_dir`, verifying checksums
+///
+/// Documents already cached with a matching checksum are not downloaded again. Downloads
+/// are written to a `.part` file and only moved into place once verified, so an
+/// interrupted or corrupted download never ends up in the cache.
+pub fn fetch_corpus(manifest: &CorpusManifest, cache_dir: &Path) -> Result<FetchSummary> {
+    let corpus_dir = std::path::absolute(cache_dir.join(&manifest.name))?;
+    let mut files = Vec::with_capacity(manifest.files.len());
+
+    for file in &manifest.files {
+        let target = corpus_dir.join(&file.path);
+
+        let status = if target.is_file() && verify_file(&target, file).is_ok() {
+            FetchStatus::Cached
+        } else {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            let mut partial = target.clone().into_os_string();
+            partial.push(".part");
+            let partial = PathBuf::from(partial);
+
+            if let Err(err) = download(&file.url, &partial).and_then(|()| verify_file(&partial, file)) {
+                let _ = std::fs::remove_file(&partial);
+                return Err(err);
+            }
+            std::fs::rename(&partial, &target)?;
+            FetchStatus::Downloaded
+        };
+
+        files.push(FetchedFile {
+            size: std::fs::metadata(&target)?.len(),
+            path: target,
+            status,
+        });
+    }
+
+    Ok(FetchSummary { corpus_dir, files })
+}
+
+/// Write one fixture per fetched document into `fixtures_dir`
+///
+/// Fixture file names are derived from the document paths, and document paths are made
+/// relative to `fixtures_dir`. Returns the paths of the written fixtures.
+pub fn write_fixtures(manifest: &CorpusManifest, summary: &FetchSummary, fixtures_dir: &Path) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(fixtures_dir)?;
+    let fixtures_dir = std::path::absolute(fixtures_dir)?;
+    let mut written = Vec::with_capacity(summary.files.len());
+
+    for (file, fetched) in manifest.files.iter().zip(&summary.files) {
+        let document = pathdiff::diff_paths(&fetched.path, &fixtures_dir).ok_or_else(|| {
+            Error::Config(format!(
+                "Cannot express {} relative to {}",
+                fetched.path.display(),
+                fixtures_dir.display()
+            ))
+        })?;
+
+        let file_type = file.file_type.clone().unwrap_or_else(|| {
+            file.path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or_default()
+                .to_ascii_lowercase()
+        });
+
+        let mut metadata = file.metadata.clone();
+        metadata.insert("corpus".to_string(), serde_json::Value::from(manifest.name.as_str()));
+        metadata.insert("source_url".to_string(), serde_json::Value::from(file.url.as_str()));
+
+        let fixture = Fixture {
+            document,
+            file_type,
+            file_size: fetched.size,
+            expected_frameworks: file.expected_frameworks.clone(),
+            metadata,
+            ground_truth: None,
+            text_layer: None,
+            spreadsheet: None,
+            code_document: None,
+            image_heavy: None,
+            retrieval: None,
+            assertions: None,
+        };
+
+        let name: Vec<String> = file
+            .path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let fixture_path = fixtures_dir.join(format!("{}_{}.json", manifest.name, name.join("_")));
+
+        fixture.validate(&fixture_path)?;
+        std::fs::write(&fixture_path, serde_json::to_string_pretty(&fixture)?)?;
+        written.push(fixture_path);
+    }
+
+    Ok(written)
+}
+
+/// Download a URL to a file using curl
+fn download(url: &str, dest: &Path) -> Result<()> {
+    let curl = which::which("curl")
+        .map_err(|_| Error::Config("curl not found (required to download benchmark corpora)".to_string()))?;
+
+    let output = Command::new(curl)
+        .args([
+            "--fail",
+            "--location",
+            "--silent",
+            "--show-error",
+            "--retry",
+            "3",
+            "--output",
+        ])
+        .arg(dest)
+        .arg(url)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::Fetch(format!(
+            "Failed to download {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check a file against the size and checksum of a manifest entry
+fn verify_file(path: &Path, file: &CorpusFile) -> Result<()> {
+    if let Some(expected) = file.size {
+        let actual = std::fs::metadata(path)?.len();
+        if actual != expected {
+            return Err(Error::ChecksumMismatch {
+                path: path.to_path_buf(),
+                expected: format!("{} bytes", expected),
+                actual: format!("{} bytes", actual),
+            });
+        }
+    }
+
+    let actual = sha256_file(path)?;
+    if !actual.eq_ignore_ascii_case(&file.sha256) {
+        return Err(Error::ChecksumMismatch {
+            path: path.to_path_buf(),
+            expected: file.sha256.to_ascii_lowercase(),
+            actual,
+        });
+    }
+
+    Ok(())
+}
+
+/// Compute the hex-encoded SHA-256 checksum of a file
+pub fn sha256_file(path: &Path) -> Result<String> {
+    let mut command = if let Ok(sha256sum) = which::which("sha256sum") {
+        Command::new(sha256sum)
+    } else if let Ok(shasum) = which::which("shasum") {
+        let mut command = Command::new(shasum);
+        command.args(["-a", "256"]);
+        command
+    } else {
+        return Err(Error::Config(
+            "sha256sum or shasum not found (required to verify benchmark corpora)".to_string(),
+        ));
+    };
+
+    let output = command.arg(path).output()?;
+    if !output.status.success() {
+        return Err(Error::Fetch(format!(
+            "Failed to checksum {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .next()
+        .map(|hash| hash.trim_start_matches('\\').to_ascii_lowercase())
+        .ok_or_else(|| Error::Fetch(format!("Empty checksum output for {}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixtureManager;
+    use tempfile::TempDir;
+
+    const HELLO_SHA256: &str = "a948904f2f0f479b8f8197694b30184b0d2ed1c1cd2a1ec0fb85d299a192a447";
+
+    fn tools_available() -> bool {
+        which::which("curl").is_ok() && (which::which("sha256sum").is_ok() || which::which("shasum").is_ok())
+    }
+
+    fn manifest_for(source: &Path, sha256: &str) -> CorpusManifest {
+        CorpusManifest {
+            name: "sample".to_string(),
+            description: None,
+            files: vec![CorpusFile {
+                url: format!("file://{}", source.display()),
+                sha256: sha256.to_string(),
+                path: PathBuf::from("docs/hello.txt"),
+                size: Some(12),
+                file_type: None,
+                expected_frameworks: vec!["kreuzberg".to_string()],
+                metadata: HashMap::new(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_manifest_validation() {
+        let source = Path::new("/tmp/hello.txt");
+        let path = Path::new("manifest.json");
+
+        assert!(manifest_for(source, HELLO_SHA256).validate(path).is_ok());
+        assert!(manifest_for(source, "abc123").validate(path).is_err());
+
+        let mut manifest = manifest_for(source, HELLO_SHA256);
+        manifest.files[0].path = PathBuf::from("../outside.txt");
+        assert!(manifest.validate(path).is_err());
+
+        let mut manifest = manifest_for(source, HELLO_SHA256);
+        manifest.files.push(manifest.files[0].clone());
+        assert!(manifest.validate(path).is_err());
+
+        let mut manifest = manifest_for(source, HELLO_SHA256);
+        manifest.name = "../escape".to_string();
+        assert!(manifest.validate(path).is_err());
+    }
+
+    #[test]
+    fn test_fetch_corpus_downloads_then_uses_cache() {
+        if !tools_available() {
+            return;
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("hello.txt");
+        std::fs::write(&source, "hello world\n").unwrap();
+        let manifest = manifest_for(&source, HELLO_SHA256);
+        let cache_dir = temp_dir.path().join("cache");
+
+        let first = fetch_corpus(&manifest, &cache_dir).unwrap();
+        assert_eq!(first.downloaded(), 1);
+        assert_eq!(std::fs::read_to_string(&first.files[0].path).unwrap(), "hello world\n");
+
+        let second = fetch_corpus(&manifest, &cache_dir).unwrap();
+        assert_eq!(second.cached(), 1);
+
+        std::fs::write(&second.files[0].path, "tampered!!!\n").unwrap();
+        let third = fetch_corpus(&manifest, &cache_dir).unwrap();
+        assert_eq!(third.downloaded(), 1);
+        assert_eq!(std::fs::read_to_string(&third.files[0].path).unwrap(), "hello world\n");
+    }
+
+    #[test]
+    fn test_fetch_corpus_rejects_checksum_mismatch() {
+        if !tools_available() {
+            return;
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("hello.txt");
+        std::fs::write(&source, "hello world\n").unwrap();
+        let manifest = manifest_for(&source, &"0".repeat(64));
+        let cache_dir = temp_dir.path().join("cache");
+
+        let result = fetch_corpus(&manifest, &cache_dir);
+        assert!(matches!(result, Err(Error::ChecksumMismatch { .. })));
+        assert!(!cache_dir.join("sample/docs/hello.txt").exists());
+        assert!(!cache_dir.join("sample/docs/hello.txt.part").exists());
+    }
+
+    #[test]
+    fn test_write_fixtures_produces_loadable_fixtures() {
+        let temp_dir = TempDir::new().unwrap();
+        let document = temp_dir.path().join("cache/sample/docs/hello.txt");
+        std::fs::create_dir_all(document.parent().unwrap()).unwrap();
+        std::fs::write(&document, "hello world\n").unwrap();
+
+        let manifest = manifest_for(&document, HELLO_SHA256);
+        let summary = FetchSummary {
+            corpus_dir: temp_dir.path().join("cache/sample"),
+            files: vec![FetchedFile {
+                path: document.clone(),
+                size: 12,
+                status: FetchStatus::Cached,
+            }],
+        };
+        let fixtures_dir = temp_dir.path().join("fixtures");
+
+        let written = write_fixtures(&manifest, &summary, &fixtures_dir).unwrap();
+        assert_eq!(written, vec![fixtures_dir.join("sample_docs_hello.txt.json")]);
+
+        let mut manager = FixtureManager::new();
+        manager.load_fixture(&written[0]).unwrap();
+        let (fixture_path, fixture) = &manager.fixtures()[0];
+        assert_eq!(fixture.file_type, "txt");
+        assert_eq!(fixture.metadata["corpus"], "sample");
+        assert_eq!(
+            fixture
+                .resolve_document_path(fixture_path.parent().unwrap())
+                .canonicalize()
+                .unwrap(),
+            document.canonicalize().unwrap()
+        );
+    }
+}
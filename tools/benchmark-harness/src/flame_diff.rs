@@ -0,0 +1,651 @@
+//! Differential flamegraphs of one fixture set under two kreuzberg versions
+//!
+//! When a run flags a slowdown, the per-version flamegraphs show where time goes but not
+//! what changed. `flame-diff` profiles the same fixtures under a baseline and a candidate
+//! revision and renders one differential flamegraph per fixture: frames are sized by the
+//! candidate profile and colored red where they take a larger share of samples than in the
+//! baseline, blue where they shrank.
+//!
+//! Each revision is checked out into a git worktree and its harness is built with the
+//! `profiling` feature, as in [`crate::bisect`]. `run --profile` writes the folded stacks of
+//! every fixture next to its flamegraph; both revisions must include this support. Sample
+//! counts are compared as shares of each profile's total, so differences in amplification
+//! or machine speed between the two runs cancel out.
+//!
+//! ## Layout
+//!
+//! ```text
+//! flame-diff/
+//! ├── flame_diff.json
+//! ├── results.json        (candidate run, loadable with `visualize`)
+//! └── flame-diffs/
+//!     └── annual_report.svg
+//! ```
+//!
+//! `visualize` picks up `flame_diff.json` in its input directories and lists the
+//! differential flamegraphs in a "Flame Diffs" tab of the HTML report.
+
+use crate::bisect::{BISECT_FRAMEWORK, git, last_line, short};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Report file written next to the differential flamegraphs
+pub const FLAME_DIFF_REPORT: &str = "flame_diff.json";
+
+/// Directory (relative to the report) holding the differential flamegraphs
+pub const FLAME_DIFFS_DIR: &str = "flame-diffs";
+
+/// Functions listed per fixture
+const TOP_FUNCTIONS: usize = 10;
+
+/// Folded stacks of one profile: `root;...;leaf` -> sample count
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FoldedProfile {
+    /// Sample count per stack
+    pub stacks: BTreeMap<String, u64>,
+}
+
+impl FoldedProfile {
+    /// Parse folded stacks (`frame;frame;frame count` per line), summing repeated stacks
+    pub fn parse(folded: &str) -> Self {
+        let mut stacks = BTreeMap::new();
+        for line in folded.lines() {
+            let Some((stack, count)) = line.trim_end().rsplit_once(' ') else {
+                continue;
+            };
+            if let Ok(count) = count.parse::<u64>()
+                && !stack.is_empty()
+            {
+                *stacks.entry(stack.to_string()).or_insert(0) += count;
+            }
+        }
+        Self { stacks }
+    }
+
+    /// Total number of samples
+    pub fn total(&self) -> u64 {
+        self.stacks.values().sum()
+    }
+
+    /// Share of samples per function: (self, total), where self counts samples with the
+    /// function as leaf and total counts every stack the function appears in (once per stack)
+    fn function_shares(&self) -> HashMap<&str, (f64, f64)> {
+        let total = self.total().max(1) as f64;
+        let mut shares: HashMap<&str, (f64, f64)> = HashMap::new();
+        for (stack, &count) in &self.stacks {
+            let share = count as f64 / total;
+            let frames: Vec<&str> = stack.split(';').collect();
+            let mut seen = HashSet::new();
+            for frame in &frames {
+                if seen.insert(*frame) {
+                    shares.entry(frame).or_default().1 += share;
+                }
+            }
+            if let Some(leaf) = frames.last() {
+                shares.entry(leaf).or_default().0 += share;
+            }
+        }
+        shares
+    }
+}
+
+/// Change of one function's share of samples between the two profiles
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionDelta {
+    /// Function (frame) name
+    pub function: String,
+
+    /// Share of baseline samples spent in the function itself (0.0-1.0)
+    pub baseline_self: f64,
+
+    /// Share of candidate samples spent in the function itself (0.0-1.0)
+    pub candidate_self: f64,
+
+    /// Share of baseline samples with the function on the stack (0.0-1.0)
+    pub baseline_total: f64,
+
+    /// Share of candidate samples with the function on the stack (0.0-1.0)
+    pub candidate_total: f64,
+}
+
+impl FunctionDelta {
+    /// Change of the self share, positive when the function got more expensive
+    pub fn self_delta(&self) -> f64 {
+        self.candidate_self - self.baseline_self
+    }
+}
+
+/// Differential profile of one fixture
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureFlameDiff {
+    /// Fixture document name (file stem)
+    pub fixture: String,
+
+    /// Samples in the baseline profile
+    pub baseline_samples: u64,
+
+    /// Samples in the candidate profile
+    pub candidate_samples: u64,
+
+    /// Mean extraction duration under the baseline, in milliseconds
+    pub baseline_ms: Option<f64>,
+
+    /// Mean extraction duration under the candidate, in milliseconds
+    pub candidate_ms: Option<f64>,
+
+    /// Functions whose self share grew the most, largest increase first
+    pub regressions: Vec<FunctionDelta>,
+
+    /// Path of the differential flamegraph, relative to the report
+    pub flamegraph: String,
+}
+
+impl FixtureFlameDiff {
+    /// Relative duration change (`0.25` = 25% slower), when both durations are known
+    pub fn duration_change(&self) -> Option<f64> {
+        match (self.baseline_ms, self.candidate_ms) {
+            (Some(baseline), Some(candidate)) if baseline > 0.0 => Some(candidate / baseline - 1.0),
+            _ => None,
+        }
+    }
+}
+
+/// Complete flame-diff report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlameDiffReport {
+    /// Full hash of the baseline revision
+    pub baseline: String,
+
+    /// Full hash of the candidate revision
+    pub candidate: String,
+
+    /// Fixture file or directory that was profiled
+    pub fixtures: PathBuf,
+
+    /// Differential profiles, largest slowdown first
+    pub diffs: Vec<FixtureFlameDiff>,
+
+    /// Fixtures profiled under only one revision
+    pub unmatched: Vec<String>,
+}
+
+/// Settings of a flame-diff run
+#[derive(Debug, Clone)]
+pub struct FlameDiffConfig {
+    /// Git repository containing the revisions
+    pub repo: PathBuf,
+
+    /// Revision profiled as the baseline
+    pub baseline: String,
+
+    /// Revision compared against the baseline
+    pub candidate: String,
+
+    /// Fixture file or directory profiled under both revisions
+    pub fixtures: PathBuf,
+
+    /// Benchmark iterations per fixture
+    pub iterations: usize,
+
+    /// Timeout in seconds per extraction
+    pub timeout: u64,
+
+    /// Directory for worktrees, the shared target directory and per-revision profiles
+    pub work_dir: PathBuf,
+
+    /// Directory receiving the report, the flamegraphs and the candidate's results
+    pub output: PathBuf,
+}
+
+/// Profiles and benchmark results of one revision
+struct RevisionProfiles {
+    /// Fixture stem -> folded stacks
+    profiles: BTreeMap<String, FoldedProfile>,
+    /// Fixture stem -> mean duration in milliseconds
+    durations: HashMap<String, f64>,
+    /// The run's results.json
+    results_file: PathBuf,
+}
+
+/// Compare the profiles of one fixture
+///
+/// `flamegraph` is stored as the report-relative path of the rendered SVG.
+pub fn diff_profiles(
+    fixture: &str,
+    baseline: &FoldedProfile,
+    candidate: &FoldedProfile,
+    flamegraph: String,
+) -> FixtureFlameDiff {
+    let before = baseline.function_shares();
+    let after = candidate.function_shares();
+
+    let functions: HashSet<&str> = before.keys().chain(after.keys()).copied().collect();
+    let mut regressions: Vec<FunctionDelta> = functions
+        .into_iter()
+        .map(|function| {
+            let (baseline_self, baseline_total) = before.get(function).copied().unwrap_or_default();
+            let (candidate_self, candidate_total) = after.get(function).copied().unwrap_or_default();
+            FunctionDelta {
+                function: function.to_string(),
+                baseline_self,
+                candidate_self,
+                baseline_total,
+                candidate_total,
+            }
+        })
+        .filter(|delta| delta.self_delta() > 0.0)
+        .collect();
+    regressions.sort_by(|a, b| {
+        b.self_delta()
+            .total_cmp(&a.self_delta())
+            .then_with(|| a.function.cmp(&b.function))
+    });
+    regressions.truncate(TOP_FUNCTIONS);
+
+    FixtureFlameDiff {
+        fixture: fixture.to_string(),
+        baseline_samples: baseline.total(),
+        candidate_samples: candidate.total(),
+        baseline_ms: None,
+        candidate_ms: None,
+        regressions,
+        flamegraph,
+    }
+}
+
+/// Differential folded lines (`stack before after`) with the baseline scaled to the candidate's total
+pub fn differential_lines(baseline: &FoldedProfile, candidate: &FoldedProfile) -> Vec<String> {
+    let scale = candidate.total() as f64 / baseline.total().max(1) as f64;
+    let stacks: BTreeSet<&String> = baseline.stacks.keys().chain(candidate.stacks.keys()).collect();
+
+    stacks
+        .into_iter()
+        .map(|stack| {
+            let before = (baseline.stacks.get(stack).copied().unwrap_or(0) as f64 * scale).round() as u64;
+            let after = candidate.stacks.get(stack).copied().unwrap_or(0);
+            format!("{} {} {}", stack, before, after)
+        })
+        .collect()
+}
+
+/// Render the differential flamegraph of `baseline` and `candidate` as SVG
+pub fn render_differential_flamegraph(
+    fixture: &str,
+    baseline: &FoldedProfile,
+    candidate: &FoldedProfile,
+    output_path: &Path,
+) -> Result<()> {
+    let lines = differential_lines(baseline, candidate);
+
+    let mut options = inferno::flamegraph::Options::default();
+    options.title = format!("{}: candidate vs. baseline", fixture);
+    options.subtitle =
+        Some("Red frames take a larger share of samples than in the baseline, blue a smaller".to_string());
+    options.count_name = "samples".to_string();
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = fs::File::create(output_path)?;
+    inferno::flamegraph::from_lines(&mut options, lines.iter().map(String::as_str), file)
+        .map_err(|e| Error::Profiling(format!("Failed to render differential flamegraph: {}", e)))
+}
+
+/// Profile the fixtures under both revisions and write the report to `config.output`
+pub fn run_flame_diff(config: &FlameDiffConfig) -> Result<FlameDiffReport> {
+    let repo = fs::canonicalize(&config.repo)?;
+    let fixtures = fs::canonicalize(&config.fixtures)?;
+    fs::create_dir_all(&config.work_dir)?;
+    let work_dir = fs::canonicalize(&config.work_dir)?;
+
+    let baseline = git(
+        &repo,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", config.baseline)],
+    )?;
+    let candidate = git(
+        &repo,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", config.candidate)],
+    )?;
+
+    let before = profile_revision(&repo, &work_dir, &fixtures, &baseline, config)?;
+    let after = profile_revision(&repo, &work_dir, &fixtures, &candidate, config)?;
+
+    fs::create_dir_all(&config.output)?;
+    fs::copy(&after.results_file, config.output.join("results.json"))?;
+
+    let mut diffs = Vec::new();
+    for (fixture, candidate_profile) in &after.profiles {
+        let Some(baseline_profile) = before.profiles.get(fixture) else {
+            continue;
+        };
+
+        let flamegraph = format!("{}/{}.svg", FLAME_DIFFS_DIR, fixture);
+        render_differential_flamegraph(
+            fixture,
+            baseline_profile,
+            candidate_profile,
+            &config.output.join(&flamegraph),
+        )?;
+
+        let mut diff = diff_profiles(fixture, baseline_profile, candidate_profile, flamegraph);
+        diff.baseline_ms = before.durations.get(fixture).copied();
+        diff.candidate_ms = after.durations.get(fixture).copied();
+        diffs.push(diff);
+    }
+    diffs.sort_by(|a, b| {
+        b.duration_change()
+            .unwrap_or(f64::MIN)
+            .total_cmp(&a.duration_change().unwrap_or(f64::MIN))
+            .then_with(|| a.fixture.cmp(&b.fixture))
+    });
+
+    let unmatched = before
+        .profiles
+        .keys()
+        .filter(|fixture| !after.profiles.contains_key(*fixture))
+        .chain(
+            after
+                .profiles
+                .keys()
+                .filter(|fixture| !before.profiles.contains_key(*fixture)),
+        )
+        .cloned()
+        .collect();
+
+    let report = FlameDiffReport {
+        baseline,
+        candidate,
+        fixtures,
+        diffs,
+        unmatched,
+    };
+    write_flame_diff_report(&report, &config.output.join(FLAME_DIFF_REPORT))?;
+    Ok(report)
+}
+
+/// Build the profiling harness of `commit` in a worktree and profile the fixture set with it
+fn profile_revision(
+    repo: &Path,
+    work_dir: &Path,
+    fixtures: &Path,
+    commit: &str,
+    config: &FlameDiffConfig,
+) -> Result<RevisionProfiles> {
+    eprintln!("[flame-diff] profiling {}", short(commit));
+    let worktree = work_dir.join("worktrees").join(short(commit));
+    if worktree.exists() {
+        git(repo, &["worktree", "remove", "--force", &worktree.to_string_lossy()])?;
+    }
+    git(
+        repo,
+        &[
+            "worktree",
+            "add",
+            "--detach",
+            "--force",
+            &worktree.to_string_lossy(),
+            commit,
+        ],
+    )?;
+
+    let result = build_and_profile(&worktree, work_dir, fixtures, commit, config);
+
+    git(repo, &["worktree", "remove", "--force", &worktree.to_string_lossy()])?;
+
+    result
+}
+
+fn build_and_profile(
+    worktree: &Path,
+    work_dir: &Path,
+    fixtures: &Path,
+    commit: &str,
+    config: &FlameDiffConfig,
+) -> Result<RevisionProfiles> {
+    let target_dir = work_dir.join("target");
+
+    let build = Command::new("cargo")
+        .args([
+            "build",
+            "--release",
+            "--package",
+            "benchmark-harness",
+            "--features",
+            "profiling",
+            "--manifest-path",
+        ])
+        .arg(worktree.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .output()?;
+    if !build.status.success() {
+        return Err(Error::Benchmark(format!(
+            "Build of {} failed: {}",
+            short(commit),
+            last_line(&build.stderr)
+        )));
+    }
+
+    // Flamegraphs are written relative to the working directory of the run
+    let profile_dir = work_dir.join("profiles").join(short(commit));
+    if profile_dir.exists() {
+        fs::remove_dir_all(&profile_dir)?;
+    }
+    fs::create_dir_all(&profile_dir)?;
+    let output_dir = profile_dir.join("results");
+
+    let run = Command::new(target_dir.join("release").join("benchmark-harness"))
+        .arg("run")
+        .arg("--fixtures")
+        .arg(fixtures)
+        .args(["--frameworks", BISECT_FRAMEWORK, "--mode", "single-file", "--profile"])
+        .args(["--iterations", &config.iterations.to_string()])
+        .args(["--timeout", &config.timeout.to_string()])
+        .arg("--output")
+        .arg(&output_dir)
+        .env("ENABLE_PROFILING", "true")
+        .current_dir(&profile_dir)
+        .output()?;
+    if !run.status.success() {
+        return Err(Error::Benchmark(format!(
+            "Profiling {} failed: {}",
+            short(commit),
+            last_line(&run.stderr)
+        )));
+    }
+
+    let folded_dir = profile_dir
+        .join("flamegraphs")
+        .join(BISECT_FRAMEWORK)
+        .join("single-file");
+    let profiles = load_folded_profiles(&folded_dir)?;
+    if profiles.is_empty() {
+        return Err(Error::Profiling(format!(
+            "{} wrote no folded stacks to {}; both revisions need `run --profile` support",
+            short(commit),
+            folded_dir.display()
+        )));
+    }
+
+    let results_file = output_dir.join("results.json");
+    let results: Vec<Value> = serde_json::from_str(&fs::read_to_string(&results_file)?)?;
+
+    Ok(RevisionProfiles {
+        profiles,
+        durations: mean_durations(&results),
+        results_file,
+    })
+}
+
+/// Load every `<fixture>.folded` file of a directory, keyed by fixture stem
+pub fn load_folded_profiles(dir: &Path) -> Result<BTreeMap<String, FoldedProfile>> {
+    let mut profiles = BTreeMap::new();
+    if !dir.is_dir() {
+        return Ok(profiles);
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "folded")
+            && let Some(stem) = path.file_stem().and_then(|stem| stem.to_str())
+        {
+            profiles.insert(stem.to_string(), FoldedProfile::parse(&fs::read_to_string(&path)?));
+        }
+    }
+    Ok(profiles)
+}
+
+/// Mean duration per fixture stem of the successful native results of a results.json
+fn mean_durations(results: &[Value]) -> HashMap<String, f64> {
+    results
+        .iter()
+        .filter(|result| result["framework"] == BISECT_FRAMEWORK && result["success"] == true)
+        .filter_map(|result| {
+            let stem = Path::new(result["file_path"].as_str()?)
+                .file_stem()?
+                .to_str()?
+                .to_string();
+            let duration = result["statistics"].get("mean").unwrap_or(&result["duration"]);
+            let ms = duration.get("secs")?.as_f64()? * 1000.0 + duration.get("nanos")?.as_f64()? / 1_000_000.0;
+            Some((stem, ms))
+        })
+        .collect()
+}
+
+/// Write a flame-diff report to a JSON file
+pub fn write_flame_diff_report(report: &FlameDiffReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize flame-diff report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Copy the differential flamegraphs of every flame-diff report in `run_dirs` next to the HTML report
+///
+/// Returns the fixture diffs with flamegraph paths relative to `report_dir`.
+pub fn collect_flame_diffs(run_dirs: &[PathBuf], report_dir: &Path) -> Result<Vec<FixtureFlameDiff>> {
+    let mut collected = Vec::new();
+    for run_dir in run_dirs {
+        let report_file = run_dir.join(FLAME_DIFF_REPORT);
+        if !report_file.is_file() {
+            continue;
+        }
+
+        let report: FlameDiffReport = serde_json::from_str(&fs::read_to_string(&report_file)?)?;
+        let target_dir = report_dir.join(FLAME_DIFFS_DIR).join(short(&report.candidate));
+        fs::create_dir_all(&target_dir)?;
+
+        for mut diff in report.diffs {
+            let source = run_dir.join(&diff.flamegraph);
+            let Some(file_name) = source.file_name() else {
+                continue;
+            };
+            if !source.is_file() {
+                eprintln!("Warning: Differential flamegraph not found: {}", source.display());
+                continue;
+            }
+            fs::copy(&source, target_dir.join(file_name))?;
+            diff.flamegraph = format!(
+                "{}/{}/{}",
+                FLAME_DIFFS_DIR,
+                short(&report.candidate),
+                file_name.to_string_lossy()
+            );
+            collected.push(diff);
+        }
+    }
+    Ok(collected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const BASELINE: &str = "main;extract;parse_pdf 60\nmain;extract;render 30\nmain;extract;parse_pdf 10\nbogus line\n";
+    const CANDIDATE: &str = "main;extract;parse_pdf 50\nmain;extract;render 100\nmain;extract;layout 50\n";
+
+    #[test]
+    fn test_parse_sums_repeated_stacks() {
+        let profile = FoldedProfile::parse(BASELINE);
+
+        assert_eq!(profile.stacks.len(), 2);
+        assert_eq!(profile.stacks["main;extract;parse_pdf"], 70);
+        assert_eq!(profile.total(), 100);
+    }
+
+    #[test]
+    fn test_diff_ranks_functions_by_self_share_increase() {
+        let diff = diff_profiles(
+            "report",
+            &FoldedProfile::parse(BASELINE),
+            &FoldedProfile::parse(CANDIDATE),
+            "flame-diffs/report.svg".to_string(),
+        );
+
+        let functions: Vec<&str> = diff.regressions.iter().map(|delta| delta.function.as_str()).collect();
+        assert_eq!(functions, vec!["layout", "render"]);
+        assert!((diff.regressions[0].candidate_self - 0.25).abs() < 1e-9);
+        assert_eq!(diff.regressions[0].baseline_self, 0.0);
+        assert!((diff.regressions[1].self_delta() - 0.2).abs() < 1e-9);
+        assert_eq!(diff.candidate_samples, 200);
+    }
+
+    #[test]
+    fn test_differential_lines_scale_baseline() {
+        let lines = differential_lines(&FoldedProfile::parse(BASELINE), &FoldedProfile::parse(CANDIDATE));
+
+        assert_eq!(
+            lines,
+            vec![
+                "main;extract;layout 0 50",
+                "main;extract;parse_pdf 140 50",
+                "main;extract;render 60 100",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_copies_flamegraphs() {
+        let run_dir = tempdir().unwrap();
+        let report_dir = tempdir().unwrap();
+        let diff = diff_profiles(
+            "report",
+            &FoldedProfile::parse(BASELINE),
+            &FoldedProfile::parse(CANDIDATE),
+            format!("{}/report.svg", FLAME_DIFFS_DIR),
+        );
+        render_differential_flamegraph(
+            "report",
+            &FoldedProfile::parse(BASELINE),
+            &FoldedProfile::parse(CANDIDATE),
+            &run_dir.path().join(&diff.flamegraph),
+        )
+        .unwrap();
+        let report = FlameDiffReport {
+            baseline: "a".repeat(40),
+            candidate: "b".repeat(40),
+            fixtures: PathBuf::from("fixtures"),
+            diffs: vec![diff],
+            unmatched: Vec::new(),
+        };
+        write_flame_diff_report(&report, &run_dir.path().join(FLAME_DIFF_REPORT)).unwrap();
+
+        let collected = collect_flame_diffs(&[run_dir.path().to_path_buf()], report_dir.path()).unwrap();
+
+        assert_eq!(collected.len(), 1);
+        assert_eq!(collected[0].flamegraph, "flame-diffs/bbbbbbbbbbbb/report.svg");
+        let svg = fs::read_to_string(report_dir.path().join(&collected[0].flamegraph)).unwrap();
+        assert!(svg.contains("<svg"));
+    }
+}
@@ -0,0 +1,629 @@
+//! Deployment footprint of benchmarked frameworks
+//!
+//! Speed is only one part of what a framework costs to operate. Deploying it also means
+//! shipping the packages it installs, the system libraries it needs, the models it
+//! downloads on first use and the container image all of that ends up in. This module
+//! measures those sizes from a footprint manifest and reports them next to each
+//! framework's measured performance.
+//!
+//! Sizes are measured on the machine running the harness:
+//!
+//! - **Packages**: installed size of a PyPI distribution (`python3`), gem (`ruby`) or npm
+//!   package (`node`), or of a directory (`path`)
+//! - **System packages**: installed size reported by `dpkg-query` or `rpm`
+//! - **Models**: size of the directory or file a model was downloaded to
+//! - **Docker image**: image size and layer count reported by `docker image inspect`
+//!
+//! Anything that cannot be measured (tool missing, package not installed, model not yet
+//! downloaded) is reported with its error instead of failing the run.
+//!
+//! ## Manifest Format
+//!
+//! ```json
+//! {
+//!   "frameworks": {
+//!     "kreuzberg-python-sync": {
+//!       "packages": [{ "ecosystem": "pypi", "name": "kreuzberg" }],
+//!       "system_packages": ["tesseract-ocr", "tesseract-ocr-eng"],
+//!       "docker_image": "kreuzberg-bench/python:latest"
+//!     },
+//!     "docling": {
+//!       "packages": [{ "ecosystem": "pypi", "name": "docling" }, { "ecosystem": "pypi", "name": "torch" }],
+//!       "models": [{ "name": "layout", "path": "/root/.cache/docling/models" }]
+//!     }
+//!   }
+//! }
+//! ```
+
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Prints `<version>\t<bytes>` for the installed distribution named by the first argument
+const PYPI_PROBE: &str = r#"
+import importlib.metadata as metadata, sys
+dist = metadata.distribution(sys.argv[1])
+size = sum(path.stat().st_size for path in (f.locate() for f in dist.files or []) if path.is_file())
+print(f"{dist.version}\t{size}")
+"#;
+
+/// Prints `<version>\t<bytes>` for the installed gem named by the first argument
+const GEM_PROBE: &str = r##"
+spec = Gem::Specification.find_by_name(ARGV[0])
+size = Dir.glob(File.join(spec.full_gem_path, '**', '*'), File::FNM_DOTMATCH).select { |f| File.file?(f) }.sum { |f| File.size(f) }
+puts "#{spec.version}\t#{size}"
+"##;
+
+/// Prints `<version>\t<bytes>` for the npm package named by the first argument, resolved
+/// from the current directory
+const NPM_PROBE: &str = r#"
+const fs = require('fs');
+const path = require('path');
+const manifest = require.resolve(`${process.argv[1]}/package.json`, { paths: [process.cwd()] });
+const size = (dir) => fs.readdirSync(dir, { withFileTypes: true }).reduce((total, entry) => {
+  const full = path.join(dir, entry.name);
+  return total + (entry.isDirectory() ? size(full) : entry.isFile() ? fs.statSync(full).size : 0);
+}, 0);
+console.log(`${require(manifest).version}\t${size(path.dirname(manifest))}`);
+"#;
+
+/// Package registry a framework package is installed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageEcosystem {
+    /// Python distribution (wheel or sdist), measured with `python3`
+    Pypi,
+    /// Ruby gem, measured with `ruby`
+    Rubygems,
+    /// npm package, measured with `node`
+    Npm,
+    /// Any directory or file, e.g. a JAR or a Go binary
+    Path,
+}
+
+/// Package a framework installs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageSpec {
+    /// Registry the package comes from
+    pub ecosystem: PackageEcosystem,
+
+    /// Package name (a display name for `path` packages)
+    pub name: String,
+
+    /// Location to measure; required for `path` packages
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+}
+
+/// Model a framework downloads on first use
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelSpec {
+    /// Display name of the model
+    pub name: String,
+
+    /// Directory or file the model is downloaded to
+    pub path: PathBuf,
+}
+
+/// Everything a framework needs at deployment
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FrameworkDependencies {
+    /// Language packages the framework installs, including heavy transitive ones
+    #[serde(default)]
+    pub packages: Vec<PackageSpec>,
+
+    /// Operating system packages the framework requires
+    #[serde(default)]
+    pub system_packages: Vec<String>,
+
+    /// Models downloaded on first use
+    #[serde(default)]
+    pub models: Vec<ModelSpec>,
+
+    /// Docker image the framework is deployed in
+    #[serde(default)]
+    pub docker_image: Option<String>,
+}
+
+/// Deployment dependencies of each framework, keyed by framework name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FootprintManifest {
+    pub frameworks: BTreeMap<String, FrameworkDependencies>,
+}
+
+impl FootprintManifest {
+    /// Load a footprint manifest from a JSON file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let contents = fs::read_to_string(path.as_ref()).map_err(Error::Io)?;
+        let manifest: FootprintManifest = serde_json::from_str(&contents)?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Validate the manifest
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::Config`] for empty names and `path` packages without a path
+    pub fn validate(&self) -> Result<()> {
+        for (framework, dependencies) in &self.frameworks {
+            let mut names = dependencies
+                .packages
+                .iter()
+                .map(|package| package.name.as_str())
+                .chain(dependencies.system_packages.iter().map(String::as_str))
+                .chain(dependencies.models.iter().map(|model| model.name.as_str()));
+            if framework.trim().is_empty() || names.any(|name| name.trim().is_empty()) {
+                return Err(Error::Config(format!(
+                    "Footprint manifest entry '{}' has an empty name",
+                    framework
+                )));
+            }
+            if let Some(package) = dependencies
+                .packages
+                .iter()
+                .find(|package| package.ecosystem == PackageEcosystem::Path && package.path.is_none())
+            {
+                return Err(Error::Config(format!(
+                    "Package '{}' of '{}' has ecosystem 'path' but no path",
+                    package.name, framework
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Measured size of a package, system package or model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArtifactSize {
+    /// Package or model name
+    pub name: String,
+
+    /// Installed version, when the source reports one
+    pub version: Option<String>,
+
+    /// Size in bytes, `None` when it could not be measured
+    pub size_bytes: Option<u64>,
+
+    /// Why the size could not be measured
+    pub error: Option<String>,
+}
+
+impl ArtifactSize {
+    fn measured(name: &str, outcome: std::result::Result<(Option<String>, u64), String>) -> Self {
+        match outcome {
+            Ok((version, size_bytes)) => Self {
+                name: name.to_string(),
+                version,
+                size_bytes: Some(size_bytes),
+                error: None,
+            },
+            Err(error) => Self {
+                name: name.to_string(),
+                version: None,
+                size_bytes: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+/// Size of a framework's Docker image
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DockerImageSize {
+    /// Image reference
+    pub image: String,
+
+    /// Uncompressed image size in bytes, `None` when it could not be measured
+    pub size_bytes: Option<u64>,
+
+    /// Number of filesystem layers
+    pub layers: Option<usize>,
+
+    /// Why the image could not be measured
+    pub error: Option<String>,
+}
+
+/// Performance of a framework in the same run, to read next to its footprint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FootprintPerformance {
+    /// Number of successful extractions
+    pub documents: usize,
+
+    /// Mean wall-clock milliseconds per successful extraction
+    pub mean_duration_ms: f64,
+
+    /// Highest peak memory of any successful extraction in bytes
+    pub peak_memory_bytes: u64,
+}
+
+/// Deployment footprint of one framework
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameworkFootprint {
+    /// Framework name
+    pub framework: String,
+
+    /// Installed language packages
+    pub packages: Vec<ArtifactSize>,
+
+    /// Installed system packages
+    pub system_packages: Vec<ArtifactSize>,
+
+    /// Downloaded models
+    pub models: Vec<ArtifactSize>,
+
+    /// Docker image, when the manifest names one
+    pub docker_image: Option<DockerImageSize>,
+
+    /// Sum of the measured package, system package and model sizes in bytes
+    pub total_bytes: u64,
+
+    /// Number of packages, system packages and models that could not be measured
+    pub unmeasured: usize,
+
+    /// Performance in the same run, `None` without successful results
+    pub performance: Option<FootprintPerformance>,
+}
+
+/// Deployment footprints of all frameworks in a manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FootprintReport {
+    /// Per-framework footprints, sorted by total size (smallest first)
+    pub frameworks: Vec<FrameworkFootprint>,
+}
+
+/// Measure the footprint of every framework in `manifest`
+///
+/// Performance is taken from the successful `results` of each framework, so the report
+/// can be read as "this fast, at this deployment size".
+///
+/// # Arguments
+/// * `manifest` - Deployment dependencies per framework
+/// * `results` - Benchmark results of the same run (may be empty)
+pub fn measure_footprints(manifest: &FootprintManifest, results: &[BenchmarkResult]) -> FootprintReport {
+    let mut frameworks: Vec<FrameworkFootprint> = manifest
+        .frameworks
+        .iter()
+        .map(|(framework, dependencies)| {
+            let packages = dependencies.packages.iter().map(measure_package).collect();
+            let system_packages = dependencies
+                .system_packages
+                .iter()
+                .map(|name| ArtifactSize::measured(name, system_package_size(name)))
+                .collect();
+            let models = dependencies
+                .models
+                .iter()
+                .map(|model| ArtifactSize::measured(&model.name, path_size(&model.path).map(|size| (None, size))))
+                .collect();
+            let docker_image = dependencies.docker_image.as_deref().map(measure_docker_image);
+            summarize(framework, packages, system_packages, models, docker_image, results)
+        })
+        .collect();
+
+    frameworks.sort_by_key(|footprint| footprint.total_bytes);
+
+    FootprintReport { frameworks }
+}
+
+/// Total the measured sizes and attach the framework's performance
+fn summarize(
+    framework: &str,
+    packages: Vec<ArtifactSize>,
+    system_packages: Vec<ArtifactSize>,
+    models: Vec<ArtifactSize>,
+    docker_image: Option<DockerImageSize>,
+    results: &[BenchmarkResult],
+) -> FrameworkFootprint {
+    let artifacts = packages.iter().chain(&system_packages).chain(&models);
+    let (total_bytes, unmeasured) = artifacts.fold((0, 0), |(total, unmeasured), artifact| match artifact.size_bytes {
+        Some(size) => (total + size, unmeasured),
+        None => (total, unmeasured + 1),
+    });
+
+    let successful: Vec<&BenchmarkResult> = results
+        .iter()
+        .filter(|result| result.success && result.framework == framework)
+        .collect();
+    let performance = (!successful.is_empty()).then(|| FootprintPerformance {
+        documents: successful.len(),
+        mean_duration_ms: successful
+            .iter()
+            .map(|result| result.duration.as_secs_f64() * 1000.0)
+            .sum::<f64>()
+            / successful.len() as f64,
+        peak_memory_bytes: successful
+            .iter()
+            .map(|result| result.metrics.peak_memory_bytes)
+            .max()
+            .unwrap_or(0),
+    });
+
+    FrameworkFootprint {
+        framework: framework.to_string(),
+        packages,
+        system_packages,
+        models,
+        docker_image,
+        total_bytes,
+        unmeasured,
+        performance,
+    }
+}
+
+fn measure_package(package: &PackageSpec) -> ArtifactSize {
+    let outcome = match (package.ecosystem, package.path.as_deref()) {
+        (_, Some(path)) => path_size(path).map(|size| (None, size)),
+        (PackageEcosystem::Pypi, None) => probe("python3", &["-c", PYPI_PROBE, &package.name]),
+        (PackageEcosystem::Rubygems, None) => probe("ruby", &["-e", GEM_PROBE, &package.name]),
+        (PackageEcosystem::Npm, None) => probe("node", &["-e", NPM_PROBE, &package.name]),
+        (PackageEcosystem::Path, None) => Err("no path given".to_string()),
+    };
+    ArtifactSize::measured(&package.name, outcome)
+}
+
+/// Run a probe script and parse its `<version>\t<bytes>` output
+fn probe(program: &str, args: &[&str]) -> std::result::Result<(Option<String>, u64), String> {
+    parse_version_and_size(&command_output(program, args)?, 1)
+}
+
+/// Installed size of a system package via `dpkg-query` (KiB) or `rpm` (bytes)
+fn system_package_size(name: &str) -> std::result::Result<(Option<String>, u64), String> {
+    if which::which("dpkg-query").is_ok() {
+        let output = command_output("dpkg-query", &["-W", "-f", "${Version}\t${Installed-Size}", name])?;
+        parse_version_and_size(&output, 1024)
+    } else if which::which("rpm").is_ok() {
+        let output = command_output("rpm", &["-q", "--qf", "%{VERSION}\t%{SIZE}", name])?;
+        parse_version_and_size(&output, 1)
+    } else {
+        Err("neither dpkg-query nor rpm is available".to_string())
+    }
+}
+
+fn measure_docker_image(image: &str) -> DockerImageSize {
+    let outcome = command_output(
+        "docker",
+        &[
+            "image",
+            "inspect",
+            "--format",
+            "{{.Size}}\t{{len .RootFS.Layers}}",
+            image,
+        ],
+    )
+    .and_then(|output| {
+        let (size, layers) = output
+            .split_once('\t')
+            .ok_or_else(|| format!("unexpected docker output: {}", output))?;
+        let size = size
+            .trim()
+            .parse::<u64>()
+            .map_err(|e| format!("invalid image size: {}", e))?;
+        let layers = layers
+            .trim()
+            .parse::<usize>()
+            .map_err(|e| format!("invalid layer count: {}", e))?;
+        Ok((size, layers))
+    });
+
+    match outcome {
+        Ok((size_bytes, layers)) => DockerImageSize {
+            image: image.to_string(),
+            size_bytes: Some(size_bytes),
+            layers: Some(layers),
+            error: None,
+        },
+        Err(error) => DockerImageSize {
+            image: image.to_string(),
+            size_bytes: None,
+            layers: None,
+            error: Some(error),
+        },
+    }
+}
+
+/// Parse `<version>\t<size>` and multiply the size by `unit` bytes
+fn parse_version_and_size(output: &str, unit: u64) -> std::result::Result<(Option<String>, u64), String> {
+    let (version, size) = output
+        .trim_end()
+        .split_once('\t')
+        .ok_or_else(|| format!("unexpected output: {}", output.trim()))?;
+    let size = size
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| format!("invalid size '{}': {}", size.trim(), e))?;
+    let version = Some(version.trim().to_string()).filter(|version| !version.is_empty());
+    Ok((version, size.saturating_mul(unit)))
+}
+
+/// Trimmed stdout of a successful command, or the reason it failed
+fn command_output(program: &str, args: &[&str]) -> std::result::Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", program, e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or("");
+        return Err(format!("{} exited with {}: {}", program, output.status, reason.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim_end().to_string())
+}
+
+/// Size of a file, or of all files below a directory (symlinks are not followed)
+fn path_size(path: &Path) -> std::result::Result<u64, String> {
+    let metadata = fs::symlink_metadata(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    if !metadata.is_dir() {
+        return Ok(if metadata.is_file() { metadata.len() } else { 0 });
+    }
+
+    let mut total = 0;
+    let entries = fs::read_dir(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("{}: {}", path.display(), e))?;
+        total += path_size(&entry.path())?;
+    }
+    Ok(total)
+}
+
+/// Write footprints to a JSON file
+///
+/// # Arguments
+/// * `report` - Measured footprints
+/// * `output_path` - Path to output JSON file (e.g., "footprint.json")
+pub fn write_footprint_report(report: &FootprintReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize footprint report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FrameworkCapabilities, PerformanceMetrics};
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn make_result(framework: &str, duration_ms: u64, peak_memory_bytes: u64, success: bool) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("/tmp/test.pdf"),
+            file_size: 1000,
+            success,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(duration_ms),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics {
+                peak_memory_bytes,
+                avg_cpu_percent: 0.0,
+                throughput_bytes_per_sec: 0.0,
+                p50_memory_bytes: 0,
+                p95_memory_bytes: 0,
+                p99_memory_bytes: 0,
+            },
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: FrameworkCapabilities::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        }
+    }
+
+    fn path_package(name: &str, path: &Path) -> PackageSpec {
+        PackageSpec {
+            ecosystem: PackageEcosystem::Path,
+            name: name.to_string(),
+            path: Some(path.to_path_buf()),
+        }
+    }
+
+    #[test]
+    fn test_measure_footprints_totals_and_performance() {
+        let dir = TempDir::new().unwrap();
+        let package_dir = dir.path().join("site-packages/framework");
+        fs::create_dir_all(package_dir.join("nested")).unwrap();
+        fs::write(package_dir.join("a.py"), vec![0u8; 100]).unwrap();
+        fs::write(package_dir.join("nested/b.so"), vec![0u8; 400]).unwrap();
+        let model = dir.path().join("model.onnx");
+        fs::write(&model, vec![0u8; 1000]).unwrap();
+
+        let mut manifest = FootprintManifest::default();
+        manifest.frameworks.insert(
+            "heavy".to_string(),
+            FrameworkDependencies {
+                packages: vec![path_package("framework", &package_dir)],
+                models: vec![
+                    ModelSpec {
+                        name: "layout".to_string(),
+                        path: model,
+                    },
+                    ModelSpec {
+                        name: "not-downloaded".to_string(),
+                        path: dir.path().join("missing"),
+                    },
+                ],
+                ..Default::default()
+            },
+        );
+        manifest.frameworks.insert(
+            "light".to_string(),
+            FrameworkDependencies {
+                packages: vec![path_package("light", &package_dir.join("a.py"))],
+                ..Default::default()
+            },
+        );
+
+        let results = vec![
+            make_result("heavy", 100, 2_000, true),
+            make_result("heavy", 300, 5_000, true),
+            make_result("heavy", 900, 9_000, false),
+        ];
+        let report = measure_footprints(&manifest, &results);
+
+        assert_eq!(report.frameworks.len(), 2);
+        assert_eq!(report.frameworks[0].framework, "light");
+        assert_eq!(report.frameworks[0].total_bytes, 100);
+        assert!(report.frameworks[0].performance.is_none());
+
+        let heavy = &report.frameworks[1];
+        assert_eq!(heavy.total_bytes, 1500);
+        assert_eq!(heavy.unmeasured, 1);
+        assert!(heavy.models[1].error.is_some());
+        let performance = heavy.performance.as_ref().unwrap();
+        assert_eq!(performance.documents, 2);
+        assert!((performance.mean_duration_ms - 200.0).abs() < 1e-9);
+        assert_eq!(performance.peak_memory_bytes, 5_000);
+    }
+
+    #[test]
+    fn test_parse_version_and_size() {
+        assert_eq!(
+            parse_version_and_size("4.0.0\t2048\n", 1),
+            Ok((Some("4.0.0".to_string()), 2048))
+        );
+        assert_eq!(parse_version_and_size("\t10", 1024), Ok((None, 10_240)));
+        assert!(parse_version_and_size("not installed", 1).is_err());
+        assert!(parse_version_and_size("1.0\tlots", 1).is_err());
+    }
+
+    #[test]
+    fn test_manifest_validation() {
+        let manifest: FootprintManifest = serde_json::from_str(
+            r#"{"frameworks": {"docling": {"packages": [{"ecosystem": "pypi", "name": "docling"}],
+                "system_packages": ["libgl1"], "docker_image": "docling:latest"}}}"#,
+        )
+        .unwrap();
+        assert!(manifest.validate().is_ok());
+
+        let manifest: FootprintManifest =
+            serde_json::from_str(r#"{"frameworks": {"jar": {"packages": [{"ecosystem": "path", "name": "tika"}]}}}"#)
+                .unwrap();
+        assert!(matches!(manifest.validate(), Err(Error::Config(_))));
+
+        let manifest: FootprintManifest =
+            serde_json::from_str(r#"{"frameworks": {"pandoc": {"system_packages": [" "]}}}"#).unwrap();
+        assert!(matches!(manifest.validate(), Err(Error::Config(_))));
+    }
+}
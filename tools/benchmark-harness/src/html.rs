@@ -7,13 +7,16 @@
 //! It also provides flamegraph index generation for interactive browsing of
 //! performance profiling data collected during benchmarks.
 
+use crate::cost::{CloudPricing, CostReport, estimate_costs};
+use crate::diff_view::{FixtureDiff, write_diff_pages};
+use crate::flame_diff::{FixtureFlameDiff, collect_flame_diffs};
 use crate::types::BenchmarkResult;
 use crate::{Error, Result};
 use minijinja::{AutoEscape, Environment, context};
 use serde::Serialize;
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use std::time::Duration;
 
@@ -32,6 +35,12 @@ struct ChartData {
     benchmark_run_date: Option<String>,
     /// HTML generation timestamp (when the HTML file was created)
     generated_at: String,
+    /// Estimated cloud cost per 1k documents
+    cost: CostReport,
+    /// Links to side-by-side output diff pages
+    diffs: Vec<FixtureDiff>,
+    /// Differential flamegraphs between two kreuzberg revisions
+    flame_diffs: Vec<FixtureFlameDiff>,
 }
 
 /// Aggregated metrics for a framework or framework-extension combination
@@ -81,6 +90,10 @@ fn init_template_env() -> Environment<'static> {
     )
     .expect("Failed to add flamegraphs template");
 
+    // Output diff page template
+    env.add_template("diff.html.jinja", include_str!("../templates/diff.html.jinja"))
+        .expect("Failed to add diff template");
+
     // Components
     env.add_template(
         "components/header.html.jinja",
@@ -129,6 +142,21 @@ fn init_template_env() -> Environment<'static> {
         include_str!("../templates/charts/success.html.jinja"),
     )
     .expect("Failed to add success chart template");
+    env.add_template(
+        "charts/cost.html.jinja",
+        include_str!("../templates/charts/cost.html.jinja"),
+    )
+    .expect("Failed to add cost table template");
+    env.add_template(
+        "charts/diffs.html.jinja",
+        include_str!("../templates/charts/diffs.html.jinja"),
+    )
+    .expect("Failed to add diffs table template");
+    env.add_template(
+        "charts/flame_diffs.html.jinja",
+        include_str!("../templates/charts/flame_diffs.html.jinja"),
+    )
+    .expect("Failed to add flame diffs table template");
 
     // Charts - Scripts
     env.add_template(
@@ -192,7 +220,7 @@ fn init_template_env() -> Environment<'static> {
 }
 
 /// Get the template environment (initializes on first call)
-fn get_template_env() -> &'static Environment<'static> {
+pub(crate) fn get_template_env() -> &'static Environment<'static> {
     TEMPLATE_ENV.get_or_init(init_template_env)
 }
 
@@ -206,17 +234,49 @@ fn get_template_env() -> &'static Environment<'static> {
 /// - File type breakdown
 /// - Success rate dashboard
 ///
+/// plus a cost table estimated with the default [`CloudPricing`] model.
+///
 /// # Arguments
 /// * `results` - Vector of benchmark results to visualize
 /// * `output_path` - Path to output HTML file
 /// * `benchmark_date` - Optional benchmark execution date (e.g., "2025-12-13 14:30:00 UTC").
 ///   If not provided, current timestamp is used as fallback
 pub fn write_html(results: &[BenchmarkResult], output_path: &Path, benchmark_date: Option<&str>) -> Result<()> {
-    if let Some(parent) = output_path.parent() {
-        fs::create_dir_all(parent).map_err(Error::Io)?;
-    }
+    write_html_with_pricing(results, output_path, benchmark_date, &CloudPricing::default())
+}
 
-    let chart_data = build_chart_data(results, benchmark_date)?;
+/// Write benchmark results as interactive HTML visualization using a custom pricing model
+///
+/// Identical to [`write_html`], but the cost section is computed with `pricing`.
+pub fn write_html_with_pricing(
+    results: &[BenchmarkResult],
+    output_path: &Path,
+    benchmark_date: Option<&str>,
+    pricing: &CloudPricing,
+) -> Result<()> {
+    write_html_with_diffs(results, output_path, benchmark_date, pricing, &[])
+}
+
+/// Write benchmark results as interactive HTML visualization with output diffs
+///
+/// Identical to [`write_html_with_pricing`], but also renders a side-by-side diff page
+/// (under `diffs/` next to `output_path`) for every fixture whose extracted texts were
+/// saved by more than one framework in one of `run_dirs`, and links them from an
+/// "Output Diffs" tab. See [`crate::diff_view`]. Differential flamegraphs of `flame-diff`
+/// runs among `run_dirs` are copied next to the report and listed in a "Flame Diffs" tab.
+pub fn write_html_with_diffs(
+    results: &[BenchmarkResult],
+    output_path: &Path,
+    benchmark_date: Option<&str>,
+    pricing: &CloudPricing,
+    run_dirs: &[PathBuf],
+) -> Result<()> {
+    let report_dir = output_path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(report_dir).map_err(Error::Io)?;
+
+    let mut chart_data = build_chart_data(results, benchmark_date, pricing)?;
+    chart_data.diffs = write_diff_pages(results, run_dirs, report_dir)?;
+    chart_data.flame_diffs = collect_flame_diffs(run_dirs, report_dir)?;
     let html = generate_html(&chart_data)?;
 
     fs::write(output_path, html).map_err(Error::Io)?;
@@ -225,7 +285,11 @@ pub fn write_html(results: &[BenchmarkResult], output_path: &Path, benchmark_dat
 }
 
 /// Build aggregated chart data from benchmark results
-fn build_chart_data(results: &[BenchmarkResult], benchmark_date: Option<&str>) -> Result<ChartData> {
+fn build_chart_data(
+    results: &[BenchmarkResult],
+    benchmark_date: Option<&str>,
+    pricing: &CloudPricing,
+) -> Result<ChartData> {
     let mut frameworks = Vec::new();
     let mut extensions = Vec::new();
     let mut framework_results: HashMap<String, Vec<&BenchmarkResult>> = HashMap::new();
@@ -281,6 +345,7 @@ fn build_chart_data(results: &[BenchmarkResult], benchmark_date: Option<&str>) -
 
     let benchmark_run_date = benchmark_date.map(|d| d.to_string());
     let generated_at = chrono::Utc::now().to_rfc3339();
+    let cost = estimate_costs(results, pricing);
 
     Ok(ChartData {
         frameworks,
@@ -289,6 +354,9 @@ fn build_chart_data(results: &[BenchmarkResult], benchmark_date: Option<&str>) -
         extension_metrics,
         benchmark_run_date,
         generated_at,
+        cost,
+        diffs: Vec::new(),
+        flame_diffs: Vec::new(),
     })
 }
 
@@ -712,9 +780,11 @@ mod tests {
             file_size: 1000,
             success: true,
             error_message: None,
+            failure: None,
             duration: Duration::from_millis(100),
             extraction_duration: None,
             subprocess_overhead: None,
+            overhead_breakdown: None,
             metrics: PerformanceMetrics {
                 throughput_bytes_per_sec: 10000.0,
                 peak_memory_bytes: 1_000_000,
@@ -736,9 +806,14 @@ mod tests {
                 sample_count: 1,
             }),
             cold_start_duration: None,
+            warmup: None,
             file_extension: "pdf".to_string(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
         };
 
         let metrics = calculate_aggregated_metrics(&[&result]);
@@ -759,9 +834,11 @@ mod tests {
             file_size: 1000,
             success: true,
             error_message: None,
+            failure: None,
             duration: Duration::from_millis(100),
             extraction_duration: None,
             subprocess_overhead: None,
+            overhead_breakdown: None,
             metrics: PerformanceMetrics {
                 throughput_bytes_per_sec: 10000.0,
                 peak_memory_bytes: 1_000_000,
@@ -783,16 +860,23 @@ mod tests {
                 sample_count: 1,
             }),
             cold_start_duration: None,
+            warmup: None,
             file_extension: "pdf".to_string(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
         };
 
-        let chart_data = build_chart_data(&[result], None).unwrap();
+        let chart_data = build_chart_data(&[result], None, &CloudPricing::default()).unwrap();
         assert_eq!(chart_data.frameworks.len(), 1);
         assert_eq!(chart_data.frameworks[0], "test-framework");
         assert_eq!(chart_data.extensions.len(), 1);
         assert_eq!(chart_data.extensions[0], "pdf");
         assert!(chart_data.framework_metrics.contains_key("test-framework"));
+        assert_eq!(chart_data.cost.frameworks.len(), 1);
+        assert_eq!(chart_data.cost.frameworks[0].framework, "test-framework");
     }
 }
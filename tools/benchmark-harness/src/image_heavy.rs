@@ -0,0 +1,435 @@
+//! Image-heavy PDF benchmark suite
+//!
+//! Scanned and image-heavy PDFs spend most of their extraction time outside text
+//! parsing, but a single wall-clock number does not say whether rasterization, OCR or
+//! text merging is the bottleneck. This suite times every framework on fixtures that
+//! carry an `image_heavy` section and, for Kreuzberg, splits the time into stages using
+//! the stage markers reported by the native adapter (see [`crate::stages`]). External
+//! frameworks report total time, plus their internal extraction time when the wrapper
+//! script measures it.
+//!
+//! ## Fixture Format
+//!
+//! ```json
+//! {
+//!   "document": "../../../../test_documents/pdfs/scanned.pdf",
+//!   "file_type": "pdf",
+//!   "file_size": 69632,
+//!   "expected_frameworks": ["kreuzberg", "docling", "unstructured"],
+//!   "image_heavy": {
+//!     "pages": 1
+//!   }
+//! }
+//! ```
+//!
+//! `expected_frameworks` selects which of the requested frameworks run a fixture.
+
+use crate::adapter::FrameworkAdapter;
+use crate::adapters::{NativeAdapter, SubprocessAdapter};
+use crate::fixture::Fixture;
+use crate::stages::{ExtractionStage, StageTimings};
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// File types the image-heavy suite accepts
+pub const IMAGE_HEAVY_FILE_TYPES: &[&str] = &["pdf"];
+
+/// Expectations for an image-heavy fixture
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImageHeavyExpectations {
+    /// Number of pages, used to report per-page timings
+    #[serde(default)]
+    pub pages: Option<usize>,
+}
+
+/// Framework competing in the image-heavy suite
+pub enum ImageHeavyFramework {
+    /// In-process Kreuzberg extraction with stage attribution
+    Kreuzberg(NativeAdapter),
+
+    /// External framework run through its wrapper script (total time only)
+    External(SubprocessAdapter),
+}
+
+impl ImageHeavyFramework {
+    /// Framework name, matched against fixture `expected_frameworks`
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Kreuzberg(_) => "kreuzberg",
+            Self::External(adapter) => adapter.name(),
+        }
+    }
+
+    async fn extract(&self, document: &Path, timeout: Duration) -> Result<(BenchmarkResult, Option<StageTimings>)> {
+        match self {
+            Self::Kreuzberg(adapter) => adapter.extract_with_stages(document, timeout).await,
+            Self::External(adapter) => Ok((adapter.extract(document, timeout).await?, None)),
+        }
+    }
+}
+
+/// Mean time per stage for one framework on one fixture
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageBreakdown {
+    /// Mean time recorded by stage spans
+    pub stages: StageTimings,
+
+    /// Mean time outside any stage span
+    pub other: Duration,
+
+    /// Stage that took the most time
+    pub bottleneck: ExtractionStage,
+}
+
+impl StageBreakdown {
+    /// Split a total extraction duration into the recorded stages and the remainder
+    pub fn new(stages: StageTimings, total: Duration) -> Self {
+        let other = total.saturating_sub(stages.total());
+        let mut breakdown = Self {
+            stages,
+            other,
+            bottleneck: ExtractionStage::Other,
+        };
+        breakdown.bottleneck = ExtractionStage::ALL
+            .into_iter()
+            .max_by_key(|stage| breakdown.get(*stage))
+            .unwrap_or(ExtractionStage::Other);
+        breakdown
+    }
+
+    /// Time attributed to a stage
+    pub fn get(&self, stage: ExtractionStage) -> Duration {
+        match stage {
+            ExtractionStage::Other => self.other,
+            stage => self.stages.get(stage),
+        }
+    }
+}
+
+/// Benchmark result for one framework on one fixture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageHeavyResult {
+    /// Framework that performed the extraction
+    pub framework: String,
+
+    /// Path to the fixture JSON file
+    pub fixture: PathBuf,
+
+    /// Path to the extracted document
+    pub document: PathBuf,
+
+    /// Page count from the fixture expectations
+    pub pages: Option<usize>,
+
+    /// Whether every iteration extracted successfully
+    pub success: bool,
+
+    /// Error message of the first failed iteration
+    pub error_message: Option<String>,
+
+    /// Mean wall-clock duration across iterations
+    pub duration: Duration,
+
+    /// Mean internal extraction time, when the framework reports it
+    pub extraction_duration: Option<Duration>,
+
+    /// Mean time per stage (only for frameworks that report stage markers)
+    pub stages: Option<StageBreakdown>,
+}
+
+/// Aggregated results for one framework
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageHeavySummary {
+    /// Framework name
+    pub framework: String,
+
+    /// Number of fixtures run
+    pub fixtures: usize,
+
+    /// Number of fixtures extracted successfully
+    pub succeeded: usize,
+
+    /// Mean extraction duration in milliseconds (successful fixtures only)
+    pub mean_duration_ms: f64,
+
+    /// Mean duration per page in milliseconds (successful fixtures with a page count)
+    pub mean_duration_per_page_ms: Option<f64>,
+
+    /// Share of total time spent in each stage, weighted by fixture duration
+    pub stage_shares: Option<BTreeMap<ExtractionStage, f64>>,
+
+    /// Stage with the largest share of time
+    pub bottleneck: Option<ExtractionStage>,
+}
+
+/// Complete image-heavy suite report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageHeavyReport {
+    /// Number of timed iterations per fixture and framework
+    pub iterations: usize,
+
+    /// Per-framework, per-fixture results
+    pub results: Vec<ImageHeavyResult>,
+
+    /// Per-framework aggregation, ordered by framework
+    pub summaries: Vec<ImageHeavySummary>,
+
+    /// Fixtures whose documents were not found
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Run the image-heavy suite for every fixture with an `image_heavy` section
+///
+/// Each fixture runs with the requested frameworks listed in its `expected_frameworks`.
+/// Extraction failures are recorded in the results and do not abort the run.
+pub async fn run_image_heavy_suite(
+    fixtures: &[(PathBuf, Fixture)],
+    frameworks: &[ImageHeavyFramework],
+    iterations: usize,
+    timeout: Duration,
+) -> Result<ImageHeavyReport> {
+    let iterations = iterations.max(1);
+    let mut results = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (fixture_path, fixture) in fixtures {
+        let Some(expectations) = &fixture.image_heavy else {
+            continue;
+        };
+
+        let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+        let document = fixture.resolve_document_path(fixture_dir);
+        if !document.exists() {
+            skipped.push(fixture_path.clone());
+            continue;
+        }
+
+        for framework in frameworks
+            .iter()
+            .filter(|framework| fixture.expected_frameworks.iter().any(|name| name == framework.name()))
+        {
+            let mut durations = Vec::with_capacity(iterations);
+            let mut extraction_durations = Vec::with_capacity(iterations);
+            let mut stage_samples = Vec::with_capacity(iterations);
+            let mut error_message = None;
+
+            for _ in 0..iterations {
+                match framework.extract(&document, timeout).await {
+                    Ok((result, _)) if !result.success => {
+                        error_message = result.error_message.or_else(|| Some("Extraction failed".to_string()));
+                        break;
+                    }
+                    Ok((result, stages)) => {
+                        durations.push(result.duration);
+                        extraction_durations.extend(result.extraction_duration);
+                        stage_samples.extend(stages);
+                    }
+                    Err(e) => {
+                        error_message = Some(e.to_string());
+                        break;
+                    }
+                }
+            }
+
+            let success = error_message.is_none();
+            let duration = mean_duration(&durations);
+            let extraction_duration =
+                (success && !extraction_durations.is_empty()).then(|| mean_duration(&extraction_durations));
+            let stages = (success && !stage_samples.is_empty()).then(|| {
+                StageBreakdown::new(
+                    StageTimings::mean(&stage_samples),
+                    extraction_duration.unwrap_or(duration),
+                )
+            });
+
+            results.push(ImageHeavyResult {
+                framework: framework.name().to_string(),
+                fixture: fixture_path.clone(),
+                document: document.clone(),
+                pages: expectations.pages,
+                success,
+                error_message,
+                duration,
+                extraction_duration,
+                stages,
+            });
+        }
+    }
+
+    Ok(ImageHeavyReport {
+        iterations,
+        summaries: summarize_results(&results),
+        results,
+        skipped,
+    })
+}
+
+/// Aggregate results per framework
+pub fn summarize_results(results: &[ImageHeavyResult]) -> Vec<ImageHeavySummary> {
+    let mut groups: BTreeMap<&str, Vec<&ImageHeavyResult>> = BTreeMap::new();
+    for result in results {
+        groups.entry(result.framework.as_str()).or_default().push(result);
+    }
+
+    groups
+        .into_iter()
+        .map(|(framework, group)| {
+            let successful: Vec<_> = group.iter().filter(|result| result.success).collect();
+            let total_seconds: f64 = successful.iter().map(|result| result.duration.as_secs_f64()).sum();
+
+            let per_page: Vec<f64> = successful
+                .iter()
+                .filter_map(|result| {
+                    let pages = result.pages.filter(|&pages| pages > 0)?;
+                    Some(result.duration.as_secs_f64() * 1000.0 / pages as f64)
+                })
+                .collect();
+
+            let breakdowns: Vec<_> = successful.iter().filter_map(|result| result.stages.as_ref()).collect();
+            let stage_shares = stage_shares(&breakdowns);
+            let bottleneck = stage_shares
+                .as_ref()
+                .and_then(|shares| shares.iter().max_by(|a, b| a.1.total_cmp(b.1)).map(|(stage, _)| *stage));
+
+            ImageHeavySummary {
+                framework: framework.to_string(),
+                fixtures: group.len(),
+                succeeded: successful.len(),
+                mean_duration_ms: if successful.is_empty() {
+                    0.0
+                } else {
+                    total_seconds * 1000.0 / successful.len() as f64
+                },
+                mean_duration_per_page_ms: (!per_page.is_empty())
+                    .then(|| per_page.iter().sum::<f64>() / per_page.len() as f64),
+                stage_shares,
+                bottleneck,
+            }
+        })
+        .collect()
+}
+
+/// Write an image-heavy suite report to a JSON file
+pub fn write_image_heavy_report(report: &ImageHeavyReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize image-heavy report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Share of summed time per stage across fixtures (longer fixtures weigh more)
+fn stage_shares(breakdowns: &[&StageBreakdown]) -> Option<BTreeMap<ExtractionStage, f64>> {
+    let total: f64 = breakdowns
+        .iter()
+        .flat_map(|breakdown| ExtractionStage::ALL.map(|stage| breakdown.get(stage).as_secs_f64()))
+        .sum();
+    if total <= 0.0 {
+        return None;
+    }
+
+    Some(
+        ExtractionStage::ALL
+            .into_iter()
+            .map(|stage| {
+                let seconds: f64 = breakdowns
+                    .iter()
+                    .map(|breakdown| breakdown.get(stage).as_secs_f64())
+                    .sum();
+                (stage, seconds / total)
+            })
+            .collect(),
+    )
+}
+
+fn mean_duration(durations: &[Duration]) -> Duration {
+    if durations.is_empty() {
+        Duration::ZERO
+    } else {
+        durations.iter().sum::<Duration>() / durations.len() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(rasterization_ms: u64, ocr_ms: u64, text_merging_ms: u64) -> StageTimings {
+        StageTimings {
+            rasterization: Duration::from_millis(rasterization_ms),
+            ocr: Duration::from_millis(ocr_ms),
+            text_merging: Duration::from_millis(text_merging_ms),
+        }
+    }
+
+    fn result(framework: &str, duration_ms: u64, stages: Option<StageTimings>) -> ImageHeavyResult {
+        let duration = Duration::from_millis(duration_ms);
+        ImageHeavyResult {
+            framework: framework.to_string(),
+            fixture: PathBuf::from("fixture.json"),
+            document: PathBuf::from("scanned.pdf"),
+            pages: Some(2),
+            success: true,
+            error_message: None,
+            duration,
+            extraction_duration: None,
+            stages: stages.map(|stages| StageBreakdown::new(stages, duration)),
+        }
+    }
+
+    #[test]
+    fn test_stage_breakdown_remainder_and_bottleneck() {
+        let breakdown = StageBreakdown::new(timings(30, 50, 5), Duration::from_millis(100));
+
+        assert_eq!(breakdown.other, Duration::from_millis(15));
+        assert_eq!(breakdown.bottleneck, ExtractionStage::Ocr);
+
+        let parse_bound = StageBreakdown::new(timings(10, 10, 1), Duration::from_millis(100));
+        assert_eq!(parse_bound.bottleneck, ExtractionStage::Other);
+
+        let overlapping = StageBreakdown::new(timings(60, 60, 0), Duration::from_millis(100));
+        assert_eq!(overlapping.other, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_summarize_results_stage_shares() {
+        let results = vec![
+            result("kreuzberg", 100, Some(timings(20, 70, 0))),
+            result("kreuzberg", 300, Some(timings(220, 60, 0))),
+            result("docling", 400, None),
+        ];
+
+        let summaries = summarize_results(&results);
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].framework, "docling");
+        assert!(summaries[0].stage_shares.is_none());
+        assert!(summaries[0].bottleneck.is_none());
+        assert!((summaries[0].mean_duration_per_page_ms.unwrap() - 200.0).abs() < 1e-9);
+
+        let kreuzberg = &summaries[1];
+        let shares = kreuzberg.stage_shares.as_ref().unwrap();
+        assert!((kreuzberg.mean_duration_ms - 200.0).abs() < 1e-9);
+        assert!((shares[&ExtractionStage::Rasterization] - 0.6).abs() < 1e-9);
+        assert!((shares[&ExtractionStage::Ocr] - 0.325).abs() < 1e-9);
+        assert!((shares[&ExtractionStage::Other] - 0.075).abs() < 1e-9);
+        assert_eq!(kreuzberg.bottleneck, Some(ExtractionStage::Rasterization));
+    }
+
+    #[test]
+    fn test_expectations_deserialize_with_defaults() {
+        let expectations: ImageHeavyExpectations = serde_json::from_str("{}").unwrap();
+
+        assert!(expectations.pages.is_none());
+    }
+}
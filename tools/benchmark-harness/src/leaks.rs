@@ -0,0 +1,313 @@
+//! Descriptor and temp-file leak checks
+//!
+//! Long-running ingestion daemons fail with `EMFILE`/`ENOSPC` when a framework leaves a
+//! file descriptor or a temp file behind for every document. A benchmark run is short
+//! enough to hide this, so every extraction is checked after it finishes:
+//!
+//! - **Temp files**: subprocess adapters run with `TMPDIR`, `TMP` and `TEMP` pointing at a
+//!   private directory; anything left in it after the process exits was leaked by that run.
+//! - **Descriptors**: wrappers report `_open_fds_before` and `_open_fds_after`, the number
+//!   of descriptors open on files right before and after the extraction call. The native
+//!   adapter counts the harness's own descriptors the same way. Descriptors opened during
+//!   the extraction and still open afterwards are leaked.
+//!
+//! Only descriptors that refer to files on disk are counted: runtimes create event loops,
+//! pipes and sockets lazily on the first call, which is a one-off cost rather than a
+//! per-document leak. Counts are read from `/proc/self/fd` and are unavailable outside
+//! Linux. Leaks are reported separately from performance, in the same way as fixture
+//! assertions (see [`crate::assertions`]).
+
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Resources left behind by one extraction
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLeaks {
+    /// Descriptors still open after the extraction that were opened by it
+    /// Only present when descriptors could be counted before and after the extraction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaked_fds: Option<usize>,
+
+    /// Names of files and directories left in the adapter's private temp directory
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub leaked_temp_files: Vec<String>,
+}
+
+impl ResourceLeaks {
+    /// Whether anything was left behind
+    pub fn is_leak(&self) -> bool {
+        self.leaked_fds.unwrap_or(0) > 0 || !self.leaked_temp_files.is_empty()
+    }
+
+    /// Add the leaks of another run of the same extraction
+    pub fn accumulate(&mut self, other: &ResourceLeaks) {
+        if let Some(fds) = other.leaked_fds {
+            self.leaked_fds = Some(self.leaked_fds.unwrap_or(0) + fds);
+        }
+        self.leaked_temp_files.extend(other.leaked_temp_files.iter().cloned());
+    }
+
+    /// Read the descriptor counts reported by a subprocess wrapper
+    ///
+    /// Returns `None` when the payload lacks `_open_fds_before` or `_open_fds_after`.
+    pub fn fds_from_wrapper_output(parsed: &serde_json::Value) -> Option<usize> {
+        let count = |key: &str| parsed.get(key).and_then(|v| v.as_u64()).map(|n| n as usize);
+        Some(count("_open_fds_after")?.saturating_sub(count("_open_fds_before")?))
+    }
+}
+
+/// Number of descriptors this process has open on files on disk
+///
+/// Device files, `/proc` entries (including the listing of `/proc/self/fd` itself), pipes,
+/// sockets and anonymous inodes are not counted. Returns `None` without `/proc`.
+pub fn open_fd_count() -> Option<usize> {
+    let entries = fs::read_dir("/proc/self/fd").ok()?;
+    let count = entries
+        .filter_map(|entry| fs::read_link(entry.ok()?.path()).ok())
+        .filter(|target| target.is_absolute() && !target.starts_with("/dev") && !target.starts_with("/proc"))
+        .count();
+    Some(count)
+}
+
+static NEXT_TEMP_DIR_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Private temp directory for one adapter process, removed on drop
+pub struct PrivateTempDir {
+    path: PathBuf,
+}
+
+impl PrivateTempDir {
+    /// Create an empty directory under the system temp directory
+    pub fn create() -> Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "kreuzberg-bench-{}-{}",
+            std::process::id(),
+            NEXT_TEMP_DIR_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&path).map_err(Error::Io)?;
+        Ok(Self { path })
+    }
+
+    /// Path of the directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Environment variables that point temp file APIs of common runtimes at the directory
+    pub fn env(&self) -> Vec<(&'static str, &Path)> {
+        ["TMPDIR", "TMP", "TEMP"]
+            .into_iter()
+            .map(|key| (key, self.path.as_path()))
+            .collect()
+    }
+
+    /// Names of the entries left in the directory, sorted
+    pub fn leftovers(&self) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(&self.path)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+}
+
+impl Drop for PrivateTempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Resources one framework left behind on one fixture document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeakFinding {
+    /// Framework that performed the extraction
+    pub framework: String,
+
+    /// Path to the fixture document
+    pub file_path: PathBuf,
+
+    /// What was left behind
+    pub leaks: ResourceLeaks,
+}
+
+/// Leak summary of a benchmark run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeakReport {
+    /// Number of results that were checked for leaks
+    pub checked: usize,
+
+    /// Number of checked results where descriptors could be counted
+    pub fd_checked: usize,
+
+    /// Results that left descriptors or temp files behind
+    pub findings: Vec<LeakFinding>,
+}
+
+impl LeakReport {
+    /// Collect leak checks from benchmark results
+    pub fn from_results(results: &[BenchmarkResult]) -> Self {
+        let mut report = Self::default();
+
+        for result in results {
+            let Some(leaks) = &result.resource_leaks else {
+                continue;
+            };
+
+            report.checked += 1;
+            if leaks.leaked_fds.is_some() {
+                report.fd_checked += 1;
+            }
+            if leaks.is_leak() {
+                report.findings.push(LeakFinding {
+                    framework: result.framework.clone(),
+                    file_path: result.file_path.clone(),
+                    leaks: leaks.clone(),
+                });
+            }
+        }
+
+        report
+    }
+
+    /// Names of the frameworks with at least one finding, sorted and deduplicated
+    pub fn leaking_frameworks(&self) -> Vec<&str> {
+        let mut frameworks: Vec<&str> = self.findings.iter().map(|f| f.framework.as_str()).collect();
+        frameworks.sort_unstable();
+        frameworks.dedup();
+        frameworks
+    }
+}
+
+/// Write the leak report as JSON
+pub fn write_leak_report(report: &LeakReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize leak report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fds_from_wrapper_output() {
+        let parsed = serde_json::json!({ "content": "text", "_open_fds_before": 12, "_open_fds_after": 15 });
+        assert_eq!(ResourceLeaks::fds_from_wrapper_output(&parsed), Some(3));
+
+        let closed_more = serde_json::json!({ "_open_fds_before": 12, "_open_fds_after": 10 });
+        assert_eq!(ResourceLeaks::fds_from_wrapper_output(&closed_more), Some(0));
+
+        let legacy = serde_json::json!({ "content": "text", "_extraction_time_ms": 5.0 });
+        assert_eq!(ResourceLeaks::fds_from_wrapper_output(&legacy), None);
+    }
+
+    #[test]
+    fn test_private_temp_dir_leftovers() {
+        let temp_dir = PrivateTempDir::create().unwrap();
+        assert!(temp_dir.leftovers().is_empty());
+
+        fs::write(temp_dir.path().join("tmp-b.pdf"), b"x").unwrap();
+        fs::create_dir(temp_dir.path().join("tmp-a")).unwrap();
+        assert_eq!(temp_dir.leftovers(), vec!["tmp-a", "tmp-b.pdf"]);
+        assert!(temp_dir.env().iter().all(|(_, path)| *path == temp_dir.path()));
+
+        let path = temp_dir.path().to_path_buf();
+        drop(temp_dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_open_fd_count_counts_files() {
+        // Exact before/after comparisons would race with other tests opening files.
+        let file = fs::File::open("Cargo.toml").unwrap();
+        assert!(open_fd_count().unwrap() >= 1);
+        drop(file);
+    }
+
+    #[test]
+    fn test_accumulate_and_is_leak() {
+        let mut leaks = ResourceLeaks::default();
+        assert!(!leaks.is_leak());
+
+        leaks.accumulate(&ResourceLeaks {
+            leaked_fds: Some(0),
+            leaked_temp_files: vec![],
+        });
+        assert_eq!(leaks.leaked_fds, Some(0));
+        assert!(!leaks.is_leak());
+
+        leaks.accumulate(&ResourceLeaks {
+            leaked_fds: Some(2),
+            leaked_temp_files: vec!["pdfium-1.tmp".to_string()],
+        });
+        assert!(leaks.is_leak());
+        assert_eq!(leaks.leaked_fds, Some(2));
+        assert_eq!(leaks.leaked_temp_files, vec!["pdfium-1.tmp"]);
+    }
+
+    #[test]
+    fn test_leak_report_from_results() {
+        let result = |framework: &str, leaks: Option<ResourceLeaks>| BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("fixtures/report.pdf"),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: std::time::Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: Default::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: leaks,
+            extracted_output: None,
+        };
+        let clean = ResourceLeaks {
+            leaked_fds: Some(0),
+            leaked_temp_files: vec![],
+        };
+        let leaky = ResourceLeaks {
+            leaked_fds: None,
+            leaked_temp_files: vec!["tmp1234.pdf".to_string()],
+        };
+
+        let report = LeakReport::from_results(&[
+            result("kreuzberg-native", Some(clean)),
+            result("kreuzberg-python", Some(leaky.clone())),
+            result("kreuzberg-node", Some(leaky)),
+            result("kreuzberg-wasm", None),
+        ]);
+
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.fd_checked, 1);
+        assert_eq!(report.findings.len(), 2);
+        assert_eq!(report.leaking_frameworks(), vec!["kreuzberg-node", "kreuzberg-python"]);
+    }
+}
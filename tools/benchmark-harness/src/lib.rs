@@ -4,37 +4,123 @@
 //! document extraction frameworks, measuring performance (throughput, memory, latency)
 //! and quality (F1 scores, text accuracy).
 
+pub mod accuracy;
 pub mod adapter;
 pub mod adapters;
+pub mod assertions;
+pub mod bisect;
+pub mod cache_correctness;
+pub mod chunking_retrieval;
+pub mod code_documents;
 pub mod config;
 pub mod consolidate;
+pub mod cost;
+pub mod diff_view;
 pub mod error;
+pub mod failures;
 pub mod fixture;
+pub mod flame_diff;
+pub mod footprint;
 pub mod html;
+pub mod image_heavy;
+pub mod leaks;
+pub mod matrix;
 pub mod monitoring;
+pub mod ocr_reuse;
 pub mod output;
 pub mod pool_metrics;
 pub mod profile_report;
 pub mod profiling;
+pub mod rag_pipeline;
 pub mod registry;
 pub mod runner;
+pub mod sanitizers;
+pub mod scenario;
+pub mod spreadsheet;
+pub mod stages;
 pub mod types;
+pub mod winners;
 
+pub use accuracy::{
+    AccuracyReport, FrameworkAccuracy, ProvenanceAccuracy, ResultProvenance, TextProvenance, score_text,
+    write_accuracy_report,
+};
 pub use adapter::FrameworkAdapter;
 pub use adapters::{NativeAdapter, NodeAdapter, PythonAdapter, RubyAdapter};
+pub use assertions::{
+    AssertionFailure, AssertionOutcome, CorrectnessRegression, CorrectnessReport, OutputAssertions,
+    write_correctness_report,
+};
+pub use bisect::{
+    BisectConfig, BisectMetric, BisectOutcome, BisectReport, BisectStep, bisect_commits, run_bisect,
+    write_bisect_report,
+};
+pub use cache_correctness::{
+    CacheCheck, CacheCorrectnessReport, ConfigPermutation, KeyCollision, ResultFingerprint, permutation_grid,
+    run_cache_correctness_suite, write_cache_correctness_report,
+};
+pub use chunking_retrieval::{
+    ChunkingMode, ChunkingRetrievalReport, ChunkingStrategy, RetrievalExpectations, RetrievalQuestion, StrategyResult,
+    StrategySummary, run_chunking_retrieval_experiment, strategy_grid, write_chunking_retrieval_report,
+};
+pub use code_documents::{
+    CodeDocumentExpectations, CodeDocumentFramework, CodeDocumentReport, StructuralFidelity, run_code_document_suite,
+    write_code_document_report,
+};
 pub use config::{BenchmarkConfig, BenchmarkMode, ProfilingConfig};
 pub use consolidate::{
     ConsolidatedResults, CrossFrameworkComparison, FrameworkAggregation, FrameworkQuality, QualityAnalysis,
     aggregate_by_framework, analyze_quality, compare_frameworks, consolidate_runs, load_run_results,
     write_consolidated_json,
 };
+pub use cost::{CloudPricing, CostReport, FrameworkCost, estimate_costs, write_cost_report};
+pub use diff_view::{FixtureDiff, write_diff_pages, write_extracted_outputs};
 pub use error::{Error, Result};
+pub use failures::{FailureBreakdown, FailureKind, FailureTaxonomy, FrameworkFailures, write_failure_report};
+pub use fixture::fetch::{
+    CorpusFile, CorpusManifest, FetchStatus, FetchSummary, FetchedFile, default_cache_dir, fetch_corpus, write_fixtures,
+};
 pub use fixture::{Fixture, FixtureManager};
-pub use html::{generate_flamegraph_index, write_html};
+pub use flame_diff::{
+    FixtureFlameDiff, FlameDiffConfig, FlameDiffReport, FoldedProfile, FunctionDelta, collect_flame_diffs,
+    diff_profiles, run_flame_diff, write_flame_diff_report,
+};
+pub use footprint::{
+    ArtifactSize, DockerImageSize, FootprintManifest, FootprintPerformance, FootprintReport, FrameworkDependencies,
+    FrameworkFootprint, ModelSpec, PackageEcosystem, PackageSpec, measure_footprints, write_footprint_report,
+};
+pub use html::{generate_flamegraph_index, write_html, write_html_with_diffs, write_html_with_pricing};
+pub use image_heavy::{
+    ImageHeavyExpectations, ImageHeavyFramework, ImageHeavyReport, StageBreakdown, run_image_heavy_suite,
+    write_image_heavy_report,
+};
+pub use leaks::{LeakFinding, LeakReport, ResourceLeaks, open_fd_count, write_leak_report};
+pub use matrix::{
+    MatrixCell, MatrixQueue, MatrixSummary, Shard, ShardState, claim_shard, complete_shard, create_queue, fail_shard,
+    load_queue, merge_shards,
+};
 pub use monitoring::{ResourceMonitor, ResourceSample, ResourceStats};
-pub use output::{write_by_extension_analysis, write_json};
+pub use ocr_reuse::{
+    OcrEngineReuse, OcrReuseReport, OcrReuseResult, run_ocr_reuse_suite, write_ocr_reuse_markdown,
+    write_ocr_reuse_report,
+};
+pub use output::{write_by_extension_analysis, write_json, write_markdown};
 pub use pool_metrics::{FilePoolMetrics, PoolMetricsReport};
 pub use profile_report::{Hotspot, MemorySnapshot, ProfileReport};
+pub use rag_pipeline::{
+    DocumentTimings, RagDocumentResult, RagFramework, RagFrameworkSummary, RagPipelineReport, VectorIndex,
+    run_rag_pipeline_benchmark, write_rag_pipeline_report,
+};
 pub use registry::AdapterRegistry;
 pub use runner::BenchmarkRunner;
+pub use sanitizers::{
+    SanitizerConfig, SanitizerFinding, SanitizerReport, SanitizerRun, SanitizerStatus, SanitizerTool, miri_documents,
+    parse_asan_log, parse_miri_output, run_sanitizers, write_sanitizer_report,
+};
+pub use scenario::{BenchmarkScenario, ScenarioThresholds, ThresholdViolation, load_scenario, load_scenarios};
+pub use spreadsheet::{
+    SpreadsheetExpectations, SpreadsheetReport, SpreadsheetWorkload, run_spreadsheet_suite, write_spreadsheet_report,
+};
+pub use stages::{ExtractionStage, StageRecorder, StageTimings};
 pub use types::{BenchmarkResult, FrameworkCapabilities, PdfMetadata};
+pub use winners::{Significance, WinnerMetric, WinnerReport, WinnerSummary, summarize_winners, write_winner_report};
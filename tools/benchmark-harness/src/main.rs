@@ -4,7 +4,7 @@
 #[global_allocator]
 static ALLOC: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
-use benchmark_harness::{BenchmarkConfig, BenchmarkMode, FixtureManager, Result};
+use benchmark_harness::{BenchmarkConfig, BenchmarkMode, FixtureManager, ProfilingConfig, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
@@ -26,6 +26,35 @@ enum OutputFormat {
     Html,
     /// Both JSON and HTML formats
     Both,
+    /// Markdown comparison report for GitHub READMEs and discussions
+    Markdown,
+}
+
+/// CLI enum for the metric compared by `bisect`
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum CliBisectMetric {
+    /// Mean extraction duration
+    MeanDuration,
+    /// Median extraction duration
+    MedianDuration,
+    /// 95th percentile extraction duration
+    P95Duration,
+    /// Peak resident memory
+    PeakMemory,
+    /// Throughput in bytes per second
+    Throughput,
+}
+
+impl From<CliBisectMetric> for benchmark_harness::BisectMetric {
+    fn from(metric: CliBisectMetric) -> Self {
+        match metric {
+            CliBisectMetric::MeanDuration => Self::MeanDuration,
+            CliBisectMetric::MedianDuration => Self::MedianDuration,
+            CliBisectMetric::P95Duration => Self::P95Duration,
+            CliBisectMetric::PeakMemory => Self::PeakMemory,
+            CliBisectMetric::Throughput => Self::Throughput,
+        }
+    }
 }
 
 impl From<CliMode> for BenchmarkMode {
@@ -61,6 +90,21 @@ enum Commands {
         fixtures: PathBuf,
     },
 
+    /// Download a public benchmark corpus from a manifest and verify its checksums
+    FetchCorpus {
+        /// Corpus manifest JSON file
+        #[arg(short, long)]
+        manifest: PathBuf,
+
+        /// Cache directory for downloaded corpora (defaults to $KREUZBERG_CORPUS_CACHE or ~/.cache)
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+
+        /// Write a fixture for each downloaded document into this directory
+        #[arg(long)]
+        write_fixtures: Option<PathBuf>,
+    },
+
     /// Generate an HTML index gallery for flamegraphs
     GenerateFlamegraphIndex {
         /// Directory containing flamegraph SVG files
@@ -75,8 +119,8 @@ enum Commands {
     /// Run benchmarks
     Run {
         /// Directory or file pattern to search for fixtures
-        #[arg(short, long)]
-        fixtures: PathBuf,
+        #[arg(short, long, required_unless_present = "scenario")]
+        fixtures: Option<PathBuf>,
 
         /// Frameworks to benchmark (comma-separated)
         #[arg(short = 'F', long, value_delimiter = ',')]
@@ -102,6 +146,10 @@ enum Commands {
         #[arg(short = 'w', long, default_value = "1")]
         warmup: usize,
 
+        /// Warmup iterations for one framework, overriding --warmup (repeatable)
+        #[arg(long = "adapter-warmup", value_name = "NAME=ITERATIONS")]
+        adapter_warmup: Vec<String>,
+
         /// Number of benchmark iterations for statistical analysis
         #[arg(short = 'i', long, default_value = "3")]
         iterations: usize,
@@ -122,6 +170,380 @@ enum Commands {
         /// Used for marking when the benchmark was run in the HTML output
         #[arg(long)]
         benchmark_date: Option<String>,
+
+        /// Cloud pricing model JSON file for cost estimates (defaults to AWS Fargate list prices)
+        #[arg(long)]
+        pricing: Option<PathBuf>,
+
+        /// Footprint manifest JSON file listing each framework's packages, system packages,
+        /// models and Docker image; their sizes are written to `footprint.json`
+        #[arg(long)]
+        footprint: Option<PathBuf>,
+
+        /// Exit with an error when any fixture assertion fails
+        #[arg(long)]
+        fail_on_regression: bool,
+
+        /// Exit with an error when any framework leaves file descriptors or temp files behind
+        #[arg(long)]
+        fail_on_leak: bool,
+
+        /// Register a third-party adapter speaking the JSON lines adapter protocol (repeatable)
+        #[arg(long = "adapter", value_name = "NAME=PROGRAM [ARGS...]")]
+        custom_adapters: Vec<String>,
+
+        /// TOML scenario file declaring fixtures, frameworks, modes, iterations and thresholds;
+        /// values it sets take precedence over the options above
+        #[arg(long)]
+        scenario: Option<PathBuf>,
+
+        /// Scenario to run when the scenario file declares several
+        #[arg(long, requires = "scenario")]
+        scenario_name: Option<String>,
+
+        /// Capture a CPU profile and flamegraph per fixture under `flamegraphs/` (requires the
+        /// `profiling` feature and ENABLE_PROFILING=true)
+        #[arg(long)]
+        profile: bool,
+    },
+
+    /// Run the spreadsheet suite (timing plus cell-count and formula correctness checks)
+    Spreadsheets {
+        /// Directory or file pattern to search for fixtures with spreadsheet expectations
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+
+        /// Number of timed iterations per fixture
+        #[arg(short = 'i', long, default_value = "3")]
+        iterations: usize,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "1800")]
+        timeout: u64,
+
+        /// Read XLSX/XLSB sheets cell by cell (spreadsheet_options.stream)
+        #[arg(long)]
+        stream: bool,
+    },
+
+    /// Run the notebook/markup suite head-to-head (timing plus heading and code-fence fidelity)
+    CodeDocuments {
+        /// Directory or file pattern to search for fixtures with code_document expectations
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Frameworks to compare (comma-separated: kreuzberg, markitdown, pandoc-markdown)
+        #[arg(
+            short = 'F',
+            long,
+            value_delimiter = ',',
+            default_value = "kreuzberg,markitdown,pandoc-markdown"
+        )]
+        frameworks: Vec<String>,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+
+        /// Number of timed iterations per fixture and framework
+        #[arg(short = 'i', long, default_value = "3")]
+        iterations: usize,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "300")]
+        timeout: u64,
+    },
+
+    /// Compare chunking strategies by retrieval hit-rate on fixtures with a Q/A set
+    ChunkingRetrieval {
+        /// Directory or file pattern to search for fixtures with retrieval questions
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+
+        /// Embedding preset for chunks and questions (fast, balanced, quality, multilingual)
+        #[arg(short, long, default_value = "fast")]
+        preset: String,
+
+        /// Chunking modes to compare (comma-separated: fixed, structural, sentences)
+        #[arg(short, long, value_delimiter = ',', default_value = "fixed,structural")]
+        modes: Vec<String>,
+
+        /// Maximum chunk sizes in characters (comma-separated)
+        #[arg(short, long, value_delimiter = ',', default_value = "256,512,1024,2000")]
+        sizes: Vec<usize>,
+
+        /// Chunk overlaps in characters (comma-separated; overlaps not below a size are skipped)
+        #[arg(long, value_delimiter = ',', default_value = "0,50,200")]
+        overlaps: Vec<usize>,
+
+        /// Number of chunks retrieved per question
+        #[arg(short = 'k', long, default_value = "3")]
+        top_k: usize,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "300")]
+        timeout: u64,
+    },
+
+    /// Run extract → chunk → embed → index → query per framework and score retrieval end to end
+    RagPipeline {
+        /// Directory or file pattern to search for fixtures with retrieval questions
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Frameworks feeding the pipeline (comma-separated: kreuzberg, docling, unstructured, markitdown)
+        #[arg(
+            short = 'F',
+            long,
+            value_delimiter = ',',
+            default_value = "kreuzberg,docling,unstructured"
+        )]
+        frameworks: Vec<String>,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+
+        /// Embedding preset for chunks and questions (fast, balanced, quality, multilingual)
+        #[arg(short, long, default_value = "fast")]
+        preset: String,
+
+        /// Chunking mode (fixed, structural or sentences)
+        #[arg(short, long, default_value = "structural")]
+        mode: String,
+
+        /// Maximum chunk size in characters
+        #[arg(short, long, default_value = "1000")]
+        size: usize,
+
+        /// Chunk overlap in characters
+        #[arg(long, default_value = "200")]
+        overlap: usize,
+
+        /// Number of chunks retrieved per question
+        #[arg(short = 'k', long, default_value = "3")]
+        top_k: usize,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "300")]
+        timeout: u64,
+    },
+
+    /// Run the image-heavy PDF suite, splitting time into rasterization, OCR and text merging
+    ImageHeavy {
+        /// Directory or file pattern to search for fixtures with image_heavy expectations
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Frameworks to compare (comma-separated: kreuzberg, docling, unstructured)
+        #[arg(
+            short = 'F',
+            long,
+            value_delimiter = ',',
+            default_value = "kreuzberg,docling,unstructured"
+        )]
+        frameworks: Vec<String>,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+
+        /// Number of timed iterations per fixture and framework
+        #[arg(short = 'i', long, default_value = "3")]
+        iterations: usize,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "1800")]
+        timeout: u64,
+    },
+
+    /// Measure whether frameworks reuse OCR engines across documents
+    OcrReuse {
+        /// Directory or file pattern to search for fixtures (images and image-heavy PDFs are used)
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Frameworks to compare (comma-separated: kreuzberg, kreuzberg-python, kreuzberg-ruby, docling, unstructured)
+        #[arg(
+            short = 'F',
+            long,
+            value_delimiter = ',',
+            default_value = "kreuzberg,kreuzberg-python,kreuzberg-ruby,docling,unstructured"
+        )]
+        frameworks: Vec<String>,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+
+        /// First-document to steady-state latency ratio at which the engine counts as reused
+        #[arg(long, default_value = "1.5")]
+        threshold: f64,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "1800")]
+        timeout: u64,
+    },
+
+    /// Check that the result cache never serves a result extracted under a different configuration
+    CacheCorrectness {
+        /// Directory or file pattern to search for fixtures
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "300")]
+        timeout: u64,
+    },
+
+    /// Find the commit that regressed the native adapter on a fixture set by bisecting git revisions
+    Bisect {
+        /// Revision known to perform well
+        #[arg(long)]
+        good: String,
+
+        /// Revision known to perform badly
+        #[arg(long, default_value = "HEAD")]
+        bad: String,
+
+        /// Directory or file pattern to search for fixtures (taken from the current checkout)
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Metric compared between revisions
+        #[arg(long, value_enum, default_value = "mean-duration")]
+        metric: CliBisectMetric,
+
+        /// Relative change against the good revision at which a revision counts as regressed
+        #[arg(long, default_value = "0.1")]
+        threshold: f64,
+
+        /// Number of benchmark iterations per fixture and revision
+        #[arg(short = 'i', long, default_value = "3")]
+        iterations: usize,
+
+        /// Number of warmup iterations per fixture and revision
+        #[arg(short = 'w', long, default_value = "1")]
+        warmup: usize,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "300")]
+        timeout: u64,
+
+        /// Git repository to bisect
+        #[arg(long, default_value = ".")]
+        repo: PathBuf,
+
+        /// Directory for worktrees, build artifacts and per-revision results
+        #[arg(long, default_value = "target/bisect")]
+        work_dir: PathBuf,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+    },
+
+    /// Profile fixtures under two kreuzberg revisions and render differential flamegraphs
+    FlameDiff {
+        /// Revision profiled as the baseline
+        #[arg(long)]
+        baseline: String,
+
+        /// Revision compared against the baseline
+        #[arg(long, default_value = "HEAD")]
+        candidate: String,
+
+        /// Directory or file pattern to search for fixtures (taken from the current checkout)
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Number of benchmark iterations per fixture and revision
+        #[arg(short = 'i', long, default_value = "3")]
+        iterations: usize,
+
+        /// Timeout in seconds per extraction
+        #[arg(short = 't', long, default_value = "300")]
+        timeout: u64,
+
+        /// Git repository containing both revisions
+        #[arg(long, default_value = ".")]
+        repo: PathBuf,
+
+        /// Directory for worktrees, build artifacts and per-revision profiles
+        #[arg(long, default_value = "target/flame-diff")]
+        work_dir: PathBuf,
+
+        /// Output directory for the report and flamegraphs (usable as `visualize` input)
+        #[arg(short, long, default_value = "flame-diff")]
+        output: PathBuf,
+    },
+
+    /// Check the native adapter for memory errors and leaks under AddressSanitizer and Miri
+    Sanitize {
+        /// Directory or file pattern to search for fixtures
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Nightly toolchain used to build the instrumented harness
+        #[arg(long, default_value = "nightly")]
+        toolchain: String,
+
+        /// Target triple to build for (defaults to the toolchain's host)
+        #[arg(long)]
+        target: Option<String>,
+
+        /// Skip the AddressSanitizer stage
+        #[arg(long)]
+        skip_asan: bool,
+
+        /// Skip the Miri stage
+        #[arg(long)]
+        skip_miri: bool,
+
+        /// Number of pure-Rust documents extracted under Miri
+        #[arg(long, default_value_t = benchmark_harness::sanitizers::DEFAULT_MIRI_DOCUMENTS)]
+        miri_documents: usize,
+
+        /// Timeout in seconds per extraction under AddressSanitizer
+        #[arg(short = 't', long, default_value = "600")]
+        timeout: u64,
+
+        /// Workspace whose harness is instrumented
+        #[arg(long, default_value = ".")]
+        repo: PathBuf,
+
+        /// Directory for instrumented builds, sanitizer logs and run results
+        #[arg(long, default_value = "target/sanitizers")]
+        work_dir: PathBuf,
+
+        /// Output directory for results
+        #[arg(short, long, default_value = "results")]
+        output: PathBuf,
+    },
+
+    /// Extract documents in-process and exit (the Miri stage of `sanitize` runs this under Miri)
+    #[command(hide = true)]
+    MiriExtract {
+        /// Documents to extract
+        documents: Vec<PathBuf>,
+    },
+
+    /// Run a benchmark matrix sharded across machines or processes through a shared work queue
+    Matrix {
+        #[command(subcommand)]
+        command: MatrixCommand,
     },
 
     /// Consolidate multiple benchmark runs
@@ -160,6 +582,120 @@ enum Commands {
         /// Benchmark execution date (e.g., "2025-12-13 14:30:00 UTC")
         #[arg(long)]
         benchmark_date: Option<String>,
+
+        /// Cloud pricing model JSON file for cost estimates (defaults to AWS Fargate list prices)
+        #[arg(long)]
+        pricing: Option<PathBuf>,
+
+        /// Footprint manifest JSON file listing each framework's packages, system packages,
+        /// models and Docker image; their sizes are written to `footprint.json`
+        #[arg(long)]
+        footprint: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MatrixCommand {
+    /// Write a work queue with one shard per framework × extension × mode combination
+    Plan {
+        /// Matrix directory shared by all workers
+        #[arg(short, long)]
+        dir: PathBuf,
+
+        /// Directory or file pattern to search for fixtures (the matrix covers their extensions)
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Frameworks in the matrix (comma-separated)
+        #[arg(short = 'F', long, value_delimiter = ',', required = true)]
+        frameworks: Vec<String>,
+
+        /// Benchmark modes in the matrix (comma-separated)
+        #[arg(
+            short = 'm',
+            long,
+            value_enum,
+            value_delimiter = ',',
+            default_value = "single-file,batch"
+        )]
+        modes: Vec<CliMode>,
+    },
+
+    /// Claim and run shards until none are left; rerun to resume shards of crashed workers
+    Work {
+        /// Matrix directory shared by all workers
+        #[arg(short, long)]
+        dir: PathBuf,
+
+        /// Directory or file pattern to search for fixtures
+        #[arg(short, long)]
+        fixtures: PathBuf,
+
+        /// Worker name recorded in claims (defaults to $HOSTNAME-<pid>)
+        #[arg(long)]
+        worker: Option<String>,
+
+        /// Minutes a claim stays valid before another worker may take the shard over
+        #[arg(long, default_value = "360")]
+        lease_minutes: u64,
+
+        /// Stop after running this many shards
+        #[arg(long)]
+        max_shards: Option<usize>,
+
+        /// Maximum concurrent extractions for batch-mode shards
+        #[arg(short = 'c', long)]
+        max_concurrent: Option<usize>,
+
+        /// Timeout in seconds
+        #[arg(short = 't', long, default_value = "1800")]
+        timeout: u64,
+
+        /// Number of warmup iterations (discarded from statistics)
+        #[arg(short = 'w', long, default_value = "1")]
+        warmup: usize,
+
+        /// Warmup iterations for one framework, overriding --warmup (repeatable)
+        #[arg(long = "adapter-warmup", value_name = "NAME=ITERATIONS")]
+        adapter_warmup: Vec<String>,
+
+        /// Number of benchmark iterations for statistical analysis
+        #[arg(short = 'i', long, default_value = "3")]
+        iterations: usize,
+
+        /// Enable OCR for image extraction
+        #[arg(long, default_value = "true")]
+        ocr: bool,
+
+        /// Enable quality assessment
+        #[arg(long, default_value = "true")]
+        measure_quality: bool,
+
+        /// Register a third-party adapter speaking the JSON lines adapter protocol (repeatable)
+        #[arg(long = "adapter", value_name = "NAME=PROGRAM [ARGS...]")]
+        custom_adapters: Vec<String>,
+    },
+
+    /// Merge the results of finished shards into one results.json
+    Merge {
+        /// Matrix directory shared by all workers
+        #[arg(short, long)]
+        dir: PathBuf,
+
+        /// Output directory for the merged results
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Merge even if shards are still pending or running
+        #[arg(long)]
+        allow_incomplete: bool,
+    },
+
+    /// Show the progress of every shard
+    Status {
+        /// Matrix directory shared by all workers
+        #[arg(short, long)]
+        dir: PathBuf,
     },
 }
 
@@ -203,6 +739,33 @@ async fn main() -> Result<()> {
             Ok(())
         }
 
+        Commands::FetchCorpus {
+            manifest,
+            cache_dir,
+            write_fixtures,
+        } => {
+            use benchmark_harness::{CorpusManifest, default_cache_dir, fetch_corpus};
+
+            let manifest = CorpusManifest::from_file(&manifest)?;
+            let cache_dir = cache_dir.unwrap_or_else(default_cache_dir);
+            let summary = fetch_corpus(&manifest, &cache_dir)?;
+
+            println!(
+                "✓ Corpus '{}': {} downloaded, {} cached ({})",
+                manifest.name,
+                summary.downloaded(),
+                summary.cached(),
+                summary.corpus_dir.display()
+            );
+
+            if let Some(fixtures_dir) = write_fixtures {
+                let written = benchmark_harness::write_fixtures(&manifest, &summary, &fixtures_dir)?;
+                println!("✓ Wrote {} fixture(s) to {}", written.len(), fixtures_dir.display());
+            }
+
+            Ok(())
+        }
+
         Commands::GenerateFlamegraphIndex { flamegraphs, output } => {
             use benchmark_harness::generate_flamegraph_index;
             generate_flamegraph_index(&flamegraphs, &output)?;
@@ -218,371 +781,930 @@ async fn main() -> Result<()> {
             timeout,
             mode,
             warmup,
+            adapter_warmup,
             iterations,
             ocr,
             measure_quality,
             format,
             benchmark_date,
+            pricing,
+            footprint,
+            fail_on_regression,
+            fail_on_leak,
+            custom_adapters,
+            scenario,
+            scenario_name,
+            profile,
         } => {
-            use benchmark_harness::{AdapterRegistry, BenchmarkRunner, NativeAdapter};
-            use kreuzberg::{ExtractionConfig, OcrConfig};
-            use std::sync::Arc;
-
             let config = BenchmarkConfig {
-                output_dir: output.clone(),
+                output_dir: output,
                 max_concurrent: max_concurrent.unwrap_or_else(num_cpus::get),
                 timeout: std::time::Duration::from_secs(timeout.unwrap_or(1800)),
                 benchmark_mode: mode.into(),
                 warmup_iterations: warmup,
+                adapter_warmup: parse_adapter_warmup(&adapter_warmup)?,
                 benchmark_iterations: iterations,
                 measure_quality,
+                profiling: ProfilingConfig {
+                    enabled: profile,
+                    ..Default::default()
+                },
                 ..Default::default()
             };
 
-            config.validate()?;
+            let options = RunOptions {
+                ocr,
+                format,
+                benchmark_date,
+                pricing: load_pricing(pricing.as_deref())?,
+                footprint: footprint
+                    .map(benchmark_harness::FootprintManifest::from_file)
+                    .transpose()?,
+                fail_on_regression,
+                fail_on_leak,
+                custom_adapters,
+            };
 
-            let extraction_config = if ocr {
-                ExtractionConfig {
-                    ocr: Some(OcrConfig {
-                        backend: "tesseract".to_string(),
-                        language: "eng".to_string(),
-                        tesseract_config: None,
-                    }),
-                    ..Default::default()
-                }
+            let Some(scenario_path) = scenario else {
+                let fixtures = fixtures.ok_or_else(|| {
+                    benchmark_harness::Error::Config("--fixtures is required without --scenario".to_string())
+                })?;
+                config.validate()?;
+                run_benchmarks(config, &fixtures, &frameworks, &options).await?;
+                return Ok(());
+            };
+
+            let scenario = benchmark_harness::load_scenario(&scenario_path, scenario_name.as_deref())?;
+            let fixtures = scenario.fixtures.clone().or(fixtures).ok_or_else(|| {
+                benchmark_harness::Error::Config(format!(
+                    "Scenario '{}' sets no fixtures; pass --fixtures",
+                    scenario.name
+                ))
+            })?;
+            let frameworks = if scenario.frameworks.is_empty() {
+                frameworks
+            } else {
+                scenario.frameworks.clone()
+            };
+            let modes = if scenario.modes.is_empty() {
+                vec![config.benchmark_mode]
             } else {
-                ExtractionConfig::default()
+                scenario.modes.clone()
             };
 
-            let mut registry = AdapterRegistry::new();
+            println!("Scenario: {}", scenario.name);
+            if let Some(description) = &scenario.description {
+                println!("  {}", description);
+            }
 
-            registry.register(Arc::new(NativeAdapter::with_config(extraction_config)))?;
-            eprintln!("[adapter] ✓ kreuzberg-native (registered)");
+            let mut violations = Vec::new();
+            for mode in modes {
+                let config = config.with_scenario(&scenario, mode);
+                config.validate()?;
 
-            use benchmark_harness::adapters::{
-                create_csharp_sync_adapter, create_go_batch_adapter, create_go_sync_adapter, create_java_sync_adapter,
-                create_node_async_adapter, create_node_batch_adapter, create_python_async_adapter,
-                create_python_batch_adapter, create_python_sync_adapter, create_ruby_batch_adapter,
-                create_ruby_sync_adapter, create_wasm_async_adapter, create_wasm_batch_adapter,
-            };
+                println!("\n=== {} ({:?}) ===", scenario.name, mode);
+                let results = run_benchmarks(config, &fixtures, &frameworks, &options).await?;
+                violations.extend(scenario.thresholds.check(&results, mode));
+            }
 
-            let mut kreuzberg_count = 1;
+            if scenario.thresholds.is_empty() {
+                return Ok(());
+            }
 
-            if let Ok(adapter) = create_python_sync_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ kreuzberg-python-sync (registered)");
-                    kreuzberg_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ kreuzberg-python-sync (registration failed)");
-                }
-            } else {
-                eprintln!("[adapter] ✗ kreuzberg-python-sync (initialization failed)");
+            println!("\nThresholds:");
+            if violations.is_empty() {
+                println!("  ✓ All frameworks within the thresholds of '{}'", scenario.name);
+                return Ok(());
+            }
+            for violation in &violations {
+                println!(
+                    "  ✗ {} ({:?}): {} {}, got {:.3}",
+                    violation.framework, violation.mode, violation.threshold, violation.limit, violation.actual
+                );
             }
 
-            if let Ok(adapter) = create_python_async_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ kreuzberg-python-async (registered)");
-                    kreuzberg_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ kreuzberg-python-async (registration failed)");
-                }
+            Err(benchmark_harness::Error::Benchmark(format!(
+                "{} scenario threshold(s) violated",
+                violations.len()
+            )))
+        }
+        Commands::Spreadsheets {
+            fixtures,
+            output,
+            iterations,
+            timeout,
+            stream,
+        } => {
+            use benchmark_harness::{run_spreadsheet_suite, write_spreadsheet_report};
+            use kreuzberg::{ExtractionConfig, SpreadsheetConfig};
+            use std::time::Duration;
+
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
             } else {
-                eprintln!("[adapter] ✗ kreuzberg-python-async (initialization failed)");
+                manager.load_fixture(&fixtures)?;
             }
 
-            if let Ok(adapter) = create_python_batch_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ kreuzberg-python-batch (registered)");
-                    kreuzberg_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ kreuzberg-python-batch (registration failed)");
-                }
-            } else {
-                eprintln!("[adapter] ✗ kreuzberg-python-batch (initialization failed)");
+            let config = ExtractionConfig {
+                use_cache: false,
+                spreadsheet_options: Some(SpreadsheetConfig {
+                    stream,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+
+            let report =
+                run_spreadsheet_suite(manager.fixtures(), &config, iterations, Duration::from_secs(timeout)).await?;
+
+            for skipped in &report.skipped {
+                eprintln!(
+                    "Skipped {} (document not found; run scripts/generate_spreadsheet_fixtures.py)",
+                    skipped.display()
+                );
             }
 
-            match create_go_sync_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-go-sync (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-go-sync (registered)");
-                        kreuzberg_count += 1;
-                    }
+            println!("\nSpreadsheet Summary:");
+            for summary in &report.workloads {
+                println!("  {}:", summary.workload.as_str());
+                println!("    Fixtures passed: {}/{}", summary.passed, summary.fixtures);
+                println!("    Mean duration: {:.2} ms", summary.mean_duration_ms);
+                println!("    Cells/sec: {:.0}", summary.cells_per_sec);
+                println!(
+                    "    Peak memory: {:.1} MB",
+                    summary.max_peak_memory_bytes as f64 / 1_048_576.0
+                );
+            }
+            for result in report.results.iter().filter(|result| !result.passed()) {
+                eprintln!("  FAILED {}", result.fixture.display());
+                if let Some(error) = &result.error_message {
+                    eprintln!("    {}", error);
+                }
+                for check in result.checks.iter().filter(|check| !check.passed) {
+                    eprintln!("    {}: expected {}, got {}", check.name, check.expected, check.actual);
                 }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-go-sync (initialization failed: {err})"),
             }
 
-            match create_go_batch_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-go-batch (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-go-batch (registered)");
-                        kreuzberg_count += 1;
-                    }
-                }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-go-batch (initialization failed: {err})"),
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("spreadsheet_results.json");
+            write_spreadsheet_report(&report, &output_file)?;
+            println!("\nSpreadsheet results written to: {}", output_file.display());
+
+            let failed = report.failed_fixtures();
+            if failed > 0 {
+                return Err(benchmark_harness::Error::Benchmark(format!(
+                    "{} spreadsheet fixture(s) failed correctness checks",
+                    failed
+                )));
             }
 
-            match create_node_async_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-node-async (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-node-async (registered)");
-                        kreuzberg_count += 1;
-                    }
-                }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-node-async (initialization failed: {err})"),
+            Ok(())
+        }
+
+        Commands::CodeDocuments {
+            fixtures,
+            frameworks,
+            output,
+            iterations,
+            timeout,
+        } => {
+            use benchmark_harness::adapters::external::{create_markitdown_adapter, create_pandoc_markdown_adapter};
+            use benchmark_harness::{CodeDocumentFramework, run_code_document_suite, write_code_document_report};
+            use kreuzberg::ExtractionConfig;
+            use std::time::Duration;
+
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
+            } else {
+                manager.load_fixture(&fixtures)?;
             }
 
-            match create_node_batch_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-node-batch (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-node-batch (registered)");
-                        kreuzberg_count += 1;
-                    }
+            let mut competitors = Vec::new();
+            for name in &frameworks {
+                let framework = match name.as_str() {
+                    "kreuzberg" => Ok(CodeDocumentFramework::Kreuzberg(ExtractionConfig {
+                        use_cache: false,
+                        ..Default::default()
+                    })),
+                    "markitdown" => create_markitdown_adapter().map(CodeDocumentFramework::External),
+                    "pandoc-markdown" => create_pandoc_markdown_adapter().map(CodeDocumentFramework::External),
+                    other => Err(benchmark_harness::Error::Config(format!(
+                        "Unknown code-document framework: {}",
+                        other
+                    ))),
+                };
+                match framework {
+                    Ok(framework) => competitors.push(framework),
+                    Err(err) => eprintln!("[adapter] ✗ {} (initialization failed: {})", name, err),
                 }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-node-batch (initialization failed: {err})"),
             }
 
-            match create_wasm_async_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-wasm-async (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-wasm-async (registered)");
-                        kreuzberg_count += 1;
-                    }
-                }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-wasm-async (initialization failed: {err})"),
+            let report = run_code_document_suite(
+                manager.fixtures(),
+                &competitors,
+                iterations,
+                Duration::from_secs(timeout),
+            )
+            .await?;
+
+            for skipped in &report.skipped {
+                eprintln!("Skipped {} (document not found)", skipped.display());
             }
 
-            match create_wasm_batch_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-wasm-batch (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-wasm-batch (registered)");
-                        kreuzberg_count += 1;
-                    }
+            println!("\nCode Document Summary:");
+            for summary in &report.summaries {
+                println!("  {} / {}:", summary.framework, summary.file_type);
+                println!("    Extracted: {}/{}", summary.succeeded, summary.fixtures);
+                println!("    Mean duration: {:.2} ms", summary.mean_duration_ms);
+                println!("    Mean fidelity: {:.2}", summary.mean_fidelity);
+            }
+            for result in report.results.iter().filter(|result| !result.success) {
+                eprintln!("  FAILED {} ({})", result.fixture.display(), result.framework);
+                if let Some(error) = &result.error_message {
+                    eprintln!("    {}", error);
                 }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-wasm-batch (initialization failed: {err})"),
             }
 
-            match create_ruby_sync_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-ruby-sync (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-ruby-sync (registered)");
-                        kreuzberg_count += 1;
-                    }
-                }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-ruby-sync (initialization failed: {err})"),
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("code_document_results.json");
+            write_code_document_report(&report, &output_file)?;
+            println!("\nCode document results written to: {}", output_file.display());
+
+            Ok(())
+        }
+
+        Commands::ChunkingRetrieval {
+            fixtures,
+            output,
+            preset,
+            modes,
+            sizes,
+            overlaps,
+            top_k,
+            timeout,
+        } => {
+            use benchmark_harness::{
+                ChunkingMode, run_chunking_retrieval_experiment, strategy_grid, write_chunking_retrieval_report,
+            };
+            use std::time::Duration;
+
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
+            } else {
+                manager.load_fixture(&fixtures)?;
             }
 
-            match create_ruby_batch_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-ruby-batch (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-ruby-batch (registered)");
-                        kreuzberg_count += 1;
-                    }
-                }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-ruby-batch (initialization failed: {err})"),
+            let modes = modes
+                .iter()
+                .map(|mode| mode.parse::<ChunkingMode>())
+                .collect::<benchmark_harness::Result<Vec<_>>>()?;
+            let strategies = strategy_grid(&modes, &sizes, &overlaps);
+            if strategies.is_empty() {
+                return Err(benchmark_harness::Error::Config(
+                    "No chunking strategies to compare (every overlap is at least the chunk size)".to_string(),
+                ));
             }
 
-            match create_java_sync_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-java-sync (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-java-sync (registered)");
-                        kreuzberg_count += 1;
-                    }
-                }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-java-sync (initialization failed: {err})"),
+            let report = run_chunking_retrieval_experiment(
+                manager.fixtures(),
+                &strategies,
+                &preset,
+                top_k,
+                Duration::from_secs(timeout),
+            )
+            .await?;
+
+            for skipped in &report.skipped {
+                eprintln!("Skipped {} (document not found)", skipped.display());
+            }
+            for (fixture, error) in &report.failed {
+                eprintln!("  FAILED {}", fixture.display());
+                eprintln!("    {}", error);
             }
 
-            match create_csharp_sync_adapter() {
-                Ok(adapter) => {
-                    if let Err(err) = registry.register(Arc::new(adapter)) {
-                        eprintln!("[adapter] ✗ kreuzberg-csharp-sync (registration failed: {err})");
-                    } else {
-                        eprintln!("[adapter] ✓ kreuzberg-csharp-sync (registered)");
-                        kreuzberg_count += 1;
-                    }
-                }
-                Err(err) => eprintln!("[adapter] ✗ kreuzberg-csharp-sync (initialization failed: {err})"),
+            println!(
+                "\nChunking Retrieval Summary (preset {}, top-{}):",
+                report.preset, report.top_k
+            );
+            for summary in &report.best_by_category {
+                println!("  {}:", summary.category);
+                println!("    Best strategy: {}", summary.strategy);
+                println!("    Hit rate: {:.2}", summary.hit_rate);
+                println!("    MRR: {:.2}", summary.mrr);
+                println!("    Mean chunks: {:.1}", summary.mean_chunk_count);
             }
 
-            eprintln!("[adapter] Kreuzberg bindings: {}/13 available", kreuzberg_count);
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("chunking_retrieval_results.json");
+            write_chunking_retrieval_report(&report, &output_file)?;
+            println!("\nChunking retrieval results written to: {}", output_file.display());
 
-            use benchmark_harness::adapters::external::{
-                create_docling_adapter, create_docling_batch_adapter, create_markitdown_adapter, create_pandoc_adapter,
-                create_tika_batch_adapter, create_tika_sync_adapter, create_unstructured_adapter,
-            };
+            Ok(())
+        }
 
-            let mut external_count = 0;
+        Commands::RagPipeline {
+            fixtures,
+            frameworks,
+            output,
+            preset,
+            mode,
+            size,
+            overlap,
+            top_k,
+            timeout,
+        } => {
+            use benchmark_harness::adapters::{
+                create_docling_adapter, create_markitdown_adapter, create_unstructured_adapter,
+            };
+            use benchmark_harness::{
+                ChunkingMode, ChunkingStrategy, RagFramework, run_rag_pipeline_benchmark, write_rag_pipeline_report,
+            };
+            use kreuzberg::ExtractionConfig;
+            use std::time::Duration;
 
-            if let Ok(adapter) = create_docling_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ docling (registered)");
-                    external_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ docling (registration failed)");
-                }
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
             } else {
-                eprintln!("[adapter] ✗ docling (initialization failed)");
+                manager.load_fixture(&fixtures)?;
             }
 
-            if let Ok(adapter) = create_docling_batch_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ docling-batch (registered)");
-                    external_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ docling-batch (registration failed)");
+            if size == 0 || overlap >= size {
+                return Err(benchmark_harness::Error::Config(format!(
+                    "Chunk overlap ({}) must be smaller than the chunk size ({})",
+                    overlap, size
+                )));
+            }
+            let strategy = ChunkingStrategy {
+                mode: mode.parse::<ChunkingMode>()?,
+                max_chars: size,
+                overlap,
+            };
+
+            let mut competitors = Vec::new();
+            for name in &frameworks {
+                let framework = match name.as_str() {
+                    "kreuzberg" => Ok(RagFramework::Kreuzberg(ExtractionConfig {
+                        use_cache: false,
+                        ..Default::default()
+                    })),
+                    "docling" => create_docling_adapter().map(RagFramework::External),
+                    "unstructured" => create_unstructured_adapter().map(RagFramework::External),
+                    "markitdown" => create_markitdown_adapter().map(RagFramework::External),
+                    other => Err(benchmark_harness::Error::Config(format!(
+                        "Unknown RAG pipeline framework: {}",
+                        other
+                    ))),
+                };
+                match framework {
+                    Ok(framework) => competitors.push(framework),
+                    Err(err) => eprintln!("[adapter] ✗ {} (initialization failed: {})", name, err),
                 }
-            } else {
-                eprintln!("[adapter] ✗ docling-batch (initialization failed)");
             }
 
-            if let Ok(adapter) = create_markitdown_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ markitdown (registered)");
-                    external_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ markitdown (registration failed)");
+            let report = run_rag_pipeline_benchmark(
+                manager.fixtures(),
+                &competitors,
+                strategy,
+                &preset,
+                top_k,
+                Duration::from_secs(timeout),
+            )
+            .await?;
+
+            for skipped in &report.skipped {
+                eprintln!("Skipped {} (document not found)", skipped.display());
+            }
+            for result in report.results.iter().filter(|result| result.error_message.is_some()) {
+                eprintln!("  FAILED {} ({})", result.fixture.display(), result.framework);
+                if let Some(error) = &result.error_message {
+                    eprintln!("    {}", error);
                 }
+            }
+
+            println!(
+                "\nRAG Pipeline Summary ({}, preset {}, top-{}):",
+                report.strategy.label(),
+                report.preset,
+                report.top_k
+            );
+            for summary in &report.summaries {
+                println!("  {}:", summary.framework);
+                println!("    Extracted: {}/{}", summary.succeeded, summary.documents);
+                println!("    End-to-end: {:.2} ms", summary.end_to_end_ms);
+                println!(
+                    "    Stages: extract {:.2} ms, chunk {:.2} ms, embed {:.2} ms, index {:.2} ms, query {:.2} ms",
+                    summary.stage_totals.extraction_ms,
+                    summary.stage_totals.chunking_ms,
+                    summary.stage_totals.embedding_ms,
+                    summary.stage_totals.indexing_ms,
+                    summary.query_ms
+                );
+                println!("    Hit rate: {:.2}", summary.hit_rate);
+                println!("    MRR: {:.2}", summary.mrr);
+            }
+
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("rag_pipeline_results.json");
+            write_rag_pipeline_report(&report, &output_file)?;
+            println!("\nRAG pipeline results written to: {}", output_file.display());
+
+            Ok(())
+        }
+
+        Commands::ImageHeavy {
+            fixtures,
+            frameworks,
+            output,
+            iterations,
+            timeout,
+        } => {
+            use benchmark_harness::adapters::{create_docling_adapter, create_unstructured_adapter};
+            use benchmark_harness::{
+                ImageHeavyFramework, NativeAdapter, run_image_heavy_suite, write_image_heavy_report,
+            };
+            use kreuzberg::{ExtractionConfig, OcrConfig};
+            use std::time::Duration;
+
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
             } else {
-                eprintln!("[adapter] ✗ markitdown (initialization failed)");
+                manager.load_fixture(&fixtures)?;
             }
 
-            if let Ok(adapter) = create_pandoc_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ pandoc (registered)");
-                    external_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ pandoc (registration failed)");
+            let mut competitors = Vec::new();
+            for name in &frameworks {
+                let framework = match name.as_str() {
+                    "kreuzberg" => Ok(ImageHeavyFramework::Kreuzberg(NativeAdapter::with_config(
+                        ExtractionConfig {
+                            use_cache: false,
+                            force_ocr: true,
+                            ocr: Some(OcrConfig {
+                                backend: "tesseract".to_string(),
+                                language: "eng".to_string(),
+                                tesseract_config: None,
+                                fallback: None,
+                                vocabulary: None,
+                            }),
+                            ..Default::default()
+                        },
+                    ))),
+                    "docling" => create_docling_adapter().map(ImageHeavyFramework::External),
+                    "unstructured" => create_unstructured_adapter().map(ImageHeavyFramework::External),
+                    other => Err(benchmark_harness::Error::Config(format!(
+                        "Unknown image-heavy framework: {}",
+                        other
+                    ))),
+                };
+                match framework {
+                    Ok(framework) => competitors.push(framework),
+                    Err(err) => eprintln!("[adapter] ✗ {} (initialization failed: {})", name, err),
                 }
-            } else {
-                eprintln!("[adapter] ✗ pandoc (initialization failed)");
             }
 
-            if let Ok(adapter) = create_unstructured_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ unstructured (registered)");
-                    external_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ unstructured (registration failed)");
+            let report = run_image_heavy_suite(
+                manager.fixtures(),
+                &competitors,
+                iterations,
+                Duration::from_secs(timeout),
+            )
+            .await?;
+
+            for skipped in &report.skipped {
+                eprintln!("Skipped {} (document not found)", skipped.display());
+            }
+
+            println!("\nImage-Heavy PDF Summary:");
+            for summary in &report.summaries {
+                println!("  {}:", summary.framework);
+                println!("    Extracted: {}/{}", summary.succeeded, summary.fixtures);
+                println!("    Mean duration: {:.2} ms", summary.mean_duration_ms);
+                if let Some(per_page) = summary.mean_duration_per_page_ms {
+                    println!("    Mean per page: {:.2} ms", per_page);
+                }
+                match &summary.stage_shares {
+                    Some(shares) => {
+                        for (stage, share) in shares {
+                            println!("    {}: {:.1}%", stage, share * 100.0);
+                        }
+                    }
+                    None => println!("    Stage breakdown: not reported"),
                 }
+                if let Some(bottleneck) = summary.bottleneck {
+                    println!("    Bottleneck: {}", bottleneck);
+                }
+            }
+            for result in report.results.iter().filter(|result| !result.success) {
+                eprintln!("  FAILED {} ({})", result.fixture.display(), result.framework);
+                if let Some(error) = &result.error_message {
+                    eprintln!("    {}", error);
+                }
+            }
+
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("image_heavy_results.json");
+            write_image_heavy_report(&report, &output_file)?;
+            println!("\nImage-heavy results written to: {}", output_file.display());
+
+            Ok(())
+        }
+
+        Commands::OcrReuse {
+            fixtures,
+            frameworks,
+            output,
+            threshold,
+            timeout,
+        } => {
+            use benchmark_harness::adapters::{
+                create_docling_adapter, create_python_sync_adapter, create_ruby_sync_adapter,
+                create_unstructured_adapter,
+            };
+            use benchmark_harness::{
+                FrameworkAdapter, NativeAdapter, run_ocr_reuse_suite, write_ocr_reuse_markdown, write_ocr_reuse_report,
+            };
+            use kreuzberg::{ExtractionConfig, OcrConfig};
+            use std::sync::Arc;
+            use std::time::Duration;
+
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
             } else {
-                eprintln!("[adapter] ✗ unstructured (initialization failed)");
+                manager.load_fixture(&fixtures)?;
             }
 
-            if let Ok(adapter) = create_tika_sync_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ tika-sync (registered)");
-                    external_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ tika-sync (registration failed)");
+            let mut adapters: Vec<Arc<dyn FrameworkAdapter>> = Vec::new();
+            for name in &frameworks {
+                let adapter: benchmark_harness::Result<Arc<dyn FrameworkAdapter>> = match name.as_str() {
+                    "kreuzberg" => Ok(Arc::new(NativeAdapter::with_config(ExtractionConfig {
+                        use_cache: false,
+                        force_ocr: true,
+                        ocr: Some(OcrConfig {
+                            backend: "tesseract".to_string(),
+                            language: "eng".to_string(),
+                            tesseract_config: None,
+                            fallback: None,
+                            vocabulary: None,
+                        }),
+                        ..Default::default()
+                    }))),
+                    "kreuzberg-python" => create_python_sync_adapter().map(|a| Arc::new(a) as _),
+                    "kreuzberg-ruby" => create_ruby_sync_adapter().map(|a| Arc::new(a) as _),
+                    "docling" => create_docling_adapter().map(|a| Arc::new(a) as _),
+                    "unstructured" => create_unstructured_adapter().map(|a| Arc::new(a) as _),
+                    other => Err(benchmark_harness::Error::Config(format!(
+                        "Unknown OCR reuse framework: {}",
+                        other
+                    ))),
+                };
+                match adapter {
+                    Ok(adapter) => adapters.push(adapter),
+                    Err(err) => eprintln!("[adapter] ✗ {} (initialization failed: {})", name, err),
                 }
-            } else {
-                eprintln!("[adapter] ✗ tika-sync (initialization failed)");
             }
 
-            if let Ok(adapter) = create_tika_batch_adapter() {
-                if let Ok(()) = registry.register(Arc::new(adapter)) {
-                    eprintln!("[adapter] ✓ tika-batch (registered)");
-                    external_count += 1;
-                } else {
-                    eprintln!("[adapter] ✗ tika-batch (registration failed)");
+            let report =
+                run_ocr_reuse_suite(manager.fixtures(), &adapters, Duration::from_secs(timeout), threshold).await?;
+
+            for skipped in &report.skipped {
+                eprintln!("Skipped {} (document not found)", skipped.display());
+            }
+
+            println!("\nOCR Engine Reuse Summary:");
+            for result in &report.results {
+                println!("  {}:", result.framework);
+                println!("    OCR documents: {}", result.first_pass.len());
+                if let (Some(first), Some(steady)) = (result.first_document, result.steady_state) {
+                    println!("    First document: {:.2} ms", first.as_secs_f64() * 1000.0);
+                    println!("    Steady state: {:.2} ms", steady.as_secs_f64() * 1000.0);
+                }
+                if let Some(ratio) = result.ratio {
+                    println!("    Ratio: {:.2}x", ratio);
+                }
+                println!("    OCR engine reuse: {}", result.reuse);
+                for failed in result.first_pass.iter().filter(|sample| !sample.success) {
+                    eprintln!("    FAILED {}", failed.fixture.display());
+                    if let Some(error) = &failed.error_message {
+                        eprintln!("      {}", error);
+                    }
                 }
-            } else {
-                eprintln!("[adapter] ✗ tika-batch (initialization failed)");
             }
 
-            eprintln!(
-                "[adapter] Open source extraction frameworks: {}/7 available",
-                external_count
-            );
-            eprintln!(
-                "[adapter] Total adapters: {} available",
-                kreuzberg_count + external_count
-            );
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("ocr_reuse_results.json");
+            write_ocr_reuse_report(&report, &output_file)?;
+            let matrix_file = output.join("ocr_reuse_results.md");
+            write_ocr_reuse_markdown(&report, &matrix_file)?;
+            println!("\nOCR reuse results written to: {}", output_file.display());
+            println!("Capability matrix written to: {}", matrix_file.display());
 
-            let mut runner = BenchmarkRunner::new(config, registry);
-            runner.load_fixtures(&fixtures)?;
+            Ok(())
+        }
 
-            println!("Loaded {} fixture(s)", runner.fixture_count());
-            println!("Frameworks: {:?}", frameworks);
-            println!("Configuration: {:?}", runner.config());
+        Commands::CacheCorrectness {
+            fixtures,
+            output,
+            timeout,
+        } => {
+            use benchmark_harness::{permutation_grid, run_cache_correctness_suite, write_cache_correctness_report};
+            use std::time::Duration;
 
-            if runner.fixture_count() == 0 {
-                println!("No fixtures to benchmark");
-                return Ok(());
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
+            } else {
+                manager.load_fixture(&fixtures)?;
             }
 
-            println!("\nRunning benchmarks...");
-            let results = runner.run(&frameworks).await?;
+            let permutations = permutation_grid();
+            let report =
+                run_cache_correctness_suite(manager.fixtures(), &permutations, Duration::from_secs(timeout)).await?;
 
-            println!("\nCompleted {} benchmark(s)", results.len());
-
-            let mut success_count = 0;
-            let mut failure_count = 0;
+            for skipped in &report.skipped {
+                eprintln!("Skipped {} (document not found)", skipped.display());
+            }
 
-            for result in &results {
-                if result.success {
-                    success_count += 1;
-                } else {
-                    failure_count += 1;
+            println!("\nCache Correctness Summary:");
+            println!("  Permutations: {}", report.permutations.len());
+            println!("  Checks: {}", report.checks.len());
+            println!("  Mismatches: {}", report.mismatches());
+            println!("  Key collisions: {}", report.key_collisions.len());
+            for check in report.checks.iter().filter(|check| check.mismatch) {
+                eprintln!("  MISMATCH {} ({})", check.document.display(), check.permutation);
+                if let Some(served_from) = &check.served_from {
+                    eprintln!("    served the result of {}", served_from);
+                }
+            }
+            for collision in &report.key_collisions {
+                eprintln!(
+                    "  COLLISION {} ({} / {}): {}",
+                    collision.document.display(),
+                    collision.permutations.0,
+                    collision.permutations.1,
+                    collision.key
+                );
+            }
+            for check in &report.checks {
+                if let Some(error) = &check.error_message {
+                    eprintln!(
+                        "  FAILED {} ({}): {}",
+                        check.document.display(),
+                        check.permutation,
+                        error
+                    );
                 }
             }
 
-            println!("\nSummary:");
-            println!("  Successful: {}", success_count);
-            println!("  Failed: {}", failure_count);
-            println!("  Total: {}", results.len());
-
-            use benchmark_harness::{write_by_extension_analysis, write_html, write_json};
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("cache_correctness_results.json");
+            write_cache_correctness_report(&report, &output_file)?;
+            println!("\nCache correctness results written to: {}", output_file.display());
+
+            if !report.is_clean() {
+                return Err(benchmark_harness::Error::Benchmark(format!(
+                    "Result cache served {} mismatched result(s) with {} key collision(s)",
+                    report.mismatches(),
+                    report.key_collisions.len()
+                )));
+            }
 
-            match format {
-                OutputFormat::Json => {
-                    let output_file = output.join("results.json");
-                    write_json(&results, &output_file)?;
-                    println!("\nResults written to: {}", output_file.display());
+            Ok(())
+        }
 
-                    let by_ext_file = output.join("by-extension.json");
-                    write_by_extension_analysis(&results, &by_ext_file)?;
-                    println!("Per-extension analysis written to: {}", by_ext_file.display());
+        Commands::Bisect {
+            good,
+            bad,
+            fixtures,
+            metric,
+            threshold,
+            iterations,
+            warmup,
+            timeout,
+            repo,
+            work_dir,
+            output,
+        } => {
+            use benchmark_harness::{BisectConfig, run_bisect, write_bisect_report};
+
+            let config = BisectConfig {
+                repo,
+                good,
+                bad,
+                fixtures,
+                metric: metric.into(),
+                threshold,
+                iterations,
+                warmup,
+                timeout,
+                work_dir,
+            };
+            let report = tokio::task::spawn_blocking(move || run_bisect(&config))
+                .await
+                .map_err(|e| benchmark_harness::Error::Benchmark(format!("Bisect task failed: {}", e)))??;
+
+            println!("\nBisect Summary:");
+            println!("  Metric: {} ({})", report.metric, report.unit);
+            println!("  Baseline ({}): {:.2}", &report.good[..12], report.baseline);
+            println!("  Commits in range: {}", report.commits_in_range);
+            println!("  Revisions measured: {}", report.steps.len() + 1);
+            for step in &report.steps {
+                match step.value {
+                    Some(value) => println!(
+                        "    {} {:.2}{}",
+                        &step.commit[..12],
+                        value,
+                        if step.regressed { " (regressed)" } else { "" }
+                    ),
+                    None => println!("    {} skipped", &step.commit[..12]),
                 }
-                OutputFormat::Html => {
-                    let html_file = output.join("index.html");
-                    write_html(&results, &html_file, benchmark_date.as_deref())?;
-                    println!("\nHTML report written to: {}", html_file.display());
+            }
+            match (&report.first_bad_commit, &report.first_bad_subject) {
+                (Some(commit), subject) => {
+                    println!(
+                        "  First bad commit: {} {}",
+                        commit,
+                        subject.as_deref().unwrap_or_default()
+                    );
+                    if !report.untestable.is_empty() {
+                        println!(
+                            "  Could also be one of {} untestable commit(s) before it: {}",
+                            report.untestable.len(),
+                            report.untestable.join(", ")
+                        );
+                    }
                 }
-                OutputFormat::Both => {
-                    let output_file = output.join("results.json");
-                    write_json(&results, &output_file)?;
-                    println!("\nResults written to: {}", output_file.display());
+                (None, _) => println!(
+                    "  No regression: {} is within {:.0}% of {}",
+                    &report.bad[..12],
+                    report.threshold * 100.0,
+                    &report.good[..12]
+                ),
+            }
 
-                    let by_ext_file = output.join("by-extension.json");
-                    write_by_extension_analysis(&results, &by_ext_file)?;
-                    println!("Per-extension analysis written to: {}", by_ext_file.display());
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("bisect_results.json");
+            write_bisect_report(&report, &output_file)?;
+            println!("\nBisect results written to: {}", output_file.display());
+
+            Ok(())
+        }
+
+        Commands::FlameDiff {
+            baseline,
+            candidate,
+            fixtures,
+            iterations,
+            timeout,
+            repo,
+            work_dir,
+            output,
+        } => {
+            use benchmark_harness::{FlameDiffConfig, run_flame_diff};
+
+            let config = FlameDiffConfig {
+                repo,
+                baseline,
+                candidate,
+                fixtures,
+                iterations,
+                timeout,
+                work_dir,
+                output: output.clone(),
+            };
+            let report = tokio::task::spawn_blocking(move || run_flame_diff(&config))
+                .await
+                .map_err(|e| benchmark_harness::Error::Benchmark(format!("Flame-diff task failed: {}", e)))??;
+
+            println!("\nFlame Diff Summary:");
+            println!("  Baseline: {}", &report.baseline[..12]);
+            println!("  Candidate: {}", &report.candidate[..12]);
+            for diff in &report.diffs {
+                let change = diff
+                    .duration_change()
+                    .map(|change| format!("{:+.1}%", change * 100.0))
+                    .unwrap_or_else(|| "n/a".to_string());
+                match diff.regressions.first() {
+                    Some(top) => println!(
+                        "    {} {} (top regression: {} {:+.1} pts)",
+                        diff.fixture,
+                        change,
+                        top.function,
+                        top.self_delta() * 100.0
+                    ),
+                    None => println!("    {} {}", diff.fixture, change),
+                }
+            }
+            if !report.unmatched.is_empty() {
+                println!("  Profiled under only one revision: {}", report.unmatched.join(", "));
+            }
+
+            println!(
+                "\nDifferential flamegraphs written to: {}",
+                output.join(benchmark_harness::flame_diff::FLAME_DIFFS_DIR).display()
+            );
+            println!(
+                "Render the HTML report with: benchmark-harness visualize --inputs {}",
+                output.display()
+            );
+
+            Ok(())
+        }
+
+        Commands::Sanitize {
+            fixtures,
+            toolchain,
+            target,
+            skip_asan,
+            skip_miri,
+            miri_documents,
+            timeout,
+            repo,
+            work_dir,
+            output,
+        } => {
+            use benchmark_harness::{SanitizerConfig, run_sanitizers, write_sanitizer_report};
+
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
+            } else {
+                manager.load_fixture(&fixtures)?;
+            }
 
-                    let html_file = output.join("index.html");
-                    write_html(&results, &html_file, benchmark_date.as_deref())?;
-                    println!("HTML report written to: {}", html_file.display());
+            let config = SanitizerConfig {
+                repo,
+                fixtures,
+                toolchain,
+                target,
+                asan: !skip_asan,
+                miri: !skip_miri,
+                miri_documents,
+                timeout,
+                work_dir,
+            };
+            let fixtures = manager.fixtures().to_vec();
+            let report = tokio::task::spawn_blocking(move || run_sanitizers(&config, &fixtures))
+                .await
+                .map_err(|e| benchmark_harness::Error::Benchmark(format!("Sanitizer task failed: {}", e)))??;
+
+            println!("\nSanitizer Summary:");
+            println!("  Toolchain: {} ({})", report.toolchain, report.target);
+            for run in &report.runs {
+                println!("  {}: {} finding(s)", run.tool, run.findings.len());
+                for document in &run.documents {
+                    println!("    checked {}", document.display());
+                }
+                for finding in &run.findings {
+                    eprintln!("    {} {}", finding.kind.to_uppercase(), finding.message);
+                    if let Some(frame) = finding.frames.first() {
+                        eprintln!("      at {}", frame);
+                    }
                 }
+                if let Some(error) = &run.error {
+                    eprintln!("    SKIPPED {}", error);
+                }
+            }
+
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+            let output_file = output.join("sanitizer_results.json");
+            write_sanitizer_report(&report, &output_file)?;
+            println!("\nSanitizer results written to: {}", output_file.display());
+
+            if report.finding_count() > 0 {
+                return Err(benchmark_harness::Error::Benchmark(format!(
+                    "{} memory-safety finding(s) reported",
+                    report.finding_count()
+                )));
             }
 
             Ok(())
         }
+
+        Commands::MiriExtract { documents } => {
+            let config = kreuzberg::ExtractionConfig {
+                use_cache: false,
+                ..Default::default()
+            };
+            for document in &documents {
+                kreuzberg::extract_file_sync(document, None, &config).map_err(|e| {
+                    benchmark_harness::Error::ExtractionFailed {
+                        framework: "kreuzberg".to_string(),
+                        file: document.clone(),
+                        message: e.to_string(),
+                    }
+                })?;
+            }
+            println!("Extracted {} document(s)", documents.len());
+            Ok(())
+        }
+
+        Commands::Matrix { command } => run_matrix(command).await,
+
         Commands::Consolidate {
             inputs,
             output,
@@ -653,6 +1775,12 @@ async fn main() -> Result<()> {
                     write_simple_html(&consolidated, &html_file)?;
                     println!("Consolidated HTML report written to: {}", html_file.display());
                 }
+                OutputFormat::Markdown => {
+                    return Err(benchmark_harness::Error::Benchmark(
+                        "Markdown output is not supported for consolidated results; use `visualize` instead"
+                            .to_string(),
+                    ));
+                }
             }
 
             Ok(())
@@ -663,8 +1791,18 @@ async fn main() -> Result<()> {
             output,
             format,
             benchmark_date,
+            pricing,
+            footprint,
         } => {
-            use benchmark_harness::{load_run_results, write_by_extension_analysis, write_html, write_json};
+            use benchmark_harness::{
+                load_run_results, write_by_extension_analysis, write_cost_report, write_html_with_diffs, write_json,
+                write_markdown, write_winner_report,
+            };
+
+            let pricing = load_pricing(pricing.as_deref())?;
+            let footprint = footprint
+                .map(benchmark_harness::FootprintManifest::from_file)
+                .transpose()?;
 
             if inputs.is_empty() {
                 return Err(benchmark_harness::Error::Benchmark(
@@ -692,6 +1830,10 @@ async fn main() -> Result<()> {
 
             std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
 
+            if let Some(manifest) = &footprint {
+                report_footprint(manifest, &results, &output)?;
+            }
+
             match format {
                 OutputFormat::Json => {
                     let output_file = output.join("results.json");
@@ -701,10 +1843,18 @@ async fn main() -> Result<()> {
                     let by_ext_file = output.join("by-extension.json");
                     write_by_extension_analysis(&results, &by_ext_file)?;
                     println!("Per-extension analysis written to: {}", by_ext_file.display());
+
+                    let cost_file = output.join("cost.json");
+                    write_cost_report(&results, &pricing, &cost_file)?;
+                    println!("Cost estimates written to: {}", cost_file.display());
+
+                    let winners_file = output.join("winners.json");
+                    write_winner_report(&results, &winners_file)?;
+                    println!("Winner summaries written to: {}", winners_file.display());
                 }
                 OutputFormat::Html => {
                     let html_file = output.join("index.html");
-                    write_html(&results, &html_file, benchmark_date.as_deref())?;
+                    write_html_with_diffs(&results, &html_file, benchmark_date.as_deref(), &pricing, &inputs)?;
                     println!("\nHTML report written to: {}", html_file.display());
                 }
                 OutputFormat::Both => {
@@ -716,17 +1866,842 @@ async fn main() -> Result<()> {
                     write_by_extension_analysis(&results, &by_ext_file)?;
                     println!("Per-extension analysis written to: {}", by_ext_file.display());
 
+                    let cost_file = output.join("cost.json");
+                    write_cost_report(&results, &pricing, &cost_file)?;
+                    println!("Cost estimates written to: {}", cost_file.display());
+
+                    let winners_file = output.join("winners.json");
+                    write_winner_report(&results, &winners_file)?;
+                    println!("Winner summaries written to: {}", winners_file.display());
+
                     let html_file = output.join("index.html");
-                    write_html(&results, &html_file, benchmark_date.as_deref())?;
+                    write_html_with_diffs(&results, &html_file, benchmark_date.as_deref(), &pricing, &inputs)?;
                     println!("HTML report written to: {}", html_file.display());
                 }
+                OutputFormat::Markdown => {
+                    let markdown_file = output.join("results.md");
+                    write_markdown(&results, &markdown_file)?;
+                    println!("\nMarkdown report written to: {}", markdown_file.display());
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Load a cloud pricing model from file, falling back to the default model
+async fn run_matrix(command: MatrixCommand) -> Result<()> {
+    use benchmark_harness::matrix::{default_worker_id, shard_results_path, unix_now};
+    use benchmark_harness::{
+        AdapterRegistry, BenchmarkRunner, MatrixQueue, ShardState, claim_shard, complete_shard, create_queue,
+        fail_shard, load_queue, merge_shards, write_by_extension_analysis, write_json, write_winner_report,
+    };
+
+    match command {
+        MatrixCommand::Plan {
+            dir,
+            fixtures,
+            frameworks,
+            modes,
+        } => {
+            let mut manager = FixtureManager::new();
+            if fixtures.is_dir() {
+                manager.load_fixtures_from_dir(&fixtures)?;
+            } else {
+                manager.load_fixture(&fixtures)?;
+            }
+
+            let mut extensions: Vec<String> = manager
+                .fixtures()
+                .iter()
+                .map(|(_, fixture)| fixture.file_type.clone())
+                .collect();
+            extensions.sort();
+            extensions.dedup();
+
+            let modes: Vec<BenchmarkMode> = modes.into_iter().map(Into::into).collect();
+            let queue = MatrixQueue::plan(&frameworks, &extensions, &modes);
+            create_queue(&dir, &queue)?;
+
+            println!(
+                "✓ Planned {} shard(s): {} framework(s) × {} extension(s) × {} mode(s)",
+                queue.shards.len(),
+                frameworks.len(),
+                extensions.len(),
+                modes.len()
+            );
+            println!(
+                "Work queue written to: {}",
+                dir.join(benchmark_harness::matrix::QUEUE_FILE).display()
+            );
+            Ok(())
+        }
+
+        MatrixCommand::Work {
+            dir,
+            fixtures,
+            worker,
+            lease_minutes,
+            max_shards,
+            max_concurrent,
+            timeout,
+            warmup,
+            adapter_warmup,
+            iterations,
+            ocr,
+            measure_quality,
+            custom_adapters,
+        } => {
+            let worker = worker.unwrap_or_else(default_worker_id);
+            let lease = std::time::Duration::from_secs(lease_minutes * 60);
+            let adapter_warmup = parse_adapter_warmup(&adapter_warmup)?;
+
+            let adapters = register_adapters(extraction_config(ocr), &custom_adapters)?;
+            let available = adapters.adapter_names();
+
+            let mut completed = 0;
+            while max_shards.is_none_or(|max| completed < max) {
+                let Some(shard) = claim_shard(&dir, &worker, &available, lease)? else {
+                    break;
+                };
+                println!("\n[{}] Running shard {} (attempt {})", worker, shard.id, shard.attempts);
+
+                let config = BenchmarkConfig {
+                    output_dir: dir.join("shards").join(&shard.id),
+                    max_concurrent: match shard.cell.mode {
+                        BenchmarkMode::SingleFile => 1,
+                        BenchmarkMode::Batch => max_concurrent.unwrap_or_else(num_cpus::get),
+                    },
+                    timeout: std::time::Duration::from_secs(timeout),
+                    benchmark_mode: shard.cell.mode,
+                    warmup_iterations: warmup,
+                    adapter_warmup: adapter_warmup.clone(),
+                    benchmark_iterations: iterations,
+                    measure_quality,
+                    ..Default::default()
+                };
+                config.validate()?;
+
+                let mut registry = AdapterRegistry::new();
+                if let Some(adapter) = adapters.get(&shard.cell.framework) {
+                    registry.register(adapter)?;
+                }
+
+                let mut runner = BenchmarkRunner::new(config, registry);
+                runner.load_fixtures(&fixtures)?;
+                runner.filter_fixtures(std::slice::from_ref(&shard.cell.extension));
+
+                match runner.run(std::slice::from_ref(&shard.cell.framework)).await {
+                    Ok(results) => {
+                        complete_shard(&dir, &shard, &worker, &results)?;
+                        println!("✓ Shard {}: {} result(s)", shard.id, results.len());
+                    }
+                    Err(e) => {
+                        fail_shard(&dir, &shard, &worker, &e.to_string())?;
+                        eprintln!("✗ Shard {} failed: {}", shard.id, e);
+                    }
+                }
+                completed += 1;
+            }
+
+            let summary = load_queue(&dir)?.summary(unix_now());
+            println!(
+                "\n[{}] Ran {} shard(s); queue: {} done, {} running, {} pending, {} failed",
+                worker, completed, summary.done, summary.running, summary.pending, summary.failed
+            );
+            Ok(())
+        }
+
+        MatrixCommand::Merge {
+            dir,
+            output,
+            allow_incomplete,
+        } => {
+            let summary = load_queue(&dir)?.summary(unix_now());
+            if !summary.is_finished() && !allow_incomplete {
+                return Err(benchmark_harness::Error::Benchmark(format!(
+                    "Matrix is incomplete ({} pending, {} running); pass --allow-incomplete to merge anyway",
+                    summary.pending, summary.running
+                )));
+            }
+
+            let results = merge_shards(&dir)?;
+            std::fs::create_dir_all(&output).map_err(benchmark_harness::Error::Io)?;
+
+            let output_file = output.join("results.json");
+            write_json(&results, &output_file)?;
+            println!(
+                "Merged {} result(s) from {} shard(s) into: {}",
+                results.len(),
+                summary.done,
+                output_file.display()
+            );
+
+            let by_ext_file = output.join("by-extension.json");
+            write_by_extension_analysis(&results, &by_ext_file)?;
+            println!("Per-extension analysis written to: {}", by_ext_file.display());
+
+            let winners_file = output.join("winners.json");
+            write_winner_report(&results, &winners_file)?;
+            println!("Winner summaries written to: {}", winners_file.display());
+
+            if summary.failed > 0 {
+                eprintln!(
+                    "Warning: {} shard(s) failed and are missing from the merge",
+                    summary.failed
+                );
+            }
+            Ok(())
+        }
+
+        MatrixCommand::Status { dir } => {
+            let queue = load_queue(&dir)?;
+            let now = unix_now();
+
+            for shard in &queue.shards {
+                let state = match &shard.state {
+                    ShardState::Pending => "pending".to_string(),
+                    ShardState::Claimed {
+                        worker,
+                        lease_expires_at,
+                    } if *lease_expires_at > now => format!("running on {}", worker),
+                    ShardState::Claimed { worker, .. } => format!("lease of {} expired", worker),
+                    ShardState::Done { result_count, .. } => format!(
+                        "done ({} result(s) in {})",
+                        result_count,
+                        shard_results_path(&dir, &shard.id).display()
+                    ),
+                    ShardState::Failed { error, .. } => format!("failed: {}", error),
+                };
+                println!("  {} [attempts: {}] {}", shard.id, shard.attempts, state);
             }
 
+            let summary = queue.summary(now);
+            println!(
+                "\n{} shard(s): {} done, {} running, {} pending, {} failed",
+                queue.shards.len(),
+                summary.done,
+                summary.running,
+                summary.pending,
+                summary.failed
+            );
             Ok(())
         }
     }
 }
 
+/// Options of `run` shared by every mode of a scenario
+struct RunOptions {
+    ocr: bool,
+    format: OutputFormat,
+    benchmark_date: Option<String>,
+    pricing: benchmark_harness::CloudPricing,
+    footprint: Option<benchmark_harness::FootprintManifest>,
+    fail_on_regression: bool,
+    fail_on_leak: bool,
+    custom_adapters: Vec<String>,
+}
+
+/// Run one benchmark configuration and write its reports to `config.output_dir`
+async fn run_benchmarks(
+    config: BenchmarkConfig,
+    fixtures: &PathBuf,
+    frameworks: &[String],
+    options: &RunOptions,
+) -> Result<Vec<benchmark_harness::BenchmarkResult>> {
+    use benchmark_harness::BenchmarkRunner;
+
+    let output = config.output_dir.clone();
+    let registry = register_adapters(extraction_config(options.ocr), &options.custom_adapters)?;
+
+    let mut runner = BenchmarkRunner::new(config, registry);
+    runner.load_fixtures(fixtures)?;
+
+    println!("Loaded {} fixture(s)", runner.fixture_count());
+    println!("Frameworks: {:?}", frameworks);
+    println!("Configuration: {:?}", runner.config());
+
+    if runner.fixture_count() == 0 {
+        println!("No fixtures to benchmark");
+        return Ok(Vec::new());
+    }
+
+    println!("\nRunning benchmarks...");
+    let results = runner.run(frameworks).await?;
+
+    println!("\nCompleted {} benchmark(s)", results.len());
+
+    let mut success_count = 0;
+    let mut failure_count = 0;
+
+    for result in &results {
+        if result.success {
+            success_count += 1;
+        } else {
+            failure_count += 1;
+        }
+    }
+
+    println!("\nSummary:");
+    println!("  Successful: {}", success_count);
+    println!("  Failed: {}", failure_count);
+    println!("  Total: {}", results.len());
+
+    use benchmark_harness::{
+        AccuracyReport, CorrectnessReport, FailureTaxonomy, LeakReport, write_accuracy_report,
+        write_by_extension_analysis, write_correctness_report, write_cost_report, write_failure_report,
+        write_html_with_diffs, write_json, write_leak_report, write_markdown, write_winner_report,
+    };
+
+    let correctness = CorrectnessReport::from_results(&results);
+    if correctness.evaluated > 0 {
+        println!("\nCorrectness:");
+        println!("  Assertions passed: {}/{}", correctness.passed, correctness.evaluated);
+        for regression in &correctness.regressions {
+            println!("  ✗ {} on {}", regression.framework, regression.file_path.display());
+            for failure in &regression.failures {
+                println!(
+                    "      {}: expected {}, got {}",
+                    failure.assertion, failure.expected, failure.actual
+                );
+            }
+        }
+
+        let correctness_file = output.join("correctness.json");
+        write_correctness_report(&correctness, &correctness_file)?;
+        println!("Correctness report written to: {}", correctness_file.display());
+    }
+
+    let accuracy = AccuracyReport::from_results(&results);
+    if !accuracy.is_empty() {
+        println!("\nAccuracy by text provenance:");
+        for framework in &accuracy.frameworks {
+            for (provenance, scores) in &framework.by_provenance {
+                println!(
+                    "  {} ({}): quality {:.3}, text F1 {:.3} over {} file(s)",
+                    framework.framework, provenance, scores.mean.quality_score, scores.mean.f1_score_text, scores.files
+                );
+            }
+            for file_path in &framework.unneeded_ocr {
+                println!("  ✗ {} OCR'd born-digital {}", framework.framework, file_path.display());
+            }
+        }
+
+        let accuracy_file = output.join("accuracy.json");
+        write_accuracy_report(&accuracy, &accuracy_file)?;
+        println!("Accuracy report written to: {}", accuracy_file.display());
+    }
+
+    let leaks = LeakReport::from_results(&results);
+    if leaks.checked > 0 {
+        println!("\nResource leaks:");
+        println!(
+            "  Leak-free: {}/{} ({} with descriptor counts)",
+            leaks.checked - leaks.findings.len(),
+            leaks.checked,
+            leaks.fd_checked
+        );
+        for finding in &leaks.findings {
+            println!("  ✗ {} on {}", finding.framework, finding.file_path.display());
+            if let Some(fds) = finding.leaks.leaked_fds.filter(|&fds| fds > 0) {
+                println!("      {} file descriptor(s) left open", fds);
+            }
+            if !finding.leaks.leaked_temp_files.is_empty() {
+                println!(
+                    "      temp files left behind: {}",
+                    finding.leaks.leaked_temp_files.join(", ")
+                );
+            }
+        }
+
+        let leaks_file = output.join("leaks.json");
+        write_leak_report(&leaks, &leaks_file)?;
+        println!("Leak report written to: {}", leaks_file.display());
+    }
+
+    let failures = FailureTaxonomy::from_results(&results);
+    if !failures.is_empty() {
+        println!("\nFailures by kind:");
+        for framework in failures.frameworks.iter().filter(|f| f.overall.failed > 0) {
+            println!(
+                "  {}: {}/{} failed ({})",
+                framework.framework,
+                framework.overall.failed,
+                framework.overall.total,
+                framework.overall.summary()
+            );
+        }
+
+        let failures_file = output.join("failures.json");
+        write_failure_report(&failures, &failures_file)?;
+        println!("Failure report written to: {}", failures_file.display());
+    }
+
+    if let Some(manifest) = &options.footprint {
+        report_footprint(manifest, &results, &output)?;
+    }
+
+    match options.format {
+        OutputFormat::Json => {
+            let output_file = output.join("results.json");
+            write_json(&results, &output_file)?;
+            println!("\nResults written to: {}", output_file.display());
+
+            let by_ext_file = output.join("by-extension.json");
+            write_by_extension_analysis(&results, &by_ext_file)?;
+            println!("Per-extension analysis written to: {}", by_ext_file.display());
+
+            let cost_file = output.join("cost.json");
+            write_cost_report(&results, &options.pricing, &cost_file)?;
+            println!("Cost estimates written to: {}", cost_file.display());
+
+            let winners_file = output.join("winners.json");
+            write_winner_report(&results, &winners_file)?;
+            println!("Winner summaries written to: {}", winners_file.display());
+        }
+        OutputFormat::Html => {
+            let html_file = output.join("index.html");
+            write_html_with_diffs(
+                &results,
+                &html_file,
+                options.benchmark_date.as_deref(),
+                &options.pricing,
+                std::slice::from_ref(&output),
+            )?;
+            println!("\nHTML report written to: {}", html_file.display());
+        }
+        OutputFormat::Both => {
+            let output_file = output.join("results.json");
+            write_json(&results, &output_file)?;
+            println!("\nResults written to: {}", output_file.display());
+
+            let by_ext_file = output.join("by-extension.json");
+            write_by_extension_analysis(&results, &by_ext_file)?;
+            println!("Per-extension analysis written to: {}", by_ext_file.display());
+
+            let cost_file = output.join("cost.json");
+            write_cost_report(&results, &options.pricing, &cost_file)?;
+            println!("Cost estimates written to: {}", cost_file.display());
+
+            let winners_file = output.join("winners.json");
+            write_winner_report(&results, &winners_file)?;
+            println!("Winner summaries written to: {}", winners_file.display());
+
+            let html_file = output.join("index.html");
+            write_html_with_diffs(
+                &results,
+                &html_file,
+                options.benchmark_date.as_deref(),
+                &options.pricing,
+                std::slice::from_ref(&output),
+            )?;
+            println!("HTML report written to: {}", html_file.display());
+        }
+        OutputFormat::Markdown => {
+            let markdown_file = output.join("results.md");
+            write_markdown(&results, &markdown_file)?;
+            println!("\nMarkdown report written to: {}", markdown_file.display());
+        }
+    }
+
+    if options.fail_on_regression && !correctness.regressions.is_empty() {
+        return Err(benchmark_harness::Error::Benchmark(format!(
+            "{} correctness regression(s) found",
+            correctness.regressions.len()
+        )));
+    }
+
+    if options.fail_on_leak && !leaks.findings.is_empty() {
+        return Err(benchmark_harness::Error::Benchmark(format!(
+            "Resource leaks found in: {}",
+            leaks.leaking_frameworks().join(", ")
+        )));
+    }
+
+    Ok(results)
+}
+
+/// Extraction config for the native adapter, with Tesseract OCR when `ocr` is set
+fn extraction_config(ocr: bool) -> kreuzberg::ExtractionConfig {
+    use kreuzberg::{ExtractionConfig, OcrConfig};
+
+    if ocr {
+        ExtractionConfig {
+            ocr: Some(OcrConfig {
+                backend: "tesseract".to_string(),
+                language: "eng".to_string(),
+                tesseract_config: None,
+                fallback: None,
+                vocabulary: None,
+            }),
+            ..Default::default()
+        }
+    } else {
+        ExtractionConfig::default()
+    }
+}
+
+/// Register the native adapter plus every binding and external framework available on this machine
+///
+/// `custom_adapters` are `name=program [args...]` specs for third-party adapters speaking the
+/// JSON lines adapter protocol; unlike the built-in frameworks, these must register successfully.
+fn register_adapters(
+    extraction_config: kreuzberg::ExtractionConfig,
+    custom_adapters: &[String],
+) -> Result<benchmark_harness::AdapterRegistry> {
+    use benchmark_harness::adapters::SubprocessAdapter;
+    use benchmark_harness::{AdapterRegistry, FrameworkAdapter, NativeAdapter};
+    use std::sync::Arc;
+
+    let mut registry = AdapterRegistry::new();
+
+    registry.register(Arc::new(NativeAdapter::with_config(extraction_config)))?;
+    eprintln!("[adapter] ✓ kreuzberg-native (registered)");
+
+    use benchmark_harness::adapters::{
+        create_csharp_sync_adapter, create_go_batch_adapter, create_go_sync_adapter, create_java_sync_adapter,
+        create_node_async_adapter, create_node_batch_adapter, create_python_async_adapter, create_python_batch_adapter,
+        create_python_sync_adapter, create_ruby_batch_adapter, create_ruby_sync_adapter, create_wasm_async_adapter,
+        create_wasm_batch_adapter,
+    };
+
+    let mut kreuzberg_count = 1;
+
+    if let Ok(adapter) = create_python_sync_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ kreuzberg-python-sync (registered)");
+            kreuzberg_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ kreuzberg-python-sync (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ kreuzberg-python-sync (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_python_async_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ kreuzberg-python-async (registered)");
+            kreuzberg_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ kreuzberg-python-async (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ kreuzberg-python-async (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_python_batch_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ kreuzberg-python-batch (registered)");
+            kreuzberg_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ kreuzberg-python-batch (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ kreuzberg-python-batch (initialization failed)");
+    }
+
+    match create_go_sync_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-go-sync (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-go-sync (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-go-sync (initialization failed: {err})"),
+    }
+
+    match create_go_batch_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-go-batch (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-go-batch (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-go-batch (initialization failed: {err})"),
+    }
+
+    match create_node_async_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-node-async (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-node-async (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-node-async (initialization failed: {err})"),
+    }
+
+    match create_node_batch_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-node-batch (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-node-batch (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-node-batch (initialization failed: {err})"),
+    }
+
+    match create_wasm_async_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-wasm-async (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-wasm-async (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-wasm-async (initialization failed: {err})"),
+    }
+
+    match create_wasm_batch_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-wasm-batch (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-wasm-batch (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-wasm-batch (initialization failed: {err})"),
+    }
+
+    match create_ruby_sync_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-ruby-sync (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-ruby-sync (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-ruby-sync (initialization failed: {err})"),
+    }
+
+    match create_ruby_batch_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-ruby-batch (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-ruby-batch (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-ruby-batch (initialization failed: {err})"),
+    }
+
+    match create_java_sync_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-java-sync (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-java-sync (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-java-sync (initialization failed: {err})"),
+    }
+
+    match create_csharp_sync_adapter() {
+        Ok(adapter) => {
+            if let Err(err) = registry.register(Arc::new(adapter)) {
+                eprintln!("[adapter] ✗ kreuzberg-csharp-sync (registration failed: {err})");
+            } else {
+                eprintln!("[adapter] ✓ kreuzberg-csharp-sync (registered)");
+                kreuzberg_count += 1;
+            }
+        }
+        Err(err) => eprintln!("[adapter] ✗ kreuzberg-csharp-sync (initialization failed: {err})"),
+    }
+
+    eprintln!("[adapter] Kreuzberg bindings: {}/13 available", kreuzberg_count);
+
+    use benchmark_harness::adapters::external::{
+        create_docling_adapter, create_docling_batch_adapter, create_markitdown_adapter, create_pandoc_adapter,
+        create_tika_batch_adapter, create_tika_sync_adapter, create_unstructured_adapter,
+    };
+
+    let mut external_count = 0;
+
+    if let Ok(adapter) = create_docling_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ docling (registered)");
+            external_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ docling (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ docling (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_docling_batch_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ docling-batch (registered)");
+            external_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ docling-batch (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ docling-batch (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_markitdown_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ markitdown (registered)");
+            external_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ markitdown (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ markitdown (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_pandoc_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ pandoc (registered)");
+            external_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ pandoc (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ pandoc (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_unstructured_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ unstructured (registered)");
+            external_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ unstructured (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ unstructured (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_tika_sync_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ tika-sync (registered)");
+            external_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ tika-sync (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ tika-sync (initialization failed)");
+    }
+
+    if let Ok(adapter) = create_tika_batch_adapter() {
+        if let Ok(()) = registry.register(Arc::new(adapter)) {
+            eprintln!("[adapter] ✓ tika-batch (registered)");
+            external_count += 1;
+        } else {
+            eprintln!("[adapter] ✗ tika-batch (registration failed)");
+        }
+    } else {
+        eprintln!("[adapter] ✗ tika-batch (initialization failed)");
+    }
+
+    eprintln!(
+        "[adapter] Open source extraction frameworks: {}/7 available",
+        external_count
+    );
+
+    for spec in custom_adapters {
+        let adapter = SubprocessAdapter::from_spec(spec)?;
+        let name = adapter.name().to_string();
+        registry.register(Arc::new(adapter))?;
+        eprintln!("[adapter] ✓ {} (registered, adapter protocol)", name);
+    }
+
+    eprintln!(
+        "[adapter] Total adapters: {} available",
+        kreuzberg_count + external_count + custom_adapters.len()
+    );
+
+    Ok(registry)
+}
+
+/// Parse `name=iterations` specs into per-framework warmup iterations
+fn parse_adapter_warmup(specs: &[String]) -> Result<std::collections::BTreeMap<String, usize>> {
+    specs
+        .iter()
+        .map(|spec| {
+            let (name, iterations) = spec
+                .split_once('=')
+                .and_then(|(name, iterations)| Some((name.trim(), iterations.trim().parse::<usize>().ok()?)))
+                .filter(|(name, _)| !name.is_empty())
+                .ok_or_else(|| {
+                    benchmark_harness::Error::Config(format!(
+                        "Invalid --adapter-warmup '{}', expected NAME=ITERATIONS",
+                        spec
+                    ))
+                })?;
+            Ok((name.to_string(), iterations))
+        })
+        .collect()
+}
+
+fn load_pricing(path: Option<&std::path::Path>) -> Result<benchmark_harness::CloudPricing> {
+    match path {
+        Some(path) => benchmark_harness::CloudPricing::from_file(path),
+        None => Ok(benchmark_harness::CloudPricing::default()),
+    }
+}
+
+/// Measure deployment footprints, print them next to performance and write `footprint.json`
+fn report_footprint(
+    manifest: &benchmark_harness::FootprintManifest,
+    results: &[benchmark_harness::BenchmarkResult],
+    output: &std::path::Path,
+) -> Result<()> {
+    use benchmark_harness::{measure_footprints, write_footprint_report};
+
+    const MB: f64 = 1024.0 * 1024.0;
+
+    let report = measure_footprints(manifest, results);
+    println!("\nDeployment footprint:");
+    for framework in &report.frameworks {
+        let image = framework
+            .docker_image
+            .as_ref()
+            .and_then(|image| image.size_bytes)
+            .map(|size| format!(", image {:.1} MB", size as f64 / MB))
+            .unwrap_or_default();
+        let performance = framework
+            .performance
+            .as_ref()
+            .map(|performance| {
+                format!(
+                    ", mean {:.1} ms, peak {:.1} MB",
+                    performance.mean_duration_ms,
+                    performance.peak_memory_bytes as f64 / MB
+                )
+            })
+            .unwrap_or_default();
+        println!(
+            "  {}: {:.1} MB installed{}{}",
+            framework.framework,
+            framework.total_bytes as f64 / MB,
+            image,
+            performance
+        );
+        if framework.unmeasured > 0 {
+            println!("      {} item(s) could not be measured", framework.unmeasured);
+        }
+    }
+
+    let footprint_file = output.join("footprint.json");
+    write_footprint_report(&report, &footprint_file)?;
+    println!("Footprint report written to: {}", footprint_file.display());
+    Ok(())
+}
+
 /// Write simple consolidated HTML report
 fn write_simple_html(consolidated: &benchmark_harness::ConsolidatedResults, path: &PathBuf) -> Result<()> {
     let mut html = String::from(
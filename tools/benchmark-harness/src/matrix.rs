@@ -0,0 +1,488 @@
+//! Sharded benchmark matrices for nightly runs
+//!
+//! A full matrix of framework × extension × mode combinations does not fit in one
+//! machine-day, so it is split into one shard per combination and worked off a queue:
+//!
+//! 1. `matrix plan` writes `queue.json` into a directory every worker can reach
+//!    (e.g. a network mount or a synced bucket).
+//! 2. Each `matrix work` process claims pending shards one at a time, runs them and writes
+//!    the shard's results to `shards/<id>/results.json` before marking it done.
+//! 3. `matrix merge` combines the results of finished shards into one `results.json`
+//!    that `consolidate` and `visualize` accept.
+//!
+//! A claim carries a lease. If a worker dies, its shard becomes claimable again once the
+//! lease expires, so restarting `matrix work` resumes an interrupted matrix; failed shards
+//! are retried until they have been attempted [`MAX_ATTEMPTS`] times. Workers only claim
+//! shards for frameworks they have available, which lets heterogeneous machines share a
+//! queue. Queue updates are serialized by a lock file created exclusively, which also
+//! works across machines on a shared filesystem.
+
+use crate::config::BenchmarkMode;
+use crate::output::write_json;
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Work queue file inside a matrix directory
+pub const QUEUE_FILE: &str = "queue.json";
+
+/// Number of times a shard is attempted before it is reported as failed
+pub const MAX_ATTEMPTS: u32 = 3;
+
+const LOCK_FILE: &str = "queue.lock";
+
+/// Lock files older than this were left behind by a crashed worker
+const STALE_LOCK_AGE: Duration = Duration::from_secs(60);
+
+const LOCK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// One framework × extension × mode combination
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixCell {
+    /// Framework adapter name
+    pub framework: String,
+
+    /// File extension without dot (e.g., "pdf")
+    pub extension: String,
+
+    /// Benchmark execution mode
+    pub mode: BenchmarkMode,
+}
+
+impl MatrixCell {
+    /// Stable identifier used as the shard directory name, e.g. `kreuzberg-native.pdf.batch`
+    pub fn id(&self) -> String {
+        let mode = match self.mode {
+            BenchmarkMode::SingleFile => "single-file",
+            BenchmarkMode::Batch => "batch",
+        };
+        format!("{}.{}.{}", self.framework, self.extension, mode)
+    }
+}
+
+/// Progress of a shard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ShardState {
+    /// Not started yet
+    Pending,
+    /// Being run by a worker until the lease expires (Unix seconds)
+    Claimed { worker: String, lease_expires_at: u64 },
+    /// Results written to the shard directory
+    Done {
+        worker: String,
+        finished_at: u64,
+        result_count: usize,
+    },
+    /// Last attempt failed
+    Failed { worker: String, error: String },
+}
+
+/// Unit of work in a matrix queue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Shard {
+    /// Identifier from [`MatrixCell::id`]
+    pub id: String,
+
+    #[serde(flatten)]
+    pub cell: MatrixCell,
+
+    pub state: ShardState,
+
+    /// Number of times the shard has been claimed
+    pub attempts: u32,
+}
+
+impl Shard {
+    /// Whether a worker may claim the shard at `now` (Unix seconds)
+    fn is_claimable(&self, now: u64) -> bool {
+        match &self.state {
+            ShardState::Pending => true,
+            ShardState::Claimed { lease_expires_at, .. } => *lease_expires_at <= now && self.attempts < MAX_ATTEMPTS,
+            ShardState::Failed { .. } => self.attempts < MAX_ATTEMPTS,
+            ShardState::Done { .. } => false,
+        }
+    }
+}
+
+/// Shard counts of a matrix queue
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixSummary {
+    /// Shards waiting for a worker, including retries and expired claims
+    pub pending: usize,
+    /// Shards claimed under an unexpired lease
+    pub running: usize,
+    pub done: usize,
+    /// Shards that failed [`MAX_ATTEMPTS`] times
+    pub failed: usize,
+}
+
+impl MatrixSummary {
+    /// Whether no shard is waiting for or being run by a worker
+    pub fn is_finished(&self) -> bool {
+        self.pending == 0 && self.running == 0
+    }
+}
+
+/// Work queue of a sharded matrix run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixQueue {
+    /// When the queue was planned (Unix seconds)
+    pub created_at: u64,
+    pub shards: Vec<Shard>,
+}
+
+impl MatrixQueue {
+    /// Plan one shard per framework × extension × mode combination
+    pub fn plan(frameworks: &[String], extensions: &[String], modes: &[BenchmarkMode]) -> Self {
+        let shards = frameworks
+            .iter()
+            .flat_map(|framework| {
+                extensions.iter().flat_map(move |extension| {
+                    modes.iter().map(move |&mode| MatrixCell {
+                        framework: framework.clone(),
+                        extension: extension.clone(),
+                        mode,
+                    })
+                })
+            })
+            .map(|cell| Shard {
+                id: cell.id(),
+                cell,
+                state: ShardState::Pending,
+                attempts: 0,
+            })
+            .collect();
+
+        Self {
+            created_at: unix_now(),
+            shards,
+        }
+    }
+
+    /// Count shards by state at `now` (Unix seconds)
+    pub fn summary(&self, now: u64) -> MatrixSummary {
+        let mut summary = MatrixSummary::default();
+        for shard in &self.shards {
+            match &shard.state {
+                ShardState::Done { .. } => summary.done += 1,
+                ShardState::Claimed { lease_expires_at, .. } if *lease_expires_at > now => summary.running += 1,
+                _ if shard.is_claimable(now) => summary.pending += 1,
+                _ => summary.failed += 1,
+            }
+        }
+        summary
+    }
+
+    /// Claim the first claimable shard whose framework is in `frameworks`
+    fn claim_next(&mut self, worker: &str, frameworks: &[String], lease: Duration, now: u64) -> Option<Shard> {
+        let shard = self
+            .shards
+            .iter_mut()
+            .find(|shard| shard.is_claimable(now) && frameworks.contains(&shard.cell.framework))?;
+
+        shard.attempts += 1;
+        shard.state = ShardState::Claimed {
+            worker: worker.to_string(),
+            lease_expires_at: now + lease.as_secs(),
+        };
+        Some(shard.clone())
+    }
+
+    fn shard_mut(&mut self, id: &str) -> Result<&mut Shard> {
+        self.shards
+            .iter_mut()
+            .find(|shard| shard.id == id)
+            .ok_or_else(|| Error::Benchmark(format!("Unknown matrix shard: {}", id)))
+    }
+}
+
+/// Write a planned queue into `dir`
+///
+/// Fails if `dir` already holds a queue; run `matrix work` against it to resume instead.
+pub fn create_queue(dir: &Path, queue: &MatrixQueue) -> Result<()> {
+    fs::create_dir_all(dir).map_err(Error::Io)?;
+
+    let path = dir.join(QUEUE_FILE);
+    if path.exists() {
+        return Err(Error::Config(format!(
+            "{} already exists; run `matrix work` to resume it or choose another directory",
+            path.display()
+        )));
+    }
+
+    write_queue(dir, queue)
+}
+
+/// Read the queue of a matrix directory
+pub fn load_queue(dir: &Path) -> Result<MatrixQueue> {
+    let path = dir.join(QUEUE_FILE);
+    let json = fs::read_to_string(&path).map_err(Error::Io)?;
+    serde_json::from_str(&json).map_err(|e| Error::Benchmark(format!("Failed to parse {}: {}", path.display(), e)))
+}
+
+/// Claim the next shard for one of `frameworks`, or `None` when nothing is left to claim
+pub fn claim_shard(dir: &Path, worker: &str, frameworks: &[String], lease: Duration) -> Result<Option<Shard>> {
+    update_queue(dir, |queue| Ok(queue.claim_next(worker, frameworks, lease, unix_now())))
+}
+
+/// Store the results of a shard and mark it done
+pub fn complete_shard(dir: &Path, shard: &Shard, worker: &str, results: &[BenchmarkResult]) -> Result<()> {
+    write_json(results, &shard_results_path(dir, &shard.id))?;
+
+    update_queue(dir, |queue| {
+        queue.shard_mut(&shard.id)?.state = ShardState::Done {
+            worker: worker.to_string(),
+            finished_at: unix_now(),
+            result_count: results.len(),
+        };
+        Ok(())
+    })
+}
+
+/// Record a failed attempt at a shard
+pub fn fail_shard(dir: &Path, shard: &Shard, worker: &str, error: &str) -> Result<()> {
+    update_queue(dir, |queue| {
+        let entry = queue.shard_mut(&shard.id)?;
+        // Another worker may have finished the shard after our lease expired
+        if !matches!(entry.state, ShardState::Done { .. }) {
+            entry.state = ShardState::Failed {
+                worker: worker.to_string(),
+                error: error.to_string(),
+            };
+        }
+        Ok(())
+    })
+}
+
+/// Results of every finished shard, in queue order
+pub fn merge_shards(dir: &Path) -> Result<Vec<BenchmarkResult>> {
+    let queue = load_queue(dir)?;
+    let mut merged = Vec::new();
+
+    for shard in &queue.shards {
+        if !matches!(shard.state, ShardState::Done { .. }) {
+            continue;
+        }
+
+        let path = shard_results_path(dir, &shard.id);
+        let json = fs::read_to_string(&path).map_err(Error::Io)?;
+        let results: Vec<BenchmarkResult> = serde_json::from_str(&json)
+            .map_err(|e| Error::Benchmark(format!("Failed to parse {}: {}", path.display(), e)))?;
+        merged.extend(results);
+    }
+
+    Ok(merged)
+}
+
+/// Where the results of a shard are written
+pub fn shard_results_path(dir: &Path, shard_id: &str) -> PathBuf {
+    dir.join("shards").join(shard_id).join("results.json")
+}
+
+/// Worker name used in claims when none is given: `$HOSTNAME-<pid>`
+pub fn default_worker_id() -> String {
+    let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "worker".to_string());
+    format!("{}-{}", host, std::process::id())
+}
+
+/// Current time in Unix seconds
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// Read, modify and write the queue while holding the lock file
+fn update_queue<T>(dir: &Path, update: impl FnOnce(&mut MatrixQueue) -> Result<T>) -> Result<T> {
+    let _lock = QueueLock::acquire(dir)?;
+    let mut queue = load_queue(dir)?;
+    let value = update(&mut queue)?;
+    write_queue(dir, &queue)?;
+    Ok(value)
+}
+
+/// Replace the queue file atomically so readers never see a partial write
+fn write_queue(dir: &Path, queue: &MatrixQueue) -> Result<()> {
+    let json = serde_json::to_string_pretty(queue)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize matrix queue: {}", e)))?;
+
+    let path = dir.join(QUEUE_FILE);
+    let tmp = dir.join(format!("{}.{}.tmp", QUEUE_FILE, std::process::id()));
+    fs::write(&tmp, json).map_err(Error::Io)?;
+    fs::rename(&tmp, &path).map_err(Error::Io)
+}
+
+/// Exclusive lock on a matrix directory, released on drop
+struct QueueLock {
+    path: PathBuf,
+}
+
+impl QueueLock {
+    fn acquire(dir: &Path) -> Result<Self> {
+        let path = dir.join(LOCK_FILE);
+        let deadline = Instant::now() + LOCK_TIMEOUT;
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if is_stale(&path) {
+                        let _ = fs::remove_file(&path);
+                        continue;
+                    }
+                    if Instant::now() >= deadline {
+                        return Err(Error::Timeout(format!("Waiting for matrix lock {}", path.display())));
+                    }
+                    std::thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => return Err(Error::Io(e)),
+            }
+        }
+    }
+}
+
+impl Drop for QueueLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn is_stale(lock: &Path) -> bool {
+    fs::metadata(lock)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age > STALE_LOCK_AGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FrameworkCapabilities, PerformanceMetrics};
+    use tempfile::TempDir;
+
+    fn result(framework: &str, extension: &str) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from(format!("fixtures/doc.{}", extension)),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: extension.to_string(),
+            framework_capabilities: FrameworkCapabilities::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        }
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_plan_covers_every_combination() {
+        let queue = MatrixQueue::plan(
+            &strings(&["kreuzberg-native", "docling"]),
+            &strings(&["pdf", "docx"]),
+            &[BenchmarkMode::SingleFile, BenchmarkMode::Batch],
+        );
+
+        assert_eq!(queue.shards.len(), 8);
+        assert_eq!(queue.shards[0].id, "kreuzberg-native.pdf.single-file");
+        assert_eq!(queue.shards[7].id, "docling.docx.batch");
+        assert_eq!(queue.summary(unix_now()).pending, 8);
+    }
+
+    #[test]
+    fn test_claims_respect_frameworks_leases_and_attempts() {
+        let mut queue = MatrixQueue::plan(
+            &strings(&["kreuzberg-native", "docling"]),
+            &strings(&["pdf"]),
+            &[BenchmarkMode::Batch],
+        );
+        let lease = Duration::from_secs(60);
+
+        let docling_only = strings(&["docling"]);
+        let claimed = queue.claim_next("a", &docling_only, lease, 1_000).unwrap();
+        assert_eq!(claimed.id, "docling.pdf.batch");
+        assert!(queue.claim_next("b", &docling_only, lease, 1_030).is_none());
+
+        // Worker "a" died: the shard is handed out again once its lease expired
+        let reclaimed = queue.claim_next("b", &docling_only, lease, 1_060).unwrap();
+        assert_eq!(reclaimed.attempts, 2);
+
+        queue.shard_mut("docling.pdf.batch").unwrap().state = ShardState::Failed {
+            worker: "b".to_string(),
+            error: "timeout".to_string(),
+        };
+        assert_eq!(queue.claim_next("c", &docling_only, lease, 1_100).unwrap().attempts, 3);
+        queue.shard_mut("docling.pdf.batch").unwrap().state = ShardState::Failed {
+            worker: "c".to_string(),
+            error: "timeout".to_string(),
+        };
+        assert!(queue.claim_next("d", &docling_only, lease, 1_200).is_none());
+
+        let summary = queue.summary(1_200);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.pending, 1);
+        assert!(!summary.is_finished());
+    }
+
+    #[test]
+    fn test_work_and_merge_shards() {
+        let dir = TempDir::new().unwrap();
+        let queue = MatrixQueue::plan(
+            &strings(&["kreuzberg-native"]),
+            &strings(&["pdf", "docx"]),
+            &[BenchmarkMode::Batch],
+        );
+        create_queue(dir.path(), &queue).unwrap();
+        assert!(create_queue(dir.path(), &queue).is_err());
+
+        let frameworks = strings(&["kreuzberg-native"]);
+        let lease = Duration::from_secs(3600);
+
+        let pdf = claim_shard(dir.path(), "w1", &frameworks, lease).unwrap().unwrap();
+        complete_shard(dir.path(), &pdf, "w1", &[result("kreuzberg-native", "pdf")]).unwrap();
+
+        let docx = claim_shard(dir.path(), "w2", &frameworks, lease).unwrap().unwrap();
+        assert_eq!(docx.cell.extension, "docx");
+        assert!(claim_shard(dir.path(), "w3", &frameworks, lease).unwrap().is_none());
+
+        let partial = merge_shards(dir.path()).unwrap();
+        assert_eq!(partial.len(), 1);
+
+        fail_shard(dir.path(), &docx, "w2", "adapter crashed").unwrap();
+        let retry = claim_shard(dir.path(), "w3", &frameworks, lease).unwrap().unwrap();
+        complete_shard(dir.path(), &retry, "w3", &[result("kreuzberg-native", "docx")]).unwrap();
+
+        let merged = merge_shards(dir.path()).unwrap();
+        let extensions: Vec<&str> = merged.iter().map(|r| r.file_extension.as_str()).collect();
+        assert_eq!(extensions, vec!["pdf", "docx"]);
+
+        let summary = load_queue(dir.path()).unwrap().summary(unix_now());
+        assert_eq!(summary.done, 2);
+        assert!(summary.is_finished());
+        assert!(!dir.path().join(LOCK_FILE).exists());
+    }
+}
@@ -0,0 +1,380 @@
+//! OCR engine reuse measurement
+//!
+//! Frameworks that construct their OCR engine per document pay the model load on every
+//! scanned page, while frameworks that keep the engine alive pay it once per process. This
+//! suite extracts every OCR-heavy fixture once, in order and without warmup, then extracts
+//! the first document again. The first extraction includes engine initialization and the
+//! repeat measures the steady state on the same document, so their ratio isolates
+//! initialization cost from document difficulty.
+//!
+//! A first-document latency at least `reuse_threshold` times the steady-state latency
+//! means the engine is reused across documents. A ratio near 1.0 means every document
+//! pays initialization again (or initialization is negligible).
+//!
+//! OCR-heavy fixtures are images and fixtures with an `image_heavy` section
+//! (see [`crate::image_heavy`]).
+
+use crate::adapter::FrameworkAdapter;
+use crate::fixture::Fixture;
+use crate::output::escape_markdown_cell;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Image file types that always go through OCR
+pub const OCR_IMAGE_FILE_TYPES: &[&str] = &["png", "jpg", "jpeg", "tif", "tiff", "bmp", "gif", "webp"];
+
+/// Default first-document to steady-state ratio above which the engine counts as reused
+pub const DEFAULT_REUSE_THRESHOLD: f64 = 1.5;
+
+/// Whether a framework keeps its OCR engine alive between documents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OcrEngineReuse {
+    /// First document is markedly slower than the steady state
+    Reused,
+
+    /// Every document pays the same initialization cost
+    PerDocument,
+
+    /// Not measurable (no OCR fixtures or failed extractions)
+    Unknown,
+}
+
+impl OcrEngineReuse {
+    /// Classify a first-document to steady-state latency ratio
+    pub fn classify(ratio: Option<f64>, reuse_threshold: f64) -> Self {
+        match ratio {
+            Some(ratio) if ratio >= reuse_threshold => Self::Reused,
+            Some(_) => Self::PerDocument,
+            None => Self::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for OcrEngineReuse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Reused => "reused",
+            Self::PerDocument => "per document",
+            Self::Unknown => "unknown",
+        })
+    }
+}
+
+/// Latency of a single extraction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentLatency {
+    /// Path to the fixture JSON file
+    pub fixture: PathBuf,
+
+    /// Path to the extracted document
+    pub document: PathBuf,
+
+    /// Whether the extraction succeeded
+    pub success: bool,
+
+    /// Error message when the extraction failed
+    pub error_message: Option<String>,
+
+    /// Wall-clock duration
+    pub duration: Duration,
+}
+
+/// OCR engine reuse measurement for one framework
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrReuseResult {
+    /// Framework name
+    pub framework: String,
+
+    /// Extractions of every OCR-heavy fixture, in run order
+    pub first_pass: Vec<DocumentLatency>,
+
+    /// Second extraction of the first document
+    pub repeat: Option<DocumentLatency>,
+
+    /// Latency of the first document (includes engine initialization)
+    pub first_document: Option<Duration>,
+
+    /// Latency of the repeated first document
+    pub steady_state: Option<Duration>,
+
+    /// First-document latency divided by steady-state latency
+    pub ratio: Option<f64>,
+
+    /// Classification of the ratio
+    pub reuse: OcrEngineReuse,
+}
+
+impl OcrReuseResult {
+    /// Derive latencies and the reuse classification from the measured extractions
+    pub fn new(
+        framework: String,
+        first_pass: Vec<DocumentLatency>,
+        repeat: Option<DocumentLatency>,
+        reuse_threshold: f64,
+    ) -> Self {
+        let first_document = first_pass.first().filter(|sample| sample.success).map(|s| s.duration);
+        let steady_state = repeat.as_ref().filter(|sample| sample.success).map(|s| s.duration);
+        let ratio = first_document
+            .zip(steady_state)
+            .filter(|(_, steady)| !steady.is_zero())
+            .map(|(first, steady)| first.as_secs_f64() / steady.as_secs_f64());
+
+        Self {
+            framework,
+            first_pass,
+            repeat,
+            first_document,
+            steady_state,
+            ratio,
+            reuse: OcrEngineReuse::classify(ratio, reuse_threshold),
+        }
+    }
+}
+
+/// Complete OCR engine reuse report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrReuseReport {
+    /// Ratio above which an engine counts as reused
+    pub reuse_threshold: f64,
+
+    /// Per-framework measurements, in the order frameworks were given
+    pub results: Vec<OcrReuseResult>,
+
+    /// Fixtures whose documents were not found
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Whether a fixture exercises OCR
+pub fn is_ocr_heavy(fixture: &Fixture) -> bool {
+    fixture.image_heavy.is_some() || OCR_IMAGE_FILE_TYPES.contains(&fixture.file_type.to_ascii_lowercase().as_str())
+}
+
+/// Run the OCR engine reuse suite
+///
+/// Each framework is set up fresh and runs the OCR-heavy fixtures it supports without
+/// warmup. Extraction failures are recorded and do not abort the run.
+pub async fn run_ocr_reuse_suite(
+    fixtures: &[(PathBuf, Fixture)],
+    frameworks: &[Arc<dyn FrameworkAdapter>],
+    timeout: Duration,
+    reuse_threshold: f64,
+) -> Result<OcrReuseReport> {
+    let mut documents = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (fixture_path, fixture) in fixtures.iter().filter(|(_, fixture)| is_ocr_heavy(fixture)) {
+        let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+        let document = fixture.resolve_document_path(fixture_dir);
+        if document.exists() {
+            documents.push((fixture_path, fixture.file_type.as_str(), document));
+        } else {
+            skipped.push(fixture_path.clone());
+        }
+    }
+
+    let mut results = Vec::with_capacity(frameworks.len());
+
+    for framework in frameworks {
+        framework.setup().await?;
+
+        let supported: Vec<_> = documents
+            .iter()
+            .filter(|(_, file_type, _)| framework.supports_format(file_type))
+            .collect();
+
+        let mut first_pass = Vec::with_capacity(supported.len());
+        for (fixture_path, _, document) in &supported {
+            first_pass.push(measure(framework.as_ref(), fixture_path, document, timeout).await);
+        }
+
+        let repeat = match supported.first() {
+            Some((fixture_path, _, document)) if first_pass.first().is_some_and(|sample| sample.success) => {
+                Some(measure(framework.as_ref(), fixture_path, document, timeout).await)
+            }
+            _ => None,
+        };
+
+        framework.teardown().await?;
+
+        results.push(OcrReuseResult::new(
+            framework.name().to_string(),
+            first_pass,
+            repeat,
+            reuse_threshold,
+        ));
+    }
+
+    Ok(OcrReuseReport {
+        reuse_threshold,
+        results,
+        skipped,
+    })
+}
+
+async fn measure(
+    framework: &dyn FrameworkAdapter,
+    fixture: &Path,
+    document: &Path,
+    timeout: Duration,
+) -> DocumentLatency {
+    let (success, error_message, duration) = match framework.extract(document, timeout).await {
+        Ok(result) if result.success => (true, None, result.duration),
+        Ok(result) => (
+            false,
+            result.error_message.or_else(|| Some("Extraction failed".to_string())),
+            result.duration,
+        ),
+        Err(e) => (false, Some(e.to_string()), Duration::ZERO),
+    };
+
+    DocumentLatency {
+        fixture: fixture.to_path_buf(),
+        document: document.to_path_buf(),
+        success,
+        error_message,
+        duration,
+    }
+}
+
+/// Write an OCR engine reuse report to a JSON file
+pub fn write_ocr_reuse_report(report: &OcrReuseReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize OCR reuse report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Write the capability matrix with the OCR engine reuse column as Markdown
+pub fn write_ocr_reuse_markdown(report: &OcrReuseReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    fs::write(output_path, render_markdown(report)).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+fn render_markdown(report: &OcrReuseReport) -> String {
+    let ms = |duration: Option<Duration>| {
+        duration.map_or_else(|| "-".to_string(), |d| format!("{:.1}", d.as_secs_f64() * 1000.0))
+    };
+
+    let mut md = String::new();
+    let _ = writeln!(md, "# OCR Engine Reuse\n");
+    let _ = writeln!(
+        md,
+        "First document is extracted without warmup; steady state re-extracts the same document after the \
+         other OCR fixtures. A ratio of at least {:.1}x means the OCR engine is reused across documents.\n",
+        report.reuse_threshold
+    );
+    let _ = writeln!(
+        md,
+        "| Framework | OCR documents | First document (ms) | Steady state (ms) | Ratio | OCR engine reuse |"
+    );
+    let _ = writeln!(md, "|---|---:|---:|---:|---:|---|");
+    for result in &report.results {
+        let succeeded = result.first_pass.iter().filter(|sample| sample.success).count();
+        let _ = writeln!(
+            md,
+            "| {} | {}/{} | {} | {} | {} | {} |",
+            escape_markdown_cell(&result.framework),
+            succeeded,
+            result.first_pass.len(),
+            ms(result.first_document),
+            ms(result.steady_state),
+            result
+                .ratio
+                .map_or_else(|| "-".to_string(), |ratio| format!("{:.2}x", ratio)),
+            result.reuse,
+        );
+    }
+
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(duration_ms: u64, success: bool) -> DocumentLatency {
+        DocumentLatency {
+            fixture: PathBuf::from("fixture.json"),
+            document: PathBuf::from("scan.png"),
+            success,
+            error_message: (!success).then(|| "failed".to_string()),
+            duration: Duration::from_millis(duration_ms),
+        }
+    }
+
+    #[test]
+    fn test_reuse_classification() {
+        let reused = OcrReuseResult::new(
+            "persistent".to_string(),
+            vec![sample(900, true), sample(320, true)],
+            Some(sample(300, true)),
+            DEFAULT_REUSE_THRESHOLD,
+        );
+        assert!((reused.ratio.unwrap() - 3.0).abs() < 1e-9);
+        assert_eq!(reused.reuse, OcrEngineReuse::Reused);
+
+        let per_document = OcrReuseResult::new(
+            "per-call".to_string(),
+            vec![sample(900, true)],
+            Some(sample(880, true)),
+            DEFAULT_REUSE_THRESHOLD,
+        );
+        assert_eq!(per_document.reuse, OcrEngineReuse::PerDocument);
+
+        let failed = OcrReuseResult::new(
+            "broken".to_string(),
+            vec![sample(0, false)],
+            None,
+            DEFAULT_REUSE_THRESHOLD,
+        );
+        assert!(failed.ratio.is_none());
+        assert_eq!(failed.reuse, OcrEngineReuse::Unknown);
+    }
+
+    #[test]
+    fn test_render_markdown_capability_matrix() {
+        let report = OcrReuseReport {
+            reuse_threshold: DEFAULT_REUSE_THRESHOLD,
+            results: vec![OcrReuseResult::new(
+                "kreuzberg-native".to_string(),
+                vec![sample(1200, true), sample(0, false)],
+                Some(sample(400, true)),
+                DEFAULT_REUSE_THRESHOLD,
+            )],
+            skipped: Vec::new(),
+        };
+
+        let md = render_markdown(&report);
+
+        assert!(md.contains("| OCR engine reuse |"));
+        assert!(md.contains("| kreuzberg-native | 1/2 | 1200.0 | 400.0 | 3.00x | reused |"));
+    }
+
+    #[test]
+    fn test_is_ocr_heavy() {
+        let fixture: Fixture =
+            serde_json::from_str(r#"{"document": "scan.PNG", "file_type": "PNG", "file_size": 1024}"#).unwrap();
+        assert!(is_ocr_heavy(&fixture));
+
+        let text: Fixture =
+            serde_json::from_str(r#"{"document": "notes.txt", "file_type": "txt", "file_size": 10}"#).unwrap();
+        assert!(!is_ocr_heavy(&text));
+    }
+}
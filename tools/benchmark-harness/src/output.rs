@@ -1,12 +1,14 @@
 //! Output writers for benchmark results
 //!
 //! This module provides functionality for persisting benchmark results to disk
-//! in JSON format.
+//! in JSON format, and as a Markdown comparison report for publishing on GitHub.
 
+use crate::failures::{FailureKind, FailureTaxonomy};
 use crate::types::BenchmarkResult;
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
 
@@ -210,6 +212,236 @@ pub fn write_by_extension_analysis(results: &[BenchmarkResult], output_path: &Pa
     Ok(())
 }
 
+/// Write a Markdown comparison report
+///
+/// The report contains a per-framework summary table, a failure taxonomy when any extraction
+/// failed, the fastest framework for each file extension, collapsible per-extension details
+/// and an environment appendix. Unlike the HTML report it can be pasted into GitHub READMEs,
+/// issues and discussions.
+///
+/// # Arguments
+/// * `results` - Vector of benchmark results to report
+/// * `output_path` - Path to output Markdown file (e.g., "results.md")
+pub fn write_markdown(results: &[BenchmarkResult], output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    fs::write(output_path, render_markdown(results)).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Render the Markdown comparison report
+fn render_markdown(results: &[BenchmarkResult]) -> String {
+    let mut md = String::new();
+    let generated = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string();
+
+    let _ = writeln!(md, "# Benchmark Results\n");
+    if results.is_empty() {
+        let _ = writeln!(md, "No benchmark results.\n");
+        write_environment_appendix(&mut md, &generated);
+        return md;
+    }
+
+    let mut by_framework: BTreeMap<&str, Vec<&BenchmarkResult>> = BTreeMap::new();
+    for result in results {
+        by_framework.entry(&result.framework).or_default().push(result);
+    }
+    let _ = writeln!(
+        md,
+        "{} results across {} frameworks and {} file types.\n",
+        results.len(),
+        by_framework.len(),
+        results
+            .iter()
+            .map(|r| r.file_extension.as_str())
+            .collect::<std::collections::BTreeSet<_>>()
+            .len()
+    );
+
+    let _ = writeln!(md, "## Summary\n");
+    let _ = writeln!(
+        md,
+        "| Framework | Files | Success rate | Mean (ms) | Median (ms) | P95 (ms) | Throughput (MB/s) | Peak memory (MB) |"
+    );
+    let _ = writeln!(md, "|---|---:|---:|---:|---:|---:|---:|---:|");
+    for (framework, framework_results) in &by_framework {
+        let stats = calculate_framework_stats(framework_results);
+        let _ = writeln!(
+            md,
+            "| {} | {} | {:.1}% | {:.1} | {:.1} | {:.1} | {:.2} | {:.1} |",
+            escape_markdown_cell(framework),
+            stats.count,
+            stats.success_rate * 100.0,
+            stats.avg_duration_ms,
+            stats.median_duration_ms,
+            stats.p95_duration_ms,
+            stats.avg_throughput_mbps,
+            stats.avg_peak_memory_mb,
+        );
+    }
+
+    let failures = FailureTaxonomy::from_results(results);
+    if !failures.is_empty() {
+        let _ = writeln!(md, "\n## Failure Taxonomy\n");
+        let mut header = "| Framework | Failed |".to_string();
+        let mut separator = "|---|---:|".to_string();
+        for kind in FailureKind::ALL {
+            let _ = write!(header, " {} |", kind.label());
+            separator.push_str("---:|");
+        }
+        let _ = writeln!(md, "{}\n{}", header, separator);
+        for framework in failures.frameworks.iter().filter(|f| f.overall.failed > 0) {
+            let _ = write!(
+                md,
+                "| {} | {}/{} |",
+                escape_markdown_cell(&framework.framework),
+                framework.overall.failed,
+                framework.overall.total
+            );
+            for kind in FailureKind::ALL {
+                let _ = write!(md, " {} |", framework.overall.count(kind));
+            }
+            let _ = writeln!(md);
+        }
+    }
+
+    let report = analyze_by_extension(results);
+    let extensions: BTreeMap<&String, &ExtensionAnalysis> = report.by_extension.iter().collect();
+
+    let _ = writeln!(md, "\n## Fastest Framework per File Type\n");
+    let _ = writeln!(
+        md,
+        "Ranked by median duration among frameworks with at least one successful extraction.\n"
+    );
+    let _ = writeln!(
+        md,
+        "| Extension | Files | Fastest | Median (ms) | Runner-up | Median (ms) | Most reliable |"
+    );
+    let _ = writeln!(md, "|---|---:|---|---:|---|---:|---|");
+    for (extension, analysis) in &extensions {
+        let ranked = rank_by_median(analysis);
+        let cell = |index: usize| {
+            ranked
+                .get(index)
+                .map_or(("-".to_string(), "-".to_string()), |(name, stats)| {
+                    (escape_markdown_cell(name), format!("{:.1}", stats.median_duration_ms))
+                })
+        };
+        let (fastest, fastest_median) = cell(0);
+        let (runner_up, runner_up_median) = cell(1);
+        let most_reliable = analysis
+            .framework_stats
+            .iter()
+            .max_by(|(a_name, a), (b_name, b)| {
+                a.success_rate
+                    .partial_cmp(&b.success_rate)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| b_name.cmp(a_name))
+            })
+            .map_or("-".to_string(), |(name, stats)| {
+                format!("{} ({:.0}%)", escape_markdown_cell(name), stats.success_rate * 100.0)
+            });
+
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            escape_markdown_cell(extension),
+            analysis.total_files,
+            fastest,
+            fastest_median,
+            runner_up,
+            runner_up_median,
+            most_reliable,
+        );
+    }
+
+    let _ = writeln!(md, "\n## Results by File Type\n");
+    for (extension, analysis) in &extensions {
+        let _ = writeln!(
+            md,
+            "<details>\n<summary><code>{}</code> ({} files)</summary>\n",
+            escape_markdown_cell(extension),
+            analysis.total_files
+        );
+        let _ = writeln!(
+            md,
+            "| Framework | Success | Median (ms) | P95 (ms) | Throughput (MB/s) | Peak memory (MB) | Failures |"
+        );
+        let _ = writeln!(md, "|---|---:|---:|---:|---:|---:|---|");
+        let frameworks: BTreeMap<&String, &FrameworkExtensionStats> = analysis.framework_stats.iter().collect();
+        for (framework, stats) in frameworks {
+            let failure_summary = failures
+                .breakdown(framework, extension)
+                .map(|breakdown| breakdown.summary())
+                .filter(|summary| !summary.is_empty())
+                .unwrap_or_else(|| "-".to_string());
+            let _ = writeln!(
+                md,
+                "| {} | {}/{} | {:.1} | {:.1} | {:.2} | {:.1} | {} |",
+                escape_markdown_cell(framework),
+                stats.successful,
+                stats.count,
+                stats.median_duration_ms,
+                stats.p95_duration_ms,
+                stats.avg_throughput_mbps,
+                stats.avg_peak_memory_mb,
+                failure_summary,
+            );
+        }
+        let _ = writeln!(md, "\n</details>\n");
+    }
+
+    write_environment_appendix(&mut md, &generated);
+    md
+}
+
+/// Frameworks with successful extractions, fastest median first
+fn rank_by_median(analysis: &ExtensionAnalysis) -> Vec<(&String, &FrameworkExtensionStats)> {
+    let mut ranked: Vec<_> = analysis
+        .framework_stats
+        .iter()
+        .filter(|(_, stats)| stats.successful > 0)
+        .collect();
+    ranked.sort_by(|(a_name, a), (b_name, b)| {
+        a.median_duration_ms
+            .partial_cmp(&b.median_duration_ms)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a_name.cmp(b_name))
+    });
+    ranked
+}
+
+/// Append the machine and harness details the results were produced with
+fn write_environment_appendix(md: &mut String, generated: &str) {
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+
+    let _ = writeln!(md, "## Environment\n");
+    let _ = writeln!(md, "| | |");
+    let _ = writeln!(md, "|---|---|");
+    let _ = writeln!(
+        md,
+        "| OS | {} ({}) |",
+        sysinfo::System::long_os_version().unwrap_or_else(|| std::env::consts::OS.to_string()),
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(md, "| CPUs | {} |", num_cpus::get());
+    let _ = writeln!(
+        md,
+        "| Memory | {:.1} GB |",
+        system.total_memory() as f64 / 1_000_000_000.0
+    );
+    let _ = writeln!(md, "| Harness version | {} |", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(md, "| Generated | {} |", generated);
+}
+
+/// Escape characters that would break a Markdown table cell
+pub(crate) fn escape_markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,9 +461,11 @@ mod tests {
             file_size: 1024,
             success: true,
             error_message: None,
+            failure: None,
             duration: Duration::from_secs(1),
             extraction_duration: None,
             subprocess_overhead: None,
+            overhead_breakdown: None,
             metrics: PerformanceMetrics {
                 peak_memory_bytes: 10_000_000,
                 avg_cpu_percent: 50.0,
@@ -244,9 +478,14 @@ mod tests {
             iterations: vec![],
             statistics: None,
             cold_start_duration: None,
+            warmup: None,
             file_extension: "txt".to_string(),
             framework_capabilities: Default::default(),
             pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
         }];
 
         write_json(&results, &output_path).unwrap();
@@ -271,4 +510,73 @@ mod tests {
         assert!(output_path.exists());
         assert!(output_path.parent().unwrap().exists());
     }
+
+    fn markdown_result(framework: &str, extension: &str, duration_ms: u64, success: bool) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from(format!("/tmp/test.{}", extension)),
+            file_size: 1024,
+            success,
+            error_message: (!success).then(|| "failed".to_string()),
+            failure: None,
+            duration: Duration::from_millis(duration_ms),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics {
+                peak_memory_bytes: 10_000_000,
+                avg_cpu_percent: 50.0,
+                throughput_bytes_per_sec: 1024.0,
+                p50_memory_bytes: 8_000_000,
+                p95_memory_bytes: 9_500_000,
+                p99_memory_bytes: 9_900_000,
+            },
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: extension.to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        }
+    }
+
+    #[test]
+    fn test_render_markdown_ranks_frameworks_per_extension() {
+        let results = vec![
+            markdown_result("kreuzberg-native", "pdf", 100, true),
+            markdown_result("docling", "pdf", 400, true),
+            markdown_result("kreuzberg-native", "docx", 50, false),
+            markdown_result("pipe|name", "docx", 80, true),
+        ];
+
+        let md = render_markdown(&results);
+
+        assert!(md.starts_with("# Benchmark Results"));
+        assert!(md.contains("4 results across 3 frameworks and 2 file types."));
+        assert!(md.contains("| pdf | 1 | kreuzberg-native | 100.0 | docling | 400.0 | docling (100%) |"));
+        assert!(md.contains("| docx | 1 | pipe\\|name | 80.0 | - | - | pipe\\|name (100%) |"));
+        assert!(md.contains("| kreuzberg-native | 0/1 | 0.0 |"));
+        assert!(md.contains("## Failure Taxonomy"));
+        assert!(md.contains("| kreuzberg-native | 1/2 | 0 | 0 | 0 | 0 | 0 | 1 |"));
+        assert!(md.contains("| 0.0 | other 1 |"));
+        assert!(md.contains("## Environment"));
+    }
+
+    #[test]
+    fn test_write_markdown_empty_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("report/results.md");
+
+        write_markdown(&[], &output_path).unwrap();
+
+        let contents = fs::read_to_string(&output_path).unwrap();
+        assert!(contents.contains("No benchmark results."));
+        assert!(contents.contains("| Harness version |"));
+    }
 }
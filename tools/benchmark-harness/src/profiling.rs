@@ -229,6 +229,39 @@ impl ProfilingResult {
 
         Ok(())
     }
+
+    /// Write the captured stacks in folded format (`frame;frame;frame count` per line)
+    ///
+    /// Frames run from the root to the leaf. Thread names are left out so profiles of
+    /// different runs can be compared stack by stack, see [`crate::flame_diff`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Profiling`](crate::Error::Profiling) if the file cannot be written.
+    pub fn write_folded_stacks(&self, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| crate::Error::Profiling(format!("Failed to create output directory: {}", e)))?;
+        }
+
+        let mut folded = String::new();
+        for (frames, count) in &self.report.data {
+            let stack: Vec<String> = frames
+                .frames
+                .iter()
+                .rev()
+                .flat_map(|frame| frame.iter().rev().map(|symbol| symbol.to_string()))
+                .collect();
+            if !stack.is_empty() && *count > 0 {
+                folded.push_str(&format!("{} {}\n", stack.join(";"), count));
+            }
+        }
+
+        std::fs::write(output_path, folded)
+            .map_err(|e| crate::Error::Profiling(format!("Failed to write folded stacks: {}", e)))
+    }
 }
 
 /// No-op profiling support when feature is disabled or on Windows
@@ -290,6 +323,12 @@ pub mod noop {
             eprintln!("Profiling is not available on this platform or feature is disabled");
             Ok(())
         }
+
+        /// No-op folded stack export
+        #[inline(always)]
+        pub fn write_folded_stacks(&self, _output_path: &Path) -> Result<()> {
+            Ok(())
+        }
     }
 }
 
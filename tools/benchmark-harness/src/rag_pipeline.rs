@@ -0,0 +1,523 @@
+//! End-to-end RAG pipeline benchmark
+//!
+//! Users choose an extraction framework for what their retrieval pipeline produces, not
+//! for extraction speed alone. This benchmark runs the complete pipeline once per
+//! framework: extract every document, chunk the content, embed the chunks with an
+//! embedding preset, index them in an in-memory vector store and answer the fixed query
+//! set of the fixtures against the whole index. It reports time per stage, end-to-end
+//! latency and retrieval quality, so a fast framework that loses structure (and answers)
+//! shows up as such.
+//!
+//! Extraction is the only stage that differs between frameworks: chunking, embedding,
+//! indexing and querying always use Kreuzberg with the same strategy and preset.
+//!
+//! ## Fixtures
+//!
+//! The benchmark uses the `retrieval` section of the chunking retrieval fixtures (see
+//! [`crate::chunking_retrieval`]). Every requested framework runs every fixture so all
+//! indexes hold the same corpus; `expected_frameworks` is not consulted. A question is
+//! answered when one of the top `k` chunks of the whole index comes from the question's
+//! document and contains its `answer`; questions about documents that failed to extract
+//! count as misses.
+
+use crate::adapters::SubprocessAdapter;
+use crate::chunking_retrieval::{ChunkingStrategy, cosine_similarity, embed, normalize_text};
+use crate::fixture::Fixture;
+use crate::{Error, Result};
+use kreuzberg::types::Chunk;
+use kreuzberg::{EmbeddingConfig, EmbeddingModelType, ExtractionConfig, extract_file};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Framework whose extraction feeds the pipeline
+pub enum RagFramework {
+    /// In-process Kreuzberg extraction with the given configuration
+    Kreuzberg(ExtractionConfig),
+
+    /// External framework run through its wrapper script (reads `content` from its JSON output)
+    External(SubprocessAdapter),
+}
+
+impl RagFramework {
+    /// Framework name used in the report
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Kreuzberg(_) => "kreuzberg",
+            Self::External(adapter) => adapter.name(),
+        }
+    }
+
+    async fn extract(&self, document: &Path, timeout: Duration) -> Result<(String, Duration)> {
+        match self {
+            Self::Kreuzberg(config) => {
+                let start = Instant::now();
+                let result = tokio::time::timeout(timeout, extract_file(document, None, config))
+                    .await
+                    .map_err(|_| Error::Timeout(format!("Extraction exceeded {:?}", timeout)))?
+                    .map_err(|e| Error::Benchmark(format!("Extraction failed: {}", e)))?;
+                Ok((result.content, start.elapsed()))
+            }
+            Self::External(adapter) => adapter.extract_content(document, timeout).await,
+        }
+    }
+}
+
+/// Chunk stored in the in-memory vector store
+#[derive(Debug, Clone)]
+pub struct IndexedChunk {
+    /// Index of the document the chunk was cut from
+    pub document: usize,
+
+    /// Chunk text, lowercased with whitespace collapsed for answer matching
+    pub content: String,
+
+    /// Chunk embedding
+    pub embedding: Vec<f32>,
+}
+
+/// Exact (brute-force) cosine-similarity vector store
+#[derive(Debug, Clone, Default)]
+pub struct VectorIndex {
+    entries: Vec<IndexedChunk>,
+}
+
+impl VectorIndex {
+    /// Add the embedded chunks of a document; chunks without an embedding are skipped
+    pub fn insert(&mut self, document: usize, chunks: Vec<Chunk>) {
+        self.entries.extend(chunks.into_iter().filter_map(|chunk| {
+            Some(IndexedChunk {
+                document,
+                content: normalize_text(&chunk.content),
+                embedding: chunk.embedding?,
+            })
+        }));
+    }
+
+    /// Number of indexed chunks
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index holds no chunks
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The `top_k` chunks most similar to `query`, best first
+    pub fn search(&self, query: &[f32], top_k: usize) -> Vec<&IndexedChunk> {
+        let mut scored: Vec<(f32, &IndexedChunk)> = self
+            .entries
+            .iter()
+            .map(|entry| (cosine_similarity(&entry.embedding, query), entry))
+            .collect();
+        scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+        scored.into_iter().take(top_k).map(|(_, entry)| entry).collect()
+    }
+
+    /// 1-based rank of the first top-`k` chunk from `document` that contains `answer`
+    pub fn answer_rank(&self, query: &[f32], document: usize, answer: &str, top_k: usize) -> Option<usize> {
+        let answer = normalize_text(answer);
+        self.search(query, top_k)
+            .iter()
+            .position(|entry| entry.document == document && entry.content.contains(&answer))
+            .map(|index| index + 1)
+    }
+}
+
+/// Time spent on one document before querying
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocumentTimings {
+    /// Extraction time reported by the framework
+    pub extraction_ms: f64,
+
+    /// Time to split the content into chunks
+    pub chunking_ms: f64,
+
+    /// Time to embed the chunks
+    pub embedding_ms: f64,
+
+    /// Time to add the chunks to the vector store
+    pub indexing_ms: f64,
+}
+
+impl DocumentTimings {
+    /// Sum of all stages
+    pub fn total_ms(&self) -> f64 {
+        self.extraction_ms + self.chunking_ms + self.embedding_ms + self.indexing_ms
+    }
+}
+
+/// Pipeline outcome for one framework on one fixture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RagDocumentResult {
+    /// Framework whose extraction fed the pipeline
+    pub framework: String,
+
+    /// Path to the fixture JSON file
+    pub fixture: PathBuf,
+
+    /// Document file type (e.g. "md")
+    pub file_type: String,
+
+    /// Error message when extraction failed
+    pub error_message: Option<String>,
+
+    /// Stage timings (extraction only, when extraction failed)
+    pub timings: DocumentTimings,
+
+    /// Number of chunks indexed for the document
+    pub chunk_count: usize,
+
+    /// Number of questions about the document
+    pub questions: usize,
+
+    /// Questions answered in the top `k` chunks of the whole index
+    pub hits: usize,
+
+    /// Mean reciprocal rank of the answering chunk (0 when not retrieved)
+    pub mrr: f64,
+
+    /// Mean time to embed a question and search the index
+    pub mean_query_ms: f64,
+}
+
+/// Aggregated pipeline outcome for one framework
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RagFrameworkSummary {
+    /// Framework name
+    pub framework: String,
+
+    /// Number of fixtures run
+    pub documents: usize,
+
+    /// Number of fixtures extracted successfully
+    pub succeeded: usize,
+
+    /// Chunks in the vector store after ingestion
+    pub indexed_chunks: usize,
+
+    /// Total time per stage across all documents
+    pub stage_totals: DocumentTimings,
+
+    /// Total time spent answering the query set
+    pub query_ms: f64,
+
+    /// Ingestion plus querying: the end-to-end latency of the pipeline
+    pub end_to_end_ms: f64,
+
+    /// Mean time to embed a question and search the index
+    pub mean_query_ms: f64,
+
+    /// Number of questions asked
+    pub questions: usize,
+
+    /// Fraction of questions answered in the top `k` chunks
+    pub hit_rate: f64,
+
+    /// Mean reciprocal rank across questions
+    pub mrr: f64,
+}
+
+/// Complete RAG pipeline benchmark report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RagPipelineReport {
+    /// Embedding preset used for chunks and questions
+    pub preset: String,
+
+    /// Chunking strategy applied to every framework's output
+    pub strategy: ChunkingStrategy,
+
+    /// Number of chunks retrieved per question
+    pub top_k: usize,
+
+    /// Per-framework, per-fixture results
+    pub results: Vec<RagDocumentResult>,
+
+    /// Per-framework aggregation, in the order frameworks were given
+    pub summaries: Vec<RagFrameworkSummary>,
+
+    /// Fixtures whose documents were not found
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Run the pipeline for every framework over every fixture with a `retrieval` section
+///
+/// Extraction failures are recorded in the results and their questions count as misses.
+/// Chunking and embedding failures abort the run, since they do not depend on the
+/// framework.
+pub async fn run_rag_pipeline_benchmark(
+    fixtures: &[(PathBuf, Fixture)],
+    frameworks: &[RagFramework],
+    strategy: ChunkingStrategy,
+    preset: &str,
+    top_k: usize,
+    timeout: Duration,
+) -> Result<RagPipelineReport> {
+    if kreuzberg::get_preset(preset).is_none() {
+        return Err(Error::Config(format!(
+            "Unknown embedding preset: {} (available: {})",
+            preset,
+            kreuzberg::list_presets().join(", ")
+        )));
+    }
+
+    let top_k = top_k.max(1);
+    let embedding = EmbeddingConfig {
+        model: EmbeddingModelType::Preset {
+            name: preset.to_string(),
+        },
+        normalize: true,
+        ..Default::default()
+    };
+
+    let mut corpus = Vec::new();
+    let mut skipped = Vec::new();
+    for (fixture_path, fixture) in fixtures {
+        if fixture.retrieval.is_none() {
+            continue;
+        }
+        let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+        let document = fixture.resolve_document_path(fixture_dir);
+        if document.exists() {
+            corpus.push((fixture_path, fixture, document));
+        } else {
+            skipped.push(fixture_path.clone());
+        }
+    }
+
+    let mut results = Vec::new();
+    let mut summaries = Vec::new();
+
+    for framework in frameworks {
+        let mut index = VectorIndex::default();
+        let mut framework_results = Vec::with_capacity(corpus.len());
+
+        for (document_id, (fixture_path, fixture, document)) in corpus.iter().enumerate() {
+            let mut result = RagDocumentResult {
+                framework: framework.name().to_string(),
+                fixture: (*fixture_path).clone(),
+                file_type: fixture.file_type.clone(),
+                error_message: None,
+                timings: DocumentTimings::default(),
+                chunk_count: 0,
+                questions: 0,
+                hits: 0,
+                mrr: 0.0,
+                mean_query_ms: 0.0,
+            };
+
+            let start = Instant::now();
+            match framework.extract(document, timeout).await {
+                Ok((content, duration)) => {
+                    result.timings.extraction_ms = duration.as_secs_f64() * 1000.0;
+
+                    let start = Instant::now();
+                    let mut chunks = strategy.chunk(&content)?;
+                    result.timings.chunking_ms = elapsed_ms(start);
+                    result.chunk_count = chunks.len();
+
+                    let start = Instant::now();
+                    kreuzberg::embeddings::generate_embeddings_for_chunks(&mut chunks, &embedding)
+                        .map_err(|e| Error::Benchmark(format!("Embedding chunks failed: {}", e)))?;
+                    result.timings.embedding_ms = elapsed_ms(start);
+
+                    let start = Instant::now();
+                    index.insert(document_id, chunks);
+                    result.timings.indexing_ms = elapsed_ms(start);
+                }
+                Err(e) => {
+                    result.timings.extraction_ms = elapsed_ms(start);
+                    result.error_message = Some(e.to_string());
+                }
+            }
+            framework_results.push(result);
+        }
+
+        let mut query_ms = 0.0;
+        for (document_id, (_, fixture, _)) in corpus.iter().enumerate() {
+            let Some(expectations) = &fixture.retrieval else {
+                continue;
+            };
+            let result = &mut framework_results[document_id];
+            let mut reciprocal_ranks = 0.0;
+            let mut document_query_ms = 0.0;
+
+            for question in &expectations.questions {
+                let start = Instant::now();
+                let query = embed(vec![question.question.clone()], &embedding)?
+                    .pop()
+                    .unwrap_or_default();
+                let rank = index.answer_rank(&query, document_id, &question.answer, top_k);
+                document_query_ms += elapsed_ms(start);
+
+                if let Some(rank) = rank {
+                    result.hits += 1;
+                    reciprocal_ranks += 1.0 / rank as f64;
+                }
+            }
+
+            result.questions = expectations.questions.len();
+            result.mrr = ratio(reciprocal_ranks, result.questions);
+            result.mean_query_ms = ratio(document_query_ms, result.questions);
+            query_ms += document_query_ms;
+        }
+
+        summaries.push(summarize_framework(
+            framework.name(),
+            &framework_results,
+            index.len(),
+            query_ms,
+        ));
+        results.extend(framework_results);
+    }
+
+    Ok(RagPipelineReport {
+        preset: preset.to_string(),
+        strategy,
+        top_k,
+        results,
+        summaries,
+        skipped,
+    })
+}
+
+/// Aggregate the per-fixture results of one framework
+pub fn summarize_framework(
+    framework: &str,
+    results: &[RagDocumentResult],
+    indexed_chunks: usize,
+    query_ms: f64,
+) -> RagFrameworkSummary {
+    let mut stage_totals = DocumentTimings::default();
+    for result in results {
+        stage_totals.extraction_ms += result.timings.extraction_ms;
+        stage_totals.chunking_ms += result.timings.chunking_ms;
+        stage_totals.embedding_ms += result.timings.embedding_ms;
+        stage_totals.indexing_ms += result.timings.indexing_ms;
+    }
+
+    let questions: usize = results.iter().map(|result| result.questions).sum();
+    let hits: usize = results.iter().map(|result| result.hits).sum();
+    let reciprocal_ranks: f64 = results.iter().map(|result| result.mrr * result.questions as f64).sum();
+
+    RagFrameworkSummary {
+        framework: framework.to_string(),
+        documents: results.len(),
+        succeeded: results.iter().filter(|result| result.error_message.is_none()).count(),
+        indexed_chunks,
+        end_to_end_ms: stage_totals.total_ms() + query_ms,
+        stage_totals,
+        query_ms,
+        mean_query_ms: ratio(query_ms, questions),
+        questions,
+        hit_rate: ratio(hits as f64, questions),
+        mrr: ratio(reciprocal_ranks, questions),
+    }
+}
+
+/// Write a RAG pipeline report to a JSON file
+pub fn write_rag_pipeline_report(report: &RagPipelineReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize RAG pipeline report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+fn ratio(numerator: f64, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator / denominator as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kreuzberg::types::ChunkMetadata;
+
+    fn chunk(content: &str, embedding: Vec<f32>) -> Chunk {
+        Chunk {
+            content: content.to_string(),
+            embedding: Some(embedding),
+            metadata: ChunkMetadata {
+                byte_start: 0,
+                byte_end: content.len(),
+                token_count: None,
+                chunk_index: 0,
+                total_chunks: 1,
+                first_page: None,
+                last_page: None,
+                annotations: Default::default(),
+            },
+        }
+    }
+
+    fn result(framework: &str, failed: bool, questions: usize, hits: usize, mrr: f64) -> RagDocumentResult {
+        RagDocumentResult {
+            framework: framework.to_string(),
+            fixture: PathBuf::from("fixture.json"),
+            file_type: "md".to_string(),
+            error_message: failed.then(|| "Extraction failed".to_string()),
+            timings: DocumentTimings {
+                extraction_ms: 10.0,
+                chunking_ms: 1.0,
+                embedding_ms: 5.0,
+                indexing_ms: 0.5,
+            },
+            chunk_count: 4,
+            questions,
+            hits,
+            mrr,
+            mean_query_ms: 2.0,
+        }
+    }
+
+    #[test]
+    fn test_index_searches_across_documents() {
+        let mut index = VectorIndex::default();
+        index.insert(
+            0,
+            vec![
+                chunk("The station began\nbroadcasting in 1941", vec![0.6, 0.8]),
+                chunk("Unrelated text", vec![1.0, 0.0]),
+            ],
+        );
+        index.insert(1, vec![chunk("Began broadcasting in 1941 too", vec![0.0, 1.0])]);
+        let query = [0.0, 1.0];
+
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.search(&query, 2)[0].document, 1);
+        assert_eq!(index.answer_rank(&query, 0, "Began broadcasting in 1941", 3), Some(2));
+        assert_eq!(index.answer_rank(&query, 0, "began broadcasting in 1941", 1), None);
+        assert_eq!(index.answer_rank(&query, 1, "began broadcasting in 1941", 1), Some(1));
+    }
+
+    #[test]
+    fn test_summary_counts_failed_documents_as_misses() {
+        let results = vec![result("docling", false, 4, 3, 0.5), result("docling", true, 2, 0, 0.0)];
+
+        let summary = summarize_framework("docling", &results, 8, 12.0);
+
+        assert_eq!(summary.documents, 2);
+        assert_eq!(summary.succeeded, 1);
+        assert_eq!(summary.questions, 6);
+        assert!((summary.hit_rate - 0.5).abs() < 1e-9);
+        assert!((summary.mrr - 2.0 / 6.0).abs() < 1e-9);
+        assert!((summary.mean_query_ms - 2.0).abs() < 1e-9);
+        assert!((summary.stage_totals.extraction_ms - 20.0).abs() < 1e-9);
+        assert!((summary.end_to_end_ms - 45.0).abs() < 1e-9);
+    }
+}
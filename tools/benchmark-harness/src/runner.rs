@@ -3,12 +3,20 @@
 //! This module orchestrates benchmark execution across multiple fixtures and frameworks,
 //! with support for concurrent execution and progress reporting.
 
+use crate::accuracy::{ResultProvenance, score_text};
 use crate::adapter::FrameworkAdapter;
+use crate::assertions::{OutputAssertions, evaluate_assertions};
 use crate::config::{BenchmarkConfig, BenchmarkMode};
+use crate::diff_view::write_extracted_outputs;
+use crate::failures::FailureKind;
 use crate::fixture::FixtureManager;
+use crate::leaks::ResourceLeaks;
 use crate::registry::AdapterRegistry;
-use crate::types::{BenchmarkResult, DurationStatistics, IterationResult, PerformanceMetrics};
+use crate::types::{
+    BenchmarkResult, DurationStatistics, IterationResult, OverheadBreakdown, PerformanceMetrics, WarmupStatistics,
+};
 use crate::{Error, Result};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
@@ -113,6 +121,25 @@ fn calculate_statistics(iterations: &[IterationResult]) -> DurationStatistics {
     }
 }
 
+/// Summarize warmup iterations against the mean of the measured ones
+///
+/// Returns `None` when no warmup iteration was run.
+fn warmup_statistics(durations: Vec<Duration>, measured_mean: Duration) -> Option<WarmupStatistics> {
+    if durations.is_empty() {
+        return None;
+    }
+
+    let mean = durations.iter().sum::<Duration>() / durations.len() as u32;
+    let slowdown = (!measured_mean.is_zero()).then(|| mean.as_secs_f64() / measured_mean.as_secs_f64());
+
+    Some(WarmupStatistics {
+        iterations: durations.len(),
+        durations,
+        mean,
+        slowdown,
+    })
+}
+
 /// Check if profiling is enabled via environment variable
 ///
 /// # Returns
@@ -160,6 +187,109 @@ fn aggregate_metrics(iterations: &[IterationResult]) -> PerformanceMetrics {
     }
 }
 
+/// Average per-phase overhead across iterations
+///
+/// Each phase is averaged over the iterations that reported it.
+fn aggregate_overhead_breakdown(results: &[BenchmarkResult]) -> Option<OverheadBreakdown> {
+    let breakdowns: Vec<&OverheadBreakdown> = results.iter().filter_map(|r| r.overhead_breakdown.as_ref()).collect();
+    if breakdowns.is_empty() {
+        return None;
+    }
+
+    let mean = |phase: fn(&OverheadBreakdown) -> Option<Duration>| {
+        let values: Vec<Duration> = breakdowns.iter().copied().filter_map(phase).collect();
+        (!values.is_empty()).then(|| values.iter().sum::<Duration>() / values.len() as u32)
+    };
+
+    Some(OverheadBreakdown {
+        spawn: mean(|b| b.spawn),
+        import: mean(|b| b.import),
+        serialization: mean(|b| b.serialization),
+        extraction: mean(|b| b.extraction),
+        other: mean(|b| b.other),
+    })
+}
+
+/// Add up the leaks of every iteration, or None when no iteration was checked
+fn aggregate_resource_leaks<'a>(results: impl IntoIterator<Item = &'a BenchmarkResult>) -> Option<ResourceLeaks> {
+    results
+        .into_iter()
+        .filter_map(|r| r.resource_leaks.as_ref())
+        .fold(None, |total: Option<ResourceLeaks>, leaks| {
+            let mut total = total.unwrap_or_default();
+            total.accumulate(leaks);
+            Some(total)
+        })
+}
+
+/// What a fixture declares for accuracy scoring
+#[derive(Debug, Clone, Default)]
+struct ExpectedText {
+    /// Ground truth text, if the fixture has one and it could be read
+    ground_truth: Option<String>,
+
+    /// Whether the document is born-digital, if known
+    born_digital: Option<bool>,
+}
+
+/// Score each result's extracted output against ground truth and record its provenance
+///
+/// `expected` maps resolved document paths to what the fixture declares. Must run before
+/// `apply_assertions`, which drops the extracted output.
+fn apply_accuracy(results: &mut [BenchmarkResult], expected: &HashMap<PathBuf, ExpectedText>) {
+    for result in results.iter_mut().filter(|r| r.success) {
+        let Some(output) = &result.extracted_output else {
+            continue;
+        };
+        let expected = expected.get(&result.file_path).cloned().unwrap_or_default();
+
+        if let Some(ground_truth) = &expected.ground_truth {
+            result.quality = Some(score_text(&output.content, ground_truth));
+        }
+        if expected.born_digital.is_some() || output.text_provenance.is_some() {
+            result.provenance = Some(ResultProvenance {
+                born_digital: expected.born_digital,
+                extracted: output.text_provenance,
+            });
+        }
+    }
+}
+
+/// Reclassify successful extractions that produced no text as `empty_output` failures
+///
+/// Must run before `apply_accuracy` and `apply_assertions`. Results without extracted
+/// output, such as aggregated batch results, are left as they are.
+fn apply_empty_output(results: &mut [BenchmarkResult]) {
+    for result in results.iter_mut().filter(|r| r.success) {
+        if result
+            .extracted_output
+            .as_ref()
+            .is_some_and(|output| output.content.trim().is_empty())
+        {
+            result.success = false;
+            result.error_message = Some("Extraction produced no text".to_string());
+            result.failure = Some(FailureKind::EmptyOutput);
+        }
+    }
+}
+
+/// Evaluate fixture assertions against each result's extracted output
+///
+/// `expected` maps resolved document paths to the fixture's assertions. Results for
+/// failed extractions and aggregated batch results are left unchecked. The extracted
+/// output is dropped from every result afterwards.
+fn apply_assertions(results: &mut [BenchmarkResult], expected: &HashMap<PathBuf, OutputAssertions>) {
+    for result in results {
+        let output = result.extracted_output.take();
+        if !result.success {
+            continue;
+        }
+        if let (Some(assertions), Some(output)) = (expected.get(&result.file_path), output) {
+            result.assertions = Some(evaluate_assertions(assertions, &output));
+        }
+    }
+}
+
 /// Orchestrates benchmark execution across fixtures and frameworks
 pub struct BenchmarkRunner {
     config: BenchmarkConfig,
@@ -180,20 +310,26 @@ impl BenchmarkRunner {
     }
 
     /// Load fixtures from a directory or file
+    ///
+    /// Fixtures outside the configured `file_types` and `max_file_size` are skipped.
     pub fn load_fixtures(&mut self, path: &PathBuf) -> Result<()> {
         if path.is_dir() {
             self.fixtures.load_fixtures_from_dir(path)?;
         } else {
             self.fixtures.load_fixture(path)?;
         }
+        if let Some(file_types) = &self.config.file_types {
+            self.fixtures.retain_types(file_types);
+        }
+        if let Some(max_file_size) = self.config.max_file_size {
+            self.fixtures.retain_max_size(max_file_size);
+        }
         Ok(())
     }
 
-    /// Filter fixtures by file type (to be implemented when needed)
-    ///
-    /// For now, filtering is done during execution based on adapter support
-    pub fn filter_fixtures(&mut self, _file_types: &[String]) {
-        // TODO: Implement fixture filtering if needed
+    /// Keep only fixtures of the given file types (extensions without dot, e.g. "pdf")
+    pub fn filter_fixtures(&mut self, file_types: &[String]) {
+        self.fixtures.retain_types(file_types);
     }
 
     /// Get count of loaded fixtures
@@ -218,12 +354,15 @@ impl BenchmarkRunner {
         cold_start_duration: Option<Duration>,
     ) -> Result<BenchmarkResult> {
         let mut all_results = Vec::new();
+        let warmup_iterations = config.warmup_iterations_for(adapter.name());
+        let mut warmup_durations = Vec::with_capacity(warmup_iterations);
 
         // Estimate task duration from first warmup iteration for adaptive profiling
         let estimated_task_duration_ms = if config.profiling.enabled {
-            let warmup_start = std::time::Instant::now();
             let warmup_result = adapter.extract(file_path, config.timeout).await?;
-            let _warmup_duration = warmup_start.elapsed();
+            if warmup_iterations > 0 {
+                warmup_durations.push(warmup_result.duration);
+            }
             warmup_result.duration.as_millis() as u64
         } else {
             config.profiling.task_duration_ms
@@ -256,10 +395,10 @@ impl BenchmarkRunner {
 
         // Run warmup iterations if needed and not already completed for profiling
         let warmup_start = if config.profiling.enabled { 1 } else { 0 };
-        for _iteration in warmup_start..config.warmup_iterations {
+        for _iteration in warmup_start..warmup_iterations {
+            // Warmup iterations are excluded from statistics; only their durations are kept
             let result = adapter.extract(file_path, config.timeout).await?;
-            // Warmup iterations are discarded
-            drop(result);
+            warmup_durations.push(result.duration);
         }
 
         // Run benchmark iterations with optional task amplification
@@ -288,6 +427,7 @@ impl BenchmarkRunner {
             let fixture_stem = file_path.file_stem().and_then(|s| s.to_str()).unwrap_or("unknown");
 
             let flamegraph_path = format!("flamegraphs/{}/{}/{}.svg", framework_name, mode_name, fixture_stem);
+            let folded_path = format!("flamegraphs/{}/{}/{}.folded", framework_name, mode_name, fixture_stem);
             let report_path = format!(
                 "flamegraphs/{}/{}/{}_report.html",
                 framework_name, mode_name, fixture_stem
@@ -314,6 +454,10 @@ impl BenchmarkRunner {
                         if let Err(e) = result.generate_flamegraph(path) {
                             eprintln!("Warning: Failed to generate flamegraph: {}", e);
                         }
+                        // Kept for differential flamegraphs between versions (`flame-diff`)
+                        if let Err(e) = result.write_folded_stacks(Path::new(&folded_path)) {
+                            eprintln!("Warning: Failed to write folded stacks: {}", e);
+                        }
 
                         // Generate profiling report
                         let profile_report = ProfileReport::from_profiling_result(&result, framework_name);
@@ -341,6 +485,7 @@ impl BenchmarkRunner {
         if config.benchmark_iterations == 1 && !all_results.is_empty() {
             let mut result = all_results.into_iter().next().unwrap();
             result.cold_start_duration = cold_start_duration;
+            result.warmup = warmup_statistics(warmup_durations, result.duration);
             return Ok(result);
         }
 
@@ -375,6 +520,9 @@ impl BenchmarkRunner {
         };
 
         let subprocess_overhead = avg_extraction_duration.map(|ext| statistics.mean.saturating_sub(ext));
+        let overhead_breakdown = aggregate_overhead_breakdown(&all_results);
+        let resource_leaks = aggregate_resource_leaks(&all_results);
+        let warmup = warmup_statistics(warmup_durations, statistics.mean);
 
         let first_result = &all_results[0];
 
@@ -384,17 +532,24 @@ impl BenchmarkRunner {
             file_size: first_result.file_size,
             success: true,
             error_message: None,
+            failure: None,
             duration: statistics.mean,
             extraction_duration: avg_extraction_duration,
             subprocess_overhead,
+            overhead_breakdown,
             metrics: aggregated_metrics,
             quality: first_result.quality.clone(),
             iterations,
             statistics: Some(statistics),
             cold_start_duration,
+            warmup,
             file_extension: first_result.file_extension.clone(),
             framework_capabilities: first_result.framework_capabilities.clone(),
             pdf_metadata: first_result.pdf_metadata.clone(),
+            provenance: None,
+            assertions: None,
+            resource_leaks,
+            extracted_output: first_result.extracted_output.clone(),
         })
     }
 
@@ -414,15 +569,19 @@ impl BenchmarkRunner {
         config: &BenchmarkConfig,
         cold_start_duration: Option<Duration>,
     ) -> Result<Vec<BenchmarkResult>> {
-        let total_iterations = config.warmup_iterations + config.benchmark_iterations;
+        let warmup_iterations = config.warmup_iterations_for(adapter.name());
+        let total_iterations = warmup_iterations + config.benchmark_iterations;
         let mut all_batch_results = Vec::new();
+        let mut warmup_durations = Vec::with_capacity(warmup_iterations);
 
         for iteration in 0..total_iterations {
             let refs: Vec<&std::path::Path> = file_paths.iter().map(|p| p.as_path()).collect();
             let batch_results = adapter.extract_batch(&refs, config.timeout).await?;
 
-            if iteration >= config.warmup_iterations {
+            if iteration >= warmup_iterations {
                 all_batch_results.push(batch_results);
+            } else if let Some(first) = batch_results.first() {
+                warmup_durations.push(first.duration);
             }
         }
 
@@ -430,6 +589,7 @@ impl BenchmarkRunner {
             let mut result = all_batch_results.into_iter().next().unwrap();
             for r in &mut result {
                 r.cold_start_duration = cold_start_duration;
+                r.warmup = warmup_statistics(warmup_durations.clone(), r.duration);
             }
             return Ok(result);
         }
@@ -463,6 +623,8 @@ impl BenchmarkRunner {
         };
 
         let subprocess_overhead = avg_extraction_duration.map(|ext| statistics.mean.saturating_sub(ext));
+        let resource_leaks = aggregate_resource_leaks(batch_iterations.iter().copied());
+        let warmup = warmup_statistics(warmup_durations, statistics.mean);
         let first_result = batch_iterations[0];
 
         let aggregated_results = vec![BenchmarkResult {
@@ -471,17 +633,24 @@ impl BenchmarkRunner {
             file_size: first_result.file_size,
             success: true,
             error_message: None,
+            failure: None,
             duration: statistics.mean,
             extraction_duration: avg_extraction_duration,
             subprocess_overhead,
+            overhead_breakdown: None,
             metrics: aggregated_metrics,
             quality: first_result.quality.clone(),
             iterations,
             statistics: Some(statistics),
             cold_start_duration,
+            warmup,
             file_extension: first_result.file_extension.clone(),
             framework_capabilities: first_result.framework_capabilities.clone(),
             pdf_metadata: first_result.pdf_metadata.clone(),
+            provenance: None,
+            assertions: None,
+            resource_leaks,
+            extracted_output: None,
         }];
 
         Ok(aggregated_results)
@@ -538,13 +707,45 @@ impl BenchmarkRunner {
             }
         }
 
+        let expected_outputs: HashMap<PathBuf, OutputAssertions> = self
+            .fixtures
+            .fixtures()
+            .iter()
+            .filter_map(|(fixture_path, fixture)| {
+                let fixture_dir = fixture_path.parent().unwrap_or_else(|| std::path::Path::new("."));
+                let assertions = fixture.assertions.clone()?;
+                Some((fixture.resolve_document_path(fixture_dir), assertions))
+            })
+            .collect();
+
+        let expected_texts: HashMap<PathBuf, ExpectedText> = self
+            .fixtures
+            .fixtures()
+            .iter()
+            .map(|(fixture_path, fixture)| {
+                let fixture_dir = fixture_path.parent().unwrap_or_else(|| std::path::Path::new("."));
+                let ground_truth = fixture.resolve_ground_truth_path(fixture_dir).and_then(|path| {
+                    match std::fs::read_to_string(&path) {
+                        Ok(text) => Some(text),
+                        Err(e) => {
+                            eprintln!("Warning: Failed to read ground truth {}: {}", path.display(), e);
+                            None
+                        }
+                    }
+                });
+                let expected = ExpectedText {
+                    ground_truth,
+                    born_digital: fixture.born_digital(),
+                };
+                (fixture.resolve_document_path(fixture_dir), expected)
+            })
+            .collect();
+
         let mut results = Vec::new();
 
         let use_batch = matches!(self.config.benchmark_mode, BenchmarkMode::Batch);
 
         if use_batch {
-            use std::collections::HashMap;
-
             let mut adapter_files: HashMap<String, Vec<PathBuf>> = HashMap::new();
 
             for (fixture_path, fixture) in self.fixtures.fixtures() {
@@ -641,6 +842,11 @@ impl BenchmarkRunner {
             adapter.teardown().await?;
         }
 
+        write_extracted_outputs(&results, &self.config.output_dir)?;
+        apply_empty_output(&mut results);
+        apply_accuracy(&mut results, &expected_texts);
+        apply_assertions(&mut results, &expected_outputs);
+
         Ok(results)
     }
 
@@ -696,6 +902,268 @@ mod tests {
         assert_eq!(calculate_amplified_iterations(1, 1000), 1000);
     }
 
+    #[test]
+    fn test_warmup_statistics() {
+        assert!(warmup_statistics(vec![], Duration::from_millis(100)).is_none());
+
+        let warmup = warmup_statistics(
+            vec![Duration::from_millis(500), Duration::from_millis(300)],
+            Duration::from_millis(100),
+        )
+        .unwrap();
+        assert_eq!(warmup.iterations, 2);
+        assert_eq!(warmup.mean, Duration::from_millis(400));
+        assert!((warmup.slowdown.unwrap() - 4.0).abs() < 1e-9);
+
+        let warmup = warmup_statistics(vec![Duration::from_millis(10)], Duration::ZERO).unwrap();
+        assert_eq!(warmup.slowdown, None);
+    }
+
+    #[test]
+    fn test_aggregate_overhead_breakdown() {
+        let result = |spawn_ms: u64, import: Option<Duration>| BenchmarkResult {
+            framework: "kreuzberg-python".to_string(),
+            file_path: PathBuf::from("test.pdf"),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(500),
+            extraction_duration: Some(Duration::from_millis(300)),
+            subprocess_overhead: Some(Duration::from_millis(200)),
+            overhead_breakdown: Some(OverheadBreakdown {
+                spawn: Some(Duration::from_millis(spawn_ms)),
+                import,
+                ..Default::default()
+            }),
+            metrics: PerformanceMetrics::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        };
+
+        let results = [result(40, Some(Duration::from_millis(100))), result(60, None)];
+        let breakdown = aggregate_overhead_breakdown(&results).unwrap();
+        assert_eq!(breakdown.spawn, Some(Duration::from_millis(50)));
+        assert_eq!(breakdown.import, Some(Duration::from_millis(100)));
+        assert_eq!(breakdown.extraction, None);
+
+        assert!(aggregate_overhead_breakdown(&[]).is_none());
+    }
+
+    #[test]
+    fn test_aggregate_resource_leaks() {
+        let result = |leaks: Option<ResourceLeaks>| BenchmarkResult {
+            framework: "kreuzberg-ruby".to_string(),
+            file_path: PathBuf::from("test.pdf"),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: leaks,
+            extracted_output: None,
+        };
+
+        let results = [
+            result(Some(ResourceLeaks {
+                leaked_fds: Some(1),
+                leaked_temp_files: vec!["a.tmp".to_string()],
+            })),
+            result(None),
+            result(Some(ResourceLeaks {
+                leaked_fds: Some(1),
+                leaked_temp_files: vec!["b.tmp".to_string()],
+            })),
+        ];
+        let leaks = aggregate_resource_leaks(&results).unwrap();
+        assert_eq!(leaks.leaked_fds, Some(2));
+        assert_eq!(leaks.leaked_temp_files, vec!["a.tmp", "b.tmp"]);
+
+        assert!(aggregate_resource_leaks(&[result(None)]).is_none());
+    }
+
+    #[test]
+    fn test_apply_assertions() {
+        let result = |framework: &str, content: &str| BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("fixtures/report.pdf"),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: Some(crate::assertions::ExtractedOutput {
+                content: content.to_string(),
+                table_count: None,
+                page_count: Some(2),
+                text_provenance: None,
+            }),
+        };
+
+        let mut results = [
+            result("kreuzberg-native", "Quarterly revenue grew"),
+            result("docling", "Quarterly"),
+        ];
+        let expected = HashMap::from([(
+            PathBuf::from("fixtures/report.pdf"),
+            OutputAssertions {
+                contains: vec!["revenue".to_string()],
+                page_count: Some(2),
+                ..Default::default()
+            },
+        )]);
+
+        apply_assertions(&mut results, &expected);
+
+        assert!(results.iter().all(|r| r.extracted_output.is_none()));
+        assert!(!results[0].assertions.as_ref().unwrap().is_regression());
+        assert!(results[1].assertions.as_ref().unwrap().is_regression());
+    }
+
+    #[test]
+    fn test_apply_accuracy() {
+        let result = |framework: &str, content: &str, text_provenance| BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("fixtures/report.pdf"),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: Some(crate::assertions::ExtractedOutput {
+                content: content.to_string(),
+                table_count: None,
+                page_count: None,
+                text_provenance,
+            }),
+        };
+
+        let mut results = [
+            result("kreuzberg-native", "Quarterly revenue grew", None),
+            result(
+                "docling",
+                "Quarterly revenue grevv",
+                Some(crate::accuracy::TextProvenance::Ocr),
+            ),
+        ];
+        let expected = HashMap::from([(
+            PathBuf::from("fixtures/report.pdf"),
+            ExpectedText {
+                ground_truth: Some("Quarterly revenue grew".to_string()),
+                born_digital: Some(true),
+            },
+        )]);
+
+        apply_accuracy(&mut results, &expected);
+
+        assert_eq!(results[0].quality.as_ref().unwrap().quality_score, 1.0);
+        assert!(!results[0].provenance.unwrap().is_unneeded_ocr());
+        assert!(results[1].quality.as_ref().unwrap().quality_score < 1.0);
+        assert!(results[1].provenance.unwrap().is_unneeded_ocr());
+        assert!(results.iter().all(|r| r.extracted_output.is_some()));
+    }
+
+    #[test]
+    fn test_apply_empty_output() {
+        let result = |framework: &str, content: Option<&str>| BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("fixtures/scan.pdf"),
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(500),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics::default(),
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: content.map(|content| crate::assertions::ExtractedOutput {
+                content: content.to_string(),
+                table_count: None,
+                page_count: None,
+                text_provenance: None,
+            }),
+        };
+
+        let mut results = [
+            result("kreuzberg-native", Some("Scanned invoice")),
+            result("docling", Some(" \n\t")),
+            result("pandoc", None),
+        ];
+
+        apply_empty_output(&mut results);
+
+        assert!(results[0].success);
+        assert!(!results[1].success);
+        assert_eq!(results[1].failure, Some(FailureKind::EmptyOutput));
+        assert!(results[2].success);
+    }
+
     #[test]
     fn test_profiling_config_optimal_frequency() {
         // Quick tasks: highest frequency
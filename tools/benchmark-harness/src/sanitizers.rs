@@ -0,0 +1,604 @@
+//! Memory-safety validation of the native adapter under AddressSanitizer and Miri
+//!
+//! Turns the harness into a memory-safety gate for downstream packagers. Two stages run
+//! against the fixture set of the current checkout:
+//!
+//! - **AddressSanitizer**: the harness is rebuilt with `-Zsanitizer=address` on a nightly
+//!   toolchain and `run` benchmarks `kreuzberg-native` once per fixture. ASAN and its leak
+//!   checker write their reports to log files (`ASAN_OPTIONS=log_path=...`), which are
+//!   parsed into findings: use-after-free, buffer overflows, double frees and leaks.
+//! - **Miri**: Miri cannot execute the C libraries behind PDF, OCR and Office extraction, so
+//!   only a few of the smallest fixtures handled by pure-Rust extractors (plain text,
+//!   Markdown, JSON, CSV, XML, YAML, TOML) are extracted under `cargo miri run`. Undefined
+//!   behavior and leaked allocations it reports become findings; an operation Miri does not
+//!   support skips the stage instead of failing it.
+//!
+//! Both stages build into their own target directory under `--work-dir`, so repeated runs
+//! rebuild incrementally without disturbing the regular release build.
+
+use crate::fixture::Fixture;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Framework run under AddressSanitizer
+pub const SANITIZER_FRAMEWORK: &str = "kreuzberg-native";
+
+/// File types extracted by pure-Rust code paths, the only ones Miri can execute
+pub const MIRI_FILE_TYPES: &[&str] = &[
+    "txt", "md", "markdown", "json", "csv", "tsv", "xml", "yaml", "yml", "toml",
+];
+
+/// Default number of documents extracted under Miri
+pub const DEFAULT_MIRI_DOCUMENTS: usize = 3;
+
+/// Stack frames kept per finding
+const MAX_FRAMES: usize = 8;
+
+/// Tool that produced a finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SanitizerTool {
+    /// AddressSanitizer with LeakSanitizer, on the native adapter
+    AddressSanitizer,
+    /// Miri, on a small pure-Rust corpus
+    Miri,
+}
+
+impl fmt::Display for SanitizerTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::AddressSanitizer => "AddressSanitizer",
+            Self::Miri => "Miri",
+        })
+    }
+}
+
+/// Outcome of one sanitizer stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SanitizerStatus {
+    /// The stage ran to completion without findings
+    Clean,
+    /// The tool reported at least one finding
+    Findings,
+    /// The stage could not run (build failure, crash without a report, unsupported operation)
+    Skipped,
+}
+
+/// One error or leak reported by a sanitizer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SanitizerFinding {
+    /// Kind of finding, e.g. `heap-use-after-free`, `direct-leak` or `undefined-behavior`
+    pub kind: String,
+
+    /// Description as reported by the tool
+    pub message: String,
+
+    /// Bytes leaked, for leak findings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaked_bytes: Option<u64>,
+
+    /// Innermost stack frames (ASAN) or source locations (Miri), innermost first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frames: Vec<String>,
+}
+
+/// Result of one sanitizer stage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SanitizerRun {
+    /// Tool of the stage
+    pub tool: SanitizerTool,
+
+    /// Outcome of the stage
+    pub status: SanitizerStatus,
+
+    /// Documents extracted under the tool (empty for ASAN, which runs the whole fixture set)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub documents: Vec<PathBuf>,
+
+    /// Errors and leaks reported by the tool
+    pub findings: Vec<SanitizerFinding>,
+
+    /// Why a skipped stage could not run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SanitizerRun {
+    fn skipped(tool: SanitizerTool, documents: Vec<PathBuf>, error: impl Into<String>) -> Self {
+        Self {
+            tool,
+            status: SanitizerStatus::Skipped,
+            documents,
+            findings: Vec::new(),
+            error: Some(error.into()),
+        }
+    }
+
+    fn completed(tool: SanitizerTool, documents: Vec<PathBuf>, findings: Vec<SanitizerFinding>) -> Self {
+        let status = if findings.is_empty() {
+            SanitizerStatus::Clean
+        } else {
+            SanitizerStatus::Findings
+        };
+        Self {
+            tool,
+            status,
+            documents,
+            findings,
+            error: None,
+        }
+    }
+}
+
+/// Complete memory-safety report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SanitizerReport {
+    /// Fixture file or directory that was checked
+    pub fixtures: PathBuf,
+
+    /// Nightly toolchain used for both stages
+    pub toolchain: String,
+
+    /// Target triple the instrumented harness was built for
+    pub target: String,
+
+    /// Stages in execution order
+    pub runs: Vec<SanitizerRun>,
+}
+
+impl SanitizerReport {
+    /// Number of findings across all stages
+    pub fn finding_count(&self) -> usize {
+        self.runs.iter().map(|run| run.findings.len()).sum()
+    }
+}
+
+/// Settings of a sanitizer run
+#[derive(Debug, Clone)]
+pub struct SanitizerConfig {
+    /// Workspace whose harness is instrumented
+    pub repo: PathBuf,
+
+    /// Fixture file or directory
+    pub fixtures: PathBuf,
+
+    /// Nightly toolchain passed to cargo as `+<toolchain>`
+    pub toolchain: String,
+
+    /// Target triple, defaulting to the toolchain's host
+    pub target: Option<String>,
+
+    /// Whether to run the AddressSanitizer stage
+    pub asan: bool,
+
+    /// Whether to run the Miri stage
+    pub miri: bool,
+
+    /// Maximum number of documents extracted under Miri
+    pub miri_documents: usize,
+
+    /// Timeout in seconds per extraction under ASAN
+    pub timeout: u64,
+
+    /// Directory for the instrumented target directories, ASAN logs and run results
+    pub work_dir: PathBuf,
+}
+
+/// Run the enabled sanitizer stages on `fixtures`
+pub fn run_sanitizers(config: &SanitizerConfig, fixtures: &[(PathBuf, Fixture)]) -> Result<SanitizerReport> {
+    fs::create_dir_all(&config.work_dir)?;
+    let repo = config.repo.canonicalize()?;
+    let work_dir = config.work_dir.canonicalize()?;
+    let target = match &config.target {
+        Some(target) => target.clone(),
+        None => host_target(&config.toolchain)?,
+    };
+
+    let mut runs = Vec::new();
+    if config.asan {
+        runs.push(run_asan(config, &repo, &work_dir, &target)?);
+    }
+    if config.miri {
+        let documents = miri_documents(fixtures, config.miri_documents)
+            .into_iter()
+            .map(|document| document.canonicalize())
+            .collect::<std::io::Result<Vec<_>>>()?;
+        runs.push(run_miri(config, &repo, &work_dir, &target, documents));
+    }
+
+    Ok(SanitizerReport {
+        fixtures: config.fixtures.clone(),
+        toolchain: config.toolchain.clone(),
+        target,
+        runs,
+    })
+}
+
+/// The smallest existing documents of `fixtures` that Miri can extract, at most `limit`
+pub fn miri_documents(fixtures: &[(PathBuf, Fixture)], limit: usize) -> Vec<PathBuf> {
+    let mut candidates: Vec<(u64, PathBuf)> = fixtures
+        .iter()
+        .filter(|(_, fixture)| MIRI_FILE_TYPES.contains(&fixture.file_type.to_lowercase().as_str()))
+        .map(|(fixture_path, fixture)| {
+            let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+            (fixture.file_size, fixture.resolve_document_path(fixture_dir))
+        })
+        .filter(|(_, document)| document.exists())
+        .collect();
+
+    candidates.sort();
+    candidates.dedup_by(|a, b| a.1 == b.1);
+    candidates
+        .into_iter()
+        .take(limit)
+        .map(|(_, document)| document)
+        .collect()
+}
+
+fn run_asan(config: &SanitizerConfig, repo: &Path, work_dir: &Path, target: &str) -> Result<SanitizerRun> {
+    let tool = SanitizerTool::AddressSanitizer;
+    let target_dir = work_dir.join("asan-target");
+
+    let build = Command::new("cargo")
+        .arg(format!("+{}", config.toolchain))
+        .args([
+            "build",
+            "--package",
+            "benchmark-harness",
+            "--target",
+            target,
+            "--manifest-path",
+        ])
+        .arg(repo.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .env("RUSTFLAGS", "-Zsanitizer=address -Cforce-frame-pointers=yes")
+        .output()?;
+    if !build.status.success() {
+        return Ok(SanitizerRun::skipped(
+            tool,
+            Vec::new(),
+            format!("Build failed: {}", last_line(&build.stderr)),
+        ));
+    }
+
+    let log_dir = work_dir.join("asan-logs");
+    let output_dir = work_dir.join("asan-run");
+    for dir in [&log_dir, &output_dir] {
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+    }
+    fs::create_dir_all(&log_dir)?;
+
+    let run = Command::new(target_dir.join(target).join("debug").join("benchmark-harness"))
+        .arg("run")
+        .arg("--fixtures")
+        .arg(&config.fixtures)
+        .args(["--frameworks", SANITIZER_FRAMEWORK, "--mode", "single-file"])
+        .args(["--iterations", "1", "--warmup", "0"])
+        .args(["--timeout", &config.timeout.to_string()])
+        .arg("--output")
+        .arg(&output_dir)
+        .env(
+            "ASAN_OPTIONS",
+            format!("detect_leaks=1:log_path={}", log_dir.join("asan").display()),
+        )
+        .output()?;
+
+    let mut findings = Vec::new();
+    for entry in fs::read_dir(&log_dir)? {
+        let log = fs::read_to_string(entry?.path())?;
+        findings.extend(parse_asan_log(&log));
+    }
+
+    if findings.is_empty() && !run.status.success() {
+        return Ok(SanitizerRun::skipped(
+            tool,
+            Vec::new(),
+            format!("Benchmark failed: {}", last_line(&run.stderr)),
+        ));
+    }
+
+    Ok(SanitizerRun::completed(tool, Vec::new(), findings))
+}
+
+fn run_miri(
+    config: &SanitizerConfig,
+    repo: &Path,
+    work_dir: &Path,
+    target: &str,
+    documents: Vec<PathBuf>,
+) -> SanitizerRun {
+    let tool = SanitizerTool::Miri;
+    if documents.is_empty() {
+        return SanitizerRun::skipped(tool, documents, "No fixtures with a pure-Rust file type");
+    }
+
+    let run = Command::new("cargo")
+        .arg(format!("+{}", config.toolchain))
+        .args([
+            "miri",
+            "run",
+            "--package",
+            "benchmark-harness",
+            "--target",
+            target,
+            "--manifest-path",
+        ])
+        .arg(repo.join("Cargo.toml"))
+        .arg("--")
+        .arg("miri-extract")
+        .args(&documents)
+        .env("CARGO_TARGET_DIR", work_dir.join("miri-target"))
+        .env("MIRIFLAGS", "-Zmiri-disable-isolation")
+        .output();
+    let run = match run {
+        Ok(run) => run,
+        Err(e) => return SanitizerRun::skipped(tool, documents, format!("Failed to start cargo miri: {}", e)),
+    };
+
+    let stderr = String::from_utf8_lossy(&run.stderr);
+    let findings = parse_miri_output(&stderr);
+    if findings.is_empty() && !run.status.success() {
+        let reason = stderr
+            .lines()
+            .find(|line| line.starts_with("error: unsupported operation"))
+            .map(str::to_string)
+            .unwrap_or_else(|| last_line(&run.stderr));
+        return SanitizerRun::skipped(tool, documents, reason);
+    }
+
+    SanitizerRun::completed(tool, documents, findings)
+}
+
+/// Parse the findings of an AddressSanitizer / LeakSanitizer log
+///
+/// Every `ERROR: AddressSanitizer: <kind>` report and every `Direct leak` / `Indirect leak`
+/// block of a LeakSanitizer report becomes one finding with its innermost frames.
+pub fn parse_asan_log(log: &str) -> Vec<SanitizerFinding> {
+    let mut findings: Vec<SanitizerFinding> = Vec::new();
+    let mut collecting = false;
+
+    for line in log.lines() {
+        let trimmed = line.trim();
+
+        if let Some(report) = trimmed
+            .split_once("ERROR: AddressSanitizer: ")
+            .map(|(_, report)| report)
+        {
+            let kind = report.split_whitespace().next().unwrap_or("unknown");
+            findings.push(SanitizerFinding {
+                kind: kind.to_string(),
+                message: report.to_string(),
+                leaked_bytes: None,
+                frames: Vec::new(),
+            });
+            collecting = true;
+        } else if let Some(kind) = ["Direct leak", "Indirect leak"]
+            .into_iter()
+            .find(|prefix| trimmed.starts_with(prefix))
+        {
+            let leaked_bytes = trimmed.split_whitespace().nth(3).and_then(|bytes| bytes.parse().ok());
+            findings.push(SanitizerFinding {
+                kind: kind.to_lowercase().replace(' ', "-"),
+                message: trimmed.trim_end_matches(':').to_string(),
+                leaked_bytes,
+                frames: Vec::new(),
+            });
+            collecting = true;
+        } else if trimmed.starts_with('#') && collecting {
+            let Some(finding) = findings.last_mut() else {
+                continue;
+            };
+            if let Some((_, frame)) = trimmed.split_once(" in ")
+                && finding.frames.len() < MAX_FRAMES
+            {
+                finding.frames.push(frame.to_string());
+            }
+        } else if trimmed.is_empty() && findings.last().is_some_and(|finding| !finding.frames.is_empty()) {
+            // Only the first stack of a report (the access or allocation site) is kept
+            collecting = false;
+        }
+    }
+
+    findings
+}
+
+/// Parse the findings of `cargo miri run` output
+///
+/// `error: Undefined Behavior: ...` and `error: memory leaked: ...` become findings with the
+/// source locations (`--> file:line:column`) that follow them.
+pub fn parse_miri_output(output: &str) -> Vec<SanitizerFinding> {
+    let mut findings: Vec<SanitizerFinding> = Vec::new();
+    let mut collecting = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+
+        if let Some(message) = trimmed.strip_prefix("error: Undefined Behavior: ") {
+            findings.push(SanitizerFinding {
+                kind: "undefined-behavior".to_string(),
+                message: message.to_string(),
+                leaked_bytes: None,
+                frames: Vec::new(),
+            });
+            collecting = true;
+        } else if let Some(message) = trimmed.strip_prefix("error: memory leaked: ") {
+            let leaked_bytes = message
+                .split_once("size: ")
+                .and_then(|(_, rest)| rest.split(|c: char| !c.is_ascii_digit()).next())
+                .and_then(|bytes| bytes.parse().ok());
+            findings.push(SanitizerFinding {
+                kind: "memory-leak".to_string(),
+                message: message.trim_end_matches(':').to_string(),
+                leaked_bytes,
+                frames: Vec::new(),
+            });
+            collecting = true;
+        } else if trimmed.starts_with("error") {
+            collecting = false;
+        } else if let Some(location) = trimmed.strip_prefix("--> ")
+            && collecting
+            && let Some(finding) = findings.last_mut()
+            && finding.frames.len() < MAX_FRAMES
+        {
+            finding.frames.push(location.to_string());
+        }
+    }
+
+    findings
+}
+
+/// Host triple of `toolchain`, from `rustc -vV`
+fn host_target(toolchain: &str) -> Result<String> {
+    let output = Command::new("rustc")
+        .arg(format!("+{}", toolchain))
+        .arg("-vV")
+        .output()?;
+    if !output.status.success() {
+        return Err(Error::Config(format!(
+            "rustc +{} is not available: {}",
+            toolchain,
+            last_line(&output.stderr)
+        )));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(|host| host.trim().to_string())
+        .ok_or_else(|| Error::Config(format!("rustc +{} did not report a host target", toolchain)))
+}
+
+fn last_line(output: &[u8]) -> String {
+    String::from_utf8_lossy(output)
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+/// Write a sanitizer report to a JSON file
+pub fn write_sanitizer_report(report: &SanitizerReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize sanitizer report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_asan_log() {
+        let log = "\
+=================================================================
+==4242==ERROR: AddressSanitizer: heap-use-after-free on address 0x602000000010 at pc 0x55d0 bp 0x7ffd sp 0x7ffc
+READ of size 8 at 0x602000000010 thread T0
+    #0 0x55d0 in kreuzberg_ffi::kreuzberg_free_result /src/lib.rs:1670:13
+    #1 0x55d1 in benchmark_harness::adapters::native::extract /src/adapters/native.rs:88:9
+
+0x602000000010 is located 0 bytes inside of 16-byte region
+freed by thread T0 here:
+    #0 0x55e0 in free
+SUMMARY: AddressSanitizer: heap-use-after-free /src/lib.rs:1670:13 in kreuzberg_ffi::kreuzberg_free_result
+
+=================================================================
+==4242==ERROR: LeakSanitizer: detected memory leaks
+
+Direct leak of 48 byte(s) in 2 object(s) allocated from:
+    #0 0x55f0 in malloc
+    #1 0x55f1 in alloc::alloc::alloc /rustc/library/alloc/src/alloc.rs:98:9
+
+Indirect leak of 16 byte(s) in 1 object(s) allocated from:
+    #0 0x55f0 in malloc
+
+SUMMARY: AddressSanitizer: 64 byte(s) leaked in 3 allocation(s).
+";
+
+        let findings = parse_asan_log(log);
+        assert_eq!(findings.len(), 3);
+
+        assert_eq!(findings[0].kind, "heap-use-after-free");
+        assert_eq!(
+            findings[0].frames,
+            vec![
+                "kreuzberg_ffi::kreuzberg_free_result /src/lib.rs:1670:13",
+                "benchmark_harness::adapters::native::extract /src/adapters/native.rs:88:9",
+            ]
+        );
+
+        assert_eq!(findings[1].kind, "direct-leak");
+        assert_eq!(findings[1].leaked_bytes, Some(48));
+        assert_eq!(findings[1].frames.len(), 2);
+        assert_eq!(findings[2].kind, "indirect-leak");
+        assert_eq!(findings[2].leaked_bytes, Some(16));
+    }
+
+    #[test]
+    fn test_parse_miri_output() {
+        let output = "\
+error: Undefined Behavior: attempting a read access using <1234> at alloc567[0x0], but that tag does not exist in the borrow stack for this location
+  --> crates/kreuzberg/src/text/buffer.rs:42:18
+   |
+42 |         unsafe { *ptr }
+   |                  ^^^^
+   = note: BACKTRACE:
+   = note: inside `kreuzberg::text::buffer::read` at crates/kreuzberg/src/text/buffer.rs:42:18
+
+error: memory leaked: alloc1234 (Rust heap, size: 24, align: 8), allocated here:
+  --> /rustc/library/alloc/src/alloc.rs:98:9
+
+error: aborting due to 2 previous errors
+";
+
+        let findings = parse_miri_output(output);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].kind, "undefined-behavior");
+        assert_eq!(findings[0].frames, vec!["crates/kreuzberg/src/text/buffer.rs:42:18"]);
+        assert_eq!(findings[1].kind, "memory-leak");
+        assert_eq!(findings[1].leaked_bytes, Some(24));
+        assert!(parse_miri_output("Extracted 3 document(s)\n").is_empty());
+    }
+
+    #[test]
+    fn test_miri_documents_picks_smallest_pure_rust_fixtures() {
+        let dir = tempfile::tempdir().unwrap();
+        let fixture = |name: &str, file_type: &str, file_size: u64| {
+            fs::write(dir.path().join(name), "content").unwrap();
+            let fixture: Fixture = serde_json::from_value(serde_json::json!({
+                "document": name,
+                "file_type": file_type,
+                "file_size": file_size,
+            }))
+            .unwrap();
+            (dir.path().join(format!("{}.json", name)), fixture)
+        };
+        let fixtures = vec![
+            fixture("large.txt", "txt", 9_000),
+            fixture("report.pdf", "pdf", 10),
+            fixture("notes.md", "md", 500),
+            fixture("data.csv", "csv", 100),
+        ];
+
+        let documents = miri_documents(&fixtures, 2);
+        assert_eq!(
+            documents,
+            vec![dir.path().join("data.csv"), dir.path().join("notes.md")]
+        );
+    }
+}
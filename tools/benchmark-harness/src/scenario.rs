@@ -0,0 +1,443 @@
+//! Named benchmark scenarios
+//!
+//! A scenario file declares benchmark runs by name, so a workload such as RAG ingestion is
+//! reviewed and shared as a file instead of being encoded in a long command line:
+//!
+//! ```toml
+//! [scenarios.rag-ingest]
+//! description = "PDF and Office documents as ingested by a RAG pipeline"
+//! fixtures = "../fixtures"
+//! file_types = ["pdf", "docx", "pptx"]
+//! max_file_size = 20_000_000
+//! frameworks = ["kreuzberg-native", "kreuzberg-python-batch"]
+//! modes = ["single-file", "batch"]
+//! warmup_iterations = 1
+//! iterations = 5
+//!
+//! [scenarios.rag-ingest.adapter_warmup]
+//! kreuzberg-java-sync = 5
+//!
+//! [scenarios.rag-ingest.thresholds]
+//! min_success_rate = 0.98
+//! max_mean_duration_ms = 1500.0
+//! ```
+//!
+//! Every field is optional; `run --scenario` falls back to its command-line options for the
+//! fields a scenario leaves out. A relative `fixtures` path is resolved against the directory
+//! of the scenario file. Each mode is run separately, with its reports written to
+//! `<output>/<mode>`, and the thresholds are checked per framework and mode.
+
+use crate::config::BenchmarkMode;
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+/// Contents of a scenario file
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScenarioFile {
+    #[serde(default)]
+    scenarios: BTreeMap<String, BenchmarkScenario>,
+}
+
+/// Benchmark run declared in a scenario file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchmarkScenario {
+    /// Scenario name, the key of its `[scenarios.<name>]` table
+    #[serde(skip)]
+    pub name: String,
+
+    /// Human-readable description
+    pub description: Option<String>,
+
+    /// Fixture directory or file, relative to the scenario file
+    pub fixtures: Option<PathBuf>,
+
+    /// File types to include (e.g., ["pdf", "docx"])
+    pub file_types: Option<Vec<String>>,
+
+    /// Maximum document size to include (bytes)
+    pub max_file_size: Option<u64>,
+
+    /// Frameworks to benchmark
+    pub frameworks: Vec<String>,
+
+    /// Benchmark modes to run, one after the other
+    pub modes: Vec<BenchmarkMode>,
+
+    /// Number of warmup iterations (discarded from statistics)
+    pub warmup_iterations: Option<usize>,
+
+    /// Warmup iterations per framework, overriding `warmup_iterations`
+    pub adapter_warmup: BTreeMap<String, usize>,
+
+    /// Number of benchmark iterations for statistical analysis
+    pub iterations: Option<usize>,
+
+    /// Maximum concurrent extractions in batch mode
+    pub max_concurrent: Option<usize>,
+
+    /// Timeout for each extraction in seconds
+    pub timeout_secs: Option<u64>,
+
+    /// Limits a run must stay within
+    pub thresholds: ScenarioThresholds,
+}
+
+/// Per-framework limits checked after a scenario run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScenarioThresholds {
+    /// Minimum share of successful extractions (0.0-1.0)
+    pub min_success_rate: Option<f64>,
+
+    /// Maximum mean duration of successful extractions in milliseconds
+    pub max_mean_duration_ms: Option<f64>,
+
+    /// Maximum peak memory of any extraction in megabytes
+    pub max_peak_memory_mb: Option<f64>,
+
+    /// Minimum mean quality score (0.0-1.0) over extractions with ground truth
+    pub min_quality_score: Option<f64>,
+}
+
+/// Threshold a framework did not stay within
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdViolation {
+    pub framework: String,
+    pub mode: BenchmarkMode,
+    /// Threshold name as written in the scenario file (e.g., "min_success_rate")
+    pub threshold: String,
+    pub limit: f64,
+    pub actual: f64,
+}
+
+/// Load every scenario of a scenario file
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if the file is not a valid scenario file or declares no scenario
+pub fn load_scenarios(path: &Path) -> Result<Vec<BenchmarkScenario>> {
+    let contents = fs::read_to_string(path).map_err(Error::Io)?;
+    let file: ScenarioFile = toml::from_str(&contents)
+        .map_err(|e| Error::Config(format!("Invalid scenario file {}: {}", path.display(), e)))?;
+
+    if file.scenarios.is_empty() {
+        return Err(Error::Config(format!(
+            "Scenario file {} declares no [scenarios.<name>] table",
+            path.display()
+        )));
+    }
+
+    let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+    file.scenarios
+        .into_iter()
+        .map(|(name, mut scenario)| {
+            scenario.name = name;
+            scenario.fixtures = scenario.fixtures.map(|fixtures| base_dir.join(fixtures));
+            scenario.validate()?;
+            Ok(scenario)
+        })
+        .collect()
+}
+
+/// Load one scenario of a scenario file
+///
+/// Without a name, the file must declare exactly one scenario.
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if the scenario is not found or the file is invalid
+pub fn load_scenario(path: &Path, name: Option<&str>) -> Result<BenchmarkScenario> {
+    let mut scenarios = load_scenarios(path)?;
+    let names = || {
+        scenarios
+            .iter()
+            .map(|scenario| scenario.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let index = match name {
+        Some(name) => scenarios
+            .iter()
+            .position(|scenario| scenario.name == name)
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "Scenario '{}' not found in {} (available: {})",
+                    name,
+                    path.display(),
+                    names()
+                ))
+            })?,
+        None if scenarios.len() == 1 => 0,
+        None => {
+            return Err(Error::Config(format!(
+                "{} declares several scenarios, choose one of: {}",
+                path.display(),
+                names()
+            )));
+        }
+    };
+
+    Ok(scenarios.swap_remove(index))
+}
+
+impl BenchmarkScenario {
+    /// Validate the scenario
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if any value is invalid
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| Error::Config(format!("Scenario '{}': {}", self.name, reason));
+
+        if self.iterations == Some(0) {
+            return Err(invalid("iterations must be > 0".to_string()));
+        }
+
+        if self.max_concurrent == Some(0) {
+            return Err(invalid("max_concurrent must be > 0".to_string()));
+        }
+
+        if self.timeout_secs == Some(0) {
+            return Err(invalid("timeout_secs must be > 0".to_string()));
+        }
+
+        for (index, mode) in self.modes.iter().enumerate() {
+            if self.modes[..index].contains(mode) {
+                return Err(invalid(format!("mode {:?} is listed twice", mode)));
+            }
+        }
+
+        let thresholds = &self.thresholds;
+        for (threshold, value) in [
+            ("min_success_rate", thresholds.min_success_rate),
+            ("min_quality_score", thresholds.min_quality_score),
+        ] {
+            if value.is_some_and(|value| !(0.0..=1.0).contains(&value)) {
+                return Err(invalid(format!("{} must be between 0.0 and 1.0", threshold)));
+            }
+        }
+
+        for (threshold, value) in [
+            ("max_mean_duration_ms", thresholds.max_mean_duration_ms),
+            ("max_peak_memory_mb", thresholds.max_peak_memory_mb),
+        ] {
+            if value.is_some_and(|value| value.is_nan() || value <= 0.0) {
+                return Err(invalid(format!("{} must be > 0", threshold)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ScenarioThresholds {
+    /// Whether no threshold is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Check the results of one mode against the thresholds, per framework
+    pub fn check(&self, results: &[BenchmarkResult], mode: BenchmarkMode) -> Vec<ThresholdViolation> {
+        let mut by_framework: BTreeMap<&str, Vec<&BenchmarkResult>> = BTreeMap::new();
+        for result in results {
+            by_framework.entry(&result.framework).or_default().push(result);
+        }
+
+        let mut violations = Vec::new();
+        for (framework, results) in by_framework {
+            let mut violation = |threshold: &str, limit: f64, actual: f64| {
+                violations.push(ThresholdViolation {
+                    framework: framework.to_string(),
+                    mode,
+                    threshold: threshold.to_string(),
+                    limit,
+                    actual,
+                });
+            };
+
+            let successful: Vec<_> = results.iter().filter(|result| result.success).collect();
+
+            if let Some(limit) = self.min_success_rate {
+                let rate = successful.len() as f64 / results.len() as f64;
+                if rate < limit {
+                    violation("min_success_rate", limit, rate);
+                }
+            }
+
+            if let Some(limit) = self.max_mean_duration_ms
+                && !successful.is_empty()
+            {
+                let mean = successful
+                    .iter()
+                    .map(|result| result.duration.as_secs_f64() * 1000.0)
+                    .sum::<f64>()
+                    / successful.len() as f64;
+                if mean > limit {
+                    violation("max_mean_duration_ms", limit, mean);
+                }
+            }
+
+            if let Some(limit) = self.max_peak_memory_mb {
+                let peak = results
+                    .iter()
+                    .map(|result| result.metrics.peak_memory_bytes)
+                    .max()
+                    .unwrap_or(0) as f64
+                    / BYTES_PER_MB;
+                if peak > limit {
+                    violation("max_peak_memory_mb", limit, peak);
+                }
+            }
+
+            if let Some(limit) = self.min_quality_score {
+                let scores: Vec<f64> = results
+                    .iter()
+                    .filter_map(|result| result.quality.as_ref())
+                    .map(|quality| quality.quality_score)
+                    .collect();
+                if !scores.is_empty() {
+                    let mean = scores.iter().sum::<f64>() / scores.len() as f64;
+                    if mean < limit {
+                        violation("min_quality_score", limit, mean);
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FrameworkCapabilities, PerformanceMetrics};
+    use std::time::Duration;
+
+    fn result(framework: &str, success: bool, duration_ms: u64, peak_memory_bytes: u64) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from("/tmp/test.pdf"),
+            file_size: 1000,
+            success,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(duration_ms),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics {
+                peak_memory_bytes,
+                avg_cpu_percent: 0.0,
+                throughput_bytes_per_sec: 0.0,
+                p50_memory_bytes: 0,
+                p95_memory_bytes: 0,
+                p99_memory_bytes: 0,
+            },
+            quality: None,
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: FrameworkCapabilities::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        }
+    }
+
+    #[test]
+    fn test_load_scenarios_resolves_fixtures() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rag-ingest-scenario.toml");
+        fs::write(
+            &path,
+            r#"
+[scenarios.rag-ingest]
+fixtures = "fixtures"
+file_types = ["pdf", "docx"]
+frameworks = ["kreuzberg-native"]
+modes = ["single-file", "batch"]
+iterations = 5
+
+[scenarios.rag-ingest.adapter_warmup]
+kreuzberg-java-sync = 5
+
+[scenarios.rag-ingest.thresholds]
+min_success_rate = 0.98
+
+[scenarios.smoke]
+iterations = 1
+"#,
+        )
+        .unwrap();
+
+        let scenarios = load_scenarios(&path).unwrap();
+        assert_eq!(scenarios.len(), 2);
+
+        let scenario = load_scenario(&path, Some("rag-ingest")).unwrap();
+        assert_eq!(scenario.fixtures, Some(dir.path().join("fixtures")));
+        assert_eq!(scenario.modes, vec![BenchmarkMode::SingleFile, BenchmarkMode::Batch]);
+        assert_eq!(scenario.iterations, Some(5));
+        assert_eq!(scenario.thresholds.min_success_rate, Some(0.98));
+
+        let config = crate::BenchmarkConfig::default().with_scenario(&scenario, BenchmarkMode::Batch);
+        assert_eq!(config.warmup_iterations_for("kreuzberg-java-sync"), 5);
+        assert_eq!(config.warmup_iterations_for("kreuzberg-native"), 1);
+
+        assert!(load_scenario(&path, None).is_err());
+        assert!(load_scenario(&path, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn test_load_scenarios_rejects_unknown_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scenario.toml");
+        fs::write(&path, "[scenarios.typo]\niteration = 3\n").unwrap();
+
+        assert!(load_scenarios(&path).is_err());
+    }
+
+    #[test]
+    fn test_thresholds_check_per_framework() {
+        let thresholds = ScenarioThresholds {
+            min_success_rate: Some(0.75),
+            max_mean_duration_ms: Some(500.0),
+            max_peak_memory_mb: Some(100.0),
+            min_quality_score: None,
+        };
+        let results = vec![
+            result("fast", true, 100, 10 * 1024 * 1024),
+            result("fast", true, 300, 20 * 1024 * 1024),
+            result("slow", true, 900, 10 * 1024 * 1024),
+            result("slow", false, 10, 200 * 1024 * 1024),
+        ];
+
+        let violations = thresholds.check(&results, BenchmarkMode::Batch);
+        let found: Vec<_> = violations
+            .iter()
+            .map(|violation| (violation.framework.as_str(), violation.threshold.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("slow", "min_success_rate"),
+                ("slow", "max_mean_duration_ms"),
+                ("slow", "max_peak_memory_mb"),
+            ]
+        );
+        assert!((violations[0].actual - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,599 @@
+//! Spreadsheet benchmark suite
+//!
+//! General document benchmarks underweight spreadsheet-heavy workloads, even though
+//! wide exports, formula-driven reports and multi-million-row dumps are among the most
+//! common enterprise inputs. This suite runs the native Kreuzberg extractor against
+//! fixtures that carry a `spreadsheet` section and checks the output for correctness
+//! (sheet and cell counts, computed formula values) in addition to timing it.
+//!
+//! ## Fixture Format
+//!
+//! ```json
+//! {
+//!   "document": "../../../../test_documents/spreadsheets/generated/formulas.xlsx",
+//!   "file_type": "xlsx",
+//!   "file_size": 4311,
+//!   "spreadsheet": {
+//!     "workload": "formulas",
+//!     "sheet_count": 1,
+//!     "cell_count": 406,
+//!     "formula_values": ["1000.0", "50500.0"]
+//!   }
+//! }
+//! ```
+//!
+//! The synthetic workbooks referenced by `fixtures/spreadsheets/` are produced by
+//! `scripts/generate_spreadsheet_fixtures.py`.
+
+use crate::fixture::Fixture;
+use crate::monitoring::ResourceMonitor;
+use crate::{Error, Result};
+use kreuzberg::{ExtractionConfig, Table, extract_file};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// File types the spreadsheet suite accepts
+pub const SPREADSHEET_FILE_TYPES: &[&str] = &["xlsx", "xlsm", "xlsb", "xls", "ods"];
+
+/// Spreadsheet workload category a fixture belongs to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpreadsheetWorkload {
+    /// Ordinary workbook without a dominant characteristic
+    #[default]
+    General,
+    /// Few rows, very many columns
+    WideSheet,
+    /// Cells computed by formulas (cached values must be extracted, not formula text)
+    Formulas,
+    /// Merged header and label cells
+    MergedCells,
+    /// Very large row counts (hundreds of thousands to millions of rows)
+    LargeRows,
+}
+
+impl SpreadsheetWorkload {
+    /// Stable identifier used in reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::General => "general",
+            Self::WideSheet => "wide_sheet",
+            Self::Formulas => "formulas",
+            Self::MergedCells => "merged_cells",
+            Self::LargeRows => "large_rows",
+        }
+    }
+}
+
+/// Correctness expectations for a spreadsheet fixture
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpreadsheetExpectations {
+    /// Workload category used to group results
+    #[serde(default)]
+    pub workload: SpreadsheetWorkload,
+
+    /// Expected number of non-empty sheets (one table per sheet)
+    #[serde(default)]
+    pub sheet_count: Option<usize>,
+
+    /// Expected number of non-empty cells across all sheets, header rows included
+    #[serde(default)]
+    pub cell_count: Option<usize>,
+
+    /// Computed formula results that must appear verbatim as cell values
+    #[serde(default)]
+    pub formula_values: Vec<String>,
+}
+
+/// Shape of the extracted spreadsheet content
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpreadsheetStats {
+    /// Number of extracted tables (one per non-empty sheet)
+    pub sheet_count: usize,
+
+    /// Total number of rows across all tables, header rows included
+    pub row_count: usize,
+
+    /// Total number of non-empty cells across all tables
+    pub cell_count: usize,
+
+    /// Cells whose value looks like formula source (e.g. `=SUM(A1:A3)`) instead of a result
+    pub formula_text_cells: usize,
+}
+
+impl SpreadsheetStats {
+    /// Compute statistics for the tables of an extraction result
+    pub fn from_tables(tables: &[Table]) -> Self {
+        let mut stats = Self {
+            sheet_count: tables.len(),
+            ..Default::default()
+        };
+
+        for row in tables.iter().flat_map(|table| &table.cells) {
+            stats.row_count += 1;
+            for cell in row.iter().map(|cell| cell.trim()).filter(|cell| !cell.is_empty()) {
+                stats.cell_count += 1;
+                if is_formula_text(cell) {
+                    stats.formula_text_cells += 1;
+                }
+            }
+        }
+
+        stats
+    }
+}
+
+/// Outcome of a single correctness check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadsheetCheck {
+    /// Check identifier (e.g. "cell_count", "formula_value")
+    pub name: String,
+
+    /// Whether the extracted output satisfied the expectation
+    pub passed: bool,
+
+    /// Expected value, rendered for display
+    pub expected: String,
+
+    /// Actual value, rendered for display
+    pub actual: String,
+}
+
+impl SpreadsheetCheck {
+    fn new(name: &str, passed: bool, expected: impl ToString, actual: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            passed,
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+}
+
+/// Benchmark and correctness result for one spreadsheet fixture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadsheetFixtureResult {
+    /// Path to the fixture JSON file
+    pub fixture: PathBuf,
+
+    /// Path to the extracted document
+    pub document: PathBuf,
+
+    /// Workload category of the fixture
+    pub workload: SpreadsheetWorkload,
+
+    /// Document size in bytes
+    pub file_size: u64,
+
+    /// Whether every iteration extracted successfully
+    pub success: bool,
+
+    /// Error message of the first failed iteration
+    pub error_message: Option<String>,
+
+    /// Mean extraction duration across iterations
+    pub duration: Duration,
+
+    /// Highest peak memory observed across iterations
+    pub peak_memory_bytes: u64,
+
+    /// Non-empty cells extracted per second (based on the mean duration)
+    pub cells_per_sec: f64,
+
+    /// Shape of the extracted content (absent if extraction failed)
+    pub stats: Option<SpreadsheetStats>,
+
+    /// Correctness checks derived from the fixture expectations
+    pub checks: Vec<SpreadsheetCheck>,
+}
+
+impl SpreadsheetFixtureResult {
+    /// Whether extraction succeeded and every correctness check passed
+    pub fn passed(&self) -> bool {
+        self.success && self.checks.iter().all(|check| check.passed)
+    }
+}
+
+/// Aggregated results for one workload category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadSummary {
+    /// Workload category
+    pub workload: SpreadsheetWorkload,
+
+    /// Number of fixtures in this category
+    pub fixtures: usize,
+
+    /// Number of fixtures that extracted successfully and passed all checks
+    pub passed: usize,
+
+    /// Mean extraction duration in milliseconds (successful fixtures only)
+    pub mean_duration_ms: f64,
+
+    /// Highest peak memory across fixtures in bytes
+    pub max_peak_memory_bytes: u64,
+
+    /// Total number of non-empty cells extracted
+    pub total_cells: usize,
+
+    /// Aggregate extraction rate in cells per second
+    pub cells_per_sec: f64,
+}
+
+/// Complete spreadsheet suite report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadsheetReport {
+    /// Number of timed iterations per fixture
+    pub iterations: usize,
+
+    /// Per-fixture results
+    pub results: Vec<SpreadsheetFixtureResult>,
+
+    /// Per-workload aggregation, ordered by workload
+    pub workloads: Vec<WorkloadSummary>,
+
+    /// Fixtures whose documents were not found (e.g. generated workbooks not yet created)
+    pub skipped: Vec<PathBuf>,
+}
+
+impl SpreadsheetReport {
+    /// Number of fixtures that failed extraction or a correctness check
+    pub fn failed_fixtures(&self) -> usize {
+        self.results.iter().filter(|result| !result.passed()).count()
+    }
+}
+
+/// Check extracted tables against the fixture expectations
+pub fn evaluate_expectations(expectations: &SpreadsheetExpectations, tables: &[Table]) -> Vec<SpreadsheetCheck> {
+    let stats = SpreadsheetStats::from_tables(tables);
+    let mut checks = Vec::new();
+
+    if let Some(expected) = expectations.sheet_count {
+        checks.push(SpreadsheetCheck::new(
+            "sheet_count",
+            stats.sheet_count == expected,
+            expected,
+            stats.sheet_count,
+        ));
+    }
+
+    if let Some(expected) = expectations.cell_count {
+        checks.push(SpreadsheetCheck::new(
+            "cell_count",
+            stats.cell_count == expected,
+            expected,
+            stats.cell_count,
+        ));
+    }
+
+    for value in &expectations.formula_values {
+        let found = tables
+            .iter()
+            .flat_map(|table| &table.cells)
+            .flatten()
+            .any(|cell| cell.trim() == value);
+        checks.push(SpreadsheetCheck::new(
+            "formula_value",
+            found,
+            value,
+            if found { "present" } else { "missing" },
+        ));
+    }
+
+    if expectations.workload == SpreadsheetWorkload::Formulas || !expectations.formula_values.is_empty() {
+        checks.push(SpreadsheetCheck::new(
+            "formula_text_cells",
+            stats.formula_text_cells == 0,
+            0,
+            stats.formula_text_cells,
+        ));
+    }
+
+    checks
+}
+
+/// Run the spreadsheet suite against all fixtures that carry spreadsheet expectations
+///
+/// Fixtures whose document does not exist are reported in [`SpreadsheetReport::skipped`]
+/// rather than failing the run, since the large synthetic workbooks are generated on demand.
+pub async fn run_spreadsheet_suite(
+    fixtures: &[(PathBuf, Fixture)],
+    config: &ExtractionConfig,
+    iterations: usize,
+    timeout: Duration,
+) -> Result<SpreadsheetReport> {
+    let iterations = iterations.max(1);
+    let mut results = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (fixture_path, fixture) in fixtures {
+        let Some(expectations) = &fixture.spreadsheet else {
+            continue;
+        };
+
+        let fixture_dir = fixture_path.parent().unwrap_or_else(|| Path::new("."));
+        let document = fixture.resolve_document_path(fixture_dir);
+        if !document.exists() {
+            skipped.push(fixture_path.clone());
+            continue;
+        }
+
+        let result = run_fixture(fixture_path, &document, expectations, config, iterations, timeout).await?;
+        results.push(result);
+    }
+
+    Ok(SpreadsheetReport {
+        iterations,
+        workloads: summarize_workloads(&results),
+        results,
+        skipped,
+    })
+}
+
+async fn run_fixture(
+    fixture_path: &Path,
+    document: &Path,
+    expectations: &SpreadsheetExpectations,
+    config: &ExtractionConfig,
+    iterations: usize,
+    timeout: Duration,
+) -> Result<SpreadsheetFixtureResult> {
+    let file_size = fs::metadata(document).map_err(Error::Io)?.len();
+
+    let mut durations = Vec::with_capacity(iterations);
+    let mut peak_memory_bytes = 0;
+    let mut error_message = None;
+    let mut last_tables = None;
+
+    for _ in 0..iterations {
+        let monitor = ResourceMonitor::new();
+        monitor.start(Duration::from_millis(10)).await;
+
+        let start = Instant::now();
+        let outcome = tokio::time::timeout(timeout, extract_file(document, None, config)).await;
+        let duration = start.elapsed();
+
+        let samples = monitor.stop().await;
+        let snapshots = monitor.get_snapshots().await;
+        let stats = ResourceMonitor::calculate_stats(&samples, &snapshots);
+        peak_memory_bytes = peak_memory_bytes.max(stats.peak_memory_bytes);
+
+        match outcome {
+            Ok(Ok(extraction)) => {
+                durations.push(duration);
+                last_tables = Some(extraction.tables);
+            }
+            Ok(Err(e)) => {
+                error_message = Some(format!("Extraction failed: {}", e));
+                break;
+            }
+            Err(_) => {
+                error_message = Some(format!("Extraction exceeded {:?}", timeout));
+                break;
+            }
+        }
+    }
+
+    let success = error_message.is_none();
+    let duration = if durations.is_empty() {
+        Duration::ZERO
+    } else {
+        durations.iter().sum::<Duration>() / durations.len() as u32
+    };
+
+    let (stats, checks) = match last_tables.filter(|_| success) {
+        Some(tables) => (
+            Some(SpreadsheetStats::from_tables(&tables)),
+            evaluate_expectations(expectations, &tables),
+        ),
+        None => (None, Vec::new()),
+    };
+
+    let cells_per_sec = match &stats {
+        Some(stats) if duration.as_secs_f64() > 0.0 => stats.cell_count as f64 / duration.as_secs_f64(),
+        _ => 0.0,
+    };
+
+    Ok(SpreadsheetFixtureResult {
+        fixture: fixture_path.to_path_buf(),
+        document: document.to_path_buf(),
+        workload: expectations.workload,
+        file_size,
+        success,
+        error_message,
+        duration,
+        peak_memory_bytes,
+        cells_per_sec,
+        stats,
+        checks,
+    })
+}
+
+/// Aggregate fixture results per workload category
+pub fn summarize_workloads(results: &[SpreadsheetFixtureResult]) -> Vec<WorkloadSummary> {
+    let mut groups: BTreeMap<SpreadsheetWorkload, Vec<&SpreadsheetFixtureResult>> = BTreeMap::new();
+    for result in results {
+        groups.entry(result.workload).or_default().push(result);
+    }
+
+    groups
+        .into_iter()
+        .map(|(workload, group)| {
+            let successful: Vec<_> = group.iter().filter(|result| result.success).collect();
+            let total_seconds: f64 = successful.iter().map(|result| result.duration.as_secs_f64()).sum();
+            let total_cells: usize = successful
+                .iter()
+                .filter_map(|result| result.stats.as_ref())
+                .map(|stats| stats.cell_count)
+                .sum();
+
+            WorkloadSummary {
+                workload,
+                fixtures: group.len(),
+                passed: group.iter().filter(|result| result.passed()).count(),
+                mean_duration_ms: if successful.is_empty() {
+                    0.0
+                } else {
+                    total_seconds * 1000.0 / successful.len() as f64
+                },
+                max_peak_memory_bytes: group.iter().map(|result| result.peak_memory_bytes).max().unwrap_or(0),
+                total_cells,
+                cells_per_sec: if total_seconds > 0.0 {
+                    total_cells as f64 / total_seconds
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect()
+}
+
+/// Write a spreadsheet suite report to a JSON file
+pub fn write_spreadsheet_report(report: &SpreadsheetReport, output_path: &Path) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize spreadsheet report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+fn is_formula_text(cell: &str) -> bool {
+    cell.strip_prefix('=')
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '(' || c == '$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rows: &[&[&str]]) -> Table {
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect();
+        Table {
+            cells,
+            markdown: String::new(),
+            page_number: 1,
+            column_types: None,
+        }
+    }
+
+    fn fixture_result(
+        workload: SpreadsheetWorkload,
+        duration_ms: u64,
+        cells: usize,
+        passed: bool,
+    ) -> SpreadsheetFixtureResult {
+        SpreadsheetFixtureResult {
+            fixture: PathBuf::from("fixture.json"),
+            document: PathBuf::from("book.xlsx"),
+            workload,
+            file_size: 1024,
+            success: true,
+            error_message: None,
+            duration: Duration::from_millis(duration_ms),
+            peak_memory_bytes: duration_ms * 1000,
+            cells_per_sec: 0.0,
+            stats: Some(SpreadsheetStats {
+                sheet_count: 1,
+                row_count: 1,
+                cell_count: cells,
+                formula_text_cells: 0,
+            }),
+            checks: vec![SpreadsheetCheck::new("cell_count", passed, cells, cells)],
+        }
+    }
+
+    #[test]
+    fn test_stats_count_non_empty_cells() {
+        let tables = vec![
+            table(&[&["Name", "Total"], &["A", "=SUM(B1:B2)"], &["", " "]]),
+            table(&[&["x"]]),
+        ];
+
+        let stats = SpreadsheetStats::from_tables(&tables);
+
+        assert_eq!(stats.sheet_count, 2);
+        assert_eq!(stats.row_count, 4);
+        assert_eq!(stats.cell_count, 5);
+        assert_eq!(stats.formula_text_cells, 1);
+    }
+
+    #[test]
+    fn test_evaluate_expectations_formulas() {
+        let tables = vec![table(&[&["Item", "Total"], &["a", "10.0"], &["Grand Total", "50.0"]])];
+        let expectations = SpreadsheetExpectations {
+            workload: SpreadsheetWorkload::Formulas,
+            sheet_count: Some(1),
+            cell_count: Some(7),
+            formula_values: vec!["50.0".to_string(), "99.0".to_string()],
+        };
+
+        let checks = evaluate_expectations(&expectations, &tables);
+        let by_name: Vec<(&str, bool)> = checks.iter().map(|c| (c.name.as_str(), c.passed)).collect();
+
+        assert_eq!(
+            by_name,
+            vec![
+                ("sheet_count", true),
+                ("cell_count", false),
+                ("formula_value", true),
+                ("formula_value", false),
+                ("formula_text_cells", true),
+            ]
+        );
+        assert_eq!(checks[1].actual, "6");
+    }
+
+    #[test]
+    fn test_formula_text_detection() {
+        assert!(is_formula_text("=SUM(A1:A3)"));
+        assert!(is_formula_text("=$A$1*2"));
+        assert!(!is_formula_text("="));
+        assert!(!is_formula_text("==="));
+        assert!(!is_formula_text("= 5"));
+        assert!(!is_formula_text("total"));
+    }
+
+    #[test]
+    fn test_summarize_workloads() {
+        let results = vec![
+            fixture_result(SpreadsheetWorkload::LargeRows, 2000, 1_000_000, true),
+            fixture_result(SpreadsheetWorkload::Formulas, 10, 400, true),
+            fixture_result(SpreadsheetWorkload::Formulas, 30, 600, false),
+        ];
+
+        let summaries = summarize_workloads(&results);
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].workload, SpreadsheetWorkload::Formulas);
+        assert_eq!(summaries[0].fixtures, 2);
+        assert_eq!(summaries[0].passed, 1);
+        assert!((summaries[0].mean_duration_ms - 20.0).abs() < 1e-9);
+        assert_eq!(summaries[0].total_cells, 1000);
+        assert!((summaries[0].cells_per_sec - 25_000.0).abs() < 1e-6);
+        assert_eq!(summaries[0].max_peak_memory_bytes, 30_000);
+        assert_eq!(summaries[1].workload, SpreadsheetWorkload::LargeRows);
+        assert!((summaries[1].cells_per_sec - 500_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_expectations_deserialize_with_defaults() {
+        let expectations: SpreadsheetExpectations =
+            serde_json::from_str(r#"{"workload": "wide_sheet", "cell_count": 50000}"#).unwrap();
+
+        assert_eq!(expectations.workload, SpreadsheetWorkload::WideSheet);
+        assert_eq!(expectations.cell_count, Some(50000));
+        assert_eq!(expectations.sheet_count, None);
+        assert!(expectations.formula_values.is_empty());
+    }
+}
@@ -0,0 +1,327 @@
+//! Extraction stage attribution
+//!
+//! With the `otel` feature, Kreuzberg wraps the phases of OCR extraction in `stage.*`
+//! tracing spans (`stage.rasterize`, `stage.ocr`, `stage.merge`). This module installs a
+//! tracing layer that measures the wall time of each stage span and attributes it to the
+//! benchmark run whose root span (`benchmark.run`) contains it, so concurrent extractions
+//! do not mix their timings.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Span, Subscriber};
+use tracing_subscriber::Registry;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Name of the root span wrapping one benchmarked extraction
+pub const RUN_SPAN: &str = "benchmark.run";
+
+/// Span emitted around page rendering and image encoding
+pub const RASTERIZE_SPAN: &str = "stage.rasterize";
+
+/// Span emitted around OCR of a single page
+pub const OCR_SPAN: &str = "stage.ocr";
+
+/// Span emitted around merging per-page OCR text
+pub const MERGE_SPAN: &str = "stage.merge";
+
+/// Extraction phase that time is attributed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionStage {
+    /// Rendering pages to images (and encoding them for the OCR backend)
+    Rasterization,
+    /// Running the OCR backend on page images
+    Ocr,
+    /// Joining per-page OCR text into the document content
+    TextMerging,
+    /// Time not covered by any stage span (parsing, metadata, post-processing)
+    Other,
+}
+
+impl ExtractionStage {
+    /// All stages in report order
+    pub const ALL: [Self; 4] = [Self::Rasterization, Self::Ocr, Self::TextMerging, Self::Other];
+
+    /// Stable identifier used in reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rasterization => "rasterization",
+            Self::Ocr => "ocr",
+            Self::TextMerging => "text_merging",
+            Self::Other => "other",
+        }
+    }
+
+    /// Stage recorded by a span with the given name, if it is a stage span
+    pub fn from_span_name(name: &str) -> Option<Self> {
+        match name {
+            RASTERIZE_SPAN => Some(Self::Rasterization),
+            OCR_SPAN => Some(Self::Ocr),
+            MERGE_SPAN => Some(Self::TextMerging),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ExtractionStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Wall time spent in each instrumented stage of one extraction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTimings {
+    /// Time spent rasterizing pages
+    pub rasterization: Duration,
+
+    /// Time spent in the OCR backend
+    pub ocr: Duration,
+
+    /// Time spent merging page texts
+    pub text_merging: Duration,
+}
+
+impl StageTimings {
+    /// Time recorded for a stage (always zero for [`ExtractionStage::Other`])
+    pub fn get(&self, stage: ExtractionStage) -> Duration {
+        match stage {
+            ExtractionStage::Rasterization => self.rasterization,
+            ExtractionStage::Ocr => self.ocr,
+            ExtractionStage::TextMerging => self.text_merging,
+            ExtractionStage::Other => Duration::ZERO,
+        }
+    }
+
+    /// Add time to a stage (ignored for [`ExtractionStage::Other`])
+    pub fn add(&mut self, stage: ExtractionStage, duration: Duration) {
+        match stage {
+            ExtractionStage::Rasterization => self.rasterization += duration,
+            ExtractionStage::Ocr => self.ocr += duration,
+            ExtractionStage::TextMerging => self.text_merging += duration,
+            ExtractionStage::Other => {}
+        }
+    }
+
+    /// Total time covered by stage spans
+    pub fn total(&self) -> Duration {
+        self.rasterization + self.ocr + self.text_merging
+    }
+
+    /// Mean timings across iterations
+    pub fn mean(samples: &[StageTimings]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let count = samples.len() as u32;
+        Self {
+            rasterization: samples.iter().map(|sample| sample.rasterization).sum::<Duration>() / count,
+            ocr: samples.iter().map(|sample| sample.ocr).sum::<Duration>() / count,
+            text_merging: samples.iter().map(|sample| sample.text_merging).sum::<Duration>() / count,
+        }
+    }
+}
+
+/// Collects stage timings per benchmark run
+#[derive(Debug, Clone, Default)]
+pub struct StageRecorder {
+    runs: Arc<Mutex<HashMap<u64, StageTimings>>>,
+    next_run_id: Arc<AtomicU64>,
+}
+
+static GLOBAL_RECORDER: OnceLock<Option<StageRecorder>> = OnceLock::new();
+
+impl StageRecorder {
+    /// Create a recorder that is not attached to any subscriber
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install the stage layer as the global tracing subscriber
+    ///
+    /// Returns the shared recorder, or `None` if another global subscriber was already set
+    /// (stage timings are then unavailable). Safe to call repeatedly.
+    pub fn install() -> Option<&'static StageRecorder> {
+        GLOBAL_RECORDER
+            .get_or_init(|| {
+                let recorder = StageRecorder::new();
+                let subscriber = Registry::default().with(recorder.layer());
+                tracing::subscriber::set_global_default(subscriber)
+                    .ok()
+                    .map(|_| recorder)
+            })
+            .as_ref()
+    }
+
+    /// Layer that feeds this recorder
+    pub fn layer(&self) -> StageLayer {
+        StageLayer { recorder: self.clone() }
+    }
+
+    /// Start a new run, returning its id and the root span extractions must run inside
+    pub fn begin_run(&self) -> (u64, Span) {
+        let run_id = self.next_run_id.fetch_add(1, Ordering::Relaxed);
+        self.lock_runs().insert(run_id, StageTimings::default());
+        (run_id, tracing::info_span!(RUN_SPAN, run_id))
+    }
+
+    /// Finish a run and return the stage timings recorded for it
+    pub fn finish_run(&self, run_id: u64) -> StageTimings {
+        self.lock_runs().remove(&run_id).unwrap_or_default()
+    }
+
+    fn record(&self, run_id: u64, stage: ExtractionStage, duration: Duration) {
+        if let Some(timings) = self.lock_runs().get_mut(&run_id) {
+            timings.add(stage, duration);
+        }
+    }
+
+    fn lock_runs(&self) -> std::sync::MutexGuard<'_, HashMap<u64, StageTimings>> {
+        self.runs.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Tracing layer measuring stage spans from creation to close
+#[derive(Debug, Clone)]
+pub struct StageLayer {
+    recorder: StageRecorder,
+}
+
+/// Run id stored in the extensions of a `benchmark.run` span
+#[derive(Debug, Clone, Copy)]
+struct RunId(u64);
+
+/// Stage and creation time stored in the extensions of a stage span
+#[derive(Debug, Clone, Copy)]
+struct StageStart(ExtractionStage, Instant);
+
+#[derive(Default)]
+struct RunIdVisitor(Option<u64>);
+
+impl Visit for RunIdVisitor {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "run_id" {
+            self.0 = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
+}
+
+impl<S> Layer<S> for StageLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let name = attrs.metadata().name();
+
+        if name == RUN_SPAN {
+            let mut visitor = RunIdVisitor::default();
+            attrs.record(&mut visitor);
+            if let Some(run_id) = visitor.0 {
+                span.extensions_mut().insert(RunId(run_id));
+            }
+        } else if let Some(stage) = ExtractionStage::from_span_name(name) {
+            span.extensions_mut().insert(StageStart(stage, Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(StageStart(stage, started)) = span.extensions().get::<StageStart>().copied() else {
+            return;
+        };
+
+        let run_id = span
+            .scope()
+            .skip(1)
+            .find_map(|ancestor| ancestor.extensions().get::<RunId>().copied());
+        if let Some(RunId(run_id)) = run_id {
+            self.recorder.record(run_id, stage, started.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_spans_attributed_to_run() {
+        let recorder = StageRecorder::new();
+        let subscriber = Registry::default().with(recorder.layer());
+
+        let (timings, other_timings) = tracing::subscriber::with_default(subscriber, || {
+            let (run_id, run_span) = recorder.begin_run();
+            let (other_run_id, _other_span) = recorder.begin_run();
+
+            run_span.in_scope(|| {
+                for page in 1..=2 {
+                    let _stage = tracing::info_span!(RASTERIZE_SPAN, page).entered();
+                    std::thread::sleep(Duration::from_millis(2));
+                }
+                let _stage = tracing::info_span!(OCR_SPAN, page = 1).entered();
+                std::thread::sleep(Duration::from_millis(5));
+            });
+            let _unattributed = tracing::info_span!(MERGE_SPAN).entered();
+
+            (recorder.finish_run(run_id), recorder.finish_run(other_run_id))
+        });
+
+        assert!(timings.rasterization >= Duration::from_millis(4));
+        assert!(timings.ocr >= Duration::from_millis(5));
+        assert_eq!(timings.text_merging, Duration::ZERO);
+        assert_eq!(other_timings, StageTimings::default());
+    }
+
+    #[test]
+    fn test_stage_timings_mean() {
+        let samples = [
+            StageTimings {
+                rasterization: Duration::from_millis(10),
+                ocr: Duration::from_millis(100),
+                text_merging: Duration::from_millis(2),
+            },
+            StageTimings {
+                rasterization: Duration::from_millis(20),
+                ocr: Duration::from_millis(200),
+                text_merging: Duration::from_millis(4),
+            },
+        ];
+
+        let mean = StageTimings::mean(&samples);
+
+        assert_eq!(mean.rasterization, Duration::from_millis(15));
+        assert_eq!(mean.ocr, Duration::from_millis(150));
+        assert_eq!(mean.text_merging, Duration::from_millis(3));
+        assert_eq!(mean.total(), Duration::from_millis(168));
+        assert_eq!(StageTimings::mean(&[]), StageTimings::default());
+    }
+
+    #[test]
+    fn test_stage_from_span_name() {
+        assert_eq!(
+            ExtractionStage::from_span_name("stage.rasterize"),
+            Some(ExtractionStage::Rasterization)
+        );
+        assert_eq!(ExtractionStage::from_span_name("stage.ocr"), Some(ExtractionStage::Ocr));
+        assert_eq!(
+            ExtractionStage::from_span_name("stage.merge"),
+            Some(ExtractionStage::TextMerging)
+        );
+        assert_eq!(ExtractionStage::from_span_name("extract_file"), None);
+    }
+}
@@ -1,5 +1,9 @@
 //! Core types for benchmark results and metrics
 
+use crate::accuracy::ResultProvenance;
+use crate::assertions::{AssertionOutcome, ExtractedOutput};
+use crate::failures::FailureKind;
+use crate::leaks::ResourceLeaks;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::time::Duration;
@@ -22,6 +26,11 @@ pub struct BenchmarkResult {
     /// Error message if extraction failed
     pub error_message: Option<String>,
 
+    /// Class of the failure, used for the failure taxonomy (see `crate::failures`)
+    /// Only present for failed extractions; older results are classified from `error_message`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<FailureKind>,
+
     /// Total wall-clock duration (process spawn + extraction)
     /// For single iteration: the actual duration
     /// For multiple iterations: mean duration across all iterations
@@ -35,6 +44,11 @@ pub struct BenchmarkResult {
     /// Only available when extraction_duration is present
     pub subprocess_overhead: Option<Duration>,
 
+    /// Subprocess overhead split into spawn, import, serialization and extraction time
+    /// Only available for wrappers that report their own phase timings
+    #[serde(default)]
+    pub overhead_breakdown: Option<OverheadBreakdown>,
+
     /// Performance metrics (averaged across iterations if multiple)
     pub metrics: PerformanceMetrics,
 
@@ -53,6 +67,11 @@ pub struct BenchmarkResult {
     /// initial framework load time (imports, initializations, etc.)
     pub cold_start_duration: Option<Duration>,
 
+    /// Warmup iterations run before the measured ones, excluded from `statistics`
+    /// Only present when at least one warmup iteration was run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<WarmupStatistics>,
+
     /// File extension without dot (e.g., "pdf", "docx")
     /// Extracted from file_path for per-extension analysis
     pub file_extension: String,
@@ -64,6 +83,53 @@ pub struct BenchmarkResult {
     /// PDF-specific metadata (only present for PDF files)
     /// Includes text layer detection results and OCR strategy
     pub pdf_metadata: Option<PdfMetadata>,
+
+    /// Whether the fixture is born-digital and whether the framework's text came from OCR
+    /// Only present when either is known (see `crate::accuracy`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<ResultProvenance>,
+
+    /// Outcome of the fixture's expected-output assertions
+    /// Only present when the fixture declares assertions and the result covers a single document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertions: Option<AssertionOutcome>,
+
+    /// Descriptors and temp files the extraction left behind
+    /// Only present when the adapter checked for leaks (see `crate::leaks`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_leaks: Option<ResourceLeaks>,
+
+    /// Extracted output, kept in memory until the runner has evaluated fixture assertions
+    #[serde(skip)]
+    pub extracted_output: Option<ExtractedOutput>,
+}
+
+/// Wall-clock time of a subprocess extraction split by phase
+///
+/// Separates harness overhead (process spawn, framework import, IPC serialization)
+/// from pure extraction time so frameworks can be compared on extraction speed alone.
+/// Phases the wrapper does not report are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OverheadBreakdown {
+    /// Time from process spawn until the wrapper script started (interpreter startup)
+    #[serde(default)]
+    pub spawn: Option<Duration>,
+
+    /// Time spent importing/requiring the framework in the wrapper
+    #[serde(default)]
+    pub import: Option<Duration>,
+
+    /// JSON encoding in the wrapper plus JSON parsing in the harness
+    #[serde(default)]
+    pub serialization: Option<Duration>,
+
+    /// Pure extraction time reported by the wrapper
+    #[serde(default)]
+    pub extraction: Option<Duration>,
+
+    /// Wall-clock time not attributed to any phase above (pipe I/O, process exit)
+    #[serde(default)]
+    pub other: Option<Duration>,
 }
 
 /// Performance metrics collected during extraction
@@ -208,6 +274,26 @@ pub struct IterationResult {
     pub metrics: PerformanceMetrics,
 }
 
+/// Warmup iterations of one benchmark, kept apart from the measured iterations
+///
+/// JIT-compiled runtimes are much slower on their first extractions; recording the warmup
+/// durations lets frameworks be compared on how quickly they reach steady state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarmupStatistics {
+    /// Number of warmup iterations run
+    pub iterations: usize,
+
+    /// Duration of each warmup iteration, in order
+    pub durations: Vec<Duration>,
+
+    /// Mean warmup duration
+    pub mean: Duration,
+
+    /// Mean warmup duration divided by the mean measured duration
+    /// Values well above 1.0 mean the framework was still warming up
+    pub slowdown: Option<f64>,
+}
+
 /// Statistical analysis of durations across multiple iterations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DurationStatistics {
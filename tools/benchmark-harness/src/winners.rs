@@ -0,0 +1,472 @@
+//! Machine-readable winner summaries
+//!
+//! For every file extension and metric (latency, peak memory, quality), this module names the
+//! framework with the best mean, the runner-up, the margin between them and whether the
+//! difference is statistically significant. Badge generators and docs tables read
+//! `winners.json` instead of re-deriving rankings from raw results.
+//!
+//! ## Significance
+//!
+//! The winner and runner-up samples are compared with a two-sided Mann-Whitney U test (normal
+//! approximation with tie and continuity correction), which makes no assumption about the
+//! shape of latency or memory distributions. A win is `significant` when the p-value is below
+//! [`SIGNIFICANCE_LEVEL`], and `insufficient_data` when either side has fewer than
+//! [`MIN_SAMPLES`] samples, where the approximation is unreliable.
+//!
+//! ## Output Format
+//!
+//! ```json
+//! {
+//!   "significance_level": 0.05,
+//!   "summaries": [
+//!     {
+//!       "extension": "pdf",
+//!       "metric": "latency",
+//!       "unit": "ms",
+//!       "winner": "kreuzberg-native",
+//!       "winner_value": 12.4,
+//!       "runner_up": "docling",
+//!       "runner_up_value": 310.2,
+//!       "margin": 297.8,
+//!       "margin_percent": 96.0,
+//!       "p_value": 0.0001,
+//!       "significance": "significant",
+//!       "frameworks": 4
+//!     }
+//!   ]
+//! }
+//! ```
+
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// p-value below which a win is reported as significant
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// Minimum samples per framework for a significance test
+pub const MIN_SAMPLES: usize = 5;
+
+/// Metric a winner is determined for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WinnerMetric {
+    /// Wall-clock duration of successful extractions (lower is better)
+    Latency,
+    /// Peak memory of successful extractions (lower is better)
+    Memory,
+    /// Quality score against ground truth (higher is better)
+    Quality,
+}
+
+impl WinnerMetric {
+    /// All metrics, in report order
+    pub const ALL: [WinnerMetric; 3] = [WinnerMetric::Latency, WinnerMetric::Memory, WinnerMetric::Quality];
+
+    /// Sample of this metric for a result, `None` if the result does not contribute
+    pub fn sample(self, result: &BenchmarkResult) -> Option<f64> {
+        if !result.success {
+            return None;
+        }
+        match self {
+            WinnerMetric::Latency => Some(result.duration.as_secs_f64() * 1000.0),
+            WinnerMetric::Memory => Some(result.metrics.peak_memory_bytes as f64 / 1_000_000.0),
+            WinnerMetric::Quality => result.quality.as_ref().map(|quality| quality.quality_score),
+        }
+    }
+
+    /// Whether higher values are better
+    pub fn higher_is_better(self) -> bool {
+        matches!(self, WinnerMetric::Quality)
+    }
+
+    /// Unit of the reported values
+    pub fn unit(self) -> &'static str {
+        match self {
+            WinnerMetric::Latency => "ms",
+            WinnerMetric::Memory => "MB",
+            WinnerMetric::Quality => "score",
+        }
+    }
+}
+
+impl fmt::Display for WinnerMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WinnerMetric::Latency => write!(f, "latency"),
+            WinnerMetric::Memory => write!(f, "memory"),
+            WinnerMetric::Quality => write!(f, "quality"),
+        }
+    }
+}
+
+/// Whether a win holds up statistically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Significance {
+    /// p-value below [`SIGNIFICANCE_LEVEL`]
+    Significant,
+    /// p-value at or above [`SIGNIFICANCE_LEVEL`]; the frameworks are effectively tied
+    NotSignificant,
+    /// Fewer than [`MIN_SAMPLES`] samples for the winner or the runner-up
+    InsufficientData,
+    /// Only one framework has samples for the extension and metric
+    Uncontested,
+}
+
+/// Winner of one metric for one file extension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WinnerSummary {
+    /// File extension (e.g., "pdf")
+    pub extension: String,
+
+    /// Metric the winner was determined for
+    pub metric: WinnerMetric,
+
+    /// Unit of the values ("ms", "MB" or "score")
+    pub unit: String,
+
+    /// Framework with the best mean
+    pub winner: String,
+
+    /// Mean of the winner
+    pub winner_value: f64,
+
+    /// Framework with the second-best mean, `None` if uncontested
+    pub runner_up: Option<String>,
+
+    /// Mean of the runner-up
+    pub runner_up_value: Option<f64>,
+
+    /// Absolute difference between the runner-up and the winner
+    pub margin: Option<f64>,
+
+    /// Margin relative to the runner-up, in percent
+    pub margin_percent: Option<f64>,
+
+    /// Two-sided Mann-Whitney U p-value, `None` without a test
+    pub p_value: Option<f64>,
+
+    /// Significance of the win
+    pub significance: Significance,
+
+    /// Number of frameworks with samples for the extension and metric
+    pub frameworks: usize,
+}
+
+/// Winner summaries for every extension and metric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WinnerReport {
+    /// p-value threshold the significance flags use
+    pub significance_level: f64,
+
+    /// Summaries sorted by extension, then metric
+    pub summaries: Vec<WinnerSummary>,
+}
+
+impl WinnerReport {
+    /// Summary for an extension and metric
+    pub fn get(&self, extension: &str, metric: WinnerMetric) -> Option<&WinnerSummary> {
+        self.summaries
+            .iter()
+            .find(|summary| summary.extension == extension && summary.metric == metric)
+    }
+}
+
+/// Determine the winner of every metric for every file extension
+///
+/// Extensions without samples for a metric (e.g., quality without ground truth) are omitted
+/// for that metric.
+///
+/// # Arguments
+/// * `results` - Benchmark results to analyze
+pub fn summarize_winners(results: &[BenchmarkResult]) -> WinnerReport {
+    let mut by_extension: BTreeMap<&str, Vec<&BenchmarkResult>> = BTreeMap::new();
+    for result in results {
+        by_extension
+            .entry(result.file_extension.as_str())
+            .or_default()
+            .push(result);
+    }
+
+    let mut summaries = Vec::new();
+    for (extension, results) in by_extension {
+        for metric in WinnerMetric::ALL {
+            if let Some(summary) = summarize_metric(extension, metric, &results) {
+                summaries.push(summary);
+            }
+        }
+    }
+
+    WinnerReport {
+        significance_level: SIGNIFICANCE_LEVEL,
+        summaries,
+    }
+}
+
+fn summarize_metric(extension: &str, metric: WinnerMetric, results: &[&BenchmarkResult]) -> Option<WinnerSummary> {
+    let mut samples: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+    for result in results {
+        if let Some(value) = metric.sample(result).filter(|value| value.is_finite()) {
+            samples.entry(result.framework.as_str()).or_default().push(value);
+        }
+    }
+
+    let mut ranked: Vec<(&str, f64, &[f64])> = samples
+        .iter()
+        .map(|(framework, values)| (*framework, mean(values), values.as_slice()))
+        .collect();
+    ranked.sort_by(|a, b| {
+        let ordering = a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal);
+        if metric.higher_is_better() {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    let (winner, winner_value, winner_samples) = *ranked.first()?;
+    let mut summary = WinnerSummary {
+        extension: extension.to_string(),
+        metric,
+        unit: metric.unit().to_string(),
+        winner: winner.to_string(),
+        winner_value,
+        runner_up: None,
+        runner_up_value: None,
+        margin: None,
+        margin_percent: None,
+        p_value: None,
+        significance: Significance::Uncontested,
+        frameworks: ranked.len(),
+    };
+
+    if let Some(&(runner_up, runner_up_value, runner_up_samples)) = ranked.get(1) {
+        let margin = (runner_up_value - winner_value).abs();
+        summary.runner_up = Some(runner_up.to_string());
+        summary.runner_up_value = Some(runner_up_value);
+        summary.margin = Some(margin);
+        summary.margin_percent = (runner_up_value != 0.0).then(|| margin / runner_up_value.abs() * 100.0);
+
+        if winner_samples.len() < MIN_SAMPLES || runner_up_samples.len() < MIN_SAMPLES {
+            summary.significance = Significance::InsufficientData;
+        } else {
+            let p_value = mann_whitney_p_value(winner_samples, runner_up_samples);
+            summary.p_value = Some(p_value);
+            summary.significance = if p_value < SIGNIFICANCE_LEVEL {
+                Significance::Significant
+            } else {
+                Significance::NotSignificant
+            };
+        }
+    }
+
+    Some(summary)
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len().max(1) as f64
+}
+
+/// Two-sided p-value of the Mann-Whitney U test (normal approximation)
+fn mann_whitney_p_value(a: &[f64], b: &[f64]) -> f64 {
+    let n1 = a.len() as f64;
+    let n2 = b.len() as f64;
+    let n = n1 + n2;
+
+    let mut pooled: Vec<(f64, bool)> = a
+        .iter()
+        .map(|&value| (value, true))
+        .chain(b.iter().map(|&value| (value, false)))
+        .collect();
+    pooled.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Average ranks over ties, accumulating the tie correction term
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut i = 0;
+    while i < pooled.len() {
+        let mut j = i;
+        while j + 1 < pooled.len() && pooled[j + 1].0 == pooled[i].0 {
+            j += 1;
+        }
+        let tied = (j - i + 1) as f64;
+        let rank = (i + j) as f64 / 2.0 + 1.0;
+        rank_sum_a += rank * pooled[i..=j].iter().filter(|(_, from_a)| *from_a).count() as f64;
+        tie_term += tied.powi(3) - tied;
+        i = j + 1;
+    }
+
+    let u = rank_sum_a - n1 * (n1 + 1.0) / 2.0;
+    let mean_u = n1 * n2 / 2.0;
+    let variance = n1 * n2 / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)));
+    if variance <= 0.0 {
+        // All samples identical
+        return 1.0;
+    }
+
+    let z = ((u - mean_u).abs() - 0.5).max(0.0) / variance.sqrt();
+    erfc(z / std::f64::consts::SQRT_2).min(1.0)
+}
+
+/// Complementary error function (Abramowitz and Stegun 7.1.26, absolute error < 1.5e-7)
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+    let poly =
+        t * (0.254_829_592 + t * (-0.284_496_736 + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let value = poly * (-x * x).exp();
+    if x >= 0.0 { value } else { 2.0 - value }
+}
+
+/// Write winner summaries to a JSON file
+///
+/// # Arguments
+/// * `results` - Benchmark results to analyze
+/// * `output_path` - Path to output JSON file (e.g., "winners.json")
+pub fn write_winner_report(results: &[BenchmarkResult], output_path: &Path) -> Result<()> {
+    let report = summarize_winners(results);
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(Error::Io)?;
+    }
+
+    let json = serde_json::to_string_pretty(&report)
+        .map_err(|e| Error::Benchmark(format!("Failed to serialize winner report: {}", e)))?;
+
+    fs::write(output_path, json).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FrameworkCapabilities, PerformanceMetrics, QualityMetrics};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn make_result(framework: &str, extension: &str, duration_ms: u64, quality: Option<f64>) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from(format!("/tmp/test.{}", extension)),
+            file_size: 1000,
+            success: true,
+            error_message: None,
+            failure: None,
+            duration: Duration::from_millis(duration_ms),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            overhead_breakdown: None,
+            metrics: PerformanceMetrics {
+                peak_memory_bytes: 50_000_000,
+                avg_cpu_percent: 0.0,
+                throughput_bytes_per_sec: 0.0,
+                p50_memory_bytes: 0,
+                p95_memory_bytes: 0,
+                p99_memory_bytes: 0,
+            },
+            quality: quality.map(|quality_score| QualityMetrics {
+                f1_score_text: quality_score,
+                f1_score_numeric: quality_score,
+                f1_score_layout: quality_score,
+                quality_score,
+            }),
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            warmup: None,
+            file_extension: extension.to_string(),
+            framework_capabilities: FrameworkCapabilities::default(),
+            pdf_metadata: None,
+            provenance: None,
+            assertions: None,
+            resource_leaks: None,
+            extracted_output: None,
+        }
+    }
+
+    #[test]
+    fn test_clear_latency_win_is_significant() {
+        let mut results = Vec::new();
+        for i in 0..10 {
+            results.push(make_result("fast", "pdf", 10 + i, None));
+            results.push(make_result("slow", "pdf", 100 + i, None));
+        }
+
+        let report = summarize_winners(&results);
+        let latency = report.get("pdf", WinnerMetric::Latency).unwrap();
+
+        assert_eq!(latency.winner, "fast");
+        assert_eq!(latency.runner_up.as_deref(), Some("slow"));
+        assert!((latency.margin.unwrap() - 90.0).abs() < 1e-9);
+        assert!(latency.p_value.unwrap() < 0.001);
+        assert_eq!(latency.significance, Significance::Significant);
+        assert_eq!(latency.frameworks, 2);
+    }
+
+    #[test]
+    fn test_identical_samples_are_not_significant() {
+        let mut results = Vec::new();
+        for _ in 0..6 {
+            results.push(make_result("a", "docx", 20, None));
+            results.push(make_result("b", "docx", 20, None));
+        }
+
+        let report = summarize_winners(&results);
+        let memory = report.get("docx", WinnerMetric::Memory).unwrap();
+
+        assert_eq!(memory.p_value, Some(1.0));
+        assert_eq!(memory.significance, Significance::NotSignificant);
+        assert_eq!(memory.margin_percent, Some(0.0));
+    }
+
+    #[test]
+    fn test_quality_prefers_higher_scores_and_flags_small_samples() {
+        let results = vec![
+            make_result("a", "html", 10, Some(0.7)),
+            make_result("b", "html", 50, Some(0.9)),
+        ];
+
+        let report = summarize_winners(&results);
+        let quality = report.get("html", WinnerMetric::Quality).unwrap();
+
+        assert_eq!(quality.winner, "b");
+        assert_eq!(quality.significance, Significance::InsufficientData);
+        assert_eq!(quality.p_value, None);
+        assert_eq!(report.get("html", WinnerMetric::Latency).unwrap().winner, "a");
+    }
+
+    #[test]
+    fn test_uncontested_and_missing_quality() {
+        let results = vec![make_result("only", "txt", 5, None)];
+
+        let report = summarize_winners(&results);
+
+        let latency = report.get("txt", WinnerMetric::Latency).unwrap();
+        assert_eq!(latency.significance, Significance::Uncontested);
+        assert_eq!(latency.runner_up, None);
+        assert!(report.get("txt", WinnerMetric::Quality).is_none());
+    }
+
+    #[test]
+    fn test_failed_results_are_ignored() {
+        let mut failed = make_result("broken", "pdf", 1, None);
+        failed.success = false;
+        let results = vec![failed, make_result("working", "pdf", 500, None)];
+
+        let report = summarize_winners(&results);
+
+        assert_eq!(report.get("pdf", WinnerMetric::Latency).unwrap().winner, "working");
+    }
+
+    #[test]
+    fn test_erfc() {
+        assert!((erfc(0.0) - 1.0).abs() < 1e-6);
+        assert!((erfc(1.0) - 0.157_299_2).abs() < 1e-6);
+        assert!((erfc(-1.0) - 1.842_700_8).abs() < 1e-6);
+    }
+}
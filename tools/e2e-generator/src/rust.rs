@@ -363,16 +363,9 @@ fn generate_clear_registry_test_rust(test_spec: &PluginTestSpec, buf: &mut Strin
 
     if func_name == "clear_post_processors" {
         writeln!(buf, "    // Clear post-processors via registry (no helper function)")?;
-        writeln!(
-            buf,
-            "    let registry = kreuzberg::plugins::registry::get_post_processor_registry();"
-        )?;
-        writeln!(
-            buf,
-            "    let mut registry = registry.write().expect(\"Failed to acquire write lock\");"
-        )?;
-        writeln!(buf, "    registry.shutdown_all().expect(\"Failed to clear registry\");")?;
-        writeln!(buf, "    drop(registry);")?;
+        writeln!(buf, "    kreuzberg::plugins::registry::get_post_processor_registry()")?;
+        writeln!(buf, "        .update(|registry| registry.shutdown_all())")?;
+        writeln!(buf, "        .expect(\"Failed to clear registry\");")?;
         writeln!(buf)?;
         writeln!(
             buf,